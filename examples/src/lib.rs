@@ -528,7 +528,7 @@ unsafe fn create_instance(window: &Window, entry: &Entry, data: &mut AppData) ->
 
     // Extensions
 
-    let mut extensions = vk_window::get_required_instance_extensions(window)
+    let mut extensions = vk_window::get_required_instance_extensions(window)?
         .iter()
         .map(|e| e.as_ptr())
         .collect::<Vec<_>>();
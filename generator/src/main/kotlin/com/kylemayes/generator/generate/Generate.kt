@@ -8,12 +8,14 @@ import com.kylemayes.generator.generate.file.generateChains
 import com.kylemayes.generator.generate.file.generateCommandStructs
 import com.kylemayes.generator.generate.file.generateCommands
 import com.kylemayes.generator.generate.file.generateConstants
+import com.kylemayes.generator.generate.file.generateDestroys
 import com.kylemayes.generator.generate.file.generateEnums
 import com.kylemayes.generator.generate.file.generateExtensionTraits
 import com.kylemayes.generator.generate.file.generateExtensions
 import com.kylemayes.generator.generate.file.generateFunctions
 import com.kylemayes.generator.generate.file.generateHandles
 import com.kylemayes.generator.generate.file.generateHeaders
+import com.kylemayes.generator.generate.file.generateHooks
 import com.kylemayes.generator.generate.file.generateMacros
 import com.kylemayes.generator.generate.file.generateResultEnums
 import com.kylemayes.generator.generate.file.generateStructs
@@ -58,8 +60,11 @@ fun generateRustFiles(
     generateRustFile("vulkanalia", "vk/builders.rs", registry.generateBuilders()),
     generateRustFile("vulkanalia", "vk/chains.rs", registry.generateChains()),
     generateRustFile("vulkanalia", "vk/commands.rs", registry.generateCommandStructs()),
+    generateRustFile("vulkanalia", "vk/destroy.rs", registry.generateDestroys()),
     generateRustFile("vulkanalia", "vk/enums.rs", registry.generateResultEnums()),
     generateRustFile("vulkanalia", "vk/extensions.rs", registry.generateExtensionTraits()),
+    generateRustFile("vulkanalia", "vk/features.rs", registry.generateFeatures()),
+    generateRustFile("vulkanalia", "vk/hooks.rs", registry.generateHooks()),
     generateRustFile("vulkanalia", "vk/versions.rs", registry.generateVersionTraits()),
 )
 
@@ -133,6 +138,7 @@ private const val PREFIX =
     non_camel_case_types,
     non_snake_case,
     clippy::bad_bit_mask,
+    clippy::let_and_return,
     clippy::let_unit_value,
     clippy::missing_safety_doc,
     clippy::missing_transmute_annotations,
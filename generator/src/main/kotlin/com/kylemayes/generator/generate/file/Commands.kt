@@ -7,6 +7,7 @@ import com.kylemayes.generator.generate.support.generateManualUrl
 import com.kylemayes.generator.generate.support.getCommandType
 import com.kylemayes.generator.registry.Command
 import com.kylemayes.generator.registry.Registry
+import com.kylemayes.generator.registry.getIdentifier
 
 /** Generates Rust type aliases for Vulkan commands. */
 fun Registry.generateCommands() =
@@ -18,7 +19,70 @@ use crate::*;
 ${commands.values
         .sortedBy { it.name }
         .joinToString("\n") { generateCommand(it) }}
+
+/// A collection of metadata for a Vulkan command.
+#[derive(Copy, Clone, Debug)]
+pub struct CommandMetadata {
+    /// The name of the command (e.g., `vkCmdBeginRenderingKHR`).
+    pub name: &'static str,
+    /// The command this command is an alias of (e.g., `vkCmdBeginRendering`).
+    pub alias: Option<&'static str>,
+
+    /// The type of the command (`static`, `entry`, `instance`, or `device`).
+    pub type_: &'static str,
+
+    /// The Vulkan version that provides the command (e.g., `1.3`).
+    pub version: Option<&'static str>,
+    /// The Vulkan extensions that provide the command.
+    pub extensions: &'static [ExtensionName],
+}
+
+impl CommandMetadata {
+    /// Finds the metadata for the Vulkan command with the supplied name.
+    #[inline]
+    pub fn by_name(name: &str) -> Option<&'static CommandMetadata> {
+        COMMANDS.iter().find(|c| c.name == name)
+    }
+}
+
+/// The metadata for every Vulkan command.
+#[allow(deprecated)]
+pub const COMMANDS: &[CommandMetadata] = &[
+    ${commands.values
+        .sortedBy { it.name }
+        .joinToString("") { generateCommandMetadata(it) }}
+];
+    """
+
+/** Generates Rust metadata for a Vulkan command. */
+private fun Registry.generateCommandMetadata(command: Command): String {
+    val alias = commandAliases[command.name]?.let { "Some(\"${it.original}\")" } ?: "None"
+    val version = versions.values.find { it.require.commands.contains(command.name) }
+
+    val providers =
+        extensions.values
+            .filter { it.require.commands.contains(command.name) }
+            .sortedBy { it.name }
+    val names =
+        providers.joinToString("") {
+            "${if (it.provisional) "#[cfg(feature = \"provisional\")]" else ""} ${it.name}_EXTENSION.name,"
+        }
+
+    // Commands only provided by provisional extensions are only included when
+    // provisional extensions are enabled.
+    val provisional = version == null && providers.isNotEmpty() && providers.all { it.provisional }
+
+    return """
+${if (provisional) "#[cfg(feature = \"provisional\")]" else ""}
+CommandMetadata {
+    name: "${command.name.original}",
+    alias: $alias,
+    type_: "${getCommandType(command).display.lowercase()}",
+    version: ${version?.let { "Some(\"${it.number}\")" } ?: "None"},
+    extensions: &[$names],
+},
     """
+}
 
 /** Generates a Rust type alias for a Vulkan command. */
 private fun Registry.generateCommand(command: Command): String {
@@ -42,8 +106,8 @@ fun Registry.generateCommandStructs(): String {
                 generateCommandStruct(it.key, supported)
             }
     return """
+use core::ffi::{c_char, c_int, c_void, CStr};
 use core::mem;
-use core::ffi::{c_char, c_int, c_void};
 
 use super::*;
 
@@ -55,7 +119,39 @@ $structs
 private fun Registry.generateCommandStruct(
     type: CommandType,
     commands: List<Command>,
-) = """
+): String {
+    // Device commands can be restricted to those provided by a Vulkan version
+    // and the enabled device extensions since most applications only use a
+    // small fraction of the device commands in the registry.
+    val load =
+        if (type == CommandType.DEVICE) {
+            """
+#[inline]
+pub unsafe fn load(loader: impl FnMut(*const c_char) -> Option<unsafe extern "system" fn()>) -> Self {
+    Self::load_enabled(loader, u32::MAX, |_| true)
+}
+
+/// Loads only the commands provided by Vulkan versions up to and including
+/// `version` and by the extensions for which `enabled` returns `true`.
+#[inline]
+pub unsafe fn load_enabled(
+    mut loader: impl FnMut(*const c_char) -> Option<unsafe extern "system" fn()>,
+    version: u32,
+    mut enabled: impl FnMut(&CStr) -> bool,
+) -> Self {
+    Self { ${commands.joinToString { generateLoad(it, generateLoadCondition(it)) }} }
+}
+            """
+        } else {
+            """
+#[inline]
+pub unsafe fn load(mut loader: impl FnMut(*const c_char) -> Option<unsafe extern "system" fn()>) -> Self {
+    Self { ${commands.joinToString { generateLoad(it) }} }
+}
+            """
+        }
+
+    return """
 /// Loaded Vulkan ${type.display.lowercase()} commands.
 #[derive(Copy, Clone)]
 pub struct ${type.display}Commands {
@@ -63,31 +159,73 @@ pub struct ${type.display}Commands {
 }
 
 impl ${type.display}Commands {
-    #[inline]
-    pub unsafe fn load(mut loader: impl FnMut(*const c_char) -> Option<unsafe extern "system" fn()>) -> Self {
-        Self { ${commands.joinToString { generateLoad(it) }} }
-    }
+    $load
 }
     """
+}
+
+/** Generates the condition under which a command should be loaded (if any). */
+private fun Registry.generateLoadCondition(command: Command): String? {
+    val conditions = mutableListOf<String>()
+
+    val version = versions.values.find { it.require.commands.contains(command.name) }
+    if (version != null) {
+        // Vulkan 1.0 commands are always loaded.
+        if (version.number == 1.0f) return null
+        val (major, minor) = version.number.toString().split('.')
+        conditions.add("version >= make_version($major, $minor, 0)")
+    }
+
+    extensions.values
+        .filter { it.require.commands.contains(command.name) }
+        .sortedBy { it.name }
+        .forEach { conditions.add("enabled(c\"${it.name.original}\")") }
+
+    return conditions.takeIf { it.isNotEmpty() }?.joinToString(" || ")
+}
 
 /** Generates a Rust struct field-value pair to load a command. */
-private fun Registry.generateLoad(command: Command) =
-    """
+private fun Registry.generateLoad(
+    command: Command,
+    condition: String? = null,
+): String {
+    val panic = """panic!("could not load ${command.name.original}")"""
+
+    // Commands that return `VkResult` can report that they could not be
+    // loaded with an error code instead of panicking (if enabled).
+    val body =
+        if (command.result.getIdentifier()?.value == "Result") {
+            """
+if cfg!(feature = "fallible_commands") {
+    Result::ERROR_EXTENSION_NOT_PRESENT
+} else {
+    $panic
+}
+            """
+        } else {
+            panic
+        }
+
+    val load = """loader(c"${command.name.original}".as_ptr())"""
+    val value = condition?.let { "if $it { $load } else { None }" } ?: load
+
+    return """
 ${command.name}: {
-    let value = loader(c"${command.name.original}".as_ptr());
+    let value = $value;
     if let Some(value) = value {
         mem::transmute(value)
     } else {
         ${generateSignature(command, "fallback")} {
-            panic!("could not load ${command.name.original}")
+            $body
         }
         fallback
     }
 }
     """
+}
 
 /** Generates a Rust function signature for a Vulkan command. */
-private fun generateSignature(
+fun generateSignature(
     command: Command,
     name: String = "",
 ): String {
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: Apache-2.0
+
+package com.kylemayes.generator.generate.file
+
+import com.kylemayes.generator.generate.support.getCommandType
+import com.kylemayes.generator.generate.support.getExtensionTraits
+import com.kylemayes.generator.registry.Command
+import com.kylemayes.generator.registry.Handle
+import com.kylemayes.generator.registry.PointerType
+import com.kylemayes.generator.registry.Registry
+import com.kylemayes.generator.registry.getIdentifier
+import com.kylemayes.generator.support.toPascalCase
+
+/** Generates Rust implementations of the `Destroy` trait for Vulkan handles. */
+fun Registry.generateDestroys(): String {
+    val impls =
+        handles.values
+            .sortedBy { it.name }
+            .mapNotNull { handle -> getDestroyCommand(handle)?.let { generateDestroy(handle, it) } }
+
+    return """
+use super::*;
+use crate::owned::Destroy;
+
+${impls.joinToString("")}
+    """
+}
+
+/**
+ * Finds the command which destroys a Vulkan handle.
+ *
+ * Only commands which take the parent instance or device, the handle, and
+ * allocation callbacks (e.g., `vkDestroyBuffer` or `vkFreeMemory`) are used.
+ */
+private fun Registry.getDestroyCommand(handle: Handle): Command? =
+    commands.values.find {
+        val name = it.name.original
+        val params = it.params.map { p -> p.type }
+        !commandAliases.containsKey(it.name) &&
+            (name.startsWith("vkDestroy") || name.startsWith("vkFree")) &&
+            params.size == 3 &&
+            params[0].getIdentifier()?.value in setOf("Instance", "Device") &&
+            params[1].getIdentifier() == handle.name &&
+            (params[2] as? PointerType)?.pointee?.getIdentifier()?.value == "AllocationCallbacks"
+    }
+
+/** Generates a Rust implementation of the `Destroy` trait for a Vulkan handle. */
+private fun Registry.generateDestroy(
+    handle: Handle,
+    command: Command,
+): String? {
+    val type = getCommandType(command).display
+
+    // The aliases of core commands (e.g., `vkDestroySamplerYcbcrConversionKHR`)
+    // are used when the core command was not loaded (e.g., for a Vulkan 1.0
+    // device which enabled the extension the command was promoted from).
+    val aliases =
+        commandAliases.entries
+            .filter { it.value == command.name }
+            .sortedBy { it.key }
+            .mapNotNull { commands[it.key] }
+
+    val destroys = listOf(command) + aliases
+    val calls = destroys.map { generateDestroyCall(it) ?: return null }
+    val body =
+        if (calls.size == 1) {
+            calls[0].second
+        } else {
+            val branches =
+                destroys
+                    .zip(calls)
+                    .dropLast(1)
+                    .joinToString(" else ") { (destroy, call) ->
+                        "if parent.is_command_loaded(\"${destroy.name.original}\") { ${call.second} }"
+                    }
+            "$branches else { ${calls.last().second} }"
+        }
+
+    return """
+${calls[0].first}
+impl Destroy for ${handle.name} {
+    type Parent = crate::$type;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        $body
+    }
+}
+    """
+}
+
+/**
+ * Generates a Rust call to a command which destroys a Vulkan handle along with
+ * the attributes required by the trait which contains the command.
+ */
+private fun Registry.generateDestroyCall(command: Command): Pair<String, String>? {
+    val type = getCommandType(command).display
+
+    val version = versions.values.find { it.require.commands.contains(command.name) }
+    val extension = getExtensionTraits().find { it.commands.contains(command) }
+
+    val (trait, attributes) =
+        when {
+            version != null -> Pair("${type}V${version.number.toString().replace('.', '_')}", "")
+            extension != null ->
+                Pair(
+                    "${extension.extension.name.value.toPascalCase()}Extension${extension.type.display}Commands",
+                    if (extension.extension.provisional) "#[cfg(feature = \"provisional\")]" else "",
+                )
+            else -> return null
+        }
+
+    return Pair(attributes, "$trait::${command.name}(parent, self, allocator);")
+}
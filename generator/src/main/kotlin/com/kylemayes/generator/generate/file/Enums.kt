@@ -112,6 +112,9 @@ pub struct ${enum.name}(i32);
 impl ${enum.name} {
     ${enum.variants.joinToString("") { "pub const ${it.name}: Self = Self(${it.value});" }}
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[${enum.variants.joinToString { "Self::${it.name}" }}];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -35,6 +35,7 @@ pub type ExtensionName = StringArray<MAX_EXTENSION_NAME_SIZE>;
 
 /// A collection of metadata for a Vulkan extension.
 #[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
 pub struct Extension {
     /// The name of the extension.
     pub name: ExtensionName,
@@ -53,6 +54,10 @@ pub struct Extension {
     pub platform: Option<&'static str>,
 
     /// The other extensions required by the extension.
+    ///
+    /// If the extension can instead depend on one of several alternatives
+    /// (e.g., `VK_KHR_get_physical_device_properties2` or Vulkan 1.1), only
+    /// the first alternative is included here and in `required_version`.
     pub required_extensions: Option<&'static [ExtensionName]>,
     /// The Vulkan version required by the extension (e.g., `1.1`).
     pub required_version: Option<&'static str>,
@@ -63,12 +68,38 @@ pub struct Extension {
     pub obsoleted_by: Option<&'static str>,
     /// The Vulkan version the extension was promoted to core in (e.g., `VK_VERSION_1_1`).
     pub promoted_to: Option<&'static str>,
+
+    /// The commands added by the extension (e.g., `vkCmdBeginRenderingKHR`).
+    pub commands: &'static [&'static str],
+}
+
+impl Extension {
+    /// Finds the metadata for the Vulkan extension with the supplied name.
+    #[inline]
+    pub fn by_name(name: &ExtensionName) -> Option<&'static Extension> {
+        EXTENSIONS.iter().find(|e| e.name == *name)
+    }
+
+    /// Finds the metadata for the Vulkan extension with the supplied number.
+    #[inline]
+    pub fn by_number(number: i32) -> Option<&'static Extension> {
+        EXTENSIONS.iter().find(|e| e.number == number)
+    }
 }
 
 ${getExtensionGroups().values
         .flatten()
         .sortedBy { it.name }
         .joinToString("") { generateExtension(it) }}
+
+/// The metadata for every Vulkan extension.
+#[allow(deprecated)]
+pub const EXTENSIONS: &[Extension] = &[
+    ${getExtensionGroups().values
+        .flatten()
+        .sortedBy { it.name }
+        .joinToString("") { "${if (it.provisional) "#[cfg(feature = \"provisional\")]" else ""} ${it.name}_EXTENSION," }}
+];
     """
 
 /** Generates a Rust constant for a Vulkan extension. */
@@ -76,17 +107,29 @@ private fun Registry.generateExtension(extension: Extension): String {
     val provisional = if (extension.provisional) PROVISIONAL else ""
     val deprecation = generateDeprecation(extension)?.let { "\n$it" } ?: ""
 
+    val (requires, requiresCore) =
+        if (extension.depends != null) {
+            parseDepends(extension.depends)
+        } else {
+            Pair(extension.requires?.split(",") ?: emptyList(), extension.requiresCore)
+        }
+
+    // Dependencies on extensions which are not supported by Vulkan (e.g.,
+    // extensions only supported by Vulkan SC) are ignored.
+    val names = requires.mapNotNull { extensions[it.intern()]?.name }
     val extensions =
-        if (extension.requires != null) {
-            val names =
-                extension.requires.split(",")
-                    .map { extensions[it.intern()]!!.name }
-                    .joinToString { "${it}_EXTENSION.name" }
-            "Some(&[$names])"
+        if (names.isNotEmpty()) {
+            "Some(&[${names.joinToString { "${it}_EXTENSION.name" }}])"
         } else {
             "None"
         }
 
+    val commands =
+        extension.require.commands
+            .filter { this.commands.containsKey(it) }
+            .map { it.original }
+            .sorted()
+
     return """
 /// <${generateManualUrl(extension)}>$provisional$deprecation
 #[allow(deprecated)]
@@ -98,14 +141,66 @@ pub const ${extension.name}_EXTENSION: Extension = Extension {
     contact: "${extension.contact}",
     platform: ${extension.platform?.let { "Some(\"$it\")" } ?: "None"},
     required_extensions: $extensions,
-    required_version: ${extension.requiresCore?.let { "Some(\"$it\")" } ?: "None"},
+    required_version: ${requiresCore?.let { "Some(\"$it\")" } ?: "None"},
     deprecated_by: ${extension.deprecatedby?.let { "Some(\"$it\")" } ?: "None"},
     obsoleted_by: ${extension.obsoletedby?.let { "Some(\"$it\")" } ?: "None"},
     promoted_to: ${extension.promotedto?.let { "Some(\"$it\")" } ?: "None"},
+    commands: &[${commands.joinToString { "\"$it\"" }}],
 };
     """
 }
 
+/**
+ * Parses the dependencies of a Vulkan extension (e.g., `VK_KHR_surface+VK_VERSION_1_1`) into the
+ * extensions and Vulkan version (e.g., `1.1`) required by the extension.
+ *
+ * In the dependency expressions, `+` separates required dependencies and `,` separates alternative
+ * dependencies. Only the first of a set of alternative dependencies is used since the alternatives
+ * are usually an extension followed by the Vulkan version the extension was promoted to (e.g.,
+ * `VK_KHR_get_physical_device_properties2,VK_VERSION_1_1`).
+ */
+private fun parseDepends(depends: String): Pair<List<String>, String?> {
+    val extensions = mutableListOf<String>()
+    var version: String? = null
+
+    fun parse(expression: String) {
+        val terms = expression.splitTopLevel('+')
+        for (term in terms) {
+            val alternatives = term.splitTopLevel(',')
+            val first = alternatives[0]
+            if (alternatives.size > 1 || first.startsWith("(")) {
+                parse(first.removeSurrounding("(", ")"))
+            } else if (first.startsWith("VK_VERSION_")) {
+                version = first.removePrefix("VK_VERSION_").replace('_', '.')
+            } else if (!extensions.contains(first)) {
+                extensions.add(first)
+            }
+        }
+    }
+
+    parse(depends)
+    return Pair(extensions, version)
+}
+
+/** Splits a string on a separator which is not enclosed in parentheses. */
+private fun String.splitTopLevel(separator: Char): List<String> {
+    val parts = mutableListOf<String>()
+    var depth = 0
+    var start = 0
+    for ((index, char) in withIndex()) {
+        when {
+            char == '(' -> depth += 1
+            char == ')' -> depth -= 1
+            char == separator && depth == 0 -> {
+                parts.add(substring(start, index))
+                start = index + 1
+            }
+        }
+    }
+    parts.add(substring(start))
+    return parts.map { it.trim() }
+}
+
 /** Generates Rust modules and traits for Vulkan extensions. */
 fun Registry.generateExtensionTraits() =
     """
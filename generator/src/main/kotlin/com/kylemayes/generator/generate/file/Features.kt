@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: Apache-2.0
+
+package com.kylemayes.generator.generate.file
+
+import com.kylemayes.generator.generate.support.getStructExtensions
+import com.kylemayes.generator.registry.Registry
+import com.kylemayes.generator.registry.Structure
+import com.kylemayes.generator.registry.intern
+
+/** Generates Rust implementations of the `Features` trait for Vulkan feature structs. */
+fun Registry.generateFeatures(): String {
+    val extensions = getStructExtensions()["VkPhysicalDeviceFeatures2".intern()] ?: emptyList()
+    val features = listOf("VkPhysicalDeviceFeatures".intern()) + extensions.sorted()
+
+    val supported =
+        features
+            .map { structs[it] ?: error("Missing feature struct.") }
+            .mapNotNull { s -> generateFeature(s)?.let { Pair(s, it) } }
+
+    val arms =
+        supported
+            .filter { (s, _) -> s.members.any { it.name.original == "sType" } }
+            .joinToString("\n") { (s, _) ->
+                val type = s.members.find { it.name.original == "sType" }?.values?.value
+                "StructureType::$type => self.insert(${s.name} { next: ptr::null_mut(), ..*base.cast() }),"
+            }
+
+    val inserts =
+        supported
+            .filter { (s, _) -> s.name.original != "VkPhysicalDeviceFeatures" }
+            .joinToString("\n") { (s, _) -> generateFeatureInsert(s) }
+
+    return """
+use alloc::collections::btree_set::BTreeSet;
+use core::{mem, ptr, slice};
+
+use super::*;
+use crate::features::{FeatureSet, Features};
+use crate::Version;
+
+${supported.joinToString("") { it.second }}
+
+impl FeatureSet {
+    /// Constructs a new empty set of features which contains every feature
+    /// struct provided by a Vulkan version or by any of a set of device
+    /// extensions (e.g., the extensions supported by a physical device).
+    #[allow(deprecated)]
+    pub fn all(version: Version, extensions: &BTreeSet<ExtensionName>) -> Self {
+        let mut set = Self::new();
+        $inserts
+        set
+    }
+
+    /// Adds a copy of a feature struct in a pointer chain to this set.
+    ///
+    /// Returns `false` if the struct is not a feature struct.
+    pub(crate) unsafe fn insert_raw(&mut self, base: *const BaseInStructure) -> bool {
+        match (*base).s_type {
+            $arms
+            _ => return false,
+        }
+
+        true
+    }
+}
+    """
+}
+
+/**
+ * Generates a Rust statement which adds a Vulkan feature struct to a `FeatureSet` if the Vulkan
+ * version or one of the extensions which provide the feature struct (or an alias of it) is present.
+ */
+private fun Registry.generateFeatureInsert(struct: Structure): String {
+    val names =
+        setOf(struct.name.original) +
+            aliases.values
+                .filter { it.type.identifier == struct.name }
+                .map { it.name.original }
+
+    val version =
+        versions.values
+            .filter { v -> v.require.types.any { names.contains(it) } }
+            .minOfOrNull { it.number }
+    val providers =
+        extensions.values
+            .filter { e -> e.require.types.any { names.contains(it) } }
+            .sortedBy { it.name }
+
+    val conditions = ArrayList<String>()
+    if (version != null) {
+        val (major, minor) = version.toString().split('.')
+        conditions.add("version >= Version::V${major}_${minor}_0")
+    }
+
+    conditions.addAll(providers.filter { !it.provisional }.map { "extensions.contains(&${it.name}_EXTENSION.name)" })
+
+    val provisional = providers.filter { it.provisional }
+    if (provisional.isNotEmpty()) {
+        val condition = provisional.joinToString(" || ") { "extensions.contains(&${it.name}_EXTENSION.name)" }
+        return """
+#[cfg(feature = "provisional")]
+if ${(conditions + condition).joinToString(" || ")} {
+    set.insert(${struct.name}::default());
+}
+        """
+    }
+
+    return """
+if ${conditions.joinToString(" || ")} {
+    set.insert(${struct.name}::default());
+}
+    """
+}
+
+/**
+ * Generates a Rust implementation of the `Features` trait for a Vulkan feature struct.
+ *
+ * Only structs which contain nothing but `VkBool32` feature flags (after the
+ * `sType` and `pNext` members, if present) are supported.
+ */
+private fun Registry.generateFeature(struct: Structure): String? {
+    val flags = struct.members.filter { it.name.value != "s_type" && it.name.value != "next" }
+    if (flags.isEmpty() || flags.any { it.type.generate() != "Bool32" }) {
+        return null
+    }
+
+    val first = flags[0].name
+    val names = flags.joinToString { "\"${it.name}\"" }
+
+    return """
+unsafe impl Features for ${struct.name} {
+    const NAME: &'static str = "${struct.name.original}";
+    const NAMES: &'static [&'static str] = &[$names];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, $first);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), ${flags.size}) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, $first);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), ${flags.size}) }
+    }
+}
+    """
+}
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: Apache-2.0
+
+package com.kylemayes.generator.generate.file
+
+import com.kylemayes.generator.generate.support.CommandType
+import com.kylemayes.generator.generate.support.getCommandType
+import com.kylemayes.generator.registry.Command
+import com.kylemayes.generator.registry.Registry
+import com.kylemayes.generator.registry.getIdentifier
+
+/** Generates Rust functions which wrap Vulkan commands with command hooks. */
+fun Registry.generateHooks(): String {
+    val types = commands.values.groupBy { getCommandType(it) }
+    val device = types[CommandType.DEVICE]!!.sortedBy { it.name }
+    val instance = types[CommandType.INSTANCE]!!.sortedBy { it.name }
+
+    return """
+use core::ffi::{c_char, c_int, c_void};
+
+use super::*;
+
+${generateHooked(CommandType.DEVICE, device)}
+${generateHooked(CommandType.INSTANCE, instance)}
+
+${(device + instance).joinToString("\n") { generateHook(it) }}
+    """
+}
+
+/** Generates a Rust method which creates a group of Vulkan commands wrapped with command hooks. */
+private fun generateHooked(
+    type: CommandType,
+    commands: List<Command>,
+): String {
+    val lower = type.display.lowercase()
+    val fields = commands.joinToString { "${it.name}: ${it.name.original}::<S>" }
+
+    return """
+impl ${type.display}Commands {
+    /// Creates $lower commands which call the command hooks installed in a
+    /// hook slot around the unhooked $lower commands installed in the slot.
+    #[inline]
+    pub(crate) fn hooked<const S: usize>() -> Self {
+        Self { $fields }
+    }
+}
+    """
+}
+
+/** Generates a Rust function which calls the command hooks around a Vulkan command. */
+private fun generateHook(command: Command): String {
+    val type = getCommandType(command).display.lowercase()
+    val name = command.name.original
+    val params = command.params.map { "_${it.name.value.removePrefix("_")}" }
+    val call = "(hooked.commands.${command.name})(${params.joinToString()})"
+
+    val body =
+        when (command.result.getIdentifier()?.value) {
+            "Result" ->
+                """
+let __result = match hooked.before("$name") {
+    Some(result) => result,
+    None => $call,
+};
+hooked.after("$name", Some(__result));
+__result
+                """
+            "void" ->
+                """
+hooked.before("$name");
+$call;
+hooked.after("$name", None);
+                """
+            else ->
+                """
+hooked.before("$name");
+let __result = $call;
+hooked.after("$name", None);
+__result
+                """
+        }
+
+    return """
+${generateSignature(command, "$name<const S: usize>")} {
+    let hooked = crate::hook::$type::<S>();
+    $body
+}
+    """
+}
@@ -114,7 +114,7 @@ pub trait $name {
 }
 
 impl $name for crate::${type.display} {
-    #[inline] fn commands(&self) -> &$commandType { &self.commands }
+    #[inline] fn commands(&self) -> &$commandType { &self.state.commands }
 
     ${if (handle) "#[inline] fn handle(&self) -> ${type.display} { self.handle }" else ""}
 }
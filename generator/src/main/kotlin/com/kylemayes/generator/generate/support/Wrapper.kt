@@ -15,14 +15,34 @@ import com.kylemayes.generator.support.PeekableIterator
 /** These commands don't work with uninitialized `Vec`s for some reason. */
 private val defaultInitVecCommands = setOf("vkGetPhysicalDeviceCooperativeMatrixPropertiesKHR")
 
-/** Generates a more Rust-friendly wrapper method around a command for a version or extension trait. */
+/**
+ * Generates a more Rust-friendly wrapper method around a command for a version or extension trait.
+ *
+ * If the command has output slice parameters, an additional `_into` wrapper method which writes the
+ * outputs into caller-provided `Vec`s instead of allocating new `Vec`s is also generated.
+ */
 fun Registry.generateCommandWrapper(command: Command): String {
+    val wrapper = generateCommandWrapper(command, into = false)
+    return if (wrapper.contains("Vec::with_capacity") || wrapper.contains("::alloc::vec!")) {
+        wrapper + generateCommandWrapper(command, into = true)
+    } else {
+        wrapper
+    }
+}
+
+/** Generates a wrapper method around a command (optionally an `_into` wrapper method). */
+private fun Registry.generateCommandWrapper(
+    command: Command,
+    into: Boolean,
+): String {
     val type = getCommandType(command)
     val hasSuccessCodes = getCommandSuccessCodes(command).isNotEmpty()
     val hasErrorCodes = command.result.getIdentifier()?.value == "Result"
 
     // The Rust method parameters.
     val params = mutableListOf<String>()
+    // The Rust method parameters for caller-provided outputs (for `_into` wrapper methods).
+    val intoParams = mutableListOf<String>()
     // The Rust method result types (which will combined into a single type).
     val resultTypes = mutableListOf<String>()
     // The Rust method result expressions (which will combined into a single expression).
@@ -37,6 +57,10 @@ fun Registry.generateCommandWrapper(command: Command): String {
     val setupArgs = mutableListOf<String>()
     // The Rust arguments for the command after setup.
     val actualArgs = mutableListOf<String>()
+    // The fields of the `tracing` span for the command (if enabled).
+    val spanFields = mutableListOf<String>()
+    // The fields of the `tracing` span for the command recorded after it is created.
+    val spanRecords = mutableListOf<String>()
 
     // Some commands return a value directly instead of returning values using
     // output pointer parameters.
@@ -59,7 +83,7 @@ fun Registry.generateCommandWrapper(command: Command): String {
     // command, the first command parameter must be either an `Instance` or
     // `Device` handle and will be provided by the version or extension trait.
     if (iterator.peek()?.type?.getIdentifier()?.value == type.display) {
-        iterator.advance()
+        spanFields.add("${iterator.advance().name} = tracing::field::debug(self.handle())")
         addArgument("self.handle()")
     }
 
@@ -78,9 +102,15 @@ fun Registry.generateCommandWrapper(command: Command): String {
                 addArgument("${current.name}.as_ptr()$cast")
             } else {
                 // Output slice parameter.
-                resultTypes.add("Vec<${pointee.generate()}>")
-                resultExprs.add(current.name.value)
-                preActualStmts.add("let mut ${current.name} = Vec::with_capacity($length as usize);")
+                if (into) {
+                    intoParams.add("${current.name}: &mut Vec<${pointee.generate()}>")
+                    preActualStmts.add("${current.name}.clear();")
+                    preActualStmts.add("${current.name}.reserve($length as usize);")
+                } else {
+                    resultTypes.add("Vec<${pointee.generate()}>")
+                    resultExprs.add(current.name.value)
+                    preActualStmts.add("let mut ${current.name} = Vec::with_capacity($length as usize);")
+                }
                 postActualStmts.add("${current.name}.set_len($length as usize);")
                 addArgument("${current.name}.as_mut_ptr()")
             }
@@ -122,18 +152,29 @@ fun Registry.generateCommandWrapper(command: Command): String {
                             "${slices[0].name}.len()"
                         }
 
-                    if (pointee.getIdentifier()?.value == "void") {
-                        resultTypes.add("Vec<u8>")
-                        addArgument("${slice.name}.as_mut_ptr() as *mut c_void", setup = "ptr::null_mut()")
+                    val item =
+                        if (pointee.getIdentifier()?.value == "void") {
+                            addArgument("${slice.name}.as_mut_ptr() as *mut c_void", setup = "ptr::null_mut()")
+                            "u8"
+                        } else {
+                            addArgument("${slice.name}.as_mut_ptr()", setup = "ptr::null_mut()")
+                            pointee.generate()
+                        }
+
+                    if (into) {
+                        intoParams.add("${slice.name}: &mut Vec<$item>")
+                        preActualStmts.add("${slice.name}.clear();")
                     } else {
-                        resultTypes.add("Vec<${pointee.generate()}>")
-                        addArgument("${slice.name}.as_mut_ptr()", setup = "ptr::null_mut()")
+                        resultTypes.add("Vec<$item>")
+                        resultExprs.add(slice.name.value)
                     }
 
-                    resultExprs.add(slice.name.value)
-
-                    if (defaultInitVecCommands.contains(command.name.original)) {
-                        preActualStmts.add("let mut ${slice.name} = ::alloc::vec![${pointee.generate()}::default(); $length as usize];")
+                    if (into && defaultInitVecCommands.contains(command.name.original)) {
+                        preActualStmts.add("${slice.name}.resize($length as usize, $item::default());")
+                    } else if (into) {
+                        preActualStmts.add("${slice.name}.reserve($length as usize);")
+                    } else if (defaultInitVecCommands.contains(command.name.original)) {
+                        preActualStmts.add("let mut ${slice.name} = ::alloc::vec![$item::default(); $length as usize];")
                     } else {
                         preActualStmts.add("let mut ${slice.name} = Vec::with_capacity($length as usize);")
                     }
@@ -244,6 +285,16 @@ fun Registry.generateCommandWrapper(command: Command): String {
             // Value parameter (non-boolean).
             params.add("${current.name}: ${current.type.generate()}")
             addArgument(current.name.value)
+            if (handles.containsKey(current.type.getIdentifier())) {
+                // The `tracing` span macros import `debug` and `display`
+                // functions which shadow parameters with the same names.
+                if (current.name.value == "debug" || current.name.value == "display") {
+                    spanFields.add("${current.name} = tracing::field::Empty")
+                    spanRecords.add("""__span.record("${current.name}", tracing::field::debug(${current.name}));""")
+                } else {
+                    spanFields.add("${current.name} = tracing::field::debug(${current.name})")
+                }
+            }
         }
     }
 
@@ -259,10 +310,41 @@ fun Registry.generateCommandWrapper(command: Command): String {
             else -> ""
         }
 
+    // Generate `tracing` span (if enabled).
+
+    if (hasErrorCodes) {
+        spanFields.add("result = tracing::field::Empty")
+    }
+
+    val span =
+        """
+#[cfg(feature = "tracing")]
+let __span = tracing::trace_span!(${(listOf("\"${command.name.original}\"") + spanFields).joinToString()}).entered();
+${spanRecords.joinToString("") { "#[cfg(feature = \"tracing\")] $it" }}
+        """
+
+    val record =
+        if (hasErrorCodes) {
+            """
+#[cfg(feature = "tracing")]
+__span.record("result", tracing::field::debug(__result));
+            """
+        } else {
+            ""
+        }
+
     // Generate setup command invocation, if required.
 
+    // Commands that use a setup command invocation to query the length of
+    // their output slices return `INCOMPLETE` if the length increases before
+    // the actual command invocation, in which case the command invocations
+    // need to be repeated.
+    val retry = preSetupStmts.isNotEmpty() && command.successcodes.any { it.value == "INCOMPLETE" }
+
     val setup =
-        if (preSetupStmts.isNotEmpty()) {
+        if (retry) {
+            preSetupStmts.joinToString("")
+        } else if (preSetupStmts.isNotEmpty()) {
             """
 ${preSetupStmts.joinToString("")}
 
@@ -297,23 +379,75 @@ if __result == Result::SUCCESS {
             else -> ""
         }
 
+    // The outputs written into caller-provided `Vec`s are only made visible if
+    // the command succeeded.
+    val post =
+        if (into && hasErrorCodes && postActualStmts.isNotEmpty()) {
+            """
+if __result >= Result::SUCCESS {
+    ${postActualStmts.joinToString("")}
+}
+            """
+        } else {
+            postActualStmts.joinToString("")
+        }
+
     val actual =
-        """
+        if (retry) {
+            """
+loop {
+    let __result = ${generateInvocation(command, setupArgs)};
+
+    if __result < Result::SUCCESS {
+        $record break Err(__result.into());
+    }
+
+    ${preActualStmts.joinToString("")}
+
+    let __result = ${generateInvocation(command, actualArgs)};
+
+    if __result == Result::INCOMPLETE {
+        continue;
+    }
+    $record
+    $post
+
+    break $outputExpr;
+}
+            """
+        } else {
+            """
 ${preActualStmts.joinToString("")}
 
 let __result = ${generateInvocation(command, actualArgs)};
-
-${postActualStmts.joinToString("")}
+$record
+$post
 
 $outputExpr
-        """
+            """
+        }
 
     // Generate wrapper method.
 
+    val (name, docs) =
+        if (into) {
+            Pair(
+                "${command.name}_into",
+                """
+///
+/// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+/// first) instead of allocating new `Vec`(s) like [`Self::${command.name}`].
+                """.trimEnd(),
+            )
+        } else {
+            Pair(command.name.value, "")
+        }
+
     return """
-/// <${generateManualUrl(command)}>
+/// <${generateManualUrl(command)}>$docs
 #[inline]
-unsafe fn ${command.name}(&self, ${params.joinToString()})$outputType {
+unsafe fn $name(&self, ${(params + intoParams).joinToString()})$outputType {
+    $span
     $setup
     $actual
 }
@@ -412,6 +412,7 @@ data class Extension(
     val platform: String?,
     val requires: String?,
     val requiresCore: String?,
+    val depends: String?,
     val deprecatedby: String?,
     val obsoletedby: String?,
     val promotedto: String?,
@@ -431,6 +432,7 @@ private fun extractExtension(e: Element) =
         platform = e.getAttributeText("platform"),
         requires = e.getAttributeText("requires"),
         requiresCore = e.getAttributeText("requiresCore"),
+        depends = e.getAttributeText("depends"),
         deprecatedby = e.getAttributeText("deprecatedby"),
         obsoletedby = e.getAttributeText("obsoletedby"),
         promotedto = e.getAttributeText("promotedto"),
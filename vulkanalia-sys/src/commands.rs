@@ -9,6 +9,7 @@
     non_camel_case_types,
     non_snake_case,
     clippy::bad_bit_mask,
+    clippy::let_and_return,
     clippy::let_unit_value,
     clippy::missing_safety_doc,
     clippy::missing_transmute_annotations,
@@ -5296,3 +5297,5868 @@ pub type PFN_vkWriteSamplerDescriptorsEXT = unsafe extern "system" fn(
     _samplers: *const SamplerCreateInfo,
     _descriptors: *const HostAddressRangeEXT,
 ) -> Result;
+
+/// A collection of metadata for a Vulkan command.
+#[derive(Copy, Clone, Debug)]
+pub struct CommandMetadata {
+    /// The name of the command (e.g., `vkCmdBeginRenderingKHR`).
+    pub name: &'static str,
+    /// The command this command is an alias of (e.g., `vkCmdBeginRendering`).
+    pub alias: Option<&'static str>,
+
+    /// The type of the command (`static`, `entry`, `instance`, or `device`).
+    pub type_: &'static str,
+
+    /// The Vulkan version that provides the command (e.g., `1.3`).
+    pub version: Option<&'static str>,
+    /// The Vulkan extensions that provide the command.
+    pub extensions: &'static [ExtensionName],
+}
+
+impl CommandMetadata {
+    /// Finds the metadata for the Vulkan command with the supplied name.
+    #[inline]
+    pub fn by_name(name: &str) -> Option<&'static CommandMetadata> {
+        COMMANDS.iter().find(|c| c.name == name)
+    }
+}
+
+/// The metadata for every Vulkan command.
+#[allow(deprecated)]
+pub const COMMANDS: &[CommandMetadata] = &[
+    CommandMetadata {
+        name: "vkAcquireDrmDisplayEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_ACQUIRE_DRM_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkAcquireFullScreenExclusiveModeEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_FULL_SCREEN_EXCLUSIVE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkAcquireNextImage2KHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            KHR_DEVICE_GROUP_EXTENSION.name,
+            KHR_SWAPCHAIN_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkAcquireNextImageKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SWAPCHAIN_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkAcquirePerformanceConfigurationINTEL",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[INTEL_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkAcquireProfilingLockKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkAcquireWinrtDisplayNV",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[NV_ACQUIRE_WINRT_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkAcquireXlibDisplayEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_ACQUIRE_XLIB_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkAllocateCommandBuffers",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkAllocateDescriptorSets",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkAllocateMemory",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkAntiLagUpdateAMD",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[AMD_ANTI_LAG_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkBeginCommandBuffer",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkBindAccelerationStructureMemoryNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_RAY_TRACING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkBindBufferMemory",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkBindBufferMemory2",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkBindBufferMemory2KHR",
+        alias: Some("vkBindBufferMemory2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_BIND_MEMORY2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkBindDataGraphPipelineSessionMemoryARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_DATA_GRAPH_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkBindImageMemory",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkBindImageMemory2",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkBindImageMemory2KHR",
+        alias: Some("vkBindImageMemory2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_BIND_MEMORY2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkBindOpticalFlowSessionImageNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_OPTICAL_FLOW_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkBindTensorMemoryARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_TENSORS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkBindVideoSessionMemoryKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkBuildAccelerationStructuresKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkBuildMicromapsEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginConditionalRenderingEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_CONDITIONAL_RENDERING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginCustomResolveEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_CUSTOM_RESOLVE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginDebugUtilsLabelEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_UTILS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginPerTileExecutionQCOM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[QCOM_TILE_SHADING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginQuery",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginQueryIndexedEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_TRANSFORM_FEEDBACK_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginRenderPass",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginRenderPass2",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginRenderPass2KHR",
+        alias: Some("vkCmdBeginRenderPass2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_CREATE_RENDERPASS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginRendering",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginRenderingKHR",
+        alias: Some("vkCmdBeginRendering"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DYNAMIC_RENDERING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginTransformFeedbackEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_TRANSFORM_FEEDBACK_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBeginVideoCodingKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindDescriptorBufferEmbeddedSamplers2EXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE6_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindDescriptorBufferEmbeddedSamplersEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindDescriptorBuffersEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindDescriptorSets",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBindDescriptorSets2",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBindDescriptorSets2KHR",
+        alias: Some("vkCmdBindDescriptorSets2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE6_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindIndexBuffer",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBindIndexBuffer2",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBindIndexBuffer2KHR",
+        alias: Some("vkCmdBindIndexBuffer2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE5_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindInvocationMaskHUAWEI",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[HUAWEI_INVOCATION_MASK_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindPipeline",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBindPipelineShaderGroupNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindResourceHeapEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_HEAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindSamplerHeapEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_HEAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindShadersEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_SHADER_OBJECT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindShadingRateImageNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_SHADING_RATE_IMAGE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindTileMemoryQCOM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[QCOM_TILE_MEMORY_HEAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindTransformFeedbackBuffersEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_TRANSFORM_FEEDBACK_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBindVertexBuffers",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBindVertexBuffers2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBindVertexBuffers2EXT",
+        alias: Some("vkCmdBindVertexBuffers2"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdBlitImage",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBlitImage2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdBlitImage2KHR",
+        alias: Some("vkCmdBlitImage2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_COPY_COMMANDS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBuildAccelerationStructureNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_RAY_TRACING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBuildAccelerationStructuresIndirectKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBuildAccelerationStructuresKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBuildClusterAccelerationStructureIndirectNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_CLUSTER_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBuildMicromapsEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdBuildPartitionedAccelerationStructuresNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_PARTITIONED_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdClearAttachments",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdClearColorImage",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdClearDepthStencilImage",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdControlVideoCodingKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdConvertCooperativeVectorMatrixNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_COOPERATIVE_VECTOR_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyAccelerationStructureKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyAccelerationStructureNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_RAY_TRACING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyAccelerationStructureToMemoryKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyBuffer",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyBuffer2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyBuffer2KHR",
+        alias: Some("vkCmdCopyBuffer2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_COPY_COMMANDS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyBufferToImage",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyBufferToImage2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyBufferToImage2KHR",
+        alias: Some("vkCmdCopyBufferToImage2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_COPY_COMMANDS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyImage",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyImage2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyImage2KHR",
+        alias: Some("vkCmdCopyImage2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_COPY_COMMANDS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyImageToBuffer",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyImageToBuffer2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyImageToBuffer2KHR",
+        alias: Some("vkCmdCopyImageToBuffer2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_COPY_COMMANDS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyMemoryIndirectKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_COPY_MEMORY_INDIRECT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyMemoryIndirectNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_COPY_MEMORY_INDIRECT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyMemoryToAccelerationStructureKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyMemoryToImageIndirectKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_COPY_MEMORY_INDIRECT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyMemoryToImageIndirectNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_COPY_MEMORY_INDIRECT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyMemoryToMicromapEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyMicromapEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyMicromapToMemoryEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyQueryPoolResults",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdCopyTensorARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_TENSORS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdCuLaunchKernelNVX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NVX_BINARY_IMPORT_EXTENSION.name],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkCmdCudaLaunchKernelNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            NV_CUDA_KERNEL_LAUNCH_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdDebugMarkerBeginEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEBUG_MARKER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDebugMarkerEndEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEBUG_MARKER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDebugMarkerInsertEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEBUG_MARKER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDecodeVideoKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_DECODE_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDecompressMemoryEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_MEMORY_DECOMPRESSION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDecompressMemoryIndirectCountEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_MEMORY_DECOMPRESSION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDecompressMemoryIndirectCountNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_MEMORY_DECOMPRESSION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDecompressMemoryNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_MEMORY_DECOMPRESSION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDispatch",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdDispatchBase",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdDispatchBaseKHR",
+        alias: Some("vkCmdDispatchBase"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DEVICE_GROUP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDispatchDataGraphARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_DATA_GRAPH_EXTENSION.name],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkCmdDispatchGraphAMDX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            AMDX_SHADER_ENQUEUE_EXTENSION.name,
+        ],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkCmdDispatchGraphIndirectAMDX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            AMDX_SHADER_ENQUEUE_EXTENSION.name,
+        ],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkCmdDispatchGraphIndirectCountAMDX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            AMDX_SHADER_ENQUEUE_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdDispatchIndirect",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdDispatchTileQCOM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[QCOM_TILE_SHADING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDraw",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawClusterHUAWEI",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[HUAWEI_CLUSTER_CULLING_SHADER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawClusterIndirectHUAWEI",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[HUAWEI_CLUSTER_CULLING_SHADER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawIndexed",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawIndexedIndirect",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawIndexedIndirectCount",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawIndexedIndirectCountAMD",
+        alias: Some("vkCmdDrawIndexedIndirectCount"),
+        type_: "device",
+        version: None,
+        extensions: &[AMD_DRAW_INDIRECT_COUNT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawIndexedIndirectCountKHR",
+        alias: Some("vkCmdDrawIndexedIndirectCount"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DRAW_INDIRECT_COUNT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawIndirect",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawIndirectByteCountEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_TRANSFORM_FEEDBACK_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawIndirectCount",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawIndirectCountAMD",
+        alias: Some("vkCmdDrawIndirectCount"),
+        type_: "device",
+        version: None,
+        extensions: &[AMD_DRAW_INDIRECT_COUNT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawIndirectCountKHR",
+        alias: Some("vkCmdDrawIndirectCount"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DRAW_INDIRECT_COUNT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawMeshTasksEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_MESH_SHADER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawMeshTasksIndirectCountEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_MESH_SHADER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawMeshTasksIndirectCountNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_MESH_SHADER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawMeshTasksIndirectEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_MESH_SHADER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawMeshTasksIndirectNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_MESH_SHADER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawMeshTasksNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_MESH_SHADER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawMultiEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_MULTI_DRAW_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdDrawMultiIndexedEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_MULTI_DRAW_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdEncodeVideoKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_ENCODE_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdEndConditionalRenderingEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_CONDITIONAL_RENDERING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdEndDebugUtilsLabelEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_UTILS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdEndPerTileExecutionQCOM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[QCOM_TILE_SHADING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdEndQuery",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdEndQueryIndexedEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_TRANSFORM_FEEDBACK_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdEndRenderPass",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdEndRenderPass2",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdEndRenderPass2KHR",
+        alias: Some("vkCmdEndRenderPass2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_CREATE_RENDERPASS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdEndRendering",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdEndRendering2EXT",
+        alias: Some("vkCmdEndRendering2KHR"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_FRAGMENT_DENSITY_MAP_OFFSET_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdEndRendering2KHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE10_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdEndRenderingKHR",
+        alias: Some("vkCmdEndRendering"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DYNAMIC_RENDERING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdEndTransformFeedbackEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_TRANSFORM_FEEDBACK_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdEndVideoCodingKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdExecuteCommands",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdExecuteGeneratedCommandsEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdExecuteGeneratedCommandsNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdFillBuffer",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkCmdInitializeGraphScratchMemoryAMDX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            AMDX_SHADER_ENQUEUE_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdInsertDebugUtilsLabelEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_UTILS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdNextSubpass",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdNextSubpass2",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdNextSubpass2KHR",
+        alias: Some("vkCmdNextSubpass2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_CREATE_RENDERPASS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdOpticalFlowExecuteNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_OPTICAL_FLOW_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdPipelineBarrier",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdPipelineBarrier2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdPipelineBarrier2KHR",
+        alias: Some("vkCmdPipelineBarrier2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SYNCHRONIZATION2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdPreprocessGeneratedCommandsEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdPreprocessGeneratedCommandsNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdPushConstants",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdPushConstants2",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdPushConstants2KHR",
+        alias: Some("vkCmdPushConstants2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE6_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdPushDataEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_HEAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdPushDescriptorSet",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdPushDescriptorSet2",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdPushDescriptorSet2KHR",
+        alias: Some("vkCmdPushDescriptorSet2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE6_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdPushDescriptorSetKHR",
+        alias: Some("vkCmdPushDescriptorSet"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PUSH_DESCRIPTOR_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdPushDescriptorSetWithTemplate",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdPushDescriptorSetWithTemplate2",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdPushDescriptorSetWithTemplate2KHR",
+        alias: Some("vkCmdPushDescriptorSetWithTemplate2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE6_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdPushDescriptorSetWithTemplateKHR",
+        alias: Some("vkCmdPushDescriptorSetWithTemplate"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            KHR_DESCRIPTOR_UPDATE_TEMPLATE_EXTENSION.name,
+            KHR_PUSH_DESCRIPTOR_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdRefreshObjectsKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_OBJECT_REFRESH_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdResetEvent",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdResetEvent2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdResetEvent2KHR",
+        alias: Some("vkCmdResetEvent2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SYNCHRONIZATION2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdResetQueryPool",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdResolveImage",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdResolveImage2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdResolveImage2KHR",
+        alias: Some("vkCmdResolveImage2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_COPY_COMMANDS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetAlphaToCoverageEnableEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetAlphaToOneEnableEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetAttachmentFeedbackLoopEnableEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_ATTACHMENT_FEEDBACK_LOOP_DYNAMIC_STATE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetBlendConstants",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetCheckpointNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_DEVICE_DIAGNOSTIC_CHECKPOINTS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetCoarseSampleOrderNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_SHADING_RATE_IMAGE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetColorBlendAdvancedEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetColorBlendEnableEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetColorBlendEquationEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetColorWriteEnableEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_COLOR_WRITE_ENABLE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetColorWriteMaskEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetComputeOccupancyPriorityNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_COMPUTE_OCCUPANCY_PRIORITY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetConservativeRasterizationModeEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetCoverageModulationModeNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetCoverageModulationTableEnableNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetCoverageModulationTableNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetCoverageReductionModeNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetCoverageToColorEnableNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetCoverageToColorLocationNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetCullMode",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetCullModeEXT",
+        alias: Some("vkCmdSetCullMode"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthBias",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthBias2EXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEPTH_BIAS_CONTROL_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthBiasEnable",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthBiasEnableEXT",
+        alias: Some("vkCmdSetDepthBiasEnable"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE2_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthBounds",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthBoundsTestEnable",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthBoundsTestEnableEXT",
+        alias: Some("vkCmdSetDepthBoundsTestEnable"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthClampEnableEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthClampRangeEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_DEPTH_CLAMP_CONTROL_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthClipEnableEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthClipNegativeOneToOneEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthCompareOp",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthCompareOpEXT",
+        alias: Some("vkCmdSetDepthCompareOp"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthTestEnable",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthTestEnableEXT",
+        alias: Some("vkCmdSetDepthTestEnable"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthWriteEnable",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDepthWriteEnableEXT",
+        alias: Some("vkCmdSetDepthWriteEnable"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDescriptorBufferOffsets2EXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE6_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDescriptorBufferOffsetsEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDeviceMask",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDeviceMaskKHR",
+        alias: Some("vkCmdSetDeviceMask"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DEVICE_GROUP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDiscardRectangleEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DISCARD_RECTANGLES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDiscardRectangleEnableEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DISCARD_RECTANGLES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetDiscardRectangleModeEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DISCARD_RECTANGLES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetEvent",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetEvent2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetEvent2KHR",
+        alias: Some("vkCmdSetEvent2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SYNCHRONIZATION2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetExclusiveScissorEnableNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_SCISSOR_EXCLUSIVE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetExclusiveScissorNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_SCISSOR_EXCLUSIVE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetExtraPrimitiveOverestimationSizeEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetFragmentShadingRateEnumNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_FRAGMENT_SHADING_RATE_ENUMS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetFragmentShadingRateKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_FRAGMENT_SHADING_RATE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetFrontFace",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetFrontFaceEXT",
+        alias: Some("vkCmdSetFrontFace"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetLineRasterizationModeEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetLineStipple",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetLineStippleEXT",
+        alias: Some("vkCmdSetLineStipple"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_LINE_RASTERIZATION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetLineStippleEnableEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetLineStippleKHR",
+        alias: Some("vkCmdSetLineStipple"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_LINE_RASTERIZATION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetLineWidth",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetLogicOpEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE2_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetLogicOpEnableEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetPatchControlPointsEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE2_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetPerformanceMarkerINTEL",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[INTEL_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetPerformanceOverrideINTEL",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[INTEL_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetPerformanceStreamMarkerINTEL",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[INTEL_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetPolygonModeEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetPrimitiveRestartEnable",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetPrimitiveRestartEnableEXT",
+        alias: Some("vkCmdSetPrimitiveRestartEnable"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE2_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetPrimitiveTopology",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetPrimitiveTopologyEXT",
+        alias: Some("vkCmdSetPrimitiveTopology"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetProvokingVertexModeEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetRasterizationSamplesEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetRasterizationStreamEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetRasterizerDiscardEnable",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetRasterizerDiscardEnableEXT",
+        alias: Some("vkCmdSetRasterizerDiscardEnable"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE2_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetRayTracingPipelineStackSizeKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_RAY_TRACING_PIPELINE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetRenderingAttachmentLocations",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetRenderingAttachmentLocationsKHR",
+        alias: Some("vkCmdSetRenderingAttachmentLocations"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DYNAMIC_RENDERING_LOCAL_READ_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetRenderingInputAttachmentIndices",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetRenderingInputAttachmentIndicesKHR",
+        alias: Some("vkCmdSetRenderingInputAttachmentIndices"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DYNAMIC_RENDERING_LOCAL_READ_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetRepresentativeFragmentTestEnableNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetSampleLocationsEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_SAMPLE_LOCATIONS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetSampleLocationsEnableEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetSampleMaskEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetScissor",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetScissorWithCount",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetScissorWithCountEXT",
+        alias: Some("vkCmdSetScissorWithCount"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetShadingRateImageEnableNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetStencilCompareMask",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetStencilOp",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetStencilOpEXT",
+        alias: Some("vkCmdSetStencilOp"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetStencilReference",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetStencilTestEnable",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetStencilTestEnableEXT",
+        alias: Some("vkCmdSetStencilTestEnable"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetStencilWriteMask",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetTessellationDomainOriginEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetVertexInputEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_SHADER_OBJECT_EXTENSION.name,
+            EXT_VERTEX_INPUT_DYNAMIC_STATE_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetViewport",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetViewportShadingRatePaletteNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_SHADING_RATE_IMAGE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetViewportSwizzleNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetViewportWScalingEnableNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSetViewportWScalingNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_CLIP_SPACE_W_SCALING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdSetViewportWithCount",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdSetViewportWithCountEXT",
+        alias: Some("vkCmdSetViewportWithCount"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name,
+            EXT_SHADER_OBJECT_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCmdSubpassShadingHUAWEI",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[HUAWEI_SUBPASS_SHADING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdTraceRaysIndirect2KHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_RAY_TRACING_MAINTENANCE1_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdTraceRaysIndirectKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_RAY_TRACING_PIPELINE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdTraceRaysKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_RAY_TRACING_PIPELINE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdTraceRaysNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_RAY_TRACING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdUpdateBuffer",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdUpdatePipelineIndirectBufferNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_DEVICE_GENERATED_COMMANDS_COMPUTE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdWaitEvents",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdWaitEvents2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdWaitEvents2KHR",
+        alias: Some("vkCmdWaitEvents2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SYNCHRONIZATION2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdWriteAccelerationStructuresPropertiesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdWriteAccelerationStructuresPropertiesNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_RAY_TRACING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdWriteBufferMarker2AMD",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[AMD_BUFFER_MARKER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdWriteBufferMarkerAMD",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[AMD_BUFFER_MARKER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdWriteMicromapsPropertiesEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCmdWriteTimestamp",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdWriteTimestamp2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCmdWriteTimestamp2KHR",
+        alias: Some("vkCmdWriteTimestamp2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SYNCHRONIZATION2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCompileDeferredNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_RAY_TRACING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkConvertCooperativeVectorMatrixNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_COOPERATIVE_VECTOR_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCopyAccelerationStructureKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCopyAccelerationStructureToMemoryKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCopyImageToImage",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCopyImageToImageEXT",
+        alias: Some("vkCopyImageToImage"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_HOST_IMAGE_COPY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCopyImageToMemory",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCopyImageToMemoryEXT",
+        alias: Some("vkCopyImageToMemory"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_HOST_IMAGE_COPY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCopyMemoryToAccelerationStructureKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCopyMemoryToImage",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCopyMemoryToImageEXT",
+        alias: Some("vkCopyMemoryToImage"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_HOST_IMAGE_COPY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCopyMemoryToMicromapEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCopyMicromapEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCopyMicromapToMemoryEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateAccelerationStructureKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateAccelerationStructureNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_RAY_TRACING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateAndroidSurfaceKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_ANDROID_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateBuffer",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateBufferCollectionFUCHSIA",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[FUCHSIA_BUFFER_COLLECTION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateBufferView",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateCommandPool",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateComputePipelines",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateCuFunctionNVX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NVX_BINARY_IMPORT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateCuModuleNVX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NVX_BINARY_IMPORT_EXTENSION.name],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkCreateCudaFunctionNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            NV_CUDA_KERNEL_LAUNCH_EXTENSION.name,
+        ],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkCreateCudaModuleNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            NV_CUDA_KERNEL_LAUNCH_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCreateDataGraphPipelineSessionARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_DATA_GRAPH_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateDataGraphPipelinesARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_DATA_GRAPH_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateDebugReportCallbackEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_REPORT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateDebugUtilsMessengerEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_UTILS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateDeferredOperationKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DEFERRED_HOST_OPERATIONS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateDescriptorPool",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateDescriptorSetLayout",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateDescriptorUpdateTemplate",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateDescriptorUpdateTemplateKHR",
+        alias: Some("vkCreateDescriptorUpdateTemplate"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DESCRIPTOR_UPDATE_TEMPLATE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateDevice",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateDirectFBSurfaceEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DIRECTFB_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateDisplayModeKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateDisplayPlaneSurfaceKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateEvent",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkCreateExecutionGraphPipelinesAMDX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            AMDX_SHADER_ENQUEUE_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkCreateExternalComputeQueueNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_EXTERNAL_COMPUTE_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateFence",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateFramebuffer",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateGraphicsPipelines",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateHeadlessSurfaceEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_HEADLESS_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateIOSSurfaceMVK",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[MVK_IOS_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateImage",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateImagePipeSurfaceFUCHSIA",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[FUCHSIA_IMAGEPIPE_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateImageView",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateIndirectCommandsLayoutEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateIndirectCommandsLayoutNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateIndirectExecutionSetEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateInstance",
+        alias: None,
+        type_: "entry",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateMacOSSurfaceMVK",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[MVK_MACOS_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateMetalSurfaceEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_METAL_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateMicromapEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateOpticalFlowSessionNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_OPTICAL_FLOW_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreatePipelineBinariesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PIPELINE_BINARY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreatePipelineCache",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreatePipelineLayout",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreatePrivateDataSlot",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreatePrivateDataSlotEXT",
+        alias: Some("vkCreatePrivateDataSlot"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_PRIVATE_DATA_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateQueryPool",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateRayTracingPipelinesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_RAY_TRACING_PIPELINE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateRayTracingPipelinesNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_RAY_TRACING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateRenderPass",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateRenderPass2",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateRenderPass2KHR",
+        alias: Some("vkCreateRenderPass2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_CREATE_RENDERPASS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateSampler",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateSamplerYcbcrConversion",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateSamplerYcbcrConversionKHR",
+        alias: Some("vkCreateSamplerYcbcrConversion"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SAMPLER_YCBCR_CONVERSION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateScreenSurfaceQNX",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[QNX_SCREEN_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateSemaphore",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateSemaphoreSciSyncPoolNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_EXTERNAL_SCI_SYNC2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateShaderModule",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkCreateShadersEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_SHADER_OBJECT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateSharedSwapchainsKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DISPLAY_SWAPCHAIN_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateStreamDescriptorSurfaceGGP",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[GGP_STREAM_DESCRIPTOR_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateSurfaceOHOS",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[OHOS_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateSwapchainKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SWAPCHAIN_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateTensorARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_TENSORS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateTensorViewARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_TENSORS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateUbmSurfaceSEC",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[SEC_UBM_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateValidationCacheEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_VALIDATION_CACHE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateViSurfaceNN",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[NN_VI_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateVideoSessionKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateVideoSessionParametersKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateWaylandSurfaceKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_WAYLAND_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateWin32SurfaceKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_WIN32_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateXcbSurfaceKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_XCB_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkCreateXlibSurfaceKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_XLIB_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDebugMarkerSetObjectNameEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEBUG_MARKER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDebugMarkerSetObjectTagEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEBUG_MARKER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDebugReportMessageEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_REPORT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDeferredOperationJoinKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DEFERRED_HOST_OPERATIONS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyAccelerationStructureKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyAccelerationStructureNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_RAY_TRACING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyBuffer",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyBufferCollectionFUCHSIA",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[FUCHSIA_BUFFER_COLLECTION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyBufferView",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyCommandPool",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyCuFunctionNVX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NVX_BINARY_IMPORT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyCuModuleNVX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NVX_BINARY_IMPORT_EXTENSION.name],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkDestroyCudaFunctionNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            NV_CUDA_KERNEL_LAUNCH_EXTENSION.name,
+        ],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkDestroyCudaModuleNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            NV_CUDA_KERNEL_LAUNCH_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkDestroyDataGraphPipelineSessionARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_DATA_GRAPH_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyDebugReportCallbackEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_REPORT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyDebugUtilsMessengerEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_UTILS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyDeferredOperationKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DEFERRED_HOST_OPERATIONS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyDescriptorPool",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyDescriptorSetLayout",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyDescriptorUpdateTemplate",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyDescriptorUpdateTemplateKHR",
+        alias: Some("vkDestroyDescriptorUpdateTemplate"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DESCRIPTOR_UPDATE_TEMPLATE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyDevice",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyEvent",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyExternalComputeQueueNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_EXTERNAL_COMPUTE_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyFence",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyFramebuffer",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyImage",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyImageView",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyIndirectCommandsLayoutEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyIndirectCommandsLayoutNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyIndirectExecutionSetEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyInstance",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyMicromapEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyOpticalFlowSessionNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_OPTICAL_FLOW_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyPipeline",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyPipelineBinaryKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PIPELINE_BINARY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyPipelineCache",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyPipelineLayout",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyPrivateDataSlot",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyPrivateDataSlotEXT",
+        alias: Some("vkDestroyPrivateDataSlot"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_PRIVATE_DATA_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyQueryPool",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroyRenderPass",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroySampler",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroySamplerYcbcrConversion",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroySamplerYcbcrConversionKHR",
+        alias: Some("vkDestroySamplerYcbcrConversion"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SAMPLER_YCBCR_CONVERSION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroySemaphore",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroySemaphoreSciSyncPoolNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_EXTERNAL_SCI_SYNC2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyShaderEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_SHADER_OBJECT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyShaderModule",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDestroySurfaceKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroySwapchainKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SWAPCHAIN_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyTensorARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_TENSORS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyTensorViewARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_TENSORS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyValidationCacheEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_VALIDATION_CACHE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyVideoSessionKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDestroyVideoSessionParametersKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkDeviceWaitIdle",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkDisplayPowerControlEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DISPLAY_CONTROL_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkEndCommandBuffer",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkEnumerateDeviceExtensionProperties",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkEnumerateDeviceLayerProperties",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkEnumerateInstanceExtensionProperties",
+        alias: None,
+        type_: "entry",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkEnumerateInstanceLayerProperties",
+        alias: None,
+        type_: "entry",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkEnumerateInstanceVersion",
+        alias: None,
+        type_: "entry",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkEnumeratePhysicalDeviceGroups",
+        alias: None,
+        type_: "instance",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkEnumeratePhysicalDeviceGroupsKHR",
+        alias: Some("vkEnumeratePhysicalDeviceGroups"),
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_DEVICE_GROUP_CREATION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkEnumeratePhysicalDeviceQueueFamilyPerformanceCountersByRegionARM",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[ARM_PERFORMANCE_COUNTERS_BY_REGION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkEnumeratePhysicalDevices",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkExportMetalObjectsEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_METAL_OBJECTS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkFlushMappedMemoryRanges",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkFreeCommandBuffers",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkFreeDescriptorSets",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkFreeMemory",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetAccelerationStructureBuildSizesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetAccelerationStructureDeviceAddressKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetAccelerationStructureHandleNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_RAY_TRACING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetAccelerationStructureMemoryRequirementsNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_RAY_TRACING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetAndroidHardwareBufferPropertiesANDROID",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ANDROID_EXTERNAL_MEMORY_ANDROID_HARDWARE_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetBufferCollectionPropertiesFUCHSIA",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[FUCHSIA_BUFFER_COLLECTION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetBufferDeviceAddress",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetBufferDeviceAddressEXT",
+        alias: Some("vkGetBufferDeviceAddress"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_BUFFER_DEVICE_ADDRESS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetBufferDeviceAddressKHR",
+        alias: Some("vkGetBufferDeviceAddress"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_BUFFER_DEVICE_ADDRESS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetBufferMemoryRequirements",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetBufferMemoryRequirements2",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetBufferMemoryRequirements2KHR",
+        alias: Some("vkGetBufferMemoryRequirements2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_GET_MEMORY_REQUIREMENTS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetBufferOpaqueCaptureAddress",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetBufferOpaqueCaptureAddressKHR",
+        alias: Some("vkGetBufferOpaqueCaptureAddress"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_BUFFER_DEVICE_ADDRESS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetBufferOpaqueCaptureDescriptorDataEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetCalibratedTimestampsEXT",
+        alias: Some("vkGetCalibratedTimestampsKHR"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_CALIBRATED_TIMESTAMPS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetCalibratedTimestampsKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_CALIBRATED_TIMESTAMPS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetClusterAccelerationStructureBuildSizesNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_CLUSTER_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkGetCudaModuleCacheNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            NV_CUDA_KERNEL_LAUNCH_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkGetDataGraphPipelineAvailablePropertiesARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_DATA_GRAPH_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDataGraphPipelinePropertiesARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_DATA_GRAPH_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDataGraphPipelineSessionBindPointRequirementsARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_DATA_GRAPH_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDataGraphPipelineSessionMemoryRequirementsARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_DATA_GRAPH_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeferredOperationMaxConcurrencyKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DEFERRED_HOST_OPERATIONS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeferredOperationResultKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DEFERRED_HOST_OPERATIONS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDescriptorEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDescriptorSetHostMappingVALVE",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[VALVE_DESCRIPTOR_SET_HOST_MAPPING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDescriptorSetLayoutBindingOffsetEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDescriptorSetLayoutHostMappingInfoVALVE",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[VALVE_DESCRIPTOR_SET_HOST_MAPPING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDescriptorSetLayoutSizeEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDescriptorSetLayoutSupport",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetDescriptorSetLayoutSupportKHR",
+        alias: Some("vkGetDescriptorSetLayoutSupport"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE3_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceAccelerationStructureCompatibilityKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceBufferMemoryRequirements",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceBufferMemoryRequirementsKHR",
+        alias: Some("vkGetDeviceBufferMemoryRequirements"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE4_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceCombinedImageSamplerIndexNVX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NVX_IMAGE_VIEW_HANDLE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceFaultInfoEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEVICE_FAULT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceGroupPeerMemoryFeatures",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceGroupPeerMemoryFeaturesKHR",
+        alias: Some("vkGetDeviceGroupPeerMemoryFeatures"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DEVICE_GROUP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceGroupPresentCapabilitiesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            KHR_DEVICE_GROUP_EXTENSION.name,
+            KHR_SWAPCHAIN_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceGroupSurfacePresentModes2EXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_FULL_SCREEN_EXCLUSIVE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceGroupSurfacePresentModesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            KHR_DEVICE_GROUP_EXTENSION.name,
+            KHR_SWAPCHAIN_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceImageMemoryRequirements",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceImageMemoryRequirementsKHR",
+        alias: Some("vkGetDeviceImageMemoryRequirements"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE4_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceImageSparseMemoryRequirements",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceImageSparseMemoryRequirementsKHR",
+        alias: Some("vkGetDeviceImageSparseMemoryRequirements"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE4_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceImageSubresourceLayout",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceImageSubresourceLayoutKHR",
+        alias: Some("vkGetDeviceImageSubresourceLayout"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE5_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceMemoryCommitment",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceMemoryOpaqueCaptureAddress",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceMemoryOpaqueCaptureAddressKHR",
+        alias: Some("vkGetDeviceMemoryOpaqueCaptureAddress"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_BUFFER_DEVICE_ADDRESS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceMicromapCompatibilityEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceProcAddr",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceQueue",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceQueue2",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[HUAWEI_SUBPASS_SHADING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDeviceTensorMemoryRequirementsARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_TENSORS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDisplayModeProperties2KHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_DISPLAY_PROPERTIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDisplayModePropertiesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDisplayPlaneCapabilities2KHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_DISPLAY_PROPERTIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDisplayPlaneCapabilitiesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDisplayPlaneSupportedDisplaysKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDrmDisplayEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_ACQUIRE_DRM_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetDynamicRenderingTilePropertiesQCOM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[QCOM_TILE_PROPERTIES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetEncodedVideoSessionParametersKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_ENCODE_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetEventStatus",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkGetExecutionGraphPipelineNodeIndexAMDX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            AMDX_SHADER_ENQUEUE_EXTENSION.name,
+        ],
+    },
+    #[cfg(feature = "provisional")]
+    CommandMetadata {
+        name: "vkGetExecutionGraphPipelineScratchSizeAMDX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            #[cfg(feature = "provisional")]
+            AMDX_SHADER_ENQUEUE_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkGetExternalComputeQueueDataNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_EXTERNAL_COMPUTE_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetFenceFdKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_EXTERNAL_FENCE_FD_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetFenceSciSyncFenceNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            NV_EXTERNAL_SCI_SYNC_EXTENSION.name,
+            NV_EXTERNAL_SCI_SYNC2_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkGetFenceSciSyncObjNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            NV_EXTERNAL_SCI_SYNC_EXTENSION.name,
+            NV_EXTERNAL_SCI_SYNC2_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkGetFenceStatus",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetFenceWin32HandleKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_EXTERNAL_FENCE_WIN32_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetFramebufferTilePropertiesQCOM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[QCOM_TILE_PROPERTIES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetGeneratedCommandsMemoryRequirementsEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetGeneratedCommandsMemoryRequirementsNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetImageDrmFormatModifierPropertiesEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_IMAGE_DRM_FORMAT_MODIFIER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetImageMemoryRequirements",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetImageMemoryRequirements2",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetImageMemoryRequirements2KHR",
+        alias: Some("vkGetImageMemoryRequirements2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_GET_MEMORY_REQUIREMENTS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetImageOpaqueCaptureDataEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_HEAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetImageOpaqueCaptureDescriptorDataEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetImageSparseMemoryRequirements",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetImageSparseMemoryRequirements2",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetImageSparseMemoryRequirements2KHR",
+        alias: Some("vkGetImageSparseMemoryRequirements2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_GET_MEMORY_REQUIREMENTS2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetImageSubresourceLayout",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetImageSubresourceLayout2",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetImageSubresourceLayout2EXT",
+        alias: Some("vkGetImageSubresourceLayout2"),
+        type_: "device",
+        version: None,
+        extensions: &[
+            EXT_HOST_IMAGE_COPY_EXTENSION.name,
+            EXT_IMAGE_COMPRESSION_CONTROL_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkGetImageSubresourceLayout2KHR",
+        alias: Some("vkGetImageSubresourceLayout2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE5_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetImageViewAddressNVX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NVX_IMAGE_VIEW_HANDLE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetImageViewHandle64NVX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NVX_IMAGE_VIEW_HANDLE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetImageViewHandleNVX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NVX_IMAGE_VIEW_HANDLE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetImageViewOpaqueCaptureDescriptorDataEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetInstanceProcAddr",
+        alias: None,
+        type_: "static",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetLatencyTimingsNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_LOW_LATENCY2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryAndroidHardwareBufferANDROID",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ANDROID_EXTERNAL_MEMORY_ANDROID_HARDWARE_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryFdKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_EXTERNAL_MEMORY_FD_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryFdPropertiesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_EXTERNAL_MEMORY_FD_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryHostPointerPropertiesEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_EXTERNAL_MEMORY_HOST_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryMetalHandleEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_EXTERNAL_MEMORY_METAL_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryMetalHandlePropertiesEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_EXTERNAL_MEMORY_METAL_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryNativeBufferOHOS",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[OHOS_EXTERNAL_MEMORY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryRemoteAddressNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_EXTERNAL_MEMORY_RDMA_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemorySciBufNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_EXTERNAL_MEMORY_SCI_BUF_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryWin32HandleKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_EXTERNAL_MEMORY_WIN32_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryWin32HandleNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_EXTERNAL_MEMORY_WIN32_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryWin32HandlePropertiesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_EXTERNAL_MEMORY_WIN32_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryZirconHandleFUCHSIA",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[FUCHSIA_EXTERNAL_MEMORY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMemoryZirconHandlePropertiesFUCHSIA",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[FUCHSIA_EXTERNAL_MEMORY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetMicromapBuildSizesEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetNativeBufferPropertiesOHOS",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[OHOS_EXTERNAL_MEMORY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPartitionedAccelerationStructuresBuildSizesNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_PARTITIONED_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPastPresentationTimingEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_PRESENT_TIMING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPastPresentationTimingGOOGLE",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[GOOGLE_DISPLAY_TIMING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPerformanceParameterINTEL",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[INTEL_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+        alias: Some("vkGetPhysicalDeviceCalibrateableTimeDomainsKHR"),
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_CALIBRATED_TIMESTAMPS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceCalibrateableTimeDomainsKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_CALIBRATED_TIMESTAMPS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceCooperativeMatrixFlexibleDimensionsPropertiesNV",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[NV_COOPERATIVE_MATRIX2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceCooperativeMatrixPropertiesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_COOPERATIVE_MATRIX_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[NV_COOPERATIVE_MATRIX_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceCooperativeVectorPropertiesNV",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[NV_COOPERATIVE_VECTOR_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceDescriptorSizeEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_HEAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceDirectFBPresentationSupportEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DIRECTFB_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_DISPLAY_PROPERTIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceDisplayProperties2KHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_DISPLAY_PROPERTIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceDisplayPropertiesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceExternalBufferProperties",
+        alias: None,
+        type_: "instance",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceExternalBufferPropertiesKHR",
+        alias: Some("vkGetPhysicalDeviceExternalBufferProperties"),
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_EXTERNAL_MEMORY_CAPABILITIES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceExternalFenceProperties",
+        alias: None,
+        type_: "instance",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceExternalFencePropertiesKHR",
+        alias: Some("vkGetPhysicalDeviceExternalFenceProperties"),
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_EXTERNAL_FENCE_CAPABILITIES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceExternalImageFormatPropertiesNV",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[NV_EXTERNAL_MEMORY_CAPABILITIES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceExternalMemorySciBufPropertiesNV",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[NV_EXTERNAL_MEMORY_SCI_BUF_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceExternalSemaphoreProperties",
+        alias: None,
+        type_: "instance",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceExternalSemaphorePropertiesKHR",
+        alias: Some("vkGetPhysicalDeviceExternalSemaphoreProperties"),
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_EXTERNAL_SEMAPHORE_CAPABILITIES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceExternalTensorPropertiesARM",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[ARM_TENSORS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceFeatures",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceFeatures2",
+        alias: None,
+        type_: "instance",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceFeatures2KHR",
+        alias: Some("vkGetPhysicalDeviceFeatures2"),
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceFormatProperties",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceFormatProperties2",
+        alias: None,
+        type_: "instance",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceFormatProperties2KHR",
+        alias: Some("vkGetPhysicalDeviceFormatProperties2"),
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceFragmentShadingRatesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_FRAGMENT_SHADING_RATE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceImageFormatProperties",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceImageFormatProperties2",
+        alias: None,
+        type_: "instance",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceImageFormatProperties2KHR",
+        alias: Some("vkGetPhysicalDeviceImageFormatProperties2"),
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceMemoryProperties",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceMemoryProperties2",
+        alias: None,
+        type_: "instance",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceMemoryProperties2KHR",
+        alias: Some("vkGetPhysicalDeviceMemoryProperties2"),
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceMultisamplePropertiesEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_SAMPLE_LOCATIONS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceOpticalFlowImageFormatsNV",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[NV_OPTICAL_FLOW_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDevicePresentRectanglesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[
+            KHR_DEVICE_GROUP_EXTENSION.name,
+            KHR_SWAPCHAIN_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceProperties",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceProperties2",
+        alias: None,
+        type_: "instance",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceProperties2KHR",
+        alias: Some("vkGetPhysicalDeviceProperties2"),
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceQueueFamilyDataGraphProcessingEnginePropertiesARM",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[ARM_DATA_GRAPH_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceQueueFamilyDataGraphPropertiesARM",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[ARM_DATA_GRAPH_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceQueueFamilyProperties",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceQueueFamilyProperties2",
+        alias: None,
+        type_: "instance",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceQueueFamilyProperties2KHR",
+        alias: Some("vkGetPhysicalDeviceQueueFamilyProperties2"),
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceRefreshableObjectTypesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_OBJECT_REFRESH_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSciBufAttributesNV",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[NV_EXTERNAL_MEMORY_SCI_BUF_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSciSyncAttributesNV",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[
+            NV_EXTERNAL_SCI_SYNC_EXTENSION.name,
+            NV_EXTERNAL_SCI_SYNC2_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceScreenPresentationSupportQNX",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[QNX_SCREEN_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSparseImageFormatProperties",
+        alias: None,
+        type_: "instance",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSparseImageFormatProperties2",
+        alias: None,
+        type_: "instance",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSparseImageFormatProperties2KHR",
+        alias: Some("vkGetPhysicalDeviceSparseImageFormatProperties2"),
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[NV_COVERAGE_REDUCTION_MODE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSurfaceCapabilities2EXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DISPLAY_SURFACE_COUNTER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSurfaceCapabilities2KHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_SURFACE_CAPABILITIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSurfaceCapabilitiesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSurfaceFormats2KHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_GET_SURFACE_CAPABILITIES2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSurfaceFormatsKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSurfacePresentModes2EXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_FULL_SCREEN_EXCLUSIVE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSurfacePresentModesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceSurfaceSupportKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceToolProperties",
+        alias: None,
+        type_: "instance",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceToolPropertiesEXT",
+        alias: Some("vkGetPhysicalDeviceToolProperties"),
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_TOOLING_INFO_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceUbmPresentationSupportSEC",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[SEC_UBM_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceVideoCapabilitiesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_VIDEO_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_VIDEO_ENCODE_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceVideoFormatPropertiesKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_VIDEO_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceWaylandPresentationSupportKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_WAYLAND_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceWin32PresentationSupportKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_WIN32_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceXcbPresentationSupportKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_XCB_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPhysicalDeviceXlibPresentationSupportKHR",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[KHR_XLIB_SURFACE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPipelineBinaryDataKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PIPELINE_BINARY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPipelineCacheData",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPipelineExecutableInternalRepresentationsKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PIPELINE_EXECUTABLE_PROPERTIES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPipelineExecutablePropertiesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PIPELINE_EXECUTABLE_PROPERTIES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPipelineExecutableStatisticsKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PIPELINE_EXECUTABLE_PROPERTIES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPipelineIndirectDeviceAddressNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_DEVICE_GENERATED_COMMANDS_COMPUTE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPipelineIndirectMemoryRequirementsNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_DEVICE_GENERATED_COMMANDS_COMPUTE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPipelineKeyKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PIPELINE_BINARY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPipelinePropertiesEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_PIPELINE_PROPERTIES_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetPrivateData",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetPrivateDataEXT",
+        alias: Some("vkGetPrivateData"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_PRIVATE_DATA_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetQueryPoolResults",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetQueueCheckpointData2NV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_DEVICE_DIAGNOSTIC_CHECKPOINTS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetQueueCheckpointDataNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_DEVICE_DIAGNOSTIC_CHECKPOINTS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetRandROutputDisplayEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_ACQUIRE_XLIB_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_RAY_TRACING_PIPELINE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetRayTracingShaderGroupHandlesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_RAY_TRACING_PIPELINE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetRayTracingShaderGroupHandlesNV",
+        alias: Some("vkGetRayTracingShaderGroupHandlesKHR"),
+        type_: "device",
+        version: None,
+        extensions: &[NV_RAY_TRACING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetRayTracingShaderGroupStackSizeKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_RAY_TRACING_PIPELINE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetRefreshCycleDurationGOOGLE",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[GOOGLE_DISPLAY_TIMING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetRenderAreaGranularity",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetRenderingAreaGranularity",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetRenderingAreaGranularityKHR",
+        alias: Some("vkGetRenderingAreaGranularity"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE5_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetSamplerOpaqueCaptureDescriptorDataEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetScreenBufferPropertiesQNX",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[QNX_EXTERNAL_MEMORY_SCREEN_BUFFER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetSemaphoreCounterValue",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkGetSemaphoreCounterValueKHR",
+        alias: Some("vkGetSemaphoreCounterValue"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_TIMELINE_SEMAPHORE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetSemaphoreFdKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_EXTERNAL_SEMAPHORE_FD_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetSemaphoreSciSyncObjNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_EXTERNAL_SCI_SYNC_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetSemaphoreWin32HandleKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_EXTERNAL_SEMAPHORE_WIN32_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetSemaphoreZirconHandleFUCHSIA",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[FUCHSIA_EXTERNAL_SEMAPHORE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetShaderBinaryDataEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_SHADER_OBJECT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetShaderInfoAMD",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[AMD_SHADER_INFO_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetShaderModuleCreateInfoIdentifierEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_SHADER_MODULE_IDENTIFIER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetShaderModuleIdentifierEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_SHADER_MODULE_IDENTIFIER_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetSwapchainCounterEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DISPLAY_CONTROL_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetSwapchainImagesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SWAPCHAIN_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetSwapchainStatusKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SHARED_PRESENTABLE_IMAGE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetSwapchainTimeDomainPropertiesEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_PRESENT_TIMING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetSwapchainTimingPropertiesEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_PRESENT_TIMING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetTensorMemoryRequirementsARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_TENSORS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetTensorOpaqueCaptureDataARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_HEAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetTensorOpaqueCaptureDescriptorDataARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_TENSORS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetTensorViewOpaqueCaptureDescriptorDataARM",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[ARM_TENSORS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetValidationCacheDataEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_VALIDATION_CACHE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetVideoSessionMemoryRequirementsKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkGetWinrtDisplayNV",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[NV_ACQUIRE_WINRT_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkImportFenceFdKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_EXTERNAL_FENCE_FD_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkImportFenceSciSyncFenceNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            NV_EXTERNAL_SCI_SYNC_EXTENSION.name,
+            NV_EXTERNAL_SCI_SYNC2_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkImportFenceSciSyncObjNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[
+            NV_EXTERNAL_SCI_SYNC_EXTENSION.name,
+            NV_EXTERNAL_SCI_SYNC2_EXTENSION.name,
+        ],
+    },
+    CommandMetadata {
+        name: "vkImportFenceWin32HandleKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_EXTERNAL_FENCE_WIN32_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkImportSemaphoreFdKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_EXTERNAL_SEMAPHORE_FD_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkImportSemaphoreSciSyncObjNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_EXTERNAL_SCI_SYNC_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkImportSemaphoreWin32HandleKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_EXTERNAL_SEMAPHORE_WIN32_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkImportSemaphoreZirconHandleFUCHSIA",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[FUCHSIA_EXTERNAL_SEMAPHORE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkInitializePerformanceApiINTEL",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[INTEL_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkInvalidateMappedMemoryRanges",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkLatencySleepNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_LOW_LATENCY2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkMapMemory",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkMapMemory2",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkMapMemory2KHR",
+        alias: Some("vkMapMemory2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAP_MEMORY2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkMergePipelineCaches",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkMergeValidationCachesEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_VALIDATION_CACHE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkQueueBeginDebugUtilsLabelEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_UTILS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkQueueBindSparse",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkQueueEndDebugUtilsLabelEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_UTILS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkQueueInsertDebugUtilsLabelEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_UTILS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkQueueNotifyOutOfBandNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_LOW_LATENCY2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkQueuePresentKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SWAPCHAIN_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkQueueSetPerformanceConfigurationINTEL",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[INTEL_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkQueueSubmit",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkQueueSubmit2",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkQueueSubmit2KHR",
+        alias: Some("vkQueueSubmit2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SYNCHRONIZATION2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkQueueWaitIdle",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkRegisterCustomBorderColorEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_HEAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkRegisterDeviceEventEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DISPLAY_CONTROL_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkRegisterDisplayEventEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DISPLAY_CONTROL_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkReleaseCapturedPipelineDataKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PIPELINE_BINARY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkReleaseDisplayEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DIRECT_MODE_DISPLAY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkReleaseFullScreenExclusiveModeEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_FULL_SCREEN_EXCLUSIVE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkReleasePerformanceConfigurationINTEL",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[INTEL_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkReleaseProfilingLockKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkReleaseSwapchainImagesEXT",
+        alias: Some("vkReleaseSwapchainImagesKHR"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_SWAPCHAIN_MAINTENANCE1_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkReleaseSwapchainImagesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_SWAPCHAIN_MAINTENANCE1_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkResetCommandBuffer",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkResetCommandPool",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkResetDescriptorPool",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkResetEvent",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkResetFences",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkResetQueryPool",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkResetQueryPoolEXT",
+        alias: Some("vkResetQueryPool"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_HOST_QUERY_RESET_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSetBufferCollectionBufferConstraintsFUCHSIA",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[FUCHSIA_BUFFER_COLLECTION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSetBufferCollectionImageConstraintsFUCHSIA",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[FUCHSIA_BUFFER_COLLECTION_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSetDebugUtilsObjectNameEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_UTILS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSetDebugUtilsObjectTagEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_UTILS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSetDeviceMemoryPriorityEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_PAGEABLE_DEVICE_LOCAL_MEMORY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSetEvent",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkSetHdrMetadataEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_HDR_METADATA_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSetLatencyMarkerNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_LOW_LATENCY2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSetLatencySleepModeNV",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[NV_LOW_LATENCY2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSetLocalDimmingAMD",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[AMD_DISPLAY_NATIVE_HDR_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSetPrivateData",
+        alias: None,
+        type_: "device",
+        version: Some("1.3"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkSetPrivateDataEXT",
+        alias: Some("vkSetPrivateData"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_PRIVATE_DATA_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSetSwapchainPresentTimingQueueSizeEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_PRESENT_TIMING_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSignalSemaphore",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkSignalSemaphoreKHR",
+        alias: Some("vkSignalSemaphore"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_TIMELINE_SEMAPHORE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkSubmitDebugUtilsMessageEXT",
+        alias: None,
+        type_: "instance",
+        version: None,
+        extensions: &[EXT_DEBUG_UTILS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkTransitionImageLayout",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkTransitionImageLayoutEXT",
+        alias: Some("vkTransitionImageLayout"),
+        type_: "device",
+        version: None,
+        extensions: &[EXT_HOST_IMAGE_COPY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkTrimCommandPool",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkTrimCommandPoolKHR",
+        alias: Some("vkTrimCommandPool"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAINTENANCE1_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkUninitializePerformanceApiINTEL",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[INTEL_PERFORMANCE_QUERY_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkUnmapMemory",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkUnmapMemory2",
+        alias: None,
+        type_: "device",
+        version: Some("1.4"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkUnmapMemory2KHR",
+        alias: Some("vkUnmapMemory2"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_MAP_MEMORY2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkUnregisterCustomBorderColorEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_HEAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkUpdateDescriptorSetWithTemplate",
+        alias: None,
+        type_: "device",
+        version: Some("1.1"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkUpdateDescriptorSetWithTemplateKHR",
+        alias: Some("vkUpdateDescriptorSetWithTemplate"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_DESCRIPTOR_UPDATE_TEMPLATE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkUpdateDescriptorSets",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkUpdateIndirectExecutionSetPipelineEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkUpdateIndirectExecutionSetShaderEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DEVICE_GENERATED_COMMANDS_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkUpdateVideoSessionParametersKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_VIDEO_QUEUE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkWaitForFences",
+        alias: None,
+        type_: "device",
+        version: Some("1.0"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkWaitForPresent2KHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PRESENT_WAIT2_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkWaitForPresentKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_PRESENT_WAIT_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkWaitSemaphores",
+        alias: None,
+        type_: "device",
+        version: Some("1.2"),
+        extensions: &[],
+    },
+    CommandMetadata {
+        name: "vkWaitSemaphoresKHR",
+        alias: Some("vkWaitSemaphores"),
+        type_: "device",
+        version: None,
+        extensions: &[KHR_TIMELINE_SEMAPHORE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkWriteAccelerationStructuresPropertiesKHR",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[KHR_ACCELERATION_STRUCTURE_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkWriteMicromapsPropertiesEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_OPACITY_MICROMAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkWriteResourceDescriptorsEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_HEAP_EXTENSION.name],
+    },
+    CommandMetadata {
+        name: "vkWriteSamplerDescriptorsEXT",
+        alias: None,
+        type_: "device",
+        version: None,
+        extensions: &[EXT_DESCRIPTOR_HEAP_EXTENSION.name],
+    },
+];
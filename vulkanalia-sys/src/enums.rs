@@ -9,6 +9,7 @@
     non_camel_case_types,
     non_snake_case,
     clippy::bad_bit_mask,
+    clippy::let_and_return,
     clippy::let_unit_value,
     clippy::missing_safety_doc,
     clippy::missing_transmute_annotations,
@@ -37,6 +38,9 @@ impl AccelerationStructureBuildTypeKHR {
     pub const DEVICE: Self = Self(1);
     pub const HOST_OR_DEVICE: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::HOST, Self::DEVICE, Self::HOST_OR_DEVICE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -70,6 +74,9 @@ impl AccelerationStructureCompatibilityKHR {
     pub const COMPATIBLE: Self = Self(0);
     pub const INCOMPATIBLE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::COMPATIBLE, Self::INCOMPATIBLE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -103,6 +110,9 @@ impl AccelerationStructureMemoryRequirementsTypeNV {
     pub const BUILD_SCRATCH: Self = Self(1);
     pub const UPDATE_SCRATCH: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::OBJECT, Self::BUILD_SCRATCH, Self::UPDATE_SCRATCH];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -137,6 +147,9 @@ impl AccelerationStructureMotionInstanceTypeNV {
     pub const MATRIX_MOTION: Self = Self(1);
     pub const SRT_MOTION: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::STATIC, Self::MATRIX_MOTION, Self::SRT_MOTION];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -171,6 +184,9 @@ impl AccelerationStructureTypeKHR {
     pub const BOTTOM_LEVEL: Self = Self(1);
     pub const GENERIC: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::TOP_LEVEL, Self::BOTTOM_LEVEL, Self::GENERIC];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -205,6 +221,9 @@ impl AntiLagModeAMD {
     pub const ON: Self = Self(1);
     pub const OFF: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::DRIVER_CONTROL, Self::ON, Self::OFF];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -238,6 +257,9 @@ impl AntiLagStageAMD {
     pub const INPUT: Self = Self(0);
     pub const PRESENT: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::INPUT, Self::PRESENT];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -272,6 +294,9 @@ impl AttachmentLoadOp {
     pub const DONT_CARE: Self = Self(2);
     pub const NONE: Self = Self(1000400000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::LOAD, Self::CLEAR, Self::DONT_CARE, Self::NONE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -307,6 +332,9 @@ impl AttachmentStoreOp {
     pub const DONT_CARE: Self = Self(1);
     pub const NONE: Self = Self(1000301000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::STORE, Self::DONT_CARE, Self::NONE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -357,6 +385,29 @@ impl BlendFactor {
     pub const SRC1_ALPHA: Self = Self(17);
     pub const ONE_MINUS_SRC1_ALPHA: Self = Self(18);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::ZERO,
+        Self::ONE,
+        Self::SRC_COLOR,
+        Self::ONE_MINUS_SRC_COLOR,
+        Self::DST_COLOR,
+        Self::ONE_MINUS_DST_COLOR,
+        Self::SRC_ALPHA,
+        Self::ONE_MINUS_SRC_ALPHA,
+        Self::DST_ALPHA,
+        Self::ONE_MINUS_DST_ALPHA,
+        Self::CONSTANT_COLOR,
+        Self::ONE_MINUS_CONSTANT_COLOR,
+        Self::CONSTANT_ALPHA,
+        Self::ONE_MINUS_CONSTANT_ALPHA,
+        Self::SRC_ALPHA_SATURATE,
+        Self::SRC1_COLOR,
+        Self::ONE_MINUS_SRC1_COLOR,
+        Self::SRC1_ALPHA,
+        Self::ONE_MINUS_SRC1_ALPHA,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -455,6 +506,61 @@ impl BlendOp {
     pub const GREEN_EXT: Self = Self(1000148044);
     pub const BLUE_EXT: Self = Self(1000148045);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::ADD,
+        Self::SUBTRACT,
+        Self::REVERSE_SUBTRACT,
+        Self::MIN,
+        Self::MAX,
+        Self::ZERO_EXT,
+        Self::SRC_EXT,
+        Self::DST_EXT,
+        Self::SRC_OVER_EXT,
+        Self::DST_OVER_EXT,
+        Self::SRC_IN_EXT,
+        Self::DST_IN_EXT,
+        Self::SRC_OUT_EXT,
+        Self::DST_OUT_EXT,
+        Self::SRC_ATOP_EXT,
+        Self::DST_ATOP_EXT,
+        Self::XOR_EXT,
+        Self::MULTIPLY_EXT,
+        Self::SCREEN_EXT,
+        Self::OVERLAY_EXT,
+        Self::DARKEN_EXT,
+        Self::LIGHTEN_EXT,
+        Self::COLORDODGE_EXT,
+        Self::COLORBURN_EXT,
+        Self::HARDLIGHT_EXT,
+        Self::SOFTLIGHT_EXT,
+        Self::DIFFERENCE_EXT,
+        Self::EXCLUSION_EXT,
+        Self::INVERT_EXT,
+        Self::INVERT_RGB_EXT,
+        Self::LINEARDODGE_EXT,
+        Self::LINEARBURN_EXT,
+        Self::VIVIDLIGHT_EXT,
+        Self::LINEARLIGHT_EXT,
+        Self::PINLIGHT_EXT,
+        Self::HARDMIX_EXT,
+        Self::HSL_HUE_EXT,
+        Self::HSL_SATURATION_EXT,
+        Self::HSL_COLOR_EXT,
+        Self::HSL_LUMINOSITY_EXT,
+        Self::PLUS_EXT,
+        Self::PLUS_CLAMPED_EXT,
+        Self::PLUS_CLAMPED_ALPHA_EXT,
+        Self::PLUS_DARKER_EXT,
+        Self::MINUS_EXT,
+        Self::MINUS_CLAMPED_EXT,
+        Self::CONTRAST_EXT,
+        Self::INVERT_OVG_EXT,
+        Self::RED_EXT,
+        Self::GREEN_EXT,
+        Self::BLUE_EXT,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -537,6 +643,9 @@ impl BlendOverlapEXT {
     pub const DISJOINT: Self = Self(1);
     pub const CONJOINT: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::UNCORRELATED, Self::DISJOINT, Self::CONJOINT];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -570,6 +679,9 @@ impl BlockMatchWindowCompareModeQCOM {
     pub const MIN: Self = Self(0);
     pub const MAX: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::MIN, Self::MAX];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -608,6 +720,18 @@ impl BorderColor {
     pub const FLOAT_CUSTOM_EXT: Self = Self(1000287003);
     pub const INT_CUSTOM_EXT: Self = Self(1000287004);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::FLOAT_TRANSPARENT_BLACK,
+        Self::INT_TRANSPARENT_BLACK,
+        Self::FLOAT_OPAQUE_BLACK,
+        Self::INT_OPAQUE_BLACK,
+        Self::FLOAT_OPAQUE_WHITE,
+        Self::INT_OPAQUE_WHITE,
+        Self::FLOAT_CUSTOM_EXT,
+        Self::INT_CUSTOM_EXT,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -646,6 +770,9 @@ impl BuildAccelerationStructureModeKHR {
     pub const BUILD: Self = Self(0);
     pub const UPDATE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::BUILD, Self::UPDATE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -677,6 +804,9 @@ pub struct BuildMicromapModeEXT(i32);
 impl BuildMicromapModeEXT {
     pub const BUILD: Self = Self(0);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::BUILD];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -708,6 +838,9 @@ impl ChromaLocation {
     pub const COSITED_EVEN: Self = Self(0);
     pub const MIDPOINT: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::COSITED_EVEN, Self::MIDPOINT];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -741,6 +874,13 @@ impl ClusterAccelerationStructureOpModeNV {
     pub const EXPLICIT_DESTINATIONS: Self = Self(1);
     pub const COMPUTE_SIZES: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::IMPLICIT_DESTINATIONS,
+        Self::EXPLICIT_DESTINATIONS,
+        Self::COMPUTE_SIZES,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -778,6 +918,16 @@ impl ClusterAccelerationStructureOpTypeNV {
     pub const INSTANTIATE_TRIANGLE_CLUSTER: Self = Self(4);
     pub const GET_CLUSTER_TEMPLATE_INDICES: Self = Self(5);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::MOVE_OBJECTS,
+        Self::BUILD_CLUSTERS_BOTTOM_LEVEL,
+        Self::BUILD_TRIANGLE_CLUSTER,
+        Self::BUILD_TRIANGLE_CLUSTER_TEMPLATE,
+        Self::INSTANTIATE_TRIANGLE_CLUSTER,
+        Self::GET_CLUSTER_TEMPLATE_INDICES,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -815,6 +965,13 @@ impl ClusterAccelerationStructureTypeNV {
     pub const TRIANGLE_CLUSTER: Self = Self(1);
     pub const TRIANGLE_CLUSTER_TEMPLATE: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::CLUSTERS_BOTTOM_LEVEL,
+        Self::TRIANGLE_CLUSTER,
+        Self::TRIANGLE_CLUSTER_TEMPLATE,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -850,6 +1007,14 @@ impl CoarseSampleOrderTypeNV {
     pub const PIXEL_MAJOR: Self = Self(2);
     pub const SAMPLE_MAJOR: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::DEFAULT,
+        Self::CUSTOM,
+        Self::PIXEL_MAJOR,
+        Self::SAMPLE_MAJOR,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -898,6 +1063,26 @@ impl ColorSpaceKHR {
     pub const EXTENDED_SRGB_NONLINEAR_EXT: Self = Self(1000104014);
     pub const DISPLAY_NATIVE_AMD: Self = Self(1000213000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::SRGB_NONLINEAR,
+        Self::DISPLAY_P3_NONLINEAR_EXT,
+        Self::EXTENDED_SRGB_LINEAR_EXT,
+        Self::DISPLAY_P3_LINEAR_EXT,
+        Self::DCI_P3_NONLINEAR_EXT,
+        Self::BT709_LINEAR_EXT,
+        Self::BT709_NONLINEAR_EXT,
+        Self::BT2020_LINEAR_EXT,
+        Self::HDR10_ST2084_EXT,
+        Self::DOLBYVISION_EXT,
+        Self::HDR10_HLG_EXT,
+        Self::ADOBERGB_LINEAR_EXT,
+        Self::ADOBERGB_NONLINEAR_EXT,
+        Self::PASS_THROUGH_EXT,
+        Self::EXTENDED_SRGB_NONLINEAR_EXT,
+        Self::DISPLAY_NATIVE_AMD,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -944,6 +1129,9 @@ impl CommandBufferLevel {
     pub const PRIMARY: Self = Self(0);
     pub const SECONDARY: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::PRIMARY, Self::SECONDARY];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -982,6 +1170,18 @@ impl CompareOp {
     pub const GREATER_OR_EQUAL: Self = Self(6);
     pub const ALWAYS: Self = Self(7);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::NEVER,
+        Self::LESS,
+        Self::EQUAL,
+        Self::LESS_OR_EQUAL,
+        Self::GREATER,
+        Self::NOT_EQUAL,
+        Self::GREATER_OR_EQUAL,
+        Self::ALWAYS,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1025,6 +1225,17 @@ impl ComponentSwizzle {
     pub const B: Self = Self(5);
     pub const A: Self = Self(6);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::IDENTITY,
+        Self::ZERO,
+        Self::ONE,
+        Self::R,
+        Self::G,
+        Self::B,
+        Self::A,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1076,6 +1287,26 @@ impl ComponentTypeKHR {
     pub const FLOAT8_E4M3_EXT: Self = Self(1000491002);
     pub const FLOAT8_E5M2_EXT: Self = Self(1000491003);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::FLOAT16,
+        Self::FLOAT32,
+        Self::FLOAT64,
+        Self::SINT8,
+        Self::SINT16,
+        Self::SINT32,
+        Self::SINT64,
+        Self::UINT8,
+        Self::UINT16,
+        Self::UINT32,
+        Self::UINT64,
+        Self::BFLOAT16,
+        Self::SINT8_PACKED_NV,
+        Self::UINT8_PACKED_NV,
+        Self::FLOAT8_E4M3_EXT,
+        Self::FLOAT8_E5M2_EXT,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1121,6 +1352,9 @@ pub struct CompressedTriangleFormatAMDX(i32);
 impl CompressedTriangleFormatAMDX {
     pub const DGF1: Self = Self(0);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::DGF1];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1153,6 +1387,9 @@ impl ConservativeRasterizationModeEXT {
     pub const OVERESTIMATE: Self = Self(1);
     pub const UNDERESTIMATE: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::DISABLED, Self::OVERESTIMATE, Self::UNDERESTIMATE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1188,6 +1425,14 @@ impl CooperativeVectorMatrixLayoutNV {
     pub const INFERENCING_OPTIMAL: Self = Self(2);
     pub const TRAINING_OPTIMAL: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::ROW_MAJOR,
+        Self::COLUMN_MAJOR,
+        Self::INFERENCING_OPTIMAL,
+        Self::TRAINING_OPTIMAL,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1224,6 +1469,14 @@ impl CopyAccelerationStructureModeKHR {
     pub const SERIALIZE: Self = Self(2);
     pub const DESERIALIZE: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::CLONE,
+        Self::COMPACT,
+        Self::SERIALIZE,
+        Self::DESERIALIZE,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1260,6 +1513,14 @@ impl CopyMicromapModeEXT {
     pub const DESERIALIZE: Self = Self(2);
     pub const COMPACT: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::CLONE,
+        Self::SERIALIZE,
+        Self::DESERIALIZE,
+        Self::COMPACT,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1296,6 +1557,9 @@ impl CoverageModulationModeNV {
     pub const ALPHA: Self = Self(2);
     pub const RGBA: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::NONE, Self::RGB, Self::ALPHA, Self::RGBA];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1330,6 +1594,9 @@ impl CoverageReductionModeNV {
     pub const MERGE: Self = Self(0);
     pub const TRUNCATE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::MERGE, Self::TRUNCATE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1364,6 +1631,14 @@ impl CubicFilterWeightsQCOM {
     pub const B_SPLINE: Self = Self(2);
     pub const MITCHELL_NETRAVALI: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::CATMULL_ROM,
+        Self::ZERO_TANGENT_CARDINAL,
+        Self::B_SPLINE,
+        Self::MITCHELL_NETRAVALI,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1397,6 +1672,9 @@ pub struct DataGraphModelCacheTypeQCOM(i32);
 impl DataGraphModelCacheTypeQCOM {
     pub const GENERIC_BINARY: Self = Self(0);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::GENERIC_BINARY];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1428,6 +1706,9 @@ impl DataGraphPipelinePropertyARM {
     pub const CREATION_LOG: Self = Self(0);
     pub const IDENTIFIER: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::CREATION_LOG, Self::IDENTIFIER];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1459,6 +1740,9 @@ pub struct DataGraphPipelineSessionBindPointARM(i32);
 impl DataGraphPipelineSessionBindPointARM {
     pub const TRANSIENT: Self = Self(0);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::TRANSIENT];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1489,6 +1773,9 @@ pub struct DataGraphPipelineSessionBindPointTypeARM(i32);
 impl DataGraphPipelineSessionBindPointTypeARM {
     pub const MEMORY: Self = Self(0);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::MEMORY];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1559,6 +1846,51 @@ impl DebugReportObjectTypeEXT {
     pub const CUDA_FUNCTION_NV: Self = Self(1000307001);
     pub const BUFFER_COLLECTION_FUCHSIA: Self = Self(1000366000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::UNKNOWN,
+        Self::INSTANCE,
+        Self::PHYSICAL_DEVICE,
+        Self::DEVICE,
+        Self::QUEUE,
+        Self::SEMAPHORE,
+        Self::COMMAND_BUFFER,
+        Self::FENCE,
+        Self::DEVICE_MEMORY,
+        Self::BUFFER,
+        Self::IMAGE,
+        Self::EVENT,
+        Self::QUERY_POOL,
+        Self::BUFFER_VIEW,
+        Self::IMAGE_VIEW,
+        Self::SHADER_MODULE,
+        Self::PIPELINE_CACHE,
+        Self::PIPELINE_LAYOUT,
+        Self::RENDER_PASS,
+        Self::PIPELINE,
+        Self::DESCRIPTOR_SET_LAYOUT,
+        Self::SAMPLER,
+        Self::DESCRIPTOR_POOL,
+        Self::DESCRIPTOR_SET,
+        Self::FRAMEBUFFER,
+        Self::COMMAND_POOL,
+        Self::SURFACE_KHR,
+        Self::SWAPCHAIN_KHR,
+        Self::DEBUG_REPORT_CALLBACK_EXT,
+        Self::DISPLAY_KHR,
+        Self::DISPLAY_MODE_KHR,
+        Self::VALIDATION_CACHE_EXT,
+        Self::SAMPLER_YCBCR_CONVERSION,
+        Self::DESCRIPTOR_UPDATE_TEMPLATE,
+        Self::CU_MODULE_NVX,
+        Self::CU_FUNCTION_NVX,
+        Self::ACCELERATION_STRUCTURE_KHR,
+        Self::ACCELERATION_STRUCTURE_NV,
+        Self::CUDA_MODULE_NV,
+        Self::CUDA_FUNCTION_NV,
+        Self::BUFFER_COLLECTION_FUCHSIA,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1630,6 +1962,9 @@ impl DefaultVertexAttributeValueKHR {
     pub const ZERO_ZERO_ZERO_ZERO: Self = Self(0);
     pub const ZERO_ZERO_ZERO_ONE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::ZERO_ZERO_ZERO_ZERO, Self::ZERO_ZERO_ZERO_ONE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1663,6 +1998,13 @@ impl DepthBiasRepresentationEXT {
     pub const LEAST_REPRESENTABLE_VALUE_FORCE_UNORM: Self = Self(1);
     pub const FLOAT: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::LEAST_REPRESENTABLE_VALUE_FORMAT,
+        Self::LEAST_REPRESENTABLE_VALUE_FORCE_UNORM,
+        Self::FLOAT,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1696,6 +2038,9 @@ impl DepthClampModeEXT {
     pub const VIEWPORT_RANGE: Self = Self(0);
     pub const USER_DEFINED_RANGE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::VIEWPORT_RANGE, Self::USER_DEFINED_RANGE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1737,6 +2082,21 @@ impl DescriptorMappingSourceEXT {
     pub const SHADER_RECORD_DATA: Self = Self(9);
     pub const SHADER_RECORD_ADDRESS: Self = Self(10);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::HEAP_WITH_CONSTANT_OFFSET,
+        Self::HEAP_WITH_PUSH_INDEX,
+        Self::HEAP_WITH_INDIRECT_INDEX,
+        Self::HEAP_WITH_INDIRECT_INDEX_ARRAY,
+        Self::RESOURCE_HEAP_DATA,
+        Self::PUSH_DATA,
+        Self::PUSH_ADDRESS,
+        Self::INDIRECT_ADDRESS,
+        Self::HEAP_WITH_SHADER_RECORD_INDEX,
+        Self::SHADER_RECORD_DATA,
+        Self::SHADER_RECORD_ADDRESS,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1795,6 +2155,29 @@ impl DescriptorType {
     pub const MUTABLE_EXT: Self = Self(1000351000);
     pub const PARTITIONED_ACCELERATION_STRUCTURE_NV: Self = Self(1000570000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::SAMPLER,
+        Self::COMBINED_IMAGE_SAMPLER,
+        Self::SAMPLED_IMAGE,
+        Self::STORAGE_IMAGE,
+        Self::UNIFORM_TEXEL_BUFFER,
+        Self::STORAGE_TEXEL_BUFFER,
+        Self::UNIFORM_BUFFER,
+        Self::STORAGE_BUFFER,
+        Self::UNIFORM_BUFFER_DYNAMIC,
+        Self::STORAGE_BUFFER_DYNAMIC,
+        Self::INPUT_ATTACHMENT,
+        Self::INLINE_UNIFORM_BLOCK,
+        Self::ACCELERATION_STRUCTURE_KHR,
+        Self::ACCELERATION_STRUCTURE_NV,
+        Self::SAMPLE_WEIGHT_IMAGE_QCOM,
+        Self::BLOCK_MATCH_IMAGE_QCOM,
+        Self::TENSOR_ARM,
+        Self::MUTABLE_EXT,
+        Self::PARTITIONED_ACCELERATION_STRUCTURE_NV,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1844,6 +2227,9 @@ impl DescriptorUpdateTemplateType {
     pub const DESCRIPTOR_SET: Self = Self(0);
     pub const PUSH_DESCRIPTORS: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::DESCRIPTOR_SET, Self::PUSH_DESCRIPTORS];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1876,6 +2262,9 @@ impl DeviceAddressBindingTypeEXT {
     pub const BIND: Self = Self(0);
     pub const UNBIND: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::BIND, Self::UNBIND];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1907,6 +2296,9 @@ pub struct DeviceEventTypeEXT(i32);
 impl DeviceEventTypeEXT {
     pub const DISPLAY_HOTPLUG: Self = Self(0);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::DISPLAY_HOTPLUG];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1943,6 +2335,17 @@ impl DeviceFaultAddressTypeEXT {
     pub const INSTRUCTION_POINTER_INVALID: Self = Self(5);
     pub const INSTRUCTION_POINTER_FAULT: Self = Self(6);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::NONE,
+        Self::READ_INVALID,
+        Self::WRITE_INVALID,
+        Self::EXECUTE_INVALID,
+        Self::INSTRUCTION_POINTER_UNKNOWN,
+        Self::INSTRUCTION_POINTER_INVALID,
+        Self::INSTRUCTION_POINTER_FAULT,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -1979,6 +2382,9 @@ pub struct DeviceFaultVendorBinaryHeaderVersionEXT(i32);
 impl DeviceFaultVendorBinaryHeaderVersionEXT {
     pub const ONE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::ONE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -2013,6 +2419,15 @@ impl DeviceMemoryReportEventTypeEXT {
     pub const UNIMPORT: Self = Self(3);
     pub const ALLOCATION_FAILED: Self = Self(4);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::ALLOCATE,
+        Self::FREE,
+        Self::IMPORT,
+        Self::UNIMPORT,
+        Self::ALLOCATION_FAILED,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -2048,6 +2463,9 @@ impl DirectDriverLoadingModeLUNARG {
     pub const EXCLUSIVE: Self = Self(0);
     pub const INCLUSIVE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::EXCLUSIVE, Self::INCLUSIVE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -2080,6 +2498,9 @@ impl DiscardRectangleModeEXT {
     pub const INCLUSIVE: Self = Self(0);
     pub const EXCLUSIVE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::INCLUSIVE, Self::EXCLUSIVE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -2113,6 +2534,13 @@ impl DisplacementMicromapFormatNV {
     pub const _256_TRIANGLES_128_BYTES: Self = Self(2);
     pub const _1024_TRIANGLES_128_BYTES: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::_64_TRIANGLES_64_BYTES,
+        Self::_256_TRIANGLES_128_BYTES,
+        Self::_1024_TRIANGLES_128_BYTES,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -2145,6 +2573,9 @@ pub struct DisplayEventTypeEXT(i32);
 impl DisplayEventTypeEXT {
     pub const FIRST_PIXEL_OUT: Self = Self(0);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::FIRST_PIXEL_OUT];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -2177,6 +2608,9 @@ impl DisplayPowerStateEXT {
     pub const SUSPEND: Self = Self(1);
     pub const ON: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::OFF, Self::SUSPEND, Self::ON];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -2212,6 +2646,14 @@ impl DisplaySurfaceStereoTypeNV {
     pub const HDMI_3D: Self = Self(2);
     pub const INBAND_DISPLAYPORT: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::NONE,
+        Self::ONBOARD_DIN,
+        Self::HDMI_3D,
+        Self::INBAND_DISPLAYPORT,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -2272,6 +2714,38 @@ impl DriverId {
     pub const VULKAN_SC_EMULATION_ON_VULKAN: Self = Self(27);
     pub const MESA_KOSMICKRISP: Self = Self(28);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::AMD_PROPRIETARY,
+        Self::AMD_OPEN_SOURCE,
+        Self::MESA_RADV,
+        Self::NVIDIA_PROPRIETARY,
+        Self::INTEL_PROPRIETARY_WINDOWS,
+        Self::INTEL_OPEN_SOURCE_MESA,
+        Self::IMAGINATION_PROPRIETARY,
+        Self::QUALCOMM_PROPRIETARY,
+        Self::ARM_PROPRIETARY,
+        Self::GOOGLE_SWIFTSHADER,
+        Self::GGP_PROPRIETARY,
+        Self::BROADCOM_PROPRIETARY,
+        Self::MESA_LLVMPIPE,
+        Self::MOLTENVK,
+        Self::COREAVI_PROPRIETARY,
+        Self::JUICE_PROPRIETARY,
+        Self::VERISILICON_PROPRIETARY,
+        Self::MESA_TURNIP,
+        Self::MESA_V3DV,
+        Self::MESA_PANVK,
+        Self::SAMSUNG_PROPRIETARY,
+        Self::MESA_VENUS,
+        Self::MESA_DOZEN,
+        Self::MESA_NVK,
+        Self::IMAGINATION_OPEN_SOURCE_MESA,
+        Self::MESA_HONEYKRISP,
+        Self::VULKAN_SC_EMULATION_ON_VULKAN,
+        Self::MESA_KOSMICKRISP,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -2401,6 +2875,83 @@ impl DynamicState {
     pub const ATTACHMENT_FEEDBACK_LOOP_ENABLE_EXT: Self = Self(1000524000);
     pub const DEPTH_CLAMP_RANGE_EXT: Self = Self(1000582000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::VIEWPORT,
+        Self::SCISSOR,
+        Self::LINE_WIDTH,
+        Self::DEPTH_BIAS,
+        Self::BLEND_CONSTANTS,
+        Self::DEPTH_BOUNDS,
+        Self::STENCIL_COMPARE_MASK,
+        Self::STENCIL_WRITE_MASK,
+        Self::STENCIL_REFERENCE,
+        Self::CULL_MODE,
+        Self::FRONT_FACE,
+        Self::PRIMITIVE_TOPOLOGY,
+        Self::VIEWPORT_WITH_COUNT,
+        Self::SCISSOR_WITH_COUNT,
+        Self::VERTEX_INPUT_BINDING_STRIDE,
+        Self::DEPTH_TEST_ENABLE,
+        Self::DEPTH_WRITE_ENABLE,
+        Self::DEPTH_COMPARE_OP,
+        Self::DEPTH_BOUNDS_TEST_ENABLE,
+        Self::STENCIL_TEST_ENABLE,
+        Self::STENCIL_OP,
+        Self::RASTERIZER_DISCARD_ENABLE,
+        Self::DEPTH_BIAS_ENABLE,
+        Self::PRIMITIVE_RESTART_ENABLE,
+        Self::LINE_STIPPLE,
+        Self::VIEWPORT_W_SCALING_NV,
+        Self::DISCARD_RECTANGLE_EXT,
+        Self::DISCARD_RECTANGLE_ENABLE_EXT,
+        Self::DISCARD_RECTANGLE_MODE_EXT,
+        Self::SAMPLE_LOCATIONS_EXT,
+        Self::RAY_TRACING_PIPELINE_STACK_SIZE_KHR,
+        Self::VIEWPORT_SHADING_RATE_PALETTE_NV,
+        Self::VIEWPORT_COARSE_SAMPLE_ORDER_NV,
+        Self::EXCLUSIVE_SCISSOR_ENABLE_NV,
+        Self::EXCLUSIVE_SCISSOR_NV,
+        Self::FRAGMENT_SHADING_RATE_KHR,
+        Self::VERTEX_INPUT_EXT,
+        Self::PATCH_CONTROL_POINTS_EXT,
+        Self::LOGIC_OP_EXT,
+        Self::COLOR_WRITE_ENABLE_EXT,
+        Self::DEPTH_CLAMP_ENABLE_EXT,
+        Self::POLYGON_MODE_EXT,
+        Self::RASTERIZATION_SAMPLES_EXT,
+        Self::SAMPLE_MASK_EXT,
+        Self::ALPHA_TO_COVERAGE_ENABLE_EXT,
+        Self::ALPHA_TO_ONE_ENABLE_EXT,
+        Self::LOGIC_OP_ENABLE_EXT,
+        Self::COLOR_BLEND_ENABLE_EXT,
+        Self::COLOR_BLEND_EQUATION_EXT,
+        Self::COLOR_WRITE_MASK_EXT,
+        Self::TESSELLATION_DOMAIN_ORIGIN_EXT,
+        Self::RASTERIZATION_STREAM_EXT,
+        Self::CONSERVATIVE_RASTERIZATION_MODE_EXT,
+        Self::EXTRA_PRIMITIVE_OVERESTIMATION_SIZE_EXT,
+        Self::DEPTH_CLIP_ENABLE_EXT,
+        Self::SAMPLE_LOCATIONS_ENABLE_EXT,
+        Self::COLOR_BLEND_ADVANCED_EXT,
+        Self::PROVOKING_VERTEX_MODE_EXT,
+        Self::LINE_RASTERIZATION_MODE_EXT,
+        Self::LINE_STIPPLE_ENABLE_EXT,
+        Self::DEPTH_CLIP_NEGATIVE_ONE_TO_ONE_EXT,
+        Self::VIEWPORT_W_SCALING_ENABLE_NV,
+        Self::VIEWPORT_SWIZZLE_NV,
+        Self::COVERAGE_TO_COLOR_ENABLE_NV,
+        Self::COVERAGE_TO_COLOR_LOCATION_NV,
+        Self::COVERAGE_MODULATION_MODE_NV,
+        Self::COVERAGE_MODULATION_TABLE_ENABLE_NV,
+        Self::COVERAGE_MODULATION_TABLE_NV,
+        Self::SHADING_RATE_IMAGE_ENABLE_NV,
+        Self::REPRESENTATIVE_FRAGMENT_TEST_ENABLE_NV,
+        Self::COVERAGE_REDUCTION_MODE_NV,
+        Self::ATTACHMENT_FEEDBACK_LOOP_ENABLE_EXT,
+        Self::DEPTH_CLAMP_RANGE_EXT,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -2505,6 +3056,9 @@ impl Filter {
     pub const LINEAR: Self = Self(1);
     pub const CUBIC_EXT: Self = Self(1000015000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::NEAREST, Self::LINEAR, Self::CUBIC_EXT];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -2834,6 +3388,308 @@ impl Format {
     pub const G14X2_B14X2R14X2_2PLANE_420_UNORM_3PACK16_ARM: Self = Self(1000609012);
     pub const G14X2_B14X2R14X2_2PLANE_422_UNORM_3PACK16_ARM: Self = Self(1000609013);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::UNDEFINED,
+        Self::R4G4_UNORM_PACK8,
+        Self::R4G4B4A4_UNORM_PACK16,
+        Self::B4G4R4A4_UNORM_PACK16,
+        Self::R5G6B5_UNORM_PACK16,
+        Self::B5G6R5_UNORM_PACK16,
+        Self::R5G5B5A1_UNORM_PACK16,
+        Self::B5G5R5A1_UNORM_PACK16,
+        Self::A1R5G5B5_UNORM_PACK16,
+        Self::R8_UNORM,
+        Self::R8_SNORM,
+        Self::R8_USCALED,
+        Self::R8_SSCALED,
+        Self::R8_UINT,
+        Self::R8_SINT,
+        Self::R8_SRGB,
+        Self::R8G8_UNORM,
+        Self::R8G8_SNORM,
+        Self::R8G8_USCALED,
+        Self::R8G8_SSCALED,
+        Self::R8G8_UINT,
+        Self::R8G8_SINT,
+        Self::R8G8_SRGB,
+        Self::R8G8B8_UNORM,
+        Self::R8G8B8_SNORM,
+        Self::R8G8B8_USCALED,
+        Self::R8G8B8_SSCALED,
+        Self::R8G8B8_UINT,
+        Self::R8G8B8_SINT,
+        Self::R8G8B8_SRGB,
+        Self::B8G8R8_UNORM,
+        Self::B8G8R8_SNORM,
+        Self::B8G8R8_USCALED,
+        Self::B8G8R8_SSCALED,
+        Self::B8G8R8_UINT,
+        Self::B8G8R8_SINT,
+        Self::B8G8R8_SRGB,
+        Self::R8G8B8A8_UNORM,
+        Self::R8G8B8A8_SNORM,
+        Self::R8G8B8A8_USCALED,
+        Self::R8G8B8A8_SSCALED,
+        Self::R8G8B8A8_UINT,
+        Self::R8G8B8A8_SINT,
+        Self::R8G8B8A8_SRGB,
+        Self::B8G8R8A8_UNORM,
+        Self::B8G8R8A8_SNORM,
+        Self::B8G8R8A8_USCALED,
+        Self::B8G8R8A8_SSCALED,
+        Self::B8G8R8A8_UINT,
+        Self::B8G8R8A8_SINT,
+        Self::B8G8R8A8_SRGB,
+        Self::A8B8G8R8_UNORM_PACK32,
+        Self::A8B8G8R8_SNORM_PACK32,
+        Self::A8B8G8R8_USCALED_PACK32,
+        Self::A8B8G8R8_SSCALED_PACK32,
+        Self::A8B8G8R8_UINT_PACK32,
+        Self::A8B8G8R8_SINT_PACK32,
+        Self::A8B8G8R8_SRGB_PACK32,
+        Self::A2R10G10B10_UNORM_PACK32,
+        Self::A2R10G10B10_SNORM_PACK32,
+        Self::A2R10G10B10_USCALED_PACK32,
+        Self::A2R10G10B10_SSCALED_PACK32,
+        Self::A2R10G10B10_UINT_PACK32,
+        Self::A2R10G10B10_SINT_PACK32,
+        Self::A2B10G10R10_UNORM_PACK32,
+        Self::A2B10G10R10_SNORM_PACK32,
+        Self::A2B10G10R10_USCALED_PACK32,
+        Self::A2B10G10R10_SSCALED_PACK32,
+        Self::A2B10G10R10_UINT_PACK32,
+        Self::A2B10G10R10_SINT_PACK32,
+        Self::R16_UNORM,
+        Self::R16_SNORM,
+        Self::R16_USCALED,
+        Self::R16_SSCALED,
+        Self::R16_UINT,
+        Self::R16_SINT,
+        Self::R16_SFLOAT,
+        Self::R16G16_UNORM,
+        Self::R16G16_SNORM,
+        Self::R16G16_USCALED,
+        Self::R16G16_SSCALED,
+        Self::R16G16_UINT,
+        Self::R16G16_SINT,
+        Self::R16G16_SFLOAT,
+        Self::R16G16B16_UNORM,
+        Self::R16G16B16_SNORM,
+        Self::R16G16B16_USCALED,
+        Self::R16G16B16_SSCALED,
+        Self::R16G16B16_UINT,
+        Self::R16G16B16_SINT,
+        Self::R16G16B16_SFLOAT,
+        Self::R16G16B16A16_UNORM,
+        Self::R16G16B16A16_SNORM,
+        Self::R16G16B16A16_USCALED,
+        Self::R16G16B16A16_SSCALED,
+        Self::R16G16B16A16_UINT,
+        Self::R16G16B16A16_SINT,
+        Self::R16G16B16A16_SFLOAT,
+        Self::R32_UINT,
+        Self::R32_SINT,
+        Self::R32_SFLOAT,
+        Self::R32G32_UINT,
+        Self::R32G32_SINT,
+        Self::R32G32_SFLOAT,
+        Self::R32G32B32_UINT,
+        Self::R32G32B32_SINT,
+        Self::R32G32B32_SFLOAT,
+        Self::R32G32B32A32_UINT,
+        Self::R32G32B32A32_SINT,
+        Self::R32G32B32A32_SFLOAT,
+        Self::R64_UINT,
+        Self::R64_SINT,
+        Self::R64_SFLOAT,
+        Self::R64G64_UINT,
+        Self::R64G64_SINT,
+        Self::R64G64_SFLOAT,
+        Self::R64G64B64_UINT,
+        Self::R64G64B64_SINT,
+        Self::R64G64B64_SFLOAT,
+        Self::R64G64B64A64_UINT,
+        Self::R64G64B64A64_SINT,
+        Self::R64G64B64A64_SFLOAT,
+        Self::B10G11R11_UFLOAT_PACK32,
+        Self::E5B9G9R9_UFLOAT_PACK32,
+        Self::D16_UNORM,
+        Self::X8_D24_UNORM_PACK32,
+        Self::D32_SFLOAT,
+        Self::S8_UINT,
+        Self::D16_UNORM_S8_UINT,
+        Self::D24_UNORM_S8_UINT,
+        Self::D32_SFLOAT_S8_UINT,
+        Self::BC1_RGB_UNORM_BLOCK,
+        Self::BC1_RGB_SRGB_BLOCK,
+        Self::BC1_RGBA_UNORM_BLOCK,
+        Self::BC1_RGBA_SRGB_BLOCK,
+        Self::BC2_UNORM_BLOCK,
+        Self::BC2_SRGB_BLOCK,
+        Self::BC3_UNORM_BLOCK,
+        Self::BC3_SRGB_BLOCK,
+        Self::BC4_UNORM_BLOCK,
+        Self::BC4_SNORM_BLOCK,
+        Self::BC5_UNORM_BLOCK,
+        Self::BC5_SNORM_BLOCK,
+        Self::BC6H_UFLOAT_BLOCK,
+        Self::BC6H_SFLOAT_BLOCK,
+        Self::BC7_UNORM_BLOCK,
+        Self::BC7_SRGB_BLOCK,
+        Self::ETC2_R8G8B8_UNORM_BLOCK,
+        Self::ETC2_R8G8B8_SRGB_BLOCK,
+        Self::ETC2_R8G8B8A1_UNORM_BLOCK,
+        Self::ETC2_R8G8B8A1_SRGB_BLOCK,
+        Self::ETC2_R8G8B8A8_UNORM_BLOCK,
+        Self::ETC2_R8G8B8A8_SRGB_BLOCK,
+        Self::EAC_R11_UNORM_BLOCK,
+        Self::EAC_R11_SNORM_BLOCK,
+        Self::EAC_R11G11_UNORM_BLOCK,
+        Self::EAC_R11G11_SNORM_BLOCK,
+        Self::ASTC_4X4_UNORM_BLOCK,
+        Self::ASTC_4X4_SRGB_BLOCK,
+        Self::ASTC_5X4_UNORM_BLOCK,
+        Self::ASTC_5X4_SRGB_BLOCK,
+        Self::ASTC_5X5_UNORM_BLOCK,
+        Self::ASTC_5X5_SRGB_BLOCK,
+        Self::ASTC_6X5_UNORM_BLOCK,
+        Self::ASTC_6X5_SRGB_BLOCK,
+        Self::ASTC_6X6_UNORM_BLOCK,
+        Self::ASTC_6X6_SRGB_BLOCK,
+        Self::ASTC_8X5_UNORM_BLOCK,
+        Self::ASTC_8X5_SRGB_BLOCK,
+        Self::ASTC_8X6_UNORM_BLOCK,
+        Self::ASTC_8X6_SRGB_BLOCK,
+        Self::ASTC_8X8_UNORM_BLOCK,
+        Self::ASTC_8X8_SRGB_BLOCK,
+        Self::ASTC_10X5_UNORM_BLOCK,
+        Self::ASTC_10X5_SRGB_BLOCK,
+        Self::ASTC_10X6_UNORM_BLOCK,
+        Self::ASTC_10X6_SRGB_BLOCK,
+        Self::ASTC_10X8_UNORM_BLOCK,
+        Self::ASTC_10X8_SRGB_BLOCK,
+        Self::ASTC_10X10_UNORM_BLOCK,
+        Self::ASTC_10X10_SRGB_BLOCK,
+        Self::ASTC_12X10_UNORM_BLOCK,
+        Self::ASTC_12X10_SRGB_BLOCK,
+        Self::ASTC_12X12_UNORM_BLOCK,
+        Self::ASTC_12X12_SRGB_BLOCK,
+        Self::G8B8G8R8_422_UNORM,
+        Self::B8G8R8G8_422_UNORM,
+        Self::G8_B8_R8_3PLANE_420_UNORM,
+        Self::G8_B8R8_2PLANE_420_UNORM,
+        Self::G8_B8_R8_3PLANE_422_UNORM,
+        Self::G8_B8R8_2PLANE_422_UNORM,
+        Self::G8_B8_R8_3PLANE_444_UNORM,
+        Self::R10X6_UNORM_PACK16,
+        Self::R10X6G10X6_UNORM_2PACK16,
+        Self::R10X6G10X6B10X6A10X6_UNORM_4PACK16,
+        Self::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16,
+        Self::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16,
+        Self::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16,
+        Self::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16,
+        Self::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16,
+        Self::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16,
+        Self::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16,
+        Self::R12X4_UNORM_PACK16,
+        Self::R12X4G12X4_UNORM_2PACK16,
+        Self::R12X4G12X4B12X4A12X4_UNORM_4PACK16,
+        Self::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16,
+        Self::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16,
+        Self::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16,
+        Self::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16,
+        Self::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16,
+        Self::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16,
+        Self::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16,
+        Self::G16B16G16R16_422_UNORM,
+        Self::B16G16R16G16_422_UNORM,
+        Self::G16_B16_R16_3PLANE_420_UNORM,
+        Self::G16_B16R16_2PLANE_420_UNORM,
+        Self::G16_B16_R16_3PLANE_422_UNORM,
+        Self::G16_B16R16_2PLANE_422_UNORM,
+        Self::G16_B16_R16_3PLANE_444_UNORM,
+        Self::G8_B8R8_2PLANE_444_UNORM,
+        Self::G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16,
+        Self::G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16,
+        Self::G16_B16R16_2PLANE_444_UNORM,
+        Self::A4R4G4B4_UNORM_PACK16,
+        Self::A4B4G4R4_UNORM_PACK16,
+        Self::ASTC_4X4_SFLOAT_BLOCK,
+        Self::ASTC_5X4_SFLOAT_BLOCK,
+        Self::ASTC_5X5_SFLOAT_BLOCK,
+        Self::ASTC_6X5_SFLOAT_BLOCK,
+        Self::ASTC_6X6_SFLOAT_BLOCK,
+        Self::ASTC_8X5_SFLOAT_BLOCK,
+        Self::ASTC_8X6_SFLOAT_BLOCK,
+        Self::ASTC_8X8_SFLOAT_BLOCK,
+        Self::ASTC_10X5_SFLOAT_BLOCK,
+        Self::ASTC_10X6_SFLOAT_BLOCK,
+        Self::ASTC_10X8_SFLOAT_BLOCK,
+        Self::ASTC_10X10_SFLOAT_BLOCK,
+        Self::ASTC_12X10_SFLOAT_BLOCK,
+        Self::ASTC_12X12_SFLOAT_BLOCK,
+        Self::A1B5G5R5_UNORM_PACK16,
+        Self::A8_UNORM,
+        Self::PVRTC1_2BPP_UNORM_BLOCK_IMG,
+        Self::PVRTC1_4BPP_UNORM_BLOCK_IMG,
+        Self::PVRTC2_2BPP_UNORM_BLOCK_IMG,
+        Self::PVRTC2_4BPP_UNORM_BLOCK_IMG,
+        Self::PVRTC1_2BPP_SRGB_BLOCK_IMG,
+        Self::PVRTC1_4BPP_SRGB_BLOCK_IMG,
+        Self::PVRTC2_2BPP_SRGB_BLOCK_IMG,
+        Self::PVRTC2_4BPP_SRGB_BLOCK_IMG,
+        Self::ASTC_3X3X3_UNORM_BLOCK_EXT,
+        Self::ASTC_3X3X3_SRGB_BLOCK_EXT,
+        Self::ASTC_3X3X3_SFLOAT_BLOCK_EXT,
+        Self::ASTC_4X3X3_UNORM_BLOCK_EXT,
+        Self::ASTC_4X3X3_SRGB_BLOCK_EXT,
+        Self::ASTC_4X3X3_SFLOAT_BLOCK_EXT,
+        Self::ASTC_4X4X3_UNORM_BLOCK_EXT,
+        Self::ASTC_4X4X3_SRGB_BLOCK_EXT,
+        Self::ASTC_4X4X3_SFLOAT_BLOCK_EXT,
+        Self::ASTC_4X4X4_UNORM_BLOCK_EXT,
+        Self::ASTC_4X4X4_SRGB_BLOCK_EXT,
+        Self::ASTC_4X4X4_SFLOAT_BLOCK_EXT,
+        Self::ASTC_5X4X4_UNORM_BLOCK_EXT,
+        Self::ASTC_5X4X4_SRGB_BLOCK_EXT,
+        Self::ASTC_5X4X4_SFLOAT_BLOCK_EXT,
+        Self::ASTC_5X5X4_UNORM_BLOCK_EXT,
+        Self::ASTC_5X5X4_SRGB_BLOCK_EXT,
+        Self::ASTC_5X5X4_SFLOAT_BLOCK_EXT,
+        Self::ASTC_5X5X5_UNORM_BLOCK_EXT,
+        Self::ASTC_5X5X5_SRGB_BLOCK_EXT,
+        Self::ASTC_5X5X5_SFLOAT_BLOCK_EXT,
+        Self::ASTC_6X5X5_UNORM_BLOCK_EXT,
+        Self::ASTC_6X5X5_SRGB_BLOCK_EXT,
+        Self::ASTC_6X5X5_SFLOAT_BLOCK_EXT,
+        Self::ASTC_6X6X5_UNORM_BLOCK_EXT,
+        Self::ASTC_6X6X5_SRGB_BLOCK_EXT,
+        Self::ASTC_6X6X5_SFLOAT_BLOCK_EXT,
+        Self::ASTC_6X6X6_UNORM_BLOCK_EXT,
+        Self::ASTC_6X6X6_SRGB_BLOCK_EXT,
+        Self::ASTC_6X6X6_SFLOAT_BLOCK_EXT,
+        Self::R8_BOOL_ARM,
+        Self::R16_SFLOAT_FPENCODING_BFLOAT16_ARM,
+        Self::R8_SFLOAT_FPENCODING_FLOAT8E4M3_ARM,
+        Self::R8_SFLOAT_FPENCODING_FLOAT8E5M2_ARM,
+        Self::R16G16_SFIXED5_NV,
+        Self::R10X6_UINT_PACK16_ARM,
+        Self::R10X6G10X6_UINT_2PACK16_ARM,
+        Self::R10X6G10X6B10X6A10X6_UINT_4PACK16_ARM,
+        Self::R12X4_UINT_PACK16_ARM,
+        Self::R12X4G12X4_UINT_2PACK16_ARM,
+        Self::R12X4G12X4B12X4A12X4_UINT_4PACK16_ARM,
+        Self::R14X2_UINT_PACK16_ARM,
+        Self::R14X2G14X2_UINT_2PACK16_ARM,
+        Self::R14X2G14X2B14X2A14X2_UINT_4PACK16_ARM,
+        Self::R14X2_UNORM_PACK16_ARM,
+        Self::R14X2G14X2_UNORM_2PACK16_ARM,
+        Self::R14X2G14X2B14X2A14X2_UNORM_4PACK16_ARM,
+        Self::G14X2_B14X2R14X2_2PLANE_420_UNORM_3PACK16_ARM,
+        Self::G14X2_B14X2R14X2_2PLANE_422_UNORM_3PACK16_ARM,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3165,6 +4021,10 @@ impl FragmentShadingRateCombinerOpKHR {
     pub const MAX: Self = Self(3);
     pub const MUL: Self = Self(4);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] =
+        &[Self::KEEP, Self::REPLACE, Self::MIN, Self::MAX, Self::MUL];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3210,6 +4070,22 @@ impl FragmentShadingRateNV {
     pub const _16_INVOCATIONS_PER_PIXEL: Self = Self(14);
     pub const NO_INVOCATIONS: Self = Self(15);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::_1_INVOCATION_PER_PIXEL,
+        Self::_1_INVOCATION_PER_1X2_PIXELS,
+        Self::_1_INVOCATION_PER_2X1_PIXELS,
+        Self::_1_INVOCATION_PER_2X2_PIXELS,
+        Self::_1_INVOCATION_PER_2X4_PIXELS,
+        Self::_1_INVOCATION_PER_4X2_PIXELS,
+        Self::_1_INVOCATION_PER_4X4_PIXELS,
+        Self::_2_INVOCATIONS_PER_PIXEL,
+        Self::_4_INVOCATIONS_PER_PIXEL,
+        Self::_8_INVOCATIONS_PER_PIXEL,
+        Self::_16_INVOCATIONS_PER_PIXEL,
+        Self::NO_INVOCATIONS,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3252,6 +4128,9 @@ impl FragmentShadingRateTypeNV {
     pub const FRAGMENT_SIZE: Self = Self(0);
     pub const ENUMS: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::FRAGMENT_SIZE, Self::ENUMS];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3284,6 +4163,9 @@ impl FrontFace {
     pub const COUNTER_CLOCKWISE: Self = Self(0);
     pub const CLOCKWISE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::COUNTER_CLOCKWISE, Self::CLOCKWISE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3318,6 +4200,14 @@ impl FullScreenExclusiveEXT {
     pub const DISALLOWED: Self = Self(2);
     pub const APPLICATION_CONTROLLED: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::DEFAULT,
+        Self::ALLOWED,
+        Self::DISALLOWED,
+        Self::APPLICATION_CONTROLLED,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3356,6 +4246,16 @@ impl GeometryTypeKHR {
     pub const LINEAR_SWEPT_SPHERES_NV: Self = Self(1000429005);
     pub const DENSE_GEOMETRY_FORMAT_TRIANGLES_AMDX: Self = Self(1000478000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::TRIANGLES,
+        Self::AABBS,
+        Self::INSTANCES,
+        Self::SPHERES_NV,
+        Self::LINEAR_SWEPT_SPHERES_NV,
+        Self::DENSE_GEOMETRY_FORMAT_TRIANGLES_AMDX,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3422,6 +4322,42 @@ impl ImageLayout {
     pub const VIDEO_ENCODE_QUANTIZATION_MAP_KHR: Self = Self(1000553000);
     pub const ZERO_INITIALIZED_EXT: Self = Self(1000620000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::UNDEFINED,
+        Self::GENERAL,
+        Self::COLOR_ATTACHMENT_OPTIMAL,
+        Self::DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
+        Self::DEPTH_STENCIL_READ_ONLY_OPTIMAL,
+        Self::SHADER_READ_ONLY_OPTIMAL,
+        Self::TRANSFER_SRC_OPTIMAL,
+        Self::TRANSFER_DST_OPTIMAL,
+        Self::PREINITIALIZED,
+        Self::DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL,
+        Self::DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL,
+        Self::DEPTH_ATTACHMENT_OPTIMAL,
+        Self::DEPTH_READ_ONLY_OPTIMAL,
+        Self::STENCIL_ATTACHMENT_OPTIMAL,
+        Self::STENCIL_READ_ONLY_OPTIMAL,
+        Self::READ_ONLY_OPTIMAL,
+        Self::ATTACHMENT_OPTIMAL,
+        Self::RENDERING_LOCAL_READ,
+        Self::PRESENT_SRC_KHR,
+        Self::VIDEO_DECODE_DST_KHR,
+        Self::VIDEO_DECODE_SRC_KHR,
+        Self::VIDEO_DECODE_DPB_KHR,
+        Self::SHARED_PRESENT_KHR,
+        Self::FRAGMENT_DENSITY_MAP_OPTIMAL_EXT,
+        Self::FRAGMENT_SHADING_RATE_ATTACHMENT_OPTIMAL_KHR,
+        Self::VIDEO_ENCODE_DST_KHR,
+        Self::VIDEO_ENCODE_SRC_KHR,
+        Self::VIDEO_ENCODE_DPB_KHR,
+        Self::ATTACHMENT_FEEDBACK_LOOP_OPTIMAL_EXT,
+        Self::TENSOR_ALIASING_ARM,
+        Self::VIDEO_ENCODE_QUANTIZATION_MAP_KHR,
+        Self::ZERO_INITIALIZED_EXT,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3485,6 +4421,10 @@ impl ImageTiling {
     pub const LINEAR: Self = Self(1);
     pub const DRM_FORMAT_MODIFIER_EXT: Self = Self(1000158000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] =
+        &[Self::OPTIMAL, Self::LINEAR, Self::DRM_FORMAT_MODIFIER_EXT];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3519,6 +4459,9 @@ impl ImageType {
     pub const _2D: Self = Self(1);
     pub const _3D: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::_1D, Self::_2D, Self::_3D];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3557,6 +4500,17 @@ impl ImageViewType {
     pub const _2D_ARRAY: Self = Self(5);
     pub const CUBE_ARRAY: Self = Self(6);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::_1D,
+        Self::_2D,
+        Self::_3D,
+        Self::CUBE,
+        Self::_1D_ARRAY,
+        Self::_2D_ARRAY,
+        Self::CUBE_ARRAY,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3596,6 +4550,9 @@ impl IndexType {
     pub const UINT8: Self = Self(1000265000);
     pub const NONE_KHR: Self = Self(1000165000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::UINT16, Self::UINT32, Self::UINT8, Self::NONE_KHR];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3645,6 +4602,27 @@ impl IndirectCommandsTokenTypeEXT {
     pub const DRAW_MESH_TASKS_COUNT: Self = Self(1000328001);
     pub const TRACE_RAYS2: Self = Self(1000386004);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::EXECUTION_SET,
+        Self::PUSH_CONSTANT,
+        Self::SEQUENCE_INDEX,
+        Self::INDEX_BUFFER,
+        Self::VERTEX_BUFFER,
+        Self::DRAW_INDEXED,
+        Self::DRAW,
+        Self::DRAW_INDEXED_COUNT,
+        Self::DRAW_COUNT,
+        Self::DISPATCH,
+        Self::PUSH_DATA,
+        Self::PUSH_DATA_SEQUENCE_INDEX,
+        Self::DRAW_MESH_TASKS_NV,
+        Self::DRAW_MESH_TASKS_COUNT_NV,
+        Self::DRAW_MESH_TASKS,
+        Self::DRAW_MESH_TASKS_COUNT,
+        Self::TRACE_RAYS2,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3702,6 +4680,22 @@ impl IndirectCommandsTokenTypeNV {
     pub const PIPELINE: Self = Self(1000428003);
     pub const DISPATCH: Self = Self(1000428004);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::SHADER_GROUP,
+        Self::STATE_FLAGS,
+        Self::INDEX_BUFFER,
+        Self::VERTEX_BUFFER,
+        Self::PUSH_CONSTANT,
+        Self::DRAW_INDEXED,
+        Self::DRAW,
+        Self::DRAW_TASKS,
+        Self::PUSH_DATA,
+        Self::DRAW_MESH_TASKS,
+        Self::PIPELINE,
+        Self::DISPATCH,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3744,6 +4738,9 @@ impl IndirectExecutionSetInfoTypeEXT {
     pub const PIPELINES: Self = Self(0);
     pub const SHADER_OBJECTS: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::PIPELINES, Self::SHADER_OBJECTS];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3775,6 +4772,9 @@ pub struct InternalAllocationType(i32);
 impl InternalAllocationType {
     pub const EXECUTABLE: Self = Self(0);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::EXECUTABLE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3816,6 +4816,22 @@ impl LatencyMarkerNV {
     pub const OUT_OF_BAND_PRESENT_START: Self = Self(10);
     pub const OUT_OF_BAND_PRESENT_END: Self = Self(11);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::SIMULATION_START,
+        Self::SIMULATION_END,
+        Self::RENDERSUBMIT_START,
+        Self::RENDERSUBMIT_END,
+        Self::PRESENT_START,
+        Self::PRESENT_END,
+        Self::INPUT_SAMPLE,
+        Self::TRIGGER_FLASH,
+        Self::OUT_OF_BAND_RENDERSUBMIT_START,
+        Self::OUT_OF_BAND_RENDERSUBMIT_END,
+        Self::OUT_OF_BAND_PRESENT_START,
+        Self::OUT_OF_BAND_PRESENT_END,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3864,6 +4880,18 @@ impl LayerSettingTypeEXT {
     pub const FLOAT64: Self = Self(6);
     pub const STRING: Self = Self(7);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::BOOL32,
+        Self::INT32,
+        Self::INT64,
+        Self::UINT32,
+        Self::UINT64,
+        Self::FLOAT32,
+        Self::FLOAT64,
+        Self::STRING,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3902,6 +4930,9 @@ impl LayeredDriverUnderlyingApiMSFT {
     pub const NONE: Self = Self(0);
     pub const D3D12: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::NONE, Self::D3D12];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3936,6 +4967,14 @@ impl LineRasterizationMode {
     pub const BRESENHAM: Self = Self(2);
     pub const RECTANGULAR_SMOOTH: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::DEFAULT,
+        Self::RECTANGULAR,
+        Self::BRESENHAM,
+        Self::RECTANGULAR_SMOOTH,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -3984,6 +5023,26 @@ impl LogicOp {
     pub const NAND: Self = Self(14);
     pub const SET: Self = Self(15);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::CLEAR,
+        Self::AND,
+        Self::AND_REVERSE,
+        Self::COPY,
+        Self::AND_INVERTED,
+        Self::NO_OP,
+        Self::XOR,
+        Self::OR,
+        Self::NOR,
+        Self::EQUIVALENT,
+        Self::INVERT,
+        Self::OR_REVERSE,
+        Self::COPY_INVERTED,
+        Self::OR_INVERTED,
+        Self::NAND,
+        Self::SET,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4031,6 +5090,9 @@ impl MemoryOverallocationBehaviorAMD {
     pub const ALLOWED: Self = Self(1);
     pub const DISALLOWED: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::DEFAULT, Self::ALLOWED, Self::DISALLOWED];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4064,6 +5126,9 @@ impl MicromapTypeEXT {
     pub const OPACITY_MICROMAP: Self = Self(0);
     pub const DISPLACEMENT_MICROMAP_NV: Self = Self(1000397000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::OPACITY_MICROMAP, Self::DISPLACEMENT_MICROMAP_NV];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4153,6 +5218,69 @@ impl ObjectType {
     pub const INDIRECT_COMMANDS_LAYOUT_EXT: Self = Self(1000572000);
     pub const INDIRECT_EXECUTION_SET_EXT: Self = Self(1000572001);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::UNKNOWN,
+        Self::INSTANCE,
+        Self::PHYSICAL_DEVICE,
+        Self::DEVICE,
+        Self::QUEUE,
+        Self::SEMAPHORE,
+        Self::COMMAND_BUFFER,
+        Self::FENCE,
+        Self::DEVICE_MEMORY,
+        Self::BUFFER,
+        Self::IMAGE,
+        Self::EVENT,
+        Self::QUERY_POOL,
+        Self::BUFFER_VIEW,
+        Self::IMAGE_VIEW,
+        Self::SHADER_MODULE,
+        Self::PIPELINE_CACHE,
+        Self::PIPELINE_LAYOUT,
+        Self::RENDER_PASS,
+        Self::PIPELINE,
+        Self::DESCRIPTOR_SET_LAYOUT,
+        Self::SAMPLER,
+        Self::DESCRIPTOR_POOL,
+        Self::DESCRIPTOR_SET,
+        Self::FRAMEBUFFER,
+        Self::COMMAND_POOL,
+        Self::DESCRIPTOR_UPDATE_TEMPLATE,
+        Self::SAMPLER_YCBCR_CONVERSION,
+        Self::PRIVATE_DATA_SLOT,
+        Self::SURFACE_KHR,
+        Self::SWAPCHAIN_KHR,
+        Self::DISPLAY_KHR,
+        Self::DISPLAY_MODE_KHR,
+        Self::DEBUG_REPORT_CALLBACK_EXT,
+        Self::VIDEO_SESSION_KHR,
+        Self::VIDEO_SESSION_PARAMETERS_KHR,
+        Self::CU_MODULE_NVX,
+        Self::CU_FUNCTION_NVX,
+        Self::DEBUG_UTILS_MESSENGER_EXT,
+        Self::ACCELERATION_STRUCTURE_KHR,
+        Self::VALIDATION_CACHE_EXT,
+        Self::ACCELERATION_STRUCTURE_NV,
+        Self::PERFORMANCE_CONFIGURATION_INTEL,
+        Self::DEFERRED_OPERATION_KHR,
+        Self::INDIRECT_COMMANDS_LAYOUT_NV,
+        Self::CUDA_MODULE_NV,
+        Self::CUDA_FUNCTION_NV,
+        Self::BUFFER_COLLECTION_FUCHSIA,
+        Self::MICROMAP_EXT,
+        Self::TENSOR_ARM,
+        Self::TENSOR_VIEW_ARM,
+        Self::OPTICAL_FLOW_SESSION_NV,
+        Self::SHADER_EXT,
+        Self::PIPELINE_BINARY_KHR,
+        Self::SEMAPHORE_SCI_SYNC_POOL_NV,
+        Self::DATA_GRAPH_PIPELINE_SESSION_ARM,
+        Self::EXTERNAL_COMPUTE_QUEUE_NV,
+        Self::INDIRECT_COMMANDS_LAYOUT_EXT,
+        Self::INDIRECT_EXECUTION_SET_EXT,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4242,6 +5370,9 @@ impl OpacityMicromapFormatEXT {
     pub const _2_STATE: Self = Self(1);
     pub const _4_STATE: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::_2_STATE, Self::_4_STATE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4277,6 +5408,15 @@ impl OpacityMicromapSpecialIndexEXT {
     pub const FULLY_UNKNOWN_OPAQUE: Self = Self(-4);
     pub const CLUSTER_GEOMETRY_DISABLE_OPACITY_MICROMAP_NV: Self = Self(-5);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::FULLY_TRANSPARENT,
+        Self::FULLY_OPAQUE,
+        Self::FULLY_UNKNOWN_TRANSPARENT,
+        Self::FULLY_UNKNOWN_OPAQUE,
+        Self::CLUSTER_GEOMETRY_DISABLE_OPACITY_MICROMAP_NV,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4314,6 +5454,9 @@ impl OpticalFlowPerformanceLevelNV {
     pub const MEDIUM: Self = Self(2);
     pub const FAST: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::UNKNOWN, Self::SLOW, Self::MEDIUM, Self::FAST];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4355,6 +5498,19 @@ impl OpticalFlowSessionBindingPointNV {
     pub const BACKWARD_COST: Self = Self(7);
     pub const GLOBAL_FLOW: Self = Self(8);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::UNKNOWN,
+        Self::INPUT,
+        Self::REFERENCE,
+        Self::HINT,
+        Self::FLOW_VECTOR,
+        Self::BACKWARD_FLOW_VECTOR,
+        Self::COST,
+        Self::BACKWARD_COST,
+        Self::GLOBAL_FLOW,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4394,6 +5550,9 @@ impl OutOfBandQueueTypeNV {
     pub const RENDER: Self = Self(0);
     pub const PRESENT: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::RENDER, Self::PRESENT];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4427,6 +5586,13 @@ impl PartitionedAccelerationStructureOpTypeNV {
     pub const UPDATE_INSTANCE: Self = Self(1);
     pub const WRITE_PARTITION_TRANSLATION: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::WRITE_INSTANCE,
+        Self::UPDATE_INSTANCE,
+        Self::WRITE_PARTITION_TRANSLATION,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4459,6 +5625,9 @@ pub struct PerformanceConfigurationTypeINTEL(i32);
 impl PerformanceConfigurationTypeINTEL {
     pub const COMMAND_QUEUE_METRICS_DISCOVERY_ACTIVATED: Self = Self(0);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::COMMAND_QUEUE_METRICS_DISCOVERY_ACTIVATED];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4491,6 +5660,9 @@ impl PerformanceCounterScopeKHR {
     pub const RENDER_PASS: Self = Self(1);
     pub const COMMAND: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::COMMAND_BUFFER, Self::RENDER_PASS, Self::COMMAND];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4528,6 +5700,16 @@ impl PerformanceCounterStorageKHR {
     pub const FLOAT32: Self = Self(4);
     pub const FLOAT64: Self = Self(5);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::INT32,
+        Self::INT64,
+        Self::UINT32,
+        Self::UINT64,
+        Self::FLOAT32,
+        Self::FLOAT64,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4573,6 +5755,21 @@ impl PerformanceCounterUnitKHR {
     pub const HERTZ: Self = Self(9);
     pub const CYCLES: Self = Self(10);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::GENERIC,
+        Self::PERCENTAGE,
+        Self::NANOSECONDS,
+        Self::BYTES,
+        Self::BYTES_PER_SECOND,
+        Self::KELVIN,
+        Self::WATTS,
+        Self::VOLTS,
+        Self::AMPS,
+        Self::HERTZ,
+        Self::CYCLES,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4614,6 +5811,9 @@ impl PerformanceOverrideTypeINTEL {
     pub const NULL_HARDWARE: Self = Self(0);
     pub const FLUSH_GPU_CACHES: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::NULL_HARDWARE, Self::FLUSH_GPU_CACHES];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4646,6 +5846,10 @@ impl PerformanceParameterTypeINTEL {
     pub const HW_COUNTERS_SUPPORTED: Self = Self(0);
     pub const STREAM_MARKER_VALID_BITS: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] =
+        &[Self::HW_COUNTERS_SUPPORTED, Self::STREAM_MARKER_VALID_BITS];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4681,6 +5885,15 @@ impl PerformanceValueTypeINTEL {
     pub const BOOL: Self = Self(3);
     pub const STRING: Self = Self(4);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::UINT32,
+        Self::UINT64,
+        Self::FLOAT,
+        Self::BOOL,
+        Self::STRING,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4717,6 +5930,13 @@ impl PhysicalDeviceDataGraphOperationTypeARM {
     pub const NEURAL_MODEL_QCOM: Self = Self(1000629000);
     pub const BUILTIN_MODEL_QCOM: Self = Self(1000629001);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::SPIRV_EXTENDED_INSTRUCTION_SET,
+        Self::NEURAL_MODEL_QCOM,
+        Self::BUILTIN_MODEL_QCOM,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4751,6 +5971,9 @@ impl PhysicalDeviceDataGraphProcessingEngineTypeARM {
     pub const NEURAL_QCOM: Self = Self(1000629000);
     pub const COMPUTE_QCOM: Self = Self(1000629001);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::DEFAULT, Self::NEURAL_QCOM, Self::COMPUTE_QCOM];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4787,6 +6010,15 @@ impl PhysicalDeviceLayeredApiKHR {
     pub const OPENGL: Self = Self(3);
     pub const OPENGLES: Self = Self(4);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::VULKAN,
+        Self::D3D12,
+        Self::METAL,
+        Self::OPENGL,
+        Self::OPENGLES,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4825,6 +6057,15 @@ impl PhysicalDeviceType {
     pub const VIRTUAL_GPU: Self = Self(3);
     pub const CPU: Self = Self(4);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::OTHER,
+        Self::INTEGRATED_GPU,
+        Self::DISCRETE_GPU,
+        Self::VIRTUAL_GPU,
+        Self::CPU,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4864,6 +6105,16 @@ impl PipelineBindPoint {
     pub const SUBPASS_SHADING_HUAWEI: Self = Self(1000369003);
     pub const DATA_GRAPH_ARM: Self = Self(1000507000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::GRAPHICS,
+        Self::COMPUTE,
+        Self::EXECUTION_GRAPH_AMDX,
+        Self::RAY_TRACING_KHR,
+        Self::SUBPASS_SHADING_HUAWEI,
+        Self::DATA_GRAPH_ARM,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4900,6 +6151,9 @@ impl PipelineCacheHeaderVersion {
     pub const ONE: Self = Self(1);
     pub const DATA_GRAPH_QCOM: Self = Self(1000629000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::ONE, Self::DATA_GRAPH_QCOM];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4934,6 +6188,9 @@ impl PipelineExecutableStatisticFormatKHR {
     pub const UINT64: Self = Self(2);
     pub const FLOAT64: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::BOOL32, Self::INT64, Self::UINT64, Self::FLOAT64];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -4970,6 +6227,14 @@ impl PipelineRobustnessBufferBehavior {
     pub const ROBUST_BUFFER_ACCESS: Self = Self(2);
     pub const ROBUST_BUFFER_ACCESS_2: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::DEVICE_DEFAULT,
+        Self::DISABLED,
+        Self::ROBUST_BUFFER_ACCESS,
+        Self::ROBUST_BUFFER_ACCESS_2,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5006,6 +6271,14 @@ impl PipelineRobustnessImageBehavior {
     pub const ROBUST_IMAGE_ACCESS: Self = Self(2);
     pub const ROBUST_IMAGE_ACCESS_2: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::DEVICE_DEFAULT,
+        Self::DISABLED,
+        Self::ROBUST_IMAGE_ACCESS,
+        Self::ROBUST_IMAGE_ACCESS_2,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5040,6 +6313,9 @@ impl PointClippingBehavior {
     pub const ALL_CLIP_PLANES: Self = Self(0);
     pub const USER_CLIP_PLANES_ONLY: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::ALL_CLIP_PLANES, Self::USER_CLIP_PLANES_ONLY];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5074,6 +6350,10 @@ impl PolygonMode {
     pub const POINT: Self = Self(2);
     pub const FILL_RECTANGLE_NV: Self = Self(1000153000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] =
+        &[Self::FILL, Self::LINE, Self::POINT, Self::FILL_RECTANGLE_NV];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5113,6 +6393,17 @@ impl PresentModeKHR {
     pub const SHARED_CONTINUOUS_REFRESH: Self = Self(1000111001);
     pub const FIFO_LATEST_READY: Self = Self(1000361000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::IMMEDIATE,
+        Self::MAILBOX,
+        Self::FIFO,
+        Self::FIFO_RELAXED,
+        Self::SHARED_DEMAND_REFRESH,
+        Self::SHARED_CONTINUOUS_REFRESH,
+        Self::FIFO_LATEST_READY,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5159,6 +6450,21 @@ impl PrimitiveTopology {
     pub const TRIANGLE_STRIP_WITH_ADJACENCY: Self = Self(9);
     pub const PATCH_LIST: Self = Self(10);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::POINT_LIST,
+        Self::LINE_LIST,
+        Self::LINE_STRIP,
+        Self::TRIANGLE_LIST,
+        Self::TRIANGLE_STRIP,
+        Self::TRIANGLE_FAN,
+        Self::LINE_LIST_WITH_ADJACENCY,
+        Self::LINE_STRIP_WITH_ADJACENCY,
+        Self::TRIANGLE_LIST_WITH_ADJACENCY,
+        Self::TRIANGLE_STRIP_WITH_ADJACENCY,
+        Self::PATCH_LIST,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5200,6 +6506,9 @@ impl ProvokingVertexModeEXT {
     pub const FIRST_VERTEX: Self = Self(0);
     pub const LAST_VERTEX: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::FIRST_VERTEX, Self::LAST_VERTEX];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5231,6 +6540,9 @@ pub struct QueryPoolSamplingModeINTEL(i32);
 impl QueryPoolSamplingModeINTEL {
     pub const MANUAL: Self = Self(0);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::MANUAL];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5264,6 +6576,14 @@ impl QueryResultStatusKHR {
     pub const COMPLETE: Self = Self(1);
     pub const INSUFFICIENT_BITSTREAM_BUFFER_RANGE: Self = Self(-1000299000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::ERROR,
+        Self::NOT_READY,
+        Self::COMPLETE,
+        Self::INSUFFICIENT_BITSTREAM_BUFFER_RANGE,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5314,6 +6634,27 @@ impl QueryType {
     pub const MICROMAP_SERIALIZATION_SIZE_EXT: Self = Self(1000396000);
     pub const MICROMAP_COMPACTED_SIZE_EXT: Self = Self(1000396001);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::OCCLUSION,
+        Self::PIPELINE_STATISTICS,
+        Self::TIMESTAMP,
+        Self::RESULT_STATUS_ONLY_KHR,
+        Self::TRANSFORM_FEEDBACK_STREAM_EXT,
+        Self::PERFORMANCE_QUERY_KHR,
+        Self::ACCELERATION_STRUCTURE_COMPACTED_SIZE_KHR,
+        Self::ACCELERATION_STRUCTURE_SERIALIZATION_SIZE_KHR,
+        Self::ACCELERATION_STRUCTURE_COMPACTED_SIZE_NV,
+        Self::PERFORMANCE_QUERY_INTEL,
+        Self::VIDEO_ENCODE_FEEDBACK_KHR,
+        Self::MESH_PRIMITIVES_GENERATED_EXT,
+        Self::PRIMITIVES_GENERATED_EXT,
+        Self::ACCELERATION_STRUCTURE_SERIALIZATION_BOTTOM_LEVEL_POINTERS_KHR,
+        Self::ACCELERATION_STRUCTURE_SIZE_KHR,
+        Self::MICROMAP_SERIALIZATION_SIZE_EXT,
+        Self::MICROMAP_COMPACTED_SIZE_EXT,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5366,6 +6707,9 @@ impl QueueGlobalPriority {
     pub const HIGH: Self = Self(512);
     pub const REALTIME: Self = Self(1024);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::LOW, Self::MEDIUM, Self::HIGH, Self::REALTIME];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5400,6 +6744,9 @@ impl RasterizationOrderAMD {
     pub const STRICT: Self = Self(0);
     pub const RELAXED: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::STRICT, Self::RELAXED];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5432,6 +6779,9 @@ impl RayTracingInvocationReorderModeEXT {
     pub const NONE: Self = Self(0);
     pub const REORDER: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::NONE, Self::REORDER];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5464,6 +6814,9 @@ impl RayTracingLssIndexingModeNV {
     pub const LIST: Self = Self(0);
     pub const SUCCESSIVE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::LIST, Self::SUCCESSIVE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5496,6 +6849,9 @@ impl RayTracingLssPrimitiveEndCapsModeNV {
     pub const NONE: Self = Self(0);
     pub const CHAINED: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::NONE, Self::CHAINED];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5529,6 +6885,13 @@ impl RayTracingShaderGroupTypeKHR {
     pub const TRIANGLES_HIT_GROUP: Self = Self(1);
     pub const PROCEDURAL_HIT_GROUP: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::GENERAL,
+        Self::TRIANGLES_HIT_GROUP,
+        Self::PROCEDURAL_HIT_GROUP,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5610,6 +6973,60 @@ impl Result {
     pub const PIPELINE_BINARY_MISSING_KHR: Self = Self(1000483000);
     pub const ERROR_NOT_ENOUGH_SPACE_KHR: Self = Self(-1000483000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::SUCCESS,
+        Self::NOT_READY,
+        Self::TIMEOUT,
+        Self::EVENT_SET,
+        Self::EVENT_RESET,
+        Self::INCOMPLETE,
+        Self::ERROR_OUT_OF_HOST_MEMORY,
+        Self::ERROR_OUT_OF_DEVICE_MEMORY,
+        Self::ERROR_INITIALIZATION_FAILED,
+        Self::ERROR_DEVICE_LOST,
+        Self::ERROR_MEMORY_MAP_FAILED,
+        Self::ERROR_LAYER_NOT_PRESENT,
+        Self::ERROR_EXTENSION_NOT_PRESENT,
+        Self::ERROR_FEATURE_NOT_PRESENT,
+        Self::ERROR_INCOMPATIBLE_DRIVER,
+        Self::ERROR_TOO_MANY_OBJECTS,
+        Self::ERROR_FORMAT_NOT_SUPPORTED,
+        Self::ERROR_FRAGMENTED_POOL,
+        Self::ERROR_UNKNOWN,
+        Self::ERROR_VALIDATION_FAILED,
+        Self::ERROR_OUT_OF_POOL_MEMORY,
+        Self::ERROR_INVALID_EXTERNAL_HANDLE,
+        Self::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS,
+        Self::ERROR_FRAGMENTATION,
+        Self::PIPELINE_COMPILE_REQUIRED,
+        Self::ERROR_NOT_PERMITTED,
+        Self::ERROR_SURFACE_LOST_KHR,
+        Self::ERROR_NATIVE_WINDOW_IN_USE_KHR,
+        Self::SUBOPTIMAL_KHR,
+        Self::ERROR_OUT_OF_DATE_KHR,
+        Self::ERROR_INCOMPATIBLE_DISPLAY_KHR,
+        Self::ERROR_INVALID_SHADER_NV,
+        Self::ERROR_IMAGE_USAGE_NOT_SUPPORTED_KHR,
+        Self::ERROR_VIDEO_PICTURE_LAYOUT_NOT_SUPPORTED_KHR,
+        Self::ERROR_VIDEO_PROFILE_OPERATION_NOT_SUPPORTED_KHR,
+        Self::ERROR_VIDEO_PROFILE_FORMAT_NOT_SUPPORTED_KHR,
+        Self::ERROR_VIDEO_PROFILE_CODEC_NOT_SUPPORTED_KHR,
+        Self::ERROR_VIDEO_STD_VERSION_NOT_SUPPORTED_KHR,
+        Self::ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT,
+        Self::ERROR_PRESENT_TIMING_QUEUE_FULL_EXT,
+        Self::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT,
+        Self::THREAD_IDLE_KHR,
+        Self::THREAD_DONE_KHR,
+        Self::OPERATION_DEFERRED_KHR,
+        Self::OPERATION_NOT_DEFERRED_KHR,
+        Self::ERROR_INVALID_VIDEO_STD_PARAMETERS_KHR,
+        Self::ERROR_COMPRESSION_EXHAUSTED_EXT,
+        Self::INCOMPATIBLE_SHADER_BINARY_EXT,
+        Self::PIPELINE_BINARY_MISSING_KHR,
+        Self::ERROR_NOT_ENOUGH_SPACE_KHR,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5754,6 +7171,15 @@ impl SamplerAddressMode {
     pub const CLAMP_TO_BORDER: Self = Self(3);
     pub const MIRROR_CLAMP_TO_EDGE: Self = Self(4);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::REPEAT,
+        Self::MIRRORED_REPEAT,
+        Self::CLAMP_TO_EDGE,
+        Self::CLAMP_TO_BORDER,
+        Self::MIRROR_CLAMP_TO_EDGE,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5789,6 +7215,9 @@ impl SamplerMipmapMode {
     pub const NEAREST: Self = Self(0);
     pub const LINEAR: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::NEAREST, Self::LINEAR];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5823,6 +7252,14 @@ impl SamplerReductionMode {
     pub const MAX: Self = Self(2);
     pub const WEIGHTED_AVERAGE_RANGECLAMP_QCOM: Self = Self(1000521000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::WEIGHTED_AVERAGE,
+        Self::MIN,
+        Self::MAX,
+        Self::WEIGHTED_AVERAGE_RANGECLAMP_QCOM,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5860,6 +7297,15 @@ impl SamplerYcbcrModelConversion {
     pub const YCBCR_601: Self = Self(3);
     pub const YCBCR_2020: Self = Self(4);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::RGB_IDENTITY,
+        Self::YCBCR_IDENTITY,
+        Self::YCBCR_709,
+        Self::YCBCR_601,
+        Self::YCBCR_2020,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5895,6 +7341,9 @@ impl SamplerYcbcrRange {
     pub const ITU_FULL: Self = Self(0);
     pub const ITU_NARROW: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::ITU_FULL, Self::ITU_NARROW];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5928,6 +7377,9 @@ impl SciSyncClientTypeNV {
     pub const WAITER: Self = Self(1);
     pub const SIGNALER_WAITER: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::SIGNALER, Self::WAITER, Self::SIGNALER_WAITER];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5961,6 +7413,9 @@ impl SciSyncPrimitiveTypeNV {
     pub const FENCE: Self = Self(0);
     pub const SEMAPHORE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::FENCE, Self::SEMAPHORE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -5995,6 +7450,14 @@ impl ScopeKHR {
     pub const SUBGROUP: Self = Self(3);
     pub const QUEUE_FAMILY: Self = Self(5);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::DEVICE,
+        Self::WORKGROUP,
+        Self::SUBGROUP,
+        Self::QUEUE_FAMILY,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -6029,6 +7492,9 @@ impl SemaphoreType {
     pub const BINARY: Self = Self(0);
     pub const TIMELINE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::BINARY, Self::TIMELINE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -6061,6 +7527,9 @@ impl ShaderCodeTypeEXT {
     pub const BINARY: Self = Self(0);
     pub const SPIRV: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::BINARY, Self::SPIRV];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -6094,6 +7563,9 @@ impl ShaderFloatControlsIndependence {
     pub const ALL: Self = Self(1);
     pub const NONE: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::_32_BIT_ONLY, Self::ALL, Self::NONE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -6129,6 +7601,14 @@ impl ShaderGroupShaderKHR {
     pub const ANY_HIT: Self = Self(2);
     pub const INTERSECTION: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::GENERAL,
+        Self::CLOSEST_HIT,
+        Self::ANY_HIT,
+        Self::INTERSECTION,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -6164,6 +7644,9 @@ impl ShaderInfoTypeAMD {
     pub const BINARY: Self = Self(1);
     pub const DISASSEMBLY: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::STATISTICS, Self::BINARY, Self::DISASSEMBLY];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -6207,6 +7690,22 @@ impl ShadingRatePaletteEntryNV {
     pub const _1_INVOCATION_PER_2X4_PIXELS: Self = Self(10);
     pub const _1_INVOCATION_PER_4X4_PIXELS: Self = Self(11);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::NO_INVOCATIONS,
+        Self::_16_INVOCATIONS_PER_PIXEL,
+        Self::_8_INVOCATIONS_PER_PIXEL,
+        Self::_4_INVOCATIONS_PER_PIXEL,
+        Self::_2_INVOCATIONS_PER_PIXEL,
+        Self::_1_INVOCATION_PER_PIXEL,
+        Self::_1_INVOCATION_PER_2X1_PIXELS,
+        Self::_1_INVOCATION_PER_1X2_PIXELS,
+        Self::_1_INVOCATION_PER_2X2_PIXELS,
+        Self::_1_INVOCATION_PER_4X2_PIXELS,
+        Self::_1_INVOCATION_PER_2X4_PIXELS,
+        Self::_1_INVOCATION_PER_4X4_PIXELS,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -6249,6 +7748,9 @@ impl SharingMode {
     pub const EXCLUSIVE: Self = Self(0);
     pub const CONCURRENT: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::EXCLUSIVE, Self::CONCURRENT];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -6287,6 +7789,18 @@ impl StencilOp {
     pub const INCREMENT_AND_WRAP: Self = Self(6);
     pub const DECREMENT_AND_WRAP: Self = Self(7);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::KEEP,
+        Self::ZERO,
+        Self::REPLACE,
+        Self::INCREMENT_AND_CLAMP,
+        Self::DECREMENT_AND_CLAMP,
+        Self::INVERT,
+        Self::INCREMENT_AND_WRAP,
+        Self::DECREMENT_AND_WRAP,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -7536,6 +9050,1197 @@ impl StructureType {
     pub const PHYSICAL_DEVICE_SHADER_MIXED_FLOAT_DOT_PRODUCT_FEATURES_VALVE: Self =
         Self(1000673000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::APPLICATION_INFO,
+        Self::INSTANCE_CREATE_INFO,
+        Self::DEVICE_QUEUE_CREATE_INFO,
+        Self::DEVICE_CREATE_INFO,
+        Self::SUBMIT_INFO,
+        Self::MEMORY_ALLOCATE_INFO,
+        Self::MAPPED_MEMORY_RANGE,
+        Self::BIND_SPARSE_INFO,
+        Self::FENCE_CREATE_INFO,
+        Self::SEMAPHORE_CREATE_INFO,
+        Self::EVENT_CREATE_INFO,
+        Self::QUERY_POOL_CREATE_INFO,
+        Self::BUFFER_CREATE_INFO,
+        Self::BUFFER_VIEW_CREATE_INFO,
+        Self::IMAGE_CREATE_INFO,
+        Self::IMAGE_VIEW_CREATE_INFO,
+        Self::SHADER_MODULE_CREATE_INFO,
+        Self::PIPELINE_CACHE_CREATE_INFO,
+        Self::PIPELINE_SHADER_STAGE_CREATE_INFO,
+        Self::PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO,
+        Self::PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO,
+        Self::PIPELINE_TESSELLATION_STATE_CREATE_INFO,
+        Self::PIPELINE_VIEWPORT_STATE_CREATE_INFO,
+        Self::PIPELINE_RASTERIZATION_STATE_CREATE_INFO,
+        Self::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO,
+        Self::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO,
+        Self::PIPELINE_COLOR_BLEND_STATE_CREATE_INFO,
+        Self::PIPELINE_DYNAMIC_STATE_CREATE_INFO,
+        Self::GRAPHICS_PIPELINE_CREATE_INFO,
+        Self::COMPUTE_PIPELINE_CREATE_INFO,
+        Self::PIPELINE_LAYOUT_CREATE_INFO,
+        Self::SAMPLER_CREATE_INFO,
+        Self::DESCRIPTOR_SET_LAYOUT_CREATE_INFO,
+        Self::DESCRIPTOR_POOL_CREATE_INFO,
+        Self::DESCRIPTOR_SET_ALLOCATE_INFO,
+        Self::WRITE_DESCRIPTOR_SET,
+        Self::COPY_DESCRIPTOR_SET,
+        Self::FRAMEBUFFER_CREATE_INFO,
+        Self::RENDER_PASS_CREATE_INFO,
+        Self::COMMAND_POOL_CREATE_INFO,
+        Self::COMMAND_BUFFER_ALLOCATE_INFO,
+        Self::COMMAND_BUFFER_INHERITANCE_INFO,
+        Self::COMMAND_BUFFER_BEGIN_INFO,
+        Self::RENDER_PASS_BEGIN_INFO,
+        Self::BUFFER_MEMORY_BARRIER,
+        Self::IMAGE_MEMORY_BARRIER,
+        Self::MEMORY_BARRIER,
+        Self::LOADER_INSTANCE_CREATE_INFO,
+        Self::LOADER_DEVICE_CREATE_INFO,
+        Self::BIND_BUFFER_MEMORY_INFO,
+        Self::BIND_IMAGE_MEMORY_INFO,
+        Self::MEMORY_DEDICATED_REQUIREMENTS,
+        Self::MEMORY_DEDICATED_ALLOCATE_INFO,
+        Self::MEMORY_ALLOCATE_FLAGS_INFO,
+        Self::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO,
+        Self::DEVICE_GROUP_SUBMIT_INFO,
+        Self::DEVICE_GROUP_BIND_SPARSE_INFO,
+        Self::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO,
+        Self::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO,
+        Self::PHYSICAL_DEVICE_GROUP_PROPERTIES,
+        Self::DEVICE_GROUP_DEVICE_CREATE_INFO,
+        Self::BUFFER_MEMORY_REQUIREMENTS_INFO_2,
+        Self::IMAGE_MEMORY_REQUIREMENTS_INFO_2,
+        Self::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2,
+        Self::MEMORY_REQUIREMENTS_2,
+        Self::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2,
+        Self::PHYSICAL_DEVICE_FEATURES_2,
+        Self::PHYSICAL_DEVICE_PROPERTIES_2,
+        Self::FORMAT_PROPERTIES_2,
+        Self::IMAGE_FORMAT_PROPERTIES_2,
+        Self::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2,
+        Self::QUEUE_FAMILY_PROPERTIES_2,
+        Self::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2,
+        Self::SPARSE_IMAGE_FORMAT_PROPERTIES_2,
+        Self::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2,
+        Self::IMAGE_VIEW_USAGE_CREATE_INFO,
+        Self::PROTECTED_SUBMIT_INFO,
+        Self::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES,
+        Self::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES,
+        Self::DEVICE_QUEUE_INFO_2,
+        Self::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO,
+        Self::EXTERNAL_IMAGE_FORMAT_PROPERTIES,
+        Self::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO,
+        Self::EXTERNAL_BUFFER_PROPERTIES,
+        Self::PHYSICAL_DEVICE_ID_PROPERTIES,
+        Self::EXTERNAL_MEMORY_BUFFER_CREATE_INFO,
+        Self::EXTERNAL_MEMORY_IMAGE_CREATE_INFO,
+        Self::EXPORT_MEMORY_ALLOCATE_INFO,
+        Self::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO,
+        Self::EXTERNAL_FENCE_PROPERTIES,
+        Self::EXPORT_FENCE_CREATE_INFO,
+        Self::EXPORT_SEMAPHORE_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO,
+        Self::EXTERNAL_SEMAPHORE_PROPERTIES,
+        Self::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES,
+        Self::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES,
+        Self::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES,
+        Self::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES,
+        Self::DESCRIPTOR_SET_LAYOUT_SUPPORT,
+        Self::SAMPLER_YCBCR_CONVERSION_CREATE_INFO,
+        Self::SAMPLER_YCBCR_CONVERSION_INFO,
+        Self::BIND_IMAGE_PLANE_MEMORY_INFO,
+        Self::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO,
+        Self::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES,
+        Self::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES,
+        Self::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO,
+        Self::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES,
+        Self::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO,
+        Self::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO,
+        Self::RENDER_PASS_MULTIVIEW_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_MULTIVIEW_FEATURES,
+        Self::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES,
+        Self::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES,
+        Self::PHYSICAL_DEVICE_DRIVER_PROPERTIES,
+        Self::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES,
+        Self::PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES,
+        Self::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES,
+        Self::PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES,
+        Self::IMAGE_FORMAT_LIST_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES,
+        Self::PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES,
+        Self::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES,
+        Self::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES,
+        Self::SEMAPHORE_TYPE_CREATE_INFO,
+        Self::TIMELINE_SEMAPHORE_SUBMIT_INFO,
+        Self::SEMAPHORE_WAIT_INFO,
+        Self::SEMAPHORE_SIGNAL_INFO,
+        Self::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES,
+        Self::BUFFER_DEVICE_ADDRESS_INFO,
+        Self::BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO,
+        Self::MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO,
+        Self::DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO,
+        Self::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES,
+        Self::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES,
+        Self::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES,
+        Self::PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES,
+        Self::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES,
+        Self::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES,
+        Self::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO,
+        Self::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT,
+        Self::PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES,
+        Self::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES,
+        Self::SAMPLER_REDUCTION_MODE_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES,
+        Self::PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES,
+        Self::ATTACHMENT_DESCRIPTION_2,
+        Self::ATTACHMENT_REFERENCE_2,
+        Self::SUBPASS_DESCRIPTION_2,
+        Self::SUBPASS_DEPENDENCY_2,
+        Self::RENDER_PASS_CREATE_INFO_2,
+        Self::SUBPASS_BEGIN_INFO,
+        Self::SUBPASS_END_INFO,
+        Self::PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES,
+        Self::SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE,
+        Self::IMAGE_STENCIL_USAGE_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES,
+        Self::FRAMEBUFFER_ATTACHMENTS_CREATE_INFO,
+        Self::FRAMEBUFFER_ATTACHMENT_IMAGE_INFO,
+        Self::RENDER_PASS_ATTACHMENT_BEGIN_INFO,
+        Self::PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES,
+        Self::ATTACHMENT_REFERENCE_STENCIL_LAYOUT,
+        Self::ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT,
+        Self::PHYSICAL_DEVICE_VULKAN_1_3_FEATURES,
+        Self::PHYSICAL_DEVICE_VULKAN_1_3_PROPERTIES,
+        Self::PHYSICAL_DEVICE_TOOL_PROPERTIES,
+        Self::PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES,
+        Self::DEVICE_PRIVATE_DATA_CREATE_INFO,
+        Self::PRIVATE_DATA_SLOT_CREATE_INFO,
+        Self::MEMORY_BARRIER_2,
+        Self::BUFFER_MEMORY_BARRIER_2,
+        Self::IMAGE_MEMORY_BARRIER_2,
+        Self::DEPENDENCY_INFO,
+        Self::SUBMIT_INFO_2,
+        Self::SEMAPHORE_SUBMIT_INFO,
+        Self::COMMAND_BUFFER_SUBMIT_INFO,
+        Self::PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES,
+        Self::COPY_BUFFER_INFO_2,
+        Self::COPY_IMAGE_INFO_2,
+        Self::COPY_BUFFER_TO_IMAGE_INFO_2,
+        Self::COPY_IMAGE_TO_BUFFER_INFO_2,
+        Self::BUFFER_COPY_2,
+        Self::IMAGE_COPY_2,
+        Self::BUFFER_IMAGE_COPY_2,
+        Self::PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES,
+        Self::FORMAT_PROPERTIES_3,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_4_PROPERTIES,
+        Self::DEVICE_BUFFER_MEMORY_REQUIREMENTS,
+        Self::DEVICE_IMAGE_MEMORY_REQUIREMENTS,
+        Self::PIPELINE_CREATION_FEEDBACK_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES,
+        Self::PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES,
+        Self::PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES,
+        Self::PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES,
+        Self::PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES,
+        Self::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES,
+        Self::PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES,
+        Self::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES,
+        Self::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES,
+        Self::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK,
+        Self::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES,
+        Self::PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES,
+        Self::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES,
+        Self::BLIT_IMAGE_INFO_2,
+        Self::RESOLVE_IMAGE_INFO_2,
+        Self::IMAGE_BLIT_2,
+        Self::IMAGE_RESOLVE_2,
+        Self::RENDERING_INFO,
+        Self::RENDERING_ATTACHMENT_INFO,
+        Self::PIPELINE_RENDERING_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES,
+        Self::COMMAND_BUFFER_INHERITANCE_RENDERING_INFO,
+        Self::PHYSICAL_DEVICE_VULKAN_1_4_FEATURES,
+        Self::PHYSICAL_DEVICE_VULKAN_1_4_PROPERTIES,
+        Self::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES,
+        Self::QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES,
+        Self::PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES,
+        Self::MEMORY_MAP_INFO,
+        Self::MEMORY_UNMAP_INFO,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_5_FEATURES,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_5_PROPERTIES,
+        Self::DEVICE_IMAGE_SUBRESOURCE_INFO,
+        Self::SUBRESOURCE_LAYOUT_2,
+        Self::IMAGE_SUBRESOURCE_2,
+        Self::BUFFER_USAGE_FLAGS_2_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_6_FEATURES,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_6_PROPERTIES,
+        Self::BIND_MEMORY_STATUS,
+        Self::PHYSICAL_DEVICE_HOST_IMAGE_COPY_FEATURES,
+        Self::PHYSICAL_DEVICE_HOST_IMAGE_COPY_PROPERTIES,
+        Self::MEMORY_TO_IMAGE_COPY,
+        Self::IMAGE_TO_MEMORY_COPY,
+        Self::COPY_IMAGE_TO_MEMORY_INFO,
+        Self::COPY_MEMORY_TO_IMAGE_INFO,
+        Self::HOST_IMAGE_LAYOUT_TRANSITION_INFO,
+        Self::COPY_IMAGE_TO_IMAGE_INFO,
+        Self::SUBRESOURCE_HOST_MEMCPY_SIZE,
+        Self::HOST_IMAGE_COPY_DEVICE_PERFORMANCE_QUERY,
+        Self::PHYSICAL_DEVICE_SHADER_SUBGROUP_ROTATE_FEATURES,
+        Self::PHYSICAL_DEVICE_SHADER_FLOAT_CONTROLS_2_FEATURES,
+        Self::PHYSICAL_DEVICE_SHADER_EXPECT_ASSUME_FEATURES,
+        Self::PIPELINE_CREATE_FLAGS_2_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES,
+        Self::BIND_DESCRIPTOR_SETS_INFO,
+        Self::PUSH_CONSTANTS_INFO,
+        Self::PUSH_DESCRIPTOR_SET_INFO,
+        Self::PUSH_DESCRIPTOR_SET_WITH_TEMPLATE_INFO,
+        Self::PHYSICAL_DEVICE_PIPELINE_PROTECTED_ACCESS_FEATURES,
+        Self::PIPELINE_ROBUSTNESS_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_FEATURES,
+        Self::PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_PROPERTIES,
+        Self::PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES,
+        Self::PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES,
+        Self::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES,
+        Self::PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO,
+        Self::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES,
+        Self::RENDERING_AREA_INFO,
+        Self::PHYSICAL_DEVICE_DYNAMIC_RENDERING_LOCAL_READ_FEATURES,
+        Self::RENDERING_ATTACHMENT_LOCATION_INFO,
+        Self::RENDERING_INPUT_ATTACHMENT_INDEX_INFO,
+        Self::SWAPCHAIN_CREATE_INFO_KHR,
+        Self::PRESENT_INFO_KHR,
+        Self::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR,
+        Self::IMAGE_SWAPCHAIN_CREATE_INFO_KHR,
+        Self::BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR,
+        Self::ACQUIRE_NEXT_IMAGE_INFO_KHR,
+        Self::DEVICE_GROUP_PRESENT_INFO_KHR,
+        Self::DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR,
+        Self::DISPLAY_MODE_CREATE_INFO_KHR,
+        Self::DISPLAY_SURFACE_CREATE_INFO_KHR,
+        Self::DISPLAY_PRESENT_INFO_KHR,
+        Self::XLIB_SURFACE_CREATE_INFO_KHR,
+        Self::XCB_SURFACE_CREATE_INFO_KHR,
+        Self::WAYLAND_SURFACE_CREATE_INFO_KHR,
+        Self::ANDROID_SURFACE_CREATE_INFO_KHR,
+        Self::WIN32_SURFACE_CREATE_INFO_KHR,
+        Self::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT,
+        Self::PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD,
+        Self::DEBUG_MARKER_OBJECT_NAME_INFO_EXT,
+        Self::DEBUG_MARKER_OBJECT_TAG_INFO_EXT,
+        Self::DEBUG_MARKER_MARKER_INFO_EXT,
+        Self::VIDEO_PROFILE_INFO_KHR,
+        Self::VIDEO_CAPABILITIES_KHR,
+        Self::VIDEO_PICTURE_RESOURCE_INFO_KHR,
+        Self::VIDEO_SESSION_MEMORY_REQUIREMENTS_KHR,
+        Self::BIND_VIDEO_SESSION_MEMORY_INFO_KHR,
+        Self::VIDEO_SESSION_CREATE_INFO_KHR,
+        Self::VIDEO_SESSION_PARAMETERS_CREATE_INFO_KHR,
+        Self::VIDEO_SESSION_PARAMETERS_UPDATE_INFO_KHR,
+        Self::VIDEO_BEGIN_CODING_INFO_KHR,
+        Self::VIDEO_END_CODING_INFO_KHR,
+        Self::VIDEO_CODING_CONTROL_INFO_KHR,
+        Self::VIDEO_REFERENCE_SLOT_INFO_KHR,
+        Self::QUEUE_FAMILY_VIDEO_PROPERTIES_KHR,
+        Self::VIDEO_PROFILE_LIST_INFO_KHR,
+        Self::PHYSICAL_DEVICE_VIDEO_FORMAT_INFO_KHR,
+        Self::VIDEO_FORMAT_PROPERTIES_KHR,
+        Self::QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR,
+        Self::VIDEO_DECODE_INFO_KHR,
+        Self::VIDEO_DECODE_CAPABILITIES_KHR,
+        Self::VIDEO_DECODE_USAGE_INFO_KHR,
+        Self::DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV,
+        Self::DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV,
+        Self::DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV,
+        Self::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT,
+        Self::PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT,
+        Self::CU_MODULE_CREATE_INFO_NVX,
+        Self::CU_FUNCTION_CREATE_INFO_NVX,
+        Self::CU_LAUNCH_INFO_NVX,
+        Self::CU_MODULE_TEXTURING_MODE_CREATE_INFO_NVX,
+        Self::IMAGE_VIEW_HANDLE_INFO_NVX,
+        Self::IMAGE_VIEW_ADDRESS_PROPERTIES_NVX,
+        Self::VIDEO_ENCODE_H264_CAPABILITIES_KHR,
+        Self::VIDEO_ENCODE_H264_SESSION_PARAMETERS_CREATE_INFO_KHR,
+        Self::VIDEO_ENCODE_H264_SESSION_PARAMETERS_ADD_INFO_KHR,
+        Self::VIDEO_ENCODE_H264_PICTURE_INFO_KHR,
+        Self::VIDEO_ENCODE_H264_DPB_SLOT_INFO_KHR,
+        Self::VIDEO_ENCODE_H264_NALU_SLICE_INFO_KHR,
+        Self::VIDEO_ENCODE_H264_GOP_REMAINING_FRAME_INFO_KHR,
+        Self::VIDEO_ENCODE_H264_PROFILE_INFO_KHR,
+        Self::VIDEO_ENCODE_H264_RATE_CONTROL_INFO_KHR,
+        Self::VIDEO_ENCODE_H264_RATE_CONTROL_LAYER_INFO_KHR,
+        Self::VIDEO_ENCODE_H264_SESSION_CREATE_INFO_KHR,
+        Self::VIDEO_ENCODE_H264_QUALITY_LEVEL_PROPERTIES_KHR,
+        Self::VIDEO_ENCODE_H264_SESSION_PARAMETERS_GET_INFO_KHR,
+        Self::VIDEO_ENCODE_H264_SESSION_PARAMETERS_FEEDBACK_INFO_KHR,
+        Self::VIDEO_ENCODE_H265_CAPABILITIES_KHR,
+        Self::VIDEO_ENCODE_H265_SESSION_PARAMETERS_CREATE_INFO_KHR,
+        Self::VIDEO_ENCODE_H265_SESSION_PARAMETERS_ADD_INFO_KHR,
+        Self::VIDEO_ENCODE_H265_PICTURE_INFO_KHR,
+        Self::VIDEO_ENCODE_H265_DPB_SLOT_INFO_KHR,
+        Self::VIDEO_ENCODE_H265_NALU_SLICE_SEGMENT_INFO_KHR,
+        Self::VIDEO_ENCODE_H265_GOP_REMAINING_FRAME_INFO_KHR,
+        Self::VIDEO_ENCODE_H265_PROFILE_INFO_KHR,
+        Self::VIDEO_ENCODE_H265_RATE_CONTROL_INFO_KHR,
+        Self::VIDEO_ENCODE_H265_RATE_CONTROL_LAYER_INFO_KHR,
+        Self::VIDEO_ENCODE_H265_SESSION_CREATE_INFO_KHR,
+        Self::VIDEO_ENCODE_H265_QUALITY_LEVEL_PROPERTIES_KHR,
+        Self::VIDEO_ENCODE_H265_SESSION_PARAMETERS_GET_INFO_KHR,
+        Self::VIDEO_ENCODE_H265_SESSION_PARAMETERS_FEEDBACK_INFO_KHR,
+        Self::VIDEO_DECODE_H264_CAPABILITIES_KHR,
+        Self::VIDEO_DECODE_H264_PICTURE_INFO_KHR,
+        Self::VIDEO_DECODE_H264_PROFILE_INFO_KHR,
+        Self::VIDEO_DECODE_H264_SESSION_PARAMETERS_CREATE_INFO_KHR,
+        Self::VIDEO_DECODE_H264_SESSION_PARAMETERS_ADD_INFO_KHR,
+        Self::VIDEO_DECODE_H264_DPB_SLOT_INFO_KHR,
+        Self::TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD,
+        Self::STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP,
+        Self::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV,
+        Self::PRIVATE_VENDOR_INFO_PLACEHOLDER_OFFSET_0_NV,
+        Self::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV,
+        Self::EXPORT_MEMORY_ALLOCATE_INFO_NV,
+        Self::IMPORT_MEMORY_WIN32_HANDLE_INFO_NV,
+        Self::EXPORT_MEMORY_WIN32_HANDLE_INFO_NV,
+        Self::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV,
+        Self::VALIDATION_FLAGS_EXT,
+        Self::VI_SURFACE_CREATE_INFO_NN,
+        Self::IMAGE_VIEW_ASTC_DECODE_MODE_EXT,
+        Self::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT,
+        Self::IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR,
+        Self::EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR,
+        Self::MEMORY_WIN32_HANDLE_PROPERTIES_KHR,
+        Self::MEMORY_GET_WIN32_HANDLE_INFO_KHR,
+        Self::IMPORT_MEMORY_FD_INFO_KHR,
+        Self::MEMORY_FD_PROPERTIES_KHR,
+        Self::MEMORY_GET_FD_INFO_KHR,
+        Self::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR,
+        Self::IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR,
+        Self::EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR,
+        Self::D3D12_FENCE_SUBMIT_INFO_KHR,
+        Self::SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR,
+        Self::IMPORT_SEMAPHORE_FD_INFO_KHR,
+        Self::SEMAPHORE_GET_FD_INFO_KHR,
+        Self::COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT,
+        Self::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT,
+        Self::CONDITIONAL_RENDERING_BEGIN_INFO_EXT,
+        Self::PRESENT_REGIONS_KHR,
+        Self::PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV,
+        Self::SURFACE_CAPABILITIES_2_EXT,
+        Self::DISPLAY_POWER_INFO_EXT,
+        Self::DEVICE_EVENT_INFO_EXT,
+        Self::DISPLAY_EVENT_INFO_EXT,
+        Self::SWAPCHAIN_COUNTER_CREATE_INFO_EXT,
+        Self::PRESENT_TIMES_INFO_GOOGLE,
+        Self::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX,
+        Self::MULTIVIEW_PER_VIEW_ATTRIBUTES_INFO_NVX,
+        Self::PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV,
+        Self::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT,
+        Self::PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT,
+        Self::PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT,
+        Self::PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT,
+        Self::HDR_METADATA_EXT,
+        Self::PHYSICAL_DEVICE_RELAXED_LINE_RASTERIZATION_FEATURES_IMG,
+        Self::SHARED_PRESENT_SURFACE_CAPABILITIES_KHR,
+        Self::IMPORT_FENCE_WIN32_HANDLE_INFO_KHR,
+        Self::EXPORT_FENCE_WIN32_HANDLE_INFO_KHR,
+        Self::FENCE_GET_WIN32_HANDLE_INFO_KHR,
+        Self::IMPORT_FENCE_FD_INFO_KHR,
+        Self::FENCE_GET_FD_INFO_KHR,
+        Self::PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR,
+        Self::QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR,
+        Self::PERFORMANCE_QUERY_SUBMIT_INFO_KHR,
+        Self::ACQUIRE_PROFILING_LOCK_INFO_KHR,
+        Self::PERFORMANCE_COUNTER_KHR,
+        Self::PERFORMANCE_COUNTER_DESCRIPTION_KHR,
+        Self::PERFORMANCE_QUERY_RESERVATION_INFO_KHR,
+        Self::PHYSICAL_DEVICE_SURFACE_INFO_2_KHR,
+        Self::SURFACE_CAPABILITIES_2_KHR,
+        Self::SURFACE_FORMAT_2_KHR,
+        Self::DISPLAY_PROPERTIES_2_KHR,
+        Self::DISPLAY_PLANE_PROPERTIES_2_KHR,
+        Self::DISPLAY_MODE_PROPERTIES_2_KHR,
+        Self::DISPLAY_PLANE_INFO_2_KHR,
+        Self::DISPLAY_PLANE_CAPABILITIES_2_KHR,
+        Self::IOS_SURFACE_CREATE_INFO_MVK,
+        Self::MACOS_SURFACE_CREATE_INFO_MVK,
+        Self::DEBUG_UTILS_OBJECT_NAME_INFO_EXT,
+        Self::DEBUG_UTILS_OBJECT_TAG_INFO_EXT,
+        Self::DEBUG_UTILS_LABEL_EXT,
+        Self::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT,
+        Self::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT,
+        Self::ANDROID_HARDWARE_BUFFER_USAGE_ANDROID,
+        Self::ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID,
+        Self::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID,
+        Self::IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID,
+        Self::MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID,
+        Self::EXTERNAL_FORMAT_ANDROID,
+        Self::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_2_ANDROID,
+        Self::PHYSICAL_DEVICE_SHADER_ENQUEUE_FEATURES_AMDX,
+        Self::PHYSICAL_DEVICE_SHADER_ENQUEUE_PROPERTIES_AMDX,
+        Self::EXECUTION_GRAPH_PIPELINE_SCRATCH_SIZE_AMDX,
+        Self::EXECUTION_GRAPH_PIPELINE_CREATE_INFO_AMDX,
+        Self::PIPELINE_SHADER_STAGE_NODE_CREATE_INFO_AMDX,
+        Self::TEXEL_BUFFER_DESCRIPTOR_INFO_EXT,
+        Self::IMAGE_DESCRIPTOR_INFO_EXT,
+        Self::RESOURCE_DESCRIPTOR_INFO_EXT,
+        Self::BIND_HEAP_INFO_EXT,
+        Self::PUSH_DATA_INFO_EXT,
+        Self::DESCRIPTOR_SET_AND_BINDING_MAPPING_EXT,
+        Self::SHADER_DESCRIPTOR_SET_AND_BINDING_MAPPING_INFO_EXT,
+        Self::OPAQUE_CAPTURE_DATA_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_DESCRIPTOR_HEAP_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_DESCRIPTOR_HEAP_FEATURES_EXT,
+        Self::COMMAND_BUFFER_INHERITANCE_DESCRIPTOR_HEAP_INFO_EXT,
+        Self::SAMPLER_CUSTOM_BORDER_COLOR_INDEX_CREATE_INFO_EXT,
+        Self::INDIRECT_COMMANDS_LAYOUT_PUSH_DATA_TOKEN_NV,
+        Self::SUBSAMPLED_IMAGE_FORMAT_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_DESCRIPTOR_HEAP_TENSOR_PROPERTIES_ARM,
+        Self::ATTACHMENT_SAMPLE_COUNT_INFO_AMD,
+        Self::PHYSICAL_DEVICE_SHADER_BFLOAT16_FEATURES_KHR,
+        Self::SAMPLE_LOCATIONS_INFO_EXT,
+        Self::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT,
+        Self::PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT,
+        Self::MULTISAMPLE_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT,
+        Self::PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT,
+        Self::PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV,
+        Self::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR,
+        Self::ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR,
+        Self::ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR,
+        Self::ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR,
+        Self::ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR,
+        Self::ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR,
+        Self::ACCELERATION_STRUCTURE_GEOMETRY_KHR,
+        Self::ACCELERATION_STRUCTURE_VERSION_INFO_KHR,
+        Self::COPY_ACCELERATION_STRUCTURE_INFO_KHR,
+        Self::COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR,
+        Self::COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR,
+        Self::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR,
+        Self::ACCELERATION_STRUCTURE_CREATE_INFO_KHR,
+        Self::ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR,
+        Self::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR,
+        Self::RAY_TRACING_PIPELINE_CREATE_INFO_KHR,
+        Self::RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR,
+        Self::RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR,
+        Self::PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR,
+        Self::PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV,
+        Self::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV,
+        Self::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT,
+        Self::PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT,
+        Self::IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT,
+        Self::IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT,
+        Self::IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT,
+        Self::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_2_EXT,
+        Self::VALIDATION_CACHE_CREATE_INFO_EXT,
+        Self::SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR,
+        Self::PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV,
+        Self::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV,
+        Self::PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV,
+        Self::RAY_TRACING_PIPELINE_CREATE_INFO_NV,
+        Self::ACCELERATION_STRUCTURE_CREATE_INFO_NV,
+        Self::GEOMETRY_NV,
+        Self::GEOMETRY_TRIANGLES_NV,
+        Self::GEOMETRY_AABB_NV,
+        Self::BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV,
+        Self::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV,
+        Self::ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV,
+        Self::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV,
+        Self::RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV,
+        Self::ACCELERATION_STRUCTURE_INFO_NV,
+        Self::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV,
+        Self::PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV,
+        Self::PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT,
+        Self::FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_CONVERSION_FEATURES_QCOM,
+        Self::IMPORT_MEMORY_HOST_POINTER_INFO_EXT,
+        Self::MEMORY_HOST_POINTER_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR,
+        Self::PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD,
+        Self::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD,
+        Self::VIDEO_DECODE_H265_CAPABILITIES_KHR,
+        Self::VIDEO_DECODE_H265_SESSION_PARAMETERS_CREATE_INFO_KHR,
+        Self::VIDEO_DECODE_H265_SESSION_PARAMETERS_ADD_INFO_KHR,
+        Self::VIDEO_DECODE_H265_PROFILE_INFO_KHR,
+        Self::VIDEO_DECODE_H265_PICTURE_INFO_KHR,
+        Self::VIDEO_DECODE_H265_DPB_SLOT_INFO_KHR,
+        Self::DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD,
+        Self::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT,
+        Self::PRESENT_FRAME_TOKEN_GGP,
+        Self::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV,
+        Self::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV,
+        Self::PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV,
+        Self::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV,
+        Self::CHECKPOINT_DATA_NV,
+        Self::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV,
+        Self::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_2_NV,
+        Self::CHECKPOINT_DATA_2_NV,
+        Self::PHYSICAL_DEVICE_PRESENT_TIMING_FEATURES_EXT,
+        Self::SWAPCHAIN_TIMING_PROPERTIES_EXT,
+        Self::SWAPCHAIN_TIME_DOMAIN_PROPERTIES_EXT,
+        Self::PRESENT_TIMINGS_INFO_EXT,
+        Self::PRESENT_TIMING_INFO_EXT,
+        Self::PAST_PRESENTATION_TIMING_INFO_EXT,
+        Self::PAST_PRESENTATION_TIMING_PROPERTIES_EXT,
+        Self::PAST_PRESENTATION_TIMING_EXT,
+        Self::PRESENT_TIMING_SURFACE_CAPABILITIES_EXT,
+        Self::SWAPCHAIN_CALIBRATED_TIMESTAMP_INFO_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL,
+        Self::QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL,
+        Self::INITIALIZE_PERFORMANCE_API_INFO_INTEL,
+        Self::PERFORMANCE_MARKER_INFO_INTEL,
+        Self::PERFORMANCE_STREAM_MARKER_INFO_INTEL,
+        Self::PERFORMANCE_OVERRIDE_INFO_INTEL,
+        Self::PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL,
+        Self::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT,
+        Self::DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD,
+        Self::SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD,
+        Self::IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA,
+        Self::METAL_SURFACE_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT,
+        Self::RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT,
+        Self::RENDERING_FRAGMENT_DENSITY_MAP_ATTACHMENT_INFO_EXT,
+        Self::FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR,
+        Self::PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR,
+        Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR,
+        Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR,
+        Self::RENDERING_FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR,
+        Self::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD,
+        Self::PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD,
+        Self::PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_QUAD_CONTROL_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT,
+        Self::MEMORY_PRIORITY_ALLOCATE_INFO_EXT,
+        Self::SURFACE_PROTECTED_CAPABILITIES_KHR,
+        Self::PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT,
+        Self::BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT,
+        Self::VALIDATION_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV,
+        Self::COOPERATIVE_MATRIX_PROPERTIES_NV,
+        Self::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV,
+        Self::PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV,
+        Self::PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV,
+        Self::FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV,
+        Self::PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_PROVOKING_VERTEX_FEATURES_EXT,
+        Self::PIPELINE_RASTERIZATION_PROVOKING_VERTEX_STATE_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_PROVOKING_VERTEX_PROPERTIES_EXT,
+        Self::SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT,
+        Self::SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT,
+        Self::SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT,
+        Self::HEADLESS_SURFACE_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR,
+        Self::PIPELINE_INFO_KHR,
+        Self::PIPELINE_EXECUTABLE_PROPERTIES_KHR,
+        Self::PIPELINE_EXECUTABLE_INFO_KHR,
+        Self::PIPELINE_EXECUTABLE_STATISTIC_KHR,
+        Self::PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR,
+        Self::PHYSICAL_DEVICE_MAP_MEMORY_PLACED_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_MAP_MEMORY_PLACED_PROPERTIES_EXT,
+        Self::MEMORY_MAP_PLACED_INFO_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_2_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV,
+        Self::GRAPHICS_SHADER_GROUP_CREATE_INFO_NV,
+        Self::GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV,
+        Self::INDIRECT_COMMANDS_LAYOUT_TOKEN_NV,
+        Self::INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV,
+        Self::GENERATED_COMMANDS_INFO_NV,
+        Self::GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV,
+        Self::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_INHERITED_VIEWPORT_SCISSOR_FEATURES_NV,
+        Self::COMMAND_BUFFER_INHERITANCE_VIEWPORT_SCISSOR_INFO_NV,
+        Self::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT,
+        Self::COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM,
+        Self::RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM,
+        Self::PHYSICAL_DEVICE_DEPTH_BIAS_CONTROL_FEATURES_EXT,
+        Self::DEPTH_BIAS_INFO_EXT,
+        Self::DEPTH_BIAS_REPRESENTATION_INFO_EXT,
+        Self::PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT,
+        Self::DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT,
+        Self::DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT,
+        Self::SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_3D_FEATURES_EXT,
+        Self::PIPELINE_LIBRARY_CREATE_INFO_KHR,
+        Self::PHYSICAL_DEVICE_PRESENT_BARRIER_FEATURES_NV,
+        Self::SURFACE_CAPABILITIES_PRESENT_BARRIER_NV,
+        Self::SWAPCHAIN_PRESENT_BARRIER_CREATE_INFO_NV,
+        Self::PRESENT_ID_KHR,
+        Self::PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR,
+        Self::VIDEO_ENCODE_INFO_KHR,
+        Self::VIDEO_ENCODE_RATE_CONTROL_INFO_KHR,
+        Self::VIDEO_ENCODE_RATE_CONTROL_LAYER_INFO_KHR,
+        Self::VIDEO_ENCODE_CAPABILITIES_KHR,
+        Self::VIDEO_ENCODE_USAGE_INFO_KHR,
+        Self::QUERY_POOL_VIDEO_ENCODE_FEEDBACK_CREATE_INFO_KHR,
+        Self::PHYSICAL_DEVICE_VIDEO_ENCODE_QUALITY_LEVEL_INFO_KHR,
+        Self::VIDEO_ENCODE_QUALITY_LEVEL_PROPERTIES_KHR,
+        Self::VIDEO_ENCODE_QUALITY_LEVEL_INFO_KHR,
+        Self::VIDEO_ENCODE_SESSION_PARAMETERS_GET_INFO_KHR,
+        Self::VIDEO_ENCODE_SESSION_PARAMETERS_FEEDBACK_INFO_KHR,
+        Self::PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV,
+        Self::DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV,
+        Self::CUDA_MODULE_CREATE_INFO_NV,
+        Self::CUDA_FUNCTION_CREATE_INFO_NV,
+        Self::CUDA_LAUNCH_INFO_NV,
+        Self::PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_PROPERTIES_NV,
+        Self::REFRESH_OBJECT_LIST_KHR,
+        Self::PHYSICAL_DEVICE_TILE_SHADING_FEATURES_QCOM,
+        Self::PHYSICAL_DEVICE_TILE_SHADING_PROPERTIES_QCOM,
+        Self::RENDER_PASS_TILE_SHADING_CREATE_INFO_QCOM,
+        Self::PER_TILE_BEGIN_INFO_QCOM,
+        Self::PER_TILE_END_INFO_QCOM,
+        Self::DISPATCH_TILE_INFO_QCOM,
+        Self::QUERY_LOW_LATENCY_SUPPORT_NV,
+        Self::EXPORT_METAL_OBJECT_CREATE_INFO_EXT,
+        Self::EXPORT_METAL_OBJECTS_INFO_EXT,
+        Self::EXPORT_METAL_DEVICE_INFO_EXT,
+        Self::EXPORT_METAL_COMMAND_QUEUE_INFO_EXT,
+        Self::EXPORT_METAL_BUFFER_INFO_EXT,
+        Self::IMPORT_METAL_BUFFER_INFO_EXT,
+        Self::EXPORT_METAL_TEXTURE_INFO_EXT,
+        Self::IMPORT_METAL_TEXTURE_INFO_EXT,
+        Self::EXPORT_METAL_IO_SURFACE_INFO_EXT,
+        Self::IMPORT_METAL_IO_SURFACE_INFO_EXT,
+        Self::EXPORT_METAL_SHARED_EVENT_INFO_EXT,
+        Self::IMPORT_METAL_SHARED_EVENT_INFO_EXT,
+        Self::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_DENSITY_MAP_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_FEATURES_EXT,
+        Self::DESCRIPTOR_ADDRESS_INFO_EXT,
+        Self::DESCRIPTOR_GET_INFO_EXT,
+        Self::BUFFER_CAPTURE_DESCRIPTOR_DATA_INFO_EXT,
+        Self::IMAGE_CAPTURE_DESCRIPTOR_DATA_INFO_EXT,
+        Self::IMAGE_VIEW_CAPTURE_DESCRIPTOR_DATA_INFO_EXT,
+        Self::SAMPLER_CAPTURE_DESCRIPTOR_DATA_INFO_EXT,
+        Self::OPAQUE_CAPTURE_DESCRIPTOR_DATA_CREATE_INFO_EXT,
+        Self::DESCRIPTOR_BUFFER_BINDING_INFO_EXT,
+        Self::DESCRIPTOR_BUFFER_BINDING_PUSH_DESCRIPTOR_BUFFER_HANDLE_EXT,
+        Self::ACCELERATION_STRUCTURE_CAPTURE_DESCRIPTOR_DATA_INFO_EXT,
+        Self::PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_PROPERTIES_EXT,
+        Self::GRAPHICS_PIPELINE_LIBRARY_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_FEATURES_AMD,
+        Self::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_PROPERTIES_KHR,
+        Self::PHYSICAL_DEVICE_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV,
+        Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV,
+        Self::PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV,
+        Self::ACCELERATION_STRUCTURE_GEOMETRY_MOTION_TRIANGLES_DATA_NV,
+        Self::PHYSICAL_DEVICE_RAY_TRACING_MOTION_BLUR_FEATURES_NV,
+        Self::ACCELERATION_STRUCTURE_MOTION_INFO_NV,
+        Self::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_YCBCR_2_PLANE_444_FORMATS_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT,
+        Self::COPY_COMMAND_TRANSFORM_INFO_QCOM,
+        Self::PHYSICAL_DEVICE_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_FEATURES_EXT,
+        Self::IMAGE_COMPRESSION_CONTROL_EXT,
+        Self::IMAGE_COMPRESSION_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_FAULT_FEATURES_EXT,
+        Self::DEVICE_FAULT_COUNTS_EXT,
+        Self::DEVICE_FAULT_INFO_EXT,
+        Self::PHYSICAL_DEVICE_RGBA10X6_FORMATS_FEATURES_EXT,
+        Self::DIRECTFB_SURFACE_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_VERTEX_INPUT_DYNAMIC_STATE_FEATURES_EXT,
+        Self::VERTEX_INPUT_BINDING_DESCRIPTION_2_EXT,
+        Self::VERTEX_INPUT_ATTRIBUTE_DESCRIPTION_2_EXT,
+        Self::PHYSICAL_DEVICE_DRM_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_ADDRESS_BINDING_REPORT_FEATURES_EXT,
+        Self::DEVICE_ADDRESS_BINDING_CALLBACK_DATA_EXT,
+        Self::PHYSICAL_DEVICE_DEPTH_CLIP_CONTROL_FEATURES_EXT,
+        Self::PIPELINE_VIEWPORT_DEPTH_CLIP_CONTROL_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_PRIMITIVE_TOPOLOGY_LIST_RESTART_FEATURES_EXT,
+        Self::IMPORT_MEMORY_ZIRCON_HANDLE_INFO_FUCHSIA,
+        Self::MEMORY_ZIRCON_HANDLE_PROPERTIES_FUCHSIA,
+        Self::MEMORY_GET_ZIRCON_HANDLE_INFO_FUCHSIA,
+        Self::IMPORT_SEMAPHORE_ZIRCON_HANDLE_INFO_FUCHSIA,
+        Self::SEMAPHORE_GET_ZIRCON_HANDLE_INFO_FUCHSIA,
+        Self::BUFFER_COLLECTION_CREATE_INFO_FUCHSIA,
+        Self::IMPORT_MEMORY_BUFFER_COLLECTION_FUCHSIA,
+        Self::BUFFER_COLLECTION_IMAGE_CREATE_INFO_FUCHSIA,
+        Self::BUFFER_COLLECTION_PROPERTIES_FUCHSIA,
+        Self::BUFFER_CONSTRAINTS_INFO_FUCHSIA,
+        Self::BUFFER_COLLECTION_BUFFER_CREATE_INFO_FUCHSIA,
+        Self::IMAGE_CONSTRAINTS_INFO_FUCHSIA,
+        Self::IMAGE_FORMAT_CONSTRAINTS_INFO_FUCHSIA,
+        Self::SYSMEM_COLOR_SPACE_FUCHSIA,
+        Self::BUFFER_COLLECTION_CONSTRAINTS_INFO_FUCHSIA,
+        Self::SUBPASS_SHADING_PIPELINE_CREATE_INFO_HUAWEI,
+        Self::PHYSICAL_DEVICE_SUBPASS_SHADING_FEATURES_HUAWEI,
+        Self::PHYSICAL_DEVICE_SUBPASS_SHADING_PROPERTIES_HUAWEI,
+        Self::PHYSICAL_DEVICE_INVOCATION_MASK_FEATURES_HUAWEI,
+        Self::MEMORY_GET_REMOTE_ADDRESS_INFO_NV,
+        Self::PHYSICAL_DEVICE_EXTERNAL_MEMORY_RDMA_FEATURES_NV,
+        Self::PIPELINE_PROPERTIES_IDENTIFIER_EXT,
+        Self::PHYSICAL_DEVICE_PIPELINE_PROPERTIES_FEATURES_EXT,
+        Self::IMPORT_FENCE_SCI_SYNC_INFO_NV,
+        Self::EXPORT_FENCE_SCI_SYNC_INFO_NV,
+        Self::FENCE_GET_SCI_SYNC_INFO_NV,
+        Self::SCI_SYNC_ATTRIBUTES_INFO_NV,
+        Self::IMPORT_SEMAPHORE_SCI_SYNC_INFO_NV,
+        Self::EXPORT_SEMAPHORE_SCI_SYNC_INFO_NV,
+        Self::SEMAPHORE_GET_SCI_SYNC_INFO_NV,
+        Self::PHYSICAL_DEVICE_EXTERNAL_SCI_SYNC_FEATURES_NV,
+        Self::IMPORT_MEMORY_SCI_BUF_INFO_NV,
+        Self::EXPORT_MEMORY_SCI_BUF_INFO_NV,
+        Self::MEMORY_GET_SCI_BUF_INFO_NV,
+        Self::MEMORY_SCI_BUF_PROPERTIES_NV,
+        Self::PHYSICAL_DEVICE_EXTERNAL_MEMORY_SCI_BUF_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_FRAME_BOUNDARY_FEATURES_EXT,
+        Self::FRAME_BOUNDARY_EXT,
+        Self::PHYSICAL_DEVICE_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_FEATURES_EXT,
+        Self::SUBPASS_RESOLVE_PERFORMANCE_QUERY_EXT,
+        Self::MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_INFO_EXT,
+        Self::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_2_FEATURES_EXT,
+        Self::SCREEN_SURFACE_CREATE_INFO_QNX,
+        Self::PHYSICAL_DEVICE_COLOR_WRITE_ENABLE_FEATURES_EXT,
+        Self::PIPELINE_COLOR_WRITE_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_PRIMITIVES_GENERATED_QUERY_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_RAY_TRACING_MAINTENANCE_1_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_SHADER_UNTYPED_POINTERS_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_VIDEO_ENCODE_RGB_CONVERSION_FEATURES_VALVE,
+        Self::VIDEO_ENCODE_RGB_CONVERSION_CAPABILITIES_VALVE,
+        Self::VIDEO_ENCODE_PROFILE_RGB_CONVERSION_INFO_VALVE,
+        Self::VIDEO_ENCODE_SESSION_RGB_CONVERSION_CREATE_INFO_VALVE,
+        Self::PHYSICAL_DEVICE_IMAGE_VIEW_MIN_LOD_FEATURES_EXT,
+        Self::IMAGE_VIEW_MIN_LOD_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_MULTI_DRAW_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_MULTI_DRAW_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_IMAGE_2D_VIEW_OF_3D_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_TILE_IMAGE_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_TILE_IMAGE_PROPERTIES_EXT,
+        Self::MICROMAP_BUILD_INFO_EXT,
+        Self::MICROMAP_VERSION_INFO_EXT,
+        Self::COPY_MICROMAP_INFO_EXT,
+        Self::COPY_MICROMAP_TO_MEMORY_INFO_EXT,
+        Self::COPY_MEMORY_TO_MICROMAP_INFO_EXT,
+        Self::PHYSICAL_DEVICE_OPACITY_MICROMAP_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_OPACITY_MICROMAP_PROPERTIES_EXT,
+        Self::MICROMAP_CREATE_INFO_EXT,
+        Self::MICROMAP_BUILD_SIZES_INFO_EXT,
+        Self::ACCELERATION_STRUCTURE_TRIANGLES_OPACITY_MICROMAP_EXT,
+        Self::PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_PROPERTIES_NV,
+        Self::ACCELERATION_STRUCTURE_TRIANGLES_DISPLACEMENT_MICROMAP_NV,
+        Self::PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_FEATURES_HUAWEI,
+        Self::PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_PROPERTIES_HUAWEI,
+        Self::PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_VRS_FEATURES_HUAWEI,
+        Self::PHYSICAL_DEVICE_BORDER_COLOR_SWIZZLE_FEATURES_EXT,
+        Self::SAMPLER_BORDER_COLOR_COMPONENT_MAPPING_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_PAGEABLE_DEVICE_LOCAL_MEMORY_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_ARM,
+        Self::DEVICE_QUEUE_SHADER_CORE_CONTROL_CREATE_INFO_ARM,
+        Self::PHYSICAL_DEVICE_SCHEDULING_CONTROLS_FEATURES_ARM,
+        Self::PHYSICAL_DEVICE_SCHEDULING_CONTROLS_PROPERTIES_ARM,
+        Self::PHYSICAL_DEVICE_IMAGE_SLICED_VIEW_OF_3D_FEATURES_EXT,
+        Self::IMAGE_VIEW_SLICED_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_DESCRIPTOR_SET_HOST_MAPPING_FEATURES_VALVE,
+        Self::DESCRIPTOR_SET_BINDING_REFERENCE_VALVE,
+        Self::DESCRIPTOR_SET_LAYOUT_HOST_MAPPING_INFO_VALVE,
+        Self::PHYSICAL_DEVICE_NON_SEAMLESS_CUBE_MAP_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_RENDER_PASS_STRIPED_FEATURES_ARM,
+        Self::PHYSICAL_DEVICE_RENDER_PASS_STRIPED_PROPERTIES_ARM,
+        Self::RENDER_PASS_STRIPE_BEGIN_INFO_ARM,
+        Self::RENDER_PASS_STRIPE_INFO_ARM,
+        Self::RENDER_PASS_STRIPE_SUBMIT_INFO_ARM,
+        Self::PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_COMPUTE_FEATURES_NV,
+        Self::COMPUTE_PIPELINE_INDIRECT_BUFFER_INFO_NV,
+        Self::PIPELINE_INDIRECT_DEVICE_ADDRESS_INFO_NV,
+        Self::PHYSICAL_DEVICE_RAY_TRACING_LINEAR_SWEPT_SPHERES_FEATURES_NV,
+        Self::ACCELERATION_STRUCTURE_GEOMETRY_LINEAR_SWEPT_SPHERES_DATA_NV,
+        Self::ACCELERATION_STRUCTURE_GEOMETRY_SPHERES_DATA_NV,
+        Self::PHYSICAL_DEVICE_LINEAR_COLOR_ATTACHMENT_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_SHADER_MAXIMAL_RECONVERGENCE_FEATURES_KHR,
+        Self::APPLICATION_PARAMETERS_EXT,
+        Self::PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_IMAGE_PROCESSING_FEATURES_QCOM,
+        Self::PHYSICAL_DEVICE_IMAGE_PROCESSING_PROPERTIES_QCOM,
+        Self::IMAGE_VIEW_SAMPLE_WEIGHT_CREATE_INFO_QCOM,
+        Self::PHYSICAL_DEVICE_NESTED_COMMAND_BUFFER_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_NESTED_COMMAND_BUFFER_PROPERTIES_EXT,
+        Self::NATIVE_BUFFER_USAGE_OHOS,
+        Self::NATIVE_BUFFER_PROPERTIES_OHOS,
+        Self::NATIVE_BUFFER_FORMAT_PROPERTIES_OHOS,
+        Self::IMPORT_NATIVE_BUFFER_INFO_OHOS,
+        Self::MEMORY_GET_NATIVE_BUFFER_INFO_OHOS,
+        Self::EXTERNAL_FORMAT_OHOS,
+        Self::EXTERNAL_MEMORY_ACQUIRE_UNMODIFIED_EXT,
+        Self::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_SUBPASS_MERGE_FEEDBACK_FEATURES_EXT,
+        Self::RENDER_PASS_CREATION_CONTROL_EXT,
+        Self::RENDER_PASS_CREATION_FEEDBACK_CREATE_INFO_EXT,
+        Self::RENDER_PASS_SUBPASS_FEEDBACK_CREATE_INFO_EXT,
+        Self::DIRECT_DRIVER_LOADING_INFO_LUNARG,
+        Self::DIRECT_DRIVER_LOADING_LIST_LUNARG,
+        Self::TENSOR_CREATE_INFO_ARM,
+        Self::TENSOR_VIEW_CREATE_INFO_ARM,
+        Self::BIND_TENSOR_MEMORY_INFO_ARM,
+        Self::WRITE_DESCRIPTOR_SET_TENSOR_ARM,
+        Self::PHYSICAL_DEVICE_TENSOR_PROPERTIES_ARM,
+        Self::TENSOR_FORMAT_PROPERTIES_ARM,
+        Self::TENSOR_DESCRIPTION_ARM,
+        Self::TENSOR_MEMORY_REQUIREMENTS_INFO_ARM,
+        Self::TENSOR_MEMORY_BARRIER_ARM,
+        Self::PHYSICAL_DEVICE_TENSOR_FEATURES_ARM,
+        Self::DEVICE_TENSOR_MEMORY_REQUIREMENTS_ARM,
+        Self::COPY_TENSOR_INFO_ARM,
+        Self::TENSOR_COPY_ARM,
+        Self::TENSOR_DEPENDENCY_INFO_ARM,
+        Self::MEMORY_DEDICATED_ALLOCATE_INFO_TENSOR_ARM,
+        Self::PHYSICAL_DEVICE_EXTERNAL_TENSOR_INFO_ARM,
+        Self::EXTERNAL_TENSOR_PROPERTIES_ARM,
+        Self::EXTERNAL_MEMORY_TENSOR_CREATE_INFO_ARM,
+        Self::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_TENSOR_FEATURES_ARM,
+        Self::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_TENSOR_PROPERTIES_ARM,
+        Self::DESCRIPTOR_GET_TENSOR_INFO_ARM,
+        Self::TENSOR_CAPTURE_DESCRIPTOR_DATA_INFO_ARM,
+        Self::TENSOR_VIEW_CAPTURE_DESCRIPTOR_DATA_INFO_ARM,
+        Self::FRAME_BOUNDARY_TENSORS_ARM,
+        Self::PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_PROPERTIES_EXT,
+        Self::PIPELINE_SHADER_STAGE_MODULE_IDENTIFIER_CREATE_INFO_EXT,
+        Self::SHADER_MODULE_IDENTIFIER_EXT,
+        Self::PHYSICAL_DEVICE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_OPTICAL_FLOW_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_OPTICAL_FLOW_PROPERTIES_NV,
+        Self::OPTICAL_FLOW_IMAGE_FORMAT_INFO_NV,
+        Self::OPTICAL_FLOW_IMAGE_FORMAT_PROPERTIES_NV,
+        Self::OPTICAL_FLOW_SESSION_CREATE_INFO_NV,
+        Self::OPTICAL_FLOW_EXECUTE_INFO_NV,
+        Self::OPTICAL_FLOW_SESSION_CREATE_PRIVATE_DATA_INFO_NV,
+        Self::PHYSICAL_DEVICE_LEGACY_DITHERING_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_EXTERNAL_FORMAT_RESOLVE_FEATURES_ANDROID,
+        Self::PHYSICAL_DEVICE_EXTERNAL_FORMAT_RESOLVE_PROPERTIES_ANDROID,
+        Self::ANDROID_HARDWARE_BUFFER_FORMAT_RESOLVE_PROPERTIES_ANDROID,
+        Self::PHYSICAL_DEVICE_ANTI_LAG_FEATURES_AMD,
+        Self::ANTI_LAG_DATA_AMD,
+        Self::ANTI_LAG_PRESENTATION_INFO_AMD,
+        Self::PHYSICAL_DEVICE_DENSE_GEOMETRY_FORMAT_FEATURES_AMDX,
+        Self::ACCELERATION_STRUCTURE_DENSE_GEOMETRY_FORMAT_TRIANGLES_DATA_AMDX,
+        Self::SURFACE_CAPABILITIES_PRESENT_ID_2_KHR,
+        Self::PRESENT_ID_2_KHR,
+        Self::PHYSICAL_DEVICE_PRESENT_ID_2_FEATURES_KHR,
+        Self::SURFACE_CAPABILITIES_PRESENT_WAIT_2_KHR,
+        Self::PHYSICAL_DEVICE_PRESENT_WAIT_2_FEATURES_KHR,
+        Self::PRESENT_WAIT_2_INFO_KHR,
+        Self::PHYSICAL_DEVICE_RAY_TRACING_POSITION_FETCH_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_SHADER_OBJECT_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_OBJECT_PROPERTIES_EXT,
+        Self::SHADER_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_PIPELINE_BINARY_FEATURES_KHR,
+        Self::PIPELINE_BINARY_CREATE_INFO_KHR,
+        Self::PIPELINE_BINARY_INFO_KHR,
+        Self::PIPELINE_BINARY_KEY_KHR,
+        Self::PHYSICAL_DEVICE_PIPELINE_BINARY_PROPERTIES_KHR,
+        Self::RELEASE_CAPTURED_PIPELINE_DATA_INFO_KHR,
+        Self::PIPELINE_BINARY_DATA_INFO_KHR,
+        Self::PIPELINE_CREATE_INFO_KHR,
+        Self::DEVICE_PIPELINE_BINARY_INTERNAL_CACHE_CONTROL_KHR,
+        Self::PIPELINE_BINARY_HANDLES_INFO_KHR,
+        Self::PHYSICAL_DEVICE_TILE_PROPERTIES_FEATURES_QCOM,
+        Self::TILE_PROPERTIES_QCOM,
+        Self::PHYSICAL_DEVICE_AMIGO_PROFILING_FEATURES_SEC,
+        Self::AMIGO_PROFILING_SUBMIT_INFO_SEC,
+        Self::SURFACE_PRESENT_MODE_KHR,
+        Self::SURFACE_PRESENT_SCALING_CAPABILITIES_KHR,
+        Self::SURFACE_PRESENT_MODE_COMPATIBILITY_KHR,
+        Self::PHYSICAL_DEVICE_SWAPCHAIN_MAINTENANCE_1_FEATURES_KHR,
+        Self::SWAPCHAIN_PRESENT_FENCE_INFO_KHR,
+        Self::SWAPCHAIN_PRESENT_MODES_CREATE_INFO_KHR,
+        Self::SWAPCHAIN_PRESENT_MODE_INFO_KHR,
+        Self::SWAPCHAIN_PRESENT_SCALING_CREATE_INFO_KHR,
+        Self::RELEASE_SWAPCHAIN_IMAGES_INFO_KHR,
+        Self::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_VIEWPORTS_FEATURES_QCOM,
+        Self::SEMAPHORE_SCI_SYNC_POOL_CREATE_INFO_NV,
+        Self::SEMAPHORE_SCI_SYNC_CREATE_INFO_NV,
+        Self::PHYSICAL_DEVICE_EXTERNAL_SCI_SYNC_2_FEATURES_NV,
+        Self::DEVICE_SEMAPHORE_SCI_SYNC_POOL_RESERVATION_CREATE_INFO_NV,
+        Self::PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_PROPERTIES_NV,
+        Self::PHYSICAL_DEVICE_COOPERATIVE_VECTOR_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_COOPERATIVE_VECTOR_PROPERTIES_NV,
+        Self::COOPERATIVE_VECTOR_PROPERTIES_NV,
+        Self::CONVERT_COOPERATIVE_VECTOR_MATRIX_INFO_NV,
+        Self::PHYSICAL_DEVICE_EXTENDED_SPARSE_ADDRESS_SPACE_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_EXTENDED_SPARSE_ADDRESS_SPACE_PROPERTIES_NV,
+        Self::PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_EXT,
+        Self::MUTABLE_DESCRIPTOR_TYPE_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_LEGACY_VERTEX_ATTRIBUTES_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_LEGACY_VERTEX_ATTRIBUTES_PROPERTIES_EXT,
+        Self::LAYER_SETTINGS_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_FEATURES_ARM,
+        Self::PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_PROPERTIES_ARM,
+        Self::PHYSICAL_DEVICE_PIPELINE_LIBRARY_GROUP_HANDLES_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_DYNAMIC_RENDERING_UNUSED_ATTACHMENTS_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_INTERNALLY_SYNCHRONIZED_QUEUES_FEATURES_KHR,
+        Self::LATENCY_SLEEP_MODE_INFO_NV,
+        Self::LATENCY_SLEEP_INFO_NV,
+        Self::SET_LATENCY_MARKER_INFO_NV,
+        Self::GET_LATENCY_MARKER_INFO_NV,
+        Self::LATENCY_TIMINGS_FRAME_REPORT_NV,
+        Self::LATENCY_SUBMISSION_PRESENT_ID_NV,
+        Self::OUT_OF_BAND_QUEUE_TYPE_INFO_NV,
+        Self::SWAPCHAIN_LATENCY_CREATE_INFO_NV,
+        Self::LATENCY_SURFACE_CAPABILITIES_NV,
+        Self::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_KHR,
+        Self::COOPERATIVE_MATRIX_PROPERTIES_KHR,
+        Self::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_KHR,
+        Self::DATA_GRAPH_PIPELINE_CREATE_INFO_ARM,
+        Self::DATA_GRAPH_PIPELINE_SESSION_CREATE_INFO_ARM,
+        Self::DATA_GRAPH_PIPELINE_RESOURCE_INFO_ARM,
+        Self::DATA_GRAPH_PIPELINE_CONSTANT_ARM,
+        Self::DATA_GRAPH_PIPELINE_SESSION_MEMORY_REQUIREMENTS_INFO_ARM,
+        Self::BIND_DATA_GRAPH_PIPELINE_SESSION_MEMORY_INFO_ARM,
+        Self::PHYSICAL_DEVICE_DATA_GRAPH_FEATURES_ARM,
+        Self::DATA_GRAPH_PIPELINE_SHADER_MODULE_CREATE_INFO_ARM,
+        Self::DATA_GRAPH_PIPELINE_PROPERTY_QUERY_RESULT_ARM,
+        Self::DATA_GRAPH_PIPELINE_INFO_ARM,
+        Self::DATA_GRAPH_PIPELINE_COMPILER_CONTROL_CREATE_INFO_ARM,
+        Self::DATA_GRAPH_PIPELINE_SESSION_BIND_POINT_REQUIREMENTS_INFO_ARM,
+        Self::DATA_GRAPH_PIPELINE_SESSION_BIND_POINT_REQUIREMENT_ARM,
+        Self::DATA_GRAPH_PIPELINE_IDENTIFIER_CREATE_INFO_ARM,
+        Self::DATA_GRAPH_PIPELINE_DISPATCH_INFO_ARM,
+        Self::DATA_GRAPH_PROCESSING_ENGINE_CREATE_INFO_ARM,
+        Self::QUEUE_FAMILY_DATA_GRAPH_PROCESSING_ENGINE_PROPERTIES_ARM,
+        Self::QUEUE_FAMILY_DATA_GRAPH_PROPERTIES_ARM,
+        Self::PHYSICAL_DEVICE_QUEUE_FAMILY_DATA_GRAPH_PROCESSING_ENGINE_INFO_ARM,
+        Self::DATA_GRAPH_PIPELINE_CONSTANT_TENSOR_SEMI_STRUCTURED_SPARSITY_INFO_ARM,
+        Self::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_RENDER_AREAS_FEATURES_QCOM,
+        Self::MULTIVIEW_PER_VIEW_RENDER_AREAS_RENDER_PASS_BEGIN_INFO_QCOM,
+        Self::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_PROPERTIES_KHR,
+        Self::VIDEO_DECODE_AV1_CAPABILITIES_KHR,
+        Self::VIDEO_DECODE_AV1_PICTURE_INFO_KHR,
+        Self::VIDEO_DECODE_AV1_PROFILE_INFO_KHR,
+        Self::VIDEO_DECODE_AV1_SESSION_PARAMETERS_CREATE_INFO_KHR,
+        Self::VIDEO_DECODE_AV1_DPB_SLOT_INFO_KHR,
+        Self::VIDEO_ENCODE_AV1_CAPABILITIES_KHR,
+        Self::VIDEO_ENCODE_AV1_SESSION_PARAMETERS_CREATE_INFO_KHR,
+        Self::VIDEO_ENCODE_AV1_PICTURE_INFO_KHR,
+        Self::VIDEO_ENCODE_AV1_DPB_SLOT_INFO_KHR,
+        Self::PHYSICAL_DEVICE_VIDEO_ENCODE_AV1_FEATURES_KHR,
+        Self::VIDEO_ENCODE_AV1_PROFILE_INFO_KHR,
+        Self::VIDEO_ENCODE_AV1_RATE_CONTROL_INFO_KHR,
+        Self::VIDEO_ENCODE_AV1_RATE_CONTROL_LAYER_INFO_KHR,
+        Self::VIDEO_ENCODE_AV1_QUALITY_LEVEL_PROPERTIES_KHR,
+        Self::VIDEO_ENCODE_AV1_SESSION_CREATE_INFO_KHR,
+        Self::VIDEO_ENCODE_AV1_GOP_REMAINING_FRAME_INFO_KHR,
+        Self::PHYSICAL_DEVICE_VIDEO_DECODE_VP9_FEATURES_KHR,
+        Self::VIDEO_DECODE_VP9_CAPABILITIES_KHR,
+        Self::VIDEO_DECODE_VP9_PICTURE_INFO_KHR,
+        Self::VIDEO_DECODE_VP9_PROFILE_INFO_KHR,
+        Self::PHYSICAL_DEVICE_VIDEO_MAINTENANCE_1_FEATURES_KHR,
+        Self::VIDEO_INLINE_QUERY_INFO_KHR,
+        Self::PHYSICAL_DEVICE_PER_STAGE_DESCRIPTOR_SET_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_IMAGE_PROCESSING_2_FEATURES_QCOM,
+        Self::PHYSICAL_DEVICE_IMAGE_PROCESSING_2_PROPERTIES_QCOM,
+        Self::SAMPLER_BLOCK_MATCH_WINDOW_CREATE_INFO_QCOM,
+        Self::SAMPLER_CUBIC_WEIGHTS_CREATE_INFO_QCOM,
+        Self::PHYSICAL_DEVICE_CUBIC_WEIGHTS_FEATURES_QCOM,
+        Self::BLIT_IMAGE_CUBIC_WEIGHTS_INFO_QCOM,
+        Self::PHYSICAL_DEVICE_YCBCR_DEGAMMA_FEATURES_QCOM,
+        Self::SAMPLER_YCBCR_CONVERSION_YCBCR_DEGAMMA_CREATE_INFO_QCOM,
+        Self::PHYSICAL_DEVICE_CUBIC_CLAMP_FEATURES_QCOM,
+        Self::PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_DYNAMIC_STATE_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_UNIFIED_IMAGE_LAYOUTS_FEATURES_KHR,
+        Self::ATTACHMENT_FEEDBACK_LOOP_INFO_EXT,
+        Self::SCREEN_BUFFER_PROPERTIES_QNX,
+        Self::SCREEN_BUFFER_FORMAT_PROPERTIES_QNX,
+        Self::IMPORT_SCREEN_BUFFER_INFO_QNX,
+        Self::EXTERNAL_FORMAT_QNX,
+        Self::PHYSICAL_DEVICE_EXTERNAL_MEMORY_SCREEN_BUFFER_FEATURES_QNX,
+        Self::PHYSICAL_DEVICE_LAYERED_DRIVER_PROPERTIES_MSFT,
+        Self::CALIBRATED_TIMESTAMP_INFO_KHR,
+        Self::SET_DESCRIPTOR_BUFFER_OFFSETS_INFO_EXT,
+        Self::BIND_DESCRIPTOR_BUFFER_EMBEDDED_SAMPLERS_INFO_EXT,
+        Self::PHYSICAL_DEVICE_DESCRIPTOR_POOL_OVERALLOCATION_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_TILE_MEMORY_HEAP_FEATURES_QCOM,
+        Self::PHYSICAL_DEVICE_TILE_MEMORY_HEAP_PROPERTIES_QCOM,
+        Self::TILE_MEMORY_REQUIREMENTS_QCOM,
+        Self::TILE_MEMORY_BIND_INFO_QCOM,
+        Self::TILE_MEMORY_SIZE_INFO_QCOM,
+        Self::PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_PROPERTIES_KHR,
+        Self::COPY_MEMORY_INDIRECT_INFO_KHR,
+        Self::COPY_MEMORY_TO_IMAGE_INDIRECT_INFO_KHR,
+        Self::PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_PROPERTIES_EXT,
+        Self::DECOMPRESS_MEMORY_INFO_EXT,
+        Self::DISPLAY_SURFACE_STEREO_CREATE_INFO_NV,
+        Self::DISPLAY_MODE_STEREO_PROPERTIES_NV,
+        Self::VIDEO_ENCODE_INTRA_REFRESH_CAPABILITIES_KHR,
+        Self::VIDEO_ENCODE_SESSION_INTRA_REFRESH_CREATE_INFO_KHR,
+        Self::VIDEO_ENCODE_INTRA_REFRESH_INFO_KHR,
+        Self::VIDEO_REFERENCE_INTRA_REFRESH_INFO_KHR,
+        Self::PHYSICAL_DEVICE_VIDEO_ENCODE_INTRA_REFRESH_FEATURES_KHR,
+        Self::VIDEO_ENCODE_QUANTIZATION_MAP_CAPABILITIES_KHR,
+        Self::VIDEO_FORMAT_QUANTIZATION_MAP_PROPERTIES_KHR,
+        Self::VIDEO_ENCODE_QUANTIZATION_MAP_INFO_KHR,
+        Self::VIDEO_ENCODE_QUANTIZATION_MAP_SESSION_PARAMETERS_CREATE_INFO_KHR,
+        Self::PHYSICAL_DEVICE_VIDEO_ENCODE_QUANTIZATION_MAP_FEATURES_KHR,
+        Self::VIDEO_ENCODE_H264_QUANTIZATION_MAP_CAPABILITIES_KHR,
+        Self::VIDEO_ENCODE_H265_QUANTIZATION_MAP_CAPABILITIES_KHR,
+        Self::VIDEO_FORMAT_H265_QUANTIZATION_MAP_PROPERTIES_KHR,
+        Self::VIDEO_ENCODE_AV1_QUANTIZATION_MAP_CAPABILITIES_KHR,
+        Self::VIDEO_FORMAT_AV1_QUANTIZATION_MAP_PROPERTIES_KHR,
+        Self::PHYSICAL_DEVICE_RAW_ACCESS_CHAINS_FEATURES_NV,
+        Self::EXTERNAL_COMPUTE_QUEUE_DEVICE_CREATE_INFO_NV,
+        Self::EXTERNAL_COMPUTE_QUEUE_CREATE_INFO_NV,
+        Self::EXTERNAL_COMPUTE_QUEUE_DATA_PARAMS_NV,
+        Self::PHYSICAL_DEVICE_EXTERNAL_COMPUTE_QUEUE_PROPERTIES_NV,
+        Self::PHYSICAL_DEVICE_SHADER_RELAXED_EXTENDED_INSTRUCTION_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_COMMAND_BUFFER_INHERITANCE_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_7_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_7_PROPERTIES_KHR,
+        Self::PHYSICAL_DEVICE_LAYERED_API_PROPERTIES_LIST_KHR,
+        Self::PHYSICAL_DEVICE_LAYERED_API_PROPERTIES_KHR,
+        Self::PHYSICAL_DEVICE_LAYERED_API_VULKAN_PROPERTIES_KHR,
+        Self::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT16_VECTOR_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_SHADER_REPLICATED_COMPOSITES_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_FLOAT8_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_RAY_TRACING_VALIDATION_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_CLUSTER_ACCELERATION_STRUCTURE_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_CLUSTER_ACCELERATION_STRUCTURE_PROPERTIES_NV,
+        Self::CLUSTER_ACCELERATION_STRUCTURE_CLUSTERS_BOTTOM_LEVEL_INPUT_NV,
+        Self::CLUSTER_ACCELERATION_STRUCTURE_TRIANGLE_CLUSTER_INPUT_NV,
+        Self::CLUSTER_ACCELERATION_STRUCTURE_MOVE_OBJECTS_INPUT_NV,
+        Self::CLUSTER_ACCELERATION_STRUCTURE_INPUT_INFO_NV,
+        Self::CLUSTER_ACCELERATION_STRUCTURE_COMMANDS_INFO_NV,
+        Self::RAY_TRACING_PIPELINE_CLUSTER_ACCELERATION_STRUCTURE_CREATE_INFO_NV,
+        Self::PHYSICAL_DEVICE_PARTITIONED_ACCELERATION_STRUCTURE_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_PARTITIONED_ACCELERATION_STRUCTURE_PROPERTIES_NV,
+        Self::WRITE_DESCRIPTOR_SET_PARTITIONED_ACCELERATION_STRUCTURE_NV,
+        Self::PARTITIONED_ACCELERATION_STRUCTURE_INSTANCES_INPUT_NV,
+        Self::BUILD_PARTITIONED_ACCELERATION_STRUCTURE_INFO_NV,
+        Self::PARTITIONED_ACCELERATION_STRUCTURE_FLAGS_NV,
+        Self::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_EXT,
+        Self::GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_EXT,
+        Self::INDIRECT_EXECUTION_SET_CREATE_INFO_EXT,
+        Self::GENERATED_COMMANDS_INFO_EXT,
+        Self::INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_EXT,
+        Self::INDIRECT_COMMANDS_LAYOUT_TOKEN_EXT,
+        Self::WRITE_INDIRECT_EXECUTION_SET_PIPELINE_EXT,
+        Self::WRITE_INDIRECT_EXECUTION_SET_SHADER_EXT,
+        Self::INDIRECT_EXECUTION_SET_PIPELINE_INFO_EXT,
+        Self::INDIRECT_EXECUTION_SET_SHADER_INFO_EXT,
+        Self::INDIRECT_EXECUTION_SET_SHADER_LAYOUT_INFO_EXT,
+        Self::GENERATED_COMMANDS_PIPELINE_INFO_EXT,
+        Self::GENERATED_COMMANDS_SHADER_INFO_EXT,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_8_FEATURES_KHR,
+        Self::MEMORY_BARRIER_ACCESS_FLAGS_3_KHR,
+        Self::PHYSICAL_DEVICE_IMAGE_ALIGNMENT_CONTROL_FEATURES_MESA,
+        Self::PHYSICAL_DEVICE_IMAGE_ALIGNMENT_CONTROL_PROPERTIES_MESA,
+        Self::IMAGE_ALIGNMENT_CONTROL_CREATE_INFO_MESA,
+        Self::PHYSICAL_DEVICE_SHADER_FMA_FEATURES_KHR,
+        Self::PUSH_CONSTANT_BANK_INFO_NV,
+        Self::PHYSICAL_DEVICE_PUSH_CONSTANT_BANK_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_PUSH_CONSTANT_BANK_PROPERTIES_NV,
+        Self::PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_DEPTH_CLAMP_CONTROL_FEATURES_EXT,
+        Self::PIPELINE_VIEWPORT_DEPTH_CLAMP_CONTROL_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_9_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_9_PROPERTIES_KHR,
+        Self::QUEUE_FAMILY_OWNERSHIP_TRANSFER_PROPERTIES_KHR,
+        Self::PHYSICAL_DEVICE_VIDEO_MAINTENANCE_2_FEATURES_KHR,
+        Self::VIDEO_DECODE_H264_INLINE_SESSION_PARAMETERS_INFO_KHR,
+        Self::VIDEO_DECODE_H265_INLINE_SESSION_PARAMETERS_INFO_KHR,
+        Self::VIDEO_DECODE_AV1_INLINE_SESSION_PARAMETERS_INFO_KHR,
+        Self::SURFACE_CREATE_INFO_OHOS,
+        Self::PHYSICAL_DEVICE_HDR_VIVID_FEATURES_HUAWEI,
+        Self::HDR_VIVID_DYNAMIC_METADATA_HUAWEI,
+        Self::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_2_FEATURES_NV,
+        Self::COOPERATIVE_MATRIX_FLEXIBLE_DIMENSIONS_PROPERTIES_NV,
+        Self::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_2_PROPERTIES_NV,
+        Self::PHYSICAL_DEVICE_PIPELINE_OPACITY_MICROMAP_FEATURES_ARM,
+        Self::IMPORT_MEMORY_METAL_HANDLE_INFO_EXT,
+        Self::MEMORY_METAL_HANDLE_PROPERTIES_EXT,
+        Self::MEMORY_GET_METAL_HANDLE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_DEPTH_CLAMP_ZERO_ONE_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_PERFORMANCE_COUNTERS_BY_REGION_FEATURES_ARM,
+        Self::PHYSICAL_DEVICE_PERFORMANCE_COUNTERS_BY_REGION_PROPERTIES_ARM,
+        Self::PERFORMANCE_COUNTER_ARM,
+        Self::PERFORMANCE_COUNTER_DESCRIPTION_ARM,
+        Self::RENDER_PASS_PERFORMANCE_COUNTERS_BY_REGION_BEGIN_INFO_ARM,
+        Self::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_ROBUSTNESS_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_FORMAT_PACK_FEATURES_ARM,
+        Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_LAYERED_FEATURES_VALVE,
+        Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_LAYERED_PROPERTIES_VALVE,
+        Self::PIPELINE_FRAGMENT_DENSITY_MAP_LAYERED_CREATE_INFO_VALVE,
+        Self::PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_KHR,
+        Self::SET_PRESENT_CONFIG_NV,
+        Self::PHYSICAL_DEVICE_PRESENT_METERING_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_PROPERTIES_EXT,
+        Self::RENDER_PASS_FRAGMENT_DENSITY_MAP_OFFSET_END_INFO_EXT,
+        Self::PHYSICAL_DEVICE_ZERO_INITIALIZE_DEVICE_MEMORY_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_PRESENT_MODE_FIFO_LATEST_READY_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_SHADER_64_BIT_INDEXING_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_CUSTOM_RESOLVE_FEATURES_EXT,
+        Self::BEGIN_CUSTOM_RESOLVE_INFO_EXT,
+        Self::CUSTOM_RESOLVE_CREATE_INFO_EXT,
+        Self::PHYSICAL_DEVICE_DATA_GRAPH_MODEL_FEATURES_QCOM,
+        Self::DATA_GRAPH_PIPELINE_BUILTIN_MODEL_CREATE_INFO_QCOM,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_10_FEATURES_KHR,
+        Self::PHYSICAL_DEVICE_MAINTENANCE_10_PROPERTIES_KHR,
+        Self::RENDERING_ATTACHMENT_FLAGS_INFO_KHR,
+        Self::RENDERING_END_INFO_KHR,
+        Self::RESOLVE_IMAGE_MODE_INFO_KHR,
+        Self::PHYSICAL_DEVICE_SHADER_LONG_VECTOR_FEATURES_EXT,
+        Self::PHYSICAL_DEVICE_SHADER_LONG_VECTOR_PROPERTIES_EXT,
+        Self::PHYSICAL_DEVICE_PIPELINE_CACHE_INCREMENTAL_MODE_FEATURES_SEC,
+        Self::PHYSICAL_DEVICE_SHADER_UNIFORM_BUFFER_UNSIZED_ARRAY_FEATURES_EXT,
+        Self::COMPUTE_OCCUPANCY_PRIORITY_PARAMETERS_NV,
+        Self::PHYSICAL_DEVICE_COMPUTE_OCCUPANCY_PRIORITY_FEATURES_NV,
+        Self::PHYSICAL_DEVICE_SHADER_SUBGROUP_PARTITIONED_FEATURES_EXT,
+        Self::UBM_SURFACE_CREATE_INFO_SEC,
+        Self::PHYSICAL_DEVICE_SHADER_MIXED_FLOAT_DOT_PRODUCT_FEATURES_VALVE,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9054,6 +11759,13 @@ impl SubpassContents {
     pub const SECONDARY_COMMAND_BUFFERS: Self = Self(1);
     pub const INLINE_AND_SECONDARY_COMMAND_BUFFERS_KHR: Self = Self(1000451000);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::INLINE,
+        Self::SECONDARY_COMMAND_BUFFERS,
+        Self::INLINE_AND_SECONDARY_COMMAND_BUFFERS_KHR,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9099,6 +11811,24 @@ impl SubpassMergeStatusEXT {
     pub const NOT_MERGED_SINGLE_SUBPASS: Self = Self(12);
     pub const NOT_MERGED_UNSPECIFIED: Self = Self(13);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::MERGED,
+        Self::DISALLOWED,
+        Self::NOT_MERGED_SIDE_EFFECTS,
+        Self::NOT_MERGED_SAMPLES_MISMATCH,
+        Self::NOT_MERGED_VIEWS_MISMATCH,
+        Self::NOT_MERGED_ALIASING,
+        Self::NOT_MERGED_DEPENDENCIES,
+        Self::NOT_MERGED_INCOMPATIBLE_INPUT_ATTACHMENT,
+        Self::NOT_MERGED_TOO_MANY_ATTACHMENTS,
+        Self::NOT_MERGED_INSUFFICIENT_STORAGE,
+        Self::NOT_MERGED_DEPTH_STENCIL_COUNT,
+        Self::NOT_MERGED_RESOLVE_ATTACHMENT_REUSE,
+        Self::NOT_MERGED_SINGLE_SUBPASS,
+        Self::NOT_MERGED_UNSPECIFIED,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9146,6 +11876,15 @@ impl SystemAllocationScope {
     pub const DEVICE: Self = Self(3);
     pub const INSTANCE: Self = Self(4);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::COMMAND,
+        Self::OBJECT,
+        Self::CACHE,
+        Self::DEVICE,
+        Self::INSTANCE,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9181,6 +11920,9 @@ impl TensorTilingARM {
     pub const OPTIMAL: Self = Self(0);
     pub const LINEAR: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::OPTIMAL, Self::LINEAR];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9213,6 +11955,9 @@ impl TessellationDomainOrigin {
     pub const UPPER_LEFT: Self = Self(0);
     pub const LOWER_LEFT: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::UPPER_LEFT, Self::LOWER_LEFT];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9249,6 +11994,16 @@ impl TimeDomainKHR {
     pub const PRESENT_STAGE_LOCAL_EXT: Self = Self(1000208000);
     pub const SWAPCHAIN_LOCAL_EXT: Self = Self(1000208001);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::DEVICE,
+        Self::CLOCK_MONOTONIC,
+        Self::CLOCK_MONOTONIC_RAW,
+        Self::QUERY_PERFORMANCE_COUNTER,
+        Self::PRESENT_STAGE_LOCAL_EXT,
+        Self::SWAPCHAIN_LOCAL_EXT,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9284,6 +12039,9 @@ pub struct ValidationCacheHeaderVersionEXT(i32);
 impl ValidationCacheHeaderVersionEXT {
     pub const ONE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::ONE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9315,6 +12073,9 @@ impl ValidationCheckEXT {
     pub const ALL: Self = Self(0);
     pub const SHADERS: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::ALL, Self::SHADERS];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9353,6 +12114,18 @@ impl ValidationFeatureDisableEXT {
     pub const UNIQUE_HANDLES: Self = Self(6);
     pub const SHADER_VALIDATION_CACHE: Self = Self(7);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::ALL,
+        Self::SHADERS,
+        Self::THREAD_SAFETY,
+        Self::API_PARAMETERS,
+        Self::OBJECT_LIFETIMES,
+        Self::CORE_CHECKS,
+        Self::UNIQUE_HANDLES,
+        Self::SHADER_VALIDATION_CACHE,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9394,6 +12167,15 @@ impl ValidationFeatureEnableEXT {
     pub const DEBUG_PRINTF: Self = Self(3);
     pub const SYNCHRONIZATION_VALIDATION: Self = Self(4);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::GPU_ASSISTED,
+        Self::GPU_ASSISTED_RESERVE_BINDING_SLOT,
+        Self::BEST_PRACTICES,
+        Self::DEBUG_PRINTF,
+        Self::SYNCHRONIZATION_VALIDATION,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9435,6 +12217,18 @@ impl VendorId {
     pub const POCL: Self = Self(65542);
     pub const MOBILEYE: Self = Self(65543);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::KHRONOS,
+        Self::VIV,
+        Self::VSI,
+        Self::KAZAN,
+        Self::CODEPLAY,
+        Self::MESA,
+        Self::POCL,
+        Self::MOBILEYE,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9473,6 +12267,9 @@ impl VertexInputRate {
     pub const VERTEX: Self = Self(0);
     pub const INSTANCE: Self = Self(1);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[Self::VERTEX, Self::INSTANCE];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9507,6 +12304,14 @@ impl VideoEncodeAV1PredictionModeKHR {
     pub const VIDEO_ENCODE_AV1_PREDICTION_MODE_UNIDIRECTIONAL_COMPOUND: Self = Self(2);
     pub const VIDEO_ENCODE_AV1_PREDICTION_MODE_BIDIRECTIONAL_COMPOUND: Self = Self(3);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::VIDEO_ENCODE_AV1_PREDICTION_MODE_INTRA_ONLY,
+        Self::VIDEO_ENCODE_AV1_PREDICTION_MODE_SINGLE_REFERENCE,
+        Self::VIDEO_ENCODE_AV1_PREDICTION_MODE_UNIDIRECTIONAL_COMPOUND,
+        Self::VIDEO_ENCODE_AV1_PREDICTION_MODE_BIDIRECTIONAL_COMPOUND,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9545,6 +12350,13 @@ impl VideoEncodeAV1RateControlGroupKHR {
     pub const VIDEO_ENCODE_AV1_RATE_CONTROL_GROUP_PREDICTIVE: Self = Self(1);
     pub const VIDEO_ENCODE_AV1_RATE_CONTROL_GROUP_BIPREDICTIVE: Self = Self(2);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::VIDEO_ENCODE_AV1_RATE_CONTROL_GROUP_INTRA,
+        Self::VIDEO_ENCODE_AV1_RATE_CONTROL_GROUP_PREDICTIVE,
+        Self::VIDEO_ENCODE_AV1_RATE_CONTROL_GROUP_BIPREDICTIVE,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9581,6 +12393,15 @@ impl VideoEncodeTuningModeKHR {
     pub const ULTRA_LOW_LATENCY: Self = Self(3);
     pub const LOSSLESS: Self = Self(4);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::DEFAULT,
+        Self::HIGH_QUALITY,
+        Self::LOW_LATENCY,
+        Self::ULTRA_LOW_LATENCY,
+        Self::LOSSLESS,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
@@ -9622,6 +12443,18 @@ impl ViewportCoordinateSwizzleNV {
     pub const POSITIVE_W: Self = Self(6);
     pub const NEGATIVE_W: Self = Self(7);
 
+    /// The values of this enum.
+    pub const VALUES: &'static [Self] = &[
+        Self::POSITIVE_X,
+        Self::NEGATIVE_X,
+        Self::POSITIVE_Y,
+        Self::NEGATIVE_Y,
+        Self::POSITIVE_Z,
+        Self::NEGATIVE_Z,
+        Self::POSITIVE_W,
+        Self::NEGATIVE_W,
+    ];
+
     /// Constructs an instance of this enum with the supplied underlying value.
     #[inline]
     pub const fn from_raw(value: i32) -> Self {
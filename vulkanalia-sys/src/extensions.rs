@@ -9,6 +9,7 @@
     non_camel_case_types,
     non_snake_case,
     clippy::bad_bit_mask,
+    clippy::let_and_return,
     clippy::let_unit_value,
     clippy::missing_safety_doc,
     clippy::missing_transmute_annotations,
@@ -27,6 +28,7 @@ pub type ExtensionName = StringArray<MAX_EXTENSION_NAME_SIZE>;
 
 /// A collection of metadata for a Vulkan extension.
 #[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
 pub struct Extension {
     /// The name of the extension.
     pub name: ExtensionName,
@@ -45,6 +47,10 @@ pub struct Extension {
     pub platform: Option<&'static str>,
 
     /// The other extensions required by the extension.
+    ///
+    /// If the extension can instead depend on one of several alternatives
+    /// (e.g., `VK_KHR_get_physical_device_properties2` or Vulkan 1.1), only
+    /// the first alternative is included here and in `required_version`.
     pub required_extensions: Option<&'static [ExtensionName]>,
     /// The Vulkan version required by the extension (e.g., `1.1`).
     pub required_version: Option<&'static str>,
@@ -55,6 +61,23 @@ pub struct Extension {
     pub obsoleted_by: Option<&'static str>,
     /// The Vulkan version the extension was promoted to core in (e.g., `VK_VERSION_1_1`).
     pub promoted_to: Option<&'static str>,
+
+    /// The commands added by the extension (e.g., `vkCmdBeginRenderingKHR`).
+    pub commands: &'static [&'static str],
+}
+
+impl Extension {
+    /// Finds the metadata for the Vulkan extension with the supplied name.
+    #[inline]
+    pub fn by_name(name: &ExtensionName) -> Option<&'static Extension> {
+        EXTENSIONS.iter().find(|e| e.name == *name)
+    }
+
+    /// Finds the metadata for the Vulkan extension with the supplied number.
+    #[inline]
+    pub fn by_number(number: i32) -> Option<&'static Extension> {
+        EXTENSIONS.iter().find(|e| e.number == number)
+    }
 }
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMDX_dense_geometry_format.html>
@@ -79,6 +102,7 @@ pub const AMDX_DENSE_GEOMETRY_FORMAT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMDX_shader_enqueue.html>
@@ -103,6 +127,15 @@ pub const AMDX_SHADER_ENQUEUE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdDispatchGraphAMDX",
+        "vkCmdDispatchGraphIndirectAMDX",
+        "vkCmdDispatchGraphIndirectCountAMDX",
+        "vkCmdInitializeGraphScratchMemoryAMDX",
+        "vkCreateExecutionGraphPipelinesAMDX",
+        "vkGetExecutionGraphPipelineNodeIndexAMDX",
+        "vkGetExecutionGraphPipelineScratchSizeAMDX",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_anti_lag.html>
@@ -119,6 +152,7 @@ pub const AMD_ANTI_LAG_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkAntiLagUpdateAMD"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_buffer_marker.html>
@@ -135,6 +169,7 @@ pub const AMD_BUFFER_MARKER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdWriteBufferMarker2AMD", "vkCmdWriteBufferMarkerAMD"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_device_coherent_memory.html>
@@ -151,6 +186,7 @@ pub const AMD_DEVICE_COHERENT_MEMORY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_display_native_hdr.html>
@@ -167,6 +203,7 @@ pub const AMD_DISPLAY_NATIVE_HDR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkSetLocalDimmingAMD"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_draw_indirect_count.html>
@@ -183,6 +220,10 @@ pub const AMD_DRAW_INDIRECT_COUNT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_draw_indirect_count"),
+    commands: &[
+        "vkCmdDrawIndexedIndirectCountAMD",
+        "vkCmdDrawIndirectCountAMD",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_gcn_shader.html>
@@ -199,6 +240,7 @@ pub const AMD_GCN_SHADER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_gpu_shader_half_float.html>
@@ -216,6 +258,7 @@ pub const AMD_GPU_SHADER_HALF_FLOAT_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_KHR_shader_float16_int8"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_gpu_shader_int16.html>
@@ -233,6 +276,7 @@ pub const AMD_GPU_SHADER_INT16_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_KHR_shader_float16_int8"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_memory_overallocation_behavior.html>
@@ -249,6 +293,7 @@ pub const AMD_MEMORY_OVERALLOCATION_BEHAVIOR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_mixed_attachment_samples.html>
@@ -265,6 +310,7 @@ pub const AMD_MIXED_ATTACHMENT_SAMPLES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_negative_viewport_height.html>
@@ -281,6 +327,7 @@ pub const AMD_NEGATIVE_VIEWPORT_HEIGHT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: Some("VK_KHR_maintenance1"),
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_pipeline_compiler_control.html>
@@ -297,6 +344,7 @@ pub const AMD_PIPELINE_COMPILER_CONTROL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_rasterization_order.html>
@@ -313,6 +361,7 @@ pub const AMD_RASTERIZATION_ORDER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_shader_ballot.html>
@@ -329,6 +378,7 @@ pub const AMD_SHADER_BALLOT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_shader_core_properties.html>
@@ -345,6 +395,7 @@ pub const AMD_SHADER_CORE_PROPERTIES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_shader_core_properties2.html>
@@ -361,6 +412,7 @@ pub const AMD_SHADER_CORE_PROPERTIES2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_shader_early_and_late_fragment_tests.html>
@@ -377,6 +429,7 @@ pub const AMD_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_EXTENSION: Extension = Extens
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_shader_explicit_vertex_parameter.html>
@@ -393,6 +446,7 @@ pub const AMD_SHADER_EXPLICIT_VERTEX_PARAMETER_EXTENSION: Extension = Extension
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_shader_fragment_mask.html>
@@ -409,6 +463,7 @@ pub const AMD_SHADER_FRAGMENT_MASK_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_shader_image_load_store_lod.html>
@@ -425,6 +480,7 @@ pub const AMD_SHADER_IMAGE_LOAD_STORE_LOD_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_shader_info.html>
@@ -441,6 +497,7 @@ pub const AMD_SHADER_INFO_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetShaderInfoAMD"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_shader_trinary_minmax.html>
@@ -457,6 +514,7 @@ pub const AMD_SHADER_TRINARY_MINMAX_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_AMD_texture_gather_bias_lod.html>
@@ -473,6 +531,7 @@ pub const AMD_TEXTURE_GATHER_BIAS_LOD_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_ANDROID_external_format_resolve.html>
@@ -489,6 +548,7 @@ pub const ANDROID_EXTERNAL_FORMAT_RESOLVE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_ANDROID_external_memory_android_hardware_buffer.html>
@@ -505,6 +565,10 @@ pub const ANDROID_EXTERNAL_MEMORY_ANDROID_HARDWARE_BUFFER_EXTENSION: Extension =
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetAndroidHardwareBufferPropertiesANDROID",
+        "vkGetMemoryAndroidHardwareBufferANDROID",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_ARM_data_graph.html>
@@ -521,6 +585,19 @@ pub const ARM_DATA_GRAPH_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkBindDataGraphPipelineSessionMemoryARM",
+        "vkCmdDispatchDataGraphARM",
+        "vkCreateDataGraphPipelineSessionARM",
+        "vkCreateDataGraphPipelinesARM",
+        "vkDestroyDataGraphPipelineSessionARM",
+        "vkGetDataGraphPipelineAvailablePropertiesARM",
+        "vkGetDataGraphPipelinePropertiesARM",
+        "vkGetDataGraphPipelineSessionBindPointRequirementsARM",
+        "vkGetDataGraphPipelineSessionMemoryRequirementsARM",
+        "vkGetPhysicalDeviceQueueFamilyDataGraphProcessingEnginePropertiesARM",
+        "vkGetPhysicalDeviceQueueFamilyDataGraphPropertiesARM",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_ARM_format_pack.html>
@@ -537,6 +614,7 @@ pub const ARM_FORMAT_PACK_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_ARM_performance_counters_by_region.html>
@@ -553,6 +631,7 @@ pub const ARM_PERFORMANCE_COUNTERS_BY_REGION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkEnumeratePhysicalDeviceQueueFamilyPerformanceCountersByRegionARM"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_ARM_pipeline_opacity_micromap.html>
@@ -569,6 +648,7 @@ pub const ARM_PIPELINE_OPACITY_MICROMAP_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_ARM_rasterization_order_attachment_access.html>
@@ -585,6 +665,7 @@ pub const ARM_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_EXTENSION: Extension = Exten
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_EXT_rasterization_order_attachment_access"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_ARM_render_pass_striped.html>
@@ -601,6 +682,7 @@ pub const ARM_RENDER_PASS_STRIPED_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_ARM_scheduling_controls.html>
@@ -617,6 +699,7 @@ pub const ARM_SCHEDULING_CONTROLS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_ARM_shader_core_builtins.html>
@@ -633,6 +716,7 @@ pub const ARM_SHADER_CORE_BUILTINS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_ARM_shader_core_properties.html>
@@ -649,6 +733,7 @@ pub const ARM_SHADER_CORE_PROPERTIES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_ARM_tensors.html>
@@ -665,6 +750,19 @@ pub const ARM_TENSORS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkBindTensorMemoryARM",
+        "vkCmdCopyTensorARM",
+        "vkCreateTensorARM",
+        "vkCreateTensorViewARM",
+        "vkDestroyTensorARM",
+        "vkDestroyTensorViewARM",
+        "vkGetDeviceTensorMemoryRequirementsARM",
+        "vkGetPhysicalDeviceExternalTensorPropertiesARM",
+        "vkGetTensorMemoryRequirementsARM",
+        "vkGetTensorOpaqueCaptureDescriptorDataARM",
+        "vkGetTensorViewOpaqueCaptureDescriptorDataARM",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_4444_formats.html>
@@ -681,6 +779,7 @@ pub const EXT_4444_FORMATS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_acquire_drm_display.html>
@@ -697,6 +796,7 @@ pub const EXT_ACQUIRE_DRM_DISPLAY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkAcquireDrmDisplayEXT", "vkGetDrmDisplayEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_acquire_xlib_display.html>
@@ -713,6 +813,7 @@ pub const EXT_ACQUIRE_XLIB_DISPLAY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkAcquireXlibDisplayEXT", "vkGetRandROutputDisplayEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_application_parameters.html>
@@ -729,6 +830,7 @@ pub const EXT_APPLICATION_PARAMETERS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_astc_decode_mode.html>
@@ -745,6 +847,7 @@ pub const EXT_ASTC_DECODE_MODE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_attachment_feedback_loop_dynamic_state.html>
@@ -761,6 +864,7 @@ pub const EXT_ATTACHMENT_FEEDBACK_LOOP_DYNAMIC_STATE_EXTENSION: Extension = Exte
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdSetAttachmentFeedbackLoopEnableEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_attachment_feedback_loop_layout.html>
@@ -777,6 +881,7 @@ pub const EXT_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_blend_operation_advanced.html>
@@ -793,6 +898,7 @@ pub const EXT_BLEND_OPERATION_ADVANCED_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_border_color_swizzle.html>
@@ -809,6 +915,7 @@ pub const EXT_BORDER_COLOR_SWIZZLE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_buffer_device_address.html>
@@ -826,6 +933,7 @@ pub const EXT_BUFFER_DEVICE_ADDRESS_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_KHR_buffer_device_address"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetBufferDeviceAddressEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_calibrated_timestamps.html>
@@ -842,6 +950,10 @@ pub const EXT_CALIBRATED_TIMESTAMPS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_calibrated_timestamps"),
+    commands: &[
+        "vkGetCalibratedTimestampsEXT",
+        "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_color_write_enable.html>
@@ -858,6 +970,7 @@ pub const EXT_COLOR_WRITE_ENABLE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdSetColorWriteEnableEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_conditional_rendering.html>
@@ -874,6 +987,10 @@ pub const EXT_CONDITIONAL_RENDERING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdBeginConditionalRenderingEXT",
+        "vkCmdEndConditionalRenderingEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_conservative_rasterization.html>
@@ -890,6 +1007,7 @@ pub const EXT_CONSERVATIVE_RASTERIZATION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_custom_border_color.html>
@@ -906,6 +1024,7 @@ pub const EXT_CUSTOM_BORDER_COLOR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_custom_resolve.html>
@@ -922,6 +1041,7 @@ pub const EXT_CUSTOM_RESOLVE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdBeginCustomResolveEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_debug_marker.html>
@@ -938,6 +1058,13 @@ pub const EXT_DEBUG_MARKER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_EXT_debug_utils"),
+    commands: &[
+        "vkCmdDebugMarkerBeginEXT",
+        "vkCmdDebugMarkerEndEXT",
+        "vkCmdDebugMarkerInsertEXT",
+        "vkDebugMarkerSetObjectNameEXT",
+        "vkDebugMarkerSetObjectTagEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_debug_report.html>
@@ -955,6 +1082,11 @@ pub const EXT_DEBUG_REPORT_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_EXT_debug_utils"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateDebugReportCallbackEXT",
+        "vkDebugReportMessageEXT",
+        "vkDestroyDebugReportCallbackEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_debug_utils.html>
@@ -971,6 +1103,19 @@ pub const EXT_DEBUG_UTILS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdBeginDebugUtilsLabelEXT",
+        "vkCmdEndDebugUtilsLabelEXT",
+        "vkCmdInsertDebugUtilsLabelEXT",
+        "vkCreateDebugUtilsMessengerEXT",
+        "vkDestroyDebugUtilsMessengerEXT",
+        "vkQueueBeginDebugUtilsLabelEXT",
+        "vkQueueEndDebugUtilsLabelEXT",
+        "vkQueueInsertDebugUtilsLabelEXT",
+        "vkSetDebugUtilsObjectNameEXT",
+        "vkSetDebugUtilsObjectTagEXT",
+        "vkSubmitDebugUtilsMessageEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_depth_bias_control.html>
@@ -987,6 +1132,7 @@ pub const EXT_DEPTH_BIAS_CONTROL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdSetDepthBias2EXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_depth_clamp_control.html>
@@ -1003,6 +1149,7 @@ pub const EXT_DEPTH_CLAMP_CONTROL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdSetDepthClampRangeEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_depth_clamp_zero_one.html>
@@ -1019,6 +1166,7 @@ pub const EXT_DEPTH_CLAMP_ZERO_ONE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_depth_clamp_zero_one"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_depth_clip_control.html>
@@ -1035,6 +1183,7 @@ pub const EXT_DEPTH_CLIP_CONTROL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_depth_clip_enable.html>
@@ -1051,6 +1200,7 @@ pub const EXT_DEPTH_CLIP_ENABLE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_depth_range_unrestricted.html>
@@ -1067,6 +1217,7 @@ pub const EXT_DEPTH_RANGE_UNRESTRICTED_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_descriptor_buffer.html>
@@ -1084,6 +1235,19 @@ pub const EXT_DESCRIPTOR_BUFFER_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_EXT_descriptor_heap"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdBindDescriptorBufferEmbeddedSamplersEXT",
+        "vkCmdBindDescriptorBuffersEXT",
+        "vkCmdSetDescriptorBufferOffsetsEXT",
+        "vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT",
+        "vkGetBufferOpaqueCaptureDescriptorDataEXT",
+        "vkGetDescriptorEXT",
+        "vkGetDescriptorSetLayoutBindingOffsetEXT",
+        "vkGetDescriptorSetLayoutSizeEXT",
+        "vkGetImageOpaqueCaptureDescriptorDataEXT",
+        "vkGetImageViewOpaqueCaptureDescriptorDataEXT",
+        "vkGetSamplerOpaqueCaptureDescriptorDataEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_descriptor_heap.html>
@@ -1100,6 +1264,18 @@ pub const EXT_DESCRIPTOR_HEAP_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdBindResourceHeapEXT",
+        "vkCmdBindSamplerHeapEXT",
+        "vkCmdPushDataEXT",
+        "vkGetImageOpaqueCaptureDataEXT",
+        "vkGetPhysicalDeviceDescriptorSizeEXT",
+        "vkGetTensorOpaqueCaptureDataARM",
+        "vkRegisterCustomBorderColorEXT",
+        "vkUnregisterCustomBorderColorEXT",
+        "vkWriteResourceDescriptorsEXT",
+        "vkWriteSamplerDescriptorsEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_descriptor_indexing.html>
@@ -1116,6 +1292,7 @@ pub const EXT_DESCRIPTOR_INDEXING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_device_address_binding_report.html>
@@ -1132,6 +1309,7 @@ pub const EXT_DEVICE_ADDRESS_BINDING_REPORT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_device_fault.html>
@@ -1148,6 +1326,7 @@ pub const EXT_DEVICE_FAULT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetDeviceFaultInfoEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_device_generated_commands.html>
@@ -1164,6 +1343,17 @@ pub const EXT_DEVICE_GENERATED_COMMANDS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdExecuteGeneratedCommandsEXT",
+        "vkCmdPreprocessGeneratedCommandsEXT",
+        "vkCreateIndirectCommandsLayoutEXT",
+        "vkCreateIndirectExecutionSetEXT",
+        "vkDestroyIndirectCommandsLayoutEXT",
+        "vkDestroyIndirectExecutionSetEXT",
+        "vkGetGeneratedCommandsMemoryRequirementsEXT",
+        "vkUpdateIndirectExecutionSetPipelineEXT",
+        "vkUpdateIndirectExecutionSetShaderEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_device_memory_report.html>
@@ -1180,6 +1370,7 @@ pub const EXT_DEVICE_MEMORY_REPORT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_direct_mode_display.html>
@@ -1196,6 +1387,7 @@ pub const EXT_DIRECT_MODE_DISPLAY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkReleaseDisplayEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_directfb_surface.html>
@@ -1212,6 +1404,10 @@ pub const EXT_DIRECTFB_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateDirectFBSurfaceEXT",
+        "vkGetPhysicalDeviceDirectFBPresentationSupportEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_discard_rectangles.html>
@@ -1228,6 +1424,11 @@ pub const EXT_DISCARD_RECTANGLES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdSetDiscardRectangleEXT",
+        "vkCmdSetDiscardRectangleEnableEXT",
+        "vkCmdSetDiscardRectangleModeEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_display_control.html>
@@ -1244,6 +1445,12 @@ pub const EXT_DISPLAY_CONTROL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkDisplayPowerControlEXT",
+        "vkGetSwapchainCounterEXT",
+        "vkRegisterDeviceEventEXT",
+        "vkRegisterDisplayEventEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_display_surface_counter.html>
@@ -1260,6 +1467,7 @@ pub const EXT_DISPLAY_SURFACE_COUNTER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetPhysicalDeviceSurfaceCapabilities2EXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_dynamic_rendering_unused_attachments.html>
@@ -1276,6 +1484,7 @@ pub const EXT_DYNAMIC_RENDERING_UNUSED_ATTACHMENTS_EXTENSION: Extension = Extens
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_extended_dynamic_state.html>
@@ -1292,6 +1501,20 @@ pub const EXT_EXTENDED_DYNAMIC_STATE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[
+        "vkCmdBindVertexBuffers2EXT",
+        "vkCmdSetCullModeEXT",
+        "vkCmdSetDepthBoundsTestEnableEXT",
+        "vkCmdSetDepthCompareOpEXT",
+        "vkCmdSetDepthTestEnableEXT",
+        "vkCmdSetDepthWriteEnableEXT",
+        "vkCmdSetFrontFaceEXT",
+        "vkCmdSetPrimitiveTopologyEXT",
+        "vkCmdSetScissorWithCountEXT",
+        "vkCmdSetStencilOpEXT",
+        "vkCmdSetStencilTestEnableEXT",
+        "vkCmdSetViewportWithCountEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_extended_dynamic_state2.html>
@@ -1308,6 +1531,13 @@ pub const EXT_EXTENDED_DYNAMIC_STATE2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[
+        "vkCmdSetDepthBiasEnableEXT",
+        "vkCmdSetLogicOpEXT",
+        "vkCmdSetPatchControlPointsEXT",
+        "vkCmdSetPrimitiveRestartEnableEXT",
+        "vkCmdSetRasterizerDiscardEnableEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_extended_dynamic_state3.html>
@@ -1324,6 +1554,39 @@ pub const EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdSetAlphaToCoverageEnableEXT",
+        "vkCmdSetAlphaToOneEnableEXT",
+        "vkCmdSetColorBlendAdvancedEXT",
+        "vkCmdSetColorBlendEnableEXT",
+        "vkCmdSetColorBlendEquationEXT",
+        "vkCmdSetColorWriteMaskEXT",
+        "vkCmdSetConservativeRasterizationModeEXT",
+        "vkCmdSetCoverageModulationModeNV",
+        "vkCmdSetCoverageModulationTableEnableNV",
+        "vkCmdSetCoverageModulationTableNV",
+        "vkCmdSetCoverageReductionModeNV",
+        "vkCmdSetCoverageToColorEnableNV",
+        "vkCmdSetCoverageToColorLocationNV",
+        "vkCmdSetDepthClampEnableEXT",
+        "vkCmdSetDepthClipEnableEXT",
+        "vkCmdSetDepthClipNegativeOneToOneEXT",
+        "vkCmdSetExtraPrimitiveOverestimationSizeEXT",
+        "vkCmdSetLineRasterizationModeEXT",
+        "vkCmdSetLineStippleEnableEXT",
+        "vkCmdSetLogicOpEnableEXT",
+        "vkCmdSetPolygonModeEXT",
+        "vkCmdSetProvokingVertexModeEXT",
+        "vkCmdSetRasterizationSamplesEXT",
+        "vkCmdSetRasterizationStreamEXT",
+        "vkCmdSetRepresentativeFragmentTestEnableNV",
+        "vkCmdSetSampleLocationsEnableEXT",
+        "vkCmdSetSampleMaskEXT",
+        "vkCmdSetShadingRateImageEnableNV",
+        "vkCmdSetTessellationDomainOriginEXT",
+        "vkCmdSetViewportSwizzleNV",
+        "vkCmdSetViewportWScalingEnableNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_external_memory_acquire_unmodified.html>
@@ -1340,6 +1603,7 @@ pub const EXT_EXTERNAL_MEMORY_ACQUIRE_UNMODIFIED_EXTENSION: Extension = Extensio
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_external_memory_dma_buf.html>
@@ -1356,6 +1620,7 @@ pub const EXT_EXTERNAL_MEMORY_DMA_BUF_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_external_memory_host.html>
@@ -1372,6 +1637,7 @@ pub const EXT_EXTERNAL_MEMORY_HOST_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetMemoryHostPointerPropertiesEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_external_memory_metal.html>
@@ -1388,6 +1654,10 @@ pub const EXT_EXTERNAL_MEMORY_METAL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetMemoryMetalHandleEXT",
+        "vkGetMemoryMetalHandlePropertiesEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_filter_cubic.html>
@@ -1404,6 +1674,7 @@ pub const EXT_FILTER_CUBIC_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_fragment_density_map.html>
@@ -1420,6 +1691,7 @@ pub const EXT_FRAGMENT_DENSITY_MAP_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_fragment_density_map2.html>
@@ -1436,6 +1708,7 @@ pub const EXT_FRAGMENT_DENSITY_MAP2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_fragment_density_map_offset.html>
@@ -1452,6 +1725,7 @@ pub const EXT_FRAGMENT_DENSITY_MAP_OFFSET_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdEndRendering2EXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_fragment_shader_interlock.html>
@@ -1468,6 +1742,7 @@ pub const EXT_FRAGMENT_SHADER_INTERLOCK_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_frame_boundary.html>
@@ -1484,6 +1759,7 @@ pub const EXT_FRAME_BOUNDARY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_full_screen_exclusive.html>
@@ -1500,6 +1776,12 @@ pub const EXT_FULL_SCREEN_EXCLUSIVE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkAcquireFullScreenExclusiveModeEXT",
+        "vkGetDeviceGroupSurfacePresentModes2EXT",
+        "vkGetPhysicalDeviceSurfacePresentModes2EXT",
+        "vkReleaseFullScreenExclusiveModeEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_global_priority.html>
@@ -1516,6 +1798,7 @@ pub const EXT_GLOBAL_PRIORITY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_global_priority"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_global_priority_query.html>
@@ -1532,6 +1815,7 @@ pub const EXT_GLOBAL_PRIORITY_QUERY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_global_priority"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_graphics_pipeline_library.html>
@@ -1548,6 +1832,7 @@ pub const EXT_GRAPHICS_PIPELINE_LIBRARY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_hdr_metadata.html>
@@ -1564,6 +1849,7 @@ pub const EXT_HDR_METADATA_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkSetHdrMetadataEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_headless_surface.html>
@@ -1580,6 +1866,7 @@ pub const EXT_HEADLESS_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCreateHeadlessSurfaceEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_host_image_copy.html>
@@ -1596,6 +1883,13 @@ pub const EXT_HOST_IMAGE_COPY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[
+        "vkCopyImageToImageEXT",
+        "vkCopyImageToMemoryEXT",
+        "vkCopyMemoryToImageEXT",
+        "vkGetImageSubresourceLayout2EXT",
+        "vkTransitionImageLayoutEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_host_query_reset.html>
@@ -1612,6 +1906,7 @@ pub const EXT_HOST_QUERY_RESET_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &["vkResetQueryPoolEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_image_2d_view_of_3d.html>
@@ -1628,6 +1923,7 @@ pub const EXT_IMAGE_2D_VIEW_OF_3D_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_image_compression_control.html>
@@ -1644,6 +1940,7 @@ pub const EXT_IMAGE_COMPRESSION_CONTROL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetImageSubresourceLayout2EXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_image_compression_control_swapchain.html>
@@ -1660,6 +1957,7 @@ pub const EXT_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_EXTENSION: Extension = Extensi
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_image_drm_format_modifier.html>
@@ -1676,6 +1974,7 @@ pub const EXT_IMAGE_DRM_FORMAT_MODIFIER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetImageDrmFormatModifierPropertiesEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_image_robustness.html>
@@ -1692,6 +1991,7 @@ pub const EXT_IMAGE_ROBUSTNESS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_image_sliced_view_of_3d.html>
@@ -1708,6 +2008,7 @@ pub const EXT_IMAGE_SLICED_VIEW_OF_3D_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_image_view_min_lod.html>
@@ -1724,6 +2025,7 @@ pub const EXT_IMAGE_VIEW_MIN_LOD_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_index_type_uint8.html>
@@ -1740,6 +2042,7 @@ pub const EXT_INDEX_TYPE_UINT8_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_index_type_uint8"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_inline_uniform_block.html>
@@ -1756,6 +2059,7 @@ pub const EXT_INLINE_UNIFORM_BLOCK_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_layer_settings.html>
@@ -1772,6 +2076,7 @@ pub const EXT_LAYER_SETTINGS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_legacy_dithering.html>
@@ -1788,6 +2093,7 @@ pub const EXT_LEGACY_DITHERING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_legacy_vertex_attributes.html>
@@ -1804,6 +2110,7 @@ pub const EXT_LEGACY_VERTEX_ATTRIBUTES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_line_rasterization.html>
@@ -1820,6 +2127,7 @@ pub const EXT_LINE_RASTERIZATION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_line_rasterization"),
+    commands: &["vkCmdSetLineStippleEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_load_store_op_none.html>
@@ -1836,6 +2144,7 @@ pub const EXT_LOAD_STORE_OP_NONE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_load_store_op_none"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_map_memory_placed.html>
@@ -1852,6 +2161,7 @@ pub const EXT_MAP_MEMORY_PLACED_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_memory_budget.html>
@@ -1868,6 +2178,7 @@ pub const EXT_MEMORY_BUDGET_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_memory_decompression.html>
@@ -1884,6 +2195,10 @@ pub const EXT_MEMORY_DECOMPRESSION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdDecompressMemoryEXT",
+        "vkCmdDecompressMemoryIndirectCountEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_memory_priority.html>
@@ -1900,6 +2215,7 @@ pub const EXT_MEMORY_PRIORITY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_mesh_shader.html>
@@ -1916,6 +2232,11 @@ pub const EXT_MESH_SHADER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdDrawMeshTasksEXT",
+        "vkCmdDrawMeshTasksIndirectCountEXT",
+        "vkCmdDrawMeshTasksIndirectEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_metal_objects.html>
@@ -1932,6 +2253,7 @@ pub const EXT_METAL_OBJECTS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkExportMetalObjectsEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_metal_surface.html>
@@ -1948,6 +2270,7 @@ pub const EXT_METAL_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCreateMetalSurfaceEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_multi_draw.html>
@@ -1964,6 +2287,7 @@ pub const EXT_MULTI_DRAW_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdDrawMultiEXT", "vkCmdDrawMultiIndexedEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_multisampled_render_to_single_sampled.html>
@@ -1980,6 +2304,7 @@ pub const EXT_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_EXTENSION: Extension = Exten
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_mutable_descriptor_type.html>
@@ -1996,6 +2321,7 @@ pub const EXT_MUTABLE_DESCRIPTOR_TYPE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_nested_command_buffer.html>
@@ -2012,6 +2338,7 @@ pub const EXT_NESTED_COMMAND_BUFFER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_non_seamless_cube_map.html>
@@ -2028,6 +2355,7 @@ pub const EXT_NON_SEAMLESS_CUBE_MAP_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_opacity_micromap.html>
@@ -2044,6 +2372,22 @@ pub const EXT_OPACITY_MICROMAP_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkBuildMicromapsEXT",
+        "vkCmdBuildMicromapsEXT",
+        "vkCmdCopyMemoryToMicromapEXT",
+        "vkCmdCopyMicromapEXT",
+        "vkCmdCopyMicromapToMemoryEXT",
+        "vkCmdWriteMicromapsPropertiesEXT",
+        "vkCopyMemoryToMicromapEXT",
+        "vkCopyMicromapEXT",
+        "vkCopyMicromapToMemoryEXT",
+        "vkCreateMicromapEXT",
+        "vkDestroyMicromapEXT",
+        "vkGetDeviceMicromapCompatibilityEXT",
+        "vkGetMicromapBuildSizesEXT",
+        "vkWriteMicromapsPropertiesEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_pageable_device_local_memory.html>
@@ -2060,6 +2404,7 @@ pub const EXT_PAGEABLE_DEVICE_LOCAL_MEMORY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkSetDeviceMemoryPriorityEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_pci_bus_info.html>
@@ -2076,6 +2421,7 @@ pub const EXT_PCI_BUS_INFO_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_physical_device_drm.html>
@@ -2092,6 +2438,7 @@ pub const EXT_PHYSICAL_DEVICE_DRM_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_pipeline_creation_cache_control.html>
@@ -2108,6 +2455,7 @@ pub const EXT_PIPELINE_CREATION_CACHE_CONTROL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_pipeline_creation_feedback.html>
@@ -2124,6 +2472,7 @@ pub const EXT_PIPELINE_CREATION_FEEDBACK_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_pipeline_library_group_handles.html>
@@ -2140,6 +2489,7 @@ pub const EXT_PIPELINE_LIBRARY_GROUP_HANDLES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_pipeline_properties.html>
@@ -2156,6 +2506,7 @@ pub const EXT_PIPELINE_PROPERTIES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetPipelinePropertiesEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_pipeline_protected_access.html>
@@ -2172,6 +2523,7 @@ pub const EXT_PIPELINE_PROTECTED_ACCESS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_pipeline_robustness.html>
@@ -2188,6 +2540,7 @@ pub const EXT_PIPELINE_ROBUSTNESS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_post_depth_coverage.html>
@@ -2204,6 +2557,7 @@ pub const EXT_POST_DEPTH_COVERAGE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_present_mode_fifo_latest_ready.html>
@@ -2220,6 +2574,7 @@ pub const EXT_PRESENT_MODE_FIFO_LATEST_READY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_present_mode_fifo_latest_ready"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_present_timing.html>
@@ -2236,6 +2591,12 @@ pub const EXT_PRESENT_TIMING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetPastPresentationTimingEXT",
+        "vkGetSwapchainTimeDomainPropertiesEXT",
+        "vkGetSwapchainTimingPropertiesEXT",
+        "vkSetSwapchainPresentTimingQueueSizeEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_primitive_topology_list_restart.html>
@@ -2252,6 +2613,7 @@ pub const EXT_PRIMITIVE_TOPOLOGY_LIST_RESTART_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_primitives_generated_query.html>
@@ -2268,6 +2630,7 @@ pub const EXT_PRIMITIVES_GENERATED_QUERY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_private_data.html>
@@ -2284,6 +2647,12 @@ pub const EXT_PRIVATE_DATA_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[
+        "vkCreatePrivateDataSlotEXT",
+        "vkDestroyPrivateDataSlotEXT",
+        "vkGetPrivateDataEXT",
+        "vkSetPrivateDataEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_provoking_vertex.html>
@@ -2300,6 +2669,7 @@ pub const EXT_PROVOKING_VERTEX_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_queue_family_foreign.html>
@@ -2316,6 +2686,7 @@ pub const EXT_QUEUE_FAMILY_FOREIGN_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_rasterization_order_attachment_access.html>
@@ -2332,6 +2703,7 @@ pub const EXT_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_EXTENSION: Extension = Exten
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_ray_tracing_invocation_reorder.html>
@@ -2348,6 +2720,7 @@ pub const EXT_RAY_TRACING_INVOCATION_REORDER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_rgba10x6_formats.html>
@@ -2364,6 +2737,7 @@ pub const EXT_RGBA10X6_FORMATS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_robustness2.html>
@@ -2380,6 +2754,7 @@ pub const EXT_ROBUSTNESS2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_robustness2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_sample_locations.html>
@@ -2396,6 +2771,10 @@ pub const EXT_SAMPLE_LOCATIONS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdSetSampleLocationsEXT",
+        "vkGetPhysicalDeviceMultisamplePropertiesEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_sampler_filter_minmax.html>
@@ -2412,6 +2791,7 @@ pub const EXT_SAMPLER_FILTER_MINMAX_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_scalar_block_layout.html>
@@ -2428,6 +2808,7 @@ pub const EXT_SCALAR_BLOCK_LAYOUT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_separate_stencil_usage.html>
@@ -2444,6 +2825,7 @@ pub const EXT_SEPARATE_STENCIL_USAGE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_64bit_indexing.html>
@@ -2460,6 +2842,7 @@ pub const EXT_SHADER_64BIT_INDEXING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_atomic_float.html>
@@ -2476,6 +2859,7 @@ pub const EXT_SHADER_ATOMIC_FLOAT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_atomic_float2.html>
@@ -2492,6 +2876,7 @@ pub const EXT_SHADER_ATOMIC_FLOAT2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_demote_to_helper_invocation.html>
@@ -2508,6 +2893,7 @@ pub const EXT_SHADER_DEMOTE_TO_HELPER_INVOCATION_EXTENSION: Extension = Extensio
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_float8.html>
@@ -2524,6 +2910,7 @@ pub const EXT_SHADER_FLOAT8_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_image_atomic_int64.html>
@@ -2540,6 +2927,7 @@ pub const EXT_SHADER_IMAGE_ATOMIC_INT64_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_long_vector.html>
@@ -2556,6 +2944,7 @@ pub const EXT_SHADER_LONG_VECTOR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_module_identifier.html>
@@ -2572,6 +2961,10 @@ pub const EXT_SHADER_MODULE_IDENTIFIER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetShaderModuleCreateInfoIdentifierEXT",
+        "vkGetShaderModuleIdentifierEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_object.html>
@@ -2588,6 +2981,62 @@ pub const EXT_SHADER_OBJECT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdBindShadersEXT",
+        "vkCmdBindVertexBuffers2EXT",
+        "vkCmdSetAlphaToCoverageEnableEXT",
+        "vkCmdSetAlphaToOneEnableEXT",
+        "vkCmdSetColorBlendAdvancedEXT",
+        "vkCmdSetColorBlendEnableEXT",
+        "vkCmdSetColorBlendEquationEXT",
+        "vkCmdSetColorWriteMaskEXT",
+        "vkCmdSetConservativeRasterizationModeEXT",
+        "vkCmdSetCoverageModulationModeNV",
+        "vkCmdSetCoverageModulationTableEnableNV",
+        "vkCmdSetCoverageModulationTableNV",
+        "vkCmdSetCoverageReductionModeNV",
+        "vkCmdSetCoverageToColorEnableNV",
+        "vkCmdSetCoverageToColorLocationNV",
+        "vkCmdSetCullModeEXT",
+        "vkCmdSetDepthBiasEnableEXT",
+        "vkCmdSetDepthBoundsTestEnableEXT",
+        "vkCmdSetDepthClampEnableEXT",
+        "vkCmdSetDepthClampRangeEXT",
+        "vkCmdSetDepthClipEnableEXT",
+        "vkCmdSetDepthClipNegativeOneToOneEXT",
+        "vkCmdSetDepthCompareOpEXT",
+        "vkCmdSetDepthTestEnableEXT",
+        "vkCmdSetDepthWriteEnableEXT",
+        "vkCmdSetExtraPrimitiveOverestimationSizeEXT",
+        "vkCmdSetFrontFaceEXT",
+        "vkCmdSetLineRasterizationModeEXT",
+        "vkCmdSetLineStippleEnableEXT",
+        "vkCmdSetLogicOpEXT",
+        "vkCmdSetLogicOpEnableEXT",
+        "vkCmdSetPatchControlPointsEXT",
+        "vkCmdSetPolygonModeEXT",
+        "vkCmdSetPrimitiveRestartEnableEXT",
+        "vkCmdSetPrimitiveTopologyEXT",
+        "vkCmdSetProvokingVertexModeEXT",
+        "vkCmdSetRasterizationSamplesEXT",
+        "vkCmdSetRasterizationStreamEXT",
+        "vkCmdSetRasterizerDiscardEnableEXT",
+        "vkCmdSetRepresentativeFragmentTestEnableNV",
+        "vkCmdSetSampleLocationsEnableEXT",
+        "vkCmdSetSampleMaskEXT",
+        "vkCmdSetScissorWithCountEXT",
+        "vkCmdSetShadingRateImageEnableNV",
+        "vkCmdSetStencilOpEXT",
+        "vkCmdSetStencilTestEnableEXT",
+        "vkCmdSetTessellationDomainOriginEXT",
+        "vkCmdSetVertexInputEXT",
+        "vkCmdSetViewportSwizzleNV",
+        "vkCmdSetViewportWScalingEnableNV",
+        "vkCmdSetViewportWithCountEXT",
+        "vkCreateShadersEXT",
+        "vkDestroyShaderEXT",
+        "vkGetShaderBinaryDataEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_replicated_composites.html>
@@ -2604,6 +3053,7 @@ pub const EXT_SHADER_REPLICATED_COMPOSITES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_stencil_export.html>
@@ -2620,6 +3070,7 @@ pub const EXT_SHADER_STENCIL_EXPORT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_subgroup_ballot.html>
@@ -2637,6 +3088,7 @@ pub const EXT_SHADER_SUBGROUP_BALLOT_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_VERSION_1_2"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_subgroup_partitioned.html>
@@ -2653,6 +3105,7 @@ pub const EXT_SHADER_SUBGROUP_PARTITIONED_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_subgroup_vote.html>
@@ -2670,6 +3123,7 @@ pub const EXT_SHADER_SUBGROUP_VOTE_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_VERSION_1_1"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_tile_image.html>
@@ -2686,6 +3140,7 @@ pub const EXT_SHADER_TILE_IMAGE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_uniform_buffer_unsized_array.html>
@@ -2702,6 +3157,7 @@ pub const EXT_SHADER_UNIFORM_BUFFER_UNSIZED_ARRAY_EXTENSION: Extension = Extensi
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_shader_viewport_index_layer.html>
@@ -2718,6 +3174,7 @@ pub const EXT_SHADER_VIEWPORT_INDEX_LAYER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_subgroup_size_control.html>
@@ -2734,6 +3191,7 @@ pub const EXT_SUBGROUP_SIZE_CONTROL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_subpass_merge_feedback.html>
@@ -2750,6 +3208,7 @@ pub const EXT_SUBPASS_MERGE_FEEDBACK_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_surface_maintenance1.html>
@@ -2766,6 +3225,7 @@ pub const EXT_SURFACE_MAINTENANCE1_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_surface_maintenance1"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_swapchain_colorspace.html>
@@ -2782,6 +3242,7 @@ pub const EXT_SWAPCHAIN_COLORSPACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_swapchain_maintenance1.html>
@@ -2798,6 +3259,7 @@ pub const EXT_SWAPCHAIN_MAINTENANCE1_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_swapchain_maintenance1"),
+    commands: &["vkReleaseSwapchainImagesEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_texel_buffer_alignment.html>
@@ -2814,6 +3276,7 @@ pub const EXT_TEXEL_BUFFER_ALIGNMENT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_texture_compression_astc_3d.html>
@@ -2830,6 +3293,7 @@ pub const EXT_TEXTURE_COMPRESSION_ASTC_3D_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_texture_compression_astc_hdr.html>
@@ -2846,6 +3310,7 @@ pub const EXT_TEXTURE_COMPRESSION_ASTC_HDR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_tooling_info.html>
@@ -2862,6 +3327,7 @@ pub const EXT_TOOLING_INFO_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &["vkGetPhysicalDeviceToolPropertiesEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_transform_feedback.html>
@@ -2878,6 +3344,14 @@ pub const EXT_TRANSFORM_FEEDBACK_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdBeginQueryIndexedEXT",
+        "vkCmdBeginTransformFeedbackEXT",
+        "vkCmdBindTransformFeedbackBuffersEXT",
+        "vkCmdDrawIndirectByteCountEXT",
+        "vkCmdEndQueryIndexedEXT",
+        "vkCmdEndTransformFeedbackEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_validation_cache.html>
@@ -2894,6 +3368,12 @@ pub const EXT_VALIDATION_CACHE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateValidationCacheEXT",
+        "vkDestroyValidationCacheEXT",
+        "vkGetValidationCacheDataEXT",
+        "vkMergeValidationCachesEXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_validation_features.html>
@@ -2911,6 +3391,7 @@ pub const EXT_VALIDATION_FEATURES_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_EXT_layer_settings"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_validation_flags.html>
@@ -2928,6 +3409,7 @@ pub const EXT_VALIDATION_FLAGS_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_EXT_layer_settings"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_vertex_attribute_divisor.html>
@@ -2944,6 +3426,7 @@ pub const EXT_VERTEX_ATTRIBUTE_DIVISOR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_vertex_attribute_divisor"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_vertex_attribute_robustness.html>
@@ -2960,6 +3443,7 @@ pub const EXT_VERTEX_ATTRIBUTE_ROBUSTNESS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_maintenance9"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_vertex_input_dynamic_state.html>
@@ -2976,6 +3460,7 @@ pub const EXT_VERTEX_INPUT_DYNAMIC_STATE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdSetVertexInputEXT"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_ycbcr_2plane_444_formats.html>
@@ -2992,6 +3477,7 @@ pub const EXT_YCBCR_2PLANE_444_FORMATS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_ycbcr_image_arrays.html>
@@ -3008,6 +3494,7 @@ pub const EXT_YCBCR_IMAGE_ARRAYS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_EXT_zero_initialize_device_memory.html>
@@ -3024,6 +3511,7 @@ pub const EXT_ZERO_INITIALIZE_DEVICE_MEMORY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_FUCHSIA_buffer_collection.html>
@@ -3040,6 +3528,13 @@ pub const FUCHSIA_BUFFER_COLLECTION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateBufferCollectionFUCHSIA",
+        "vkDestroyBufferCollectionFUCHSIA",
+        "vkGetBufferCollectionPropertiesFUCHSIA",
+        "vkSetBufferCollectionBufferConstraintsFUCHSIA",
+        "vkSetBufferCollectionImageConstraintsFUCHSIA",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_FUCHSIA_external_memory.html>
@@ -3056,6 +3551,10 @@ pub const FUCHSIA_EXTERNAL_MEMORY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetMemoryZirconHandleFUCHSIA",
+        "vkGetMemoryZirconHandlePropertiesFUCHSIA",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_FUCHSIA_external_semaphore.html>
@@ -3072,6 +3571,10 @@ pub const FUCHSIA_EXTERNAL_SEMAPHORE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetSemaphoreZirconHandleFUCHSIA",
+        "vkImportSemaphoreZirconHandleFUCHSIA",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_FUCHSIA_imagepipe_surface.html>
@@ -3088,6 +3591,7 @@ pub const FUCHSIA_IMAGEPIPE_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCreateImagePipeSurfaceFUCHSIA"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_GGP_frame_token.html>
@@ -3104,6 +3608,7 @@ pub const GGP_FRAME_TOKEN_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_GGP_stream_descriptor_surface.html>
@@ -3120,6 +3625,7 @@ pub const GGP_STREAM_DESCRIPTOR_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCreateStreamDescriptorSurfaceGGP"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_GOOGLE_decorate_string.html>
@@ -3136,6 +3642,7 @@ pub const GOOGLE_DECORATE_STRING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_GOOGLE_display_timing.html>
@@ -3152,6 +3659,10 @@ pub const GOOGLE_DISPLAY_TIMING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetPastPresentationTimingGOOGLE",
+        "vkGetRefreshCycleDurationGOOGLE",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_GOOGLE_hlsl_functionality1.html>
@@ -3168,6 +3679,7 @@ pub const GOOGLE_HLSL_FUNCTIONALITY1_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_GOOGLE_surfaceless_query.html>
@@ -3184,6 +3696,7 @@ pub const GOOGLE_SURFACELESS_QUERY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_GOOGLE_user_type.html>
@@ -3200,6 +3713,7 @@ pub const GOOGLE_USER_TYPE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_HUAWEI_cluster_culling_shader.html>
@@ -3216,6 +3730,7 @@ pub const HUAWEI_CLUSTER_CULLING_SHADER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdDrawClusterHUAWEI", "vkCmdDrawClusterIndirectHUAWEI"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_HUAWEI_hdr_vivid.html>
@@ -3232,6 +3747,7 @@ pub const HUAWEI_HDR_VIVID_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_HUAWEI_invocation_mask.html>
@@ -3248,6 +3764,7 @@ pub const HUAWEI_INVOCATION_MASK_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdBindInvocationMaskHUAWEI"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_HUAWEI_subpass_shading.html>
@@ -3264,6 +3781,10 @@ pub const HUAWEI_SUBPASS_SHADING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdSubpassShadingHUAWEI",
+        "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_IMG_filter_cubic.html>
@@ -3280,6 +3801,7 @@ pub const IMG_FILTER_CUBIC_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_IMG_format_pvrtc.html>
@@ -3297,6 +3819,7 @@ pub const IMG_FORMAT_PVRTC_EXTENSION: Extension = Extension {
     deprecated_by: Some(""),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_IMG_relaxed_line_rasterization.html>
@@ -3313,6 +3836,7 @@ pub const IMG_RELAXED_LINE_RASTERIZATION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_INTEL_performance_query.html>
@@ -3329,6 +3853,17 @@ pub const INTEL_PERFORMANCE_QUERY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkAcquirePerformanceConfigurationINTEL",
+        "vkCmdSetPerformanceMarkerINTEL",
+        "vkCmdSetPerformanceOverrideINTEL",
+        "vkCmdSetPerformanceStreamMarkerINTEL",
+        "vkGetPerformanceParameterINTEL",
+        "vkInitializePerformanceApiINTEL",
+        "vkQueueSetPerformanceConfigurationINTEL",
+        "vkReleasePerformanceConfigurationINTEL",
+        "vkUninitializePerformanceApiINTEL",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_INTEL_shader_integer_functions2.html>
@@ -3345,6 +3880,7 @@ pub const INTEL_SHADER_INTEGER_FUNCTIONS2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_16bit_storage.html>
@@ -3361,6 +3897,7 @@ pub const KHR_16BIT_STORAGE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_8bit_storage.html>
@@ -3377,6 +3914,7 @@ pub const KHR_8BIT_STORAGE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_acceleration_structure.html>
@@ -3393,6 +3931,24 @@ pub const KHR_ACCELERATION_STRUCTURE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkBuildAccelerationStructuresKHR",
+        "vkCmdBuildAccelerationStructuresIndirectKHR",
+        "vkCmdBuildAccelerationStructuresKHR",
+        "vkCmdCopyAccelerationStructureKHR",
+        "vkCmdCopyAccelerationStructureToMemoryKHR",
+        "vkCmdCopyMemoryToAccelerationStructureKHR",
+        "vkCmdWriteAccelerationStructuresPropertiesKHR",
+        "vkCopyAccelerationStructureKHR",
+        "vkCopyAccelerationStructureToMemoryKHR",
+        "vkCopyMemoryToAccelerationStructureKHR",
+        "vkCreateAccelerationStructureKHR",
+        "vkDestroyAccelerationStructureKHR",
+        "vkGetAccelerationStructureBuildSizesKHR",
+        "vkGetAccelerationStructureDeviceAddressKHR",
+        "vkGetDeviceAccelerationStructureCompatibilityKHR",
+        "vkWriteAccelerationStructuresPropertiesKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_android_surface.html>
@@ -3409,6 +3965,7 @@ pub const KHR_ANDROID_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCreateAndroidSurfaceKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_bind_memory2.html>
@@ -3425,6 +3982,7 @@ pub const KHR_BIND_MEMORY2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &["vkBindBufferMemory2KHR", "vkBindImageMemory2KHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_buffer_device_address.html>
@@ -3441,6 +3999,11 @@ pub const KHR_BUFFER_DEVICE_ADDRESS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[
+        "vkGetBufferDeviceAddressKHR",
+        "vkGetBufferOpaqueCaptureAddressKHR",
+        "vkGetDeviceMemoryOpaqueCaptureAddressKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_calibrated_timestamps.html>
@@ -3457,6 +4020,10 @@ pub const KHR_CALIBRATED_TIMESTAMPS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetCalibratedTimestampsKHR",
+        "vkGetPhysicalDeviceCalibrateableTimeDomainsKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_compute_shader_derivatives.html>
@@ -3473,6 +4040,7 @@ pub const KHR_COMPUTE_SHADER_DERIVATIVES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_cooperative_matrix.html>
@@ -3489,6 +4057,7 @@ pub const KHR_COOPERATIVE_MATRIX_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetPhysicalDeviceCooperativeMatrixPropertiesKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_copy_commands2.html>
@@ -3505,6 +4074,14 @@ pub const KHR_COPY_COMMANDS2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[
+        "vkCmdBlitImage2KHR",
+        "vkCmdCopyBuffer2KHR",
+        "vkCmdCopyBufferToImage2KHR",
+        "vkCmdCopyImage2KHR",
+        "vkCmdCopyImageToBuffer2KHR",
+        "vkCmdResolveImage2KHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_copy_memory_indirect.html>
@@ -3521,6 +4098,10 @@ pub const KHR_COPY_MEMORY_INDIRECT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdCopyMemoryIndirectKHR",
+        "vkCmdCopyMemoryToImageIndirectKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_create_renderpass2.html>
@@ -3537,6 +4118,12 @@ pub const KHR_CREATE_RENDERPASS2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[
+        "vkCmdBeginRenderPass2KHR",
+        "vkCmdEndRenderPass2KHR",
+        "vkCmdNextSubpass2KHR",
+        "vkCreateRenderPass2KHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_dedicated_allocation.html>
@@ -3553,6 +4140,7 @@ pub const KHR_DEDICATED_ALLOCATION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_deferred_host_operations.html>
@@ -3569,6 +4157,13 @@ pub const KHR_DEFERRED_HOST_OPERATIONS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateDeferredOperationKHR",
+        "vkDeferredOperationJoinKHR",
+        "vkDestroyDeferredOperationKHR",
+        "vkGetDeferredOperationMaxConcurrencyKHR",
+        "vkGetDeferredOperationResultKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_depth_clamp_zero_one.html>
@@ -3585,6 +4180,7 @@ pub const KHR_DEPTH_CLAMP_ZERO_ONE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_depth_stencil_resolve.html>
@@ -3601,6 +4197,7 @@ pub const KHR_DEPTH_STENCIL_RESOLVE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_descriptor_update_template.html>
@@ -3617,6 +4214,12 @@ pub const KHR_DESCRIPTOR_UPDATE_TEMPLATE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[
+        "vkCmdPushDescriptorSetWithTemplateKHR",
+        "vkCreateDescriptorUpdateTemplateKHR",
+        "vkDestroyDescriptorUpdateTemplateKHR",
+        "vkUpdateDescriptorSetWithTemplateKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_device_group.html>
@@ -3633,6 +4236,15 @@ pub const KHR_DEVICE_GROUP_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[
+        "vkAcquireNextImage2KHR",
+        "vkCmdDispatchBaseKHR",
+        "vkCmdSetDeviceMaskKHR",
+        "vkGetDeviceGroupPeerMemoryFeaturesKHR",
+        "vkGetDeviceGroupPresentCapabilitiesKHR",
+        "vkGetDeviceGroupSurfacePresentModesKHR",
+        "vkGetPhysicalDevicePresentRectanglesKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_device_group_creation.html>
@@ -3649,6 +4261,7 @@ pub const KHR_DEVICE_GROUP_CREATION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &["vkEnumeratePhysicalDeviceGroupsKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_display.html>
@@ -3665,6 +4278,15 @@ pub const KHR_DISPLAY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateDisplayModeKHR",
+        "vkCreateDisplayPlaneSurfaceKHR",
+        "vkGetDisplayModePropertiesKHR",
+        "vkGetDisplayPlaneCapabilitiesKHR",
+        "vkGetDisplayPlaneSupportedDisplaysKHR",
+        "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+        "vkGetPhysicalDeviceDisplayPropertiesKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_display_swapchain.html>
@@ -3681,6 +4303,7 @@ pub const KHR_DISPLAY_SWAPCHAIN_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCreateSharedSwapchainsKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_draw_indirect_count.html>
@@ -3697,6 +4320,10 @@ pub const KHR_DRAW_INDIRECT_COUNT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[
+        "vkCmdDrawIndexedIndirectCountKHR",
+        "vkCmdDrawIndirectCountKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_driver_properties.html>
@@ -3713,6 +4340,7 @@ pub const KHR_DRIVER_PROPERTIES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_dynamic_rendering.html>
@@ -3729,6 +4357,7 @@ pub const KHR_DYNAMIC_RENDERING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &["vkCmdBeginRenderingKHR", "vkCmdEndRenderingKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_dynamic_rendering_local_read.html>
@@ -3745,6 +4374,10 @@ pub const KHR_DYNAMIC_RENDERING_LOCAL_READ_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[
+        "vkCmdSetRenderingAttachmentLocationsKHR",
+        "vkCmdSetRenderingInputAttachmentIndicesKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_external_fence.html>
@@ -3761,6 +4394,7 @@ pub const KHR_EXTERNAL_FENCE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_external_fence_capabilities.html>
@@ -3777,6 +4411,7 @@ pub const KHR_EXTERNAL_FENCE_CAPABILITIES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &["vkGetPhysicalDeviceExternalFencePropertiesKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_external_fence_fd.html>
@@ -3793,6 +4428,7 @@ pub const KHR_EXTERNAL_FENCE_FD_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetFenceFdKHR", "vkImportFenceFdKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_external_fence_win32.html>
@@ -3809,6 +4445,7 @@ pub const KHR_EXTERNAL_FENCE_WIN32_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetFenceWin32HandleKHR", "vkImportFenceWin32HandleKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_external_memory.html>
@@ -3825,6 +4462,7 @@ pub const KHR_EXTERNAL_MEMORY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_external_memory_capabilities.html>
@@ -3841,6 +4479,7 @@ pub const KHR_EXTERNAL_MEMORY_CAPABILITIES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &["vkGetPhysicalDeviceExternalBufferPropertiesKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_external_memory_fd.html>
@@ -3857,6 +4496,7 @@ pub const KHR_EXTERNAL_MEMORY_FD_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetMemoryFdKHR", "vkGetMemoryFdPropertiesKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_external_memory_win32.html>
@@ -3873,6 +4513,10 @@ pub const KHR_EXTERNAL_MEMORY_WIN32_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetMemoryWin32HandleKHR",
+        "vkGetMemoryWin32HandlePropertiesKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_external_semaphore.html>
@@ -3889,6 +4533,7 @@ pub const KHR_EXTERNAL_SEMAPHORE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_external_semaphore_capabilities.html>
@@ -3905,6 +4550,7 @@ pub const KHR_EXTERNAL_SEMAPHORE_CAPABILITIES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &["vkGetPhysicalDeviceExternalSemaphorePropertiesKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_external_semaphore_fd.html>
@@ -3921,6 +4567,7 @@ pub const KHR_EXTERNAL_SEMAPHORE_FD_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetSemaphoreFdKHR", "vkImportSemaphoreFdKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_external_semaphore_win32.html>
@@ -3937,6 +4584,10 @@ pub const KHR_EXTERNAL_SEMAPHORE_WIN32_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetSemaphoreWin32HandleKHR",
+        "vkImportSemaphoreWin32HandleKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_format_feature_flags2.html>
@@ -3953,6 +4604,7 @@ pub const KHR_FORMAT_FEATURE_FLAGS2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_fragment_shader_barycentric.html>
@@ -3969,6 +4621,7 @@ pub const KHR_FRAGMENT_SHADER_BARYCENTRIC_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_fragment_shading_rate.html>
@@ -3985,6 +4638,10 @@ pub const KHR_FRAGMENT_SHADING_RATE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdSetFragmentShadingRateKHR",
+        "vkGetPhysicalDeviceFragmentShadingRatesKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_get_display_properties2.html>
@@ -4001,6 +4658,12 @@ pub const KHR_GET_DISPLAY_PROPERTIES2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetDisplayModeProperties2KHR",
+        "vkGetDisplayPlaneCapabilities2KHR",
+        "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+        "vkGetPhysicalDeviceDisplayProperties2KHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_get_memory_requirements2.html>
@@ -4017,6 +4680,11 @@ pub const KHR_GET_MEMORY_REQUIREMENTS2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[
+        "vkGetBufferMemoryRequirements2KHR",
+        "vkGetImageMemoryRequirements2KHR",
+        "vkGetImageSparseMemoryRequirements2KHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_get_physical_device_properties2.html>
@@ -4033,6 +4701,15 @@ pub const KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[
+        "vkGetPhysicalDeviceFeatures2KHR",
+        "vkGetPhysicalDeviceFormatProperties2KHR",
+        "vkGetPhysicalDeviceImageFormatProperties2KHR",
+        "vkGetPhysicalDeviceMemoryProperties2KHR",
+        "vkGetPhysicalDeviceProperties2KHR",
+        "vkGetPhysicalDeviceQueueFamilyProperties2KHR",
+        "vkGetPhysicalDeviceSparseImageFormatProperties2KHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_get_surface_capabilities2.html>
@@ -4049,6 +4726,10 @@ pub const KHR_GET_SURFACE_CAPABILITIES2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetPhysicalDeviceSurfaceCapabilities2KHR",
+        "vkGetPhysicalDeviceSurfaceFormats2KHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_global_priority.html>
@@ -4065,6 +4746,7 @@ pub const KHR_GLOBAL_PRIORITY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_image_format_list.html>
@@ -4081,6 +4763,7 @@ pub const KHR_IMAGE_FORMAT_LIST_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_imageless_framebuffer.html>
@@ -4097,6 +4780,7 @@ pub const KHR_IMAGELESS_FRAMEBUFFER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_incremental_present.html>
@@ -4113,6 +4797,7 @@ pub const KHR_INCREMENTAL_PRESENT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_index_type_uint8.html>
@@ -4129,6 +4814,7 @@ pub const KHR_INDEX_TYPE_UINT8_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_internally_synchronized_queues.html>
@@ -4145,6 +4831,7 @@ pub const KHR_INTERNALLY_SYNCHRONIZED_QUEUES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_line_rasterization.html>
@@ -4161,6 +4848,7 @@ pub const KHR_LINE_RASTERIZATION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &["vkCmdSetLineStippleKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_load_store_op_none.html>
@@ -4177,6 +4865,7 @@ pub const KHR_LOAD_STORE_OP_NONE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_maintenance1.html>
@@ -4193,6 +4882,7 @@ pub const KHR_MAINTENANCE1_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &["vkTrimCommandPoolKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_maintenance10.html>
@@ -4209,6 +4899,7 @@ pub const KHR_MAINTENANCE10_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdEndRendering2KHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_maintenance2.html>
@@ -4225,6 +4916,7 @@ pub const KHR_MAINTENANCE2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_maintenance3.html>
@@ -4241,6 +4933,7 @@ pub const KHR_MAINTENANCE3_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &["vkGetDescriptorSetLayoutSupportKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_maintenance4.html>
@@ -4257,6 +4950,11 @@ pub const KHR_MAINTENANCE4_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[
+        "vkGetDeviceBufferMemoryRequirementsKHR",
+        "vkGetDeviceImageMemoryRequirementsKHR",
+        "vkGetDeviceImageSparseMemoryRequirementsKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_maintenance5.html>
@@ -4273,6 +4971,12 @@ pub const KHR_MAINTENANCE5_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[
+        "vkCmdBindIndexBuffer2KHR",
+        "vkGetDeviceImageSubresourceLayoutKHR",
+        "vkGetImageSubresourceLayout2KHR",
+        "vkGetRenderingAreaGranularityKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_maintenance6.html>
@@ -4289,6 +4993,14 @@ pub const KHR_MAINTENANCE6_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[
+        "vkCmdBindDescriptorBufferEmbeddedSamplers2EXT",
+        "vkCmdBindDescriptorSets2KHR",
+        "vkCmdPushConstants2KHR",
+        "vkCmdPushDescriptorSet2KHR",
+        "vkCmdPushDescriptorSetWithTemplate2KHR",
+        "vkCmdSetDescriptorBufferOffsets2EXT",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_maintenance7.html>
@@ -4305,6 +5017,7 @@ pub const KHR_MAINTENANCE7_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_maintenance8.html>
@@ -4321,6 +5034,7 @@ pub const KHR_MAINTENANCE8_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_maintenance9.html>
@@ -4337,6 +5051,7 @@ pub const KHR_MAINTENANCE9_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_map_memory2.html>
@@ -4353,6 +5068,7 @@ pub const KHR_MAP_MEMORY2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &["vkMapMemory2KHR", "vkUnmapMemory2KHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_multiview.html>
@@ -4369,6 +5085,7 @@ pub const KHR_MULTIVIEW_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_object_refresh.html>
@@ -4385,6 +5102,10 @@ pub const KHR_OBJECT_REFRESH_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdRefreshObjectsKHR",
+        "vkGetPhysicalDeviceRefreshableObjectTypesKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_performance_query.html>
@@ -4401,6 +5122,12 @@ pub const KHR_PERFORMANCE_QUERY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkAcquireProfilingLockKHR",
+        "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR",
+        "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR",
+        "vkReleaseProfilingLockKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_pipeline_binary.html>
@@ -4417,6 +5144,13 @@ pub const KHR_PIPELINE_BINARY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreatePipelineBinariesKHR",
+        "vkDestroyPipelineBinaryKHR",
+        "vkGetPipelineBinaryDataKHR",
+        "vkGetPipelineKeyKHR",
+        "vkReleaseCapturedPipelineDataKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_pipeline_executable_properties.html>
@@ -4433,6 +5167,11 @@ pub const KHR_PIPELINE_EXECUTABLE_PROPERTIES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetPipelineExecutableInternalRepresentationsKHR",
+        "vkGetPipelineExecutablePropertiesKHR",
+        "vkGetPipelineExecutableStatisticsKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_pipeline_library.html>
@@ -4449,6 +5188,7 @@ pub const KHR_PIPELINE_LIBRARY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_portability_enumeration.html>
@@ -4465,6 +5205,7 @@ pub const KHR_PORTABILITY_ENUMERATION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_portability_subset.html>
@@ -4489,6 +5230,7 @@ pub const KHR_PORTABILITY_SUBSET_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_present_id.html>
@@ -4505,6 +5247,7 @@ pub const KHR_PRESENT_ID_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_present_id2.html>
@@ -4521,6 +5264,7 @@ pub const KHR_PRESENT_ID2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_present_mode_fifo_latest_ready.html>
@@ -4537,6 +5281,7 @@ pub const KHR_PRESENT_MODE_FIFO_LATEST_READY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_present_wait.html>
@@ -4553,6 +5298,7 @@ pub const KHR_PRESENT_WAIT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkWaitForPresentKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_present_wait2.html>
@@ -4569,6 +5315,7 @@ pub const KHR_PRESENT_WAIT2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkWaitForPresent2KHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_push_descriptor.html>
@@ -4585,6 +5332,10 @@ pub const KHR_PUSH_DESCRIPTOR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[
+        "vkCmdPushDescriptorSetKHR",
+        "vkCmdPushDescriptorSetWithTemplateKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_ray_query.html>
@@ -4601,6 +5352,7 @@ pub const KHR_RAY_QUERY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_ray_tracing_maintenance1.html>
@@ -4617,6 +5369,7 @@ pub const KHR_RAY_TRACING_MAINTENANCE1_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdTraceRaysIndirect2KHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_ray_tracing_pipeline.html>
@@ -4633,6 +5386,15 @@ pub const KHR_RAY_TRACING_PIPELINE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdSetRayTracingPipelineStackSizeKHR",
+        "vkCmdTraceRaysIndirectKHR",
+        "vkCmdTraceRaysKHR",
+        "vkCreateRayTracingPipelinesKHR",
+        "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR",
+        "vkGetRayTracingShaderGroupHandlesKHR",
+        "vkGetRayTracingShaderGroupStackSizeKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_ray_tracing_position_fetch.html>
@@ -4649,6 +5411,7 @@ pub const KHR_RAY_TRACING_POSITION_FETCH_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_relaxed_block_layout.html>
@@ -4665,6 +5428,7 @@ pub const KHR_RELAXED_BLOCK_LAYOUT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_robustness2.html>
@@ -4681,6 +5445,7 @@ pub const KHR_ROBUSTNESS2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_sampler_mirror_clamp_to_edge.html>
@@ -4697,6 +5462,7 @@ pub const KHR_SAMPLER_MIRROR_CLAMP_TO_EDGE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_sampler_ycbcr_conversion.html>
@@ -4713,6 +5479,10 @@ pub const KHR_SAMPLER_YCBCR_CONVERSION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[
+        "vkCreateSamplerYcbcrConversionKHR",
+        "vkDestroySamplerYcbcrConversionKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_separate_depth_stencil_layouts.html>
@@ -4729,6 +5499,7 @@ pub const KHR_SEPARATE_DEPTH_STENCIL_LAYOUTS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_atomic_int64.html>
@@ -4745,6 +5516,7 @@ pub const KHR_SHADER_ATOMIC_INT64_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_bfloat16.html>
@@ -4761,6 +5533,7 @@ pub const KHR_SHADER_BFLOAT16_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_clock.html>
@@ -4777,6 +5550,7 @@ pub const KHR_SHADER_CLOCK_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_draw_parameters.html>
@@ -4793,6 +5567,7 @@ pub const KHR_SHADER_DRAW_PARAMETERS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_expect_assume.html>
@@ -4809,6 +5584,7 @@ pub const KHR_SHADER_EXPECT_ASSUME_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_float16_int8.html>
@@ -4825,6 +5601,7 @@ pub const KHR_SHADER_FLOAT16_INT8_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_float_controls.html>
@@ -4841,6 +5618,7 @@ pub const KHR_SHADER_FLOAT_CONTROLS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_float_controls2.html>
@@ -4857,6 +5635,7 @@ pub const KHR_SHADER_FLOAT_CONTROLS2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_fma.html>
@@ -4873,6 +5652,7 @@ pub const KHR_SHADER_FMA_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_integer_dot_product.html>
@@ -4889,6 +5669,7 @@ pub const KHR_SHADER_INTEGER_DOT_PRODUCT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_maximal_reconvergence.html>
@@ -4905,6 +5686,7 @@ pub const KHR_SHADER_MAXIMAL_RECONVERGENCE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_non_semantic_info.html>
@@ -4921,6 +5703,7 @@ pub const KHR_SHADER_NON_SEMANTIC_INFO_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_quad_control.html>
@@ -4937,6 +5720,7 @@ pub const KHR_SHADER_QUAD_CONTROL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_relaxed_extended_instruction.html>
@@ -4953,6 +5737,7 @@ pub const KHR_SHADER_RELAXED_EXTENDED_INSTRUCTION_EXTENSION: Extension = Extensi
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_subgroup_extended_types.html>
@@ -4969,6 +5754,7 @@ pub const KHR_SHADER_SUBGROUP_EXTENDED_TYPES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_subgroup_rotate.html>
@@ -4985,6 +5771,7 @@ pub const KHR_SHADER_SUBGROUP_ROTATE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_subgroup_uniform_control_flow.html>
@@ -5001,6 +5788,7 @@ pub const KHR_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_EXTENSION: Extension = Extens
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_terminate_invocation.html>
@@ -5017,6 +5805,7 @@ pub const KHR_SHADER_TERMINATE_INVOCATION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shader_untyped_pointers.html>
@@ -5033,6 +5822,7 @@ pub const KHR_SHADER_UNTYPED_POINTERS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_shared_presentable_image.html>
@@ -5049,6 +5839,7 @@ pub const KHR_SHARED_PRESENTABLE_IMAGE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetSwapchainStatusKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_spirv_1_4.html>
@@ -5065,6 +5856,7 @@ pub const KHR_SPIRV_1_4_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_storage_buffer_storage_class.html>
@@ -5081,6 +5873,7 @@ pub const KHR_STORAGE_BUFFER_STORAGE_CLASS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_surface.html>
@@ -5097,6 +5890,13 @@ pub const KHR_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkDestroySurfaceKHR",
+        "vkGetPhysicalDeviceSurfaceCapabilitiesKHR",
+        "vkGetPhysicalDeviceSurfaceFormatsKHR",
+        "vkGetPhysicalDeviceSurfacePresentModesKHR",
+        "vkGetPhysicalDeviceSurfaceSupportKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_surface_maintenance1.html>
@@ -5113,6 +5913,7 @@ pub const KHR_SURFACE_MAINTENANCE1_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_surface_protected_capabilities.html>
@@ -5129,6 +5930,7 @@ pub const KHR_SURFACE_PROTECTED_CAPABILITIES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_swapchain.html>
@@ -5145,6 +5947,17 @@ pub const KHR_SWAPCHAIN_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkAcquireNextImage2KHR",
+        "vkAcquireNextImageKHR",
+        "vkCreateSwapchainKHR",
+        "vkDestroySwapchainKHR",
+        "vkGetDeviceGroupPresentCapabilitiesKHR",
+        "vkGetDeviceGroupSurfacePresentModesKHR",
+        "vkGetPhysicalDevicePresentRectanglesKHR",
+        "vkGetSwapchainImagesKHR",
+        "vkQueuePresentKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_swapchain_maintenance1.html>
@@ -5161,6 +5974,7 @@ pub const KHR_SWAPCHAIN_MAINTENANCE1_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkReleaseSwapchainImagesKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_swapchain_mutable_format.html>
@@ -5177,6 +5991,7 @@ pub const KHR_SWAPCHAIN_MUTABLE_FORMAT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_synchronization2.html>
@@ -5193,6 +6008,14 @@ pub const KHR_SYNCHRONIZATION2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[
+        "vkCmdPipelineBarrier2KHR",
+        "vkCmdResetEvent2KHR",
+        "vkCmdSetEvent2KHR",
+        "vkCmdWaitEvents2KHR",
+        "vkCmdWriteTimestamp2KHR",
+        "vkQueueSubmit2KHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_timeline_semaphore.html>
@@ -5209,6 +6032,11 @@ pub const KHR_TIMELINE_SEMAPHORE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[
+        "vkGetSemaphoreCounterValueKHR",
+        "vkSignalSemaphoreKHR",
+        "vkWaitSemaphoresKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_unified_image_layouts.html>
@@ -5225,6 +6053,7 @@ pub const KHR_UNIFIED_IMAGE_LAYOUTS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_uniform_buffer_standard_layout.html>
@@ -5241,6 +6070,7 @@ pub const KHR_UNIFORM_BUFFER_STANDARD_LAYOUT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_variable_pointers.html>
@@ -5257,6 +6087,7 @@ pub const KHR_VARIABLE_POINTERS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_1"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_vertex_attribute_divisor.html>
@@ -5273,6 +6104,7 @@ pub const KHR_VERTEX_ATTRIBUTE_DIVISOR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_4"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_decode_av1.html>
@@ -5289,6 +6121,7 @@ pub const KHR_VIDEO_DECODE_AV1_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_decode_h264.html>
@@ -5305,6 +6138,7 @@ pub const KHR_VIDEO_DECODE_H264_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_decode_h265.html>
@@ -5321,6 +6155,7 @@ pub const KHR_VIDEO_DECODE_H265_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_decode_queue.html>
@@ -5337,6 +6172,7 @@ pub const KHR_VIDEO_DECODE_QUEUE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdDecodeVideoKHR"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_decode_vp9.html>
@@ -5353,6 +6189,7 @@ pub const KHR_VIDEO_DECODE_VP9_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_encode_av1.html>
@@ -5369,6 +6206,7 @@ pub const KHR_VIDEO_ENCODE_AV1_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_encode_h264.html>
@@ -5385,6 +6223,7 @@ pub const KHR_VIDEO_ENCODE_H264_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_encode_h265.html>
@@ -5401,6 +6240,7 @@ pub const KHR_VIDEO_ENCODE_H265_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_encode_intra_refresh.html>
@@ -5417,6 +6257,7 @@ pub const KHR_VIDEO_ENCODE_INTRA_REFRESH_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_encode_quantization_map.html>
@@ -5433,6 +6274,7 @@ pub const KHR_VIDEO_ENCODE_QUANTIZATION_MAP_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_encode_queue.html>
@@ -5449,6 +6291,11 @@ pub const KHR_VIDEO_ENCODE_QUEUE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdEncodeVideoKHR",
+        "vkGetEncodedVideoSessionParametersKHR",
+        "vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_maintenance1.html>
@@ -5465,6 +6312,7 @@ pub const KHR_VIDEO_MAINTENANCE1_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_maintenance2.html>
@@ -5481,6 +6329,7 @@ pub const KHR_VIDEO_MAINTENANCE2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_video_queue.html>
@@ -5497,6 +6346,20 @@ pub const KHR_VIDEO_QUEUE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkBindVideoSessionMemoryKHR",
+        "vkCmdBeginVideoCodingKHR",
+        "vkCmdControlVideoCodingKHR",
+        "vkCmdEndVideoCodingKHR",
+        "vkCreateVideoSessionKHR",
+        "vkCreateVideoSessionParametersKHR",
+        "vkDestroyVideoSessionKHR",
+        "vkDestroyVideoSessionParametersKHR",
+        "vkGetPhysicalDeviceVideoCapabilitiesKHR",
+        "vkGetPhysicalDeviceVideoFormatPropertiesKHR",
+        "vkGetVideoSessionMemoryRequirementsKHR",
+        "vkUpdateVideoSessionParametersKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_vulkan_memory_model.html>
@@ -5513,6 +6376,7 @@ pub const KHR_VULKAN_MEMORY_MODEL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_2"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_wayland_surface.html>
@@ -5529,6 +6393,10 @@ pub const KHR_WAYLAND_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateWaylandSurfaceKHR",
+        "vkGetPhysicalDeviceWaylandPresentationSupportKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_win32_keyed_mutex.html>
@@ -5545,6 +6413,7 @@ pub const KHR_WIN32_KEYED_MUTEX_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_win32_surface.html>
@@ -5561,6 +6430,10 @@ pub const KHR_WIN32_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateWin32SurfaceKHR",
+        "vkGetPhysicalDeviceWin32PresentationSupportKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_workgroup_memory_explicit_layout.html>
@@ -5577,6 +6450,7 @@ pub const KHR_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_EXTENSION: Extension = Extension
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_xcb_surface.html>
@@ -5593,6 +6467,10 @@ pub const KHR_XCB_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateXcbSurfaceKHR",
+        "vkGetPhysicalDeviceXcbPresentationSupportKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_xlib_surface.html>
@@ -5609,6 +6487,10 @@ pub const KHR_XLIB_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateXlibSurfaceKHR",
+        "vkGetPhysicalDeviceXlibPresentationSupportKHR",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_KHR_zero_initialize_workgroup_memory.html>
@@ -5625,6 +6507,7 @@ pub const KHR_ZERO_INITIALIZE_WORKGROUP_MEMORY_EXTENSION: Extension = Extension
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_VERSION_1_3"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_LUNARG_direct_driver_loading.html>
@@ -5641,6 +6524,7 @@ pub const LUNARG_DIRECT_DRIVER_LOADING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_MESA_image_alignment_control.html>
@@ -5657,6 +6541,7 @@ pub const MESA_IMAGE_ALIGNMENT_CONTROL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_MSFT_layered_driver.html>
@@ -5673,6 +6558,7 @@ pub const MSFT_LAYERED_DRIVER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_MVK_ios_surface.html>
@@ -5690,6 +6576,7 @@ pub const MVK_IOS_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_EXT_metal_surface"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCreateIOSSurfaceMVK"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_MVK_macos_surface.html>
@@ -5707,6 +6594,7 @@ pub const MVK_MACOS_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_EXT_metal_surface"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCreateMacOSSurfaceMVK"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NN_vi_surface.html>
@@ -5723,6 +6611,7 @@ pub const NN_VI_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCreateViSurfaceNN"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NVX_binary_import.html>
@@ -5739,6 +6628,13 @@ pub const NVX_BINARY_IMPORT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdCuLaunchKernelNVX",
+        "vkCreateCuFunctionNVX",
+        "vkCreateCuModuleNVX",
+        "vkDestroyCuFunctionNVX",
+        "vkDestroyCuModuleNVX",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NVX_image_view_handle.html>
@@ -5755,6 +6651,12 @@ pub const NVX_IMAGE_VIEW_HANDLE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetDeviceCombinedImageSamplerIndexNVX",
+        "vkGetImageViewAddressNVX",
+        "vkGetImageViewHandle64NVX",
+        "vkGetImageViewHandleNVX",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NVX_multiview_per_view_attributes.html>
@@ -5771,6 +6673,7 @@ pub const NVX_MULTIVIEW_PER_VIEW_ATTRIBUTES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_acquire_winrt_display.html>
@@ -5787,6 +6690,7 @@ pub const NV_ACQUIRE_WINRT_DISPLAY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkAcquireWinrtDisplayNV", "vkGetWinrtDisplayNV"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_clip_space_w_scaling.html>
@@ -5803,6 +6707,7 @@ pub const NV_CLIP_SPACE_W_SCALING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdSetViewportWScalingNV"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_cluster_acceleration_structure.html>
@@ -5819,6 +6724,10 @@ pub const NV_CLUSTER_ACCELERATION_STRUCTURE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdBuildClusterAccelerationStructureIndirectNV",
+        "vkGetClusterAccelerationStructureBuildSizesNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_command_buffer_inheritance.html>
@@ -5835,6 +6744,7 @@ pub const NV_COMMAND_BUFFER_INHERITANCE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_compute_occupancy_priority.html>
@@ -5851,6 +6761,7 @@ pub const NV_COMPUTE_OCCUPANCY_PRIORITY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdSetComputeOccupancyPriorityNV"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_compute_shader_derivatives.html>
@@ -5867,6 +6778,7 @@ pub const NV_COMPUTE_SHADER_DERIVATIVES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_compute_shader_derivatives"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_cooperative_matrix.html>
@@ -5883,6 +6795,7 @@ pub const NV_COOPERATIVE_MATRIX_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetPhysicalDeviceCooperativeMatrixPropertiesNV"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_cooperative_matrix2.html>
@@ -5899,6 +6812,7 @@ pub const NV_COOPERATIVE_MATRIX2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetPhysicalDeviceCooperativeMatrixFlexibleDimensionsPropertiesNV"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_cooperative_vector.html>
@@ -5915,6 +6829,11 @@ pub const NV_COOPERATIVE_VECTOR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdConvertCooperativeVectorMatrixNV",
+        "vkConvertCooperativeVectorMatrixNV",
+        "vkGetPhysicalDeviceCooperativeVectorPropertiesNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_copy_memory_indirect.html>
@@ -5931,6 +6850,10 @@ pub const NV_COPY_MEMORY_INDIRECT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_copy_memory_indirect"),
+    commands: &[
+        "vkCmdCopyMemoryIndirectNV",
+        "vkCmdCopyMemoryToImageIndirectNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_corner_sampled_image.html>
@@ -5947,6 +6870,7 @@ pub const NV_CORNER_SAMPLED_IMAGE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_coverage_reduction_mode.html>
@@ -5963,6 +6887,7 @@ pub const NV_COVERAGE_REDUCTION_MODE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_cuda_kernel_launch.html>
@@ -5987,6 +6912,14 @@ pub const NV_CUDA_KERNEL_LAUNCH_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdCudaLaunchKernelNV",
+        "vkCreateCudaFunctionNV",
+        "vkCreateCudaModuleNV",
+        "vkDestroyCudaFunctionNV",
+        "vkDestroyCudaModuleNV",
+        "vkGetCudaModuleCacheNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_dedicated_allocation.html>
@@ -6004,6 +6937,7 @@ pub const NV_DEDICATED_ALLOCATION_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_KHR_dedicated_allocation"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_dedicated_allocation_image_aliasing.html>
@@ -6020,6 +6954,7 @@ pub const NV_DEDICATED_ALLOCATION_IMAGE_ALIASING_EXTENSION: Extension = Extensio
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_descriptor_pool_overallocation.html>
@@ -6036,6 +6971,7 @@ pub const NV_DESCRIPTOR_POOL_OVERALLOCATION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_device_diagnostic_checkpoints.html>
@@ -6052,6 +6988,11 @@ pub const NV_DEVICE_DIAGNOSTIC_CHECKPOINTS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdSetCheckpointNV",
+        "vkGetQueueCheckpointData2NV",
+        "vkGetQueueCheckpointDataNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_device_diagnostics_config.html>
@@ -6068,6 +7009,7 @@ pub const NV_DEVICE_DIAGNOSTICS_CONFIG_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_device_generated_commands.html>
@@ -6084,6 +7026,14 @@ pub const NV_DEVICE_GENERATED_COMMANDS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdBindPipelineShaderGroupNV",
+        "vkCmdExecuteGeneratedCommandsNV",
+        "vkCmdPreprocessGeneratedCommandsNV",
+        "vkCreateIndirectCommandsLayoutNV",
+        "vkDestroyIndirectCommandsLayoutNV",
+        "vkGetGeneratedCommandsMemoryRequirementsNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_device_generated_commands_compute.html>
@@ -6100,6 +7050,11 @@ pub const NV_DEVICE_GENERATED_COMMANDS_COMPUTE_EXTENSION: Extension = Extension
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdUpdatePipelineIndirectBufferNV",
+        "vkGetPipelineIndirectDeviceAddressNV",
+        "vkGetPipelineIndirectMemoryRequirementsNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_displacement_micromap.html>
@@ -6125,6 +7080,7 @@ pub const NV_DISPLACEMENT_MICROMAP_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_NV_cluster_acceleration_structure"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_display_stereo.html>
@@ -6141,6 +7097,7 @@ pub const NV_DISPLAY_STEREO_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_extended_sparse_address_space.html>
@@ -6157,6 +7114,7 @@ pub const NV_EXTENDED_SPARSE_ADDRESS_SPACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_external_compute_queue.html>
@@ -6173,6 +7131,11 @@ pub const NV_EXTERNAL_COMPUTE_QUEUE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateExternalComputeQueueNV",
+        "vkDestroyExternalComputeQueueNV",
+        "vkGetExternalComputeQueueDataNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_external_memory.html>
@@ -6190,6 +7153,7 @@ pub const NV_EXTERNAL_MEMORY_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_KHR_external_memory"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_external_memory_capabilities.html>
@@ -6207,6 +7171,7 @@ pub const NV_EXTERNAL_MEMORY_CAPABILITIES_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_KHR_external_memory_capabilities"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetPhysicalDeviceExternalImageFormatPropertiesNV"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_external_memory_rdma.html>
@@ -6223,6 +7188,7 @@ pub const NV_EXTERNAL_MEMORY_RDMA_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetMemoryRemoteAddressNV"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_external_memory_sci_buf.html>
@@ -6239,6 +7205,11 @@ pub const NV_EXTERNAL_MEMORY_SCI_BUF_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetMemorySciBufNV",
+        "vkGetPhysicalDeviceExternalMemorySciBufPropertiesNV",
+        "vkGetPhysicalDeviceSciBufAttributesNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_external_memory_win32.html>
@@ -6256,6 +7227,7 @@ pub const NV_EXTERNAL_MEMORY_WIN32_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_KHR_external_memory_win32"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetMemoryWin32HandleNV"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_external_sci_sync.html>
@@ -6273,6 +7245,15 @@ pub const NV_EXTERNAL_SCI_SYNC_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_NV_external_sci_sync2"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetFenceSciSyncFenceNV",
+        "vkGetFenceSciSyncObjNV",
+        "vkGetPhysicalDeviceSciSyncAttributesNV",
+        "vkGetSemaphoreSciSyncObjNV",
+        "vkImportFenceSciSyncFenceNV",
+        "vkImportFenceSciSyncObjNV",
+        "vkImportSemaphoreSciSyncObjNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_external_sci_sync2.html>
@@ -6289,6 +7270,15 @@ pub const NV_EXTERNAL_SCI_SYNC2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateSemaphoreSciSyncPoolNV",
+        "vkDestroySemaphoreSciSyncPoolNV",
+        "vkGetFenceSciSyncFenceNV",
+        "vkGetFenceSciSyncObjNV",
+        "vkGetPhysicalDeviceSciSyncAttributesNV",
+        "vkImportFenceSciSyncFenceNV",
+        "vkImportFenceSciSyncObjNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_fill_rectangle.html>
@@ -6305,6 +7295,7 @@ pub const NV_FILL_RECTANGLE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_fragment_coverage_to_color.html>
@@ -6321,6 +7312,7 @@ pub const NV_FRAGMENT_COVERAGE_TO_COLOR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_fragment_shader_barycentric.html>
@@ -6337,6 +7329,7 @@ pub const NV_FRAGMENT_SHADER_BARYCENTRIC_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_fragment_shader_barycentric"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_fragment_shading_rate_enums.html>
@@ -6353,6 +7346,7 @@ pub const NV_FRAGMENT_SHADING_RATE_ENUMS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdSetFragmentShadingRateEnumNV"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_framebuffer_mixed_samples.html>
@@ -6369,6 +7363,7 @@ pub const NV_FRAMEBUFFER_MIXED_SAMPLES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_geometry_shader_passthrough.html>
@@ -6385,6 +7380,7 @@ pub const NV_GEOMETRY_SHADER_PASSTHROUGH_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_glsl_shader.html>
@@ -6402,6 +7398,7 @@ pub const NV_GLSL_SHADER_EXTENSION: Extension = Extension {
     deprecated_by: Some(""),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_inherited_viewport_scissor.html>
@@ -6418,6 +7415,7 @@ pub const NV_INHERITED_VIEWPORT_SCISSOR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_linear_color_attachment.html>
@@ -6434,6 +7432,7 @@ pub const NV_LINEAR_COLOR_ATTACHMENT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_low_latency.html>
@@ -6450,6 +7449,7 @@ pub const NV_LOW_LATENCY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_low_latency2.html>
@@ -6466,6 +7466,13 @@ pub const NV_LOW_LATENCY2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetLatencyTimingsNV",
+        "vkLatencySleepNV",
+        "vkQueueNotifyOutOfBandNV",
+        "vkSetLatencyMarkerNV",
+        "vkSetLatencySleepModeNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_memory_decompression.html>
@@ -6482,6 +7489,10 @@ pub const NV_MEMORY_DECOMPRESSION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_EXT_memory_decompression"),
+    commands: &[
+        "vkCmdDecompressMemoryIndirectCountNV",
+        "vkCmdDecompressMemoryNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_mesh_shader.html>
@@ -6498,6 +7509,11 @@ pub const NV_MESH_SHADER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdDrawMeshTasksIndirectCountNV",
+        "vkCmdDrawMeshTasksIndirectNV",
+        "vkCmdDrawMeshTasksNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_optical_flow.html>
@@ -6514,6 +7530,13 @@ pub const NV_OPTICAL_FLOW_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkBindOpticalFlowSessionImageNV",
+        "vkCmdOpticalFlowExecuteNV",
+        "vkCreateOpticalFlowSessionNV",
+        "vkDestroyOpticalFlowSessionNV",
+        "vkGetPhysicalDeviceOpticalFlowImageFormatsNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_partitioned_acceleration_structure.html>
@@ -6530,6 +7553,10 @@ pub const NV_PARTITIONED_ACCELERATION_STRUCTURE_EXTENSION: Extension = Extension
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdBuildPartitionedAccelerationStructuresNV",
+        "vkGetPartitionedAccelerationStructuresBuildSizesNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_per_stage_descriptor_set.html>
@@ -6546,6 +7573,7 @@ pub const NV_PER_STAGE_DESCRIPTOR_SET_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_present_barrier.html>
@@ -6562,6 +7590,7 @@ pub const NV_PRESENT_BARRIER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_present_metering.html>
@@ -6586,6 +7615,7 @@ pub const NV_PRESENT_METERING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_private_vendor_info.html>
@@ -6602,6 +7632,7 @@ pub const NV_PRIVATE_VENDOR_INFO_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_push_constant_bank.html>
@@ -6618,6 +7649,7 @@ pub const NV_PUSH_CONSTANT_BANK_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_raw_access_chains.html>
@@ -6634,6 +7666,7 @@ pub const NV_RAW_ACCESS_CHAINS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_ray_tracing.html>
@@ -6651,6 +7684,20 @@ pub const NV_RAY_TRACING_EXTENSION: Extension = Extension {
     deprecated_by: Some("VK_KHR_ray_tracing_pipeline"),
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkBindAccelerationStructureMemoryNV",
+        "vkCmdBuildAccelerationStructureNV",
+        "vkCmdCopyAccelerationStructureNV",
+        "vkCmdTraceRaysNV",
+        "vkCmdWriteAccelerationStructuresPropertiesNV",
+        "vkCompileDeferredNV",
+        "vkCreateAccelerationStructureNV",
+        "vkCreateRayTracingPipelinesNV",
+        "vkDestroyAccelerationStructureNV",
+        "vkGetAccelerationStructureHandleNV",
+        "vkGetAccelerationStructureMemoryRequirementsNV",
+        "vkGetRayTracingShaderGroupHandlesNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_ray_tracing_invocation_reorder.html>
@@ -6667,6 +7714,7 @@ pub const NV_RAY_TRACING_INVOCATION_REORDER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_EXT_ray_tracing_invocation_reorder"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_ray_tracing_linear_swept_spheres.html>
@@ -6683,6 +7731,7 @@ pub const NV_RAY_TRACING_LINEAR_SWEPT_SPHERES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_ray_tracing_motion_blur.html>
@@ -6699,6 +7748,7 @@ pub const NV_RAY_TRACING_MOTION_BLUR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_ray_tracing_validation.html>
@@ -6715,6 +7765,7 @@ pub const NV_RAY_TRACING_VALIDATION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_representative_fragment_test.html>
@@ -6731,6 +7782,7 @@ pub const NV_REPRESENTATIVE_FRAGMENT_TEST_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_sample_mask_override_coverage.html>
@@ -6747,6 +7799,7 @@ pub const NV_SAMPLE_MASK_OVERRIDE_COVERAGE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_scissor_exclusive.html>
@@ -6763,6 +7816,10 @@ pub const NV_SCISSOR_EXCLUSIVE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdSetExclusiveScissorEnableNV",
+        "vkCmdSetExclusiveScissorNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_shader_atomic_float16_vector.html>
@@ -6779,6 +7836,7 @@ pub const NV_SHADER_ATOMIC_FLOAT16_VECTOR_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_shader_image_footprint.html>
@@ -6795,6 +7853,7 @@ pub const NV_SHADER_IMAGE_FOOTPRINT_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_shader_sm_builtins.html>
@@ -6811,6 +7870,7 @@ pub const NV_SHADER_SM_BUILTINS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_shader_subgroup_partitioned.html>
@@ -6827,6 +7887,7 @@ pub const NV_SHADER_SUBGROUP_PARTITIONED_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_EXT_shader_subgroup_partitioned"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_shading_rate_image.html>
@@ -6843,6 +7904,11 @@ pub const NV_SHADING_RATE_IMAGE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdBindShadingRateImageNV",
+        "vkCmdSetCoarseSampleOrderNV",
+        "vkCmdSetViewportShadingRatePaletteNV",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_viewport_array2.html>
@@ -6859,6 +7925,7 @@ pub const NV_VIEWPORT_ARRAY2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_viewport_swizzle.html>
@@ -6875,6 +7942,7 @@ pub const NV_VIEWPORT_SWIZZLE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_NV_win32_keyed_mutex.html>
@@ -6891,6 +7959,7 @@ pub const NV_WIN32_KEYED_MUTEX_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_KHR_win32_keyed_mutex"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_OHOS_external_memory.html>
@@ -6907,6 +7976,10 @@ pub const OHOS_EXTERNAL_MEMORY_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetMemoryNativeBufferOHOS",
+        "vkGetNativeBufferPropertiesOHOS",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_OHOS_surface.html>
@@ -6923,6 +7996,7 @@ pub const OHOS_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCreateSurfaceOHOS"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_cooperative_matrix_conversion.html>
@@ -6939,6 +8013,7 @@ pub const QCOM_COOPERATIVE_MATRIX_CONVERSION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_data_graph_model.html>
@@ -6955,6 +8030,7 @@ pub const QCOM_DATA_GRAPH_MODEL_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_filter_cubic_clamp.html>
@@ -6971,6 +8047,7 @@ pub const QCOM_FILTER_CUBIC_CLAMP_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_filter_cubic_weights.html>
@@ -6987,6 +8064,7 @@ pub const QCOM_FILTER_CUBIC_WEIGHTS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_fragment_density_map_offset.html>
@@ -7003,6 +8081,7 @@ pub const QCOM_FRAGMENT_DENSITY_MAP_OFFSET_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_EXT_fragment_density_map_offset"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_image_processing.html>
@@ -7019,6 +8098,7 @@ pub const QCOM_IMAGE_PROCESSING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_image_processing2.html>
@@ -7035,6 +8115,7 @@ pub const QCOM_IMAGE_PROCESSING2_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_multiview_per_view_render_areas.html>
@@ -7051,6 +8132,7 @@ pub const QCOM_MULTIVIEW_PER_VIEW_RENDER_AREAS_EXTENSION: Extension = Extension
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_multiview_per_view_viewports.html>
@@ -7067,6 +8149,7 @@ pub const QCOM_MULTIVIEW_PER_VIEW_VIEWPORTS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_render_pass_shader_resolve.html>
@@ -7083,6 +8166,7 @@ pub const QCOM_RENDER_PASS_SHADER_RESOLVE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_EXT_custom_resolve"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_render_pass_store_ops.html>
@@ -7099,6 +8183,7 @@ pub const QCOM_RENDER_PASS_STORE_OPS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_render_pass_transform.html>
@@ -7115,6 +8200,7 @@ pub const QCOM_RENDER_PASS_TRANSFORM_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_rotated_copy_commands.html>
@@ -7131,6 +8217,7 @@ pub const QCOM_ROTATED_COPY_COMMANDS_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_tile_memory_heap.html>
@@ -7147,6 +8234,7 @@ pub const QCOM_TILE_MEMORY_HEAP_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkCmdBindTileMemoryQCOM"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_tile_properties.html>
@@ -7163,6 +8251,10 @@ pub const QCOM_TILE_PROPERTIES_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetDynamicRenderingTilePropertiesQCOM",
+        "vkGetFramebufferTilePropertiesQCOM",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_tile_shading.html>
@@ -7179,6 +8271,11 @@ pub const QCOM_TILE_SHADING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCmdBeginPerTileExecutionQCOM",
+        "vkCmdDispatchTileQCOM",
+        "vkCmdEndPerTileExecutionQCOM",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QCOM_ycbcr_degamma.html>
@@ -7195,6 +8292,7 @@ pub const QCOM_YCBCR_DEGAMMA_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QNX_external_memory_screen_buffer.html>
@@ -7211,6 +8309,7 @@ pub const QNX_EXTERNAL_MEMORY_SCREEN_BUFFER_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &["vkGetScreenBufferPropertiesQNX"],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_QNX_screen_surface.html>
@@ -7227,6 +8326,10 @@ pub const QNX_SCREEN_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateScreenSurfaceQNX",
+        "vkGetPhysicalDeviceScreenPresentationSupportQNX",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_SEC_amigo_profiling.html>
@@ -7243,6 +8346,7 @@ pub const SEC_AMIGO_PROFILING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_SEC_pipeline_cache_incremental_mode.html>
@@ -7259,6 +8363,7 @@ pub const SEC_PIPELINE_CACHE_INCREMENTAL_MODE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_SEC_ubm_surface.html>
@@ -7275,6 +8380,10 @@ pub const SEC_UBM_SURFACE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkCreateUbmSurfaceSEC",
+        "vkGetPhysicalDeviceUbmPresentationSupportSEC",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_VALVE_descriptor_set_host_mapping.html>
@@ -7291,6 +8400,10 @@ pub const VALVE_DESCRIPTOR_SET_HOST_MAPPING_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[
+        "vkGetDescriptorSetHostMappingVALVE",
+        "vkGetDescriptorSetLayoutHostMappingInfoVALVE",
+    ],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_VALVE_fragment_density_map_layered.html>
@@ -7307,6 +8420,7 @@ pub const VALVE_FRAGMENT_DENSITY_MAP_LAYERED_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_VALVE_mutable_descriptor_type.html>
@@ -7323,6 +8437,7 @@ pub const VALVE_MUTABLE_DESCRIPTOR_TYPE_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: Some("VK_EXT_mutable_descriptor_type"),
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_VALVE_shader_mixed_float_dot_product.html>
@@ -7339,6 +8454,7 @@ pub const VALVE_SHADER_MIXED_FLOAT_DOT_PRODUCT_EXTENSION: Extension = Extension
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
+    commands: &[],
 };
 
 /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/VK_VALVE_video_encode_rgb_conversion.html>
@@ -7355,4 +8471,468 @@ pub const VALVE_VIDEO_ENCODE_RGB_CONVERSION_EXTENSION: Extension = Extension {
     deprecated_by: None,
     obsoleted_by: None,
     promoted_to: None,
-};
+    commands: &[],
+};
+
+/// The metadata for every Vulkan extension.
+#[allow(deprecated)]
+pub const EXTENSIONS: &[Extension] = &[
+    #[cfg(feature = "provisional")]
+    AMDX_DENSE_GEOMETRY_FORMAT_EXTENSION,
+    #[cfg(feature = "provisional")]
+    AMDX_SHADER_ENQUEUE_EXTENSION,
+    AMD_ANTI_LAG_EXTENSION,
+    AMD_BUFFER_MARKER_EXTENSION,
+    AMD_DEVICE_COHERENT_MEMORY_EXTENSION,
+    AMD_DISPLAY_NATIVE_HDR_EXTENSION,
+    AMD_DRAW_INDIRECT_COUNT_EXTENSION,
+    AMD_GCN_SHADER_EXTENSION,
+    AMD_GPU_SHADER_HALF_FLOAT_EXTENSION,
+    AMD_GPU_SHADER_INT16_EXTENSION,
+    AMD_MEMORY_OVERALLOCATION_BEHAVIOR_EXTENSION,
+    AMD_MIXED_ATTACHMENT_SAMPLES_EXTENSION,
+    AMD_NEGATIVE_VIEWPORT_HEIGHT_EXTENSION,
+    AMD_PIPELINE_COMPILER_CONTROL_EXTENSION,
+    AMD_RASTERIZATION_ORDER_EXTENSION,
+    AMD_SHADER_BALLOT_EXTENSION,
+    AMD_SHADER_CORE_PROPERTIES_EXTENSION,
+    AMD_SHADER_CORE_PROPERTIES2_EXTENSION,
+    AMD_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_EXTENSION,
+    AMD_SHADER_EXPLICIT_VERTEX_PARAMETER_EXTENSION,
+    AMD_SHADER_FRAGMENT_MASK_EXTENSION,
+    AMD_SHADER_IMAGE_LOAD_STORE_LOD_EXTENSION,
+    AMD_SHADER_INFO_EXTENSION,
+    AMD_SHADER_TRINARY_MINMAX_EXTENSION,
+    AMD_TEXTURE_GATHER_BIAS_LOD_EXTENSION,
+    ANDROID_EXTERNAL_FORMAT_RESOLVE_EXTENSION,
+    ANDROID_EXTERNAL_MEMORY_ANDROID_HARDWARE_BUFFER_EXTENSION,
+    ARM_DATA_GRAPH_EXTENSION,
+    ARM_FORMAT_PACK_EXTENSION,
+    ARM_PERFORMANCE_COUNTERS_BY_REGION_EXTENSION,
+    ARM_PIPELINE_OPACITY_MICROMAP_EXTENSION,
+    ARM_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_EXTENSION,
+    ARM_RENDER_PASS_STRIPED_EXTENSION,
+    ARM_SCHEDULING_CONTROLS_EXTENSION,
+    ARM_SHADER_CORE_BUILTINS_EXTENSION,
+    ARM_SHADER_CORE_PROPERTIES_EXTENSION,
+    ARM_TENSORS_EXTENSION,
+    EXT_4444_FORMATS_EXTENSION,
+    EXT_ACQUIRE_DRM_DISPLAY_EXTENSION,
+    EXT_ACQUIRE_XLIB_DISPLAY_EXTENSION,
+    EXT_APPLICATION_PARAMETERS_EXTENSION,
+    EXT_ASTC_DECODE_MODE_EXTENSION,
+    EXT_ATTACHMENT_FEEDBACK_LOOP_DYNAMIC_STATE_EXTENSION,
+    EXT_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_EXTENSION,
+    EXT_BLEND_OPERATION_ADVANCED_EXTENSION,
+    EXT_BORDER_COLOR_SWIZZLE_EXTENSION,
+    EXT_BUFFER_DEVICE_ADDRESS_EXTENSION,
+    EXT_CALIBRATED_TIMESTAMPS_EXTENSION,
+    EXT_COLOR_WRITE_ENABLE_EXTENSION,
+    EXT_CONDITIONAL_RENDERING_EXTENSION,
+    EXT_CONSERVATIVE_RASTERIZATION_EXTENSION,
+    EXT_CUSTOM_BORDER_COLOR_EXTENSION,
+    EXT_CUSTOM_RESOLVE_EXTENSION,
+    EXT_DEBUG_MARKER_EXTENSION,
+    EXT_DEBUG_REPORT_EXTENSION,
+    EXT_DEBUG_UTILS_EXTENSION,
+    EXT_DEPTH_BIAS_CONTROL_EXTENSION,
+    EXT_DEPTH_CLAMP_CONTROL_EXTENSION,
+    EXT_DEPTH_CLAMP_ZERO_ONE_EXTENSION,
+    EXT_DEPTH_CLIP_CONTROL_EXTENSION,
+    EXT_DEPTH_CLIP_ENABLE_EXTENSION,
+    EXT_DEPTH_RANGE_UNRESTRICTED_EXTENSION,
+    EXT_DESCRIPTOR_BUFFER_EXTENSION,
+    EXT_DESCRIPTOR_HEAP_EXTENSION,
+    EXT_DESCRIPTOR_INDEXING_EXTENSION,
+    EXT_DEVICE_ADDRESS_BINDING_REPORT_EXTENSION,
+    EXT_DEVICE_FAULT_EXTENSION,
+    EXT_DEVICE_GENERATED_COMMANDS_EXTENSION,
+    EXT_DEVICE_MEMORY_REPORT_EXTENSION,
+    EXT_DIRECT_MODE_DISPLAY_EXTENSION,
+    EXT_DIRECTFB_SURFACE_EXTENSION,
+    EXT_DISCARD_RECTANGLES_EXTENSION,
+    EXT_DISPLAY_CONTROL_EXTENSION,
+    EXT_DISPLAY_SURFACE_COUNTER_EXTENSION,
+    EXT_DYNAMIC_RENDERING_UNUSED_ATTACHMENTS_EXTENSION,
+    EXT_EXTENDED_DYNAMIC_STATE_EXTENSION,
+    EXT_EXTENDED_DYNAMIC_STATE2_EXTENSION,
+    EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION,
+    EXT_EXTERNAL_MEMORY_ACQUIRE_UNMODIFIED_EXTENSION,
+    EXT_EXTERNAL_MEMORY_DMA_BUF_EXTENSION,
+    EXT_EXTERNAL_MEMORY_HOST_EXTENSION,
+    EXT_EXTERNAL_MEMORY_METAL_EXTENSION,
+    EXT_FILTER_CUBIC_EXTENSION,
+    EXT_FRAGMENT_DENSITY_MAP_EXTENSION,
+    EXT_FRAGMENT_DENSITY_MAP2_EXTENSION,
+    EXT_FRAGMENT_DENSITY_MAP_OFFSET_EXTENSION,
+    EXT_FRAGMENT_SHADER_INTERLOCK_EXTENSION,
+    EXT_FRAME_BOUNDARY_EXTENSION,
+    EXT_FULL_SCREEN_EXCLUSIVE_EXTENSION,
+    EXT_GLOBAL_PRIORITY_EXTENSION,
+    EXT_GLOBAL_PRIORITY_QUERY_EXTENSION,
+    EXT_GRAPHICS_PIPELINE_LIBRARY_EXTENSION,
+    EXT_HDR_METADATA_EXTENSION,
+    EXT_HEADLESS_SURFACE_EXTENSION,
+    EXT_HOST_IMAGE_COPY_EXTENSION,
+    EXT_HOST_QUERY_RESET_EXTENSION,
+    EXT_IMAGE_2D_VIEW_OF_3D_EXTENSION,
+    EXT_IMAGE_COMPRESSION_CONTROL_EXTENSION,
+    EXT_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_EXTENSION,
+    EXT_IMAGE_DRM_FORMAT_MODIFIER_EXTENSION,
+    EXT_IMAGE_ROBUSTNESS_EXTENSION,
+    EXT_IMAGE_SLICED_VIEW_OF_3D_EXTENSION,
+    EXT_IMAGE_VIEW_MIN_LOD_EXTENSION,
+    EXT_INDEX_TYPE_UINT8_EXTENSION,
+    EXT_INLINE_UNIFORM_BLOCK_EXTENSION,
+    EXT_LAYER_SETTINGS_EXTENSION,
+    EXT_LEGACY_DITHERING_EXTENSION,
+    EXT_LEGACY_VERTEX_ATTRIBUTES_EXTENSION,
+    EXT_LINE_RASTERIZATION_EXTENSION,
+    EXT_LOAD_STORE_OP_NONE_EXTENSION,
+    EXT_MAP_MEMORY_PLACED_EXTENSION,
+    EXT_MEMORY_BUDGET_EXTENSION,
+    EXT_MEMORY_DECOMPRESSION_EXTENSION,
+    EXT_MEMORY_PRIORITY_EXTENSION,
+    EXT_MESH_SHADER_EXTENSION,
+    EXT_METAL_OBJECTS_EXTENSION,
+    EXT_METAL_SURFACE_EXTENSION,
+    EXT_MULTI_DRAW_EXTENSION,
+    EXT_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_EXTENSION,
+    EXT_MUTABLE_DESCRIPTOR_TYPE_EXTENSION,
+    EXT_NESTED_COMMAND_BUFFER_EXTENSION,
+    EXT_NON_SEAMLESS_CUBE_MAP_EXTENSION,
+    EXT_OPACITY_MICROMAP_EXTENSION,
+    EXT_PAGEABLE_DEVICE_LOCAL_MEMORY_EXTENSION,
+    EXT_PCI_BUS_INFO_EXTENSION,
+    EXT_PHYSICAL_DEVICE_DRM_EXTENSION,
+    EXT_PIPELINE_CREATION_CACHE_CONTROL_EXTENSION,
+    EXT_PIPELINE_CREATION_FEEDBACK_EXTENSION,
+    EXT_PIPELINE_LIBRARY_GROUP_HANDLES_EXTENSION,
+    EXT_PIPELINE_PROPERTIES_EXTENSION,
+    EXT_PIPELINE_PROTECTED_ACCESS_EXTENSION,
+    EXT_PIPELINE_ROBUSTNESS_EXTENSION,
+    EXT_POST_DEPTH_COVERAGE_EXTENSION,
+    EXT_PRESENT_MODE_FIFO_LATEST_READY_EXTENSION,
+    EXT_PRESENT_TIMING_EXTENSION,
+    EXT_PRIMITIVE_TOPOLOGY_LIST_RESTART_EXTENSION,
+    EXT_PRIMITIVES_GENERATED_QUERY_EXTENSION,
+    EXT_PRIVATE_DATA_EXTENSION,
+    EXT_PROVOKING_VERTEX_EXTENSION,
+    EXT_QUEUE_FAMILY_FOREIGN_EXTENSION,
+    EXT_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_EXTENSION,
+    EXT_RAY_TRACING_INVOCATION_REORDER_EXTENSION,
+    EXT_RGBA10X6_FORMATS_EXTENSION,
+    EXT_ROBUSTNESS2_EXTENSION,
+    EXT_SAMPLE_LOCATIONS_EXTENSION,
+    EXT_SAMPLER_FILTER_MINMAX_EXTENSION,
+    EXT_SCALAR_BLOCK_LAYOUT_EXTENSION,
+    EXT_SEPARATE_STENCIL_USAGE_EXTENSION,
+    EXT_SHADER_64BIT_INDEXING_EXTENSION,
+    EXT_SHADER_ATOMIC_FLOAT_EXTENSION,
+    EXT_SHADER_ATOMIC_FLOAT2_EXTENSION,
+    EXT_SHADER_DEMOTE_TO_HELPER_INVOCATION_EXTENSION,
+    EXT_SHADER_FLOAT8_EXTENSION,
+    EXT_SHADER_IMAGE_ATOMIC_INT64_EXTENSION,
+    EXT_SHADER_LONG_VECTOR_EXTENSION,
+    EXT_SHADER_MODULE_IDENTIFIER_EXTENSION,
+    EXT_SHADER_OBJECT_EXTENSION,
+    EXT_SHADER_REPLICATED_COMPOSITES_EXTENSION,
+    EXT_SHADER_STENCIL_EXPORT_EXTENSION,
+    EXT_SHADER_SUBGROUP_BALLOT_EXTENSION,
+    EXT_SHADER_SUBGROUP_PARTITIONED_EXTENSION,
+    EXT_SHADER_SUBGROUP_VOTE_EXTENSION,
+    EXT_SHADER_TILE_IMAGE_EXTENSION,
+    EXT_SHADER_UNIFORM_BUFFER_UNSIZED_ARRAY_EXTENSION,
+    EXT_SHADER_VIEWPORT_INDEX_LAYER_EXTENSION,
+    EXT_SUBGROUP_SIZE_CONTROL_EXTENSION,
+    EXT_SUBPASS_MERGE_FEEDBACK_EXTENSION,
+    EXT_SURFACE_MAINTENANCE1_EXTENSION,
+    EXT_SWAPCHAIN_COLORSPACE_EXTENSION,
+    EXT_SWAPCHAIN_MAINTENANCE1_EXTENSION,
+    EXT_TEXEL_BUFFER_ALIGNMENT_EXTENSION,
+    EXT_TEXTURE_COMPRESSION_ASTC_3D_EXTENSION,
+    EXT_TEXTURE_COMPRESSION_ASTC_HDR_EXTENSION,
+    EXT_TOOLING_INFO_EXTENSION,
+    EXT_TRANSFORM_FEEDBACK_EXTENSION,
+    EXT_VALIDATION_CACHE_EXTENSION,
+    EXT_VALIDATION_FEATURES_EXTENSION,
+    EXT_VALIDATION_FLAGS_EXTENSION,
+    EXT_VERTEX_ATTRIBUTE_DIVISOR_EXTENSION,
+    EXT_VERTEX_ATTRIBUTE_ROBUSTNESS_EXTENSION,
+    EXT_VERTEX_INPUT_DYNAMIC_STATE_EXTENSION,
+    EXT_YCBCR_2PLANE_444_FORMATS_EXTENSION,
+    EXT_YCBCR_IMAGE_ARRAYS_EXTENSION,
+    EXT_ZERO_INITIALIZE_DEVICE_MEMORY_EXTENSION,
+    FUCHSIA_BUFFER_COLLECTION_EXTENSION,
+    FUCHSIA_EXTERNAL_MEMORY_EXTENSION,
+    FUCHSIA_EXTERNAL_SEMAPHORE_EXTENSION,
+    FUCHSIA_IMAGEPIPE_SURFACE_EXTENSION,
+    GGP_FRAME_TOKEN_EXTENSION,
+    GGP_STREAM_DESCRIPTOR_SURFACE_EXTENSION,
+    GOOGLE_DECORATE_STRING_EXTENSION,
+    GOOGLE_DISPLAY_TIMING_EXTENSION,
+    GOOGLE_HLSL_FUNCTIONALITY1_EXTENSION,
+    GOOGLE_SURFACELESS_QUERY_EXTENSION,
+    GOOGLE_USER_TYPE_EXTENSION,
+    HUAWEI_CLUSTER_CULLING_SHADER_EXTENSION,
+    HUAWEI_HDR_VIVID_EXTENSION,
+    HUAWEI_INVOCATION_MASK_EXTENSION,
+    HUAWEI_SUBPASS_SHADING_EXTENSION,
+    IMG_FILTER_CUBIC_EXTENSION,
+    IMG_FORMAT_PVRTC_EXTENSION,
+    IMG_RELAXED_LINE_RASTERIZATION_EXTENSION,
+    INTEL_PERFORMANCE_QUERY_EXTENSION,
+    INTEL_SHADER_INTEGER_FUNCTIONS2_EXTENSION,
+    KHR_16BIT_STORAGE_EXTENSION,
+    KHR_8BIT_STORAGE_EXTENSION,
+    KHR_ACCELERATION_STRUCTURE_EXTENSION,
+    KHR_ANDROID_SURFACE_EXTENSION,
+    KHR_BIND_MEMORY2_EXTENSION,
+    KHR_BUFFER_DEVICE_ADDRESS_EXTENSION,
+    KHR_CALIBRATED_TIMESTAMPS_EXTENSION,
+    KHR_COMPUTE_SHADER_DERIVATIVES_EXTENSION,
+    KHR_COOPERATIVE_MATRIX_EXTENSION,
+    KHR_COPY_COMMANDS2_EXTENSION,
+    KHR_COPY_MEMORY_INDIRECT_EXTENSION,
+    KHR_CREATE_RENDERPASS2_EXTENSION,
+    KHR_DEDICATED_ALLOCATION_EXTENSION,
+    KHR_DEFERRED_HOST_OPERATIONS_EXTENSION,
+    KHR_DEPTH_CLAMP_ZERO_ONE_EXTENSION,
+    KHR_DEPTH_STENCIL_RESOLVE_EXTENSION,
+    KHR_DESCRIPTOR_UPDATE_TEMPLATE_EXTENSION,
+    KHR_DEVICE_GROUP_EXTENSION,
+    KHR_DEVICE_GROUP_CREATION_EXTENSION,
+    KHR_DISPLAY_EXTENSION,
+    KHR_DISPLAY_SWAPCHAIN_EXTENSION,
+    KHR_DRAW_INDIRECT_COUNT_EXTENSION,
+    KHR_DRIVER_PROPERTIES_EXTENSION,
+    KHR_DYNAMIC_RENDERING_EXTENSION,
+    KHR_DYNAMIC_RENDERING_LOCAL_READ_EXTENSION,
+    KHR_EXTERNAL_FENCE_EXTENSION,
+    KHR_EXTERNAL_FENCE_CAPABILITIES_EXTENSION,
+    KHR_EXTERNAL_FENCE_FD_EXTENSION,
+    KHR_EXTERNAL_FENCE_WIN32_EXTENSION,
+    KHR_EXTERNAL_MEMORY_EXTENSION,
+    KHR_EXTERNAL_MEMORY_CAPABILITIES_EXTENSION,
+    KHR_EXTERNAL_MEMORY_FD_EXTENSION,
+    KHR_EXTERNAL_MEMORY_WIN32_EXTENSION,
+    KHR_EXTERNAL_SEMAPHORE_EXTENSION,
+    KHR_EXTERNAL_SEMAPHORE_CAPABILITIES_EXTENSION,
+    KHR_EXTERNAL_SEMAPHORE_FD_EXTENSION,
+    KHR_EXTERNAL_SEMAPHORE_WIN32_EXTENSION,
+    KHR_FORMAT_FEATURE_FLAGS2_EXTENSION,
+    KHR_FRAGMENT_SHADER_BARYCENTRIC_EXTENSION,
+    KHR_FRAGMENT_SHADING_RATE_EXTENSION,
+    KHR_GET_DISPLAY_PROPERTIES2_EXTENSION,
+    KHR_GET_MEMORY_REQUIREMENTS2_EXTENSION,
+    KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION,
+    KHR_GET_SURFACE_CAPABILITIES2_EXTENSION,
+    KHR_GLOBAL_PRIORITY_EXTENSION,
+    KHR_IMAGE_FORMAT_LIST_EXTENSION,
+    KHR_IMAGELESS_FRAMEBUFFER_EXTENSION,
+    KHR_INCREMENTAL_PRESENT_EXTENSION,
+    KHR_INDEX_TYPE_UINT8_EXTENSION,
+    KHR_INTERNALLY_SYNCHRONIZED_QUEUES_EXTENSION,
+    KHR_LINE_RASTERIZATION_EXTENSION,
+    KHR_LOAD_STORE_OP_NONE_EXTENSION,
+    KHR_MAINTENANCE1_EXTENSION,
+    KHR_MAINTENANCE10_EXTENSION,
+    KHR_MAINTENANCE2_EXTENSION,
+    KHR_MAINTENANCE3_EXTENSION,
+    KHR_MAINTENANCE4_EXTENSION,
+    KHR_MAINTENANCE5_EXTENSION,
+    KHR_MAINTENANCE6_EXTENSION,
+    KHR_MAINTENANCE7_EXTENSION,
+    KHR_MAINTENANCE8_EXTENSION,
+    KHR_MAINTENANCE9_EXTENSION,
+    KHR_MAP_MEMORY2_EXTENSION,
+    KHR_MULTIVIEW_EXTENSION,
+    KHR_OBJECT_REFRESH_EXTENSION,
+    KHR_PERFORMANCE_QUERY_EXTENSION,
+    KHR_PIPELINE_BINARY_EXTENSION,
+    KHR_PIPELINE_EXECUTABLE_PROPERTIES_EXTENSION,
+    KHR_PIPELINE_LIBRARY_EXTENSION,
+    KHR_PORTABILITY_ENUMERATION_EXTENSION,
+    #[cfg(feature = "provisional")]
+    KHR_PORTABILITY_SUBSET_EXTENSION,
+    KHR_PRESENT_ID_EXTENSION,
+    KHR_PRESENT_ID2_EXTENSION,
+    KHR_PRESENT_MODE_FIFO_LATEST_READY_EXTENSION,
+    KHR_PRESENT_WAIT_EXTENSION,
+    KHR_PRESENT_WAIT2_EXTENSION,
+    KHR_PUSH_DESCRIPTOR_EXTENSION,
+    KHR_RAY_QUERY_EXTENSION,
+    KHR_RAY_TRACING_MAINTENANCE1_EXTENSION,
+    KHR_RAY_TRACING_PIPELINE_EXTENSION,
+    KHR_RAY_TRACING_POSITION_FETCH_EXTENSION,
+    KHR_RELAXED_BLOCK_LAYOUT_EXTENSION,
+    KHR_ROBUSTNESS2_EXTENSION,
+    KHR_SAMPLER_MIRROR_CLAMP_TO_EDGE_EXTENSION,
+    KHR_SAMPLER_YCBCR_CONVERSION_EXTENSION,
+    KHR_SEPARATE_DEPTH_STENCIL_LAYOUTS_EXTENSION,
+    KHR_SHADER_ATOMIC_INT64_EXTENSION,
+    KHR_SHADER_BFLOAT16_EXTENSION,
+    KHR_SHADER_CLOCK_EXTENSION,
+    KHR_SHADER_DRAW_PARAMETERS_EXTENSION,
+    KHR_SHADER_EXPECT_ASSUME_EXTENSION,
+    KHR_SHADER_FLOAT16_INT8_EXTENSION,
+    KHR_SHADER_FLOAT_CONTROLS_EXTENSION,
+    KHR_SHADER_FLOAT_CONTROLS2_EXTENSION,
+    KHR_SHADER_FMA_EXTENSION,
+    KHR_SHADER_INTEGER_DOT_PRODUCT_EXTENSION,
+    KHR_SHADER_MAXIMAL_RECONVERGENCE_EXTENSION,
+    KHR_SHADER_NON_SEMANTIC_INFO_EXTENSION,
+    KHR_SHADER_QUAD_CONTROL_EXTENSION,
+    KHR_SHADER_RELAXED_EXTENDED_INSTRUCTION_EXTENSION,
+    KHR_SHADER_SUBGROUP_EXTENDED_TYPES_EXTENSION,
+    KHR_SHADER_SUBGROUP_ROTATE_EXTENSION,
+    KHR_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_EXTENSION,
+    KHR_SHADER_TERMINATE_INVOCATION_EXTENSION,
+    KHR_SHADER_UNTYPED_POINTERS_EXTENSION,
+    KHR_SHARED_PRESENTABLE_IMAGE_EXTENSION,
+    KHR_SPIRV_1_4_EXTENSION,
+    KHR_STORAGE_BUFFER_STORAGE_CLASS_EXTENSION,
+    KHR_SURFACE_EXTENSION,
+    KHR_SURFACE_MAINTENANCE1_EXTENSION,
+    KHR_SURFACE_PROTECTED_CAPABILITIES_EXTENSION,
+    KHR_SWAPCHAIN_EXTENSION,
+    KHR_SWAPCHAIN_MAINTENANCE1_EXTENSION,
+    KHR_SWAPCHAIN_MUTABLE_FORMAT_EXTENSION,
+    KHR_SYNCHRONIZATION2_EXTENSION,
+    KHR_TIMELINE_SEMAPHORE_EXTENSION,
+    KHR_UNIFIED_IMAGE_LAYOUTS_EXTENSION,
+    KHR_UNIFORM_BUFFER_STANDARD_LAYOUT_EXTENSION,
+    KHR_VARIABLE_POINTERS_EXTENSION,
+    KHR_VERTEX_ATTRIBUTE_DIVISOR_EXTENSION,
+    KHR_VIDEO_DECODE_AV1_EXTENSION,
+    KHR_VIDEO_DECODE_H264_EXTENSION,
+    KHR_VIDEO_DECODE_H265_EXTENSION,
+    KHR_VIDEO_DECODE_QUEUE_EXTENSION,
+    KHR_VIDEO_DECODE_VP9_EXTENSION,
+    KHR_VIDEO_ENCODE_AV1_EXTENSION,
+    KHR_VIDEO_ENCODE_H264_EXTENSION,
+    KHR_VIDEO_ENCODE_H265_EXTENSION,
+    KHR_VIDEO_ENCODE_INTRA_REFRESH_EXTENSION,
+    KHR_VIDEO_ENCODE_QUANTIZATION_MAP_EXTENSION,
+    KHR_VIDEO_ENCODE_QUEUE_EXTENSION,
+    KHR_VIDEO_MAINTENANCE1_EXTENSION,
+    KHR_VIDEO_MAINTENANCE2_EXTENSION,
+    KHR_VIDEO_QUEUE_EXTENSION,
+    KHR_VULKAN_MEMORY_MODEL_EXTENSION,
+    KHR_WAYLAND_SURFACE_EXTENSION,
+    KHR_WIN32_KEYED_MUTEX_EXTENSION,
+    KHR_WIN32_SURFACE_EXTENSION,
+    KHR_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_EXTENSION,
+    KHR_XCB_SURFACE_EXTENSION,
+    KHR_XLIB_SURFACE_EXTENSION,
+    KHR_ZERO_INITIALIZE_WORKGROUP_MEMORY_EXTENSION,
+    LUNARG_DIRECT_DRIVER_LOADING_EXTENSION,
+    MESA_IMAGE_ALIGNMENT_CONTROL_EXTENSION,
+    MSFT_LAYERED_DRIVER_EXTENSION,
+    MVK_IOS_SURFACE_EXTENSION,
+    MVK_MACOS_SURFACE_EXTENSION,
+    NN_VI_SURFACE_EXTENSION,
+    NVX_BINARY_IMPORT_EXTENSION,
+    NVX_IMAGE_VIEW_HANDLE_EXTENSION,
+    NVX_MULTIVIEW_PER_VIEW_ATTRIBUTES_EXTENSION,
+    NV_ACQUIRE_WINRT_DISPLAY_EXTENSION,
+    NV_CLIP_SPACE_W_SCALING_EXTENSION,
+    NV_CLUSTER_ACCELERATION_STRUCTURE_EXTENSION,
+    NV_COMMAND_BUFFER_INHERITANCE_EXTENSION,
+    NV_COMPUTE_OCCUPANCY_PRIORITY_EXTENSION,
+    NV_COMPUTE_SHADER_DERIVATIVES_EXTENSION,
+    NV_COOPERATIVE_MATRIX_EXTENSION,
+    NV_COOPERATIVE_MATRIX2_EXTENSION,
+    NV_COOPERATIVE_VECTOR_EXTENSION,
+    NV_COPY_MEMORY_INDIRECT_EXTENSION,
+    NV_CORNER_SAMPLED_IMAGE_EXTENSION,
+    NV_COVERAGE_REDUCTION_MODE_EXTENSION,
+    #[cfg(feature = "provisional")]
+    NV_CUDA_KERNEL_LAUNCH_EXTENSION,
+    NV_DEDICATED_ALLOCATION_EXTENSION,
+    NV_DEDICATED_ALLOCATION_IMAGE_ALIASING_EXTENSION,
+    NV_DESCRIPTOR_POOL_OVERALLOCATION_EXTENSION,
+    NV_DEVICE_DIAGNOSTIC_CHECKPOINTS_EXTENSION,
+    NV_DEVICE_DIAGNOSTICS_CONFIG_EXTENSION,
+    NV_DEVICE_GENERATED_COMMANDS_EXTENSION,
+    NV_DEVICE_GENERATED_COMMANDS_COMPUTE_EXTENSION,
+    #[cfg(feature = "provisional")]
+    NV_DISPLACEMENT_MICROMAP_EXTENSION,
+    NV_DISPLAY_STEREO_EXTENSION,
+    NV_EXTENDED_SPARSE_ADDRESS_SPACE_EXTENSION,
+    NV_EXTERNAL_COMPUTE_QUEUE_EXTENSION,
+    NV_EXTERNAL_MEMORY_EXTENSION,
+    NV_EXTERNAL_MEMORY_CAPABILITIES_EXTENSION,
+    NV_EXTERNAL_MEMORY_RDMA_EXTENSION,
+    NV_EXTERNAL_MEMORY_SCI_BUF_EXTENSION,
+    NV_EXTERNAL_MEMORY_WIN32_EXTENSION,
+    NV_EXTERNAL_SCI_SYNC_EXTENSION,
+    NV_EXTERNAL_SCI_SYNC2_EXTENSION,
+    NV_FILL_RECTANGLE_EXTENSION,
+    NV_FRAGMENT_COVERAGE_TO_COLOR_EXTENSION,
+    NV_FRAGMENT_SHADER_BARYCENTRIC_EXTENSION,
+    NV_FRAGMENT_SHADING_RATE_ENUMS_EXTENSION,
+    NV_FRAMEBUFFER_MIXED_SAMPLES_EXTENSION,
+    NV_GEOMETRY_SHADER_PASSTHROUGH_EXTENSION,
+    NV_GLSL_SHADER_EXTENSION,
+    NV_INHERITED_VIEWPORT_SCISSOR_EXTENSION,
+    NV_LINEAR_COLOR_ATTACHMENT_EXTENSION,
+    NV_LOW_LATENCY_EXTENSION,
+    NV_LOW_LATENCY2_EXTENSION,
+    NV_MEMORY_DECOMPRESSION_EXTENSION,
+    NV_MESH_SHADER_EXTENSION,
+    NV_OPTICAL_FLOW_EXTENSION,
+    NV_PARTITIONED_ACCELERATION_STRUCTURE_EXTENSION,
+    NV_PER_STAGE_DESCRIPTOR_SET_EXTENSION,
+    NV_PRESENT_BARRIER_EXTENSION,
+    #[cfg(feature = "provisional")]
+    NV_PRESENT_METERING_EXTENSION,
+    NV_PRIVATE_VENDOR_INFO_EXTENSION,
+    NV_PUSH_CONSTANT_BANK_EXTENSION,
+    NV_RAW_ACCESS_CHAINS_EXTENSION,
+    NV_RAY_TRACING_EXTENSION,
+    NV_RAY_TRACING_INVOCATION_REORDER_EXTENSION,
+    NV_RAY_TRACING_LINEAR_SWEPT_SPHERES_EXTENSION,
+    NV_RAY_TRACING_MOTION_BLUR_EXTENSION,
+    NV_RAY_TRACING_VALIDATION_EXTENSION,
+    NV_REPRESENTATIVE_FRAGMENT_TEST_EXTENSION,
+    NV_SAMPLE_MASK_OVERRIDE_COVERAGE_EXTENSION,
+    NV_SCISSOR_EXCLUSIVE_EXTENSION,
+    NV_SHADER_ATOMIC_FLOAT16_VECTOR_EXTENSION,
+    NV_SHADER_IMAGE_FOOTPRINT_EXTENSION,
+    NV_SHADER_SM_BUILTINS_EXTENSION,
+    NV_SHADER_SUBGROUP_PARTITIONED_EXTENSION,
+    NV_SHADING_RATE_IMAGE_EXTENSION,
+    NV_VIEWPORT_ARRAY2_EXTENSION,
+    NV_VIEWPORT_SWIZZLE_EXTENSION,
+    NV_WIN32_KEYED_MUTEX_EXTENSION,
+    OHOS_EXTERNAL_MEMORY_EXTENSION,
+    OHOS_SURFACE_EXTENSION,
+    QCOM_COOPERATIVE_MATRIX_CONVERSION_EXTENSION,
+    QCOM_DATA_GRAPH_MODEL_EXTENSION,
+    QCOM_FILTER_CUBIC_CLAMP_EXTENSION,
+    QCOM_FILTER_CUBIC_WEIGHTS_EXTENSION,
+    QCOM_FRAGMENT_DENSITY_MAP_OFFSET_EXTENSION,
+    QCOM_IMAGE_PROCESSING_EXTENSION,
+    QCOM_IMAGE_PROCESSING2_EXTENSION,
+    QCOM_MULTIVIEW_PER_VIEW_RENDER_AREAS_EXTENSION,
+    QCOM_MULTIVIEW_PER_VIEW_VIEWPORTS_EXTENSION,
+    QCOM_RENDER_PASS_SHADER_RESOLVE_EXTENSION,
+    QCOM_RENDER_PASS_STORE_OPS_EXTENSION,
+    QCOM_RENDER_PASS_TRANSFORM_EXTENSION,
+    QCOM_ROTATED_COPY_COMMANDS_EXTENSION,
+    QCOM_TILE_MEMORY_HEAP_EXTENSION,
+    QCOM_TILE_PROPERTIES_EXTENSION,
+    QCOM_TILE_SHADING_EXTENSION,
+    QCOM_YCBCR_DEGAMMA_EXTENSION,
+    QNX_EXTERNAL_MEMORY_SCREEN_BUFFER_EXTENSION,
+    QNX_SCREEN_SURFACE_EXTENSION,
+    SEC_AMIGO_PROFILING_EXTENSION,
+    SEC_PIPELINE_CACHE_INCREMENTAL_MODE_EXTENSION,
+    SEC_UBM_SURFACE_EXTENSION,
+    VALVE_DESCRIPTOR_SET_HOST_MAPPING_EXTENSION,
+    VALVE_FRAGMENT_DENSITY_MAP_LAYERED_EXTENSION,
+    VALVE_MUTABLE_DESCRIPTOR_TYPE_EXTENSION,
+    VALVE_SHADER_MIXED_FLOAT_DOT_PRODUCT_EXTENSION,
+    VALVE_VIDEO_ENCODE_RGB_CONVERSION_EXTENSION,
+];
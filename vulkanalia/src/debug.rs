@@ -0,0 +1,474 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Debug messengers.
+//!
+//! A [`DebugMessenger`] is a `VK_EXT_debug_utils` messenger which calls a Rust
+//! closure with the messages reported by the Vulkan implementation and any
+//! enabled layers (e.g., the validation layers). The callback data of each
+//! message is decoded into an owned [`Message`] before the closure is called
+//! and the messenger is destroyed when it is dropped.
+//!
+//! ```no_run
+//! # use vulkanalia::prelude::v1_0::*;
+//! # use vulkanalia::debug::DebugMessenger;
+//! # fn example(instance: &Instance) -> VkResult<()> {
+//! let messenger = unsafe {
+//!     DebugMessenger::new(
+//!         instance,
+//!         vk::DebugUtilsMessageSeverityFlagsEXT::all(),
+//!         vk::DebugUtilsMessageTypeFlagsEXT::all(),
+//!         |message| println!("{:?}: {}", message.severity, message.message),
+//!     )?
+//! };
+//!
+//! // The messenger is destroyed here when `messenger` is dropped.
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! When the `log` or `tracing` features are enabled, the `log` or `tracing`
+//! functions in this module can be used as the closure to forward messages to
+//! the `log` or `tracing` crates at the level that corresponds to the severity
+//! of each message.
+//!
+//! Vulkan objects can be named (and tagged) for debugging tools and layers
+//! with [`Device::set_object_name`] (and [`Device::set_object_tag`]) which
+//! use `VK_EXT_debug_utils` or `VK_EXT_debug_marker` (whichever is available).
+//!
+//! ```no_run
+//! # use vulkanalia::prelude::v1_0::*;
+//! # fn example(device: &Device, image: vk::Image) -> VkResult<()> {
+//! unsafe { device.set_object_name(image, "shadow map")? };
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::btree_set::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::{CStr, c_char, c_void};
+use core::{fmt, mem, slice};
+
+use crate::vk::{
+    self, ExtDebugMarkerExtensionDeviceCommands, ExtDebugUtilsExtensionInstanceCommands, Handle,
+    HasBuilder,
+};
+use crate::{Device, Instance, ResultExt, VkResult};
+
+/// The type of the closures called by debug messengers.
+type Callback = dyn Fn(&Message) + Send + Sync + 'static;
+
+/// A debug label (e.g., a queue label or command buffer label).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Label {
+    /// The name of the label.
+    pub name: String,
+    /// The color of the label (RGBA).
+    pub color: [f32; 4],
+}
+
+/// A Vulkan object related to a debug message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Object {
+    /// The type of the object.
+    pub object_type: vk::ObjectType,
+    /// The raw value of the object handle.
+    pub handle: u64,
+    /// The name of the object (if it has been named).
+    pub name: Option<String>,
+}
+
+/// A debug message reported to a debug messenger.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Message {
+    /// The severity of the message.
+    pub severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    /// The types of the message.
+    pub types: vk::DebugUtilsMessageTypeFlagsEXT,
+    /// The name of the message identifier (e.g., a validation error ID).
+    pub id_name: Option<String>,
+    /// The number of the message identifier.
+    pub id_number: i32,
+    /// The message.
+    pub message: String,
+    /// The active queue labels (most recent first).
+    pub queue_labels: Vec<Label>,
+    /// The active command buffer labels (most recent first).
+    pub cmd_buf_labels: Vec<Label>,
+    /// The objects related to the message.
+    pub objects: Vec<Object>,
+}
+
+impl Message {
+    /// Decodes the callback data of a debug message.
+    ///
+    /// # Safety
+    ///
+    /// The pointers in `data` must be valid as described by the Vulkan
+    /// specification for `VkDebugUtilsMessengerCallbackDataEXT`.
+    pub unsafe fn from_raw(
+        severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+        types: vk::DebugUtilsMessageTypeFlagsEXT,
+        data: &vk::DebugUtilsMessengerCallbackDataEXT,
+    ) -> Self {
+        let labels = |count: u32, labels: *const vk::DebugUtilsLabelEXT| {
+            array(count, labels)
+                .iter()
+                .map(|l| Label {
+                    name: string(l.label_name).unwrap_or_default(),
+                    color: l.color,
+                })
+                .collect()
+        };
+
+        let objects = array(data.object_count, data.objects)
+            .iter()
+            .map(|o| Object {
+                object_type: o.object_type,
+                handle: o.object_handle,
+                name: string(o.object_name),
+            })
+            .collect();
+
+        Self {
+            severity,
+            types,
+            id_name: string(data.message_id_name),
+            id_number: data.message_id_number,
+            message: string(data.message).unwrap_or_default(),
+            queue_labels: labels(data.queue_label_count, data.queue_labels),
+            cmd_buf_labels: labels(data.cmd_buf_label_count, data.cmd_buf_labels),
+            objects,
+        }
+    }
+}
+
+/// A `VK_EXT_debug_utils` messenger which calls a Rust closure and is
+/// destroyed when dropped.
+///
+/// The [`Instance`] used to create the messenger is cloned (which only
+/// increments a reference count) and stored alongside the messenger, so the
+/// messenger must be dropped before the instance is destroyed.
+///
+/// The closure is called on whichever thread the Vulkan command that reported
+/// the message was called on. Panics in the closure abort the process.
+pub struct DebugMessenger {
+    instance: Instance,
+    handle: vk::DebugUtilsMessengerEXT,
+    callback: *mut Box<Callback>,
+}
+
+impl DebugMessenger {
+    /// Creates a debug messenger which calls a closure with the messages that
+    /// have one of the supplied severities and one of the supplied types.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must have been created with the `VK_EXT_debug_utils`
+    /// extension enabled.
+    pub unsafe fn new(
+        instance: &Instance,
+        severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+        types: vk::DebugUtilsMessageTypeFlagsEXT,
+        callback: impl Fn(&Message) + Send + Sync + 'static,
+    ) -> VkResult<Self> {
+        let callback = Box::into_raw(Box::new(Box::new(callback) as Box<Callback>));
+
+        let info = vk::DebugUtilsMessengerCreateInfoEXT::builder()
+            .message_severity(severity)
+            .message_type(types)
+            .user_callback(Some(trampoline))
+            .user_data(&mut *callback);
+
+        match instance.create_debug_utils_messenger_ext(&info, None) {
+            Ok(handle) => Ok(Self {
+                instance: instance.clone(),
+                handle,
+                callback,
+            }),
+            Err(error) => {
+                drop(Box::from_raw(callback));
+                Err(error)
+            }
+        }
+    }
+
+    /// Gets the Vulkan handle of this debug messenger.
+    #[inline]
+    pub fn handle(&self) -> vk::DebugUtilsMessengerEXT {
+        self.handle
+    }
+}
+
+impl fmt::Debug for DebugMessenger {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("DebugMessenger").field(&self.handle).finish()
+    }
+}
+
+impl Drop for DebugMessenger {
+    fn drop(&mut self) {
+        unsafe {
+            self.instance
+                .destroy_debug_utils_messenger_ext(self.handle, None);
+            drop(Box::from_raw(self.callback));
+        }
+    }
+}
+
+// SAFETY: The closure is `Send + Sync` and is only freed when dropped.
+unsafe impl Send for DebugMessenger {}
+unsafe impl Sync for DebugMessenger {}
+
+/// The callback passed to Vulkan for every debug messenger.
+unsafe extern "system" fn trampoline(
+    severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    types: vk::DebugUtilsMessageTypeFlagsEXT,
+    data: *const vk::DebugUtilsMessengerCallbackDataEXT,
+    user_data: *mut c_void,
+) -> vk::Bool32 {
+    let callback = &*user_data.cast::<Box<Callback>>();
+    callback(&Message::from_raw(severity, types, &*data));
+    vk::FALSE
+}
+
+unsafe fn array<'a, T>(count: u32, values: *const T) -> &'a [T] {
+    if count == 0 || values.is_null() {
+        &[]
+    } else {
+        slice::from_raw_parts(values, count as usize)
+    }
+}
+
+unsafe fn string(string: *const c_char) -> Option<String> {
+    if string.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(string).to_string_lossy().into_owned())
+    }
+}
+
+//================================================
+// Object Names
+//================================================
+
+/// The `VK_EXT_debug_utils` commands used to name and tag the objects of a
+/// Vulkan device.
+#[derive(Copy, Clone)]
+pub(crate) struct DebugUtilsCommands {
+    set_object_name: vk::PFN_vkSetDebugUtilsObjectNameEXT,
+    set_object_tag: vk::PFN_vkSetDebugUtilsObjectTagEXT,
+}
+
+impl DebugUtilsCommands {
+    /// Loads the commands with a device-level loader if `VK_EXT_debug_utils`
+    /// is one of the extensions enabled for the instance the device was created
+    /// with.
+    ///
+    /// Some drivers return these commands even if the extension was not
+    /// enabled, in which case calling them would be invalid.
+    pub(crate) unsafe fn load(
+        instance_extensions: &BTreeSet<vk::ExtensionName>,
+        mut loader: impl FnMut(*const c_char) -> vk::PFN_vkVoidFunction,
+    ) -> Option<Self> {
+        if !instance_extensions.contains(&vk::EXT_DEBUG_UTILS_EXTENSION.name) {
+            return None;
+        }
+
+        type Void = unsafe extern "system" fn();
+        let set_object_name = loader(c"vkSetDebugUtilsObjectNameEXT".as_ptr())?;
+        let set_object_tag = loader(c"vkSetDebugUtilsObjectTagEXT".as_ptr())?;
+        Some(Self {
+            set_object_name: mem::transmute::<Void, vk::PFN_vkSetDebugUtilsObjectNameEXT>(
+                set_object_name,
+            ),
+            set_object_tag: mem::transmute::<Void, vk::PFN_vkSetDebugUtilsObjectTagEXT>(
+                set_object_tag,
+            ),
+        })
+    }
+}
+
+impl Device {
+    /// Sets the name of a Vulkan object (e.g., `"shadow map"`) that is
+    /// displayed by debugging tools and layers.
+    ///
+    /// The object is named with `VK_EXT_debug_utils` if it was enabled for the
+    /// instance this device was created with (using [`Instance::create_device`])
+    /// or with `VK_EXT_debug_marker` if
+    /// it was enabled for this device. If neither extension is enabled (or the
+    /// object type is not supported by `VK_EXT_debug_marker`), this method does
+    /// nothing.
+    ///
+    /// Names are truncated at the first null character (if any).
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be this device or an object created from this device (or
+    /// from its instance or physical device).
+    pub unsafe fn set_object_name<H>(&self, handle: H, name: &str) -> VkResult<()>
+    where
+        H: Handle,
+        u64: TryFrom<H::Repr>,
+    {
+        let object = raw(handle);
+        let name = name
+            .bytes()
+            .take_while(|b| *b != 0)
+            .chain(Some(0))
+            .collect::<Vec<_>>();
+
+        if let Some(commands) = self.state.debug_utils {
+            let info = vk::DebugUtilsObjectNameInfoEXT::builder()
+                .object_type(H::TYPE)
+                .object_handle(object)
+                .object_name(&name);
+            (commands.set_object_name)(self.handle, &*info).result()
+        } else if let Some(object_type) = self.debug_marker_object_type(H::TYPE) {
+            let info = vk::DebugMarkerObjectNameInfoEXT::builder()
+                .object_type(object_type)
+                .object(object)
+                .object_name(&name);
+            self.debug_marker_set_object_name_ext(&info)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Attaches a tag (arbitrary binary data identified by a numerical tag
+    /// name) to a Vulkan object for use by debugging tools and layers.
+    ///
+    /// The object is tagged with `VK_EXT_debug_utils` or `VK_EXT_debug_marker`
+    /// in the same way objects are named with [`Device::set_object_name`].
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be this device or an object created from this device (or
+    /// from its instance or physical device).
+    pub unsafe fn set_object_tag<H>(&self, handle: H, tag_name: u64, tag: &[u8]) -> VkResult<()>
+    where
+        H: Handle,
+        u64: TryFrom<H::Repr>,
+    {
+        let object = raw(handle);
+
+        if let Some(commands) = self.state.debug_utils {
+            let info = vk::DebugUtilsObjectTagInfoEXT::builder()
+                .object_type(H::TYPE)
+                .object_handle(object)
+                .tag_name(tag_name)
+                .tag(tag);
+            (commands.set_object_tag)(self.handle, &*info).result()
+        } else if let Some(object_type) = self.debug_marker_object_type(H::TYPE) {
+            let info = vk::DebugMarkerObjectTagInfoEXT::builder()
+                .object_type(object_type)
+                .object(object)
+                .tag_name(tag_name)
+                .tag(tag);
+            self.debug_marker_set_object_tag_ext(&info)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Gets the `VK_EXT_debug_marker` object type for an object type (if
+    /// `VK_EXT_debug_marker` is enabled for this device and supports it).
+    fn debug_marker_object_type(
+        &self,
+        object_type: vk::ObjectType,
+    ) -> Option<vk::DebugReportObjectTypeEXT> {
+        if !self
+            .extensions()
+            .contains(&vk::EXT_DEBUG_MARKER_EXTENSION.name)
+        {
+            return None;
+        }
+
+        // The object types shared with the core API have the same values in
+        // both enums but the object types added by extensions do not.
+        use vk::DebugReportObjectTypeEXT as Report;
+        let object_type = match object_type {
+            vk::ObjectType::SURFACE_KHR => Report::SURFACE_KHR,
+            vk::ObjectType::SWAPCHAIN_KHR => Report::SWAPCHAIN_KHR,
+            vk::ObjectType::DEBUG_REPORT_CALLBACK_EXT => Report::DEBUG_REPORT_CALLBACK_EXT,
+            vk::ObjectType::DISPLAY_KHR => Report::DISPLAY_KHR,
+            vk::ObjectType::DISPLAY_MODE_KHR => Report::DISPLAY_MODE_KHR,
+            vk::ObjectType::VALIDATION_CACHE_EXT => Report::VALIDATION_CACHE_EXT,
+            _ => Report::from_raw(object_type.as_raw()),
+        };
+
+        if object_type != Report::UNKNOWN && Report::VALUES.contains(&object_type) {
+            Some(object_type)
+        } else {
+            None
+        }
+    }
+}
+
+/// Gets the raw value of a handle as a `u64`.
+fn raw<H>(handle: H) -> u64
+where
+    H: Handle,
+    u64: TryFrom<H::Repr>,
+{
+    u64::try_from(handle.as_raw()).unwrap_or_else(|_| unreachable!())
+}
+
+//================================================
+// Adapters
+//================================================
+
+/// Forwards a debug message to the `log` crate.
+///
+/// Errors are logged as errors, warnings as warnings, informational messages
+/// as debug messages, and verbose messages as trace messages.
+#[cfg(feature = "log")]
+pub fn log(message: &Message) {
+    use vk::DebugUtilsMessageSeverityFlagsEXT as Severity;
+
+    let level = if message.severity >= Severity::ERROR {
+        ::log::Level::Error
+    } else if message.severity >= Severity::WARNING {
+        ::log::Level::Warn
+    } else if message.severity >= Severity::INFO {
+        ::log::Level::Debug
+    } else {
+        ::log::Level::Trace
+    };
+
+    ::log::log!(level, "({:?}) {}", message.types, message.message);
+}
+
+/// Forwards a debug message to the `tracing` crate.
+///
+/// Errors are recorded as error events, warnings as warning events,
+/// informational messages as debug events, and verbose messages as trace
+/// events. The types and identifier of the message are recorded as fields.
+#[cfg(feature = "tracing")]
+pub fn tracing(message: &Message) {
+    use vk::DebugUtilsMessageSeverityFlagsEXT as Severity;
+
+    macro_rules! event {
+        ($level:ident) => {
+            ::tracing::event!(
+                ::tracing::Level::$level,
+                types = ?message.types,
+                id_name = message.id_name.as_deref(),
+                id_number = message.id_number,
+                "{}",
+                message.message,
+            )
+        };
+    }
+
+    if message.severity >= Severity::ERROR {
+        event!(ERROR);
+    } else if message.severity >= Severity::WARNING {
+        event!(WARN);
+    } else if message.severity >= Severity::INFO {
+        event!(DEBUG);
+    } else {
+        event!(TRACE);
+    }
+}
@@ -0,0 +1,633 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Vulkan physical device features.
+//!
+//! The [`Features`] trait is implemented for [`vk::PhysicalDeviceFeatures`]
+//! and for every Vulkan struct which can extend
+//! [`vk::PhysicalDeviceFeatures2`] (e.g., [`vk::PhysicalDeviceVulkan12Features`]).
+//! It provides access to the feature flags of these structs along with the
+//! names of the features.
+//!
+//! ```
+//! # use vulkanalia::prelude::v1_2::*;
+//! # use vulkanalia::features::Features;
+//! let required = vk::PhysicalDeviceVulkan12Features {
+//!     timeline_semaphore: vk::TRUE,
+//!     buffer_device_address: vk::TRUE,
+//!     ..Default::default()
+//! };
+//!
+//! let available = vk::PhysicalDeviceVulkan12Features {
+//!     timeline_semaphore: vk::TRUE,
+//!     ..Default::default()
+//! };
+//!
+//! assert!(!available.is_superset(&required));
+//! assert_eq!(required.difference(&available).names(), &["buffer_device_address"]);
+//! ```
+//!
+//! A [`FeatureSet`] owns a [`vk::PhysicalDeviceFeatures2`] and a chain of
+//! feature structs which extend it. It can be used to query the features
+//! supported by a physical device in one call and to enable features when
+//! creating a device.
+//!
+//! ```no_run
+//! # use vulkanalia::prelude::v1_3::*;
+//! # use vulkanalia::Version;
+//! # use vulkanalia::features::FeatureSet;
+//! # fn example(instance: &Instance, physical_device: vk::PhysicalDevice) -> Result<(), Box<dyn std::error::Error>> {
+//! let mut requested = FeatureSet::core(Version::V1_3_0)
+//!     .with(vk::PhysicalDeviceMeshShaderFeaturesEXT {
+//!         mesh_shader: vk::TRUE,
+//!         ..Default::default()
+//!     });
+//!
+//! requested.features_mut().sampler_anisotropy = vk::TRUE;
+//! requested.get_mut::<vk::PhysicalDeviceVulkan13Features>().unwrap().dynamic_rendering = vk::TRUE;
+//!
+//! // Fails with the names of any requested features which are not supported.
+//! let features = unsafe { requested.enable(instance, physical_device)? };
+//!
+//! let info = vk::DeviceCreateInfo::builder().push_next(features);
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::fmt;
+use core::ptr;
+
+#[cfg(all(feature = "no_std_error", not(feature = "std")))]
+use core::error;
+#[cfg(feature = "std")]
+use std::error;
+
+use crate::Version;
+use crate::prelude::v1_1::*;
+use crate::vk::KhrGetPhysicalDeviceProperties2ExtensionInstanceCommands;
+
+/// A Vulkan struct which contains physical device feature flags.
+///
+/// # Safety
+///
+/// [`Features::flags`] and [`Features::flags_mut`] must return all of the
+/// feature flags of the struct in the order they are declared in and
+/// [`Features::NAMES`] must contain the names of the feature flags in the same
+/// order.
+pub unsafe trait Features: Copy + Default {
+    /// The name of the Vulkan struct (e.g., `VkPhysicalDeviceVulkan12Features`).
+    const NAME: &'static str;
+    /// The names of the feature flags (e.g., `timeline_semaphore`).
+    const NAMES: &'static [&'static str];
+
+    /// Gets the feature flags.
+    fn flags(&self) -> &[vk::Bool32];
+
+    /// Gets the feature flags mutably.
+    fn flags_mut(&mut self) -> &mut [vk::Bool32];
+
+    /// Gets whether no features are enabled.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.flags().iter().all(|f| *f == vk::FALSE)
+    }
+
+    /// Gets whether every feature enabled in `other` is also enabled in this
+    /// value.
+    #[inline]
+    fn is_superset(&self, other: &Self) -> bool {
+        let mut flags = self.flags().iter().zip(other.flags());
+        flags.all(|(s, o)| *s != vk::FALSE || *o == vk::FALSE)
+    }
+
+    /// Gets the features enabled in both this value and `other`.
+    #[inline]
+    fn intersection(&self, other: &Self) -> Self {
+        let mut result = *self;
+        intersect(result.flags_mut(), other.flags());
+        result
+    }
+
+    /// Gets the features enabled in either this value or `other`.
+    #[inline]
+    fn union(&self, other: &Self) -> Self {
+        let mut result = *self;
+        unite(result.flags_mut(), other.flags());
+        result
+    }
+
+    /// Gets the features enabled in this value but not in `other`.
+    #[inline]
+    fn difference(&self, other: &Self) -> Self {
+        let mut result = *self;
+        subtract(result.flags_mut(), other.flags());
+        result
+    }
+
+    /// Gets the names of the enabled features.
+    #[inline]
+    fn names(&self) -> Vec<&'static str> {
+        let flags = Self::NAMES.iter().zip(self.flags());
+        flags
+            .filter(|(_, f)| **f != vk::FALSE)
+            .map(|(n, _)| *n)
+            .collect()
+    }
+}
+
+/// Disables the features in `flags` which are not enabled in `other`.
+fn intersect(flags: &mut [vk::Bool32], other: &[vk::Bool32]) {
+    for (flag, other) in flags.iter_mut().zip(other) {
+        if *other == vk::FALSE {
+            *flag = vk::FALSE;
+        }
+    }
+}
+
+/// Enables the features in `flags` which are enabled in `other`.
+fn unite(flags: &mut [vk::Bool32], other: &[vk::Bool32]) {
+    for (flag, other) in flags.iter_mut().zip(other) {
+        if *other != vk::FALSE {
+            *flag = vk::TRUE;
+        }
+    }
+}
+
+/// Disables the features in `flags` which are enabled in `other`.
+fn subtract(flags: &mut [vk::Bool32], other: &[vk::Bool32]) {
+    for (flag, other) in flags.iter_mut().zip(other) {
+        if *other != vk::FALSE {
+            *flag = vk::FALSE;
+        }
+    }
+}
+
+/// A physical device feature.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Feature {
+    /// The name of the Vulkan struct which contains the feature (e.g.,
+    /// `VkPhysicalDeviceVulkan12Features`).
+    pub structure: &'static str,
+    /// The name of the feature (e.g., `timeline_semaphore`).
+    pub name: &'static str,
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}::{}", self.structure, self.name)
+    }
+}
+
+/// An error returned when requested physical device features are not
+/// supported.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FeatureError {
+    /// Requested features are not supported.
+    Missing(Vec<Feature>),
+}
+
+impl fmt::Display for FeatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Missing(features) => {
+                write!(f, "missing features (")?;
+                for (index, feature) in features.iter().enumerate() {
+                    let separator = if index == 0 { "" } else { ", " };
+                    write!(f, "{separator}{feature}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "no_std_error"))]
+impl error::Error for FeatureError {}
+
+/// A feature struct which can extend [`vk::PhysicalDeviceFeatures2`] with its
+/// type erased.
+trait Extension: Any + Send + Sync {
+    fn name(&self) -> &'static str;
+    fn names(&self) -> &'static [&'static str];
+    fn flags(&self) -> &[vk::Bool32];
+    fn flags_mut(&mut self) -> &mut [vk::Bool32];
+    fn base_mut(&mut self) -> *mut vk::BaseOutStructure;
+    fn clone_empty(&self) -> Box<dyn Extension>;
+    fn clone_box(&self) -> Box<dyn Extension>;
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<F> Extension for F
+where
+    F: Features
+        + vk::ExtendsPhysicalDeviceFeatures2
+        + vk::OutputChainStruct
+        + Send
+        + Sync
+        + 'static,
+{
+    fn name(&self) -> &'static str {
+        F::NAME
+    }
+
+    fn names(&self) -> &'static [&'static str] {
+        F::NAMES
+    }
+
+    fn flags(&self) -> &[vk::Bool32] {
+        Features::flags(self)
+    }
+
+    fn flags_mut(&mut self) -> &mut [vk::Bool32] {
+        Features::flags_mut(self)
+    }
+
+    fn base_mut(&mut self) -> *mut vk::BaseOutStructure {
+        ptr::from_mut(self).cast()
+    }
+
+    fn clone_empty(&self) -> Box<dyn Extension> {
+        Box::new(F::default())
+    }
+
+    fn clone_box(&self) -> Box<dyn Extension> {
+        Box::new(*self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// A set of physical device features.
+///
+/// Contains a [`vk::PhysicalDeviceFeatures2`] and any number of feature
+/// structs which can extend [`vk::PhysicalDeviceFeatures2`] (at most one of
+/// each type).
+///
+/// See the [module-level documentation](self) for more details.
+pub struct FeatureSet {
+    features: vk::PhysicalDeviceFeatures2,
+    extensions: Vec<Box<dyn Extension>>,
+}
+
+impl FeatureSet {
+    /// Constructs a new empty set of features which only contains a
+    /// [`vk::PhysicalDeviceFeatures`].
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            features: vk::PhysicalDeviceFeatures2::default(),
+            extensions: Vec::new(),
+        }
+    }
+
+    /// Constructs a new empty set of features which contains the feature
+    /// structs for the core features of a Vulkan version (e.g.,
+    /// [`vk::PhysicalDeviceVulkan11Features`] and
+    /// [`vk::PhysicalDeviceVulkan12Features`] for Vulkan 1.2).
+    pub fn core(version: Version) -> Self {
+        let mut set = Self::new();
+
+        if version >= Version::V1_2_0 {
+            set.insert(vk::PhysicalDeviceVulkan11Features::default());
+            set.insert(vk::PhysicalDeviceVulkan12Features::default());
+        }
+
+        if version >= Version::V1_3_0 {
+            set.insert(vk::PhysicalDeviceVulkan13Features::default());
+        }
+
+        if version >= Version::V1_4_0 {
+            set.insert(vk::PhysicalDeviceVulkan14Features::default());
+        }
+
+        set
+    }
+
+    /// Constructs a new set of features which contains copies of the features
+    /// enabled by a [`vk::DeviceCreateInfo`].
+    ///
+    /// Both [`vk::DeviceCreateInfo::enabled_features`] and any
+    /// [`vk::PhysicalDeviceFeatures2`] (and the feature structs which extend
+    /// it) in the pointer chain of the [`vk::DeviceCreateInfo`] are copied.
+    ///
+    /// # Safety
+    ///
+    /// The pointers in `info` must be valid.
+    pub unsafe fn from_device_create_info(info: &vk::DeviceCreateInfo) -> Self {
+        let mut set = Self::new();
+
+        if !info.enabled_features.is_null() {
+            set.features.features = *info.enabled_features;
+        }
+
+        let mut next = info.next.cast::<vk::BaseInStructure>();
+        while !next.is_null() {
+            if (*next).s_type == vk::StructureType::PHYSICAL_DEVICE_FEATURES_2 {
+                let features = &*next.cast::<vk::PhysicalDeviceFeatures2>();
+                set.features.features = features.features;
+            } else {
+                set.insert_raw(next);
+            }
+
+            next = (*next).next;
+        }
+
+        set
+    }
+
+    /// Adds a feature struct to this set (replacing any existing feature
+    /// struct of the same type).
+    #[inline]
+    pub fn with<F>(mut self, features: F) -> Self
+    where
+        F: Features
+            + vk::ExtendsPhysicalDeviceFeatures2
+            + vk::OutputChainStruct
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.insert(features);
+        self
+    }
+
+    /// Adds a feature struct to this set (replacing any existing feature
+    /// struct of the same type).
+    pub fn insert<F>(&mut self, features: F)
+    where
+        F: Features
+            + vk::ExtendsPhysicalDeviceFeatures2
+            + vk::OutputChainStruct
+            + Send
+            + Sync
+            + 'static,
+    {
+        match self.get_mut::<F>() {
+            Some(existing) => *existing = features,
+            None => self.extensions.push(Box::new(features)),
+        }
+    }
+
+    /// Gets the Vulkan 1.0 features in this set.
+    #[inline]
+    pub fn features(&self) -> &vk::PhysicalDeviceFeatures {
+        &self.features.features
+    }
+
+    /// Gets the Vulkan 1.0 features in this set mutably.
+    #[inline]
+    pub fn features_mut(&mut self) -> &mut vk::PhysicalDeviceFeatures {
+        &mut self.features.features
+    }
+
+    /// Gets a feature struct in this set.
+    ///
+    /// Use [`FeatureSet::features`] to get the Vulkan 1.0 features.
+    #[inline]
+    pub fn get<F: Features + 'static>(&self) -> Option<&F> {
+        self.extensions
+            .iter()
+            .find_map(|e| e.as_any().downcast_ref::<F>())
+    }
+
+    /// Gets a feature struct in this set mutably.
+    #[inline]
+    pub fn get_mut<F: Features + 'static>(&mut self) -> Option<&mut F> {
+        self.extensions
+            .iter_mut()
+            .find_map(|e| e.as_any_mut().downcast_mut::<F>())
+    }
+
+    /// Gets whether no features are enabled in this set.
+    pub fn is_empty(&self) -> bool {
+        self.features.features.is_empty() && self.extensions.iter().all(|e| is_empty(e.flags()))
+    }
+
+    /// Gets the features in this set and whether they are enabled.
+    pub fn iter(&self) -> impl Iterator<Item = (Feature, bool)> + '_ {
+        let structure = <vk::PhysicalDeviceFeatures as Features>::NAME;
+        let names = <vk::PhysicalDeviceFeatures as Features>::NAMES;
+        let features = named(structure, names, self.features.features.flags());
+
+        let extensions = self
+            .extensions
+            .iter()
+            .flat_map(|e| named(e.name(), e.names(), e.flags()));
+
+        features.chain(extensions)
+    }
+
+    /// Gets the features enabled in this set.
+    pub fn enabled(&self) -> Vec<Feature> {
+        self.iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(feature, _)| feature)
+            .collect()
+    }
+
+    /// Gets whether every feature enabled in `other` is also enabled in this
+    /// set.
+    pub fn is_superset(&self, other: &FeatureSet) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    /// Gets the features enabled in both this set and `other`.
+    ///
+    /// The result contains the same feature structs as this set (the features
+    /// in feature structs which are not in `other` are disabled).
+    pub fn intersection(&self, other: &FeatureSet) -> FeatureSet {
+        self.combine(other, |f, o| match o {
+            Some(o) => intersect(f, o),
+            None => f.fill(vk::FALSE),
+        })
+    }
+
+    /// Gets the features enabled in either this set or `other`.
+    ///
+    /// The result contains the feature structs in this set followed by any
+    /// other feature structs in `other`.
+    pub fn union(&self, other: &FeatureSet) -> FeatureSet {
+        let mut result = self.combine(other, |f, o| unite(f, o.unwrap_or(&[])));
+        for extension in &other.extensions {
+            if result.find(extension.as_any().type_id()).is_none() {
+                result.extensions.push(extension.clone_box());
+            }
+        }
+
+        result
+    }
+
+    /// Gets the features enabled in this set but not in `other`.
+    ///
+    /// The result contains the same feature structs as this set.
+    pub fn difference(&self, other: &FeatureSet) -> FeatureSet {
+        self.combine(other, |f, o| subtract(f, o.unwrap_or(&[])))
+    }
+
+    /// Gets the features enabled in this set which are not enabled in
+    /// `available`.
+    pub fn missing(&self, available: &FeatureSet) -> Vec<Feature> {
+        self.difference(available).enabled()
+    }
+
+    /// Queries the features supported by a physical device for the feature
+    /// structs in this set.
+    ///
+    /// The result contains the same feature structs as this set. If the
+    /// instance was created for Vulkan 1.0 without the
+    /// `VK_KHR_get_physical_device_properties2` extension, only the Vulkan 1.0
+    /// features are queried.
+    ///
+    /// # Safety
+    ///
+    /// The physical device must support all of the feature structs in this
+    /// set (e.g., [`vk::PhysicalDeviceVulkan13Features`] requires a physical
+    /// device which supports Vulkan 1.3).
+    pub unsafe fn query(
+        &self,
+        instance: &Instance,
+        physical_device: vk::PhysicalDevice,
+    ) -> FeatureSet {
+        let mut supported = FeatureSet {
+            features: vk::PhysicalDeviceFeatures2::default(),
+            extensions: self.extensions.iter().map(|e| e.clone_empty()).collect(),
+        };
+
+        let extension = vk::KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION.name;
+        if instance.version() >= Version::V1_1_0 {
+            let features = supported.chain();
+            instance.get_physical_device_features2(physical_device, features);
+        } else if instance.extensions().contains(&extension) {
+            let features = supported.chain();
+            instance.get_physical_device_features2_khr(physical_device, features);
+        } else {
+            supported.features.features = instance.get_physical_device_features(physical_device);
+        }
+
+        supported
+    }
+
+    /// Checks that the features enabled in this set are supported by a
+    /// physical device and returns the pointer chain for this set (see
+    /// [`FeatureSet::chain`]).
+    ///
+    /// If any features are not supported, [`FeatureError::Missing`] is
+    /// returned with the names of the unsupported features.
+    ///
+    /// # Safety
+    ///
+    /// See [`FeatureSet::query`].
+    pub unsafe fn enable(
+        &mut self,
+        instance: &Instance,
+        physical_device: vk::PhysicalDevice,
+    ) -> Result<&mut vk::PhysicalDeviceFeatures2, FeatureError> {
+        let supported = self.query(instance, physical_device);
+
+        let missing = self.missing(&supported);
+        if !missing.is_empty() {
+            return Err(FeatureError::Missing(missing));
+        }
+
+        Ok(self.chain())
+    }
+
+    /// Links the feature structs in this set into a pointer chain and returns
+    /// the head of the pointer chain.
+    ///
+    /// The pointer chain can be used to query supported features with
+    /// [`InstanceV1_1::get_physical_device_features2`] or to enable features
+    /// by adding it to the pointer chain of a [`vk::DeviceCreateInfo`] (in
+    /// which case [`vk::DeviceCreateInfo::enabled_features`] must be null).
+    pub fn chain(&mut self) -> &mut vk::PhysicalDeviceFeatures2 {
+        let mut next = ptr::null_mut();
+        for extension in self.extensions.iter_mut().rev() {
+            let base = extension.base_mut();
+            unsafe { (*base).next = next };
+            next = base;
+        }
+
+        self.features.next = next.cast();
+        &mut self.features
+    }
+
+    /// Combines the feature flags of this set with the feature flags of the
+    /// matching feature structs in another set.
+    fn combine(
+        &self,
+        other: &FeatureSet,
+        f: impl Fn(&mut [vk::Bool32], Option<&[vk::Bool32]>),
+    ) -> FeatureSet {
+        let mut result = self.clone();
+
+        let features = result.features.features.flags_mut();
+        f(features, Some(other.features.features.flags()));
+
+        for extension in &mut result.extensions {
+            let other = other.find(extension.as_any().type_id());
+            f(extension.flags_mut(), other.map(|e| e.flags()));
+        }
+
+        result
+    }
+
+    /// Finds the feature struct with the supplied type in this set.
+    fn find(&self, type_id: TypeId) -> Option<&dyn Extension> {
+        self.extensions
+            .iter()
+            .find(|e| e.as_any().type_id() == type_id)
+            .map(|e| &**e)
+    }
+}
+
+/// Gets whether no feature flags are enabled.
+fn is_empty(flags: &[vk::Bool32]) -> bool {
+    flags.iter().all(|f| *f == vk::FALSE)
+}
+
+/// Gets the features and whether they are enabled from a list of feature
+/// flags.
+fn named<'a>(
+    structure: &'static str,
+    names: &'static [&'static str],
+    flags: &'a [vk::Bool32],
+) -> impl Iterator<Item = (Feature, bool)> + 'a {
+    names
+        .iter()
+        .zip(flags)
+        .map(move |(n, f)| (Feature { structure, name: n }, *f != vk::FALSE))
+}
+
+impl Clone for FeatureSet {
+    fn clone(&self) -> Self {
+        Self {
+            features: vk::PhysicalDeviceFeatures2 {
+                next: ptr::null_mut(),
+                ..self.features
+            },
+            extensions: self.extensions.iter().map(|e| e.clone_box()).collect(),
+        }
+    }
+}
+
+impl Default for FeatureSet {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for FeatureSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.enabled()).finish()
+    }
+}
@@ -0,0 +1,401 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Futures for Vulkan synchronization objects.
+//!
+//! A [`Waiter`] owns a background thread which waits on fences and timeline
+//! semaphores on behalf of [`WaitFuture`]s so that asynchronous tasks can wait
+//! for work submitted to the device without blocking the threads of their
+//! executor. The futures do not depend on any particular executor.
+//!
+//! The background thread waits on all of the pending fences (and all of the
+//! pending timeline semaphores) at once with [`DeviceV1_0::wait_for_fences`]
+//! (and [`DeviceV1_2::wait_semaphores`]) using a short timeout so that fences
+//! and timeline semaphores that are waited on while the thread is waiting are
+//! picked up promptly.
+//!
+//! ```no_run
+//! # use vulkanalia::prelude::v1_0::*;
+//! # use vulkanalia::future::Waiter;
+//! # async fn example(waiter: &Waiter, fence: vk::Fence) -> VkResult<()> {
+//! // Submit work which signals `fence`...
+//!
+//! unsafe { waiter.wait_fence(fence) }.await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`DeviceV1_0::wait_for_fences`]: crate::vk::DeviceV1_0::wait_for_fences
+//! [`DeviceV1_2::wait_semaphores`]: crate::vk::DeviceV1_2::wait_semaphores
+
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+
+use crate::prelude::v1_2::*;
+use crate::sync::supports_timeline_semaphores;
+
+/// The default timeout used by the background thread of a [`Waiter`].
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(1);
+
+/// A synchronization object being waited on.
+#[derive(Copy, Clone, Debug)]
+enum Object {
+    Fence(vk::Fence),
+    Semaphore(vk::Semaphore, u64),
+}
+
+/// The state shared between a [`WaitFuture`] and the background thread.
+#[derive(Default)]
+struct Slot {
+    result: Option<VkResult<()>>,
+    waker: Option<Waker>,
+}
+
+/// A pending wait on a synchronization object.
+struct Pending {
+    object: Object,
+    slot: Arc<Mutex<Slot>>,
+}
+
+impl Pending {
+    /// Returns whether the future for this wait has been dropped.
+    fn is_cancelled(&self) -> bool {
+        Arc::strong_count(&self.slot) == 1
+    }
+
+    /// Completes this wait and wakes the task waiting on its future (if any).
+    fn complete(&self, result: VkResult<()>) {
+        let waker = {
+            let mut slot = lock(&self.slot);
+            slot.result = Some(result);
+            slot.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    pending: Vec<Pending>,
+    shutdown: bool,
+}
+
+struct Shared {
+    device: Device,
+    timeout: Duration,
+    state: Mutex<State>,
+    condvar: Condvar,
+}
+
+/// Waits on fences and timeline semaphores in a background thread on behalf
+/// of [`WaitFuture`]s.
+///
+/// The [`Device`] used to create the waiter is cloned (which only increments a
+/// reference count) and used by the background thread, so the waiter must be
+/// dropped before the device is destroyed. Dropping the waiter stops (and
+/// joins) the background thread and completes any futures that are still
+/// pending with [`vk::ErrorCode::UNKNOWN`].
+pub struct Waiter {
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Waiter {
+    /// Creates a waiter which uses [`DEFAULT_TIMEOUT`].
+    ///
+    /// # Panics
+    ///
+    /// * the background thread could not be spawned
+    ///
+    /// # Safety
+    ///
+    /// `device` must not have been destroyed.
+    #[inline]
+    pub unsafe fn new(device: &Device) -> Self {
+        Self::with_timeout(device, DEFAULT_TIMEOUT)
+    }
+
+    /// Creates a waiter whose background thread waits on the pending fences
+    /// and timeline semaphores for at most `timeout` at a time.
+    ///
+    /// Shorter timeouts allow the background thread to start waiting on newly
+    /// pending fences and timeline semaphores sooner at the cost of calling
+    /// the wait commands more often.
+    ///
+    /// # Panics
+    ///
+    /// * the background thread could not be spawned
+    ///
+    /// # Safety
+    ///
+    /// `device` must not have been destroyed.
+    pub unsafe fn with_timeout(device: &Device, timeout: Duration) -> Self {
+        let shared = Arc::new(Shared {
+            device: device.clone(),
+            timeout,
+            state: Mutex::new(State::default()),
+            condvar: Condvar::new(),
+        });
+
+        let thread = thread::Builder::new()
+            .name("vulkanalia-waiter".into())
+            .spawn({
+                let shared = shared.clone();
+                move || run(&shared)
+            })
+            .expect("failed to spawn waiter thread");
+
+        Self {
+            shared,
+            thread: Some(thread),
+        }
+    }
+
+    /// Gets the device used by this waiter.
+    #[inline]
+    pub fn device(&self) -> &Device {
+        &self.shared.device
+    }
+
+    /// Returns a future which completes when a fence is signaled.
+    ///
+    /// # Safety
+    ///
+    /// `fence` must have been created with the device used by this waiter and
+    /// must not be destroyed until it has been signaled (even if the future
+    /// is dropped) or this waiter has been dropped.
+    pub unsafe fn wait_fence(&self, fence: vk::Fence) -> WaitFuture {
+        self.wait(Object::Fence(fence))
+    }
+
+    /// Returns a future which completes when the value of a timeline
+    /// semaphore is greater than or equal to `value`.
+    ///
+    /// The future completes with [`vk::ErrorCode::FEATURE_NOT_PRESENT`] if the
+    /// device used by this waiter is not a Vulkan 1.2+ device or was not
+    /// created with the `timelineSemaphore` feature enabled.
+    ///
+    /// # Safety
+    ///
+    /// `semaphore` must be a timeline semaphore created with the device used
+    /// by this waiter and must not be destroyed until its value has reached
+    /// `value` (even if the future is dropped) or this waiter has been
+    /// dropped.
+    pub unsafe fn wait_semaphore(&self, semaphore: vk::Semaphore, value: u64) -> WaitFuture {
+        if !supports_timeline_semaphores(&self.shared.device) {
+            return WaitFuture::ready(Err(vk::ErrorCode::FEATURE_NOT_PRESENT));
+        }
+
+        self.wait(Object::Semaphore(semaphore, value))
+    }
+
+    fn wait(&self, object: Object) -> WaitFuture {
+        let slot = Arc::new(Mutex::new(Slot::default()));
+
+        let mut state = lock(&self.shared.state);
+        state.pending.push(Pending {
+            object,
+            slot: slot.clone(),
+        });
+        self.shared.condvar.notify_one();
+
+        WaitFuture { slot }
+    }
+}
+
+impl fmt::Debug for Waiter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Waiter")
+            .field("timeout", &self.shared.timeout)
+            .field("pending", &lock(&self.shared.state).pending.len())
+            .finish()
+    }
+}
+
+impl Drop for Waiter {
+    fn drop(&mut self) {
+        lock(&self.shared.state).shutdown = true;
+        self.shared.condvar.notify_one();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// A future which completes when a fence or timeline semaphore being waited on
+/// by a [`Waiter`] is signaled (or waiting on it fails).
+///
+/// Dropping this future cancels the wait.
+#[must_use]
+pub struct WaitFuture {
+    slot: Arc<Mutex<Slot>>,
+}
+
+impl WaitFuture {
+    fn ready(result: VkResult<()>) -> Self {
+        let slot = Slot {
+            result: Some(result),
+            waker: None,
+        };
+
+        Self {
+            slot: Arc::new(Mutex::new(slot)),
+        }
+    }
+}
+
+impl fmt::Debug for WaitFuture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WaitFuture")
+            .field("result", &lock(&self.slot).result)
+            .finish()
+    }
+}
+
+impl Future for WaitFuture {
+    type Output = VkResult<()>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = lock(&self.slot);
+        match slot.result {
+            Some(result) => Poll::Ready(result),
+            None => {
+                match &mut slot.waker {
+                    Some(waker) => waker.clone_from(context.waker()),
+                    None => slot.waker = Some(context.waker().clone()),
+                }
+
+                Poll::Pending
+            }
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Runs the background thread of a waiter.
+fn run(shared: &Shared) {
+    let timeout = shared.timeout.as_nanos().min(u64::MAX as u128) as u64;
+
+    let mut fences = Vec::new();
+    let mut semaphores = Vec::new();
+    let mut values = Vec::new();
+
+    loop {
+        // Wait for pending waits (or shutdown).
+
+        {
+            let mut state = lock(&shared.state);
+            loop {
+                state.pending.retain(|p| !p.is_cancelled());
+                if state.shutdown || !state.pending.is_empty() {
+                    break;
+                }
+
+                state = shared
+                    .condvar
+                    .wait(state)
+                    .unwrap_or_else(|e| e.into_inner());
+            }
+
+            if state.shutdown {
+                for pending in state.pending.drain(..) {
+                    pending.complete(Err(vk::ErrorCode::UNKNOWN));
+                }
+
+                return;
+            }
+
+            fences.clear();
+            semaphores.clear();
+            values.clear();
+            for pending in &state.pending {
+                match pending.object {
+                    Object::Fence(fence) => fences.push(fence),
+                    Object::Semaphore(semaphore, value) => {
+                        semaphores.push(semaphore);
+                        values.push(value);
+                    }
+                }
+            }
+        }
+
+        // Wait for any of the pending fences or timeline semaphores.
+
+        let result = unsafe { wait(&shared.device, &fences, &semaphores, &values, timeout) };
+
+        // Complete the waits for any signaled fences or timeline semaphores.
+
+        let mut state = lock(&shared.state);
+        state.pending.retain(|pending| {
+            let result = match result {
+                Ok(()) => unsafe { status(&shared.device, pending.object) },
+                Err(error) => Some(Err(error)),
+            };
+
+            match result {
+                Some(result) => {
+                    pending.complete(result);
+                    false
+                }
+                None => true,
+            }
+        });
+    }
+}
+
+/// Waits for any of a set of fences or timeline semaphores to be signaled.
+unsafe fn wait(
+    device: &Device,
+    fences: &[vk::Fence],
+    semaphores: &[vk::Semaphore],
+    values: &[u64],
+    timeout: u64,
+) -> VkResult<()> {
+    if !fences.is_empty() {
+        device.wait_for_fences(fences, false, timeout)?;
+    }
+
+    if !semaphores.is_empty() {
+        // Only wait on the timeline semaphores for the full timeout if there
+        // were no fences to wait on.
+        let timeout = if fences.is_empty() { timeout } else { 0 };
+        let info = vk::SemaphoreWaitInfo::builder()
+            .flags(vk::SemaphoreWaitFlags::ANY)
+            .semaphores(semaphores)
+            .values(values);
+        device.wait_semaphores(&info, timeout)?;
+    }
+
+    Ok(())
+}
+
+/// Gets the result of a wait on a fence or timeline semaphore (or `None` if
+/// the fence or timeline semaphore has not been signaled yet).
+unsafe fn status(device: &Device, object: Object) -> Option<VkResult<()>> {
+    match object {
+        Object::Fence(fence) => match device.get_fence_status(fence) {
+            Ok(vk::SuccessCode::SUCCESS) => Some(Ok(())),
+            Ok(_) => None,
+            Err(error) => Some(Err(error)),
+        },
+        Object::Semaphore(semaphore, value) => {
+            match device.get_semaphore_counter_value(semaphore) {
+                Ok(current) if current >= value => Some(Ok(())),
+                Ok(_) => None,
+                Err(error) => Some(Err(error)),
+            }
+        }
+    }
+}
@@ -0,0 +1,263 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Command interception.
+//!
+//! Instances and devices can be hooked with a [`CommandHook`] that is called
+//! before and after each of their commands is called (e.g., for timing,
+//! logging, or fault injection) using [`Instance::with_hook`] or
+//! [`Device::with_hook`].
+//!
+//! ```no_run
+//! # use vulkanalia::prelude::v1_0::*;
+//! # use vulkanalia::hook::CommandHook;
+//! struct Logger;
+//!
+//! impl CommandHook for Logger {
+//!     fn after(&self, command: &'static str, result: Option<vk::Result>) {
+//!         println!("{command}: {result:?}");
+//!     }
+//! }
+//!
+//! # fn example(device: &Device) {
+//! let device = unsafe { device.with_hook(Logger) }.unwrap();
+//! # }
+//! ```
+//!
+//! The hooks are only called for the commands called with the hooked instance
+//! or device (or its clones), not for the commands called with the instance or
+//! device it was created from (or any other hooked copy of it).
+//!
+//! The commands of a hooked instance or device are generated functions which
+//! find their hooks in one of a fixed number of slots so at most [`MAX_HOOKED`]
+//! hooked instances (and at most [`MAX_HOOKED`] hooked devices) can exist at
+//! once. A slot is released when the hooked instance or device (and all of its
+//! clones) have been dropped, after which any remaining uses of its commands
+//! call the unhooked commands (until the slot is reused).
+//!
+//! [`Device::with_hook`]: crate::Device::with_hook
+//! [`Instance::with_hook`]: crate::Instance::with_hook
+
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::vk::{self, DeviceCommands, InstanceCommands};
+
+/// The maximum number of hooked instances (or devices) that can exist at once.
+pub const MAX_HOOKED: usize = 8;
+
+/// A hook which is called before and after hooked Vulkan commands are called.
+pub trait CommandHook: Send + Sync + 'static {
+    /// Called before a Vulkan command (e.g., `vkCreateBuffer`) is called.
+    ///
+    /// If this method returns a result for a command that returns a
+    /// [`vk::Result`], the command will not be called and the returned result
+    /// will be returned from the command instead.
+    #[inline]
+    fn before(&self, command: &'static str) -> Option<vk::Result> {
+        let _ = command;
+        None
+    }
+
+    /// Called after a Vulkan command (e.g., `vkCreateBuffer`) is called.
+    ///
+    /// The result will be provided for commands that return a [`vk::Result`].
+    #[inline]
+    fn after(&self, command: &'static str, result: Option<vk::Result>) {
+        let _ = (command, result);
+    }
+}
+
+/// The unhooked commands and hooks for a hooked Vulkan instance or device.
+pub(crate) struct Hooked<C> {
+    pub commands: C,
+    hooks: Vec<Arc<dyn CommandHook>>,
+}
+
+impl<C> Hooked<C> {
+    /// Calls the hooks before a Vulkan command is called.
+    ///
+    /// Every hook is called even if an earlier hook returns a result, in which
+    /// case the result returned by the first such hook is returned.
+    #[inline]
+    pub fn before(&self, command: &'static str) -> Option<vk::Result> {
+        let mut result = None;
+        for hook in &self.hooks {
+            if let Some(value) = hook.before(command) {
+                result.get_or_insert(value);
+            }
+        }
+        result
+    }
+
+    /// Calls the hooks (in reverse order) after a Vulkan command is called.
+    #[inline]
+    pub fn after(&self, command: &'static str, result: Option<vk::Result>) {
+        for hook in self.hooks.iter().rev() {
+            hook.after(command, result);
+        }
+    }
+}
+
+/// A slot the hooked commands of a Vulkan instance or device find their
+/// unhooked commands and hooks in.
+///
+/// Slots initially contain commands which have not been loaded, but these are
+/// never called since the hooked commands for a slot are only created after
+/// the unhooked commands have been installed in the slot.
+struct Slot<C: 'static> {
+    used: AtomicBool,
+    hooked: LazyLock<RwLock<Arc<Hooked<C>>>>,
+}
+
+impl<C> Slot<C> {
+    const fn new(unloaded: fn() -> RwLock<Arc<Hooked<C>>>) -> Self {
+        Self {
+            used: AtomicBool::new(false),
+            hooked: LazyLock::new(unloaded),
+        }
+    }
+}
+
+fn unloaded_instance() -> RwLock<Arc<Hooked<InstanceCommands>>> {
+    let commands = unsafe { InstanceCommands::load(|_| None) };
+    RwLock::new(Arc::new(Hooked {
+        commands,
+        hooks: Vec::new(),
+    }))
+}
+
+fn unloaded_device() -> RwLock<Arc<Hooked<DeviceCommands>>> {
+    let commands = unsafe { DeviceCommands::load(|_| None) };
+    RwLock::new(Arc::new(Hooked {
+        commands,
+        hooks: Vec::new(),
+    }))
+}
+
+static INSTANCES: [Slot<InstanceCommands>; MAX_HOOKED] =
+    [const { Slot::new(unloaded_instance) }; MAX_HOOKED];
+static DEVICES: [Slot<DeviceCommands>; MAX_HOOKED] =
+    [const { Slot::new(unloaded_device) }; MAX_HOOKED];
+
+const HOOKED_INSTANCE_COMMANDS: [fn() -> InstanceCommands; MAX_HOOKED] = [
+    InstanceCommands::hooked::<0>,
+    InstanceCommands::hooked::<1>,
+    InstanceCommands::hooked::<2>,
+    InstanceCommands::hooked::<3>,
+    InstanceCommands::hooked::<4>,
+    InstanceCommands::hooked::<5>,
+    InstanceCommands::hooked::<6>,
+    InstanceCommands::hooked::<7>,
+];
+
+const HOOKED_DEVICE_COMMANDS: [fn() -> DeviceCommands; MAX_HOOKED] = [
+    DeviceCommands::hooked::<0>,
+    DeviceCommands::hooked::<1>,
+    DeviceCommands::hooked::<2>,
+    DeviceCommands::hooked::<3>,
+    DeviceCommands::hooked::<4>,
+    DeviceCommands::hooked::<5>,
+    DeviceCommands::hooked::<6>,
+    DeviceCommands::hooked::<7>,
+];
+
+fn read<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(|e| e.into_inner())
+}
+
+fn write<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The unhooked commands and hooks installed in a slot for a hooked Vulkan
+/// instance or device.
+///
+/// The hooks are removed from the slot and the slot is released when this is
+/// dropped.
+pub(crate) struct Installed<C: Copy + 'static> {
+    slot: &'static Slot<C>,
+    hooked: Arc<Hooked<C>>,
+}
+
+impl<C: Copy> Installed<C> {
+    /// Gets the unhooked commands.
+    #[inline]
+    pub fn commands(&self) -> C {
+        self.hooked.commands
+    }
+
+    /// Gets the hooks.
+    #[inline]
+    pub fn hooks(&self) -> &[Arc<dyn CommandHook>] {
+        &self.hooked.hooks
+    }
+}
+
+impl<C: Copy + 'static> Drop for Installed<C> {
+    fn drop(&mut self) {
+        let mut hooked = write(&self.slot.hooked);
+        *hooked = Arc::new(Hooked {
+            commands: self.hooked.commands,
+            hooks: Vec::new(),
+        });
+        self.slot.used.store(false, Ordering::Release);
+    }
+}
+
+/// Installs unhooked commands and hooks in an unused slot.
+///
+/// Returns the hooked commands for the slot and the installed commands and
+/// hooks (or `None` if every slot is in use).
+fn install<C: Copy>(
+    slots: &'static [Slot<C>; MAX_HOOKED],
+    hooked: &[fn() -> C; MAX_HOOKED],
+    commands: C,
+    hooks: Vec<Arc<dyn CommandHook>>,
+) -> Option<(C, Installed<C>)> {
+    let index = slots.iter().position(|s| {
+        s.used
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+    })?;
+
+    let slot = &slots[index];
+    let installed = Arc::new(Hooked { commands, hooks });
+    *write(&slot.hooked) = installed.clone();
+
+    let installed = Installed {
+        slot,
+        hooked: installed,
+    };
+
+    Some((hooked[index](), installed))
+}
+
+#[inline]
+pub(crate) fn install_instance(
+    commands: InstanceCommands,
+    hooks: Vec<Arc<dyn CommandHook>>,
+) -> Option<(InstanceCommands, Installed<InstanceCommands>)> {
+    install(&INSTANCES, &HOOKED_INSTANCE_COMMANDS, commands, hooks)
+}
+
+#[inline]
+pub(crate) fn install_device(
+    commands: DeviceCommands,
+    hooks: Vec<Arc<dyn CommandHook>>,
+) -> Option<(DeviceCommands, Installed<DeviceCommands>)> {
+    install(&DEVICES, &HOOKED_DEVICE_COMMANDS, commands, hooks)
+}
+
+/// Gets the unhooked commands and hooks installed in an instance slot.
+#[inline]
+pub(crate) fn instance<const S: usize>() -> Arc<Hooked<InstanceCommands>> {
+    read(&INSTANCES[S].hooked).clone()
+}
+
+/// Gets the unhooked commands and hooks installed in a device slot.
+#[inline]
+pub(crate) fn device<const S: usize>() -> Arc<Hooked<DeviceCommands>> {
+    read(&DEVICES[S].hooked).clone()
+}
@@ -0,0 +1,378 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Vulkan instance creation.
+//!
+//! [`InstanceBuilder`] creates a Vulkan instance after checking that the
+//! requested layers and extensions are supported by the Vulkan implementation
+//! (see [`Entry::instance_builder`]). If any required layers or extensions are
+//! not supported, the instance is not created and the unsupported names are
+//! reported by [`InstanceError::Missing`].
+//!
+//! ```no_run
+//! # use vulkanalia::prelude::v1_0::*;
+//! # use vulkanalia::Version;
+//! # fn example(entry: &Entry) -> Result<(), vulkanalia::instance::InstanceError> {
+//! let validation = vk::ExtensionName::from_bytes(b"VK_LAYER_KHRONOS_validation");
+//!
+//! let instance = unsafe {
+//!     entry
+//!         .instance_builder()
+//!         .application_name(c"Example")
+//!         .minimum_api_version(Version::V1_1_0)
+//!         .optional_layer(validation)
+//!         .extension(vk::KHR_SURFACE_EXTENSION.name)
+//!         .optional_extension(vk::EXT_DEBUG_UTILS_EXTENSION.name)
+//!         .portability_enumeration(true)
+//!         .create()?
+//! };
+//!
+//! if instance.layers().contains(&validation) {
+//!     println!("Validation enabled.");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::collections::btree_set::BTreeSet;
+use alloc::ffi::CString;
+use alloc::vec::Vec;
+use core::ffi::CStr;
+use core::fmt;
+use core::ptr;
+
+#[cfg(all(feature = "no_std_error", not(feature = "std")))]
+use core::error;
+#[cfg(feature = "std")]
+use std::error;
+
+use crate::Version;
+use crate::prelude::v1_0::*;
+
+/// An error encountered while creating a Vulkan instance with an
+/// [`InstanceBuilder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InstanceError {
+    /// Required layers and/or extensions are not supported.
+    Missing {
+        /// The required layers that are not supported.
+        layers: Vec<vk::ExtensionName>,
+        /// The required extensions that are not supported.
+        extensions: Vec<vk::ExtensionName>,
+    },
+    /// The instance-level version of the Vulkan implementation is lower than
+    /// the required minimum version.
+    Version {
+        /// The required minimum version.
+        required: Version,
+        /// The instance-level version of the Vulkan implementation.
+        available: Version,
+    },
+    /// A Vulkan command failed.
+    Vulkan(vk::ErrorCode),
+}
+
+impl From<vk::ErrorCode> for InstanceError {
+    #[inline]
+    fn from(error: vk::ErrorCode) -> Self {
+        Self::Vulkan(error)
+    }
+}
+
+impl fmt::Display for InstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Missing { layers, extensions } => {
+                write!(
+                    f,
+                    "missing layers ({layers:?}) and/or extensions ({extensions:?})"
+                )
+            }
+            Self::Version {
+                required,
+                available,
+            } => {
+                write!(
+                    f,
+                    "required Vulkan {required} but only Vulkan {available} is available"
+                )
+            }
+            Self::Vulkan(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "no_std_error"))]
+impl error::Error for InstanceError {}
+
+/// A builder for a Vulkan instance which checks that the requested layers and
+/// extensions are supported before creating the instance.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Clone, Debug)]
+pub struct InstanceBuilder<'a> {
+    entry: &'a Entry,
+    application_name: Option<CString>,
+    application_version: Version,
+    engine_name: Option<CString>,
+    engine_version: Version,
+    api_version: Option<Version>,
+    minimum_api_version: Version,
+    flags: vk::InstanceCreateFlags,
+    layers: Vec<(vk::ExtensionName, bool)>,
+    extensions: Vec<(vk::ExtensionName, bool)>,
+    portability_enumeration: bool,
+}
+
+impl<'a> InstanceBuilder<'a> {
+    /// Constructs a new builder for a Vulkan instance.
+    #[inline]
+    pub fn new(entry: &'a Entry) -> Self {
+        Self {
+            entry,
+            application_name: None,
+            application_version: Version::V1_0_0,
+            engine_name: None,
+            engine_version: Version::V1_0_0,
+            api_version: None,
+            minimum_api_version: Version::V1_0_0,
+            flags: vk::InstanceCreateFlags::empty(),
+            layers: Vec::new(),
+            extensions: Vec::new(),
+            portability_enumeration: false,
+        }
+    }
+
+    /// Sets the name of the application.
+    #[inline]
+    pub fn application_name(mut self, name: &CStr) -> Self {
+        self.application_name = Some(name.into());
+        self
+    }
+
+    /// Sets the version of the application.
+    #[inline]
+    pub fn application_version(mut self, version: Version) -> Self {
+        self.application_version = version;
+        self
+    }
+
+    /// Sets the name of the engine used to create the application.
+    #[inline]
+    pub fn engine_name(mut self, name: &CStr) -> Self {
+        self.engine_name = Some(name.into());
+        self
+    }
+
+    /// Sets the version of the engine used to create the application.
+    #[inline]
+    pub fn engine_version(mut self, version: Version) -> Self {
+        self.engine_version = version;
+        self
+    }
+
+    /// Sets the highest Vulkan version the application is designed to use.
+    ///
+    /// The Vulkan version requested when creating the instance is the lower
+    /// of this version and the instance-level version of the Vulkan
+    /// implementation (see [`Entry::version`]). If this is not set, the
+    /// instance-level version of the Vulkan implementation is requested.
+    #[inline]
+    pub fn api_version(mut self, version: Version) -> Self {
+        self.api_version = Some(version);
+        self
+    }
+
+    /// Sets the lowest Vulkan version the application supports.
+    ///
+    /// If the instance-level version of the Vulkan implementation (see
+    /// [`Entry::version`]) is lower than this version, the instance is not
+    /// created and [`InstanceError::Version`] is returned.
+    #[inline]
+    pub fn minimum_api_version(mut self, version: Version) -> Self {
+        self.minimum_api_version = version;
+        self
+    }
+
+    /// Sets the flags used to create the instance.
+    #[inline]
+    pub fn flags(mut self, flags: vk::InstanceCreateFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Adds a required layer.
+    #[inline]
+    pub fn layer(mut self, name: vk::ExtensionName) -> Self {
+        self.layers.push((name, true));
+        self
+    }
+
+    /// Adds a layer that is only enabled if it is supported.
+    #[inline]
+    pub fn optional_layer(mut self, name: vk::ExtensionName) -> Self {
+        self.layers.push((name, false));
+        self
+    }
+
+    /// Adds required layers.
+    #[inline]
+    pub fn layers(mut self, names: impl IntoIterator<Item = vk::ExtensionName>) -> Self {
+        self.layers.extend(names.into_iter().map(|n| (n, true)));
+        self
+    }
+
+    /// Adds a required extension.
+    #[inline]
+    pub fn extension(mut self, name: vk::ExtensionName) -> Self {
+        self.extensions.push((name, true));
+        self
+    }
+
+    /// Adds an extension that is only enabled if it is supported.
+    #[inline]
+    pub fn optional_extension(mut self, name: vk::ExtensionName) -> Self {
+        self.extensions.push((name, false));
+        self
+    }
+
+    /// Adds required extensions.
+    #[inline]
+    pub fn extensions(mut self, names: impl IntoIterator<Item = vk::ExtensionName>) -> Self {
+        self.extensions.extend(names.into_iter().map(|n| (n, true)));
+        self
+    }
+
+    /// Sets whether physical devices which do not fully conform to the Vulkan
+    /// specification (e.g., MoltenVK on macOS) should be enumerated.
+    ///
+    /// If enabled and the `VK_KHR_portability_enumeration` extension is
+    /// supported, the extension and the
+    /// [`vk::InstanceCreateFlags::ENUMERATE_PORTABILITY_KHR`] flag are used to
+    /// create the instance.
+    #[inline]
+    pub fn portability_enumeration(mut self, enabled: bool) -> Self {
+        self.portability_enumeration = enabled;
+        self
+    }
+
+    /// Checks the requested layers and extensions and creates the Vulkan
+    /// instance.
+    ///
+    /// The extensions provided by the enabled layers are considered supported.
+    /// Optional layers and extensions are only enabled if they are supported.
+    ///
+    /// # Safety
+    ///
+    /// See [`Entry::create_instance`].
+    pub unsafe fn create(self) -> Result<Instance, InstanceError> {
+        // Negotiate the Vulkan version.
+
+        let available = self.entry.version()?;
+        if available < self.minimum_api_version {
+            return Err(InstanceError::Version {
+                required: self.minimum_api_version,
+                available,
+            });
+        }
+
+        let version = self.api_version.map_or(available, |v| v.min(available));
+        let version = Version::new(version.major, version.minor, 0);
+
+        // Check the requested layers.
+
+        let supported = self
+            .entry
+            .enumerate_instance_layer_properties()?
+            .into_iter()
+            .map(|l| l.layer_name)
+            .collect::<BTreeSet<_>>();
+
+        let (layers, missing_layers) = select(&self.layers, &supported);
+
+        // Check the requested extensions.
+
+        let mut supported = BTreeSet::new();
+        for layer in [None].into_iter().chain(layers.iter().map(Some)) {
+            let layer = layer.map(|l| l.as_cstr());
+            let properties = self.entry.enumerate_instance_extension_properties(layer)?;
+            supported.extend(properties.into_iter().map(|e| e.extension_name));
+        }
+
+        let mut extensions = self.extensions.clone();
+        let mut flags = self.flags;
+        let portability = vk::KHR_PORTABILITY_ENUMERATION_EXTENSION.name;
+        if self.portability_enumeration && supported.contains(&portability) {
+            extensions.push((portability, false));
+            flags |= vk::InstanceCreateFlags::ENUMERATE_PORTABILITY_KHR;
+        }
+
+        let (extensions, missing_extensions) = select(&extensions, &supported);
+
+        if !missing_layers.is_empty() || !missing_extensions.is_empty() {
+            return Err(InstanceError::Missing {
+                layers: missing_layers,
+                extensions: missing_extensions,
+            });
+        }
+
+        // Create the instance.
+
+        let application_info = vk::ApplicationInfo {
+            application_name: self
+                .application_name
+                .as_ref()
+                .map_or(ptr::null(), |n| n.as_ptr()),
+            application_version: self.application_version.into(),
+            engine_name: self
+                .engine_name
+                .as_ref()
+                .map_or(ptr::null(), |n| n.as_ptr()),
+            engine_version: self.engine_version.into(),
+            api_version: version.into(),
+            ..Default::default()
+        };
+
+        let layers = layers.iter().map(|n| n.as_ptr()).collect::<Vec<_>>();
+        let extensions = extensions.iter().map(|n| n.as_ptr()).collect::<Vec<_>>();
+
+        let info = vk::InstanceCreateInfo::builder()
+            .flags(flags)
+            .application_info(&application_info)
+            .enabled_layer_names(&layers)
+            .enabled_extension_names(&extensions);
+
+        Ok(self.entry.create_instance(&info, None)?)
+    }
+}
+
+/// Selects the supported names (without duplicates) from a list of requested
+/// names and returns them along with the required names that are not
+/// supported.
+fn select(
+    requested: &[(vk::ExtensionName, bool)],
+    supported: &BTreeSet<vk::ExtensionName>,
+) -> (Vec<vk::ExtensionName>, Vec<vk::ExtensionName>) {
+    let mut selected = Vec::new();
+    let mut missing = Vec::new();
+
+    for (name, required) in requested {
+        if supported.contains(name) {
+            if !selected.contains(name) {
+                selected.push(*name);
+            }
+        } else if *required && !missing.contains(name) {
+            missing.push(*name);
+        }
+    }
+
+    (selected, missing)
+}
+
+impl Entry {
+    /// Creates a builder for a Vulkan instance which checks that the requested
+    /// layers and extensions are supported before creating the instance.
+    #[inline]
+    pub fn instance_builder(&self) -> InstanceBuilder<'_> {
+        InstanceBuilder::new(self)
+    }
+}
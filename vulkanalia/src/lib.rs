@@ -62,7 +62,20 @@ extern crate alloc;
 
 pub mod bytecode;
 pub mod chain;
+pub mod debug;
+pub mod features;
+#[cfg(feature = "std")]
+pub mod future;
+#[cfg(feature = "std")]
+pub mod hook;
+pub mod instance;
 pub mod loader;
+pub mod owned;
+pub mod physical_device;
+#[cfg(feature = "report")]
+pub mod report;
+pub mod resolve;
+pub mod sync;
 pub mod vk;
 
 #[cfg(feature = "window")]
@@ -70,12 +83,16 @@ pub mod window;
 
 use alloc::boxed::Box;
 use alloc::collections::btree_set::BTreeSet;
+use alloc::string::String;
 use alloc::sync::Arc;
-use core::ffi::c_char;
+use alloc::vec::Vec;
+use core::ffi::{CStr, c_char};
 use core::fmt;
 use core::mem;
 use core::slice;
 
+use self::debug::DebugUtilsCommands;
+use self::features::FeatureSet;
 use self::loader::{Loader, LoaderError};
 use self::prelude::v1_0::*;
 use self::vk::{DeviceCommands, EntryCommands, InstanceCommands, StaticCommands};
@@ -216,11 +233,19 @@ impl From<Version> for (u32, u32, u32) {
 }
 
 /// A Vulkan entry point.
+///
+/// The commands and other state for a Vulkan entry point are shared between
+/// clones of the entry point so cloning an entry point is cheap.
 #[derive(Clone)]
 pub struct Entry {
-    _loader: Option<Arc<dyn Loader>>,
+    state: Arc<EntryState>,
+}
+
+struct EntryState {
+    _loader: Option<Box<dyn Loader>>,
     static_commands: StaticCommands,
     commands: EntryCommands,
+    loaded: BTreeSet<String>,
 }
 
 impl Entry {
@@ -231,13 +256,19 @@ impl Entry {
     /// `static_commands` must contain valid Vulkan commands.
     #[inline]
     pub unsafe fn from_commands(static_commands: &StaticCommands) -> Self {
+        let mut loaded = BTreeSet::new();
         let load = |n| (static_commands.get_instance_proc_addr)(vk::Instance::null(), n);
-        let commands = EntryCommands::load(load);
+        let commands = EntryCommands::load(record(&mut loaded, load));
 
-        Self {
+        let state = EntryState {
             _loader: None,
             static_commands: *static_commands,
             commands,
+            loaded,
+        };
+
+        Self {
+            state: Arc::new(state),
         }
     }
 
@@ -250,7 +281,7 @@ impl Entry {
     /// [`Loader::load`] for the [`Loader`] implementation used must be upheld.
     #[inline]
     pub unsafe fn new(loader: impl Loader + 'static) -> Result<Self, Box<dyn LoaderError>> {
-        let loader = Arc::new(loader);
+        let loader = Box::new(loader);
 
         type F = extern "system" fn();
 
@@ -261,20 +292,38 @@ impl Entry {
             get_instance_proc_addr,
         };
 
+        let mut loaded = BTreeSet::new();
         let load = |n| get_instance_proc_addr(vk::Instance::null(), n);
-        let commands = EntryCommands::load(load);
+        let commands = EntryCommands::load(record(&mut loaded, load));
 
-        Ok(Self {
+        let state = EntryState {
             _loader: Some(loader),
             static_commands,
             commands,
+            loaded,
+        };
+
+        Ok(Self {
+            state: Arc::new(state),
         })
     }
 
     /// Gets the instance-level version of this Vulkan entry point.
     #[inline]
     pub fn version(&self) -> VkResult<Version> {
-        unsafe { get_version(self.static_commands.get_instance_proc_addr) }
+        unsafe { get_version(self.state.static_commands.get_instance_proc_addr) }
+    }
+
+    /// Returns whether an entry command (e.g., `vkCreateInstance`) was loaded
+    /// for this Vulkan entry point.
+    ///
+    /// Commands that were not loaded will panic if called unless the
+    /// `fallible_commands` feature is enabled and the command returns a
+    /// [`vk::Result`] (in which case it will return
+    /// [`vk::Result::ERROR_EXTENSION_NOT_PRESENT`] instead).
+    #[inline]
+    pub fn is_command_loaded(&self, name: &str) -> bool {
+        self.state.loaded.contains(name)
     }
 
     /// Creates a Vulkan instance using this Vulkan entry point.
@@ -292,7 +341,7 @@ impl Entry {
         allocator: Option<&vk::AllocationCallbacks>,
     ) -> VkResult<Instance> {
         let instance = EntryV1_0::create_instance(self, info, allocator)?;
-        Instance::from_created(&self.static_commands, info, instance)
+        Instance::from_created(&self.state.static_commands, info, instance)
     }
 }
 
@@ -302,17 +351,28 @@ impl fmt::Debug for Entry {
     }
 }
 
-unsafe impl Send for Entry {}
-unsafe impl Sync for Entry {}
+unsafe impl Send for EntryState {}
+unsafe impl Sync for EntryState {}
 
 /// A Vulkan instance.
+///
+/// The commands and other state for a Vulkan instance are shared between
+/// clones of the instance so cloning an instance is cheap.
 #[derive(Clone)]
 pub struct Instance {
     handle: vk::Instance,
+    state: Arc<InstanceState>,
+}
+
+struct InstanceState {
     commands: InstanceCommands,
+    loaded: BTreeSet<String>,
     version: Version,
+    api_version: Version,
     extensions: BTreeSet<vk::ExtensionName>,
     layers: BTreeSet<vk::ExtensionName>,
+    #[cfg(feature = "std")]
+    hooks: Option<hook::Installed<InstanceCommands>>,
 }
 
 impl Instance {
@@ -327,39 +387,125 @@ impl Instance {
         info: &vk::InstanceCreateInfo,
         instance: vk::Instance,
     ) -> VkResult<Self> {
+        let mut loaded = BTreeSet::new();
         let load = |n| (static_commands.get_instance_proc_addr)(instance, n);
-        let commands = InstanceCommands::load(load);
+        let commands = InstanceCommands::load(record(&mut loaded, load));
 
         let version = get_version(static_commands.get_instance_proc_addr)?;
+        let api_version = get_api_version(info);
 
         let extensions = get_names(info.enabled_extension_count, info.enabled_extension_names);
         let layers = get_names(info.enabled_layer_count, info.enabled_layer_names);
 
-        Ok(Self {
-            handle: instance,
+        let state = InstanceState {
             commands,
+            loaded,
             version,
+            api_version,
             extensions,
             layers,
+            #[cfg(feature = "std")]
+            hooks: None,
+        };
+
+        Ok(Self {
+            handle: instance,
+            state: Arc::new(state),
         })
     }
 
     /// Gets the version for this Vulkan instance.
     #[inline]
     pub fn version(&self) -> Version {
-        self.version
+        self.state.version
+    }
+
+    /// Gets the Vulkan API version the application requested when creating
+    /// this Vulkan instance (`1.0.0` if no API version was requested).
+    #[inline]
+    pub fn api_version(&self) -> Version {
+        self.state.api_version
     }
 
     /// Gets the loaded extensions for this Vulkan instance.
     #[inline]
     pub fn extensions(&self) -> &BTreeSet<vk::ExtensionName> {
-        &self.extensions
+        &self.state.extensions
     }
 
     /// Gets the loaded layers for this Vulkan instance.
     #[inline]
     pub fn layers(&self) -> &BTreeSet<vk::ExtensionName> {
-        &self.layers
+        &self.state.layers
+    }
+
+    /// Returns whether an instance command (e.g., `vkCreateDebugUtilsMessengerEXT`)
+    /// was loaded for this Vulkan instance.
+    ///
+    /// See [`Entry::is_command_loaded`] for the behavior of commands that were
+    /// not loaded.
+    #[inline]
+    pub fn is_command_loaded(&self, name: &str) -> bool {
+        self.state.loaded.contains(name)
+    }
+
+    /// Creates a copy of this Vulkan instance that uses the supplied instance
+    /// commands.
+    ///
+    /// # Safety
+    ///
+    /// `commands` must contain valid Vulkan commands for this instance.
+    #[inline]
+    pub unsafe fn with_commands(&self, commands: InstanceCommands) -> Self {
+        Self {
+            handle: self.handle,
+            state: Arc::new(self.copy_state(commands)),
+        }
+    }
+
+    /// Creates a copy of this Vulkan instance that calls the supplied hook
+    /// before and after each instance command is called (see the [`hook`]
+    /// module).
+    ///
+    /// If this instance is hooked, the hook is called after the existing hooks.
+    ///
+    /// Returns `None` if [`hook::MAX_HOOKED`] hooked instances already exist.
+    ///
+    /// # Safety
+    ///
+    /// `hook` must not return a successful result from
+    /// [`CommandHook::before`](hook::CommandHook::before) for a command that
+    /// returns values through its parameters (since the command is not called).
+    #[cfg(feature = "std")]
+    pub unsafe fn with_hook(&self, hook: impl hook::CommandHook) -> Option<Self> {
+        let (commands, mut hooks) = match &self.state.hooks {
+            Some(installed) => (installed.commands(), installed.hooks().to_vec()),
+            None => (self.state.commands, Vec::new()),
+        };
+
+        hooks.push(Arc::new(hook));
+        let (commands, installed) = hook::install_instance(commands, hooks)?;
+
+        let mut state = self.copy_state(commands);
+        state.hooks = Some(installed);
+
+        Some(Self {
+            handle: self.handle,
+            state: Arc::new(state),
+        })
+    }
+
+    fn copy_state(&self, commands: InstanceCommands) -> InstanceState {
+        InstanceState {
+            commands,
+            loaded: self.state.loaded.clone(),
+            version: self.state.version,
+            api_version: self.state.api_version,
+            extensions: self.state.extensions.clone(),
+            layers: self.state.layers.clone(),
+            #[cfg(feature = "std")]
+            hooks: None,
+        }
     }
 
     /// Creates a Vulkan device using this Vulkan instance.
@@ -378,11 +524,19 @@ impl Instance {
     ) -> VkResult<Device> {
         let device = InstanceV1_0::create_device(self, physical_device, info, allocator)?;
 
-        Device::from_created(
-            self.commands.get_device_proc_addr,
+        // Applications can only use the device-level functionality of Vulkan
+        // versions supported by both the physical device and the API version
+        // requested by the application.
+        let properties = self.get_physical_device_properties(physical_device);
+        let version = self.state.api_version.min(properties.api_version.into());
+
+        Device::load_enabled(
+            self.state.commands.get_device_proc_addr,
             physical_device,
             info,
             device,
+            version,
+            &self.state.extensions,
         )
     }
 }
@@ -391,8 +545,8 @@ impl fmt::Debug for Instance {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Instance")
             .field("handle", &self.handle)
-            .field("extensions", &self.extensions)
-            .field("layers", &self.layers)
+            .field("extensions", &self.state.extensions)
+            .field("layers", &self.state.layers)
             .finish()
     }
 }
@@ -401,18 +555,44 @@ unsafe impl Send for Instance {}
 unsafe impl Sync for Instance {}
 
 /// A Vulkan device.
+///
+/// The commands and other state for a Vulkan device are shared between clones
+/// of the device so cloning a device is cheap.
 #[derive(Clone)]
 pub struct Device {
     handle: vk::Device,
+    state: Arc<DeviceState>,
+}
+
+struct DeviceState {
     commands: DeviceCommands,
+    loaded: BTreeSet<String>,
     physical_device: vk::PhysicalDevice,
+    version: Version,
     extensions: BTreeSet<vk::ExtensionName>,
     layers: BTreeSet<vk::ExtensionName>,
+    enabled_features: FeatureSet,
+    queue_families: Vec<DeviceQueueFamily>,
+    debug_utils: Option<DebugUtilsCommands>,
+    #[cfg(feature = "std")]
+    hooks: Option<hook::Installed<DeviceCommands>>,
 }
 
 impl Device {
     /// Loads a Vulkan device from a previously created [`vk::Device`].
     ///
+    /// All of the device commands will be loaded (see
+    /// [`Device::from_created_enabled`] to only load the device commands
+    /// provided by a Vulkan version or by the enabled extensions).
+    ///
+    /// The features and queues requested in `info` are copied so they can be
+    /// retrieved later (e.g., with [`Device::enabled_features`]).
+    ///
+    /// The extensions enabled for the instance `device` was created with are
+    /// not known, so the `VK_EXT_debug_utils` commands used by
+    /// [`Device::set_object_name`] and [`Device::set_object_tag`] will not be
+    /// loaded (use [`Instance::create_device`] instead if needed).
+    ///
     /// # Safety
     ///
     /// `device` must have been created using `get_device_proc_addr`, `physical_device`, and `info`.
@@ -423,37 +603,237 @@ impl Device {
         info: &vk::DeviceCreateInfo,
         device: vk::Device,
     ) -> VkResult<Self> {
+        let mut loaded = BTreeSet::new();
+        let load = |n| (get_device_proc_addr)(device, n);
+        let commands = DeviceCommands::load(record(&mut loaded, load));
+
+        // The device commands provided by every Vulkan version were loaded.
+        let version = Version::V1_4_0;
+        Self::from_commands(
+            get_device_proc_addr,
+            physical_device,
+            info,
+            device,
+            version,
+            commands,
+            loaded,
+            &BTreeSet::new(),
+        )
+    }
+
+    /// Loads a Vulkan device from a previously created [`vk::Device`].
+    ///
+    /// Only the device commands provided by Vulkan versions up to and including
+    /// `version` (usually the lower of the API version requested for the
+    /// instance the device was created with and the API version of the
+    /// physical device) and by the extensions enabled in `info` will be loaded.
+    ///
+    /// The features and queues requested in `info` are copied so they can be
+    /// retrieved later (e.g., with [`Device::enabled_features`]).
+    ///
+    /// As with [`Device::from_created`], the `VK_EXT_debug_utils` commands will
+    /// not be loaded.
+    ///
+    /// # Safety
+    ///
+    /// `device` must have been created using `get_device_proc_addr`, `physical_device`, and `info`.
+    #[inline]
+    pub unsafe fn from_created_enabled(
+        get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+        physical_device: vk::PhysicalDevice,
+        info: &vk::DeviceCreateInfo,
+        device: vk::Device,
+        version: Version,
+    ) -> VkResult<Self> {
+        Self::load_enabled(
+            get_device_proc_addr,
+            physical_device,
+            info,
+            device,
+            version,
+            &BTreeSet::new(),
+        )
+    }
+
+    unsafe fn load_enabled(
+        get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+        physical_device: vk::PhysicalDevice,
+        info: &vk::DeviceCreateInfo,
+        device: vk::Device,
+        version: Version,
+        instance_extensions: &BTreeSet<vk::ExtensionName>,
+    ) -> VkResult<Self> {
+        let extensions = get_names(info.enabled_extension_count, info.enabled_extension_names);
+
+        let mut loaded = BTreeSet::new();
         let load = |n| (get_device_proc_addr)(device, n);
-        let commands = DeviceCommands::load(load);
+        let enabled = |n: &CStr| extensions.contains(&vk::ExtensionName::from_cstr(n));
+        let commands =
+            DeviceCommands::load_enabled(record(&mut loaded, load), version.into(), enabled);
 
+        Self::from_commands(
+            get_device_proc_addr,
+            physical_device,
+            info,
+            device,
+            version,
+            commands,
+            loaded,
+            instance_extensions,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn from_commands(
+        get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+        physical_device: vk::PhysicalDevice,
+        info: &vk::DeviceCreateInfo,
+        device: vk::Device,
+        version: Version,
+        commands: DeviceCommands,
+        loaded: BTreeSet<String>,
+        instance_extensions: &BTreeSet<vk::ExtensionName>,
+    ) -> VkResult<Self> {
         let extensions = get_names(info.enabled_extension_count, info.enabled_extension_names);
         let layers = get_names(info.enabled_layer_count, info.enabled_layer_names);
 
-        Ok(Self {
-            handle: device,
+        let load = |n| (get_device_proc_addr)(device, n);
+        let debug_utils = DebugUtilsCommands::load(instance_extensions, load);
+
+        let enabled_features = FeatureSet::from_device_create_info(info);
+        let queue_families = get_queue_families(info);
+
+        let state = DeviceState {
             commands,
+            loaded,
             physical_device,
+            version,
             extensions,
             layers,
+            enabled_features,
+            queue_families,
+            debug_utils,
+            #[cfg(feature = "std")]
+            hooks: None,
+        };
+
+        Ok(Self {
+            handle: device,
+            state: Arc::new(state),
         })
     }
 
     /// Gets the physical device for this Vulkan device.
     #[inline]
     pub fn physical_device(&self) -> vk::PhysicalDevice {
-        self.physical_device
+        self.state.physical_device
+    }
+
+    /// Gets the version for this Vulkan device (the version the device
+    /// commands were loaded for).
+    ///
+    /// For devices loaded with [`Device::from_created`], this is the latest
+    /// Vulkan version supported by `vulkanalia` since the device commands
+    /// provided by every Vulkan version are loaded.
+    #[inline]
+    pub fn version(&self) -> Version {
+        self.state.version
     }
 
     /// Gets the loaded extensions for this Vulkan device.
     #[inline]
     pub fn extensions(&self) -> &BTreeSet<vk::ExtensionName> {
-        &self.extensions
+        &self.state.extensions
     }
 
     /// Gets the loaded layers for this Vulkan device.
     #[inline]
     pub fn layers(&self) -> &BTreeSet<vk::ExtensionName> {
-        &self.layers
+        &self.state.layers
+    }
+
+    /// Gets the features enabled for this Vulkan device.
+    #[inline]
+    pub fn enabled_features(&self) -> &FeatureSet {
+        &self.state.enabled_features
+    }
+
+    /// Gets the queue families queues were created from for this Vulkan device.
+    #[inline]
+    pub fn queue_families(&self) -> &[DeviceQueueFamily] {
+        &self.state.queue_families
+    }
+
+    /// Returns whether a device command (e.g., `vkCmdDrawMeshTasksEXT`) was
+    /// loaded for this Vulkan device.
+    ///
+    /// See [`Entry::is_command_loaded`] for the behavior of commands that were
+    /// not loaded.
+    #[inline]
+    pub fn is_command_loaded(&self, name: &str) -> bool {
+        self.state.loaded.contains(name)
+    }
+
+    /// Creates a copy of this Vulkan device that uses the supplied device
+    /// commands.
+    ///
+    /// # Safety
+    ///
+    /// `commands` must contain valid Vulkan commands for this device.
+    #[inline]
+    pub unsafe fn with_commands(&self, commands: DeviceCommands) -> Self {
+        Self {
+            handle: self.handle,
+            state: Arc::new(self.copy_state(commands)),
+        }
+    }
+
+    /// Creates a copy of this Vulkan device that calls the supplied hook
+    /// before and after each device command is called (see the [`hook`]
+    /// module).
+    ///
+    /// If this device is hooked, the hook is called after the existing hooks.
+    ///
+    /// Returns `None` if [`hook::MAX_HOOKED`] hooked devices already exist.
+    ///
+    /// # Safety
+    ///
+    /// `hook` must not return a successful result from
+    /// [`CommandHook::before`](hook::CommandHook::before) for a command that
+    /// returns values through its parameters (since the command is not called).
+    #[cfg(feature = "std")]
+    pub unsafe fn with_hook(&self, hook: impl hook::CommandHook) -> Option<Self> {
+        let (commands, mut hooks) = match &self.state.hooks {
+            Some(installed) => (installed.commands(), installed.hooks().to_vec()),
+            None => (self.state.commands, Vec::new()),
+        };
+
+        hooks.push(Arc::new(hook));
+        let (commands, installed) = hook::install_device(commands, hooks)?;
+
+        let mut state = self.copy_state(commands);
+        state.hooks = Some(installed);
+
+        Some(Self {
+            handle: self.handle,
+            state: Arc::new(state),
+        })
+    }
+
+    fn copy_state(&self, commands: DeviceCommands) -> DeviceState {
+        DeviceState {
+            commands,
+            loaded: self.state.loaded.clone(),
+            physical_device: self.state.physical_device,
+            version: self.state.version,
+            extensions: self.state.extensions.clone(),
+            layers: self.state.layers.clone(),
+            enabled_features: self.state.enabled_features.clone(),
+            queue_families: self.state.queue_families.clone(),
+            debug_utils: self.state.debug_utils,
+            #[cfg(feature = "std")]
+            hooks: None,
+        }
     }
 }
 
@@ -461,8 +841,11 @@ impl fmt::Debug for Device {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Device")
             .field("handle", &self.handle)
-            .field("extensions", &self.extensions)
-            .field("layers", &self.layers)
+            .field("version", &self.state.version)
+            .field("extensions", &self.state.extensions)
+            .field("layers", &self.state.layers)
+            .field("enabled_features", &self.state.enabled_features)
+            .field("queue_families", &self.state.queue_families)
             .finish()
     }
 }
@@ -470,6 +853,40 @@ impl fmt::Debug for Device {
 unsafe impl Send for Device {}
 unsafe impl Sync for Device {}
 
+/// A queue family queues were created from for a Vulkan device.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeviceQueueFamily {
+    /// The flags the queues were created with.
+    pub flags: vk::DeviceQueueCreateFlags,
+    /// The index of the queue family.
+    pub queue_family_index: u32,
+    /// The priorities of the queues (one per queue).
+    pub queue_priorities: Vec<f32>,
+}
+
+impl DeviceQueueFamily {
+    /// Gets the number of queues created from this queue family.
+    #[inline]
+    pub fn queue_count(&self) -> u32 {
+        self.queue_priorities.len() as u32
+    }
+}
+
+/// Wraps a command loader to record the names of the commands it loads.
+#[inline]
+unsafe fn record<'a>(
+    loaded: &'a mut BTreeSet<String>,
+    mut loader: impl FnMut(*const c_char) -> vk::PFN_vkVoidFunction + 'a,
+) -> impl FnMut(*const c_char) -> vk::PFN_vkVoidFunction + 'a {
+    move |name| {
+        let value = loader(name);
+        if let (Some(_), Ok(name)) = (value, CStr::from_ptr(name).to_str()) {
+            loaded.insert(name.into());
+        }
+        value
+    }
+}
+
 #[inline]
 unsafe fn get_names(
     num_strings: u32,
@@ -485,6 +902,38 @@ unsafe fn get_names(
         .collect()
 }
 
+#[inline]
+unsafe fn get_queue_families(info: &vk::DeviceCreateInfo) -> Vec<DeviceQueueFamily> {
+    if info.queue_create_infos.is_null() || info.queue_create_info_count == 0 {
+        return Vec::new();
+    }
+
+    slice::from_raw_parts(
+        info.queue_create_infos,
+        info.queue_create_info_count as usize,
+    )
+    .iter()
+    .map(|i| DeviceQueueFamily {
+        flags: i.flags,
+        queue_family_index: i.queue_family_index,
+        queue_priorities: if i.queue_priorities.is_null() {
+            Vec::new()
+        } else {
+            slice::from_raw_parts(i.queue_priorities, i.queue_count as usize).to_vec()
+        },
+    })
+    .collect()
+}
+
+#[inline]
+unsafe fn get_api_version(info: &vk::InstanceCreateInfo) -> Version {
+    if info.application_info.is_null() || (*info.application_info).api_version == 0 {
+        Version::V1_0_0
+    } else {
+        (*info.application_info).api_version.into()
+    }
+}
+
 #[inline]
 unsafe fn get_version(get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr) -> VkResult<Version> {
     let name = c"vkEnumerateInstanceVersion".as_ptr();
@@ -132,3 +132,9 @@ mod libloading_loader {
 
 #[cfg(feature = "libloading")]
 pub use self::libloading_loader::*;
+
+#[cfg(feature = "mock")]
+mod mock;
+
+#[cfg(feature = "mock")]
+pub use self::mock::*;
@@ -0,0 +1,2412 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! An in-process mock Vulkan driver.
+
+use core::fmt;
+use std::boxed::Box;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::ffi::{CStr, CString, c_char, c_void};
+use std::mem;
+use std::ptr;
+use std::slice;
+use std::string::String;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::vec::Vec;
+
+use super::*;
+use crate::Version;
+use crate::features::Features;
+use crate::vk::{self, Handle};
+
+thread_local! {
+    /// The mock driver most recently loaded on the current thread.
+    static CURRENT: RefCell<Weak<DriverState>> = const { RefCell::new(Weak::new()) };
+}
+
+/// The mock driver most recently loaded on any thread.
+static LAST: Mutex<Weak<DriverState>> = Mutex::new(Weak::new());
+
+/// Creates extension properties for a Vulkan extension.
+#[inline]
+pub fn extension_properties(extension: &vk::Extension) -> vk::ExtensionProperties {
+    vk::ExtensionProperties {
+        extension_name: extension.name,
+        spec_version: 1,
+    }
+}
+
+/// Creates layer properties for a Vulkan layer name.
+#[inline]
+pub fn layer_properties(name: &vk::ExtensionName) -> vk::LayerProperties {
+    vk::LayerProperties {
+        layer_name: *name,
+        spec_version: Version::V1_0_0.into(),
+        implementation_version: 1,
+        description: vk::StringArray::from_bytes(b"vulkanalia mock layer"),
+    }
+}
+
+/// The configuration for a mock Vulkan physical device.
+#[derive(Clone, Debug)]
+pub struct MockPhysicalDevice {
+    /// The properties reported for the physical device.
+    pub properties: vk::PhysicalDeviceProperties,
+    /// The features supported by the physical device.
+    pub features: vk::PhysicalDeviceFeatures,
+    /// The Vulkan 1.1 features supported by the physical device.
+    pub vulkan11_features: vk::PhysicalDeviceVulkan11Features,
+    /// The Vulkan 1.2 features supported by the physical device.
+    pub vulkan12_features: vk::PhysicalDeviceVulkan12Features,
+    /// The Vulkan 1.3 features supported by the physical device.
+    pub vulkan13_features: vk::PhysicalDeviceVulkan13Features,
+    /// The Vulkan 1.4 features supported by the physical device.
+    pub vulkan14_features: vk::PhysicalDeviceVulkan14Features,
+    /// The device extensions supported by the physical device.
+    pub extensions: Vec<vk::ExtensionProperties>,
+    /// The device layers supported by the physical device.
+    pub layers: Vec<vk::LayerProperties>,
+    /// The queue families of the physical device.
+    pub queue_families: Vec<vk::QueueFamilyProperties>,
+    /// The memory heaps and types of the physical device.
+    pub memory_properties: vk::PhysicalDeviceMemoryProperties,
+    /// The properties of the formats supported by the physical device (formats
+    /// which are not present are not supported).
+    pub format_properties: HashMap<vk::Format, vk::FormatProperties>,
+    /// Whether the queue families of the physical device support presenting
+    /// to surfaces.
+    pub surface_support: bool,
+    /// The capabilities of surfaces with the physical device.
+    pub surface_capabilities: vk::SurfaceCapabilitiesKHR,
+    /// The formats supported for surfaces with the physical device.
+    pub surface_formats: Vec<vk::SurfaceFormatKHR>,
+    /// The present modes supported for surfaces with the physical device.
+    pub present_modes: Vec<vk::PresentModeKHR>,
+    /// The displays attached to the physical device (see `VK_KHR_display`).
+    ///
+    /// Each display has a display plane which can only be used with that
+    /// display.
+    pub displays: Vec<MockDisplay>,
+}
+
+impl MockPhysicalDevice {
+    /// Adds device extensions to this mock physical device.
+    pub fn with_extensions(mut self, extensions: &[vk::Extension]) -> Self {
+        self.extensions
+            .extend(extensions.iter().map(extension_properties));
+        self
+    }
+}
+
+impl Default for MockPhysicalDevice {
+    fn default() -> Self {
+        let properties = vk::PhysicalDeviceProperties {
+            api_version: Version::V1_3_0.into(),
+            driver_version: 1,
+            device_type: vk::PhysicalDeviceType::DISCRETE_GPU,
+            device_name: vk::StringArray::from_bytes(b"vulkanalia mock device"),
+            ..Default::default()
+        };
+
+        let queue_family = vk::QueueFamilyProperties {
+            queue_flags: vk::QueueFlags::GRAPHICS
+                | vk::QueueFlags::COMPUTE
+                | vk::QueueFlags::TRANSFER,
+            queue_count: 2,
+            timestamp_valid_bits: 64,
+            min_image_transfer_granularity: vk::Extent3D {
+                width: 1,
+                height: 1,
+                depth: 1,
+            },
+        };
+
+        let mut memory_properties = vk::PhysicalDeviceMemoryProperties {
+            memory_type_count: 2,
+            memory_heap_count: 2,
+            ..Default::default()
+        };
+
+        memory_properties.memory_heaps[0] = vk::MemoryHeap {
+            size: 1 << 30,
+            flags: vk::MemoryHeapFlags::DEVICE_LOCAL,
+        };
+        memory_properties.memory_heaps[1] = vk::MemoryHeap {
+            size: 1 << 30,
+            flags: vk::MemoryHeapFlags::empty(),
+        };
+        memory_properties.memory_types[0] = vk::MemoryType {
+            property_flags: vk::MemoryPropertyFlags::DEVICE_LOCAL,
+            heap_index: 0,
+        };
+        memory_properties.memory_types[1] = vk::MemoryType {
+            property_flags: vk::MemoryPropertyFlags::HOST_VISIBLE
+                | vk::MemoryPropertyFlags::HOST_COHERENT,
+            heap_index: 1,
+        };
+
+        // Like the surfaces of some window systems, the extent of the surface
+        // is determined by the extent of the swapchain.
+        let surface_capabilities = vk::SurfaceCapabilitiesKHR {
+            min_image_count: 2,
+            max_image_count: 8,
+            current_extent: vk::Extent2D {
+                width: u32::MAX,
+                height: u32::MAX,
+            },
+            min_image_extent: vk::Extent2D {
+                width: 1,
+                height: 1,
+            },
+            max_image_extent: vk::Extent2D {
+                width: 16384,
+                height: 16384,
+            },
+            max_image_array_layers: 1,
+            supported_transforms: vk::SurfaceTransformFlagsKHR::IDENTITY,
+            current_transform: vk::SurfaceTransformFlagsKHR::IDENTITY,
+            supported_composite_alpha: vk::CompositeAlphaFlagsKHR::OPAQUE,
+            supported_usage_flags: vk::ImageUsageFlags::COLOR_ATTACHMENT
+                | vk::ImageUsageFlags::TRANSFER_DST,
+        };
+
+        let surface_formats = vec![
+            vk::SurfaceFormatKHR {
+                format: vk::Format::B8G8R8A8_UNORM,
+                color_space: vk::ColorSpaceKHR::SRGB_NONLINEAR,
+            },
+            vk::SurfaceFormatKHR {
+                format: vk::Format::B8G8R8A8_SRGB,
+                color_space: vk::ColorSpaceKHR::SRGB_NONLINEAR,
+            },
+        ];
+
+        Self {
+            properties,
+            features: vk::PhysicalDeviceFeatures::default(),
+            vulkan11_features: vk::PhysicalDeviceVulkan11Features::default(),
+            vulkan12_features: vk::PhysicalDeviceVulkan12Features::default(),
+            vulkan13_features: vk::PhysicalDeviceVulkan13Features::default(),
+            vulkan14_features: vk::PhysicalDeviceVulkan14Features::default(),
+            extensions: Vec::new(),
+            layers: Vec::new(),
+            queue_families: vec![queue_family],
+            memory_properties,
+            format_properties: HashMap::new(),
+            surface_support: true,
+            surface_capabilities,
+            surface_formats,
+            present_modes: vec![vk::PresentModeKHR::FIFO, vk::PresentModeKHR::MAILBOX],
+            displays: Vec::new(),
+        }
+    }
+}
+
+/// The configuration for a mock Vulkan display (see `VK_KHR_display`).
+#[derive(Clone, Debug, Default)]
+pub struct MockDisplay {
+    /// The name of the display.
+    pub name: String,
+    /// The physical dimensions of the display (in millimeters).
+    pub physical_dimensions: vk::Extent2D,
+    /// The physical resolution of the display.
+    pub physical_resolution: vk::Extent2D,
+    /// The transforms supported by the display.
+    pub supported_transforms: vk::SurfaceTransformFlagsKHR,
+    /// The modes supported by the display.
+    pub modes: Vec<vk::DisplayModeParametersKHR>,
+}
+
+/// The configuration for a mock Vulkan driver.
+#[derive(Clone, Debug)]
+pub struct MockConfig {
+    /// The instance-level version reported by the driver.
+    pub version: Version,
+    /// The instance extensions supported by the driver.
+    pub extensions: Vec<vk::ExtensionProperties>,
+    /// The instance layers supported by the driver.
+    pub layers: Vec<vk::LayerProperties>,
+    /// The physical devices exposed by the driver.
+    pub physical_devices: Vec<MockPhysicalDevice>,
+    /// Whether `vkGetDeviceProcAddr` returns the device commands provided by
+    /// instance extensions (e.g., `vkSetDebugUtilsObjectNameEXT`) even if the
+    /// extensions were not enabled for the instance (like some real drivers).
+    pub unfiltered_device_commands: bool,
+}
+
+impl MockConfig {
+    /// Adds instance extensions to this mock driver configuration.
+    pub fn with_extensions(mut self, extensions: &[vk::Extension]) -> Self {
+        self.extensions
+            .extend(extensions.iter().map(extension_properties));
+        self
+    }
+
+    /// Adds instance layers to this mock driver configuration.
+    pub fn with_layers(mut self, layers: &[vk::ExtensionName]) -> Self {
+        self.layers.extend(layers.iter().map(layer_properties));
+        self
+    }
+}
+
+impl Default for MockConfig {
+    fn default() -> Self {
+        Self {
+            version: Version::V1_3_0,
+            extensions: Vec::new(),
+            layers: Vec::new(),
+            physical_devices: vec![MockPhysicalDevice::default()],
+            unfiltered_device_commands: false,
+        }
+    }
+}
+
+/// A Vulkan command invocation recorded by a mock Vulkan driver.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockCall {
+    /// The name of the command (e.g., `vkQueueSubmit`).
+    pub command: &'static str,
+    /// The handle and scalar arguments of the command as raw values.
+    ///
+    /// Pointer arguments are not recorded.
+    pub args: Vec<u64>,
+}
+
+/// The scripted results for a command.
+#[derive(Default)]
+struct Script {
+    queued: VecDeque<vk::Result>,
+    fallback: Option<vk::Result>,
+}
+
+/// An object created by a mock Vulkan driver.
+#[derive(Copy, Clone, Debug)]
+enum Object {
+    Instance,
+    Device,
+    Buffer {
+        size: vk::DeviceSize,
+    },
+    Image {
+        size: vk::DeviceSize,
+    },
+    Memory,
+    Fence {
+        signaled: bool,
+    },
+    Semaphore {
+        value: Option<u64>,
+    },
+    Surface,
+    Swapchain {
+        first_image: u64,
+        image_count: u32,
+        next_image: u32,
+    },
+    ImageView,
+    SamplerYcbcrConversion,
+    DebugUtilsMessenger {
+        severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+        types: vk::DebugUtilsMessageTypeFlagsEXT,
+        callback: vk::PFN_vkDebugUtilsMessengerCallbackEXT,
+        user_data: usize,
+    },
+}
+
+impl Object {
+    fn type_(self) -> vk::ObjectType {
+        match self {
+            Self::Instance => vk::ObjectType::INSTANCE,
+            Self::Device => vk::ObjectType::DEVICE,
+            Self::Buffer { .. } => vk::ObjectType::BUFFER,
+            Self::Image { .. } => vk::ObjectType::IMAGE,
+            Self::Memory => vk::ObjectType::DEVICE_MEMORY,
+            Self::Fence { .. } => vk::ObjectType::FENCE,
+            Self::Semaphore { .. } => vk::ObjectType::SEMAPHORE,
+            Self::Surface => vk::ObjectType::SURFACE_KHR,
+            Self::Swapchain { .. } => vk::ObjectType::SWAPCHAIN_KHR,
+            Self::ImageView => vk::ObjectType::IMAGE_VIEW,
+            Self::SamplerYcbcrConversion => vk::ObjectType::SAMPLER_YCBCR_CONVERSION,
+            Self::DebugUtilsMessenger { .. } => vk::ObjectType::DEBUG_UTILS_MESSENGER_EXT,
+        }
+    }
+}
+
+struct DriverState {
+    config: MockConfig,
+    calls: Mutex<Vec<MockCall>>,
+    scripts: Mutex<HashMap<String, Script>>,
+    objects: Mutex<HashMap<u64, Object>>,
+    names: Mutex<HashMap<u64, String>>,
+    next: AtomicU64,
+}
+
+/// A handle to the state of a mock Vulkan driver.
+///
+/// Cloning this handle does not clone the underlying driver state.
+#[derive(Clone)]
+pub struct MockDriver(Arc<DriverState>);
+
+impl MockDriver {
+    /// Gets the configuration of this mock driver.
+    #[inline]
+    pub fn config(&self) -> &MockConfig {
+        &self.0.config
+    }
+
+    /// Gets the commands that have been called so far (in order).
+    pub fn calls(&self) -> Vec<MockCall> {
+        lock(&self.0.calls).clone()
+    }
+
+    /// Gets the calls that have been made to a command so far (in order).
+    pub fn calls_to(&self, command: &str) -> Vec<MockCall> {
+        let calls = lock(&self.0.calls);
+        calls
+            .iter()
+            .filter(|c| c.command == command)
+            .cloned()
+            .collect()
+    }
+
+    /// Forgets the commands that have been called so far.
+    pub fn clear_calls(&self) {
+        lock(&self.0.calls).clear();
+    }
+
+    /// Sets the result returned by all future calls to a command.
+    ///
+    /// Only commands that return a [`vk::Result`] are affected. A command that
+    /// returns an error code does not perform any of its other effects.
+    pub fn set_result(&self, command: &str, result: impl Into<vk::Result>) {
+        let mut scripts = lock(&self.0.scripts);
+        scripts.entry(command.into()).or_default().fallback = Some(result.into());
+    }
+
+    /// Queues the result returned by the next call to a command.
+    ///
+    /// Queued results take precedence over results set with
+    /// [`MockDriver::set_result`].
+    pub fn queue_result(&self, command: &str, result: impl Into<vk::Result>) {
+        let mut scripts = lock(&self.0.scripts);
+        scripts
+            .entry(command.into())
+            .or_default()
+            .queued
+            .push_back(result.into());
+    }
+
+    /// Forgets the scripted results for all commands.
+    pub fn clear_results(&self) {
+        lock(&self.0.scripts).clear();
+    }
+
+    /// Gets the types and raw values of the objects that have been created
+    /// but not yet destroyed.
+    pub fn live_objects(&self) -> Vec<(vk::ObjectType, u64)> {
+        let objects = lock(&self.0.objects);
+        let mut live = objects
+            .iter()
+            .map(|(h, o)| (o.type_(), *h))
+            .collect::<Vec<_>>();
+        live.sort_by_key(|(_, h)| *h);
+        live
+    }
+
+    /// Gets the name of an object (set with `vkSetDebugUtilsObjectNameEXT` or
+    /// `vkDebugMarkerSetObjectNameEXT`).
+    pub fn object_name(&self, handle: u64) -> Option<String> {
+        lock(&self.0.names).get(&handle).cloned()
+    }
+
+    /// Records a call to a command and returns the result it should return.
+    fn call(&self, command: &'static str, args: &[u64]) -> vk::Result {
+        let args = args.to_vec();
+        lock(&self.0.calls).push(MockCall { command, args });
+
+        let mut scripts = lock(&self.0.scripts);
+        match scripts.get_mut(command) {
+            Some(script) => script
+                .queued
+                .pop_front()
+                .or(script.fallback)
+                .unwrap_or(vk::Result::SUCCESS),
+            None => vk::Result::SUCCESS,
+        }
+    }
+
+    /// Allocates a non-dispatchable handle which is not associated with an
+    /// object (e.g., for a display).
+    fn allocate(&self) -> u64 {
+        self.allocate_many(1)
+    }
+
+    /// Allocates consecutive non-dispatchable handles which are not associated
+    /// with objects (e.g., for swapchain images) and returns the first handle.
+    fn allocate_many(&self, count: u64) -> u64 {
+        self.0.next.fetch_add(count, Ordering::Relaxed)
+    }
+
+    /// Creates a non-dispatchable object.
+    fn create(&self, object: Object) -> u64 {
+        let handle = self.allocate();
+        lock(&self.0.objects).insert(handle, object);
+        handle
+    }
+
+    /// Destroys an object.
+    fn destroy(&self, handle: u64) {
+        lock(&self.0.objects).remove(&handle);
+        lock(&self.0.names).remove(&handle);
+    }
+
+    /// Sets (or clears if `name` is null or empty) the name of an object.
+    unsafe fn name(&self, handle: u64, name: *const c_char) {
+        let mut names = lock(&self.0.names);
+        if name.is_null() || *name == 0 {
+            names.remove(&handle);
+        } else {
+            let name = CStr::from_ptr(name).to_string_lossy().into_owned();
+            names.insert(handle, name);
+        }
+    }
+
+    /// Gets an object.
+    fn object(&self, handle: u64) -> Option<Object> {
+        lock(&self.0.objects).get(&handle).copied()
+    }
+
+    /// Replaces an object.
+    fn update(&self, handle: u64, object: Object) {
+        lock(&self.0.objects).insert(handle, object);
+    }
+}
+
+impl fmt::Debug for MockDriver {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MockDriver")
+            .field("config", &self.0.config)
+            .finish()
+    }
+}
+
+/// A Vulkan function loader backed by an in-process mock Vulkan driver.
+///
+/// This loader can be used with [`crate::Entry::new`] to create entry points,
+/// instances, and devices without a Vulkan implementation (e.g., for testing
+/// on machines without a GPU). The mock driver only implements a subset of the
+/// Vulkan commands (instance and device creation and introspection, queues,
+/// buffers, images, image views, memory, fences, binary and timeline
+/// semaphores, headless and display surfaces, swapchains, debug messengers,
+/// and object names and tags). The other commands will not be loaded.
+///
+/// Commands that are loaded with a null instance (e.g., `vkCreateInstance`)
+/// use the mock driver that was most recently loaded by an entry point on the
+/// current thread (or any thread if no entry point was loaded on the current
+/// thread). The other commands use the mock driver associated with the handle
+/// they are called with.
+///
+/// # Example
+///
+/// ```
+/// # use vulkanalia::prelude::v1_0::*;
+/// # use vulkanalia::loader::{MockConfig, MockLoader};
+/// let loader = MockLoader::new(MockConfig::default());
+/// let driver = loader.driver();
+///
+/// let entry = unsafe { Entry::new(loader) }.unwrap();
+/// let info = vk::InstanceCreateInfo::builder();
+/// let instance = unsafe { entry.create_instance(&info, None) }.unwrap();
+///
+/// assert_eq!(driver.calls_to("vkCreateInstance").len(), 1);
+/// # unsafe { instance.destroy_instance(None) };
+/// ```
+#[derive(Debug)]
+pub struct MockLoader(MockDriver);
+
+impl MockLoader {
+    /// Constructs a Vulkan function loader backed by a new mock driver.
+    pub fn new(config: MockConfig) -> Self {
+        Self(MockDriver(Arc::new(DriverState {
+            config,
+            calls: Mutex::new(Vec::new()),
+            scripts: Mutex::new(HashMap::new()),
+            objects: Mutex::new(HashMap::new()),
+            names: Mutex::new(HashMap::new()),
+            next: AtomicU64::new(1),
+        })))
+    }
+
+    /// Gets a handle to the mock driver used by this loader.
+    #[inline]
+    pub fn driver(&self) -> MockDriver {
+        self.0.clone()
+    }
+}
+
+impl Loader for MockLoader {
+    /// Loads a Vulkan function.
+    ///
+    /// Only `vkGetInstanceProcAddr` can be loaded.
+    ///
+    /// # Safety
+    ///
+    /// This method is always safe to call.
+    #[inline]
+    unsafe fn load(&self, name: &[u8]) -> Result<extern "system" fn(), Box<dyn LoaderError>> {
+        if name != b"vkGetInstanceProcAddr" {
+            return Err("the mock driver only exports vkGetInstanceProcAddr".into());
+        }
+
+        let weak = Arc::downgrade(&(self.0).0);
+        CURRENT.with(|c| *c.borrow_mut() = weak.clone());
+        *lock(&LAST) = weak;
+
+        let pointer = get_instance_proc_addr as vk::PFN_vkGetInstanceProcAddr;
+        Ok(mem::transmute::<
+            vk::PFN_vkGetInstanceProcAddr,
+            extern "system" fn(),
+        >(pointer))
+    }
+}
+
+//================================================
+// State
+//================================================
+
+// The physical device and queue states are never added or removed after they
+// are created so their addresses are used as dispatchable handles.
+//
+// Like the dispatchable objects of real Vulkan drivers, these states start
+// with a pointer-sized dispatch key which is shared between an instance and
+// its physical devices and between a device and its queues.
+
+#[repr(C)]
+struct InstanceState {
+    dispatch: usize,
+    driver: MockDriver,
+    extensions: Vec<vk::ExtensionName>,
+    physical_devices: Vec<PhysicalDeviceState>,
+}
+
+#[repr(C)]
+struct PhysicalDeviceState {
+    dispatch: usize,
+    driver: MockDriver,
+    config: MockPhysicalDevice,
+    debug_utils: bool,
+    displays: Vec<DisplayState>,
+}
+
+struct DisplayState {
+    handle: vk::DisplayKHR,
+    name: CString,
+    modes: Vec<vk::DisplayModeKHR>,
+}
+
+#[repr(C)]
+struct DeviceState {
+    dispatch: usize,
+    driver: MockDriver,
+    queues: Vec<QueueState>,
+    debug_utils: bool,
+}
+
+#[repr(C)]
+struct QueueState {
+    dispatch: usize,
+    driver: MockDriver,
+    family: u32,
+    index: u32,
+}
+
+/// Allocates a dispatch key that is unique across all mock drivers.
+fn dispatch() -> usize {
+    static NEXT: AtomicUsize = AtomicUsize::new(1);
+    NEXT.fetch_add(1, Ordering::Relaxed)
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn current() -> Option<MockDriver> {
+    CURRENT
+        .with(|c| c.borrow().upgrade())
+        .or_else(|| lock(&LAST).upgrade())
+        .map(MockDriver)
+}
+
+unsafe fn instance<'a>(handle: vk::Instance) -> &'a InstanceState {
+    &*(handle.as_raw() as *const InstanceState)
+}
+
+unsafe fn physical_device<'a>(handle: vk::PhysicalDevice) -> &'a PhysicalDeviceState {
+    &*(handle.as_raw() as *const PhysicalDeviceState)
+}
+
+unsafe fn device<'a>(handle: vk::Device) -> &'a DeviceState {
+    &*(handle.as_raw() as *const DeviceState)
+}
+
+unsafe fn queue<'a>(handle: vk::Queue) -> &'a QueueState {
+    &*(handle.as_raw() as *const QueueState)
+}
+
+unsafe fn names<'a>(count: u32, names: *const *const c_char) -> Vec<&'a CStr> {
+    if count == 0 || names.is_null() {
+        return Vec::new();
+    }
+
+    let names = slice::from_raw_parts(names, count as usize);
+    names.iter().map(|n| CStr::from_ptr(*n)).collect()
+}
+
+unsafe fn enumerate<T: Copy>(values: &[T], count: *mut u32, output: *mut T) -> vk::Result {
+    if output.is_null() {
+        *count = values.len() as u32;
+        return vk::Result::SUCCESS;
+    }
+
+    let written = (*count as usize).min(values.len());
+    ptr::copy_nonoverlapping(values.as_ptr(), output, written);
+    *count = written as u32;
+
+    if written < values.len() {
+        vk::Result::INCOMPLETE
+    } else {
+        vk::Result::SUCCESS
+    }
+}
+
+fn supports(supported: &[vk::ExtensionName], requested: &[&CStr]) -> bool {
+    requested
+        .iter()
+        .all(|r| supported.iter().any(|s| s.as_cstr() == *r))
+}
+
+/// Checks that the features requested in a pointer chain are supported.
+unsafe fn supports_features(config: &MockPhysicalDevice, head: *const c_void) -> bool {
+    let mut next = head.cast::<vk::BaseInStructure>();
+    while !next.is_null() {
+        let supported = match (*next).s_type {
+            vk::StructureType::PHYSICAL_DEVICE_FEATURES_2 => {
+                let requested = &*next.cast::<vk::PhysicalDeviceFeatures2>();
+                config.features.is_superset(&requested.features)
+            }
+            vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES => {
+                config.vulkan11_features.is_superset(&*next.cast())
+            }
+            vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES => {
+                config.vulkan12_features.is_superset(&*next.cast())
+            }
+            vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_3_FEATURES => {
+                config.vulkan13_features.is_superset(&*next.cast())
+            }
+            vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_4_FEATURES => {
+                config.vulkan14_features.is_superset(&*next.cast())
+            }
+            _ => true,
+        };
+
+        if !supported {
+            return false;
+        }
+
+        next = (*next).next;
+    }
+
+    true
+}
+
+fn memory_size(size: vk::DeviceSize) -> vk::MemoryRequirements {
+    vk::MemoryRequirements {
+        size: size.div_ceil(256) * 256,
+        alignment: 256,
+        memory_type_bits: u32::MAX,
+    }
+}
+
+//================================================
+// Commands
+//================================================
+
+macro_rules! lookup {
+    ($name:expr, { $($command:literal => $function:ident: $type:ident),* $(,)? }) => {
+        match $name {
+            $($command => Some(mem::transmute::<vk::$type, unsafe extern "system" fn()>($function)),)*
+            _ => None,
+        }
+    };
+}
+
+unsafe fn lookup(name: *const c_char) -> vk::PFN_vkVoidFunction {
+    lookup!(CStr::from_ptr(name).to_bytes(), {
+        // Entry
+        b"vkGetInstanceProcAddr" => get_instance_proc_addr: PFN_vkGetInstanceProcAddr,
+        b"vkEnumerateInstanceVersion" => enumerate_instance_version: PFN_vkEnumerateInstanceVersion,
+        b"vkEnumerateInstanceExtensionProperties" => enumerate_instance_extension_properties: PFN_vkEnumerateInstanceExtensionProperties,
+        b"vkEnumerateInstanceLayerProperties" => enumerate_instance_layer_properties: PFN_vkEnumerateInstanceLayerProperties,
+        b"vkCreateInstance" => create_instance: PFN_vkCreateInstance,
+        // Instance
+        b"vkDestroyInstance" => destroy_instance: PFN_vkDestroyInstance,
+        b"vkEnumeratePhysicalDevices" => enumerate_physical_devices: PFN_vkEnumeratePhysicalDevices,
+        b"vkGetPhysicalDeviceProperties" => get_physical_device_properties: PFN_vkGetPhysicalDeviceProperties,
+        b"vkGetPhysicalDeviceProperties2" => get_physical_device_properties2: PFN_vkGetPhysicalDeviceProperties2,
+        b"vkGetPhysicalDeviceFeatures" => get_physical_device_features: PFN_vkGetPhysicalDeviceFeatures,
+        b"vkGetPhysicalDeviceFeatures2" => get_physical_device_features2: PFN_vkGetPhysicalDeviceFeatures2,
+        b"vkGetPhysicalDeviceQueueFamilyProperties" => get_physical_device_queue_family_properties: PFN_vkGetPhysicalDeviceQueueFamilyProperties,
+        b"vkGetPhysicalDeviceFormatProperties" => get_physical_device_format_properties: PFN_vkGetPhysicalDeviceFormatProperties,
+        b"vkGetPhysicalDeviceMemoryProperties" => get_physical_device_memory_properties: PFN_vkGetPhysicalDeviceMemoryProperties,
+        b"vkGetPhysicalDeviceSurfaceSupportKHR" => get_physical_device_surface_support_khr: PFN_vkGetPhysicalDeviceSurfaceSupportKHR,
+        b"vkGetPhysicalDeviceSurfaceCapabilitiesKHR" => get_physical_device_surface_capabilities_khr: PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR,
+        b"vkGetPhysicalDeviceSurfaceFormatsKHR" => get_physical_device_surface_formats_khr: PFN_vkGetPhysicalDeviceSurfaceFormatsKHR,
+        b"vkGetPhysicalDeviceSurfacePresentModesKHR" => get_physical_device_surface_present_modes_khr: PFN_vkGetPhysicalDeviceSurfacePresentModesKHR,
+        b"vkDestroySurfaceKHR" => destroy_surface_khr: PFN_vkDestroySurfaceKHR,
+        b"vkCreateHeadlessSurfaceEXT" => create_headless_surface_ext: PFN_vkCreateHeadlessSurfaceEXT,
+        b"vkGetPhysicalDeviceDisplayPropertiesKHR" => get_physical_device_display_properties_khr: PFN_vkGetPhysicalDeviceDisplayPropertiesKHR,
+        b"vkGetPhysicalDeviceDisplayPlanePropertiesKHR" => get_physical_device_display_plane_properties_khr: PFN_vkGetPhysicalDeviceDisplayPlanePropertiesKHR,
+        b"vkGetDisplayPlaneSupportedDisplaysKHR" => get_display_plane_supported_displays_khr: PFN_vkGetDisplayPlaneSupportedDisplaysKHR,
+        b"vkGetDisplayModePropertiesKHR" => get_display_mode_properties_khr: PFN_vkGetDisplayModePropertiesKHR,
+        b"vkGetDisplayPlaneCapabilitiesKHR" => get_display_plane_capabilities_khr: PFN_vkGetDisplayPlaneCapabilitiesKHR,
+        b"vkCreateDisplayPlaneSurfaceKHR" => create_display_plane_surface_khr: PFN_vkCreateDisplayPlaneSurfaceKHR,
+        b"vkEnumerateDeviceExtensionProperties" => enumerate_device_extension_properties: PFN_vkEnumerateDeviceExtensionProperties,
+        b"vkEnumerateDeviceLayerProperties" => enumerate_device_layer_properties: PFN_vkEnumerateDeviceLayerProperties,
+        b"vkCreateDevice" => create_device: PFN_vkCreateDevice,
+        b"vkGetDeviceProcAddr" => get_device_proc_addr: PFN_vkGetDeviceProcAddr,
+        b"vkCreateDebugUtilsMessengerEXT" => create_debug_utils_messenger_ext: PFN_vkCreateDebugUtilsMessengerEXT,
+        b"vkDestroyDebugUtilsMessengerEXT" => destroy_debug_utils_messenger_ext: PFN_vkDestroyDebugUtilsMessengerEXT,
+        b"vkSubmitDebugUtilsMessageEXT" => submit_debug_utils_message_ext: PFN_vkSubmitDebugUtilsMessageEXT,
+        // Device
+        b"vkDestroyDevice" => destroy_device: PFN_vkDestroyDevice,
+        b"vkDeviceWaitIdle" => device_wait_idle: PFN_vkDeviceWaitIdle,
+        b"vkGetDeviceQueue" => get_device_queue: PFN_vkGetDeviceQueue,
+        b"vkGetDeviceQueue2" => get_device_queue2: PFN_vkGetDeviceQueue2,
+        b"vkQueueSubmit" => queue_submit: PFN_vkQueueSubmit,
+        b"vkQueueWaitIdle" => queue_wait_idle: PFN_vkQueueWaitIdle,
+        b"vkCreateBuffer" => create_buffer: PFN_vkCreateBuffer,
+        b"vkDestroyBuffer" => destroy_buffer: PFN_vkDestroyBuffer,
+        b"vkGetBufferMemoryRequirements" => get_buffer_memory_requirements: PFN_vkGetBufferMemoryRequirements,
+        b"vkBindBufferMemory" => bind_buffer_memory: PFN_vkBindBufferMemory,
+        b"vkCreateImage" => create_image: PFN_vkCreateImage,
+        b"vkDestroyImage" => destroy_image: PFN_vkDestroyImage,
+        b"vkGetImageMemoryRequirements" => get_image_memory_requirements: PFN_vkGetImageMemoryRequirements,
+        b"vkBindImageMemory" => bind_image_memory: PFN_vkBindImageMemory,
+        b"vkCreateImageView" => create_image_view: PFN_vkCreateImageView,
+        b"vkDestroyImageView" => destroy_image_view: PFN_vkDestroyImageView,
+        b"vkCreateSamplerYcbcrConversion" => create_sampler_ycbcr_conversion: PFN_vkCreateSamplerYcbcrConversion,
+        b"vkDestroySamplerYcbcrConversion" => destroy_sampler_ycbcr_conversion: PFN_vkDestroySamplerYcbcrConversion,
+        b"vkCreateSamplerYcbcrConversionKHR" => create_sampler_ycbcr_conversion_khr: PFN_vkCreateSamplerYcbcrConversionKHR,
+        b"vkDestroySamplerYcbcrConversionKHR" => destroy_sampler_ycbcr_conversion_khr: PFN_vkDestroySamplerYcbcrConversionKHR,
+        b"vkAllocateMemory" => allocate_memory: PFN_vkAllocateMemory,
+        b"vkFreeMemory" => free_memory: PFN_vkFreeMemory,
+        b"vkCreateFence" => create_fence: PFN_vkCreateFence,
+        b"vkDestroyFence" => destroy_fence: PFN_vkDestroyFence,
+        b"vkGetFenceStatus" => get_fence_status: PFN_vkGetFenceStatus,
+        b"vkResetFences" => reset_fences: PFN_vkResetFences,
+        b"vkWaitForFences" => wait_for_fences: PFN_vkWaitForFences,
+        b"vkCreateSemaphore" => create_semaphore: PFN_vkCreateSemaphore,
+        b"vkDestroySemaphore" => destroy_semaphore: PFN_vkDestroySemaphore,
+        b"vkGetSemaphoreCounterValue" => get_semaphore_counter_value: PFN_vkGetSemaphoreCounterValue,
+        b"vkWaitSemaphores" => wait_semaphores: PFN_vkWaitSemaphores,
+        b"vkSignalSemaphore" => signal_semaphore: PFN_vkSignalSemaphore,
+        b"vkCreateSwapchainKHR" => create_swapchain_khr: PFN_vkCreateSwapchainKHR,
+        b"vkDestroySwapchainKHR" => destroy_swapchain_khr: PFN_vkDestroySwapchainKHR,
+        b"vkGetSwapchainImagesKHR" => get_swapchain_images_khr: PFN_vkGetSwapchainImagesKHR,
+        b"vkAcquireNextImageKHR" => acquire_next_image_khr: PFN_vkAcquireNextImageKHR,
+        b"vkQueuePresentKHR" => queue_present_khr: PFN_vkQueuePresentKHR,
+        b"vkSetDebugUtilsObjectNameEXT" => set_debug_utils_object_name_ext: PFN_vkSetDebugUtilsObjectNameEXT,
+        b"vkSetDebugUtilsObjectTagEXT" => set_debug_utils_object_tag_ext: PFN_vkSetDebugUtilsObjectTagEXT,
+        b"vkDebugMarkerSetObjectNameEXT" => debug_marker_set_object_name_ext: PFN_vkDebugMarkerSetObjectNameEXT,
+        b"vkDebugMarkerSetObjectTagEXT" => debug_marker_set_object_tag_ext: PFN_vkDebugMarkerSetObjectTagEXT,
+    })
+}
+
+//================================================
+// Commands (Entry)
+//================================================
+
+unsafe extern "system" fn get_instance_proc_addr(
+    handle: vk::Instance,
+    name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    // Like real Vulkan drivers, commands provided only by instance extensions
+    // are only available if one of the extensions was enabled for the instance.
+    if !handle.is_null() {
+        let command = CStr::from_ptr(name).to_str().unwrap_or_default();
+        let extensions = vk::EXTENSIONS
+            .iter()
+            .filter(|e| e.commands.contains(&command))
+            .collect::<Vec<_>>();
+        let enabled = &instance(handle).extensions;
+        let available = extensions
+            .iter()
+            .any(|e| e.type_ != "instance" || enabled.contains(&e.name));
+        if !extensions.is_empty() && !available {
+            return None;
+        }
+    }
+
+    lookup(name)
+}
+
+unsafe extern "system" fn enumerate_instance_version(version: *mut u32) -> vk::Result {
+    let Some(driver) = current() else {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    };
+
+    let result = driver.call("vkEnumerateInstanceVersion", &[]);
+    if result == vk::Result::SUCCESS {
+        *version = driver.config().version.into();
+    }
+
+    result
+}
+
+unsafe extern "system" fn enumerate_instance_extension_properties(
+    layer_name: *const c_char,
+    count: *mut u32,
+    properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    let Some(driver) = current() else {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    };
+
+    let result = driver.call("vkEnumerateInstanceExtensionProperties", &[]);
+    match result {
+        vk::Result::SUCCESS if layer_name.is_null() => {
+            enumerate(&driver.config().extensions, count, properties)
+        }
+        vk::Result::SUCCESS => enumerate(&[], count, properties),
+        _ => result,
+    }
+}
+
+unsafe extern "system" fn enumerate_instance_layer_properties(
+    count: *mut u32,
+    properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    let Some(driver) = current() else {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    };
+
+    match driver.call("vkEnumerateInstanceLayerProperties", &[]) {
+        vk::Result::SUCCESS => enumerate(&driver.config().layers, count, properties),
+        result => result,
+    }
+}
+
+unsafe extern "system" fn create_instance(
+    info: *const vk::InstanceCreateInfo,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::Instance,
+) -> vk::Result {
+    let Some(driver) = current() else {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    };
+
+    let result = driver.call("vkCreateInstance", &[]);
+    if result != vk::Result::SUCCESS {
+        return result;
+    }
+
+    let info = &*info;
+    let config = driver.config();
+
+    let layers = names(info.enabled_layer_count, info.enabled_layer_names);
+    let supported = config
+        .layers
+        .iter()
+        .map(|l| l.layer_name)
+        .collect::<Vec<_>>();
+    if !supports(&supported, &layers) {
+        return vk::Result::ERROR_LAYER_NOT_PRESENT;
+    }
+
+    let extensions = names(info.enabled_extension_count, info.enabled_extension_names);
+    let supported = config
+        .extensions
+        .iter()
+        .map(|e| e.extension_name)
+        .collect::<Vec<_>>();
+    if !supports(&supported, &extensions) {
+        return vk::Result::ERROR_EXTENSION_NOT_PRESENT;
+    }
+
+    let debug_utils = config.unfiltered_device_commands
+        || extensions
+            .iter()
+            .any(|e| *e == vk::EXT_DEBUG_UTILS_EXTENSION.name.as_cstr());
+
+    let dispatch = dispatch();
+    let physical_devices = config
+        .physical_devices
+        .iter()
+        .map(|c| PhysicalDeviceState {
+            dispatch,
+            driver: driver.clone(),
+            config: c.clone(),
+            debug_utils,
+            displays: c
+                .displays
+                .iter()
+                .map(|d| DisplayState {
+                    handle: vk::DisplayKHR::from_raw(driver.allocate()),
+                    name: CString::new(d.name.clone()).unwrap_or_default(),
+                    modes: d
+                        .modes
+                        .iter()
+                        .map(|_| vk::DisplayModeKHR::from_raw(driver.allocate()))
+                        .collect(),
+                })
+                .collect(),
+        })
+        .collect();
+
+    let state = Box::new(InstanceState {
+        dispatch,
+        driver: driver.clone(),
+        extensions: extensions
+            .iter()
+            .map(|e| vk::ExtensionName::from_cstr(e))
+            .collect(),
+        physical_devices,
+    });
+
+    let handle = Box::into_raw(state) as usize;
+    driver.update(handle as u64, Object::Instance);
+    *output = vk::Instance::from_raw(handle);
+
+    vk::Result::SUCCESS
+}
+
+//================================================
+// Commands (Instance)
+//================================================
+
+unsafe extern "system" fn destroy_instance(
+    handle: vk::Instance,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    if handle.is_null() {
+        return;
+    }
+
+    let state = Box::from_raw(handle.as_raw() as *mut InstanceState);
+    state
+        .driver
+        .call("vkDestroyInstance", &[handle.as_raw() as u64]);
+    state.driver.destroy(handle.as_raw() as u64);
+}
+
+unsafe extern "system" fn enumerate_physical_devices(
+    handle: vk::Instance,
+    count: *mut u32,
+    output: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    let state = instance(handle);
+    match state
+        .driver
+        .call("vkEnumeratePhysicalDevices", &[handle.as_raw() as u64])
+    {
+        vk::Result::SUCCESS => {
+            let physical_devices = state
+                .physical_devices
+                .iter()
+                .map(|p| vk::PhysicalDevice::from_raw(p as *const PhysicalDeviceState as usize))
+                .collect::<Vec<_>>();
+            enumerate(&physical_devices, count, output)
+        }
+        result => result,
+    }
+}
+
+unsafe extern "system" fn get_physical_device_properties(
+    handle: vk::PhysicalDevice,
+    properties: *mut vk::PhysicalDeviceProperties,
+) {
+    let state = physical_device(handle);
+    state
+        .driver
+        .call("vkGetPhysicalDeviceProperties", &[handle.as_raw() as u64]);
+    *properties = state.config.properties;
+}
+
+unsafe extern "system" fn get_physical_device_properties2(
+    handle: vk::PhysicalDevice,
+    properties: *mut vk::PhysicalDeviceProperties2,
+) {
+    let state = physical_device(handle);
+    state
+        .driver
+        .call("vkGetPhysicalDeviceProperties2", &[handle.as_raw() as u64]);
+    (*properties).properties = state.config.properties;
+}
+
+unsafe extern "system" fn get_physical_device_features(
+    handle: vk::PhysicalDevice,
+    features: *mut vk::PhysicalDeviceFeatures,
+) {
+    let state = physical_device(handle);
+    state
+        .driver
+        .call("vkGetPhysicalDeviceFeatures", &[handle.as_raw() as u64]);
+    *features = state.config.features;
+}
+
+unsafe extern "system" fn get_physical_device_features2(
+    handle: vk::PhysicalDevice,
+    features: *mut vk::PhysicalDeviceFeatures2,
+) {
+    let state = physical_device(handle);
+    state
+        .driver
+        .call("vkGetPhysicalDeviceFeatures2", &[handle.as_raw() as u64]);
+    (*features).features = state.config.features;
+
+    let mut next = (*features).next.cast::<vk::BaseOutStructure>();
+    while !next.is_null() {
+        match (*next).s_type {
+            vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES => {
+                copy_features(next, &state.config.vulkan11_features)
+            }
+            vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES => {
+                copy_features(next, &state.config.vulkan12_features)
+            }
+            vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_3_FEATURES => {
+                copy_features(next, &state.config.vulkan13_features)
+            }
+            vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_4_FEATURES => {
+                copy_features(next, &state.config.vulkan14_features)
+            }
+            _ => {}
+        }
+
+        next = (*next).next;
+    }
+}
+
+/// Copies the feature flags of a feature struct into a struct in a chain.
+unsafe fn copy_features<F: Features>(target: *mut vk::BaseOutStructure, features: &F) {
+    let target = &mut *target.cast::<F>();
+    target.flags_mut().copy_from_slice(features.flags());
+}
+
+unsafe extern "system" fn get_physical_device_queue_family_properties(
+    handle: vk::PhysicalDevice,
+    count: *mut u32,
+    properties: *mut vk::QueueFamilyProperties,
+) {
+    let state = physical_device(handle);
+    state.driver.call(
+        "vkGetPhysicalDeviceQueueFamilyProperties",
+        &[handle.as_raw() as u64],
+    );
+    enumerate(&state.config.queue_families, count, properties);
+}
+
+unsafe extern "system" fn get_physical_device_format_properties(
+    handle: vk::PhysicalDevice,
+    format: vk::Format,
+    properties: *mut vk::FormatProperties,
+) {
+    let state = physical_device(handle);
+    state.driver.call(
+        "vkGetPhysicalDeviceFormatProperties",
+        &[handle.as_raw() as u64, format.as_raw() as u64],
+    );
+    let supported = state.config.format_properties.get(&format);
+    *properties = supported.copied().unwrap_or_default();
+}
+
+unsafe extern "system" fn get_physical_device_memory_properties(
+    handle: vk::PhysicalDevice,
+    properties: *mut vk::PhysicalDeviceMemoryProperties,
+) {
+    let state = physical_device(handle);
+    state.driver.call(
+        "vkGetPhysicalDeviceMemoryProperties",
+        &[handle.as_raw() as u64],
+    );
+    *properties = state.config.memory_properties;
+}
+
+unsafe extern "system" fn get_physical_device_surface_support_khr(
+    handle: vk::PhysicalDevice,
+    queue_family_index: u32,
+    surface: vk::SurfaceKHR,
+    supported: *mut vk::Bool32,
+) -> vk::Result {
+    let state = physical_device(handle);
+    let args = [
+        handle.as_raw() as u64,
+        queue_family_index as u64,
+        surface.as_raw(),
+    ];
+    let result = state
+        .driver
+        .call("vkGetPhysicalDeviceSurfaceSupportKHR", &args);
+    if result == vk::Result::SUCCESS {
+        let support = state.config.surface_support
+            && (queue_family_index as usize) < state.config.queue_families.len();
+        *supported = if support { vk::TRUE } else { vk::FALSE };
+    }
+
+    result
+}
+
+unsafe extern "system" fn get_physical_device_surface_capabilities_khr(
+    handle: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    capabilities: *mut vk::SurfaceCapabilitiesKHR,
+) -> vk::Result {
+    let state = physical_device(handle);
+    let args = [handle.as_raw() as u64, surface.as_raw()];
+    let result = state
+        .driver
+        .call("vkGetPhysicalDeviceSurfaceCapabilitiesKHR", &args);
+    if result == vk::Result::SUCCESS {
+        *capabilities = state.config.surface_capabilities;
+    }
+
+    result
+}
+
+unsafe extern "system" fn get_physical_device_surface_formats_khr(
+    handle: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    count: *mut u32,
+    formats: *mut vk::SurfaceFormatKHR,
+) -> vk::Result {
+    let state = physical_device(handle);
+    let args = [handle.as_raw() as u64, surface.as_raw()];
+    match state
+        .driver
+        .call("vkGetPhysicalDeviceSurfaceFormatsKHR", &args)
+    {
+        vk::Result::SUCCESS => enumerate(&state.config.surface_formats, count, formats),
+        result => result,
+    }
+}
+
+unsafe extern "system" fn get_physical_device_surface_present_modes_khr(
+    handle: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    count: *mut u32,
+    present_modes: *mut vk::PresentModeKHR,
+) -> vk::Result {
+    let state = physical_device(handle);
+    let args = [handle.as_raw() as u64, surface.as_raw()];
+    match state
+        .driver
+        .call("vkGetPhysicalDeviceSurfacePresentModesKHR", &args)
+    {
+        vk::Result::SUCCESS => enumerate(&state.config.present_modes, count, present_modes),
+        result => result,
+    }
+}
+
+unsafe extern "system" fn destroy_surface_khr(
+    handle: vk::Instance,
+    surface: vk::SurfaceKHR,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    let state = instance(handle);
+    let args = [handle.as_raw() as u64, surface.as_raw()];
+    state.driver.call("vkDestroySurfaceKHR", &args);
+    state.driver.destroy(surface.as_raw());
+}
+
+unsafe extern "system" fn create_headless_surface_ext(
+    handle: vk::Instance,
+    _info: *const vk::HeadlessSurfaceCreateInfoEXT,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let state = instance(handle);
+
+    let result = state
+        .driver
+        .call("vkCreateHeadlessSurfaceEXT", &[handle.as_raw() as u64]);
+    if result == vk::Result::SUCCESS {
+        *output = vk::SurfaceKHR::from_raw(state.driver.create(Object::Surface));
+    }
+
+    result
+}
+
+unsafe extern "system" fn get_physical_device_display_properties_khr(
+    handle: vk::PhysicalDevice,
+    count: *mut u32,
+    properties: *mut vk::DisplayPropertiesKHR,
+) -> vk::Result {
+    let state = physical_device(handle);
+    let result = state.driver.call(
+        "vkGetPhysicalDeviceDisplayPropertiesKHR",
+        &[handle.as_raw() as u64],
+    );
+    if result != vk::Result::SUCCESS {
+        return result;
+    }
+
+    let values = state
+        .displays
+        .iter()
+        .zip(state.config.displays.iter())
+        .map(|(s, c)| vk::DisplayPropertiesKHR {
+            display: s.handle,
+            display_name: s.name.as_ptr(),
+            physical_dimensions: c.physical_dimensions,
+            physical_resolution: c.physical_resolution,
+            supported_transforms: c.supported_transforms,
+            plane_reorder_possible: vk::FALSE,
+            persistent_content: vk::FALSE,
+        })
+        .collect::<Vec<_>>();
+    enumerate(&values, count, properties)
+}
+
+unsafe extern "system" fn get_physical_device_display_plane_properties_khr(
+    handle: vk::PhysicalDevice,
+    count: *mut u32,
+    properties: *mut vk::DisplayPlanePropertiesKHR,
+) -> vk::Result {
+    let state = physical_device(handle);
+    let result = state.driver.call(
+        "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+        &[handle.as_raw() as u64],
+    );
+    if result != vk::Result::SUCCESS {
+        return result;
+    }
+
+    let values = state
+        .displays
+        .iter()
+        .map(|d| vk::DisplayPlanePropertiesKHR {
+            current_display: d.handle,
+            current_stack_index: 0,
+        })
+        .collect::<Vec<_>>();
+    enumerate(&values, count, properties)
+}
+
+unsafe extern "system" fn get_display_plane_supported_displays_khr(
+    handle: vk::PhysicalDevice,
+    plane_index: u32,
+    count: *mut u32,
+    displays: *mut vk::DisplayKHR,
+) -> vk::Result {
+    let state = physical_device(handle);
+    let args = [handle.as_raw() as u64, plane_index as u64];
+    let result = state
+        .driver
+        .call("vkGetDisplayPlaneSupportedDisplaysKHR", &args);
+    if result != vk::Result::SUCCESS {
+        return result;
+    }
+
+    let values = state
+        .displays
+        .get(plane_index as usize)
+        .map(|d| vec![d.handle])
+        .unwrap_or_default();
+    enumerate(&values, count, displays)
+}
+
+unsafe extern "system" fn get_display_mode_properties_khr(
+    handle: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+    count: *mut u32,
+    properties: *mut vk::DisplayModePropertiesKHR,
+) -> vk::Result {
+    let state = physical_device(handle);
+    let args = [handle.as_raw() as u64, display.as_raw()];
+    let result = state.driver.call("vkGetDisplayModePropertiesKHR", &args);
+    if result != vk::Result::SUCCESS {
+        return result;
+    }
+
+    let values = state
+        .displays
+        .iter()
+        .zip(state.config.displays.iter())
+        .filter(|(s, _)| s.handle == display)
+        .flat_map(|(s, c)| s.modes.iter().zip(c.modes.iter()))
+        .map(|(m, p)| vk::DisplayModePropertiesKHR {
+            display_mode: *m,
+            parameters: *p,
+        })
+        .collect::<Vec<_>>();
+    enumerate(&values, count, properties)
+}
+
+unsafe extern "system" fn get_display_plane_capabilities_khr(
+    handle: vk::PhysicalDevice,
+    mode: vk::DisplayModeKHR,
+    plane_index: u32,
+    capabilities: *mut vk::DisplayPlaneCapabilitiesKHR,
+) -> vk::Result {
+    let state = physical_device(handle);
+    let args = [handle.as_raw() as u64, mode.as_raw(), plane_index as u64];
+    let result = state.driver.call("vkGetDisplayPlaneCapabilitiesKHR", &args);
+    if result != vk::Result::SUCCESS {
+        return result;
+    }
+
+    let extent = state
+        .displays
+        .iter()
+        .zip(state.config.displays.iter())
+        .flat_map(|(s, c)| s.modes.iter().zip(c.modes.iter()))
+        .find(|(m, _)| **m == mode)
+        .map(|(_, p)| p.visible_region)
+        .unwrap_or_default();
+
+    *capabilities = vk::DisplayPlaneCapabilitiesKHR {
+        supported_alpha: vk::DisplayPlaneAlphaFlagsKHR::OPAQUE,
+        max_src_extent: extent,
+        max_dst_extent: extent,
+        ..Default::default()
+    };
+
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn create_display_plane_surface_khr(
+    handle: vk::Instance,
+    _info: *const vk::DisplaySurfaceCreateInfoKHR,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let state = instance(handle);
+
+    let result = state
+        .driver
+        .call("vkCreateDisplayPlaneSurfaceKHR", &[handle.as_raw() as u64]);
+    if result == vk::Result::SUCCESS {
+        *output = vk::SurfaceKHR::from_raw(state.driver.create(Object::Surface));
+    }
+
+    result
+}
+
+unsafe extern "system" fn enumerate_device_extension_properties(
+    handle: vk::PhysicalDevice,
+    layer_name: *const c_char,
+    count: *mut u32,
+    properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    let state = physical_device(handle);
+    let args = [handle.as_raw() as u64];
+    match state
+        .driver
+        .call("vkEnumerateDeviceExtensionProperties", &args)
+    {
+        vk::Result::SUCCESS if layer_name.is_null() => {
+            enumerate(&state.config.extensions, count, properties)
+        }
+        vk::Result::SUCCESS => enumerate(&[], count, properties),
+        result => result,
+    }
+}
+
+unsafe extern "system" fn enumerate_device_layer_properties(
+    handle: vk::PhysicalDevice,
+    count: *mut u32,
+    properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    let state = physical_device(handle);
+    let args = [handle.as_raw() as u64];
+    match state.driver.call("vkEnumerateDeviceLayerProperties", &args) {
+        vk::Result::SUCCESS => enumerate(&state.config.layers, count, properties),
+        result => result,
+    }
+}
+
+unsafe extern "system" fn create_device(
+    handle: vk::PhysicalDevice,
+    info: *const vk::DeviceCreateInfo,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::Device,
+) -> vk::Result {
+    let state = physical_device(handle);
+
+    let result = state
+        .driver
+        .call("vkCreateDevice", &[handle.as_raw() as u64]);
+    if result != vk::Result::SUCCESS {
+        return result;
+    }
+
+    let info = &*info;
+    let config = &state.config;
+
+    let extensions = names(info.enabled_extension_count, info.enabled_extension_names);
+    let supported = config
+        .extensions
+        .iter()
+        .map(|e| e.extension_name)
+        .collect::<Vec<_>>();
+    if !supports(&supported, &extensions) {
+        return vk::Result::ERROR_EXTENSION_NOT_PRESENT;
+    }
+
+    let supported = match info.enabled_features.as_ref() {
+        Some(requested) => config.features.is_superset(requested),
+        None => true,
+    };
+    if !supported || !supports_features(config, info.next) {
+        return vk::Result::ERROR_FEATURE_NOT_PRESENT;
+    }
+
+    let dispatch = dispatch();
+    let mut queues = Vec::new();
+    if info.queue_create_info_count != 0 && !info.queue_create_infos.is_null() {
+        let infos = slice::from_raw_parts(
+            info.queue_create_infos,
+            info.queue_create_info_count as usize,
+        );
+        for info in infos {
+            let family = config.queue_families.get(info.queue_family_index as usize);
+            if family.is_none_or(|f| info.queue_count > f.queue_count) {
+                return vk::Result::ERROR_INITIALIZATION_FAILED;
+            }
+
+            for index in 0..info.queue_count {
+                queues.push(QueueState {
+                    dispatch,
+                    driver: state.driver.clone(),
+                    family: info.queue_family_index,
+                    index,
+                });
+            }
+        }
+    }
+
+    let device = Box::new(DeviceState {
+        dispatch,
+        driver: state.driver.clone(),
+        queues,
+        debug_utils: state.debug_utils,
+    });
+
+    let raw = Box::into_raw(device) as usize;
+    state.driver.update(raw as u64, Object::Device);
+    *output = vk::Device::from_raw(raw);
+
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn get_device_proc_addr(
+    handle: vk::Device,
+    name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    // Like real Vulkan drivers, device commands provided by instance extensions
+    // are only available if the extension was enabled for the instance.
+    let instance = CStr::from_ptr(name)
+        .to_bytes()
+        .starts_with(b"vkSetDebugUtilsObject");
+    if instance && !device(handle).debug_utils {
+        return None;
+    }
+
+    lookup(name)
+}
+
+unsafe extern "system" fn create_debug_utils_messenger_ext(
+    handle: vk::Instance,
+    info: *const vk::DebugUtilsMessengerCreateInfoEXT,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::DebugUtilsMessengerEXT,
+) -> vk::Result {
+    let state = instance(handle);
+    let info = &*info;
+
+    let result = state
+        .driver
+        .call("vkCreateDebugUtilsMessengerEXT", &[handle.as_raw() as u64]);
+    if result == vk::Result::SUCCESS {
+        let object = Object::DebugUtilsMessenger {
+            severity: info.message_severity,
+            types: info.message_type,
+            callback: info.user_callback,
+            user_data: info.user_data as usize,
+        };
+
+        *output = vk::DebugUtilsMessengerEXT::from_raw(state.driver.create(object));
+    }
+
+    result
+}
+
+unsafe extern "system" fn destroy_debug_utils_messenger_ext(
+    handle: vk::Instance,
+    messenger: vk::DebugUtilsMessengerEXT,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    let state = instance(handle);
+    let args = [handle.as_raw() as u64, messenger.as_raw()];
+    state.driver.call("vkDestroyDebugUtilsMessengerEXT", &args);
+    state.driver.destroy(messenger.as_raw());
+}
+
+/// Calls the callbacks of the debug messengers that match the severity and
+/// types of the submitted message (in the order they were created).
+unsafe extern "system" fn submit_debug_utils_message_ext(
+    handle: vk::Instance,
+    severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    types: vk::DebugUtilsMessageTypeFlagsEXT,
+    data: *const vk::DebugUtilsMessengerCallbackDataEXT,
+) {
+    let state = instance(handle);
+    let args = [
+        handle.as_raw() as u64,
+        severity.bits() as u64,
+        types.bits() as u64,
+    ];
+    state.driver.call("vkSubmitDebugUtilsMessageEXT", &args);
+
+    // The callbacks are collected first so they can create or destroy objects.
+    let mut callbacks = lock(&state.driver.0.objects)
+        .iter()
+        .filter_map(|(h, o)| match *o {
+            Object::DebugUtilsMessenger {
+                severity: s,
+                types: t,
+                callback: Some(callback),
+                user_data,
+            } if s.intersects(severity) && t.intersects(types) => Some((*h, callback, user_data)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    callbacks.sort_by_key(|(h, _, _)| *h);
+
+    for (_, callback, user_data) in callbacks {
+        callback(severity, types, data, user_data as *mut c_void);
+    }
+}
+
+//================================================
+// Commands (Device)
+//================================================
+
+unsafe extern "system" fn destroy_device(
+    handle: vk::Device,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    if handle.is_null() {
+        return;
+    }
+
+    let state = Box::from_raw(handle.as_raw() as *mut DeviceState);
+    state
+        .driver
+        .call("vkDestroyDevice", &[handle.as_raw() as u64]);
+    state.driver.destroy(handle.as_raw() as u64);
+}
+
+unsafe extern "system" fn device_wait_idle(handle: vk::Device) -> vk::Result {
+    device(handle)
+        .driver
+        .call("vkDeviceWaitIdle", &[handle.as_raw() as u64])
+}
+
+unsafe extern "system" fn get_device_queue(
+    handle: vk::Device,
+    family: u32,
+    index: u32,
+    output: *mut vk::Queue,
+) {
+    let state = device(handle);
+    let args = [handle.as_raw() as u64, family as u64, index as u64];
+    state.driver.call("vkGetDeviceQueue", &args);
+
+    let queue = state
+        .queues
+        .iter()
+        .find(|q| q.family == family && q.index == index);
+    *output = queue.map_or(vk::Queue::null(), |q| {
+        vk::Queue::from_raw(q as *const QueueState as usize)
+    });
+}
+
+unsafe extern "system" fn get_device_queue2(
+    handle: vk::Device,
+    info: *const vk::DeviceQueueInfo2,
+    output: *mut vk::Queue,
+) {
+    let info = &*info;
+    let state = device(handle);
+    let args = [
+        handle.as_raw() as u64,
+        info.queue_family_index as u64,
+        info.queue_index as u64,
+    ];
+    state.driver.call("vkGetDeviceQueue2", &args);
+
+    let queue = state
+        .queues
+        .iter()
+        .find(|q| q.family == info.queue_family_index && q.index == info.queue_index);
+    *output = queue.map_or(vk::Queue::null(), |q| {
+        vk::Queue::from_raw(q as *const QueueState as usize)
+    });
+}
+
+/// Completes the submitted work immediately (signaling the supplied fence and
+/// any timeline semaphores signaled by the submissions).
+unsafe extern "system" fn queue_submit(
+    handle: vk::Queue,
+    count: u32,
+    submits: *const vk::SubmitInfo,
+    fence: vk::Fence,
+) -> vk::Result {
+    let state = queue(handle);
+    let args = [handle.as_raw() as u64, count as u64, fence.as_raw()];
+
+    let result = state.driver.call("vkQueueSubmit", &args);
+    if result != vk::Result::SUCCESS {
+        return result;
+    }
+
+    let submits = if count == 0 || submits.is_null() {
+        &[]
+    } else {
+        slice::from_raw_parts(submits, count as usize)
+    };
+
+    for submit in submits {
+        let mut next = submit.next.cast::<vk::BaseInStructure>();
+        while !next.is_null() {
+            if (*next).s_type == vk::StructureType::TIMELINE_SEMAPHORE_SUBMIT_INFO {
+                let info = &*next.cast::<vk::TimelineSemaphoreSubmitInfo>();
+                let count = submit
+                    .signal_semaphore_count
+                    .min(info.signal_semaphore_value_count) as usize;
+                if count != 0 {
+                    let semaphores = slice::from_raw_parts(submit.signal_semaphores, count);
+                    let values = slice::from_raw_parts(info.signal_semaphore_values, count);
+                    for (semaphore, value) in semaphores.iter().zip(values) {
+                        signal(&state.driver, *semaphore, *value);
+                    }
+                }
+            }
+
+            next = (*next).next;
+        }
+    }
+
+    if !fence.is_null() {
+        state
+            .driver
+            .update(fence.as_raw(), Object::Fence { signaled: true });
+    }
+
+    result
+}
+
+unsafe extern "system" fn queue_wait_idle(handle: vk::Queue) -> vk::Result {
+    queue(handle)
+        .driver
+        .call("vkQueueWaitIdle", &[handle.as_raw() as u64])
+}
+
+unsafe extern "system" fn create_buffer(
+    handle: vk::Device,
+    info: *const vk::BufferCreateInfo,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::Buffer,
+) -> vk::Result {
+    let state = device(handle);
+    let size = (*info).size;
+
+    let result = state
+        .driver
+        .call("vkCreateBuffer", &[handle.as_raw() as u64, size]);
+    if result == vk::Result::SUCCESS {
+        *output = vk::Buffer::from_raw(state.driver.create(Object::Buffer { size }));
+    }
+
+    result
+}
+
+unsafe extern "system" fn destroy_buffer(
+    handle: vk::Device,
+    buffer: vk::Buffer,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    let state = device(handle);
+    state.driver.call(
+        "vkDestroyBuffer",
+        &[handle.as_raw() as u64, buffer.as_raw()],
+    );
+    state.driver.destroy(buffer.as_raw());
+}
+
+unsafe extern "system" fn get_buffer_memory_requirements(
+    handle: vk::Device,
+    buffer: vk::Buffer,
+    requirements: *mut vk::MemoryRequirements,
+) {
+    let state = device(handle);
+    let args = [handle.as_raw() as u64, buffer.as_raw()];
+    state.driver.call("vkGetBufferMemoryRequirements", &args);
+
+    if let Some(Object::Buffer { size }) = state.driver.object(buffer.as_raw()) {
+        *requirements = memory_size(size);
+    }
+}
+
+unsafe extern "system" fn bind_buffer_memory(
+    handle: vk::Device,
+    buffer: vk::Buffer,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+) -> vk::Result {
+    let args = [
+        handle.as_raw() as u64,
+        buffer.as_raw(),
+        memory.as_raw(),
+        offset,
+    ];
+    device(handle).driver.call("vkBindBufferMemory", &args)
+}
+
+unsafe extern "system" fn create_image(
+    handle: vk::Device,
+    info: *const vk::ImageCreateInfo,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::Image,
+) -> vk::Result {
+    let state = device(handle);
+    let info = &*info;
+
+    let texels = info.extent.width as u64 * info.extent.height as u64 * info.extent.depth as u64;
+    let size = texels * info.array_layers.max(1) as u64 * 4;
+
+    let result = state
+        .driver
+        .call("vkCreateImage", &[handle.as_raw() as u64]);
+    if result == vk::Result::SUCCESS {
+        *output = vk::Image::from_raw(state.driver.create(Object::Image { size }));
+    }
+
+    result
+}
+
+unsafe extern "system" fn destroy_image(
+    handle: vk::Device,
+    image: vk::Image,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    let state = device(handle);
+    state
+        .driver
+        .call("vkDestroyImage", &[handle.as_raw() as u64, image.as_raw()]);
+    state.driver.destroy(image.as_raw());
+}
+
+unsafe extern "system" fn get_image_memory_requirements(
+    handle: vk::Device,
+    image: vk::Image,
+    requirements: *mut vk::MemoryRequirements,
+) {
+    let state = device(handle);
+    let args = [handle.as_raw() as u64, image.as_raw()];
+    state.driver.call("vkGetImageMemoryRequirements", &args);
+
+    if let Some(Object::Image { size }) = state.driver.object(image.as_raw()) {
+        *requirements = memory_size(size);
+    }
+}
+
+unsafe extern "system" fn bind_image_memory(
+    handle: vk::Device,
+    image: vk::Image,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+) -> vk::Result {
+    let args = [
+        handle.as_raw() as u64,
+        image.as_raw(),
+        memory.as_raw(),
+        offset,
+    ];
+    device(handle).driver.call("vkBindImageMemory", &args)
+}
+
+unsafe extern "system" fn create_image_view(
+    handle: vk::Device,
+    info: *const vk::ImageViewCreateInfo,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::ImageView,
+) -> vk::Result {
+    let state = device(handle);
+    let args = [handle.as_raw() as u64, (*info).image.as_raw()];
+
+    let result = state.driver.call("vkCreateImageView", &args);
+    if result == vk::Result::SUCCESS {
+        *output = vk::ImageView::from_raw(state.driver.create(Object::ImageView));
+    }
+
+    result
+}
+
+unsafe extern "system" fn destroy_image_view(
+    handle: vk::Device,
+    view: vk::ImageView,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    let state = device(handle);
+    let args = [handle.as_raw() as u64, view.as_raw()];
+    state.driver.call("vkDestroyImageView", &args);
+    state.driver.destroy(view.as_raw());
+}
+
+unsafe extern "system" fn create_sampler_ycbcr_conversion(
+    handle: vk::Device,
+    _info: *const vk::SamplerYcbcrConversionCreateInfo,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::SamplerYcbcrConversion,
+) -> vk::Result {
+    create_conversion("vkCreateSamplerYcbcrConversion", handle, output)
+}
+
+unsafe extern "system" fn destroy_sampler_ycbcr_conversion(
+    handle: vk::Device,
+    conversion: vk::SamplerYcbcrConversion,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    destroy_conversion("vkDestroySamplerYcbcrConversion", handle, conversion);
+}
+
+unsafe extern "system" fn create_sampler_ycbcr_conversion_khr(
+    handle: vk::Device,
+    _info: *const vk::SamplerYcbcrConversionCreateInfo,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::SamplerYcbcrConversion,
+) -> vk::Result {
+    create_conversion("vkCreateSamplerYcbcrConversionKHR", handle, output)
+}
+
+unsafe extern "system" fn destroy_sampler_ycbcr_conversion_khr(
+    handle: vk::Device,
+    conversion: vk::SamplerYcbcrConversion,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    destroy_conversion("vkDestroySamplerYcbcrConversionKHR", handle, conversion);
+}
+
+unsafe fn create_conversion(
+    command: &'static str,
+    handle: vk::Device,
+    output: *mut vk::SamplerYcbcrConversion,
+) -> vk::Result {
+    let state = device(handle);
+
+    let result = state.driver.call(command, &[handle.as_raw() as u64]);
+    if result == vk::Result::SUCCESS {
+        let conversion = state.driver.create(Object::SamplerYcbcrConversion);
+        *output = vk::SamplerYcbcrConversion::from_raw(conversion);
+    }
+
+    result
+}
+
+unsafe fn destroy_conversion(
+    command: &'static str,
+    handle: vk::Device,
+    conversion: vk::SamplerYcbcrConversion,
+) {
+    let state = device(handle);
+    let args = [handle.as_raw() as u64, conversion.as_raw()];
+    state.driver.call(command, &args);
+    state.driver.destroy(conversion.as_raw());
+}
+
+unsafe extern "system" fn allocate_memory(
+    handle: vk::Device,
+    info: *const vk::MemoryAllocateInfo,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::DeviceMemory,
+) -> vk::Result {
+    let state = device(handle);
+    let info = &*info;
+
+    let args = [
+        handle.as_raw() as u64,
+        info.allocation_size,
+        info.memory_type_index as u64,
+    ];
+    let result = state.driver.call("vkAllocateMemory", &args);
+    if result == vk::Result::SUCCESS {
+        *output = vk::DeviceMemory::from_raw(state.driver.create(Object::Memory));
+    }
+
+    result
+}
+
+unsafe extern "system" fn free_memory(
+    handle: vk::Device,
+    memory: vk::DeviceMemory,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    let state = device(handle);
+    state
+        .driver
+        .call("vkFreeMemory", &[handle.as_raw() as u64, memory.as_raw()]);
+    state.driver.destroy(memory.as_raw());
+}
+
+unsafe extern "system" fn create_fence(
+    handle: vk::Device,
+    info: *const vk::FenceCreateInfo,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::Fence,
+) -> vk::Result {
+    let state = device(handle);
+    let signaled = (*info).flags.contains(vk::FenceCreateFlags::SIGNALED);
+
+    let result = state
+        .driver
+        .call("vkCreateFence", &[handle.as_raw() as u64]);
+    if result == vk::Result::SUCCESS {
+        *output = vk::Fence::from_raw(state.driver.create(Object::Fence { signaled }));
+    }
+
+    result
+}
+
+unsafe extern "system" fn destroy_fence(
+    handle: vk::Device,
+    fence: vk::Fence,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    let state = device(handle);
+    state
+        .driver
+        .call("vkDestroyFence", &[handle.as_raw() as u64, fence.as_raw()]);
+    state.driver.destroy(fence.as_raw());
+}
+
+unsafe extern "system" fn get_fence_status(handle: vk::Device, fence: vk::Fence) -> vk::Result {
+    let state = device(handle);
+    let result = state.driver.call(
+        "vkGetFenceStatus",
+        &[handle.as_raw() as u64, fence.as_raw()],
+    );
+    match (result, state.driver.object(fence.as_raw())) {
+        (vk::Result::SUCCESS, Some(Object::Fence { signaled: false })) => vk::Result::NOT_READY,
+        _ => result,
+    }
+}
+
+unsafe extern "system" fn reset_fences(
+    handle: vk::Device,
+    count: u32,
+    fences: *const vk::Fence,
+) -> vk::Result {
+    let state = device(handle);
+    let fences = slice::from_raw_parts(fences, count as usize);
+
+    let mut args = vec![handle.as_raw() as u64];
+    args.extend(fences.iter().map(|f| f.as_raw()));
+
+    let result = state.driver.call("vkResetFences", &args);
+    if result == vk::Result::SUCCESS {
+        for fence in fences {
+            state
+                .driver
+                .update(fence.as_raw(), Object::Fence { signaled: false });
+        }
+    }
+
+    result
+}
+
+unsafe extern "system" fn wait_for_fences(
+    handle: vk::Device,
+    count: u32,
+    fences: *const vk::Fence,
+    wait_all: vk::Bool32,
+    timeout: u64,
+) -> vk::Result {
+    let state = device(handle);
+    let fences = slice::from_raw_parts(fences, count as usize);
+
+    let mut args = vec![handle.as_raw() as u64];
+    args.extend(fences.iter().map(|f| f.as_raw()));
+    args.extend([wait_all as u64, timeout]);
+
+    let result = state.driver.call("vkWaitForFences", &args);
+    if result != vk::Result::SUCCESS {
+        return result;
+    }
+
+    let signaled = |f: &vk::Fence| {
+        matches!(
+            state.driver.object(f.as_raw()),
+            Some(Object::Fence { signaled: true })
+        )
+    };
+
+    let done = if wait_all == vk::TRUE {
+        fences.iter().all(signaled)
+    } else {
+        fences.iter().any(signaled)
+    };
+
+    if done {
+        vk::Result::SUCCESS
+    } else {
+        vk::Result::TIMEOUT
+    }
+}
+
+unsafe extern "system" fn create_semaphore(
+    handle: vk::Device,
+    info: *const vk::SemaphoreCreateInfo,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::Semaphore,
+) -> vk::Result {
+    let state = device(handle);
+
+    let mut value = None;
+    let mut next = (*info).next.cast::<vk::BaseInStructure>();
+    while !next.is_null() {
+        if (*next).s_type == vk::StructureType::SEMAPHORE_TYPE_CREATE_INFO {
+            let info = &*next.cast::<vk::SemaphoreTypeCreateInfo>();
+            if info.semaphore_type == vk::SemaphoreType::TIMELINE {
+                value = Some(info.initial_value);
+            }
+        }
+
+        next = (*next).next;
+    }
+
+    let result = state
+        .driver
+        .call("vkCreateSemaphore", &[handle.as_raw() as u64]);
+    if result == vk::Result::SUCCESS {
+        let object = Object::Semaphore { value };
+        *output = vk::Semaphore::from_raw(state.driver.create(object));
+    }
+
+    result
+}
+
+unsafe extern "system" fn destroy_semaphore(
+    handle: vk::Device,
+    semaphore: vk::Semaphore,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    let state = device(handle);
+    let args = [handle.as_raw() as u64, semaphore.as_raw()];
+    state.driver.call("vkDestroySemaphore", &args);
+    state.driver.destroy(semaphore.as_raw());
+}
+
+/// Gets the current value of a timeline semaphore.
+fn counter(driver: &MockDriver, semaphore: vk::Semaphore) -> Option<u64> {
+    match driver.object(semaphore.as_raw()) {
+        Some(Object::Semaphore { value }) => value,
+        _ => None,
+    }
+}
+
+/// Signals a timeline semaphore (if the value is greater than its current
+/// value).
+fn signal(driver: &MockDriver, semaphore: vk::Semaphore, value: u64) {
+    if let Some(current) = counter(driver, semaphore) {
+        let value = Some(current.max(value));
+        driver.update(semaphore.as_raw(), Object::Semaphore { value });
+    }
+}
+
+unsafe extern "system" fn get_semaphore_counter_value(
+    handle: vk::Device,
+    semaphore: vk::Semaphore,
+    output: *mut u64,
+) -> vk::Result {
+    let state = device(handle);
+    let args = [handle.as_raw() as u64, semaphore.as_raw()];
+    let result = state.driver.call("vkGetSemaphoreCounterValue", &args);
+    if result == vk::Result::SUCCESS {
+        *output = counter(&state.driver, semaphore).unwrap_or_default();
+    }
+
+    result
+}
+
+unsafe extern "system" fn wait_semaphores(
+    handle: vk::Device,
+    info: *const vk::SemaphoreWaitInfo,
+    timeout: u64,
+) -> vk::Result {
+    let state = device(handle);
+    let info = &*info;
+
+    let count = info.semaphore_count as usize;
+    let (semaphores, values) = if count == 0 {
+        (&[][..], &[][..])
+    } else {
+        let semaphores = slice::from_raw_parts(info.semaphores, count);
+        let values = slice::from_raw_parts(info.values, count);
+        (semaphores, values)
+    };
+
+    let mut args = vec![handle.as_raw() as u64];
+    args.extend(semaphores.iter().map(|s| s.as_raw()));
+    args.extend(values.iter().copied());
+    args.extend([info.flags.bits() as u64, timeout]);
+
+    let result = state.driver.call("vkWaitSemaphores", &args);
+    if result != vk::Result::SUCCESS {
+        return result;
+    }
+
+    let reached =
+        |(s, v): (&vk::Semaphore, &u64)| counter(&state.driver, *s).is_some_and(|c| c >= *v);
+
+    let done = if info.flags.contains(vk::SemaphoreWaitFlags::ANY) {
+        semaphores.iter().zip(values).any(reached)
+    } else {
+        semaphores.iter().zip(values).all(reached)
+    };
+
+    if done {
+        vk::Result::SUCCESS
+    } else {
+        vk::Result::TIMEOUT
+    }
+}
+
+unsafe extern "system" fn signal_semaphore(
+    handle: vk::Device,
+    info: *const vk::SemaphoreSignalInfo,
+) -> vk::Result {
+    let state = device(handle);
+    let info = &*info;
+
+    let args = [handle.as_raw() as u64, info.semaphore.as_raw(), info.value];
+    let result = state.driver.call("vkSignalSemaphore", &args);
+    if result == vk::Result::SUCCESS {
+        signal(&state.driver, info.semaphore, info.value);
+    }
+
+    result
+}
+
+unsafe extern "system" fn create_swapchain_khr(
+    handle: vk::Device,
+    info: *const vk::SwapchainCreateInfoKHR,
+    _allocator: *const vk::AllocationCallbacks,
+    output: *mut vk::SwapchainKHR,
+) -> vk::Result {
+    let state = device(handle);
+    let info = &*info;
+
+    let args = [
+        handle.as_raw() as u64,
+        info.surface.as_raw(),
+        info.min_image_count as u64,
+        info.old_swapchain.as_raw(),
+    ];
+    let result = state.driver.call("vkCreateSwapchainKHR", &args);
+    if result == vk::Result::SUCCESS {
+        let image_count = info.min_image_count.max(1);
+        let object = Object::Swapchain {
+            first_image: state.driver.allocate_many(image_count as u64),
+            image_count,
+            next_image: 0,
+        };
+
+        *output = vk::SwapchainKHR::from_raw(state.driver.create(object));
+    }
+
+    result
+}
+
+unsafe extern "system" fn destroy_swapchain_khr(
+    handle: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    _allocator: *const vk::AllocationCallbacks,
+) {
+    let state = device(handle);
+    let args = [handle.as_raw() as u64, swapchain.as_raw()];
+    state.driver.call("vkDestroySwapchainKHR", &args);
+    state.driver.destroy(swapchain.as_raw());
+}
+
+unsafe extern "system" fn get_swapchain_images_khr(
+    handle: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    count: *mut u32,
+    images: *mut vk::Image,
+) -> vk::Result {
+    let state = device(handle);
+    let args = [handle.as_raw() as u64, swapchain.as_raw()];
+    let result = state.driver.call("vkGetSwapchainImagesKHR", &args);
+    if result != vk::Result::SUCCESS {
+        return result;
+    }
+
+    let values = match state.driver.object(swapchain.as_raw()) {
+        Some(Object::Swapchain {
+            first_image,
+            image_count,
+            ..
+        }) => (0..image_count as u64)
+            .map(|i| vk::Image::from_raw(first_image + i))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    enumerate(&values, count, images)
+}
+
+/// Acquires the swapchain images in order (wrapping around) and immediately
+/// signals the supplied fence (if any).
+unsafe extern "system" fn acquire_next_image_khr(
+    handle: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    timeout: u64,
+    semaphore: vk::Semaphore,
+    fence: vk::Fence,
+    output: *mut u32,
+) -> vk::Result {
+    let state = device(handle);
+    let args = [
+        handle.as_raw() as u64,
+        swapchain.as_raw(),
+        timeout,
+        semaphore.as_raw(),
+        fence.as_raw(),
+    ];
+
+    let result = state.driver.call("vkAcquireNextImageKHR", &args);
+    if result != vk::Result::SUCCESS && result != vk::Result::SUBOPTIMAL_KHR {
+        return result;
+    }
+
+    if let Some(Object::Swapchain {
+        first_image,
+        image_count,
+        next_image,
+    }) = state.driver.object(swapchain.as_raw())
+    {
+        *output = next_image;
+        let object = Object::Swapchain {
+            first_image,
+            image_count,
+            next_image: (next_image + 1) % image_count,
+        };
+
+        state.driver.update(swapchain.as_raw(), object);
+    }
+
+    if !fence.is_null() {
+        state
+            .driver
+            .update(fence.as_raw(), Object::Fence { signaled: true });
+    }
+
+    result
+}
+
+unsafe extern "system" fn queue_present_khr(
+    handle: vk::Queue,
+    info: *const vk::PresentInfoKHR,
+) -> vk::Result {
+    let state = queue(handle);
+    let info = &*info;
+
+    let count = info.swapchain_count as usize;
+    let swapchains = slice::from_raw_parts(info.swapchains, count);
+    let indices = slice::from_raw_parts(info.image_indices, count);
+
+    let mut args = vec![handle.as_raw() as u64];
+    args.extend(swapchains.iter().map(|s| s.as_raw()));
+    args.extend(indices.iter().map(|i| *i as u64));
+
+    let result = state.driver.call("vkQueuePresentKHR", &args);
+    if !info.results.is_null() {
+        slice::from_raw_parts_mut(info.results, count).fill(result);
+    }
+
+    result
+}
+
+unsafe extern "system" fn set_debug_utils_object_name_ext(
+    handle: vk::Device,
+    info: *const vk::DebugUtilsObjectNameInfoEXT,
+) -> vk::Result {
+    let state = device(handle);
+    let info = &*info;
+
+    let args = [
+        handle.as_raw() as u64,
+        info.object_type.as_raw() as u64,
+        info.object_handle,
+    ];
+    let result = state.driver.call("vkSetDebugUtilsObjectNameEXT", &args);
+    if result == vk::Result::SUCCESS {
+        state.driver.name(info.object_handle, info.object_name);
+    }
+
+    result
+}
+
+unsafe extern "system" fn set_debug_utils_object_tag_ext(
+    handle: vk::Device,
+    info: *const vk::DebugUtilsObjectTagInfoEXT,
+) -> vk::Result {
+    let state = device(handle);
+    let info = &*info;
+
+    let args = [
+        handle.as_raw() as u64,
+        info.object_type.as_raw() as u64,
+        info.object_handle,
+        info.tag_name,
+    ];
+    state.driver.call("vkSetDebugUtilsObjectTagEXT", &args)
+}
+
+unsafe extern "system" fn debug_marker_set_object_name_ext(
+    handle: vk::Device,
+    info: *const vk::DebugMarkerObjectNameInfoEXT,
+) -> vk::Result {
+    let state = device(handle);
+    let info = &*info;
+
+    let args = [
+        handle.as_raw() as u64,
+        info.object_type.as_raw() as u64,
+        info.object,
+    ];
+    let result = state.driver.call("vkDebugMarkerSetObjectNameEXT", &args);
+    if result == vk::Result::SUCCESS {
+        state.driver.name(info.object, info.object_name);
+    }
+
+    result
+}
+
+unsafe extern "system" fn debug_marker_set_object_tag_ext(
+    handle: vk::Device,
+    info: *const vk::DebugMarkerObjectTagInfoEXT,
+) -> vk::Result {
+    let state = device(handle);
+    let info = &*info;
+
+    let args = [
+        handle.as_raw() as u64,
+        info.object_type.as_raw() as u64,
+        info.object,
+        info.tag_name,
+    ];
+    state.driver.call("vkDebugMarkerSetObjectTagEXT", &args)
+}
@@ -0,0 +1,324 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Owned Vulkan handles.
+//!
+//! Vulkan objects created with `vulkanalia` need to be destroyed manually
+//! (e.g., with [`DeviceV1_0::destroy_buffer`]). An [`Owned`] Vulkan handle
+//! instead destroys its Vulkan object when it is dropped using the command
+//! registered for the handle type with the [`Destroy`] trait.
+//!
+//! ```no_run
+//! # use vulkanalia::prelude::v1_0::*;
+//! # use vulkanalia::owned::Owned;
+//! # fn example(device: &Device) -> VkResult<()> {
+//! let info = vk::BufferCreateInfo::builder()
+//!     .size(1024)
+//!     .usage(vk::BufferUsageFlags::VERTEX_BUFFER);
+//!
+//! let buffer = unsafe { Owned::new(device, device.create_buffer(&info, None)?) };
+//! let requirements = unsafe { device.get_buffer_memory_requirements(*buffer) };
+//!
+//! // The buffer is destroyed here when `buffer` is dropped.
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Vulkan instances and devices can similarly be owned with [`OwnedInstance`]
+//! and [`OwnedDevice`] which destroy the instance or device when the last
+//! clone is dropped. An owned device keeps the owned instance it was created
+//! with alive, so the instance is always destroyed after the device.
+//!
+//! Note that an [`Owned`] handle only stores a (non-owning) [`Device`] or
+//! [`Instance`], so owned handles must be dropped before the owned device or
+//! instance that created them is destroyed.
+//!
+//! [`DeviceV1_0::destroy_buffer`]: crate::vk::DeviceV1_0::destroy_buffer
+
+use alloc::sync::Arc;
+use core::fmt;
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+use core::ptr;
+
+use crate::vk::{self, DeviceV1_0, Handle, InstanceV1_0};
+use crate::{Device, Entry, Instance, VkResult};
+
+/// A Vulkan handle type that can be destroyed with a Vulkan command.
+///
+/// This trait is implemented for every Vulkan handle type that is destroyed
+/// by passing the handle to a command along with its parent [`Instance`] or
+/// [`Device`] and allocation callbacks (e.g., [`vk::SurfaceKHR`] which is
+/// destroyed with `vkDestroySurfaceKHR` or [`vk::DeviceMemory`] which is
+/// destroyed with `vkFreeMemory`).
+///
+/// [`Device`]: crate::Device
+/// [`Instance`]: crate::Instance
+pub trait Destroy: Handle {
+    /// The type of the parent of this handle (either [`crate::Instance`] or
+    /// [`crate::Device`]).
+    type Parent: Clone;
+
+    /// Destroys this Vulkan handle.
+    ///
+    /// # Safety
+    ///
+    /// This handle must have been created with `parent` and `allocator` must be
+    /// compatible with the allocation callbacks provided when this handle was
+    /// created.
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&vk::AllocationCallbacks>);
+}
+
+/// A Vulkan handle which is destroyed when dropped.
+///
+/// The parent [`Instance`] or [`Device`] of the handle is cloned (which only
+/// increments a reference count) and stored alongside the handle.
+///
+/// Owned handles are destroyed without allocation callbacks, so handles
+/// created with allocation callbacks should not be owned.
+///
+/// [`Device`]: crate::Device
+/// [`Instance`]: crate::Instance
+pub struct Owned<H: Destroy> {
+    handle: H,
+    parent: H::Parent,
+}
+
+impl<H: Destroy> Owned<H> {
+    /// Takes ownership of a Vulkan handle.
+    ///
+    /// # Safety
+    ///
+    /// The handle must have been created with `parent` without allocation
+    /// callbacks and must not be destroyed except by dropping this value.
+    ///
+    /// `parent` must outlive the returned value (i.e., the Vulkan instance or
+    /// device must not be destroyed while the returned value exists).
+    #[inline]
+    pub unsafe fn new(parent: &H::Parent, handle: H) -> Self {
+        Self {
+            handle,
+            parent: parent.clone(),
+        }
+    }
+
+    /// Gets the Vulkan handle.
+    #[inline]
+    pub fn handle(&self) -> H {
+        self.handle
+    }
+
+    /// Gets the parent of the Vulkan handle.
+    #[inline]
+    pub fn parent(&self) -> &H::Parent {
+        &self.parent
+    }
+
+    /// Releases ownership of the Vulkan handle without destroying it.
+    #[inline]
+    pub fn into_raw(self) -> H {
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY: `this` is not dropped so the parent is not used again.
+        unsafe { ptr::drop_in_place(&mut this.parent) };
+        this.handle
+    }
+}
+
+impl<H: Destroy> Deref for Owned<H> {
+    type Target = H;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl<H: Destroy> fmt::Debug for Owned<H> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Owned").field(&self.handle).finish()
+    }
+}
+
+impl<H: Destroy> Drop for Owned<H> {
+    #[inline]
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe { self.handle.destroy(&self.parent, None) };
+        }
+    }
+}
+
+/// A Vulkan instance which is destroyed when the last clone is dropped.
+///
+/// The Vulkan entry point used to create the instance is kept alive until the
+/// instance is destroyed. The instance is destroyed without allocation
+/// callbacks.
+#[derive(Clone)]
+pub struct OwnedInstance {
+    state: Arc<OwnedInstanceState>,
+}
+
+struct OwnedInstanceState {
+    entry: Entry,
+    instance: Instance,
+}
+
+impl OwnedInstance {
+    /// Takes ownership of a Vulkan instance.
+    ///
+    /// # Safety
+    ///
+    /// The instance must have been created with `entry` without allocation
+    /// callbacks and must not be destroyed except by dropping this value and
+    /// its clones.
+    #[inline]
+    pub unsafe fn new(entry: &Entry, instance: Instance) -> Self {
+        let state = OwnedInstanceState {
+            entry: entry.clone(),
+            instance,
+        };
+
+        Self {
+            state: Arc::new(state),
+        }
+    }
+
+    /// Creates a Vulkan instance which is destroyed when the last clone is
+    /// dropped.
+    ///
+    /// # Safety
+    ///
+    /// See [`Entry::create_instance`].
+    #[inline]
+    pub unsafe fn create(entry: &Entry, info: &vk::InstanceCreateInfo) -> VkResult<Self> {
+        let instance = entry.create_instance(info, None)?;
+        Ok(Self::new(entry, instance))
+    }
+
+    /// Gets the Vulkan entry point used to create this instance.
+    #[inline]
+    pub fn entry(&self) -> &Entry {
+        &self.state.entry
+    }
+
+    /// Gets the Vulkan instance.
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.state.instance
+    }
+}
+
+impl Deref for OwnedInstance {
+    type Target = Instance;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.state.instance
+    }
+}
+
+impl fmt::Debug for OwnedInstance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OwnedInstance")
+            .field(&self.state.instance)
+            .finish()
+    }
+}
+
+impl Drop for OwnedInstanceState {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { self.instance.destroy_instance(None) };
+    }
+}
+
+/// A Vulkan device which is destroyed when the last clone is dropped.
+///
+/// The Vulkan instance used to create the device is kept alive until the
+/// device is destroyed. Before the device is destroyed, it is waited on to
+/// become idle. The device is destroyed without allocation callbacks.
+#[derive(Clone)]
+pub struct OwnedDevice {
+    state: Arc<OwnedDeviceState>,
+}
+
+struct OwnedDeviceState {
+    instance: OwnedInstance,
+    device: Device,
+}
+
+impl OwnedDevice {
+    /// Takes ownership of a Vulkan device.
+    ///
+    /// # Safety
+    ///
+    /// The device must have been created with `instance` without allocation
+    /// callbacks and must not be destroyed except by dropping this value and
+    /// its clones.
+    #[inline]
+    pub unsafe fn new(instance: &OwnedInstance, device: Device) -> Self {
+        let state = OwnedDeviceState {
+            instance: instance.clone(),
+            device,
+        };
+
+        Self {
+            state: Arc::new(state),
+        }
+    }
+
+    /// Creates a Vulkan device which is destroyed when the last clone is
+    /// dropped.
+    ///
+    /// # Safety
+    ///
+    /// See [`Instance::create_device`].
+    #[inline]
+    pub unsafe fn create(
+        instance: &OwnedInstance,
+        physical_device: vk::PhysicalDevice,
+        info: &vk::DeviceCreateInfo,
+    ) -> VkResult<Self> {
+        let device = instance.create_device(physical_device, info, None)?;
+        Ok(Self::new(instance, device))
+    }
+
+    /// Gets the Vulkan instance used to create this device.
+    #[inline]
+    pub fn instance(&self) -> &OwnedInstance {
+        &self.state.instance
+    }
+
+    /// Gets the Vulkan device.
+    #[inline]
+    pub fn device(&self) -> &Device {
+        &self.state.device
+    }
+}
+
+impl Deref for OwnedDevice {
+    type Target = Device;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.state.device
+    }
+}
+
+impl fmt::Debug for OwnedDevice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OwnedDevice")
+            .field(&self.state.device)
+            .finish()
+    }
+}
+
+impl Drop for OwnedDeviceState {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            // Errors are ignored since the device is destroyed regardless.
+            let _ = self.device.device_wait_idle();
+            self.device.destroy_device(None);
+        }
+    }
+}
@@ -0,0 +1,544 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Vulkan physical device selection.
+//!
+//! [`PhysicalDeviceSelector`] checks the physical devices available to a
+//! Vulkan instance against a set of requirements (see
+//! [`Instance::physical_device_selector`]). The physical devices are returned
+//! as [`Candidate`]s which are ranked by suitability and preference and which
+//! explain why a physical device is not suitable (see [`Rejection`]).
+//!
+//! ```no_run
+//! # use vulkanalia::prelude::v1_2::*;
+//! # use vulkanalia::Version;
+//! # fn example(instance: &Instance, surface: vk::SurfaceKHR) -> Result<(), vulkanalia::physical_device::SelectError> {
+//! let candidate = unsafe {
+//!     instance
+//!         .physical_device_selector()
+//!         .minimum_api_version(Version::V1_2_0)
+//!         .extension(vk::KHR_SWAPCHAIN_EXTENSION.name)
+//!         .vulkan12_features(vk::PhysicalDeviceVulkan12Features {
+//!             timeline_semaphore: vk::TRUE,
+//!             ..Default::default()
+//!         })
+//!         .queue(vk::QueueFlags::GRAPHICS)
+//!         .surface(surface)
+//!         .pick()?
+//! };
+//!
+//! println!("Selected physical device ({}).", candidate.properties.device_name);
+//! let graphics = candidate.queue_families[0].unwrap();
+//! let present = candidate.present_queue_family.unwrap();
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt;
+
+#[cfg(all(feature = "no_std_error", not(feature = "std")))]
+use core::error;
+#[cfg(feature = "std")]
+use std::error;
+
+use crate::Version;
+use crate::features::Features;
+use crate::prelude::v1_1::*;
+use crate::vk::KhrSurfaceExtensionInstanceCommands;
+
+/// A reason a physical device is not suitable.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    /// The Vulkan version supported by the physical device is lower than the
+    /// required minimum version.
+    Version {
+        /// The required minimum version.
+        required: Version,
+        /// The Vulkan version supported by the physical device.
+        available: Version,
+    },
+    /// Required extensions are not supported.
+    Extensions(Vec<vk::ExtensionName>),
+    /// Required features are not supported.
+    Features {
+        /// The name of the Vulkan struct which contains the features (e.g.,
+        /// `VkPhysicalDeviceVulkan12Features`).
+        structure: &'static str,
+        /// The names of the required features that are not supported.
+        features: Vec<&'static str>,
+    },
+    /// No queue family supports the required queue capabilities.
+    Queue(vk::QueueFlags),
+    /// No queue family supports presenting to the surface.
+    Present,
+    /// The largest device-local memory heap is smaller than the required
+    /// minimum size.
+    Memory {
+        /// The required minimum size (in bytes).
+        required: vk::DeviceSize,
+        /// The size of the largest device-local memory heap (in bytes).
+        available: vk::DeviceSize,
+    },
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Version {
+                required,
+                available,
+            } => {
+                write!(
+                    f,
+                    "required Vulkan {required} but only Vulkan {available} is supported"
+                )
+            }
+            Self::Extensions(extensions) => write!(f, "missing extensions ({extensions:?})"),
+            Self::Features {
+                structure,
+                features,
+            } => write!(f, "missing features from {structure} ({features:?})"),
+            Self::Queue(flags) => write!(f, "no queue family supports {flags:?}"),
+            Self::Present => write!(f, "no queue family supports presenting to the surface"),
+            Self::Memory {
+                required,
+                available,
+            } => {
+                write!(
+                    f,
+                    "required {required} bytes of device-local memory but only {available} bytes are available"
+                )
+            }
+        }
+    }
+}
+
+/// A physical device checked by a [`PhysicalDeviceSelector`].
+#[derive(Clone, Debug)]
+pub struct Candidate {
+    /// The physical device.
+    pub physical_device: vk::PhysicalDevice,
+    /// The properties of the physical device.
+    pub properties: vk::PhysicalDeviceProperties,
+    /// The size of the largest device-local memory heap of the physical device
+    /// (in bytes).
+    pub device_local_memory: vk::DeviceSize,
+    /// The index of a queue family which supports each of the required queue
+    /// capabilities (in the order the requirements were added).
+    pub queue_families: Vec<Option<u32>>,
+    /// The index of a queue family which supports presenting to the surface.
+    ///
+    /// Queue families which were selected for the required queue capabilities
+    /// are preferred.
+    pub present_queue_family: Option<u32>,
+    /// The reasons the physical device is not suitable.
+    pub rejections: Vec<Rejection>,
+}
+
+impl Candidate {
+    /// Gets whether the physical device meets all of the requirements.
+    #[inline]
+    pub fn is_suitable(&self) -> bool {
+        self.rejections.is_empty()
+    }
+}
+
+/// An error encountered while selecting a physical device with a
+/// [`PhysicalDeviceSelector`].
+#[derive(Clone, Debug)]
+pub enum SelectError {
+    /// No physical device meets all of the requirements.
+    Unsuitable(Vec<Candidate>),
+    /// A Vulkan command failed.
+    Vulkan(vk::ErrorCode),
+}
+
+impl From<vk::ErrorCode> for SelectError {
+    #[inline]
+    fn from(error: vk::ErrorCode) -> Self {
+        Self::Vulkan(error)
+    }
+}
+
+impl fmt::Display for SelectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unsuitable(candidates) if candidates.is_empty() => {
+                write!(f, "no physical devices are available")
+            }
+            Self::Unsuitable(candidates) => {
+                write!(f, "no suitable physical device")?;
+                for candidate in candidates {
+                    write!(f, "; {}:", candidate.properties.device_name)?;
+                    for (index, rejection) in candidate.rejections.iter().enumerate() {
+                        let separator = if index == 0 { " " } else { ", " };
+                        write!(f, "{separator}{rejection}")?;
+                    }
+                }
+                Ok(())
+            }
+            Self::Vulkan(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "no_std_error"))]
+impl error::Error for SelectError {}
+
+/// A selector for a Vulkan physical device which checks the available
+/// physical devices against a set of requirements.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Clone, Debug)]
+pub struct PhysicalDeviceSelector<'a> {
+    instance: &'a Instance,
+    minimum_api_version: Version,
+    extensions: Vec<vk::ExtensionName>,
+    features: vk::PhysicalDeviceFeatures,
+    vulkan11_features: vk::PhysicalDeviceVulkan11Features,
+    vulkan12_features: vk::PhysicalDeviceVulkan12Features,
+    vulkan13_features: vk::PhysicalDeviceVulkan13Features,
+    vulkan14_features: vk::PhysicalDeviceVulkan14Features,
+    queues: Vec<vk::QueueFlags>,
+    surface: Option<vk::SurfaceKHR>,
+    minimum_memory: vk::DeviceSize,
+    preferred_device_type: vk::PhysicalDeviceType,
+}
+
+impl<'a> PhysicalDeviceSelector<'a> {
+    /// Constructs a new selector for a Vulkan physical device.
+    #[inline]
+    pub fn new(instance: &'a Instance) -> Self {
+        Self {
+            instance,
+            minimum_api_version: Version::V1_0_0,
+            extensions: Vec::new(),
+            features: vk::PhysicalDeviceFeatures::default(),
+            vulkan11_features: vk::PhysicalDeviceVulkan11Features::default(),
+            vulkan12_features: vk::PhysicalDeviceVulkan12Features::default(),
+            vulkan13_features: vk::PhysicalDeviceVulkan13Features::default(),
+            vulkan14_features: vk::PhysicalDeviceVulkan14Features::default(),
+            queues: Vec::new(),
+            surface: None,
+            minimum_memory: 0,
+            preferred_device_type: vk::PhysicalDeviceType::DISCRETE_GPU,
+        }
+    }
+
+    /// Sets the lowest Vulkan version the physical device must support.
+    #[inline]
+    pub fn minimum_api_version(mut self, version: Version) -> Self {
+        self.minimum_api_version = version;
+        self
+    }
+
+    /// Adds a required device extension.
+    #[inline]
+    pub fn extension(mut self, name: vk::ExtensionName) -> Self {
+        self.extensions.push(name);
+        self
+    }
+
+    /// Adds required device extensions.
+    #[inline]
+    pub fn extensions(mut self, names: impl IntoIterator<Item = vk::ExtensionName>) -> Self {
+        self.extensions.extend(names);
+        self
+    }
+
+    /// Sets the required Vulkan 1.0 features.
+    #[inline]
+    pub fn features(mut self, features: vk::PhysicalDeviceFeatures) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// Sets the required Vulkan 1.1 features.
+    ///
+    /// These features can only be queried for physical devices which support
+    /// Vulkan 1.2 with an instance created for Vulkan 1.1 or later.
+    #[inline]
+    pub fn vulkan11_features(mut self, features: vk::PhysicalDeviceVulkan11Features) -> Self {
+        self.vulkan11_features = features;
+        self
+    }
+
+    /// Sets the required Vulkan 1.2 features.
+    #[inline]
+    pub fn vulkan12_features(mut self, features: vk::PhysicalDeviceVulkan12Features) -> Self {
+        self.vulkan12_features = features;
+        self
+    }
+
+    /// Sets the required Vulkan 1.3 features.
+    #[inline]
+    pub fn vulkan13_features(mut self, features: vk::PhysicalDeviceVulkan13Features) -> Self {
+        self.vulkan13_features = features;
+        self
+    }
+
+    /// Sets the required Vulkan 1.4 features.
+    #[inline]
+    pub fn vulkan14_features(mut self, features: vk::PhysicalDeviceVulkan14Features) -> Self {
+        self.vulkan14_features = features;
+        self
+    }
+
+    /// Adds required queue capabilities which must all be supported by a
+    /// single queue family (e.g., `GRAPHICS | COMPUTE`).
+    #[inline]
+    pub fn queue(mut self, flags: vk::QueueFlags) -> Self {
+        self.queues.push(flags);
+        self
+    }
+
+    /// Sets a surface which a queue family must support presenting to.
+    ///
+    /// The instance must have been created with the `VK_KHR_surface`
+    /// extension enabled.
+    #[inline]
+    pub fn surface(mut self, surface: vk::SurfaceKHR) -> Self {
+        self.surface = Some(surface);
+        self
+    }
+
+    /// Sets the minimum size (in bytes) of the largest device-local memory
+    /// heap.
+    #[inline]
+    pub fn minimum_memory(mut self, size: vk::DeviceSize) -> Self {
+        self.minimum_memory = size;
+        self
+    }
+
+    /// Sets the type of physical device that is preferred when multiple
+    /// physical devices are suitable (defaults to
+    /// [`vk::PhysicalDeviceType::DISCRETE_GPU`]).
+    #[inline]
+    pub fn preferred_device_type(mut self, type_: vk::PhysicalDeviceType) -> Self {
+        self.preferred_device_type = type_;
+        self
+    }
+
+    /// Checks the available physical devices against the requirements.
+    ///
+    /// The candidates are ranked so that suitable physical devices come
+    /// first, followed by physical devices of the preferred type, followed by
+    /// physical devices with more device-local memory. Otherwise, physical
+    /// devices are in the order they were enumerated in.
+    ///
+    /// # Safety
+    ///
+    /// The surface (if any) must have been created with the instance.
+    pub unsafe fn select(&self) -> VkResult<Vec<Candidate>> {
+        let mut candidates = Vec::new();
+        for physical_device in self.instance.enumerate_physical_devices()? {
+            candidates.push(self.check(physical_device)?);
+        }
+
+        candidates.sort_by_key(|c| {
+            let type_ = c.properties.device_type;
+            (
+                !c.is_suitable(),
+                type_ != self.preferred_device_type,
+                rank(type_),
+                Reverse(c.device_local_memory),
+            )
+        });
+
+        Ok(candidates)
+    }
+
+    /// Selects the highest ranked physical device which meets all of the
+    /// requirements (see [`PhysicalDeviceSelector::select`]).
+    ///
+    /// If no physical device is suitable, [`SelectError::Unsuitable`] is
+    /// returned with all of the candidates.
+    ///
+    /// # Safety
+    ///
+    /// See [`PhysicalDeviceSelector::select`].
+    pub unsafe fn pick(&self) -> Result<Candidate, SelectError> {
+        let mut candidates = self.select()?;
+        if candidates.first().is_some_and(|c| c.is_suitable()) {
+            Ok(candidates.swap_remove(0))
+        } else {
+            Err(SelectError::Unsuitable(candidates))
+        }
+    }
+
+    /// Checks a physical device against the requirements.
+    unsafe fn check(&self, physical_device: vk::PhysicalDevice) -> VkResult<Candidate> {
+        let mut rejections = Vec::new();
+
+        // Check the Vulkan version.
+
+        let properties = self
+            .instance
+            .get_physical_device_properties(physical_device);
+        let version = Version::from(properties.api_version);
+        if version < self.minimum_api_version {
+            rejections.push(Rejection::Version {
+                required: self.minimum_api_version,
+                available: version,
+            });
+        }
+
+        // Check the extensions.
+
+        let supported = self
+            .instance
+            .enumerate_device_extension_properties(physical_device, None)?
+            .into_iter()
+            .map(|e| e.extension_name)
+            .collect::<Vec<_>>();
+
+        let mut missing = Vec::new();
+        for extension in &self.extensions {
+            if !supported.contains(extension) && !missing.contains(extension) {
+                missing.push(*extension);
+            }
+        }
+
+        if !missing.is_empty() {
+            rejections.push(Rejection::Extensions(missing));
+        }
+
+        // Check the features.
+
+        let features = self.instance.get_physical_device_features(physical_device);
+        check_features(&mut rejections, &self.features, &features);
+
+        let mut vulkan11 = vk::PhysicalDeviceVulkan11Features::default();
+        let mut vulkan12 = vk::PhysicalDeviceVulkan12Features::default();
+        let mut vulkan13 = vk::PhysicalDeviceVulkan13Features::default();
+        let mut vulkan14 = vk::PhysicalDeviceVulkan14Features::default();
+
+        let required = !self.vulkan11_features.is_empty()
+            || !self.vulkan12_features.is_empty()
+            || !self.vulkan13_features.is_empty()
+            || !self.vulkan14_features.is_empty();
+
+        if required && self.instance.version() >= Version::V1_1_0 {
+            let mut info = vk::PhysicalDeviceFeatures2::builder();
+            if version >= Version::V1_2_0 {
+                info = info.push_next(&mut vulkan11).push_next(&mut vulkan12);
+            }
+            if version >= Version::V1_3_0 {
+                info = info.push_next(&mut vulkan13);
+            }
+            if version >= Version::V1_4_0 {
+                info = info.push_next(&mut vulkan14);
+            }
+
+            self.instance
+                .get_physical_device_features2(physical_device, &mut info);
+        }
+
+        check_features(&mut rejections, &self.vulkan11_features, &vulkan11);
+        check_features(&mut rejections, &self.vulkan12_features, &vulkan12);
+        check_features(&mut rejections, &self.vulkan13_features, &vulkan13);
+        check_features(&mut rejections, &self.vulkan14_features, &vulkan14);
+
+        // Check the queue families.
+
+        let families = self
+            .instance
+            .get_physical_device_queue_family_properties(physical_device);
+
+        let mut queue_families = Vec::with_capacity(self.queues.len());
+        for flags in &self.queues {
+            let index = families
+                .iter()
+                .position(|f| f.queue_count > 0 && f.queue_flags.contains(*flags));
+            if index.is_none() {
+                rejections.push(Rejection::Queue(*flags));
+            }
+
+            queue_families.push(index.map(|i| i as u32));
+        }
+
+        let mut present_queue_family = None;
+        if let Some(surface) = self.surface {
+            let mut indices = queue_families.iter().flatten().copied().collect::<Vec<_>>();
+            indices.extend(0..families.len() as u32);
+
+            for index in indices {
+                let supported = self.instance.get_physical_device_surface_support_khr(
+                    physical_device,
+                    index,
+                    surface,
+                )?;
+
+                if supported {
+                    present_queue_family = Some(index);
+                    break;
+                }
+            }
+
+            if present_queue_family.is_none() {
+                rejections.push(Rejection::Present);
+            }
+        }
+
+        // Check the memory heaps.
+
+        let memory = self
+            .instance
+            .get_physical_device_memory_properties(physical_device);
+
+        let device_local_memory = memory.memory_heaps[..memory.memory_heap_count as usize]
+            .iter()
+            .filter(|h| h.flags.contains(vk::MemoryHeapFlags::DEVICE_LOCAL))
+            .map(|h| h.size)
+            .max()
+            .unwrap_or(0);
+
+        if device_local_memory < self.minimum_memory {
+            rejections.push(Rejection::Memory {
+                required: self.minimum_memory,
+                available: device_local_memory,
+            });
+        }
+
+        Ok(Candidate {
+            physical_device,
+            properties,
+            device_local_memory,
+            queue_families,
+            present_queue_family,
+            rejections,
+        })
+    }
+}
+
+/// Adds a rejection for the required features which are not available.
+fn check_features<F: Features>(rejections: &mut Vec<Rejection>, required: &F, available: &F) {
+    let missing = required.difference(available);
+    if !missing.is_empty() {
+        rejections.push(Rejection::Features {
+            structure: F::NAME,
+            features: missing.names(),
+        });
+    }
+}
+
+/// Ranks a physical device type (lower is better).
+fn rank(type_: vk::PhysicalDeviceType) -> u32 {
+    match type_ {
+        vk::PhysicalDeviceType::DISCRETE_GPU => 0,
+        vk::PhysicalDeviceType::INTEGRATED_GPU => 1,
+        vk::PhysicalDeviceType::VIRTUAL_GPU => 2,
+        vk::PhysicalDeviceType::CPU => 3,
+        _ => 4,
+    }
+}
+
+impl Instance {
+    /// Creates a selector for a Vulkan physical device which checks the
+    /// available physical devices against a set of requirements.
+    #[inline]
+    pub fn physical_device_selector(&self) -> PhysicalDeviceSelector<'_> {
+        PhysicalDeviceSelector::new(self)
+    }
+}
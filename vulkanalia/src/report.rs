@@ -0,0 +1,693 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Vulkan physical device capability reports.
+//!
+//! A [`Report`] describes the capabilities of the physical devices available
+//! to a Vulkan instance (properties, limits, features, extensions, queue
+//! families, memory heaps and types, format properties, and optionally the
+//! capabilities of a surface) similar to the output of `vulkaninfo`. Reports
+//! can be serialized (e.g., as JSON with [`Report::to_json`]) and compared
+//! (see [`Report::diff`]) to explain differences in behavior between machines.
+//!
+//! ```no_run
+//! # use vulkanalia::prelude::v1_0::*;
+//! # use vulkanalia::report::Report;
+//! # fn example(instance: &Instance, json: &str) -> Result<(), Box<dyn std::error::Error>> {
+//! let report = unsafe { Report::new(instance, None)? };
+//! report.write_json(std::io::stdout())?;
+//!
+//! // Compare this machine with a report attached to a bug report.
+//! let other = Report::from_json(json)?;
+//! for difference in report.diff(&other) {
+//!     println!("{difference}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::format;
+use std::io;
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Version;
+use crate::features::FeatureSet;
+use crate::prelude::v1_0::*;
+use crate::vk::KhrSurfaceExtensionInstanceCommands;
+
+/// A report of the capabilities of the physical devices available to a
+/// Vulkan instance.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    /// The version of the Vulkan instance (e.g., `1.3.0`).
+    pub version: String,
+    /// The reports for the physical devices.
+    pub devices: Vec<DeviceReport>,
+}
+
+impl Report {
+    /// Gathers a report of the capabilities of the physical devices available
+    /// to a Vulkan instance.
+    ///
+    /// If a surface is supplied, the capabilities of the physical devices for
+    /// the surface are included in the report.
+    ///
+    /// # Safety
+    ///
+    /// `surface`, if supplied, must be a valid surface created with `instance`
+    /// (which requires `VK_KHR_surface` to be enabled for `instance`).
+    pub unsafe fn new(instance: &Instance, surface: Option<vk::SurfaceKHR>) -> VkResult<Self> {
+        let devices = instance
+            .enumerate_physical_devices()?
+            .into_iter()
+            .map(|p| DeviceReport::new(instance, p, surface))
+            .collect::<VkResult<_>>()?;
+
+        Ok(Self {
+            version: instance.version().to_string(),
+            devices,
+        })
+    }
+
+    /// Parses a report from JSON.
+    #[inline]
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Serializes this report as pretty-printed JSON.
+    #[inline]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("failed to serialize report")
+    }
+
+    /// Writes this report as pretty-printed JSON.
+    #[inline]
+    pub fn write_json(&self, writer: impl io::Write) -> serde_json::Result<()> {
+        serde_json::to_writer_pretty(writer, self)
+    }
+
+    /// Gets the differences between this report and `other`.
+    ///
+    /// Physical devices are matched by name (and by the order of physical
+    /// devices with the same name).
+    pub fn diff(&self, other: &Report) -> Vec<Difference> {
+        let mut differences = Vec::new();
+
+        let left = Value::from(self.version.as_str());
+        let right = Value::from(other.version.as_str());
+        compare(
+            "version".into(),
+            Some(&left),
+            Some(&right),
+            &mut differences,
+        );
+
+        let left = devices(&self.devices);
+        let right = devices(&other.devices);
+        for name in left.keys().chain(right.keys()).collect::<BTreeSet<_>>() {
+            let path = format!("devices[{name}]");
+            compare(path, left.get(name), right.get(name), &mut differences);
+        }
+
+        differences
+    }
+}
+
+/// Gets the serialized reports for physical devices keyed by name.
+fn devices(devices: &[DeviceReport]) -> BTreeMap<String, Value> {
+    let mut keyed = BTreeMap::new();
+    for device in devices {
+        let mut key = device.properties.device_name.clone();
+        let mut index = 1;
+        while keyed.contains_key(&key) {
+            index += 1;
+            key = format!("{} #{index}", device.properties.device_name);
+        }
+
+        let value = serde_json::to_value(device).expect("failed to serialize report");
+        keyed.insert(key, value);
+    }
+
+    keyed
+}
+
+/// Adds the differences between two values to a list of differences.
+fn compare(
+    path: String,
+    left: Option<&Value>,
+    right: Option<&Value>,
+    differences: &mut Vec<Difference>,
+) {
+    match (left, right) {
+        (Some(Value::Object(left)), Some(Value::Object(right))) => {
+            for key in left.keys().chain(right.keys()).collect::<BTreeSet<_>>() {
+                let path = format!("{path}.{key}");
+                compare(path, left.get(key), right.get(key), differences);
+            }
+        }
+        (Some(Value::Array(left)), Some(Value::Array(right))) => {
+            for index in 0..left.len().max(right.len()) {
+                let path = format!("{path}[{index}]");
+                compare(path, left.get(index), right.get(index), differences);
+            }
+        }
+        (left, right) if left != right => differences.push(Difference {
+            path,
+            left: left.cloned(),
+            right: right.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+/// A difference between two reports.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Difference {
+    /// The path of the value which differs (e.g.,
+    /// `devices[NVIDIA GeForce RTX 4090].limits.max_image_dimension2_d`).
+    pub path: String,
+    /// The value in the first report (if present).
+    pub left: Option<Value>,
+    /// The value in the second report (if present).
+    pub right: Option<Value>,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = |v: &Option<Value>| match v {
+            Some(value) => value.to_string(),
+            None => "(missing)".into(),
+        };
+
+        write!(
+            f,
+            "{}: {} != {}",
+            self.path,
+            display(&self.left),
+            display(&self.right)
+        )
+    }
+}
+
+/// A report of the capabilities of a physical device.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeviceReport {
+    /// The properties of the physical device.
+    pub properties: PropertiesReport,
+    /// The limits of the physical device.
+    pub limits: BTreeMap<String, Value>,
+    /// The sparse properties of the physical device.
+    pub sparse_properties: BTreeMap<String, bool>,
+    /// The features supported by the physical device keyed by the names of the
+    /// Vulkan structs which contain them (e.g., `VkPhysicalDeviceVulkan12Features`).
+    pub features: BTreeMap<String, BTreeMap<String, bool>>,
+    /// The spec versions of the device extensions supported by the physical
+    /// device keyed by extension name.
+    pub extensions: BTreeMap<String, u32>,
+    /// The queue families of the physical device.
+    pub queue_families: Vec<QueueFamilyReport>,
+    /// The memory heaps of the physical device.
+    pub memory_heaps: Vec<MemoryHeapReport>,
+    /// The memory types of the physical device.
+    pub memory_types: Vec<MemoryTypeReport>,
+    /// The properties of the formats supported by the physical device keyed by
+    /// format (formats which are not supported at all are omitted).
+    pub formats: BTreeMap<String, FormatReport>,
+    /// The capabilities of the physical device for a surface (if a surface was
+    /// supplied when gathering the report).
+    pub surface: Option<SurfaceReport>,
+}
+
+impl DeviceReport {
+    /// Gathers a report of the capabilities of a physical device.
+    ///
+    /// # Safety
+    ///
+    /// See [`Report::new`].
+    pub unsafe fn new(
+        instance: &Instance,
+        physical_device: vk::PhysicalDevice,
+        surface: Option<vk::SurfaceKHR>,
+    ) -> VkResult<Self> {
+        let properties = instance.get_physical_device_properties(physical_device);
+
+        let extensions = instance.enumerate_device_extension_properties(physical_device, None)?;
+        let names = extensions
+            .iter()
+            .map(|e| e.extension_name)
+            .collect::<BTreeSet<_>>();
+        let extensions = extensions
+            .iter()
+            .map(|e| (e.extension_name.to_string(), e.spec_version))
+            .collect();
+
+        let version = instance.version().min(properties.api_version.into());
+        let features = FeatureSet::all(version, &names).query(instance, physical_device);
+        let mut structs = BTreeMap::<String, BTreeMap<String, bool>>::new();
+        for (feature, enabled) in features.iter() {
+            let flags = structs.entry(feature.structure.into()).or_default();
+            flags.insert(feature.name.into(), enabled);
+        }
+
+        let queue_families = instance
+            .get_physical_device_queue_family_properties(physical_device)
+            .iter()
+            .map(QueueFamilyReport::from)
+            .collect();
+
+        let memory = instance.get_physical_device_memory_properties(physical_device);
+        let memory_heaps = memory.memory_heaps[..memory.memory_heap_count as usize]
+            .iter()
+            .map(MemoryHeapReport::from)
+            .collect();
+        let memory_types = memory.memory_types[..memory.memory_type_count as usize]
+            .iter()
+            .map(MemoryTypeReport::from)
+            .collect();
+
+        let mut formats = BTreeMap::new();
+        for format in vk::Format::VALUES
+            .iter()
+            .filter(|f| **f != vk::Format::UNDEFINED)
+        {
+            let properties =
+                instance.get_physical_device_format_properties(physical_device, *format);
+            let report = FormatReport::from(&properties);
+            if report != FormatReport::default() {
+                formats.insert(format!("{format:?}"), report);
+            }
+        }
+
+        let surface = match surface {
+            Some(surface) => Some(SurfaceReport::new(instance, physical_device, surface)?),
+            None => None,
+        };
+
+        Ok(Self {
+            properties: PropertiesReport::from(&properties),
+            limits: limits(&properties.limits),
+            sparse_properties: sparse_properties(&properties.sparse_properties),
+            features: structs,
+            extensions,
+            queue_families,
+            memory_heaps,
+            memory_types,
+            formats,
+            surface,
+        })
+    }
+}
+
+/// Inserts the values of the fields of a Vulkan struct into a map.
+macro_rules! fields {
+    ($map:expr, $struct:expr, $convert:expr, [$($field:ident),* $(,)?]) => {
+        $($map.insert(stringify!($field).into(), $convert($struct.$field));)*
+    };
+}
+
+/// Gets the limits of a physical device.
+fn limits(limits: &vk::PhysicalDeviceLimits) -> BTreeMap<String, Value> {
+    let mut map = BTreeMap::new();
+
+    fields!(
+        map,
+        limits,
+        |v| Value::Bool(v != vk::FALSE),
+        [
+            timestamp_compute_and_graphics,
+            strict_lines,
+            standard_sample_locations,
+        ]
+    );
+
+    fields!(
+        map,
+        limits,
+        |v| Value::from(names(v)),
+        [
+            framebuffer_color_sample_counts,
+            framebuffer_depth_sample_counts,
+            framebuffer_stencil_sample_counts,
+            framebuffer_no_attachments_sample_counts,
+            sampled_image_color_sample_counts,
+            sampled_image_integer_sample_counts,
+            sampled_image_depth_sample_counts,
+            sampled_image_stencil_sample_counts,
+            storage_image_sample_counts,
+        ]
+    );
+
+    fields!(
+        map,
+        limits,
+        serialize,
+        [
+            max_image_dimension_1d,
+            max_image_dimension_2d,
+            max_image_dimension_3d,
+            max_image_dimension_cube,
+            max_image_array_layers,
+            max_texel_buffer_elements,
+            max_uniform_buffer_range,
+            max_storage_buffer_range,
+            max_push_constants_size,
+            max_memory_allocation_count,
+            max_sampler_allocation_count,
+            buffer_image_granularity,
+            sparse_address_space_size,
+            max_bound_descriptor_sets,
+            max_per_stage_descriptor_samplers,
+            max_per_stage_descriptor_uniform_buffers,
+            max_per_stage_descriptor_storage_buffers,
+            max_per_stage_descriptor_sampled_images,
+            max_per_stage_descriptor_storage_images,
+            max_per_stage_descriptor_input_attachments,
+            max_per_stage_resources,
+            max_descriptor_set_samplers,
+            max_descriptor_set_uniform_buffers,
+            max_descriptor_set_uniform_buffers_dynamic,
+            max_descriptor_set_storage_buffers,
+            max_descriptor_set_storage_buffers_dynamic,
+            max_descriptor_set_sampled_images,
+            max_descriptor_set_storage_images,
+            max_descriptor_set_input_attachments,
+            max_vertex_input_attributes,
+            max_vertex_input_bindings,
+            max_vertex_input_attribute_offset,
+            max_vertex_input_binding_stride,
+            max_vertex_output_components,
+            max_tessellation_generation_level,
+            max_tessellation_patch_size,
+            max_tessellation_control_per_vertex_input_components,
+            max_tessellation_control_per_vertex_output_components,
+            max_tessellation_control_per_patch_output_components,
+            max_tessellation_control_total_output_components,
+            max_tessellation_evaluation_input_components,
+            max_tessellation_evaluation_output_components,
+            max_geometry_shader_invocations,
+            max_geometry_input_components,
+            max_geometry_output_components,
+            max_geometry_output_vertices,
+            max_geometry_total_output_components,
+            max_fragment_input_components,
+            max_fragment_output_attachments,
+            max_fragment_dual_src_attachments,
+            max_fragment_combined_output_resources,
+            max_compute_shared_memory_size,
+            max_compute_work_group_count,
+            max_compute_work_group_invocations,
+            max_compute_work_group_size,
+            sub_pixel_precision_bits,
+            sub_texel_precision_bits,
+            mipmap_precision_bits,
+            max_draw_indexed_index_value,
+            max_draw_indirect_count,
+            max_sampler_lod_bias,
+            max_sampler_anisotropy,
+            max_viewports,
+            max_viewport_dimensions,
+            viewport_bounds_range,
+            viewport_sub_pixel_bits,
+            min_memory_map_alignment,
+            min_texel_buffer_offset_alignment,
+            min_uniform_buffer_offset_alignment,
+            min_storage_buffer_offset_alignment,
+            min_texel_offset,
+            max_texel_offset,
+            min_texel_gather_offset,
+            max_texel_gather_offset,
+            min_interpolation_offset,
+            max_interpolation_offset,
+            sub_pixel_interpolation_offset_bits,
+            max_framebuffer_width,
+            max_framebuffer_height,
+            max_framebuffer_layers,
+            max_color_attachments,
+            max_sample_mask_words,
+            timestamp_period,
+            max_clip_distances,
+            max_cull_distances,
+            max_combined_clip_and_cull_distances,
+            discrete_queue_priorities,
+            point_size_range,
+            line_width_range,
+            point_size_granularity,
+            line_width_granularity,
+            optimal_buffer_copy_offset_alignment,
+            optimal_buffer_copy_row_pitch_alignment,
+            non_coherent_atom_size,
+        ]
+    );
+
+    map
+}
+
+/// Gets the sparse properties of a physical device.
+fn sparse_properties(properties: &vk::PhysicalDeviceSparseProperties) -> BTreeMap<String, bool> {
+    let mut map = BTreeMap::new();
+
+    fields!(
+        map,
+        properties,
+        |v| v != vk::FALSE,
+        [
+            residency_standard_2d_block_shape,
+            residency_standard_2d_multisample_block_shape,
+            residency_standard_3d_block_shape,
+            residency_aligned_mip_size,
+            residency_non_resident_strict,
+        ]
+    );
+
+    map
+}
+
+/// Serializes a value.
+fn serialize(value: impl Serialize) -> Value {
+    serde_json::to_value(value).expect("failed to serialize value")
+}
+
+/// Gets the names of the flags set in a Vulkan bitmask (e.g., `["GRAPHICS", "COMPUTE"]`).
+fn names(flags: impl fmt::Debug) -> Vec<String> {
+    let debug = format!("{flags:?}");
+    debug
+        .split(" | ")
+        .filter(|n| *n != "(empty)")
+        .map(|n| n.into())
+        .collect()
+}
+
+/// A report of the properties of a physical device.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PropertiesReport {
+    /// The name of the physical device.
+    pub device_name: String,
+    /// The type of the physical device (e.g., `DISCRETE_GPU`).
+    pub device_type: String,
+    /// The Vulkan version supported by the physical device (e.g., `1.3.0`).
+    pub api_version: String,
+    /// The vendor-specified version of the driver.
+    pub driver_version: u32,
+    /// The vendor ID of the physical device.
+    pub vendor_id: u32,
+    /// The device ID of the physical device.
+    pub device_id: u32,
+    /// The UUID of the pipeline cache of the physical device (in hexadecimal).
+    pub pipeline_cache_uuid: String,
+}
+
+impl From<&vk::PhysicalDeviceProperties> for PropertiesReport {
+    fn from(properties: &vk::PhysicalDeviceProperties) -> Self {
+        let uuid = properties.pipeline_cache_uuid.iter();
+        Self {
+            device_name: properties.device_name.to_string(),
+            device_type: format!("{:?}", properties.device_type),
+            api_version: Version::from(properties.api_version).to_string(),
+            driver_version: properties.driver_version,
+            vendor_id: properties.vendor_id,
+            device_id: properties.device_id,
+            pipeline_cache_uuid: uuid.map(|b| format!("{b:02x}")).collect(),
+        }
+    }
+}
+
+/// A report of the properties of a queue family.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueueFamilyReport {
+    /// The capabilities of the queues in the queue family (e.g., `GRAPHICS`).
+    pub queue_flags: Vec<String>,
+    /// The number of queues in the queue family.
+    pub queue_count: u32,
+    /// The number of meaningful bits in timestamps written by the queues.
+    pub timestamp_valid_bits: u32,
+    /// The minimum granularity supported for image transfer operations.
+    pub min_image_transfer_granularity: [u32; 3],
+}
+
+impl From<&vk::QueueFamilyProperties> for QueueFamilyReport {
+    fn from(properties: &vk::QueueFamilyProperties) -> Self {
+        let granularity = properties.min_image_transfer_granularity;
+        Self {
+            queue_flags: names(properties.queue_flags),
+            queue_count: properties.queue_count,
+            timestamp_valid_bits: properties.timestamp_valid_bits,
+            min_image_transfer_granularity: [
+                granularity.width,
+                granularity.height,
+                granularity.depth,
+            ],
+        }
+    }
+}
+
+/// A report of the properties of a memory heap.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryHeapReport {
+    /// The size of the memory heap in bytes.
+    pub size: u64,
+    /// The attributes of the memory heap (e.g., `DEVICE_LOCAL`).
+    pub flags: Vec<String>,
+}
+
+impl From<&vk::MemoryHeap> for MemoryHeapReport {
+    fn from(heap: &vk::MemoryHeap) -> Self {
+        Self {
+            size: heap.size,
+            flags: names(heap.flags),
+        }
+    }
+}
+
+/// A report of the properties of a memory type.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryTypeReport {
+    /// The properties of the memory type (e.g., `HOST_VISIBLE`).
+    pub property_flags: Vec<String>,
+    /// The index of the memory heap the memory type corresponds to.
+    pub heap_index: u32,
+}
+
+impl From<&vk::MemoryType> for MemoryTypeReport {
+    fn from(type_: &vk::MemoryType) -> Self {
+        Self {
+            property_flags: names(type_.property_flags),
+            heap_index: type_.heap_index,
+        }
+    }
+}
+
+/// A report of the properties of a format.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormatReport {
+    /// The features supported by images with linear tiling.
+    pub linear_tiling_features: Vec<String>,
+    /// The features supported by images with optimal tiling.
+    pub optimal_tiling_features: Vec<String>,
+    /// The features supported by buffers.
+    pub buffer_features: Vec<String>,
+}
+
+impl From<&vk::FormatProperties> for FormatReport {
+    fn from(properties: &vk::FormatProperties) -> Self {
+        Self {
+            linear_tiling_features: names(properties.linear_tiling_features),
+            optimal_tiling_features: names(properties.optimal_tiling_features),
+            buffer_features: names(properties.buffer_features),
+        }
+    }
+}
+
+/// A report of the capabilities of a physical device for a surface.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SurfaceReport {
+    /// The indices of the queue families which support presenting to the
+    /// surface.
+    pub present_queue_families: Vec<u32>,
+    /// The minimum number of images in a swapchain for the surface.
+    pub min_image_count: u32,
+    /// The maximum number of images in a swapchain for the surface (`0` if
+    /// there is no limit).
+    pub max_image_count: u32,
+    /// The current width and height of the surface.
+    pub current_extent: [u32; 2],
+    /// The minimum width and height of a swapchain for the surface.
+    pub min_image_extent: [u32; 2],
+    /// The maximum width and height of a swapchain for the surface.
+    pub max_image_extent: [u32; 2],
+    /// The maximum number of layers of the images in a swapchain for the
+    /// surface.
+    pub max_image_array_layers: u32,
+    /// The supported transforms (e.g., `IDENTITY`).
+    pub supported_transforms: Vec<String>,
+    /// The current transform (e.g., `IDENTITY`).
+    pub current_transform: Vec<String>,
+    /// The supported alpha compositing modes (e.g., `OPAQUE`).
+    pub supported_composite_alpha: Vec<String>,
+    /// The supported usages of the images in a swapchain for the surface.
+    pub supported_usage_flags: Vec<String>,
+    /// The supported formats and color spaces (e.g., `B8G8R8A8_SRGB
+    /// SRGB_NONLINEAR`).
+    pub formats: Vec<String>,
+    /// The supported presentation modes (e.g., `FIFO`).
+    pub present_modes: Vec<String>,
+}
+
+impl SurfaceReport {
+    /// Gathers a report of the capabilities of a physical device for a
+    /// surface.
+    ///
+    /// # Safety
+    ///
+    /// See [`Report::new`].
+    pub unsafe fn new(
+        instance: &Instance,
+        physical_device: vk::PhysicalDevice,
+        surface: vk::SurfaceKHR,
+    ) -> VkResult<Self> {
+        let families = instance.get_physical_device_queue_family_properties(physical_device);
+        let mut present_queue_families = Vec::new();
+        for index in 0..families.len() as u32 {
+            if instance.get_physical_device_surface_support_khr(physical_device, index, surface)? {
+                present_queue_families.push(index);
+            }
+        }
+
+        let capabilities =
+            instance.get_physical_device_surface_capabilities_khr(physical_device, surface)?;
+        let extent = |e: vk::Extent2D| [e.width, e.height];
+
+        let formats = instance
+            .get_physical_device_surface_formats_khr(physical_device, surface)?
+            .iter()
+            .map(|f| format!("{:?} {:?}", f.format, f.color_space))
+            .collect();
+
+        let present_modes = instance
+            .get_physical_device_surface_present_modes_khr(physical_device, surface)?
+            .iter()
+            .map(|m| format!("{m:?}"))
+            .collect();
+
+        Ok(Self {
+            present_queue_families,
+            min_image_count: capabilities.min_image_count,
+            max_image_count: capabilities.max_image_count,
+            current_extent: extent(capabilities.current_extent),
+            min_image_extent: extent(capabilities.min_image_extent),
+            max_image_extent: extent(capabilities.max_image_extent),
+            max_image_array_layers: capabilities.max_image_array_layers,
+            supported_transforms: names(capabilities.supported_transforms),
+            current_transform: names(capabilities.current_transform),
+            supported_composite_alpha: names(capabilities.supported_composite_alpha),
+            supported_usage_flags: names(capabilities.supported_usage_flags),
+            formats,
+            present_modes,
+        })
+    }
+}
@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Vulkan extension dependency resolution.
+//!
+//! [`resolve`] finds all of the instance and device extensions that need to be
+//! enabled to use a set of extensions with a Vulkan version using the
+//! extension metadata in [`vk::EXTENSIONS`].
+//!
+//! ```
+//! # use vulkanalia::prelude::v1_0::*;
+//! # use vulkanalia::Version;
+//! # use vulkanalia::resolve::resolve;
+//! let wanted = [vk::KHR_SWAPCHAIN_EXTENSION.name, vk::KHR_MAINTENANCE1_EXTENSION.name];
+//! let resolved = resolve(&wanted, Version::V1_1_0).unwrap();
+//!
+//! // `VK_KHR_maintenance1` was promoted to Vulkan 1.1.
+//! assert_eq!(resolved.device_extensions, &[vk::KHR_SWAPCHAIN_EXTENSION.name]);
+//! assert_eq!(resolved.promoted, &[vk::KHR_MAINTENANCE1_EXTENSION.name]);
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(all(feature = "no_std_error", not(feature = "std")))]
+use core::error;
+#[cfg(feature = "std")]
+use std::error;
+
+use crate::Version;
+use crate::vk;
+
+/// The extensions resolved for a set of extensions and a Vulkan version.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Resolved {
+    /// The instance extensions that need to be enabled.
+    ///
+    /// Extensions are ordered so that every extension comes after the
+    /// extensions it requires.
+    pub instance_extensions: Vec<vk::ExtensionName>,
+    /// The device extensions that need to be enabled.
+    ///
+    /// Extensions are ordered so that every extension comes after the
+    /// extensions it requires.
+    pub device_extensions: Vec<vk::ExtensionName>,
+    /// The extensions that do not need to be enabled since they were promoted
+    /// to core in the Vulkan version.
+    pub promoted: Vec<vk::ExtensionName>,
+    /// The warnings for the extensions that need to be enabled.
+    pub warnings: Vec<ResolveWarning>,
+}
+
+/// A warning for an extension resolved by [`resolve`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ResolveWarning {
+    /// The extension has been deprecated (possibly in favor of another
+    /// extension or a Vulkan version, e.g., `VK_EXT_debug_utils`).
+    Deprecated {
+        /// The deprecated extension.
+        extension: vk::ExtensionName,
+        /// The extension or Vulkan version that deprecated the extension.
+        by: Option<&'static str>,
+    },
+    /// The extension has been obsoleted by another extension or a Vulkan
+    /// version (e.g., `VK_VERSION_1_1`).
+    Obsoleted {
+        /// The obsoleted extension.
+        extension: vk::ExtensionName,
+        /// The extension or Vulkan version that obsoleted the extension.
+        by: &'static str,
+    },
+}
+
+impl fmt::Display for ResolveWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Deprecated {
+                extension,
+                by: Some(by),
+            } => write!(f, "{extension} is deprecated by {by}"),
+            Self::Deprecated {
+                extension,
+                by: None,
+            } => write!(f, "{extension} is deprecated"),
+            Self::Obsoleted { extension, by } => write!(f, "{extension} is obsoleted by {by}"),
+        }
+    }
+}
+
+/// An error encountered while resolving extensions with [`resolve`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ResolveError {
+    /// An extension is not a known Vulkan extension.
+    Unknown(vk::ExtensionName),
+    /// An extension requires a higher Vulkan version.
+    Version {
+        /// The extension.
+        extension: vk::ExtensionName,
+        /// The Vulkan version required by the extension.
+        required: Version,
+    },
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unknown(extension) => write!(f, "unknown extension ({extension})"),
+            Self::Version {
+                extension,
+                required,
+            } => write!(f, "{extension} requires Vulkan {required}"),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "no_std_error"))]
+impl error::Error for ResolveError {}
+
+/// Resolves the instance and device extensions that need to be enabled to use
+/// a set of extensions with a Vulkan version using the extension metadata in
+/// [`vk::EXTENSIONS`].
+///
+/// The extensions required by the supplied extensions are included
+/// (transitively). Extensions promoted to core in the supplied Vulkan version
+/// (and the extensions required only by such extensions) are excluded.
+///
+/// Only the first of a set of alternative dependencies of an extension is
+/// resolved (see [`vk::Extension::required_extensions`]). The alternatives are
+/// usually an extension followed by the Vulkan version the extension was
+/// promoted to, in which case the extension is excluded as promoted if the
+/// supplied Vulkan version is high enough.
+#[inline]
+#[allow(clippy::result_large_err)]
+pub fn resolve(wanted: &[vk::ExtensionName], version: Version) -> Result<Resolved, ResolveError> {
+    resolve_with(vk::EXTENSIONS, wanted, version)
+}
+
+/// Resolves the instance and device extensions that need to be enabled to use
+/// a set of extensions with a Vulkan version using the supplied extension
+/// metadata.
+///
+/// See [`resolve`] for more details.
+#[allow(clippy::result_large_err)]
+pub fn resolve_with(
+    extensions: &[vk::Extension],
+    wanted: &[vk::ExtensionName],
+    version: Version,
+) -> Result<Resolved, ResolveError> {
+    let mut resolver = Resolver {
+        extensions,
+        version,
+        visited: Vec::new(),
+        resolved: Resolved::default(),
+    };
+
+    for name in wanted {
+        resolver.visit(name)?;
+    }
+
+    Ok(resolver.resolved)
+}
+
+struct Resolver<'a> {
+    extensions: &'a [vk::Extension],
+    version: Version,
+    visited: Vec<vk::ExtensionName>,
+    resolved: Resolved,
+}
+
+impl Resolver<'_> {
+    #[allow(clippy::result_large_err)]
+    fn visit(&mut self, name: &vk::ExtensionName) -> Result<(), ResolveError> {
+        if self.visited.contains(name) {
+            return Ok(());
+        }
+
+        self.visited.push(*name);
+
+        let extension = self
+            .extensions
+            .iter()
+            .find(|e| e.name == *name)
+            .ok_or(ResolveError::Unknown(*name))?;
+
+        let promoted = extension.promoted_to.and_then(parse_version);
+        if promoted.is_some_and(|v| v <= self.version) {
+            self.resolved.promoted.push(*name);
+            return Ok(());
+        }
+
+        let required = extension.required_version.and_then(parse_version);
+        if let Some(required) = required.filter(|v| *v > self.version) {
+            return Err(ResolveError::Version {
+                extension: *name,
+                required,
+            });
+        }
+
+        for required in extension.required_extensions.unwrap_or_default() {
+            self.visit(required)?;
+        }
+
+        if let Some(by) = extension.obsoleted_by {
+            let extension = *name;
+            self.resolved
+                .warnings
+                .push(ResolveWarning::Obsoleted { extension, by });
+        } else if let Some(by) = extension.deprecated_by {
+            let extension = *name;
+            let by = Some(by).filter(|b| !b.is_empty());
+            self.resolved
+                .warnings
+                .push(ResolveWarning::Deprecated { extension, by });
+        }
+
+        if extension.type_ == "instance" {
+            self.resolved.instance_extensions.push(*name);
+        } else {
+            self.resolved.device_extensions.push(*name);
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a Vulkan version from the extension metadata (e.g., `1.1` or
+/// `VK_VERSION_1_1`).
+fn parse_version(version: &str) -> Option<Version> {
+    let version = version.strip_prefix("VK_VERSION_").unwrap_or(version);
+    let (major, minor) = version.split_once(['.', '_'])?;
+    Some(Version::new(major.parse().ok()?, minor.parse().ok()?, 0))
+}
@@ -0,0 +1,418 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Frame synchronization.
+//!
+//! A [`FrameSync`] owns the synchronization objects needed to render multiple
+//! frames at once (the frames in flight): a semaphore which is signaled when
+//! the swapchain image for a frame has been acquired, a semaphore which is
+//! signaled when rendering a frame has finished, and either a fence for each
+//! frame or a single timeline semaphore (on Vulkan 1.2+ devices) which is
+//! signaled when the work submitted for a frame has completed.
+//!
+//! ```no_run
+//! # use vulkanalia::prelude::v1_0::*;
+//! # use vulkanalia::sync::FrameSync;
+//! # use vulkanalia::vk::KhrSwapchainExtensionDeviceCommands;
+//! # fn example(
+//! #     device: &Device,
+//! #     swapchain: vk::SwapchainKHR,
+//! #     queue: vk::Queue,
+//! #     command_buffer: vk::CommandBuffer,
+//! # ) -> VkResult<()> {
+//! let mut sync = unsafe { FrameSync::new(device, 2)? };
+//!
+//! // Wait for the previous use of the current frame to complete.
+//! let frame = unsafe { sync.begin(u64::MAX)? }.unwrap();
+//!
+//! let (index, _) = unsafe {
+//!     device.acquire_next_image_khr(
+//!         swapchain,
+//!         u64::MAX,
+//!         frame.image_available,
+//!         vk::Fence::null(),
+//!     )?
+//! };
+//!
+//! // Wait for the previous use of the acquired image to complete.
+//! unsafe { sync.image_acquired(index, u64::MAX)? };
+//!
+//! // Record `command_buffer` to render to the acquired image...
+//!
+//! let stage = vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT;
+//! unsafe { sync.submit(queue, &[command_buffer], stage)? };
+//!
+//! // Present the acquired image once `frame.render_finished` is signaled...
+//!
+//! sync.advance();
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::Version;
+use crate::prelude::v1_2::*;
+
+/// The synchronization objects for a frame in flight.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    /// The index of the frame (less than the number of frames in flight).
+    pub index: usize,
+    /// The semaphore to signal when acquiring a swapchain image (and to wait
+    /// on before rendering to it).
+    pub image_available: vk::Semaphore,
+    /// The semaphore to signal when rendering has finished (and to wait on
+    /// before presenting).
+    pub render_finished: vk::Semaphore,
+    /// The fence to signal when the work submitted for the frame has
+    /// completed (null when using a timeline semaphore).
+    pub fence: vk::Fence,
+    /// The timeline semaphore to signal when the work submitted for the frame
+    /// has completed (null when using fences).
+    pub timeline: vk::Semaphore,
+    /// The value to signal `timeline` with (`0` when using fences).
+    pub timeline_value: u64,
+}
+
+/// How the completion of the work submitted for frames is tracked.
+enum Completion {
+    /// A fence for each frame.
+    Fences(Vec<vk::Fence>),
+    /// A timeline semaphore with the value signaled by each frame.
+    Timeline {
+        semaphore: vk::Semaphore,
+        values: Vec<u64>,
+        next: u64,
+    },
+}
+
+/// The synchronization objects for multiple frames in flight which are
+/// destroyed when dropped.
+///
+/// The [`Device`] used to create the synchronization objects is cloned (which
+/// only increments a reference count) and stored alongside the objects, so
+/// this must be dropped before the device is destroyed (and after the work
+/// submitted for every frame has completed).
+///
+/// Each frame is rendered by calling [`FrameSync::begin`] (which waits for the
+/// previous use of the current frame to complete), acquiring a swapchain image
+/// with [`Frame::image_available`], calling [`FrameSync::image_acquired`]
+/// (which waits for the previous use of the acquired image to complete),
+/// submitting work which signals [`Frame::render_finished`] and either
+/// [`Frame::fence`] or [`Frame::timeline`] (e.g., with [`FrameSync::submit`]),
+/// presenting the image, and calling [`FrameSync::advance`].
+pub struct FrameSync {
+    device: Device,
+    frame: usize,
+    image_available: Vec<vk::Semaphore>,
+    render_finished: Vec<vk::Semaphore>,
+    completion: Completion,
+    images: Vec<Option<usize>>,
+}
+
+impl FrameSync {
+    /// Creates the synchronization objects for a number of frames in flight
+    /// which use a fence for each frame.
+    ///
+    /// # Panics
+    ///
+    /// * `frames_in_flight` is `0`
+    ///
+    /// # Safety
+    ///
+    /// `device` must not have been destroyed.
+    pub unsafe fn new(device: &Device, frames_in_flight: usize) -> VkResult<Self> {
+        let mut sync = Self::empty(device, frames_in_flight, Completion::Fences(Vec::new()));
+
+        let info = vk::FenceCreateInfo::builder().flags(vk::FenceCreateFlags::SIGNALED);
+        for _ in 0..frames_in_flight {
+            let fence = device.create_fence(&info, None)?;
+            if let Completion::Fences(fences) = &mut sync.completion {
+                fences.push(fence);
+            }
+        }
+
+        sync.create_semaphores(frames_in_flight)?;
+        Ok(sync)
+    }
+
+    /// Creates the synchronization objects for a number of frames in flight
+    /// which use a single timeline semaphore.
+    ///
+    /// Returns [`vk::ErrorCode::FEATURE_NOT_PRESENT`] if `device` is not a
+    /// Vulkan 1.2+ device or was not created with the `timelineSemaphore`
+    /// feature enabled.
+    ///
+    /// # Panics
+    ///
+    /// * `frames_in_flight` is `0`
+    ///
+    /// # Safety
+    ///
+    /// `device` must not have been destroyed.
+    pub unsafe fn timeline(device: &Device, frames_in_flight: usize) -> VkResult<Self> {
+        if !supports_timeline_semaphores(device) {
+            return Err(vk::ErrorCode::FEATURE_NOT_PRESENT);
+        }
+
+        let completion = Completion::Timeline {
+            semaphore: vk::Semaphore::null(),
+            values: vec![0; frames_in_flight],
+            next: 1,
+        };
+
+        let mut sync = Self::empty(device, frames_in_flight, completion);
+
+        let mut type_info = vk::SemaphoreTypeCreateInfo::builder()
+            .semaphore_type(vk::SemaphoreType::TIMELINE)
+            .initial_value(0);
+        let info = vk::SemaphoreCreateInfo::builder().push_next(&mut type_info);
+        let timeline = device.create_semaphore(&info, None)?;
+        if let Completion::Timeline { semaphore, .. } = &mut sync.completion {
+            *semaphore = timeline;
+        }
+
+        sync.create_semaphores(frames_in_flight)?;
+        Ok(sync)
+    }
+
+    fn empty(device: &Device, frames_in_flight: usize, completion: Completion) -> Self {
+        assert!(frames_in_flight > 0, "no frames in flight");
+        Self {
+            device: device.clone(),
+            frame: 0,
+            image_available: Vec::with_capacity(frames_in_flight),
+            render_finished: Vec::with_capacity(frames_in_flight),
+            completion,
+            images: Vec::new(),
+        }
+    }
+
+    unsafe fn create_semaphores(&mut self, frames_in_flight: usize) -> VkResult<()> {
+        let info = vk::SemaphoreCreateInfo::builder();
+        for _ in 0..frames_in_flight {
+            let semaphore = self.device.create_semaphore(&info, None)?;
+            self.image_available.push(semaphore);
+            let semaphore = self.device.create_semaphore(&info, None)?;
+            self.render_finished.push(semaphore);
+        }
+
+        Ok(())
+    }
+
+    /// Gets the number of frames in flight.
+    #[inline]
+    pub fn frames_in_flight(&self) -> usize {
+        self.image_available.len()
+    }
+
+    /// Returns whether this uses a timeline semaphore (instead of fences).
+    #[inline]
+    pub fn is_timeline(&self) -> bool {
+        matches!(self.completion, Completion::Timeline { .. })
+    }
+
+    /// Gets the synchronization objects for the current frame.
+    pub fn frame(&self) -> Frame {
+        let (fence, timeline, timeline_value) = match &self.completion {
+            Completion::Fences(fences) => (fences[self.frame], vk::Semaphore::null(), 0),
+            Completion::Timeline {
+                semaphore, next, ..
+            } => (vk::Fence::null(), *semaphore, *next),
+        };
+
+        Frame {
+            index: self.frame,
+            image_available: self.image_available[self.frame],
+            render_finished: self.render_finished[self.frame],
+            fence,
+            timeline,
+            timeline_value,
+        }
+    }
+
+    /// Waits for the work previously submitted for the current frame to
+    /// complete and returns the synchronization objects for the current frame
+    /// (or `None` if the timeout expired first).
+    ///
+    /// # Safety
+    ///
+    /// The synchronization objects for the current frame must not be in use
+    /// by other threads.
+    pub unsafe fn begin(&mut self, timeout: u64) -> VkResult<Option<Frame>> {
+        if self.wait(self.frame, timeout)? {
+            Ok(Some(self.frame()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Waits for the work previously submitted for the last frame which used
+    /// an acquired swapchain image to complete (returning `false` if the
+    /// timeout expired first) and then prepares the current frame for
+    /// submission.
+    ///
+    /// Preparing the current frame resets its fence (when using fences), so
+    /// work which signals the fence (or the timeline semaphore) for the
+    /// current frame must be submitted before the current frame is waited on
+    /// again.
+    ///
+    /// # Safety
+    ///
+    /// [`FrameSync::begin`] must have been called for the current frame and
+    /// `image_index` must be the index of a swapchain image acquired with the
+    /// `image_available` semaphore of the current frame.
+    pub unsafe fn image_acquired(&mut self, image_index: u32, timeout: u64) -> VkResult<bool> {
+        let image_index = image_index as usize;
+        if image_index >= self.images.len() {
+            self.images.resize(image_index + 1, None);
+        }
+
+        if let Some(previous) = self.images[image_index]
+            && previous != self.frame
+            && !self.wait(previous, timeout)?
+        {
+            return Ok(false);
+        }
+
+        self.images[image_index] = Some(self.frame);
+
+        match &mut self.completion {
+            Completion::Fences(fences) => self.device.reset_fences(&[fences[self.frame]])?,
+            Completion::Timeline { values, next, .. } => {
+                values[self.frame] = *next;
+                *next += 1;
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Submits command buffers for the current frame which wait on the
+    /// `image_available` semaphore (at the supplied pipeline stage) and signal
+    /// the `render_finished` semaphore and either the fence or the timeline
+    /// semaphore of the current frame.
+    ///
+    /// # Safety
+    ///
+    /// [`FrameSync::image_acquired`] must have been called for the current
+    /// frame and the command buffers must be valid for submission to `queue`.
+    pub unsafe fn submit(
+        &self,
+        queue: vk::Queue,
+        command_buffers: &[vk::CommandBuffer],
+        wait_stage: vk::PipelineStageFlags,
+    ) -> VkResult<()> {
+        let wait_semaphores = &[self.image_available[self.frame]];
+        let wait_stages = &[wait_stage];
+        let info = vk::SubmitInfo::builder()
+            .wait_semaphores(wait_semaphores)
+            .wait_dst_stage_mask(wait_stages)
+            .command_buffers(command_buffers);
+
+        match &self.completion {
+            Completion::Fences(fences) => {
+                let signal_semaphores = &[self.render_finished[self.frame]];
+                let info = info.signal_semaphores(signal_semaphores);
+                self.device.queue_submit(queue, &[info], fences[self.frame])
+            }
+            Completion::Timeline {
+                semaphore, values, ..
+            } => {
+                // The value for the binary semaphore is ignored.
+                let signal_semaphores = &[self.render_finished[self.frame], *semaphore];
+                let signal_values = &[0, values[self.frame]];
+                let mut timeline_info = vk::TimelineSemaphoreSubmitInfo::builder()
+                    .signal_semaphore_values(signal_values);
+                let info = info
+                    .signal_semaphores(signal_semaphores)
+                    .push_next(&mut timeline_info);
+                self.device.queue_submit(queue, &[info], vk::Fence::null())
+            }
+        }
+    }
+
+    /// Advances to the next frame.
+    #[inline]
+    pub fn advance(&mut self) {
+        self.frame = (self.frame + 1) % self.frames_in_flight();
+    }
+
+    /// Forgets which frames last used the swapchain images (e.g., after the
+    /// swapchain has been recreated).
+    #[inline]
+    pub fn forget_images(&mut self) {
+        self.images.clear();
+    }
+
+    /// Waits for the work previously submitted for a frame to complete.
+    unsafe fn wait(&self, frame: usize, timeout: u64) -> VkResult<bool> {
+        let result = match &self.completion {
+            Completion::Fences(fences) => {
+                self.device
+                    .wait_for_fences(&[fences[frame]], true, timeout)?
+            }
+            Completion::Timeline {
+                semaphore, values, ..
+            } => {
+                let semaphores = &[*semaphore];
+                let values = &[values[frame]];
+                let info = vk::SemaphoreWaitInfo::builder()
+                    .semaphores(semaphores)
+                    .values(values);
+                self.device.wait_semaphores(&info, timeout)?
+            }
+        };
+
+        Ok(result == vk::SuccessCode::SUCCESS)
+    }
+}
+
+impl fmt::Debug for FrameSync {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FrameSync")
+            .field("frame", &self.frame)
+            .field("frames_in_flight", &self.frames_in_flight())
+            .field("timeline", &self.is_timeline())
+            .finish()
+    }
+}
+
+impl Drop for FrameSync {
+    fn drop(&mut self) {
+        unsafe {
+            let semaphores = self.image_available.iter().chain(&self.render_finished);
+            for semaphore in semaphores {
+                self.device.destroy_semaphore(*semaphore, None);
+            }
+
+            match &self.completion {
+                Completion::Fences(fences) => {
+                    for fence in fences {
+                        self.device.destroy_fence(*fence, None);
+                    }
+                }
+                Completion::Timeline { semaphore, .. } => {
+                    if !semaphore.is_null() {
+                        self.device.destroy_semaphore(*semaphore, None);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns whether a device is a Vulkan 1.2+ device which was created with the
+/// `timelineSemaphore` feature enabled.
+pub(crate) fn supports_timeline_semaphores(device: &Device) -> bool {
+    let features = device.enabled_features();
+    let vulkan12 = features
+        .get::<vk::PhysicalDeviceVulkan12Features>()
+        .is_some_and(|f| f.timeline_semaphore == vk::TRUE);
+    let timeline = features
+        .get::<vk::PhysicalDeviceTimelineSemaphoreFeatures>()
+        .is_some_and(|f| f.timeline_semaphore == vk::TRUE);
+    device.version() >= Version::V1_2_0 && (vulkan12 || timeline)
+}
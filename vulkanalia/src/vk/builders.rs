@@ -9,6 +9,7 @@
     non_camel_case_types,
     non_snake_case,
     clippy::bad_bit_mask,
+    clippy::let_and_return,
     clippy::let_unit_value,
     clippy::missing_safety_doc,
     clippy::missing_transmute_annotations,
@@ -9,6 +9,7 @@
     non_camel_case_types,
     non_snake_case,
     clippy::bad_bit_mask,
+    clippy::let_and_return,
     clippy::let_unit_value,
     clippy::missing_safety_doc,
     clippy::missing_transmute_annotations,
@@ -20,7 +21,7 @@
     clippy::useless_transmute
 )]
 
-use core::ffi::{c_char, c_int, c_void};
+use core::ffi::{c_char, c_int, c_void, CStr};
 use core::mem;
 
 use super::*;
@@ -739,11 +740,26 @@ pub struct DeviceCommands {
 impl DeviceCommands {
     #[inline]
     pub unsafe fn load(
+        loader: impl FnMut(*const c_char) -> Option<unsafe extern "system" fn()>,
+    ) -> Self {
+        Self::load_enabled(loader, u32::MAX, |_| true)
+    }
+
+    /// Loads only the commands provided by Vulkan versions up to and including
+    /// `version` and by the extensions for which `enabled` returns `true`.
+    #[inline]
+    pub unsafe fn load_enabled(
         mut loader: impl FnMut(*const c_char) -> Option<unsafe extern "system" fn()>,
+        version: u32,
+        mut enabled: impl FnMut(&CStr) -> bool,
     ) -> Self {
         Self {
             acquire_full_screen_exclusive_mode_ext: {
-                let value = loader(c"vkAcquireFullScreenExclusiveModeEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_full_screen_exclusive") {
+                    loader(c"vkAcquireFullScreenExclusiveModeEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -751,13 +767,21 @@ impl DeviceCommands {
                         _device: Device,
                         _swapchain: SwapchainKHR,
                     ) -> Result {
-                        panic!("could not load vkAcquireFullScreenExclusiveModeEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkAcquireFullScreenExclusiveModeEXT")
+                        }
                     }
                     fallback
                 }
             },
             acquire_next_image2_khr: {
-                let value = loader(c"vkAcquireNextImage2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_device_group") || enabled(c"VK_KHR_swapchain") {
+                    loader(c"vkAcquireNextImage2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -766,13 +790,21 @@ impl DeviceCommands {
                         _acquire_info: *const AcquireNextImageInfoKHR,
                         _image_index: *mut u32,
                     ) -> Result {
-                        panic!("could not load vkAcquireNextImage2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkAcquireNextImage2KHR")
+                        }
                     }
                     fallback
                 }
             },
             acquire_next_image_khr: {
-                let value = loader(c"vkAcquireNextImageKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_swapchain") {
+                    loader(c"vkAcquireNextImageKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -784,13 +816,21 @@ impl DeviceCommands {
                         _fence: Fence,
                         _image_index: *mut u32,
                     ) -> Result {
-                        panic!("could not load vkAcquireNextImageKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkAcquireNextImageKHR")
+                        }
                     }
                     fallback
                 }
             },
             acquire_performance_configuration_intel: {
-                let value = loader(c"vkAcquirePerformanceConfigurationINTEL".as_ptr());
+                let value = if enabled(c"VK_INTEL_performance_query") {
+                    loader(c"vkAcquirePerformanceConfigurationINTEL".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -799,13 +839,21 @@ impl DeviceCommands {
                         _acquire_info: *const PerformanceConfigurationAcquireInfoINTEL,
                         _configuration: *mut PerformanceConfigurationINTEL,
                     ) -> Result {
-                        panic!("could not load vkAcquirePerformanceConfigurationINTEL")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkAcquirePerformanceConfigurationINTEL")
+                        }
                     }
                     fallback
                 }
             },
             acquire_profiling_lock_khr: {
-                let value = loader(c"vkAcquireProfilingLockKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_performance_query") {
+                    loader(c"vkAcquireProfilingLockKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -813,7 +861,11 @@ impl DeviceCommands {
                         _device: Device,
                         _info: *const AcquireProfilingLockInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkAcquireProfilingLockKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkAcquireProfilingLockKHR")
+                        }
                     }
                     fallback
                 }
@@ -828,7 +880,11 @@ impl DeviceCommands {
                         _allocate_info: *const CommandBufferAllocateInfo,
                         _command_buffers: *mut CommandBuffer,
                     ) -> Result {
-                        panic!("could not load vkAllocateCommandBuffers")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkAllocateCommandBuffers")
+                        }
                     }
                     fallback
                 }
@@ -843,7 +899,11 @@ impl DeviceCommands {
                         _allocate_info: *const DescriptorSetAllocateInfo,
                         _descriptor_sets: *mut DescriptorSet,
                     ) -> Result {
-                        panic!("could not load vkAllocateDescriptorSets")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkAllocateDescriptorSets")
+                        }
                     }
                     fallback
                 }
@@ -859,13 +919,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _memory: *mut DeviceMemory,
                     ) -> Result {
-                        panic!("could not load vkAllocateMemory")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkAllocateMemory")
+                        }
                     }
                     fallback
                 }
             },
             anti_lag_update_amd: {
-                let value = loader(c"vkAntiLagUpdateAMD".as_ptr());
+                let value = if enabled(c"VK_AMD_anti_lag") {
+                    loader(c"vkAntiLagUpdateAMD".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -887,13 +955,21 @@ impl DeviceCommands {
                         _command_buffer: CommandBuffer,
                         _begin_info: *const CommandBufferBeginInfo,
                     ) -> Result {
-                        panic!("could not load vkBeginCommandBuffer")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBeginCommandBuffer")
+                        }
                     }
                     fallback
                 }
             },
             bind_acceleration_structure_memory_nv: {
-                let value = loader(c"vkBindAccelerationStructureMemoryNV".as_ptr());
+                let value = if enabled(c"VK_NV_ray_tracing") {
+                    loader(c"vkBindAccelerationStructureMemoryNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -902,7 +978,11 @@ impl DeviceCommands {
                         _bind_info_count: u32,
                         _bind_infos: *const BindAccelerationStructureMemoryInfoNV,
                     ) -> Result {
-                        panic!("could not load vkBindAccelerationStructureMemoryNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBindAccelerationStructureMemoryNV")
+                        }
                     }
                     fallback
                 }
@@ -918,13 +998,21 @@ impl DeviceCommands {
                         _memory: DeviceMemory,
                         _memory_offset: DeviceSize,
                     ) -> Result {
-                        panic!("could not load vkBindBufferMemory")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBindBufferMemory")
+                        }
                     }
                     fallback
                 }
             },
             bind_buffer_memory2: {
-                let value = loader(c"vkBindBufferMemory2".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkBindBufferMemory2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -933,13 +1021,21 @@ impl DeviceCommands {
                         _bind_info_count: u32,
                         _bind_infos: *const BindBufferMemoryInfo,
                     ) -> Result {
-                        panic!("could not load vkBindBufferMemory2")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBindBufferMemory2")
+                        }
                     }
                     fallback
                 }
             },
             bind_buffer_memory2_khr: {
-                let value = loader(c"vkBindBufferMemory2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_bind_memory2") {
+                    loader(c"vkBindBufferMemory2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -948,13 +1044,21 @@ impl DeviceCommands {
                         _bind_info_count: u32,
                         _bind_infos: *const BindBufferMemoryInfo,
                     ) -> Result {
-                        panic!("could not load vkBindBufferMemory2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBindBufferMemory2KHR")
+                        }
                     }
                     fallback
                 }
             },
             bind_data_graph_pipeline_session_memory_arm: {
-                let value = loader(c"vkBindDataGraphPipelineSessionMemoryARM".as_ptr());
+                let value = if enabled(c"VK_ARM_data_graph") {
+                    loader(c"vkBindDataGraphPipelineSessionMemoryARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -963,7 +1067,11 @@ impl DeviceCommands {
                         _bind_info_count: u32,
                         _bind_infos: *const BindDataGraphPipelineSessionMemoryInfoARM,
                     ) -> Result {
-                        panic!("could not load vkBindDataGraphPipelineSessionMemoryARM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBindDataGraphPipelineSessionMemoryARM")
+                        }
                     }
                     fallback
                 }
@@ -979,13 +1087,21 @@ impl DeviceCommands {
                         _memory: DeviceMemory,
                         _memory_offset: DeviceSize,
                     ) -> Result {
-                        panic!("could not load vkBindImageMemory")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBindImageMemory")
+                        }
                     }
                     fallback
                 }
             },
             bind_image_memory2: {
-                let value = loader(c"vkBindImageMemory2".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkBindImageMemory2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -994,13 +1110,21 @@ impl DeviceCommands {
                         _bind_info_count: u32,
                         _bind_infos: *const BindImageMemoryInfo,
                     ) -> Result {
-                        panic!("could not load vkBindImageMemory2")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBindImageMemory2")
+                        }
                     }
                     fallback
                 }
             },
             bind_image_memory2_khr: {
-                let value = loader(c"vkBindImageMemory2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_bind_memory2") {
+                    loader(c"vkBindImageMemory2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1009,13 +1133,21 @@ impl DeviceCommands {
                         _bind_info_count: u32,
                         _bind_infos: *const BindImageMemoryInfo,
                     ) -> Result {
-                        panic!("could not load vkBindImageMemory2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBindImageMemory2KHR")
+                        }
                     }
                     fallback
                 }
             },
             bind_optical_flow_session_image_nv: {
-                let value = loader(c"vkBindOpticalFlowSessionImageNV".as_ptr());
+                let value = if enabled(c"VK_NV_optical_flow") {
+                    loader(c"vkBindOpticalFlowSessionImageNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1026,13 +1158,21 @@ impl DeviceCommands {
                         _view: ImageView,
                         _layout: ImageLayout,
                     ) -> Result {
-                        panic!("could not load vkBindOpticalFlowSessionImageNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBindOpticalFlowSessionImageNV")
+                        }
                     }
                     fallback
                 }
             },
             bind_tensor_memory_arm: {
-                let value = loader(c"vkBindTensorMemoryARM".as_ptr());
+                let value = if enabled(c"VK_ARM_tensors") {
+                    loader(c"vkBindTensorMemoryARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1041,13 +1181,21 @@ impl DeviceCommands {
                         _bind_info_count: u32,
                         _bind_infos: *const BindTensorMemoryInfoARM,
                     ) -> Result {
-                        panic!("could not load vkBindTensorMemoryARM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBindTensorMemoryARM")
+                        }
                     }
                     fallback
                 }
             },
             bind_video_session_memory_khr: {
-                let value = loader(c"vkBindVideoSessionMemoryKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_queue") {
+                    loader(c"vkBindVideoSessionMemoryKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1057,13 +1205,21 @@ impl DeviceCommands {
                         _bind_session_memory_info_count: u32,
                         _bind_session_memory_infos: *const BindVideoSessionMemoryInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkBindVideoSessionMemoryKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBindVideoSessionMemoryKHR")
+                        }
                     }
                     fallback
                 }
             },
             build_acceleration_structures_khr: {
-                let value = loader(c"vkBuildAccelerationStructuresKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkBuildAccelerationStructuresKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1074,13 +1230,21 @@ impl DeviceCommands {
                         _infos: *const AccelerationStructureBuildGeometryInfoKHR,
                         _build_range_infos: *const *const AccelerationStructureBuildRangeInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkBuildAccelerationStructuresKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBuildAccelerationStructuresKHR")
+                        }
                     }
                     fallback
                 }
             },
             build_micromaps_ext: {
-                let value = loader(c"vkBuildMicromapsEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkBuildMicromapsEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1090,13 +1254,21 @@ impl DeviceCommands {
                         _info_count: u32,
                         _infos: *const MicromapBuildInfoEXT,
                     ) -> Result {
-                        panic!("could not load vkBuildMicromapsEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkBuildMicromapsEXT")
+                        }
                     }
                     fallback
                 }
             },
             cmd_begin_conditional_rendering_ext: {
-                let value = loader(c"vkCmdBeginConditionalRenderingEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_conditional_rendering") {
+                    loader(c"vkCmdBeginConditionalRenderingEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1110,7 +1282,11 @@ impl DeviceCommands {
                 }
             },
             cmd_begin_custom_resolve_ext: {
-                let value = loader(c"vkCmdBeginCustomResolveEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_custom_resolve") {
+                    loader(c"vkCmdBeginCustomResolveEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1124,7 +1300,11 @@ impl DeviceCommands {
                 }
             },
             cmd_begin_per_tile_execution_qcom: {
-                let value = loader(c"vkCmdBeginPerTileExecutionQCOM".as_ptr());
+                let value = if enabled(c"VK_QCOM_tile_shading") {
+                    loader(c"vkCmdBeginPerTileExecutionQCOM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1154,7 +1334,11 @@ impl DeviceCommands {
                 }
             },
             cmd_begin_query_indexed_ext: {
-                let value = loader(c"vkCmdBeginQueryIndexedEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_transform_feedback") {
+                    loader(c"vkCmdBeginQueryIndexedEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1186,7 +1370,11 @@ impl DeviceCommands {
                 }
             },
             cmd_begin_render_pass2: {
-                let value = loader(c"vkCmdBeginRenderPass2".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkCmdBeginRenderPass2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1201,7 +1389,11 @@ impl DeviceCommands {
                 }
             },
             cmd_begin_render_pass2_khr: {
-                let value = loader(c"vkCmdBeginRenderPass2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_create_renderpass2") {
+                    loader(c"vkCmdBeginRenderPass2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1216,7 +1408,11 @@ impl DeviceCommands {
                 }
             },
             cmd_begin_rendering: {
-                let value = loader(c"vkCmdBeginRendering".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdBeginRendering".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1230,7 +1426,11 @@ impl DeviceCommands {
                 }
             },
             cmd_begin_rendering_khr: {
-                let value = loader(c"vkCmdBeginRenderingKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_dynamic_rendering") {
+                    loader(c"vkCmdBeginRenderingKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1244,7 +1444,11 @@ impl DeviceCommands {
                 }
             },
             cmd_begin_transform_feedback_ext: {
-                let value = loader(c"vkCmdBeginTransformFeedbackEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_transform_feedback") {
+                    loader(c"vkCmdBeginTransformFeedbackEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1261,7 +1465,11 @@ impl DeviceCommands {
                 }
             },
             cmd_begin_video_coding_khr: {
-                let value = loader(c"vkCmdBeginVideoCodingKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_queue") {
+                    loader(c"vkCmdBeginVideoCodingKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1275,7 +1483,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_descriptor_buffer_embedded_samplers2_ext: {
-                let value = loader(c"vkCmdBindDescriptorBufferEmbeddedSamplers2EXT".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance6") {
+                    loader(c"vkCmdBindDescriptorBufferEmbeddedSamplers2EXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1289,7 +1501,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_descriptor_buffer_embedded_samplers_ext: {
-                let value = loader(c"vkCmdBindDescriptorBufferEmbeddedSamplersEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_buffer") {
+                    loader(c"vkCmdBindDescriptorBufferEmbeddedSamplersEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1305,7 +1521,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_descriptor_buffers_ext: {
-                let value = loader(c"vkCmdBindDescriptorBuffersEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_buffer") {
+                    loader(c"vkCmdBindDescriptorBuffersEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1340,7 +1560,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_descriptor_sets2: {
-                let value = loader(c"vkCmdBindDescriptorSets2".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCmdBindDescriptorSets2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1354,7 +1578,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_descriptor_sets2_khr: {
-                let value = loader(c"vkCmdBindDescriptorSets2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance6") {
+                    loader(c"vkCmdBindDescriptorSets2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1384,7 +1612,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_index_buffer2: {
-                let value = loader(c"vkCmdBindIndexBuffer2".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCmdBindIndexBuffer2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1401,7 +1633,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_index_buffer2_khr: {
-                let value = loader(c"vkCmdBindIndexBuffer2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance5") {
+                    loader(c"vkCmdBindIndexBuffer2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1418,7 +1654,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_invocation_mask_huawei: {
-                let value = loader(c"vkCmdBindInvocationMaskHUAWEI".as_ptr());
+                let value = if enabled(c"VK_HUAWEI_invocation_mask") {
+                    loader(c"vkCmdBindInvocationMaskHUAWEI".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1448,7 +1688,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_pipeline_shader_group_nv: {
-                let value = loader(c"vkCmdBindPipelineShaderGroupNV".as_ptr());
+                let value = if enabled(c"VK_NV_device_generated_commands") {
+                    loader(c"vkCmdBindPipelineShaderGroupNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1464,7 +1708,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_resource_heap_ext: {
-                let value = loader(c"vkCmdBindResourceHeapEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_heap") {
+                    loader(c"vkCmdBindResourceHeapEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1478,7 +1726,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_sampler_heap_ext: {
-                let value = loader(c"vkCmdBindSamplerHeapEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_heap") {
+                    loader(c"vkCmdBindSamplerHeapEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1492,7 +1744,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_shaders_ext: {
-                let value = loader(c"vkCmdBindShadersEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_shader_object") {
+                    loader(c"vkCmdBindShadersEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1508,7 +1764,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_shading_rate_image_nv: {
-                let value = loader(c"vkCmdBindShadingRateImageNV".as_ptr());
+                let value = if enabled(c"VK_NV_shading_rate_image") {
+                    loader(c"vkCmdBindShadingRateImageNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1523,7 +1783,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_tile_memory_qcom: {
-                let value = loader(c"vkCmdBindTileMemoryQCOM".as_ptr());
+                let value = if enabled(c"VK_QCOM_tile_memory_heap") {
+                    loader(c"vkCmdBindTileMemoryQCOM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1537,7 +1801,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_transform_feedback_buffers_ext: {
-                let value = loader(c"vkCmdBindTransformFeedbackBuffersEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_transform_feedback") {
+                    loader(c"vkCmdBindTransformFeedbackBuffersEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1572,7 +1840,11 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_vertex_buffers2: {
-                let value = loader(c"vkCmdBindVertexBuffers2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdBindVertexBuffers2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1591,7 +1863,13 @@ impl DeviceCommands {
                 }
             },
             cmd_bind_vertex_buffers2_ext: {
-                let value = loader(c"vkCmdBindVertexBuffers2EXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdBindVertexBuffers2EXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1630,7 +1908,11 @@ impl DeviceCommands {
                 }
             },
             cmd_blit_image2: {
-                let value = loader(c"vkCmdBlitImage2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdBlitImage2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1644,7 +1926,11 @@ impl DeviceCommands {
                 }
             },
             cmd_blit_image2_khr: {
-                let value = loader(c"vkCmdBlitImage2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_copy_commands2") {
+                    loader(c"vkCmdBlitImage2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1658,7 +1944,11 @@ impl DeviceCommands {
                 }
             },
             cmd_build_acceleration_structure_nv: {
-                let value = loader(c"vkCmdBuildAccelerationStructureNV".as_ptr());
+                let value = if enabled(c"VK_NV_ray_tracing") {
+                    loader(c"vkCmdBuildAccelerationStructureNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1679,7 +1969,11 @@ impl DeviceCommands {
                 }
             },
             cmd_build_acceleration_structures_indirect_khr: {
-                let value = loader(c"vkCmdBuildAccelerationStructuresIndirectKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkCmdBuildAccelerationStructuresIndirectKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1697,7 +1991,11 @@ impl DeviceCommands {
                 }
             },
             cmd_build_acceleration_structures_khr: {
-                let value = loader(c"vkCmdBuildAccelerationStructuresKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkCmdBuildAccelerationStructuresKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1713,7 +2011,11 @@ impl DeviceCommands {
                 }
             },
             cmd_build_cluster_acceleration_structure_indirect_nv: {
-                let value = loader(c"vkCmdBuildClusterAccelerationStructureIndirectNV".as_ptr());
+                let value = if enabled(c"VK_NV_cluster_acceleration_structure") {
+                    loader(c"vkCmdBuildClusterAccelerationStructureIndirectNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1727,7 +2029,11 @@ impl DeviceCommands {
                 }
             },
             cmd_build_micromaps_ext: {
-                let value = loader(c"vkCmdBuildMicromapsEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkCmdBuildMicromapsEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1742,7 +2048,11 @@ impl DeviceCommands {
                 }
             },
             cmd_build_partitioned_acceleration_structures_nv: {
-                let value = loader(c"vkCmdBuildPartitionedAccelerationStructuresNV".as_ptr());
+                let value = if enabled(c"VK_NV_partitioned_acceleration_structure") {
+                    loader(c"vkCmdBuildPartitionedAccelerationStructuresNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1809,7 +2119,11 @@ impl DeviceCommands {
                 }
             },
             cmd_control_video_coding_khr: {
-                let value = loader(c"vkCmdControlVideoCodingKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_queue") {
+                    loader(c"vkCmdControlVideoCodingKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1823,7 +2137,11 @@ impl DeviceCommands {
                 }
             },
             cmd_convert_cooperative_vector_matrix_nv: {
-                let value = loader(c"vkCmdConvertCooperativeVectorMatrixNV".as_ptr());
+                let value = if enabled(c"VK_NV_cooperative_vector") {
+                    loader(c"vkCmdConvertCooperativeVectorMatrixNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1838,7 +2156,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_acceleration_structure_khr: {
-                let value = loader(c"vkCmdCopyAccelerationStructureKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkCmdCopyAccelerationStructureKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1852,7 +2174,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_acceleration_structure_nv: {
-                let value = loader(c"vkCmdCopyAccelerationStructureNV".as_ptr());
+                let value = if enabled(c"VK_NV_ray_tracing") {
+                    loader(c"vkCmdCopyAccelerationStructureNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1868,7 +2194,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_acceleration_structure_to_memory_khr: {
-                let value = loader(c"vkCmdCopyAccelerationStructureToMemoryKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkCmdCopyAccelerationStructureToMemoryKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1899,7 +2229,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_buffer2: {
-                let value = loader(c"vkCmdCopyBuffer2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdCopyBuffer2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1913,7 +2247,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_buffer2_khr: {
-                let value = loader(c"vkCmdCopyBuffer2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_copy_commands2") {
+                    loader(c"vkCmdCopyBuffer2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1945,7 +2283,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_buffer_to_image2: {
-                let value = loader(c"vkCmdCopyBufferToImage2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdCopyBufferToImage2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1959,7 +2301,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_buffer_to_image2_khr: {
-                let value = loader(c"vkCmdCopyBufferToImage2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_copy_commands2") {
+                    loader(c"vkCmdCopyBufferToImage2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -1992,7 +2338,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_image2: {
-                let value = loader(c"vkCmdCopyImage2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdCopyImage2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2006,7 +2356,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_image2_khr: {
-                let value = loader(c"vkCmdCopyImage2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_copy_commands2") {
+                    loader(c"vkCmdCopyImage2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2038,7 +2392,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_image_to_buffer2: {
-                let value = loader(c"vkCmdCopyImageToBuffer2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdCopyImageToBuffer2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2052,7 +2410,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_image_to_buffer2_khr: {
-                let value = loader(c"vkCmdCopyImageToBuffer2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_copy_commands2") {
+                    loader(c"vkCmdCopyImageToBuffer2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2066,7 +2428,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_memory_indirect_khr: {
-                let value = loader(c"vkCmdCopyMemoryIndirectKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_copy_memory_indirect") {
+                    loader(c"vkCmdCopyMemoryIndirectKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2080,7 +2446,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_memory_indirect_nv: {
-                let value = loader(c"vkCmdCopyMemoryIndirectNV".as_ptr());
+                let value = if enabled(c"VK_NV_copy_memory_indirect") {
+                    loader(c"vkCmdCopyMemoryIndirectNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2096,7 +2466,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_memory_to_acceleration_structure_khr: {
-                let value = loader(c"vkCmdCopyMemoryToAccelerationStructureKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkCmdCopyMemoryToAccelerationStructureKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2110,7 +2484,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_memory_to_image_indirect_khr: {
-                let value = loader(c"vkCmdCopyMemoryToImageIndirectKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_copy_memory_indirect") {
+                    loader(c"vkCmdCopyMemoryToImageIndirectKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2124,7 +2502,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_memory_to_image_indirect_nv: {
-                let value = loader(c"vkCmdCopyMemoryToImageIndirectNV".as_ptr());
+                let value = if enabled(c"VK_NV_copy_memory_indirect") {
+                    loader(c"vkCmdCopyMemoryToImageIndirectNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2143,7 +2525,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_memory_to_micromap_ext: {
-                let value = loader(c"vkCmdCopyMemoryToMicromapEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkCmdCopyMemoryToMicromapEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2157,7 +2543,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_micromap_ext: {
-                let value = loader(c"vkCmdCopyMicromapEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkCmdCopyMicromapEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2171,7 +2561,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_micromap_to_memory_ext: {
-                let value = loader(c"vkCmdCopyMicromapToMemoryEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkCmdCopyMicromapToMemoryEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2205,7 +2599,11 @@ impl DeviceCommands {
                 }
             },
             cmd_copy_tensor_arm: {
-                let value = loader(c"vkCmdCopyTensorARM".as_ptr());
+                let value = if enabled(c"VK_ARM_tensors") {
+                    loader(c"vkCmdCopyTensorARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2219,7 +2617,11 @@ impl DeviceCommands {
                 }
             },
             cmd_cu_launch_kernel_nvx: {
-                let value = loader(c"vkCmdCuLaunchKernelNVX".as_ptr());
+                let value = if enabled(c"VK_NVX_binary_import") {
+                    loader(c"vkCmdCuLaunchKernelNVX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2233,7 +2635,11 @@ impl DeviceCommands {
                 }
             },
             cmd_cuda_launch_kernel_nv: {
-                let value = loader(c"vkCmdCudaLaunchKernelNV".as_ptr());
+                let value = if enabled(c"VK_NV_cuda_kernel_launch") {
+                    loader(c"vkCmdCudaLaunchKernelNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2247,7 +2653,11 @@ impl DeviceCommands {
                 }
             },
             cmd_debug_marker_begin_ext: {
-                let value = loader(c"vkCmdDebugMarkerBeginEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_debug_marker") {
+                    loader(c"vkCmdDebugMarkerBeginEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2261,7 +2671,11 @@ impl DeviceCommands {
                 }
             },
             cmd_debug_marker_end_ext: {
-                let value = loader(c"vkCmdDebugMarkerEndEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_debug_marker") {
+                    loader(c"vkCmdDebugMarkerEndEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2272,7 +2686,11 @@ impl DeviceCommands {
                 }
             },
             cmd_debug_marker_insert_ext: {
-                let value = loader(c"vkCmdDebugMarkerInsertEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_debug_marker") {
+                    loader(c"vkCmdDebugMarkerInsertEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2286,7 +2704,11 @@ impl DeviceCommands {
                 }
             },
             cmd_decode_video_khr: {
-                let value = loader(c"vkCmdDecodeVideoKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_decode_queue") {
+                    loader(c"vkCmdDecodeVideoKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2300,7 +2722,11 @@ impl DeviceCommands {
                 }
             },
             cmd_decompress_memory_ext: {
-                let value = loader(c"vkCmdDecompressMemoryEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_memory_decompression") {
+                    loader(c"vkCmdDecompressMemoryEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2314,7 +2740,11 @@ impl DeviceCommands {
                 }
             },
             cmd_decompress_memory_indirect_count_ext: {
-                let value = loader(c"vkCmdDecompressMemoryIndirectCountEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_memory_decompression") {
+                    loader(c"vkCmdDecompressMemoryIndirectCountEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2332,7 +2762,11 @@ impl DeviceCommands {
                 }
             },
             cmd_decompress_memory_indirect_count_nv: {
-                let value = loader(c"vkCmdDecompressMemoryIndirectCountNV".as_ptr());
+                let value = if enabled(c"VK_NV_memory_decompression") {
+                    loader(c"vkCmdDecompressMemoryIndirectCountNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2348,7 +2782,11 @@ impl DeviceCommands {
                 }
             },
             cmd_decompress_memory_nv: {
-                let value = loader(c"vkCmdDecompressMemoryNV".as_ptr());
+                let value = if enabled(c"VK_NV_memory_decompression") {
+                    loader(c"vkCmdDecompressMemoryNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2379,7 +2817,11 @@ impl DeviceCommands {
                 }
             },
             cmd_dispatch_base: {
-                let value = loader(c"vkCmdDispatchBase".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkCmdDispatchBase".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2398,7 +2840,11 @@ impl DeviceCommands {
                 }
             },
             cmd_dispatch_base_khr: {
-                let value = loader(c"vkCmdDispatchBaseKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_device_group") {
+                    loader(c"vkCmdDispatchBaseKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2417,7 +2863,11 @@ impl DeviceCommands {
                 }
             },
             cmd_dispatch_data_graph_arm: {
-                let value = loader(c"vkCmdDispatchDataGraphARM".as_ptr());
+                let value = if enabled(c"VK_ARM_data_graph") {
+                    loader(c"vkCmdDispatchDataGraphARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2432,7 +2882,11 @@ impl DeviceCommands {
                 }
             },
             cmd_dispatch_graph_amdx: {
-                let value = loader(c"vkCmdDispatchGraphAMDX".as_ptr());
+                let value = if enabled(c"VK_AMDX_shader_enqueue") {
+                    loader(c"vkCmdDispatchGraphAMDX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2448,7 +2902,11 @@ impl DeviceCommands {
                 }
             },
             cmd_dispatch_graph_indirect_amdx: {
-                let value = loader(c"vkCmdDispatchGraphIndirectAMDX".as_ptr());
+                let value = if enabled(c"VK_AMDX_shader_enqueue") {
+                    loader(c"vkCmdDispatchGraphIndirectAMDX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2464,7 +2922,11 @@ impl DeviceCommands {
                 }
             },
             cmd_dispatch_graph_indirect_count_amdx: {
-                let value = loader(c"vkCmdDispatchGraphIndirectCountAMDX".as_ptr());
+                let value = if enabled(c"VK_AMDX_shader_enqueue") {
+                    loader(c"vkCmdDispatchGraphIndirectCountAMDX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2495,7 +2957,11 @@ impl DeviceCommands {
                 }
             },
             cmd_dispatch_tile_qcom: {
-                let value = loader(c"vkCmdDispatchTileQCOM".as_ptr());
+                let value = if enabled(c"VK_QCOM_tile_shading") {
+                    loader(c"vkCmdDispatchTileQCOM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2526,7 +2992,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_cluster_huawei: {
-                let value = loader(c"vkCmdDrawClusterHUAWEI".as_ptr());
+                let value = if enabled(c"VK_HUAWEI_cluster_culling_shader") {
+                    loader(c"vkCmdDrawClusterHUAWEI".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2542,7 +3012,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_cluster_indirect_huawei: {
-                let value = loader(c"vkCmdDrawClusterIndirectHUAWEI".as_ptr());
+                let value = if enabled(c"VK_HUAWEI_cluster_culling_shader") {
+                    loader(c"vkCmdDrawClusterIndirectHUAWEI".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2592,7 +3066,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_indexed_indirect_count: {
-                let value = loader(c"vkCmdDrawIndexedIndirectCount".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkCmdDrawIndexedIndirectCount".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2611,7 +3089,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_indexed_indirect_count_amd: {
-                let value = loader(c"vkCmdDrawIndexedIndirectCountAMD".as_ptr());
+                let value = if enabled(c"VK_AMD_draw_indirect_count") {
+                    loader(c"vkCmdDrawIndexedIndirectCountAMD".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2630,7 +3112,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_indexed_indirect_count_khr: {
-                let value = loader(c"vkCmdDrawIndexedIndirectCountKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_draw_indirect_count") {
+                    loader(c"vkCmdDrawIndexedIndirectCountKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2666,7 +3152,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_indirect_byte_count_ext: {
-                let value = loader(c"vkCmdDrawIndirectByteCountEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_transform_feedback") {
+                    loader(c"vkCmdDrawIndirectByteCountEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2685,7 +3175,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_indirect_count: {
-                let value = loader(c"vkCmdDrawIndirectCount".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkCmdDrawIndirectCount".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2704,7 +3198,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_indirect_count_amd: {
-                let value = loader(c"vkCmdDrawIndirectCountAMD".as_ptr());
+                let value = if enabled(c"VK_AMD_draw_indirect_count") {
+                    loader(c"vkCmdDrawIndirectCountAMD".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2723,7 +3221,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_indirect_count_khr: {
-                let value = loader(c"vkCmdDrawIndirectCountKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_draw_indirect_count") {
+                    loader(c"vkCmdDrawIndirectCountKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2742,7 +3244,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_mesh_tasks_ext: {
-                let value = loader(c"vkCmdDrawMeshTasksEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_mesh_shader") {
+                    loader(c"vkCmdDrawMeshTasksEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2758,7 +3264,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_mesh_tasks_indirect_count_ext: {
-                let value = loader(c"vkCmdDrawMeshTasksIndirectCountEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_mesh_shader") {
+                    loader(c"vkCmdDrawMeshTasksIndirectCountEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2777,7 +3287,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_mesh_tasks_indirect_count_nv: {
-                let value = loader(c"vkCmdDrawMeshTasksIndirectCountNV".as_ptr());
+                let value = if enabled(c"VK_NV_mesh_shader") {
+                    loader(c"vkCmdDrawMeshTasksIndirectCountNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2796,7 +3310,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_mesh_tasks_indirect_ext: {
-                let value = loader(c"vkCmdDrawMeshTasksIndirectEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_mesh_shader") {
+                    loader(c"vkCmdDrawMeshTasksIndirectEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2813,7 +3331,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_mesh_tasks_indirect_nv: {
-                let value = loader(c"vkCmdDrawMeshTasksIndirectNV".as_ptr());
+                let value = if enabled(c"VK_NV_mesh_shader") {
+                    loader(c"vkCmdDrawMeshTasksIndirectNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2830,7 +3352,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_mesh_tasks_nv: {
-                let value = loader(c"vkCmdDrawMeshTasksNV".as_ptr());
+                let value = if enabled(c"VK_NV_mesh_shader") {
+                    loader(c"vkCmdDrawMeshTasksNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2845,7 +3371,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_multi_ext: {
-                let value = loader(c"vkCmdDrawMultiEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_multi_draw") {
+                    loader(c"vkCmdDrawMultiEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2863,7 +3393,11 @@ impl DeviceCommands {
                 }
             },
             cmd_draw_multi_indexed_ext: {
-                let value = loader(c"vkCmdDrawMultiIndexedEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_multi_draw") {
+                    loader(c"vkCmdDrawMultiIndexedEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2882,7 +3416,11 @@ impl DeviceCommands {
                 }
             },
             cmd_encode_video_khr: {
-                let value = loader(c"vkCmdEncodeVideoKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_encode_queue") {
+                    loader(c"vkCmdEncodeVideoKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2896,7 +3434,11 @@ impl DeviceCommands {
                 }
             },
             cmd_end_conditional_rendering_ext: {
-                let value = loader(c"vkCmdEndConditionalRenderingEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_conditional_rendering") {
+                    loader(c"vkCmdEndConditionalRenderingEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2907,7 +3449,11 @@ impl DeviceCommands {
                 }
             },
             cmd_end_per_tile_execution_qcom: {
-                let value = loader(c"vkCmdEndPerTileExecutionQCOM".as_ptr());
+                let value = if enabled(c"VK_QCOM_tile_shading") {
+                    loader(c"vkCmdEndPerTileExecutionQCOM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2936,7 +3482,11 @@ impl DeviceCommands {
                 }
             },
             cmd_end_query_indexed_ext: {
-                let value = loader(c"vkCmdEndQueryIndexedEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_transform_feedback") {
+                    loader(c"vkCmdEndQueryIndexedEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2963,7 +3513,11 @@ impl DeviceCommands {
                 }
             },
             cmd_end_render_pass2: {
-                let value = loader(c"vkCmdEndRenderPass2".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkCmdEndRenderPass2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2977,7 +3531,11 @@ impl DeviceCommands {
                 }
             },
             cmd_end_render_pass2_khr: {
-                let value = loader(c"vkCmdEndRenderPass2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_create_renderpass2") {
+                    loader(c"vkCmdEndRenderPass2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -2991,7 +3549,11 @@ impl DeviceCommands {
                 }
             },
             cmd_end_rendering: {
-                let value = loader(c"vkCmdEndRendering".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdEndRendering".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3002,7 +3564,11 @@ impl DeviceCommands {
                 }
             },
             cmd_end_rendering2_ext: {
-                let value = loader(c"vkCmdEndRendering2EXT".as_ptr());
+                let value = if enabled(c"VK_EXT_fragment_density_map_offset") {
+                    loader(c"vkCmdEndRendering2EXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3016,7 +3582,11 @@ impl DeviceCommands {
                 }
             },
             cmd_end_rendering2_khr: {
-                let value = loader(c"vkCmdEndRendering2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance10") {
+                    loader(c"vkCmdEndRendering2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3030,7 +3600,11 @@ impl DeviceCommands {
                 }
             },
             cmd_end_rendering_khr: {
-                let value = loader(c"vkCmdEndRenderingKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_dynamic_rendering") {
+                    loader(c"vkCmdEndRenderingKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3041,7 +3615,11 @@ impl DeviceCommands {
                 }
             },
             cmd_end_transform_feedback_ext: {
-                let value = loader(c"vkCmdEndTransformFeedbackEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_transform_feedback") {
+                    loader(c"vkCmdEndTransformFeedbackEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3058,7 +3636,11 @@ impl DeviceCommands {
                 }
             },
             cmd_end_video_coding_khr: {
-                let value = loader(c"vkCmdEndVideoCodingKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_queue") {
+                    loader(c"vkCmdEndVideoCodingKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3087,7 +3669,11 @@ impl DeviceCommands {
                 }
             },
             cmd_execute_generated_commands_ext: {
-                let value = loader(c"vkCmdExecuteGeneratedCommandsEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_device_generated_commands") {
+                    loader(c"vkCmdExecuteGeneratedCommandsEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3102,7 +3688,11 @@ impl DeviceCommands {
                 }
             },
             cmd_execute_generated_commands_nv: {
-                let value = loader(c"vkCmdExecuteGeneratedCommandsNV".as_ptr());
+                let value = if enabled(c"VK_NV_device_generated_commands") {
+                    loader(c"vkCmdExecuteGeneratedCommandsNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3134,7 +3724,11 @@ impl DeviceCommands {
                 }
             },
             cmd_initialize_graph_scratch_memory_amdx: {
-                let value = loader(c"vkCmdInitializeGraphScratchMemoryAMDX".as_ptr());
+                let value = if enabled(c"VK_AMDX_shader_enqueue") {
+                    loader(c"vkCmdInitializeGraphScratchMemoryAMDX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3164,7 +3758,11 @@ impl DeviceCommands {
                 }
             },
             cmd_next_subpass2: {
-                let value = loader(c"vkCmdNextSubpass2".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkCmdNextSubpass2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3179,7 +3777,11 @@ impl DeviceCommands {
                 }
             },
             cmd_next_subpass2_khr: {
-                let value = loader(c"vkCmdNextSubpass2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_create_renderpass2") {
+                    loader(c"vkCmdNextSubpass2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3194,7 +3796,11 @@ impl DeviceCommands {
                 }
             },
             cmd_optical_flow_execute_nv: {
-                let value = loader(c"vkCmdOpticalFlowExecuteNV".as_ptr());
+                let value = if enabled(c"VK_NV_optical_flow") {
+                    loader(c"vkCmdOpticalFlowExecuteNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3231,7 +3837,11 @@ impl DeviceCommands {
                 }
             },
             cmd_pipeline_barrier2: {
-                let value = loader(c"vkCmdPipelineBarrier2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdPipelineBarrier2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3245,7 +3855,11 @@ impl DeviceCommands {
                 }
             },
             cmd_pipeline_barrier2_khr: {
-                let value = loader(c"vkCmdPipelineBarrier2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_synchronization2") {
+                    loader(c"vkCmdPipelineBarrier2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3259,7 +3873,11 @@ impl DeviceCommands {
                 }
             },
             cmd_preprocess_generated_commands_ext: {
-                let value = loader(c"vkCmdPreprocessGeneratedCommandsEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_device_generated_commands") {
+                    loader(c"vkCmdPreprocessGeneratedCommandsEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3274,7 +3892,11 @@ impl DeviceCommands {
                 }
             },
             cmd_preprocess_generated_commands_nv: {
-                let value = loader(c"vkCmdPreprocessGeneratedCommandsNV".as_ptr());
+                let value = if enabled(c"VK_NV_device_generated_commands") {
+                    loader(c"vkCmdPreprocessGeneratedCommandsNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3306,7 +3928,11 @@ impl DeviceCommands {
                 }
             },
             cmd_push_constants2: {
-                let value = loader(c"vkCmdPushConstants2".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCmdPushConstants2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3320,7 +3946,11 @@ impl DeviceCommands {
                 }
             },
             cmd_push_constants2_khr: {
-                let value = loader(c"vkCmdPushConstants2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance6") {
+                    loader(c"vkCmdPushConstants2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3334,7 +3964,11 @@ impl DeviceCommands {
                 }
             },
             cmd_push_data_ext: {
-                let value = loader(c"vkCmdPushDataEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_heap") {
+                    loader(c"vkCmdPushDataEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3348,7 +3982,11 @@ impl DeviceCommands {
                 }
             },
             cmd_push_descriptor_set: {
-                let value = loader(c"vkCmdPushDescriptorSet".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCmdPushDescriptorSet".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3366,7 +4004,11 @@ impl DeviceCommands {
                 }
             },
             cmd_push_descriptor_set2: {
-                let value = loader(c"vkCmdPushDescriptorSet2".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCmdPushDescriptorSet2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3380,7 +4022,11 @@ impl DeviceCommands {
                 }
             },
             cmd_push_descriptor_set2_khr: {
-                let value = loader(c"vkCmdPushDescriptorSet2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance6") {
+                    loader(c"vkCmdPushDescriptorSet2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3394,7 +4040,11 @@ impl DeviceCommands {
                 }
             },
             cmd_push_descriptor_set_khr: {
-                let value = loader(c"vkCmdPushDescriptorSetKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_push_descriptor") {
+                    loader(c"vkCmdPushDescriptorSetKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3412,7 +4062,11 @@ impl DeviceCommands {
                 }
             },
             cmd_push_descriptor_set_with_template: {
-                let value = loader(c"vkCmdPushDescriptorSetWithTemplate".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCmdPushDescriptorSetWithTemplate".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3429,7 +4083,11 @@ impl DeviceCommands {
                 }
             },
             cmd_push_descriptor_set_with_template2: {
-                let value = loader(c"vkCmdPushDescriptorSetWithTemplate2".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCmdPushDescriptorSetWithTemplate2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3443,7 +4101,11 @@ impl DeviceCommands {
                 }
             },
             cmd_push_descriptor_set_with_template2_khr: {
-                let value = loader(c"vkCmdPushDescriptorSetWithTemplate2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance6") {
+                    loader(c"vkCmdPushDescriptorSetWithTemplate2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3457,7 +4119,13 @@ impl DeviceCommands {
                 }
             },
             cmd_push_descriptor_set_with_template_khr: {
-                let value = loader(c"vkCmdPushDescriptorSetWithTemplateKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_descriptor_update_template")
+                    || enabled(c"VK_KHR_push_descriptor")
+                {
+                    loader(c"vkCmdPushDescriptorSetWithTemplateKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3474,7 +4142,11 @@ impl DeviceCommands {
                 }
             },
             cmd_refresh_objects_khr: {
-                let value = loader(c"vkCmdRefreshObjectsKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_object_refresh") {
+                    loader(c"vkCmdRefreshObjectsKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3503,7 +4175,11 @@ impl DeviceCommands {
                 }
             },
             cmd_reset_event2: {
-                let value = loader(c"vkCmdResetEvent2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdResetEvent2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3518,7 +4194,11 @@ impl DeviceCommands {
                 }
             },
             cmd_reset_event2_khr: {
-                let value = loader(c"vkCmdResetEvent2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_synchronization2") {
+                    loader(c"vkCmdResetEvent2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3568,7 +4248,11 @@ impl DeviceCommands {
                 }
             },
             cmd_resolve_image2: {
-                let value = loader(c"vkCmdResolveImage2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdResolveImage2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3582,7 +4266,11 @@ impl DeviceCommands {
                 }
             },
             cmd_resolve_image2_khr: {
-                let value = loader(c"vkCmdResolveImage2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_copy_commands2") {
+                    loader(c"vkCmdResolveImage2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3596,7 +4284,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_alpha_to_coverage_enable_ext: {
-                let value = loader(c"vkCmdSetAlphaToCoverageEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetAlphaToCoverageEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3610,7 +4304,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_alpha_to_one_enable_ext: {
-                let value = loader(c"vkCmdSetAlphaToOneEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetAlphaToOneEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3624,7 +4324,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_attachment_feedback_loop_enable_ext: {
-                let value = loader(c"vkCmdSetAttachmentFeedbackLoopEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_attachment_feedback_loop_dynamic_state") {
+                    loader(c"vkCmdSetAttachmentFeedbackLoopEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3652,7 +4356,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_checkpoint_nv: {
-                let value = loader(c"vkCmdSetCheckpointNV".as_ptr());
+                let value = if enabled(c"VK_NV_device_diagnostic_checkpoints") {
+                    loader(c"vkCmdSetCheckpointNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3666,7 +4374,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_coarse_sample_order_nv: {
-                let value = loader(c"vkCmdSetCoarseSampleOrderNV".as_ptr());
+                let value = if enabled(c"VK_NV_shading_rate_image") {
+                    loader(c"vkCmdSetCoarseSampleOrderNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3682,7 +4394,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_color_blend_advanced_ext: {
-                let value = loader(c"vkCmdSetColorBlendAdvancedEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetColorBlendAdvancedEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3698,7 +4416,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_color_blend_enable_ext: {
-                let value = loader(c"vkCmdSetColorBlendEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetColorBlendEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3714,7 +4438,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_color_blend_equation_ext: {
-                let value = loader(c"vkCmdSetColorBlendEquationEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetColorBlendEquationEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3730,7 +4460,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_color_write_enable_ext: {
-                let value = loader(c"vkCmdSetColorWriteEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_color_write_enable") {
+                    loader(c"vkCmdSetColorWriteEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3745,7 +4479,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_color_write_mask_ext: {
-                let value = loader(c"vkCmdSetColorWriteMaskEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetColorWriteMaskEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3761,7 +4501,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_compute_occupancy_priority_nv: {
-                let value = loader(c"vkCmdSetComputeOccupancyPriorityNV".as_ptr());
+                let value = if enabled(c"VK_NV_compute_occupancy_priority") {
+                    loader(c"vkCmdSetComputeOccupancyPriorityNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3775,7 +4519,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_conservative_rasterization_mode_ext: {
-                let value = loader(c"vkCmdSetConservativeRasterizationModeEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetConservativeRasterizationModeEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3789,7 +4539,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_coverage_modulation_mode_nv: {
-                let value = loader(c"vkCmdSetCoverageModulationModeNV".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetCoverageModulationModeNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3803,7 +4559,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_coverage_modulation_table_enable_nv: {
-                let value = loader(c"vkCmdSetCoverageModulationTableEnableNV".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetCoverageModulationTableEnableNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3817,7 +4579,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_coverage_modulation_table_nv: {
-                let value = loader(c"vkCmdSetCoverageModulationTableNV".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetCoverageModulationTableNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3832,7 +4600,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_coverage_reduction_mode_nv: {
-                let value = loader(c"vkCmdSetCoverageReductionModeNV".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetCoverageReductionModeNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3846,7 +4620,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_coverage_to_color_enable_nv: {
-                let value = loader(c"vkCmdSetCoverageToColorEnableNV".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetCoverageToColorEnableNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3860,7 +4640,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_coverage_to_color_location_nv: {
-                let value = loader(c"vkCmdSetCoverageToColorLocationNV".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetCoverageToColorLocationNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3874,7 +4660,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_cull_mode: {
-                let value = loader(c"vkCmdSetCullMode".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetCullMode".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3888,7 +4678,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_cull_mode_ext: {
-                let value = loader(c"vkCmdSetCullModeEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetCullModeEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3918,7 +4714,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_bias2_ext: {
-                let value = loader(c"vkCmdSetDepthBias2EXT".as_ptr());
+                let value = if enabled(c"VK_EXT_depth_bias_control") {
+                    loader(c"vkCmdSetDepthBias2EXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3932,7 +4732,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_bias_enable: {
-                let value = loader(c"vkCmdSetDepthBiasEnable".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetDepthBiasEnable".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3946,7 +4750,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_bias_enable_ext: {
-                let value = loader(c"vkCmdSetDepthBiasEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state2")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetDepthBiasEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3975,7 +4785,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_bounds_test_enable: {
-                let value = loader(c"vkCmdSetDepthBoundsTestEnable".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetDepthBoundsTestEnable".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -3989,7 +4803,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_bounds_test_enable_ext: {
-                let value = loader(c"vkCmdSetDepthBoundsTestEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetDepthBoundsTestEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4003,7 +4823,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_clamp_enable_ext: {
-                let value = loader(c"vkCmdSetDepthClampEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetDepthClampEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4017,7 +4843,12 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_clamp_range_ext: {
-                let value = loader(c"vkCmdSetDepthClampRangeEXT".as_ptr());
+                let value =
+                    if enabled(c"VK_EXT_depth_clamp_control") || enabled(c"VK_EXT_shader_object") {
+                        loader(c"vkCmdSetDepthClampRangeEXT".as_ptr())
+                    } else {
+                        None
+                    };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4032,7 +4863,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_clip_enable_ext: {
-                let value = loader(c"vkCmdSetDepthClipEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetDepthClipEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4046,7 +4883,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_clip_negative_one_to_one_ext: {
-                let value = loader(c"vkCmdSetDepthClipNegativeOneToOneEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetDepthClipNegativeOneToOneEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4060,7 +4903,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_compare_op: {
-                let value = loader(c"vkCmdSetDepthCompareOp".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetDepthCompareOp".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4074,7 +4921,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_compare_op_ext: {
-                let value = loader(c"vkCmdSetDepthCompareOpEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetDepthCompareOpEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4088,7 +4941,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_test_enable: {
-                let value = loader(c"vkCmdSetDepthTestEnable".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetDepthTestEnable".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4102,7 +4959,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_test_enable_ext: {
-                let value = loader(c"vkCmdSetDepthTestEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetDepthTestEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4116,7 +4979,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_write_enable: {
-                let value = loader(c"vkCmdSetDepthWriteEnable".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetDepthWriteEnable".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4130,7 +4997,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_depth_write_enable_ext: {
-                let value = loader(c"vkCmdSetDepthWriteEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetDepthWriteEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4144,7 +5017,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_descriptor_buffer_offsets2_ext: {
-                let value = loader(c"vkCmdSetDescriptorBufferOffsets2EXT".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance6") {
+                    loader(c"vkCmdSetDescriptorBufferOffsets2EXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4158,7 +5035,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_descriptor_buffer_offsets_ext: {
-                let value = loader(c"vkCmdSetDescriptorBufferOffsetsEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_buffer") {
+                    loader(c"vkCmdSetDescriptorBufferOffsetsEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4177,7 +5058,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_device_mask: {
-                let value = loader(c"vkCmdSetDeviceMask".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkCmdSetDeviceMask".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4191,7 +5076,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_device_mask_khr: {
-                let value = loader(c"vkCmdSetDeviceMaskKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_device_group") {
+                    loader(c"vkCmdSetDeviceMaskKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4205,7 +5094,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_discard_rectangle_ext: {
-                let value = loader(c"vkCmdSetDiscardRectangleEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_discard_rectangles") {
+                    loader(c"vkCmdSetDiscardRectangleEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4221,7 +5114,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_discard_rectangle_enable_ext: {
-                let value = loader(c"vkCmdSetDiscardRectangleEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_discard_rectangles") {
+                    loader(c"vkCmdSetDiscardRectangleEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4235,7 +5132,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_discard_rectangle_mode_ext: {
-                let value = loader(c"vkCmdSetDiscardRectangleModeEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_discard_rectangles") {
+                    loader(c"vkCmdSetDiscardRectangleModeEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4264,7 +5165,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_event2: {
-                let value = loader(c"vkCmdSetEvent2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetEvent2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4279,7 +5184,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_event2_khr: {
-                let value = loader(c"vkCmdSetEvent2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_synchronization2") {
+                    loader(c"vkCmdSetEvent2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4294,7 +5203,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_exclusive_scissor_enable_nv: {
-                let value = loader(c"vkCmdSetExclusiveScissorEnableNV".as_ptr());
+                let value = if enabled(c"VK_NV_scissor_exclusive") {
+                    loader(c"vkCmdSetExclusiveScissorEnableNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4310,7 +5223,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_exclusive_scissor_nv: {
-                let value = loader(c"vkCmdSetExclusiveScissorNV".as_ptr());
+                let value = if enabled(c"VK_NV_scissor_exclusive") {
+                    loader(c"vkCmdSetExclusiveScissorNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4326,7 +5243,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_extra_primitive_overestimation_size_ext: {
-                let value = loader(c"vkCmdSetExtraPrimitiveOverestimationSizeEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetExtraPrimitiveOverestimationSizeEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4340,7 +5263,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_fragment_shading_rate_enum_nv: {
-                let value = loader(c"vkCmdSetFragmentShadingRateEnumNV".as_ptr());
+                let value = if enabled(c"VK_NV_fragment_shading_rate_enums") {
+                    loader(c"vkCmdSetFragmentShadingRateEnumNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4355,7 +5282,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_fragment_shading_rate_khr: {
-                let value = loader(c"vkCmdSetFragmentShadingRateKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_fragment_shading_rate") {
+                    loader(c"vkCmdSetFragmentShadingRateKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4370,7 +5301,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_front_face: {
-                let value = loader(c"vkCmdSetFrontFace".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetFrontFace".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4384,7 +5319,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_front_face_ext: {
-                let value = loader(c"vkCmdSetFrontFaceEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetFrontFaceEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4398,7 +5339,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_line_rasterization_mode_ext: {
-                let value = loader(c"vkCmdSetLineRasterizationModeEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetLineRasterizationModeEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4412,7 +5359,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_line_stipple: {
-                let value = loader(c"vkCmdSetLineStipple".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCmdSetLineStipple".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4427,7 +5378,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_line_stipple_ext: {
-                let value = loader(c"vkCmdSetLineStippleEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_line_rasterization") {
+                    loader(c"vkCmdSetLineStippleEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4442,7 +5397,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_line_stipple_enable_ext: {
-                let value = loader(c"vkCmdSetLineStippleEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetLineStippleEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4456,7 +5417,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_line_stipple_khr: {
-                let value = loader(c"vkCmdSetLineStippleKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_line_rasterization") {
+                    loader(c"vkCmdSetLineStippleKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4485,7 +5450,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_logic_op_ext: {
-                let value = loader(c"vkCmdSetLogicOpEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state2")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetLogicOpEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4499,7 +5470,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_logic_op_enable_ext: {
-                let value = loader(c"vkCmdSetLogicOpEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetLogicOpEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4513,7 +5490,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_patch_control_points_ext: {
-                let value = loader(c"vkCmdSetPatchControlPointsEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state2")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetPatchControlPointsEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4527,7 +5510,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_performance_marker_intel: {
-                let value = loader(c"vkCmdSetPerformanceMarkerINTEL".as_ptr());
+                let value = if enabled(c"VK_INTEL_performance_query") {
+                    loader(c"vkCmdSetPerformanceMarkerINTEL".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4535,13 +5522,21 @@ impl DeviceCommands {
                         _command_buffer: CommandBuffer,
                         _marker_info: *const PerformanceMarkerInfoINTEL,
                     ) -> Result {
-                        panic!("could not load vkCmdSetPerformanceMarkerINTEL")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCmdSetPerformanceMarkerINTEL")
+                        }
                     }
                     fallback
                 }
             },
             cmd_set_performance_override_intel: {
-                let value = loader(c"vkCmdSetPerformanceOverrideINTEL".as_ptr());
+                let value = if enabled(c"VK_INTEL_performance_query") {
+                    loader(c"vkCmdSetPerformanceOverrideINTEL".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4549,13 +5544,21 @@ impl DeviceCommands {
                         _command_buffer: CommandBuffer,
                         _override_info: *const PerformanceOverrideInfoINTEL,
                     ) -> Result {
-                        panic!("could not load vkCmdSetPerformanceOverrideINTEL")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCmdSetPerformanceOverrideINTEL")
+                        }
                     }
                     fallback
                 }
             },
             cmd_set_performance_stream_marker_intel: {
-                let value = loader(c"vkCmdSetPerformanceStreamMarkerINTEL".as_ptr());
+                let value = if enabled(c"VK_INTEL_performance_query") {
+                    loader(c"vkCmdSetPerformanceStreamMarkerINTEL".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4563,13 +5566,23 @@ impl DeviceCommands {
                         _command_buffer: CommandBuffer,
                         _marker_info: *const PerformanceStreamMarkerInfoINTEL,
                     ) -> Result {
-                        panic!("could not load vkCmdSetPerformanceStreamMarkerINTEL")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCmdSetPerformanceStreamMarkerINTEL")
+                        }
                     }
                     fallback
                 }
             },
             cmd_set_polygon_mode_ext: {
-                let value = loader(c"vkCmdSetPolygonModeEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetPolygonModeEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4583,7 +5596,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_primitive_restart_enable: {
-                let value = loader(c"vkCmdSetPrimitiveRestartEnable".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetPrimitiveRestartEnable".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4597,7 +5614,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_primitive_restart_enable_ext: {
-                let value = loader(c"vkCmdSetPrimitiveRestartEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state2")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetPrimitiveRestartEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4611,7 +5634,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_primitive_topology: {
-                let value = loader(c"vkCmdSetPrimitiveTopology".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetPrimitiveTopology".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4625,7 +5652,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_primitive_topology_ext: {
-                let value = loader(c"vkCmdSetPrimitiveTopologyEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetPrimitiveTopologyEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4639,7 +5672,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_provoking_vertex_mode_ext: {
-                let value = loader(c"vkCmdSetProvokingVertexModeEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetProvokingVertexModeEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4653,7 +5692,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_rasterization_samples_ext: {
-                let value = loader(c"vkCmdSetRasterizationSamplesEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetRasterizationSamplesEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4667,7 +5712,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_rasterization_stream_ext: {
-                let value = loader(c"vkCmdSetRasterizationStreamEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetRasterizationStreamEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4681,7 +5732,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_rasterizer_discard_enable: {
-                let value = loader(c"vkCmdSetRasterizerDiscardEnable".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetRasterizerDiscardEnable".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4695,7 +5750,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_rasterizer_discard_enable_ext: {
-                let value = loader(c"vkCmdSetRasterizerDiscardEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state2")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetRasterizerDiscardEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4709,7 +5770,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_ray_tracing_pipeline_stack_size_khr: {
-                let value = loader(c"vkCmdSetRayTracingPipelineStackSizeKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_ray_tracing_pipeline") {
+                    loader(c"vkCmdSetRayTracingPipelineStackSizeKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4723,7 +5788,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_rendering_attachment_locations: {
-                let value = loader(c"vkCmdSetRenderingAttachmentLocations".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCmdSetRenderingAttachmentLocations".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4737,7 +5806,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_rendering_attachment_locations_khr: {
-                let value = loader(c"vkCmdSetRenderingAttachmentLocationsKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_dynamic_rendering_local_read") {
+                    loader(c"vkCmdSetRenderingAttachmentLocationsKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4751,7 +5824,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_rendering_input_attachment_indices: {
-                let value = loader(c"vkCmdSetRenderingInputAttachmentIndices".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCmdSetRenderingInputAttachmentIndices".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4765,7 +5842,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_rendering_input_attachment_indices_khr: {
-                let value = loader(c"vkCmdSetRenderingInputAttachmentIndicesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_dynamic_rendering_local_read") {
+                    loader(c"vkCmdSetRenderingInputAttachmentIndicesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4779,7 +5860,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_representative_fragment_test_enable_nv: {
-                let value = loader(c"vkCmdSetRepresentativeFragmentTestEnableNV".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetRepresentativeFragmentTestEnableNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4793,7 +5880,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_sample_locations_ext: {
-                let value = loader(c"vkCmdSetSampleLocationsEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_sample_locations") {
+                    loader(c"vkCmdSetSampleLocationsEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4807,7 +5898,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_sample_locations_enable_ext: {
-                let value = loader(c"vkCmdSetSampleLocationsEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetSampleLocationsEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4821,7 +5918,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_sample_mask_ext: {
-                let value = loader(c"vkCmdSetSampleMaskEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetSampleMaskEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4852,7 +5955,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_scissor_with_count: {
-                let value = loader(c"vkCmdSetScissorWithCount".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetScissorWithCount".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4867,7 +5974,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_scissor_with_count_ext: {
-                let value = loader(c"vkCmdSetScissorWithCountEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetScissorWithCountEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4882,7 +5995,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_shading_rate_image_enable_nv: {
-                let value = loader(c"vkCmdSetShadingRateImageEnableNV".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetShadingRateImageEnableNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4911,7 +6030,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_stencil_op: {
-                let value = loader(c"vkCmdSetStencilOp".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetStencilOp".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4929,7 +6052,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_stencil_op_ext: {
-                let value = loader(c"vkCmdSetStencilOpEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetStencilOpEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4962,7 +6091,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_stencil_test_enable: {
-                let value = loader(c"vkCmdSetStencilTestEnable".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetStencilTestEnable".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -4976,7 +6109,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_stencil_test_enable_ext: {
-                let value = loader(c"vkCmdSetStencilTestEnableEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetStencilTestEnableEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5005,7 +6144,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_tessellation_domain_origin_ext: {
-                let value = loader(c"vkCmdSetTessellationDomainOriginEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetTessellationDomainOriginEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5019,7 +6164,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_vertex_input_ext: {
-                let value = loader(c"vkCmdSetVertexInputEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_shader_object")
+                    || enabled(c"VK_EXT_vertex_input_dynamic_state")
+                {
+                    loader(c"vkCmdSetVertexInputEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5052,7 +6203,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_viewport_shading_rate_palette_nv: {
-                let value = loader(c"vkCmdSetViewportShadingRatePaletteNV".as_ptr());
+                let value = if enabled(c"VK_NV_shading_rate_image") {
+                    loader(c"vkCmdSetViewportShadingRatePaletteNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5068,7 +6223,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_viewport_swizzle_nv: {
-                let value = loader(c"vkCmdSetViewportSwizzleNV".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetViewportSwizzleNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5084,7 +6245,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_viewport_w_scaling_enable_nv: {
-                let value = loader(c"vkCmdSetViewportWScalingEnableNV".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state3")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetViewportWScalingEnableNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5098,7 +6265,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_viewport_w_scaling_nv: {
-                let value = loader(c"vkCmdSetViewportWScalingNV".as_ptr());
+                let value = if enabled(c"VK_NV_clip_space_w_scaling") {
+                    loader(c"vkCmdSetViewportWScalingNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5114,7 +6285,11 @@ impl DeviceCommands {
                 }
             },
             cmd_set_viewport_with_count: {
-                let value = loader(c"vkCmdSetViewportWithCount".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdSetViewportWithCount".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5129,7 +6304,13 @@ impl DeviceCommands {
                 }
             },
             cmd_set_viewport_with_count_ext: {
-                let value = loader(c"vkCmdSetViewportWithCountEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_extended_dynamic_state")
+                    || enabled(c"VK_EXT_shader_object")
+                {
+                    loader(c"vkCmdSetViewportWithCountEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5144,7 +6325,11 @@ impl DeviceCommands {
                 }
             },
             cmd_subpass_shading_huawei: {
-                let value = loader(c"vkCmdSubpassShadingHUAWEI".as_ptr());
+                let value = if enabled(c"VK_HUAWEI_subpass_shading") {
+                    loader(c"vkCmdSubpassShadingHUAWEI".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5155,7 +6340,11 @@ impl DeviceCommands {
                 }
             },
             cmd_trace_rays_indirect2_khr: {
-                let value = loader(c"vkCmdTraceRaysIndirect2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_ray_tracing_maintenance1") {
+                    loader(c"vkCmdTraceRaysIndirect2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5169,7 +6358,11 @@ impl DeviceCommands {
                 }
             },
             cmd_trace_rays_indirect_khr: {
-                let value = loader(c"vkCmdTraceRaysIndirectKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_ray_tracing_pipeline") {
+                    loader(c"vkCmdTraceRaysIndirectKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5187,7 +6380,11 @@ impl DeviceCommands {
                 }
             },
             cmd_trace_rays_khr: {
-                let value = loader(c"vkCmdTraceRaysKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_ray_tracing_pipeline") {
+                    loader(c"vkCmdTraceRaysKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5207,7 +6404,11 @@ impl DeviceCommands {
                 }
             },
             cmd_trace_rays_nv: {
-                let value = loader(c"vkCmdTraceRaysNV".as_ptr());
+                let value = if enabled(c"VK_NV_ray_tracing") {
+                    loader(c"vkCmdTraceRaysNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5251,7 +6452,11 @@ impl DeviceCommands {
                 }
             },
             cmd_update_pipeline_indirect_buffer_nv: {
-                let value = loader(c"vkCmdUpdatePipelineIndirectBufferNV".as_ptr());
+                let value = if enabled(c"VK_NV_device_generated_commands_compute") {
+                    loader(c"vkCmdUpdatePipelineIndirectBufferNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5289,7 +6494,11 @@ impl DeviceCommands {
                 }
             },
             cmd_wait_events2: {
-                let value = loader(c"vkCmdWaitEvents2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdWaitEvents2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5305,7 +6514,11 @@ impl DeviceCommands {
                 }
             },
             cmd_wait_events2_khr: {
-                let value = loader(c"vkCmdWaitEvents2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_synchronization2") {
+                    loader(c"vkCmdWaitEvents2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5321,7 +6534,11 @@ impl DeviceCommands {
                 }
             },
             cmd_write_acceleration_structures_properties_khr: {
-                let value = loader(c"vkCmdWriteAccelerationStructuresPropertiesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkCmdWriteAccelerationStructuresPropertiesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5339,7 +6556,11 @@ impl DeviceCommands {
                 }
             },
             cmd_write_acceleration_structures_properties_nv: {
-                let value = loader(c"vkCmdWriteAccelerationStructuresPropertiesNV".as_ptr());
+                let value = if enabled(c"VK_NV_ray_tracing") {
+                    loader(c"vkCmdWriteAccelerationStructuresPropertiesNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5357,7 +6578,11 @@ impl DeviceCommands {
                 }
             },
             cmd_write_buffer_marker2_amd: {
-                let value = loader(c"vkCmdWriteBufferMarker2AMD".as_ptr());
+                let value = if enabled(c"VK_AMD_buffer_marker") {
+                    loader(c"vkCmdWriteBufferMarker2AMD".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5374,7 +6599,11 @@ impl DeviceCommands {
                 }
             },
             cmd_write_buffer_marker_amd: {
-                let value = loader(c"vkCmdWriteBufferMarkerAMD".as_ptr());
+                let value = if enabled(c"VK_AMD_buffer_marker") {
+                    loader(c"vkCmdWriteBufferMarkerAMD".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5391,7 +6620,11 @@ impl DeviceCommands {
                 }
             },
             cmd_write_micromaps_properties_ext: {
-                let value = loader(c"vkCmdWriteMicromapsPropertiesEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkCmdWriteMicromapsPropertiesEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5425,7 +6658,11 @@ impl DeviceCommands {
                 }
             },
             cmd_write_timestamp2: {
-                let value = loader(c"vkCmdWriteTimestamp2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCmdWriteTimestamp2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5441,7 +6678,11 @@ impl DeviceCommands {
                 }
             },
             cmd_write_timestamp2_khr: {
-                let value = loader(c"vkCmdWriteTimestamp2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_synchronization2") {
+                    loader(c"vkCmdWriteTimestamp2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5457,7 +6698,11 @@ impl DeviceCommands {
                 }
             },
             compile_deferred_nv: {
-                let value = loader(c"vkCompileDeferredNV".as_ptr());
+                let value = if enabled(c"VK_NV_ray_tracing") {
+                    loader(c"vkCompileDeferredNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5466,13 +6711,21 @@ impl DeviceCommands {
                         _pipeline: Pipeline,
                         _shader: u32,
                     ) -> Result {
-                        panic!("could not load vkCompileDeferredNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCompileDeferredNV")
+                        }
                     }
                     fallback
                 }
             },
             convert_cooperative_vector_matrix_nv: {
-                let value = loader(c"vkConvertCooperativeVectorMatrixNV".as_ptr());
+                let value = if enabled(c"VK_NV_cooperative_vector") {
+                    loader(c"vkConvertCooperativeVectorMatrixNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5480,13 +6733,21 @@ impl DeviceCommands {
                         _device: Device,
                         _info: *const ConvertCooperativeVectorMatrixInfoNV,
                     ) -> Result {
-                        panic!("could not load vkConvertCooperativeVectorMatrixNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkConvertCooperativeVectorMatrixNV")
+                        }
                     }
                     fallback
                 }
             },
             copy_acceleration_structure_khr: {
-                let value = loader(c"vkCopyAccelerationStructureKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkCopyAccelerationStructureKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5495,13 +6756,21 @@ impl DeviceCommands {
                         _deferred_operation: DeferredOperationKHR,
                         _info: *const CopyAccelerationStructureInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkCopyAccelerationStructureKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCopyAccelerationStructureKHR")
+                        }
                     }
                     fallback
                 }
             },
             copy_acceleration_structure_to_memory_khr: {
-                let value = loader(c"vkCopyAccelerationStructureToMemoryKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkCopyAccelerationStructureToMemoryKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5510,13 +6779,21 @@ impl DeviceCommands {
                         _deferred_operation: DeferredOperationKHR,
                         _info: *const CopyAccelerationStructureToMemoryInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkCopyAccelerationStructureToMemoryKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCopyAccelerationStructureToMemoryKHR")
+                        }
                     }
                     fallback
                 }
             },
             copy_image_to_image: {
-                let value = loader(c"vkCopyImageToImage".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCopyImageToImage".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5524,13 +6801,21 @@ impl DeviceCommands {
                         _device: Device,
                         _copy_image_to_image_info: *const CopyImageToImageInfo,
                     ) -> Result {
-                        panic!("could not load vkCopyImageToImage")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCopyImageToImage")
+                        }
                     }
                     fallback
                 }
             },
             copy_image_to_image_ext: {
-                let value = loader(c"vkCopyImageToImageEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_host_image_copy") {
+                    loader(c"vkCopyImageToImageEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5538,13 +6823,21 @@ impl DeviceCommands {
                         _device: Device,
                         _copy_image_to_image_info: *const CopyImageToImageInfo,
                     ) -> Result {
-                        panic!("could not load vkCopyImageToImageEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCopyImageToImageEXT")
+                        }
                     }
                     fallback
                 }
             },
             copy_image_to_memory: {
-                let value = loader(c"vkCopyImageToMemory".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCopyImageToMemory".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5552,13 +6845,21 @@ impl DeviceCommands {
                         _device: Device,
                         _copy_image_to_memory_info: *const CopyImageToMemoryInfo,
                     ) -> Result {
-                        panic!("could not load vkCopyImageToMemory")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCopyImageToMemory")
+                        }
                     }
                     fallback
                 }
             },
             copy_image_to_memory_ext: {
-                let value = loader(c"vkCopyImageToMemoryEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_host_image_copy") {
+                    loader(c"vkCopyImageToMemoryEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5566,13 +6867,21 @@ impl DeviceCommands {
                         _device: Device,
                         _copy_image_to_memory_info: *const CopyImageToMemoryInfo,
                     ) -> Result {
-                        panic!("could not load vkCopyImageToMemoryEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCopyImageToMemoryEXT")
+                        }
                     }
                     fallback
                 }
             },
             copy_memory_to_acceleration_structure_khr: {
-                let value = loader(c"vkCopyMemoryToAccelerationStructureKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkCopyMemoryToAccelerationStructureKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5581,13 +6890,21 @@ impl DeviceCommands {
                         _deferred_operation: DeferredOperationKHR,
                         _info: *const CopyMemoryToAccelerationStructureInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkCopyMemoryToAccelerationStructureKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCopyMemoryToAccelerationStructureKHR")
+                        }
                     }
                     fallback
                 }
             },
             copy_memory_to_image: {
-                let value = loader(c"vkCopyMemoryToImage".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkCopyMemoryToImage".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5595,13 +6912,21 @@ impl DeviceCommands {
                         _device: Device,
                         _copy_memory_to_image_info: *const CopyMemoryToImageInfo,
                     ) -> Result {
-                        panic!("could not load vkCopyMemoryToImage")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCopyMemoryToImage")
+                        }
                     }
                     fallback
                 }
             },
             copy_memory_to_image_ext: {
-                let value = loader(c"vkCopyMemoryToImageEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_host_image_copy") {
+                    loader(c"vkCopyMemoryToImageEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5609,13 +6934,21 @@ impl DeviceCommands {
                         _device: Device,
                         _copy_memory_to_image_info: *const CopyMemoryToImageInfo,
                     ) -> Result {
-                        panic!("could not load vkCopyMemoryToImageEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCopyMemoryToImageEXT")
+                        }
                     }
                     fallback
                 }
             },
             copy_memory_to_micromap_ext: {
-                let value = loader(c"vkCopyMemoryToMicromapEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkCopyMemoryToMicromapEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5624,13 +6957,21 @@ impl DeviceCommands {
                         _deferred_operation: DeferredOperationKHR,
                         _info: *const CopyMemoryToMicromapInfoEXT,
                     ) -> Result {
-                        panic!("could not load vkCopyMemoryToMicromapEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCopyMemoryToMicromapEXT")
+                        }
                     }
                     fallback
                 }
             },
             copy_micromap_ext: {
-                let value = loader(c"vkCopyMicromapEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkCopyMicromapEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5639,13 +6980,21 @@ impl DeviceCommands {
                         _deferred_operation: DeferredOperationKHR,
                         _info: *const CopyMicromapInfoEXT,
                     ) -> Result {
-                        panic!("could not load vkCopyMicromapEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCopyMicromapEXT")
+                        }
                     }
                     fallback
                 }
             },
             copy_micromap_to_memory_ext: {
-                let value = loader(c"vkCopyMicromapToMemoryEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkCopyMicromapToMemoryEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5654,13 +7003,21 @@ impl DeviceCommands {
                         _deferred_operation: DeferredOperationKHR,
                         _info: *const CopyMicromapToMemoryInfoEXT,
                     ) -> Result {
-                        panic!("could not load vkCopyMicromapToMemoryEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCopyMicromapToMemoryEXT")
+                        }
                     }
                     fallback
                 }
             },
             create_acceleration_structure_khr: {
-                let value = loader(c"vkCreateAccelerationStructureKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkCreateAccelerationStructureKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5670,13 +7027,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _acceleration_structure: *mut AccelerationStructureKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateAccelerationStructureKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateAccelerationStructureKHR")
+                        }
                     }
                     fallback
                 }
             },
             create_acceleration_structure_nv: {
-                let value = loader(c"vkCreateAccelerationStructureNV".as_ptr());
+                let value = if enabled(c"VK_NV_ray_tracing") {
+                    loader(c"vkCreateAccelerationStructureNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5686,7 +7051,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _acceleration_structure: *mut AccelerationStructureNV,
                     ) -> Result {
-                        panic!("could not load vkCreateAccelerationStructureNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateAccelerationStructureNV")
+                        }
                     }
                     fallback
                 }
@@ -5702,13 +7071,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _buffer: *mut Buffer,
                     ) -> Result {
-                        panic!("could not load vkCreateBuffer")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateBuffer")
+                        }
                     }
                     fallback
                 }
             },
             create_buffer_collection_fuchsia: {
-                let value = loader(c"vkCreateBufferCollectionFUCHSIA".as_ptr());
+                let value = if enabled(c"VK_FUCHSIA_buffer_collection") {
+                    loader(c"vkCreateBufferCollectionFUCHSIA".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5718,7 +7095,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _collection: *mut BufferCollectionFUCHSIA,
                     ) -> Result {
-                        panic!("could not load vkCreateBufferCollectionFUCHSIA")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateBufferCollectionFUCHSIA")
+                        }
                     }
                     fallback
                 }
@@ -5734,7 +7115,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _view: *mut BufferView,
                     ) -> Result {
-                        panic!("could not load vkCreateBufferView")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateBufferView")
+                        }
                     }
                     fallback
                 }
@@ -5750,7 +7135,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _command_pool: *mut CommandPool,
                     ) -> Result {
-                        panic!("could not load vkCreateCommandPool")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateCommandPool")
+                        }
                     }
                     fallback
                 }
@@ -5768,13 +7157,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _pipelines: *mut Pipeline,
                     ) -> Result {
-                        panic!("could not load vkCreateComputePipelines")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateComputePipelines")
+                        }
                     }
                     fallback
                 }
             },
             create_cu_function_nvx: {
-                let value = loader(c"vkCreateCuFunctionNVX".as_ptr());
+                let value = if enabled(c"VK_NVX_binary_import") {
+                    loader(c"vkCreateCuFunctionNVX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5784,13 +7181,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _function: *mut CuFunctionNVX,
                     ) -> Result {
-                        panic!("could not load vkCreateCuFunctionNVX")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateCuFunctionNVX")
+                        }
                     }
                     fallback
                 }
             },
             create_cu_module_nvx: {
-                let value = loader(c"vkCreateCuModuleNVX".as_ptr());
+                let value = if enabled(c"VK_NVX_binary_import") {
+                    loader(c"vkCreateCuModuleNVX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5800,13 +7205,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _module: *mut CuModuleNVX,
                     ) -> Result {
-                        panic!("could not load vkCreateCuModuleNVX")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateCuModuleNVX")
+                        }
                     }
                     fallback
                 }
             },
             create_cuda_function_nv: {
-                let value = loader(c"vkCreateCudaFunctionNV".as_ptr());
+                let value = if enabled(c"VK_NV_cuda_kernel_launch") {
+                    loader(c"vkCreateCudaFunctionNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5816,13 +7229,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _function: *mut CudaFunctionNV,
                     ) -> Result {
-                        panic!("could not load vkCreateCudaFunctionNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateCudaFunctionNV")
+                        }
                     }
                     fallback
                 }
             },
             create_cuda_module_nv: {
-                let value = loader(c"vkCreateCudaModuleNV".as_ptr());
+                let value = if enabled(c"VK_NV_cuda_kernel_launch") {
+                    loader(c"vkCreateCudaModuleNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5832,13 +7253,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _module: *mut CudaModuleNV,
                     ) -> Result {
-                        panic!("could not load vkCreateCudaModuleNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateCudaModuleNV")
+                        }
                     }
                     fallback
                 }
             },
             create_data_graph_pipeline_session_arm: {
-                let value = loader(c"vkCreateDataGraphPipelineSessionARM".as_ptr());
+                let value = if enabled(c"VK_ARM_data_graph") {
+                    loader(c"vkCreateDataGraphPipelineSessionARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5848,13 +7277,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _session: *mut DataGraphPipelineSessionARM,
                     ) -> Result {
-                        panic!("could not load vkCreateDataGraphPipelineSessionARM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDataGraphPipelineSessionARM")
+                        }
                     }
                     fallback
                 }
             },
             create_data_graph_pipelines_arm: {
-                let value = loader(c"vkCreateDataGraphPipelinesARM".as_ptr());
+                let value = if enabled(c"VK_ARM_data_graph") {
+                    loader(c"vkCreateDataGraphPipelinesARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5867,13 +7304,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _pipelines: *mut Pipeline,
                     ) -> Result {
-                        panic!("could not load vkCreateDataGraphPipelinesARM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDataGraphPipelinesARM")
+                        }
                     }
                     fallback
                 }
             },
             create_deferred_operation_khr: {
-                let value = loader(c"vkCreateDeferredOperationKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_deferred_host_operations") {
+                    loader(c"vkCreateDeferredOperationKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5882,7 +7327,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _deferred_operation: *mut DeferredOperationKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateDeferredOperationKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDeferredOperationKHR")
+                        }
                     }
                     fallback
                 }
@@ -5898,7 +7347,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _descriptor_pool: *mut DescriptorPool,
                     ) -> Result {
-                        panic!("could not load vkCreateDescriptorPool")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDescriptorPool")
+                        }
                     }
                     fallback
                 }
@@ -5914,13 +7367,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _set_layout: *mut DescriptorSetLayout,
                     ) -> Result {
-                        panic!("could not load vkCreateDescriptorSetLayout")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDescriptorSetLayout")
+                        }
                     }
                     fallback
                 }
             },
             create_descriptor_update_template: {
-                let value = loader(c"vkCreateDescriptorUpdateTemplate".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkCreateDescriptorUpdateTemplate".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5930,13 +7391,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _descriptor_update_template: *mut DescriptorUpdateTemplate,
                     ) -> Result {
-                        panic!("could not load vkCreateDescriptorUpdateTemplate")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDescriptorUpdateTemplate")
+                        }
                     }
                     fallback
                 }
             },
             create_descriptor_update_template_khr: {
-                let value = loader(c"vkCreateDescriptorUpdateTemplateKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_descriptor_update_template") {
+                    loader(c"vkCreateDescriptorUpdateTemplateKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5946,7 +7415,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _descriptor_update_template: *mut DescriptorUpdateTemplate,
                     ) -> Result {
-                        panic!("could not load vkCreateDescriptorUpdateTemplateKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDescriptorUpdateTemplateKHR")
+                        }
                     }
                     fallback
                 }
@@ -5962,13 +7435,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _event: *mut Event,
                     ) -> Result {
-                        panic!("could not load vkCreateEvent")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateEvent")
+                        }
                     }
                     fallback
                 }
             },
             create_execution_graph_pipelines_amdx: {
-                let value = loader(c"vkCreateExecutionGraphPipelinesAMDX".as_ptr());
+                let value = if enabled(c"VK_AMDX_shader_enqueue") {
+                    loader(c"vkCreateExecutionGraphPipelinesAMDX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5980,13 +7461,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _pipelines: *mut Pipeline,
                     ) -> Result {
-                        panic!("could not load vkCreateExecutionGraphPipelinesAMDX")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateExecutionGraphPipelinesAMDX")
+                        }
                     }
                     fallback
                 }
             },
             create_external_compute_queue_nv: {
-                let value = loader(c"vkCreateExternalComputeQueueNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_compute_queue") {
+                    loader(c"vkCreateExternalComputeQueueNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -5996,7 +7485,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _external_queue: *mut ExternalComputeQueueNV,
                     ) -> Result {
-                        panic!("could not load vkCreateExternalComputeQueueNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateExternalComputeQueueNV")
+                        }
                     }
                     fallback
                 }
@@ -6012,7 +7505,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _fence: *mut Fence,
                     ) -> Result {
-                        panic!("could not load vkCreateFence")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateFence")
+                        }
                     }
                     fallback
                 }
@@ -6028,7 +7525,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _framebuffer: *mut Framebuffer,
                     ) -> Result {
-                        panic!("could not load vkCreateFramebuffer")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateFramebuffer")
+                        }
                     }
                     fallback
                 }
@@ -6046,7 +7547,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _pipelines: *mut Pipeline,
                     ) -> Result {
-                        panic!("could not load vkCreateGraphicsPipelines")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateGraphicsPipelines")
+                        }
                     }
                     fallback
                 }
@@ -6062,7 +7567,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _image: *mut Image,
                     ) -> Result {
-                        panic!("could not load vkCreateImage")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateImage")
+                        }
                     }
                     fallback
                 }
@@ -6078,13 +7587,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _view: *mut ImageView,
                     ) -> Result {
-                        panic!("could not load vkCreateImageView")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateImageView")
+                        }
                     }
                     fallback
                 }
             },
             create_indirect_commands_layout_ext: {
-                let value = loader(c"vkCreateIndirectCommandsLayoutEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_device_generated_commands") {
+                    loader(c"vkCreateIndirectCommandsLayoutEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6094,13 +7611,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _indirect_commands_layout: *mut IndirectCommandsLayoutEXT,
                     ) -> Result {
-                        panic!("could not load vkCreateIndirectCommandsLayoutEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateIndirectCommandsLayoutEXT")
+                        }
                     }
                     fallback
                 }
             },
             create_indirect_commands_layout_nv: {
-                let value = loader(c"vkCreateIndirectCommandsLayoutNV".as_ptr());
+                let value = if enabled(c"VK_NV_device_generated_commands") {
+                    loader(c"vkCreateIndirectCommandsLayoutNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6110,13 +7635,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _indirect_commands_layout: *mut IndirectCommandsLayoutNV,
                     ) -> Result {
-                        panic!("could not load vkCreateIndirectCommandsLayoutNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateIndirectCommandsLayoutNV")
+                        }
                     }
                     fallback
                 }
             },
             create_indirect_execution_set_ext: {
-                let value = loader(c"vkCreateIndirectExecutionSetEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_device_generated_commands") {
+                    loader(c"vkCreateIndirectExecutionSetEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6126,13 +7659,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _indirect_execution_set: *mut IndirectExecutionSetEXT,
                     ) -> Result {
-                        panic!("could not load vkCreateIndirectExecutionSetEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateIndirectExecutionSetEXT")
+                        }
                     }
                     fallback
                 }
             },
             create_micromap_ext: {
-                let value = loader(c"vkCreateMicromapEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkCreateMicromapEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6142,13 +7683,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _micromap: *mut MicromapEXT,
                     ) -> Result {
-                        panic!("could not load vkCreateMicromapEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateMicromapEXT")
+                        }
                     }
                     fallback
                 }
             },
             create_optical_flow_session_nv: {
-                let value = loader(c"vkCreateOpticalFlowSessionNV".as_ptr());
+                let value = if enabled(c"VK_NV_optical_flow") {
+                    loader(c"vkCreateOpticalFlowSessionNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6158,13 +7707,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _session: *mut OpticalFlowSessionNV,
                     ) -> Result {
-                        panic!("could not load vkCreateOpticalFlowSessionNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateOpticalFlowSessionNV")
+                        }
                     }
                     fallback
                 }
             },
             create_pipeline_binaries_khr: {
-                let value = loader(c"vkCreatePipelineBinariesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_pipeline_binary") {
+                    loader(c"vkCreatePipelineBinariesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6174,7 +7731,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _binaries: *mut PipelineBinaryHandlesInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkCreatePipelineBinariesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreatePipelineBinariesKHR")
+                        }
                     }
                     fallback
                 }
@@ -6190,7 +7751,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _pipeline_cache: *mut PipelineCache,
                     ) -> Result {
-                        panic!("could not load vkCreatePipelineCache")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreatePipelineCache")
+                        }
                     }
                     fallback
                 }
@@ -6206,13 +7771,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _pipeline_layout: *mut PipelineLayout,
                     ) -> Result {
-                        panic!("could not load vkCreatePipelineLayout")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreatePipelineLayout")
+                        }
                     }
                     fallback
                 }
             },
             create_private_data_slot: {
-                let value = loader(c"vkCreatePrivateDataSlot".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkCreatePrivateDataSlot".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6222,13 +7795,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _private_data_slot: *mut PrivateDataSlot,
                     ) -> Result {
-                        panic!("could not load vkCreatePrivateDataSlot")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreatePrivateDataSlot")
+                        }
                     }
                     fallback
                 }
             },
             create_private_data_slot_ext: {
-                let value = loader(c"vkCreatePrivateDataSlotEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_private_data") {
+                    loader(c"vkCreatePrivateDataSlotEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6238,7 +7819,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _private_data_slot: *mut PrivateDataSlot,
                     ) -> Result {
-                        panic!("could not load vkCreatePrivateDataSlotEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreatePrivateDataSlotEXT")
+                        }
                     }
                     fallback
                 }
@@ -6254,13 +7839,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _query_pool: *mut QueryPool,
                     ) -> Result {
-                        panic!("could not load vkCreateQueryPool")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateQueryPool")
+                        }
                     }
                     fallback
                 }
             },
             create_ray_tracing_pipelines_khr: {
-                let value = loader(c"vkCreateRayTracingPipelinesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_ray_tracing_pipeline") {
+                    loader(c"vkCreateRayTracingPipelinesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6273,13 +7866,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _pipelines: *mut Pipeline,
                     ) -> Result {
-                        panic!("could not load vkCreateRayTracingPipelinesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateRayTracingPipelinesKHR")
+                        }
                     }
                     fallback
                 }
             },
             create_ray_tracing_pipelines_nv: {
-                let value = loader(c"vkCreateRayTracingPipelinesNV".as_ptr());
+                let value = if enabled(c"VK_NV_ray_tracing") {
+                    loader(c"vkCreateRayTracingPipelinesNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6291,7 +7892,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _pipelines: *mut Pipeline,
                     ) -> Result {
-                        panic!("could not load vkCreateRayTracingPipelinesNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateRayTracingPipelinesNV")
+                        }
                     }
                     fallback
                 }
@@ -6307,13 +7912,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _render_pass: *mut RenderPass,
                     ) -> Result {
-                        panic!("could not load vkCreateRenderPass")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateRenderPass")
+                        }
                     }
                     fallback
                 }
             },
             create_render_pass2: {
-                let value = loader(c"vkCreateRenderPass2".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkCreateRenderPass2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6323,13 +7936,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _render_pass: *mut RenderPass,
                     ) -> Result {
-                        panic!("could not load vkCreateRenderPass2")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateRenderPass2")
+                        }
                     }
                     fallback
                 }
             },
             create_render_pass2_khr: {
-                let value = loader(c"vkCreateRenderPass2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_create_renderpass2") {
+                    loader(c"vkCreateRenderPass2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6339,7 +7960,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _render_pass: *mut RenderPass,
                     ) -> Result {
-                        panic!("could not load vkCreateRenderPass2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateRenderPass2KHR")
+                        }
                     }
                     fallback
                 }
@@ -6355,13 +7980,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _sampler: *mut Sampler,
                     ) -> Result {
-                        panic!("could not load vkCreateSampler")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateSampler")
+                        }
                     }
                     fallback
                 }
             },
             create_sampler_ycbcr_conversion: {
-                let value = loader(c"vkCreateSamplerYcbcrConversion".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkCreateSamplerYcbcrConversion".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6371,13 +8004,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _ycbcr_conversion: *mut SamplerYcbcrConversion,
                     ) -> Result {
-                        panic!("could not load vkCreateSamplerYcbcrConversion")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateSamplerYcbcrConversion")
+                        }
                     }
                     fallback
                 }
             },
             create_sampler_ycbcr_conversion_khr: {
-                let value = loader(c"vkCreateSamplerYcbcrConversionKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_sampler_ycbcr_conversion") {
+                    loader(c"vkCreateSamplerYcbcrConversionKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6387,7 +8028,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _ycbcr_conversion: *mut SamplerYcbcrConversion,
                     ) -> Result {
-                        panic!("could not load vkCreateSamplerYcbcrConversionKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateSamplerYcbcrConversionKHR")
+                        }
                     }
                     fallback
                 }
@@ -6403,13 +8048,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _semaphore: *mut Semaphore,
                     ) -> Result {
-                        panic!("could not load vkCreateSemaphore")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateSemaphore")
+                        }
                     }
                     fallback
                 }
             },
             create_semaphore_sci_sync_pool_nv: {
-                let value = loader(c"vkCreateSemaphoreSciSyncPoolNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_sci_sync2") {
+                    loader(c"vkCreateSemaphoreSciSyncPoolNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6419,7 +8072,11 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _semaphore_pool: *mut SemaphoreSciSyncPoolNV,
                     ) -> Result {
-                        panic!("could not load vkCreateSemaphoreSciSyncPoolNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateSemaphoreSciSyncPoolNV")
+                        }
                     }
                     fallback
                 }
@@ -6435,13 +8092,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _shader_module: *mut ShaderModule,
                     ) -> Result {
-                        panic!("could not load vkCreateShaderModule")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateShaderModule")
+                        }
                     }
                     fallback
                 }
             },
             create_shaders_ext: {
-                let value = loader(c"vkCreateShadersEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_shader_object") {
+                    loader(c"vkCreateShadersEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6452,13 +8117,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _shaders: *mut ShaderEXT,
                     ) -> Result {
-                        panic!("could not load vkCreateShadersEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateShadersEXT")
+                        }
                     }
                     fallback
                 }
             },
             create_shared_swapchains_khr: {
-                let value = loader(c"vkCreateSharedSwapchainsKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_display_swapchain") {
+                    loader(c"vkCreateSharedSwapchainsKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6469,13 +8142,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _swapchains: *mut SwapchainKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateSharedSwapchainsKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateSharedSwapchainsKHR")
+                        }
                     }
                     fallback
                 }
             },
             create_swapchain_khr: {
-                let value = loader(c"vkCreateSwapchainKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_swapchain") {
+                    loader(c"vkCreateSwapchainKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6485,13 +8166,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _swapchain: *mut SwapchainKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateSwapchainKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateSwapchainKHR")
+                        }
                     }
                     fallback
                 }
             },
             create_tensor_arm: {
-                let value = loader(c"vkCreateTensorARM".as_ptr());
+                let value = if enabled(c"VK_ARM_tensors") {
+                    loader(c"vkCreateTensorARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6501,13 +8190,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _tensor: *mut TensorARM,
                     ) -> Result {
-                        panic!("could not load vkCreateTensorARM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateTensorARM")
+                        }
                     }
                     fallback
                 }
             },
             create_tensor_view_arm: {
-                let value = loader(c"vkCreateTensorViewARM".as_ptr());
+                let value = if enabled(c"VK_ARM_tensors") {
+                    loader(c"vkCreateTensorViewARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6517,13 +8214,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _view: *mut TensorViewARM,
                     ) -> Result {
-                        panic!("could not load vkCreateTensorViewARM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateTensorViewARM")
+                        }
                     }
                     fallback
                 }
             },
             create_validation_cache_ext: {
-                let value = loader(c"vkCreateValidationCacheEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_validation_cache") {
+                    loader(c"vkCreateValidationCacheEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6533,13 +8238,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _validation_cache: *mut ValidationCacheEXT,
                     ) -> Result {
-                        panic!("could not load vkCreateValidationCacheEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateValidationCacheEXT")
+                        }
                     }
                     fallback
                 }
             },
             create_video_session_khr: {
-                let value = loader(c"vkCreateVideoSessionKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_queue") {
+                    loader(c"vkCreateVideoSessionKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6549,13 +8262,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _video_session: *mut VideoSessionKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateVideoSessionKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateVideoSessionKHR")
+                        }
                     }
                     fallback
                 }
             },
             create_video_session_parameters_khr: {
-                let value = loader(c"vkCreateVideoSessionParametersKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_queue") {
+                    loader(c"vkCreateVideoSessionParametersKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6565,13 +8286,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _video_session_parameters: *mut VideoSessionParametersKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateVideoSessionParametersKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateVideoSessionParametersKHR")
+                        }
                     }
                     fallback
                 }
             },
             debug_marker_set_object_name_ext: {
-                let value = loader(c"vkDebugMarkerSetObjectNameEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_debug_marker") {
+                    loader(c"vkDebugMarkerSetObjectNameEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6579,13 +8308,21 @@ impl DeviceCommands {
                         _device: Device,
                         _name_info: *const DebugMarkerObjectNameInfoEXT,
                     ) -> Result {
-                        panic!("could not load vkDebugMarkerSetObjectNameEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkDebugMarkerSetObjectNameEXT")
+                        }
                     }
                     fallback
                 }
             },
             debug_marker_set_object_tag_ext: {
-                let value = loader(c"vkDebugMarkerSetObjectTagEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_debug_marker") {
+                    loader(c"vkDebugMarkerSetObjectTagEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6593,13 +8330,21 @@ impl DeviceCommands {
                         _device: Device,
                         _tag_info: *const DebugMarkerObjectTagInfoEXT,
                     ) -> Result {
-                        panic!("could not load vkDebugMarkerSetObjectTagEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkDebugMarkerSetObjectTagEXT")
+                        }
                     }
                     fallback
                 }
             },
             deferred_operation_join_khr: {
-                let value = loader(c"vkDeferredOperationJoinKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_deferred_host_operations") {
+                    loader(c"vkDeferredOperationJoinKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6607,13 +8352,21 @@ impl DeviceCommands {
                         _device: Device,
                         _operation: DeferredOperationKHR,
                     ) -> Result {
-                        panic!("could not load vkDeferredOperationJoinKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkDeferredOperationJoinKHR")
+                        }
                     }
                     fallback
                 }
             },
             destroy_acceleration_structure_khr: {
-                let value = loader(c"vkDestroyAccelerationStructureKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkDestroyAccelerationStructureKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6628,7 +8381,11 @@ impl DeviceCommands {
                 }
             },
             destroy_acceleration_structure_nv: {
-                let value = loader(c"vkDestroyAccelerationStructureNV".as_ptr());
+                let value = if enabled(c"VK_NV_ray_tracing") {
+                    loader(c"vkDestroyAccelerationStructureNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6658,7 +8415,11 @@ impl DeviceCommands {
                 }
             },
             destroy_buffer_collection_fuchsia: {
-                let value = loader(c"vkDestroyBufferCollectionFUCHSIA".as_ptr());
+                let value = if enabled(c"VK_FUCHSIA_buffer_collection") {
+                    loader(c"vkDestroyBufferCollectionFUCHSIA".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6703,7 +8464,11 @@ impl DeviceCommands {
                 }
             },
             destroy_cu_function_nvx: {
-                let value = loader(c"vkDestroyCuFunctionNVX".as_ptr());
+                let value = if enabled(c"VK_NVX_binary_import") {
+                    loader(c"vkDestroyCuFunctionNVX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6718,7 +8483,11 @@ impl DeviceCommands {
                 }
             },
             destroy_cu_module_nvx: {
-                let value = loader(c"vkDestroyCuModuleNVX".as_ptr());
+                let value = if enabled(c"VK_NVX_binary_import") {
+                    loader(c"vkDestroyCuModuleNVX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6733,7 +8502,11 @@ impl DeviceCommands {
                 }
             },
             destroy_cuda_function_nv: {
-                let value = loader(c"vkDestroyCudaFunctionNV".as_ptr());
+                let value = if enabled(c"VK_NV_cuda_kernel_launch") {
+                    loader(c"vkDestroyCudaFunctionNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6748,7 +8521,11 @@ impl DeviceCommands {
                 }
             },
             destroy_cuda_module_nv: {
-                let value = loader(c"vkDestroyCudaModuleNV".as_ptr());
+                let value = if enabled(c"VK_NV_cuda_kernel_launch") {
+                    loader(c"vkDestroyCudaModuleNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6763,7 +8540,11 @@ impl DeviceCommands {
                 }
             },
             destroy_data_graph_pipeline_session_arm: {
-                let value = loader(c"vkDestroyDataGraphPipelineSessionARM".as_ptr());
+                let value = if enabled(c"VK_ARM_data_graph") {
+                    loader(c"vkDestroyDataGraphPipelineSessionARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6778,7 +8559,11 @@ impl DeviceCommands {
                 }
             },
             destroy_deferred_operation_khr: {
-                let value = loader(c"vkDestroyDeferredOperationKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_deferred_host_operations") {
+                    loader(c"vkDestroyDeferredOperationKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6823,7 +8608,11 @@ impl DeviceCommands {
                 }
             },
             destroy_descriptor_update_template: {
-                let value = loader(c"vkDestroyDescriptorUpdateTemplate".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkDestroyDescriptorUpdateTemplate".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6838,7 +8627,11 @@ impl DeviceCommands {
                 }
             },
             destroy_descriptor_update_template_khr: {
-                let value = loader(c"vkDestroyDescriptorUpdateTemplateKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_descriptor_update_template") {
+                    loader(c"vkDestroyDescriptorUpdateTemplateKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6882,7 +8675,11 @@ impl DeviceCommands {
                 }
             },
             destroy_external_compute_queue_nv: {
-                let value = loader(c"vkDestroyExternalComputeQueueNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_compute_queue") {
+                    loader(c"vkDestroyExternalComputeQueueNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6957,7 +8754,11 @@ impl DeviceCommands {
                 }
             },
             destroy_indirect_commands_layout_ext: {
-                let value = loader(c"vkDestroyIndirectCommandsLayoutEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_device_generated_commands") {
+                    loader(c"vkDestroyIndirectCommandsLayoutEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6972,7 +8773,11 @@ impl DeviceCommands {
                 }
             },
             destroy_indirect_commands_layout_nv: {
-                let value = loader(c"vkDestroyIndirectCommandsLayoutNV".as_ptr());
+                let value = if enabled(c"VK_NV_device_generated_commands") {
+                    loader(c"vkDestroyIndirectCommandsLayoutNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -6987,7 +8792,11 @@ impl DeviceCommands {
                 }
             },
             destroy_indirect_execution_set_ext: {
-                let value = loader(c"vkDestroyIndirectExecutionSetEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_device_generated_commands") {
+                    loader(c"vkDestroyIndirectExecutionSetEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7002,7 +8811,11 @@ impl DeviceCommands {
                 }
             },
             destroy_micromap_ext: {
-                let value = loader(c"vkDestroyMicromapEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkDestroyMicromapEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7017,7 +8830,11 @@ impl DeviceCommands {
                 }
             },
             destroy_optical_flow_session_nv: {
-                let value = loader(c"vkDestroyOpticalFlowSessionNV".as_ptr());
+                let value = if enabled(c"VK_NV_optical_flow") {
+                    loader(c"vkDestroyOpticalFlowSessionNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7047,7 +8864,11 @@ impl DeviceCommands {
                 }
             },
             destroy_pipeline_binary_khr: {
-                let value = loader(c"vkDestroyPipelineBinaryKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_pipeline_binary") {
+                    loader(c"vkDestroyPipelineBinaryKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7092,7 +8913,11 @@ impl DeviceCommands {
                 }
             },
             destroy_private_data_slot: {
-                let value = loader(c"vkDestroyPrivateDataSlot".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkDestroyPrivateDataSlot".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7107,7 +8932,11 @@ impl DeviceCommands {
                 }
             },
             destroy_private_data_slot_ext: {
-                let value = loader(c"vkDestroyPrivateDataSlotEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_private_data") {
+                    loader(c"vkDestroyPrivateDataSlotEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7167,7 +8996,11 @@ impl DeviceCommands {
                 }
             },
             destroy_sampler_ycbcr_conversion: {
-                let value = loader(c"vkDestroySamplerYcbcrConversion".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkDestroySamplerYcbcrConversion".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7182,7 +9015,11 @@ impl DeviceCommands {
                 }
             },
             destroy_sampler_ycbcr_conversion_khr: {
-                let value = loader(c"vkDestroySamplerYcbcrConversionKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_sampler_ycbcr_conversion") {
+                    loader(c"vkDestroySamplerYcbcrConversionKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7212,7 +9049,11 @@ impl DeviceCommands {
                 }
             },
             destroy_semaphore_sci_sync_pool_nv: {
-                let value = loader(c"vkDestroySemaphoreSciSyncPoolNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_sci_sync2") {
+                    loader(c"vkDestroySemaphoreSciSyncPoolNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7227,7 +9068,11 @@ impl DeviceCommands {
                 }
             },
             destroy_shader_ext: {
-                let value = loader(c"vkDestroyShaderEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_shader_object") {
+                    loader(c"vkDestroyShaderEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7257,7 +9102,11 @@ impl DeviceCommands {
                 }
             },
             destroy_swapchain_khr: {
-                let value = loader(c"vkDestroySwapchainKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_swapchain") {
+                    loader(c"vkDestroySwapchainKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7272,7 +9121,11 @@ impl DeviceCommands {
                 }
             },
             destroy_tensor_arm: {
-                let value = loader(c"vkDestroyTensorARM".as_ptr());
+                let value = if enabled(c"VK_ARM_tensors") {
+                    loader(c"vkDestroyTensorARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7287,7 +9140,11 @@ impl DeviceCommands {
                 }
             },
             destroy_tensor_view_arm: {
-                let value = loader(c"vkDestroyTensorViewARM".as_ptr());
+                let value = if enabled(c"VK_ARM_tensors") {
+                    loader(c"vkDestroyTensorViewARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7302,7 +9159,11 @@ impl DeviceCommands {
                 }
             },
             destroy_validation_cache_ext: {
-                let value = loader(c"vkDestroyValidationCacheEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_validation_cache") {
+                    loader(c"vkDestroyValidationCacheEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7317,7 +9178,11 @@ impl DeviceCommands {
                 }
             },
             destroy_video_session_khr: {
-                let value = loader(c"vkDestroyVideoSessionKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_queue") {
+                    loader(c"vkDestroyVideoSessionKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7332,7 +9197,11 @@ impl DeviceCommands {
                 }
             },
             destroy_video_session_parameters_khr: {
-                let value = loader(c"vkDestroyVideoSessionParametersKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_queue") {
+                    loader(c"vkDestroyVideoSessionParametersKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7352,13 +9221,21 @@ impl DeviceCommands {
                     mem::transmute(value)
                 } else {
                     unsafe extern "system" fn fallback(_device: Device) -> Result {
-                        panic!("could not load vkDeviceWaitIdle")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkDeviceWaitIdle")
+                        }
                     }
                     fallback
                 }
             },
             display_power_control_ext: {
-                let value = loader(c"vkDisplayPowerControlEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_display_control") {
+                    loader(c"vkDisplayPowerControlEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7367,7 +9244,11 @@ impl DeviceCommands {
                         _display: DisplayKHR,
                         _display_power_info: *const DisplayPowerInfoEXT,
                     ) -> Result {
-                        panic!("could not load vkDisplayPowerControlEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkDisplayPowerControlEXT")
+                        }
                     }
                     fallback
                 }
@@ -7378,13 +9259,21 @@ impl DeviceCommands {
                     mem::transmute(value)
                 } else {
                     unsafe extern "system" fn fallback(_command_buffer: CommandBuffer) -> Result {
-                        panic!("could not load vkEndCommandBuffer")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkEndCommandBuffer")
+                        }
                     }
                     fallback
                 }
             },
             export_metal_objects_ext: {
-                let value = loader(c"vkExportMetalObjectsEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_metal_objects") {
+                    loader(c"vkExportMetalObjectsEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7407,7 +9296,11 @@ impl DeviceCommands {
                         _memory_range_count: u32,
                         _memory_ranges: *const MappedMemoryRange,
                     ) -> Result {
-                        panic!("could not load vkFlushMappedMemoryRanges")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkFlushMappedMemoryRanges")
+                        }
                     }
                     fallback
                 }
@@ -7439,7 +9332,11 @@ impl DeviceCommands {
                         _descriptor_set_count: u32,
                         _descriptor_sets: *const DescriptorSet,
                     ) -> Result {
-                        panic!("could not load vkFreeDescriptorSets")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkFreeDescriptorSets")
+                        }
                     }
                     fallback
                 }
@@ -7460,7 +9357,11 @@ impl DeviceCommands {
                 }
             },
             get_acceleration_structure_build_sizes_khr: {
-                let value = loader(c"vkGetAccelerationStructureBuildSizesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkGetAccelerationStructureBuildSizesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7477,7 +9378,11 @@ impl DeviceCommands {
                 }
             },
             get_acceleration_structure_device_address_khr: {
-                let value = loader(c"vkGetAccelerationStructureDeviceAddressKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkGetAccelerationStructureDeviceAddressKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7491,7 +9396,11 @@ impl DeviceCommands {
                 }
             },
             get_acceleration_structure_handle_nv: {
-                let value = loader(c"vkGetAccelerationStructureHandleNV".as_ptr());
+                let value = if enabled(c"VK_NV_ray_tracing") {
+                    loader(c"vkGetAccelerationStructureHandleNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7501,13 +9410,21 @@ impl DeviceCommands {
                         _data_size: usize,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetAccelerationStructureHandleNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetAccelerationStructureHandleNV")
+                        }
                     }
                     fallback
                 }
             },
             get_acceleration_structure_memory_requirements_nv: {
-                let value = loader(c"vkGetAccelerationStructureMemoryRequirementsNV".as_ptr());
+                let value = if enabled(c"VK_NV_ray_tracing") {
+                    loader(c"vkGetAccelerationStructureMemoryRequirementsNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7532,13 +9449,21 @@ impl DeviceCommands {
                         _info: *const AccelerationStructureCaptureDescriptorDataInfoEXT,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_android_hardware_buffer_properties_android: {
-                let value = loader(c"vkGetAndroidHardwareBufferPropertiesANDROID".as_ptr());
+                let value = if enabled(c"VK_ANDROID_external_memory_android_hardware_buffer") {
+                    loader(c"vkGetAndroidHardwareBufferPropertiesANDROID".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7547,13 +9472,21 @@ impl DeviceCommands {
                         _buffer: *const AHardwareBuffer,
                         _properties: *mut AndroidHardwareBufferPropertiesANDROID,
                     ) -> Result {
-                        panic!("could not load vkGetAndroidHardwareBufferPropertiesANDROID")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetAndroidHardwareBufferPropertiesANDROID")
+                        }
                     }
                     fallback
                 }
             },
             get_buffer_collection_properties_fuchsia: {
-                let value = loader(c"vkGetBufferCollectionPropertiesFUCHSIA".as_ptr());
+                let value = if enabled(c"VK_FUCHSIA_buffer_collection") {
+                    loader(c"vkGetBufferCollectionPropertiesFUCHSIA".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7562,13 +9495,21 @@ impl DeviceCommands {
                         _collection: BufferCollectionFUCHSIA,
                         _properties: *mut BufferCollectionPropertiesFUCHSIA,
                     ) -> Result {
-                        panic!("could not load vkGetBufferCollectionPropertiesFUCHSIA")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetBufferCollectionPropertiesFUCHSIA")
+                        }
                     }
                     fallback
                 }
             },
             get_buffer_device_address: {
-                let value = loader(c"vkGetBufferDeviceAddress".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkGetBufferDeviceAddress".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7582,7 +9523,11 @@ impl DeviceCommands {
                 }
             },
             get_buffer_device_address_ext: {
-                let value = loader(c"vkGetBufferDeviceAddressEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_buffer_device_address") {
+                    loader(c"vkGetBufferDeviceAddressEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7596,7 +9541,11 @@ impl DeviceCommands {
                 }
             },
             get_buffer_device_address_khr: {
-                let value = loader(c"vkGetBufferDeviceAddressKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_buffer_device_address") {
+                    loader(c"vkGetBufferDeviceAddressKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7625,7 +9574,11 @@ impl DeviceCommands {
                 }
             },
             get_buffer_memory_requirements2: {
-                let value = loader(c"vkGetBufferMemoryRequirements2".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkGetBufferMemoryRequirements2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7640,7 +9593,11 @@ impl DeviceCommands {
                 }
             },
             get_buffer_memory_requirements2_khr: {
-                let value = loader(c"vkGetBufferMemoryRequirements2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_get_memory_requirements2") {
+                    loader(c"vkGetBufferMemoryRequirements2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7655,7 +9612,11 @@ impl DeviceCommands {
                 }
             },
             get_buffer_opaque_capture_address: {
-                let value = loader(c"vkGetBufferOpaqueCaptureAddress".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkGetBufferOpaqueCaptureAddress".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7669,7 +9630,11 @@ impl DeviceCommands {
                 }
             },
             get_buffer_opaque_capture_address_khr: {
-                let value = loader(c"vkGetBufferOpaqueCaptureAddressKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_buffer_device_address") {
+                    loader(c"vkGetBufferOpaqueCaptureAddressKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7683,7 +9648,11 @@ impl DeviceCommands {
                 }
             },
             get_buffer_opaque_capture_descriptor_data_ext: {
-                let value = loader(c"vkGetBufferOpaqueCaptureDescriptorDataEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_buffer") {
+                    loader(c"vkGetBufferOpaqueCaptureDescriptorDataEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7692,13 +9661,21 @@ impl DeviceCommands {
                         _info: *const BufferCaptureDescriptorDataInfoEXT,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetBufferOpaqueCaptureDescriptorDataEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetBufferOpaqueCaptureDescriptorDataEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_calibrated_timestamps_ext: {
-                let value = loader(c"vkGetCalibratedTimestampsEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_calibrated_timestamps") {
+                    loader(c"vkGetCalibratedTimestampsEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7709,13 +9686,21 @@ impl DeviceCommands {
                         _timestamps: *mut u64,
                         _max_deviation: *mut u64,
                     ) -> Result {
-                        panic!("could not load vkGetCalibratedTimestampsEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetCalibratedTimestampsEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_calibrated_timestamps_khr: {
-                let value = loader(c"vkGetCalibratedTimestampsKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_calibrated_timestamps") {
+                    loader(c"vkGetCalibratedTimestampsKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7726,13 +9711,21 @@ impl DeviceCommands {
                         _timestamps: *mut u64,
                         _max_deviation: *mut u64,
                     ) -> Result {
-                        panic!("could not load vkGetCalibratedTimestampsKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetCalibratedTimestampsKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_cluster_acceleration_structure_build_sizes_nv: {
-                let value = loader(c"vkGetClusterAccelerationStructureBuildSizesNV".as_ptr());
+                let value = if enabled(c"VK_NV_cluster_acceleration_structure") {
+                    loader(c"vkGetClusterAccelerationStructureBuildSizesNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7747,7 +9740,11 @@ impl DeviceCommands {
                 }
             },
             get_cuda_module_cache_nv: {
-                let value = loader(c"vkGetCudaModuleCacheNV".as_ptr());
+                let value = if enabled(c"VK_NV_cuda_kernel_launch") {
+                    loader(c"vkGetCudaModuleCacheNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7757,13 +9754,21 @@ impl DeviceCommands {
                         _cache_size: *mut usize,
                         _cache_data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetCudaModuleCacheNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetCudaModuleCacheNV")
+                        }
                     }
                     fallback
                 }
             },
             get_data_graph_pipeline_available_properties_arm: {
-                let value = loader(c"vkGetDataGraphPipelineAvailablePropertiesARM".as_ptr());
+                let value = if enabled(c"VK_ARM_data_graph") {
+                    loader(c"vkGetDataGraphPipelineAvailablePropertiesARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7773,13 +9778,21 @@ impl DeviceCommands {
                         _properties_count: *mut u32,
                         _properties: *mut DataGraphPipelinePropertyARM,
                     ) -> Result {
-                        panic!("could not load vkGetDataGraphPipelineAvailablePropertiesARM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDataGraphPipelineAvailablePropertiesARM")
+                        }
                     }
                     fallback
                 }
             },
             get_data_graph_pipeline_properties_arm: {
-                let value = loader(c"vkGetDataGraphPipelinePropertiesARM".as_ptr());
+                let value = if enabled(c"VK_ARM_data_graph") {
+                    loader(c"vkGetDataGraphPipelinePropertiesARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7789,7 +9802,11 @@ impl DeviceCommands {
                         _properties_count: u32,
                         _properties: *mut DataGraphPipelinePropertyQueryResultARM,
                     ) -> Result {
-                        panic!("could not load vkGetDataGraphPipelinePropertiesARM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDataGraphPipelinePropertiesARM")
+                        }
                     }
                     fallback
                 }
@@ -7814,7 +9831,11 @@ impl DeviceCommands {
                 }
             },
             get_data_graph_pipeline_session_memory_requirements_arm: {
-                let value = loader(c"vkGetDataGraphPipelineSessionMemoryRequirementsARM".as_ptr());
+                let value = if enabled(c"VK_ARM_data_graph") {
+                    loader(c"vkGetDataGraphPipelineSessionMemoryRequirementsARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7829,7 +9850,11 @@ impl DeviceCommands {
                 }
             },
             get_deferred_operation_max_concurrency_khr: {
-                let value = loader(c"vkGetDeferredOperationMaxConcurrencyKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_deferred_host_operations") {
+                    loader(c"vkGetDeferredOperationMaxConcurrencyKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7843,7 +9868,11 @@ impl DeviceCommands {
                 }
             },
             get_deferred_operation_result_khr: {
-                let value = loader(c"vkGetDeferredOperationResultKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_deferred_host_operations") {
+                    loader(c"vkGetDeferredOperationResultKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7851,13 +9880,21 @@ impl DeviceCommands {
                         _device: Device,
                         _operation: DeferredOperationKHR,
                     ) -> Result {
-                        panic!("could not load vkGetDeferredOperationResultKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDeferredOperationResultKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_descriptor_ext: {
-                let value = loader(c"vkGetDescriptorEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_buffer") {
+                    loader(c"vkGetDescriptorEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7873,7 +9910,11 @@ impl DeviceCommands {
                 }
             },
             get_descriptor_set_host_mapping_valve: {
-                let value = loader(c"vkGetDescriptorSetHostMappingVALVE".as_ptr());
+                let value = if enabled(c"VK_VALVE_descriptor_set_host_mapping") {
+                    loader(c"vkGetDescriptorSetHostMappingVALVE".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7888,7 +9929,11 @@ impl DeviceCommands {
                 }
             },
             get_descriptor_set_layout_binding_offset_ext: {
-                let value = loader(c"vkGetDescriptorSetLayoutBindingOffsetEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_buffer") {
+                    loader(c"vkGetDescriptorSetLayoutBindingOffsetEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7904,7 +9949,11 @@ impl DeviceCommands {
                 }
             },
             get_descriptor_set_layout_host_mapping_info_valve: {
-                let value = loader(c"vkGetDescriptorSetLayoutHostMappingInfoVALVE".as_ptr());
+                let value = if enabled(c"VK_VALVE_descriptor_set_host_mapping") {
+                    loader(c"vkGetDescriptorSetLayoutHostMappingInfoVALVE".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7919,7 +9968,11 @@ impl DeviceCommands {
                 }
             },
             get_descriptor_set_layout_size_ext: {
-                let value = loader(c"vkGetDescriptorSetLayoutSizeEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_buffer") {
+                    loader(c"vkGetDescriptorSetLayoutSizeEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7934,7 +9987,11 @@ impl DeviceCommands {
                 }
             },
             get_descriptor_set_layout_support: {
-                let value = loader(c"vkGetDescriptorSetLayoutSupport".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkGetDescriptorSetLayoutSupport".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7949,7 +10006,11 @@ impl DeviceCommands {
                 }
             },
             get_descriptor_set_layout_support_khr: {
-                let value = loader(c"vkGetDescriptorSetLayoutSupportKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance3") {
+                    loader(c"vkGetDescriptorSetLayoutSupportKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7964,7 +10025,11 @@ impl DeviceCommands {
                 }
             },
             get_device_acceleration_structure_compatibility_khr: {
-                let value = loader(c"vkGetDeviceAccelerationStructureCompatibilityKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkGetDeviceAccelerationStructureCompatibilityKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7979,7 +10044,11 @@ impl DeviceCommands {
                 }
             },
             get_device_buffer_memory_requirements: {
-                let value = loader(c"vkGetDeviceBufferMemoryRequirements".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkGetDeviceBufferMemoryRequirements".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -7994,7 +10063,11 @@ impl DeviceCommands {
                 }
             },
             get_device_buffer_memory_requirements_khr: {
-                let value = loader(c"vkGetDeviceBufferMemoryRequirementsKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance4") {
+                    loader(c"vkGetDeviceBufferMemoryRequirementsKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8009,7 +10082,11 @@ impl DeviceCommands {
                 }
             },
             get_device_combined_image_sampler_index_nvx: {
-                let value = loader(c"vkGetDeviceCombinedImageSamplerIndexNVX".as_ptr());
+                let value = if enabled(c"VK_NVX_image_view_handle") {
+                    loader(c"vkGetDeviceCombinedImageSamplerIndexNVX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8024,7 +10101,11 @@ impl DeviceCommands {
                 }
             },
             get_device_fault_info_ext: {
-                let value = loader(c"vkGetDeviceFaultInfoEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_device_fault") {
+                    loader(c"vkGetDeviceFaultInfoEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8033,13 +10114,21 @@ impl DeviceCommands {
                         _fault_counts: *mut DeviceFaultCountsEXT,
                         _fault_info: *mut DeviceFaultInfoEXT,
                     ) -> Result {
-                        panic!("could not load vkGetDeviceFaultInfoEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDeviceFaultInfoEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_device_group_peer_memory_features: {
-                let value = loader(c"vkGetDeviceGroupPeerMemoryFeatures".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkGetDeviceGroupPeerMemoryFeatures".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8056,7 +10145,11 @@ impl DeviceCommands {
                 }
             },
             get_device_group_peer_memory_features_khr: {
-                let value = loader(c"vkGetDeviceGroupPeerMemoryFeaturesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_device_group") {
+                    loader(c"vkGetDeviceGroupPeerMemoryFeaturesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8073,7 +10166,11 @@ impl DeviceCommands {
                 }
             },
             get_device_group_present_capabilities_khr: {
-                let value = loader(c"vkGetDeviceGroupPresentCapabilitiesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_device_group") || enabled(c"VK_KHR_swapchain") {
+                    loader(c"vkGetDeviceGroupPresentCapabilitiesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8081,13 +10178,21 @@ impl DeviceCommands {
                         _device: Device,
                         _device_group_present_capabilities: *mut DeviceGroupPresentCapabilitiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetDeviceGroupPresentCapabilitiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDeviceGroupPresentCapabilitiesKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_device_group_surface_present_modes2_ext: {
-                let value = loader(c"vkGetDeviceGroupSurfacePresentModes2EXT".as_ptr());
+                let value = if enabled(c"VK_EXT_full_screen_exclusive") {
+                    loader(c"vkGetDeviceGroupSurfacePresentModes2EXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8096,13 +10201,21 @@ impl DeviceCommands {
                         _surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
                         _modes: *mut DeviceGroupPresentModeFlagsKHR,
                     ) -> Result {
-                        panic!("could not load vkGetDeviceGroupSurfacePresentModes2EXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDeviceGroupSurfacePresentModes2EXT")
+                        }
                     }
                     fallback
                 }
             },
             get_device_group_surface_present_modes_khr: {
-                let value = loader(c"vkGetDeviceGroupSurfacePresentModesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_device_group") || enabled(c"VK_KHR_swapchain") {
+                    loader(c"vkGetDeviceGroupSurfacePresentModesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8111,13 +10224,21 @@ impl DeviceCommands {
                         _surface: SurfaceKHR,
                         _modes: *mut DeviceGroupPresentModeFlagsKHR,
                     ) -> Result {
-                        panic!("could not load vkGetDeviceGroupSurfacePresentModesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDeviceGroupSurfacePresentModesKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_device_image_memory_requirements: {
-                let value = loader(c"vkGetDeviceImageMemoryRequirements".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkGetDeviceImageMemoryRequirements".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8132,7 +10253,11 @@ impl DeviceCommands {
                 }
             },
             get_device_image_memory_requirements_khr: {
-                let value = loader(c"vkGetDeviceImageMemoryRequirementsKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance4") {
+                    loader(c"vkGetDeviceImageMemoryRequirementsKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8147,7 +10272,11 @@ impl DeviceCommands {
                 }
             },
             get_device_image_sparse_memory_requirements: {
-                let value = loader(c"vkGetDeviceImageSparseMemoryRequirements".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkGetDeviceImageSparseMemoryRequirements".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8163,7 +10292,11 @@ impl DeviceCommands {
                 }
             },
             get_device_image_sparse_memory_requirements_khr: {
-                let value = loader(c"vkGetDeviceImageSparseMemoryRequirementsKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance4") {
+                    loader(c"vkGetDeviceImageSparseMemoryRequirementsKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8179,7 +10312,11 @@ impl DeviceCommands {
                 }
             },
             get_device_image_subresource_layout: {
-                let value = loader(c"vkGetDeviceImageSubresourceLayout".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkGetDeviceImageSubresourceLayout".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8194,7 +10331,11 @@ impl DeviceCommands {
                 }
             },
             get_device_image_subresource_layout_khr: {
-                let value = loader(c"vkGetDeviceImageSubresourceLayoutKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance5") {
+                    loader(c"vkGetDeviceImageSubresourceLayoutKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8224,7 +10365,11 @@ impl DeviceCommands {
                 }
             },
             get_device_memory_opaque_capture_address: {
-                let value = loader(c"vkGetDeviceMemoryOpaqueCaptureAddress".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkGetDeviceMemoryOpaqueCaptureAddress".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8238,7 +10383,11 @@ impl DeviceCommands {
                 }
             },
             get_device_memory_opaque_capture_address_khr: {
-                let value = loader(c"vkGetDeviceMemoryOpaqueCaptureAddressKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_buffer_device_address") {
+                    loader(c"vkGetDeviceMemoryOpaqueCaptureAddressKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8252,7 +10401,11 @@ impl DeviceCommands {
                 }
             },
             get_device_micromap_compatibility_ext: {
-                let value = loader(c"vkGetDeviceMicromapCompatibilityEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkGetDeviceMicromapCompatibilityEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8283,7 +10436,11 @@ impl DeviceCommands {
                 }
             },
             get_device_queue2: {
-                let value = loader(c"vkGetDeviceQueue2".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkGetDeviceQueue2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8298,7 +10455,11 @@ impl DeviceCommands {
                 }
             },
             get_device_subpass_shading_max_workgroup_size_huawei: {
-                let value = loader(c"vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI".as_ptr());
+                let value = if enabled(c"VK_HUAWEI_subpass_shading") {
+                    loader(c"vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8307,13 +10468,21 @@ impl DeviceCommands {
                         _renderpass: RenderPass,
                         _max_workgroup_size: *mut Extent2D,
                     ) -> Result {
-                        panic!("could not load vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI")
+                        }
                     }
                     fallback
                 }
             },
             get_device_tensor_memory_requirements_arm: {
-                let value = loader(c"vkGetDeviceTensorMemoryRequirementsARM".as_ptr());
+                let value = if enabled(c"VK_ARM_tensors") {
+                    loader(c"vkGetDeviceTensorMemoryRequirementsARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8328,7 +10497,11 @@ impl DeviceCommands {
                 }
             },
             get_dynamic_rendering_tile_properties_qcom: {
-                let value = loader(c"vkGetDynamicRenderingTilePropertiesQCOM".as_ptr());
+                let value = if enabled(c"VK_QCOM_tile_properties") {
+                    loader(c"vkGetDynamicRenderingTilePropertiesQCOM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8337,13 +10510,21 @@ impl DeviceCommands {
                         _rendering_info: *const RenderingInfo,
                         _properties: *mut TilePropertiesQCOM,
                     ) -> Result {
-                        panic!("could not load vkGetDynamicRenderingTilePropertiesQCOM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDynamicRenderingTilePropertiesQCOM")
+                        }
                     }
                     fallback
                 }
             },
             get_encoded_video_session_parameters_khr: {
-                let value = loader(c"vkGetEncodedVideoSessionParametersKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_encode_queue") {
+                    loader(c"vkGetEncodedVideoSessionParametersKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8354,7 +10535,11 @@ impl DeviceCommands {
                         _data_size: *mut usize,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetEncodedVideoSessionParametersKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetEncodedVideoSessionParametersKHR")
+                        }
                     }
                     fallback
                 }
@@ -8365,13 +10550,21 @@ impl DeviceCommands {
                     mem::transmute(value)
                 } else {
                     unsafe extern "system" fn fallback(_device: Device, _event: Event) -> Result {
-                        panic!("could not load vkGetEventStatus")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetEventStatus")
+                        }
                     }
                     fallback
                 }
             },
             get_execution_graph_pipeline_node_index_amdx: {
-                let value = loader(c"vkGetExecutionGraphPipelineNodeIndexAMDX".as_ptr());
+                let value = if enabled(c"VK_AMDX_shader_enqueue") {
+                    loader(c"vkGetExecutionGraphPipelineNodeIndexAMDX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8381,13 +10574,21 @@ impl DeviceCommands {
                         _node_info: *const PipelineShaderStageNodeCreateInfoAMDX,
                         _node_index: *mut u32,
                     ) -> Result {
-                        panic!("could not load vkGetExecutionGraphPipelineNodeIndexAMDX")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetExecutionGraphPipelineNodeIndexAMDX")
+                        }
                     }
                     fallback
                 }
             },
             get_execution_graph_pipeline_scratch_size_amdx: {
-                let value = loader(c"vkGetExecutionGraphPipelineScratchSizeAMDX".as_ptr());
+                let value = if enabled(c"VK_AMDX_shader_enqueue") {
+                    loader(c"vkGetExecutionGraphPipelineScratchSizeAMDX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8396,13 +10597,21 @@ impl DeviceCommands {
                         _execution_graph: Pipeline,
                         _size_info: *mut ExecutionGraphPipelineScratchSizeAMDX,
                     ) -> Result {
-                        panic!("could not load vkGetExecutionGraphPipelineScratchSizeAMDX")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetExecutionGraphPipelineScratchSizeAMDX")
+                        }
                     }
                     fallback
                 }
             },
             get_external_compute_queue_data_nv: {
-                let value = loader(c"vkGetExternalComputeQueueDataNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_compute_queue") {
+                    loader(c"vkGetExternalComputeQueueDataNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8417,7 +10626,11 @@ impl DeviceCommands {
                 }
             },
             get_fence_fd_khr: {
-                let value = loader(c"vkGetFenceFdKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_external_fence_fd") {
+                    loader(c"vkGetFenceFdKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8426,13 +10639,23 @@ impl DeviceCommands {
                         _get_fd_info: *const FenceGetFdInfoKHR,
                         _fd: *mut c_int,
                     ) -> Result {
-                        panic!("could not load vkGetFenceFdKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetFenceFdKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_fence_sci_sync_fence_nv: {
-                let value = loader(c"vkGetFenceSciSyncFenceNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_sci_sync")
+                    || enabled(c"VK_NV_external_sci_sync2")
+                {
+                    loader(c"vkGetFenceSciSyncFenceNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8441,13 +10664,23 @@ impl DeviceCommands {
                         _get_sci_sync_handle_info: *const FenceGetSciSyncInfoNV,
                         _handle: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetFenceSciSyncFenceNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetFenceSciSyncFenceNV")
+                        }
                     }
                     fallback
                 }
             },
             get_fence_sci_sync_obj_nv: {
-                let value = loader(c"vkGetFenceSciSyncObjNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_sci_sync")
+                    || enabled(c"VK_NV_external_sci_sync2")
+                {
+                    loader(c"vkGetFenceSciSyncObjNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8456,7 +10689,11 @@ impl DeviceCommands {
                         _get_sci_sync_handle_info: *const FenceGetSciSyncInfoNV,
                         _handle: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetFenceSciSyncObjNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetFenceSciSyncObjNV")
+                        }
                     }
                     fallback
                 }
@@ -8467,13 +10704,21 @@ impl DeviceCommands {
                     mem::transmute(value)
                 } else {
                     unsafe extern "system" fn fallback(_device: Device, _fence: Fence) -> Result {
-                        panic!("could not load vkGetFenceStatus")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetFenceStatus")
+                        }
                     }
                     fallback
                 }
             },
             get_fence_win32_handle_khr: {
-                let value = loader(c"vkGetFenceWin32HandleKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_external_fence_win32") {
+                    loader(c"vkGetFenceWin32HandleKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8482,13 +10727,21 @@ impl DeviceCommands {
                         _get_win32_handle_info: *const FenceGetWin32HandleInfoKHR,
                         _handle: *mut HANDLE,
                     ) -> Result {
-                        panic!("could not load vkGetFenceWin32HandleKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetFenceWin32HandleKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_framebuffer_tile_properties_qcom: {
-                let value = loader(c"vkGetFramebufferTilePropertiesQCOM".as_ptr());
+                let value = if enabled(c"VK_QCOM_tile_properties") {
+                    loader(c"vkGetFramebufferTilePropertiesQCOM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8498,13 +10751,21 @@ impl DeviceCommands {
                         _properties_count: *mut u32,
                         _properties: *mut TilePropertiesQCOM,
                     ) -> Result {
-                        panic!("could not load vkGetFramebufferTilePropertiesQCOM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetFramebufferTilePropertiesQCOM")
+                        }
                     }
                     fallback
                 }
             },
             get_generated_commands_memory_requirements_ext: {
-                let value = loader(c"vkGetGeneratedCommandsMemoryRequirementsEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_device_generated_commands") {
+                    loader(c"vkGetGeneratedCommandsMemoryRequirementsEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8519,7 +10780,11 @@ impl DeviceCommands {
                 }
             },
             get_generated_commands_memory_requirements_nv: {
-                let value = loader(c"vkGetGeneratedCommandsMemoryRequirementsNV".as_ptr());
+                let value = if enabled(c"VK_NV_device_generated_commands") {
+                    loader(c"vkGetGeneratedCommandsMemoryRequirementsNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8534,7 +10799,11 @@ impl DeviceCommands {
                 }
             },
             get_image_drm_format_modifier_properties_ext: {
-                let value = loader(c"vkGetImageDrmFormatModifierPropertiesEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_image_drm_format_modifier") {
+                    loader(c"vkGetImageDrmFormatModifierPropertiesEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8543,7 +10812,11 @@ impl DeviceCommands {
                         _image: Image,
                         _properties: *mut ImageDrmFormatModifierPropertiesEXT,
                     ) -> Result {
-                        panic!("could not load vkGetImageDrmFormatModifierPropertiesEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetImageDrmFormatModifierPropertiesEXT")
+                        }
                     }
                     fallback
                 }
@@ -8564,7 +10837,11 @@ impl DeviceCommands {
                 }
             },
             get_image_memory_requirements2: {
-                let value = loader(c"vkGetImageMemoryRequirements2".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkGetImageMemoryRequirements2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8579,7 +10856,11 @@ impl DeviceCommands {
                 }
             },
             get_image_memory_requirements2_khr: {
-                let value = loader(c"vkGetImageMemoryRequirements2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_get_memory_requirements2") {
+                    loader(c"vkGetImageMemoryRequirements2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8594,7 +10875,11 @@ impl DeviceCommands {
                 }
             },
             get_image_opaque_capture_data_ext: {
-                let value = loader(c"vkGetImageOpaqueCaptureDataEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_heap") {
+                    loader(c"vkGetImageOpaqueCaptureDataEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8604,13 +10889,21 @@ impl DeviceCommands {
                         _images: *const Image,
                         _datas: *mut HostAddressRangeEXT,
                     ) -> Result {
-                        panic!("could not load vkGetImageOpaqueCaptureDataEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetImageOpaqueCaptureDataEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_image_opaque_capture_descriptor_data_ext: {
-                let value = loader(c"vkGetImageOpaqueCaptureDescriptorDataEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_buffer") {
+                    loader(c"vkGetImageOpaqueCaptureDescriptorDataEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8619,7 +10912,11 @@ impl DeviceCommands {
                         _info: *const ImageCaptureDescriptorDataInfoEXT,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetImageOpaqueCaptureDescriptorDataEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetImageOpaqueCaptureDescriptorDataEXT")
+                        }
                     }
                     fallback
                 }
@@ -8641,7 +10938,11 @@ impl DeviceCommands {
                 }
             },
             get_image_sparse_memory_requirements2: {
-                let value = loader(c"vkGetImageSparseMemoryRequirements2".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkGetImageSparseMemoryRequirements2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8657,7 +10958,11 @@ impl DeviceCommands {
                 }
             },
             get_image_sparse_memory_requirements2_khr: {
-                let value = loader(c"vkGetImageSparseMemoryRequirements2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_get_memory_requirements2") {
+                    loader(c"vkGetImageSparseMemoryRequirements2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8689,7 +10994,11 @@ impl DeviceCommands {
                 }
             },
             get_image_subresource_layout2: {
-                let value = loader(c"vkGetImageSubresourceLayout2".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkGetImageSubresourceLayout2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8705,7 +11014,13 @@ impl DeviceCommands {
                 }
             },
             get_image_subresource_layout2_ext: {
-                let value = loader(c"vkGetImageSubresourceLayout2EXT".as_ptr());
+                let value = if enabled(c"VK_EXT_host_image_copy")
+                    || enabled(c"VK_EXT_image_compression_control")
+                {
+                    loader(c"vkGetImageSubresourceLayout2EXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8721,7 +11036,11 @@ impl DeviceCommands {
                 }
             },
             get_image_subresource_layout2_khr: {
-                let value = loader(c"vkGetImageSubresourceLayout2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance5") {
+                    loader(c"vkGetImageSubresourceLayout2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8737,7 +11056,11 @@ impl DeviceCommands {
                 }
             },
             get_image_view_address_nvx: {
-                let value = loader(c"vkGetImageViewAddressNVX".as_ptr());
+                let value = if enabled(c"VK_NVX_image_view_handle") {
+                    loader(c"vkGetImageViewAddressNVX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8746,13 +11069,21 @@ impl DeviceCommands {
                         _image_view: ImageView,
                         _properties: *mut ImageViewAddressPropertiesNVX,
                     ) -> Result {
-                        panic!("could not load vkGetImageViewAddressNVX")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetImageViewAddressNVX")
+                        }
                     }
                     fallback
                 }
             },
             get_image_view_handle64_nvx: {
-                let value = loader(c"vkGetImageViewHandle64NVX".as_ptr());
+                let value = if enabled(c"VK_NVX_image_view_handle") {
+                    loader(c"vkGetImageViewHandle64NVX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8766,7 +11097,11 @@ impl DeviceCommands {
                 }
             },
             get_image_view_handle_nvx: {
-                let value = loader(c"vkGetImageViewHandleNVX".as_ptr());
+                let value = if enabled(c"VK_NVX_image_view_handle") {
+                    loader(c"vkGetImageViewHandleNVX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8780,7 +11115,11 @@ impl DeviceCommands {
                 }
             },
             get_image_view_opaque_capture_descriptor_data_ext: {
-                let value = loader(c"vkGetImageViewOpaqueCaptureDescriptorDataEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_buffer") {
+                    loader(c"vkGetImageViewOpaqueCaptureDescriptorDataEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8789,13 +11128,21 @@ impl DeviceCommands {
                         _info: *const ImageViewCaptureDescriptorDataInfoEXT,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetImageViewOpaqueCaptureDescriptorDataEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetImageViewOpaqueCaptureDescriptorDataEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_latency_timings_nv: {
-                let value = loader(c"vkGetLatencyTimingsNV".as_ptr());
+                let value = if enabled(c"VK_NV_low_latency2") {
+                    loader(c"vkGetLatencyTimingsNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8810,7 +11157,11 @@ impl DeviceCommands {
                 }
             },
             get_memory_android_hardware_buffer_android: {
-                let value = loader(c"vkGetMemoryAndroidHardwareBufferANDROID".as_ptr());
+                let value = if enabled(c"VK_ANDROID_external_memory_android_hardware_buffer") {
+                    loader(c"vkGetMemoryAndroidHardwareBufferANDROID".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8819,13 +11170,21 @@ impl DeviceCommands {
                         _info: *const MemoryGetAndroidHardwareBufferInfoANDROID,
                         _buffer: *mut *mut AHardwareBuffer,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryAndroidHardwareBufferANDROID")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryAndroidHardwareBufferANDROID")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_fd_khr: {
-                let value = loader(c"vkGetMemoryFdKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_external_memory_fd") {
+                    loader(c"vkGetMemoryFdKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8834,13 +11193,21 @@ impl DeviceCommands {
                         _get_fd_info: *const MemoryGetFdInfoKHR,
                         _fd: *mut c_int,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryFdKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryFdKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_fd_properties_khr: {
-                let value = loader(c"vkGetMemoryFdPropertiesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_external_memory_fd") {
+                    loader(c"vkGetMemoryFdPropertiesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8850,13 +11217,21 @@ impl DeviceCommands {
                         _fd: c_int,
                         _memory_fd_properties: *mut MemoryFdPropertiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryFdPropertiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryFdPropertiesKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_host_pointer_properties_ext: {
-                let value = loader(c"vkGetMemoryHostPointerPropertiesEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_external_memory_host") {
+                    loader(c"vkGetMemoryHostPointerPropertiesEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8866,13 +11241,21 @@ impl DeviceCommands {
                         _host_pointer: *const c_void,
                         _memory_host_pointer_properties: *mut MemoryHostPointerPropertiesEXT,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryHostPointerPropertiesEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryHostPointerPropertiesEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_metal_handle_ext: {
-                let value = loader(c"vkGetMemoryMetalHandleEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_external_memory_metal") {
+                    loader(c"vkGetMemoryMetalHandleEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8881,13 +11264,21 @@ impl DeviceCommands {
                         _get_metal_handle_info: *const MemoryGetMetalHandleInfoEXT,
                         _handle: *mut *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryMetalHandleEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryMetalHandleEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_metal_handle_properties_ext: {
-                let value = loader(c"vkGetMemoryMetalHandlePropertiesEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_external_memory_metal") {
+                    loader(c"vkGetMemoryMetalHandlePropertiesEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8897,13 +11288,21 @@ impl DeviceCommands {
                         _handle: *const c_void,
                         _memory_metal_handle_properties: *mut MemoryMetalHandlePropertiesEXT,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryMetalHandlePropertiesEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryMetalHandlePropertiesEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_native_buffer_ohos: {
-                let value = loader(c"vkGetMemoryNativeBufferOHOS".as_ptr());
+                let value = if enabled(c"VK_OHOS_external_memory") {
+                    loader(c"vkGetMemoryNativeBufferOHOS".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8912,13 +11311,21 @@ impl DeviceCommands {
                         _info: *const MemoryGetNativeBufferInfoOHOS,
                         _buffer: *mut *mut OH_NativeBuffer,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryNativeBufferOHOS")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryNativeBufferOHOS")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_remote_address_nv: {
-                let value = loader(c"vkGetMemoryRemoteAddressNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_memory_rdma") {
+                    loader(c"vkGetMemoryRemoteAddressNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8927,13 +11334,21 @@ impl DeviceCommands {
                         _memory_get_remote_address_info: *const MemoryGetRemoteAddressInfoNV,
                         _address: *mut RemoteAddressNV,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryRemoteAddressNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryRemoteAddressNV")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_sci_buf_nv: {
-                let value = loader(c"vkGetMemorySciBufNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_memory_sci_buf") {
+                    loader(c"vkGetMemorySciBufNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8942,13 +11357,21 @@ impl DeviceCommands {
                         _get_sci_buf_info: *const MemoryGetSciBufInfoNV,
                         _handle: *mut NvSciBufObj,
                     ) -> Result {
-                        panic!("could not load vkGetMemorySciBufNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemorySciBufNV")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_win32_handle_khr: {
-                let value = loader(c"vkGetMemoryWin32HandleKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_external_memory_win32") {
+                    loader(c"vkGetMemoryWin32HandleKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8957,13 +11380,21 @@ impl DeviceCommands {
                         _get_win32_handle_info: *const MemoryGetWin32HandleInfoKHR,
                         _handle: *mut HANDLE,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryWin32HandleKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryWin32HandleKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_win32_handle_nv: {
-                let value = loader(c"vkGetMemoryWin32HandleNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_memory_win32") {
+                    loader(c"vkGetMemoryWin32HandleNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8973,13 +11404,21 @@ impl DeviceCommands {
                         _handle_type: ExternalMemoryHandleTypeFlagsNV,
                         _handle: *mut HANDLE,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryWin32HandleNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryWin32HandleNV")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_win32_handle_properties_khr: {
-                let value = loader(c"vkGetMemoryWin32HandlePropertiesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_external_memory_win32") {
+                    loader(c"vkGetMemoryWin32HandlePropertiesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -8989,13 +11428,21 @@ impl DeviceCommands {
                         _handle: HANDLE,
                         _memory_win32_handle_properties: *mut MemoryWin32HandlePropertiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryWin32HandlePropertiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryWin32HandlePropertiesKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_zircon_handle_fuchsia: {
-                let value = loader(c"vkGetMemoryZirconHandleFUCHSIA".as_ptr());
+                let value = if enabled(c"VK_FUCHSIA_external_memory") {
+                    loader(c"vkGetMemoryZirconHandleFUCHSIA".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9004,13 +11451,21 @@ impl DeviceCommands {
                         _get_zircon_handle_info: *const MemoryGetZirconHandleInfoFUCHSIA,
                         _zircon_handle: *mut zx_handle_t,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryZirconHandleFUCHSIA")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryZirconHandleFUCHSIA")
+                        }
                     }
                     fallback
                 }
             },
             get_memory_zircon_handle_properties_fuchsia: {
-                let value = loader(c"vkGetMemoryZirconHandlePropertiesFUCHSIA".as_ptr());
+                let value = if enabled(c"VK_FUCHSIA_external_memory") {
+                    loader(c"vkGetMemoryZirconHandlePropertiesFUCHSIA".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9020,13 +11475,21 @@ impl DeviceCommands {
                         _zircon_handle: zx_handle_t,
                         _memory_zircon_handle_properties: *mut MemoryZirconHandlePropertiesFUCHSIA,
                     ) -> Result {
-                        panic!("could not load vkGetMemoryZirconHandlePropertiesFUCHSIA")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetMemoryZirconHandlePropertiesFUCHSIA")
+                        }
                     }
                     fallback
                 }
             },
             get_micromap_build_sizes_ext: {
-                let value = loader(c"vkGetMicromapBuildSizesEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkGetMicromapBuildSizesEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9042,7 +11505,11 @@ impl DeviceCommands {
                 }
             },
             get_native_buffer_properties_ohos: {
-                let value = loader(c"vkGetNativeBufferPropertiesOHOS".as_ptr());
+                let value = if enabled(c"VK_OHOS_external_memory") {
+                    loader(c"vkGetNativeBufferPropertiesOHOS".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9051,13 +11518,21 @@ impl DeviceCommands {
                         _buffer: *const OH_NativeBuffer,
                         _properties: *mut NativeBufferPropertiesOHOS,
                     ) -> Result {
-                        panic!("could not load vkGetNativeBufferPropertiesOHOS")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetNativeBufferPropertiesOHOS")
+                        }
                     }
                     fallback
                 }
             },
             get_partitioned_acceleration_structures_build_sizes_nv: {
-                let value = loader(c"vkGetPartitionedAccelerationStructuresBuildSizesNV".as_ptr());
+                let value = if enabled(c"VK_NV_partitioned_acceleration_structure") {
+                    loader(c"vkGetPartitionedAccelerationStructuresBuildSizesNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9072,7 +11547,11 @@ impl DeviceCommands {
                 }
             },
             get_past_presentation_timing_ext: {
-                let value = loader(c"vkGetPastPresentationTimingEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_present_timing") {
+                    loader(c"vkGetPastPresentationTimingEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9081,13 +11560,21 @@ impl DeviceCommands {
                         _past_presentation_timing_info: *const PastPresentationTimingInfoEXT,
                         _past_presentation_timing_properties: *mut PastPresentationTimingPropertiesEXT,
                     ) -> Result {
-                        panic!("could not load vkGetPastPresentationTimingEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPastPresentationTimingEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_past_presentation_timing_google: {
-                let value = loader(c"vkGetPastPresentationTimingGOOGLE".as_ptr());
+                let value = if enabled(c"VK_GOOGLE_display_timing") {
+                    loader(c"vkGetPastPresentationTimingGOOGLE".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9097,13 +11584,21 @@ impl DeviceCommands {
                         _presentation_timing_count: *mut u32,
                         _presentation_timings: *mut PastPresentationTimingGOOGLE,
                     ) -> Result {
-                        panic!("could not load vkGetPastPresentationTimingGOOGLE")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPastPresentationTimingGOOGLE")
+                        }
                     }
                     fallback
                 }
             },
             get_performance_parameter_intel: {
-                let value = loader(c"vkGetPerformanceParameterINTEL".as_ptr());
+                let value = if enabled(c"VK_INTEL_performance_query") {
+                    loader(c"vkGetPerformanceParameterINTEL".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9112,13 +11607,21 @@ impl DeviceCommands {
                         _parameter: PerformanceParameterTypeINTEL,
                         _value: *mut PerformanceValueINTEL,
                     ) -> Result {
-                        panic!("could not load vkGetPerformanceParameterINTEL")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPerformanceParameterINTEL")
+                        }
                     }
                     fallback
                 }
             },
             get_pipeline_binary_data_khr: {
-                let value = loader(c"vkGetPipelineBinaryDataKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_pipeline_binary") {
+                    loader(c"vkGetPipelineBinaryDataKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9129,7 +11632,11 @@ impl DeviceCommands {
                         _pipeline_binary_data_size: *mut usize,
                         _pipeline_binary_data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetPipelineBinaryDataKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPipelineBinaryDataKHR")
+                        }
                     }
                     fallback
                 }
@@ -9145,13 +11652,21 @@ impl DeviceCommands {
                         _data_size: *mut usize,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetPipelineCacheData")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPipelineCacheData")
+                        }
                     }
                     fallback
                 }
             },
             get_pipeline_executable_internal_representations_khr: {
-                let value = loader(c"vkGetPipelineExecutableInternalRepresentationsKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_pipeline_executable_properties") {
+                    loader(c"vkGetPipelineExecutableInternalRepresentationsKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9161,13 +11676,23 @@ impl DeviceCommands {
                         _internal_representation_count: *mut u32,
                         _internal_representations: *mut PipelineExecutableInternalRepresentationKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPipelineExecutableInternalRepresentationsKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!(
+                                "could not load vkGetPipelineExecutableInternalRepresentationsKHR"
+                            )
+                        }
                     }
                     fallback
                 }
             },
             get_pipeline_executable_properties_khr: {
-                let value = loader(c"vkGetPipelineExecutablePropertiesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_pipeline_executable_properties") {
+                    loader(c"vkGetPipelineExecutablePropertiesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9177,13 +11702,21 @@ impl DeviceCommands {
                         _executable_count: *mut u32,
                         _properties: *mut PipelineExecutablePropertiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPipelineExecutablePropertiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPipelineExecutablePropertiesKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_pipeline_executable_statistics_khr: {
-                let value = loader(c"vkGetPipelineExecutableStatisticsKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_pipeline_executable_properties") {
+                    loader(c"vkGetPipelineExecutableStatisticsKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9193,13 +11726,21 @@ impl DeviceCommands {
                         _statistic_count: *mut u32,
                         _statistics: *mut PipelineExecutableStatisticKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPipelineExecutableStatisticsKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPipelineExecutableStatisticsKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_pipeline_indirect_device_address_nv: {
-                let value = loader(c"vkGetPipelineIndirectDeviceAddressNV".as_ptr());
+                let value = if enabled(c"VK_NV_device_generated_commands_compute") {
+                    loader(c"vkGetPipelineIndirectDeviceAddressNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9213,7 +11754,11 @@ impl DeviceCommands {
                 }
             },
             get_pipeline_indirect_memory_requirements_nv: {
-                let value = loader(c"vkGetPipelineIndirectMemoryRequirementsNV".as_ptr());
+                let value = if enabled(c"VK_NV_device_generated_commands_compute") {
+                    loader(c"vkGetPipelineIndirectMemoryRequirementsNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9228,7 +11773,11 @@ impl DeviceCommands {
                 }
             },
             get_pipeline_key_khr: {
-                let value = loader(c"vkGetPipelineKeyKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_pipeline_binary") {
+                    loader(c"vkGetPipelineKeyKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9237,13 +11786,21 @@ impl DeviceCommands {
                         _pipeline_create_info: *const PipelineCreateInfoKHR,
                         _pipeline_key: *mut PipelineBinaryKeyKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPipelineKeyKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPipelineKeyKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_pipeline_properties_ext: {
-                let value = loader(c"vkGetPipelinePropertiesEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_pipeline_properties") {
+                    loader(c"vkGetPipelinePropertiesEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9252,13 +11809,21 @@ impl DeviceCommands {
                         _pipeline_info: *const PipelineInfoEXT,
                         _pipeline_properties: *mut BaseOutStructure,
                     ) -> Result {
-                        panic!("could not load vkGetPipelinePropertiesEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPipelinePropertiesEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_private_data: {
-                let value = loader(c"vkGetPrivateData".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkGetPrivateData".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9275,7 +11840,11 @@ impl DeviceCommands {
                 }
             },
             get_private_data_ext: {
-                let value = loader(c"vkGetPrivateDataEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_private_data") {
+                    loader(c"vkGetPrivateDataEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9306,13 +11875,21 @@ impl DeviceCommands {
                         _stride: DeviceSize,
                         _flags: QueryResultFlags,
                     ) -> Result {
-                        panic!("could not load vkGetQueryPoolResults")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetQueryPoolResults")
+                        }
                     }
                     fallback
                 }
             },
             get_queue_checkpoint_data2_nv: {
-                let value = loader(c"vkGetQueueCheckpointData2NV".as_ptr());
+                let value = if enabled(c"VK_NV_device_diagnostic_checkpoints") {
+                    loader(c"vkGetQueueCheckpointData2NV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9327,7 +11904,11 @@ impl DeviceCommands {
                 }
             },
             get_queue_checkpoint_data_nv: {
-                let value = loader(c"vkGetQueueCheckpointDataNV".as_ptr());
+                let value = if enabled(c"VK_NV_device_diagnostic_checkpoints") {
+                    loader(c"vkGetQueueCheckpointDataNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9342,7 +11923,11 @@ impl DeviceCommands {
                 }
             },
             get_ray_tracing_capture_replay_shader_group_handles_khr: {
-                let value = loader(c"vkGetRayTracingCaptureReplayShaderGroupHandlesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_ray_tracing_pipeline") {
+                    loader(c"vkGetRayTracingCaptureReplayShaderGroupHandlesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9354,13 +11939,23 @@ impl DeviceCommands {
                         _data_size: usize,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetRayTracingCaptureReplayShaderGroupHandlesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!(
+                                "could not load vkGetRayTracingCaptureReplayShaderGroupHandlesKHR"
+                            )
+                        }
                     }
                     fallback
                 }
             },
             get_ray_tracing_shader_group_handles_khr: {
-                let value = loader(c"vkGetRayTracingShaderGroupHandlesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_ray_tracing_pipeline") {
+                    loader(c"vkGetRayTracingShaderGroupHandlesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9372,13 +11967,21 @@ impl DeviceCommands {
                         _data_size: usize,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetRayTracingShaderGroupHandlesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetRayTracingShaderGroupHandlesKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_ray_tracing_shader_group_handles_nv: {
-                let value = loader(c"vkGetRayTracingShaderGroupHandlesNV".as_ptr());
+                let value = if enabled(c"VK_NV_ray_tracing") {
+                    loader(c"vkGetRayTracingShaderGroupHandlesNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9390,13 +11993,21 @@ impl DeviceCommands {
                         _data_size: usize,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetRayTracingShaderGroupHandlesNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetRayTracingShaderGroupHandlesNV")
+                        }
                     }
                     fallback
                 }
             },
             get_ray_tracing_shader_group_stack_size_khr: {
-                let value = loader(c"vkGetRayTracingShaderGroupStackSizeKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_ray_tracing_pipeline") {
+                    loader(c"vkGetRayTracingShaderGroupStackSizeKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9412,7 +12023,11 @@ impl DeviceCommands {
                 }
             },
             get_refresh_cycle_duration_google: {
-                let value = loader(c"vkGetRefreshCycleDurationGOOGLE".as_ptr());
+                let value = if enabled(c"VK_GOOGLE_display_timing") {
+                    loader(c"vkGetRefreshCycleDurationGOOGLE".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9421,7 +12036,11 @@ impl DeviceCommands {
                         _swapchain: SwapchainKHR,
                         _display_timing_properties: *mut RefreshCycleDurationGOOGLE,
                     ) -> Result {
-                        panic!("could not load vkGetRefreshCycleDurationGOOGLE")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetRefreshCycleDurationGOOGLE")
+                        }
                     }
                     fallback
                 }
@@ -9442,7 +12061,11 @@ impl DeviceCommands {
                 }
             },
             get_rendering_area_granularity: {
-                let value = loader(c"vkGetRenderingAreaGranularity".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkGetRenderingAreaGranularity".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9457,7 +12080,11 @@ impl DeviceCommands {
                 }
             },
             get_rendering_area_granularity_khr: {
-                let value = loader(c"vkGetRenderingAreaGranularityKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance5") {
+                    loader(c"vkGetRenderingAreaGranularityKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9472,7 +12099,11 @@ impl DeviceCommands {
                 }
             },
             get_sampler_opaque_capture_descriptor_data_ext: {
-                let value = loader(c"vkGetSamplerOpaqueCaptureDescriptorDataEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_buffer") {
+                    loader(c"vkGetSamplerOpaqueCaptureDescriptorDataEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9481,13 +12112,21 @@ impl DeviceCommands {
                         _info: *const SamplerCaptureDescriptorDataInfoEXT,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetSamplerOpaqueCaptureDescriptorDataEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetSamplerOpaqueCaptureDescriptorDataEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_screen_buffer_properties_qnx: {
-                let value = loader(c"vkGetScreenBufferPropertiesQNX".as_ptr());
+                let value = if enabled(c"VK_QNX_external_memory_screen_buffer") {
+                    loader(c"vkGetScreenBufferPropertiesQNX".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9496,13 +12135,21 @@ impl DeviceCommands {
                         _buffer: *const _screen_buffer,
                         _properties: *mut ScreenBufferPropertiesQNX,
                     ) -> Result {
-                        panic!("could not load vkGetScreenBufferPropertiesQNX")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetScreenBufferPropertiesQNX")
+                        }
                     }
                     fallback
                 }
             },
             get_semaphore_counter_value: {
-                let value = loader(c"vkGetSemaphoreCounterValue".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkGetSemaphoreCounterValue".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9511,13 +12158,21 @@ impl DeviceCommands {
                         _semaphore: Semaphore,
                         _value: *mut u64,
                     ) -> Result {
-                        panic!("could not load vkGetSemaphoreCounterValue")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetSemaphoreCounterValue")
+                        }
                     }
                     fallback
                 }
             },
             get_semaphore_counter_value_khr: {
-                let value = loader(c"vkGetSemaphoreCounterValueKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_timeline_semaphore") {
+                    loader(c"vkGetSemaphoreCounterValueKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9526,13 +12181,21 @@ impl DeviceCommands {
                         _semaphore: Semaphore,
                         _value: *mut u64,
                     ) -> Result {
-                        panic!("could not load vkGetSemaphoreCounterValueKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetSemaphoreCounterValueKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_semaphore_fd_khr: {
-                let value = loader(c"vkGetSemaphoreFdKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_external_semaphore_fd") {
+                    loader(c"vkGetSemaphoreFdKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9541,13 +12204,21 @@ impl DeviceCommands {
                         _get_fd_info: *const SemaphoreGetFdInfoKHR,
                         _fd: *mut c_int,
                     ) -> Result {
-                        panic!("could not load vkGetSemaphoreFdKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetSemaphoreFdKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_semaphore_sci_sync_obj_nv: {
-                let value = loader(c"vkGetSemaphoreSciSyncObjNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_sci_sync") {
+                    loader(c"vkGetSemaphoreSciSyncObjNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9556,13 +12227,21 @@ impl DeviceCommands {
                         _get_sci_sync_info: *const SemaphoreGetSciSyncInfoNV,
                         _handle: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetSemaphoreSciSyncObjNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetSemaphoreSciSyncObjNV")
+                        }
                     }
                     fallback
                 }
             },
             get_semaphore_win32_handle_khr: {
-                let value = loader(c"vkGetSemaphoreWin32HandleKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_external_semaphore_win32") {
+                    loader(c"vkGetSemaphoreWin32HandleKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9571,13 +12250,21 @@ impl DeviceCommands {
                         _get_win32_handle_info: *const SemaphoreGetWin32HandleInfoKHR,
                         _handle: *mut HANDLE,
                     ) -> Result {
-                        panic!("could not load vkGetSemaphoreWin32HandleKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetSemaphoreWin32HandleKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_semaphore_zircon_handle_fuchsia: {
-                let value = loader(c"vkGetSemaphoreZirconHandleFUCHSIA".as_ptr());
+                let value = if enabled(c"VK_FUCHSIA_external_semaphore") {
+                    loader(c"vkGetSemaphoreZirconHandleFUCHSIA".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9586,13 +12273,21 @@ impl DeviceCommands {
                         _get_zircon_handle_info: *const SemaphoreGetZirconHandleInfoFUCHSIA,
                         _zircon_handle: *mut zx_handle_t,
                     ) -> Result {
-                        panic!("could not load vkGetSemaphoreZirconHandleFUCHSIA")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetSemaphoreZirconHandleFUCHSIA")
+                        }
                     }
                     fallback
                 }
             },
             get_shader_binary_data_ext: {
-                let value = loader(c"vkGetShaderBinaryDataEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_shader_object") {
+                    loader(c"vkGetShaderBinaryDataEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9602,13 +12297,21 @@ impl DeviceCommands {
                         _data_size: *mut usize,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetShaderBinaryDataEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetShaderBinaryDataEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_shader_info_amd: {
-                let value = loader(c"vkGetShaderInfoAMD".as_ptr());
+                let value = if enabled(c"VK_AMD_shader_info") {
+                    loader(c"vkGetShaderInfoAMD".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9620,13 +12323,21 @@ impl DeviceCommands {
                         _info_size: *mut usize,
                         _info: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetShaderInfoAMD")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetShaderInfoAMD")
+                        }
                     }
                     fallback
                 }
             },
             get_shader_module_create_info_identifier_ext: {
-                let value = loader(c"vkGetShaderModuleCreateInfoIdentifierEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_shader_module_identifier") {
+                    loader(c"vkGetShaderModuleCreateInfoIdentifierEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9641,7 +12352,11 @@ impl DeviceCommands {
                 }
             },
             get_shader_module_identifier_ext: {
-                let value = loader(c"vkGetShaderModuleIdentifierEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_shader_module_identifier") {
+                    loader(c"vkGetShaderModuleIdentifierEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9656,7 +12371,11 @@ impl DeviceCommands {
                 }
             },
             get_swapchain_counter_ext: {
-                let value = loader(c"vkGetSwapchainCounterEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_display_control") {
+                    loader(c"vkGetSwapchainCounterEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9666,13 +12385,21 @@ impl DeviceCommands {
                         _counter: SurfaceCounterFlagsEXT,
                         _counter_value: *mut u64,
                     ) -> Result {
-                        panic!("could not load vkGetSwapchainCounterEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetSwapchainCounterEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_swapchain_images_khr: {
-                let value = loader(c"vkGetSwapchainImagesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_swapchain") {
+                    loader(c"vkGetSwapchainImagesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9682,13 +12409,21 @@ impl DeviceCommands {
                         _swapchain_image_count: *mut u32,
                         _swapchain_images: *mut Image,
                     ) -> Result {
-                        panic!("could not load vkGetSwapchainImagesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetSwapchainImagesKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_swapchain_status_khr: {
-                let value = loader(c"vkGetSwapchainStatusKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_shared_presentable_image") {
+                    loader(c"vkGetSwapchainStatusKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9696,13 +12431,21 @@ impl DeviceCommands {
                         _device: Device,
                         _swapchain: SwapchainKHR,
                     ) -> Result {
-                        panic!("could not load vkGetSwapchainStatusKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetSwapchainStatusKHR")
+                        }
                     }
                     fallback
                 }
             },
             get_swapchain_time_domain_properties_ext: {
-                let value = loader(c"vkGetSwapchainTimeDomainPropertiesEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_present_timing") {
+                    loader(c"vkGetSwapchainTimeDomainPropertiesEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9712,13 +12455,21 @@ impl DeviceCommands {
                         _swapchain_time_domain_properties: *mut SwapchainTimeDomainPropertiesEXT,
                         _time_domains_counter: *mut u64,
                     ) -> Result {
-                        panic!("could not load vkGetSwapchainTimeDomainPropertiesEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetSwapchainTimeDomainPropertiesEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_swapchain_timing_properties_ext: {
-                let value = loader(c"vkGetSwapchainTimingPropertiesEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_present_timing") {
+                    loader(c"vkGetSwapchainTimingPropertiesEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9728,13 +12479,21 @@ impl DeviceCommands {
                         _swapchain_timing_properties: *mut SwapchainTimingPropertiesEXT,
                         _swapchain_timing_properties_counter: *mut u64,
                     ) -> Result {
-                        panic!("could not load vkGetSwapchainTimingPropertiesEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetSwapchainTimingPropertiesEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_tensor_memory_requirements_arm: {
-                let value = loader(c"vkGetTensorMemoryRequirementsARM".as_ptr());
+                let value = if enabled(c"VK_ARM_tensors") {
+                    loader(c"vkGetTensorMemoryRequirementsARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9749,7 +12508,11 @@ impl DeviceCommands {
                 }
             },
             get_tensor_opaque_capture_data_arm: {
-                let value = loader(c"vkGetTensorOpaqueCaptureDataARM".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_heap") {
+                    loader(c"vkGetTensorOpaqueCaptureDataARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9759,13 +12522,21 @@ impl DeviceCommands {
                         _tensors: *const TensorARM,
                         _datas: *mut HostAddressRangeEXT,
                     ) -> Result {
-                        panic!("could not load vkGetTensorOpaqueCaptureDataARM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetTensorOpaqueCaptureDataARM")
+                        }
                     }
                     fallback
                 }
             },
             get_tensor_opaque_capture_descriptor_data_arm: {
-                let value = loader(c"vkGetTensorOpaqueCaptureDescriptorDataARM".as_ptr());
+                let value = if enabled(c"VK_ARM_tensors") {
+                    loader(c"vkGetTensorOpaqueCaptureDescriptorDataARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9774,13 +12545,21 @@ impl DeviceCommands {
                         _info: *const TensorCaptureDescriptorDataInfoARM,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetTensorOpaqueCaptureDescriptorDataARM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetTensorOpaqueCaptureDescriptorDataARM")
+                        }
                     }
                     fallback
                 }
             },
             get_tensor_view_opaque_capture_descriptor_data_arm: {
-                let value = loader(c"vkGetTensorViewOpaqueCaptureDescriptorDataARM".as_ptr());
+                let value = if enabled(c"VK_ARM_tensors") {
+                    loader(c"vkGetTensorViewOpaqueCaptureDescriptorDataARM".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9789,13 +12568,21 @@ impl DeviceCommands {
                         _info: *const TensorViewCaptureDescriptorDataInfoARM,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetTensorViewOpaqueCaptureDescriptorDataARM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetTensorViewOpaqueCaptureDescriptorDataARM")
+                        }
                     }
                     fallback
                 }
             },
             get_validation_cache_data_ext: {
-                let value = loader(c"vkGetValidationCacheDataEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_validation_cache") {
+                    loader(c"vkGetValidationCacheDataEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9805,13 +12592,21 @@ impl DeviceCommands {
                         _data_size: *mut usize,
                         _data: *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkGetValidationCacheDataEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetValidationCacheDataEXT")
+                        }
                     }
                     fallback
                 }
             },
             get_video_session_memory_requirements_khr: {
-                let value = loader(c"vkGetVideoSessionMemoryRequirementsKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_queue") {
+                    loader(c"vkGetVideoSessionMemoryRequirementsKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9821,13 +12616,21 @@ impl DeviceCommands {
                         _memory_requirements_count: *mut u32,
                         _memory_requirements: *mut VideoSessionMemoryRequirementsKHR,
                     ) -> Result {
-                        panic!("could not load vkGetVideoSessionMemoryRequirementsKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetVideoSessionMemoryRequirementsKHR")
+                        }
                     }
                     fallback
                 }
             },
             import_fence_fd_khr: {
-                let value = loader(c"vkImportFenceFdKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_external_fence_fd") {
+                    loader(c"vkImportFenceFdKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9835,13 +12638,23 @@ impl DeviceCommands {
                         _device: Device,
                         _import_fence_fd_info: *const ImportFenceFdInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkImportFenceFdKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkImportFenceFdKHR")
+                        }
                     }
                     fallback
                 }
             },
             import_fence_sci_sync_fence_nv: {
-                let value = loader(c"vkImportFenceSciSyncFenceNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_sci_sync")
+                    || enabled(c"VK_NV_external_sci_sync2")
+                {
+                    loader(c"vkImportFenceSciSyncFenceNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9849,13 +12662,23 @@ impl DeviceCommands {
                         _device: Device,
                         _import_fence_sci_sync_info: *const ImportFenceSciSyncInfoNV,
                     ) -> Result {
-                        panic!("could not load vkImportFenceSciSyncFenceNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkImportFenceSciSyncFenceNV")
+                        }
                     }
                     fallback
                 }
             },
             import_fence_sci_sync_obj_nv: {
-                let value = loader(c"vkImportFenceSciSyncObjNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_sci_sync")
+                    || enabled(c"VK_NV_external_sci_sync2")
+                {
+                    loader(c"vkImportFenceSciSyncObjNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9863,13 +12686,21 @@ impl DeviceCommands {
                         _device: Device,
                         _import_fence_sci_sync_info: *const ImportFenceSciSyncInfoNV,
                     ) -> Result {
-                        panic!("could not load vkImportFenceSciSyncObjNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkImportFenceSciSyncObjNV")
+                        }
                     }
                     fallback
                 }
             },
             import_fence_win32_handle_khr: {
-                let value = loader(c"vkImportFenceWin32HandleKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_external_fence_win32") {
+                    loader(c"vkImportFenceWin32HandleKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9877,13 +12708,21 @@ impl DeviceCommands {
                         _device: Device,
                         _import_fence_win32_handle_info: *const ImportFenceWin32HandleInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkImportFenceWin32HandleKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkImportFenceWin32HandleKHR")
+                        }
                     }
                     fallback
                 }
             },
             import_semaphore_fd_khr: {
-                let value = loader(c"vkImportSemaphoreFdKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_external_semaphore_fd") {
+                    loader(c"vkImportSemaphoreFdKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9891,13 +12730,21 @@ impl DeviceCommands {
                         _device: Device,
                         _import_semaphore_fd_info: *const ImportSemaphoreFdInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkImportSemaphoreFdKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkImportSemaphoreFdKHR")
+                        }
                     }
                     fallback
                 }
             },
             import_semaphore_sci_sync_obj_nv: {
-                let value = loader(c"vkImportSemaphoreSciSyncObjNV".as_ptr());
+                let value = if enabled(c"VK_NV_external_sci_sync") {
+                    loader(c"vkImportSemaphoreSciSyncObjNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9905,13 +12752,21 @@ impl DeviceCommands {
                         _device: Device,
                         _import_semaphore_sci_sync_info: *const ImportSemaphoreSciSyncInfoNV,
                     ) -> Result {
-                        panic!("could not load vkImportSemaphoreSciSyncObjNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkImportSemaphoreSciSyncObjNV")
+                        }
                     }
                     fallback
                 }
             },
             import_semaphore_win32_handle_khr: {
-                let value = loader(c"vkImportSemaphoreWin32HandleKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_external_semaphore_win32") {
+                    loader(c"vkImportSemaphoreWin32HandleKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9919,13 +12774,21 @@ impl DeviceCommands {
                         _device: Device,
                         _import_semaphore_win32_handle_info: *const ImportSemaphoreWin32HandleInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkImportSemaphoreWin32HandleKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkImportSemaphoreWin32HandleKHR")
+                        }
                     }
                     fallback
                 }
             },
             import_semaphore_zircon_handle_fuchsia: {
-                let value = loader(c"vkImportSemaphoreZirconHandleFUCHSIA".as_ptr());
+                let value = if enabled(c"VK_FUCHSIA_external_semaphore") {
+                    loader(c"vkImportSemaphoreZirconHandleFUCHSIA".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9933,13 +12796,21 @@ impl DeviceCommands {
                         _device: Device,
                         _import_semaphore_zircon_handle_info: *const ImportSemaphoreZirconHandleInfoFUCHSIA,
                     ) -> Result {
-                        panic!("could not load vkImportSemaphoreZirconHandleFUCHSIA")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkImportSemaphoreZirconHandleFUCHSIA")
+                        }
                     }
                     fallback
                 }
             },
             initialize_performance_api_intel: {
-                let value = loader(c"vkInitializePerformanceApiINTEL".as_ptr());
+                let value = if enabled(c"VK_INTEL_performance_query") {
+                    loader(c"vkInitializePerformanceApiINTEL".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9947,7 +12818,11 @@ impl DeviceCommands {
                         _device: Device,
                         _initialize_info: *const InitializePerformanceApiInfoINTEL,
                     ) -> Result {
-                        panic!("could not load vkInitializePerformanceApiINTEL")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkInitializePerformanceApiINTEL")
+                        }
                     }
                     fallback
                 }
@@ -9962,13 +12837,21 @@ impl DeviceCommands {
                         _memory_range_count: u32,
                         _memory_ranges: *const MappedMemoryRange,
                     ) -> Result {
-                        panic!("could not load vkInvalidateMappedMemoryRanges")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkInvalidateMappedMemoryRanges")
+                        }
                     }
                     fallback
                 }
             },
             latency_sleep_nv: {
-                let value = loader(c"vkLatencySleepNV".as_ptr());
+                let value = if enabled(c"VK_NV_low_latency2") {
+                    loader(c"vkLatencySleepNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -9977,7 +12860,11 @@ impl DeviceCommands {
                         _swapchain: SwapchainKHR,
                         _sleep_info: *const LatencySleepInfoNV,
                     ) -> Result {
-                        panic!("could not load vkLatencySleepNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkLatencySleepNV")
+                        }
                     }
                     fallback
                 }
@@ -9995,13 +12882,21 @@ impl DeviceCommands {
                         _flags: MemoryMapFlags,
                         _data: *mut *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkMapMemory")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkMapMemory")
+                        }
                     }
                     fallback
                 }
             },
             map_memory2: {
-                let value = loader(c"vkMapMemory2".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkMapMemory2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10010,13 +12905,21 @@ impl DeviceCommands {
                         _memory_map_info: *const MemoryMapInfo,
                         _data: *mut *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkMapMemory2")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkMapMemory2")
+                        }
                     }
                     fallback
                 }
             },
             map_memory2_khr: {
-                let value = loader(c"vkMapMemory2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_map_memory2") {
+                    loader(c"vkMapMemory2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10025,7 +12928,11 @@ impl DeviceCommands {
                         _memory_map_info: *const MemoryMapInfo,
                         _data: *mut *mut c_void,
                     ) -> Result {
-                        panic!("could not load vkMapMemory2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkMapMemory2KHR")
+                        }
                     }
                     fallback
                 }
@@ -10041,13 +12948,21 @@ impl DeviceCommands {
                         _src_cache_count: u32,
                         _src_caches: *const PipelineCache,
                     ) -> Result {
-                        panic!("could not load vkMergePipelineCaches")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkMergePipelineCaches")
+                        }
                     }
                     fallback
                 }
             },
             merge_validation_caches_ext: {
-                let value = loader(c"vkMergeValidationCachesEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_validation_cache") {
+                    loader(c"vkMergeValidationCachesEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10057,7 +12972,11 @@ impl DeviceCommands {
                         _src_cache_count: u32,
                         _src_caches: *const ValidationCacheEXT,
                     ) -> Result {
-                        panic!("could not load vkMergeValidationCachesEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkMergeValidationCachesEXT")
+                        }
                     }
                     fallback
                 }
@@ -10073,13 +12992,21 @@ impl DeviceCommands {
                         _bind_info: *const BindSparseInfo,
                         _fence: Fence,
                     ) -> Result {
-                        panic!("could not load vkQueueBindSparse")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkQueueBindSparse")
+                        }
                     }
                     fallback
                 }
             },
             queue_notify_out_of_band_nv: {
-                let value = loader(c"vkQueueNotifyOutOfBandNV".as_ptr());
+                let value = if enabled(c"VK_NV_low_latency2") {
+                    loader(c"vkQueueNotifyOutOfBandNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10093,7 +13020,11 @@ impl DeviceCommands {
                 }
             },
             queue_present_khr: {
-                let value = loader(c"vkQueuePresentKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_swapchain") {
+                    loader(c"vkQueuePresentKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10101,13 +13032,21 @@ impl DeviceCommands {
                         _queue: Queue,
                         _present_info: *const PresentInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkQueuePresentKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkQueuePresentKHR")
+                        }
                     }
                     fallback
                 }
             },
             queue_set_performance_configuration_intel: {
-                let value = loader(c"vkQueueSetPerformanceConfigurationINTEL".as_ptr());
+                let value = if enabled(c"VK_INTEL_performance_query") {
+                    loader(c"vkQueueSetPerformanceConfigurationINTEL".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10115,7 +13054,11 @@ impl DeviceCommands {
                         _queue: Queue,
                         _configuration: PerformanceConfigurationINTEL,
                     ) -> Result {
-                        panic!("could not load vkQueueSetPerformanceConfigurationINTEL")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkQueueSetPerformanceConfigurationINTEL")
+                        }
                     }
                     fallback
                 }
@@ -10131,13 +13074,21 @@ impl DeviceCommands {
                         _submits: *const SubmitInfo,
                         _fence: Fence,
                     ) -> Result {
-                        panic!("could not load vkQueueSubmit")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkQueueSubmit")
+                        }
                     }
                     fallback
                 }
             },
             queue_submit2: {
-                let value = loader(c"vkQueueSubmit2".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkQueueSubmit2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10147,13 +13098,21 @@ impl DeviceCommands {
                         _submits: *const SubmitInfo2,
                         _fence: Fence,
                     ) -> Result {
-                        panic!("could not load vkQueueSubmit2")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkQueueSubmit2")
+                        }
                     }
                     fallback
                 }
             },
             queue_submit2_khr: {
-                let value = loader(c"vkQueueSubmit2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_synchronization2") {
+                    loader(c"vkQueueSubmit2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10163,7 +13122,11 @@ impl DeviceCommands {
                         _submits: *const SubmitInfo2,
                         _fence: Fence,
                     ) -> Result {
-                        panic!("could not load vkQueueSubmit2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkQueueSubmit2KHR")
+                        }
                     }
                     fallback
                 }
@@ -10174,13 +13137,21 @@ impl DeviceCommands {
                     mem::transmute(value)
                 } else {
                     unsafe extern "system" fn fallback(_queue: Queue) -> Result {
-                        panic!("could not load vkQueueWaitIdle")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkQueueWaitIdle")
+                        }
                     }
                     fallback
                 }
             },
             register_custom_border_color_ext: {
-                let value = loader(c"vkRegisterCustomBorderColorEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_heap") {
+                    loader(c"vkRegisterCustomBorderColorEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10190,13 +13161,21 @@ impl DeviceCommands {
                         _request_index: Bool32,
                         _index: *mut u32,
                     ) -> Result {
-                        panic!("could not load vkRegisterCustomBorderColorEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkRegisterCustomBorderColorEXT")
+                        }
                     }
                     fallback
                 }
             },
             register_device_event_ext: {
-                let value = loader(c"vkRegisterDeviceEventEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_display_control") {
+                    loader(c"vkRegisterDeviceEventEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10206,13 +13185,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _fence: *mut Fence,
                     ) -> Result {
-                        panic!("could not load vkRegisterDeviceEventEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkRegisterDeviceEventEXT")
+                        }
                     }
                     fallback
                 }
             },
             register_display_event_ext: {
-                let value = loader(c"vkRegisterDisplayEventEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_display_control") {
+                    loader(c"vkRegisterDisplayEventEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10223,13 +13210,21 @@ impl DeviceCommands {
                         _allocator: *const AllocationCallbacks,
                         _fence: *mut Fence,
                     ) -> Result {
-                        panic!("could not load vkRegisterDisplayEventEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkRegisterDisplayEventEXT")
+                        }
                     }
                     fallback
                 }
             },
             release_captured_pipeline_data_khr: {
-                let value = loader(c"vkReleaseCapturedPipelineDataKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_pipeline_binary") {
+                    loader(c"vkReleaseCapturedPipelineDataKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10238,13 +13233,21 @@ impl DeviceCommands {
                         _info: *const ReleaseCapturedPipelineDataInfoKHR,
                         _allocator: *const AllocationCallbacks,
                     ) -> Result {
-                        panic!("could not load vkReleaseCapturedPipelineDataKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkReleaseCapturedPipelineDataKHR")
+                        }
                     }
                     fallback
                 }
             },
             release_full_screen_exclusive_mode_ext: {
-                let value = loader(c"vkReleaseFullScreenExclusiveModeEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_full_screen_exclusive") {
+                    loader(c"vkReleaseFullScreenExclusiveModeEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10252,13 +13255,21 @@ impl DeviceCommands {
                         _device: Device,
                         _swapchain: SwapchainKHR,
                     ) -> Result {
-                        panic!("could not load vkReleaseFullScreenExclusiveModeEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkReleaseFullScreenExclusiveModeEXT")
+                        }
                     }
                     fallback
                 }
             },
             release_performance_configuration_intel: {
-                let value = loader(c"vkReleasePerformanceConfigurationINTEL".as_ptr());
+                let value = if enabled(c"VK_INTEL_performance_query") {
+                    loader(c"vkReleasePerformanceConfigurationINTEL".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10266,13 +13277,21 @@ impl DeviceCommands {
                         _device: Device,
                         _configuration: PerformanceConfigurationINTEL,
                     ) -> Result {
-                        panic!("could not load vkReleasePerformanceConfigurationINTEL")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkReleasePerformanceConfigurationINTEL")
+                        }
                     }
                     fallback
                 }
             },
             release_profiling_lock_khr: {
-                let value = loader(c"vkReleaseProfilingLockKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_performance_query") {
+                    loader(c"vkReleaseProfilingLockKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10283,7 +13302,11 @@ impl DeviceCommands {
                 }
             },
             release_swapchain_images_ext: {
-                let value = loader(c"vkReleaseSwapchainImagesEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_swapchain_maintenance1") {
+                    loader(c"vkReleaseSwapchainImagesEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10291,13 +13314,21 @@ impl DeviceCommands {
                         _device: Device,
                         _release_info: *const ReleaseSwapchainImagesInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkReleaseSwapchainImagesEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkReleaseSwapchainImagesEXT")
+                        }
                     }
                     fallback
                 }
             },
             release_swapchain_images_khr: {
-                let value = loader(c"vkReleaseSwapchainImagesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_swapchain_maintenance1") {
+                    loader(c"vkReleaseSwapchainImagesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10305,7 +13336,11 @@ impl DeviceCommands {
                         _device: Device,
                         _release_info: *const ReleaseSwapchainImagesInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkReleaseSwapchainImagesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkReleaseSwapchainImagesKHR")
+                        }
                     }
                     fallback
                 }
@@ -10319,7 +13354,11 @@ impl DeviceCommands {
                         _command_buffer: CommandBuffer,
                         _flags: CommandBufferResetFlags,
                     ) -> Result {
-                        panic!("could not load vkResetCommandBuffer")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkResetCommandBuffer")
+                        }
                     }
                     fallback
                 }
@@ -10334,7 +13373,11 @@ impl DeviceCommands {
                         _command_pool: CommandPool,
                         _flags: CommandPoolResetFlags,
                     ) -> Result {
-                        panic!("could not load vkResetCommandPool")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkResetCommandPool")
+                        }
                     }
                     fallback
                 }
@@ -10349,7 +13392,11 @@ impl DeviceCommands {
                         _descriptor_pool: DescriptorPool,
                         _flags: DescriptorPoolResetFlags,
                     ) -> Result {
-                        panic!("could not load vkResetDescriptorPool")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkResetDescriptorPool")
+                        }
                     }
                     fallback
                 }
@@ -10360,7 +13407,11 @@ impl DeviceCommands {
                     mem::transmute(value)
                 } else {
                     unsafe extern "system" fn fallback(_device: Device, _event: Event) -> Result {
-                        panic!("could not load vkResetEvent")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkResetEvent")
+                        }
                     }
                     fallback
                 }
@@ -10375,13 +13426,21 @@ impl DeviceCommands {
                         _fence_count: u32,
                         _fences: *const Fence,
                     ) -> Result {
-                        panic!("could not load vkResetFences")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkResetFences")
+                        }
                     }
                     fallback
                 }
             },
             reset_query_pool: {
-                let value = loader(c"vkResetQueryPool".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkResetQueryPool".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10397,7 +13456,11 @@ impl DeviceCommands {
                 }
             },
             reset_query_pool_ext: {
-                let value = loader(c"vkResetQueryPoolEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_host_query_reset") {
+                    loader(c"vkResetQueryPoolEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10413,7 +13476,11 @@ impl DeviceCommands {
                 }
             },
             set_buffer_collection_buffer_constraints_fuchsia: {
-                let value = loader(c"vkSetBufferCollectionBufferConstraintsFUCHSIA".as_ptr());
+                let value = if enabled(c"VK_FUCHSIA_buffer_collection") {
+                    loader(c"vkSetBufferCollectionBufferConstraintsFUCHSIA".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10422,13 +13489,21 @@ impl DeviceCommands {
                         _collection: BufferCollectionFUCHSIA,
                         _buffer_constraints_info: *const BufferConstraintsInfoFUCHSIA,
                     ) -> Result {
-                        panic!("could not load vkSetBufferCollectionBufferConstraintsFUCHSIA")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkSetBufferCollectionBufferConstraintsFUCHSIA")
+                        }
                     }
                     fallback
                 }
             },
             set_buffer_collection_image_constraints_fuchsia: {
-                let value = loader(c"vkSetBufferCollectionImageConstraintsFUCHSIA".as_ptr());
+                let value = if enabled(c"VK_FUCHSIA_buffer_collection") {
+                    loader(c"vkSetBufferCollectionImageConstraintsFUCHSIA".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10437,13 +13512,21 @@ impl DeviceCommands {
                         _collection: BufferCollectionFUCHSIA,
                         _image_constraints_info: *const ImageConstraintsInfoFUCHSIA,
                     ) -> Result {
-                        panic!("could not load vkSetBufferCollectionImageConstraintsFUCHSIA")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkSetBufferCollectionImageConstraintsFUCHSIA")
+                        }
                     }
                     fallback
                 }
             },
             set_device_memory_priority_ext: {
-                let value = loader(c"vkSetDeviceMemoryPriorityEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_pageable_device_local_memory") {
+                    loader(c"vkSetDeviceMemoryPriorityEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10463,13 +13546,21 @@ impl DeviceCommands {
                     mem::transmute(value)
                 } else {
                     unsafe extern "system" fn fallback(_device: Device, _event: Event) -> Result {
-                        panic!("could not load vkSetEvent")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkSetEvent")
+                        }
                     }
                     fallback
                 }
             },
             set_hdr_metadata_ext: {
-                let value = loader(c"vkSetHdrMetadataEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_hdr_metadata") {
+                    loader(c"vkSetHdrMetadataEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10485,7 +13576,11 @@ impl DeviceCommands {
                 }
             },
             set_latency_marker_nv: {
-                let value = loader(c"vkSetLatencyMarkerNV".as_ptr());
+                let value = if enabled(c"VK_NV_low_latency2") {
+                    loader(c"vkSetLatencyMarkerNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10500,7 +13595,11 @@ impl DeviceCommands {
                 }
             },
             set_latency_sleep_mode_nv: {
-                let value = loader(c"vkSetLatencySleepModeNV".as_ptr());
+                let value = if enabled(c"VK_NV_low_latency2") {
+                    loader(c"vkSetLatencySleepModeNV".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10509,13 +13608,21 @@ impl DeviceCommands {
                         _swapchain: SwapchainKHR,
                         _sleep_mode_info: *const LatencySleepModeInfoNV,
                     ) -> Result {
-                        panic!("could not load vkSetLatencySleepModeNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkSetLatencySleepModeNV")
+                        }
                     }
                     fallback
                 }
             },
             set_local_dimming_amd: {
-                let value = loader(c"vkSetLocalDimmingAMD".as_ptr());
+                let value = if enabled(c"VK_AMD_display_native_hdr") {
+                    loader(c"vkSetLocalDimmingAMD".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10530,7 +13637,11 @@ impl DeviceCommands {
                 }
             },
             set_private_data: {
-                let value = loader(c"vkSetPrivateData".as_ptr());
+                let value = if version >= make_version(1, 3, 0) {
+                    loader(c"vkSetPrivateData".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10541,13 +13652,21 @@ impl DeviceCommands {
                         _private_data_slot: PrivateDataSlot,
                         _data: u64,
                     ) -> Result {
-                        panic!("could not load vkSetPrivateData")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkSetPrivateData")
+                        }
                     }
                     fallback
                 }
             },
             set_private_data_ext: {
-                let value = loader(c"vkSetPrivateDataEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_private_data") {
+                    loader(c"vkSetPrivateDataEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10558,13 +13677,21 @@ impl DeviceCommands {
                         _private_data_slot: PrivateDataSlot,
                         _data: u64,
                     ) -> Result {
-                        panic!("could not load vkSetPrivateDataEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkSetPrivateDataEXT")
+                        }
                     }
                     fallback
                 }
             },
             set_swapchain_present_timing_queue_size_ext: {
-                let value = loader(c"vkSetSwapchainPresentTimingQueueSizeEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_present_timing") {
+                    loader(c"vkSetSwapchainPresentTimingQueueSizeEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10573,13 +13700,21 @@ impl DeviceCommands {
                         _swapchain: SwapchainKHR,
                         _size: u32,
                     ) -> Result {
-                        panic!("could not load vkSetSwapchainPresentTimingQueueSizeEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkSetSwapchainPresentTimingQueueSizeEXT")
+                        }
                     }
                     fallback
                 }
             },
             signal_semaphore: {
-                let value = loader(c"vkSignalSemaphore".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkSignalSemaphore".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10587,13 +13722,21 @@ impl DeviceCommands {
                         _device: Device,
                         _signal_info: *const SemaphoreSignalInfo,
                     ) -> Result {
-                        panic!("could not load vkSignalSemaphore")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkSignalSemaphore")
+                        }
                     }
                     fallback
                 }
             },
             signal_semaphore_khr: {
-                let value = loader(c"vkSignalSemaphoreKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_timeline_semaphore") {
+                    loader(c"vkSignalSemaphoreKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10601,13 +13744,21 @@ impl DeviceCommands {
                         _device: Device,
                         _signal_info: *const SemaphoreSignalInfo,
                     ) -> Result {
-                        panic!("could not load vkSignalSemaphoreKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkSignalSemaphoreKHR")
+                        }
                     }
                     fallback
                 }
             },
             transition_image_layout: {
-                let value = loader(c"vkTransitionImageLayout".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkTransitionImageLayout".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10616,13 +13767,21 @@ impl DeviceCommands {
                         _transition_count: u32,
                         _transitions: *const HostImageLayoutTransitionInfo,
                     ) -> Result {
-                        panic!("could not load vkTransitionImageLayout")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkTransitionImageLayout")
+                        }
                     }
                     fallback
                 }
             },
             transition_image_layout_ext: {
-                let value = loader(c"vkTransitionImageLayoutEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_host_image_copy") {
+                    loader(c"vkTransitionImageLayoutEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10631,13 +13790,21 @@ impl DeviceCommands {
                         _transition_count: u32,
                         _transitions: *const HostImageLayoutTransitionInfo,
                     ) -> Result {
-                        panic!("could not load vkTransitionImageLayoutEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkTransitionImageLayoutEXT")
+                        }
                     }
                     fallback
                 }
             },
             trim_command_pool: {
-                let value = loader(c"vkTrimCommandPool".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkTrimCommandPool".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10652,7 +13819,11 @@ impl DeviceCommands {
                 }
             },
             trim_command_pool_khr: {
-                let value = loader(c"vkTrimCommandPoolKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_maintenance1") {
+                    loader(c"vkTrimCommandPoolKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10667,7 +13838,11 @@ impl DeviceCommands {
                 }
             },
             uninitialize_performance_api_intel: {
-                let value = loader(c"vkUninitializePerformanceApiINTEL".as_ptr());
+                let value = if enabled(c"VK_INTEL_performance_query") {
+                    loader(c"vkUninitializePerformanceApiINTEL".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10689,7 +13864,11 @@ impl DeviceCommands {
                 }
             },
             unmap_memory2: {
-                let value = loader(c"vkUnmapMemory2".as_ptr());
+                let value = if version >= make_version(1, 4, 0) {
+                    loader(c"vkUnmapMemory2".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10697,13 +13876,21 @@ impl DeviceCommands {
                         _device: Device,
                         _memory_unmap_info: *const MemoryUnmapInfo,
                     ) -> Result {
-                        panic!("could not load vkUnmapMemory2")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkUnmapMemory2")
+                        }
                     }
                     fallback
                 }
             },
             unmap_memory2_khr: {
-                let value = loader(c"vkUnmapMemory2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_map_memory2") {
+                    loader(c"vkUnmapMemory2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10711,13 +13898,21 @@ impl DeviceCommands {
                         _device: Device,
                         _memory_unmap_info: *const MemoryUnmapInfo,
                     ) -> Result {
-                        panic!("could not load vkUnmapMemory2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkUnmapMemory2KHR")
+                        }
                     }
                     fallback
                 }
             },
             unregister_custom_border_color_ext: {
-                let value = loader(c"vkUnregisterCustomBorderColorEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_heap") {
+                    loader(c"vkUnregisterCustomBorderColorEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10728,7 +13923,11 @@ impl DeviceCommands {
                 }
             },
             update_descriptor_set_with_template: {
-                let value = loader(c"vkUpdateDescriptorSetWithTemplate".as_ptr());
+                let value = if version >= make_version(1, 1, 0) {
+                    loader(c"vkUpdateDescriptorSetWithTemplate".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10744,7 +13943,11 @@ impl DeviceCommands {
                 }
             },
             update_descriptor_set_with_template_khr: {
-                let value = loader(c"vkUpdateDescriptorSetWithTemplateKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_descriptor_update_template") {
+                    loader(c"vkUpdateDescriptorSetWithTemplateKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10777,7 +13980,11 @@ impl DeviceCommands {
                 }
             },
             update_indirect_execution_set_pipeline_ext: {
-                let value = loader(c"vkUpdateIndirectExecutionSetPipelineEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_device_generated_commands") {
+                    loader(c"vkUpdateIndirectExecutionSetPipelineEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10793,7 +14000,11 @@ impl DeviceCommands {
                 }
             },
             update_indirect_execution_set_shader_ext: {
-                let value = loader(c"vkUpdateIndirectExecutionSetShaderEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_device_generated_commands") {
+                    loader(c"vkUpdateIndirectExecutionSetShaderEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10809,7 +14020,11 @@ impl DeviceCommands {
                 }
             },
             update_video_session_parameters_khr: {
-                let value = loader(c"vkUpdateVideoSessionParametersKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_video_queue") {
+                    loader(c"vkUpdateVideoSessionParametersKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10818,7 +14033,11 @@ impl DeviceCommands {
                         _video_session_parameters: VideoSessionParametersKHR,
                         _update_info: *const VideoSessionParametersUpdateInfoKHR,
                     ) -> Result {
-                        panic!("could not load vkUpdateVideoSessionParametersKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkUpdateVideoSessionParametersKHR")
+                        }
                     }
                     fallback
                 }
@@ -10835,13 +14054,21 @@ impl DeviceCommands {
                         _wait_all: Bool32,
                         _timeout: u64,
                     ) -> Result {
-                        panic!("could not load vkWaitForFences")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkWaitForFences")
+                        }
                     }
                     fallback
                 }
             },
             wait_for_present2_khr: {
-                let value = loader(c"vkWaitForPresent2KHR".as_ptr());
+                let value = if enabled(c"VK_KHR_present_wait2") {
+                    loader(c"vkWaitForPresent2KHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10850,13 +14077,21 @@ impl DeviceCommands {
                         _swapchain: SwapchainKHR,
                         _present_wait2_info: *const PresentWait2InfoKHR,
                     ) -> Result {
-                        panic!("could not load vkWaitForPresent2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkWaitForPresent2KHR")
+                        }
                     }
                     fallback
                 }
             },
             wait_for_present_khr: {
-                let value = loader(c"vkWaitForPresentKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_present_wait") {
+                    loader(c"vkWaitForPresentKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10866,13 +14101,21 @@ impl DeviceCommands {
                         _present_id: u64,
                         _timeout: u64,
                     ) -> Result {
-                        panic!("could not load vkWaitForPresentKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkWaitForPresentKHR")
+                        }
                     }
                     fallback
                 }
             },
             wait_semaphores: {
-                let value = loader(c"vkWaitSemaphores".as_ptr());
+                let value = if version >= make_version(1, 2, 0) {
+                    loader(c"vkWaitSemaphores".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10881,13 +14124,21 @@ impl DeviceCommands {
                         _wait_info: *const SemaphoreWaitInfo,
                         _timeout: u64,
                     ) -> Result {
-                        panic!("could not load vkWaitSemaphores")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkWaitSemaphores")
+                        }
                     }
                     fallback
                 }
             },
             wait_semaphores_khr: {
-                let value = loader(c"vkWaitSemaphoresKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_timeline_semaphore") {
+                    loader(c"vkWaitSemaphoresKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10896,13 +14147,21 @@ impl DeviceCommands {
                         _wait_info: *const SemaphoreWaitInfo,
                         _timeout: u64,
                     ) -> Result {
-                        panic!("could not load vkWaitSemaphoresKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkWaitSemaphoresKHR")
+                        }
                     }
                     fallback
                 }
             },
             write_acceleration_structures_properties_khr: {
-                let value = loader(c"vkWriteAccelerationStructuresPropertiesKHR".as_ptr());
+                let value = if enabled(c"VK_KHR_acceleration_structure") {
+                    loader(c"vkWriteAccelerationStructuresPropertiesKHR".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10915,13 +14174,21 @@ impl DeviceCommands {
                         _data: *mut c_void,
                         _stride: usize,
                     ) -> Result {
-                        panic!("could not load vkWriteAccelerationStructuresPropertiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkWriteAccelerationStructuresPropertiesKHR")
+                        }
                     }
                     fallback
                 }
             },
             write_micromaps_properties_ext: {
-                let value = loader(c"vkWriteMicromapsPropertiesEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_opacity_micromap") {
+                    loader(c"vkWriteMicromapsPropertiesEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10934,13 +14201,21 @@ impl DeviceCommands {
                         _data: *mut c_void,
                         _stride: usize,
                     ) -> Result {
-                        panic!("could not load vkWriteMicromapsPropertiesEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkWriteMicromapsPropertiesEXT")
+                        }
                     }
                     fallback
                 }
             },
             write_resource_descriptors_ext: {
-                let value = loader(c"vkWriteResourceDescriptorsEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_heap") {
+                    loader(c"vkWriteResourceDescriptorsEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10950,13 +14225,21 @@ impl DeviceCommands {
                         _resources: *const ResourceDescriptorInfoEXT,
                         _descriptors: *const HostAddressRangeEXT,
                     ) -> Result {
-                        panic!("could not load vkWriteResourceDescriptorsEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkWriteResourceDescriptorsEXT")
+                        }
                     }
                     fallback
                 }
             },
             write_sampler_descriptors_ext: {
-                let value = loader(c"vkWriteSamplerDescriptorsEXT".as_ptr());
+                let value = if enabled(c"VK_EXT_descriptor_heap") {
+                    loader(c"vkWriteSamplerDescriptorsEXT".as_ptr())
+                } else {
+                    None
+                };
                 if let Some(value) = value {
                     mem::transmute(value)
                 } else {
@@ -10966,7 +14249,11 @@ impl DeviceCommands {
                         _samplers: *const SamplerCreateInfo,
                         _descriptors: *const HostAddressRangeEXT,
                     ) -> Result {
-                        panic!("could not load vkWriteSamplerDescriptorsEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkWriteSamplerDescriptorsEXT")
+                        }
                     }
                     fallback
                 }
@@ -11000,7 +14287,11 @@ impl EntryCommands {
                         _allocator: *const AllocationCallbacks,
                         _instance: *mut Instance,
                     ) -> Result {
-                        panic!("could not load vkCreateInstance")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateInstance")
+                        }
                     }
                     fallback
                 }
@@ -11015,7 +14306,11 @@ impl EntryCommands {
                         _property_count: *mut u32,
                         _properties: *mut ExtensionProperties,
                     ) -> Result {
-                        panic!("could not load vkEnumerateInstanceExtensionProperties")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkEnumerateInstanceExtensionProperties")
+                        }
                     }
                     fallback
                 }
@@ -11029,7 +14324,11 @@ impl EntryCommands {
                         _property_count: *mut u32,
                         _properties: *mut LayerProperties,
                     ) -> Result {
-                        panic!("could not load vkEnumerateInstanceLayerProperties")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkEnumerateInstanceLayerProperties")
+                        }
                     }
                     fallback
                 }
@@ -11040,7 +14339,11 @@ impl EntryCommands {
                     mem::transmute(value)
                 } else {
                     unsafe extern "system" fn fallback(_api_version: *mut u32) -> Result {
-                        panic!("could not load vkEnumerateInstanceVersion")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkEnumerateInstanceVersion")
+                        }
                     }
                     fallback
                 }
@@ -11242,7 +14545,11 @@ impl InstanceCommands {
                         _drm_fd: i32,
                         _display: DisplayKHR,
                     ) -> Result {
-                        panic!("could not load vkAcquireDrmDisplayEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkAcquireDrmDisplayEXT")
+                        }
                     }
                     fallback
                 }
@@ -11256,7 +14563,11 @@ impl InstanceCommands {
                         _physical_device: PhysicalDevice,
                         _display: DisplayKHR,
                     ) -> Result {
-                        panic!("could not load vkAcquireWinrtDisplayNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkAcquireWinrtDisplayNV")
+                        }
                     }
                     fallback
                 }
@@ -11271,7 +14582,11 @@ impl InstanceCommands {
                         _dpy: *mut Display,
                         _display: DisplayKHR,
                     ) -> Result {
-                        panic!("could not load vkAcquireXlibDisplayEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkAcquireXlibDisplayEXT")
+                        }
                     }
                     fallback
                 }
@@ -11326,7 +14641,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateAndroidSurfaceKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateAndroidSurfaceKHR")
+                        }
                     }
                     fallback
                 }
@@ -11342,7 +14661,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _callback: *mut DebugReportCallbackEXT,
                     ) -> Result {
-                        panic!("could not load vkCreateDebugReportCallbackEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDebugReportCallbackEXT")
+                        }
                     }
                     fallback
                 }
@@ -11358,7 +14681,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _messenger: *mut DebugUtilsMessengerEXT,
                     ) -> Result {
-                        panic!("could not load vkCreateDebugUtilsMessengerEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDebugUtilsMessengerEXT")
+                        }
                     }
                     fallback
                 }
@@ -11374,7 +14701,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _device: *mut Device,
                     ) -> Result {
-                        panic!("could not load vkCreateDevice")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDevice")
+                        }
                     }
                     fallback
                 }
@@ -11390,7 +14721,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateDirectFBSurfaceEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDirectFBSurfaceEXT")
+                        }
                     }
                     fallback
                 }
@@ -11407,7 +14742,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _mode: *mut DisplayModeKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateDisplayModeKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDisplayModeKHR")
+                        }
                     }
                     fallback
                 }
@@ -11423,7 +14762,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateDisplayPlaneSurfaceKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateDisplayPlaneSurfaceKHR")
+                        }
                     }
                     fallback
                 }
@@ -11439,7 +14782,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateHeadlessSurfaceEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateHeadlessSurfaceEXT")
+                        }
                     }
                     fallback
                 }
@@ -11455,7 +14802,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateIOSSurfaceMVK")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateIOSSurfaceMVK")
+                        }
                     }
                     fallback
                 }
@@ -11471,7 +14822,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateImagePipeSurfaceFUCHSIA")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateImagePipeSurfaceFUCHSIA")
+                        }
                     }
                     fallback
                 }
@@ -11487,7 +14842,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateMacOSSurfaceMVK")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateMacOSSurfaceMVK")
+                        }
                     }
                     fallback
                 }
@@ -11503,7 +14862,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateMetalSurfaceEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateMetalSurfaceEXT")
+                        }
                     }
                     fallback
                 }
@@ -11519,7 +14882,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateScreenSurfaceQNX")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateScreenSurfaceQNX")
+                        }
                     }
                     fallback
                 }
@@ -11535,7 +14902,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateStreamDescriptorSurfaceGGP")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateStreamDescriptorSurfaceGGP")
+                        }
                     }
                     fallback
                 }
@@ -11551,7 +14922,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateSurfaceOHOS")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateSurfaceOHOS")
+                        }
                     }
                     fallback
                 }
@@ -11567,7 +14942,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateUbmSurfaceSEC")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateUbmSurfaceSEC")
+                        }
                     }
                     fallback
                 }
@@ -11583,7 +14962,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateViSurfaceNN")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateViSurfaceNN")
+                        }
                     }
                     fallback
                 }
@@ -11599,7 +14982,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateWaylandSurfaceKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateWaylandSurfaceKHR")
+                        }
                     }
                     fallback
                 }
@@ -11615,7 +15002,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateWin32SurfaceKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateWin32SurfaceKHR")
+                        }
                     }
                     fallback
                 }
@@ -11631,7 +15022,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateXcbSurfaceKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateXcbSurfaceKHR")
+                        }
                     }
                     fallback
                 }
@@ -11647,7 +15042,11 @@ impl InstanceCommands {
                         _allocator: *const AllocationCallbacks,
                         _surface: *mut SurfaceKHR,
                     ) -> Result {
-                        panic!("could not load vkCreateXlibSurfaceKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkCreateXlibSurfaceKHR")
+                        }
                     }
                     fallback
                 }
@@ -11742,7 +15141,11 @@ impl InstanceCommands {
                         _property_count: *mut u32,
                         _properties: *mut ExtensionProperties,
                     ) -> Result {
-                        panic!("could not load vkEnumerateDeviceExtensionProperties")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkEnumerateDeviceExtensionProperties")
+                        }
                     }
                     fallback
                 }
@@ -11757,7 +15160,11 @@ impl InstanceCommands {
                         _property_count: *mut u32,
                         _properties: *mut LayerProperties,
                     ) -> Result {
-                        panic!("could not load vkEnumerateDeviceLayerProperties")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkEnumerateDeviceLayerProperties")
+                        }
                     }
                     fallback
                 }
@@ -11772,7 +15179,11 @@ impl InstanceCommands {
                         _physical_device_group_count: *mut u32,
                         _physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
                     ) -> Result {
-                        panic!("could not load vkEnumeratePhysicalDeviceGroups")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkEnumeratePhysicalDeviceGroups")
+                        }
                     }
                     fallback
                 }
@@ -11787,7 +15198,11 @@ impl InstanceCommands {
                         _physical_device_group_count: *mut u32,
                         _physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
                     ) -> Result {
-                        panic!("could not load vkEnumeratePhysicalDeviceGroupsKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkEnumeratePhysicalDeviceGroupsKHR")
+                        }
                     }
                     fallback
                 }
@@ -11806,7 +15221,11 @@ impl InstanceCommands {
                         _counters: *mut PerformanceCounterARM,
                         _counter_descriptions: *mut PerformanceCounterDescriptionARM,
                     ) -> Result {
-                        panic!("could not load vkEnumeratePhysicalDeviceQueueFamilyPerformanceCountersByRegionARM")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkEnumeratePhysicalDeviceQueueFamilyPerformanceCountersByRegionARM")
+                        }
                     }
                     fallback
                 }
@@ -11825,7 +15244,11 @@ impl InstanceCommands {
                         _counters: *mut PerformanceCounterKHR,
                         _counter_descriptions: *mut PerformanceCounterDescriptionKHR,
                     ) -> Result {
-                        panic!("could not load vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR")
+                        }
                     }
                     fallback
                 }
@@ -11840,7 +15263,11 @@ impl InstanceCommands {
                         _physical_device_count: *mut u32,
                         _physical_devices: *mut PhysicalDevice,
                     ) -> Result {
-                        panic!("could not load vkEnumeratePhysicalDevices")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkEnumeratePhysicalDevices")
+                        }
                     }
                     fallback
                 }
@@ -11870,7 +15297,11 @@ impl InstanceCommands {
                         _property_count: *mut u32,
                         _properties: *mut DisplayModeProperties2KHR,
                     ) -> Result {
-                        panic!("could not load vkGetDisplayModeProperties2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDisplayModeProperties2KHR")
+                        }
                     }
                     fallback
                 }
@@ -11886,7 +15317,11 @@ impl InstanceCommands {
                         _property_count: *mut u32,
                         _properties: *mut DisplayModePropertiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetDisplayModePropertiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDisplayModePropertiesKHR")
+                        }
                     }
                     fallback
                 }
@@ -11901,7 +15336,11 @@ impl InstanceCommands {
                         _display_plane_info: *const DisplayPlaneInfo2KHR,
                         _capabilities: *mut DisplayPlaneCapabilities2KHR,
                     ) -> Result {
-                        panic!("could not load vkGetDisplayPlaneCapabilities2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDisplayPlaneCapabilities2KHR")
+                        }
                     }
                     fallback
                 }
@@ -11917,7 +15356,11 @@ impl InstanceCommands {
                         _plane_index: u32,
                         _capabilities: *mut DisplayPlaneCapabilitiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetDisplayPlaneCapabilitiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDisplayPlaneCapabilitiesKHR")
+                        }
                     }
                     fallback
                 }
@@ -11933,7 +15376,11 @@ impl InstanceCommands {
                         _display_count: *mut u32,
                         _displays: *mut DisplayKHR,
                     ) -> Result {
-                        panic!("could not load vkGetDisplayPlaneSupportedDisplaysKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDisplayPlaneSupportedDisplaysKHR")
+                        }
                     }
                     fallback
                 }
@@ -11949,7 +15396,11 @@ impl InstanceCommands {
                         _connector_id: u32,
                         _display: *mut DisplayKHR,
                     ) -> Result {
-                        panic!("could not load vkGetDrmDisplayEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetDrmDisplayEXT")
+                        }
                     }
                     fallback
                 }
@@ -11964,7 +15415,11 @@ impl InstanceCommands {
                         _time_domain_count: *mut u32,
                         _time_domains: *mut TimeDomainKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceCalibrateableTimeDomainsEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceCalibrateableTimeDomainsEXT")
+                        }
                     }
                     fallback
                 }
@@ -11979,7 +15434,11 @@ impl InstanceCommands {
                         _time_domain_count: *mut u32,
                         _time_domains: *mut TimeDomainKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceCalibrateableTimeDomainsKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceCalibrateableTimeDomainsKHR")
+                        }
                     }
                     fallback
                 }
@@ -11996,7 +15455,11 @@ impl InstanceCommands {
                         _property_count: *mut u32,
                         _properties: *mut CooperativeMatrixFlexibleDimensionsPropertiesNV,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceCooperativeMatrixFlexibleDimensionsPropertiesNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceCooperativeMatrixFlexibleDimensionsPropertiesNV")
+                        }
                     }
                     fallback
                 }
@@ -12011,7 +15474,13 @@ impl InstanceCommands {
                         _property_count: *mut u32,
                         _properties: *mut CooperativeMatrixPropertiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceCooperativeMatrixPropertiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!(
+                                "could not load vkGetPhysicalDeviceCooperativeMatrixPropertiesKHR"
+                            )
+                        }
                     }
                     fallback
                 }
@@ -12026,7 +15495,13 @@ impl InstanceCommands {
                         _property_count: *mut u32,
                         _properties: *mut CooperativeMatrixPropertiesNV,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceCooperativeMatrixPropertiesNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!(
+                                "could not load vkGetPhysicalDeviceCooperativeMatrixPropertiesNV"
+                            )
+                        }
                     }
                     fallback
                 }
@@ -12041,7 +15516,13 @@ impl InstanceCommands {
                         _property_count: *mut u32,
                         _properties: *mut CooperativeVectorPropertiesNV,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceCooperativeVectorPropertiesNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!(
+                                "could not load vkGetPhysicalDeviceCooperativeVectorPropertiesNV"
+                            )
+                        }
                     }
                     fallback
                 }
@@ -12085,7 +15566,11 @@ impl InstanceCommands {
                         _property_count: *mut u32,
                         _properties: *mut DisplayPlaneProperties2KHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceDisplayPlaneProperties2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceDisplayPlaneProperties2KHR")
+                        }
                     }
                     fallback
                 }
@@ -12100,7 +15585,11 @@ impl InstanceCommands {
                         _property_count: *mut u32,
                         _properties: *mut DisplayPlanePropertiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceDisplayPlanePropertiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceDisplayPlanePropertiesKHR")
+                        }
                     }
                     fallback
                 }
@@ -12115,7 +15604,11 @@ impl InstanceCommands {
                         _property_count: *mut u32,
                         _properties: *mut DisplayProperties2KHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceDisplayProperties2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceDisplayProperties2KHR")
+                        }
                     }
                     fallback
                 }
@@ -12130,7 +15623,11 @@ impl InstanceCommands {
                         _property_count: *mut u32,
                         _properties: *mut DisplayPropertiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceDisplayPropertiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceDisplayPropertiesKHR")
+                        }
                     }
                     fallback
                 }
@@ -12210,7 +15707,13 @@ impl InstanceCommands {
                         _external_handle_type: ExternalMemoryHandleTypeFlagsNV,
                         _external_image_format_properties: *mut ExternalImageFormatPropertiesNV,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceExternalImageFormatPropertiesNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!(
+                                "could not load vkGetPhysicalDeviceExternalImageFormatPropertiesNV"
+                            )
+                        }
                     }
                     fallback
                 }
@@ -12226,7 +15729,11 @@ impl InstanceCommands {
                         _handle: NvSciBufObj,
                         _memory_sci_buf_properties: *mut MemorySciBufPropertiesNV,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceExternalMemorySciBufPropertiesNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceExternalMemorySciBufPropertiesNV")
+                        }
                     }
                     fallback
                 }
@@ -12373,7 +15880,11 @@ impl InstanceCommands {
                         _fragment_shading_rate_count: *mut u32,
                         _fragment_shading_rates: *mut PhysicalDeviceFragmentShadingRateKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceFragmentShadingRatesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceFragmentShadingRatesKHR")
+                        }
                     }
                     fallback
                 }
@@ -12392,7 +15903,11 @@ impl InstanceCommands {
                         _flags: ImageCreateFlags,
                         _image_format_properties: *mut ImageFormatProperties,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceImageFormatProperties")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceImageFormatProperties")
+                        }
                     }
                     fallback
                 }
@@ -12407,7 +15922,11 @@ impl InstanceCommands {
                         _image_format_info: *const PhysicalDeviceImageFormatInfo2,
                         _image_format_properties: *mut ImageFormatProperties2,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceImageFormatProperties2")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceImageFormatProperties2")
+                        }
                     }
                     fallback
                 }
@@ -12422,7 +15941,11 @@ impl InstanceCommands {
                         _image_format_info: *const PhysicalDeviceImageFormatInfo2,
                         _image_format_properties: *mut ImageFormatProperties2,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceImageFormatProperties2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceImageFormatProperties2KHR")
+                        }
                     }
                     fallback
                 }
@@ -12495,7 +16018,11 @@ impl InstanceCommands {
                         _format_count: *mut u32,
                         _image_format_properties: *mut OpticalFlowImageFormatPropertiesNV,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceOpticalFlowImageFormatsNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceOpticalFlowImageFormatsNV")
+                        }
                     }
                     fallback
                 }
@@ -12511,7 +16038,11 @@ impl InstanceCommands {
                         _rect_count: *mut u32,
                         _rects: *mut Rect2D,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDevicePresentRectanglesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDevicePresentRectanglesKHR")
+                        }
                     }
                     fallback
                 }
@@ -12666,7 +16197,11 @@ impl InstanceCommands {
                         _refreshable_object_type_count: *mut u32,
                         _refreshable_object_types: *mut ObjectType,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceRefreshableObjectTypesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceRefreshableObjectTypesKHR")
+                        }
                     }
                     fallback
                 }
@@ -12680,7 +16215,11 @@ impl InstanceCommands {
                         _physical_device: PhysicalDevice,
                         _attributes: NvSciBufAttrList,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceSciBufAttributesNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceSciBufAttributesNV")
+                        }
                     }
                     fallback
                 }
@@ -12695,7 +16234,11 @@ impl InstanceCommands {
                         _sci_sync_attributes_info: *const SciSyncAttributesInfoNV,
                         _attributes: NvSciSyncAttrList,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceSciSyncAttributesNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceSciSyncAttributesNV")
+                        }
                     }
                     fallback
                 }
@@ -12779,7 +16322,11 @@ impl InstanceCommands {
                         _combination_count: *mut u32,
                         _combinations: *mut FramebufferMixedSamplesCombinationNV,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV")
+                        }
                     }
                     fallback
                 }
@@ -12794,7 +16341,11 @@ impl InstanceCommands {
                         _surface: SurfaceKHR,
                         _surface_capabilities: *mut SurfaceCapabilities2EXT,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceSurfaceCapabilities2EXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceSurfaceCapabilities2EXT")
+                        }
                     }
                     fallback
                 }
@@ -12809,7 +16360,11 @@ impl InstanceCommands {
                         _surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
                         _surface_capabilities: *mut SurfaceCapabilities2KHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceSurfaceCapabilities2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceSurfaceCapabilities2KHR")
+                        }
                     }
                     fallback
                 }
@@ -12824,7 +16379,11 @@ impl InstanceCommands {
                         _surface: SurfaceKHR,
                         _surface_capabilities: *mut SurfaceCapabilitiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceSurfaceCapabilitiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceSurfaceCapabilitiesKHR")
+                        }
                     }
                     fallback
                 }
@@ -12840,7 +16399,11 @@ impl InstanceCommands {
                         _surface_format_count: *mut u32,
                         _surface_formats: *mut SurfaceFormat2KHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceSurfaceFormats2KHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceSurfaceFormats2KHR")
+                        }
                     }
                     fallback
                 }
@@ -12856,7 +16419,11 @@ impl InstanceCommands {
                         _surface_format_count: *mut u32,
                         _surface_formats: *mut SurfaceFormatKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceSurfaceFormatsKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceSurfaceFormatsKHR")
+                        }
                     }
                     fallback
                 }
@@ -12872,7 +16439,11 @@ impl InstanceCommands {
                         _present_mode_count: *mut u32,
                         _present_modes: *mut PresentModeKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceSurfacePresentModes2EXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceSurfacePresentModes2EXT")
+                        }
                     }
                     fallback
                 }
@@ -12888,7 +16459,11 @@ impl InstanceCommands {
                         _present_mode_count: *mut u32,
                         _present_modes: *mut PresentModeKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceSurfacePresentModesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceSurfacePresentModesKHR")
+                        }
                     }
                     fallback
                 }
@@ -12904,7 +16479,11 @@ impl InstanceCommands {
                         _surface: SurfaceKHR,
                         _supported: *mut Bool32,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceSurfaceSupportKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceSurfaceSupportKHR")
+                        }
                     }
                     fallback
                 }
@@ -12919,7 +16498,11 @@ impl InstanceCommands {
                         _tool_count: *mut u32,
                         _tool_properties: *mut PhysicalDeviceToolProperties,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceToolProperties")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceToolProperties")
+                        }
                     }
                     fallback
                 }
@@ -12934,7 +16517,11 @@ impl InstanceCommands {
                         _tool_count: *mut u32,
                         _tool_properties: *mut PhysicalDeviceToolProperties,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceToolPropertiesEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceToolPropertiesEXT")
+                        }
                     }
                     fallback
                 }
@@ -12964,7 +16551,11 @@ impl InstanceCommands {
                         _video_profile: *const VideoProfileInfoKHR,
                         _capabilities: *mut VideoCapabilitiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceVideoCapabilitiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceVideoCapabilitiesKHR")
+                        }
                     }
                     fallback
                 }
@@ -12980,7 +16571,11 @@ impl InstanceCommands {
                         _quality_level_info: *const PhysicalDeviceVideoEncodeQualityLevelInfoKHR,
                         _quality_level_properties: *mut VideoEncodeQualityLevelPropertiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR")
+                        }
                     }
                     fallback
                 }
@@ -12996,7 +16591,11 @@ impl InstanceCommands {
                         _video_format_property_count: *mut u32,
                         _video_format_properties: *mut VideoFormatPropertiesKHR,
                     ) -> Result {
-                        panic!("could not load vkGetPhysicalDeviceVideoFormatPropertiesKHR")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetPhysicalDeviceVideoFormatPropertiesKHR")
+                        }
                     }
                     fallback
                 }
@@ -13073,7 +16672,11 @@ impl InstanceCommands {
                         _rr_output: RROutput,
                         _display: *mut DisplayKHR,
                     ) -> Result {
-                        panic!("could not load vkGetRandROutputDisplayEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetRandROutputDisplayEXT")
+                        }
                     }
                     fallback
                 }
@@ -13088,7 +16691,11 @@ impl InstanceCommands {
                         _device_relative_id: u32,
                         _display: *mut DisplayKHR,
                     ) -> Result {
-                        panic!("could not load vkGetWinrtDisplayNV")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkGetWinrtDisplayNV")
+                        }
                     }
                     fallback
                 }
@@ -13141,7 +16748,11 @@ impl InstanceCommands {
                         _physical_device: PhysicalDevice,
                         _display: DisplayKHR,
                     ) -> Result {
-                        panic!("could not load vkReleaseDisplayEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkReleaseDisplayEXT")
+                        }
                     }
                     fallback
                 }
@@ -13155,7 +16766,11 @@ impl InstanceCommands {
                         _device: Device,
                         _name_info: *const DebugUtilsObjectNameInfoEXT,
                     ) -> Result {
-                        panic!("could not load vkSetDebugUtilsObjectNameEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkSetDebugUtilsObjectNameEXT")
+                        }
                     }
                     fallback
                 }
@@ -13169,7 +16784,11 @@ impl InstanceCommands {
                         _device: Device,
                         _tag_info: *const DebugUtilsObjectTagInfoEXT,
                     ) -> Result {
-                        panic!("could not load vkSetDebugUtilsObjectTagEXT")
+                        if cfg!(feature = "fallible_commands") {
+                            Result::ERROR_EXTENSION_NOT_PRESENT
+                        } else {
+                            panic!("could not load vkSetDebugUtilsObjectTagEXT")
+                        }
                     }
                     fallback
                 }
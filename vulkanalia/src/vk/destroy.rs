@@ -0,0 +1,518 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// DO NOT EDIT.
+//
+// This file has been generated by the Kotlin project in the `generator`
+// directory from a Vulkan API registry.
+
+#![allow(
+    non_camel_case_types,
+    non_snake_case,
+    clippy::bad_bit_mask,
+    clippy::let_and_return,
+    clippy::let_unit_value,
+    clippy::missing_safety_doc,
+    clippy::missing_transmute_annotations,
+    clippy::needless_lifetimes,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unnecessary_cast,
+    clippy::upper_case_acronyms,
+    clippy::useless_transmute
+)]
+
+use super::*;
+use crate::owned::Destroy;
+
+impl Destroy for AccelerationStructureKHR {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        KhrAccelerationStructureExtensionDeviceCommands::destroy_acceleration_structure_khr(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for AccelerationStructureNV {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        NvRayTracingExtensionDeviceCommands::destroy_acceleration_structure_nv(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for Buffer {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_buffer(parent, self, allocator);
+    }
+}
+
+impl Destroy for BufferCollectionFUCHSIA {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        FuchsiaBufferCollectionExtensionDeviceCommands::destroy_buffer_collection_fuchsia(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for BufferView {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_buffer_view(parent, self, allocator);
+    }
+}
+
+impl Destroy for CommandPool {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_command_pool(parent, self, allocator);
+    }
+}
+
+impl Destroy for CuFunctionNVX {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        NvxBinaryImportExtensionDeviceCommands::destroy_cu_function_nvx(parent, self, allocator);
+    }
+}
+
+impl Destroy for CuModuleNVX {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        NvxBinaryImportExtensionDeviceCommands::destroy_cu_module_nvx(parent, self, allocator);
+    }
+}
+
+#[cfg(feature = "provisional")]
+impl Destroy for CudaFunctionNV {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        NvCudaKernelLaunchExtensionDeviceCommands::destroy_cuda_function_nv(
+            parent, self, allocator,
+        );
+    }
+}
+
+#[cfg(feature = "provisional")]
+impl Destroy for CudaModuleNV {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        NvCudaKernelLaunchExtensionDeviceCommands::destroy_cuda_module_nv(parent, self, allocator);
+    }
+}
+
+impl Destroy for DataGraphPipelineSessionARM {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        ArmDataGraphExtensionDeviceCommands::destroy_data_graph_pipeline_session_arm(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for DebugReportCallbackEXT {
+    type Parent = crate::Instance;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        ExtDebugReportExtensionInstanceCommands::destroy_debug_report_callback_ext(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for DebugUtilsMessengerEXT {
+    type Parent = crate::Instance;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        ExtDebugUtilsExtensionInstanceCommands::destroy_debug_utils_messenger_ext(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for DeferredOperationKHR {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        KhrDeferredHostOperationsExtensionDeviceCommands::destroy_deferred_operation_khr(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for DescriptorPool {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_descriptor_pool(parent, self, allocator);
+    }
+}
+
+impl Destroy for DescriptorSetLayout {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_descriptor_set_layout(parent, self, allocator);
+    }
+}
+
+impl Destroy for DescriptorUpdateTemplate {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        if parent.is_command_loaded("vkDestroyDescriptorUpdateTemplate") {
+            DeviceV1_1::destroy_descriptor_update_template(parent, self, allocator);
+        } else {
+            KhrDescriptorUpdateTemplateExtensionDeviceCommands::destroy_descriptor_update_template_khr(parent, self, allocator);
+        }
+    }
+}
+
+impl Destroy for DeviceMemory {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::free_memory(parent, self, allocator);
+    }
+}
+
+impl Destroy for Event {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_event(parent, self, allocator);
+    }
+}
+
+impl Destroy for ExternalComputeQueueNV {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        NvExternalComputeQueueExtensionDeviceCommands::destroy_external_compute_queue_nv(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for Fence {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_fence(parent, self, allocator);
+    }
+}
+
+impl Destroy for Framebuffer {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_framebuffer(parent, self, allocator);
+    }
+}
+
+impl Destroy for Image {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_image(parent, self, allocator);
+    }
+}
+
+impl Destroy for ImageView {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_image_view(parent, self, allocator);
+    }
+}
+
+impl Destroy for IndirectCommandsLayoutEXT {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        ExtDeviceGeneratedCommandsExtensionDeviceCommands::destroy_indirect_commands_layout_ext(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for IndirectCommandsLayoutNV {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        NvDeviceGeneratedCommandsExtensionDeviceCommands::destroy_indirect_commands_layout_nv(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for IndirectExecutionSetEXT {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        ExtDeviceGeneratedCommandsExtensionDeviceCommands::destroy_indirect_execution_set_ext(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for MicromapEXT {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        ExtOpacityMicromapExtensionDeviceCommands::destroy_micromap_ext(parent, self, allocator);
+    }
+}
+
+impl Destroy for OpticalFlowSessionNV {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        NvOpticalFlowExtensionDeviceCommands::destroy_optical_flow_session_nv(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for Pipeline {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_pipeline(parent, self, allocator);
+    }
+}
+
+impl Destroy for PipelineBinaryKHR {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        KhrPipelineBinaryExtensionDeviceCommands::destroy_pipeline_binary_khr(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for PipelineCache {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_pipeline_cache(parent, self, allocator);
+    }
+}
+
+impl Destroy for PipelineLayout {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_pipeline_layout(parent, self, allocator);
+    }
+}
+
+impl Destroy for PrivateDataSlot {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        if parent.is_command_loaded("vkDestroyPrivateDataSlot") {
+            DeviceV1_3::destroy_private_data_slot(parent, self, allocator);
+        } else {
+            ExtPrivateDataExtensionDeviceCommands::destroy_private_data_slot_ext(
+                parent, self, allocator,
+            );
+        }
+    }
+}
+
+impl Destroy for QueryPool {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_query_pool(parent, self, allocator);
+    }
+}
+
+impl Destroy for RenderPass {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_render_pass(parent, self, allocator);
+    }
+}
+
+impl Destroy for Sampler {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_sampler(parent, self, allocator);
+    }
+}
+
+impl Destroy for SamplerYcbcrConversion {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        if parent.is_command_loaded("vkDestroySamplerYcbcrConversion") {
+            DeviceV1_1::destroy_sampler_ycbcr_conversion(parent, self, allocator);
+        } else {
+            KhrSamplerYcbcrConversionExtensionDeviceCommands::destroy_sampler_ycbcr_conversion_khr(
+                parent, self, allocator,
+            );
+        }
+    }
+}
+
+impl Destroy for Semaphore {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_semaphore(parent, self, allocator);
+    }
+}
+
+impl Destroy for SemaphoreSciSyncPoolNV {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        NvExternalSciSync2ExtensionDeviceCommands::destroy_semaphore_sci_sync_pool_nv(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for ShaderEXT {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        ExtShaderObjectExtensionDeviceCommands::destroy_shader_ext(parent, self, allocator);
+    }
+}
+
+impl Destroy for ShaderModule {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        DeviceV1_0::destroy_shader_module(parent, self, allocator);
+    }
+}
+
+impl Destroy for SurfaceKHR {
+    type Parent = crate::Instance;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        KhrSurfaceExtensionInstanceCommands::destroy_surface_khr(parent, self, allocator);
+    }
+}
+
+impl Destroy for SwapchainKHR {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        KhrSwapchainExtensionDeviceCommands::destroy_swapchain_khr(parent, self, allocator);
+    }
+}
+
+impl Destroy for TensorARM {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        ArmTensorsExtensionDeviceCommands::destroy_tensor_arm(parent, self, allocator);
+    }
+}
+
+impl Destroy for TensorViewARM {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        ArmTensorsExtensionDeviceCommands::destroy_tensor_view_arm(parent, self, allocator);
+    }
+}
+
+impl Destroy for ValidationCacheEXT {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        ExtValidationCacheExtensionDeviceCommands::destroy_validation_cache_ext(
+            parent, self, allocator,
+        );
+    }
+}
+
+impl Destroy for VideoSessionKHR {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        KhrVideoQueueExtensionDeviceCommands::destroy_video_session_khr(parent, self, allocator);
+    }
+}
+
+impl Destroy for VideoSessionParametersKHR {
+    type Parent = crate::Device;
+
+    #[inline]
+    unsafe fn destroy(self, parent: &Self::Parent, allocator: Option<&AllocationCallbacks>) {
+        KhrVideoQueueExtensionDeviceCommands::destroy_video_session_parameters_khr(
+            parent, self, allocator,
+        );
+    }
+}
@@ -9,6 +9,7 @@
     non_camel_case_types,
     non_snake_case,
     clippy::bad_bit_mask,
+    clippy::let_and_return,
     clippy::let_unit_value,
     clippy::missing_safety_doc,
     clippy::missing_transmute_annotations,
@@ -38,6 +39,13 @@ pub trait AmdxShaderEnqueueExtensionDeviceCommands: DeviceV1_0 {
         scratch_size: DeviceSize,
         count_info: &DispatchGraphCountInfoAMDX,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDispatchGraphAMDX",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_dispatch_graph_amdx)(
             command_buffer,
             scratch,
@@ -55,6 +63,13 @@ pub trait AmdxShaderEnqueueExtensionDeviceCommands: DeviceV1_0 {
         scratch_size: DeviceSize,
         count_info: &DispatchGraphCountInfoAMDX,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDispatchGraphIndirectAMDX",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_dispatch_graph_indirect_amdx)(
             command_buffer,
             scratch,
@@ -72,6 +87,13 @@ pub trait AmdxShaderEnqueueExtensionDeviceCommands: DeviceV1_0 {
         scratch_size: DeviceSize,
         count_info: DeviceAddress,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDispatchGraphIndirectCountAMDX",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_dispatch_graph_indirect_count_amdx)(
             command_buffer,
             scratch,
@@ -89,6 +111,14 @@ pub trait AmdxShaderEnqueueExtensionDeviceCommands: DeviceV1_0 {
         scratch: DeviceAddress,
         scratch_size: DeviceSize,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdInitializeGraphScratchMemoryAMDX",
+            command_buffer = tracing::field::debug(command_buffer),
+            execution_graph = tracing::field::debug(execution_graph)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_initialize_graph_scratch_memory_amdx)(
             command_buffer,
             execution_graph,
@@ -105,6 +135,15 @@ pub trait AmdxShaderEnqueueExtensionDeviceCommands: DeviceV1_0 {
         create_infos: &[impl Cast<Target = ExecutionGraphPipelineCreateInfoAMDX>],
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkSuccessResult<Vec<Pipeline>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateExecutionGraphPipelinesAMDX",
+            device = tracing::field::debug(self.handle()),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut pipelines = Vec::with_capacity(create_infos.len() as usize);
 
         let __result = (self.commands().create_execution_graph_pipelines_amdx)(
@@ -116,6 +155,9 @@ pub trait AmdxShaderEnqueueExtensionDeviceCommands: DeviceV1_0 {
             pipelines.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         pipelines.set_len(create_infos.len() as usize);
 
         if __result >= Result::SUCCESS {
@@ -125,6 +167,53 @@ pub trait AmdxShaderEnqueueExtensionDeviceCommands: DeviceV1_0 {
         }
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCreateExecutionGraphPipelinesAMDX.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::create_execution_graph_pipelines_amdx`].
+    #[inline]
+    unsafe fn create_execution_graph_pipelines_amdx_into(
+        &self,
+        pipeline_cache: PipelineCache,
+        create_infos: &[impl Cast<Target = ExecutionGraphPipelineCreateInfoAMDX>],
+        allocator: Option<&AllocationCallbacks>,
+        pipelines: &mut Vec<Pipeline>,
+    ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateExecutionGraphPipelinesAMDX",
+            device = tracing::field::debug(self.handle()),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        pipelines.clear();
+        pipelines.reserve(create_infos.len() as usize);
+
+        let __result = (self.commands().create_execution_graph_pipelines_amdx)(
+            self.handle(),
+            pipeline_cache,
+            create_infos.len() as u32,
+            create_infos.as_ptr().cast(),
+            allocator.map_or(ptr::null(), |v| v),
+            pipelines.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            pipelines.set_len(create_infos.len() as usize);
+        }
+
+        if __result >= Result::SUCCESS {
+            Ok(__result.into())
+        } else {
+            Err(__result.into())
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetExecutionGraphPipelineNodeIndexAMDX.html>
     #[inline]
     unsafe fn get_execution_graph_pipeline_node_index_amdx(
@@ -132,6 +221,15 @@ pub trait AmdxShaderEnqueueExtensionDeviceCommands: DeviceV1_0 {
         execution_graph: Pipeline,
         node_info: &PipelineShaderStageNodeCreateInfoAMDX,
     ) -> crate::VkResult<u32> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetExecutionGraphPipelineNodeIndexAMDX",
+            device = tracing::field::debug(self.handle()),
+            execution_graph = tracing::field::debug(execution_graph),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut node_index = MaybeUninit::<u32>::uninit();
 
         let __result = (self.commands().get_execution_graph_pipeline_node_index_amdx)(
@@ -141,6 +239,9 @@ pub trait AmdxShaderEnqueueExtensionDeviceCommands: DeviceV1_0 {
             node_index.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(node_index.assume_init())
         } else {
@@ -155,6 +256,15 @@ pub trait AmdxShaderEnqueueExtensionDeviceCommands: DeviceV1_0 {
         execution_graph: Pipeline,
         size_info: &mut ExecutionGraphPipelineScratchSizeAMDX,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetExecutionGraphPipelineScratchSizeAMDX",
+            device = tracing::field::debug(self.handle()),
+            execution_graph = tracing::field::debug(execution_graph),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_execution_graph_pipeline_scratch_size_amdx)(
@@ -163,6 +273,9 @@ pub trait AmdxShaderEnqueueExtensionDeviceCommands: DeviceV1_0 {
             size_info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -179,6 +292,13 @@ pub trait AmdAntiLagExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkAntiLagUpdateAMD.html>
     #[inline]
     unsafe fn anti_lag_update_amd(&self, data: &AntiLagDataAMD) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAntiLagUpdateAMD",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().anti_lag_update_amd)(self.handle(), data);
     }
 }
@@ -197,6 +317,14 @@ pub trait AmdBufferMarkerExtensionDeviceCommands: DeviceV1_0 {
         dst_offset: DeviceSize,
         marker: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdWriteBufferMarker2AMD",
+            command_buffer = tracing::field::debug(command_buffer),
+            dst_buffer = tracing::field::debug(dst_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_write_buffer_marker2_amd)(
             command_buffer,
             stage,
@@ -216,6 +344,14 @@ pub trait AmdBufferMarkerExtensionDeviceCommands: DeviceV1_0 {
         dst_offset: DeviceSize,
         marker: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdWriteBufferMarkerAMD",
+            command_buffer = tracing::field::debug(command_buffer),
+            dst_buffer = tracing::field::debug(dst_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_write_buffer_marker_amd)(
             command_buffer,
             pipeline_stage,
@@ -233,6 +369,14 @@ pub trait AmdDisplayNativeHdrExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkSetLocalDimmingAMD.html>
     #[inline]
     unsafe fn set_local_dimming_amd(&self, swap_chain: SwapchainKHR, local_dimming_enable: bool) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetLocalDimmingAMD",
+            device = tracing::field::debug(self.handle()),
+            swap_chain = tracing::field::debug(swap_chain)
+        )
+        .entered();
+
         let __result = (self.commands().set_local_dimming_amd)(
             self.handle(),
             swap_chain,
@@ -257,6 +401,15 @@ pub trait AmdDrawIndirectCountExtensionDeviceCommands: DeviceV1_0 {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawIndexedIndirectCountAMD",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer),
+            count_buffer = tracing::field::debug(count_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_indexed_indirect_count_amd)(
             command_buffer,
             buffer,
@@ -280,6 +433,15 @@ pub trait AmdDrawIndirectCountExtensionDeviceCommands: DeviceV1_0 {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawIndirectCountAMD",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer),
+            count_buffer = tracing::field::debug(count_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_indirect_count_amd)(
             command_buffer,
             buffer,
@@ -304,35 +466,130 @@ pub trait AmdShaderInfoExtensionDeviceCommands: DeviceV1_0 {
         shader_stage: ShaderStageFlags,
         info_type: ShaderInfoTypeAMD,
     ) -> crate::VkResult<Vec<u8>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetShaderInfoAMD",
+            device = tracing::field::debug(self.handle()),
+            pipeline = tracing::field::debug(pipeline),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut info_size = 0;
 
-        (self.commands().get_shader_info_amd)(
-            self.handle(),
-            pipeline,
-            shader_stage,
-            info_type,
-            &mut info_size,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_shader_info_amd)(
+                self.handle(),
+                pipeline,
+                shader_stage,
+                info_type,
+                &mut info_size,
+                ptr::null_mut(),
+            );
 
-        let mut info = Vec::with_capacity(info_size as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_shader_info_amd)(
-            self.handle(),
-            pipeline,
-            shader_stage,
-            info_type,
-            &mut info_size,
-            info.as_mut_ptr() as *mut c_void,
-        );
+            let mut info = Vec::with_capacity(info_size as usize);
 
-        debug_assert!(info.capacity() >= info_size as usize);
-        info.set_len(info_size as usize);
+            let __result = (self.commands().get_shader_info_amd)(
+                self.handle(),
+                pipeline,
+                shader_stage,
+                info_type,
+                &mut info_size,
+                info.as_mut_ptr() as *mut c_void,
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(info)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(info.capacity() >= info_size as usize);
+            info.set_len(info_size as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(info)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetShaderInfoAMD.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_shader_info_amd`].
+    #[inline]
+    unsafe fn get_shader_info_amd_into(
+        &self,
+        pipeline: Pipeline,
+        shader_stage: ShaderStageFlags,
+        info_type: ShaderInfoTypeAMD,
+        info: &mut Vec<u8>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetShaderInfoAMD",
+            device = tracing::field::debug(self.handle()),
+            pipeline = tracing::field::debug(pipeline),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut info_size = 0;
+
+        loop {
+            let __result = (self.commands().get_shader_info_amd)(
+                self.handle(),
+                pipeline,
+                shader_stage,
+                info_type,
+                &mut info_size,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            info.clear();
+            info.reserve(info_size as usize);
+
+            let __result = (self.commands().get_shader_info_amd)(
+                self.handle(),
+                pipeline,
+                shader_stage,
+                info_type,
+                &mut info_size,
+                info.as_mut_ptr() as *mut c_void,
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(info.capacity() >= info_size as usize);
+                info.set_len(info_size as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -348,12 +605,23 @@ pub trait AndroidExternalMemoryAndroidHardwareBufferExtensionDeviceCommands: Dev
         buffer: *const AHardwareBuffer,
         properties: &mut AndroidHardwareBufferPropertiesANDROID,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetAndroidHardwareBufferPropertiesANDROID",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_android_hardware_buffer_properties_android)(
             self.handle(), buffer, properties
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -367,6 +635,14 @@ pub trait AndroidExternalMemoryAndroidHardwareBufferExtensionDeviceCommands: Dev
         &self,
         info: &MemoryGetAndroidHardwareBufferInfoANDROID,
     ) -> crate::VkResult<*mut AHardwareBuffer> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryAndroidHardwareBufferANDROID",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut buffer = MaybeUninit::<*mut AHardwareBuffer>::uninit();
 
         let __result = (self.commands().get_memory_android_hardware_buffer_android)(
@@ -375,6 +651,9 @@ pub trait AndroidExternalMemoryAndroidHardwareBufferExtensionDeviceCommands: Dev
             buffer.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(buffer.assume_init())
         } else {
@@ -396,12 +675,23 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
         &self,
         bind_infos: &[impl Cast<Target = BindDataGraphPipelineSessionMemoryInfoARM>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBindDataGraphPipelineSessionMemoryARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().bind_data_graph_pipeline_session_memory_arm)(
             self.handle(),
             bind_infos.len() as u32,
             bind_infos.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -417,6 +707,14 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
         session: DataGraphPipelineSessionARM,
         info: Option<&DataGraphPipelineDispatchInfoARM>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDispatchDataGraphARM",
+            command_buffer = tracing::field::debug(command_buffer),
+            session = tracing::field::debug(session)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_dispatch_data_graph_arm)(
             command_buffer,
             session,
@@ -431,6 +729,14 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
         create_info: &DataGraphPipelineSessionCreateInfoARM,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<DataGraphPipelineSessionARM> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDataGraphPipelineSessionARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut session = MaybeUninit::<DataGraphPipelineSessionARM>::uninit();
 
         let __result = (self.commands().create_data_graph_pipeline_session_arm)(
@@ -440,6 +746,9 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
             session.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(session.assume_init())
         } else {
@@ -456,6 +765,16 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
         create_infos: &[impl Cast<Target = DataGraphPipelineCreateInfoARM>],
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkSuccessResult<Vec<Pipeline>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDataGraphPipelinesARM",
+            device = tracing::field::debug(self.handle()),
+            deferred_operation = tracing::field::debug(deferred_operation),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut pipelines = Vec::with_capacity(create_infos.len() as usize);
 
         let __result = (self.commands().create_data_graph_pipelines_arm)(
@@ -468,6 +787,9 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
             pipelines.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         pipelines.set_len(create_infos.len() as usize);
 
         if __result >= Result::SUCCESS {
@@ -477,6 +799,56 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
         }
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCreateDataGraphPipelinesARM.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::create_data_graph_pipelines_arm`].
+    #[inline]
+    unsafe fn create_data_graph_pipelines_arm_into(
+        &self,
+        deferred_operation: DeferredOperationKHR,
+        pipeline_cache: PipelineCache,
+        create_infos: &[impl Cast<Target = DataGraphPipelineCreateInfoARM>],
+        allocator: Option<&AllocationCallbacks>,
+        pipelines: &mut Vec<Pipeline>,
+    ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDataGraphPipelinesARM",
+            device = tracing::field::debug(self.handle()),
+            deferred_operation = tracing::field::debug(deferred_operation),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        pipelines.clear();
+        pipelines.reserve(create_infos.len() as usize);
+
+        let __result = (self.commands().create_data_graph_pipelines_arm)(
+            self.handle(),
+            deferred_operation,
+            pipeline_cache,
+            create_infos.len() as u32,
+            create_infos.as_ptr().cast(),
+            allocator.map_or(ptr::null(), |v| v),
+            pipelines.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            pipelines.set_len(create_infos.len() as usize);
+        }
+
+        if __result >= Result::SUCCESS {
+            Ok(__result.into())
+        } else {
+            Err(__result.into())
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkDestroyDataGraphPipelineSessionARM.html>
     #[inline]
     unsafe fn destroy_data_graph_pipeline_session_arm(
@@ -484,6 +856,14 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
         session: DataGraphPipelineSessionARM,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyDataGraphPipelineSessionARM",
+            device = tracing::field::debug(self.handle()),
+            session = tracing::field::debug(session)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_data_graph_pipeline_session_arm)(
             self.handle(),
             session,
@@ -497,35 +877,126 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
         &self,
         pipeline_info: &DataGraphPipelineInfoARM,
     ) -> crate::VkResult<Vec<DataGraphPipelinePropertyARM>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDataGraphPipelineAvailablePropertiesARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut properties_count = 0;
 
-        (self
-            .commands()
-            .get_data_graph_pipeline_available_properties_arm)(
-            self.handle(),
-            pipeline_info,
-            &mut properties_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_data_graph_pipeline_available_properties_arm)(
+                self.handle(),
+                pipeline_info,
+                &mut properties_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut properties = Vec::with_capacity(properties_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_data_graph_pipeline_available_properties_arm)(
+                self.handle(),
+                pipeline_info,
+                &mut properties_count,
+                properties.as_mut_ptr(),
+            );
 
-        let mut properties = Vec::with_capacity(properties_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        let __result = (self
-            .commands()
-            .get_data_graph_pipeline_available_properties_arm)(
-            self.handle(),
-            pipeline_info,
-            &mut properties_count,
-            properties.as_mut_ptr(),
-        );
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
 
-        debug_assert!(properties.capacity() >= properties_count as usize);
-        properties.set_len(properties_count as usize);
+            debug_assert!(properties.capacity() >= properties_count as usize);
+            properties.set_len(properties_count as usize);
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetDataGraphPipelineAvailablePropertiesARM.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_data_graph_pipeline_available_properties_arm`].
+    #[inline]
+    unsafe fn get_data_graph_pipeline_available_properties_arm_into(
+        &self,
+        pipeline_info: &DataGraphPipelineInfoARM,
+        properties: &mut Vec<DataGraphPipelinePropertyARM>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDataGraphPipelineAvailablePropertiesARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut properties_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_data_graph_pipeline_available_properties_arm)(
+                self.handle(),
+                pipeline_info,
+                &mut properties_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(properties_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_data_graph_pipeline_available_properties_arm)(
+                self.handle(),
+                pipeline_info,
+                &mut properties_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= properties_count as usize);
+                properties.set_len(properties_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -536,6 +1007,14 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
         pipeline_info: &DataGraphPipelineInfoARM,
         properties: &mut [impl Cast<Target = DataGraphPipelinePropertyQueryResultARM>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDataGraphPipelinePropertiesARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_data_graph_pipeline_properties_arm)(
             self.handle(),
             pipeline_info,
@@ -543,6 +1022,9 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
             properties.as_mut_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -556,35 +1038,131 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
         &self,
         info: &DataGraphPipelineSessionBindPointRequirementsInfoARM,
     ) -> crate::VkResult<Vec<DataGraphPipelineSessionBindPointRequirementARM>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDataGraphPipelineSessionBindPointRequirementsARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut bind_point_requirement_count = 0;
 
-        (self
-            .commands()
-            .get_data_graph_pipeline_session_bind_point_requirements_arm)(
-            self.handle(),
-            info,
-            &mut bind_point_requirement_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_data_graph_pipeline_session_bind_point_requirements_arm)(
+                self.handle(),
+                info,
+                &mut bind_point_requirement_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut bind_point_requirements =
+                Vec::with_capacity(bind_point_requirement_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_data_graph_pipeline_session_bind_point_requirements_arm)(
+                self.handle(),
+                info,
+                &mut bind_point_requirement_count,
+                bind_point_requirements.as_mut_ptr(),
+            );
 
-        let mut bind_point_requirements = Vec::with_capacity(bind_point_requirement_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        let __result = (self
-            .commands()
-            .get_data_graph_pipeline_session_bind_point_requirements_arm)(
-            self.handle(),
-            info,
-            &mut bind_point_requirement_count,
-            bind_point_requirements.as_mut_ptr(),
-        );
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
 
-        debug_assert!(bind_point_requirements.capacity() >= bind_point_requirement_count as usize);
-        bind_point_requirements.set_len(bind_point_requirement_count as usize);
+            debug_assert!(
+                bind_point_requirements.capacity() >= bind_point_requirement_count as usize
+            );
+            bind_point_requirements.set_len(bind_point_requirement_count as usize);
 
-        if __result == Result::SUCCESS {
-            Ok(bind_point_requirements)
-        } else {
-            Err(__result.into())
+            break if __result == Result::SUCCESS {
+                Ok(bind_point_requirements)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetDataGraphPipelineSessionBindPointRequirementsARM.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_data_graph_pipeline_session_bind_point_requirements_arm`].
+    #[inline]
+    unsafe fn get_data_graph_pipeline_session_bind_point_requirements_arm_into(
+        &self,
+        info: &DataGraphPipelineSessionBindPointRequirementsInfoARM,
+        bind_point_requirements: &mut Vec<DataGraphPipelineSessionBindPointRequirementARM>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDataGraphPipelineSessionBindPointRequirementsARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut bind_point_requirement_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_data_graph_pipeline_session_bind_point_requirements_arm)(
+                self.handle(),
+                info,
+                &mut bind_point_requirement_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            bind_point_requirements.clear();
+            bind_point_requirements.reserve(bind_point_requirement_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_data_graph_pipeline_session_bind_point_requirements_arm)(
+                self.handle(),
+                info,
+                &mut bind_point_requirement_count,
+                bind_point_requirements.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(
+                    bind_point_requirements.capacity() >= bind_point_requirement_count as usize
+                );
+                bind_point_requirements.set_len(bind_point_requirement_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -595,6 +1173,13 @@ pub trait ArmDataGraphExtensionDeviceCommands: DeviceV1_0 {
         info: &DataGraphPipelineSessionMemoryRequirementsInfoARM,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDataGraphPipelineSessionMemoryRequirementsARM",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_data_graph_pipeline_session_memory_requirements_arm)(
@@ -617,6 +1202,13 @@ pub trait ArmDataGraphExtensionInstanceCommands: InstanceV1_0 {
         queue_family_data_graph_processing_engine_info: &PhysicalDeviceQueueFamilyDataGraphProcessingEngineInfoARM,
         queue_family_data_graph_processing_engine_properties: &mut QueueFamilyDataGraphProcessingEnginePropertiesARM,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceQueueFamilyDataGraphProcessingEnginePropertiesARM",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_queue_family_data_graph_processing_engine_properties_arm)(
@@ -633,39 +1225,137 @@ pub trait ArmDataGraphExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         queue_family_index: u32,
     ) -> crate::VkResult<Vec<QueueFamilyDataGraphPropertiesARM>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceQueueFamilyDataGraphPropertiesARM",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut queue_family_data_graph_property_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_queue_family_data_graph_properties_arm)(
-            physical_device,
-            queue_family_index,
-            &mut queue_family_data_graph_property_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_queue_family_data_graph_properties_arm)(
+                physical_device,
+                queue_family_index,
+                &mut queue_family_data_graph_property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut queue_family_data_graph_properties =
+                Vec::with_capacity(queue_family_data_graph_property_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_queue_family_data_graph_properties_arm)(
+                physical_device,
+                queue_family_index,
+                &mut queue_family_data_graph_property_count,
+                queue_family_data_graph_properties.as_mut_ptr(),
+            );
 
-        let mut queue_family_data_graph_properties =
-            Vec::with_capacity(queue_family_data_graph_property_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_queue_family_data_graph_properties_arm)(
-            physical_device,
-            queue_family_index,
-            &mut queue_family_data_graph_property_count,
-            queue_family_data_graph_properties.as_mut_ptr(),
-        );
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
 
-        debug_assert!(
-            queue_family_data_graph_properties.capacity()
-                >= queue_family_data_graph_property_count as usize
-        );
-        queue_family_data_graph_properties.set_len(queue_family_data_graph_property_count as usize);
+            debug_assert!(
+                queue_family_data_graph_properties.capacity()
+                    >= queue_family_data_graph_property_count as usize
+            );
+            queue_family_data_graph_properties
+                .set_len(queue_family_data_graph_property_count as usize);
 
-        if __result == Result::SUCCESS {
-            Ok(queue_family_data_graph_properties)
-        } else {
-            Err(__result.into())
+            break if __result == Result::SUCCESS {
+                Ok(queue_family_data_graph_properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceQueueFamilyDataGraphPropertiesARM.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_queue_family_data_graph_properties_arm`].
+    #[inline]
+    unsafe fn get_physical_device_queue_family_data_graph_properties_arm_into(
+        &self,
+        physical_device: PhysicalDevice,
+        queue_family_index: u32,
+        queue_family_data_graph_properties: &mut Vec<QueueFamilyDataGraphPropertiesARM>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceQueueFamilyDataGraphPropertiesARM",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut queue_family_data_graph_property_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_queue_family_data_graph_properties_arm)(
+                physical_device,
+                queue_family_index,
+                &mut queue_family_data_graph_property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            queue_family_data_graph_properties.clear();
+            queue_family_data_graph_properties
+                .reserve(queue_family_data_graph_property_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_queue_family_data_graph_properties_arm)(
+                physical_device,
+                queue_family_index,
+                &mut queue_family_data_graph_property_count,
+                queue_family_data_graph_properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(
+                    queue_family_data_graph_properties.capacity()
+                        >= queue_family_data_graph_property_count as usize
+                );
+            }
+            queue_family_data_graph_properties
+                .set_len(queue_family_data_graph_property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -684,40 +1374,139 @@ pub trait ArmPerformanceCountersByRegionExtensionInstanceCommands: InstanceV1_0
         Vec<PerformanceCounterARM>,
         Vec<PerformanceCounterDescriptionARM>,
     )> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumeratePhysicalDeviceQueueFamilyPerformanceCountersByRegionARM",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut counter_count = 0;
 
-        (self
-            .commands()
-            .enumerate_physical_device_queue_family_performance_counters_by_region_arm)(
-            physical_device,
-            queue_family_index,
-            &mut counter_count,
-            ptr::null_mut(),
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .enumerate_physical_device_queue_family_performance_counters_by_region_arm)(
+                physical_device,
+                queue_family_index,
+                &mut counter_count,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut counters = Vec::with_capacity(counter_count as usize);
+            let mut counter_descriptions = Vec::with_capacity(counter_count as usize);
+
+            let __result = (self
+                .commands()
+                .enumerate_physical_device_queue_family_performance_counters_by_region_arm)(
+                physical_device,
+                queue_family_index,
+                &mut counter_count,
+                counters.as_mut_ptr(),
+                counter_descriptions.as_mut_ptr(),
+            );
 
-        let mut counters = Vec::with_capacity(counter_count as usize);
-        let mut counter_descriptions = Vec::with_capacity(counter_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        let __result = (self
-            .commands()
-            .enumerate_physical_device_queue_family_performance_counters_by_region_arm)(
-            physical_device,
-            queue_family_index,
-            &mut counter_count,
-            counters.as_mut_ptr(),
-            counter_descriptions.as_mut_ptr(),
-        );
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
 
-        debug_assert!(counters.capacity() >= counter_count as usize);
-        counters.set_len(counter_count as usize);
-        debug_assert!(counter_descriptions.capacity() >= counter_count as usize);
-        counter_descriptions.set_len(counter_count as usize);
+            debug_assert!(counters.capacity() >= counter_count as usize);
+            counters.set_len(counter_count as usize);
+            debug_assert!(counter_descriptions.capacity() >= counter_count as usize);
+            counter_descriptions.set_len(counter_count as usize);
 
-        if __result == Result::SUCCESS {
-            Ok((counters, counter_descriptions))
-        } else {
-            Err(__result.into())
+            break if __result == Result::SUCCESS {
+                Ok((counters, counter_descriptions))
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEnumeratePhysicalDeviceQueueFamilyPerformanceCountersByRegionARM.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::enumerate_physical_device_queue_family_performance_counters_by_region_arm`].
+    #[inline]
+    unsafe fn enumerate_physical_device_queue_family_performance_counters_by_region_arm_into(
+        &self,
+        physical_device: PhysicalDevice,
+        queue_family_index: u32,
+        counters: &mut Vec<PerformanceCounterARM>,
+        counter_descriptions: &mut Vec<PerformanceCounterDescriptionARM>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumeratePhysicalDeviceQueueFamilyPerformanceCountersByRegionARM",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut counter_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .enumerate_physical_device_queue_family_performance_counters_by_region_arm)(
+                physical_device,
+                queue_family_index,
+                &mut counter_count,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            counters.clear();
+            counters.reserve(counter_count as usize);
+            counter_descriptions.clear();
+            counter_descriptions.reserve(counter_count as usize);
+
+            let __result = (self
+                .commands()
+                .enumerate_physical_device_queue_family_performance_counters_by_region_arm)(
+                physical_device,
+                queue_family_index,
+                &mut counter_count,
+                counters.as_mut_ptr(),
+                counter_descriptions.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(counters.capacity() >= counter_count as usize);
+                counters.set_len(counter_count as usize);
+                debug_assert!(counter_descriptions.capacity() >= counter_count as usize);
+                counter_descriptions.set_len(counter_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -732,12 +1521,23 @@ pub trait ArmTensorsExtensionDeviceCommands: DeviceV1_0 {
         &self,
         bind_infos: &[impl Cast<Target = BindTensorMemoryInfoARM>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBindTensorMemoryARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().bind_tensor_memory_arm)(
             self.handle(),
             bind_infos.len() as u32,
             bind_infos.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -752,6 +1552,13 @@ pub trait ArmTensorsExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         copy_tensor_info: &CopyTensorInfoARM,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyTensorARM",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_tensor_arm)(command_buffer, copy_tensor_info);
     }
 
@@ -762,6 +1569,14 @@ pub trait ArmTensorsExtensionDeviceCommands: DeviceV1_0 {
         create_info: &TensorCreateInfoARM,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<TensorARM> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateTensorARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut tensor = MaybeUninit::<TensorARM>::uninit();
 
         let __result = (self.commands().create_tensor_arm)(
@@ -771,6 +1586,9 @@ pub trait ArmTensorsExtensionDeviceCommands: DeviceV1_0 {
             tensor.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(tensor.assume_init())
         } else {
@@ -785,6 +1603,14 @@ pub trait ArmTensorsExtensionDeviceCommands: DeviceV1_0 {
         create_info: &TensorViewCreateInfoARM,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<TensorViewARM> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateTensorViewARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut view = MaybeUninit::<TensorViewARM>::uninit();
 
         let __result = (self.commands().create_tensor_view_arm)(
@@ -794,6 +1620,9 @@ pub trait ArmTensorsExtensionDeviceCommands: DeviceV1_0 {
             view.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(view.assume_init())
         } else {
@@ -808,6 +1637,14 @@ pub trait ArmTensorsExtensionDeviceCommands: DeviceV1_0 {
         tensor: TensorARM,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyTensorARM",
+            device = tracing::field::debug(self.handle()),
+            tensor = tracing::field::debug(tensor)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_tensor_arm)(
             self.handle(),
             tensor,
@@ -822,6 +1659,14 @@ pub trait ArmTensorsExtensionDeviceCommands: DeviceV1_0 {
         tensor_view: TensorViewARM,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyTensorViewARM",
+            device = tracing::field::debug(self.handle()),
+            tensor_view = tracing::field::debug(tensor_view)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_tensor_view_arm)(
             self.handle(),
             tensor_view,
@@ -836,6 +1681,13 @@ pub trait ArmTensorsExtensionDeviceCommands: DeviceV1_0 {
         info: &DeviceTensorMemoryRequirementsARM,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceTensorMemoryRequirementsARM",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_device_tensor_memory_requirements_arm)(
             self.handle(),
             info,
@@ -850,6 +1702,13 @@ pub trait ArmTensorsExtensionDeviceCommands: DeviceV1_0 {
         info: &TensorMemoryRequirementsInfoARM,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetTensorMemoryRequirementsARM",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_tensor_memory_requirements_arm)(
             self.handle(),
             info,
@@ -864,12 +1723,23 @@ pub trait ArmTensorsExtensionDeviceCommands: DeviceV1_0 {
         info: &TensorCaptureDescriptorDataInfoARM,
         data: *mut c_void,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetTensorOpaqueCaptureDescriptorDataARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_tensor_opaque_capture_descriptor_data_arm)(
             self.handle(), info, data
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -884,12 +1754,23 @@ pub trait ArmTensorsExtensionDeviceCommands: DeviceV1_0 {
         info: &TensorViewCaptureDescriptorDataInfoARM,
         data: *mut c_void,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetTensorViewOpaqueCaptureDescriptorDataARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_tensor_view_opaque_capture_descriptor_data_arm)(
             self.handle(), info, data
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -910,6 +1791,13 @@ pub trait ArmTensorsExtensionInstanceCommands: InstanceV1_0 {
         external_tensor_info: &PhysicalDeviceExternalTensorInfoARM,
         external_tensor_properties: &mut ExternalTensorPropertiesARM,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceExternalTensorPropertiesARM",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_external_tensor_properties_arm)(
@@ -932,8 +1820,22 @@ pub trait ExtAcquireDrmDisplayExtensionInstanceCommands: InstanceV1_0 {
         drm_fd: i32,
         display: DisplayKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAcquireDrmDisplayEXT",
+            physical_device = tracing::field::debug(physical_device),
+            display = tracing::field::Empty,
+            result = tracing::field::Empty
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        __span.record("display", tracing::field::debug(display));
+
         let __result = (self.commands().acquire_drm_display_ext)(physical_device, drm_fd, display);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -949,6 +1851,14 @@ pub trait ExtAcquireDrmDisplayExtensionInstanceCommands: InstanceV1_0 {
         drm_fd: i32,
         connector_id: u32,
     ) -> crate::VkResult<DisplayKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDrmDisplayEXT",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut display = MaybeUninit::<DisplayKHR>::uninit();
 
         let __result = (self.commands().get_drm_display_ext)(
@@ -958,6 +1868,9 @@ pub trait ExtAcquireDrmDisplayExtensionInstanceCommands: InstanceV1_0 {
             display.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(display.assume_init())
         } else {
@@ -977,11 +1890,25 @@ pub trait ExtAcquireXlibDisplayExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         display: DisplayKHR,
     ) -> crate::VkResult<Display> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAcquireXlibDisplayEXT",
+            physical_device = tracing::field::debug(physical_device),
+            display = tracing::field::Empty,
+            result = tracing::field::Empty
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        __span.record("display", tracing::field::debug(display));
+
         let mut dpy = MaybeUninit::<Display>::uninit();
 
         let __result =
             (self.commands().acquire_xlib_display_ext)(physical_device, dpy.as_mut_ptr(), display);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(dpy.assume_init())
         } else {
@@ -996,6 +1923,14 @@ pub trait ExtAcquireXlibDisplayExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         rr_output: RROutput,
     ) -> crate::VkResult<(Display, DisplayKHR)> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetRandROutputDisplayEXT",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut dpy = MaybeUninit::<Display>::uninit();
         let mut display = MaybeUninit::<DisplayKHR>::uninit();
 
@@ -1006,6 +1941,9 @@ pub trait ExtAcquireXlibDisplayExtensionInstanceCommands: InstanceV1_0 {
             display.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok((dpy.assume_init(), display.assume_init()))
         } else {
@@ -1025,6 +1963,13 @@ pub trait ExtAttachmentFeedbackLoopDynamicStateExtensionDeviceCommands: DeviceV1
         command_buffer: CommandBuffer,
         aspect_mask: ImageAspectFlags,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetAttachmentFeedbackLoopEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_attachment_feedback_loop_enable_ext)(
             command_buffer,
             aspect_mask,
@@ -1042,6 +1987,13 @@ pub trait ExtBufferDeviceAddressExtensionDeviceCommands: DeviceV1_0 {
         &self,
         info: &BufferDeviceAddressInfo,
     ) -> DeviceAddress {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetBufferDeviceAddressEXT",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_buffer_device_address_ext)(self.handle(), info);
 
         __result
@@ -1059,6 +2011,14 @@ pub trait ExtCalibratedTimestampsExtensionDeviceCommands: DeviceV1_0 {
         &self,
         timestamp_infos: &[impl Cast<Target = CalibratedTimestampInfoKHR>],
     ) -> crate::VkResult<(Vec<u64>, u64)> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetCalibratedTimestampsEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut timestamps = Vec::with_capacity(timestamp_infos.len() as usize);
         let mut max_deviation = MaybeUninit::<u64>::uninit();
 
@@ -1070,6 +2030,9 @@ pub trait ExtCalibratedTimestampsExtensionDeviceCommands: DeviceV1_0 {
             max_deviation.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         debug_assert!(timestamps.capacity() >= timestamp_infos.len() as usize);
         timestamps.set_len(timestamp_infos.len() as usize);
 
@@ -1079,6 +2042,51 @@ pub trait ExtCalibratedTimestampsExtensionDeviceCommands: DeviceV1_0 {
             Err(__result.into())
         }
     }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetCalibratedTimestampsEXT.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_calibrated_timestamps_ext`].
+    #[inline]
+    unsafe fn get_calibrated_timestamps_ext_into(
+        &self,
+        timestamp_infos: &[impl Cast<Target = CalibratedTimestampInfoKHR>],
+        timestamps: &mut Vec<u64>,
+    ) -> crate::VkResult<u64> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetCalibratedTimestampsEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        timestamps.clear();
+        timestamps.reserve(timestamp_infos.len() as usize);
+        let mut max_deviation = MaybeUninit::<u64>::uninit();
+
+        let __result = (self.commands().get_calibrated_timestamps_ext)(
+            self.handle(),
+            timestamp_infos.len() as u32,
+            timestamp_infos.as_ptr().cast(),
+            timestamps.as_mut_ptr(),
+            max_deviation.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            debug_assert!(timestamps.capacity() >= timestamp_infos.len() as usize);
+            timestamps.set_len(timestamp_infos.len() as usize);
+        }
+
+        if __result == Result::SUCCESS {
+            Ok(max_deviation.assume_init())
+        } else {
+            Err(__result.into())
+        }
+    }
 }
 
 impl<C: DeviceV1_0 + ?Sized> ExtCalibratedTimestampsExtensionDeviceCommands for C {}
@@ -1091,33 +2099,122 @@ pub trait ExtCalibratedTimestampsExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<TimeDomainKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut time_domain_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_calibrateable_time_domains_ext)(
-            physical_device,
-            &mut time_domain_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_calibrateable_time_domains_ext)(
+                physical_device,
+                &mut time_domain_count,
+                ptr::null_mut(),
+            );
 
-        let mut time_domains = Vec::with_capacity(time_domain_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_calibrateable_time_domains_ext)(
-            physical_device,
-            &mut time_domain_count,
-            time_domains.as_mut_ptr(),
-        );
+            let mut time_domains = Vec::with_capacity(time_domain_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_calibrateable_time_domains_ext)(
+                physical_device,
+                &mut time_domain_count,
+                time_domains.as_mut_ptr(),
+            );
 
-        debug_assert!(time_domains.capacity() >= time_domain_count as usize);
-        time_domains.set_len(time_domain_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        if __result == Result::SUCCESS {
-            Ok(time_domains)
-        } else {
-            Err(__result.into())
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(time_domains.capacity() >= time_domain_count as usize);
+            time_domains.set_len(time_domain_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(time_domains)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceCalibrateableTimeDomainsEXT.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_calibrateable_time_domains_ext`].
+    #[inline]
+    unsafe fn get_physical_device_calibrateable_time_domains_ext_into(
+        &self,
+        physical_device: PhysicalDevice,
+        time_domains: &mut Vec<TimeDomainKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut time_domain_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_calibrateable_time_domains_ext)(
+                physical_device,
+                &mut time_domain_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            time_domains.clear();
+            time_domains.reserve(time_domain_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_calibrateable_time_domains_ext)(
+                physical_device,
+                &mut time_domain_count,
+                time_domains.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(time_domains.capacity() >= time_domain_count as usize);
+                time_domains.set_len(time_domain_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -1133,6 +2230,13 @@ pub trait ExtColorWriteEnableExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         color_write_enables: &[Bool32],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetColorWriteEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_color_write_enable_ext)(
             command_buffer,
             color_write_enables.len() as u32,
@@ -1152,6 +2256,13 @@ pub trait ExtConditionalRenderingExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         conditional_rendering_begin: &ConditionalRenderingBeginInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginConditionalRenderingEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_begin_conditional_rendering_ext)(
             command_buffer,
             conditional_rendering_begin,
@@ -1161,6 +2272,13 @@ pub trait ExtConditionalRenderingExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdEndConditionalRenderingEXT.html>
     #[inline]
     unsafe fn cmd_end_conditional_rendering_ext(&self, command_buffer: CommandBuffer) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndConditionalRenderingEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_end_conditional_rendering_ext)(command_buffer);
     }
 }
@@ -1176,6 +2294,13 @@ pub trait ExtCustomResolveExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         begin_custom_resolve_info: Option<&BeginCustomResolveInfoEXT>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginCustomResolveEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_begin_custom_resolve_ext)(
             command_buffer,
             begin_custom_resolve_info.map_or(ptr::null(), |v| v),
@@ -1194,12 +2319,26 @@ pub trait ExtDebugMarkerExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         marker_info: &DebugMarkerMarkerInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDebugMarkerBeginEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_debug_marker_begin_ext)(command_buffer, marker_info);
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdDebugMarkerEndEXT.html>
     #[inline]
     unsafe fn cmd_debug_marker_end_ext(&self, command_buffer: CommandBuffer) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDebugMarkerEndEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_debug_marker_end_ext)(command_buffer);
     }
 
@@ -1210,6 +2349,13 @@ pub trait ExtDebugMarkerExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         marker_info: &DebugMarkerMarkerInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDebugMarkerInsertEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_debug_marker_insert_ext)(command_buffer, marker_info);
     }
 
@@ -1219,8 +2365,19 @@ pub trait ExtDebugMarkerExtensionDeviceCommands: DeviceV1_0 {
         &self,
         name_info: &DebugMarkerObjectNameInfoEXT,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDebugMarkerSetObjectNameEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().debug_marker_set_object_name_ext)(self.handle(), name_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -1234,8 +2391,19 @@ pub trait ExtDebugMarkerExtensionDeviceCommands: DeviceV1_0 {
         &self,
         tag_info: &DebugMarkerObjectTagInfoEXT,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDebugMarkerSetObjectTagEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().debug_marker_set_object_tag_ext)(self.handle(), tag_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -1255,6 +2423,14 @@ pub trait ExtDebugReportExtensionInstanceCommands: InstanceV1_0 {
         create_info: &DebugReportCallbackCreateInfoEXT,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<DebugReportCallbackEXT> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDebugReportCallbackEXT",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut callback = MaybeUninit::<DebugReportCallbackEXT>::uninit();
 
         let __result = (self.commands().create_debug_report_callback_ext)(
@@ -1264,6 +2440,9 @@ pub trait ExtDebugReportExtensionInstanceCommands: InstanceV1_0 {
             callback.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(callback.assume_init())
         } else {
@@ -1283,6 +2462,13 @@ pub trait ExtDebugReportExtensionInstanceCommands: InstanceV1_0 {
         layer_prefix: &CStr,
         message: &CStr,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDebugReportMessageEXT",
+            instance = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().debug_report_message_ext)(
             self.handle(),
             flags,
@@ -1302,6 +2488,14 @@ pub trait ExtDebugReportExtensionInstanceCommands: InstanceV1_0 {
         callback: DebugReportCallbackEXT,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyDebugReportCallbackEXT",
+            instance = tracing::field::debug(self.handle()),
+            callback = tracing::field::debug(callback)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_debug_report_callback_ext)(
             self.handle(),
             callback,
@@ -1322,6 +2516,13 @@ pub trait ExtDebugUtilsExtensionInstanceCommands: InstanceV1_0 {
         command_buffer: CommandBuffer,
         label_info: &DebugUtilsLabelEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginDebugUtilsLabelEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_begin_debug_utils_label_ext)(command_buffer, label_info);
     }
@@ -1329,6 +2530,13 @@ pub trait ExtDebugUtilsExtensionInstanceCommands: InstanceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdEndDebugUtilsLabelEXT.html>
     #[inline]
     unsafe fn cmd_end_debug_utils_label_ext(&self, command_buffer: CommandBuffer) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndDebugUtilsLabelEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_end_debug_utils_label_ext)(command_buffer);
     }
 
@@ -1339,6 +2547,13 @@ pub trait ExtDebugUtilsExtensionInstanceCommands: InstanceV1_0 {
         command_buffer: CommandBuffer,
         label_info: &DebugUtilsLabelEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdInsertDebugUtilsLabelEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_insert_debug_utils_label_ext)(command_buffer, label_info);
     }
@@ -1350,6 +2565,14 @@ pub trait ExtDebugUtilsExtensionInstanceCommands: InstanceV1_0 {
         create_info: &DebugUtilsMessengerCreateInfoEXT,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<DebugUtilsMessengerEXT> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDebugUtilsMessengerEXT",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut messenger = MaybeUninit::<DebugUtilsMessengerEXT>::uninit();
 
         let __result = (self.commands().create_debug_utils_messenger_ext)(
@@ -1359,6 +2582,9 @@ pub trait ExtDebugUtilsExtensionInstanceCommands: InstanceV1_0 {
             messenger.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(messenger.assume_init())
         } else {
@@ -1373,6 +2599,14 @@ pub trait ExtDebugUtilsExtensionInstanceCommands: InstanceV1_0 {
         messenger: DebugUtilsMessengerEXT,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyDebugUtilsMessengerEXT",
+            instance = tracing::field::debug(self.handle()),
+            messenger = tracing::field::debug(messenger)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_debug_utils_messenger_ext)(
             self.handle(),
             messenger,
@@ -1387,12 +2621,26 @@ pub trait ExtDebugUtilsExtensionInstanceCommands: InstanceV1_0 {
         queue: Queue,
         label_info: &DebugUtilsLabelEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkQueueBeginDebugUtilsLabelEXT",
+            queue = tracing::field::debug(queue)
+        )
+        .entered();
+
         let __result = (self.commands().queue_begin_debug_utils_label_ext)(queue, label_info);
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkQueueEndDebugUtilsLabelEXT.html>
     #[inline]
     unsafe fn queue_end_debug_utils_label_ext(&self, queue: Queue) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkQueueEndDebugUtilsLabelEXT",
+            queue = tracing::field::debug(queue)
+        )
+        .entered();
+
         let __result = (self.commands().queue_end_debug_utils_label_ext)(queue);
     }
 
@@ -1403,6 +2651,13 @@ pub trait ExtDebugUtilsExtensionInstanceCommands: InstanceV1_0 {
         queue: Queue,
         label_info: &DebugUtilsLabelEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkQueueInsertDebugUtilsLabelEXT",
+            queue = tracing::field::debug(queue)
+        )
+        .entered();
+
         let __result = (self.commands().queue_insert_debug_utils_label_ext)(queue, label_info);
     }
 
@@ -1413,8 +2668,19 @@ pub trait ExtDebugUtilsExtensionInstanceCommands: InstanceV1_0 {
         device: Device,
         name_info: &DebugUtilsObjectNameInfoEXT,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetDebugUtilsObjectNameEXT",
+            device = tracing::field::debug(device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().set_debug_utils_object_name_ext)(device, name_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -1429,8 +2695,19 @@ pub trait ExtDebugUtilsExtensionInstanceCommands: InstanceV1_0 {
         device: Device,
         tag_info: &DebugUtilsObjectTagInfoEXT,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetDebugUtilsObjectTagEXT",
+            device = tracing::field::debug(device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().set_debug_utils_object_tag_ext)(device, tag_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -1446,6 +2723,13 @@ pub trait ExtDebugUtilsExtensionInstanceCommands: InstanceV1_0 {
         message_types: DebugUtilsMessageTypeFlagsEXT,
         callback_data: &DebugUtilsMessengerCallbackDataEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSubmitDebugUtilsMessageEXT",
+            instance = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().submit_debug_utils_message_ext)(
             self.handle(),
             message_severity,
@@ -1466,6 +2750,13 @@ pub trait ExtDepthBiasControlExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_bias_info: &DepthBiasInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthBias2EXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_bias2_ext)(command_buffer, depth_bias_info);
     }
 }
@@ -1482,6 +2773,13 @@ pub trait ExtDepthClampControlExtensionDeviceCommands: DeviceV1_0 {
         depth_clamp_mode: DepthClampModeEXT,
         depth_clamp_range: Option<&DepthClampRangeEXT>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthClampRangeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_clamp_range_ext)(
             command_buffer,
             depth_clamp_mode,
@@ -1503,6 +2801,14 @@ pub trait ExtDescriptorBufferExtensionDeviceCommands: DeviceV1_0 {
         layout: PipelineLayout,
         set: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindDescriptorBufferEmbeddedSamplersEXT",
+            command_buffer = tracing::field::debug(command_buffer),
+            layout = tracing::field::debug(layout)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .cmd_bind_descriptor_buffer_embedded_samplers_ext)(
@@ -1520,6 +2826,13 @@ pub trait ExtDescriptorBufferExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         binding_infos: &[impl Cast<Target = DescriptorBufferBindingInfoEXT>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindDescriptorBuffersEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_descriptor_buffers_ext)(
             command_buffer,
             binding_infos.len() as u32,
@@ -1538,6 +2851,14 @@ pub trait ExtDescriptorBufferExtensionDeviceCommands: DeviceV1_0 {
         buffer_indices: &[u32],
         offsets: &[DeviceSize],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDescriptorBufferOffsetsEXT",
+            command_buffer = tracing::field::debug(command_buffer),
+            layout = tracing::field::debug(layout)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_descriptor_buffer_offsets_ext)(
             command_buffer,
             pipeline_bind_point,
@@ -1556,6 +2877,14 @@ pub trait ExtDescriptorBufferExtensionDeviceCommands: DeviceV1_0 {
         info: &AccelerationStructureCaptureDescriptorDataInfoEXT,
         data: *mut c_void,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_acceleration_structure_opaque_capture_descriptor_data_ext)(
@@ -1564,6 +2893,9 @@ pub trait ExtDescriptorBufferExtensionDeviceCommands: DeviceV1_0 {
             data,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -1578,12 +2910,23 @@ pub trait ExtDescriptorBufferExtensionDeviceCommands: DeviceV1_0 {
         info: &BufferCaptureDescriptorDataInfoEXT,
         data: *mut c_void,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetBufferOpaqueCaptureDescriptorDataEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_buffer_opaque_capture_descriptor_data_ext)(
             self.handle(), info, data
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -1598,6 +2941,13 @@ pub trait ExtDescriptorBufferExtensionDeviceCommands: DeviceV1_0 {
         descriptor_info: &DescriptorGetInfoEXT,
         descriptor: &mut [u8],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDescriptorEXT",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_descriptor_ext)(
             self.handle(),
             descriptor_info,
@@ -1613,6 +2963,14 @@ pub trait ExtDescriptorBufferExtensionDeviceCommands: DeviceV1_0 {
         layout: DescriptorSetLayout,
         binding: u32,
     ) -> DeviceSize {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDescriptorSetLayoutBindingOffsetEXT",
+            device = tracing::field::debug(self.handle()),
+            layout = tracing::field::debug(layout)
+        )
+        .entered();
+
         let mut offset = MaybeUninit::<DeviceSize>::uninit();
 
         let __result = (self.commands().get_descriptor_set_layout_binding_offset_ext)(
@@ -1628,6 +2986,14 @@ pub trait ExtDescriptorBufferExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetDescriptorSetLayoutSizeEXT.html>
     #[inline]
     unsafe fn get_descriptor_set_layout_size_ext(&self, layout: DescriptorSetLayout) -> DeviceSize {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDescriptorSetLayoutSizeEXT",
+            device = tracing::field::debug(self.handle()),
+            layout = tracing::field::debug(layout)
+        )
+        .entered();
+
         let mut layout_size_in_bytes = MaybeUninit::<DeviceSize>::uninit();
 
         let __result = (self.commands().get_descriptor_set_layout_size_ext)(
@@ -1646,12 +3012,23 @@ pub trait ExtDescriptorBufferExtensionDeviceCommands: DeviceV1_0 {
         info: &ImageCaptureDescriptorDataInfoEXT,
         data: *mut c_void,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageOpaqueCaptureDescriptorDataEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_image_opaque_capture_descriptor_data_ext)(
             self.handle(),
             info,
             data,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -1666,12 +3043,23 @@ pub trait ExtDescriptorBufferExtensionDeviceCommands: DeviceV1_0 {
         info: &ImageViewCaptureDescriptorDataInfoEXT,
         data: *mut c_void,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageViewOpaqueCaptureDescriptorDataEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_image_view_opaque_capture_descriptor_data_ext)(
             self.handle(), info, data
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -1686,12 +3074,23 @@ pub trait ExtDescriptorBufferExtensionDeviceCommands: DeviceV1_0 {
         info: &SamplerCaptureDescriptorDataInfoEXT,
         data: *mut c_void,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSamplerOpaqueCaptureDescriptorDataEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_sampler_opaque_capture_descriptor_data_ext)(
             self.handle(), info, data
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -1712,6 +3111,13 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         bind_info: &BindHeapInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindResourceHeapEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_resource_heap_ext)(command_buffer, bind_info);
     }
 
@@ -1722,6 +3128,13 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         bind_info: &BindHeapInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindSamplerHeapEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_sampler_heap_ext)(command_buffer, bind_info);
     }
 
@@ -1732,6 +3145,13 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         push_data_info: &PushDataInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushDataEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_push_data_ext)(command_buffer, push_data_info);
     }
 
@@ -1741,6 +3161,14 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
         &self,
         images: &[Image],
     ) -> crate::VkResult<Vec<HostAddressRangeEXT>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageOpaqueCaptureDataEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut datas = Vec::with_capacity(images.len() as usize);
 
         let __result = (self.commands().get_image_opaque_capture_data_ext)(
@@ -1750,6 +3178,9 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
             datas.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         debug_assert!(datas.capacity() >= images.len() as usize);
         datas.set_len(images.len() as usize);
 
@@ -1760,12 +3191,63 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
         }
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetImageOpaqueCaptureDataEXT.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_image_opaque_capture_data_ext`].
+    #[inline]
+    unsafe fn get_image_opaque_capture_data_ext_into(
+        &self,
+        images: &[Image],
+        datas: &mut Vec<HostAddressRangeEXT>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageOpaqueCaptureDataEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        datas.clear();
+        datas.reserve(images.len() as usize);
+
+        let __result = (self.commands().get_image_opaque_capture_data_ext)(
+            self.handle(),
+            images.len() as u32,
+            images.as_ptr(),
+            datas.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            debug_assert!(datas.capacity() >= images.len() as usize);
+            datas.set_len(images.len() as usize);
+        }
+
+        if __result == Result::SUCCESS {
+            Ok(())
+        } else {
+            Err(__result.into())
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetTensorOpaqueCaptureDataARM.html>
     #[inline]
     unsafe fn get_tensor_opaque_capture_data_arm(
         &self,
         tensors: &[TensorARM],
     ) -> crate::VkResult<Vec<HostAddressRangeEXT>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetTensorOpaqueCaptureDataARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut datas = Vec::with_capacity(tensors.len() as usize);
 
         let __result = (self.commands().get_tensor_opaque_capture_data_arm)(
@@ -1775,6 +3257,9 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
             datas.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         debug_assert!(datas.capacity() >= tensors.len() as usize);
         datas.set_len(tensors.len() as usize);
 
@@ -1785,6 +3270,49 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
         }
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetTensorOpaqueCaptureDataARM.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_tensor_opaque_capture_data_arm`].
+    #[inline]
+    unsafe fn get_tensor_opaque_capture_data_arm_into(
+        &self,
+        tensors: &[TensorARM],
+        datas: &mut Vec<HostAddressRangeEXT>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetTensorOpaqueCaptureDataARM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        datas.clear();
+        datas.reserve(tensors.len() as usize);
+
+        let __result = (self.commands().get_tensor_opaque_capture_data_arm)(
+            self.handle(),
+            tensors.len() as u32,
+            tensors.as_ptr(),
+            datas.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            debug_assert!(datas.capacity() >= tensors.len() as usize);
+            datas.set_len(tensors.len() as usize);
+        }
+
+        if __result == Result::SUCCESS {
+            Ok(())
+        } else {
+            Err(__result.into())
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkRegisterCustomBorderColorEXT.html>
     #[inline]
     unsafe fn register_custom_border_color_ext(
@@ -1792,6 +3320,14 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
         border_color: &SamplerCustomBorderColorCreateInfoEXT,
         request_index: bool,
     ) -> crate::VkResult<u32> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkRegisterCustomBorderColorEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut index = MaybeUninit::<u32>::uninit();
 
         let __result = (self.commands().register_custom_border_color_ext)(
@@ -1801,6 +3337,9 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
             index.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(index.assume_init())
         } else {
@@ -1811,6 +3350,13 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkUnregisterCustomBorderColorEXT.html>
     #[inline]
     unsafe fn unregister_custom_border_color_ext(&self, index: u32) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkUnregisterCustomBorderColorEXT",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().unregister_custom_border_color_ext)(self.handle(), index);
     }
 
@@ -1821,6 +3367,14 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
         resources: &[impl Cast<Target = ResourceDescriptorInfoEXT>],
         descriptors: &[impl Cast<Target = HostAddressRangeEXT>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkWriteResourceDescriptorsEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().write_resource_descriptors_ext)(
             self.handle(),
             resources.len() as u32,
@@ -1828,6 +3382,9 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
             descriptors.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -1842,6 +3399,14 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
         samplers: &[impl Cast<Target = SamplerCreateInfo>],
         descriptors: &[impl Cast<Target = HostAddressRangeEXT>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkWriteSamplerDescriptorsEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().write_sampler_descriptors_ext)(
             self.handle(),
             samplers.len() as u32,
@@ -1849,6 +3414,9 @@ pub trait ExtDescriptorHeapExtensionDeviceCommands: DeviceV1_0 {
             descriptors.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -1868,6 +3436,13 @@ pub trait ExtDescriptorHeapExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         descriptor_type: DescriptorType,
     ) -> DeviceSize {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceDescriptorSizeEXT",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self.commands().get_physical_device_descriptor_size_ext)(
             physical_device,
             descriptor_type,
@@ -1888,12 +3463,23 @@ pub trait ExtDeviceFaultExtensionDeviceCommands: DeviceV1_0 {
         fault_counts: &mut DeviceFaultCountsEXT,
         fault_info: Option<&mut DeviceFaultInfoEXT>,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceFaultInfoEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_device_fault_info_ext)(
             self.handle(),
             fault_counts,
             fault_info.map_or(ptr::null_mut(), |v| v),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -1914,6 +3500,13 @@ pub trait ExtDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         is_preprocessed: bool,
         generated_commands_info: &GeneratedCommandsInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdExecuteGeneratedCommandsEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_execute_generated_commands_ext)(
             command_buffer,
             is_preprocessed as Bool32,
@@ -1929,6 +3522,14 @@ pub trait ExtDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         generated_commands_info: &GeneratedCommandsInfoEXT,
         state_command_buffer: CommandBuffer,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPreprocessGeneratedCommandsEXT",
+            command_buffer = tracing::field::debug(command_buffer),
+            state_command_buffer = tracing::field::debug(state_command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_preprocess_generated_commands_ext)(
             command_buffer,
             generated_commands_info,
@@ -1943,6 +3544,14 @@ pub trait ExtDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         create_info: &IndirectCommandsLayoutCreateInfoEXT,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<IndirectCommandsLayoutEXT> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateIndirectCommandsLayoutEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut indirect_commands_layout = MaybeUninit::<IndirectCommandsLayoutEXT>::uninit();
 
         let __result = (self.commands().create_indirect_commands_layout_ext)(
@@ -1952,6 +3561,9 @@ pub trait ExtDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
             indirect_commands_layout.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(indirect_commands_layout.assume_init())
         } else {
@@ -1966,6 +3578,14 @@ pub trait ExtDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         create_info: &IndirectExecutionSetCreateInfoEXT,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<IndirectExecutionSetEXT> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateIndirectExecutionSetEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut indirect_execution_set = MaybeUninit::<IndirectExecutionSetEXT>::uninit();
 
         let __result = (self.commands().create_indirect_execution_set_ext)(
@@ -1975,6 +3595,9 @@ pub trait ExtDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
             indirect_execution_set.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(indirect_execution_set.assume_init())
         } else {
@@ -1989,6 +3612,14 @@ pub trait ExtDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         indirect_commands_layout: IndirectCommandsLayoutEXT,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyIndirectCommandsLayoutEXT",
+            device = tracing::field::debug(self.handle()),
+            indirect_commands_layout = tracing::field::debug(indirect_commands_layout)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_indirect_commands_layout_ext)(
             self.handle(),
             indirect_commands_layout,
@@ -2003,6 +3634,14 @@ pub trait ExtDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         indirect_execution_set: IndirectExecutionSetEXT,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyIndirectExecutionSetEXT",
+            device = tracing::field::debug(self.handle()),
+            indirect_execution_set = tracing::field::debug(indirect_execution_set)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_indirect_execution_set_ext)(
             self.handle(),
             indirect_execution_set,
@@ -2017,6 +3656,13 @@ pub trait ExtDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         info: &GeneratedCommandsMemoryRequirementsInfoEXT,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetGeneratedCommandsMemoryRequirementsEXT",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_generated_commands_memory_requirements_ext)(
@@ -2033,6 +3679,14 @@ pub trait ExtDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         indirect_execution_set: IndirectExecutionSetEXT,
         execution_set_writes: &[impl Cast<Target = WriteIndirectExecutionSetPipelineEXT>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkUpdateIndirectExecutionSetPipelineEXT",
+            device = tracing::field::debug(self.handle()),
+            indirect_execution_set = tracing::field::debug(indirect_execution_set)
+        )
+        .entered();
+
         let __result = (self.commands().update_indirect_execution_set_pipeline_ext)(
             self.handle(),
             indirect_execution_set,
@@ -2048,6 +3702,14 @@ pub trait ExtDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         indirect_execution_set: IndirectExecutionSetEXT,
         execution_set_writes: &[impl Cast<Target = WriteIndirectExecutionSetShaderEXT>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkUpdateIndirectExecutionSetShaderEXT",
+            device = tracing::field::debug(self.handle()),
+            indirect_execution_set = tracing::field::debug(indirect_execution_set)
+        )
+        .entered();
+
         let __result = (self.commands().update_indirect_execution_set_shader_ext)(
             self.handle(),
             indirect_execution_set,
@@ -2068,8 +3730,22 @@ pub trait ExtDirectModeDisplayExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         display: DisplayKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkReleaseDisplayEXT",
+            physical_device = tracing::field::debug(physical_device),
+            display = tracing::field::Empty,
+            result = tracing::field::Empty
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        __span.record("display", tracing::field::debug(display));
+
         let __result = (self.commands().release_display_ext)(physical_device, display);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2089,6 +3765,14 @@ pub trait ExtDirectfbSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &DirectFBSurfaceCreateInfoEXT,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDirectFBSurfaceEXT",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_direct_fb_surface_ext)(
@@ -2098,6 +3782,9 @@ pub trait ExtDirectfbSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -2113,6 +3800,13 @@ pub trait ExtDirectfbSurfaceExtensionInstanceCommands: InstanceV1_0 {
         queue_family_index: u32,
         dfb: *mut IDirectFB,
     ) -> Bool32 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceDirectFBPresentationSupportEXT",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_direct_fb_presentation_support_ext)(
@@ -2137,6 +3831,13 @@ pub trait ExtDiscardRectanglesExtensionDeviceCommands: DeviceV1_0 {
         first_discard_rectangle: u32,
         discard_rectangles: &[impl Cast<Target = Rect2D>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDiscardRectangleEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_discard_rectangle_ext)(
             command_buffer,
             first_discard_rectangle,
@@ -2152,6 +3853,13 @@ pub trait ExtDiscardRectanglesExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         discard_rectangle_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDiscardRectangleEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_discard_rectangle_enable_ext)(
             command_buffer,
             discard_rectangle_enable as Bool32,
@@ -2165,6 +3873,13 @@ pub trait ExtDiscardRectanglesExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         discard_rectangle_mode: DiscardRectangleModeEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDiscardRectangleModeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_discard_rectangle_mode_ext)(
             command_buffer,
             discard_rectangle_mode,
@@ -2183,9 +3898,23 @@ pub trait ExtDisplayControlExtensionDeviceCommands: DeviceV1_0 {
         display: DisplayKHR,
         display_power_info: &DisplayPowerInfoEXT,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDisplayPowerControlEXT",
+            device = tracing::field::debug(self.handle()),
+            display = tracing::field::Empty,
+            result = tracing::field::Empty
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        __span.record("display", tracing::field::debug(display));
+
         let __result =
             (self.commands().display_power_control_ext)(self.handle(), display, display_power_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2200,6 +3929,15 @@ pub trait ExtDisplayControlExtensionDeviceCommands: DeviceV1_0 {
         swapchain: SwapchainKHR,
         counter: SurfaceCounterFlagsEXT,
     ) -> crate::VkResult<u64> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSwapchainCounterEXT",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut counter_value = MaybeUninit::<u64>::uninit();
 
         let __result = (self.commands().get_swapchain_counter_ext)(
@@ -2209,6 +3947,9 @@ pub trait ExtDisplayControlExtensionDeviceCommands: DeviceV1_0 {
             counter_value.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(counter_value.assume_init())
         } else {
@@ -2223,6 +3964,14 @@ pub trait ExtDisplayControlExtensionDeviceCommands: DeviceV1_0 {
         device_event_info: &DeviceEventInfoEXT,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<Fence> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkRegisterDeviceEventEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut fence = MaybeUninit::<Fence>::uninit();
 
         let __result = (self.commands().register_device_event_ext)(
@@ -2232,6 +3981,9 @@ pub trait ExtDisplayControlExtensionDeviceCommands: DeviceV1_0 {
             fence.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(fence.assume_init())
         } else {
@@ -2247,6 +3999,17 @@ pub trait ExtDisplayControlExtensionDeviceCommands: DeviceV1_0 {
         display_event_info: &DisplayEventInfoEXT,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<Fence> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkRegisterDisplayEventEXT",
+            device = tracing::field::debug(self.handle()),
+            display = tracing::field::Empty,
+            result = tracing::field::Empty
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        __span.record("display", tracing::field::debug(display));
+
         let mut fence = MaybeUninit::<Fence>::uninit();
 
         let __result = (self.commands().register_display_event_ext)(
@@ -2257,6 +4020,9 @@ pub trait ExtDisplayControlExtensionDeviceCommands: DeviceV1_0 {
             fence.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(fence.assume_init())
         } else {
@@ -2277,6 +4043,15 @@ pub trait ExtDisplaySurfaceCounterExtensionInstanceCommands: InstanceV1_0 {
         surface: SurfaceKHR,
         surface_capabilities: &mut SurfaceCapabilities2EXT,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSurfaceCapabilities2EXT",
+            physical_device = tracing::field::debug(physical_device),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_surface_capabilities2_ext)(
@@ -2285,6 +4060,9 @@ pub trait ExtDisplaySurfaceCounterExtensionInstanceCommands: InstanceV1_0 {
             surface_capabilities,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2308,6 +4086,13 @@ pub trait ExtExtendedDynamicStateExtensionDeviceCommands: DeviceV1_0 {
         sizes: &[DeviceSize],
         strides: &[DeviceSize],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindVertexBuffers2EXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_vertex_buffers2_ext)(
             command_buffer,
             first_binding,
@@ -2326,6 +4111,13 @@ pub trait ExtExtendedDynamicStateExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         cull_mode: CullModeFlags,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCullModeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_cull_mode_ext)(command_buffer, cull_mode);
     }
 
@@ -2336,6 +4128,13 @@ pub trait ExtExtendedDynamicStateExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_bounds_test_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthBoundsTestEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_bounds_test_enable_ext)(
             command_buffer,
             depth_bounds_test_enable as Bool32,
@@ -2349,6 +4148,13 @@ pub trait ExtExtendedDynamicStateExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_compare_op: CompareOp,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthCompareOpEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_depth_compare_op_ext)(command_buffer, depth_compare_op);
     }
@@ -2360,6 +4166,13 @@ pub trait ExtExtendedDynamicStateExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_test_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthTestEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_test_enable_ext)(
             command_buffer,
             depth_test_enable as Bool32,
@@ -2373,6 +4186,13 @@ pub trait ExtExtendedDynamicStateExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_write_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthWriteEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_write_enable_ext)(
             command_buffer,
             depth_write_enable as Bool32,
@@ -2382,6 +4202,13 @@ pub trait ExtExtendedDynamicStateExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdSetFrontFaceEXT.html>
     #[inline]
     unsafe fn cmd_set_front_face_ext(&self, command_buffer: CommandBuffer, front_face: FrontFace) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetFrontFaceEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_front_face_ext)(command_buffer, front_face);
     }
 
@@ -2392,6 +4219,13 @@ pub trait ExtExtendedDynamicStateExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         primitive_topology: PrimitiveTopology,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPrimitiveTopologyEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_primitive_topology_ext)(command_buffer, primitive_topology);
     }
@@ -2403,6 +4237,13 @@ pub trait ExtExtendedDynamicStateExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         scissors: &[impl Cast<Target = Rect2D>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetScissorWithCountEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_scissor_with_count_ext)(
             command_buffer,
             scissors.len() as u32,
@@ -2421,6 +4262,13 @@ pub trait ExtExtendedDynamicStateExtensionDeviceCommands: DeviceV1_0 {
         depth_fail_op: StencilOp,
         compare_op: CompareOp,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetStencilOpEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_stencil_op_ext)(
             command_buffer,
             face_mask,
@@ -2438,6 +4286,13 @@ pub trait ExtExtendedDynamicStateExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         stencil_test_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetStencilTestEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_stencil_test_enable_ext)(
             command_buffer,
             stencil_test_enable as Bool32,
@@ -2451,6 +4306,13 @@ pub trait ExtExtendedDynamicStateExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         viewports: &[impl Cast<Target = Viewport>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetViewportWithCountEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_viewport_with_count_ext)(
             command_buffer,
             viewports.len() as u32,
@@ -2470,6 +4332,13 @@ pub trait ExtExtendedDynamicState2ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_bias_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthBiasEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_bias_enable_ext)(
             command_buffer,
             depth_bias_enable as Bool32,
@@ -2479,6 +4348,13 @@ pub trait ExtExtendedDynamicState2ExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdSetLogicOpEXT.html>
     #[inline]
     unsafe fn cmd_set_logic_op_ext(&self, command_buffer: CommandBuffer, logic_op: LogicOp) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetLogicOpEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_logic_op_ext)(command_buffer, logic_op);
     }
 
@@ -2489,6 +4365,13 @@ pub trait ExtExtendedDynamicState2ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         patch_control_points: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPatchControlPointsEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_patch_control_points_ext)(
             command_buffer,
             patch_control_points,
@@ -2502,6 +4385,13 @@ pub trait ExtExtendedDynamicState2ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         primitive_restart_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPrimitiveRestartEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_primitive_restart_enable_ext)(
             command_buffer,
             primitive_restart_enable as Bool32,
@@ -2515,6 +4405,13 @@ pub trait ExtExtendedDynamicState2ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         rasterizer_discard_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRasterizerDiscardEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_rasterizer_discard_enable_ext)(
             command_buffer,
             rasterizer_discard_enable as Bool32,
@@ -2533,6 +4430,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         alpha_to_coverage_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetAlphaToCoverageEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_alpha_to_coverage_enable_ext)(
             command_buffer,
             alpha_to_coverage_enable as Bool32,
@@ -2546,6 +4450,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         alpha_to_one_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetAlphaToOneEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_alpha_to_one_enable_ext)(
             command_buffer,
             alpha_to_one_enable as Bool32,
@@ -2560,6 +4471,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         first_attachment: u32,
         color_blend_advanced: &[impl Cast<Target = ColorBlendAdvancedEXT>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetColorBlendAdvancedEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_color_blend_advanced_ext)(
             command_buffer,
             first_attachment,
@@ -2576,6 +4494,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         first_attachment: u32,
         color_blend_enables: &[Bool32],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetColorBlendEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_color_blend_enable_ext)(
             command_buffer,
             first_attachment,
@@ -2592,6 +4517,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         first_attachment: u32,
         color_blend_equations: &[impl Cast<Target = ColorBlendEquationEXT>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetColorBlendEquationEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_color_blend_equation_ext)(
             command_buffer,
             first_attachment,
@@ -2608,6 +4540,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         first_attachment: u32,
         color_write_masks: &[ColorComponentFlags],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetColorWriteMaskEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_color_write_mask_ext)(
             command_buffer,
             first_attachment,
@@ -2623,6 +4562,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         conservative_rasterization_mode: ConservativeRasterizationModeEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetConservativeRasterizationModeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_conservative_rasterization_mode_ext)(
             command_buffer,
             conservative_rasterization_mode,
@@ -2636,6 +4582,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coverage_modulation_mode: CoverageModulationModeNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoverageModulationModeNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coverage_modulation_mode_nv)(
             command_buffer,
             coverage_modulation_mode,
@@ -2649,6 +4602,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coverage_modulation_table_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoverageModulationTableEnableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coverage_modulation_table_enable_nv)(
             command_buffer,
             coverage_modulation_table_enable as Bool32,
@@ -2662,6 +4622,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coverage_modulation_table: &[f32],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoverageModulationTableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coverage_modulation_table_nv)(
             command_buffer,
             coverage_modulation_table.len() as u32,
@@ -2676,6 +4643,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coverage_reduction_mode: CoverageReductionModeNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoverageReductionModeNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coverage_reduction_mode_nv)(
             command_buffer,
             coverage_reduction_mode,
@@ -2689,6 +4663,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coverage_to_color_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoverageToColorEnableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coverage_to_color_enable_nv)(
             command_buffer,
             coverage_to_color_enable as Bool32,
@@ -2702,6 +4683,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coverage_to_color_location: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoverageToColorLocationNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coverage_to_color_location_nv)(
             command_buffer,
             coverage_to_color_location,
@@ -2715,6 +4703,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_clamp_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthClampEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_clamp_enable_ext)(
             command_buffer,
             depth_clamp_enable as Bool32,
@@ -2728,6 +4723,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_clip_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthClipEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_clip_enable_ext)(
             command_buffer,
             depth_clip_enable as Bool32,
@@ -2741,6 +4743,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         negative_one_to_one: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthClipNegativeOneToOneEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_clip_negative_one_to_one_ext)(
             command_buffer,
             negative_one_to_one as Bool32,
@@ -2754,6 +4763,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         extra_primitive_overestimation_size: f32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetExtraPrimitiveOverestimationSizeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .cmd_set_extra_primitive_overestimation_size_ext)(
@@ -2769,6 +4785,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         line_rasterization_mode: LineRasterizationModeEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetLineRasterizationModeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_line_rasterization_mode_ext)(
             command_buffer,
             line_rasterization_mode,
@@ -2782,6 +4805,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         stippled_line_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetLineStippleEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_line_stipple_enable_ext)(
             command_buffer,
             stippled_line_enable as Bool32,
@@ -2795,6 +4825,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         logic_op_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetLogicOpEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_logic_op_enable_ext)(
             command_buffer,
             logic_op_enable as Bool32,
@@ -2808,6 +4845,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         polygon_mode: PolygonMode,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPolygonModeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_polygon_mode_ext)(command_buffer, polygon_mode);
     }
 
@@ -2818,6 +4862,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         provoking_vertex_mode: ProvokingVertexModeEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetProvokingVertexModeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_provoking_vertex_mode_ext)(
             command_buffer,
             provoking_vertex_mode,
@@ -2831,6 +4882,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         rasterization_samples: SampleCountFlags,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRasterizationSamplesEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_rasterization_samples_ext)(
             command_buffer,
             rasterization_samples,
@@ -2844,6 +4902,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         rasterization_stream: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRasterizationStreamEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_rasterization_stream_ext)(
             command_buffer,
             rasterization_stream,
@@ -2857,6 +4922,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         representative_fragment_test_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRepresentativeFragmentTestEnableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .cmd_set_representative_fragment_test_enable_nv)(
@@ -2872,6 +4944,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         sample_locations_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetSampleLocationsEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_sample_locations_enable_ext)(
             command_buffer,
             sample_locations_enable as Bool32,
@@ -2886,6 +4965,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         samples: SampleCountFlags,
         sample_mask: Option<&SampleMask>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetSampleMaskEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_sample_mask_ext)(
             command_buffer,
             samples,
@@ -2900,6 +4986,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         shading_rate_image_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetShadingRateImageEnableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_shading_rate_image_enable_nv)(
             command_buffer,
             shading_rate_image_enable as Bool32,
@@ -2913,6 +5006,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         domain_origin: TessellationDomainOrigin,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetTessellationDomainOriginEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_tessellation_domain_origin_ext)(command_buffer, domain_origin);
     }
@@ -2925,6 +5025,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         first_viewport: u32,
         viewport_swizzles: &[impl Cast<Target = ViewportSwizzleNV>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetViewportSwizzleNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_viewport_swizzle_nv)(
             command_buffer,
             first_viewport,
@@ -2940,6 +5047,13 @@ pub trait ExtExtendedDynamicState3ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         viewport_w_scaling_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetViewportWScalingEnableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_viewport_w_scaling_enable_nv)(
             command_buffer,
             viewport_w_scaling_enable as Bool32,
@@ -2959,6 +5073,14 @@ pub trait ExtExternalMemoryHostExtensionDeviceCommands: DeviceV1_0 {
         host_pointer: *const c_void,
         memory_host_pointer_properties: &mut MemoryHostPointerPropertiesEXT,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryHostPointerPropertiesEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_memory_host_pointer_properties_ext)(
             self.handle(),
             handle_type,
@@ -2966,6 +5088,9 @@ pub trait ExtExternalMemoryHostExtensionDeviceCommands: DeviceV1_0 {
             memory_host_pointer_properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2984,6 +5109,14 @@ pub trait ExtExternalMemoryMetalExtensionDeviceCommands: DeviceV1_0 {
         &self,
         get_metal_handle_info: &MemoryGetMetalHandleInfoEXT,
     ) -> crate::VkResult<*mut c_void> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryMetalHandleEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut handle = MaybeUninit::<*mut c_void>::uninit();
 
         let __result = (self.commands().get_memory_metal_handle_ext)(
@@ -2992,6 +5125,9 @@ pub trait ExtExternalMemoryMetalExtensionDeviceCommands: DeviceV1_0 {
             handle.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(handle.assume_init())
         } else {
@@ -3007,6 +5143,14 @@ pub trait ExtExternalMemoryMetalExtensionDeviceCommands: DeviceV1_0 {
         handle: *const c_void,
         memory_metal_handle_properties: &mut MemoryMetalHandlePropertiesEXT,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryMetalHandlePropertiesEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_memory_metal_handle_properties_ext)(
             self.handle(),
             handle_type,
@@ -3014,6 +5158,9 @@ pub trait ExtExternalMemoryMetalExtensionDeviceCommands: DeviceV1_0 {
             memory_metal_handle_properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3033,6 +5180,13 @@ pub trait ExtFragmentDensityMapOffsetExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         rendering_end_info: Option<&RenderingEndInfoKHR>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndRendering2EXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_end_rendering2_ext)(
             command_buffer,
             rendering_end_info.map_or(ptr::null(), |v| v),
@@ -3050,9 +5204,21 @@ pub trait ExtFullScreenExclusiveExtensionDeviceCommands: DeviceV1_0 {
         &self,
         swapchain: SwapchainKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAcquireFullScreenExclusiveModeEXT",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().acquire_full_screen_exclusive_mode_ext)(self.handle(), swapchain);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3066,6 +5232,14 @@ pub trait ExtFullScreenExclusiveExtensionDeviceCommands: DeviceV1_0 {
         &self,
         surface_info: &PhysicalDeviceSurfaceInfo2KHR,
     ) -> crate::VkResult<DeviceGroupPresentModeFlagsKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceGroupSurfacePresentModes2EXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut modes = MaybeUninit::<DeviceGroupPresentModeFlagsKHR>::uninit();
 
         let __result = (self.commands().get_device_group_surface_present_modes2_ext)(
@@ -3074,6 +5248,9 @@ pub trait ExtFullScreenExclusiveExtensionDeviceCommands: DeviceV1_0 {
             modes.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(modes.assume_init())
         } else {
@@ -3087,9 +5264,21 @@ pub trait ExtFullScreenExclusiveExtensionDeviceCommands: DeviceV1_0 {
         &self,
         swapchain: SwapchainKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkReleaseFullScreenExclusiveModeEXT",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().release_full_screen_exclusive_mode_ext)(self.handle(), swapchain);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3109,35 +5298,127 @@ pub trait ExtFullScreenExclusiveExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         surface_info: &PhysicalDeviceSurfaceInfo2KHR,
     ) -> crate::VkResult<Vec<PresentModeKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSurfacePresentModes2EXT",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut present_mode_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_surface_present_modes2_ext)(
-            physical_device,
-            surface_info,
-            &mut present_mode_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_surface_present_modes2_ext)(
+                physical_device,
+                surface_info,
+                &mut present_mode_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut present_modes = Vec::with_capacity(present_mode_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_surface_present_modes2_ext)(
+                physical_device,
+                surface_info,
+                &mut present_mode_count,
+                present_modes.as_mut_ptr(),
+            );
 
-        let mut present_modes = Vec::with_capacity(present_mode_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_surface_present_modes2_ext)(
-            physical_device,
-            surface_info,
-            &mut present_mode_count,
-            present_modes.as_mut_ptr(),
-        );
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
 
-        debug_assert!(present_modes.capacity() >= present_mode_count as usize);
-        present_modes.set_len(present_mode_count as usize);
+            debug_assert!(present_modes.capacity() >= present_mode_count as usize);
+            present_modes.set_len(present_mode_count as usize);
 
-        if __result == Result::SUCCESS {
-            Ok(present_modes)
-        } else {
-            Err(__result.into())
+            break if __result == Result::SUCCESS {
+                Ok(present_modes)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceSurfacePresentModes2EXT.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_surface_present_modes2_ext`].
+    #[inline]
+    unsafe fn get_physical_device_surface_present_modes2_ext_into(
+        &self,
+        physical_device: PhysicalDevice,
+        surface_info: &PhysicalDeviceSurfaceInfo2KHR,
+        present_modes: &mut Vec<PresentModeKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSurfacePresentModes2EXT",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut present_mode_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_surface_present_modes2_ext)(
+                physical_device,
+                surface_info,
+                &mut present_mode_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            present_modes.clear();
+            present_modes.reserve(present_mode_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_surface_present_modes2_ext)(
+                physical_device,
+                surface_info,
+                &mut present_mode_count,
+                present_modes.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(present_modes.capacity() >= present_mode_count as usize);
+                present_modes.set_len(present_mode_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -3153,6 +5434,13 @@ pub trait ExtHdrMetadataExtensionDeviceCommands: DeviceV1_0 {
         swapchains: &[SwapchainKHR],
         metadata: &[impl Cast<Target = HdrMetadataEXT>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetHdrMetadataEXT",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().set_hdr_metadata_ext)(
             self.handle(),
             swapchains.len() as u32,
@@ -3173,6 +5461,14 @@ pub trait ExtHeadlessSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &HeadlessSurfaceCreateInfoEXT,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateHeadlessSurfaceEXT",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_headless_surface_ext)(
@@ -3182,6 +5478,9 @@ pub trait ExtHeadlessSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -3200,9 +5499,20 @@ pub trait ExtHostImageCopyExtensionDeviceCommands: DeviceV1_0 {
         &self,
         copy_image_to_image_info: &CopyImageToImageInfo,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCopyImageToImageEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().copy_image_to_image_ext)(self.handle(), copy_image_to_image_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3216,9 +5526,20 @@ pub trait ExtHostImageCopyExtensionDeviceCommands: DeviceV1_0 {
         &self,
         copy_image_to_memory_info: &CopyImageToMemoryInfo,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCopyImageToMemoryEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().copy_image_to_memory_ext)(self.handle(), copy_image_to_memory_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3232,9 +5553,20 @@ pub trait ExtHostImageCopyExtensionDeviceCommands: DeviceV1_0 {
         &self,
         copy_memory_to_image_info: &CopyMemoryToImageInfo,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCopyMemoryToImageEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().copy_memory_to_image_ext)(self.handle(), copy_memory_to_image_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3250,6 +5582,14 @@ pub trait ExtHostImageCopyExtensionDeviceCommands: DeviceV1_0 {
         subresource: &ImageSubresource2,
         layout: &mut SubresourceLayout2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageSubresourceLayout2EXT",
+            device = tracing::field::debug(self.handle()),
+            image = tracing::field::debug(image)
+        )
+        .entered();
+
         let __result = (self.commands().get_image_subresource_layout2_ext)(
             self.handle(),
             image,
@@ -3264,12 +5604,23 @@ pub trait ExtHostImageCopyExtensionDeviceCommands: DeviceV1_0 {
         &self,
         transitions: &[impl Cast<Target = HostImageLayoutTransitionInfo>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkTransitionImageLayoutEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().transition_image_layout_ext)(
             self.handle(),
             transitions.len() as u32,
             transitions.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3290,6 +5641,14 @@ pub trait ExtHostQueryResetExtensionDeviceCommands: DeviceV1_0 {
         first_query: u32,
         query_count: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkResetQueryPoolEXT",
+            device = tracing::field::debug(self.handle()),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result = (self.commands().reset_query_pool_ext)(
             self.handle(),
             query_pool,
@@ -3311,6 +5670,14 @@ pub trait ExtImageCompressionControlExtensionDeviceCommands: DeviceV1_0 {
         subresource: &ImageSubresource2,
         layout: &mut SubresourceLayout2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageSubresourceLayout2EXT",
+            device = tracing::field::debug(self.handle()),
+            image = tracing::field::debug(image)
+        )
+        .entered();
+
         let __result = (self.commands().get_image_subresource_layout2_ext)(
             self.handle(),
             image,
@@ -3331,12 +5698,24 @@ pub trait ExtImageDrmFormatModifierExtensionDeviceCommands: DeviceV1_0 {
         image: Image,
         properties: &mut ImageDrmFormatModifierPropertiesEXT,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageDrmFormatModifierPropertiesEXT",
+            device = tracing::field::debug(self.handle()),
+            image = tracing::field::debug(image),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_image_drm_format_modifier_properties_ext)(
             self.handle(),
             image,
             properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3357,6 +5736,13 @@ pub trait ExtLineRasterizationExtensionDeviceCommands: DeviceV1_0 {
         line_stipple_factor: u32,
         line_stipple_pattern: u16,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetLineStippleEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_line_stipple_ext)(
             command_buffer,
             line_stipple_factor,
@@ -3376,6 +5762,13 @@ pub trait ExtMemoryDecompressionExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         decompress_memory_info_ext: &DecompressMemoryInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDecompressMemoryEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_decompress_memory_ext)(command_buffer, decompress_memory_info_ext);
     }
@@ -3391,6 +5784,13 @@ pub trait ExtMemoryDecompressionExtensionDeviceCommands: DeviceV1_0 {
         max_decompression_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDecompressMemoryIndirectCountEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_decompress_memory_indirect_count_ext)(
             command_buffer,
             decompression_method,
@@ -3415,6 +5815,13 @@ pub trait ExtMeshShaderExtensionDeviceCommands: DeviceV1_0 {
         group_count_y: u32,
         group_count_z: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawMeshTasksEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_mesh_tasks_ext)(
             command_buffer,
             group_count_x,
@@ -3435,6 +5842,15 @@ pub trait ExtMeshShaderExtensionDeviceCommands: DeviceV1_0 {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawMeshTasksIndirectCountEXT",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer),
+            count_buffer = tracing::field::debug(count_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_mesh_tasks_indirect_count_ext)(
             command_buffer,
             buffer,
@@ -3456,6 +5872,14 @@ pub trait ExtMeshShaderExtensionDeviceCommands: DeviceV1_0 {
         draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawMeshTasksIndirectEXT",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_mesh_tasks_indirect_ext)(
             command_buffer,
             buffer,
@@ -3473,6 +5897,13 @@ pub trait ExtMetalObjectsExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkExportMetalObjectsEXT.html>
     #[inline]
     unsafe fn export_metal_objects_ext(&self, metal_objects_info: &mut ExportMetalObjectsInfoEXT) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkExportMetalObjectsEXT",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result =
             (self.commands().export_metal_objects_ext)(self.handle(), metal_objects_info);
     }
@@ -3489,6 +5920,14 @@ pub trait ExtMetalSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &MetalSurfaceCreateInfoEXT,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateMetalSurfaceEXT",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_metal_surface_ext)(
@@ -3498,6 +5937,9 @@ pub trait ExtMetalSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -3520,6 +5962,13 @@ pub trait ExtMultiDrawExtensionDeviceCommands: DeviceV1_0 {
         first_instance: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawMultiEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_multi_ext)(
             command_buffer,
             vertex_info.len() as u32,
@@ -3541,6 +5990,13 @@ pub trait ExtMultiDrawExtensionDeviceCommands: DeviceV1_0 {
         stride: u32,
         vertex_offset: Option<&i32>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawMultiIndexedEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_multi_indexed_ext)(
             command_buffer,
             index_info.len() as u32,
@@ -3564,6 +6020,15 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         deferred_operation: DeferredOperationKHR,
         infos: &[impl Cast<Target = MicromapBuildInfoEXT>],
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBuildMicromapsEXT",
+            device = tracing::field::debug(self.handle()),
+            deferred_operation = tracing::field::debug(deferred_operation),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().build_micromaps_ext)(
             self.handle(),
             deferred_operation,
@@ -3571,6 +6036,9 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
             infos.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -3585,6 +6053,13 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         infos: &[impl Cast<Target = MicromapBuildInfoEXT>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBuildMicromapsEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_build_micromaps_ext)(
             command_buffer,
             infos.len() as u32,
@@ -3599,6 +6074,13 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         info: &CopyMemoryToMicromapInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyMemoryToMicromapEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_memory_to_micromap_ext)(command_buffer, info);
     }
 
@@ -3609,6 +6091,13 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         info: &CopyMicromapInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyMicromapEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_micromap_ext)(command_buffer, info);
     }
 
@@ -3619,6 +6108,13 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         info: &CopyMicromapToMemoryInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyMicromapToMemoryEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_micromap_to_memory_ext)(command_buffer, info);
     }
 
@@ -3632,6 +6128,14 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         query_pool: QueryPool,
         first_query: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdWriteMicromapsPropertiesEXT",
+            command_buffer = tracing::field::debug(command_buffer),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_write_micromaps_properties_ext)(
             command_buffer,
             micromaps.len() as u32,
@@ -3649,9 +6153,21 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         deferred_operation: DeferredOperationKHR,
         info: &CopyMemoryToMicromapInfoEXT,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCopyMemoryToMicromapEXT",
+            device = tracing::field::debug(self.handle()),
+            deferred_operation = tracing::field::debug(deferred_operation),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().copy_memory_to_micromap_ext)(self.handle(), deferred_operation, info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -3666,8 +6182,20 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         deferred_operation: DeferredOperationKHR,
         info: &CopyMicromapInfoEXT,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCopyMicromapEXT",
+            device = tracing::field::debug(self.handle()),
+            deferred_operation = tracing::field::debug(deferred_operation),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().copy_micromap_ext)(self.handle(), deferred_operation, info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -3682,9 +6210,21 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         deferred_operation: DeferredOperationKHR,
         info: &CopyMicromapToMemoryInfoEXT,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCopyMicromapToMemoryEXT",
+            device = tracing::field::debug(self.handle()),
+            deferred_operation = tracing::field::debug(deferred_operation),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().copy_micromap_to_memory_ext)(self.handle(), deferred_operation, info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -3699,6 +6239,14 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         create_info: &MicromapCreateInfoEXT,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<MicromapEXT> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateMicromapEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut micromap = MaybeUninit::<MicromapEXT>::uninit();
 
         let __result = (self.commands().create_micromap_ext)(
@@ -3708,6 +6256,9 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
             micromap.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(micromap.assume_init())
         } else {
@@ -3722,6 +6273,14 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         micromap: MicromapEXT,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyMicromapEXT",
+            device = tracing::field::debug(self.handle()),
+            micromap = tracing::field::debug(micromap)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_micromap_ext)(
             self.handle(),
             micromap,
@@ -3735,6 +6294,13 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         &self,
         version_info: &MicromapVersionInfoEXT,
     ) -> AccelerationStructureCompatibilityKHR {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceMicromapCompatibilityEXT",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut compatibility = MaybeUninit::<AccelerationStructureCompatibilityKHR>::uninit();
 
         let __result = (self.commands().get_device_micromap_compatibility_ext)(
@@ -3754,6 +6320,13 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         build_info: &MicromapBuildInfoEXT,
         size_info: &mut MicromapBuildSizesInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMicromapBuildSizesEXT",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_micromap_build_sizes_ext)(
             self.handle(),
             build_type,
@@ -3771,6 +6344,14 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
         data: &mut [u8],
         stride: usize,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkWriteMicromapsPropertiesEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().write_micromaps_properties_ext)(
             self.handle(),
             micromaps.len() as u32,
@@ -3781,6 +6362,9 @@ pub trait ExtOpacityMicromapExtensionDeviceCommands: DeviceV1_0 {
             stride,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3796,6 +6380,14 @@ pub trait ExtPageableDeviceLocalMemoryExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkSetDeviceMemoryPriorityEXT.html>
     #[inline]
     unsafe fn set_device_memory_priority_ext(&self, memory: DeviceMemory, priority: f32) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetDeviceMemoryPriorityEXT",
+            device = tracing::field::debug(self.handle()),
+            memory = tracing::field::debug(memory)
+        )
+        .entered();
+
         let __result =
             (self.commands().set_device_memory_priority_ext)(self.handle(), memory, priority);
     }
@@ -3811,6 +6403,14 @@ pub trait ExtPipelinePropertiesExtensionDeviceCommands: DeviceV1_0 {
         &self,
         pipeline_info: &PipelineInfoEXT,
     ) -> crate::VkResult<BaseOutStructure> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelinePropertiesEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut pipeline_properties = MaybeUninit::<BaseOutStructure>::uninit();
 
         let __result = (self.commands().get_pipeline_properties_ext)(
@@ -3819,6 +6419,9 @@ pub trait ExtPipelinePropertiesExtensionDeviceCommands: DeviceV1_0 {
             pipeline_properties.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(pipeline_properties.assume_init())
         } else {
@@ -3838,12 +6441,23 @@ pub trait ExtPresentTimingExtensionDeviceCommands: DeviceV1_0 {
         past_presentation_timing_info: &PastPresentationTimingInfoEXT,
         past_presentation_timing_properties: &mut PastPresentationTimingPropertiesEXT,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPastPresentationTimingEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_past_presentation_timing_ext)(
             self.handle(),
             past_presentation_timing_info,
             past_presentation_timing_properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3858,6 +6472,15 @@ pub trait ExtPresentTimingExtensionDeviceCommands: DeviceV1_0 {
         swapchain: SwapchainKHR,
         swapchain_time_domain_properties: &mut SwapchainTimeDomainPropertiesEXT,
     ) -> crate::VkResult<u64> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSwapchainTimeDomainPropertiesEXT",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut time_domains_counter = MaybeUninit::<u64>::uninit();
 
         let __result = (self.commands().get_swapchain_time_domain_properties_ext)(
@@ -3867,6 +6490,9 @@ pub trait ExtPresentTimingExtensionDeviceCommands: DeviceV1_0 {
             time_domains_counter.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(time_domains_counter.assume_init())
         } else {
@@ -3881,6 +6507,15 @@ pub trait ExtPresentTimingExtensionDeviceCommands: DeviceV1_0 {
         swapchain: SwapchainKHR,
         swapchain_timing_properties: &mut SwapchainTimingPropertiesEXT,
     ) -> crate::VkSuccessResult<u64> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSwapchainTimingPropertiesEXT",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut swapchain_timing_properties_counter = MaybeUninit::<u64>::uninit();
 
         let __result = (self.commands().get_swapchain_timing_properties_ext)(
@@ -3890,6 +6525,9 @@ pub trait ExtPresentTimingExtensionDeviceCommands: DeviceV1_0 {
             swapchain_timing_properties_counter.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok((
                 swapchain_timing_properties_counter.assume_init(),
@@ -3907,12 +6545,24 @@ pub trait ExtPresentTimingExtensionDeviceCommands: DeviceV1_0 {
         swapchain: SwapchainKHR,
         size: u32,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetSwapchainPresentTimingQueueSizeEXT",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().set_swapchain_present_timing_queue_size_ext)(
             self.handle(),
             swapchain,
             size,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -3932,6 +6582,14 @@ pub trait ExtPrivateDataExtensionDeviceCommands: DeviceV1_0 {
         create_info: &PrivateDataSlotCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<PrivateDataSlot> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreatePrivateDataSlotEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut private_data_slot = MaybeUninit::<PrivateDataSlot>::uninit();
 
         let __result = (self.commands().create_private_data_slot_ext)(
@@ -3941,6 +6599,9 @@ pub trait ExtPrivateDataExtensionDeviceCommands: DeviceV1_0 {
             private_data_slot.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(private_data_slot.assume_init())
         } else {
@@ -3955,6 +6616,14 @@ pub trait ExtPrivateDataExtensionDeviceCommands: DeviceV1_0 {
         private_data_slot: PrivateDataSlot,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyPrivateDataSlotEXT",
+            device = tracing::field::debug(self.handle()),
+            private_data_slot = tracing::field::debug(private_data_slot)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_private_data_slot_ext)(
             self.handle(),
             private_data_slot,
@@ -3970,6 +6639,14 @@ pub trait ExtPrivateDataExtensionDeviceCommands: DeviceV1_0 {
         object_handle: u64,
         private_data_slot: PrivateDataSlot,
     ) -> u64 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPrivateDataEXT",
+            device = tracing::field::debug(self.handle()),
+            private_data_slot = tracing::field::debug(private_data_slot)
+        )
+        .entered();
+
         let mut data = MaybeUninit::<u64>::uninit();
 
         let __result = (self.commands().get_private_data_ext)(
@@ -3992,6 +6669,15 @@ pub trait ExtPrivateDataExtensionDeviceCommands: DeviceV1_0 {
         private_data_slot: PrivateDataSlot,
         data: u64,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetPrivateDataEXT",
+            device = tracing::field::debug(self.handle()),
+            private_data_slot = tracing::field::debug(private_data_slot),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().set_private_data_ext)(
             self.handle(),
             object_type,
@@ -4000,6 +6686,9 @@ pub trait ExtPrivateDataExtensionDeviceCommands: DeviceV1_0 {
             data,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -4019,6 +6708,13 @@ pub trait ExtSampleLocationsExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         sample_locations_info: &SampleLocationsInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetSampleLocationsEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_sample_locations_ext)(command_buffer, sample_locations_info);
     }
@@ -4036,6 +6732,13 @@ pub trait ExtSampleLocationsExtensionInstanceCommands: InstanceV1_0 {
         samples: SampleCountFlags,
         multisample_properties: &mut MultisamplePropertiesEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceMultisamplePropertiesEXT",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_multisample_properties_ext)(
@@ -4057,6 +6760,13 @@ pub trait ExtShaderModuleIdentifierExtensionDeviceCommands: DeviceV1_0 {
         create_info: &ShaderModuleCreateInfo,
         identifier: &mut ShaderModuleIdentifierEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetShaderModuleCreateInfoIdentifierEXT",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_shader_module_create_info_identifier_ext)(
             self.handle(),
             create_info,
@@ -4071,6 +6781,14 @@ pub trait ExtShaderModuleIdentifierExtensionDeviceCommands: DeviceV1_0 {
         shader_module: ShaderModule,
         identifier: &mut ShaderModuleIdentifierEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetShaderModuleIdentifierEXT",
+            device = tracing::field::debug(self.handle()),
+            shader_module = tracing::field::debug(shader_module)
+        )
+        .entered();
+
         let __result = (self.commands().get_shader_module_identifier_ext)(
             self.handle(),
             shader_module,
@@ -4091,6 +6809,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         stages: &[ShaderStageFlags],
         shaders: &[ShaderEXT],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindShadersEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_shaders_ext)(
             command_buffer,
             stages.len() as u32,
@@ -4110,6 +6835,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         sizes: &[DeviceSize],
         strides: &[DeviceSize],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindVertexBuffers2EXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_vertex_buffers2_ext)(
             command_buffer,
             first_binding,
@@ -4128,6 +6860,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         alpha_to_coverage_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetAlphaToCoverageEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_alpha_to_coverage_enable_ext)(
             command_buffer,
             alpha_to_coverage_enable as Bool32,
@@ -4141,6 +6880,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         alpha_to_one_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetAlphaToOneEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_alpha_to_one_enable_ext)(
             command_buffer,
             alpha_to_one_enable as Bool32,
@@ -4155,6 +6901,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         first_attachment: u32,
         color_blend_advanced: &[impl Cast<Target = ColorBlendAdvancedEXT>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetColorBlendAdvancedEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_color_blend_advanced_ext)(
             command_buffer,
             first_attachment,
@@ -4171,6 +6924,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         first_attachment: u32,
         color_blend_enables: &[Bool32],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetColorBlendEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_color_blend_enable_ext)(
             command_buffer,
             first_attachment,
@@ -4187,6 +6947,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         first_attachment: u32,
         color_blend_equations: &[impl Cast<Target = ColorBlendEquationEXT>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetColorBlendEquationEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_color_blend_equation_ext)(
             command_buffer,
             first_attachment,
@@ -4203,6 +6970,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         first_attachment: u32,
         color_write_masks: &[ColorComponentFlags],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetColorWriteMaskEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_color_write_mask_ext)(
             command_buffer,
             first_attachment,
@@ -4218,6 +6992,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         conservative_rasterization_mode: ConservativeRasterizationModeEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetConservativeRasterizationModeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_conservative_rasterization_mode_ext)(
             command_buffer,
             conservative_rasterization_mode,
@@ -4231,6 +7012,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coverage_modulation_mode: CoverageModulationModeNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoverageModulationModeNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coverage_modulation_mode_nv)(
             command_buffer,
             coverage_modulation_mode,
@@ -4244,6 +7032,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coverage_modulation_table_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoverageModulationTableEnableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coverage_modulation_table_enable_nv)(
             command_buffer,
             coverage_modulation_table_enable as Bool32,
@@ -4257,6 +7052,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coverage_modulation_table: &[f32],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoverageModulationTableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coverage_modulation_table_nv)(
             command_buffer,
             coverage_modulation_table.len() as u32,
@@ -4271,6 +7073,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coverage_reduction_mode: CoverageReductionModeNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoverageReductionModeNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coverage_reduction_mode_nv)(
             command_buffer,
             coverage_reduction_mode,
@@ -4284,6 +7093,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coverage_to_color_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoverageToColorEnableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coverage_to_color_enable_nv)(
             command_buffer,
             coverage_to_color_enable as Bool32,
@@ -4297,6 +7113,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coverage_to_color_location: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoverageToColorLocationNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coverage_to_color_location_nv)(
             command_buffer,
             coverage_to_color_location,
@@ -4310,6 +7133,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         cull_mode: CullModeFlags,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCullModeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_cull_mode_ext)(command_buffer, cull_mode);
     }
 
@@ -4320,6 +7150,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_bias_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthBiasEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_bias_enable_ext)(
             command_buffer,
             depth_bias_enable as Bool32,
@@ -4333,6 +7170,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_bounds_test_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthBoundsTestEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_bounds_test_enable_ext)(
             command_buffer,
             depth_bounds_test_enable as Bool32,
@@ -4346,6 +7190,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_clamp_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthClampEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_clamp_enable_ext)(
             command_buffer,
             depth_clamp_enable as Bool32,
@@ -4360,6 +7211,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         depth_clamp_mode: DepthClampModeEXT,
         depth_clamp_range: Option<&DepthClampRangeEXT>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthClampRangeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_clamp_range_ext)(
             command_buffer,
             depth_clamp_mode,
@@ -4374,6 +7232,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_clip_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthClipEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_clip_enable_ext)(
             command_buffer,
             depth_clip_enable as Bool32,
@@ -4387,6 +7252,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         negative_one_to_one: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthClipNegativeOneToOneEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_clip_negative_one_to_one_ext)(
             command_buffer,
             negative_one_to_one as Bool32,
@@ -4400,6 +7272,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_compare_op: CompareOp,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthCompareOpEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_depth_compare_op_ext)(command_buffer, depth_compare_op);
     }
@@ -4411,6 +7290,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_test_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthTestEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_test_enable_ext)(
             command_buffer,
             depth_test_enable as Bool32,
@@ -4424,6 +7310,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         depth_write_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthWriteEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_write_enable_ext)(
             command_buffer,
             depth_write_enable as Bool32,
@@ -4437,6 +7330,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         extra_primitive_overestimation_size: f32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetExtraPrimitiveOverestimationSizeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .cmd_set_extra_primitive_overestimation_size_ext)(
@@ -4448,6 +7348,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdSetFrontFaceEXT.html>
     #[inline]
     unsafe fn cmd_set_front_face_ext(&self, command_buffer: CommandBuffer, front_face: FrontFace) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetFrontFaceEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_front_face_ext)(command_buffer, front_face);
     }
 
@@ -4458,6 +7365,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         line_rasterization_mode: LineRasterizationModeEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetLineRasterizationModeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_line_rasterization_mode_ext)(
             command_buffer,
             line_rasterization_mode,
@@ -4471,6 +7385,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         stippled_line_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetLineStippleEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_line_stipple_enable_ext)(
             command_buffer,
             stippled_line_enable as Bool32,
@@ -4480,6 +7401,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdSetLogicOpEXT.html>
     #[inline]
     unsafe fn cmd_set_logic_op_ext(&self, command_buffer: CommandBuffer, logic_op: LogicOp) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetLogicOpEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_logic_op_ext)(command_buffer, logic_op);
     }
 
@@ -4490,6 +7418,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         logic_op_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetLogicOpEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_logic_op_enable_ext)(
             command_buffer,
             logic_op_enable as Bool32,
@@ -4503,6 +7438,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         patch_control_points: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPatchControlPointsEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_patch_control_points_ext)(
             command_buffer,
             patch_control_points,
@@ -4516,6 +7458,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         polygon_mode: PolygonMode,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPolygonModeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_polygon_mode_ext)(command_buffer, polygon_mode);
     }
 
@@ -4526,6 +7475,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         primitive_restart_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPrimitiveRestartEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_primitive_restart_enable_ext)(
             command_buffer,
             primitive_restart_enable as Bool32,
@@ -4539,6 +7495,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         primitive_topology: PrimitiveTopology,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPrimitiveTopologyEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_primitive_topology_ext)(command_buffer, primitive_topology);
     }
@@ -4550,6 +7513,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         provoking_vertex_mode: ProvokingVertexModeEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetProvokingVertexModeEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_provoking_vertex_mode_ext)(
             command_buffer,
             provoking_vertex_mode,
@@ -4563,6 +7533,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         rasterization_samples: SampleCountFlags,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRasterizationSamplesEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_rasterization_samples_ext)(
             command_buffer,
             rasterization_samples,
@@ -4576,6 +7553,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         rasterization_stream: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRasterizationStreamEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_rasterization_stream_ext)(
             command_buffer,
             rasterization_stream,
@@ -4589,6 +7573,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         rasterizer_discard_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRasterizerDiscardEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_rasterizer_discard_enable_ext)(
             command_buffer,
             rasterizer_discard_enable as Bool32,
@@ -4602,6 +7593,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         representative_fragment_test_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRepresentativeFragmentTestEnableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .cmd_set_representative_fragment_test_enable_nv)(
@@ -4617,6 +7615,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         sample_locations_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetSampleLocationsEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_sample_locations_enable_ext)(
             command_buffer,
             sample_locations_enable as Bool32,
@@ -4631,6 +7636,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         samples: SampleCountFlags,
         sample_mask: Option<&SampleMask>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetSampleMaskEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_sample_mask_ext)(
             command_buffer,
             samples,
@@ -4645,6 +7657,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         scissors: &[impl Cast<Target = Rect2D>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetScissorWithCountEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_scissor_with_count_ext)(
             command_buffer,
             scissors.len() as u32,
@@ -4659,6 +7678,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         shading_rate_image_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetShadingRateImageEnableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_shading_rate_image_enable_nv)(
             command_buffer,
             shading_rate_image_enable as Bool32,
@@ -4676,6 +7702,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         depth_fail_op: StencilOp,
         compare_op: CompareOp,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetStencilOpEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_stencil_op_ext)(
             command_buffer,
             face_mask,
@@ -4693,6 +7726,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         stencil_test_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetStencilTestEnableEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_stencil_test_enable_ext)(
             command_buffer,
             stencil_test_enable as Bool32,
@@ -4706,6 +7746,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         domain_origin: TessellationDomainOrigin,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetTessellationDomainOriginEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_tessellation_domain_origin_ext)(command_buffer, domain_origin);
     }
@@ -4718,6 +7765,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         vertex_binding_descriptions: &[impl Cast<Target = VertexInputBindingDescription2EXT>],
         vertex_attribute_descriptions: &[impl Cast<Target = VertexInputAttributeDescription2EXT>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetVertexInputEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_vertex_input_ext)(
             command_buffer,
             vertex_binding_descriptions.len() as u32,
@@ -4735,6 +7789,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         first_viewport: u32,
         viewport_swizzles: &[impl Cast<Target = ViewportSwizzleNV>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetViewportSwizzleNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_viewport_swizzle_nv)(
             command_buffer,
             first_viewport,
@@ -4750,6 +7811,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         viewport_w_scaling_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetViewportWScalingEnableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_viewport_w_scaling_enable_nv)(
             command_buffer,
             viewport_w_scaling_enable as Bool32,
@@ -4763,6 +7831,13 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         viewports: &[impl Cast<Target = Viewport>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetViewportWithCountEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_viewport_with_count_ext)(
             command_buffer,
             viewports.len() as u32,
@@ -4777,6 +7852,14 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         create_infos: &[impl Cast<Target = ShaderCreateInfoEXT>],
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkSuccessResult<Vec<ShaderEXT>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateShadersEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut shaders = Vec::with_capacity(create_infos.len() as usize);
 
         let __result = (self.commands().create_shaders_ext)(
@@ -4787,6 +7870,9 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
             shaders.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         shaders.set_len(create_infos.len() as usize);
 
         if __result >= Result::SUCCESS {
@@ -4796,6 +7882,50 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         }
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCreateShadersEXT.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::create_shaders_ext`].
+    #[inline]
+    unsafe fn create_shaders_ext_into(
+        &self,
+        create_infos: &[impl Cast<Target = ShaderCreateInfoEXT>],
+        allocator: Option<&AllocationCallbacks>,
+        shaders: &mut Vec<ShaderEXT>,
+    ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateShadersEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        shaders.clear();
+        shaders.reserve(create_infos.len() as usize);
+
+        let __result = (self.commands().create_shaders_ext)(
+            self.handle(),
+            create_infos.len() as u32,
+            create_infos.as_ptr().cast(),
+            allocator.map_or(ptr::null(), |v| v),
+            shaders.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            shaders.set_len(create_infos.len() as usize);
+        }
+
+        if __result >= Result::SUCCESS {
+            Ok(__result.into())
+        } else {
+            Err(__result.into())
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkDestroyShaderEXT.html>
     #[inline]
     unsafe fn destroy_shader_ext(
@@ -4803,6 +7933,14 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
         shader: ShaderEXT,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyShaderEXT",
+            device = tracing::field::debug(self.handle()),
+            shader = tracing::field::debug(shader)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_shader_ext)(
             self.handle(),
             shader,
@@ -4813,31 +7951,120 @@ pub trait ExtShaderObjectExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetShaderBinaryDataEXT.html>
     #[inline]
     unsafe fn get_shader_binary_data_ext(&self, shader: ShaderEXT) -> crate::VkResult<Vec<u8>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetShaderBinaryDataEXT",
+            device = tracing::field::debug(self.handle()),
+            shader = tracing::field::debug(shader),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut data_size = 0;
 
-        (self.commands().get_shader_binary_data_ext)(
-            self.handle(),
-            shader,
-            &mut data_size,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_shader_binary_data_ext)(
+                self.handle(),
+                shader,
+                &mut data_size,
+                ptr::null_mut(),
+            );
 
-        let mut data = Vec::with_capacity(data_size as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_shader_binary_data_ext)(
-            self.handle(),
-            shader,
-            &mut data_size,
-            data.as_mut_ptr() as *mut c_void,
-        );
+            let mut data = Vec::with_capacity(data_size as usize);
 
-        debug_assert!(data.capacity() >= data_size as usize);
-        data.set_len(data_size as usize);
+            let __result = (self.commands().get_shader_binary_data_ext)(
+                self.handle(),
+                shader,
+                &mut data_size,
+                data.as_mut_ptr() as *mut c_void,
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(data)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(data.capacity() >= data_size as usize);
+            data.set_len(data_size as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(data)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetShaderBinaryDataEXT.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_shader_binary_data_ext`].
+    #[inline]
+    unsafe fn get_shader_binary_data_ext_into(
+        &self,
+        shader: ShaderEXT,
+        data: &mut Vec<u8>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetShaderBinaryDataEXT",
+            device = tracing::field::debug(self.handle()),
+            shader = tracing::field::debug(shader),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut data_size = 0;
+
+        loop {
+            let __result = (self.commands().get_shader_binary_data_ext)(
+                self.handle(),
+                shader,
+                &mut data_size,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            data.clear();
+            data.reserve(data_size as usize);
+
+            let __result = (self.commands().get_shader_binary_data_ext)(
+                self.handle(),
+                shader,
+                &mut data_size,
+                data.as_mut_ptr() as *mut c_void,
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(data.capacity() >= data_size as usize);
+                data.set_len(data_size as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -4852,8 +8079,19 @@ pub trait ExtSwapchainMaintenance1ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         release_info: &ReleaseSwapchainImagesInfoKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkReleaseSwapchainImagesEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().release_swapchain_images_ext)(self.handle(), release_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -4872,29 +8110,114 @@ pub trait ExtToolingInfoExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<PhysicalDeviceToolProperties>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceToolPropertiesEXT",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut tool_count = 0;
 
-        (self.commands().get_physical_device_tool_properties_ext)(
-            physical_device,
-            &mut tool_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_physical_device_tool_properties_ext)(
+                physical_device,
+                &mut tool_count,
+                ptr::null_mut(),
+            );
 
-        let mut tool_properties = Vec::with_capacity(tool_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_physical_device_tool_properties_ext)(
-            physical_device,
-            &mut tool_count,
-            tool_properties.as_mut_ptr(),
-        );
+            let mut tool_properties = Vec::with_capacity(tool_count as usize);
 
-        debug_assert!(tool_properties.capacity() >= tool_count as usize);
-        tool_properties.set_len(tool_count as usize);
+            let __result = (self.commands().get_physical_device_tool_properties_ext)(
+                physical_device,
+                &mut tool_count,
+                tool_properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(tool_properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(tool_properties.capacity() >= tool_count as usize);
+            tool_properties.set_len(tool_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(tool_properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceToolPropertiesEXT.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_tool_properties_ext`].
+    #[inline]
+    unsafe fn get_physical_device_tool_properties_ext_into(
+        &self,
+        physical_device: PhysicalDevice,
+        tool_properties: &mut Vec<PhysicalDeviceToolProperties>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceToolPropertiesEXT",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut tool_count = 0;
+
+        loop {
+            let __result = (self.commands().get_physical_device_tool_properties_ext)(
+                physical_device,
+                &mut tool_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            tool_properties.clear();
+            tool_properties.reserve(tool_count as usize);
+
+            let __result = (self.commands().get_physical_device_tool_properties_ext)(
+                physical_device,
+                &mut tool_count,
+                tool_properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(tool_properties.capacity() >= tool_count as usize);
+                tool_properties.set_len(tool_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -4913,6 +8236,14 @@ pub trait ExtTransformFeedbackExtensionDeviceCommands: DeviceV1_0 {
         flags: QueryControlFlags,
         index: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginQueryIndexedEXT",
+            command_buffer = tracing::field::debug(command_buffer),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_begin_query_indexed_ext)(
             command_buffer,
             query_pool,
@@ -4931,6 +8262,13 @@ pub trait ExtTransformFeedbackExtensionDeviceCommands: DeviceV1_0 {
         counter_buffers: &[Buffer],
         counter_buffer_offsets: &[DeviceSize],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginTransformFeedbackEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_begin_transform_feedback_ext)(
             command_buffer,
             first_counter_buffer,
@@ -4950,6 +8288,13 @@ pub trait ExtTransformFeedbackExtensionDeviceCommands: DeviceV1_0 {
         offsets: &[DeviceSize],
         sizes: &[DeviceSize],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindTransformFeedbackBuffersEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_transform_feedback_buffers_ext)(
             command_buffer,
             first_binding,
@@ -4972,6 +8317,14 @@ pub trait ExtTransformFeedbackExtensionDeviceCommands: DeviceV1_0 {
         counter_offset: u32,
         vertex_stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawIndirectByteCountEXT",
+            command_buffer = tracing::field::debug(command_buffer),
+            counter_buffer = tracing::field::debug(counter_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_indirect_byte_count_ext)(
             command_buffer,
             instance_count,
@@ -4992,6 +8345,14 @@ pub trait ExtTransformFeedbackExtensionDeviceCommands: DeviceV1_0 {
         query: u32,
         index: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndQueryIndexedEXT",
+            command_buffer = tracing::field::debug(command_buffer),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_end_query_indexed_ext)(command_buffer, query_pool, query, index);
     }
@@ -5005,6 +8366,13 @@ pub trait ExtTransformFeedbackExtensionDeviceCommands: DeviceV1_0 {
         counter_buffers: &[Buffer],
         counter_buffer_offsets: &[DeviceSize],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndTransformFeedbackEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_end_transform_feedback_ext)(
             command_buffer,
             first_counter_buffer,
@@ -5026,6 +8394,14 @@ pub trait ExtValidationCacheExtensionDeviceCommands: DeviceV1_0 {
         create_info: &ValidationCacheCreateInfoEXT,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<ValidationCacheEXT> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateValidationCacheEXT",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut validation_cache = MaybeUninit::<ValidationCacheEXT>::uninit();
 
         let __result = (self.commands().create_validation_cache_ext)(
@@ -5035,6 +8411,9 @@ pub trait ExtValidationCacheExtensionDeviceCommands: DeviceV1_0 {
             validation_cache.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(validation_cache.assume_init())
         } else {
@@ -5049,6 +8428,14 @@ pub trait ExtValidationCacheExtensionDeviceCommands: DeviceV1_0 {
         validation_cache: ValidationCacheEXT,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyValidationCacheEXT",
+            device = tracing::field::debug(self.handle()),
+            validation_cache = tracing::field::debug(validation_cache)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_validation_cache_ext)(
             self.handle(),
             validation_cache,
@@ -5062,31 +8449,120 @@ pub trait ExtValidationCacheExtensionDeviceCommands: DeviceV1_0 {
         &self,
         validation_cache: ValidationCacheEXT,
     ) -> crate::VkResult<Vec<u8>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetValidationCacheDataEXT",
+            device = tracing::field::debug(self.handle()),
+            validation_cache = tracing::field::debug(validation_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut data_size = 0;
 
-        (self.commands().get_validation_cache_data_ext)(
-            self.handle(),
-            validation_cache,
-            &mut data_size,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_validation_cache_data_ext)(
+                self.handle(),
+                validation_cache,
+                &mut data_size,
+                ptr::null_mut(),
+            );
 
-        let mut data = Vec::with_capacity(data_size as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_validation_cache_data_ext)(
-            self.handle(),
-            validation_cache,
-            &mut data_size,
-            data.as_mut_ptr() as *mut c_void,
-        );
+            let mut data = Vec::with_capacity(data_size as usize);
 
-        debug_assert!(data.capacity() >= data_size as usize);
-        data.set_len(data_size as usize);
+            let __result = (self.commands().get_validation_cache_data_ext)(
+                self.handle(),
+                validation_cache,
+                &mut data_size,
+                data.as_mut_ptr() as *mut c_void,
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(data)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(data.capacity() >= data_size as usize);
+            data.set_len(data_size as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(data)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetValidationCacheDataEXT.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_validation_cache_data_ext`].
+    #[inline]
+    unsafe fn get_validation_cache_data_ext_into(
+        &self,
+        validation_cache: ValidationCacheEXT,
+        data: &mut Vec<u8>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetValidationCacheDataEXT",
+            device = tracing::field::debug(self.handle()),
+            validation_cache = tracing::field::debug(validation_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut data_size = 0;
+
+        loop {
+            let __result = (self.commands().get_validation_cache_data_ext)(
+                self.handle(),
+                validation_cache,
+                &mut data_size,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            data.clear();
+            data.reserve(data_size as usize);
+
+            let __result = (self.commands().get_validation_cache_data_ext)(
+                self.handle(),
+                validation_cache,
+                &mut data_size,
+                data.as_mut_ptr() as *mut c_void,
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(data.capacity() >= data_size as usize);
+                data.set_len(data_size as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -5097,6 +8573,15 @@ pub trait ExtValidationCacheExtensionDeviceCommands: DeviceV1_0 {
         dst_cache: ValidationCacheEXT,
         src_caches: &[ValidationCacheEXT],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkMergeValidationCachesEXT",
+            device = tracing::field::debug(self.handle()),
+            dst_cache = tracing::field::debug(dst_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().merge_validation_caches_ext)(
             self.handle(),
             dst_cache,
@@ -5104,6 +8589,9 @@ pub trait ExtValidationCacheExtensionDeviceCommands: DeviceV1_0 {
             src_caches.as_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5124,6 +8612,13 @@ pub trait ExtVertexInputDynamicStateExtensionDeviceCommands: DeviceV1_0 {
         vertex_binding_descriptions: &[impl Cast<Target = VertexInputBindingDescription2EXT>],
         vertex_attribute_descriptions: &[impl Cast<Target = VertexInputAttributeDescription2EXT>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetVertexInputEXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_vertex_input_ext)(
             command_buffer,
             vertex_binding_descriptions.len() as u32,
@@ -5145,6 +8640,14 @@ pub trait FuchsiaBufferCollectionExtensionDeviceCommands: DeviceV1_0 {
         create_info: &BufferCollectionCreateInfoFUCHSIA,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<BufferCollectionFUCHSIA> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateBufferCollectionFUCHSIA",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut collection = MaybeUninit::<BufferCollectionFUCHSIA>::uninit();
 
         let __result = (self.commands().create_buffer_collection_fuchsia)(
@@ -5154,6 +8657,9 @@ pub trait FuchsiaBufferCollectionExtensionDeviceCommands: DeviceV1_0 {
             collection.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(collection.assume_init())
         } else {
@@ -5168,6 +8674,14 @@ pub trait FuchsiaBufferCollectionExtensionDeviceCommands: DeviceV1_0 {
         collection: BufferCollectionFUCHSIA,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyBufferCollectionFUCHSIA",
+            device = tracing::field::debug(self.handle()),
+            collection = tracing::field::debug(collection)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_buffer_collection_fuchsia)(
             self.handle(),
             collection,
@@ -5182,12 +8696,24 @@ pub trait FuchsiaBufferCollectionExtensionDeviceCommands: DeviceV1_0 {
         collection: BufferCollectionFUCHSIA,
         properties: &mut BufferCollectionPropertiesFUCHSIA,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetBufferCollectionPropertiesFUCHSIA",
+            device = tracing::field::debug(self.handle()),
+            collection = tracing::field::debug(collection),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_buffer_collection_properties_fuchsia)(
             self.handle(),
             collection,
             properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5202,6 +8728,15 @@ pub trait FuchsiaBufferCollectionExtensionDeviceCommands: DeviceV1_0 {
         collection: BufferCollectionFUCHSIA,
         buffer_constraints_info: &BufferConstraintsInfoFUCHSIA,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetBufferCollectionBufferConstraintsFUCHSIA",
+            device = tracing::field::debug(self.handle()),
+            collection = tracing::field::debug(collection),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .set_buffer_collection_buffer_constraints_fuchsia)(
@@ -5210,6 +8745,9 @@ pub trait FuchsiaBufferCollectionExtensionDeviceCommands: DeviceV1_0 {
             buffer_constraints_info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5224,6 +8762,15 @@ pub trait FuchsiaBufferCollectionExtensionDeviceCommands: DeviceV1_0 {
         collection: BufferCollectionFUCHSIA,
         image_constraints_info: &ImageConstraintsInfoFUCHSIA,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetBufferCollectionImageConstraintsFUCHSIA",
+            device = tracing::field::debug(self.handle()),
+            collection = tracing::field::debug(collection),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .set_buffer_collection_image_constraints_fuchsia)(
@@ -5232,6 +8779,9 @@ pub trait FuchsiaBufferCollectionExtensionDeviceCommands: DeviceV1_0 {
             image_constraints_info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5250,6 +8800,14 @@ pub trait FuchsiaExternalMemoryExtensionDeviceCommands: DeviceV1_0 {
         &self,
         get_zircon_handle_info: &MemoryGetZirconHandleInfoFUCHSIA,
     ) -> crate::VkResult<zx_handle_t> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryZirconHandleFUCHSIA",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut zircon_handle = MaybeUninit::<zx_handle_t>::uninit();
 
         let __result = (self.commands().get_memory_zircon_handle_fuchsia)(
@@ -5258,6 +8816,9 @@ pub trait FuchsiaExternalMemoryExtensionDeviceCommands: DeviceV1_0 {
             zircon_handle.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(zircon_handle.assume_init())
         } else {
@@ -5273,6 +8834,14 @@ pub trait FuchsiaExternalMemoryExtensionDeviceCommands: DeviceV1_0 {
         zircon_handle: zx_handle_t,
         memory_zircon_handle_properties: &mut MemoryZirconHandlePropertiesFUCHSIA,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryZirconHandlePropertiesFUCHSIA",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_memory_zircon_handle_properties_fuchsia)(
             self.handle(),
             handle_type,
@@ -5280,6 +8849,9 @@ pub trait FuchsiaExternalMemoryExtensionDeviceCommands: DeviceV1_0 {
             memory_zircon_handle_properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5298,6 +8870,14 @@ pub trait FuchsiaExternalSemaphoreExtensionDeviceCommands: DeviceV1_0 {
         &self,
         get_zircon_handle_info: &SemaphoreGetZirconHandleInfoFUCHSIA,
     ) -> crate::VkResult<zx_handle_t> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSemaphoreZirconHandleFUCHSIA",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut zircon_handle = MaybeUninit::<zx_handle_t>::uninit();
 
         let __result = (self.commands().get_semaphore_zircon_handle_fuchsia)(
@@ -5306,6 +8886,9 @@ pub trait FuchsiaExternalSemaphoreExtensionDeviceCommands: DeviceV1_0 {
             zircon_handle.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(zircon_handle.assume_init())
         } else {
@@ -5319,11 +8902,22 @@ pub trait FuchsiaExternalSemaphoreExtensionDeviceCommands: DeviceV1_0 {
         &self,
         import_semaphore_zircon_handle_info: &ImportSemaphoreZirconHandleInfoFUCHSIA,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkImportSemaphoreZirconHandleFUCHSIA",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().import_semaphore_zircon_handle_fuchsia)(
             self.handle(),
             import_semaphore_zircon_handle_info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5343,6 +8937,14 @@ pub trait FuchsiaImagepipeSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &ImagePipeSurfaceCreateInfoFUCHSIA,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateImagePipeSurfaceFUCHSIA",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_image_pipe_surface_fuchsia)(
@@ -5352,6 +8954,9 @@ pub trait FuchsiaImagepipeSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -5371,6 +8976,14 @@ pub trait GgpStreamDescriptorSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &StreamDescriptorSurfaceCreateInfoGGP,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateStreamDescriptorSurfaceGGP",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_stream_descriptor_surface_ggp)(
@@ -5380,6 +8993,9 @@ pub trait GgpStreamDescriptorSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -5398,31 +9014,122 @@ pub trait GoogleDisplayTimingExtensionDeviceCommands: DeviceV1_0 {
         &self,
         swapchain: SwapchainKHR,
     ) -> crate::VkResult<Vec<PastPresentationTimingGOOGLE>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPastPresentationTimingGOOGLE",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut presentation_timing_count = 0;
 
-        (self.commands().get_past_presentation_timing_google)(
-            self.handle(),
-            swapchain,
-            &mut presentation_timing_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_past_presentation_timing_google)(
+                self.handle(),
+                swapchain,
+                &mut presentation_timing_count,
+                ptr::null_mut(),
+            );
 
-        let mut presentation_timings = Vec::with_capacity(presentation_timing_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_past_presentation_timing_google)(
-            self.handle(),
-            swapchain,
-            &mut presentation_timing_count,
-            presentation_timings.as_mut_ptr(),
-        );
+            let mut presentation_timings = Vec::with_capacity(presentation_timing_count as usize);
 
-        debug_assert!(presentation_timings.capacity() >= presentation_timing_count as usize);
-        presentation_timings.set_len(presentation_timing_count as usize);
+            let __result = (self.commands().get_past_presentation_timing_google)(
+                self.handle(),
+                swapchain,
+                &mut presentation_timing_count,
+                presentation_timings.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(presentation_timings)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(presentation_timings.capacity() >= presentation_timing_count as usize);
+            presentation_timings.set_len(presentation_timing_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(presentation_timings)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPastPresentationTimingGOOGLE.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_past_presentation_timing_google`].
+    #[inline]
+    unsafe fn get_past_presentation_timing_google_into(
+        &self,
+        swapchain: SwapchainKHR,
+        presentation_timings: &mut Vec<PastPresentationTimingGOOGLE>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPastPresentationTimingGOOGLE",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut presentation_timing_count = 0;
+
+        loop {
+            let __result = (self.commands().get_past_presentation_timing_google)(
+                self.handle(),
+                swapchain,
+                &mut presentation_timing_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            presentation_timings.clear();
+            presentation_timings.reserve(presentation_timing_count as usize);
+
+            let __result = (self.commands().get_past_presentation_timing_google)(
+                self.handle(),
+                swapchain,
+                &mut presentation_timing_count,
+                presentation_timings.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(
+                    presentation_timings.capacity() >= presentation_timing_count as usize
+                );
+                presentation_timings.set_len(presentation_timing_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -5432,6 +9139,15 @@ pub trait GoogleDisplayTimingExtensionDeviceCommands: DeviceV1_0 {
         &self,
         swapchain: SwapchainKHR,
     ) -> crate::VkResult<RefreshCycleDurationGOOGLE> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetRefreshCycleDurationGOOGLE",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut display_timing_properties = MaybeUninit::<RefreshCycleDurationGOOGLE>::uninit();
 
         let __result = (self.commands().get_refresh_cycle_duration_google)(
@@ -5440,6 +9156,9 @@ pub trait GoogleDisplayTimingExtensionDeviceCommands: DeviceV1_0 {
             display_timing_properties.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(display_timing_properties.assume_init())
         } else {
@@ -5461,6 +9180,13 @@ pub trait HuaweiClusterCullingShaderExtensionDeviceCommands: DeviceV1_0 {
         group_count_y: u32,
         group_count_z: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawClusterHUAWEI",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_cluster_huawei)(
             command_buffer,
             group_count_x,
@@ -5477,6 +9203,14 @@ pub trait HuaweiClusterCullingShaderExtensionDeviceCommands: DeviceV1_0 {
         buffer: Buffer,
         offset: DeviceSize,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawClusterIndirectHUAWEI",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_draw_cluster_indirect_huawei)(command_buffer, buffer, offset);
     }
@@ -5494,6 +9228,14 @@ pub trait HuaweiInvocationMaskExtensionDeviceCommands: DeviceV1_0 {
         image_view: ImageView,
         image_layout: ImageLayout,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindInvocationMaskHUAWEI",
+            command_buffer = tracing::field::debug(command_buffer),
+            image_view = tracing::field::debug(image_view)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_invocation_mask_huawei)(
             command_buffer,
             image_view,
@@ -5509,6 +9251,13 @@ pub trait HuaweiSubpassShadingExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdSubpassShadingHUAWEI.html>
     #[inline]
     unsafe fn cmd_subpass_shading_huawei(&self, command_buffer: CommandBuffer) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSubpassShadingHUAWEI",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_subpass_shading_huawei)(command_buffer);
     }
 
@@ -5518,6 +9267,15 @@ pub trait HuaweiSubpassShadingExtensionDeviceCommands: DeviceV1_0 {
         &self,
         renderpass: RenderPass,
     ) -> crate::VkResult<Extent2D> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI",
+            device = tracing::field::debug(self.handle()),
+            renderpass = tracing::field::debug(renderpass),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut max_workgroup_size = MaybeUninit::<Extent2D>::uninit();
 
         let __result = (self
@@ -5528,6 +9286,9 @@ pub trait HuaweiSubpassShadingExtensionDeviceCommands: DeviceV1_0 {
             max_workgroup_size.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(max_workgroup_size.assume_init())
         } else {
@@ -5546,6 +9307,14 @@ pub trait IntelPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
         &self,
         acquire_info: &PerformanceConfigurationAcquireInfoINTEL,
     ) -> crate::VkResult<PerformanceConfigurationINTEL> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAcquirePerformanceConfigurationINTEL",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut configuration = MaybeUninit::<PerformanceConfigurationINTEL>::uninit();
 
         let __result = (self.commands().acquire_performance_configuration_intel)(
@@ -5554,6 +9323,9 @@ pub trait IntelPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
             configuration.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(configuration.assume_init())
         } else {
@@ -5568,9 +9340,20 @@ pub trait IntelPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         marker_info: &PerformanceMarkerInfoINTEL,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPerformanceMarkerINTEL",
+            command_buffer = tracing::field::debug(command_buffer),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_performance_marker_intel)(command_buffer, marker_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5585,9 +9368,20 @@ pub trait IntelPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         override_info: &PerformanceOverrideInfoINTEL,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPerformanceOverrideINTEL",
+            command_buffer = tracing::field::debug(command_buffer),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_performance_override_intel)(command_buffer, override_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5602,9 +9396,20 @@ pub trait IntelPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         marker_info: &PerformanceStreamMarkerInfoINTEL,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPerformanceStreamMarkerINTEL",
+            command_buffer = tracing::field::debug(command_buffer),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_performance_stream_marker_intel)(command_buffer, marker_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5618,6 +9423,14 @@ pub trait IntelPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
         &self,
         parameter: PerformanceParameterTypeINTEL,
     ) -> crate::VkResult<PerformanceValueINTEL> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPerformanceParameterINTEL",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut value = MaybeUninit::<PerformanceValueINTEL>::uninit();
 
         let __result = (self.commands().get_performance_parameter_intel)(
@@ -5626,6 +9439,9 @@ pub trait IntelPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
             value.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(value.assume_init())
         } else {
@@ -5639,9 +9455,20 @@ pub trait IntelPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
         &self,
         initialize_info: &InitializePerformanceApiInfoINTEL,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkInitializePerformanceApiINTEL",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().initialize_performance_api_intel)(self.handle(), initialize_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5656,9 +9483,21 @@ pub trait IntelPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
         queue: Queue,
         configuration: PerformanceConfigurationINTEL,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkQueueSetPerformanceConfigurationINTEL",
+            queue = tracing::field::debug(queue),
+            configuration = tracing::field::debug(configuration),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().queue_set_performance_configuration_intel)(queue, configuration);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5672,9 +9511,21 @@ pub trait IntelPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
         &self,
         configuration: PerformanceConfigurationINTEL,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkReleasePerformanceConfigurationINTEL",
+            device = tracing::field::debug(self.handle()),
+            configuration = tracing::field::debug(configuration),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().release_performance_configuration_intel)(self.handle(), configuration);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5685,6 +9536,13 @@ pub trait IntelPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkUninitializePerformanceApiINTEL.html>
     #[inline]
     unsafe fn uninitialize_performance_api_intel(&self) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkUninitializePerformanceApiINTEL",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().uninitialize_performance_api_intel)(self.handle());
     }
 }
@@ -5701,6 +9559,15 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         infos: &[impl Cast<Target = AccelerationStructureBuildGeometryInfoKHR>],
         build_range_infos: &[&[impl Cast<Target = AccelerationStructureBuildRangeInfoKHR>]],
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBuildAccelerationStructuresKHR",
+            device = tracing::field::debug(self.handle()),
+            deferred_operation = tracing::field::debug(deferred_operation),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().build_acceleration_structures_khr)(
             self.handle(),
             deferred_operation,
@@ -5709,6 +9576,9 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
             build_range_infos.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -5726,6 +9596,13 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         indirect_strides: &[u32],
         max_primitive_counts: &[&[u32]],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBuildAccelerationStructuresIndirectKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .cmd_build_acceleration_structures_indirect_khr)(
@@ -5746,6 +9623,13 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         infos: &[impl Cast<Target = AccelerationStructureBuildGeometryInfoKHR>],
         build_range_infos: &[&[impl Cast<Target = AccelerationStructureBuildRangeInfoKHR>]],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBuildAccelerationStructuresKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_build_acceleration_structures_khr)(
             command_buffer,
             infos.len() as u32,
@@ -5761,6 +9645,13 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         info: &CopyAccelerationStructureInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyAccelerationStructureKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_acceleration_structure_khr)(command_buffer, info);
     }
 
@@ -5771,6 +9662,13 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         info: &CopyAccelerationStructureToMemoryInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyAccelerationStructureToMemoryKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self
                 .commands()
@@ -5784,6 +9682,13 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         info: &CopyMemoryToAccelerationStructureInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyMemoryToAccelerationStructureKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self
                 .commands()
@@ -5800,6 +9705,14 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         query_pool: QueryPool,
         first_query: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdWriteAccelerationStructuresPropertiesKHR",
+            command_buffer = tracing::field::debug(command_buffer),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .cmd_write_acceleration_structures_properties_khr)(
@@ -5819,12 +9732,24 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         deferred_operation: DeferredOperationKHR,
         info: &CopyAccelerationStructureInfoKHR,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCopyAccelerationStructureKHR",
+            device = tracing::field::debug(self.handle()),
+            deferred_operation = tracing::field::debug(deferred_operation),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().copy_acceleration_structure_khr)(
             self.handle(),
             deferred_operation,
             info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -5839,12 +9764,24 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         deferred_operation: DeferredOperationKHR,
         info: &CopyAccelerationStructureToMemoryInfoKHR,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCopyAccelerationStructureToMemoryKHR",
+            device = tracing::field::debug(self.handle()),
+            deferred_operation = tracing::field::debug(deferred_operation),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().copy_acceleration_structure_to_memory_khr)(
             self.handle(),
             deferred_operation,
             info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -5859,12 +9796,24 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         deferred_operation: DeferredOperationKHR,
         info: &CopyMemoryToAccelerationStructureInfoKHR,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCopyMemoryToAccelerationStructureKHR",
+            device = tracing::field::debug(self.handle()),
+            deferred_operation = tracing::field::debug(deferred_operation),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().copy_memory_to_acceleration_structure_khr)(
             self.handle(),
             deferred_operation,
             info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -5879,6 +9828,14 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         create_info: &AccelerationStructureCreateInfoKHR,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<AccelerationStructureKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateAccelerationStructureKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut acceleration_structure = MaybeUninit::<AccelerationStructureKHR>::uninit();
 
         let __result = (self.commands().create_acceleration_structure_khr)(
@@ -5888,6 +9845,9 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
             acceleration_structure.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(acceleration_structure.assume_init())
         } else {
@@ -5902,6 +9862,14 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         acceleration_structure: AccelerationStructureKHR,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyAccelerationStructureKHR",
+            device = tracing::field::debug(self.handle()),
+            acceleration_structure = tracing::field::debug(acceleration_structure)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_acceleration_structure_khr)(
             self.handle(),
             acceleration_structure,
@@ -5918,6 +9886,13 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         max_primitive_counts: &[u32],
         size_info: &mut AccelerationStructureBuildSizesInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetAccelerationStructureBuildSizesKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_acceleration_structure_build_sizes_khr)(
             self.handle(),
             build_type,
@@ -5933,6 +9908,13 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         &self,
         info: &AccelerationStructureDeviceAddressInfoKHR,
     ) -> DeviceAddress {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetAccelerationStructureDeviceAddressKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result =
             (self
                 .commands()
@@ -5947,6 +9929,13 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         &self,
         version_info: &AccelerationStructureVersionInfoKHR,
     ) -> AccelerationStructureCompatibilityKHR {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceAccelerationStructureCompatibilityKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut compatibility = MaybeUninit::<AccelerationStructureCompatibilityKHR>::uninit();
 
         let __result = (self
@@ -5969,6 +9958,14 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         data: &mut [u8],
         stride: usize,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkWriteAccelerationStructuresPropertiesKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().write_acceleration_structures_properties_khr)(
             self.handle(),
             acceleration_structures.len() as u32,
@@ -5979,6 +9976,9 @@ pub trait KhrAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
             stride,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -5998,6 +9998,14 @@ pub trait KhrAndroidSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &AndroidSurfaceCreateInfoKHR,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateAndroidSurfaceKHR",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_android_surface_khr)(
@@ -6007,6 +10015,9 @@ pub trait KhrAndroidSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -6025,12 +10036,23 @@ pub trait KhrBindMemory2ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         bind_infos: &[impl Cast<Target = BindBufferMemoryInfo>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBindBufferMemory2KHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().bind_buffer_memory2_khr)(
             self.handle(),
             bind_infos.len() as u32,
             bind_infos.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -6044,12 +10066,23 @@ pub trait KhrBindMemory2ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         bind_infos: &[impl Cast<Target = BindImageMemoryInfo>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBindImageMemory2KHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().bind_image_memory2_khr)(
             self.handle(),
             bind_infos.len() as u32,
             bind_infos.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -6068,6 +10101,13 @@ pub trait KhrBufferDeviceAddressExtensionDeviceCommands: DeviceV1_0 {
         &self,
         info: &BufferDeviceAddressInfo,
     ) -> DeviceAddress {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetBufferDeviceAddressKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_buffer_device_address_khr)(self.handle(), info);
 
         __result
@@ -6076,6 +10116,13 @@ pub trait KhrBufferDeviceAddressExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetBufferOpaqueCaptureAddressKHR.html>
     #[inline]
     unsafe fn get_buffer_opaque_capture_address_khr(&self, info: &BufferDeviceAddressInfo) -> u64 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetBufferOpaqueCaptureAddressKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_buffer_opaque_capture_address_khr)(self.handle(), info);
 
         __result
@@ -6087,6 +10134,13 @@ pub trait KhrBufferDeviceAddressExtensionDeviceCommands: DeviceV1_0 {
         &self,
         info: &DeviceMemoryOpaqueCaptureAddressInfo,
     ) -> u64 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceMemoryOpaqueCaptureAddressKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result =
             (self.commands().get_device_memory_opaque_capture_address_khr)(self.handle(), info);
 
@@ -6104,6 +10158,14 @@ pub trait KhrCalibratedTimestampsExtensionDeviceCommands: DeviceV1_0 {
         &self,
         timestamp_infos: &[impl Cast<Target = CalibratedTimestampInfoKHR>],
     ) -> crate::VkResult<(Vec<u64>, u64)> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetCalibratedTimestampsKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut timestamps = Vec::with_capacity(timestamp_infos.len() as usize);
         let mut max_deviation = MaybeUninit::<u64>::uninit();
 
@@ -6115,6 +10177,9 @@ pub trait KhrCalibratedTimestampsExtensionDeviceCommands: DeviceV1_0 {
             max_deviation.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         debug_assert!(timestamps.capacity() >= timestamp_infos.len() as usize);
         timestamps.set_len(timestamp_infos.len() as usize);
 
@@ -6124,6 +10189,51 @@ pub trait KhrCalibratedTimestampsExtensionDeviceCommands: DeviceV1_0 {
             Err(__result.into())
         }
     }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetCalibratedTimestampsKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_calibrated_timestamps_khr`].
+    #[inline]
+    unsafe fn get_calibrated_timestamps_khr_into(
+        &self,
+        timestamp_infos: &[impl Cast<Target = CalibratedTimestampInfoKHR>],
+        timestamps: &mut Vec<u64>,
+    ) -> crate::VkResult<u64> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetCalibratedTimestampsKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        timestamps.clear();
+        timestamps.reserve(timestamp_infos.len() as usize);
+        let mut max_deviation = MaybeUninit::<u64>::uninit();
+
+        let __result = (self.commands().get_calibrated_timestamps_khr)(
+            self.handle(),
+            timestamp_infos.len() as u32,
+            timestamp_infos.as_ptr().cast(),
+            timestamps.as_mut_ptr(),
+            max_deviation.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            debug_assert!(timestamps.capacity() >= timestamp_infos.len() as usize);
+            timestamps.set_len(timestamp_infos.len() as usize);
+        }
+
+        if __result == Result::SUCCESS {
+            Ok(max_deviation.assume_init())
+        } else {
+            Err(__result.into())
+        }
+    }
 }
 
 impl<C: DeviceV1_0 + ?Sized> KhrCalibratedTimestampsExtensionDeviceCommands for C {}
@@ -6136,33 +10246,122 @@ pub trait KhrCalibratedTimestampsExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<TimeDomainKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceCalibrateableTimeDomainsKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut time_domain_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_calibrateable_time_domains_khr)(
-            physical_device,
-            &mut time_domain_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_calibrateable_time_domains_khr)(
+                physical_device,
+                &mut time_domain_count,
+                ptr::null_mut(),
+            );
 
-        let mut time_domains = Vec::with_capacity(time_domain_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_calibrateable_time_domains_khr)(
-            physical_device,
-            &mut time_domain_count,
-            time_domains.as_mut_ptr(),
-        );
+            let mut time_domains = Vec::with_capacity(time_domain_count as usize);
 
-        debug_assert!(time_domains.capacity() >= time_domain_count as usize);
-        time_domains.set_len(time_domain_count as usize);
+            let __result = (self
+                .commands()
+                .get_physical_device_calibrateable_time_domains_khr)(
+                physical_device,
+                &mut time_domain_count,
+                time_domains.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(time_domains)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(time_domains.capacity() >= time_domain_count as usize);
+            time_domains.set_len(time_domain_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(time_domains)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceCalibrateableTimeDomainsKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_calibrateable_time_domains_khr`].
+    #[inline]
+    unsafe fn get_physical_device_calibrateable_time_domains_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        time_domains: &mut Vec<TimeDomainKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceCalibrateableTimeDomainsKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut time_domain_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_calibrateable_time_domains_khr)(
+                physical_device,
+                &mut time_domain_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            time_domains.clear();
+            time_domains.reserve(time_domain_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_calibrateable_time_domains_khr)(
+                physical_device,
+                &mut time_domain_count,
+                time_domains.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(time_domains.capacity() >= time_domain_count as usize);
+                time_domains.set_len(time_domain_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -6177,34 +10376,126 @@ pub trait KhrCooperativeMatrixExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<CooperativeMatrixPropertiesKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceCooperativeMatrixPropertiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut property_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_cooperative_matrix_properties_khr)(
-            physical_device,
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_matrix_properties_khr)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut properties =
+                ::alloc::vec![CooperativeMatrixPropertiesKHR::default(); property_count as usize];
+
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_matrix_properties_khr)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
 
-        let mut properties =
-            ::alloc::vec![CooperativeMatrixPropertiesKHR::default(); property_count as usize];
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_cooperative_matrix_properties_khr)(
-            physical_device,
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceCooperativeMatrixPropertiesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_cooperative_matrix_properties_khr`].
+    #[inline]
+    unsafe fn get_physical_device_cooperative_matrix_properties_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        properties: &mut Vec<CooperativeMatrixPropertiesKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceCooperativeMatrixPropertiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut property_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_matrix_properties_khr)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.resize(
+                property_count as usize,
+                CooperativeMatrixPropertiesKHR::default(),
+            );
+
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_matrix_properties_khr)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -6220,6 +10511,13 @@ pub trait KhrCopyCommands2ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         blit_image_info: &BlitImageInfo2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBlitImage2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_blit_image2_khr)(command_buffer, blit_image_info);
     }
 
@@ -6230,6 +10528,13 @@ pub trait KhrCopyCommands2ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         copy_buffer_info: &CopyBufferInfo2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyBuffer2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_buffer2_khr)(command_buffer, copy_buffer_info);
     }
 
@@ -6240,6 +10545,13 @@ pub trait KhrCopyCommands2ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         copy_buffer_to_image_info: &CopyBufferToImageInfo2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyBufferToImage2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_buffer_to_image2_khr)(
             command_buffer,
             copy_buffer_to_image_info,
@@ -6253,6 +10565,13 @@ pub trait KhrCopyCommands2ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         copy_image_info: &CopyImageInfo2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyImage2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_image2_khr)(command_buffer, copy_image_info);
     }
 
@@ -6263,6 +10582,13 @@ pub trait KhrCopyCommands2ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         copy_image_to_buffer_info: &CopyImageToBufferInfo2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyImageToBuffer2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_image_to_buffer2_khr)(
             command_buffer,
             copy_image_to_buffer_info,
@@ -6276,6 +10602,13 @@ pub trait KhrCopyCommands2ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         resolve_image_info: &ResolveImageInfo2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdResolveImage2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_resolve_image2_khr)(command_buffer, resolve_image_info);
     }
 }
@@ -6291,6 +10624,13 @@ pub trait KhrCopyMemoryIndirectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         copy_memory_indirect_info: &CopyMemoryIndirectInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyMemoryIndirectKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_memory_indirect_khr)(
             command_buffer,
             copy_memory_indirect_info,
@@ -6304,6 +10644,13 @@ pub trait KhrCopyMemoryIndirectExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         copy_memory_to_image_indirect_info: &CopyMemoryToImageIndirectInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyMemoryToImageIndirectKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_memory_to_image_indirect_khr)(
             command_buffer,
             copy_memory_to_image_indirect_info,
@@ -6323,6 +10670,13 @@ pub trait KhrCreateRenderpass2ExtensionDeviceCommands: DeviceV1_0 {
         render_pass_begin: &RenderPassBeginInfo,
         subpass_begin_info: &SubpassBeginInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginRenderPass2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_begin_render_pass2_khr)(
             command_buffer,
             render_pass_begin,
@@ -6337,6 +10691,13 @@ pub trait KhrCreateRenderpass2ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         subpass_end_info: &SubpassEndInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndRenderPass2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_end_render_pass2_khr)(command_buffer, subpass_end_info);
     }
 
@@ -6348,6 +10709,13 @@ pub trait KhrCreateRenderpass2ExtensionDeviceCommands: DeviceV1_0 {
         subpass_begin_info: &SubpassBeginInfo,
         subpass_end_info: &SubpassEndInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdNextSubpass2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_next_subpass2_khr)(
             command_buffer,
             subpass_begin_info,
@@ -6362,6 +10730,14 @@ pub trait KhrCreateRenderpass2ExtensionDeviceCommands: DeviceV1_0 {
         create_info: &RenderPassCreateInfo2,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<RenderPass> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateRenderPass2KHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut render_pass = MaybeUninit::<RenderPass>::uninit();
 
         let __result = (self.commands().create_render_pass2_khr)(
@@ -6371,6 +10747,9 @@ pub trait KhrCreateRenderpass2ExtensionDeviceCommands: DeviceV1_0 {
             render_pass.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(render_pass.assume_init())
         } else {
@@ -6389,6 +10768,14 @@ pub trait KhrDeferredHostOperationsExtensionDeviceCommands: DeviceV1_0 {
         &self,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<DeferredOperationKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDeferredOperationKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut deferred_operation = MaybeUninit::<DeferredOperationKHR>::uninit();
 
         let __result = (self.commands().create_deferred_operation_khr)(
@@ -6397,6 +10784,9 @@ pub trait KhrDeferredHostOperationsExtensionDeviceCommands: DeviceV1_0 {
             deferred_operation.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(deferred_operation.assume_init())
         } else {
@@ -6410,8 +10800,20 @@ pub trait KhrDeferredHostOperationsExtensionDeviceCommands: DeviceV1_0 {
         &self,
         operation: DeferredOperationKHR,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDeferredOperationJoinKHR",
+            device = tracing::field::debug(self.handle()),
+            operation = tracing::field::debug(operation),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().deferred_operation_join_khr)(self.handle(), operation);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -6426,6 +10828,14 @@ pub trait KhrDeferredHostOperationsExtensionDeviceCommands: DeviceV1_0 {
         operation: DeferredOperationKHR,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyDeferredOperationKHR",
+            device = tracing::field::debug(self.handle()),
+            operation = tracing::field::debug(operation)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_deferred_operation_khr)(
             self.handle(),
             operation,
@@ -6439,6 +10849,14 @@ pub trait KhrDeferredHostOperationsExtensionDeviceCommands: DeviceV1_0 {
         &self,
         operation: DeferredOperationKHR,
     ) -> u32 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeferredOperationMaxConcurrencyKHR",
+            device = tracing::field::debug(self.handle()),
+            operation = tracing::field::debug(operation)
+        )
+        .entered();
+
         let __result =
             (self.commands().get_deferred_operation_max_concurrency_khr)(self.handle(), operation);
 
@@ -6451,9 +10869,21 @@ pub trait KhrDeferredHostOperationsExtensionDeviceCommands: DeviceV1_0 {
         &self,
         operation: DeferredOperationKHR,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeferredOperationResultKHR",
+            device = tracing::field::debug(self.handle()),
+            operation = tracing::field::debug(operation),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().get_deferred_operation_result_khr)(self.handle(), operation);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -6476,6 +10906,15 @@ pub trait KhrDescriptorUpdateTemplateExtensionDeviceCommands: DeviceV1_0 {
         set: u32,
         data: *const c_void,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushDescriptorSetWithTemplateKHR",
+            command_buffer = tracing::field::debug(command_buffer),
+            descriptor_update_template = tracing::field::debug(descriptor_update_template),
+            layout = tracing::field::debug(layout)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_push_descriptor_set_with_template_khr)(
             command_buffer,
             descriptor_update_template,
@@ -6492,6 +10931,14 @@ pub trait KhrDescriptorUpdateTemplateExtensionDeviceCommands: DeviceV1_0 {
         create_info: &DescriptorUpdateTemplateCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<DescriptorUpdateTemplate> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDescriptorUpdateTemplateKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut descriptor_update_template = MaybeUninit::<DescriptorUpdateTemplate>::uninit();
 
         let __result = (self.commands().create_descriptor_update_template_khr)(
@@ -6501,6 +10948,9 @@ pub trait KhrDescriptorUpdateTemplateExtensionDeviceCommands: DeviceV1_0 {
             descriptor_update_template.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(descriptor_update_template.assume_init())
         } else {
@@ -6515,6 +10965,14 @@ pub trait KhrDescriptorUpdateTemplateExtensionDeviceCommands: DeviceV1_0 {
         descriptor_update_template: DescriptorUpdateTemplate,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyDescriptorUpdateTemplateKHR",
+            device = tracing::field::debug(self.handle()),
+            descriptor_update_template = tracing::field::debug(descriptor_update_template)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_descriptor_update_template_khr)(
             self.handle(),
             descriptor_update_template,
@@ -6530,6 +10988,15 @@ pub trait KhrDescriptorUpdateTemplateExtensionDeviceCommands: DeviceV1_0 {
         descriptor_update_template: DescriptorUpdateTemplate,
         data: *const c_void,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkUpdateDescriptorSetWithTemplateKHR",
+            device = tracing::field::debug(self.handle()),
+            descriptor_set = tracing::field::debug(descriptor_set),
+            descriptor_update_template = tracing::field::debug(descriptor_update_template)
+        )
+        .entered();
+
         let __result = (self.commands().update_descriptor_set_with_template_khr)(
             self.handle(),
             descriptor_set,
@@ -6549,6 +11016,14 @@ pub trait KhrDeviceGroupExtensionDeviceCommands: DeviceV1_0 {
         &self,
         acquire_info: &AcquireNextImageInfoKHR,
     ) -> crate::VkSuccessResult<u32> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAcquireNextImage2KHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut image_index = MaybeUninit::<u32>::uninit();
 
         let __result = (self.commands().acquire_next_image2_khr)(
@@ -6557,6 +11032,9 @@ pub trait KhrDeviceGroupExtensionDeviceCommands: DeviceV1_0 {
             image_index.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok((image_index.assume_init(), __result.into()))
         } else {
@@ -6576,6 +11054,13 @@ pub trait KhrDeviceGroupExtensionDeviceCommands: DeviceV1_0 {
         group_count_y: u32,
         group_count_z: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDispatchBaseKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_dispatch_base_khr)(
             command_buffer,
             base_group_x,
@@ -6590,6 +11075,13 @@ pub trait KhrDeviceGroupExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdSetDeviceMaskKHR.html>
     #[inline]
     unsafe fn cmd_set_device_mask_khr(&self, command_buffer: CommandBuffer, device_mask: u32) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDeviceMaskKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_device_mask_khr)(command_buffer, device_mask);
     }
 
@@ -6601,6 +11093,13 @@ pub trait KhrDeviceGroupExtensionDeviceCommands: DeviceV1_0 {
         local_device_index: u32,
         remote_device_index: u32,
     ) -> PeerMemoryFeatureFlags {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceGroupPeerMemoryFeaturesKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut peer_memory_features = MaybeUninit::<PeerMemoryFeatureFlags>::uninit();
 
         let __result = (self.commands().get_device_group_peer_memory_features_khr)(
@@ -6620,11 +11119,22 @@ pub trait KhrDeviceGroupExtensionDeviceCommands: DeviceV1_0 {
         &self,
         device_group_present_capabilities: &mut DeviceGroupPresentCapabilitiesKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceGroupPresentCapabilitiesKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_device_group_present_capabilities_khr)(
             self.handle(),
             device_group_present_capabilities,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -6638,6 +11148,15 @@ pub trait KhrDeviceGroupExtensionDeviceCommands: DeviceV1_0 {
         &self,
         surface: SurfaceKHR,
     ) -> crate::VkResult<DeviceGroupPresentModeFlagsKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceGroupSurfacePresentModesKHR",
+            device = tracing::field::debug(self.handle()),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut modes = MaybeUninit::<DeviceGroupPresentModeFlagsKHR>::uninit();
 
         let __result = (self.commands().get_device_group_surface_present_modes_khr)(
@@ -6646,6 +11165,9 @@ pub trait KhrDeviceGroupExtensionDeviceCommands: DeviceV1_0 {
             modes.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(modes.assume_init())
         } else {
@@ -6665,31 +11187,121 @@ pub trait KhrDeviceGroupExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         surface: SurfaceKHR,
     ) -> crate::VkResult<Vec<Rect2D>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDevicePresentRectanglesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut rect_count = 0;
 
-        (self.commands().get_physical_device_present_rectangles_khr)(
-            physical_device,
-            surface,
-            &mut rect_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_physical_device_present_rectangles_khr)(
+                physical_device,
+                surface,
+                &mut rect_count,
+                ptr::null_mut(),
+            );
 
-        let mut rects = Vec::with_capacity(rect_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_physical_device_present_rectangles_khr)(
-            physical_device,
-            surface,
-            &mut rect_count,
-            rects.as_mut_ptr(),
-        );
+            let mut rects = Vec::with_capacity(rect_count as usize);
 
-        debug_assert!(rects.capacity() >= rect_count as usize);
-        rects.set_len(rect_count as usize);
+            let __result = (self.commands().get_physical_device_present_rectangles_khr)(
+                physical_device,
+                surface,
+                &mut rect_count,
+                rects.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(rects)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(rects.capacity() >= rect_count as usize);
+            rects.set_len(rect_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(rects)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDevicePresentRectanglesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_present_rectangles_khr`].
+    #[inline]
+    unsafe fn get_physical_device_present_rectangles_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+        rects: &mut Vec<Rect2D>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDevicePresentRectanglesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut rect_count = 0;
+
+        loop {
+            let __result = (self.commands().get_physical_device_present_rectangles_khr)(
+                physical_device,
+                surface,
+                &mut rect_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            rects.clear();
+            rects.reserve(rect_count as usize);
+
+            let __result = (self.commands().get_physical_device_present_rectangles_khr)(
+                physical_device,
+                surface,
+                &mut rect_count,
+                rects.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(rects.capacity() >= rect_count as usize);
+                rects.set_len(rect_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -6703,32 +11315,119 @@ pub trait KhrDeviceGroupCreationExtensionInstanceCommands: InstanceV1_0 {
     unsafe fn enumerate_physical_device_groups_khr(
         &self,
     ) -> crate::VkResult<Vec<PhysicalDeviceGroupProperties>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumeratePhysicalDeviceGroupsKHR",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut physical_device_group_count = 0;
 
-        (self.commands().enumerate_physical_device_groups_khr)(
-            self.handle(),
-            &mut physical_device_group_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().enumerate_physical_device_groups_khr)(
+                self.handle(),
+                &mut physical_device_group_count,
+                ptr::null_mut(),
+            );
 
-        let mut physical_device_group_properties =
-            Vec::with_capacity(physical_device_group_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().enumerate_physical_device_groups_khr)(
-            self.handle(),
-            &mut physical_device_group_count,
-            physical_device_group_properties.as_mut_ptr(),
-        );
+            let mut physical_device_group_properties =
+                Vec::with_capacity(physical_device_group_count as usize);
 
-        debug_assert!(
-            physical_device_group_properties.capacity() >= physical_device_group_count as usize
-        );
-        physical_device_group_properties.set_len(physical_device_group_count as usize);
+            let __result = (self.commands().enumerate_physical_device_groups_khr)(
+                self.handle(),
+                &mut physical_device_group_count,
+                physical_device_group_properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(physical_device_group_properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(
+                physical_device_group_properties.capacity() >= physical_device_group_count as usize
+            );
+            physical_device_group_properties.set_len(physical_device_group_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(physical_device_group_properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEnumeratePhysicalDeviceGroupsKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::enumerate_physical_device_groups_khr`].
+    #[inline]
+    unsafe fn enumerate_physical_device_groups_khr_into(
+        &self,
+        physical_device_group_properties: &mut Vec<PhysicalDeviceGroupProperties>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumeratePhysicalDeviceGroupsKHR",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut physical_device_group_count = 0;
+
+        loop {
+            let __result = (self.commands().enumerate_physical_device_groups_khr)(
+                self.handle(),
+                &mut physical_device_group_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            physical_device_group_properties.clear();
+            physical_device_group_properties.reserve(physical_device_group_count as usize);
+
+            let __result = (self.commands().enumerate_physical_device_groups_khr)(
+                self.handle(),
+                &mut physical_device_group_count,
+                physical_device_group_properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(
+                    physical_device_group_properties.capacity()
+                        >= physical_device_group_count as usize
+                );
+                physical_device_group_properties.set_len(physical_device_group_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -6746,6 +11445,17 @@ pub trait KhrDisplayExtensionInstanceCommands: InstanceV1_0 {
         create_info: &DisplayModeCreateInfoKHR,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<DisplayModeKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDisplayModeKHR",
+            physical_device = tracing::field::debug(physical_device),
+            display = tracing::field::Empty,
+            result = tracing::field::Empty
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        __span.record("display", tracing::field::debug(display));
+
         let mut mode = MaybeUninit::<DisplayModeKHR>::uninit();
 
         let __result = (self.commands().create_display_mode_khr)(
@@ -6756,6 +11466,9 @@ pub trait KhrDisplayExtensionInstanceCommands: InstanceV1_0 {
             mode.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(mode.assume_init())
         } else {
@@ -6770,6 +11483,14 @@ pub trait KhrDisplayExtensionInstanceCommands: InstanceV1_0 {
         create_info: &DisplaySurfaceCreateInfoKHR,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDisplayPlaneSurfaceKHR",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_display_plane_surface_khr)(
@@ -6779,6 +11500,9 @@ pub trait KhrDisplayExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -6793,31 +11517,125 @@ pub trait KhrDisplayExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         display: DisplayKHR,
     ) -> crate::VkResult<Vec<DisplayModePropertiesKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDisplayModePropertiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            display = tracing::field::Empty,
+            result = tracing::field::Empty
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        __span.record("display", tracing::field::debug(display));
+
         let mut property_count = 0;
 
-        (self.commands().get_display_mode_properties_khr)(
-            physical_device,
-            display,
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_display_mode_properties_khr)(
+                physical_device,
+                display,
+                &mut property_count,
+                ptr::null_mut(),
+            );
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_display_mode_properties_khr)(
-            physical_device,
-            display,
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+            let mut properties = Vec::with_capacity(property_count as usize);
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+            let __result = (self.commands().get_display_mode_properties_khr)(
+                physical_device,
+                display,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetDisplayModePropertiesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_display_mode_properties_khr`].
+    #[inline]
+    unsafe fn get_display_mode_properties_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        display: DisplayKHR,
+        properties: &mut Vec<DisplayModePropertiesKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDisplayModePropertiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            display = tracing::field::Empty,
+            result = tracing::field::Empty
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        __span.record("display", tracing::field::debug(display));
+
+        let mut property_count = 0;
+
+        loop {
+            let __result = (self.commands().get_display_mode_properties_khr)(
+                physical_device,
+                display,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self.commands().get_display_mode_properties_khr)(
+                physical_device,
+                display,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -6829,6 +11647,15 @@ pub trait KhrDisplayExtensionInstanceCommands: InstanceV1_0 {
         mode: DisplayModeKHR,
         plane_index: u32,
     ) -> crate::VkResult<DisplayPlaneCapabilitiesKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDisplayPlaneCapabilitiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            mode = tracing::field::debug(mode),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut capabilities = MaybeUninit::<DisplayPlaneCapabilitiesKHR>::uninit();
 
         let __result = (self.commands().get_display_plane_capabilities_khr)(
@@ -6838,6 +11665,9 @@ pub trait KhrDisplayExtensionInstanceCommands: InstanceV1_0 {
             capabilities.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(capabilities.assume_init())
         } else {
@@ -6852,31 +11682,119 @@ pub trait KhrDisplayExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         plane_index: u32,
     ) -> crate::VkResult<Vec<DisplayKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDisplayPlaneSupportedDisplaysKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut display_count = 0;
 
-        (self.commands().get_display_plane_supported_displays_khr)(
-            physical_device,
-            plane_index,
-            &mut display_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_display_plane_supported_displays_khr)(
+                physical_device,
+                plane_index,
+                &mut display_count,
+                ptr::null_mut(),
+            );
 
-        let mut displays = Vec::with_capacity(display_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_display_plane_supported_displays_khr)(
-            physical_device,
-            plane_index,
-            &mut display_count,
-            displays.as_mut_ptr(),
-        );
+            let mut displays = Vec::with_capacity(display_count as usize);
 
-        debug_assert!(displays.capacity() >= display_count as usize);
-        displays.set_len(display_count as usize);
+            let __result = (self.commands().get_display_plane_supported_displays_khr)(
+                physical_device,
+                plane_index,
+                &mut display_count,
+                displays.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(displays)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(displays.capacity() >= display_count as usize);
+            displays.set_len(display_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(displays)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetDisplayPlaneSupportedDisplaysKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_display_plane_supported_displays_khr`].
+    #[inline]
+    unsafe fn get_display_plane_supported_displays_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        plane_index: u32,
+        displays: &mut Vec<DisplayKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDisplayPlaneSupportedDisplaysKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut display_count = 0;
+
+        loop {
+            let __result = (self.commands().get_display_plane_supported_displays_khr)(
+                physical_device,
+                plane_index,
+                &mut display_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            displays.clear();
+            displays.reserve(display_count as usize);
+
+            let __result = (self.commands().get_display_plane_supported_displays_khr)(
+                physical_device,
+                plane_index,
+                &mut display_count,
+                displays.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(displays.capacity() >= display_count as usize);
+                displays.set_len(display_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -6886,33 +11804,122 @@ pub trait KhrDisplayExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<DisplayPlanePropertiesKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut property_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_display_plane_properties_khr)(
-            physical_device,
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_display_plane_properties_khr)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_display_plane_properties_khr)(
-            physical_device,
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+            let mut properties = Vec::with_capacity(property_count as usize);
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+            let __result = (self
+                .commands()
+                .get_physical_device_display_plane_properties_khr)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceDisplayPlanePropertiesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_display_plane_properties_khr`].
+    #[inline]
+    unsafe fn get_physical_device_display_plane_properties_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        properties: &mut Vec<DisplayPlanePropertiesKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut property_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_display_plane_properties_khr)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_display_plane_properties_khr)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -6922,44 +11929,137 @@ pub trait KhrDisplayExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<DisplayPropertiesKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceDisplayPropertiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut property_count = 0;
 
-        (self.commands().get_physical_device_display_properties_khr)(
-            physical_device,
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_physical_device_display_properties_khr)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_physical_device_display_properties_khr)(
-            physical_device,
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+            let mut properties = Vec::with_capacity(property_count as usize);
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+            let __result = (self.commands().get_physical_device_display_properties_khr)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
         }
     }
-}
-
-impl<C: InstanceV1_0 + ?Sized> KhrDisplayExtensionInstanceCommands for C {}
 
-/// The device-level commands added by [`KHR_DISPLAY_SWAPCHAIN_EXTENSION`].
-pub trait KhrDisplaySwapchainExtensionDeviceCommands: DeviceV1_0 {
-    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCreateSharedSwapchainsKHR.html>
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceDisplayPropertiesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_display_properties_khr`].
     #[inline]
-    unsafe fn create_shared_swapchains_khr(
+    unsafe fn get_physical_device_display_properties_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        properties: &mut Vec<DisplayPropertiesKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceDisplayPropertiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut property_count = 0;
+
+        loop {
+            let __result = (self.commands().get_physical_device_display_properties_khr)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self.commands().get_physical_device_display_properties_khr)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+}
+
+impl<C: InstanceV1_0 + ?Sized> KhrDisplayExtensionInstanceCommands for C {}
+
+/// The device-level commands added by [`KHR_DISPLAY_SWAPCHAIN_EXTENSION`].
+pub trait KhrDisplaySwapchainExtensionDeviceCommands: DeviceV1_0 {
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCreateSharedSwapchainsKHR.html>
+    #[inline]
+    unsafe fn create_shared_swapchains_khr(
         &self,
         create_infos: &[impl Cast<Target = SwapchainCreateInfoKHR>],
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<Vec<SwapchainKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateSharedSwapchainsKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut swapchains = Vec::with_capacity(create_infos.len() as usize);
 
         let __result = (self.commands().create_shared_swapchains_khr)(
@@ -6970,6 +12070,9 @@ pub trait KhrDisplaySwapchainExtensionDeviceCommands: DeviceV1_0 {
             swapchains.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         swapchains.set_len(create_infos.len() as usize);
 
         if __result == Result::SUCCESS {
@@ -6978,6 +12081,50 @@ pub trait KhrDisplaySwapchainExtensionDeviceCommands: DeviceV1_0 {
             Err(__result.into())
         }
     }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCreateSharedSwapchainsKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::create_shared_swapchains_khr`].
+    #[inline]
+    unsafe fn create_shared_swapchains_khr_into(
+        &self,
+        create_infos: &[impl Cast<Target = SwapchainCreateInfoKHR>],
+        allocator: Option<&AllocationCallbacks>,
+        swapchains: &mut Vec<SwapchainKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateSharedSwapchainsKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        swapchains.clear();
+        swapchains.reserve(create_infos.len() as usize);
+
+        let __result = (self.commands().create_shared_swapchains_khr)(
+            self.handle(),
+            create_infos.len() as u32,
+            create_infos.as_ptr().cast(),
+            allocator.map_or(ptr::null(), |v| v),
+            swapchains.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            swapchains.set_len(create_infos.len() as usize);
+        }
+
+        if __result == Result::SUCCESS {
+            Ok(())
+        } else {
+            Err(__result.into())
+        }
+    }
 }
 
 impl<C: DeviceV1_0 + ?Sized> KhrDisplaySwapchainExtensionDeviceCommands for C {}
@@ -6996,6 +12143,15 @@ pub trait KhrDrawIndirectCountExtensionDeviceCommands: DeviceV1_0 {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawIndexedIndirectCountKHR",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer),
+            count_buffer = tracing::field::debug(count_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_indexed_indirect_count_khr)(
             command_buffer,
             buffer,
@@ -7019,6 +12175,15 @@ pub trait KhrDrawIndirectCountExtensionDeviceCommands: DeviceV1_0 {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawIndirectCountKHR",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer),
+            count_buffer = tracing::field::debug(count_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_indirect_count_khr)(
             command_buffer,
             buffer,
@@ -7042,12 +12207,26 @@ pub trait KhrDynamicRenderingExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         rendering_info: &RenderingInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginRenderingKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_begin_rendering_khr)(command_buffer, rendering_info);
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdEndRenderingKHR.html>
     #[inline]
     unsafe fn cmd_end_rendering_khr(&self, command_buffer: CommandBuffer) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndRenderingKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_end_rendering_khr)(command_buffer);
     }
 }
@@ -7063,6 +12242,13 @@ pub trait KhrDynamicRenderingLocalReadExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         location_info: &RenderingAttachmentLocationInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRenderingAttachmentLocationsKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_rendering_attachment_locations_khr)(
             command_buffer,
             location_info,
@@ -7076,6 +12262,13 @@ pub trait KhrDynamicRenderingLocalReadExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         input_attachment_index_info: &RenderingInputAttachmentIndexInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRenderingInputAttachmentIndicesKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .cmd_set_rendering_input_attachment_indices_khr)(
@@ -7097,6 +12290,13 @@ pub trait KhrExternalFenceCapabilitiesExtensionInstanceCommands: InstanceV1_0 {
         external_fence_info: &PhysicalDeviceExternalFenceInfo,
         external_fence_properties: &mut ExternalFenceProperties,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceExternalFencePropertiesKHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_external_fence_properties_khr)(
@@ -7114,11 +12314,22 @@ pub trait KhrExternalFenceFdExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetFenceFdKHR.html>
     #[inline]
     unsafe fn get_fence_fd_khr(&self, get_fd_info: &FenceGetFdInfoKHR) -> crate::VkResult<c_int> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetFenceFdKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut fd = MaybeUninit::<c_int>::uninit();
 
         let __result =
             (self.commands().get_fence_fd_khr)(self.handle(), get_fd_info, fd.as_mut_ptr());
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(fd.assume_init())
         } else {
@@ -7132,8 +12343,19 @@ pub trait KhrExternalFenceFdExtensionDeviceCommands: DeviceV1_0 {
         &self,
         import_fence_fd_info: &ImportFenceFdInfoKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkImportFenceFdKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().import_fence_fd_khr)(self.handle(), import_fence_fd_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -7152,6 +12374,14 @@ pub trait KhrExternalFenceWin32ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         get_win32_handle_info: &FenceGetWin32HandleInfoKHR,
     ) -> crate::VkResult<HANDLE> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetFenceWin32HandleKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut handle = MaybeUninit::<HANDLE>::uninit();
 
         let __result = (self.commands().get_fence_win32_handle_khr)(
@@ -7160,6 +12390,9 @@ pub trait KhrExternalFenceWin32ExtensionDeviceCommands: DeviceV1_0 {
             handle.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(handle.assume_init())
         } else {
@@ -7173,11 +12406,22 @@ pub trait KhrExternalFenceWin32ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         import_fence_win32_handle_info: &ImportFenceWin32HandleInfoKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkImportFenceWin32HandleKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().import_fence_win32_handle_khr)(
             self.handle(),
             import_fence_win32_handle_info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -7198,6 +12442,13 @@ pub trait KhrExternalMemoryCapabilitiesExtensionInstanceCommands: InstanceV1_0 {
         external_buffer_info: &PhysicalDeviceExternalBufferInfo,
         external_buffer_properties: &mut ExternalBufferProperties,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceExternalBufferPropertiesKHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_external_buffer_properties_khr)(
@@ -7215,11 +12466,22 @@ pub trait KhrExternalMemoryFdExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetMemoryFdKHR.html>
     #[inline]
     unsafe fn get_memory_fd_khr(&self, get_fd_info: &MemoryGetFdInfoKHR) -> crate::VkResult<c_int> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryFdKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut fd = MaybeUninit::<c_int>::uninit();
 
         let __result =
             (self.commands().get_memory_fd_khr)(self.handle(), get_fd_info, fd.as_mut_ptr());
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(fd.assume_init())
         } else {
@@ -7235,6 +12497,14 @@ pub trait KhrExternalMemoryFdExtensionDeviceCommands: DeviceV1_0 {
         fd: c_int,
         memory_fd_properties: &mut MemoryFdPropertiesKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryFdPropertiesKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_memory_fd_properties_khr)(
             self.handle(),
             handle_type,
@@ -7242,6 +12512,9 @@ pub trait KhrExternalMemoryFdExtensionDeviceCommands: DeviceV1_0 {
             memory_fd_properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -7260,6 +12533,14 @@ pub trait KhrExternalMemoryWin32ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         get_win32_handle_info: &MemoryGetWin32HandleInfoKHR,
     ) -> crate::VkResult<HANDLE> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryWin32HandleKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut handle = MaybeUninit::<HANDLE>::uninit();
 
         let __result = (self.commands().get_memory_win32_handle_khr)(
@@ -7268,6 +12549,9 @@ pub trait KhrExternalMemoryWin32ExtensionDeviceCommands: DeviceV1_0 {
             handle.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(handle.assume_init())
         } else {
@@ -7283,6 +12567,14 @@ pub trait KhrExternalMemoryWin32ExtensionDeviceCommands: DeviceV1_0 {
         handle: HANDLE,
         memory_win32_handle_properties: &mut MemoryWin32HandlePropertiesKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryWin32HandlePropertiesKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_memory_win32_handle_properties_khr)(
             self.handle(),
             handle_type,
@@ -7290,6 +12582,9 @@ pub trait KhrExternalMemoryWin32ExtensionDeviceCommands: DeviceV1_0 {
             memory_win32_handle_properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -7310,6 +12605,13 @@ pub trait KhrExternalSemaphoreCapabilitiesExtensionInstanceCommands: InstanceV1_
         external_semaphore_info: &PhysicalDeviceExternalSemaphoreInfo,
         external_semaphore_properties: &mut ExternalSemaphoreProperties,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceExternalSemaphorePropertiesKHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_external_semaphore_properties_khr)(
@@ -7330,11 +12632,22 @@ pub trait KhrExternalSemaphoreFdExtensionDeviceCommands: DeviceV1_0 {
         &self,
         get_fd_info: &SemaphoreGetFdInfoKHR,
     ) -> crate::VkResult<c_int> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSemaphoreFdKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut fd = MaybeUninit::<c_int>::uninit();
 
         let __result =
             (self.commands().get_semaphore_fd_khr)(self.handle(), get_fd_info, fd.as_mut_ptr());
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(fd.assume_init())
         } else {
@@ -7348,9 +12661,20 @@ pub trait KhrExternalSemaphoreFdExtensionDeviceCommands: DeviceV1_0 {
         &self,
         import_semaphore_fd_info: &ImportSemaphoreFdInfoKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkImportSemaphoreFdKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().import_semaphore_fd_khr)(self.handle(), import_semaphore_fd_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -7369,6 +12693,14 @@ pub trait KhrExternalSemaphoreWin32ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         get_win32_handle_info: &SemaphoreGetWin32HandleInfoKHR,
     ) -> crate::VkResult<HANDLE> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSemaphoreWin32HandleKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut handle = MaybeUninit::<HANDLE>::uninit();
 
         let __result = (self.commands().get_semaphore_win32_handle_khr)(
@@ -7377,6 +12709,9 @@ pub trait KhrExternalSemaphoreWin32ExtensionDeviceCommands: DeviceV1_0 {
             handle.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(handle.assume_init())
         } else {
@@ -7390,11 +12725,22 @@ pub trait KhrExternalSemaphoreWin32ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         import_semaphore_win32_handle_info: &ImportSemaphoreWin32HandleInfoKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkImportSemaphoreWin32HandleKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().import_semaphore_win32_handle_khr)(
             self.handle(),
             import_semaphore_win32_handle_info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -7415,6 +12761,13 @@ pub trait KhrFragmentShadingRateExtensionDeviceCommands: DeviceV1_0 {
         fragment_size: &Extent2D,
         combiner_ops: [FragmentShadingRateCombinerOpKHR; 2],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetFragmentShadingRateKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_fragment_shading_rate_khr)(
             command_buffer,
             fragment_size,
@@ -7433,33 +12786,127 @@ pub trait KhrFragmentShadingRateExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<PhysicalDeviceFragmentShadingRateKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceFragmentShadingRatesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut fragment_shading_rate_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_fragment_shading_rates_khr)(
-            physical_device,
-            &mut fragment_shading_rate_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_fragment_shading_rates_khr)(
+                physical_device,
+                &mut fragment_shading_rate_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut fragment_shading_rates =
+                Vec::with_capacity(fragment_shading_rate_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_fragment_shading_rates_khr)(
+                physical_device,
+                &mut fragment_shading_rate_count,
+                fragment_shading_rates.as_mut_ptr(),
+            );
 
-        let mut fragment_shading_rates = Vec::with_capacity(fragment_shading_rate_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_fragment_shading_rates_khr)(
-            physical_device,
-            &mut fragment_shading_rate_count,
-            fragment_shading_rates.as_mut_ptr(),
-        );
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
 
-        debug_assert!(fragment_shading_rates.capacity() >= fragment_shading_rate_count as usize);
-        fragment_shading_rates.set_len(fragment_shading_rate_count as usize);
+            debug_assert!(
+                fragment_shading_rates.capacity() >= fragment_shading_rate_count as usize
+            );
+            fragment_shading_rates.set_len(fragment_shading_rate_count as usize);
 
-        if __result == Result::SUCCESS {
-            Ok(fragment_shading_rates)
-        } else {
-            Err(__result.into())
+            break if __result == Result::SUCCESS {
+                Ok(fragment_shading_rates)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceFragmentShadingRatesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_fragment_shading_rates_khr`].
+    #[inline]
+    unsafe fn get_physical_device_fragment_shading_rates_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        fragment_shading_rates: &mut Vec<PhysicalDeviceFragmentShadingRateKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceFragmentShadingRatesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut fragment_shading_rate_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_fragment_shading_rates_khr)(
+                physical_device,
+                &mut fragment_shading_rate_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            fragment_shading_rates.clear();
+            fragment_shading_rates.reserve(fragment_shading_rate_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_fragment_shading_rates_khr)(
+                physical_device,
+                &mut fragment_shading_rate_count,
+                fragment_shading_rates.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(
+                    fragment_shading_rates.capacity() >= fragment_shading_rate_count as usize
+                );
+                fragment_shading_rates.set_len(fragment_shading_rate_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -7475,31 +12922,125 @@ pub trait KhrGetDisplayProperties2ExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         display: DisplayKHR,
     ) -> crate::VkResult<Vec<DisplayModeProperties2KHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDisplayModeProperties2KHR",
+            physical_device = tracing::field::debug(physical_device),
+            display = tracing::field::Empty,
+            result = tracing::field::Empty
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        __span.record("display", tracing::field::debug(display));
+
         let mut property_count = 0;
 
-        (self.commands().get_display_mode_properties2_khr)(
-            physical_device,
-            display,
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_display_mode_properties2_khr)(
+                physical_device,
+                display,
+                &mut property_count,
+                ptr::null_mut(),
+            );
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_display_mode_properties2_khr)(
-            physical_device,
-            display,
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+            let mut properties = Vec::with_capacity(property_count as usize);
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+            let __result = (self.commands().get_display_mode_properties2_khr)(
+                physical_device,
+                display,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetDisplayModeProperties2KHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_display_mode_properties2_khr`].
+    #[inline]
+    unsafe fn get_display_mode_properties2_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        display: DisplayKHR,
+        properties: &mut Vec<DisplayModeProperties2KHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDisplayModeProperties2KHR",
+            physical_device = tracing::field::debug(physical_device),
+            display = tracing::field::Empty,
+            result = tracing::field::Empty
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        __span.record("display", tracing::field::debug(display));
+
+        let mut property_count = 0;
+
+        loop {
+            let __result = (self.commands().get_display_mode_properties2_khr)(
+                physical_device,
+                display,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self.commands().get_display_mode_properties2_khr)(
+                physical_device,
+                display,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -7511,12 +13052,23 @@ pub trait KhrGetDisplayProperties2ExtensionInstanceCommands: InstanceV1_0 {
         display_plane_info: &DisplayPlaneInfo2KHR,
         capabilities: &mut DisplayPlaneCapabilities2KHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDisplayPlaneCapabilities2KHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_display_plane_capabilities2_khr)(
             physical_device,
             display_plane_info,
             capabilities,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -7530,33 +13082,122 @@ pub trait KhrGetDisplayProperties2ExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<DisplayPlaneProperties2KHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut property_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_display_plane_properties2_khr)(
-            physical_device,
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_display_plane_properties2_khr)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_display_plane_properties2_khr)(
-            physical_device,
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+            let mut properties = Vec::with_capacity(property_count as usize);
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+            let __result = (self
+                .commands()
+                .get_physical_device_display_plane_properties2_khr)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceDisplayPlaneProperties2KHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_display_plane_properties2_khr`].
+    #[inline]
+    unsafe fn get_physical_device_display_plane_properties2_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        properties: &mut Vec<DisplayPlaneProperties2KHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut property_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_display_plane_properties2_khr)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_display_plane_properties2_khr)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -7566,29 +13207,114 @@ pub trait KhrGetDisplayProperties2ExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<DisplayProperties2KHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceDisplayProperties2KHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut property_count = 0;
 
-        (self.commands().get_physical_device_display_properties2_khr)(
-            physical_device,
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_physical_device_display_properties2_khr)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_physical_device_display_properties2_khr)(
-            physical_device,
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+            let mut properties = Vec::with_capacity(property_count as usize);
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+            let __result = (self.commands().get_physical_device_display_properties2_khr)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceDisplayProperties2KHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_display_properties2_khr`].
+    #[inline]
+    unsafe fn get_physical_device_display_properties2_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        properties: &mut Vec<DisplayProperties2KHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceDisplayProperties2KHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut property_count = 0;
+
+        loop {
+            let __result = (self.commands().get_physical_device_display_properties2_khr)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self.commands().get_physical_device_display_properties2_khr)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -7604,6 +13330,13 @@ pub trait KhrGetMemoryRequirements2ExtensionDeviceCommands: DeviceV1_0 {
         info: &BufferMemoryRequirementsInfo2,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetBufferMemoryRequirements2KHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_buffer_memory_requirements2_khr)(
             self.handle(),
             info,
@@ -7618,6 +13351,13 @@ pub trait KhrGetMemoryRequirements2ExtensionDeviceCommands: DeviceV1_0 {
         info: &ImageMemoryRequirementsInfo2,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageMemoryRequirements2KHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_image_memory_requirements2_khr)(
             self.handle(),
             info,
@@ -7631,6 +13371,13 @@ pub trait KhrGetMemoryRequirements2ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         info: &ImageSparseMemoryRequirementsInfo2,
     ) -> Vec<SparseImageMemoryRequirements2> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageSparseMemoryRequirements2KHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut sparse_memory_requirement_count = 0;
 
         (self.commands().get_image_sparse_memory_requirements2_khr)(
@@ -7657,31 +13404,87 @@ pub trait KhrGetMemoryRequirements2ExtensionDeviceCommands: DeviceV1_0 {
 
         sparse_memory_requirements
     }
-}
-
-impl<C: DeviceV1_0 + ?Sized> KhrGetMemoryRequirements2ExtensionDeviceCommands for C {}
 
-/// The instance-level commands added by [`KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION`].
-pub trait KhrGetPhysicalDeviceProperties2ExtensionInstanceCommands: InstanceV1_0 {
-    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceFeatures2KHR.html>
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetImageSparseMemoryRequirements2KHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_image_sparse_memory_requirements2_khr`].
     #[inline]
-    unsafe fn get_physical_device_features2_khr(
+    unsafe fn get_image_sparse_memory_requirements2_khr_into(
         &self,
-        physical_device: PhysicalDevice,
-        features: &mut PhysicalDeviceFeatures2,
+        info: &ImageSparseMemoryRequirementsInfo2,
+        sparse_memory_requirements: &mut Vec<SparseImageMemoryRequirements2>,
     ) {
-        let __result =
-            (self.commands().get_physical_device_features2_khr)(physical_device, features);
-    }
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageSparseMemoryRequirements2KHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
 
-    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceFormatProperties2KHR.html>
-    #[inline]
+        let mut sparse_memory_requirement_count = 0;
+
+        (self.commands().get_image_sparse_memory_requirements2_khr)(
+            self.handle(),
+            info,
+            &mut sparse_memory_requirement_count,
+            ptr::null_mut(),
+        );
+
+        sparse_memory_requirements.clear();
+        sparse_memory_requirements.reserve(sparse_memory_requirement_count as usize);
+
+        let __result = (self.commands().get_image_sparse_memory_requirements2_khr)(
+            self.handle(),
+            info,
+            &mut sparse_memory_requirement_count,
+            sparse_memory_requirements.as_mut_ptr(),
+        );
+
+        debug_assert!(
+            sparse_memory_requirements.capacity() >= sparse_memory_requirement_count as usize
+        );
+        sparse_memory_requirements.set_len(sparse_memory_requirement_count as usize);
+    }
+}
+
+impl<C: DeviceV1_0 + ?Sized> KhrGetMemoryRequirements2ExtensionDeviceCommands for C {}
+
+/// The instance-level commands added by [`KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION`].
+pub trait KhrGetPhysicalDeviceProperties2ExtensionInstanceCommands: InstanceV1_0 {
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceFeatures2KHR.html>
+    #[inline]
+    unsafe fn get_physical_device_features2_khr(
+        &self,
+        physical_device: PhysicalDevice,
+        features: &mut PhysicalDeviceFeatures2,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceFeatures2KHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
+        let __result =
+            (self.commands().get_physical_device_features2_khr)(physical_device, features);
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceFormatProperties2KHR.html>
+    #[inline]
     unsafe fn get_physical_device_format_properties2_khr(
         &self,
         physical_device: PhysicalDevice,
         format: Format,
         format_properties: &mut FormatProperties2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceFormatProperties2KHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self.commands().get_physical_device_format_properties2_khr)(
             physical_device,
             format,
@@ -7697,6 +13500,14 @@ pub trait KhrGetPhysicalDeviceProperties2ExtensionInstanceCommands: InstanceV1_0
         image_format_info: &PhysicalDeviceImageFormatInfo2,
         image_format_properties: &mut ImageFormatProperties2,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceImageFormatProperties2KHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_image_format_properties2_khr)(
@@ -7705,6 +13516,9 @@ pub trait KhrGetPhysicalDeviceProperties2ExtensionInstanceCommands: InstanceV1_0
             image_format_properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -7719,6 +13533,13 @@ pub trait KhrGetPhysicalDeviceProperties2ExtensionInstanceCommands: InstanceV1_0
         physical_device: PhysicalDevice,
         memory_properties: &mut PhysicalDeviceMemoryProperties2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceMemoryProperties2KHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self.commands().get_physical_device_memory_properties2_khr)(
             physical_device,
             memory_properties,
@@ -7732,6 +13553,13 @@ pub trait KhrGetPhysicalDeviceProperties2ExtensionInstanceCommands: InstanceV1_0
         physical_device: PhysicalDevice,
         properties: &mut PhysicalDeviceProperties2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceProperties2KHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result =
             (self.commands().get_physical_device_properties2_khr)(physical_device, properties);
     }
@@ -7742,6 +13570,13 @@ pub trait KhrGetPhysicalDeviceProperties2ExtensionInstanceCommands: InstanceV1_0
         &self,
         physical_device: PhysicalDevice,
     ) -> Vec<QueueFamilyProperties2> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceQueueFamilyProperties2KHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut queue_family_property_count = 0;
 
         (self
@@ -7768,6 +13603,48 @@ pub trait KhrGetPhysicalDeviceProperties2ExtensionInstanceCommands: InstanceV1_0
         queue_family_properties
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceQueueFamilyProperties2KHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_queue_family_properties2_khr`].
+    #[inline]
+    unsafe fn get_physical_device_queue_family_properties2_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        queue_family_properties: &mut Vec<QueueFamilyProperties2>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceQueueFamilyProperties2KHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
+        let mut queue_family_property_count = 0;
+
+        (self
+            .commands()
+            .get_physical_device_queue_family_properties2_khr)(
+            physical_device,
+            &mut queue_family_property_count,
+            ptr::null_mut(),
+        );
+
+        queue_family_properties.clear();
+        queue_family_properties.reserve(queue_family_property_count as usize);
+
+        let __result = (self
+            .commands()
+            .get_physical_device_queue_family_properties2_khr)(
+            physical_device,
+            &mut queue_family_property_count,
+            queue_family_properties.as_mut_ptr(),
+        );
+
+        debug_assert!(queue_family_properties.capacity() >= queue_family_property_count as usize);
+        queue_family_properties.set_len(queue_family_property_count as usize);
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceSparseImageFormatProperties2KHR.html>
     #[inline]
     unsafe fn get_physical_device_sparse_image_format_properties2_khr(
@@ -7775,6 +13652,13 @@ pub trait KhrGetPhysicalDeviceProperties2ExtensionInstanceCommands: InstanceV1_0
         physical_device: PhysicalDevice,
         format_info: &PhysicalDeviceSparseImageFormatInfo2,
     ) -> Vec<SparseImageFormatProperties2> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSparseImageFormatProperties2KHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut property_count = 0;
 
         (self
@@ -7802,6 +13686,51 @@ pub trait KhrGetPhysicalDeviceProperties2ExtensionInstanceCommands: InstanceV1_0
 
         properties
     }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceSparseImageFormatProperties2KHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_sparse_image_format_properties2_khr`].
+    #[inline]
+    unsafe fn get_physical_device_sparse_image_format_properties2_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        format_info: &PhysicalDeviceSparseImageFormatInfo2,
+        properties: &mut Vec<SparseImageFormatProperties2>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSparseImageFormatProperties2KHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
+        let mut property_count = 0;
+
+        (self
+            .commands()
+            .get_physical_device_sparse_image_format_properties2_khr)(
+            physical_device,
+            format_info,
+            &mut property_count,
+            ptr::null_mut(),
+        );
+
+        properties.clear();
+        properties.reserve(property_count as usize);
+
+        let __result = (self
+            .commands()
+            .get_physical_device_sparse_image_format_properties2_khr)(
+            physical_device,
+            format_info,
+            &mut property_count,
+            properties.as_mut_ptr(),
+        );
+
+        debug_assert!(properties.capacity() >= property_count as usize);
+        properties.set_len(property_count as usize);
+    }
 }
 
 impl<C: InstanceV1_0 + ?Sized> KhrGetPhysicalDeviceProperties2ExtensionInstanceCommands for C {}
@@ -7816,6 +13745,14 @@ pub trait KhrGetSurfaceCapabilities2ExtensionInstanceCommands: InstanceV1_0 {
         surface_info: &PhysicalDeviceSurfaceInfo2KHR,
         surface_capabilities: &mut SurfaceCapabilities2KHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSurfaceCapabilities2KHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_surface_capabilities2_khr)(
@@ -7824,6 +13761,9 @@ pub trait KhrGetSurfaceCapabilities2ExtensionInstanceCommands: InstanceV1_0 {
             surface_capabilities,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -7838,31 +13778,119 @@ pub trait KhrGetSurfaceCapabilities2ExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         surface_info: &PhysicalDeviceSurfaceInfo2KHR,
     ) -> crate::VkResult<Vec<SurfaceFormat2KHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSurfaceFormats2KHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface_format_count = 0;
 
-        (self.commands().get_physical_device_surface_formats2_khr)(
-            physical_device,
-            surface_info,
-            &mut surface_format_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_physical_device_surface_formats2_khr)(
+                physical_device,
+                surface_info,
+                &mut surface_format_count,
+                ptr::null_mut(),
+            );
 
-        let mut surface_formats = Vec::with_capacity(surface_format_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_physical_device_surface_formats2_khr)(
-            physical_device,
-            surface_info,
-            &mut surface_format_count,
-            surface_formats.as_mut_ptr(),
-        );
+            let mut surface_formats = Vec::with_capacity(surface_format_count as usize);
 
-        debug_assert!(surface_formats.capacity() >= surface_format_count as usize);
-        surface_formats.set_len(surface_format_count as usize);
+            let __result = (self.commands().get_physical_device_surface_formats2_khr)(
+                physical_device,
+                surface_info,
+                &mut surface_format_count,
+                surface_formats.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(surface_formats)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(surface_formats.capacity() >= surface_format_count as usize);
+            surface_formats.set_len(surface_format_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(surface_formats)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceSurfaceFormats2KHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_surface_formats2_khr`].
+    #[inline]
+    unsafe fn get_physical_device_surface_formats2_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        surface_info: &PhysicalDeviceSurfaceInfo2KHR,
+        surface_formats: &mut Vec<SurfaceFormat2KHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSurfaceFormats2KHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut surface_format_count = 0;
+
+        loop {
+            let __result = (self.commands().get_physical_device_surface_formats2_khr)(
+                physical_device,
+                surface_info,
+                &mut surface_format_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            surface_formats.clear();
+            surface_formats.reserve(surface_format_count as usize);
+
+            let __result = (self.commands().get_physical_device_surface_formats2_khr)(
+                physical_device,
+                surface_info,
+                &mut surface_format_count,
+                surface_formats.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(surface_formats.capacity() >= surface_format_count as usize);
+                surface_formats.set_len(surface_format_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -7879,6 +13907,13 @@ pub trait KhrLineRasterizationExtensionDeviceCommands: DeviceV1_0 {
         line_stipple_factor: u32,
         line_stipple_pattern: u16,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetLineStippleKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_line_stipple_khr)(
             command_buffer,
             line_stipple_factor,
@@ -7894,6 +13929,14 @@ pub trait KhrMaintenance1ExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkTrimCommandPoolKHR.html>
     #[inline]
     unsafe fn trim_command_pool_khr(&self, command_pool: CommandPool, flags: CommandPoolTrimFlags) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkTrimCommandPoolKHR",
+            device = tracing::field::debug(self.handle()),
+            command_pool = tracing::field::debug(command_pool)
+        )
+        .entered();
+
         let __result = (self.commands().trim_command_pool_khr)(self.handle(), command_pool, flags);
     }
 }
@@ -7909,6 +13952,13 @@ pub trait KhrMaintenance10ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         rendering_end_info: Option<&RenderingEndInfoKHR>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndRendering2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_end_rendering2_khr)(
             command_buffer,
             rendering_end_info.map_or(ptr::null(), |v| v),
@@ -7927,6 +13977,13 @@ pub trait KhrMaintenance3ExtensionDeviceCommands: DeviceV1_0 {
         create_info: &DescriptorSetLayoutCreateInfo,
         support: &mut DescriptorSetLayoutSupport,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDescriptorSetLayoutSupportKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_descriptor_set_layout_support_khr)(
             self.handle(),
             create_info,
@@ -7946,6 +14003,13 @@ pub trait KhrMaintenance4ExtensionDeviceCommands: DeviceV1_0 {
         info: &DeviceBufferMemoryRequirements,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceBufferMemoryRequirementsKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_device_buffer_memory_requirements_khr)(
             self.handle(),
             info,
@@ -7960,6 +14024,13 @@ pub trait KhrMaintenance4ExtensionDeviceCommands: DeviceV1_0 {
         info: &DeviceImageMemoryRequirements,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceImageMemoryRequirementsKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_device_image_memory_requirements_khr)(
             self.handle(),
             info,
@@ -7973,6 +14044,13 @@ pub trait KhrMaintenance4ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         info: &DeviceImageMemoryRequirements,
     ) -> Vec<SparseImageMemoryRequirements2> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceImageSparseMemoryRequirementsKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut sparse_memory_requirement_count = 0;
 
         (self
@@ -8003,6 +14081,52 @@ pub trait KhrMaintenance4ExtensionDeviceCommands: DeviceV1_0 {
 
         sparse_memory_requirements
     }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetDeviceImageSparseMemoryRequirementsKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_device_image_sparse_memory_requirements_khr`].
+    #[inline]
+    unsafe fn get_device_image_sparse_memory_requirements_khr_into(
+        &self,
+        info: &DeviceImageMemoryRequirements,
+        sparse_memory_requirements: &mut Vec<SparseImageMemoryRequirements2>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceImageSparseMemoryRequirementsKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
+        let mut sparse_memory_requirement_count = 0;
+
+        (self
+            .commands()
+            .get_device_image_sparse_memory_requirements_khr)(
+            self.handle(),
+            info,
+            &mut sparse_memory_requirement_count,
+            ptr::null_mut(),
+        );
+
+        sparse_memory_requirements.clear();
+        sparse_memory_requirements.reserve(sparse_memory_requirement_count as usize);
+
+        let __result = (self
+            .commands()
+            .get_device_image_sparse_memory_requirements_khr)(
+            self.handle(),
+            info,
+            &mut sparse_memory_requirement_count,
+            sparse_memory_requirements.as_mut_ptr(),
+        );
+
+        debug_assert!(
+            sparse_memory_requirements.capacity() >= sparse_memory_requirement_count as usize
+        );
+        sparse_memory_requirements.set_len(sparse_memory_requirement_count as usize);
+    }
 }
 
 impl<C: DeviceV1_0 + ?Sized> KhrMaintenance4ExtensionDeviceCommands for C {}
@@ -8019,6 +14143,14 @@ pub trait KhrMaintenance5ExtensionDeviceCommands: DeviceV1_0 {
         size: DeviceSize,
         index_type: IndexType,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindIndexBuffer2KHR",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_index_buffer2_khr)(
             command_buffer,
             buffer,
@@ -8035,6 +14167,13 @@ pub trait KhrMaintenance5ExtensionDeviceCommands: DeviceV1_0 {
         info: &DeviceImageSubresourceInfo,
         layout: &mut SubresourceLayout2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceImageSubresourceLayoutKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result =
             (self.commands().get_device_image_subresource_layout_khr)(self.handle(), info, layout);
     }
@@ -8047,6 +14186,14 @@ pub trait KhrMaintenance5ExtensionDeviceCommands: DeviceV1_0 {
         subresource: &ImageSubresource2,
         layout: &mut SubresourceLayout2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageSubresourceLayout2KHR",
+            device = tracing::field::debug(self.handle()),
+            image = tracing::field::debug(image)
+        )
+        .entered();
+
         let __result = (self.commands().get_image_subresource_layout2_khr)(
             self.handle(),
             image,
@@ -8061,6 +14208,13 @@ pub trait KhrMaintenance5ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         rendering_area_info: &RenderingAreaInfo,
     ) -> Extent2D {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetRenderingAreaGranularityKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut granularity = MaybeUninit::<Extent2D>::uninit();
 
         let __result = (self.commands().get_rendering_area_granularity_khr)(
@@ -8084,6 +14238,13 @@ pub trait KhrMaintenance6ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         bind_descriptor_buffer_embedded_samplers_info: &BindDescriptorBufferEmbeddedSamplersInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindDescriptorBufferEmbeddedSamplers2EXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .cmd_bind_descriptor_buffer_embedded_samplers2_ext)(
@@ -8099,6 +14260,13 @@ pub trait KhrMaintenance6ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         bind_descriptor_sets_info: &BindDescriptorSetsInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindDescriptorSets2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_descriptor_sets2_khr)(
             command_buffer,
             bind_descriptor_sets_info,
@@ -8112,6 +14280,13 @@ pub trait KhrMaintenance6ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         push_constants_info: &PushConstantsInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushConstants2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_push_constants2_khr)(command_buffer, push_constants_info);
     }
@@ -8123,6 +14298,13 @@ pub trait KhrMaintenance6ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         push_descriptor_set_info: &PushDescriptorSetInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushDescriptorSet2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_push_descriptor_set2_khr)(
             command_buffer,
             push_descriptor_set_info,
@@ -8136,6 +14318,13 @@ pub trait KhrMaintenance6ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         push_descriptor_set_with_template_info: &PushDescriptorSetWithTemplateInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushDescriptorSetWithTemplate2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_push_descriptor_set_with_template2_khr)(
             command_buffer,
             push_descriptor_set_with_template_info,
@@ -8149,6 +14338,13 @@ pub trait KhrMaintenance6ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         set_descriptor_buffer_offsets_info: &SetDescriptorBufferOffsetsInfoEXT,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDescriptorBufferOffsets2EXT",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_descriptor_buffer_offsets2_ext)(
             command_buffer,
             set_descriptor_buffer_offsets_info,
@@ -8166,11 +14362,22 @@ pub trait KhrMapMemory2ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         memory_map_info: &MemoryMapInfo,
     ) -> crate::VkResult<*mut c_void> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkMapMemory2KHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut data = MaybeUninit::<*mut c_void>::uninit();
 
         let __result =
             (self.commands().map_memory2_khr)(self.handle(), memory_map_info, data.as_mut_ptr());
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(data.assume_init())
         } else {
@@ -8181,8 +14388,19 @@ pub trait KhrMapMemory2ExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkUnmapMemory2KHR.html>
     #[inline]
     unsafe fn unmap_memory2_khr(&self, memory_unmap_info: &MemoryUnmapInfo) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkUnmapMemory2KHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().unmap_memory2_khr)(self.handle(), memory_unmap_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -8202,6 +14420,13 @@ pub trait KhrObjectRefreshExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         refresh_objects: &RefreshObjectListKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdRefreshObjectsKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_refresh_objects_khr)(command_buffer, refresh_objects);
     }
 }
@@ -8216,36 +14441,127 @@ pub trait KhrObjectRefreshExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<ObjectType>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceRefreshableObjectTypesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut refreshable_object_type_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_refreshable_object_types_khr)(
-            physical_device,
-            &mut refreshable_object_type_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_refreshable_object_types_khr)(
+                physical_device,
+                &mut refreshable_object_type_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut refreshable_object_types =
+                Vec::with_capacity(refreshable_object_type_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_refreshable_object_types_khr)(
+                physical_device,
+                &mut refreshable_object_type_count,
+                refreshable_object_types.as_mut_ptr(),
+            );
 
-        let mut refreshable_object_types =
-            Vec::with_capacity(refreshable_object_type_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_refreshable_object_types_khr)(
-            physical_device,
-            &mut refreshable_object_type_count,
-            refreshable_object_types.as_mut_ptr(),
-        );
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
 
-        debug_assert!(
-            refreshable_object_types.capacity() >= refreshable_object_type_count as usize
-        );
-        refreshable_object_types.set_len(refreshable_object_type_count as usize);
+            debug_assert!(
+                refreshable_object_types.capacity() >= refreshable_object_type_count as usize
+            );
+            refreshable_object_types.set_len(refreshable_object_type_count as usize);
 
-        if __result == Result::SUCCESS {
-            Ok(refreshable_object_types)
-        } else {
-            Err(__result.into())
+            break if __result == Result::SUCCESS {
+                Ok(refreshable_object_types)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceRefreshableObjectTypesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_refreshable_object_types_khr`].
+    #[inline]
+    unsafe fn get_physical_device_refreshable_object_types_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        refreshable_object_types: &mut Vec<ObjectType>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceRefreshableObjectTypesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut refreshable_object_type_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_refreshable_object_types_khr)(
+                physical_device,
+                &mut refreshable_object_type_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            refreshable_object_types.clear();
+            refreshable_object_types.reserve(refreshable_object_type_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_refreshable_object_types_khr)(
+                physical_device,
+                &mut refreshable_object_type_count,
+                refreshable_object_types.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(
+                    refreshable_object_types.capacity() >= refreshable_object_type_count as usize
+                );
+                refreshable_object_types.set_len(refreshable_object_type_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -8260,8 +14576,19 @@ pub trait KhrPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
         &self,
         info: &AcquireProfilingLockInfoKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAcquireProfilingLockKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().acquire_profiling_lock_khr)(self.handle(), info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -8272,6 +14599,13 @@ pub trait KhrPerformanceQueryExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkReleaseProfilingLockKHR.html>
     #[inline]
     unsafe fn release_profiling_lock_khr(&self) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkReleaseProfilingLockKHR",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().release_profiling_lock_khr)(self.handle());
     }
 }
@@ -8290,40 +14624,139 @@ pub trait KhrPerformanceQueryExtensionInstanceCommands: InstanceV1_0 {
         Vec<PerformanceCounterKHR>,
         Vec<PerformanceCounterDescriptionKHR>,
     )> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut counter_count = 0;
 
-        (self
-            .commands()
-            .enumerate_physical_device_queue_family_performance_query_counters_khr)(
-            physical_device,
-            queue_family_index,
-            &mut counter_count,
-            ptr::null_mut(),
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .enumerate_physical_device_queue_family_performance_query_counters_khr)(
+                physical_device,
+                queue_family_index,
+                &mut counter_count,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut counters = Vec::with_capacity(counter_count as usize);
+            let mut counter_descriptions = Vec::with_capacity(counter_count as usize);
+
+            let __result = (self
+                .commands()
+                .enumerate_physical_device_queue_family_performance_query_counters_khr)(
+                physical_device,
+                queue_family_index,
+                &mut counter_count,
+                counters.as_mut_ptr(),
+                counter_descriptions.as_mut_ptr(),
+            );
 
-        let mut counters = Vec::with_capacity(counter_count as usize);
-        let mut counter_descriptions = Vec::with_capacity(counter_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        let __result = (self
-            .commands()
-            .enumerate_physical_device_queue_family_performance_query_counters_khr)(
-            physical_device,
-            queue_family_index,
-            &mut counter_count,
-            counters.as_mut_ptr(),
-            counter_descriptions.as_mut_ptr(),
-        );
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
 
-        debug_assert!(counters.capacity() >= counter_count as usize);
-        counters.set_len(counter_count as usize);
-        debug_assert!(counter_descriptions.capacity() >= counter_count as usize);
-        counter_descriptions.set_len(counter_count as usize);
+            debug_assert!(counters.capacity() >= counter_count as usize);
+            counters.set_len(counter_count as usize);
+            debug_assert!(counter_descriptions.capacity() >= counter_count as usize);
+            counter_descriptions.set_len(counter_count as usize);
 
-        if __result == Result::SUCCESS {
-            Ok((counters, counter_descriptions))
-        } else {
-            Err(__result.into())
+            break if __result == Result::SUCCESS {
+                Ok((counters, counter_descriptions))
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::enumerate_physical_device_queue_family_performance_query_counters_khr`].
+    #[inline]
+    unsafe fn enumerate_physical_device_queue_family_performance_query_counters_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        queue_family_index: u32,
+        counters: &mut Vec<PerformanceCounterKHR>,
+        counter_descriptions: &mut Vec<PerformanceCounterDescriptionKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut counter_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .enumerate_physical_device_queue_family_performance_query_counters_khr)(
+                physical_device,
+                queue_family_index,
+                &mut counter_count,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            counters.clear();
+            counters.reserve(counter_count as usize);
+            counter_descriptions.clear();
+            counter_descriptions.reserve(counter_count as usize);
+
+            let __result = (self
+                .commands()
+                .enumerate_physical_device_queue_family_performance_query_counters_khr)(
+                physical_device,
+                queue_family_index,
+                &mut counter_count,
+                counters.as_mut_ptr(),
+                counter_descriptions.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(counters.capacity() >= counter_count as usize);
+                counters.set_len(counter_count as usize);
+                debug_assert!(counter_descriptions.capacity() >= counter_count as usize);
+                counter_descriptions.set_len(counter_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -8334,6 +14767,13 @@ pub trait KhrPerformanceQueryExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         performance_query_create_info: &QueryPoolPerformanceCreateInfoKHR,
     ) -> u32 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut num_passes = MaybeUninit::<u32>::uninit();
 
         let __result = (self
@@ -8360,6 +14800,14 @@ pub trait KhrPipelineBinaryExtensionDeviceCommands: DeviceV1_0 {
         allocator: Option<&AllocationCallbacks>,
         binaries: &mut PipelineBinaryHandlesInfoKHR,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreatePipelineBinariesKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().create_pipeline_binaries_khr)(
             self.handle(),
             create_info,
@@ -8367,6 +14815,9 @@ pub trait KhrPipelineBinaryExtensionDeviceCommands: DeviceV1_0 {
             binaries,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -8381,6 +14832,14 @@ pub trait KhrPipelineBinaryExtensionDeviceCommands: DeviceV1_0 {
         pipeline_binary: PipelineBinaryKHR,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyPipelineBinaryKHR",
+            device = tracing::field::debug(self.handle()),
+            pipeline_binary = tracing::field::debug(pipeline_binary)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_pipeline_binary_khr)(
             self.handle(),
             pipeline_binary,
@@ -8395,6 +14854,14 @@ pub trait KhrPipelineBinaryExtensionDeviceCommands: DeviceV1_0 {
         info: &PipelineBinaryDataInfoKHR,
         pipeline_binary_key: &mut PipelineBinaryKeyKHR,
     ) -> crate::VkResult<Vec<u8>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineBinaryDataKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut pipeline_binary_data_size = 0;
 
         (self.commands().get_pipeline_binary_data_khr)(
@@ -8415,6 +14882,9 @@ pub trait KhrPipelineBinaryExtensionDeviceCommands: DeviceV1_0 {
             pipeline_binary_data.as_mut_ptr() as *mut c_void,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         debug_assert!(pipeline_binary_data.capacity() >= pipeline_binary_data_size as usize);
         pipeline_binary_data.set_len(pipeline_binary_data_size as usize);
 
@@ -8425,6 +14895,61 @@ pub trait KhrPipelineBinaryExtensionDeviceCommands: DeviceV1_0 {
         }
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPipelineBinaryDataKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_pipeline_binary_data_khr`].
+    #[inline]
+    unsafe fn get_pipeline_binary_data_khr_into(
+        &self,
+        info: &PipelineBinaryDataInfoKHR,
+        pipeline_binary_key: &mut PipelineBinaryKeyKHR,
+        pipeline_binary_data: &mut Vec<u8>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineBinaryDataKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut pipeline_binary_data_size = 0;
+
+        (self.commands().get_pipeline_binary_data_khr)(
+            self.handle(),
+            info,
+            ptr::null_mut(),
+            &mut pipeline_binary_data_size,
+            ptr::null_mut(),
+        );
+
+        pipeline_binary_data.clear();
+        pipeline_binary_data.reserve(pipeline_binary_data_size as usize);
+
+        let __result = (self.commands().get_pipeline_binary_data_khr)(
+            self.handle(),
+            info,
+            pipeline_binary_key,
+            &mut pipeline_binary_data_size,
+            pipeline_binary_data.as_mut_ptr() as *mut c_void,
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            debug_assert!(pipeline_binary_data.capacity() >= pipeline_binary_data_size as usize);
+            pipeline_binary_data.set_len(pipeline_binary_data_size as usize);
+        }
+
+        if __result == Result::SUCCESS {
+            Ok(())
+        } else {
+            Err(__result.into())
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPipelineKeyKHR.html>
     #[inline]
     unsafe fn get_pipeline_key_khr(
@@ -8432,12 +14957,23 @@ pub trait KhrPipelineBinaryExtensionDeviceCommands: DeviceV1_0 {
         pipeline_create_info: Option<&PipelineCreateInfoKHR>,
         pipeline_key: &mut PipelineBinaryKeyKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineKeyKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_pipeline_key_khr)(
             self.handle(),
             pipeline_create_info.map_or(ptr::null(), |v| v),
             pipeline_key,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -8452,12 +14988,23 @@ pub trait KhrPipelineBinaryExtensionDeviceCommands: DeviceV1_0 {
         info: &ReleaseCapturedPipelineDataInfoKHR,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkReleaseCapturedPipelineDataKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().release_captured_pipeline_data_khr)(
             self.handle(),
             info,
             allocator.map_or(ptr::null(), |v| v),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -8476,38 +15023,131 @@ pub trait KhrPipelineExecutablePropertiesExtensionDeviceCommands: DeviceV1_0 {
         &self,
         executable_info: &PipelineExecutableInfoKHR,
     ) -> crate::VkResult<Vec<PipelineExecutableInternalRepresentationKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineExecutableInternalRepresentationsKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut internal_representation_count = 0;
 
-        (self
-            .commands()
-            .get_pipeline_executable_internal_representations_khr)(
-            self.handle(),
-            executable_info,
-            &mut internal_representation_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_pipeline_executable_internal_representations_khr)(
+                self.handle(),
+                executable_info,
+                &mut internal_representation_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut internal_representations =
+                Vec::with_capacity(internal_representation_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_pipeline_executable_internal_representations_khr)(
+                self.handle(),
+                executable_info,
+                &mut internal_representation_count,
+                internal_representations.as_mut_ptr(),
+            );
 
-        let mut internal_representations =
-            Vec::with_capacity(internal_representation_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        let __result = (self
-            .commands()
-            .get_pipeline_executable_internal_representations_khr)(
-            self.handle(),
-            executable_info,
-            &mut internal_representation_count,
-            internal_representations.as_mut_ptr(),
-        );
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
 
-        debug_assert!(
-            internal_representations.capacity() >= internal_representation_count as usize
-        );
-        internal_representations.set_len(internal_representation_count as usize);
+            debug_assert!(
+                internal_representations.capacity() >= internal_representation_count as usize
+            );
+            internal_representations.set_len(internal_representation_count as usize);
 
-        if __result == Result::SUCCESS {
-            Ok(internal_representations)
-        } else {
-            Err(__result.into())
+            break if __result == Result::SUCCESS {
+                Ok(internal_representations)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPipelineExecutableInternalRepresentationsKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_pipeline_executable_internal_representations_khr`].
+    #[inline]
+    unsafe fn get_pipeline_executable_internal_representations_khr_into(
+        &self,
+        executable_info: &PipelineExecutableInfoKHR,
+        internal_representations: &mut Vec<PipelineExecutableInternalRepresentationKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineExecutableInternalRepresentationsKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut internal_representation_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_pipeline_executable_internal_representations_khr)(
+                self.handle(),
+                executable_info,
+                &mut internal_representation_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            internal_representations.clear();
+            internal_representations.reserve(internal_representation_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_pipeline_executable_internal_representations_khr)(
+                self.handle(),
+                executable_info,
+                &mut internal_representation_count,
+                internal_representations.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(
+                    internal_representations.capacity() >= internal_representation_count as usize
+                );
+                internal_representations.set_len(internal_representation_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -8517,31 +15157,118 @@ pub trait KhrPipelineExecutablePropertiesExtensionDeviceCommands: DeviceV1_0 {
         &self,
         pipeline_info: &PipelineInfoKHR,
     ) -> crate::VkResult<Vec<PipelineExecutablePropertiesKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineExecutablePropertiesKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut executable_count = 0;
 
-        (self.commands().get_pipeline_executable_properties_khr)(
-            self.handle(),
-            pipeline_info,
-            &mut executable_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_pipeline_executable_properties_khr)(
+                self.handle(),
+                pipeline_info,
+                &mut executable_count,
+                ptr::null_mut(),
+            );
 
-        let mut properties = Vec::with_capacity(executable_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_pipeline_executable_properties_khr)(
-            self.handle(),
-            pipeline_info,
-            &mut executable_count,
-            properties.as_mut_ptr(),
-        );
+            let mut properties = Vec::with_capacity(executable_count as usize);
 
-        debug_assert!(properties.capacity() >= executable_count as usize);
-        properties.set_len(executable_count as usize);
+            let __result = (self.commands().get_pipeline_executable_properties_khr)(
+                self.handle(),
+                pipeline_info,
+                &mut executable_count,
+                properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= executable_count as usize);
+            properties.set_len(executable_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPipelineExecutablePropertiesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_pipeline_executable_properties_khr`].
+    #[inline]
+    unsafe fn get_pipeline_executable_properties_khr_into(
+        &self,
+        pipeline_info: &PipelineInfoKHR,
+        properties: &mut Vec<PipelineExecutablePropertiesKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineExecutablePropertiesKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut executable_count = 0;
+
+        loop {
+            let __result = (self.commands().get_pipeline_executable_properties_khr)(
+                self.handle(),
+                pipeline_info,
+                &mut executable_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(executable_count as usize);
+
+            let __result = (self.commands().get_pipeline_executable_properties_khr)(
+                self.handle(),
+                pipeline_info,
+                &mut executable_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= executable_count as usize);
+                properties.set_len(executable_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -8551,31 +15278,118 @@ pub trait KhrPipelineExecutablePropertiesExtensionDeviceCommands: DeviceV1_0 {
         &self,
         executable_info: &PipelineExecutableInfoKHR,
     ) -> crate::VkResult<Vec<PipelineExecutableStatisticKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineExecutableStatisticsKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut statistic_count = 0;
 
-        (self.commands().get_pipeline_executable_statistics_khr)(
-            self.handle(),
-            executable_info,
-            &mut statistic_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_pipeline_executable_statistics_khr)(
+                self.handle(),
+                executable_info,
+                &mut statistic_count,
+                ptr::null_mut(),
+            );
 
-        let mut statistics = Vec::with_capacity(statistic_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_pipeline_executable_statistics_khr)(
-            self.handle(),
-            executable_info,
-            &mut statistic_count,
-            statistics.as_mut_ptr(),
-        );
+            let mut statistics = Vec::with_capacity(statistic_count as usize);
 
-        debug_assert!(statistics.capacity() >= statistic_count as usize);
-        statistics.set_len(statistic_count as usize);
+            let __result = (self.commands().get_pipeline_executable_statistics_khr)(
+                self.handle(),
+                executable_info,
+                &mut statistic_count,
+                statistics.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(statistics)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(statistics.capacity() >= statistic_count as usize);
+            statistics.set_len(statistic_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(statistics)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPipelineExecutableStatisticsKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_pipeline_executable_statistics_khr`].
+    #[inline]
+    unsafe fn get_pipeline_executable_statistics_khr_into(
+        &self,
+        executable_info: &PipelineExecutableInfoKHR,
+        statistics: &mut Vec<PipelineExecutableStatisticKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineExecutableStatisticsKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut statistic_count = 0;
+
+        loop {
+            let __result = (self.commands().get_pipeline_executable_statistics_khr)(
+                self.handle(),
+                executable_info,
+                &mut statistic_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            statistics.clear();
+            statistics.reserve(statistic_count as usize);
+
+            let __result = (self.commands().get_pipeline_executable_statistics_khr)(
+                self.handle(),
+                executable_info,
+                &mut statistic_count,
+                statistics.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(statistics.capacity() >= statistic_count as usize);
+                statistics.set_len(statistic_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -8592,9 +15406,21 @@ pub trait KhrPresentWaitExtensionDeviceCommands: DeviceV1_0 {
         present_id: u64,
         timeout: u64,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkWaitForPresentKHR",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().wait_for_present_khr)(self.handle(), swapchain, present_id, timeout);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -8614,9 +15440,21 @@ pub trait KhrPresentWait2ExtensionDeviceCommands: DeviceV1_0 {
         swapchain: SwapchainKHR,
         present_wait2_info: &PresentWait2InfoKHR,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkWaitForPresent2KHR",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().wait_for_present2_khr)(self.handle(), swapchain, present_wait2_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -8639,6 +15477,14 @@ pub trait KhrPushDescriptorExtensionDeviceCommands: DeviceV1_0 {
         set: u32,
         descriptor_writes: &[impl Cast<Target = WriteDescriptorSet>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushDescriptorSetKHR",
+            command_buffer = tracing::field::debug(command_buffer),
+            layout = tracing::field::debug(layout)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_push_descriptor_set_khr)(
             command_buffer,
             pipeline_bind_point,
@@ -8659,6 +15505,15 @@ pub trait KhrPushDescriptorExtensionDeviceCommands: DeviceV1_0 {
         set: u32,
         data: *const c_void,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushDescriptorSetWithTemplateKHR",
+            command_buffer = tracing::field::debug(command_buffer),
+            descriptor_update_template = tracing::field::debug(descriptor_update_template),
+            layout = tracing::field::debug(layout)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_push_descriptor_set_with_template_khr)(
             command_buffer,
             descriptor_update_template,
@@ -8680,6 +15535,13 @@ pub trait KhrRayTracingMaintenance1ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         indirect_device_address: DeviceAddress,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdTraceRaysIndirect2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_trace_rays_indirect2_khr)(command_buffer, indirect_device_address);
     }
@@ -8696,6 +15558,13 @@ pub trait KhrRayTracingPipelineExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         pipeline_stack_size: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRayTracingPipelineStackSizeKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_ray_tracing_pipeline_stack_size_khr)(
             command_buffer,
             pipeline_stack_size,
@@ -8713,6 +15582,13 @@ pub trait KhrRayTracingPipelineExtensionDeviceCommands: DeviceV1_0 {
         callable_shader_binding_table: &StridedDeviceAddressRegionKHR,
         indirect_device_address: DeviceAddress,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdTraceRaysIndirectKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_trace_rays_indirect_khr)(
             command_buffer,
             raygen_shader_binding_table,
@@ -8736,6 +15612,13 @@ pub trait KhrRayTracingPipelineExtensionDeviceCommands: DeviceV1_0 {
         height: u32,
         depth: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdTraceRaysKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_trace_rays_khr)(
             command_buffer,
             raygen_shader_binding_table,
@@ -8757,6 +15640,16 @@ pub trait KhrRayTracingPipelineExtensionDeviceCommands: DeviceV1_0 {
         create_infos: &[impl Cast<Target = RayTracingPipelineCreateInfoKHR>],
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkSuccessResult<Vec<Pipeline>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateRayTracingPipelinesKHR",
+            device = tracing::field::debug(self.handle()),
+            deferred_operation = tracing::field::debug(deferred_operation),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut pipelines = Vec::with_capacity(create_infos.len() as usize);
 
         let __result = (self.commands().create_ray_tracing_pipelines_khr)(
@@ -8769,6 +15662,9 @@ pub trait KhrRayTracingPipelineExtensionDeviceCommands: DeviceV1_0 {
             pipelines.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         pipelines.set_len(create_infos.len() as usize);
 
         if __result >= Result::SUCCESS {
@@ -8778,6 +15674,56 @@ pub trait KhrRayTracingPipelineExtensionDeviceCommands: DeviceV1_0 {
         }
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCreateRayTracingPipelinesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::create_ray_tracing_pipelines_khr`].
+    #[inline]
+    unsafe fn create_ray_tracing_pipelines_khr_into(
+        &self,
+        deferred_operation: DeferredOperationKHR,
+        pipeline_cache: PipelineCache,
+        create_infos: &[impl Cast<Target = RayTracingPipelineCreateInfoKHR>],
+        allocator: Option<&AllocationCallbacks>,
+        pipelines: &mut Vec<Pipeline>,
+    ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateRayTracingPipelinesKHR",
+            device = tracing::field::debug(self.handle()),
+            deferred_operation = tracing::field::debug(deferred_operation),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        pipelines.clear();
+        pipelines.reserve(create_infos.len() as usize);
+
+        let __result = (self.commands().create_ray_tracing_pipelines_khr)(
+            self.handle(),
+            deferred_operation,
+            pipeline_cache,
+            create_infos.len() as u32,
+            create_infos.as_ptr().cast(),
+            allocator.map_or(ptr::null(), |v| v),
+            pipelines.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            pipelines.set_len(create_infos.len() as usize);
+        }
+
+        if __result >= Result::SUCCESS {
+            Ok(__result.into())
+        } else {
+            Err(__result.into())
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetRayTracingCaptureReplayShaderGroupHandlesKHR.html>
     #[inline]
     unsafe fn get_ray_tracing_capture_replay_shader_group_handles_khr(
@@ -8787,6 +15733,15 @@ pub trait KhrRayTracingPipelineExtensionDeviceCommands: DeviceV1_0 {
         group_count: u32,
         data: &mut [u8],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR",
+            device = tracing::field::debug(self.handle()),
+            pipeline = tracing::field::debug(pipeline),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_ray_tracing_capture_replay_shader_group_handles_khr)(
@@ -8798,6 +15753,9 @@ pub trait KhrRayTracingPipelineExtensionDeviceCommands: DeviceV1_0 {
             data.as_mut_ptr() as *mut c_void,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -8814,6 +15772,15 @@ pub trait KhrRayTracingPipelineExtensionDeviceCommands: DeviceV1_0 {
         group_count: u32,
         data: &mut [u8],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetRayTracingShaderGroupHandlesKHR",
+            device = tracing::field::debug(self.handle()),
+            pipeline = tracing::field::debug(pipeline),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_ray_tracing_shader_group_handles_khr)(
             self.handle(),
             pipeline,
@@ -8823,6 +15790,9 @@ pub trait KhrRayTracingPipelineExtensionDeviceCommands: DeviceV1_0 {
             data.as_mut_ptr() as *mut c_void,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -8838,6 +15808,14 @@ pub trait KhrRayTracingPipelineExtensionDeviceCommands: DeviceV1_0 {
         group: u32,
         group_shader: ShaderGroupShaderKHR,
     ) -> DeviceSize {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetRayTracingShaderGroupStackSizeKHR",
+            device = tracing::field::debug(self.handle()),
+            pipeline = tracing::field::debug(pipeline)
+        )
+        .entered();
+
         let __result = (self.commands().get_ray_tracing_shader_group_stack_size_khr)(
             self.handle(),
             pipeline,
@@ -8860,6 +15838,14 @@ pub trait KhrSamplerYcbcrConversionExtensionDeviceCommands: DeviceV1_0 {
         create_info: &SamplerYcbcrConversionCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SamplerYcbcrConversion> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateSamplerYcbcrConversionKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut ycbcr_conversion = MaybeUninit::<SamplerYcbcrConversion>::uninit();
 
         let __result = (self.commands().create_sampler_ycbcr_conversion_khr)(
@@ -8869,6 +15855,9 @@ pub trait KhrSamplerYcbcrConversionExtensionDeviceCommands: DeviceV1_0 {
             ycbcr_conversion.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(ycbcr_conversion.assume_init())
         } else {
@@ -8883,6 +15872,14 @@ pub trait KhrSamplerYcbcrConversionExtensionDeviceCommands: DeviceV1_0 {
         ycbcr_conversion: SamplerYcbcrConversion,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroySamplerYcbcrConversionKHR",
+            device = tracing::field::debug(self.handle()),
+            ycbcr_conversion = tracing::field::debug(ycbcr_conversion)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_sampler_ycbcr_conversion_khr)(
             self.handle(),
             ycbcr_conversion,
@@ -8901,8 +15898,20 @@ pub trait KhrSharedPresentableImageExtensionDeviceCommands: DeviceV1_0 {
         &self,
         swapchain: SwapchainKHR,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSwapchainStatusKHR",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_swapchain_status_khr)(self.handle(), swapchain);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -8922,6 +15931,14 @@ pub trait KhrSurfaceExtensionInstanceCommands: InstanceV1_0 {
         surface: SurfaceKHR,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroySurfaceKHR",
+            instance = tracing::field::debug(self.handle()),
+            surface = tracing::field::debug(surface)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_surface_khr)(
             self.handle(),
             surface,
@@ -8936,6 +15953,15 @@ pub trait KhrSurfaceExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         surface: SurfaceKHR,
     ) -> crate::VkResult<SurfaceCapabilitiesKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSurfaceCapabilitiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface_capabilities = MaybeUninit::<SurfaceCapabilitiesKHR>::uninit();
 
         let __result = (self.commands().get_physical_device_surface_capabilities_khr)(
@@ -8944,45 +15970,138 @@ pub trait KhrSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface_capabilities.as_mut_ptr(),
         );
 
-        if __result == Result::SUCCESS {
-            Ok(surface_capabilities.assume_init())
-        } else {
-            Err(__result.into())
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result == Result::SUCCESS {
+            Ok(surface_capabilities.assume_init())
+        } else {
+            Err(__result.into())
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceSurfaceFormatsKHR.html>
+    #[inline]
+    unsafe fn get_physical_device_surface_formats_khr(
+        &self,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+    ) -> crate::VkResult<Vec<SurfaceFormatKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSurfaceFormatsKHR",
+            physical_device = tracing::field::debug(physical_device),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut surface_format_count = 0;
+
+        loop {
+            let __result = (self.commands().get_physical_device_surface_formats_khr)(
+                physical_device,
+                surface,
+                &mut surface_format_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut surface_formats = Vec::with_capacity(surface_format_count as usize);
+
+            let __result = (self.commands().get_physical_device_surface_formats_khr)(
+                physical_device,
+                surface,
+                &mut surface_format_count,
+                surface_formats.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(surface_formats.capacity() >= surface_format_count as usize);
+            surface_formats.set_len(surface_format_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(surface_formats)
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceSurfaceFormatsKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_surface_formats_khr`].
     #[inline]
-    unsafe fn get_physical_device_surface_formats_khr(
+    unsafe fn get_physical_device_surface_formats_khr_into(
         &self,
         physical_device: PhysicalDevice,
         surface: SurfaceKHR,
-    ) -> crate::VkResult<Vec<SurfaceFormatKHR>> {
+        surface_formats: &mut Vec<SurfaceFormatKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSurfaceFormatsKHR",
+            physical_device = tracing::field::debug(physical_device),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface_format_count = 0;
 
-        (self.commands().get_physical_device_surface_formats_khr)(
-            physical_device,
-            surface,
-            &mut surface_format_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_physical_device_surface_formats_khr)(
+                physical_device,
+                surface,
+                &mut surface_format_count,
+                ptr::null_mut(),
+            );
 
-        let mut surface_formats = Vec::with_capacity(surface_format_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_physical_device_surface_formats_khr)(
-            physical_device,
-            surface,
-            &mut surface_format_count,
-            surface_formats.as_mut_ptr(),
-        );
+            surface_formats.clear();
+            surface_formats.reserve(surface_format_count as usize);
 
-        debug_assert!(surface_formats.capacity() >= surface_format_count as usize);
-        surface_formats.set_len(surface_format_count as usize);
+            let __result = (self.commands().get_physical_device_surface_formats_khr)(
+                physical_device,
+                surface,
+                &mut surface_format_count,
+                surface_formats.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(surface_formats)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(surface_formats.capacity() >= surface_format_count as usize);
+                surface_formats.set_len(surface_format_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -8993,35 +16112,129 @@ pub trait KhrSurfaceExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         surface: SurfaceKHR,
     ) -> crate::VkResult<Vec<PresentModeKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSurfacePresentModesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut present_mode_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_surface_present_modes_khr)(
-            physical_device,
-            surface,
-            &mut present_mode_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_surface_present_modes_khr)(
+                physical_device,
+                surface,
+                &mut present_mode_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut present_modes = Vec::with_capacity(present_mode_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_surface_present_modes_khr)(
+                physical_device,
+                surface,
+                &mut present_mode_count,
+                present_modes.as_mut_ptr(),
+            );
 
-        let mut present_modes = Vec::with_capacity(present_mode_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_surface_present_modes_khr)(
-            physical_device,
-            surface,
-            &mut present_mode_count,
-            present_modes.as_mut_ptr(),
-        );
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
 
-        debug_assert!(present_modes.capacity() >= present_mode_count as usize);
-        present_modes.set_len(present_mode_count as usize);
+            debug_assert!(present_modes.capacity() >= present_mode_count as usize);
+            present_modes.set_len(present_mode_count as usize);
 
-        if __result == Result::SUCCESS {
-            Ok(present_modes)
-        } else {
-            Err(__result.into())
+            break if __result == Result::SUCCESS {
+                Ok(present_modes)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceSurfacePresentModesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_surface_present_modes_khr`].
+    #[inline]
+    unsafe fn get_physical_device_surface_present_modes_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+        present_modes: &mut Vec<PresentModeKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSurfacePresentModesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut present_mode_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_surface_present_modes_khr)(
+                physical_device,
+                surface,
+                &mut present_mode_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            present_modes.clear();
+            present_modes.reserve(present_mode_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_surface_present_modes_khr)(
+                physical_device,
+                surface,
+                &mut present_mode_count,
+                present_modes.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(present_modes.capacity() >= present_mode_count as usize);
+                present_modes.set_len(present_mode_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -9033,6 +16246,15 @@ pub trait KhrSurfaceExtensionInstanceCommands: InstanceV1_0 {
         queue_family_index: u32,
         surface: SurfaceKHR,
     ) -> crate::VkResult<bool> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSurfaceSupportKHR",
+            physical_device = tracing::field::debug(physical_device),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut supported = MaybeUninit::<Bool32>::uninit();
 
         let __result = (self.commands().get_physical_device_surface_support_khr)(
@@ -9042,6 +16264,9 @@ pub trait KhrSurfaceExtensionInstanceCommands: InstanceV1_0 {
             supported.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(supported.assume_init() == TRUE)
         } else {
@@ -9060,6 +16285,14 @@ pub trait KhrSwapchainExtensionDeviceCommands: DeviceV1_0 {
         &self,
         acquire_info: &AcquireNextImageInfoKHR,
     ) -> crate::VkSuccessResult<u32> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAcquireNextImage2KHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut image_index = MaybeUninit::<u32>::uninit();
 
         let __result = (self.commands().acquire_next_image2_khr)(
@@ -9068,6 +16301,9 @@ pub trait KhrSwapchainExtensionDeviceCommands: DeviceV1_0 {
             image_index.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok((image_index.assume_init(), __result.into()))
         } else {
@@ -9084,6 +16320,17 @@ pub trait KhrSwapchainExtensionDeviceCommands: DeviceV1_0 {
         semaphore: Semaphore,
         fence: Fence,
     ) -> crate::VkSuccessResult<u32> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAcquireNextImageKHR",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            semaphore = tracing::field::debug(semaphore),
+            fence = tracing::field::debug(fence),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut image_index = MaybeUninit::<u32>::uninit();
 
         let __result = (self.commands().acquire_next_image_khr)(
@@ -9095,6 +16342,9 @@ pub trait KhrSwapchainExtensionDeviceCommands: DeviceV1_0 {
             image_index.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok((image_index.assume_init(), __result.into()))
         } else {
@@ -9109,6 +16359,14 @@ pub trait KhrSwapchainExtensionDeviceCommands: DeviceV1_0 {
         create_info: &SwapchainCreateInfoKHR,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SwapchainKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateSwapchainKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut swapchain = MaybeUninit::<SwapchainKHR>::uninit();
 
         let __result = (self.commands().create_swapchain_khr)(
@@ -9118,6 +16376,9 @@ pub trait KhrSwapchainExtensionDeviceCommands: DeviceV1_0 {
             swapchain.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(swapchain.assume_init())
         } else {
@@ -9132,6 +16393,14 @@ pub trait KhrSwapchainExtensionDeviceCommands: DeviceV1_0 {
         swapchain: SwapchainKHR,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroySwapchainKHR",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_swapchain_khr)(
             self.handle(),
             swapchain,
@@ -9145,11 +16414,22 @@ pub trait KhrSwapchainExtensionDeviceCommands: DeviceV1_0 {
         &self,
         device_group_present_capabilities: &mut DeviceGroupPresentCapabilitiesKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceGroupPresentCapabilitiesKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_device_group_present_capabilities_khr)(
             self.handle(),
             device_group_present_capabilities,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -9163,6 +16443,15 @@ pub trait KhrSwapchainExtensionDeviceCommands: DeviceV1_0 {
         &self,
         surface: SurfaceKHR,
     ) -> crate::VkResult<DeviceGroupPresentModeFlagsKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceGroupSurfacePresentModesKHR",
+            device = tracing::field::debug(self.handle()),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut modes = MaybeUninit::<DeviceGroupPresentModeFlagsKHR>::uninit();
 
         let __result = (self.commands().get_device_group_surface_present_modes_khr)(
@@ -9171,6 +16460,9 @@ pub trait KhrSwapchainExtensionDeviceCommands: DeviceV1_0 {
             modes.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(modes.assume_init())
         } else {
@@ -9184,31 +16476,120 @@ pub trait KhrSwapchainExtensionDeviceCommands: DeviceV1_0 {
         &self,
         swapchain: SwapchainKHR,
     ) -> crate::VkResult<Vec<Image>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSwapchainImagesKHR",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut swapchain_image_count = 0;
 
-        (self.commands().get_swapchain_images_khr)(
-            self.handle(),
-            swapchain,
-            &mut swapchain_image_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_swapchain_images_khr)(
+                self.handle(),
+                swapchain,
+                &mut swapchain_image_count,
+                ptr::null_mut(),
+            );
 
-        let mut swapchain_images = Vec::with_capacity(swapchain_image_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_swapchain_images_khr)(
-            self.handle(),
-            swapchain,
-            &mut swapchain_image_count,
-            swapchain_images.as_mut_ptr(),
-        );
+            let mut swapchain_images = Vec::with_capacity(swapchain_image_count as usize);
 
-        debug_assert!(swapchain_images.capacity() >= swapchain_image_count as usize);
-        swapchain_images.set_len(swapchain_image_count as usize);
+            let __result = (self.commands().get_swapchain_images_khr)(
+                self.handle(),
+                swapchain,
+                &mut swapchain_image_count,
+                swapchain_images.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(swapchain_images)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(swapchain_images.capacity() >= swapchain_image_count as usize);
+            swapchain_images.set_len(swapchain_image_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(swapchain_images)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetSwapchainImagesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_swapchain_images_khr`].
+    #[inline]
+    unsafe fn get_swapchain_images_khr_into(
+        &self,
+        swapchain: SwapchainKHR,
+        swapchain_images: &mut Vec<Image>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSwapchainImagesKHR",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut swapchain_image_count = 0;
+
+        loop {
+            let __result = (self.commands().get_swapchain_images_khr)(
+                self.handle(),
+                swapchain,
+                &mut swapchain_image_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            swapchain_images.clear();
+            swapchain_images.reserve(swapchain_image_count as usize);
+
+            let __result = (self.commands().get_swapchain_images_khr)(
+                self.handle(),
+                swapchain,
+                &mut swapchain_image_count,
+                swapchain_images.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(swapchain_images.capacity() >= swapchain_image_count as usize);
+                swapchain_images.set_len(swapchain_image_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -9219,8 +16600,19 @@ pub trait KhrSwapchainExtensionDeviceCommands: DeviceV1_0 {
         queue: Queue,
         present_info: &PresentInfoKHR,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkQueuePresentKHR",
+            queue = tracing::field::debug(queue),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().queue_present_khr)(queue, present_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -9240,31 +16632,121 @@ pub trait KhrSwapchainExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         surface: SurfaceKHR,
     ) -> crate::VkResult<Vec<Rect2D>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDevicePresentRectanglesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut rect_count = 0;
 
-        (self.commands().get_physical_device_present_rectangles_khr)(
-            physical_device,
-            surface,
-            &mut rect_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_physical_device_present_rectangles_khr)(
+                physical_device,
+                surface,
+                &mut rect_count,
+                ptr::null_mut(),
+            );
 
-        let mut rects = Vec::with_capacity(rect_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_physical_device_present_rectangles_khr)(
-            physical_device,
-            surface,
-            &mut rect_count,
-            rects.as_mut_ptr(),
-        );
+            let mut rects = Vec::with_capacity(rect_count as usize);
 
-        debug_assert!(rects.capacity() >= rect_count as usize);
-        rects.set_len(rect_count as usize);
+            let __result = (self.commands().get_physical_device_present_rectangles_khr)(
+                physical_device,
+                surface,
+                &mut rect_count,
+                rects.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(rects)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(rects.capacity() >= rect_count as usize);
+            rects.set_len(rect_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(rects)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDevicePresentRectanglesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_present_rectangles_khr`].
+    #[inline]
+    unsafe fn get_physical_device_present_rectangles_khr_into(
+        &self,
+        physical_device: PhysicalDevice,
+        surface: SurfaceKHR,
+        rects: &mut Vec<Rect2D>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDevicePresentRectanglesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            surface = tracing::field::debug(surface),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut rect_count = 0;
+
+        loop {
+            let __result = (self.commands().get_physical_device_present_rectangles_khr)(
+                physical_device,
+                surface,
+                &mut rect_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            rects.clear();
+            rects.reserve(rect_count as usize);
+
+            let __result = (self.commands().get_physical_device_present_rectangles_khr)(
+                physical_device,
+                surface,
+                &mut rect_count,
+                rects.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(rects.capacity() >= rect_count as usize);
+                rects.set_len(rect_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -9279,8 +16761,19 @@ pub trait KhrSwapchainMaintenance1ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         release_info: &ReleaseSwapchainImagesInfoKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkReleaseSwapchainImagesKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().release_swapchain_images_khr)(self.handle(), release_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -9300,6 +16793,13 @@ pub trait KhrSynchronization2ExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         dependency_info: &DependencyInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPipelineBarrier2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_pipeline_barrier2_khr)(command_buffer, dependency_info);
     }
 
@@ -9311,6 +16811,14 @@ pub trait KhrSynchronization2ExtensionDeviceCommands: DeviceV1_0 {
         event: Event,
         stage_mask: PipelineStageFlags2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdResetEvent2KHR",
+            command_buffer = tracing::field::debug(command_buffer),
+            event = tracing::field::debug(event)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_reset_event2_khr)(command_buffer, event, stage_mask);
     }
 
@@ -9322,6 +16830,14 @@ pub trait KhrSynchronization2ExtensionDeviceCommands: DeviceV1_0 {
         event: Event,
         dependency_info: &DependencyInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetEvent2KHR",
+            command_buffer = tracing::field::debug(command_buffer),
+            event = tracing::field::debug(event)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_event2_khr)(command_buffer, event, dependency_info);
     }
 
@@ -9333,6 +16849,13 @@ pub trait KhrSynchronization2ExtensionDeviceCommands: DeviceV1_0 {
         events: &[Event],
         dependency_infos: &[impl Cast<Target = DependencyInfo>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdWaitEvents2KHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_wait_events2_khr)(
             command_buffer,
             events.len() as u32,
@@ -9350,6 +16873,14 @@ pub trait KhrSynchronization2ExtensionDeviceCommands: DeviceV1_0 {
         query_pool: QueryPool,
         query: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdWriteTimestamp2KHR",
+            command_buffer = tracing::field::debug(command_buffer),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_write_timestamp2_khr)(command_buffer, stage, query_pool, query);
     }
@@ -9362,6 +16893,15 @@ pub trait KhrSynchronization2ExtensionDeviceCommands: DeviceV1_0 {
         submits: &[impl Cast<Target = SubmitInfo2>],
         fence: Fence,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkQueueSubmit2KHR",
+            queue = tracing::field::debug(queue),
+            fence = tracing::field::debug(fence),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().queue_submit2_khr)(
             queue,
             submits.len() as u32,
@@ -9369,6 +16909,9 @@ pub trait KhrSynchronization2ExtensionDeviceCommands: DeviceV1_0 {
             fence,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -9384,6 +16927,15 @@ pub trait KhrTimelineSemaphoreExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetSemaphoreCounterValueKHR.html>
     #[inline]
     unsafe fn get_semaphore_counter_value_khr(&self, semaphore: Semaphore) -> crate::VkResult<u64> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSemaphoreCounterValueKHR",
+            device = tracing::field::debug(self.handle()),
+            semaphore = tracing::field::debug(semaphore),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut value = MaybeUninit::<u64>::uninit();
 
         let __result = (self.commands().get_semaphore_counter_value_khr)(
@@ -9392,6 +16944,9 @@ pub trait KhrTimelineSemaphoreExtensionDeviceCommands: DeviceV1_0 {
             value.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(value.assume_init())
         } else {
@@ -9405,8 +16960,19 @@ pub trait KhrTimelineSemaphoreExtensionDeviceCommands: DeviceV1_0 {
         &self,
         signal_info: &SemaphoreSignalInfo,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSignalSemaphoreKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().signal_semaphore_khr)(self.handle(), signal_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -9421,8 +16987,19 @@ pub trait KhrTimelineSemaphoreExtensionDeviceCommands: DeviceV1_0 {
         wait_info: &SemaphoreWaitInfo,
         timeout: u64,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkWaitSemaphoresKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().wait_semaphores_khr)(self.handle(), wait_info, timeout);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -9442,6 +17019,13 @@ pub trait KhrVideoDecodeQueueExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         decode_info: &VideoDecodeInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDecodeVideoKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_decode_video_khr)(command_buffer, decode_info);
     }
 }
@@ -9457,6 +17041,13 @@ pub trait KhrVideoEncodeQueueExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         encode_info: &VideoEncodeInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEncodeVideoKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_encode_video_khr)(command_buffer, encode_info);
     }
 
@@ -9467,6 +17058,14 @@ pub trait KhrVideoEncodeQueueExtensionDeviceCommands: DeviceV1_0 {
         video_session_parameters_info: &VideoEncodeSessionParametersGetInfoKHR,
         feedback_info: Option<&mut VideoEncodeSessionParametersFeedbackInfoKHR>,
     ) -> crate::VkResult<Vec<u8>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetEncodedVideoSessionParametersKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut data_size = 0;
 
         (self.commands().get_encoded_video_session_parameters_khr)(
@@ -9487,6 +17086,9 @@ pub trait KhrVideoEncodeQueueExtensionDeviceCommands: DeviceV1_0 {
             data.as_mut_ptr() as *mut c_void,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         debug_assert!(data.capacity() >= data_size as usize);
         data.set_len(data_size as usize);
 
@@ -9496,6 +17098,61 @@ pub trait KhrVideoEncodeQueueExtensionDeviceCommands: DeviceV1_0 {
             Err(__result.into())
         }
     }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetEncodedVideoSessionParametersKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_encoded_video_session_parameters_khr`].
+    #[inline]
+    unsafe fn get_encoded_video_session_parameters_khr_into(
+        &self,
+        video_session_parameters_info: &VideoEncodeSessionParametersGetInfoKHR,
+        feedback_info: Option<&mut VideoEncodeSessionParametersFeedbackInfoKHR>,
+        data: &mut Vec<u8>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetEncodedVideoSessionParametersKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut data_size = 0;
+
+        (self.commands().get_encoded_video_session_parameters_khr)(
+            self.handle(),
+            video_session_parameters_info,
+            ptr::null_mut(),
+            &mut data_size,
+            ptr::null_mut(),
+        );
+
+        data.clear();
+        data.reserve(data_size as usize);
+
+        let __result = (self.commands().get_encoded_video_session_parameters_khr)(
+            self.handle(),
+            video_session_parameters_info,
+            feedback_info.map_or(ptr::null_mut(), |v| v),
+            &mut data_size,
+            data.as_mut_ptr() as *mut c_void,
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            debug_assert!(data.capacity() >= data_size as usize);
+            data.set_len(data_size as usize);
+        }
+
+        if __result == Result::SUCCESS {
+            Ok(())
+        } else {
+            Err(__result.into())
+        }
+    }
 }
 
 impl<C: DeviceV1_0 + ?Sized> KhrVideoEncodeQueueExtensionDeviceCommands for C {}
@@ -9510,6 +17167,14 @@ pub trait KhrVideoEncodeQueueExtensionInstanceCommands: InstanceV1_0 {
         quality_level_info: &PhysicalDeviceVideoEncodeQualityLevelInfoKHR,
         quality_level_properties: &mut VideoEncodeQualityLevelPropertiesKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_video_encode_quality_level_properties_khr)(
@@ -9518,6 +17183,9 @@ pub trait KhrVideoEncodeQueueExtensionInstanceCommands: InstanceV1_0 {
             quality_level_properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -9537,6 +17205,15 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
         video_session: VideoSessionKHR,
         bind_session_memory_infos: &[impl Cast<Target = BindVideoSessionMemoryInfoKHR>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBindVideoSessionMemoryKHR",
+            device = tracing::field::debug(self.handle()),
+            video_session = tracing::field::debug(video_session),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().bind_video_session_memory_khr)(
             self.handle(),
             video_session,
@@ -9544,6 +17221,9 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
             bind_session_memory_infos.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -9558,6 +17238,13 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         begin_info: &VideoBeginCodingInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginVideoCodingKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_begin_video_coding_khr)(command_buffer, begin_info);
     }
 
@@ -9568,6 +17255,13 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         coding_control_info: &VideoCodingControlInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdControlVideoCodingKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_control_video_coding_khr)(command_buffer, coding_control_info);
     }
@@ -9579,6 +17273,13 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         end_coding_info: &VideoEndCodingInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndVideoCodingKHR",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_end_video_coding_khr)(command_buffer, end_coding_info);
     }
 
@@ -9589,6 +17290,14 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
         create_info: &VideoSessionCreateInfoKHR,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<VideoSessionKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateVideoSessionKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut video_session = MaybeUninit::<VideoSessionKHR>::uninit();
 
         let __result = (self.commands().create_video_session_khr)(
@@ -9598,6 +17307,9 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
             video_session.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(video_session.assume_init())
         } else {
@@ -9612,6 +17324,14 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
         create_info: &VideoSessionParametersCreateInfoKHR,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<VideoSessionParametersKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateVideoSessionParametersKHR",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut video_session_parameters = MaybeUninit::<VideoSessionParametersKHR>::uninit();
 
         let __result = (self.commands().create_video_session_parameters_khr)(
@@ -9621,6 +17341,9 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
             video_session_parameters.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(video_session_parameters.assume_init())
         } else {
@@ -9635,6 +17358,14 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
         video_session: VideoSessionKHR,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyVideoSessionKHR",
+            device = tracing::field::debug(self.handle()),
+            video_session = tracing::field::debug(video_session)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_video_session_khr)(
             self.handle(),
             video_session,
@@ -9649,6 +17380,14 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
         video_session_parameters: VideoSessionParametersKHR,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyVideoSessionParametersKHR",
+            device = tracing::field::debug(self.handle()),
+            video_session_parameters = tracing::field::debug(video_session_parameters)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_video_session_parameters_khr)(
             self.handle(),
             video_session_parameters,
@@ -9662,31 +17401,120 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
         &self,
         video_session: VideoSessionKHR,
     ) -> crate::VkResult<Vec<VideoSessionMemoryRequirementsKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetVideoSessionMemoryRequirementsKHR",
+            device = tracing::field::debug(self.handle()),
+            video_session = tracing::field::debug(video_session),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut memory_requirements_count = 0;
 
-        (self.commands().get_video_session_memory_requirements_khr)(
-            self.handle(),
-            video_session,
-            &mut memory_requirements_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_video_session_memory_requirements_khr)(
+                self.handle(),
+                video_session,
+                &mut memory_requirements_count,
+                ptr::null_mut(),
+            );
 
-        let mut memory_requirements = Vec::with_capacity(memory_requirements_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_video_session_memory_requirements_khr)(
-            self.handle(),
-            video_session,
-            &mut memory_requirements_count,
-            memory_requirements.as_mut_ptr(),
-        );
+            let mut memory_requirements = Vec::with_capacity(memory_requirements_count as usize);
 
-        debug_assert!(memory_requirements.capacity() >= memory_requirements_count as usize);
-        memory_requirements.set_len(memory_requirements_count as usize);
+            let __result = (self.commands().get_video_session_memory_requirements_khr)(
+                self.handle(),
+                video_session,
+                &mut memory_requirements_count,
+                memory_requirements.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(memory_requirements)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(memory_requirements.capacity() >= memory_requirements_count as usize);
+            memory_requirements.set_len(memory_requirements_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(memory_requirements)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetVideoSessionMemoryRequirementsKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_video_session_memory_requirements_khr`].
+    #[inline]
+    unsafe fn get_video_session_memory_requirements_khr_into(
+        &self,
+        video_session: VideoSessionKHR,
+        memory_requirements: &mut Vec<VideoSessionMemoryRequirementsKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetVideoSessionMemoryRequirementsKHR",
+            device = tracing::field::debug(self.handle()),
+            video_session = tracing::field::debug(video_session),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut memory_requirements_count = 0;
+
+        loop {
+            let __result = (self.commands().get_video_session_memory_requirements_khr)(
+                self.handle(),
+                video_session,
+                &mut memory_requirements_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            memory_requirements.clear();
+            memory_requirements.reserve(memory_requirements_count as usize);
+
+            let __result = (self.commands().get_video_session_memory_requirements_khr)(
+                self.handle(),
+                video_session,
+                &mut memory_requirements_count,
+                memory_requirements.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(memory_requirements.capacity() >= memory_requirements_count as usize);
+                memory_requirements.set_len(memory_requirements_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -9697,12 +17525,24 @@ pub trait KhrVideoQueueExtensionDeviceCommands: DeviceV1_0 {
         video_session_parameters: VideoSessionParametersKHR,
         update_info: &VideoSessionParametersUpdateInfoKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkUpdateVideoSessionParametersKHR",
+            device = tracing::field::debug(self.handle()),
+            video_session_parameters = tracing::field::debug(video_session_parameters),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().update_video_session_parameters_khr)(
             self.handle(),
             video_session_parameters,
             update_info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -9723,12 +17563,23 @@ pub trait KhrVideoQueueExtensionInstanceCommands: InstanceV1_0 {
         video_profile: &VideoProfileInfoKHR,
         capabilities: &mut VideoCapabilitiesKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceVideoCapabilitiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_physical_device_video_capabilities_khr)(
             physical_device,
             video_profile,
             capabilities,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -9738,40 +17589,137 @@ pub trait KhrVideoQueueExtensionInstanceCommands: InstanceV1_0 {
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceVideoFormatPropertiesKHR.html>
     #[inline]
-    unsafe fn get_physical_device_video_format_properties_khr(
+    unsafe fn get_physical_device_video_format_properties_khr(
+        &self,
+        physical_device: PhysicalDevice,
+        video_format_info: &PhysicalDeviceVideoFormatInfoKHR,
+    ) -> crate::VkResult<Vec<VideoFormatPropertiesKHR>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceVideoFormatPropertiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut video_format_property_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_video_format_properties_khr)(
+                physical_device,
+                video_format_info,
+                &mut video_format_property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut video_format_properties =
+                Vec::with_capacity(video_format_property_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_video_format_properties_khr)(
+                physical_device,
+                video_format_info,
+                &mut video_format_property_count,
+                video_format_properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(
+                video_format_properties.capacity() >= video_format_property_count as usize
+            );
+            video_format_properties.set_len(video_format_property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(video_format_properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceVideoFormatPropertiesKHR.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_video_format_properties_khr`].
+    #[inline]
+    unsafe fn get_physical_device_video_format_properties_khr_into(
         &self,
         physical_device: PhysicalDevice,
         video_format_info: &PhysicalDeviceVideoFormatInfoKHR,
-    ) -> crate::VkResult<Vec<VideoFormatPropertiesKHR>> {
-        let mut video_format_property_count = 0;
-
-        (self
-            .commands()
-            .get_physical_device_video_format_properties_khr)(
-            physical_device,
-            video_format_info,
-            &mut video_format_property_count,
-            ptr::null_mut(),
-        );
-
-        let mut video_format_properties = Vec::with_capacity(video_format_property_count as usize);
-
-        let __result = (self
-            .commands()
-            .get_physical_device_video_format_properties_khr)(
-            physical_device,
-            video_format_info,
-            &mut video_format_property_count,
-            video_format_properties.as_mut_ptr(),
-        );
+        video_format_properties: &mut Vec<VideoFormatPropertiesKHR>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceVideoFormatPropertiesKHR",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        debug_assert!(video_format_properties.capacity() >= video_format_property_count as usize);
-        video_format_properties.set_len(video_format_property_count as usize);
+        let mut video_format_property_count = 0;
 
-        if __result == Result::SUCCESS {
-            Ok(video_format_properties)
-        } else {
-            Err(__result.into())
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_video_format_properties_khr)(
+                physical_device,
+                video_format_info,
+                &mut video_format_property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            video_format_properties.clear();
+            video_format_properties.reserve(video_format_property_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_video_format_properties_khr)(
+                physical_device,
+                video_format_info,
+                &mut video_format_property_count,
+                video_format_properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(
+                    video_format_properties.capacity() >= video_format_property_count as usize
+                );
+                video_format_properties.set_len(video_format_property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -9787,6 +17735,14 @@ pub trait KhrWaylandSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &WaylandSurfaceCreateInfoKHR,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateWaylandSurfaceKHR",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_wayland_surface_khr)(
@@ -9796,6 +17752,9 @@ pub trait KhrWaylandSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -9811,6 +17770,13 @@ pub trait KhrWaylandSurfaceExtensionInstanceCommands: InstanceV1_0 {
         queue_family_index: u32,
         display: *mut wl_display,
     ) -> Bool32 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceWaylandPresentationSupportKHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_wayland_presentation_support_khr)(
@@ -9834,6 +17800,14 @@ pub trait KhrWin32SurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &Win32SurfaceCreateInfoKHR,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateWin32SurfaceKHR",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_win32_surface_khr)(
@@ -9843,6 +17817,9 @@ pub trait KhrWin32SurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -9857,6 +17834,13 @@ pub trait KhrWin32SurfaceExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         queue_family_index: u32,
     ) -> Bool32 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceWin32PresentationSupportKHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_win32_presentation_support_khr)(
@@ -9879,6 +17863,14 @@ pub trait KhrXcbSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &XcbSurfaceCreateInfoKHR,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateXcbSurfaceKHR",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_xcb_surface_khr)(
@@ -9888,6 +17880,9 @@ pub trait KhrXcbSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -9904,6 +17899,13 @@ pub trait KhrXcbSurfaceExtensionInstanceCommands: InstanceV1_0 {
         connection: *mut xcb_connection_t,
         visual_id: xcb_visualid_t,
     ) -> Bool32 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceXcbPresentationSupportKHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_xcb_presentation_support_khr)(
@@ -9928,6 +17930,14 @@ pub trait KhrXlibSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &XlibSurfaceCreateInfoKHR,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateXlibSurfaceKHR",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_xlib_surface_khr)(
@@ -9937,6 +17947,9 @@ pub trait KhrXlibSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -9952,6 +17965,13 @@ pub trait KhrXlibSurfaceExtensionInstanceCommands: InstanceV1_0 {
         queue_family_index: u32,
         visual_id: VisualID,
     ) -> (Bool32, Display) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceXlibPresentationSupportKHR",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut dpy = MaybeUninit::<Display>::uninit();
 
         let __result = (self
@@ -9978,6 +17998,14 @@ pub trait MvkIosSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &IOSSurfaceCreateInfoMVK,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateIOSSurfaceMVK",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_ios_surface_mvk)(
@@ -9987,6 +18015,9 @@ pub trait MvkIosSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -10007,6 +18038,14 @@ pub trait MvkMacosSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &MacOSSurfaceCreateInfoMVK,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateMacOSSurfaceMVK",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_mac_os_surface_mvk)(
@@ -10016,6 +18055,9 @@ pub trait MvkMacosSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -10036,6 +18078,14 @@ pub trait NnViSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &ViSurfaceCreateInfoNN,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateViSurfaceNN",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_vi_surface_nn)(
@@ -10045,6 +18095,9 @@ pub trait NnViSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -10064,6 +18117,13 @@ pub trait NvxBinaryImportExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         launch_info: &CuLaunchInfoNVX,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCuLaunchKernelNVX",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_cu_launch_kernel_nvx)(command_buffer, launch_info);
     }
 
@@ -10074,6 +18134,14 @@ pub trait NvxBinaryImportExtensionDeviceCommands: DeviceV1_0 {
         create_info: &CuFunctionCreateInfoNVX,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<CuFunctionNVX> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateCuFunctionNVX",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut function = MaybeUninit::<CuFunctionNVX>::uninit();
 
         let __result = (self.commands().create_cu_function_nvx)(
@@ -10083,6 +18151,9 @@ pub trait NvxBinaryImportExtensionDeviceCommands: DeviceV1_0 {
             function.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(function.assume_init())
         } else {
@@ -10097,6 +18168,14 @@ pub trait NvxBinaryImportExtensionDeviceCommands: DeviceV1_0 {
         create_info: &CuModuleCreateInfoNVX,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<CuModuleNVX> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateCuModuleNVX",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut module = MaybeUninit::<CuModuleNVX>::uninit();
 
         let __result = (self.commands().create_cu_module_nvx)(
@@ -10106,6 +18185,9 @@ pub trait NvxBinaryImportExtensionDeviceCommands: DeviceV1_0 {
             module.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(module.assume_init())
         } else {
@@ -10120,6 +18202,14 @@ pub trait NvxBinaryImportExtensionDeviceCommands: DeviceV1_0 {
         function: CuFunctionNVX,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyCuFunctionNVX",
+            device = tracing::field::debug(self.handle()),
+            function = tracing::field::debug(function)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_cu_function_nvx)(
             self.handle(),
             function,
@@ -10134,6 +18224,14 @@ pub trait NvxBinaryImportExtensionDeviceCommands: DeviceV1_0 {
         module: CuModuleNVX,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyCuModuleNVX",
+            device = tracing::field::debug(self.handle()),
+            module = tracing::field::debug(module)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_cu_module_nvx)(
             self.handle(),
             module,
@@ -10153,6 +18251,13 @@ pub trait NvxImageViewHandleExtensionDeviceCommands: DeviceV1_0 {
         image_view_index: u64,
         sampler_index: u64,
     ) -> u64 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceCombinedImageSamplerIndexNVX",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_device_combined_image_sampler_index_nvx)(
             self.handle(),
             image_view_index,
@@ -10169,9 +18274,21 @@ pub trait NvxImageViewHandleExtensionDeviceCommands: DeviceV1_0 {
         image_view: ImageView,
         properties: &mut ImageViewAddressPropertiesNVX,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageViewAddressNVX",
+            device = tracing::field::debug(self.handle()),
+            image_view = tracing::field::debug(image_view),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().get_image_view_address_nvx)(self.handle(), image_view, properties);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -10182,6 +18299,13 @@ pub trait NvxImageViewHandleExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetImageViewHandle64NVX.html>
     #[inline]
     unsafe fn get_image_view_handle64_nvx(&self, info: &ImageViewHandleInfoNVX) -> u64 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageViewHandle64NVX",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_image_view_handle64_nvx)(self.handle(), info);
 
         __result
@@ -10190,6 +18314,13 @@ pub trait NvxImageViewHandleExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetImageViewHandleNVX.html>
     #[inline]
     unsafe fn get_image_view_handle_nvx(&self, info: &ImageViewHandleInfoNVX) -> u32 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageViewHandleNVX",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_image_view_handle_nvx)(self.handle(), info);
 
         __result
@@ -10207,8 +18338,22 @@ pub trait NvAcquireWinrtDisplayExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         display: DisplayKHR,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAcquireWinrtDisplayNV",
+            physical_device = tracing::field::debug(physical_device),
+            display = tracing::field::Empty,
+            result = tracing::field::Empty
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        __span.record("display", tracing::field::debug(display));
+
         let __result = (self.commands().acquire_winrt_display_nv)(physical_device, display);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -10223,6 +18368,14 @@ pub trait NvAcquireWinrtDisplayExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         device_relative_id: u32,
     ) -> crate::VkResult<DisplayKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetWinrtDisplayNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut display = MaybeUninit::<DisplayKHR>::uninit();
 
         let __result = (self.commands().get_winrt_display_nv)(
@@ -10231,6 +18384,9 @@ pub trait NvAcquireWinrtDisplayExtensionInstanceCommands: InstanceV1_0 {
             display.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(display.assume_init())
         } else {
@@ -10251,6 +18407,13 @@ pub trait NvClipSpaceWScalingExtensionDeviceCommands: DeviceV1_0 {
         first_viewport: u32,
         viewport_w_scalings: &[impl Cast<Target = ViewportWScalingNV>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetViewportWScalingNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_viewport_w_scaling_nv)(
             command_buffer,
             first_viewport,
@@ -10271,6 +18434,13 @@ pub trait NvClusterAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         command_infos: &ClusterAccelerationStructureCommandsInfoNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBuildClusterAccelerationStructureIndirectNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .cmd_build_cluster_acceleration_structure_indirect_nv)(
@@ -10285,6 +18455,13 @@ pub trait NvClusterAccelerationStructureExtensionDeviceCommands: DeviceV1_0 {
         info: &ClusterAccelerationStructureInputInfoNV,
         size_info: &mut AccelerationStructureBuildSizesInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetClusterAccelerationStructureBuildSizesNV",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_cluster_acceleration_structure_build_sizes_nv)(
@@ -10304,6 +18481,13 @@ pub trait NvComputeOccupancyPriorityExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         parameters: &ComputeOccupancyPriorityParametersNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetComputeOccupancyPriorityNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_compute_occupancy_priority_nv)(command_buffer, parameters);
     }
@@ -10319,33 +18503,122 @@ pub trait NvCooperativeMatrixExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<CooperativeMatrixPropertiesNV>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut property_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_cooperative_matrix_properties_nv)(
-            physical_device,
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_matrix_properties_nv)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_cooperative_matrix_properties_nv)(
-            physical_device,
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+            let mut properties = Vec::with_capacity(property_count as usize);
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_matrix_properties_nv)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceCooperativeMatrixPropertiesNV.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_cooperative_matrix_properties_nv`].
+    #[inline]
+    unsafe fn get_physical_device_cooperative_matrix_properties_nv_into(
+        &self,
+        physical_device: PhysicalDevice,
+        properties: &mut Vec<CooperativeMatrixPropertiesNV>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut property_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_matrix_properties_nv)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_matrix_properties_nv)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -10360,33 +18633,122 @@ pub trait NvCooperativeMatrix2ExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<CooperativeMatrixFlexibleDimensionsPropertiesNV>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceCooperativeMatrixFlexibleDimensionsPropertiesNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut property_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_cooperative_matrix_flexible_dimensions_properties_nv)(
-            physical_device,
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_matrix_flexible_dimensions_properties_nv)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_cooperative_matrix_flexible_dimensions_properties_nv)(
-            physical_device,
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+            let mut properties = Vec::with_capacity(property_count as usize);
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_matrix_flexible_dimensions_properties_nv)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceCooperativeMatrixFlexibleDimensionsPropertiesNV.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_cooperative_matrix_flexible_dimensions_properties_nv`].
+    #[inline]
+    unsafe fn get_physical_device_cooperative_matrix_flexible_dimensions_properties_nv_into(
+        &self,
+        physical_device: PhysicalDevice,
+        properties: &mut Vec<CooperativeMatrixFlexibleDimensionsPropertiesNV>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceCooperativeMatrixFlexibleDimensionsPropertiesNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut property_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_matrix_flexible_dimensions_properties_nv)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_matrix_flexible_dimensions_properties_nv)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -10402,6 +18764,13 @@ pub trait NvCooperativeVectorExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         infos: &[impl Cast<Target = ConvertCooperativeVectorMatrixInfoNV>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdConvertCooperativeVectorMatrixNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_convert_cooperative_vector_matrix_nv)(
             command_buffer,
             infos.len() as u32,
@@ -10415,8 +18784,19 @@ pub trait NvCooperativeVectorExtensionDeviceCommands: DeviceV1_0 {
         &self,
         info: &ConvertCooperativeVectorMatrixInfoNV,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkConvertCooperativeVectorMatrixNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().convert_cooperative_vector_matrix_nv)(self.handle(), info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -10435,33 +18815,122 @@ pub trait NvCooperativeVectorExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<CooperativeVectorPropertiesNV>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceCooperativeVectorPropertiesNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut property_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_cooperative_vector_properties_nv)(
-            physical_device,
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_vector_properties_nv)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_cooperative_vector_properties_nv)(
-            physical_device,
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+            let mut properties = Vec::with_capacity(property_count as usize);
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_vector_properties_nv)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceCooperativeVectorPropertiesNV.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_cooperative_vector_properties_nv`].
+    #[inline]
+    unsafe fn get_physical_device_cooperative_vector_properties_nv_into(
+        &self,
+        physical_device: PhysicalDevice,
+        properties: &mut Vec<CooperativeVectorPropertiesNV>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceCooperativeVectorPropertiesNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut property_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_vector_properties_nv)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_cooperative_vector_properties_nv)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -10479,6 +18948,13 @@ pub trait NvCopyMemoryIndirectExtensionDeviceCommands: DeviceV1_0 {
         copy_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyMemoryIndirectNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_memory_indirect_nv)(
             command_buffer,
             copy_buffer_address,
@@ -10499,6 +18975,14 @@ pub trait NvCopyMemoryIndirectExtensionDeviceCommands: DeviceV1_0 {
         dst_image_layout: ImageLayout,
         image_subresources: &[impl Cast<Target = ImageSubresourceLayers>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyMemoryToImageIndirectNV",
+            command_buffer = tracing::field::debug(command_buffer),
+            dst_image = tracing::field::debug(dst_image)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_memory_to_image_indirect_nv)(
             command_buffer,
             copy_buffer_address,
@@ -10521,33 +19005,122 @@ pub trait NvCoverageReductionModeExtensionInstanceCommands: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<FramebufferMixedSamplesCombinationNV>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut combination_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_supported_framebuffer_mixed_samples_combinations_nv)(
-            physical_device,
-            &mut combination_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_supported_framebuffer_mixed_samples_combinations_nv)(
+                physical_device,
+                &mut combination_count,
+                ptr::null_mut(),
+            );
 
-        let mut combinations = Vec::with_capacity(combination_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_supported_framebuffer_mixed_samples_combinations_nv)(
-            physical_device,
-            &mut combination_count,
-            combinations.as_mut_ptr(),
-        );
+            let mut combinations = Vec::with_capacity(combination_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_supported_framebuffer_mixed_samples_combinations_nv)(
+                physical_device,
+                &mut combination_count,
+                combinations.as_mut_ptr(),
+            );
 
-        debug_assert!(combinations.capacity() >= combination_count as usize);
-        combinations.set_len(combination_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        if __result == Result::SUCCESS {
-            Ok(combinations)
-        } else {
-            Err(__result.into())
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(combinations.capacity() >= combination_count as usize);
+            combinations.set_len(combination_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(combinations)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_supported_framebuffer_mixed_samples_combinations_nv`].
+    #[inline]
+    unsafe fn get_physical_device_supported_framebuffer_mixed_samples_combinations_nv_into(
+        &self,
+        physical_device: PhysicalDevice,
+        combinations: &mut Vec<FramebufferMixedSamplesCombinationNV>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut combination_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_supported_framebuffer_mixed_samples_combinations_nv)(
+                physical_device,
+                &mut combination_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            combinations.clear();
+            combinations.reserve(combination_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_supported_framebuffer_mixed_samples_combinations_nv)(
+                physical_device,
+                &mut combination_count,
+                combinations.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(combinations.capacity() >= combination_count as usize);
+                combinations.set_len(combination_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -10563,6 +19136,13 @@ pub trait NvCudaKernelLaunchExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         launch_info: &CudaLaunchInfoNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCudaLaunchKernelNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_cuda_launch_kernel_nv)(command_buffer, launch_info);
     }
 
@@ -10573,6 +19153,14 @@ pub trait NvCudaKernelLaunchExtensionDeviceCommands: DeviceV1_0 {
         create_info: &CudaFunctionCreateInfoNV,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<CudaFunctionNV> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateCudaFunctionNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut function = MaybeUninit::<CudaFunctionNV>::uninit();
 
         let __result = (self.commands().create_cuda_function_nv)(
@@ -10582,6 +19170,9 @@ pub trait NvCudaKernelLaunchExtensionDeviceCommands: DeviceV1_0 {
             function.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(function.assume_init())
         } else {
@@ -10596,6 +19187,14 @@ pub trait NvCudaKernelLaunchExtensionDeviceCommands: DeviceV1_0 {
         create_info: &CudaModuleCreateInfoNV,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<CudaModuleNV> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateCudaModuleNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut module = MaybeUninit::<CudaModuleNV>::uninit();
 
         let __result = (self.commands().create_cuda_module_nv)(
@@ -10605,6 +19204,9 @@ pub trait NvCudaKernelLaunchExtensionDeviceCommands: DeviceV1_0 {
             module.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(module.assume_init())
         } else {
@@ -10619,6 +19221,14 @@ pub trait NvCudaKernelLaunchExtensionDeviceCommands: DeviceV1_0 {
         function: CudaFunctionNV,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyCudaFunctionNV",
+            device = tracing::field::debug(self.handle()),
+            function = tracing::field::debug(function)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_cuda_function_nv)(
             self.handle(),
             function,
@@ -10633,6 +19243,14 @@ pub trait NvCudaKernelLaunchExtensionDeviceCommands: DeviceV1_0 {
         module: CudaModuleNV,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyCudaModuleNV",
+            device = tracing::field::debug(self.handle()),
+            module = tracing::field::debug(module)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_cuda_module_nv)(
             self.handle(),
             module,
@@ -10643,31 +19261,120 @@ pub trait NvCudaKernelLaunchExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetCudaModuleCacheNV.html>
     #[inline]
     unsafe fn get_cuda_module_cache_nv(&self, module: CudaModuleNV) -> crate::VkResult<Vec<u8>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetCudaModuleCacheNV",
+            device = tracing::field::debug(self.handle()),
+            module = tracing::field::debug(module),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut cache_size = 0;
 
-        (self.commands().get_cuda_module_cache_nv)(
-            self.handle(),
-            module,
-            &mut cache_size,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_cuda_module_cache_nv)(
+                self.handle(),
+                module,
+                &mut cache_size,
+                ptr::null_mut(),
+            );
 
-        let mut cache_data = Vec::with_capacity(cache_size as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_cuda_module_cache_nv)(
-            self.handle(),
-            module,
-            &mut cache_size,
-            cache_data.as_mut_ptr() as *mut c_void,
-        );
+            let mut cache_data = Vec::with_capacity(cache_size as usize);
 
-        debug_assert!(cache_data.capacity() >= cache_size as usize);
-        cache_data.set_len(cache_size as usize);
+            let __result = (self.commands().get_cuda_module_cache_nv)(
+                self.handle(),
+                module,
+                &mut cache_size,
+                cache_data.as_mut_ptr() as *mut c_void,
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(cache_data)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(cache_data.capacity() >= cache_size as usize);
+            cache_data.set_len(cache_size as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(cache_data)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetCudaModuleCacheNV.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_cuda_module_cache_nv`].
+    #[inline]
+    unsafe fn get_cuda_module_cache_nv_into(
+        &self,
+        module: CudaModuleNV,
+        cache_data: &mut Vec<u8>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetCudaModuleCacheNV",
+            device = tracing::field::debug(self.handle()),
+            module = tracing::field::debug(module),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut cache_size = 0;
+
+        loop {
+            let __result = (self.commands().get_cuda_module_cache_nv)(
+                self.handle(),
+                module,
+                &mut cache_size,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            cache_data.clear();
+            cache_data.reserve(cache_size as usize);
+
+            let __result = (self.commands().get_cuda_module_cache_nv)(
+                self.handle(),
+                module,
+                &mut cache_size,
+                cache_data.as_mut_ptr() as *mut c_void,
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(cache_data.capacity() >= cache_size as usize);
+                cache_data.set_len(cache_size as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -10684,12 +19391,26 @@ pub trait NvDeviceDiagnosticCheckpointsExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         checkpoint_marker: *const c_void,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCheckpointNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_checkpoint_nv)(command_buffer, checkpoint_marker);
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetQueueCheckpointData2NV.html>
     #[inline]
     unsafe fn get_queue_checkpoint_data2_nv(&self, queue: Queue) -> Vec<CheckpointData2NV> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetQueueCheckpointData2NV",
+            queue = tracing::field::debug(queue)
+        )
+        .entered();
+
         let mut checkpoint_data_count = 0;
 
         (self.commands().get_queue_checkpoint_data2_nv)(
@@ -10712,9 +19433,54 @@ pub trait NvDeviceDiagnosticCheckpointsExtensionDeviceCommands: DeviceV1_0 {
         checkpoint_data
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetQueueCheckpointData2NV.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_queue_checkpoint_data2_nv`].
+    #[inline]
+    unsafe fn get_queue_checkpoint_data2_nv_into(
+        &self,
+        queue: Queue,
+        checkpoint_data: &mut Vec<CheckpointData2NV>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetQueueCheckpointData2NV",
+            queue = tracing::field::debug(queue)
+        )
+        .entered();
+
+        let mut checkpoint_data_count = 0;
+
+        (self.commands().get_queue_checkpoint_data2_nv)(
+            queue,
+            &mut checkpoint_data_count,
+            ptr::null_mut(),
+        );
+
+        checkpoint_data.clear();
+        checkpoint_data.reserve(checkpoint_data_count as usize);
+
+        let __result = (self.commands().get_queue_checkpoint_data2_nv)(
+            queue,
+            &mut checkpoint_data_count,
+            checkpoint_data.as_mut_ptr(),
+        );
+
+        debug_assert!(checkpoint_data.capacity() >= checkpoint_data_count as usize);
+        checkpoint_data.set_len(checkpoint_data_count as usize);
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetQueueCheckpointDataNV.html>
     #[inline]
     unsafe fn get_queue_checkpoint_data_nv(&self, queue: Queue) -> Vec<CheckpointDataNV> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetQueueCheckpointDataNV",
+            queue = tracing::field::debug(queue)
+        )
+        .entered();
+
         let mut checkpoint_data_count = 0;
 
         (self.commands().get_queue_checkpoint_data_nv)(
@@ -10736,6 +19502,44 @@ pub trait NvDeviceDiagnosticCheckpointsExtensionDeviceCommands: DeviceV1_0 {
 
         checkpoint_data
     }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetQueueCheckpointDataNV.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_queue_checkpoint_data_nv`].
+    #[inline]
+    unsafe fn get_queue_checkpoint_data_nv_into(
+        &self,
+        queue: Queue,
+        checkpoint_data: &mut Vec<CheckpointDataNV>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetQueueCheckpointDataNV",
+            queue = tracing::field::debug(queue)
+        )
+        .entered();
+
+        let mut checkpoint_data_count = 0;
+
+        (self.commands().get_queue_checkpoint_data_nv)(
+            queue,
+            &mut checkpoint_data_count,
+            ptr::null_mut(),
+        );
+
+        checkpoint_data.clear();
+        checkpoint_data.reserve(checkpoint_data_count as usize);
+
+        let __result = (self.commands().get_queue_checkpoint_data_nv)(
+            queue,
+            &mut checkpoint_data_count,
+            checkpoint_data.as_mut_ptr(),
+        );
+
+        debug_assert!(checkpoint_data.capacity() >= checkpoint_data_count as usize);
+        checkpoint_data.set_len(checkpoint_data_count as usize);
+    }
 }
 
 impl<C: DeviceV1_0 + ?Sized> NvDeviceDiagnosticCheckpointsExtensionDeviceCommands for C {}
@@ -10751,6 +19555,14 @@ pub trait NvDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         pipeline: Pipeline,
         group_index: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindPipelineShaderGroupNV",
+            command_buffer = tracing::field::debug(command_buffer),
+            pipeline = tracing::field::debug(pipeline)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_pipeline_shader_group_nv)(
             command_buffer,
             pipeline_bind_point,
@@ -10767,6 +19579,13 @@ pub trait NvDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         is_preprocessed: bool,
         generated_commands_info: &GeneratedCommandsInfoNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdExecuteGeneratedCommandsNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_execute_generated_commands_nv)(
             command_buffer,
             is_preprocessed as Bool32,
@@ -10781,6 +19600,13 @@ pub trait NvDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         generated_commands_info: &GeneratedCommandsInfoNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPreprocessGeneratedCommandsNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_preprocess_generated_commands_nv)(
             command_buffer,
             generated_commands_info,
@@ -10794,6 +19620,14 @@ pub trait NvDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         create_info: &IndirectCommandsLayoutCreateInfoNV,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<IndirectCommandsLayoutNV> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateIndirectCommandsLayoutNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut indirect_commands_layout = MaybeUninit::<IndirectCommandsLayoutNV>::uninit();
 
         let __result = (self.commands().create_indirect_commands_layout_nv)(
@@ -10803,6 +19637,9 @@ pub trait NvDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
             indirect_commands_layout.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(indirect_commands_layout.assume_init())
         } else {
@@ -10817,6 +19654,14 @@ pub trait NvDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         indirect_commands_layout: IndirectCommandsLayoutNV,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyIndirectCommandsLayoutNV",
+            device = tracing::field::debug(self.handle()),
+            indirect_commands_layout = tracing::field::debug(indirect_commands_layout)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_indirect_commands_layout_nv)(
             self.handle(),
             indirect_commands_layout,
@@ -10831,6 +19676,13 @@ pub trait NvDeviceGeneratedCommandsExtensionDeviceCommands: DeviceV1_0 {
         info: &GeneratedCommandsMemoryRequirementsInfoNV,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetGeneratedCommandsMemoryRequirementsNV",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_generated_commands_memory_requirements_nv)(
@@ -10853,6 +19705,14 @@ pub trait NvDeviceGeneratedCommandsComputeExtensionDeviceCommands: DeviceV1_0 {
         pipeline_bind_point: PipelineBindPoint,
         pipeline: Pipeline,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdUpdatePipelineIndirectBufferNV",
+            command_buffer = tracing::field::debug(command_buffer),
+            pipeline = tracing::field::debug(pipeline)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_update_pipeline_indirect_buffer_nv)(
             command_buffer,
             pipeline_bind_point,
@@ -10866,6 +19726,13 @@ pub trait NvDeviceGeneratedCommandsComputeExtensionDeviceCommands: DeviceV1_0 {
         &self,
         info: &PipelineIndirectDeviceAddressInfoNV,
     ) -> DeviceAddress {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineIndirectDeviceAddressNV",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result =
             (self.commands().get_pipeline_indirect_device_address_nv)(self.handle(), info);
 
@@ -10879,6 +19746,13 @@ pub trait NvDeviceGeneratedCommandsComputeExtensionDeviceCommands: DeviceV1_0 {
         create_info: &ComputePipelineCreateInfo,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineIndirectMemoryRequirementsNV",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_pipeline_indirect_memory_requirements_nv)(
             self.handle(),
             create_info,
@@ -10898,6 +19772,14 @@ pub trait NvExternalComputeQueueExtensionDeviceCommands: DeviceV1_0 {
         create_info: &ExternalComputeQueueCreateInfoNV,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<ExternalComputeQueueNV> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateExternalComputeQueueNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut external_queue = MaybeUninit::<ExternalComputeQueueNV>::uninit();
 
         let __result = (self.commands().create_external_compute_queue_nv)(
@@ -10907,6 +19789,9 @@ pub trait NvExternalComputeQueueExtensionDeviceCommands: DeviceV1_0 {
             external_queue.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(external_queue.assume_init())
         } else {
@@ -10921,6 +19806,14 @@ pub trait NvExternalComputeQueueExtensionDeviceCommands: DeviceV1_0 {
         external_queue: ExternalComputeQueueNV,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyExternalComputeQueueNV",
+            device = tracing::field::debug(self.handle()),
+            external_queue = tracing::field::debug(external_queue)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_external_compute_queue_nv)(
             self.handle(),
             external_queue,
@@ -10936,6 +19829,13 @@ pub trait NvExternalComputeQueueExtensionDeviceCommands: DeviceV1_0 {
         params: &mut ExternalComputeQueueDataParamsNV,
         data: *mut c_void,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetExternalComputeQueueDataNV",
+            external_queue = tracing::field::debug(external_queue)
+        )
+        .entered();
+
         let __result =
             (self.commands().get_external_compute_queue_data_nv)(external_queue, params, data);
     }
@@ -10957,6 +19857,14 @@ pub trait NvExternalMemoryCapabilitiesExtensionInstanceCommands: InstanceV1_0 {
         flags: ImageCreateFlags,
         external_handle_type: ExternalMemoryHandleTypeFlagsNV,
     ) -> crate::VkResult<ExternalImageFormatPropertiesNV> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceExternalImageFormatPropertiesNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut external_image_format_properties =
             MaybeUninit::<ExternalImageFormatPropertiesNV>::uninit();
 
@@ -10973,6 +19881,9 @@ pub trait NvExternalMemoryCapabilitiesExtensionInstanceCommands: InstanceV1_0 {
             external_image_format_properties.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(external_image_format_properties.assume_init())
         } else {
@@ -10992,6 +19903,14 @@ pub trait NvExternalMemoryRdmaExtensionDeviceCommands: DeviceV1_0 {
         &self,
         memory_get_remote_address_info: &MemoryGetRemoteAddressInfoNV,
     ) -> crate::VkResult<RemoteAddressNV> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryRemoteAddressNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut address = MaybeUninit::<RemoteAddressNV>::uninit();
 
         let __result = (self.commands().get_memory_remote_address_nv)(
@@ -11000,6 +19919,9 @@ pub trait NvExternalMemoryRdmaExtensionDeviceCommands: DeviceV1_0 {
             address.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(address.assume_init())
         } else {
@@ -11018,6 +19940,14 @@ pub trait NvExternalMemorySciBufExtensionDeviceCommands: DeviceV1_0 {
         &self,
         get_sci_buf_info: &MemoryGetSciBufInfoNV,
     ) -> crate::VkResult<NvSciBufObj> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemorySciBufNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut handle = MaybeUninit::<NvSciBufObj>::uninit();
 
         let __result = (self.commands().get_memory_sci_buf_nv)(
@@ -11026,6 +19956,9 @@ pub trait NvExternalMemorySciBufExtensionDeviceCommands: DeviceV1_0 {
             handle.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(handle.assume_init())
         } else {
@@ -11047,6 +19980,14 @@ pub trait NvExternalMemorySciBufExtensionInstanceCommands: InstanceV1_0 {
         handle: NvSciBufObj,
         memory_sci_buf_properties: &mut MemorySciBufPropertiesNV,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceExternalMemorySciBufPropertiesNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_external_memory_sci_buf_properties_nv)(
@@ -11056,6 +19997,9 @@ pub trait NvExternalMemorySciBufExtensionInstanceCommands: InstanceV1_0 {
             memory_sci_buf_properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11070,11 +20014,22 @@ pub trait NvExternalMemorySciBufExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         attributes: NvSciBufAttrList,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSciBufAttributesNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_physical_device_sci_buf_attributes_nv)(
             physical_device,
             attributes,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11094,6 +20049,15 @@ pub trait NvExternalMemoryWin32ExtensionDeviceCommands: DeviceV1_0 {
         memory: DeviceMemory,
         handle_type: ExternalMemoryHandleTypeFlagsNV,
     ) -> crate::VkResult<HANDLE> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryWin32HandleNV",
+            device = tracing::field::debug(self.handle()),
+            memory = tracing::field::debug(memory),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut handle = MaybeUninit::<HANDLE>::uninit();
 
         let __result = (self.commands().get_memory_win32_handle_nv)(
@@ -11103,6 +20067,9 @@ pub trait NvExternalMemoryWin32ExtensionDeviceCommands: DeviceV1_0 {
             handle.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(handle.assume_init())
         } else {
@@ -11123,12 +20090,23 @@ pub trait NvExternalSciSyncExtensionDeviceCommands: DeviceV1_0 {
         get_sci_sync_handle_info: &FenceGetSciSyncInfoNV,
         handle: *mut c_void,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetFenceSciSyncFenceNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_fence_sci_sync_fence_nv)(
             self.handle(),
             get_sci_sync_handle_info,
             handle,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11143,12 +20121,23 @@ pub trait NvExternalSciSyncExtensionDeviceCommands: DeviceV1_0 {
         get_sci_sync_handle_info: &FenceGetSciSyncInfoNV,
         handle: *mut c_void,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetFenceSciSyncObjNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_fence_sci_sync_obj_nv)(
             self.handle(),
             get_sci_sync_handle_info,
             handle,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11163,12 +20152,23 @@ pub trait NvExternalSciSyncExtensionDeviceCommands: DeviceV1_0 {
         get_sci_sync_info: &SemaphoreGetSciSyncInfoNV,
         handle: *mut c_void,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSemaphoreSciSyncObjNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_semaphore_sci_sync_obj_nv)(
             self.handle(),
             get_sci_sync_info,
             handle,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11182,11 +20182,22 @@ pub trait NvExternalSciSyncExtensionDeviceCommands: DeviceV1_0 {
         &self,
         import_fence_sci_sync_info: &ImportFenceSciSyncInfoNV,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkImportFenceSciSyncFenceNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().import_fence_sci_sync_fence_nv)(
             self.handle(),
             import_fence_sci_sync_info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11200,11 +20211,22 @@ pub trait NvExternalSciSyncExtensionDeviceCommands: DeviceV1_0 {
         &self,
         import_fence_sci_sync_info: &ImportFenceSciSyncInfoNV,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkImportFenceSciSyncObjNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().import_fence_sci_sync_obj_nv)(
             self.handle(),
             import_fence_sci_sync_info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11218,11 +20240,22 @@ pub trait NvExternalSciSyncExtensionDeviceCommands: DeviceV1_0 {
         &self,
         import_semaphore_sci_sync_info: &ImportSemaphoreSciSyncInfoNV,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkImportSemaphoreSciSyncObjNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().import_semaphore_sci_sync_obj_nv)(
             self.handle(),
             import_semaphore_sci_sync_info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11244,12 +20277,23 @@ pub trait NvExternalSciSyncExtensionInstanceCommands: InstanceV1_0 {
         sci_sync_attributes_info: &SciSyncAttributesInfoNV,
         attributes: NvSciSyncAttrList,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSciSyncAttributesNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_physical_device_sci_sync_attributes_nv)(
             physical_device,
             sci_sync_attributes_info,
             attributes,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11270,6 +20314,14 @@ pub trait NvExternalSciSync2ExtensionDeviceCommands: DeviceV1_0 {
         create_info: &SemaphoreSciSyncPoolCreateInfoNV,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SemaphoreSciSyncPoolNV> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateSemaphoreSciSyncPoolNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut semaphore_pool = MaybeUninit::<SemaphoreSciSyncPoolNV>::uninit();
 
         let __result = (self.commands().create_semaphore_sci_sync_pool_nv)(
@@ -11279,6 +20331,9 @@ pub trait NvExternalSciSync2ExtensionDeviceCommands: DeviceV1_0 {
             semaphore_pool.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(semaphore_pool.assume_init())
         } else {
@@ -11293,6 +20348,14 @@ pub trait NvExternalSciSync2ExtensionDeviceCommands: DeviceV1_0 {
         semaphore_pool: SemaphoreSciSyncPoolNV,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroySemaphoreSciSyncPoolNV",
+            device = tracing::field::debug(self.handle()),
+            semaphore_pool = tracing::field::debug(semaphore_pool)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_semaphore_sci_sync_pool_nv)(
             self.handle(),
             semaphore_pool,
@@ -11307,12 +20370,23 @@ pub trait NvExternalSciSync2ExtensionDeviceCommands: DeviceV1_0 {
         get_sci_sync_handle_info: &FenceGetSciSyncInfoNV,
         handle: *mut c_void,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetFenceSciSyncFenceNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_fence_sci_sync_fence_nv)(
             self.handle(),
             get_sci_sync_handle_info,
             handle,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11327,12 +20401,23 @@ pub trait NvExternalSciSync2ExtensionDeviceCommands: DeviceV1_0 {
         get_sci_sync_handle_info: &FenceGetSciSyncInfoNV,
         handle: *mut c_void,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetFenceSciSyncObjNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_fence_sci_sync_obj_nv)(
             self.handle(),
             get_sci_sync_handle_info,
             handle,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11346,11 +20431,22 @@ pub trait NvExternalSciSync2ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         import_fence_sci_sync_info: &ImportFenceSciSyncInfoNV,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkImportFenceSciSyncFenceNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().import_fence_sci_sync_fence_nv)(
             self.handle(),
             import_fence_sci_sync_info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11364,11 +20460,22 @@ pub trait NvExternalSciSync2ExtensionDeviceCommands: DeviceV1_0 {
         &self,
         import_fence_sci_sync_info: &ImportFenceSciSyncInfoNV,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkImportFenceSciSyncObjNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().import_fence_sci_sync_obj_nv)(
             self.handle(),
             import_fence_sci_sync_info,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11389,12 +20496,23 @@ pub trait NvExternalSciSync2ExtensionInstanceCommands: InstanceV1_0 {
         sci_sync_attributes_info: &SciSyncAttributesInfoNV,
         attributes: NvSciSyncAttrList,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSciSyncAttributesNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_physical_device_sci_sync_attributes_nv)(
             physical_device,
             sci_sync_attributes_info,
             attributes,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11415,6 +20533,13 @@ pub trait NvFragmentShadingRateEnumsExtensionDeviceCommands: DeviceV1_0 {
         shading_rate: FragmentShadingRateNV,
         combiner_ops: [FragmentShadingRateCombinerOpKHR; 2],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetFragmentShadingRateEnumNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_fragment_shading_rate_enum_nv)(
             command_buffer,
             shading_rate,
@@ -11434,6 +20559,14 @@ pub trait NvLowLatency2ExtensionDeviceCommands: DeviceV1_0 {
         swapchain: SwapchainKHR,
         latency_marker_info: &mut GetLatencyMarkerInfoNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetLatencyTimingsNV",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain)
+        )
+        .entered();
+
         let __result =
             (self.commands().get_latency_timings_nv)(self.handle(), swapchain, latency_marker_info);
     }
@@ -11445,8 +20578,20 @@ pub trait NvLowLatency2ExtensionDeviceCommands: DeviceV1_0 {
         swapchain: SwapchainKHR,
         sleep_info: &LatencySleepInfoNV,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkLatencySleepNV",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().latency_sleep_nv)(self.handle(), swapchain, sleep_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11461,6 +20606,13 @@ pub trait NvLowLatency2ExtensionDeviceCommands: DeviceV1_0 {
         queue: Queue,
         queue_type_info: &OutOfBandQueueTypeInfoNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkQueueNotifyOutOfBandNV",
+            queue = tracing::field::debug(queue)
+        )
+        .entered();
+
         let __result = (self.commands().queue_notify_out_of_band_nv)(queue, queue_type_info);
     }
 
@@ -11471,6 +20623,14 @@ pub trait NvLowLatency2ExtensionDeviceCommands: DeviceV1_0 {
         swapchain: SwapchainKHR,
         latency_marker_info: &SetLatencyMarkerInfoNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetLatencyMarkerNV",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain)
+        )
+        .entered();
+
         let __result =
             (self.commands().set_latency_marker_nv)(self.handle(), swapchain, latency_marker_info);
     }
@@ -11482,9 +20642,21 @@ pub trait NvLowLatency2ExtensionDeviceCommands: DeviceV1_0 {
         swapchain: SwapchainKHR,
         sleep_mode_info: &LatencySleepModeInfoNV,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetLatencySleepModeNV",
+            device = tracing::field::debug(self.handle()),
+            swapchain = tracing::field::debug(swapchain),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().set_latency_sleep_mode_nv)(self.handle(), swapchain, sleep_mode_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11506,6 +20678,13 @@ pub trait NvMemoryDecompressionExtensionDeviceCommands: DeviceV1_0 {
         indirect_commands_count_address: DeviceAddress,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDecompressMemoryIndirectCountNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_decompress_memory_indirect_count_nv)(
             command_buffer,
             indirect_commands_address,
@@ -11521,6 +20700,13 @@ pub trait NvMemoryDecompressionExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         decompress_memory_regions: &[impl Cast<Target = DecompressMemoryRegionNV>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDecompressMemoryNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_decompress_memory_nv)(
             command_buffer,
             decompress_memory_regions.len() as u32,
@@ -11545,6 +20731,15 @@ pub trait NvMeshShaderExtensionDeviceCommands: DeviceV1_0 {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawMeshTasksIndirectCountNV",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer),
+            count_buffer = tracing::field::debug(count_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_mesh_tasks_indirect_count_nv)(
             command_buffer,
             buffer,
@@ -11566,6 +20761,14 @@ pub trait NvMeshShaderExtensionDeviceCommands: DeviceV1_0 {
         draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawMeshTasksIndirectNV",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_mesh_tasks_indirect_nv)(
             command_buffer,
             buffer,
@@ -11583,6 +20786,13 @@ pub trait NvMeshShaderExtensionDeviceCommands: DeviceV1_0 {
         task_count: u32,
         first_task: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawMeshTasksNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_draw_mesh_tasks_nv)(command_buffer, task_count, first_task);
     }
@@ -11601,6 +20811,16 @@ pub trait NvOpticalFlowExtensionDeviceCommands: DeviceV1_0 {
         view: ImageView,
         layout: ImageLayout,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBindOpticalFlowSessionImageNV",
+            device = tracing::field::debug(self.handle()),
+            session = tracing::field::debug(session),
+            view = tracing::field::debug(view),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().bind_optical_flow_session_image_nv)(
             self.handle(),
             session,
@@ -11609,6 +20829,9 @@ pub trait NvOpticalFlowExtensionDeviceCommands: DeviceV1_0 {
             layout,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11624,6 +20847,14 @@ pub trait NvOpticalFlowExtensionDeviceCommands: DeviceV1_0 {
         session: OpticalFlowSessionNV,
         execute_info: &OpticalFlowExecuteInfoNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdOpticalFlowExecuteNV",
+            command_buffer = tracing::field::debug(command_buffer),
+            session = tracing::field::debug(session)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_optical_flow_execute_nv)(command_buffer, session, execute_info);
     }
@@ -11635,6 +20866,14 @@ pub trait NvOpticalFlowExtensionDeviceCommands: DeviceV1_0 {
         create_info: &OpticalFlowSessionCreateInfoNV,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<OpticalFlowSessionNV> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateOpticalFlowSessionNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut session = MaybeUninit::<OpticalFlowSessionNV>::uninit();
 
         let __result = (self.commands().create_optical_flow_session_nv)(
@@ -11644,6 +20883,9 @@ pub trait NvOpticalFlowExtensionDeviceCommands: DeviceV1_0 {
             session.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(session.assume_init())
         } else {
@@ -11658,6 +20900,14 @@ pub trait NvOpticalFlowExtensionDeviceCommands: DeviceV1_0 {
         session: OpticalFlowSessionNV,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyOpticalFlowSessionNV",
+            device = tracing::field::debug(self.handle()),
+            session = tracing::field::debug(session)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_optical_flow_session_nv)(
             self.handle(),
             session,
@@ -11677,35 +20927,127 @@ pub trait NvOpticalFlowExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         optical_flow_image_format_info: &OpticalFlowImageFormatInfoNV,
     ) -> crate::VkResult<Vec<OpticalFlowImageFormatPropertiesNV>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceOpticalFlowImageFormatsNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut format_count = 0;
 
-        (self
-            .commands()
-            .get_physical_device_optical_flow_image_formats_nv)(
-            physical_device,
-            optical_flow_image_format_info,
-            &mut format_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_optical_flow_image_formats_nv)(
+                physical_device,
+                optical_flow_image_format_info,
+                &mut format_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut image_format_properties = Vec::with_capacity(format_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_optical_flow_image_formats_nv)(
+                physical_device,
+                optical_flow_image_format_info,
+                &mut format_count,
+                image_format_properties.as_mut_ptr(),
+            );
 
-        let mut image_format_properties = Vec::with_capacity(format_count as usize);
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
 
-        let __result = (self
-            .commands()
-            .get_physical_device_optical_flow_image_formats_nv)(
-            physical_device,
-            optical_flow_image_format_info,
-            &mut format_count,
-            image_format_properties.as_mut_ptr(),
-        );
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
 
-        debug_assert!(image_format_properties.capacity() >= format_count as usize);
-        image_format_properties.set_len(format_count as usize);
+            debug_assert!(image_format_properties.capacity() >= format_count as usize);
+            image_format_properties.set_len(format_count as usize);
 
-        if __result == Result::SUCCESS {
-            Ok(image_format_properties)
-        } else {
-            Err(__result.into())
+            break if __result == Result::SUCCESS {
+                Ok(image_format_properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceOpticalFlowImageFormatsNV.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_optical_flow_image_formats_nv`].
+    #[inline]
+    unsafe fn get_physical_device_optical_flow_image_formats_nv_into(
+        &self,
+        physical_device: PhysicalDevice,
+        optical_flow_image_format_info: &OpticalFlowImageFormatInfoNV,
+        image_format_properties: &mut Vec<OpticalFlowImageFormatPropertiesNV>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceOpticalFlowImageFormatsNV",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut format_count = 0;
+
+        loop {
+            let __result = (self
+                .commands()
+                .get_physical_device_optical_flow_image_formats_nv)(
+                physical_device,
+                optical_flow_image_format_info,
+                &mut format_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            image_format_properties.clear();
+            image_format_properties.reserve(format_count as usize);
+
+            let __result = (self
+                .commands()
+                .get_physical_device_optical_flow_image_formats_nv)(
+                physical_device,
+                optical_flow_image_format_info,
+                &mut format_count,
+                image_format_properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(image_format_properties.capacity() >= format_count as usize);
+                image_format_properties.set_len(format_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -11721,6 +21063,13 @@ pub trait NvPartitionedAccelerationStructureExtensionDeviceCommands: DeviceV1_0
         command_buffer: CommandBuffer,
         build_info: &BuildPartitionedAccelerationStructureInfoNV,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBuildPartitionedAccelerationStructuresNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .cmd_build_partitioned_acceleration_structures_nv)(
@@ -11735,6 +21084,13 @@ pub trait NvPartitionedAccelerationStructureExtensionDeviceCommands: DeviceV1_0
         info: &PartitionedAccelerationStructureInstancesInputNV,
         size_info: &mut AccelerationStructureBuildSizesInfoKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPartitionedAccelerationStructuresBuildSizesNV",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_partitioned_acceleration_structures_build_sizes_nv)(
@@ -11755,12 +21111,23 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
         &self,
         bind_infos: &[impl Cast<Target = BindAccelerationStructureMemoryInfoNV>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBindAccelerationStructureMemoryNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().bind_acceleration_structure_memory_nv)(
             self.handle(),
             bind_infos.len() as u32,
             bind_infos.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11782,6 +21149,17 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
         scratch: Buffer,
         scratch_offset: DeviceSize,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBuildAccelerationStructureNV",
+            command_buffer = tracing::field::debug(command_buffer),
+            instance_data = tracing::field::debug(instance_data),
+            dst = tracing::field::debug(dst),
+            src = tracing::field::debug(src),
+            scratch = tracing::field::debug(scratch)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_build_acceleration_structure_nv)(
             command_buffer,
             info,
@@ -11804,6 +21182,15 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
         src: AccelerationStructureNV,
         mode: CopyAccelerationStructureModeKHR,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyAccelerationStructureNV",
+            command_buffer = tracing::field::debug(command_buffer),
+            dst = tracing::field::debug(dst),
+            src = tracing::field::debug(src)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_copy_acceleration_structure_nv)(command_buffer, dst, src, mode);
     }
@@ -11828,6 +21215,21 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
         height: u32,
         depth: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdTraceRaysNV",
+            command_buffer = tracing::field::debug(command_buffer),
+            raygen_shader_binding_table_buffer =
+                tracing::field::debug(raygen_shader_binding_table_buffer),
+            miss_shader_binding_table_buffer =
+                tracing::field::debug(miss_shader_binding_table_buffer),
+            hit_shader_binding_table_buffer =
+                tracing::field::debug(hit_shader_binding_table_buffer),
+            callable_shader_binding_table_buffer =
+                tracing::field::debug(callable_shader_binding_table_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_trace_rays_nv)(
             command_buffer,
             raygen_shader_binding_table_buffer,
@@ -11857,6 +21259,14 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
         query_pool: QueryPool,
         first_query: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdWriteAccelerationStructuresPropertiesNV",
+            command_buffer = tracing::field::debug(command_buffer),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .cmd_write_acceleration_structures_properties_nv)(
@@ -11872,8 +21282,20 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCompileDeferredNV.html>
     #[inline]
     unsafe fn compile_deferred_nv(&self, pipeline: Pipeline, shader: u32) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCompileDeferredNV",
+            device = tracing::field::debug(self.handle()),
+            pipeline = tracing::field::debug(pipeline),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().compile_deferred_nv)(self.handle(), pipeline, shader);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11888,6 +21310,14 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
         create_info: &AccelerationStructureCreateInfoNV,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<AccelerationStructureNV> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateAccelerationStructureNV",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut acceleration_structure = MaybeUninit::<AccelerationStructureNV>::uninit();
 
         let __result = (self.commands().create_acceleration_structure_nv)(
@@ -11897,6 +21327,9 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
             acceleration_structure.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(acceleration_structure.assume_init())
         } else {
@@ -11912,6 +21345,15 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
         create_infos: &[impl Cast<Target = RayTracingPipelineCreateInfoNV>],
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkSuccessResult<Vec<Pipeline>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateRayTracingPipelinesNV",
+            device = tracing::field::debug(self.handle()),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut pipelines = Vec::with_capacity(create_infos.len() as usize);
 
         let __result = (self.commands().create_ray_tracing_pipelines_nv)(
@@ -11923,6 +21365,9 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
             pipelines.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         pipelines.set_len(create_infos.len() as usize);
 
         if __result >= Result::SUCCESS {
@@ -11932,6 +21377,53 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
         }
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCreateRayTracingPipelinesNV.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::create_ray_tracing_pipelines_nv`].
+    #[inline]
+    unsafe fn create_ray_tracing_pipelines_nv_into(
+        &self,
+        pipeline_cache: PipelineCache,
+        create_infos: &[impl Cast<Target = RayTracingPipelineCreateInfoNV>],
+        allocator: Option<&AllocationCallbacks>,
+        pipelines: &mut Vec<Pipeline>,
+    ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateRayTracingPipelinesNV",
+            device = tracing::field::debug(self.handle()),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        pipelines.clear();
+        pipelines.reserve(create_infos.len() as usize);
+
+        let __result = (self.commands().create_ray_tracing_pipelines_nv)(
+            self.handle(),
+            pipeline_cache,
+            create_infos.len() as u32,
+            create_infos.as_ptr().cast(),
+            allocator.map_or(ptr::null(), |v| v),
+            pipelines.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            pipelines.set_len(create_infos.len() as usize);
+        }
+
+        if __result >= Result::SUCCESS {
+            Ok(__result.into())
+        } else {
+            Err(__result.into())
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkDestroyAccelerationStructureNV.html>
     #[inline]
     unsafe fn destroy_acceleration_structure_nv(
@@ -11939,6 +21431,14 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
         acceleration_structure: AccelerationStructureNV,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyAccelerationStructureNV",
+            device = tracing::field::debug(self.handle()),
+            acceleration_structure = tracing::field::debug(acceleration_structure)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_acceleration_structure_nv)(
             self.handle(),
             acceleration_structure,
@@ -11953,6 +21453,15 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
         acceleration_structure: AccelerationStructureNV,
         data: &mut [u8],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetAccelerationStructureHandleNV",
+            device = tracing::field::debug(self.handle()),
+            acceleration_structure = tracing::field::debug(acceleration_structure),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_acceleration_structure_handle_nv)(
             self.handle(),
             acceleration_structure,
@@ -11960,6 +21469,9 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
             data.as_mut_ptr() as *mut c_void,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -11973,6 +21485,13 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
         &self,
         info: &AccelerationStructureMemoryRequirementsInfoNV,
     ) -> MemoryRequirements2KHR {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetAccelerationStructureMemoryRequirementsNV",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut memory_requirements = MaybeUninit::<MemoryRequirements2KHR>::uninit();
 
         let __result = (self
@@ -11995,6 +21514,15 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
         group_count: u32,
         data: &mut [u8],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetRayTracingShaderGroupHandlesNV",
+            device = tracing::field::debug(self.handle()),
+            pipeline = tracing::field::debug(pipeline),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_ray_tracing_shader_group_handles_nv)(
             self.handle(),
             pipeline,
@@ -12004,6 +21532,9 @@ pub trait NvRayTracingExtensionDeviceCommands: DeviceV1_0 {
             data.as_mut_ptr() as *mut c_void,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -12025,6 +21556,13 @@ pub trait NvScissorExclusiveExtensionDeviceCommands: DeviceV1_0 {
         first_exclusive_scissor: u32,
         exclusive_scissor_enables: &[Bool32],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetExclusiveScissorEnableNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_exclusive_scissor_enable_nv)(
             command_buffer,
             first_exclusive_scissor,
@@ -12041,6 +21579,13 @@ pub trait NvScissorExclusiveExtensionDeviceCommands: DeviceV1_0 {
         first_exclusive_scissor: u32,
         exclusive_scissors: &[impl Cast<Target = Rect2D>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetExclusiveScissorNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_exclusive_scissor_nv)(
             command_buffer,
             first_exclusive_scissor,
@@ -12062,6 +21607,14 @@ pub trait NvShadingRateImageExtensionDeviceCommands: DeviceV1_0 {
         image_view: ImageView,
         image_layout: ImageLayout,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindShadingRateImageNV",
+            command_buffer = tracing::field::debug(command_buffer),
+            image_view = tracing::field::debug(image_view)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_shading_rate_image_nv)(
             command_buffer,
             image_view,
@@ -12077,6 +21630,13 @@ pub trait NvShadingRateImageExtensionDeviceCommands: DeviceV1_0 {
         sample_order_type: CoarseSampleOrderTypeNV,
         custom_sample_orders: &[impl Cast<Target = CoarseSampleOrderCustomNV>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCoarseSampleOrderNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_coarse_sample_order_nv)(
             command_buffer,
             sample_order_type,
@@ -12093,6 +21653,13 @@ pub trait NvShadingRateImageExtensionDeviceCommands: DeviceV1_0 {
         first_viewport: u32,
         shading_rate_palettes: &[impl Cast<Target = ShadingRatePaletteNV>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetViewportShadingRatePaletteNV",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_viewport_shading_rate_palette_nv)(
             command_buffer,
             first_viewport,
@@ -12112,6 +21679,14 @@ pub trait OhosExternalMemoryExtensionDeviceCommands: DeviceV1_0 {
         &self,
         info: &MemoryGetNativeBufferInfoOHOS,
     ) -> crate::VkResult<*mut OH_NativeBuffer> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetMemoryNativeBufferOHOS",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut buffer = MaybeUninit::<*mut OH_NativeBuffer>::uninit();
 
         let __result = (self.commands().get_memory_native_buffer_ohos)(
@@ -12120,6 +21695,9 @@ pub trait OhosExternalMemoryExtensionDeviceCommands: DeviceV1_0 {
             buffer.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(buffer.assume_init())
         } else {
@@ -12134,9 +21712,20 @@ pub trait OhosExternalMemoryExtensionDeviceCommands: DeviceV1_0 {
         buffer: &OH_NativeBuffer,
         properties: &mut NativeBufferPropertiesOHOS,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetNativeBufferPropertiesOHOS",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().get_native_buffer_properties_ohos)(self.handle(), buffer, properties);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -12156,6 +21745,14 @@ pub trait OhosSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &SurfaceCreateInfoOHOS,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateSurfaceOHOS",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_surface_ohos)(
@@ -12165,6 +21762,9 @@ pub trait OhosSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -12184,6 +21784,13 @@ pub trait QcomTileMemoryHeapExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         tile_memory_bind_info: Option<&TileMemoryBindInfoQCOM>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindTileMemoryQCOM",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_tile_memory_qcom)(
             command_buffer,
             tile_memory_bind_info.map_or(ptr::null(), |v| v),
@@ -12202,12 +21809,23 @@ pub trait QcomTilePropertiesExtensionDeviceCommands: DeviceV1_0 {
         rendering_info: &RenderingInfo,
         properties: &mut TilePropertiesQCOM,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDynamicRenderingTilePropertiesQCOM",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_dynamic_rendering_tile_properties_qcom)(
             self.handle(),
             rendering_info,
             properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -12221,31 +21839,120 @@ pub trait QcomTilePropertiesExtensionDeviceCommands: DeviceV1_0 {
         &self,
         framebuffer: Framebuffer,
     ) -> crate::VkResult<Vec<TilePropertiesQCOM>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetFramebufferTilePropertiesQCOM",
+            device = tracing::field::debug(self.handle()),
+            framebuffer = tracing::field::debug(framebuffer),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut properties_count = 0;
 
-        (self.commands().get_framebuffer_tile_properties_qcom)(
-            self.handle(),
-            framebuffer,
-            &mut properties_count,
-            ptr::null_mut(),
-        );
+        loop {
+            let __result = (self.commands().get_framebuffer_tile_properties_qcom)(
+                self.handle(),
+                framebuffer,
+                &mut properties_count,
+                ptr::null_mut(),
+            );
 
-        let mut properties = Vec::with_capacity(properties_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().get_framebuffer_tile_properties_qcom)(
-            self.handle(),
-            framebuffer,
-            &mut properties_count,
-            properties.as_mut_ptr(),
-        );
+            let mut properties = Vec::with_capacity(properties_count as usize);
 
-        debug_assert!(properties.capacity() >= properties_count as usize);
-        properties.set_len(properties_count as usize);
+            let __result = (self.commands().get_framebuffer_tile_properties_qcom)(
+                self.handle(),
+                framebuffer,
+                &mut properties_count,
+                properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= properties_count as usize);
+            properties.set_len(properties_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetFramebufferTilePropertiesQCOM.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_framebuffer_tile_properties_qcom`].
+    #[inline]
+    unsafe fn get_framebuffer_tile_properties_qcom_into(
+        &self,
+        framebuffer: Framebuffer,
+        properties: &mut Vec<TilePropertiesQCOM>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetFramebufferTilePropertiesQCOM",
+            device = tracing::field::debug(self.handle()),
+            framebuffer = tracing::field::debug(framebuffer),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut properties_count = 0;
+
+        loop {
+            let __result = (self.commands().get_framebuffer_tile_properties_qcom)(
+                self.handle(),
+                framebuffer,
+                &mut properties_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(properties_count as usize);
+
+            let __result = (self.commands().get_framebuffer_tile_properties_qcom)(
+                self.handle(),
+                framebuffer,
+                &mut properties_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= properties_count as usize);
+                properties.set_len(properties_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -12261,6 +21968,13 @@ pub trait QcomTileShadingExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         per_tile_begin_info: &PerTileBeginInfoQCOM,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginPerTileExecutionQCOM",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_begin_per_tile_execution_qcom)(
             command_buffer,
             per_tile_begin_info,
@@ -12274,6 +21988,13 @@ pub trait QcomTileShadingExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         dispatch_tile_info: &DispatchTileInfoQCOM,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDispatchTileQCOM",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_dispatch_tile_qcom)(command_buffer, dispatch_tile_info);
     }
 
@@ -12284,6 +22005,13 @@ pub trait QcomTileShadingExtensionDeviceCommands: DeviceV1_0 {
         command_buffer: CommandBuffer,
         per_tile_end_info: &PerTileEndInfoQCOM,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndPerTileExecutionQCOM",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_end_per_tile_execution_qcom)(command_buffer, per_tile_end_info);
     }
@@ -12300,9 +22028,20 @@ pub trait QnxExternalMemoryScreenBufferExtensionDeviceCommands: DeviceV1_0 {
         buffer: &_screen_buffer,
         properties: &mut ScreenBufferPropertiesQNX,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetScreenBufferPropertiesQNX",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().get_screen_buffer_properties_qnx)(self.handle(), buffer, properties);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -12322,6 +22061,14 @@ pub trait QnxScreenSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &ScreenSurfaceCreateInfoQNX,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateScreenSurfaceQNX",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_screen_surface_qnx)(
@@ -12331,6 +22078,9 @@ pub trait QnxScreenSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -12346,6 +22096,13 @@ pub trait QnxScreenSurfaceExtensionInstanceCommands: InstanceV1_0 {
         queue_family_index: u32,
         window: *mut _screen_window,
     ) -> Bool32 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceScreenPresentationSupportQNX",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_screen_presentation_support_qnx)(
@@ -12369,6 +22126,14 @@ pub trait SecUbmSurfaceExtensionInstanceCommands: InstanceV1_0 {
         create_info: &UbmSurfaceCreateInfoSEC,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SurfaceKHR> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateUbmSurfaceSEC",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut surface = MaybeUninit::<SurfaceKHR>::uninit();
 
         let __result = (self.commands().create_ubm_surface_sec)(
@@ -12378,6 +22143,9 @@ pub trait SecUbmSurfaceExtensionInstanceCommands: InstanceV1_0 {
             surface.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(surface.assume_init())
         } else {
@@ -12392,6 +22160,13 @@ pub trait SecUbmSurfaceExtensionInstanceCommands: InstanceV1_0 {
         physical_device: PhysicalDevice,
         queue_family_index: u32,
     ) -> (Bool32, ubm_device) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceUbmPresentationSupportSEC",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut device = MaybeUninit::<ubm_device>::uninit();
 
         let __result = (self
@@ -12416,6 +22191,14 @@ pub trait ValveDescriptorSetHostMappingExtensionDeviceCommands: DeviceV1_0 {
         &self,
         descriptor_set: DescriptorSet,
     ) -> *mut c_void {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDescriptorSetHostMappingVALVE",
+            device = tracing::field::debug(self.handle()),
+            descriptor_set = tracing::field::debug(descriptor_set)
+        )
+        .entered();
+
         let mut data = MaybeUninit::<*mut c_void>::uninit();
 
         let __result = (self.commands().get_descriptor_set_host_mapping_valve)(
@@ -12434,6 +22217,13 @@ pub trait ValveDescriptorSetHostMappingExtensionDeviceCommands: DeviceV1_0 {
         binding_reference: &DescriptorSetBindingReferenceVALVE,
         host_mapping: &mut DescriptorSetLayoutHostMappingInfoVALVE,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDescriptorSetLayoutHostMappingInfoVALVE",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_descriptor_set_layout_host_mapping_info_valve)(
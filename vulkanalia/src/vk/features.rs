@@ -0,0 +1,6451 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// DO NOT EDIT.
+//
+// This file has been generated by the Kotlin project in the `generator`
+// directory from a Vulkan API registry.
+
+#![allow(
+    non_camel_case_types,
+    non_snake_case,
+    clippy::bad_bit_mask,
+    clippy::let_and_return,
+    clippy::let_unit_value,
+    clippy::missing_safety_doc,
+    clippy::missing_transmute_annotations,
+    clippy::needless_lifetimes,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unnecessary_cast,
+    clippy::upper_case_acronyms,
+    clippy::useless_transmute
+)]
+
+use alloc::collections::btree_set::BTreeSet;
+use core::{mem, ptr, slice};
+
+use super::*;
+use crate::features::{FeatureSet, Features};
+use crate::Version;
+
+unsafe impl Features for PhysicalDeviceFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceFeatures";
+    const NAMES: &'static [&'static str] = &[
+        "robust_buffer_access",
+        "full_draw_index_uint32",
+        "image_cube_array",
+        "independent_blend",
+        "geometry_shader",
+        "tessellation_shader",
+        "sample_rate_shading",
+        "dual_src_blend",
+        "logic_op",
+        "multi_draw_indirect",
+        "draw_indirect_first_instance",
+        "depth_clamp",
+        "depth_bias_clamp",
+        "fill_mode_non_solid",
+        "depth_bounds",
+        "wide_lines",
+        "large_points",
+        "alpha_to_one",
+        "multi_viewport",
+        "sampler_anisotropy",
+        "texture_compression_etc2",
+        "texture_compression_astc_ldr",
+        "texture_compression_bc",
+        "occlusion_query_precise",
+        "pipeline_statistics_query",
+        "vertex_pipeline_stores_and_atomics",
+        "fragment_stores_and_atomics",
+        "shader_tessellation_and_geometry_point_size",
+        "shader_image_gather_extended",
+        "shader_storage_image_extended_formats",
+        "shader_storage_image_multisample",
+        "shader_storage_image_read_without_format",
+        "shader_storage_image_write_without_format",
+        "shader_uniform_buffer_array_dynamic_indexing",
+        "shader_sampled_image_array_dynamic_indexing",
+        "shader_storage_buffer_array_dynamic_indexing",
+        "shader_storage_image_array_dynamic_indexing",
+        "shader_clip_distance",
+        "shader_cull_distance",
+        "shader_float64",
+        "shader_int64",
+        "shader_int16",
+        "shader_resource_residency",
+        "shader_resource_min_lod",
+        "sparse_binding",
+        "sparse_residency_buffer",
+        "sparse_residency_image_2d",
+        "sparse_residency_image_3d",
+        "sparse_residency2_samples",
+        "sparse_residency4_samples",
+        "sparse_residency8_samples",
+        "sparse_residency16_samples",
+        "sparse_residency_aliased",
+        "variable_multisample_rate",
+        "inherited_queries",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, robust_buffer_access);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 55) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, robust_buffer_access);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 55) }
+    }
+}
+
+unsafe impl Features for PhysicalDevice16BitStorageFeatures {
+    const NAME: &'static str = "VkPhysicalDevice16BitStorageFeatures";
+    const NAMES: &'static [&'static str] = &[
+        "storage_buffer_16bit_access",
+        "uniform_and_storage_buffer_16bit_access",
+        "storage_push_constant16",
+        "storage_input_output16",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, storage_buffer_16bit_access);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 4) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, storage_buffer_16bit_access);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 4) }
+    }
+}
+
+unsafe impl Features for PhysicalDevice4444FormatsFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDevice4444FormatsFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["format_a4_r4_g4_b4", "format_a4_b4_g4_r4"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, format_a4_r4_g4_b4);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, format_a4_r4_g4_b4);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDevice8BitStorageFeatures {
+    const NAME: &'static str = "VkPhysicalDevice8BitStorageFeatures";
+    const NAMES: &'static [&'static str] = &[
+        "storage_buffer_8bit_access",
+        "uniform_and_storage_buffer_8bit_access",
+        "storage_push_constant8",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, storage_buffer_8bit_access);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, storage_buffer_8bit_access);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceASTCDecodeFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceASTCDecodeFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["decode_mode_shared_exponent"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, decode_mode_shared_exponent);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, decode_mode_shared_exponent);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceAccelerationStructureFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceAccelerationStructureFeaturesKHR";
+    const NAMES: &'static [&'static str] = &[
+        "acceleration_structure",
+        "acceleration_structure_capture_replay",
+        "acceleration_structure_indirect_build",
+        "acceleration_structure_host_commands",
+        "descriptor_binding_acceleration_structure_update_after_bind",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, acceleration_structure);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 5) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, acceleration_structure);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 5) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceAddressBindingReportFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceAddressBindingReportFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["report_address_binding"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, report_address_binding);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, report_address_binding);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceAmigoProfilingFeaturesSEC {
+    const NAME: &'static str = "VkPhysicalDeviceAmigoProfilingFeaturesSEC";
+    const NAMES: &'static [&'static str] = &["amigo_profiling"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, amigo_profiling);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, amigo_profiling);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceAntiLagFeaturesAMD {
+    const NAME: &'static str = "VkPhysicalDeviceAntiLagFeaturesAMD";
+    const NAMES: &'static [&'static str] = &["anti_lag"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, anti_lag);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, anti_lag);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceBlendOperationAdvancedFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceBlendOperationAdvancedFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["advanced_blend_coherent_operations"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, advanced_blend_coherent_operations);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, advanced_blend_coherent_operations);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceBorderColorSwizzleFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceBorderColorSwizzleFeaturesEXT";
+    const NAMES: &'static [&'static str] =
+        &["border_color_swizzle", "border_color_swizzle_from_image"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, border_color_swizzle);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, border_color_swizzle);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceBufferDeviceAddressFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceBufferDeviceAddressFeatures";
+    const NAMES: &'static [&'static str] = &[
+        "buffer_device_address",
+        "buffer_device_address_capture_replay",
+        "buffer_device_address_multi_device",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, buffer_device_address);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, buffer_device_address);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceBufferDeviceAddressFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "buffer_device_address",
+        "buffer_device_address_capture_replay",
+        "buffer_device_address_multi_device",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, buffer_device_address);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, buffer_device_address);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceClusterCullingShaderFeaturesHUAWEI {
+    const NAME: &'static str = "VkPhysicalDeviceClusterCullingShaderFeaturesHUAWEI";
+    const NAMES: &'static [&'static str] =
+        &["clusterculling_shader", "multiview_cluster_culling_shader"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, clusterculling_shader);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, clusterculling_shader);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCoherentMemoryFeaturesAMD {
+    const NAME: &'static str = "VkPhysicalDeviceCoherentMemoryFeaturesAMD";
+    const NAMES: &'static [&'static str] = &["device_coherent_memory"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, device_coherent_memory);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, device_coherent_memory);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceColorWriteEnableFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceColorWriteEnableFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["color_write_enable"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, color_write_enable);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, color_write_enable);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCommandBufferInheritanceFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceCommandBufferInheritanceFeaturesNV";
+    const NAMES: &'static [&'static str] = &["command_buffer_inheritance"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, command_buffer_inheritance);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, command_buffer_inheritance);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceComputeOccupancyPriorityFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceComputeOccupancyPriorityFeaturesNV";
+    const NAMES: &'static [&'static str] = &["compute_occupancy_priority"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, compute_occupancy_priority);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, compute_occupancy_priority);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceComputeShaderDerivativesFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceComputeShaderDerivativesFeaturesKHR";
+    const NAMES: &'static [&'static str] = &[
+        "compute_derivative_group_quads",
+        "compute_derivative_group_linear",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, compute_derivative_group_quads);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, compute_derivative_group_quads);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceConditionalRenderingFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceConditionalRenderingFeaturesEXT";
+    const NAMES: &'static [&'static str] =
+        &["conditional_rendering", "inherited_conditional_rendering"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, conditional_rendering);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, conditional_rendering);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCooperativeMatrix2FeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceCooperativeMatrix2FeaturesNV";
+    const NAMES: &'static [&'static str] = &[
+        "cooperative_matrix_workgroup_scope",
+        "cooperative_matrix_flexible_dimensions",
+        "cooperative_matrix_reductions",
+        "cooperative_matrix_conversions",
+        "cooperative_matrix_per_element_operations",
+        "cooperative_matrix_tensor_addressing",
+        "cooperative_matrix_block_loads",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, cooperative_matrix_workgroup_scope);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 7) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, cooperative_matrix_workgroup_scope);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 7) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCooperativeMatrixFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceCooperativeMatrixFeaturesKHR";
+    const NAMES: &'static [&'static str] = &[
+        "cooperative_matrix",
+        "cooperative_matrix_robust_buffer_access",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, cooperative_matrix);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, cooperative_matrix);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCooperativeMatrixFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceCooperativeMatrixFeaturesNV";
+    const NAMES: &'static [&'static str] = &[
+        "cooperative_matrix",
+        "cooperative_matrix_robust_buffer_access",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, cooperative_matrix);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, cooperative_matrix);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCooperativeVectorFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceCooperativeVectorFeaturesNV";
+    const NAMES: &'static [&'static str] = &["cooperative_vector", "cooperative_vector_training"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, cooperative_vector);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, cooperative_vector);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCopyMemoryIndirectFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceCopyMemoryIndirectFeaturesKHR";
+    const NAMES: &'static [&'static str] =
+        &["indirect_memory_copy", "indirect_memory_to_image_copy"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, indirect_memory_copy);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, indirect_memory_copy);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCopyMemoryIndirectFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceCopyMemoryIndirectFeaturesNV";
+    const NAMES: &'static [&'static str] = &["indirect_copy"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, indirect_copy);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, indirect_copy);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCornerSampledImageFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceCornerSampledImageFeaturesNV";
+    const NAMES: &'static [&'static str] = &["corner_sampled_image"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, corner_sampled_image);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, corner_sampled_image);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCoverageReductionModeFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceCoverageReductionModeFeaturesNV";
+    const NAMES: &'static [&'static str] = &["coverage_reduction_mode"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, coverage_reduction_mode);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, coverage_reduction_mode);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCubicClampFeaturesQCOM {
+    const NAME: &'static str = "VkPhysicalDeviceCubicClampFeaturesQCOM";
+    const NAMES: &'static [&'static str] = &["cubic_range_clamp"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, cubic_range_clamp);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, cubic_range_clamp);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCubicWeightsFeaturesQCOM {
+    const NAME: &'static str = "VkPhysicalDeviceCubicWeightsFeaturesQCOM";
+    const NAMES: &'static [&'static str] = &["selectable_cubic_weights"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, selectable_cubic_weights);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, selectable_cubic_weights);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCudaKernelLaunchFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceCudaKernelLaunchFeaturesNV";
+    const NAMES: &'static [&'static str] = &["cuda_kernel_launch_features"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, cuda_kernel_launch_features);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, cuda_kernel_launch_features);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCustomBorderColorFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceCustomBorderColorFeaturesEXT";
+    const NAMES: &'static [&'static str] =
+        &["custom_border_colors", "custom_border_color_without_format"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, custom_border_colors);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, custom_border_colors);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceCustomResolveFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceCustomResolveFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["custom_resolve"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, custom_resolve);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, custom_resolve);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDataGraphFeaturesARM {
+    const NAME: &'static str = "VkPhysicalDeviceDataGraphFeaturesARM";
+    const NAMES: &'static [&'static str] = &[
+        "data_graph",
+        "data_graph_update_after_bind",
+        "data_graph_specialization_constants",
+        "data_graph_descriptor_buffer",
+        "data_graph_shader_module",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, data_graph);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 5) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, data_graph);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 5) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDataGraphModelFeaturesQCOM {
+    const NAME: &'static str = "VkPhysicalDeviceDataGraphModelFeaturesQCOM";
+    const NAMES: &'static [&'static str] = &["data_graph_model"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, data_graph_model);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, data_graph_model);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDenseGeometryFormatFeaturesAMDX {
+    const NAME: &'static str = "VkPhysicalDeviceDenseGeometryFormatFeaturesAMDX";
+    const NAMES: &'static [&'static str] = &["dense_geometry_format"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, dense_geometry_format);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, dense_geometry_format);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDepthBiasControlFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceDepthBiasControlFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "depth_bias_control",
+        "least_representable_value_force_unorm_representation",
+        "float_representation",
+        "depth_bias_exact",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, depth_bias_control);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 4) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, depth_bias_control);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 4) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDepthClampControlFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceDepthClampControlFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["depth_clamp_control"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, depth_clamp_control);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, depth_clamp_control);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDepthClampZeroOneFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceDepthClampZeroOneFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["depth_clamp_zero_one"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, depth_clamp_zero_one);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, depth_clamp_zero_one);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDepthClipControlFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceDepthClipControlFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["depth_clip_control"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, depth_clip_control);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, depth_clip_control);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDepthClipEnableFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceDepthClipEnableFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["depth_clip_enable"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, depth_clip_enable);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, depth_clip_enable);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDescriptorBufferFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceDescriptorBufferFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "descriptor_buffer",
+        "descriptor_buffer_capture_replay",
+        "descriptor_buffer_image_layout_ignored",
+        "descriptor_buffer_push_descriptors",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, descriptor_buffer);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 4) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, descriptor_buffer);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 4) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDescriptorBufferTensorFeaturesARM {
+    const NAME: &'static str = "VkPhysicalDeviceDescriptorBufferTensorFeaturesARM";
+    const NAMES: &'static [&'static str] = &["descriptor_buffer_tensor_descriptors"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, descriptor_buffer_tensor_descriptors);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, descriptor_buffer_tensor_descriptors);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDescriptorHeapFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceDescriptorHeapFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["descriptor_heap", "descriptor_heap_capture_replay"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, descriptor_heap);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, descriptor_heap);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDescriptorIndexingFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceDescriptorIndexingFeatures";
+    const NAMES: &'static [&'static str] = &[
+        "shader_input_attachment_array_dynamic_indexing",
+        "shader_uniform_texel_buffer_array_dynamic_indexing",
+        "shader_storage_texel_buffer_array_dynamic_indexing",
+        "shader_uniform_buffer_array_non_uniform_indexing",
+        "shader_sampled_image_array_non_uniform_indexing",
+        "shader_storage_buffer_array_non_uniform_indexing",
+        "shader_storage_image_array_non_uniform_indexing",
+        "shader_input_attachment_array_non_uniform_indexing",
+        "shader_uniform_texel_buffer_array_non_uniform_indexing",
+        "shader_storage_texel_buffer_array_non_uniform_indexing",
+        "descriptor_binding_uniform_buffer_update_after_bind",
+        "descriptor_binding_sampled_image_update_after_bind",
+        "descriptor_binding_storage_image_update_after_bind",
+        "descriptor_binding_storage_buffer_update_after_bind",
+        "descriptor_binding_uniform_texel_buffer_update_after_bind",
+        "descriptor_binding_storage_texel_buffer_update_after_bind",
+        "descriptor_binding_update_unused_while_pending",
+        "descriptor_binding_partially_bound",
+        "descriptor_binding_variable_descriptor_count",
+        "runtime_descriptor_array",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_input_attachment_array_dynamic_indexing);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 20) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_input_attachment_array_dynamic_indexing);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 20) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE {
+    const NAME: &'static str = "VkPhysicalDeviceDescriptorSetHostMappingFeaturesVALVE";
+    const NAMES: &'static [&'static str] = &["descriptor_set_host_mapping"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, descriptor_set_host_mapping);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, descriptor_set_host_mapping);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDeviceGeneratedCommandsFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceDeviceGeneratedCommandsFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "device_generated_commands",
+        "dynamic_generated_pipeline_layout",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, device_generated_commands);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, device_generated_commands);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDeviceGeneratedCommandsFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceDeviceGeneratedCommandsFeaturesNV";
+    const NAMES: &'static [&'static str] = &["device_generated_commands"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, device_generated_commands);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, device_generated_commands);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDeviceMemoryReportFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceDeviceMemoryReportFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["device_memory_report"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, device_memory_report);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, device_memory_report);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDiagnosticsConfigFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceDiagnosticsConfigFeaturesNV";
+    const NAMES: &'static [&'static str] = &["diagnostics_config"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, diagnostics_config);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, diagnostics_config);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDisplacementMicromapFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceDisplacementMicromapFeaturesNV";
+    const NAMES: &'static [&'static str] = &["displacement_micromap"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, displacement_micromap);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, displacement_micromap);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDynamicRenderingFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceDynamicRenderingFeatures";
+    const NAMES: &'static [&'static str] = &["dynamic_rendering"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, dynamic_rendering);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, dynamic_rendering);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceDynamicRenderingLocalReadFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceDynamicRenderingLocalReadFeatures";
+    const NAMES: &'static [&'static str] = &["dynamic_rendering_local_read"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, dynamic_rendering_local_read);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, dynamic_rendering_local_read);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceExclusiveScissorFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceExclusiveScissorFeaturesNV";
+    const NAMES: &'static [&'static str] = &["exclusive_scissor"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, exclusive_scissor);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, exclusive_scissor);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceExtendedDynamicState2FeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceExtendedDynamicState2FeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "extended_dynamic_state2",
+        "extended_dynamic_state2_logic_op",
+        "extended_dynamic_state2_patch_control_points",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, extended_dynamic_state2);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, extended_dynamic_state2);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceExtendedDynamicState3FeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "extended_dynamic_state3_tessellation_domain_origin",
+        "extended_dynamic_state_3depth_clamp_enable",
+        "extended_dynamic_state3_polygon_mode",
+        "extended_dynamic_state3_rasterization_samples",
+        "extended_dynamic_state3_sample_mask",
+        "extended_dynamic_state3_alpha_to_coverage_enable",
+        "extended_dynamic_state3_alpha_to_one_enable",
+        "extended_dynamic_state3_logic_op_enable",
+        "extended_dynamic_state3_color_blend_enable",
+        "extended_dynamic_state3_color_blend_equation",
+        "extended_dynamic_state3_color_write_mask",
+        "extended_dynamic_state3_rasterization_stream",
+        "extended_dynamic_state3_conservative_rasterization_mode",
+        "extended_dynamic_state3_extra_primitive_overestimation_size",
+        "extended_dynamic_state_3depth_clip_enable",
+        "extended_dynamic_state3_sample_locations_enable",
+        "extended_dynamic_state3_color_blend_advanced",
+        "extended_dynamic_state3_provoking_vertex_mode",
+        "extended_dynamic_state3_line_rasterization_mode",
+        "extended_dynamic_state3_line_stipple_enable",
+        "extended_dynamic_state_3depth_clip_negative_one_to_one",
+        "extended_dynamic_state3_viewport_w_scaling_enable",
+        "extended_dynamic_state3_viewport_swizzle",
+        "extended_dynamic_state3_coverage_to_color_enable",
+        "extended_dynamic_state3_coverage_to_color_location",
+        "extended_dynamic_state3_coverage_modulation_mode",
+        "extended_dynamic_state3_coverage_modulation_table_enable",
+        "extended_dynamic_state3_coverage_modulation_table",
+        "extended_dynamic_state3_coverage_reduction_mode",
+        "extended_dynamic_state3_representative_fragment_test_enable",
+        "extended_dynamic_state3_shading_rate_image_enable",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, extended_dynamic_state3_tessellation_domain_origin);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 31) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, extended_dynamic_state3_tessellation_domain_origin);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 31) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceExtendedDynamicStateFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceExtendedDynamicStateFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["extended_dynamic_state"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, extended_dynamic_state);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, extended_dynamic_state);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceExtendedSparseAddressSpaceFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceExtendedSparseAddressSpaceFeaturesNV";
+    const NAMES: &'static [&'static str] = &["extended_sparse_address_space"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, extended_sparse_address_space);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, extended_sparse_address_space);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceExternalFormatResolveFeaturesANDROID {
+    const NAME: &'static str = "VkPhysicalDeviceExternalFormatResolveFeaturesANDROID";
+    const NAMES: &'static [&'static str] = &["external_format_resolve"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, external_format_resolve);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, external_format_resolve);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceExternalMemoryRDMAFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceExternalMemoryRDMAFeaturesNV";
+    const NAMES: &'static [&'static str] = &["external_memory_rdma"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, external_memory_rdma);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, external_memory_rdma);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceExternalMemorySciBufFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceExternalMemorySciBufFeaturesNV";
+    const NAMES: &'static [&'static str] = &["sci_buf_import", "sci_buf_export"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, sci_buf_import);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, sci_buf_import);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceExternalMemoryScreenBufferFeaturesQNX {
+    const NAME: &'static str = "VkPhysicalDeviceExternalMemoryScreenBufferFeaturesQNX";
+    const NAMES: &'static [&'static str] = &["screen_buffer_import"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, screen_buffer_import);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, screen_buffer_import);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceExternalSciSync2FeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceExternalSciSync2FeaturesNV";
+    const NAMES: &'static [&'static str] = &[
+        "sci_sync_fence",
+        "sci_sync_semaphore2",
+        "sci_sync_import",
+        "sci_sync_export",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, sci_sync_fence);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 4) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, sci_sync_fence);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 4) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceExternalSciSyncFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceExternalSciSyncFeaturesNV";
+    const NAMES: &'static [&'static str] = &[
+        "sci_sync_fence",
+        "sci_sync_semaphore",
+        "sci_sync_import",
+        "sci_sync_export",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, sci_sync_fence);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 4) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, sci_sync_fence);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 4) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceFaultFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceFaultFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["device_fault", "device_fault_vendor_binary"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, device_fault);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, device_fault);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceFormatPackFeaturesARM {
+    const NAME: &'static str = "VkPhysicalDeviceFormatPackFeaturesARM";
+    const NAMES: &'static [&'static str] = &["format_pack"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, format_pack);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, format_pack);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceFragmentDensityMap2FeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceFragmentDensityMap2FeaturesEXT";
+    const NAMES: &'static [&'static str] = &["fragment_density_map_deferred"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, fragment_density_map_deferred);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, fragment_density_map_deferred);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceFragmentDensityMapFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceFragmentDensityMapFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "fragment_density_map",
+        "fragment_density_map_dynamic",
+        "fragment_density_map_non_subsampled_images",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, fragment_density_map);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, fragment_density_map);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceFragmentDensityMapOffsetFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceFragmentDensityMapOffsetFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["fragment_density_map_offset"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, fragment_density_map_offset);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, fragment_density_map_offset);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceFragmentShaderBarycentricFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceFragmentShaderBarycentricFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["fragment_shader_barycentric"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, fragment_shader_barycentric);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, fragment_shader_barycentric);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceFragmentShaderInterlockFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "fragment_shader_sample_interlock",
+        "fragment_shader_pixel_interlock",
+        "fragment_shader_shading_rate_interlock",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, fragment_shader_sample_interlock);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, fragment_shader_sample_interlock);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceFragmentShadingRateEnumsFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceFragmentShadingRateEnumsFeaturesNV";
+    const NAMES: &'static [&'static str] = &[
+        "fragment_shading_rate_enums",
+        "supersample_fragment_shading_rates",
+        "no_invocation_fragment_shading_rates",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, fragment_shading_rate_enums);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, fragment_shading_rate_enums);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceFragmentShadingRateFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceFragmentShadingRateFeaturesKHR";
+    const NAMES: &'static [&'static str] = &[
+        "pipeline_fragment_shading_rate",
+        "primitive_fragment_shading_rate",
+        "attachment_fragment_shading_rate",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_fragment_shading_rate);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_fragment_shading_rate);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceFrameBoundaryFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceFrameBoundaryFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["frame_boundary"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, frame_boundary);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, frame_boundary);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceGlobalPriorityQueryFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceGlobalPriorityQueryFeatures";
+    const NAMES: &'static [&'static str] = &["global_priority_query"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, global_priority_query);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, global_priority_query);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceGraphicsPipelineLibraryFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["graphics_pipeline_library"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, graphics_pipeline_library);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, graphics_pipeline_library);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceHdrVividFeaturesHUAWEI {
+    const NAME: &'static str = "VkPhysicalDeviceHdrVividFeaturesHUAWEI";
+    const NAMES: &'static [&'static str] = &["hdr_vivid"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, hdr_vivid);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, hdr_vivid);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceHostImageCopyFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceHostImageCopyFeatures";
+    const NAMES: &'static [&'static str] = &["host_image_copy"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, host_image_copy);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, host_image_copy);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceHostQueryResetFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceHostQueryResetFeatures";
+    const NAMES: &'static [&'static str] = &["host_query_reset"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, host_query_reset);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, host_query_reset);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceImage2DViewOf3DFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceImage2DViewOf3DFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["image_2d_view_of_3d", "sampler_2d_view_of_3d"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, image_2d_view_of_3d);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, image_2d_view_of_3d);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceImageAlignmentControlFeaturesMESA {
+    const NAME: &'static str = "VkPhysicalDeviceImageAlignmentControlFeaturesMESA";
+    const NAMES: &'static [&'static str] = &["image_alignment_control"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, image_alignment_control);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, image_alignment_control);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceImageCompressionControlFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceImageCompressionControlFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["image_compression_control"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, image_compression_control);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, image_compression_control);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceImageProcessing2FeaturesQCOM {
+    const NAME: &'static str = "VkPhysicalDeviceImageProcessing2FeaturesQCOM";
+    const NAMES: &'static [&'static str] = &["texture_block_match2"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, texture_block_match2);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, texture_block_match2);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceImageProcessingFeaturesQCOM {
+    const NAME: &'static str = "VkPhysicalDeviceImageProcessingFeaturesQCOM";
+    const NAMES: &'static [&'static str] = &[
+        "texture_sample_weighted",
+        "texture_box_filter",
+        "texture_block_match",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, texture_sample_weighted);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, texture_sample_weighted);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceImageRobustnessFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceImageRobustnessFeatures";
+    const NAMES: &'static [&'static str] = &["robust_image_access"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, robust_image_access);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, robust_image_access);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceImageSlicedViewOf3DFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceImageSlicedViewOf3DFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["image_sliced_view_of_3d"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, image_sliced_view_of_3d);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, image_sliced_view_of_3d);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceImageViewMinLodFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceImageViewMinLodFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["min_lod"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, min_lod);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, min_lod);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceImagelessFramebufferFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceImagelessFramebufferFeatures";
+    const NAMES: &'static [&'static str] = &["imageless_framebuffer"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, imageless_framebuffer);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, imageless_framebuffer);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceIndexTypeUint8Features {
+    const NAME: &'static str = "VkPhysicalDeviceIndexTypeUint8Features";
+    const NAMES: &'static [&'static str] = &["index_type_uint8"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, index_type_uint8);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, index_type_uint8);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceInheritedViewportScissorFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceInheritedViewportScissorFeaturesNV";
+    const NAMES: &'static [&'static str] = &["inherited_viewport_scissor_2d"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, inherited_viewport_scissor_2d);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, inherited_viewport_scissor_2d);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceInlineUniformBlockFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceInlineUniformBlockFeatures";
+    const NAMES: &'static [&'static str] = &[
+        "inline_uniform_block",
+        "descriptor_binding_inline_uniform_block_update_after_bind",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, inline_uniform_block);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, inline_uniform_block);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceInvocationMaskFeaturesHUAWEI {
+    const NAME: &'static str = "VkPhysicalDeviceInvocationMaskFeaturesHUAWEI";
+    const NAMES: &'static [&'static str] = &["invocation_mask"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, invocation_mask);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, invocation_mask);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceLegacyDitheringFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceLegacyDitheringFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["legacy_dithering"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, legacy_dithering);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, legacy_dithering);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceLegacyVertexAttributesFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceLegacyVertexAttributesFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["legacy_vertex_attributes"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, legacy_vertex_attributes);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, legacy_vertex_attributes);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceLineRasterizationFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceLineRasterizationFeatures";
+    const NAMES: &'static [&'static str] = &[
+        "rectangular_lines",
+        "bresenham_lines",
+        "smooth_lines",
+        "stippled_rectangular_lines",
+        "stippled_bresenham_lines",
+        "stippled_smooth_lines",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, rectangular_lines);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 6) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, rectangular_lines);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 6) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceLinearColorAttachmentFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceLinearColorAttachmentFeaturesNV";
+    const NAMES: &'static [&'static str] = &["linear_color_attachment"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, linear_color_attachment);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, linear_color_attachment);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMaintenance10FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceMaintenance10FeaturesKHR";
+    const NAMES: &'static [&'static str] = &["maintenance10"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, maintenance10);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, maintenance10);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMaintenance4Features {
+    const NAME: &'static str = "VkPhysicalDeviceMaintenance4Features";
+    const NAMES: &'static [&'static str] = &["maintenance4"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, maintenance4);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, maintenance4);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMaintenance5Features {
+    const NAME: &'static str = "VkPhysicalDeviceMaintenance5Features";
+    const NAMES: &'static [&'static str] = &["maintenance5"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, maintenance5);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, maintenance5);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMaintenance6Features {
+    const NAME: &'static str = "VkPhysicalDeviceMaintenance6Features";
+    const NAMES: &'static [&'static str] = &["maintenance6"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, maintenance6);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, maintenance6);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMaintenance7FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceMaintenance7FeaturesKHR";
+    const NAMES: &'static [&'static str] = &["maintenance7"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, maintenance7);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, maintenance7);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMaintenance8FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceMaintenance8FeaturesKHR";
+    const NAMES: &'static [&'static str] = &["maintenance8"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, maintenance8);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, maintenance8);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMaintenance9FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceMaintenance9FeaturesKHR";
+    const NAMES: &'static [&'static str] = &["maintenance9"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, maintenance9);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, maintenance9);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMapMemoryPlacedFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceMapMemoryPlacedFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "memory_map_placed",
+        "memory_map_range_placed",
+        "memory_unmap_reserve",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, memory_map_placed);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, memory_map_placed);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMemoryDecompressionFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceMemoryDecompressionFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["memory_decompression"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, memory_decompression);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, memory_decompression);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMemoryPriorityFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceMemoryPriorityFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["memory_priority"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, memory_priority);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, memory_priority);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMeshShaderFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceMeshShaderFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "task_shader",
+        "mesh_shader",
+        "multiview_mesh_shader",
+        "primitive_fragment_shading_rate_mesh_shader",
+        "mesh_shader_queries",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, task_shader);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 5) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, task_shader);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 5) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMeshShaderFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceMeshShaderFeaturesNV";
+    const NAMES: &'static [&'static str] = &["task_shader", "mesh_shader"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, task_shader);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, task_shader);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMultiDrawFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceMultiDrawFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["multi_draw"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, multi_draw);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, multi_draw);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMultiviewFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceMultiviewFeatures";
+    const NAMES: &'static [&'static str] = &[
+        "multiview",
+        "multiview_geometry_shader",
+        "multiview_tessellation_shader",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, multiview);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, multiview);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM {
+    const NAME: &'static str = "VkPhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM";
+    const NAMES: &'static [&'static str] = &["multiview_per_view_viewports"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, multiview_per_view_viewports);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, multiview_per_view_viewports);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceMutableDescriptorTypeFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceMutableDescriptorTypeFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["mutable_descriptor_type"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, mutable_descriptor_type);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, mutable_descriptor_type);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceNestedCommandBufferFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceNestedCommandBufferFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "nested_command_buffer",
+        "nested_command_buffer_rendering",
+        "nested_command_buffer_simultaneous_use",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, nested_command_buffer);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, nested_command_buffer);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceNonSeamlessCubeMapFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceNonSeamlessCubeMapFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["non_seamless_cube_map"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, non_seamless_cube_map);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, non_seamless_cube_map);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceOpacityMicromapFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceOpacityMicromapFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "micromap",
+        "micromap_capture_replay",
+        "micromap_host_commands",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, micromap);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, micromap);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceOpticalFlowFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceOpticalFlowFeaturesNV";
+    const NAMES: &'static [&'static str] = &["optical_flow"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, optical_flow);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, optical_flow);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDevicePageableDeviceLocalMemoryFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["pageable_device_local_memory"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, pageable_device_local_memory);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, pageable_device_local_memory);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePerStageDescriptorSetFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDevicePerStageDescriptorSetFeaturesNV";
+    const NAMES: &'static [&'static str] = &["per_stage_descriptor_set", "dynamic_pipeline_layout"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, per_stage_descriptor_set);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, per_stage_descriptor_set);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePerformanceQueryFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDevicePerformanceQueryFeaturesKHR";
+    const NAMES: &'static [&'static str] = &[
+        "performance_counter_query_pools",
+        "performance_counter_multiple_query_pools",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, performance_counter_query_pools);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, performance_counter_query_pools);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePipelineBinaryFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDevicePipelineBinaryFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["pipeline_binaries"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_binaries);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_binaries);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePipelineCreationCacheControlFeatures {
+    const NAME: &'static str = "VkPhysicalDevicePipelineCreationCacheControlFeatures";
+    const NAMES: &'static [&'static str] = &["pipeline_creation_cache_control"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_creation_cache_control);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_creation_cache_control);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePipelineOpacityMicromapFeaturesARM {
+    const NAME: &'static str = "VkPhysicalDevicePipelineOpacityMicromapFeaturesARM";
+    const NAMES: &'static [&'static str] = &["pipeline_opacity_micromap"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_opacity_micromap);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_opacity_micromap);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePipelinePropertiesFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDevicePipelinePropertiesFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["pipeline_properties_identifier"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_properties_identifier);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_properties_identifier);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePipelineProtectedAccessFeatures {
+    const NAME: &'static str = "VkPhysicalDevicePipelineProtectedAccessFeatures";
+    const NAMES: &'static [&'static str] = &["pipeline_protected_access"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_protected_access);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_protected_access);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePipelineRobustnessFeatures {
+    const NAME: &'static str = "VkPhysicalDevicePipelineRobustnessFeatures";
+    const NAMES: &'static [&'static str] = &["pipeline_robustness"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_robustness);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, pipeline_robustness);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePortabilitySubsetFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDevicePortabilitySubsetFeaturesKHR";
+    const NAMES: &'static [&'static str] = &[
+        "constant_alpha_color_blend_factors",
+        "events",
+        "image_view_format_reinterpretation",
+        "image_view_format_swizzle",
+        "image_view_2d_on_3d_image",
+        "multisample_array_image",
+        "mutable_comparison_samplers",
+        "point_polygons",
+        "sampler_mip_lod_bias",
+        "separate_stencil_mask_ref",
+        "shader_sample_rate_interpolation_functions",
+        "tessellation_isolines",
+        "tessellation_point_mode",
+        "triangle_fans",
+        "vertex_attribute_access_beyond_stride",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, constant_alpha_color_blend_factors);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 15) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, constant_alpha_color_blend_factors);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 15) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePresentBarrierFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDevicePresentBarrierFeaturesNV";
+    const NAMES: &'static [&'static str] = &["present_barrier"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, present_barrier);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, present_barrier);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePresentId2FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDevicePresentId2FeaturesKHR";
+    const NAMES: &'static [&'static str] = &["present_id2"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, present_id2);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, present_id2);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePresentIdFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDevicePresentIdFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["present_id"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, present_id);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, present_id);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePresentMeteringFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDevicePresentMeteringFeaturesNV";
+    const NAMES: &'static [&'static str] = &["present_metering"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, present_metering);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, present_metering);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePresentModeFifoLatestReadyFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDevicePresentModeFifoLatestReadyFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["present_mode_fifo_latest_ready"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, present_mode_fifo_latest_ready);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, present_mode_fifo_latest_ready);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePresentTimingFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDevicePresentTimingFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "present_timing",
+        "present_at_absolute_time",
+        "present_at_relative_time",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, present_timing);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, present_timing);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePresentWait2FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDevicePresentWait2FeaturesKHR";
+    const NAMES: &'static [&'static str] = &["present_wait2"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, present_wait2);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, present_wait2);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePresentWaitFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDevicePresentWaitFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["present_wait"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, present_wait);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, present_wait);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDevicePrimitivesGeneratedQueryFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "primitives_generated_query",
+        "primitives_generated_query_with_rasterizer_discard",
+        "primitives_generated_query_with_non_zero_streams",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, primitives_generated_query);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, primitives_generated_query);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePrivateDataFeatures {
+    const NAME: &'static str = "VkPhysicalDevicePrivateDataFeatures";
+    const NAMES: &'static [&'static str] = &["private_data"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, private_data);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, private_data);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceProtectedMemoryFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceProtectedMemoryFeatures";
+    const NAMES: &'static [&'static str] = &["protected_memory"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, protected_memory);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, protected_memory);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceProvokingVertexFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceProvokingVertexFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "provoking_vertex_last",
+        "transform_feedback_preserves_provoking_vertex",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, provoking_vertex_last);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, provoking_vertex_last);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDevicePushConstantBankFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDevicePushConstantBankFeaturesNV";
+    const NAMES: &'static [&'static str] = &["push_constant_bank"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, push_constant_bank);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, push_constant_bank);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRGBA10X6FormatsFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceRGBA10X6FormatsFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["format_rgba10x6_without_y_cb_cr_sampler"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, format_rgba10x6_without_y_cb_cr_sampler);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, format_rgba10x6_without_y_cb_cr_sampler);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRawAccessChainsFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceRawAccessChainsFeaturesNV";
+    const NAMES: &'static [&'static str] = &["shader_raw_access_chains"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_raw_access_chains);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_raw_access_chains);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRayQueryFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceRayQueryFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["ray_query"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, ray_query);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, ray_query);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRayTracingInvocationReorderFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceRayTracingInvocationReorderFeaturesNV";
+    const NAMES: &'static [&'static str] = &["ray_tracing_invocation_reorder"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, ray_tracing_invocation_reorder);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, ray_tracing_invocation_reorder);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRayTracingMaintenance1FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceRayTracingMaintenance1FeaturesKHR";
+    const NAMES: &'static [&'static str] = &[
+        "ray_tracing_maintenance1",
+        "ray_tracing_pipeline_trace_rays_indirect2",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, ray_tracing_maintenance1);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, ray_tracing_maintenance1);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRayTracingMotionBlurFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceRayTracingMotionBlurFeaturesNV";
+    const NAMES: &'static [&'static str] = &[
+        "ray_tracing_motion_blur",
+        "ray_tracing_motion_blur_pipeline_trace_rays_indirect",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, ray_tracing_motion_blur);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, ray_tracing_motion_blur);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRayTracingPipelineFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceRayTracingPipelineFeaturesKHR";
+    const NAMES: &'static [&'static str] = &[
+        "ray_tracing_pipeline",
+        "ray_tracing_pipeline_shader_group_handle_capture_replay",
+        "ray_tracing_pipeline_shader_group_handle_capture_replay_mixed",
+        "ray_tracing_pipeline_trace_rays_indirect",
+        "ray_traversal_primitive_culling",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, ray_tracing_pipeline);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 5) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, ray_tracing_pipeline);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 5) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRayTracingPositionFetchFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceRayTracingPositionFetchFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["ray_tracing_position_fetch"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, ray_tracing_position_fetch);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, ray_tracing_position_fetch);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRayTracingValidationFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceRayTracingValidationFeaturesNV";
+    const NAMES: &'static [&'static str] = &["ray_tracing_validation"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, ray_tracing_validation);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, ray_tracing_validation);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRelaxedLineRasterizationFeaturesIMG {
+    const NAME: &'static str = "VkPhysicalDeviceRelaxedLineRasterizationFeaturesIMG";
+    const NAMES: &'static [&'static str] = &["relaxed_line_rasterization"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, relaxed_line_rasterization);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, relaxed_line_rasterization);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRenderPassStripedFeaturesARM {
+    const NAME: &'static str = "VkPhysicalDeviceRenderPassStripedFeaturesARM";
+    const NAMES: &'static [&'static str] = &["render_pass_striped"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, render_pass_striped);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, render_pass_striped);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRepresentativeFragmentTestFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceRepresentativeFragmentTestFeaturesNV";
+    const NAMES: &'static [&'static str] = &["representative_fragment_test"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, representative_fragment_test);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, representative_fragment_test);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceRobustness2FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceRobustness2FeaturesKHR";
+    const NAMES: &'static [&'static str] = &[
+        "robust_buffer_access2",
+        "robust_image_access2",
+        "null_descriptor",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, robust_buffer_access2);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, robust_buffer_access2);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceSamplerYcbcrConversionFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceSamplerYcbcrConversionFeatures";
+    const NAMES: &'static [&'static str] = &["sampler_ycbcr_conversion"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, sampler_ycbcr_conversion);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, sampler_ycbcr_conversion);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceScalarBlockLayoutFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceScalarBlockLayoutFeatures";
+    const NAMES: &'static [&'static str] = &["scalar_block_layout"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, scalar_block_layout);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, scalar_block_layout);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceSchedulingControlsFeaturesARM {
+    const NAME: &'static str = "VkPhysicalDeviceSchedulingControlsFeaturesARM";
+    const NAMES: &'static [&'static str] = &["scheduling_controls"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, scheduling_controls);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, scheduling_controls);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceSeparateDepthStencilLayoutsFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures";
+    const NAMES: &'static [&'static str] = &["separate_depth_stencil_layouts"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, separate_depth_stencil_layouts);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, separate_depth_stencil_layouts);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShader64BitIndexingFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceShader64BitIndexingFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["shader_64bit_indexing"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_64bit_indexing);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_64bit_indexing);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderAtomicFloat16VectorFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceShaderAtomicFloat16VectorFeaturesNV";
+    const NAMES: &'static [&'static str] = &["shader_float16_vector_atomics"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_float16_vector_atomics);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_float16_vector_atomics);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderAtomicFloat2FeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "shader_buffer_float16_atomics",
+        "shader_buffer_float16_atomic_add",
+        "shader_buffer_float16_atomic_min_max",
+        "shader_buffer_float32_atomic_min_max",
+        "shader_buffer_float64_atomic_min_max",
+        "shader_shared_float16_atomics",
+        "shader_shared_float16_atomic_add",
+        "shader_shared_float16_atomic_min_max",
+        "shader_shared_float32_atomic_min_max",
+        "shader_shared_float64_atomic_min_max",
+        "shader_image_float32_atomic_min_max",
+        "sparse_image_float32_atomic_min_max",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_buffer_float16_atomics);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 12) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_buffer_float16_atomics);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 12) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderAtomicFloatFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "shader_buffer_float32_atomics",
+        "shader_buffer_float32_atomic_add",
+        "shader_buffer_float64_atomics",
+        "shader_buffer_float64_atomic_add",
+        "shader_shared_float32_atomics",
+        "shader_shared_float32_atomic_add",
+        "shader_shared_float64_atomics",
+        "shader_shared_float64_atomic_add",
+        "shader_image_float32_atomics",
+        "shader_image_float32_atomic_add",
+        "sparse_image_float32_atomics",
+        "sparse_image_float32_atomic_add",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_buffer_float32_atomics);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 12) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_buffer_float32_atomics);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 12) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderAtomicInt64Features {
+    const NAME: &'static str = "VkPhysicalDeviceShaderAtomicInt64Features";
+    const NAMES: &'static [&'static str] =
+        &["shader_buffer_int64_atomics", "shader_shared_int64_atomics"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_buffer_int64_atomics);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_buffer_int64_atomics);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderBfloat16FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceShaderBfloat16FeaturesKHR";
+    const NAMES: &'static [&'static str] = &[
+        "shader_b_float16_type",
+        "shader_b_float_16dot_product",
+        "shader_b_float16_cooperative_matrix",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_b_float16_type);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_b_float16_type);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderClockFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceShaderClockFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["shader_subgroup_clock", "shader_device_clock"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_subgroup_clock);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_subgroup_clock);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderCoreBuiltinsFeaturesARM {
+    const NAME: &'static str = "VkPhysicalDeviceShaderCoreBuiltinsFeaturesARM";
+    const NAMES: &'static [&'static str] = &["shader_core_builtins"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_core_builtins);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_core_builtins);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderDrawParametersFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceShaderDrawParametersFeatures";
+    const NAMES: &'static [&'static str] = &["shader_draw_parameters"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_draw_parameters);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_draw_parameters);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderEnqueueFeaturesAMDX {
+    const NAME: &'static str = "VkPhysicalDeviceShaderEnqueueFeaturesAMDX";
+    const NAMES: &'static [&'static str] = &["shader_enqueue", "shader_mesh_enqueue"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_enqueue);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_enqueue);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderExpectAssumeFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceShaderExpectAssumeFeatures";
+    const NAMES: &'static [&'static str] = &["shader_expect_assume"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_expect_assume);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_expect_assume);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderFloat16Int8Features {
+    const NAME: &'static str = "VkPhysicalDeviceShaderFloat16Int8Features";
+    const NAMES: &'static [&'static str] = &["shader_float16", "shader_int8"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_float16);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_float16);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderFloat8FeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceShaderFloat8FeaturesEXT";
+    const NAMES: &'static [&'static str] = &["shader_float8", "shader_float8_cooperative_matrix"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_float8);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_float8);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderFloatControls2Features {
+    const NAME: &'static str = "VkPhysicalDeviceShaderFloatControls2Features";
+    const NAMES: &'static [&'static str] = &["shader_float_controls2"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_float_controls2);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_float_controls2);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderFmaFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceShaderFmaFeaturesKHR";
+    const NAMES: &'static [&'static str] = &[
+        "shader_fma_float16",
+        "shader_fma_float32",
+        "shader_fma_float64",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_fma_float16);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_fma_float16);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderImageAtomicInt64FeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT";
+    const NAMES: &'static [&'static str] =
+        &["shader_image_int64_atomics", "sparse_image_int64_atomics"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_image_int64_atomics);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_image_int64_atomics);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderImageFootprintFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceShaderImageFootprintFeaturesNV";
+    const NAMES: &'static [&'static str] = &["image_footprint"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, image_footprint);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, image_footprint);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderIntegerDotProductFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceShaderIntegerDotProductFeatures";
+    const NAMES: &'static [&'static str] = &["shader_integer_dot_product"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_integer_dot_product);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_integer_dot_product);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL {
+    const NAME: &'static str = "VkPhysicalDeviceShaderIntegerFunctions2FeaturesINTEL";
+    const NAMES: &'static [&'static str] = &["shader_integer_functions2"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_integer_functions2);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_integer_functions2);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderLongVectorFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceShaderLongVectorFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["long_vector"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, long_vector);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, long_vector);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderMaximalReconvergenceFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceShaderMaximalReconvergenceFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["shader_maximal_reconvergence"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_maximal_reconvergence);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_maximal_reconvergence);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderModuleIdentifierFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceShaderModuleIdentifierFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["shader_module_identifier"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_module_identifier);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_module_identifier);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderObjectFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceShaderObjectFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["shader_object"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_object);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_object);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderQuadControlFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceShaderQuadControlFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["shader_quad_control"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_quad_control);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_quad_control);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderReplicatedCompositesFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceShaderReplicatedCompositesFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["shader_replicated_composites"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_replicated_composites);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_replicated_composites);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderSMBuiltinsFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceShaderSMBuiltinsFeaturesNV";
+    const NAMES: &'static [&'static str] = &["shader_sm_builtins"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_sm_builtins);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_sm_builtins);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderSubgroupExtendedTypesFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures";
+    const NAMES: &'static [&'static str] = &["shader_subgroup_extended_types"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_subgroup_extended_types);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_subgroup_extended_types);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderSubgroupPartitionedFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceShaderSubgroupPartitionedFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["shader_subgroup_partitioned"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_subgroup_partitioned);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_subgroup_partitioned);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderSubgroupRotateFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceShaderSubgroupRotateFeatures";
+    const NAMES: &'static [&'static str] =
+        &["shader_subgroup_rotate", "shader_subgroup_rotate_clustered"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_subgroup_rotate);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_subgroup_rotate);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderTerminateInvocationFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceShaderTerminateInvocationFeatures";
+    const NAMES: &'static [&'static str] = &["shader_terminate_invocation"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_terminate_invocation);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_terminate_invocation);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderTileImageFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceShaderTileImageFeaturesEXT";
+    const NAMES: &'static [&'static str] = &[
+        "shader_tile_image_color_read_access",
+        "shader_tile_image_depth_read_access",
+        "shader_tile_image_stencil_read_access",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_tile_image_color_read_access);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_tile_image_color_read_access);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShaderUntypedPointersFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceShaderUntypedPointersFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["shader_untyped_pointers"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_untyped_pointers);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_untyped_pointers);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceShadingRateImageFeaturesNV {
+    const NAME: &'static str = "VkPhysicalDeviceShadingRateImageFeaturesNV";
+    const NAMES: &'static [&'static str] =
+        &["shading_rate_image", "shading_rate_coarse_sample_order"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shading_rate_image);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shading_rate_image);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceSubgroupSizeControlFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceSubgroupSizeControlFeatures";
+    const NAMES: &'static [&'static str] = &["subgroup_size_control", "compute_full_subgroups"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, subgroup_size_control);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, subgroup_size_control);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceSubpassMergeFeedbackFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceSubpassMergeFeedbackFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["subpass_merge_feedback"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, subpass_merge_feedback);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, subpass_merge_feedback);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceSubpassShadingFeaturesHUAWEI {
+    const NAME: &'static str = "VkPhysicalDeviceSubpassShadingFeaturesHUAWEI";
+    const NAMES: &'static [&'static str] = &["subpass_shading"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, subpass_shading);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, subpass_shading);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceSwapchainMaintenance1FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceSwapchainMaintenance1FeaturesKHR";
+    const NAMES: &'static [&'static str] = &["swapchain_maintenance1"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, swapchain_maintenance1);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, swapchain_maintenance1);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceSynchronization2Features {
+    const NAME: &'static str = "VkPhysicalDeviceSynchronization2Features";
+    const NAMES: &'static [&'static str] = &["synchronization2"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, synchronization2);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, synchronization2);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceTensorFeaturesARM {
+    const NAME: &'static str = "VkPhysicalDeviceTensorFeaturesARM";
+    const NAMES: &'static [&'static str] = &[
+        "tensor_non_packed",
+        "shader_tensor_access",
+        "shader_storage_tensor_array_dynamic_indexing",
+        "shader_storage_tensor_array_non_uniform_indexing",
+        "descriptor_binding_storage_tensor_update_after_bind",
+        "tensors",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, tensor_non_packed);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 6) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, tensor_non_packed);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 6) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceTexelBufferAlignmentFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceTexelBufferAlignmentFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["texel_buffer_alignment"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, texel_buffer_alignment);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, texel_buffer_alignment);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceTextureCompressionASTC3DFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceTextureCompressionASTC3DFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["textureCompressionASTC_3D"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, textureCompressionASTC_3D);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, textureCompressionASTC_3D);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceTextureCompressionASTCHDRFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceTextureCompressionASTCHDRFeatures";
+    const NAMES: &'static [&'static str] = &["texture_compression_astc_hdr"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, texture_compression_astc_hdr);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, texture_compression_astc_hdr);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceTileMemoryHeapFeaturesQCOM {
+    const NAME: &'static str = "VkPhysicalDeviceTileMemoryHeapFeaturesQCOM";
+    const NAMES: &'static [&'static str] = &["tile_memory_heap"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, tile_memory_heap);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, tile_memory_heap);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceTilePropertiesFeaturesQCOM {
+    const NAME: &'static str = "VkPhysicalDeviceTilePropertiesFeaturesQCOM";
+    const NAMES: &'static [&'static str] = &["tile_properties"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, tile_properties);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, tile_properties);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceTileShadingFeaturesQCOM {
+    const NAME: &'static str = "VkPhysicalDeviceTileShadingFeaturesQCOM";
+    const NAMES: &'static [&'static str] = &[
+        "tile_shading",
+        "tile_shading_fragment_stage",
+        "tile_shading_color_attachments",
+        "tile_shading_depth_attachments",
+        "tile_shading_stencil_attachments",
+        "tile_shading_input_attachments",
+        "tile_shading_sampled_attachments",
+        "tile_shading_per_tile_draw",
+        "tile_shading_per_tile_dispatch",
+        "tile_shading_dispatch_tile",
+        "tile_shading_apron",
+        "tile_shading_anisotropic_apron",
+        "tile_shading_atomic_ops",
+        "tile_shading_image_processing",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, tile_shading);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 14) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, tile_shading);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 14) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceTimelineSemaphoreFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceTimelineSemaphoreFeatures";
+    const NAMES: &'static [&'static str] = &["timeline_semaphore"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, timeline_semaphore);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, timeline_semaphore);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceTransformFeedbackFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceTransformFeedbackFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["transform_feedback", "geometry_streams"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, transform_feedback);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, transform_feedback);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceUnifiedImageLayoutsFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceUnifiedImageLayoutsFeaturesKHR";
+    const NAMES: &'static [&'static str] =
+        &["unified_image_layouts", "unified_image_layouts_video"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, unified_image_layouts);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, unified_image_layouts);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceUniformBufferStandardLayoutFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceUniformBufferStandardLayoutFeatures";
+    const NAMES: &'static [&'static str] = &["uniform_buffer_standard_layout"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, uniform_buffer_standard_layout);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, uniform_buffer_standard_layout);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVariablePointersFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceVariablePointersFeatures";
+    const NAMES: &'static [&'static str] =
+        &["variable_pointers_storage_buffer", "variable_pointers"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, variable_pointers_storage_buffer);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, variable_pointers_storage_buffer);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVertexAttributeDivisorFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceVertexAttributeDivisorFeatures";
+    const NAMES: &'static [&'static str] = &[
+        "vertex_attribute_instance_rate_divisor",
+        "vertex_attribute_instance_rate_zero_divisor",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, vertex_attribute_instance_rate_divisor);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 2) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, vertex_attribute_instance_rate_divisor);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 2) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVertexAttributeRobustnessFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceVertexAttributeRobustnessFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["vertex_attribute_robustness"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, vertex_attribute_robustness);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, vertex_attribute_robustness);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVertexInputDynamicStateFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceVertexInputDynamicStateFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["vertex_input_dynamic_state"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, vertex_input_dynamic_state);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, vertex_input_dynamic_state);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVideoDecodeVP9FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceVideoDecodeVP9FeaturesKHR";
+    const NAMES: &'static [&'static str] = &["video_decode_vp9"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, video_decode_vp9);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, video_decode_vp9);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVideoEncodeAV1FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceVideoEncodeAV1FeaturesKHR";
+    const NAMES: &'static [&'static str] = &["video_encode_av1"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, video_encode_av1);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, video_encode_av1);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVideoEncodeIntraRefreshFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceVideoEncodeIntraRefreshFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["video_encode_intra_refresh"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, video_encode_intra_refresh);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, video_encode_intra_refresh);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVideoEncodeQuantizationMapFeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceVideoEncodeQuantizationMapFeaturesKHR";
+    const NAMES: &'static [&'static str] = &["video_encode_quantization_map"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, video_encode_quantization_map);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, video_encode_quantization_map);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVideoEncodeRgbConversionFeaturesVALVE {
+    const NAME: &'static str = "VkPhysicalDeviceVideoEncodeRgbConversionFeaturesVALVE";
+    const NAMES: &'static [&'static str] = &["video_encode_rgb_conversion"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, video_encode_rgb_conversion);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, video_encode_rgb_conversion);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVideoMaintenance1FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceVideoMaintenance1FeaturesKHR";
+    const NAMES: &'static [&'static str] = &["video_maintenance1"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, video_maintenance1);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, video_maintenance1);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVideoMaintenance2FeaturesKHR {
+    const NAME: &'static str = "VkPhysicalDeviceVideoMaintenance2FeaturesKHR";
+    const NAMES: &'static [&'static str] = &["video_maintenance2"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, video_maintenance2);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, video_maintenance2);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVulkan11Features {
+    const NAME: &'static str = "VkPhysicalDeviceVulkan11Features";
+    const NAMES: &'static [&'static str] = &[
+        "storage_buffer_16bit_access",
+        "uniform_and_storage_buffer_16bit_access",
+        "storage_push_constant16",
+        "storage_input_output16",
+        "multiview",
+        "multiview_geometry_shader",
+        "multiview_tessellation_shader",
+        "variable_pointers_storage_buffer",
+        "variable_pointers",
+        "protected_memory",
+        "sampler_ycbcr_conversion",
+        "shader_draw_parameters",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, storage_buffer_16bit_access);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 12) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, storage_buffer_16bit_access);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 12) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVulkan12Features {
+    const NAME: &'static str = "VkPhysicalDeviceVulkan12Features";
+    const NAMES: &'static [&'static str] = &[
+        "sampler_mirror_clamp_to_edge",
+        "draw_indirect_count",
+        "storage_buffer_8bit_access",
+        "uniform_and_storage_buffer_8bit_access",
+        "storage_push_constant8",
+        "shader_buffer_int64_atomics",
+        "shader_shared_int64_atomics",
+        "shader_float16",
+        "shader_int8",
+        "descriptor_indexing",
+        "shader_input_attachment_array_dynamic_indexing",
+        "shader_uniform_texel_buffer_array_dynamic_indexing",
+        "shader_storage_texel_buffer_array_dynamic_indexing",
+        "shader_uniform_buffer_array_non_uniform_indexing",
+        "shader_sampled_image_array_non_uniform_indexing",
+        "shader_storage_buffer_array_non_uniform_indexing",
+        "shader_storage_image_array_non_uniform_indexing",
+        "shader_input_attachment_array_non_uniform_indexing",
+        "shader_uniform_texel_buffer_array_non_uniform_indexing",
+        "shader_storage_texel_buffer_array_non_uniform_indexing",
+        "descriptor_binding_uniform_buffer_update_after_bind",
+        "descriptor_binding_sampled_image_update_after_bind",
+        "descriptor_binding_storage_image_update_after_bind",
+        "descriptor_binding_storage_buffer_update_after_bind",
+        "descriptor_binding_uniform_texel_buffer_update_after_bind",
+        "descriptor_binding_storage_texel_buffer_update_after_bind",
+        "descriptor_binding_update_unused_while_pending",
+        "descriptor_binding_partially_bound",
+        "descriptor_binding_variable_descriptor_count",
+        "runtime_descriptor_array",
+        "sampler_filter_minmax",
+        "scalar_block_layout",
+        "imageless_framebuffer",
+        "uniform_buffer_standard_layout",
+        "shader_subgroup_extended_types",
+        "separate_depth_stencil_layouts",
+        "host_query_reset",
+        "timeline_semaphore",
+        "buffer_device_address",
+        "buffer_device_address_capture_replay",
+        "buffer_device_address_multi_device",
+        "vulkan_memory_model",
+        "vulkan_memory_model_device_scope",
+        "vulkan_memory_model_availability_visibility_chains",
+        "shader_output_viewport_index",
+        "shader_output_layer",
+        "subgroup_broadcast_dynamic_id",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, sampler_mirror_clamp_to_edge);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 47) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, sampler_mirror_clamp_to_edge);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 47) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVulkan13Features {
+    const NAME: &'static str = "VkPhysicalDeviceVulkan13Features";
+    const NAMES: &'static [&'static str] = &[
+        "robust_image_access",
+        "inline_uniform_block",
+        "descriptor_binding_inline_uniform_block_update_after_bind",
+        "pipeline_creation_cache_control",
+        "private_data",
+        "shader_demote_to_helper_invocation",
+        "shader_terminate_invocation",
+        "subgroup_size_control",
+        "compute_full_subgroups",
+        "synchronization2",
+        "texture_compression_astc_hdr",
+        "shader_zero_initialize_workgroup_memory",
+        "dynamic_rendering",
+        "shader_integer_dot_product",
+        "maintenance4",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, robust_image_access);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 15) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, robust_image_access);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 15) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVulkan14Features {
+    const NAME: &'static str = "VkPhysicalDeviceVulkan14Features";
+    const NAMES: &'static [&'static str] = &[
+        "global_priority_query",
+        "shader_subgroup_rotate",
+        "shader_subgroup_rotate_clustered",
+        "shader_float_controls2",
+        "shader_expect_assume",
+        "rectangular_lines",
+        "bresenham_lines",
+        "smooth_lines",
+        "stippled_rectangular_lines",
+        "stippled_bresenham_lines",
+        "stippled_smooth_lines",
+        "vertex_attribute_instance_rate_divisor",
+        "vertex_attribute_instance_rate_zero_divisor",
+        "index_type_uint8",
+        "dynamic_rendering_local_read",
+        "maintenance5",
+        "maintenance6",
+        "pipeline_protected_access",
+        "pipeline_robustness",
+        "host_image_copy",
+        "push_descriptor",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, global_priority_query);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 21) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, global_priority_query);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 21) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceVulkanMemoryModelFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceVulkanMemoryModelFeatures";
+    const NAMES: &'static [&'static str] = &[
+        "vulkan_memory_model",
+        "vulkan_memory_model_device_scope",
+        "vulkan_memory_model_availability_visibility_chains",
+    ];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, vulkan_memory_model);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 3) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, vulkan_memory_model);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 3) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["ycbcr2plane444_formats"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, ycbcr2plane444_formats);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, ycbcr2plane444_formats);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceYcbcrDegammaFeaturesQCOM {
+    const NAME: &'static str = "VkPhysicalDeviceYcbcrDegammaFeaturesQCOM";
+    const NAMES: &'static [&'static str] = &["ycbcr_degamma"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, ycbcr_degamma);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, ycbcr_degamma);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceYcbcrImageArraysFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceYcbcrImageArraysFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["ycbcr_image_arrays"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, ycbcr_image_arrays);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, ycbcr_image_arrays);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceZeroInitializeDeviceMemoryFeaturesEXT {
+    const NAME: &'static str = "VkPhysicalDeviceZeroInitializeDeviceMemoryFeaturesEXT";
+    const NAMES: &'static [&'static str] = &["zero_initialize_device_memory"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, zero_initialize_device_memory);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, zero_initialize_device_memory);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+unsafe impl Features for PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures {
+    const NAME: &'static str = "VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeatures";
+    const NAMES: &'static [&'static str] = &["shader_zero_initialize_workgroup_memory"];
+
+    #[inline]
+    fn flags(&self) -> &[Bool32] {
+        let offset = mem::offset_of!(Self, shader_zero_initialize_workgroup_memory);
+        unsafe { slice::from_raw_parts(ptr::from_ref(self).byte_add(offset).cast(), 1) }
+    }
+
+    #[inline]
+    fn flags_mut(&mut self) -> &mut [Bool32] {
+        let offset = mem::offset_of!(Self, shader_zero_initialize_workgroup_memory);
+        unsafe { slice::from_raw_parts_mut(ptr::from_mut(self).byte_add(offset).cast(), 1) }
+    }
+}
+
+impl FeatureSet {
+    /// Constructs a new empty set of features which contains every feature
+    /// struct provided by a Vulkan version or by any of a set of device
+    /// extensions (e.g., the extensions supported by a physical device).
+    #[allow(deprecated)]
+    pub fn all(version: Version, extensions: &BTreeSet<ExtensionName>) -> Self {
+        let mut set = Self::new();
+        if version >= Version::V1_1_0 || extensions.contains(&KHR_16BIT_STORAGE_EXTENSION.name) {
+            set.insert(PhysicalDevice16BitStorageFeatures::default());
+        }
+        if extensions.contains(&EXT_4444_FORMATS_EXTENSION.name) {
+            set.insert(PhysicalDevice4444FormatsFeaturesEXT::default());
+        }
+        if version >= Version::V1_2_0 || extensions.contains(&KHR_8BIT_STORAGE_EXTENSION.name) {
+            set.insert(PhysicalDevice8BitStorageFeatures::default());
+        }
+        if extensions.contains(&EXT_ASTC_DECODE_MODE_EXTENSION.name) {
+            set.insert(PhysicalDeviceASTCDecodeFeaturesEXT::default());
+        }
+        if extensions.contains(&KHR_ACCELERATION_STRUCTURE_EXTENSION.name) {
+            set.insert(PhysicalDeviceAccelerationStructureFeaturesKHR::default());
+        }
+        if extensions.contains(&EXT_DEVICE_ADDRESS_BINDING_REPORT_EXTENSION.name) {
+            set.insert(PhysicalDeviceAddressBindingReportFeaturesEXT::default());
+        }
+        if extensions.contains(&SEC_AMIGO_PROFILING_EXTENSION.name) {
+            set.insert(PhysicalDeviceAmigoProfilingFeaturesSEC::default());
+        }
+        if extensions.contains(&AMD_ANTI_LAG_EXTENSION.name) {
+            set.insert(PhysicalDeviceAntiLagFeaturesAMD::default());
+        }
+        if extensions.contains(&EXT_BLEND_OPERATION_ADVANCED_EXTENSION.name) {
+            set.insert(PhysicalDeviceBlendOperationAdvancedFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_BORDER_COLOR_SWIZZLE_EXTENSION.name) {
+            set.insert(PhysicalDeviceBorderColorSwizzleFeaturesEXT::default());
+        }
+        if version >= Version::V1_2_0
+            || extensions.contains(&KHR_BUFFER_DEVICE_ADDRESS_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceBufferDeviceAddressFeatures::default());
+        }
+        if extensions.contains(&EXT_BUFFER_DEVICE_ADDRESS_EXTENSION.name) {
+            set.insert(PhysicalDeviceBufferDeviceAddressFeaturesEXT::default());
+        }
+        if extensions.contains(&HUAWEI_CLUSTER_CULLING_SHADER_EXTENSION.name) {
+            set.insert(PhysicalDeviceClusterCullingShaderFeaturesHUAWEI::default());
+        }
+        if extensions.contains(&AMD_DEVICE_COHERENT_MEMORY_EXTENSION.name) {
+            set.insert(PhysicalDeviceCoherentMemoryFeaturesAMD::default());
+        }
+        if extensions.contains(&EXT_COLOR_WRITE_ENABLE_EXTENSION.name) {
+            set.insert(PhysicalDeviceColorWriteEnableFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_COMMAND_BUFFER_INHERITANCE_EXTENSION.name) {
+            set.insert(PhysicalDeviceCommandBufferInheritanceFeaturesNV::default());
+        }
+        if extensions.contains(&NV_COMPUTE_OCCUPANCY_PRIORITY_EXTENSION.name) {
+            set.insert(PhysicalDeviceComputeOccupancyPriorityFeaturesNV::default());
+        }
+        if extensions.contains(&KHR_COMPUTE_SHADER_DERIVATIVES_EXTENSION.name)
+            || extensions.contains(&NV_COMPUTE_SHADER_DERIVATIVES_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceComputeShaderDerivativesFeaturesKHR::default());
+        }
+        if extensions.contains(&EXT_CONDITIONAL_RENDERING_EXTENSION.name) {
+            set.insert(PhysicalDeviceConditionalRenderingFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_COOPERATIVE_MATRIX2_EXTENSION.name) {
+            set.insert(PhysicalDeviceCooperativeMatrix2FeaturesNV::default());
+        }
+        if extensions.contains(&KHR_COOPERATIVE_MATRIX_EXTENSION.name) {
+            set.insert(PhysicalDeviceCooperativeMatrixFeaturesKHR::default());
+        }
+        if extensions.contains(&NV_COOPERATIVE_MATRIX_EXTENSION.name) {
+            set.insert(PhysicalDeviceCooperativeMatrixFeaturesNV::default());
+        }
+        if extensions.contains(&NV_COOPERATIVE_VECTOR_EXTENSION.name) {
+            set.insert(PhysicalDeviceCooperativeVectorFeaturesNV::default());
+        }
+        if extensions.contains(&KHR_COPY_MEMORY_INDIRECT_EXTENSION.name) {
+            set.insert(PhysicalDeviceCopyMemoryIndirectFeaturesKHR::default());
+        }
+        if extensions.contains(&NV_COPY_MEMORY_INDIRECT_EXTENSION.name) {
+            set.insert(PhysicalDeviceCopyMemoryIndirectFeaturesNV::default());
+        }
+        if extensions.contains(&NV_CORNER_SAMPLED_IMAGE_EXTENSION.name) {
+            set.insert(PhysicalDeviceCornerSampledImageFeaturesNV::default());
+        }
+        if extensions.contains(&NV_COVERAGE_REDUCTION_MODE_EXTENSION.name) {
+            set.insert(PhysicalDeviceCoverageReductionModeFeaturesNV::default());
+        }
+        if extensions.contains(&QCOM_FILTER_CUBIC_CLAMP_EXTENSION.name) {
+            set.insert(PhysicalDeviceCubicClampFeaturesQCOM::default());
+        }
+        if extensions.contains(&QCOM_FILTER_CUBIC_WEIGHTS_EXTENSION.name) {
+            set.insert(PhysicalDeviceCubicWeightsFeaturesQCOM::default());
+        }
+        #[cfg(feature = "provisional")]
+        if extensions.contains(&NV_CUDA_KERNEL_LAUNCH_EXTENSION.name) {
+            set.insert(PhysicalDeviceCudaKernelLaunchFeaturesNV::default());
+        }
+        if extensions.contains(&EXT_CUSTOM_BORDER_COLOR_EXTENSION.name) {
+            set.insert(PhysicalDeviceCustomBorderColorFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_CUSTOM_RESOLVE_EXTENSION.name) {
+            set.insert(PhysicalDeviceCustomResolveFeaturesEXT::default());
+        }
+        if extensions.contains(&ARM_DATA_GRAPH_EXTENSION.name) {
+            set.insert(PhysicalDeviceDataGraphFeaturesARM::default());
+        }
+        if extensions.contains(&QCOM_DATA_GRAPH_MODEL_EXTENSION.name) {
+            set.insert(PhysicalDeviceDataGraphModelFeaturesQCOM::default());
+        }
+        #[cfg(feature = "provisional")]
+        if extensions.contains(&AMDX_DENSE_GEOMETRY_FORMAT_EXTENSION.name) {
+            set.insert(PhysicalDeviceDenseGeometryFormatFeaturesAMDX::default());
+        }
+        if extensions.contains(&EXT_DEPTH_BIAS_CONTROL_EXTENSION.name) {
+            set.insert(PhysicalDeviceDepthBiasControlFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_DEPTH_CLAMP_CONTROL_EXTENSION.name) {
+            set.insert(PhysicalDeviceDepthClampControlFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_DEPTH_CLAMP_ZERO_ONE_EXTENSION.name)
+            || extensions.contains(&KHR_DEPTH_CLAMP_ZERO_ONE_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceDepthClampZeroOneFeaturesKHR::default());
+        }
+        if extensions.contains(&EXT_DEPTH_CLIP_CONTROL_EXTENSION.name) {
+            set.insert(PhysicalDeviceDepthClipControlFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_DEPTH_CLIP_ENABLE_EXTENSION.name) {
+            set.insert(PhysicalDeviceDepthClipEnableFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_DESCRIPTOR_BUFFER_EXTENSION.name) {
+            set.insert(PhysicalDeviceDescriptorBufferFeaturesEXT::default());
+        }
+        if extensions.contains(&ARM_TENSORS_EXTENSION.name) {
+            set.insert(PhysicalDeviceDescriptorBufferTensorFeaturesARM::default());
+        }
+        if extensions.contains(&EXT_DESCRIPTOR_HEAP_EXTENSION.name) {
+            set.insert(PhysicalDeviceDescriptorHeapFeaturesEXT::default());
+        }
+        if version >= Version::V1_2_0
+            || extensions.contains(&EXT_DESCRIPTOR_INDEXING_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceDescriptorIndexingFeatures::default());
+        }
+        if extensions.contains(&VALVE_DESCRIPTOR_SET_HOST_MAPPING_EXTENSION.name) {
+            set.insert(PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE::default());
+        }
+        if extensions.contains(&EXT_DEVICE_GENERATED_COMMANDS_EXTENSION.name) {
+            set.insert(PhysicalDeviceDeviceGeneratedCommandsFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_DEVICE_GENERATED_COMMANDS_EXTENSION.name) {
+            set.insert(PhysicalDeviceDeviceGeneratedCommandsFeaturesNV::default());
+        }
+        if extensions.contains(&EXT_DEVICE_MEMORY_REPORT_EXTENSION.name) {
+            set.insert(PhysicalDeviceDeviceMemoryReportFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_DEVICE_DIAGNOSTICS_CONFIG_EXTENSION.name) {
+            set.insert(PhysicalDeviceDiagnosticsConfigFeaturesNV::default());
+        }
+        #[cfg(feature = "provisional")]
+        if extensions.contains(&NV_DISPLACEMENT_MICROMAP_EXTENSION.name) {
+            set.insert(PhysicalDeviceDisplacementMicromapFeaturesNV::default());
+        }
+        if version >= Version::V1_3_0 || extensions.contains(&KHR_DYNAMIC_RENDERING_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceDynamicRenderingFeatures::default());
+        }
+        if version >= Version::V1_4_0
+            || extensions.contains(&KHR_DYNAMIC_RENDERING_LOCAL_READ_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceDynamicRenderingLocalReadFeatures::default());
+        }
+        if extensions.contains(&NV_SCISSOR_EXCLUSIVE_EXTENSION.name) {
+            set.insert(PhysicalDeviceExclusiveScissorFeaturesNV::default());
+        }
+        if extensions.contains(&EXT_EXTENDED_DYNAMIC_STATE2_EXTENSION.name) {
+            set.insert(PhysicalDeviceExtendedDynamicState2FeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_EXTENDED_DYNAMIC_STATE3_EXTENSION.name) {
+            set.insert(PhysicalDeviceExtendedDynamicState3FeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_EXTENDED_DYNAMIC_STATE_EXTENSION.name) {
+            set.insert(PhysicalDeviceExtendedDynamicStateFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_EXTENDED_SPARSE_ADDRESS_SPACE_EXTENSION.name) {
+            set.insert(PhysicalDeviceExtendedSparseAddressSpaceFeaturesNV::default());
+        }
+        if extensions.contains(&ANDROID_EXTERNAL_FORMAT_RESOLVE_EXTENSION.name) {
+            set.insert(PhysicalDeviceExternalFormatResolveFeaturesANDROID::default());
+        }
+        if extensions.contains(&NV_EXTERNAL_MEMORY_RDMA_EXTENSION.name) {
+            set.insert(PhysicalDeviceExternalMemoryRDMAFeaturesNV::default());
+        }
+        if extensions.contains(&NV_EXTERNAL_MEMORY_SCI_BUF_EXTENSION.name) {
+            set.insert(PhysicalDeviceExternalMemorySciBufFeaturesNV::default());
+        }
+        if extensions.contains(&QNX_EXTERNAL_MEMORY_SCREEN_BUFFER_EXTENSION.name) {
+            set.insert(PhysicalDeviceExternalMemoryScreenBufferFeaturesQNX::default());
+        }
+        if extensions.contains(&NV_EXTERNAL_SCI_SYNC2_EXTENSION.name) {
+            set.insert(PhysicalDeviceExternalSciSync2FeaturesNV::default());
+        }
+        if extensions.contains(&NV_EXTERNAL_SCI_SYNC_EXTENSION.name) {
+            set.insert(PhysicalDeviceExternalSciSyncFeaturesNV::default());
+        }
+        if extensions.contains(&EXT_DEVICE_FAULT_EXTENSION.name) {
+            set.insert(PhysicalDeviceFaultFeaturesEXT::default());
+        }
+        if extensions.contains(&ARM_FORMAT_PACK_EXTENSION.name) {
+            set.insert(PhysicalDeviceFormatPackFeaturesARM::default());
+        }
+        if extensions.contains(&EXT_FRAGMENT_DENSITY_MAP2_EXTENSION.name) {
+            set.insert(PhysicalDeviceFragmentDensityMap2FeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_FRAGMENT_DENSITY_MAP_EXTENSION.name) {
+            set.insert(PhysicalDeviceFragmentDensityMapFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_FRAGMENT_DENSITY_MAP_OFFSET_EXTENSION.name)
+            || extensions.contains(&QCOM_FRAGMENT_DENSITY_MAP_OFFSET_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceFragmentDensityMapOffsetFeaturesEXT::default());
+        }
+        if extensions.contains(&KHR_FRAGMENT_SHADER_BARYCENTRIC_EXTENSION.name)
+            || extensions.contains(&NV_FRAGMENT_SHADER_BARYCENTRIC_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceFragmentShaderBarycentricFeaturesKHR::default());
+        }
+        if extensions.contains(&EXT_FRAGMENT_SHADER_INTERLOCK_EXTENSION.name) {
+            set.insert(PhysicalDeviceFragmentShaderInterlockFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_FRAGMENT_SHADING_RATE_ENUMS_EXTENSION.name) {
+            set.insert(PhysicalDeviceFragmentShadingRateEnumsFeaturesNV::default());
+        }
+        if extensions.contains(&KHR_FRAGMENT_SHADING_RATE_EXTENSION.name) {
+            set.insert(PhysicalDeviceFragmentShadingRateFeaturesKHR::default());
+        }
+        if extensions.contains(&EXT_FRAME_BOUNDARY_EXTENSION.name) {
+            set.insert(PhysicalDeviceFrameBoundaryFeaturesEXT::default());
+        }
+        if version >= Version::V1_4_0
+            || extensions.contains(&EXT_GLOBAL_PRIORITY_QUERY_EXTENSION.name)
+            || extensions.contains(&KHR_GLOBAL_PRIORITY_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceGlobalPriorityQueryFeatures::default());
+        }
+        if extensions.contains(&EXT_GRAPHICS_PIPELINE_LIBRARY_EXTENSION.name) {
+            set.insert(PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT::default());
+        }
+        if extensions.contains(&HUAWEI_HDR_VIVID_EXTENSION.name) {
+            set.insert(PhysicalDeviceHdrVividFeaturesHUAWEI::default());
+        }
+        if version >= Version::V1_4_0 || extensions.contains(&EXT_HOST_IMAGE_COPY_EXTENSION.name) {
+            set.insert(PhysicalDeviceHostImageCopyFeatures::default());
+        }
+        if version >= Version::V1_2_0 || extensions.contains(&EXT_HOST_QUERY_RESET_EXTENSION.name) {
+            set.insert(PhysicalDeviceHostQueryResetFeatures::default());
+        }
+        if extensions.contains(&EXT_IMAGE_2D_VIEW_OF_3D_EXTENSION.name) {
+            set.insert(PhysicalDeviceImage2DViewOf3DFeaturesEXT::default());
+        }
+        if extensions.contains(&MESA_IMAGE_ALIGNMENT_CONTROL_EXTENSION.name) {
+            set.insert(PhysicalDeviceImageAlignmentControlFeaturesMESA::default());
+        }
+        if extensions.contains(&EXT_IMAGE_COMPRESSION_CONTROL_EXTENSION.name) {
+            set.insert(PhysicalDeviceImageCompressionControlFeaturesEXT::default());
+        }
+        if extensions.contains(&QCOM_IMAGE_PROCESSING2_EXTENSION.name) {
+            set.insert(PhysicalDeviceImageProcessing2FeaturesQCOM::default());
+        }
+        if extensions.contains(&QCOM_IMAGE_PROCESSING_EXTENSION.name) {
+            set.insert(PhysicalDeviceImageProcessingFeaturesQCOM::default());
+        }
+        if version >= Version::V1_3_0 || extensions.contains(&EXT_IMAGE_ROBUSTNESS_EXTENSION.name) {
+            set.insert(PhysicalDeviceImageRobustnessFeatures::default());
+        }
+        if extensions.contains(&EXT_IMAGE_SLICED_VIEW_OF_3D_EXTENSION.name) {
+            set.insert(PhysicalDeviceImageSlicedViewOf3DFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_IMAGE_VIEW_MIN_LOD_EXTENSION.name) {
+            set.insert(PhysicalDeviceImageViewMinLodFeaturesEXT::default());
+        }
+        if version >= Version::V1_2_0
+            || extensions.contains(&KHR_IMAGELESS_FRAMEBUFFER_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceImagelessFramebufferFeatures::default());
+        }
+        if version >= Version::V1_4_0
+            || extensions.contains(&EXT_INDEX_TYPE_UINT8_EXTENSION.name)
+            || extensions.contains(&KHR_INDEX_TYPE_UINT8_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceIndexTypeUint8Features::default());
+        }
+        if extensions.contains(&NV_INHERITED_VIEWPORT_SCISSOR_EXTENSION.name) {
+            set.insert(PhysicalDeviceInheritedViewportScissorFeaturesNV::default());
+        }
+        if version >= Version::V1_3_0
+            || extensions.contains(&EXT_INLINE_UNIFORM_BLOCK_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceInlineUniformBlockFeatures::default());
+        }
+        if extensions.contains(&HUAWEI_INVOCATION_MASK_EXTENSION.name) {
+            set.insert(PhysicalDeviceInvocationMaskFeaturesHUAWEI::default());
+        }
+        if extensions.contains(&EXT_LEGACY_DITHERING_EXTENSION.name) {
+            set.insert(PhysicalDeviceLegacyDitheringFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_LEGACY_VERTEX_ATTRIBUTES_EXTENSION.name) {
+            set.insert(PhysicalDeviceLegacyVertexAttributesFeaturesEXT::default());
+        }
+        if version >= Version::V1_4_0
+            || extensions.contains(&EXT_LINE_RASTERIZATION_EXTENSION.name)
+            || extensions.contains(&KHR_LINE_RASTERIZATION_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceLineRasterizationFeatures::default());
+        }
+        if extensions.contains(&NV_LINEAR_COLOR_ATTACHMENT_EXTENSION.name) {
+            set.insert(PhysicalDeviceLinearColorAttachmentFeaturesNV::default());
+        }
+        if extensions.contains(&KHR_MAINTENANCE10_EXTENSION.name) {
+            set.insert(PhysicalDeviceMaintenance10FeaturesKHR::default());
+        }
+        if version >= Version::V1_3_0 || extensions.contains(&KHR_MAINTENANCE4_EXTENSION.name) {
+            set.insert(PhysicalDeviceMaintenance4Features::default());
+        }
+        if version >= Version::V1_4_0 || extensions.contains(&KHR_MAINTENANCE5_EXTENSION.name) {
+            set.insert(PhysicalDeviceMaintenance5Features::default());
+        }
+        if version >= Version::V1_4_0 || extensions.contains(&KHR_MAINTENANCE6_EXTENSION.name) {
+            set.insert(PhysicalDeviceMaintenance6Features::default());
+        }
+        if extensions.contains(&KHR_MAINTENANCE7_EXTENSION.name) {
+            set.insert(PhysicalDeviceMaintenance7FeaturesKHR::default());
+        }
+        if extensions.contains(&KHR_MAINTENANCE8_EXTENSION.name) {
+            set.insert(PhysicalDeviceMaintenance8FeaturesKHR::default());
+        }
+        if extensions.contains(&KHR_MAINTENANCE9_EXTENSION.name) {
+            set.insert(PhysicalDeviceMaintenance9FeaturesKHR::default());
+        }
+        if extensions.contains(&EXT_MAP_MEMORY_PLACED_EXTENSION.name) {
+            set.insert(PhysicalDeviceMapMemoryPlacedFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_MEMORY_DECOMPRESSION_EXTENSION.name)
+            || extensions.contains(&NV_MEMORY_DECOMPRESSION_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceMemoryDecompressionFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_MEMORY_PRIORITY_EXTENSION.name) {
+            set.insert(PhysicalDeviceMemoryPriorityFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_MESH_SHADER_EXTENSION.name) {
+            set.insert(PhysicalDeviceMeshShaderFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_MESH_SHADER_EXTENSION.name) {
+            set.insert(PhysicalDeviceMeshShaderFeaturesNV::default());
+        }
+        if extensions.contains(&EXT_MULTI_DRAW_EXTENSION.name) {
+            set.insert(PhysicalDeviceMultiDrawFeaturesEXT::default());
+        }
+        if version >= Version::V1_1_0 || extensions.contains(&KHR_MULTIVIEW_EXTENSION.name) {
+            set.insert(PhysicalDeviceMultiviewFeatures::default());
+        }
+        if extensions.contains(&QCOM_MULTIVIEW_PER_VIEW_VIEWPORTS_EXTENSION.name) {
+            set.insert(PhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM::default());
+        }
+        if extensions.contains(&EXT_MUTABLE_DESCRIPTOR_TYPE_EXTENSION.name)
+            || extensions.contains(&VALVE_MUTABLE_DESCRIPTOR_TYPE_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceMutableDescriptorTypeFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_NESTED_COMMAND_BUFFER_EXTENSION.name) {
+            set.insert(PhysicalDeviceNestedCommandBufferFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_NON_SEAMLESS_CUBE_MAP_EXTENSION.name) {
+            set.insert(PhysicalDeviceNonSeamlessCubeMapFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_OPACITY_MICROMAP_EXTENSION.name) {
+            set.insert(PhysicalDeviceOpacityMicromapFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_OPTICAL_FLOW_EXTENSION.name) {
+            set.insert(PhysicalDeviceOpticalFlowFeaturesNV::default());
+        }
+        if extensions.contains(&EXT_PAGEABLE_DEVICE_LOCAL_MEMORY_EXTENSION.name) {
+            set.insert(PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_PER_STAGE_DESCRIPTOR_SET_EXTENSION.name) {
+            set.insert(PhysicalDevicePerStageDescriptorSetFeaturesNV::default());
+        }
+        if extensions.contains(&KHR_PERFORMANCE_QUERY_EXTENSION.name) {
+            set.insert(PhysicalDevicePerformanceQueryFeaturesKHR::default());
+        }
+        if extensions.contains(&KHR_PIPELINE_BINARY_EXTENSION.name) {
+            set.insert(PhysicalDevicePipelineBinaryFeaturesKHR::default());
+        }
+        if version >= Version::V1_3_0
+            || extensions.contains(&EXT_PIPELINE_CREATION_CACHE_CONTROL_EXTENSION.name)
+        {
+            set.insert(PhysicalDevicePipelineCreationCacheControlFeatures::default());
+        }
+        if extensions.contains(&ARM_PIPELINE_OPACITY_MICROMAP_EXTENSION.name) {
+            set.insert(PhysicalDevicePipelineOpacityMicromapFeaturesARM::default());
+        }
+        if extensions.contains(&EXT_PIPELINE_PROPERTIES_EXTENSION.name) {
+            set.insert(PhysicalDevicePipelinePropertiesFeaturesEXT::default());
+        }
+        if version >= Version::V1_4_0
+            || extensions.contains(&EXT_PIPELINE_PROTECTED_ACCESS_EXTENSION.name)
+        {
+            set.insert(PhysicalDevicePipelineProtectedAccessFeatures::default());
+        }
+        if version >= Version::V1_4_0
+            || extensions.contains(&EXT_PIPELINE_ROBUSTNESS_EXTENSION.name)
+        {
+            set.insert(PhysicalDevicePipelineRobustnessFeatures::default());
+        }
+        #[cfg(feature = "provisional")]
+        if extensions.contains(&KHR_PORTABILITY_SUBSET_EXTENSION.name) {
+            set.insert(PhysicalDevicePortabilitySubsetFeaturesKHR::default());
+        }
+        if extensions.contains(&NV_PRESENT_BARRIER_EXTENSION.name) {
+            set.insert(PhysicalDevicePresentBarrierFeaturesNV::default());
+        }
+        if extensions.contains(&KHR_PRESENT_ID2_EXTENSION.name) {
+            set.insert(PhysicalDevicePresentId2FeaturesKHR::default());
+        }
+        if extensions.contains(&KHR_PRESENT_ID_EXTENSION.name) {
+            set.insert(PhysicalDevicePresentIdFeaturesKHR::default());
+        }
+        #[cfg(feature = "provisional")]
+        if extensions.contains(&NV_PRESENT_METERING_EXTENSION.name) {
+            set.insert(PhysicalDevicePresentMeteringFeaturesNV::default());
+        }
+        if extensions.contains(&EXT_PRESENT_MODE_FIFO_LATEST_READY_EXTENSION.name)
+            || extensions.contains(&KHR_PRESENT_MODE_FIFO_LATEST_READY_EXTENSION.name)
+        {
+            set.insert(PhysicalDevicePresentModeFifoLatestReadyFeaturesKHR::default());
+        }
+        if extensions.contains(&EXT_PRESENT_TIMING_EXTENSION.name) {
+            set.insert(PhysicalDevicePresentTimingFeaturesEXT::default());
+        }
+        if extensions.contains(&KHR_PRESENT_WAIT2_EXTENSION.name) {
+            set.insert(PhysicalDevicePresentWait2FeaturesKHR::default());
+        }
+        if extensions.contains(&KHR_PRESENT_WAIT_EXTENSION.name) {
+            set.insert(PhysicalDevicePresentWaitFeaturesKHR::default());
+        }
+        if extensions.contains(&EXT_PRIMITIVES_GENERATED_QUERY_EXTENSION.name) {
+            set.insert(PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT::default());
+        }
+        if version >= Version::V1_3_0 || extensions.contains(&EXT_PRIVATE_DATA_EXTENSION.name) {
+            set.insert(PhysicalDevicePrivateDataFeatures::default());
+        }
+        if version >= Version::V1_1_0 {
+            set.insert(PhysicalDeviceProtectedMemoryFeatures::default());
+        }
+        if extensions.contains(&EXT_PROVOKING_VERTEX_EXTENSION.name) {
+            set.insert(PhysicalDeviceProvokingVertexFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_PUSH_CONSTANT_BANK_EXTENSION.name) {
+            set.insert(PhysicalDevicePushConstantBankFeaturesNV::default());
+        }
+        if extensions.contains(&EXT_RGBA10X6_FORMATS_EXTENSION.name) {
+            set.insert(PhysicalDeviceRGBA10X6FormatsFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_RAW_ACCESS_CHAINS_EXTENSION.name) {
+            set.insert(PhysicalDeviceRawAccessChainsFeaturesNV::default());
+        }
+        if extensions.contains(&KHR_RAY_QUERY_EXTENSION.name) {
+            set.insert(PhysicalDeviceRayQueryFeaturesKHR::default());
+        }
+        if extensions.contains(&NV_RAY_TRACING_INVOCATION_REORDER_EXTENSION.name) {
+            set.insert(PhysicalDeviceRayTracingInvocationReorderFeaturesNV::default());
+        }
+        if extensions.contains(&KHR_RAY_TRACING_MAINTENANCE1_EXTENSION.name) {
+            set.insert(PhysicalDeviceRayTracingMaintenance1FeaturesKHR::default());
+        }
+        if extensions.contains(&NV_RAY_TRACING_MOTION_BLUR_EXTENSION.name) {
+            set.insert(PhysicalDeviceRayTracingMotionBlurFeaturesNV::default());
+        }
+        if extensions.contains(&KHR_RAY_TRACING_PIPELINE_EXTENSION.name) {
+            set.insert(PhysicalDeviceRayTracingPipelineFeaturesKHR::default());
+        }
+        if extensions.contains(&KHR_RAY_TRACING_POSITION_FETCH_EXTENSION.name) {
+            set.insert(PhysicalDeviceRayTracingPositionFetchFeaturesKHR::default());
+        }
+        if extensions.contains(&NV_RAY_TRACING_VALIDATION_EXTENSION.name) {
+            set.insert(PhysicalDeviceRayTracingValidationFeaturesNV::default());
+        }
+        if extensions.contains(&IMG_RELAXED_LINE_RASTERIZATION_EXTENSION.name) {
+            set.insert(PhysicalDeviceRelaxedLineRasterizationFeaturesIMG::default());
+        }
+        if extensions.contains(&ARM_RENDER_PASS_STRIPED_EXTENSION.name) {
+            set.insert(PhysicalDeviceRenderPassStripedFeaturesARM::default());
+        }
+        if extensions.contains(&NV_REPRESENTATIVE_FRAGMENT_TEST_EXTENSION.name) {
+            set.insert(PhysicalDeviceRepresentativeFragmentTestFeaturesNV::default());
+        }
+        if extensions.contains(&EXT_ROBUSTNESS2_EXTENSION.name)
+            || extensions.contains(&KHR_ROBUSTNESS2_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceRobustness2FeaturesKHR::default());
+        }
+        if version >= Version::V1_1_0
+            || extensions.contains(&KHR_SAMPLER_YCBCR_CONVERSION_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceSamplerYcbcrConversionFeatures::default());
+        }
+        if version >= Version::V1_2_0
+            || extensions.contains(&EXT_SCALAR_BLOCK_LAYOUT_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceScalarBlockLayoutFeatures::default());
+        }
+        if extensions.contains(&ARM_SCHEDULING_CONTROLS_EXTENSION.name) {
+            set.insert(PhysicalDeviceSchedulingControlsFeaturesARM::default());
+        }
+        if version >= Version::V1_2_0
+            || extensions.contains(&KHR_SEPARATE_DEPTH_STENCIL_LAYOUTS_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceSeparateDepthStencilLayoutsFeatures::default());
+        }
+        if extensions.contains(&EXT_SHADER_64BIT_INDEXING_EXTENSION.name) {
+            set.insert(PhysicalDeviceShader64BitIndexingFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_SHADER_ATOMIC_FLOAT16_VECTOR_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderAtomicFloat16VectorFeaturesNV::default());
+        }
+        if extensions.contains(&EXT_SHADER_ATOMIC_FLOAT2_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderAtomicFloat2FeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_SHADER_ATOMIC_FLOAT_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderAtomicFloatFeaturesEXT::default());
+        }
+        if version >= Version::V1_2_0
+            || extensions.contains(&KHR_SHADER_ATOMIC_INT64_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceShaderAtomicInt64Features::default());
+        }
+        if extensions.contains(&KHR_SHADER_BFLOAT16_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderBfloat16FeaturesKHR::default());
+        }
+        if extensions.contains(&KHR_SHADER_CLOCK_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderClockFeaturesKHR::default());
+        }
+        if extensions.contains(&ARM_SHADER_CORE_BUILTINS_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderCoreBuiltinsFeaturesARM::default());
+        }
+        if version >= Version::V1_1_0
+            || extensions.contains(&KHR_SHADER_DRAW_PARAMETERS_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceShaderDrawParametersFeatures::default());
+        }
+        #[cfg(feature = "provisional")]
+        if extensions.contains(&AMDX_SHADER_ENQUEUE_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderEnqueueFeaturesAMDX::default());
+        }
+        if version >= Version::V1_4_0
+            || extensions.contains(&KHR_SHADER_EXPECT_ASSUME_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceShaderExpectAssumeFeatures::default());
+        }
+        if version >= Version::V1_2_0
+            || extensions.contains(&KHR_SHADER_FLOAT16_INT8_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceShaderFloat16Int8Features::default());
+        }
+        if extensions.contains(&EXT_SHADER_FLOAT8_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderFloat8FeaturesEXT::default());
+        }
+        if version >= Version::V1_4_0
+            || extensions.contains(&KHR_SHADER_FLOAT_CONTROLS2_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceShaderFloatControls2Features::default());
+        }
+        if extensions.contains(&KHR_SHADER_FMA_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderFmaFeaturesKHR::default());
+        }
+        if extensions.contains(&EXT_SHADER_IMAGE_ATOMIC_INT64_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderImageAtomicInt64FeaturesEXT::default());
+        }
+        if extensions.contains(&NV_SHADER_IMAGE_FOOTPRINT_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderImageFootprintFeaturesNV::default());
+        }
+        if version >= Version::V1_3_0
+            || extensions.contains(&KHR_SHADER_INTEGER_DOT_PRODUCT_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceShaderIntegerDotProductFeatures::default());
+        }
+        if extensions.contains(&INTEL_SHADER_INTEGER_FUNCTIONS2_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL::default());
+        }
+        if extensions.contains(&EXT_SHADER_LONG_VECTOR_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderLongVectorFeaturesEXT::default());
+        }
+        if extensions.contains(&KHR_SHADER_MAXIMAL_RECONVERGENCE_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderMaximalReconvergenceFeaturesKHR::default());
+        }
+        if extensions.contains(&EXT_SHADER_MODULE_IDENTIFIER_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderModuleIdentifierFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_SHADER_OBJECT_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderObjectFeaturesEXT::default());
+        }
+        if extensions.contains(&KHR_SHADER_QUAD_CONTROL_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderQuadControlFeaturesKHR::default());
+        }
+        if extensions.contains(&EXT_SHADER_REPLICATED_COMPOSITES_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderReplicatedCompositesFeaturesEXT::default());
+        }
+        if extensions.contains(&NV_SHADER_SM_BUILTINS_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderSMBuiltinsFeaturesNV::default());
+        }
+        if version >= Version::V1_2_0
+            || extensions.contains(&KHR_SHADER_SUBGROUP_EXTENDED_TYPES_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceShaderSubgroupExtendedTypesFeatures::default());
+        }
+        if extensions.contains(&EXT_SHADER_SUBGROUP_PARTITIONED_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderSubgroupPartitionedFeaturesEXT::default());
+        }
+        if version >= Version::V1_4_0
+            || extensions.contains(&KHR_SHADER_SUBGROUP_ROTATE_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceShaderSubgroupRotateFeatures::default());
+        }
+        if version >= Version::V1_3_0
+            || extensions.contains(&KHR_SHADER_TERMINATE_INVOCATION_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceShaderTerminateInvocationFeatures::default());
+        }
+        if extensions.contains(&EXT_SHADER_TILE_IMAGE_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderTileImageFeaturesEXT::default());
+        }
+        if extensions.contains(&KHR_SHADER_UNTYPED_POINTERS_EXTENSION.name) {
+            set.insert(PhysicalDeviceShaderUntypedPointersFeaturesKHR::default());
+        }
+        if extensions.contains(&NV_SHADING_RATE_IMAGE_EXTENSION.name) {
+            set.insert(PhysicalDeviceShadingRateImageFeaturesNV::default());
+        }
+        if version >= Version::V1_3_0
+            || extensions.contains(&EXT_SUBGROUP_SIZE_CONTROL_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceSubgroupSizeControlFeatures::default());
+        }
+        if extensions.contains(&EXT_SUBPASS_MERGE_FEEDBACK_EXTENSION.name) {
+            set.insert(PhysicalDeviceSubpassMergeFeedbackFeaturesEXT::default());
+        }
+        if extensions.contains(&HUAWEI_SUBPASS_SHADING_EXTENSION.name) {
+            set.insert(PhysicalDeviceSubpassShadingFeaturesHUAWEI::default());
+        }
+        if extensions.contains(&EXT_SWAPCHAIN_MAINTENANCE1_EXTENSION.name)
+            || extensions.contains(&KHR_SWAPCHAIN_MAINTENANCE1_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceSwapchainMaintenance1FeaturesKHR::default());
+        }
+        if version >= Version::V1_3_0 || extensions.contains(&KHR_SYNCHRONIZATION2_EXTENSION.name) {
+            set.insert(PhysicalDeviceSynchronization2Features::default());
+        }
+        if extensions.contains(&ARM_TENSORS_EXTENSION.name) {
+            set.insert(PhysicalDeviceTensorFeaturesARM::default());
+        }
+        if extensions.contains(&EXT_TEXEL_BUFFER_ALIGNMENT_EXTENSION.name) {
+            set.insert(PhysicalDeviceTexelBufferAlignmentFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_TEXTURE_COMPRESSION_ASTC_3D_EXTENSION.name) {
+            set.insert(PhysicalDeviceTextureCompressionASTC3DFeaturesEXT::default());
+        }
+        if version >= Version::V1_3_0
+            || extensions.contains(&EXT_TEXTURE_COMPRESSION_ASTC_HDR_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceTextureCompressionASTCHDRFeatures::default());
+        }
+        if extensions.contains(&QCOM_TILE_MEMORY_HEAP_EXTENSION.name) {
+            set.insert(PhysicalDeviceTileMemoryHeapFeaturesQCOM::default());
+        }
+        if extensions.contains(&QCOM_TILE_PROPERTIES_EXTENSION.name) {
+            set.insert(PhysicalDeviceTilePropertiesFeaturesQCOM::default());
+        }
+        if extensions.contains(&QCOM_TILE_SHADING_EXTENSION.name) {
+            set.insert(PhysicalDeviceTileShadingFeaturesQCOM::default());
+        }
+        if version >= Version::V1_2_0 || extensions.contains(&KHR_TIMELINE_SEMAPHORE_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceTimelineSemaphoreFeatures::default());
+        }
+        if extensions.contains(&EXT_TRANSFORM_FEEDBACK_EXTENSION.name) {
+            set.insert(PhysicalDeviceTransformFeedbackFeaturesEXT::default());
+        }
+        if extensions.contains(&KHR_UNIFIED_IMAGE_LAYOUTS_EXTENSION.name) {
+            set.insert(PhysicalDeviceUnifiedImageLayoutsFeaturesKHR::default());
+        }
+        if version >= Version::V1_2_0
+            || extensions.contains(&KHR_UNIFORM_BUFFER_STANDARD_LAYOUT_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceUniformBufferStandardLayoutFeatures::default());
+        }
+        if version >= Version::V1_1_0 || extensions.contains(&KHR_VARIABLE_POINTERS_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceVariablePointersFeatures::default());
+        }
+        if version >= Version::V1_4_0
+            || extensions.contains(&EXT_VERTEX_ATTRIBUTE_DIVISOR_EXTENSION.name)
+            || extensions.contains(&KHR_VERTEX_ATTRIBUTE_DIVISOR_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceVertexAttributeDivisorFeatures::default());
+        }
+        if extensions.contains(&EXT_VERTEX_ATTRIBUTE_ROBUSTNESS_EXTENSION.name) {
+            set.insert(PhysicalDeviceVertexAttributeRobustnessFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_VERTEX_INPUT_DYNAMIC_STATE_EXTENSION.name) {
+            set.insert(PhysicalDeviceVertexInputDynamicStateFeaturesEXT::default());
+        }
+        if extensions.contains(&KHR_VIDEO_DECODE_VP9_EXTENSION.name) {
+            set.insert(PhysicalDeviceVideoDecodeVP9FeaturesKHR::default());
+        }
+        if extensions.contains(&KHR_VIDEO_ENCODE_AV1_EXTENSION.name) {
+            set.insert(PhysicalDeviceVideoEncodeAV1FeaturesKHR::default());
+        }
+        if extensions.contains(&KHR_VIDEO_ENCODE_INTRA_REFRESH_EXTENSION.name) {
+            set.insert(PhysicalDeviceVideoEncodeIntraRefreshFeaturesKHR::default());
+        }
+        if extensions.contains(&KHR_VIDEO_ENCODE_QUANTIZATION_MAP_EXTENSION.name) {
+            set.insert(PhysicalDeviceVideoEncodeQuantizationMapFeaturesKHR::default());
+        }
+        if extensions.contains(&VALVE_VIDEO_ENCODE_RGB_CONVERSION_EXTENSION.name) {
+            set.insert(PhysicalDeviceVideoEncodeRgbConversionFeaturesVALVE::default());
+        }
+        if extensions.contains(&KHR_VIDEO_MAINTENANCE1_EXTENSION.name) {
+            set.insert(PhysicalDeviceVideoMaintenance1FeaturesKHR::default());
+        }
+        if extensions.contains(&KHR_VIDEO_MAINTENANCE2_EXTENSION.name) {
+            set.insert(PhysicalDeviceVideoMaintenance2FeaturesKHR::default());
+        }
+        if version >= Version::V1_2_0 {
+            set.insert(PhysicalDeviceVulkan11Features::default());
+        }
+        if version >= Version::V1_2_0 {
+            set.insert(PhysicalDeviceVulkan12Features::default());
+        }
+        if version >= Version::V1_3_0 {
+            set.insert(PhysicalDeviceVulkan13Features::default());
+        }
+        if version >= Version::V1_4_0 {
+            set.insert(PhysicalDeviceVulkan14Features::default());
+        }
+        if version >= Version::V1_2_0
+            || extensions.contains(&KHR_VULKAN_MEMORY_MODEL_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceVulkanMemoryModelFeatures::default());
+        }
+        if extensions.contains(&EXT_YCBCR_2PLANE_444_FORMATS_EXTENSION.name) {
+            set.insert(PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT::default());
+        }
+        if extensions.contains(&QCOM_YCBCR_DEGAMMA_EXTENSION.name) {
+            set.insert(PhysicalDeviceYcbcrDegammaFeaturesQCOM::default());
+        }
+        if extensions.contains(&EXT_YCBCR_IMAGE_ARRAYS_EXTENSION.name) {
+            set.insert(PhysicalDeviceYcbcrImageArraysFeaturesEXT::default());
+        }
+        if extensions.contains(&EXT_ZERO_INITIALIZE_DEVICE_MEMORY_EXTENSION.name) {
+            set.insert(PhysicalDeviceZeroInitializeDeviceMemoryFeaturesEXT::default());
+        }
+        if version >= Version::V1_3_0
+            || extensions.contains(&KHR_ZERO_INITIALIZE_WORKGROUP_MEMORY_EXTENSION.name)
+        {
+            set.insert(PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures::default());
+        }
+
+        set
+    }
+
+    /// Adds a copy of a feature struct in a pointer chain to this set.
+    ///
+    /// Returns `false` if the struct is not a feature struct.
+    pub(crate) unsafe fn insert_raw(&mut self, base: *const BaseInStructure) -> bool {
+        match (*base).s_type {
+            StructureType::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES => {
+                self.insert(PhysicalDevice16BitStorageFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT => {
+                self.insert(PhysicalDevice4444FormatsFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES => {
+                self.insert(PhysicalDevice8BitStorageFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT => {
+                self.insert(PhysicalDeviceASTCDecodeFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR => {
+                self.insert(PhysicalDeviceAccelerationStructureFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_ADDRESS_BINDING_REPORT_FEATURES_EXT => {
+                self.insert(PhysicalDeviceAddressBindingReportFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_AMIGO_PROFILING_FEATURES_SEC => {
+                self.insert(PhysicalDeviceAmigoProfilingFeaturesSEC {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_ANTI_LAG_FEATURES_AMD => {
+                self.insert(PhysicalDeviceAntiLagFeaturesAMD {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT => {
+                self.insert(PhysicalDeviceBlendOperationAdvancedFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_BORDER_COLOR_SWIZZLE_FEATURES_EXT => {
+                self.insert(PhysicalDeviceBorderColorSwizzleFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES => {
+                self.insert(PhysicalDeviceBufferDeviceAddressFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT => {
+                self.insert(PhysicalDeviceBufferDeviceAddressFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_FEATURES_HUAWEI => {
+                self.insert(PhysicalDeviceClusterCullingShaderFeaturesHUAWEI {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD => {
+                self.insert(PhysicalDeviceCoherentMemoryFeaturesAMD {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_COLOR_WRITE_ENABLE_FEATURES_EXT => {
+                self.insert(PhysicalDeviceColorWriteEnableFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_COMMAND_BUFFER_INHERITANCE_FEATURES_NV => {
+                self.insert(PhysicalDeviceCommandBufferInheritanceFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_COMPUTE_OCCUPANCY_PRIORITY_FEATURES_NV => {
+                self.insert(PhysicalDeviceComputeOccupancyPriorityFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_KHR => {
+                self.insert(PhysicalDeviceComputeShaderDerivativesFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT => {
+                self.insert(PhysicalDeviceConditionalRenderingFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_2_FEATURES_NV => {
+                self.insert(PhysicalDeviceCooperativeMatrix2FeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_KHR => {
+                self.insert(PhysicalDeviceCooperativeMatrixFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV => {
+                self.insert(PhysicalDeviceCooperativeMatrixFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_COOPERATIVE_VECTOR_FEATURES_NV => {
+                self.insert(PhysicalDeviceCooperativeVectorFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_FEATURES_KHR => {
+                self.insert(PhysicalDeviceCopyMemoryIndirectFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_FEATURES_NV => {
+                self.insert(PhysicalDeviceCopyMemoryIndirectFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV => {
+                self.insert(PhysicalDeviceCornerSampledImageFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV => {
+                self.insert(PhysicalDeviceCoverageReductionModeFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_CUBIC_CLAMP_FEATURES_QCOM => {
+                self.insert(PhysicalDeviceCubicClampFeaturesQCOM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_CUBIC_WEIGHTS_FEATURES_QCOM => {
+                self.insert(PhysicalDeviceCubicWeightsFeaturesQCOM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_FEATURES_NV => {
+                self.insert(PhysicalDeviceCudaKernelLaunchFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT => {
+                self.insert(PhysicalDeviceCustomBorderColorFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_CUSTOM_RESOLVE_FEATURES_EXT => {
+                self.insert(PhysicalDeviceCustomResolveFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DATA_GRAPH_FEATURES_ARM => {
+                self.insert(PhysicalDeviceDataGraphFeaturesARM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DATA_GRAPH_MODEL_FEATURES_QCOM => {
+                self.insert(PhysicalDeviceDataGraphModelFeaturesQCOM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DENSE_GEOMETRY_FORMAT_FEATURES_AMDX => {
+                self.insert(PhysicalDeviceDenseGeometryFormatFeaturesAMDX {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DEPTH_BIAS_CONTROL_FEATURES_EXT => {
+                self.insert(PhysicalDeviceDepthBiasControlFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DEPTH_CLAMP_CONTROL_FEATURES_EXT => {
+                self.insert(PhysicalDeviceDepthClampControlFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DEPTH_CLAMP_ZERO_ONE_FEATURES_KHR => {
+                self.insert(PhysicalDeviceDepthClampZeroOneFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DEPTH_CLIP_CONTROL_FEATURES_EXT => {
+                self.insert(PhysicalDeviceDepthClipControlFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT => {
+                self.insert(PhysicalDeviceDepthClipEnableFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_FEATURES_EXT => {
+                self.insert(PhysicalDeviceDescriptorBufferFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_TENSOR_FEATURES_ARM => {
+                self.insert(PhysicalDeviceDescriptorBufferTensorFeaturesARM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DESCRIPTOR_HEAP_FEATURES_EXT => {
+                self.insert(PhysicalDeviceDescriptorHeapFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES => {
+                self.insert(PhysicalDeviceDescriptorIndexingFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DESCRIPTOR_SET_HOST_MAPPING_FEATURES_VALVE => self
+                .insert(PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_EXT => {
+                self.insert(PhysicalDeviceDeviceGeneratedCommandsFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV => {
+                self.insert(PhysicalDeviceDeviceGeneratedCommandsFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT => {
+                self.insert(PhysicalDeviceDeviceMemoryReportFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV => {
+                self.insert(PhysicalDeviceDiagnosticsConfigFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_FEATURES_NV => {
+                self.insert(PhysicalDeviceDisplacementMicromapFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES => {
+                self.insert(PhysicalDeviceDynamicRenderingFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_DYNAMIC_RENDERING_LOCAL_READ_FEATURES => {
+                self.insert(PhysicalDeviceDynamicRenderingLocalReadFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV => {
+                self.insert(PhysicalDeviceExclusiveScissorFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_2_FEATURES_EXT => {
+                self.insert(PhysicalDeviceExtendedDynamicState2FeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_FEATURES_EXT => {
+                self.insert(PhysicalDeviceExtendedDynamicState3FeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT => {
+                self.insert(PhysicalDeviceExtendedDynamicStateFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_EXTENDED_SPARSE_ADDRESS_SPACE_FEATURES_NV => self
+                .insert(PhysicalDeviceExtendedSparseAddressSpaceFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_FORMAT_RESOLVE_FEATURES_ANDROID => {
+                self.insert(PhysicalDeviceExternalFormatResolveFeaturesANDROID {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_RDMA_FEATURES_NV => {
+                self.insert(PhysicalDeviceExternalMemoryRDMAFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_SCI_BUF_FEATURES_NV => {
+                self.insert(PhysicalDeviceExternalMemorySciBufFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_MEMORY_SCREEN_BUFFER_FEATURES_QNX => self
+                .insert(PhysicalDeviceExternalMemoryScreenBufferFeaturesQNX {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_SCI_SYNC_2_FEATURES_NV => {
+                self.insert(PhysicalDeviceExternalSciSync2FeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_EXTERNAL_SCI_SYNC_FEATURES_NV => {
+                self.insert(PhysicalDeviceExternalSciSyncFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_FAULT_FEATURES_EXT => {
+                self.insert(PhysicalDeviceFaultFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_FORMAT_PACK_FEATURES_ARM => {
+                self.insert(PhysicalDeviceFormatPackFeaturesARM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT => {
+                self.insert(PhysicalDeviceFragmentDensityMap2FeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT => {
+                self.insert(PhysicalDeviceFragmentDensityMapFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_FEATURES_EXT => {
+                self.insert(PhysicalDeviceFragmentDensityMapOffsetFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_KHR => {
+                self.insert(PhysicalDeviceFragmentShaderBarycentricFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT => {
+                self.insert(PhysicalDeviceFragmentShaderInterlockFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV => {
+                self.insert(PhysicalDeviceFragmentShadingRateEnumsFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR => {
+                self.insert(PhysicalDeviceFragmentShadingRateFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_FRAME_BOUNDARY_FEATURES_EXT => {
+                self.insert(PhysicalDeviceFrameBoundaryFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES => {
+                self.insert(PhysicalDeviceGlobalPriorityQueryFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_FEATURES_EXT => {
+                self.insert(PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_HDR_VIVID_FEATURES_HUAWEI => {
+                self.insert(PhysicalDeviceHdrVividFeaturesHUAWEI {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_HOST_IMAGE_COPY_FEATURES => {
+                self.insert(PhysicalDeviceHostImageCopyFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES => {
+                self.insert(PhysicalDeviceHostQueryResetFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_IMAGE_2D_VIEW_OF_3D_FEATURES_EXT => {
+                self.insert(PhysicalDeviceImage2DViewOf3DFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_IMAGE_ALIGNMENT_CONTROL_FEATURES_MESA => {
+                self.insert(PhysicalDeviceImageAlignmentControlFeaturesMESA {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_FEATURES_EXT => {
+                self.insert(PhysicalDeviceImageCompressionControlFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_IMAGE_PROCESSING_2_FEATURES_QCOM => {
+                self.insert(PhysicalDeviceImageProcessing2FeaturesQCOM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_IMAGE_PROCESSING_FEATURES_QCOM => {
+                self.insert(PhysicalDeviceImageProcessingFeaturesQCOM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES => {
+                self.insert(PhysicalDeviceImageRobustnessFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_IMAGE_SLICED_VIEW_OF_3D_FEATURES_EXT => {
+                self.insert(PhysicalDeviceImageSlicedViewOf3DFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_IMAGE_VIEW_MIN_LOD_FEATURES_EXT => {
+                self.insert(PhysicalDeviceImageViewMinLodFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES => {
+                self.insert(PhysicalDeviceImagelessFramebufferFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES => {
+                self.insert(PhysicalDeviceIndexTypeUint8Features {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_INHERITED_VIEWPORT_SCISSOR_FEATURES_NV => {
+                self.insert(PhysicalDeviceInheritedViewportScissorFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES => {
+                self.insert(PhysicalDeviceInlineUniformBlockFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_INVOCATION_MASK_FEATURES_HUAWEI => {
+                self.insert(PhysicalDeviceInvocationMaskFeaturesHUAWEI {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_LEGACY_DITHERING_FEATURES_EXT => {
+                self.insert(PhysicalDeviceLegacyDitheringFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_LEGACY_VERTEX_ATTRIBUTES_FEATURES_EXT => {
+                self.insert(PhysicalDeviceLegacyVertexAttributesFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES => {
+                self.insert(PhysicalDeviceLineRasterizationFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_LINEAR_COLOR_ATTACHMENT_FEATURES_NV => {
+                self.insert(PhysicalDeviceLinearColorAttachmentFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MAINTENANCE_10_FEATURES_KHR => {
+                self.insert(PhysicalDeviceMaintenance10FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES => {
+                self.insert(PhysicalDeviceMaintenance4Features {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MAINTENANCE_5_FEATURES => {
+                self.insert(PhysicalDeviceMaintenance5Features {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MAINTENANCE_6_FEATURES => {
+                self.insert(PhysicalDeviceMaintenance6Features {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MAINTENANCE_7_FEATURES_KHR => {
+                self.insert(PhysicalDeviceMaintenance7FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MAINTENANCE_8_FEATURES_KHR => {
+                self.insert(PhysicalDeviceMaintenance8FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MAINTENANCE_9_FEATURES_KHR => {
+                self.insert(PhysicalDeviceMaintenance9FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MAP_MEMORY_PLACED_FEATURES_EXT => {
+                self.insert(PhysicalDeviceMapMemoryPlacedFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_FEATURES_EXT => {
+                self.insert(PhysicalDeviceMemoryDecompressionFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT => {
+                self.insert(PhysicalDeviceMemoryPriorityFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT => {
+                self.insert(PhysicalDeviceMeshShaderFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV => {
+                self.insert(PhysicalDeviceMeshShaderFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MULTI_DRAW_FEATURES_EXT => {
+                self.insert(PhysicalDeviceMultiDrawFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES => {
+                self.insert(PhysicalDeviceMultiviewFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_VIEWPORTS_FEATURES_QCOM => self
+                .insert(PhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_EXT => {
+                self.insert(PhysicalDeviceMutableDescriptorTypeFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_NESTED_COMMAND_BUFFER_FEATURES_EXT => {
+                self.insert(PhysicalDeviceNestedCommandBufferFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_NON_SEAMLESS_CUBE_MAP_FEATURES_EXT => {
+                self.insert(PhysicalDeviceNonSeamlessCubeMapFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_OPACITY_MICROMAP_FEATURES_EXT => {
+                self.insert(PhysicalDeviceOpacityMicromapFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_OPTICAL_FLOW_FEATURES_NV => {
+                self.insert(PhysicalDeviceOpticalFlowFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PAGEABLE_DEVICE_LOCAL_MEMORY_FEATURES_EXT => self
+                .insert(PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_PER_STAGE_DESCRIPTOR_SET_FEATURES_NV => {
+                self.insert(PhysicalDevicePerStageDescriptorSetFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR => {
+                self.insert(PhysicalDevicePerformanceQueryFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PIPELINE_BINARY_FEATURES_KHR => {
+                self.insert(PhysicalDevicePipelineBinaryFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES => {
+                self.insert(PhysicalDevicePipelineCreationCacheControlFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PIPELINE_OPACITY_MICROMAP_FEATURES_ARM => {
+                self.insert(PhysicalDevicePipelineOpacityMicromapFeaturesARM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PIPELINE_PROPERTIES_FEATURES_EXT => {
+                self.insert(PhysicalDevicePipelinePropertiesFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PIPELINE_PROTECTED_ACCESS_FEATURES => {
+                self.insert(PhysicalDevicePipelineProtectedAccessFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_FEATURES => {
+                self.insert(PhysicalDevicePipelineRobustnessFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR => {
+                self.insert(PhysicalDevicePortabilitySubsetFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PRESENT_BARRIER_FEATURES_NV => {
+                self.insert(PhysicalDevicePresentBarrierFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PRESENT_ID_2_FEATURES_KHR => {
+                self.insert(PhysicalDevicePresentId2FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR => {
+                self.insert(PhysicalDevicePresentIdFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PRESENT_METERING_FEATURES_NV => {
+                self.insert(PhysicalDevicePresentMeteringFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PRESENT_MODE_FIFO_LATEST_READY_FEATURES_KHR => self
+                .insert(PhysicalDevicePresentModeFifoLatestReadyFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_PRESENT_TIMING_FEATURES_EXT => {
+                self.insert(PhysicalDevicePresentTimingFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PRESENT_WAIT_2_FEATURES_KHR => {
+                self.insert(PhysicalDevicePresentWait2FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR => {
+                self.insert(PhysicalDevicePresentWaitFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PRIMITIVES_GENERATED_QUERY_FEATURES_EXT => {
+                self.insert(PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES => {
+                self.insert(PhysicalDevicePrivateDataFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES => {
+                self.insert(PhysicalDeviceProtectedMemoryFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PROVOKING_VERTEX_FEATURES_EXT => {
+                self.insert(PhysicalDeviceProvokingVertexFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_PUSH_CONSTANT_BANK_FEATURES_NV => {
+                self.insert(PhysicalDevicePushConstantBankFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_RGBA10X6_FORMATS_FEATURES_EXT => {
+                self.insert(PhysicalDeviceRGBA10X6FormatsFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_RAW_ACCESS_CHAINS_FEATURES_NV => {
+                self.insert(PhysicalDeviceRawAccessChainsFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR => {
+                self.insert(PhysicalDeviceRayQueryFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_FEATURES_NV => self
+                .insert(PhysicalDeviceRayTracingInvocationReorderFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_RAY_TRACING_MAINTENANCE_1_FEATURES_KHR => {
+                self.insert(PhysicalDeviceRayTracingMaintenance1FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_RAY_TRACING_MOTION_BLUR_FEATURES_NV => {
+                self.insert(PhysicalDeviceRayTracingMotionBlurFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR => {
+                self.insert(PhysicalDeviceRayTracingPipelineFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_RAY_TRACING_POSITION_FETCH_FEATURES_KHR => {
+                self.insert(PhysicalDeviceRayTracingPositionFetchFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_RAY_TRACING_VALIDATION_FEATURES_NV => {
+                self.insert(PhysicalDeviceRayTracingValidationFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_RELAXED_LINE_RASTERIZATION_FEATURES_IMG => {
+                self.insert(PhysicalDeviceRelaxedLineRasterizationFeaturesIMG {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_RENDER_PASS_STRIPED_FEATURES_ARM => {
+                self.insert(PhysicalDeviceRenderPassStripedFeaturesARM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV => {
+                self.insert(PhysicalDeviceRepresentativeFragmentTestFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_KHR => {
+                self.insert(PhysicalDeviceRobustness2FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES => {
+                self.insert(PhysicalDeviceSamplerYcbcrConversionFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES => {
+                self.insert(PhysicalDeviceScalarBlockLayoutFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SCHEDULING_CONTROLS_FEATURES_ARM => {
+                self.insert(PhysicalDeviceSchedulingControlsFeaturesARM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES => {
+                self.insert(PhysicalDeviceSeparateDepthStencilLayoutsFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_64_BIT_INDEXING_FEATURES_EXT => {
+                self.insert(PhysicalDeviceShader64BitIndexingFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT16_VECTOR_FEATURES_NV => {
+                self.insert(PhysicalDeviceShaderAtomicFloat16VectorFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_2_FEATURES_EXT => {
+                self.insert(PhysicalDeviceShaderAtomicFloat2FeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT => {
+                self.insert(PhysicalDeviceShaderAtomicFloatFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES => {
+                self.insert(PhysicalDeviceShaderAtomicInt64Features {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_BFLOAT16_FEATURES_KHR => {
+                self.insert(PhysicalDeviceShaderBfloat16FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR => {
+                self.insert(PhysicalDeviceShaderClockFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_FEATURES_ARM => {
+                self.insert(PhysicalDeviceShaderCoreBuiltinsFeaturesARM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES => {
+                self.insert(PhysicalDeviceShaderDrawParametersFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_ENQUEUE_FEATURES_AMDX => {
+                self.insert(PhysicalDeviceShaderEnqueueFeaturesAMDX {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_EXPECT_ASSUME_FEATURES => {
+                self.insert(PhysicalDeviceShaderExpectAssumeFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES => {
+                self.insert(PhysicalDeviceShaderFloat16Int8Features {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_FLOAT8_FEATURES_EXT => {
+                self.insert(PhysicalDeviceShaderFloat8FeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_FLOAT_CONTROLS_2_FEATURES => {
+                self.insert(PhysicalDeviceShaderFloatControls2Features {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_FMA_FEATURES_KHR => {
+                self.insert(PhysicalDeviceShaderFmaFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT => {
+                self.insert(PhysicalDeviceShaderImageAtomicInt64FeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV => {
+                self.insert(PhysicalDeviceShaderImageFootprintFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES => {
+                self.insert(PhysicalDeviceShaderIntegerDotProductFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL => self
+                .insert(PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_SHADER_LONG_VECTOR_FEATURES_EXT => {
+                self.insert(PhysicalDeviceShaderLongVectorFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_MAXIMAL_RECONVERGENCE_FEATURES_KHR => self
+                .insert(PhysicalDeviceShaderMaximalReconvergenceFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_FEATURES_EXT => {
+                self.insert(PhysicalDeviceShaderModuleIdentifierFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_OBJECT_FEATURES_EXT => {
+                self.insert(PhysicalDeviceShaderObjectFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_QUAD_CONTROL_FEATURES_KHR => {
+                self.insert(PhysicalDeviceShaderQuadControlFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_REPLICATED_COMPOSITES_FEATURES_EXT => self
+                .insert(PhysicalDeviceShaderReplicatedCompositesFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV => {
+                self.insert(PhysicalDeviceShaderSMBuiltinsFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES => {
+                self.insert(PhysicalDeviceShaderSubgroupExtendedTypesFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_SUBGROUP_PARTITIONED_FEATURES_EXT => {
+                self.insert(PhysicalDeviceShaderSubgroupPartitionedFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_SUBGROUP_ROTATE_FEATURES => {
+                self.insert(PhysicalDeviceShaderSubgroupRotateFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES => {
+                self.insert(PhysicalDeviceShaderTerminateInvocationFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_TILE_IMAGE_FEATURES_EXT => {
+                self.insert(PhysicalDeviceShaderTileImageFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADER_UNTYPED_POINTERS_FEATURES_KHR => {
+                self.insert(PhysicalDeviceShaderUntypedPointersFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV => {
+                self.insert(PhysicalDeviceShadingRateImageFeaturesNV {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES => {
+                self.insert(PhysicalDeviceSubgroupSizeControlFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SUBPASS_MERGE_FEEDBACK_FEATURES_EXT => {
+                self.insert(PhysicalDeviceSubpassMergeFeedbackFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SUBPASS_SHADING_FEATURES_HUAWEI => {
+                self.insert(PhysicalDeviceSubpassShadingFeaturesHUAWEI {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SWAPCHAIN_MAINTENANCE_1_FEATURES_KHR => {
+                self.insert(PhysicalDeviceSwapchainMaintenance1FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES => {
+                self.insert(PhysicalDeviceSynchronization2Features {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_TENSOR_FEATURES_ARM => {
+                self.insert(PhysicalDeviceTensorFeaturesARM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT => {
+                self.insert(PhysicalDeviceTexelBufferAlignmentFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_3D_FEATURES_EXT => {
+                self.insert(PhysicalDeviceTextureCompressionASTC3DFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES => {
+                self.insert(PhysicalDeviceTextureCompressionASTCHDRFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_TILE_MEMORY_HEAP_FEATURES_QCOM => {
+                self.insert(PhysicalDeviceTileMemoryHeapFeaturesQCOM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_TILE_PROPERTIES_FEATURES_QCOM => {
+                self.insert(PhysicalDeviceTilePropertiesFeaturesQCOM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_TILE_SHADING_FEATURES_QCOM => {
+                self.insert(PhysicalDeviceTileShadingFeaturesQCOM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES => {
+                self.insert(PhysicalDeviceTimelineSemaphoreFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT => {
+                self.insert(PhysicalDeviceTransformFeedbackFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_UNIFIED_IMAGE_LAYOUTS_FEATURES_KHR => {
+                self.insert(PhysicalDeviceUnifiedImageLayoutsFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES => {
+                self.insert(PhysicalDeviceUniformBufferStandardLayoutFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES => {
+                self.insert(PhysicalDeviceVariablePointersFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES => {
+                self.insert(PhysicalDeviceVertexAttributeDivisorFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_ROBUSTNESS_FEATURES_EXT => {
+                self.insert(PhysicalDeviceVertexAttributeRobustnessFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VERTEX_INPUT_DYNAMIC_STATE_FEATURES_EXT => {
+                self.insert(PhysicalDeviceVertexInputDynamicStateFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VIDEO_DECODE_VP9_FEATURES_KHR => {
+                self.insert(PhysicalDeviceVideoDecodeVP9FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VIDEO_ENCODE_AV1_FEATURES_KHR => {
+                self.insert(PhysicalDeviceVideoEncodeAV1FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VIDEO_ENCODE_INTRA_REFRESH_FEATURES_KHR => {
+                self.insert(PhysicalDeviceVideoEncodeIntraRefreshFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VIDEO_ENCODE_QUANTIZATION_MAP_FEATURES_KHR => self
+                .insert(PhysicalDeviceVideoEncodeQuantizationMapFeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_VIDEO_ENCODE_RGB_CONVERSION_FEATURES_VALVE => self
+                .insert(PhysicalDeviceVideoEncodeRgbConversionFeaturesVALVE {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_VIDEO_MAINTENANCE_1_FEATURES_KHR => {
+                self.insert(PhysicalDeviceVideoMaintenance1FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VIDEO_MAINTENANCE_2_FEATURES_KHR => {
+                self.insert(PhysicalDeviceVideoMaintenance2FeaturesKHR {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES => {
+                self.insert(PhysicalDeviceVulkan11Features {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES => {
+                self.insert(PhysicalDeviceVulkan12Features {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VULKAN_1_3_FEATURES => {
+                self.insert(PhysicalDeviceVulkan13Features {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VULKAN_1_4_FEATURES => {
+                self.insert(PhysicalDeviceVulkan14Features {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES => {
+                self.insert(PhysicalDeviceVulkanMemoryModelFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_YCBCR_2_PLANE_444_FORMATS_FEATURES_EXT => {
+                self.insert(PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_YCBCR_DEGAMMA_FEATURES_QCOM => {
+                self.insert(PhysicalDeviceYcbcrDegammaFeaturesQCOM {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT => {
+                self.insert(PhysicalDeviceYcbcrImageArraysFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                })
+            }
+            StructureType::PHYSICAL_DEVICE_ZERO_INITIALIZE_DEVICE_MEMORY_FEATURES_EXT => self
+                .insert(PhysicalDeviceZeroInitializeDeviceMemoryFeaturesEXT {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            StructureType::PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES => self
+                .insert(PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures {
+                    next: ptr::null_mut(),
+                    ..*base.cast()
+                }),
+            _ => return false,
+        }
+
+        true
+    }
+}
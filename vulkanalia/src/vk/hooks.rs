@@ -0,0 +1,14038 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// DO NOT EDIT.
+//
+// This file has been generated by the Kotlin project in the `generator`
+// directory from a Vulkan API registry.
+
+#![allow(
+    non_camel_case_types,
+    non_snake_case,
+    clippy::bad_bit_mask,
+    clippy::let_and_return,
+    clippy::let_unit_value,
+    clippy::missing_safety_doc,
+    clippy::missing_transmute_annotations,
+    clippy::needless_lifetimes,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unnecessary_cast,
+    clippy::upper_case_acronyms,
+    clippy::useless_transmute
+)]
+
+use core::ffi::{c_char, c_int, c_void};
+
+use super::*;
+
+impl DeviceCommands {
+    /// Creates device commands which call the command hooks installed in a
+    /// hook slot around the unhooked device commands installed in the slot.
+    #[inline]
+    pub(crate) fn hooked<const S: usize>() -> Self {
+        Self {
+            acquire_full_screen_exclusive_mode_ext: vkAcquireFullScreenExclusiveModeEXT::<S>,
+            acquire_next_image2_khr: vkAcquireNextImage2KHR::<S>,
+            acquire_next_image_khr: vkAcquireNextImageKHR::<S>,
+            acquire_performance_configuration_intel: vkAcquirePerformanceConfigurationINTEL::<S>,
+            acquire_profiling_lock_khr: vkAcquireProfilingLockKHR::<S>,
+            allocate_command_buffers: vkAllocateCommandBuffers::<S>,
+            allocate_descriptor_sets: vkAllocateDescriptorSets::<S>,
+            allocate_memory: vkAllocateMemory::<S>,
+            anti_lag_update_amd: vkAntiLagUpdateAMD::<S>,
+            begin_command_buffer: vkBeginCommandBuffer::<S>,
+            bind_acceleration_structure_memory_nv: vkBindAccelerationStructureMemoryNV::<S>,
+            bind_buffer_memory: vkBindBufferMemory::<S>,
+            bind_buffer_memory2: vkBindBufferMemory2::<S>,
+            bind_buffer_memory2_khr: vkBindBufferMemory2KHR::<S>,
+            bind_data_graph_pipeline_session_memory_arm: vkBindDataGraphPipelineSessionMemoryARM::<S>,
+            bind_image_memory: vkBindImageMemory::<S>,
+            bind_image_memory2: vkBindImageMemory2::<S>,
+            bind_image_memory2_khr: vkBindImageMemory2KHR::<S>,
+            bind_optical_flow_session_image_nv: vkBindOpticalFlowSessionImageNV::<S>,
+            bind_tensor_memory_arm: vkBindTensorMemoryARM::<S>,
+            bind_video_session_memory_khr: vkBindVideoSessionMemoryKHR::<S>,
+            build_acceleration_structures_khr: vkBuildAccelerationStructuresKHR::<S>,
+            build_micromaps_ext: vkBuildMicromapsEXT::<S>,
+            cmd_begin_conditional_rendering_ext: vkCmdBeginConditionalRenderingEXT::<S>,
+            cmd_begin_custom_resolve_ext: vkCmdBeginCustomResolveEXT::<S>,
+            cmd_begin_per_tile_execution_qcom: vkCmdBeginPerTileExecutionQCOM::<S>,
+            cmd_begin_query: vkCmdBeginQuery::<S>,
+            cmd_begin_query_indexed_ext: vkCmdBeginQueryIndexedEXT::<S>,
+            cmd_begin_render_pass: vkCmdBeginRenderPass::<S>,
+            cmd_begin_render_pass2: vkCmdBeginRenderPass2::<S>,
+            cmd_begin_render_pass2_khr: vkCmdBeginRenderPass2KHR::<S>,
+            cmd_begin_rendering: vkCmdBeginRendering::<S>,
+            cmd_begin_rendering_khr: vkCmdBeginRenderingKHR::<S>,
+            cmd_begin_transform_feedback_ext: vkCmdBeginTransformFeedbackEXT::<S>,
+            cmd_begin_video_coding_khr: vkCmdBeginVideoCodingKHR::<S>,
+            cmd_bind_descriptor_buffer_embedded_samplers2_ext:
+                vkCmdBindDescriptorBufferEmbeddedSamplers2EXT::<S>,
+            cmd_bind_descriptor_buffer_embedded_samplers_ext:
+                vkCmdBindDescriptorBufferEmbeddedSamplersEXT::<S>,
+            cmd_bind_descriptor_buffers_ext: vkCmdBindDescriptorBuffersEXT::<S>,
+            cmd_bind_descriptor_sets: vkCmdBindDescriptorSets::<S>,
+            cmd_bind_descriptor_sets2: vkCmdBindDescriptorSets2::<S>,
+            cmd_bind_descriptor_sets2_khr: vkCmdBindDescriptorSets2KHR::<S>,
+            cmd_bind_index_buffer: vkCmdBindIndexBuffer::<S>,
+            cmd_bind_index_buffer2: vkCmdBindIndexBuffer2::<S>,
+            cmd_bind_index_buffer2_khr: vkCmdBindIndexBuffer2KHR::<S>,
+            cmd_bind_invocation_mask_huawei: vkCmdBindInvocationMaskHUAWEI::<S>,
+            cmd_bind_pipeline: vkCmdBindPipeline::<S>,
+            cmd_bind_pipeline_shader_group_nv: vkCmdBindPipelineShaderGroupNV::<S>,
+            cmd_bind_resource_heap_ext: vkCmdBindResourceHeapEXT::<S>,
+            cmd_bind_sampler_heap_ext: vkCmdBindSamplerHeapEXT::<S>,
+            cmd_bind_shaders_ext: vkCmdBindShadersEXT::<S>,
+            cmd_bind_shading_rate_image_nv: vkCmdBindShadingRateImageNV::<S>,
+            cmd_bind_tile_memory_qcom: vkCmdBindTileMemoryQCOM::<S>,
+            cmd_bind_transform_feedback_buffers_ext: vkCmdBindTransformFeedbackBuffersEXT::<S>,
+            cmd_bind_vertex_buffers: vkCmdBindVertexBuffers::<S>,
+            cmd_bind_vertex_buffers2: vkCmdBindVertexBuffers2::<S>,
+            cmd_bind_vertex_buffers2_ext: vkCmdBindVertexBuffers2EXT::<S>,
+            cmd_blit_image: vkCmdBlitImage::<S>,
+            cmd_blit_image2: vkCmdBlitImage2::<S>,
+            cmd_blit_image2_khr: vkCmdBlitImage2KHR::<S>,
+            cmd_build_acceleration_structure_nv: vkCmdBuildAccelerationStructureNV::<S>,
+            cmd_build_acceleration_structures_indirect_khr:
+                vkCmdBuildAccelerationStructuresIndirectKHR::<S>,
+            cmd_build_acceleration_structures_khr: vkCmdBuildAccelerationStructuresKHR::<S>,
+            cmd_build_cluster_acceleration_structure_indirect_nv:
+                vkCmdBuildClusterAccelerationStructureIndirectNV::<S>,
+            cmd_build_micromaps_ext: vkCmdBuildMicromapsEXT::<S>,
+            cmd_build_partitioned_acceleration_structures_nv:
+                vkCmdBuildPartitionedAccelerationStructuresNV::<S>,
+            cmd_clear_attachments: vkCmdClearAttachments::<S>,
+            cmd_clear_color_image: vkCmdClearColorImage::<S>,
+            cmd_clear_depth_stencil_image: vkCmdClearDepthStencilImage::<S>,
+            cmd_control_video_coding_khr: vkCmdControlVideoCodingKHR::<S>,
+            cmd_convert_cooperative_vector_matrix_nv: vkCmdConvertCooperativeVectorMatrixNV::<S>,
+            cmd_copy_acceleration_structure_khr: vkCmdCopyAccelerationStructureKHR::<S>,
+            cmd_copy_acceleration_structure_nv: vkCmdCopyAccelerationStructureNV::<S>,
+            cmd_copy_acceleration_structure_to_memory_khr:
+                vkCmdCopyAccelerationStructureToMemoryKHR::<S>,
+            cmd_copy_buffer: vkCmdCopyBuffer::<S>,
+            cmd_copy_buffer2: vkCmdCopyBuffer2::<S>,
+            cmd_copy_buffer2_khr: vkCmdCopyBuffer2KHR::<S>,
+            cmd_copy_buffer_to_image: vkCmdCopyBufferToImage::<S>,
+            cmd_copy_buffer_to_image2: vkCmdCopyBufferToImage2::<S>,
+            cmd_copy_buffer_to_image2_khr: vkCmdCopyBufferToImage2KHR::<S>,
+            cmd_copy_image: vkCmdCopyImage::<S>,
+            cmd_copy_image2: vkCmdCopyImage2::<S>,
+            cmd_copy_image2_khr: vkCmdCopyImage2KHR::<S>,
+            cmd_copy_image_to_buffer: vkCmdCopyImageToBuffer::<S>,
+            cmd_copy_image_to_buffer2: vkCmdCopyImageToBuffer2::<S>,
+            cmd_copy_image_to_buffer2_khr: vkCmdCopyImageToBuffer2KHR::<S>,
+            cmd_copy_memory_indirect_khr: vkCmdCopyMemoryIndirectKHR::<S>,
+            cmd_copy_memory_indirect_nv: vkCmdCopyMemoryIndirectNV::<S>,
+            cmd_copy_memory_to_acceleration_structure_khr:
+                vkCmdCopyMemoryToAccelerationStructureKHR::<S>,
+            cmd_copy_memory_to_image_indirect_khr: vkCmdCopyMemoryToImageIndirectKHR::<S>,
+            cmd_copy_memory_to_image_indirect_nv: vkCmdCopyMemoryToImageIndirectNV::<S>,
+            cmd_copy_memory_to_micromap_ext: vkCmdCopyMemoryToMicromapEXT::<S>,
+            cmd_copy_micromap_ext: vkCmdCopyMicromapEXT::<S>,
+            cmd_copy_micromap_to_memory_ext: vkCmdCopyMicromapToMemoryEXT::<S>,
+            cmd_copy_query_pool_results: vkCmdCopyQueryPoolResults::<S>,
+            cmd_copy_tensor_arm: vkCmdCopyTensorARM::<S>,
+            cmd_cu_launch_kernel_nvx: vkCmdCuLaunchKernelNVX::<S>,
+            cmd_cuda_launch_kernel_nv: vkCmdCudaLaunchKernelNV::<S>,
+            cmd_debug_marker_begin_ext: vkCmdDebugMarkerBeginEXT::<S>,
+            cmd_debug_marker_end_ext: vkCmdDebugMarkerEndEXT::<S>,
+            cmd_debug_marker_insert_ext: vkCmdDebugMarkerInsertEXT::<S>,
+            cmd_decode_video_khr: vkCmdDecodeVideoKHR::<S>,
+            cmd_decompress_memory_ext: vkCmdDecompressMemoryEXT::<S>,
+            cmd_decompress_memory_indirect_count_ext: vkCmdDecompressMemoryIndirectCountEXT::<S>,
+            cmd_decompress_memory_indirect_count_nv: vkCmdDecompressMemoryIndirectCountNV::<S>,
+            cmd_decompress_memory_nv: vkCmdDecompressMemoryNV::<S>,
+            cmd_dispatch: vkCmdDispatch::<S>,
+            cmd_dispatch_base: vkCmdDispatchBase::<S>,
+            cmd_dispatch_base_khr: vkCmdDispatchBaseKHR::<S>,
+            cmd_dispatch_data_graph_arm: vkCmdDispatchDataGraphARM::<S>,
+            cmd_dispatch_graph_amdx: vkCmdDispatchGraphAMDX::<S>,
+            cmd_dispatch_graph_indirect_amdx: vkCmdDispatchGraphIndirectAMDX::<S>,
+            cmd_dispatch_graph_indirect_count_amdx: vkCmdDispatchGraphIndirectCountAMDX::<S>,
+            cmd_dispatch_indirect: vkCmdDispatchIndirect::<S>,
+            cmd_dispatch_tile_qcom: vkCmdDispatchTileQCOM::<S>,
+            cmd_draw: vkCmdDraw::<S>,
+            cmd_draw_cluster_huawei: vkCmdDrawClusterHUAWEI::<S>,
+            cmd_draw_cluster_indirect_huawei: vkCmdDrawClusterIndirectHUAWEI::<S>,
+            cmd_draw_indexed: vkCmdDrawIndexed::<S>,
+            cmd_draw_indexed_indirect: vkCmdDrawIndexedIndirect::<S>,
+            cmd_draw_indexed_indirect_count: vkCmdDrawIndexedIndirectCount::<S>,
+            cmd_draw_indexed_indirect_count_amd: vkCmdDrawIndexedIndirectCountAMD::<S>,
+            cmd_draw_indexed_indirect_count_khr: vkCmdDrawIndexedIndirectCountKHR::<S>,
+            cmd_draw_indirect: vkCmdDrawIndirect::<S>,
+            cmd_draw_indirect_byte_count_ext: vkCmdDrawIndirectByteCountEXT::<S>,
+            cmd_draw_indirect_count: vkCmdDrawIndirectCount::<S>,
+            cmd_draw_indirect_count_amd: vkCmdDrawIndirectCountAMD::<S>,
+            cmd_draw_indirect_count_khr: vkCmdDrawIndirectCountKHR::<S>,
+            cmd_draw_mesh_tasks_ext: vkCmdDrawMeshTasksEXT::<S>,
+            cmd_draw_mesh_tasks_indirect_count_ext: vkCmdDrawMeshTasksIndirectCountEXT::<S>,
+            cmd_draw_mesh_tasks_indirect_count_nv: vkCmdDrawMeshTasksIndirectCountNV::<S>,
+            cmd_draw_mesh_tasks_indirect_ext: vkCmdDrawMeshTasksIndirectEXT::<S>,
+            cmd_draw_mesh_tasks_indirect_nv: vkCmdDrawMeshTasksIndirectNV::<S>,
+            cmd_draw_mesh_tasks_nv: vkCmdDrawMeshTasksNV::<S>,
+            cmd_draw_multi_ext: vkCmdDrawMultiEXT::<S>,
+            cmd_draw_multi_indexed_ext: vkCmdDrawMultiIndexedEXT::<S>,
+            cmd_encode_video_khr: vkCmdEncodeVideoKHR::<S>,
+            cmd_end_conditional_rendering_ext: vkCmdEndConditionalRenderingEXT::<S>,
+            cmd_end_per_tile_execution_qcom: vkCmdEndPerTileExecutionQCOM::<S>,
+            cmd_end_query: vkCmdEndQuery::<S>,
+            cmd_end_query_indexed_ext: vkCmdEndQueryIndexedEXT::<S>,
+            cmd_end_render_pass: vkCmdEndRenderPass::<S>,
+            cmd_end_render_pass2: vkCmdEndRenderPass2::<S>,
+            cmd_end_render_pass2_khr: vkCmdEndRenderPass2KHR::<S>,
+            cmd_end_rendering: vkCmdEndRendering::<S>,
+            cmd_end_rendering2_ext: vkCmdEndRendering2EXT::<S>,
+            cmd_end_rendering2_khr: vkCmdEndRendering2KHR::<S>,
+            cmd_end_rendering_khr: vkCmdEndRenderingKHR::<S>,
+            cmd_end_transform_feedback_ext: vkCmdEndTransformFeedbackEXT::<S>,
+            cmd_end_video_coding_khr: vkCmdEndVideoCodingKHR::<S>,
+            cmd_execute_commands: vkCmdExecuteCommands::<S>,
+            cmd_execute_generated_commands_ext: vkCmdExecuteGeneratedCommandsEXT::<S>,
+            cmd_execute_generated_commands_nv: vkCmdExecuteGeneratedCommandsNV::<S>,
+            cmd_fill_buffer: vkCmdFillBuffer::<S>,
+            cmd_initialize_graph_scratch_memory_amdx: vkCmdInitializeGraphScratchMemoryAMDX::<S>,
+            cmd_next_subpass: vkCmdNextSubpass::<S>,
+            cmd_next_subpass2: vkCmdNextSubpass2::<S>,
+            cmd_next_subpass2_khr: vkCmdNextSubpass2KHR::<S>,
+            cmd_optical_flow_execute_nv: vkCmdOpticalFlowExecuteNV::<S>,
+            cmd_pipeline_barrier: vkCmdPipelineBarrier::<S>,
+            cmd_pipeline_barrier2: vkCmdPipelineBarrier2::<S>,
+            cmd_pipeline_barrier2_khr: vkCmdPipelineBarrier2KHR::<S>,
+            cmd_preprocess_generated_commands_ext: vkCmdPreprocessGeneratedCommandsEXT::<S>,
+            cmd_preprocess_generated_commands_nv: vkCmdPreprocessGeneratedCommandsNV::<S>,
+            cmd_push_constants: vkCmdPushConstants::<S>,
+            cmd_push_constants2: vkCmdPushConstants2::<S>,
+            cmd_push_constants2_khr: vkCmdPushConstants2KHR::<S>,
+            cmd_push_data_ext: vkCmdPushDataEXT::<S>,
+            cmd_push_descriptor_set: vkCmdPushDescriptorSet::<S>,
+            cmd_push_descriptor_set2: vkCmdPushDescriptorSet2::<S>,
+            cmd_push_descriptor_set2_khr: vkCmdPushDescriptorSet2KHR::<S>,
+            cmd_push_descriptor_set_khr: vkCmdPushDescriptorSetKHR::<S>,
+            cmd_push_descriptor_set_with_template: vkCmdPushDescriptorSetWithTemplate::<S>,
+            cmd_push_descriptor_set_with_template2: vkCmdPushDescriptorSetWithTemplate2::<S>,
+            cmd_push_descriptor_set_with_template2_khr: vkCmdPushDescriptorSetWithTemplate2KHR::<S>,
+            cmd_push_descriptor_set_with_template_khr: vkCmdPushDescriptorSetWithTemplateKHR::<S>,
+            cmd_refresh_objects_khr: vkCmdRefreshObjectsKHR::<S>,
+            cmd_reset_event: vkCmdResetEvent::<S>,
+            cmd_reset_event2: vkCmdResetEvent2::<S>,
+            cmd_reset_event2_khr: vkCmdResetEvent2KHR::<S>,
+            cmd_reset_query_pool: vkCmdResetQueryPool::<S>,
+            cmd_resolve_image: vkCmdResolveImage::<S>,
+            cmd_resolve_image2: vkCmdResolveImage2::<S>,
+            cmd_resolve_image2_khr: vkCmdResolveImage2KHR::<S>,
+            cmd_set_alpha_to_coverage_enable_ext: vkCmdSetAlphaToCoverageEnableEXT::<S>,
+            cmd_set_alpha_to_one_enable_ext: vkCmdSetAlphaToOneEnableEXT::<S>,
+            cmd_set_attachment_feedback_loop_enable_ext: vkCmdSetAttachmentFeedbackLoopEnableEXT::<S>,
+            cmd_set_blend_constants: vkCmdSetBlendConstants::<S>,
+            cmd_set_checkpoint_nv: vkCmdSetCheckpointNV::<S>,
+            cmd_set_coarse_sample_order_nv: vkCmdSetCoarseSampleOrderNV::<S>,
+            cmd_set_color_blend_advanced_ext: vkCmdSetColorBlendAdvancedEXT::<S>,
+            cmd_set_color_blend_enable_ext: vkCmdSetColorBlendEnableEXT::<S>,
+            cmd_set_color_blend_equation_ext: vkCmdSetColorBlendEquationEXT::<S>,
+            cmd_set_color_write_enable_ext: vkCmdSetColorWriteEnableEXT::<S>,
+            cmd_set_color_write_mask_ext: vkCmdSetColorWriteMaskEXT::<S>,
+            cmd_set_compute_occupancy_priority_nv: vkCmdSetComputeOccupancyPriorityNV::<S>,
+            cmd_set_conservative_rasterization_mode_ext: vkCmdSetConservativeRasterizationModeEXT::<
+                S,
+            >,
+            cmd_set_coverage_modulation_mode_nv: vkCmdSetCoverageModulationModeNV::<S>,
+            cmd_set_coverage_modulation_table_enable_nv: vkCmdSetCoverageModulationTableEnableNV::<S>,
+            cmd_set_coverage_modulation_table_nv: vkCmdSetCoverageModulationTableNV::<S>,
+            cmd_set_coverage_reduction_mode_nv: vkCmdSetCoverageReductionModeNV::<S>,
+            cmd_set_coverage_to_color_enable_nv: vkCmdSetCoverageToColorEnableNV::<S>,
+            cmd_set_coverage_to_color_location_nv: vkCmdSetCoverageToColorLocationNV::<S>,
+            cmd_set_cull_mode: vkCmdSetCullMode::<S>,
+            cmd_set_cull_mode_ext: vkCmdSetCullModeEXT::<S>,
+            cmd_set_depth_bias: vkCmdSetDepthBias::<S>,
+            cmd_set_depth_bias2_ext: vkCmdSetDepthBias2EXT::<S>,
+            cmd_set_depth_bias_enable: vkCmdSetDepthBiasEnable::<S>,
+            cmd_set_depth_bias_enable_ext: vkCmdSetDepthBiasEnableEXT::<S>,
+            cmd_set_depth_bounds: vkCmdSetDepthBounds::<S>,
+            cmd_set_depth_bounds_test_enable: vkCmdSetDepthBoundsTestEnable::<S>,
+            cmd_set_depth_bounds_test_enable_ext: vkCmdSetDepthBoundsTestEnableEXT::<S>,
+            cmd_set_depth_clamp_enable_ext: vkCmdSetDepthClampEnableEXT::<S>,
+            cmd_set_depth_clamp_range_ext: vkCmdSetDepthClampRangeEXT::<S>,
+            cmd_set_depth_clip_enable_ext: vkCmdSetDepthClipEnableEXT::<S>,
+            cmd_set_depth_clip_negative_one_to_one_ext: vkCmdSetDepthClipNegativeOneToOneEXT::<S>,
+            cmd_set_depth_compare_op: vkCmdSetDepthCompareOp::<S>,
+            cmd_set_depth_compare_op_ext: vkCmdSetDepthCompareOpEXT::<S>,
+            cmd_set_depth_test_enable: vkCmdSetDepthTestEnable::<S>,
+            cmd_set_depth_test_enable_ext: vkCmdSetDepthTestEnableEXT::<S>,
+            cmd_set_depth_write_enable: vkCmdSetDepthWriteEnable::<S>,
+            cmd_set_depth_write_enable_ext: vkCmdSetDepthWriteEnableEXT::<S>,
+            cmd_set_descriptor_buffer_offsets2_ext: vkCmdSetDescriptorBufferOffsets2EXT::<S>,
+            cmd_set_descriptor_buffer_offsets_ext: vkCmdSetDescriptorBufferOffsetsEXT::<S>,
+            cmd_set_device_mask: vkCmdSetDeviceMask::<S>,
+            cmd_set_device_mask_khr: vkCmdSetDeviceMaskKHR::<S>,
+            cmd_set_discard_rectangle_ext: vkCmdSetDiscardRectangleEXT::<S>,
+            cmd_set_discard_rectangle_enable_ext: vkCmdSetDiscardRectangleEnableEXT::<S>,
+            cmd_set_discard_rectangle_mode_ext: vkCmdSetDiscardRectangleModeEXT::<S>,
+            cmd_set_event: vkCmdSetEvent::<S>,
+            cmd_set_event2: vkCmdSetEvent2::<S>,
+            cmd_set_event2_khr: vkCmdSetEvent2KHR::<S>,
+            cmd_set_exclusive_scissor_enable_nv: vkCmdSetExclusiveScissorEnableNV::<S>,
+            cmd_set_exclusive_scissor_nv: vkCmdSetExclusiveScissorNV::<S>,
+            cmd_set_extra_primitive_overestimation_size_ext:
+                vkCmdSetExtraPrimitiveOverestimationSizeEXT::<S>,
+            cmd_set_fragment_shading_rate_enum_nv: vkCmdSetFragmentShadingRateEnumNV::<S>,
+            cmd_set_fragment_shading_rate_khr: vkCmdSetFragmentShadingRateKHR::<S>,
+            cmd_set_front_face: vkCmdSetFrontFace::<S>,
+            cmd_set_front_face_ext: vkCmdSetFrontFaceEXT::<S>,
+            cmd_set_line_rasterization_mode_ext: vkCmdSetLineRasterizationModeEXT::<S>,
+            cmd_set_line_stipple: vkCmdSetLineStipple::<S>,
+            cmd_set_line_stipple_ext: vkCmdSetLineStippleEXT::<S>,
+            cmd_set_line_stipple_enable_ext: vkCmdSetLineStippleEnableEXT::<S>,
+            cmd_set_line_stipple_khr: vkCmdSetLineStippleKHR::<S>,
+            cmd_set_line_width: vkCmdSetLineWidth::<S>,
+            cmd_set_logic_op_ext: vkCmdSetLogicOpEXT::<S>,
+            cmd_set_logic_op_enable_ext: vkCmdSetLogicOpEnableEXT::<S>,
+            cmd_set_patch_control_points_ext: vkCmdSetPatchControlPointsEXT::<S>,
+            cmd_set_performance_marker_intel: vkCmdSetPerformanceMarkerINTEL::<S>,
+            cmd_set_performance_override_intel: vkCmdSetPerformanceOverrideINTEL::<S>,
+            cmd_set_performance_stream_marker_intel: vkCmdSetPerformanceStreamMarkerINTEL::<S>,
+            cmd_set_polygon_mode_ext: vkCmdSetPolygonModeEXT::<S>,
+            cmd_set_primitive_restart_enable: vkCmdSetPrimitiveRestartEnable::<S>,
+            cmd_set_primitive_restart_enable_ext: vkCmdSetPrimitiveRestartEnableEXT::<S>,
+            cmd_set_primitive_topology: vkCmdSetPrimitiveTopology::<S>,
+            cmd_set_primitive_topology_ext: vkCmdSetPrimitiveTopologyEXT::<S>,
+            cmd_set_provoking_vertex_mode_ext: vkCmdSetProvokingVertexModeEXT::<S>,
+            cmd_set_rasterization_samples_ext: vkCmdSetRasterizationSamplesEXT::<S>,
+            cmd_set_rasterization_stream_ext: vkCmdSetRasterizationStreamEXT::<S>,
+            cmd_set_rasterizer_discard_enable: vkCmdSetRasterizerDiscardEnable::<S>,
+            cmd_set_rasterizer_discard_enable_ext: vkCmdSetRasterizerDiscardEnableEXT::<S>,
+            cmd_set_ray_tracing_pipeline_stack_size_khr: vkCmdSetRayTracingPipelineStackSizeKHR::<S>,
+            cmd_set_rendering_attachment_locations: vkCmdSetRenderingAttachmentLocations::<S>,
+            cmd_set_rendering_attachment_locations_khr: vkCmdSetRenderingAttachmentLocationsKHR::<S>,
+            cmd_set_rendering_input_attachment_indices: vkCmdSetRenderingInputAttachmentIndices::<S>,
+            cmd_set_rendering_input_attachment_indices_khr:
+                vkCmdSetRenderingInputAttachmentIndicesKHR::<S>,
+            cmd_set_representative_fragment_test_enable_nv:
+                vkCmdSetRepresentativeFragmentTestEnableNV::<S>,
+            cmd_set_sample_locations_ext: vkCmdSetSampleLocationsEXT::<S>,
+            cmd_set_sample_locations_enable_ext: vkCmdSetSampleLocationsEnableEXT::<S>,
+            cmd_set_sample_mask_ext: vkCmdSetSampleMaskEXT::<S>,
+            cmd_set_scissor: vkCmdSetScissor::<S>,
+            cmd_set_scissor_with_count: vkCmdSetScissorWithCount::<S>,
+            cmd_set_scissor_with_count_ext: vkCmdSetScissorWithCountEXT::<S>,
+            cmd_set_shading_rate_image_enable_nv: vkCmdSetShadingRateImageEnableNV::<S>,
+            cmd_set_stencil_compare_mask: vkCmdSetStencilCompareMask::<S>,
+            cmd_set_stencil_op: vkCmdSetStencilOp::<S>,
+            cmd_set_stencil_op_ext: vkCmdSetStencilOpEXT::<S>,
+            cmd_set_stencil_reference: vkCmdSetStencilReference::<S>,
+            cmd_set_stencil_test_enable: vkCmdSetStencilTestEnable::<S>,
+            cmd_set_stencil_test_enable_ext: vkCmdSetStencilTestEnableEXT::<S>,
+            cmd_set_stencil_write_mask: vkCmdSetStencilWriteMask::<S>,
+            cmd_set_tessellation_domain_origin_ext: vkCmdSetTessellationDomainOriginEXT::<S>,
+            cmd_set_vertex_input_ext: vkCmdSetVertexInputEXT::<S>,
+            cmd_set_viewport: vkCmdSetViewport::<S>,
+            cmd_set_viewport_shading_rate_palette_nv: vkCmdSetViewportShadingRatePaletteNV::<S>,
+            cmd_set_viewport_swizzle_nv: vkCmdSetViewportSwizzleNV::<S>,
+            cmd_set_viewport_w_scaling_enable_nv: vkCmdSetViewportWScalingEnableNV::<S>,
+            cmd_set_viewport_w_scaling_nv: vkCmdSetViewportWScalingNV::<S>,
+            cmd_set_viewport_with_count: vkCmdSetViewportWithCount::<S>,
+            cmd_set_viewport_with_count_ext: vkCmdSetViewportWithCountEXT::<S>,
+            cmd_subpass_shading_huawei: vkCmdSubpassShadingHUAWEI::<S>,
+            cmd_trace_rays_indirect2_khr: vkCmdTraceRaysIndirect2KHR::<S>,
+            cmd_trace_rays_indirect_khr: vkCmdTraceRaysIndirectKHR::<S>,
+            cmd_trace_rays_khr: vkCmdTraceRaysKHR::<S>,
+            cmd_trace_rays_nv: vkCmdTraceRaysNV::<S>,
+            cmd_update_buffer: vkCmdUpdateBuffer::<S>,
+            cmd_update_pipeline_indirect_buffer_nv: vkCmdUpdatePipelineIndirectBufferNV::<S>,
+            cmd_wait_events: vkCmdWaitEvents::<S>,
+            cmd_wait_events2: vkCmdWaitEvents2::<S>,
+            cmd_wait_events2_khr: vkCmdWaitEvents2KHR::<S>,
+            cmd_write_acceleration_structures_properties_khr:
+                vkCmdWriteAccelerationStructuresPropertiesKHR::<S>,
+            cmd_write_acceleration_structures_properties_nv:
+                vkCmdWriteAccelerationStructuresPropertiesNV::<S>,
+            cmd_write_buffer_marker2_amd: vkCmdWriteBufferMarker2AMD::<S>,
+            cmd_write_buffer_marker_amd: vkCmdWriteBufferMarkerAMD::<S>,
+            cmd_write_micromaps_properties_ext: vkCmdWriteMicromapsPropertiesEXT::<S>,
+            cmd_write_timestamp: vkCmdWriteTimestamp::<S>,
+            cmd_write_timestamp2: vkCmdWriteTimestamp2::<S>,
+            cmd_write_timestamp2_khr: vkCmdWriteTimestamp2KHR::<S>,
+            compile_deferred_nv: vkCompileDeferredNV::<S>,
+            convert_cooperative_vector_matrix_nv: vkConvertCooperativeVectorMatrixNV::<S>,
+            copy_acceleration_structure_khr: vkCopyAccelerationStructureKHR::<S>,
+            copy_acceleration_structure_to_memory_khr: vkCopyAccelerationStructureToMemoryKHR::<S>,
+            copy_image_to_image: vkCopyImageToImage::<S>,
+            copy_image_to_image_ext: vkCopyImageToImageEXT::<S>,
+            copy_image_to_memory: vkCopyImageToMemory::<S>,
+            copy_image_to_memory_ext: vkCopyImageToMemoryEXT::<S>,
+            copy_memory_to_acceleration_structure_khr: vkCopyMemoryToAccelerationStructureKHR::<S>,
+            copy_memory_to_image: vkCopyMemoryToImage::<S>,
+            copy_memory_to_image_ext: vkCopyMemoryToImageEXT::<S>,
+            copy_memory_to_micromap_ext: vkCopyMemoryToMicromapEXT::<S>,
+            copy_micromap_ext: vkCopyMicromapEXT::<S>,
+            copy_micromap_to_memory_ext: vkCopyMicromapToMemoryEXT::<S>,
+            create_acceleration_structure_khr: vkCreateAccelerationStructureKHR::<S>,
+            create_acceleration_structure_nv: vkCreateAccelerationStructureNV::<S>,
+            create_buffer: vkCreateBuffer::<S>,
+            create_buffer_collection_fuchsia: vkCreateBufferCollectionFUCHSIA::<S>,
+            create_buffer_view: vkCreateBufferView::<S>,
+            create_command_pool: vkCreateCommandPool::<S>,
+            create_compute_pipelines: vkCreateComputePipelines::<S>,
+            create_cu_function_nvx: vkCreateCuFunctionNVX::<S>,
+            create_cu_module_nvx: vkCreateCuModuleNVX::<S>,
+            create_cuda_function_nv: vkCreateCudaFunctionNV::<S>,
+            create_cuda_module_nv: vkCreateCudaModuleNV::<S>,
+            create_data_graph_pipeline_session_arm: vkCreateDataGraphPipelineSessionARM::<S>,
+            create_data_graph_pipelines_arm: vkCreateDataGraphPipelinesARM::<S>,
+            create_deferred_operation_khr: vkCreateDeferredOperationKHR::<S>,
+            create_descriptor_pool: vkCreateDescriptorPool::<S>,
+            create_descriptor_set_layout: vkCreateDescriptorSetLayout::<S>,
+            create_descriptor_update_template: vkCreateDescriptorUpdateTemplate::<S>,
+            create_descriptor_update_template_khr: vkCreateDescriptorUpdateTemplateKHR::<S>,
+            create_event: vkCreateEvent::<S>,
+            create_execution_graph_pipelines_amdx: vkCreateExecutionGraphPipelinesAMDX::<S>,
+            create_external_compute_queue_nv: vkCreateExternalComputeQueueNV::<S>,
+            create_fence: vkCreateFence::<S>,
+            create_framebuffer: vkCreateFramebuffer::<S>,
+            create_graphics_pipelines: vkCreateGraphicsPipelines::<S>,
+            create_image: vkCreateImage::<S>,
+            create_image_view: vkCreateImageView::<S>,
+            create_indirect_commands_layout_ext: vkCreateIndirectCommandsLayoutEXT::<S>,
+            create_indirect_commands_layout_nv: vkCreateIndirectCommandsLayoutNV::<S>,
+            create_indirect_execution_set_ext: vkCreateIndirectExecutionSetEXT::<S>,
+            create_micromap_ext: vkCreateMicromapEXT::<S>,
+            create_optical_flow_session_nv: vkCreateOpticalFlowSessionNV::<S>,
+            create_pipeline_binaries_khr: vkCreatePipelineBinariesKHR::<S>,
+            create_pipeline_cache: vkCreatePipelineCache::<S>,
+            create_pipeline_layout: vkCreatePipelineLayout::<S>,
+            create_private_data_slot: vkCreatePrivateDataSlot::<S>,
+            create_private_data_slot_ext: vkCreatePrivateDataSlotEXT::<S>,
+            create_query_pool: vkCreateQueryPool::<S>,
+            create_ray_tracing_pipelines_khr: vkCreateRayTracingPipelinesKHR::<S>,
+            create_ray_tracing_pipelines_nv: vkCreateRayTracingPipelinesNV::<S>,
+            create_render_pass: vkCreateRenderPass::<S>,
+            create_render_pass2: vkCreateRenderPass2::<S>,
+            create_render_pass2_khr: vkCreateRenderPass2KHR::<S>,
+            create_sampler: vkCreateSampler::<S>,
+            create_sampler_ycbcr_conversion: vkCreateSamplerYcbcrConversion::<S>,
+            create_sampler_ycbcr_conversion_khr: vkCreateSamplerYcbcrConversionKHR::<S>,
+            create_semaphore: vkCreateSemaphore::<S>,
+            create_semaphore_sci_sync_pool_nv: vkCreateSemaphoreSciSyncPoolNV::<S>,
+            create_shader_module: vkCreateShaderModule::<S>,
+            create_shaders_ext: vkCreateShadersEXT::<S>,
+            create_shared_swapchains_khr: vkCreateSharedSwapchainsKHR::<S>,
+            create_swapchain_khr: vkCreateSwapchainKHR::<S>,
+            create_tensor_arm: vkCreateTensorARM::<S>,
+            create_tensor_view_arm: vkCreateTensorViewARM::<S>,
+            create_validation_cache_ext: vkCreateValidationCacheEXT::<S>,
+            create_video_session_khr: vkCreateVideoSessionKHR::<S>,
+            create_video_session_parameters_khr: vkCreateVideoSessionParametersKHR::<S>,
+            debug_marker_set_object_name_ext: vkDebugMarkerSetObjectNameEXT::<S>,
+            debug_marker_set_object_tag_ext: vkDebugMarkerSetObjectTagEXT::<S>,
+            deferred_operation_join_khr: vkDeferredOperationJoinKHR::<S>,
+            destroy_acceleration_structure_khr: vkDestroyAccelerationStructureKHR::<S>,
+            destroy_acceleration_structure_nv: vkDestroyAccelerationStructureNV::<S>,
+            destroy_buffer: vkDestroyBuffer::<S>,
+            destroy_buffer_collection_fuchsia: vkDestroyBufferCollectionFUCHSIA::<S>,
+            destroy_buffer_view: vkDestroyBufferView::<S>,
+            destroy_command_pool: vkDestroyCommandPool::<S>,
+            destroy_cu_function_nvx: vkDestroyCuFunctionNVX::<S>,
+            destroy_cu_module_nvx: vkDestroyCuModuleNVX::<S>,
+            destroy_cuda_function_nv: vkDestroyCudaFunctionNV::<S>,
+            destroy_cuda_module_nv: vkDestroyCudaModuleNV::<S>,
+            destroy_data_graph_pipeline_session_arm: vkDestroyDataGraphPipelineSessionARM::<S>,
+            destroy_deferred_operation_khr: vkDestroyDeferredOperationKHR::<S>,
+            destroy_descriptor_pool: vkDestroyDescriptorPool::<S>,
+            destroy_descriptor_set_layout: vkDestroyDescriptorSetLayout::<S>,
+            destroy_descriptor_update_template: vkDestroyDescriptorUpdateTemplate::<S>,
+            destroy_descriptor_update_template_khr: vkDestroyDescriptorUpdateTemplateKHR::<S>,
+            destroy_device: vkDestroyDevice::<S>,
+            destroy_event: vkDestroyEvent::<S>,
+            destroy_external_compute_queue_nv: vkDestroyExternalComputeQueueNV::<S>,
+            destroy_fence: vkDestroyFence::<S>,
+            destroy_framebuffer: vkDestroyFramebuffer::<S>,
+            destroy_image: vkDestroyImage::<S>,
+            destroy_image_view: vkDestroyImageView::<S>,
+            destroy_indirect_commands_layout_ext: vkDestroyIndirectCommandsLayoutEXT::<S>,
+            destroy_indirect_commands_layout_nv: vkDestroyIndirectCommandsLayoutNV::<S>,
+            destroy_indirect_execution_set_ext: vkDestroyIndirectExecutionSetEXT::<S>,
+            destroy_micromap_ext: vkDestroyMicromapEXT::<S>,
+            destroy_optical_flow_session_nv: vkDestroyOpticalFlowSessionNV::<S>,
+            destroy_pipeline: vkDestroyPipeline::<S>,
+            destroy_pipeline_binary_khr: vkDestroyPipelineBinaryKHR::<S>,
+            destroy_pipeline_cache: vkDestroyPipelineCache::<S>,
+            destroy_pipeline_layout: vkDestroyPipelineLayout::<S>,
+            destroy_private_data_slot: vkDestroyPrivateDataSlot::<S>,
+            destroy_private_data_slot_ext: vkDestroyPrivateDataSlotEXT::<S>,
+            destroy_query_pool: vkDestroyQueryPool::<S>,
+            destroy_render_pass: vkDestroyRenderPass::<S>,
+            destroy_sampler: vkDestroySampler::<S>,
+            destroy_sampler_ycbcr_conversion: vkDestroySamplerYcbcrConversion::<S>,
+            destroy_sampler_ycbcr_conversion_khr: vkDestroySamplerYcbcrConversionKHR::<S>,
+            destroy_semaphore: vkDestroySemaphore::<S>,
+            destroy_semaphore_sci_sync_pool_nv: vkDestroySemaphoreSciSyncPoolNV::<S>,
+            destroy_shader_ext: vkDestroyShaderEXT::<S>,
+            destroy_shader_module: vkDestroyShaderModule::<S>,
+            destroy_swapchain_khr: vkDestroySwapchainKHR::<S>,
+            destroy_tensor_arm: vkDestroyTensorARM::<S>,
+            destroy_tensor_view_arm: vkDestroyTensorViewARM::<S>,
+            destroy_validation_cache_ext: vkDestroyValidationCacheEXT::<S>,
+            destroy_video_session_khr: vkDestroyVideoSessionKHR::<S>,
+            destroy_video_session_parameters_khr: vkDestroyVideoSessionParametersKHR::<S>,
+            device_wait_idle: vkDeviceWaitIdle::<S>,
+            display_power_control_ext: vkDisplayPowerControlEXT::<S>,
+            end_command_buffer: vkEndCommandBuffer::<S>,
+            export_metal_objects_ext: vkExportMetalObjectsEXT::<S>,
+            flush_mapped_memory_ranges: vkFlushMappedMemoryRanges::<S>,
+            free_command_buffers: vkFreeCommandBuffers::<S>,
+            free_descriptor_sets: vkFreeDescriptorSets::<S>,
+            free_memory: vkFreeMemory::<S>,
+            get_acceleration_structure_build_sizes_khr: vkGetAccelerationStructureBuildSizesKHR::<S>,
+            get_acceleration_structure_device_address_khr:
+                vkGetAccelerationStructureDeviceAddressKHR::<S>,
+            get_acceleration_structure_handle_nv: vkGetAccelerationStructureHandleNV::<S>,
+            get_acceleration_structure_memory_requirements_nv:
+                vkGetAccelerationStructureMemoryRequirementsNV::<S>,
+            get_acceleration_structure_opaque_capture_descriptor_data_ext:
+                vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT::<S>,
+            get_android_hardware_buffer_properties_android:
+                vkGetAndroidHardwareBufferPropertiesANDROID::<S>,
+            get_buffer_collection_properties_fuchsia: vkGetBufferCollectionPropertiesFUCHSIA::<S>,
+            get_buffer_device_address: vkGetBufferDeviceAddress::<S>,
+            get_buffer_device_address_ext: vkGetBufferDeviceAddressEXT::<S>,
+            get_buffer_device_address_khr: vkGetBufferDeviceAddressKHR::<S>,
+            get_buffer_memory_requirements: vkGetBufferMemoryRequirements::<S>,
+            get_buffer_memory_requirements2: vkGetBufferMemoryRequirements2::<S>,
+            get_buffer_memory_requirements2_khr: vkGetBufferMemoryRequirements2KHR::<S>,
+            get_buffer_opaque_capture_address: vkGetBufferOpaqueCaptureAddress::<S>,
+            get_buffer_opaque_capture_address_khr: vkGetBufferOpaqueCaptureAddressKHR::<S>,
+            get_buffer_opaque_capture_descriptor_data_ext:
+                vkGetBufferOpaqueCaptureDescriptorDataEXT::<S>,
+            get_calibrated_timestamps_ext: vkGetCalibratedTimestampsEXT::<S>,
+            get_calibrated_timestamps_khr: vkGetCalibratedTimestampsKHR::<S>,
+            get_cluster_acceleration_structure_build_sizes_nv:
+                vkGetClusterAccelerationStructureBuildSizesNV::<S>,
+            get_cuda_module_cache_nv: vkGetCudaModuleCacheNV::<S>,
+            get_data_graph_pipeline_available_properties_arm:
+                vkGetDataGraphPipelineAvailablePropertiesARM::<S>,
+            get_data_graph_pipeline_properties_arm: vkGetDataGraphPipelinePropertiesARM::<S>,
+            get_data_graph_pipeline_session_bind_point_requirements_arm:
+                vkGetDataGraphPipelineSessionBindPointRequirementsARM::<S>,
+            get_data_graph_pipeline_session_memory_requirements_arm:
+                vkGetDataGraphPipelineSessionMemoryRequirementsARM::<S>,
+            get_deferred_operation_max_concurrency_khr: vkGetDeferredOperationMaxConcurrencyKHR::<S>,
+            get_deferred_operation_result_khr: vkGetDeferredOperationResultKHR::<S>,
+            get_descriptor_ext: vkGetDescriptorEXT::<S>,
+            get_descriptor_set_host_mapping_valve: vkGetDescriptorSetHostMappingVALVE::<S>,
+            get_descriptor_set_layout_binding_offset_ext: vkGetDescriptorSetLayoutBindingOffsetEXT::<
+                S,
+            >,
+            get_descriptor_set_layout_host_mapping_info_valve:
+                vkGetDescriptorSetLayoutHostMappingInfoVALVE::<S>,
+            get_descriptor_set_layout_size_ext: vkGetDescriptorSetLayoutSizeEXT::<S>,
+            get_descriptor_set_layout_support: vkGetDescriptorSetLayoutSupport::<S>,
+            get_descriptor_set_layout_support_khr: vkGetDescriptorSetLayoutSupportKHR::<S>,
+            get_device_acceleration_structure_compatibility_khr:
+                vkGetDeviceAccelerationStructureCompatibilityKHR::<S>,
+            get_device_buffer_memory_requirements: vkGetDeviceBufferMemoryRequirements::<S>,
+            get_device_buffer_memory_requirements_khr: vkGetDeviceBufferMemoryRequirementsKHR::<S>,
+            get_device_combined_image_sampler_index_nvx: vkGetDeviceCombinedImageSamplerIndexNVX::<S>,
+            get_device_fault_info_ext: vkGetDeviceFaultInfoEXT::<S>,
+            get_device_group_peer_memory_features: vkGetDeviceGroupPeerMemoryFeatures::<S>,
+            get_device_group_peer_memory_features_khr: vkGetDeviceGroupPeerMemoryFeaturesKHR::<S>,
+            get_device_group_present_capabilities_khr: vkGetDeviceGroupPresentCapabilitiesKHR::<S>,
+            get_device_group_surface_present_modes2_ext: vkGetDeviceGroupSurfacePresentModes2EXT::<S>,
+            get_device_group_surface_present_modes_khr: vkGetDeviceGroupSurfacePresentModesKHR::<S>,
+            get_device_image_memory_requirements: vkGetDeviceImageMemoryRequirements::<S>,
+            get_device_image_memory_requirements_khr: vkGetDeviceImageMemoryRequirementsKHR::<S>,
+            get_device_image_sparse_memory_requirements: vkGetDeviceImageSparseMemoryRequirements::<
+                S,
+            >,
+            get_device_image_sparse_memory_requirements_khr:
+                vkGetDeviceImageSparseMemoryRequirementsKHR::<S>,
+            get_device_image_subresource_layout: vkGetDeviceImageSubresourceLayout::<S>,
+            get_device_image_subresource_layout_khr: vkGetDeviceImageSubresourceLayoutKHR::<S>,
+            get_device_memory_commitment: vkGetDeviceMemoryCommitment::<S>,
+            get_device_memory_opaque_capture_address: vkGetDeviceMemoryOpaqueCaptureAddress::<S>,
+            get_device_memory_opaque_capture_address_khr: vkGetDeviceMemoryOpaqueCaptureAddressKHR::<
+                S,
+            >,
+            get_device_micromap_compatibility_ext: vkGetDeviceMicromapCompatibilityEXT::<S>,
+            get_device_queue: vkGetDeviceQueue::<S>,
+            get_device_queue2: vkGetDeviceQueue2::<S>,
+            get_device_subpass_shading_max_workgroup_size_huawei:
+                vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI::<S>,
+            get_device_tensor_memory_requirements_arm: vkGetDeviceTensorMemoryRequirementsARM::<S>,
+            get_dynamic_rendering_tile_properties_qcom: vkGetDynamicRenderingTilePropertiesQCOM::<S>,
+            get_encoded_video_session_parameters_khr: vkGetEncodedVideoSessionParametersKHR::<S>,
+            get_event_status: vkGetEventStatus::<S>,
+            get_execution_graph_pipeline_node_index_amdx: vkGetExecutionGraphPipelineNodeIndexAMDX::<
+                S,
+            >,
+            get_execution_graph_pipeline_scratch_size_amdx:
+                vkGetExecutionGraphPipelineScratchSizeAMDX::<S>,
+            get_external_compute_queue_data_nv: vkGetExternalComputeQueueDataNV::<S>,
+            get_fence_fd_khr: vkGetFenceFdKHR::<S>,
+            get_fence_sci_sync_fence_nv: vkGetFenceSciSyncFenceNV::<S>,
+            get_fence_sci_sync_obj_nv: vkGetFenceSciSyncObjNV::<S>,
+            get_fence_status: vkGetFenceStatus::<S>,
+            get_fence_win32_handle_khr: vkGetFenceWin32HandleKHR::<S>,
+            get_framebuffer_tile_properties_qcom: vkGetFramebufferTilePropertiesQCOM::<S>,
+            get_generated_commands_memory_requirements_ext:
+                vkGetGeneratedCommandsMemoryRequirementsEXT::<S>,
+            get_generated_commands_memory_requirements_nv:
+                vkGetGeneratedCommandsMemoryRequirementsNV::<S>,
+            get_image_drm_format_modifier_properties_ext: vkGetImageDrmFormatModifierPropertiesEXT::<
+                S,
+            >,
+            get_image_memory_requirements: vkGetImageMemoryRequirements::<S>,
+            get_image_memory_requirements2: vkGetImageMemoryRequirements2::<S>,
+            get_image_memory_requirements2_khr: vkGetImageMemoryRequirements2KHR::<S>,
+            get_image_opaque_capture_data_ext: vkGetImageOpaqueCaptureDataEXT::<S>,
+            get_image_opaque_capture_descriptor_data_ext: vkGetImageOpaqueCaptureDescriptorDataEXT::<
+                S,
+            >,
+            get_image_sparse_memory_requirements: vkGetImageSparseMemoryRequirements::<S>,
+            get_image_sparse_memory_requirements2: vkGetImageSparseMemoryRequirements2::<S>,
+            get_image_sparse_memory_requirements2_khr: vkGetImageSparseMemoryRequirements2KHR::<S>,
+            get_image_subresource_layout: vkGetImageSubresourceLayout::<S>,
+            get_image_subresource_layout2: vkGetImageSubresourceLayout2::<S>,
+            get_image_subresource_layout2_ext: vkGetImageSubresourceLayout2EXT::<S>,
+            get_image_subresource_layout2_khr: vkGetImageSubresourceLayout2KHR::<S>,
+            get_image_view_address_nvx: vkGetImageViewAddressNVX::<S>,
+            get_image_view_handle64_nvx: vkGetImageViewHandle64NVX::<S>,
+            get_image_view_handle_nvx: vkGetImageViewHandleNVX::<S>,
+            get_image_view_opaque_capture_descriptor_data_ext:
+                vkGetImageViewOpaqueCaptureDescriptorDataEXT::<S>,
+            get_latency_timings_nv: vkGetLatencyTimingsNV::<S>,
+            get_memory_android_hardware_buffer_android: vkGetMemoryAndroidHardwareBufferANDROID::<S>,
+            get_memory_fd_khr: vkGetMemoryFdKHR::<S>,
+            get_memory_fd_properties_khr: vkGetMemoryFdPropertiesKHR::<S>,
+            get_memory_host_pointer_properties_ext: vkGetMemoryHostPointerPropertiesEXT::<S>,
+            get_memory_metal_handle_ext: vkGetMemoryMetalHandleEXT::<S>,
+            get_memory_metal_handle_properties_ext: vkGetMemoryMetalHandlePropertiesEXT::<S>,
+            get_memory_native_buffer_ohos: vkGetMemoryNativeBufferOHOS::<S>,
+            get_memory_remote_address_nv: vkGetMemoryRemoteAddressNV::<S>,
+            get_memory_sci_buf_nv: vkGetMemorySciBufNV::<S>,
+            get_memory_win32_handle_khr: vkGetMemoryWin32HandleKHR::<S>,
+            get_memory_win32_handle_nv: vkGetMemoryWin32HandleNV::<S>,
+            get_memory_win32_handle_properties_khr: vkGetMemoryWin32HandlePropertiesKHR::<S>,
+            get_memory_zircon_handle_fuchsia: vkGetMemoryZirconHandleFUCHSIA::<S>,
+            get_memory_zircon_handle_properties_fuchsia: vkGetMemoryZirconHandlePropertiesFUCHSIA::<
+                S,
+            >,
+            get_micromap_build_sizes_ext: vkGetMicromapBuildSizesEXT::<S>,
+            get_native_buffer_properties_ohos: vkGetNativeBufferPropertiesOHOS::<S>,
+            get_partitioned_acceleration_structures_build_sizes_nv:
+                vkGetPartitionedAccelerationStructuresBuildSizesNV::<S>,
+            get_past_presentation_timing_ext: vkGetPastPresentationTimingEXT::<S>,
+            get_past_presentation_timing_google: vkGetPastPresentationTimingGOOGLE::<S>,
+            get_performance_parameter_intel: vkGetPerformanceParameterINTEL::<S>,
+            get_pipeline_binary_data_khr: vkGetPipelineBinaryDataKHR::<S>,
+            get_pipeline_cache_data: vkGetPipelineCacheData::<S>,
+            get_pipeline_executable_internal_representations_khr:
+                vkGetPipelineExecutableInternalRepresentationsKHR::<S>,
+            get_pipeline_executable_properties_khr: vkGetPipelineExecutablePropertiesKHR::<S>,
+            get_pipeline_executable_statistics_khr: vkGetPipelineExecutableStatisticsKHR::<S>,
+            get_pipeline_indirect_device_address_nv: vkGetPipelineIndirectDeviceAddressNV::<S>,
+            get_pipeline_indirect_memory_requirements_nv: vkGetPipelineIndirectMemoryRequirementsNV::<
+                S,
+            >,
+            get_pipeline_key_khr: vkGetPipelineKeyKHR::<S>,
+            get_pipeline_properties_ext: vkGetPipelinePropertiesEXT::<S>,
+            get_private_data: vkGetPrivateData::<S>,
+            get_private_data_ext: vkGetPrivateDataEXT::<S>,
+            get_query_pool_results: vkGetQueryPoolResults::<S>,
+            get_queue_checkpoint_data2_nv: vkGetQueueCheckpointData2NV::<S>,
+            get_queue_checkpoint_data_nv: vkGetQueueCheckpointDataNV::<S>,
+            get_ray_tracing_capture_replay_shader_group_handles_khr:
+                vkGetRayTracingCaptureReplayShaderGroupHandlesKHR::<S>,
+            get_ray_tracing_shader_group_handles_khr: vkGetRayTracingShaderGroupHandlesKHR::<S>,
+            get_ray_tracing_shader_group_handles_nv: vkGetRayTracingShaderGroupHandlesNV::<S>,
+            get_ray_tracing_shader_group_stack_size_khr: vkGetRayTracingShaderGroupStackSizeKHR::<S>,
+            get_refresh_cycle_duration_google: vkGetRefreshCycleDurationGOOGLE::<S>,
+            get_render_area_granularity: vkGetRenderAreaGranularity::<S>,
+            get_rendering_area_granularity: vkGetRenderingAreaGranularity::<S>,
+            get_rendering_area_granularity_khr: vkGetRenderingAreaGranularityKHR::<S>,
+            get_sampler_opaque_capture_descriptor_data_ext:
+                vkGetSamplerOpaqueCaptureDescriptorDataEXT::<S>,
+            get_screen_buffer_properties_qnx: vkGetScreenBufferPropertiesQNX::<S>,
+            get_semaphore_counter_value: vkGetSemaphoreCounterValue::<S>,
+            get_semaphore_counter_value_khr: vkGetSemaphoreCounterValueKHR::<S>,
+            get_semaphore_fd_khr: vkGetSemaphoreFdKHR::<S>,
+            get_semaphore_sci_sync_obj_nv: vkGetSemaphoreSciSyncObjNV::<S>,
+            get_semaphore_win32_handle_khr: vkGetSemaphoreWin32HandleKHR::<S>,
+            get_semaphore_zircon_handle_fuchsia: vkGetSemaphoreZirconHandleFUCHSIA::<S>,
+            get_shader_binary_data_ext: vkGetShaderBinaryDataEXT::<S>,
+            get_shader_info_amd: vkGetShaderInfoAMD::<S>,
+            get_shader_module_create_info_identifier_ext: vkGetShaderModuleCreateInfoIdentifierEXT::<
+                S,
+            >,
+            get_shader_module_identifier_ext: vkGetShaderModuleIdentifierEXT::<S>,
+            get_swapchain_counter_ext: vkGetSwapchainCounterEXT::<S>,
+            get_swapchain_images_khr: vkGetSwapchainImagesKHR::<S>,
+            get_swapchain_status_khr: vkGetSwapchainStatusKHR::<S>,
+            get_swapchain_time_domain_properties_ext: vkGetSwapchainTimeDomainPropertiesEXT::<S>,
+            get_swapchain_timing_properties_ext: vkGetSwapchainTimingPropertiesEXT::<S>,
+            get_tensor_memory_requirements_arm: vkGetTensorMemoryRequirementsARM::<S>,
+            get_tensor_opaque_capture_data_arm: vkGetTensorOpaqueCaptureDataARM::<S>,
+            get_tensor_opaque_capture_descriptor_data_arm:
+                vkGetTensorOpaqueCaptureDescriptorDataARM::<S>,
+            get_tensor_view_opaque_capture_descriptor_data_arm:
+                vkGetTensorViewOpaqueCaptureDescriptorDataARM::<S>,
+            get_validation_cache_data_ext: vkGetValidationCacheDataEXT::<S>,
+            get_video_session_memory_requirements_khr: vkGetVideoSessionMemoryRequirementsKHR::<S>,
+            import_fence_fd_khr: vkImportFenceFdKHR::<S>,
+            import_fence_sci_sync_fence_nv: vkImportFenceSciSyncFenceNV::<S>,
+            import_fence_sci_sync_obj_nv: vkImportFenceSciSyncObjNV::<S>,
+            import_fence_win32_handle_khr: vkImportFenceWin32HandleKHR::<S>,
+            import_semaphore_fd_khr: vkImportSemaphoreFdKHR::<S>,
+            import_semaphore_sci_sync_obj_nv: vkImportSemaphoreSciSyncObjNV::<S>,
+            import_semaphore_win32_handle_khr: vkImportSemaphoreWin32HandleKHR::<S>,
+            import_semaphore_zircon_handle_fuchsia: vkImportSemaphoreZirconHandleFUCHSIA::<S>,
+            initialize_performance_api_intel: vkInitializePerformanceApiINTEL::<S>,
+            invalidate_mapped_memory_ranges: vkInvalidateMappedMemoryRanges::<S>,
+            latency_sleep_nv: vkLatencySleepNV::<S>,
+            map_memory: vkMapMemory::<S>,
+            map_memory2: vkMapMemory2::<S>,
+            map_memory2_khr: vkMapMemory2KHR::<S>,
+            merge_pipeline_caches: vkMergePipelineCaches::<S>,
+            merge_validation_caches_ext: vkMergeValidationCachesEXT::<S>,
+            queue_bind_sparse: vkQueueBindSparse::<S>,
+            queue_notify_out_of_band_nv: vkQueueNotifyOutOfBandNV::<S>,
+            queue_present_khr: vkQueuePresentKHR::<S>,
+            queue_set_performance_configuration_intel: vkQueueSetPerformanceConfigurationINTEL::<S>,
+            queue_submit: vkQueueSubmit::<S>,
+            queue_submit2: vkQueueSubmit2::<S>,
+            queue_submit2_khr: vkQueueSubmit2KHR::<S>,
+            queue_wait_idle: vkQueueWaitIdle::<S>,
+            register_custom_border_color_ext: vkRegisterCustomBorderColorEXT::<S>,
+            register_device_event_ext: vkRegisterDeviceEventEXT::<S>,
+            register_display_event_ext: vkRegisterDisplayEventEXT::<S>,
+            release_captured_pipeline_data_khr: vkReleaseCapturedPipelineDataKHR::<S>,
+            release_full_screen_exclusive_mode_ext: vkReleaseFullScreenExclusiveModeEXT::<S>,
+            release_performance_configuration_intel: vkReleasePerformanceConfigurationINTEL::<S>,
+            release_profiling_lock_khr: vkReleaseProfilingLockKHR::<S>,
+            release_swapchain_images_ext: vkReleaseSwapchainImagesEXT::<S>,
+            release_swapchain_images_khr: vkReleaseSwapchainImagesKHR::<S>,
+            reset_command_buffer: vkResetCommandBuffer::<S>,
+            reset_command_pool: vkResetCommandPool::<S>,
+            reset_descriptor_pool: vkResetDescriptorPool::<S>,
+            reset_event: vkResetEvent::<S>,
+            reset_fences: vkResetFences::<S>,
+            reset_query_pool: vkResetQueryPool::<S>,
+            reset_query_pool_ext: vkResetQueryPoolEXT::<S>,
+            set_buffer_collection_buffer_constraints_fuchsia:
+                vkSetBufferCollectionBufferConstraintsFUCHSIA::<S>,
+            set_buffer_collection_image_constraints_fuchsia:
+                vkSetBufferCollectionImageConstraintsFUCHSIA::<S>,
+            set_device_memory_priority_ext: vkSetDeviceMemoryPriorityEXT::<S>,
+            set_event: vkSetEvent::<S>,
+            set_hdr_metadata_ext: vkSetHdrMetadataEXT::<S>,
+            set_latency_marker_nv: vkSetLatencyMarkerNV::<S>,
+            set_latency_sleep_mode_nv: vkSetLatencySleepModeNV::<S>,
+            set_local_dimming_amd: vkSetLocalDimmingAMD::<S>,
+            set_private_data: vkSetPrivateData::<S>,
+            set_private_data_ext: vkSetPrivateDataEXT::<S>,
+            set_swapchain_present_timing_queue_size_ext: vkSetSwapchainPresentTimingQueueSizeEXT::<S>,
+            signal_semaphore: vkSignalSemaphore::<S>,
+            signal_semaphore_khr: vkSignalSemaphoreKHR::<S>,
+            transition_image_layout: vkTransitionImageLayout::<S>,
+            transition_image_layout_ext: vkTransitionImageLayoutEXT::<S>,
+            trim_command_pool: vkTrimCommandPool::<S>,
+            trim_command_pool_khr: vkTrimCommandPoolKHR::<S>,
+            uninitialize_performance_api_intel: vkUninitializePerformanceApiINTEL::<S>,
+            unmap_memory: vkUnmapMemory::<S>,
+            unmap_memory2: vkUnmapMemory2::<S>,
+            unmap_memory2_khr: vkUnmapMemory2KHR::<S>,
+            unregister_custom_border_color_ext: vkUnregisterCustomBorderColorEXT::<S>,
+            update_descriptor_set_with_template: vkUpdateDescriptorSetWithTemplate::<S>,
+            update_descriptor_set_with_template_khr: vkUpdateDescriptorSetWithTemplateKHR::<S>,
+            update_descriptor_sets: vkUpdateDescriptorSets::<S>,
+            update_indirect_execution_set_pipeline_ext: vkUpdateIndirectExecutionSetPipelineEXT::<S>,
+            update_indirect_execution_set_shader_ext: vkUpdateIndirectExecutionSetShaderEXT::<S>,
+            update_video_session_parameters_khr: vkUpdateVideoSessionParametersKHR::<S>,
+            wait_for_fences: vkWaitForFences::<S>,
+            wait_for_present2_khr: vkWaitForPresent2KHR::<S>,
+            wait_for_present_khr: vkWaitForPresentKHR::<S>,
+            wait_semaphores: vkWaitSemaphores::<S>,
+            wait_semaphores_khr: vkWaitSemaphoresKHR::<S>,
+            write_acceleration_structures_properties_khr:
+                vkWriteAccelerationStructuresPropertiesKHR::<S>,
+            write_micromaps_properties_ext: vkWriteMicromapsPropertiesEXT::<S>,
+            write_resource_descriptors_ext: vkWriteResourceDescriptorsEXT::<S>,
+            write_sampler_descriptors_ext: vkWriteSamplerDescriptorsEXT::<S>,
+        }
+    }
+}
+
+impl InstanceCommands {
+    /// Creates instance commands which call the command hooks installed in a
+    /// hook slot around the unhooked instance commands installed in the slot.
+    #[inline]
+    pub(crate) fn hooked<const S: usize>() -> Self {
+        Self {
+            acquire_drm_display_ext: vkAcquireDrmDisplayEXT::<S>,
+            acquire_winrt_display_nv: vkAcquireWinrtDisplayNV::<S>,
+            acquire_xlib_display_ext: vkAcquireXlibDisplayEXT::<S>,
+            cmd_begin_debug_utils_label_ext: vkCmdBeginDebugUtilsLabelEXT::<S>,
+            cmd_end_debug_utils_label_ext: vkCmdEndDebugUtilsLabelEXT::<S>,
+            cmd_insert_debug_utils_label_ext: vkCmdInsertDebugUtilsLabelEXT::<S>,
+            create_android_surface_khr: vkCreateAndroidSurfaceKHR::<S>,
+            create_debug_report_callback_ext: vkCreateDebugReportCallbackEXT::<S>,
+            create_debug_utils_messenger_ext: vkCreateDebugUtilsMessengerEXT::<S>,
+            create_device: vkCreateDevice::<S>,
+            create_direct_fb_surface_ext: vkCreateDirectFBSurfaceEXT::<S>,
+            create_display_mode_khr: vkCreateDisplayModeKHR::<S>,
+            create_display_plane_surface_khr: vkCreateDisplayPlaneSurfaceKHR::<S>,
+            create_headless_surface_ext: vkCreateHeadlessSurfaceEXT::<S>,
+            create_ios_surface_mvk: vkCreateIOSSurfaceMVK::<S>,
+            create_image_pipe_surface_fuchsia: vkCreateImagePipeSurfaceFUCHSIA::<S>,
+            create_mac_os_surface_mvk: vkCreateMacOSSurfaceMVK::<S>,
+            create_metal_surface_ext: vkCreateMetalSurfaceEXT::<S>,
+            create_screen_surface_qnx: vkCreateScreenSurfaceQNX::<S>,
+            create_stream_descriptor_surface_ggp: vkCreateStreamDescriptorSurfaceGGP::<S>,
+            create_surface_ohos: vkCreateSurfaceOHOS::<S>,
+            create_ubm_surface_sec: vkCreateUbmSurfaceSEC::<S>,
+            create_vi_surface_nn: vkCreateViSurfaceNN::<S>,
+            create_wayland_surface_khr: vkCreateWaylandSurfaceKHR::<S>,
+            create_win32_surface_khr: vkCreateWin32SurfaceKHR::<S>,
+            create_xcb_surface_khr: vkCreateXcbSurfaceKHR::<S>,
+            create_xlib_surface_khr: vkCreateXlibSurfaceKHR::<S>,
+            debug_report_message_ext: vkDebugReportMessageEXT::<S>,
+            destroy_debug_report_callback_ext: vkDestroyDebugReportCallbackEXT::<S>,
+            destroy_debug_utils_messenger_ext: vkDestroyDebugUtilsMessengerEXT::<S>,
+            destroy_instance: vkDestroyInstance::<S>,
+            destroy_surface_khr: vkDestroySurfaceKHR::<S>,
+            enumerate_device_extension_properties: vkEnumerateDeviceExtensionProperties::<S>,
+            enumerate_device_layer_properties: vkEnumerateDeviceLayerProperties::<S>,
+            enumerate_physical_device_groups: vkEnumeratePhysicalDeviceGroups::<S>,
+            enumerate_physical_device_groups_khr: vkEnumeratePhysicalDeviceGroupsKHR::<S>,
+            enumerate_physical_device_queue_family_performance_counters_by_region_arm:
+                vkEnumeratePhysicalDeviceQueueFamilyPerformanceCountersByRegionARM::<S>,
+            enumerate_physical_device_queue_family_performance_query_counters_khr:
+                vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR::<S>,
+            enumerate_physical_devices: vkEnumeratePhysicalDevices::<S>,
+            get_device_proc_addr: vkGetDeviceProcAddr::<S>,
+            get_display_mode_properties2_khr: vkGetDisplayModeProperties2KHR::<S>,
+            get_display_mode_properties_khr: vkGetDisplayModePropertiesKHR::<S>,
+            get_display_plane_capabilities2_khr: vkGetDisplayPlaneCapabilities2KHR::<S>,
+            get_display_plane_capabilities_khr: vkGetDisplayPlaneCapabilitiesKHR::<S>,
+            get_display_plane_supported_displays_khr: vkGetDisplayPlaneSupportedDisplaysKHR::<S>,
+            get_drm_display_ext: vkGetDrmDisplayEXT::<S>,
+            get_physical_device_calibrateable_time_domains_ext:
+                vkGetPhysicalDeviceCalibrateableTimeDomainsEXT::<S>,
+            get_physical_device_calibrateable_time_domains_khr:
+                vkGetPhysicalDeviceCalibrateableTimeDomainsKHR::<S>,
+            get_physical_device_cooperative_matrix_flexible_dimensions_properties_nv:
+                vkGetPhysicalDeviceCooperativeMatrixFlexibleDimensionsPropertiesNV::<S>,
+            get_physical_device_cooperative_matrix_properties_khr:
+                vkGetPhysicalDeviceCooperativeMatrixPropertiesKHR::<S>,
+            get_physical_device_cooperative_matrix_properties_nv:
+                vkGetPhysicalDeviceCooperativeMatrixPropertiesNV::<S>,
+            get_physical_device_cooperative_vector_properties_nv:
+                vkGetPhysicalDeviceCooperativeVectorPropertiesNV::<S>,
+            get_physical_device_descriptor_size_ext: vkGetPhysicalDeviceDescriptorSizeEXT::<S>,
+            get_physical_device_direct_fb_presentation_support_ext:
+                vkGetPhysicalDeviceDirectFBPresentationSupportEXT::<S>,
+            get_physical_device_display_plane_properties2_khr:
+                vkGetPhysicalDeviceDisplayPlaneProperties2KHR::<S>,
+            get_physical_device_display_plane_properties_khr:
+                vkGetPhysicalDeviceDisplayPlanePropertiesKHR::<S>,
+            get_physical_device_display_properties2_khr: vkGetPhysicalDeviceDisplayProperties2KHR::<
+                S,
+            >,
+            get_physical_device_display_properties_khr: vkGetPhysicalDeviceDisplayPropertiesKHR::<S>,
+            get_physical_device_external_buffer_properties:
+                vkGetPhysicalDeviceExternalBufferProperties::<S>,
+            get_physical_device_external_buffer_properties_khr:
+                vkGetPhysicalDeviceExternalBufferPropertiesKHR::<S>,
+            get_physical_device_external_fence_properties:
+                vkGetPhysicalDeviceExternalFenceProperties::<S>,
+            get_physical_device_external_fence_properties_khr:
+                vkGetPhysicalDeviceExternalFencePropertiesKHR::<S>,
+            get_physical_device_external_image_format_properties_nv:
+                vkGetPhysicalDeviceExternalImageFormatPropertiesNV::<S>,
+            get_physical_device_external_memory_sci_buf_properties_nv:
+                vkGetPhysicalDeviceExternalMemorySciBufPropertiesNV::<S>,
+            get_physical_device_external_semaphore_properties:
+                vkGetPhysicalDeviceExternalSemaphoreProperties::<S>,
+            get_physical_device_external_semaphore_properties_khr:
+                vkGetPhysicalDeviceExternalSemaphorePropertiesKHR::<S>,
+            get_physical_device_external_tensor_properties_arm:
+                vkGetPhysicalDeviceExternalTensorPropertiesARM::<S>,
+            get_physical_device_features: vkGetPhysicalDeviceFeatures::<S>,
+            get_physical_device_features2: vkGetPhysicalDeviceFeatures2::<S>,
+            get_physical_device_features2_khr: vkGetPhysicalDeviceFeatures2KHR::<S>,
+            get_physical_device_format_properties: vkGetPhysicalDeviceFormatProperties::<S>,
+            get_physical_device_format_properties2: vkGetPhysicalDeviceFormatProperties2::<S>,
+            get_physical_device_format_properties2_khr: vkGetPhysicalDeviceFormatProperties2KHR::<S>,
+            get_physical_device_fragment_shading_rates_khr:
+                vkGetPhysicalDeviceFragmentShadingRatesKHR::<S>,
+            get_physical_device_image_format_properties: vkGetPhysicalDeviceImageFormatProperties::<
+                S,
+            >,
+            get_physical_device_image_format_properties2: vkGetPhysicalDeviceImageFormatProperties2::<
+                S,
+            >,
+            get_physical_device_image_format_properties2_khr:
+                vkGetPhysicalDeviceImageFormatProperties2KHR::<S>,
+            get_physical_device_memory_properties: vkGetPhysicalDeviceMemoryProperties::<S>,
+            get_physical_device_memory_properties2: vkGetPhysicalDeviceMemoryProperties2::<S>,
+            get_physical_device_memory_properties2_khr: vkGetPhysicalDeviceMemoryProperties2KHR::<S>,
+            get_physical_device_multisample_properties_ext:
+                vkGetPhysicalDeviceMultisamplePropertiesEXT::<S>,
+            get_physical_device_optical_flow_image_formats_nv:
+                vkGetPhysicalDeviceOpticalFlowImageFormatsNV::<S>,
+            get_physical_device_present_rectangles_khr: vkGetPhysicalDevicePresentRectanglesKHR::<S>,
+            get_physical_device_properties: vkGetPhysicalDeviceProperties::<S>,
+            get_physical_device_properties2: vkGetPhysicalDeviceProperties2::<S>,
+            get_physical_device_properties2_khr: vkGetPhysicalDeviceProperties2KHR::<S>,
+            get_physical_device_queue_family_data_graph_processing_engine_properties_arm:
+                vkGetPhysicalDeviceQueueFamilyDataGraphProcessingEnginePropertiesARM::<S>,
+            get_physical_device_queue_family_data_graph_properties_arm:
+                vkGetPhysicalDeviceQueueFamilyDataGraphPropertiesARM::<S>,
+            get_physical_device_queue_family_performance_query_passes_khr:
+                vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR::<S>,
+            get_physical_device_queue_family_properties: vkGetPhysicalDeviceQueueFamilyProperties::<
+                S,
+            >,
+            get_physical_device_queue_family_properties2: vkGetPhysicalDeviceQueueFamilyProperties2::<
+                S,
+            >,
+            get_physical_device_queue_family_properties2_khr:
+                vkGetPhysicalDeviceQueueFamilyProperties2KHR::<S>,
+            get_physical_device_refreshable_object_types_khr:
+                vkGetPhysicalDeviceRefreshableObjectTypesKHR::<S>,
+            get_physical_device_sci_buf_attributes_nv: vkGetPhysicalDeviceSciBufAttributesNV::<S>,
+            get_physical_device_sci_sync_attributes_nv: vkGetPhysicalDeviceSciSyncAttributesNV::<S>,
+            get_physical_device_screen_presentation_support_qnx:
+                vkGetPhysicalDeviceScreenPresentationSupportQNX::<S>,
+            get_physical_device_sparse_image_format_properties:
+                vkGetPhysicalDeviceSparseImageFormatProperties::<S>,
+            get_physical_device_sparse_image_format_properties2:
+                vkGetPhysicalDeviceSparseImageFormatProperties2::<S>,
+            get_physical_device_sparse_image_format_properties2_khr:
+                vkGetPhysicalDeviceSparseImageFormatProperties2KHR::<S>,
+            get_physical_device_supported_framebuffer_mixed_samples_combinations_nv:
+                vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV::<S>,
+            get_physical_device_surface_capabilities2_ext:
+                vkGetPhysicalDeviceSurfaceCapabilities2EXT::<S>,
+            get_physical_device_surface_capabilities2_khr:
+                vkGetPhysicalDeviceSurfaceCapabilities2KHR::<S>,
+            get_physical_device_surface_capabilities_khr: vkGetPhysicalDeviceSurfaceCapabilitiesKHR::<
+                S,
+            >,
+            get_physical_device_surface_formats2_khr: vkGetPhysicalDeviceSurfaceFormats2KHR::<S>,
+            get_physical_device_surface_formats_khr: vkGetPhysicalDeviceSurfaceFormatsKHR::<S>,
+            get_physical_device_surface_present_modes2_ext:
+                vkGetPhysicalDeviceSurfacePresentModes2EXT::<S>,
+            get_physical_device_surface_present_modes_khr:
+                vkGetPhysicalDeviceSurfacePresentModesKHR::<S>,
+            get_physical_device_surface_support_khr: vkGetPhysicalDeviceSurfaceSupportKHR::<S>,
+            get_physical_device_tool_properties: vkGetPhysicalDeviceToolProperties::<S>,
+            get_physical_device_tool_properties_ext: vkGetPhysicalDeviceToolPropertiesEXT::<S>,
+            get_physical_device_ubm_presentation_support_sec:
+                vkGetPhysicalDeviceUbmPresentationSupportSEC::<S>,
+            get_physical_device_video_capabilities_khr: vkGetPhysicalDeviceVideoCapabilitiesKHR::<S>,
+            get_physical_device_video_encode_quality_level_properties_khr:
+                vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR::<S>,
+            get_physical_device_video_format_properties_khr:
+                vkGetPhysicalDeviceVideoFormatPropertiesKHR::<S>,
+            get_physical_device_wayland_presentation_support_khr:
+                vkGetPhysicalDeviceWaylandPresentationSupportKHR::<S>,
+            get_physical_device_win32_presentation_support_khr:
+                vkGetPhysicalDeviceWin32PresentationSupportKHR::<S>,
+            get_physical_device_xcb_presentation_support_khr:
+                vkGetPhysicalDeviceXcbPresentationSupportKHR::<S>,
+            get_physical_device_xlib_presentation_support_khr:
+                vkGetPhysicalDeviceXlibPresentationSupportKHR::<S>,
+            get_rand_r_output_display_ext: vkGetRandROutputDisplayEXT::<S>,
+            get_winrt_display_nv: vkGetWinrtDisplayNV::<S>,
+            queue_begin_debug_utils_label_ext: vkQueueBeginDebugUtilsLabelEXT::<S>,
+            queue_end_debug_utils_label_ext: vkQueueEndDebugUtilsLabelEXT::<S>,
+            queue_insert_debug_utils_label_ext: vkQueueInsertDebugUtilsLabelEXT::<S>,
+            release_display_ext: vkReleaseDisplayEXT::<S>,
+            set_debug_utils_object_name_ext: vkSetDebugUtilsObjectNameEXT::<S>,
+            set_debug_utils_object_tag_ext: vkSetDebugUtilsObjectTagEXT::<S>,
+            submit_debug_utils_message_ext: vkSubmitDebugUtilsMessageEXT::<S>,
+        }
+    }
+}
+
+unsafe extern "system" fn vkAcquireFullScreenExclusiveModeEXT<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkAcquireFullScreenExclusiveModeEXT") {
+        Some(result) => result,
+        None => (hooked.commands.acquire_full_screen_exclusive_mode_ext)(_device, _swapchain),
+    };
+    hooked.after("vkAcquireFullScreenExclusiveModeEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkAcquireNextImage2KHR<const S: usize>(
+    _device: Device,
+    _acquire_info: *const AcquireNextImageInfoKHR,
+    _image_index: *mut u32,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkAcquireNextImage2KHR") {
+        Some(result) => result,
+        None => (hooked.commands.acquire_next_image2_khr)(_device, _acquire_info, _image_index),
+    };
+    hooked.after("vkAcquireNextImage2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkAcquireNextImageKHR<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _timeout: u64,
+    _semaphore: Semaphore,
+    _fence: Fence,
+    _image_index: *mut u32,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkAcquireNextImageKHR") {
+        Some(result) => result,
+        None => (hooked.commands.acquire_next_image_khr)(
+            _device,
+            _swapchain,
+            _timeout,
+            _semaphore,
+            _fence,
+            _image_index,
+        ),
+    };
+    hooked.after("vkAcquireNextImageKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkAcquirePerformanceConfigurationINTEL<const S: usize>(
+    _device: Device,
+    _acquire_info: *const PerformanceConfigurationAcquireInfoINTEL,
+    _configuration: *mut PerformanceConfigurationINTEL,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkAcquirePerformanceConfigurationINTEL") {
+        Some(result) => result,
+        None => (hooked.commands.acquire_performance_configuration_intel)(
+            _device,
+            _acquire_info,
+            _configuration,
+        ),
+    };
+    hooked.after("vkAcquirePerformanceConfigurationINTEL", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkAcquireProfilingLockKHR<const S: usize>(
+    _device: Device,
+    _info: *const AcquireProfilingLockInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkAcquireProfilingLockKHR") {
+        Some(result) => result,
+        None => (hooked.commands.acquire_profiling_lock_khr)(_device, _info),
+    };
+    hooked.after("vkAcquireProfilingLockKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkAllocateCommandBuffers<const S: usize>(
+    _device: Device,
+    _allocate_info: *const CommandBufferAllocateInfo,
+    _command_buffers: *mut CommandBuffer,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkAllocateCommandBuffers") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.allocate_command_buffers)(_device, _allocate_info, _command_buffers)
+        }
+    };
+    hooked.after("vkAllocateCommandBuffers", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkAllocateDescriptorSets<const S: usize>(
+    _device: Device,
+    _allocate_info: *const DescriptorSetAllocateInfo,
+    _descriptor_sets: *mut DescriptorSet,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkAllocateDescriptorSets") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.allocate_descriptor_sets)(_device, _allocate_info, _descriptor_sets)
+        }
+    };
+    hooked.after("vkAllocateDescriptorSets", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkAllocateMemory<const S: usize>(
+    _device: Device,
+    _allocate_info: *const MemoryAllocateInfo,
+    _allocator: *const AllocationCallbacks,
+    _memory: *mut DeviceMemory,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkAllocateMemory") {
+        Some(result) => result,
+        None => (hooked.commands.allocate_memory)(_device, _allocate_info, _allocator, _memory),
+    };
+    hooked.after("vkAllocateMemory", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkAntiLagUpdateAMD<const S: usize>(
+    _device: Device,
+    _data: *const AntiLagDataAMD,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkAntiLagUpdateAMD");
+    (hooked.commands.anti_lag_update_amd)(_device, _data);
+    hooked.after("vkAntiLagUpdateAMD", None);
+}
+
+unsafe extern "system" fn vkBeginCommandBuffer<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _begin_info: *const CommandBufferBeginInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBeginCommandBuffer") {
+        Some(result) => result,
+        None => (hooked.commands.begin_command_buffer)(_command_buffer, _begin_info),
+    };
+    hooked.after("vkBeginCommandBuffer", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBindAccelerationStructureMemoryNV<const S: usize>(
+    _device: Device,
+    _bind_info_count: u32,
+    _bind_infos: *const BindAccelerationStructureMemoryInfoNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBindAccelerationStructureMemoryNV") {
+        Some(result) => result,
+        None => (hooked.commands.bind_acceleration_structure_memory_nv)(
+            _device,
+            _bind_info_count,
+            _bind_infos,
+        ),
+    };
+    hooked.after("vkBindAccelerationStructureMemoryNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBindBufferMemory<const S: usize>(
+    _device: Device,
+    _buffer: Buffer,
+    _memory: DeviceMemory,
+    _memory_offset: DeviceSize,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBindBufferMemory") {
+        Some(result) => result,
+        None => (hooked.commands.bind_buffer_memory)(_device, _buffer, _memory, _memory_offset),
+    };
+    hooked.after("vkBindBufferMemory", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBindBufferMemory2<const S: usize>(
+    _device: Device,
+    _bind_info_count: u32,
+    _bind_infos: *const BindBufferMemoryInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBindBufferMemory2") {
+        Some(result) => result,
+        None => (hooked.commands.bind_buffer_memory2)(_device, _bind_info_count, _bind_infos),
+    };
+    hooked.after("vkBindBufferMemory2", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBindBufferMemory2KHR<const S: usize>(
+    _device: Device,
+    _bind_info_count: u32,
+    _bind_infos: *const BindBufferMemoryInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBindBufferMemory2KHR") {
+        Some(result) => result,
+        None => (hooked.commands.bind_buffer_memory2_khr)(_device, _bind_info_count, _bind_infos),
+    };
+    hooked.after("vkBindBufferMemory2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBindDataGraphPipelineSessionMemoryARM<const S: usize>(
+    _device: Device,
+    _bind_info_count: u32,
+    _bind_infos: *const BindDataGraphPipelineSessionMemoryInfoARM,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBindDataGraphPipelineSessionMemoryARM") {
+        Some(result) => result,
+        None => (hooked.commands.bind_data_graph_pipeline_session_memory_arm)(
+            _device,
+            _bind_info_count,
+            _bind_infos,
+        ),
+    };
+    hooked.after("vkBindDataGraphPipelineSessionMemoryARM", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBindImageMemory<const S: usize>(
+    _device: Device,
+    _image: Image,
+    _memory: DeviceMemory,
+    _memory_offset: DeviceSize,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBindImageMemory") {
+        Some(result) => result,
+        None => (hooked.commands.bind_image_memory)(_device, _image, _memory, _memory_offset),
+    };
+    hooked.after("vkBindImageMemory", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBindImageMemory2<const S: usize>(
+    _device: Device,
+    _bind_info_count: u32,
+    _bind_infos: *const BindImageMemoryInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBindImageMemory2") {
+        Some(result) => result,
+        None => (hooked.commands.bind_image_memory2)(_device, _bind_info_count, _bind_infos),
+    };
+    hooked.after("vkBindImageMemory2", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBindImageMemory2KHR<const S: usize>(
+    _device: Device,
+    _bind_info_count: u32,
+    _bind_infos: *const BindImageMemoryInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBindImageMemory2KHR") {
+        Some(result) => result,
+        None => (hooked.commands.bind_image_memory2_khr)(_device, _bind_info_count, _bind_infos),
+    };
+    hooked.after("vkBindImageMemory2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBindOpticalFlowSessionImageNV<const S: usize>(
+    _device: Device,
+    _session: OpticalFlowSessionNV,
+    _binding_point: OpticalFlowSessionBindingPointNV,
+    _view: ImageView,
+    _layout: ImageLayout,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBindOpticalFlowSessionImageNV") {
+        Some(result) => result,
+        None => (hooked.commands.bind_optical_flow_session_image_nv)(
+            _device,
+            _session,
+            _binding_point,
+            _view,
+            _layout,
+        ),
+    };
+    hooked.after("vkBindOpticalFlowSessionImageNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBindTensorMemoryARM<const S: usize>(
+    _device: Device,
+    _bind_info_count: u32,
+    _bind_infos: *const BindTensorMemoryInfoARM,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBindTensorMemoryARM") {
+        Some(result) => result,
+        None => (hooked.commands.bind_tensor_memory_arm)(_device, _bind_info_count, _bind_infos),
+    };
+    hooked.after("vkBindTensorMemoryARM", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBindVideoSessionMemoryKHR<const S: usize>(
+    _device: Device,
+    _video_session: VideoSessionKHR,
+    _bind_session_memory_info_count: u32,
+    _bind_session_memory_infos: *const BindVideoSessionMemoryInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBindVideoSessionMemoryKHR") {
+        Some(result) => result,
+        None => (hooked.commands.bind_video_session_memory_khr)(
+            _device,
+            _video_session,
+            _bind_session_memory_info_count,
+            _bind_session_memory_infos,
+        ),
+    };
+    hooked.after("vkBindVideoSessionMemoryKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBuildAccelerationStructuresKHR<const S: usize>(
+    _device: Device,
+    _deferred_operation: DeferredOperationKHR,
+    _info_count: u32,
+    _infos: *const AccelerationStructureBuildGeometryInfoKHR,
+    _build_range_infos: *const *const AccelerationStructureBuildRangeInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBuildAccelerationStructuresKHR") {
+        Some(result) => result,
+        None => (hooked.commands.build_acceleration_structures_khr)(
+            _device,
+            _deferred_operation,
+            _info_count,
+            _infos,
+            _build_range_infos,
+        ),
+    };
+    hooked.after("vkBuildAccelerationStructuresKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkBuildMicromapsEXT<const S: usize>(
+    _device: Device,
+    _deferred_operation: DeferredOperationKHR,
+    _info_count: u32,
+    _infos: *const MicromapBuildInfoEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkBuildMicromapsEXT") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.build_micromaps_ext)(_device, _deferred_operation, _info_count, _infos)
+        }
+    };
+    hooked.after("vkBuildMicromapsEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCmdBeginConditionalRenderingEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _conditional_rendering_begin: *const ConditionalRenderingBeginInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBeginConditionalRenderingEXT");
+    (hooked.commands.cmd_begin_conditional_rendering_ext)(
+        _command_buffer,
+        _conditional_rendering_begin,
+    );
+    hooked.after("vkCmdBeginConditionalRenderingEXT", None);
+}
+
+unsafe extern "system" fn vkCmdBeginCustomResolveEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _begin_custom_resolve_info: *const BeginCustomResolveInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBeginCustomResolveEXT");
+    (hooked.commands.cmd_begin_custom_resolve_ext)(_command_buffer, _begin_custom_resolve_info);
+    hooked.after("vkCmdBeginCustomResolveEXT", None);
+}
+
+unsafe extern "system" fn vkCmdBeginPerTileExecutionQCOM<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _per_tile_begin_info: *const PerTileBeginInfoQCOM,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBeginPerTileExecutionQCOM");
+    (hooked.commands.cmd_begin_per_tile_execution_qcom)(_command_buffer, _per_tile_begin_info);
+    hooked.after("vkCmdBeginPerTileExecutionQCOM", None);
+}
+
+unsafe extern "system" fn vkCmdBeginQuery<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _query_pool: QueryPool,
+    _query: u32,
+    _flags: QueryControlFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBeginQuery");
+    (hooked.commands.cmd_begin_query)(_command_buffer, _query_pool, _query, _flags);
+    hooked.after("vkCmdBeginQuery", None);
+}
+
+unsafe extern "system" fn vkCmdBeginQueryIndexedEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _query_pool: QueryPool,
+    _query: u32,
+    _flags: QueryControlFlags,
+    _index: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBeginQueryIndexedEXT");
+    (hooked.commands.cmd_begin_query_indexed_ext)(
+        _command_buffer,
+        _query_pool,
+        _query,
+        _flags,
+        _index,
+    );
+    hooked.after("vkCmdBeginQueryIndexedEXT", None);
+}
+
+unsafe extern "system" fn vkCmdBeginRenderPass<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _render_pass_begin: *const RenderPassBeginInfo,
+    _contents: SubpassContents,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBeginRenderPass");
+    (hooked.commands.cmd_begin_render_pass)(_command_buffer, _render_pass_begin, _contents);
+    hooked.after("vkCmdBeginRenderPass", None);
+}
+
+unsafe extern "system" fn vkCmdBeginRenderPass2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _render_pass_begin: *const RenderPassBeginInfo,
+    _subpass_begin_info: *const SubpassBeginInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBeginRenderPass2");
+    (hooked.commands.cmd_begin_render_pass2)(
+        _command_buffer,
+        _render_pass_begin,
+        _subpass_begin_info,
+    );
+    hooked.after("vkCmdBeginRenderPass2", None);
+}
+
+unsafe extern "system" fn vkCmdBeginRenderPass2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _render_pass_begin: *const RenderPassBeginInfo,
+    _subpass_begin_info: *const SubpassBeginInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBeginRenderPass2KHR");
+    (hooked.commands.cmd_begin_render_pass2_khr)(
+        _command_buffer,
+        _render_pass_begin,
+        _subpass_begin_info,
+    );
+    hooked.after("vkCmdBeginRenderPass2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdBeginRendering<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _rendering_info: *const RenderingInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBeginRendering");
+    (hooked.commands.cmd_begin_rendering)(_command_buffer, _rendering_info);
+    hooked.after("vkCmdBeginRendering", None);
+}
+
+unsafe extern "system" fn vkCmdBeginRenderingKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _rendering_info: *const RenderingInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBeginRenderingKHR");
+    (hooked.commands.cmd_begin_rendering_khr)(_command_buffer, _rendering_info);
+    hooked.after("vkCmdBeginRenderingKHR", None);
+}
+
+unsafe extern "system" fn vkCmdBeginTransformFeedbackEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_counter_buffer: u32,
+    _counter_buffer_count: u32,
+    _counter_buffers: *const Buffer,
+    _counter_buffer_offsets: *const DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBeginTransformFeedbackEXT");
+    (hooked.commands.cmd_begin_transform_feedback_ext)(
+        _command_buffer,
+        _first_counter_buffer,
+        _counter_buffer_count,
+        _counter_buffers,
+        _counter_buffer_offsets,
+    );
+    hooked.after("vkCmdBeginTransformFeedbackEXT", None);
+}
+
+unsafe extern "system" fn vkCmdBeginVideoCodingKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _begin_info: *const VideoBeginCodingInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBeginVideoCodingKHR");
+    (hooked.commands.cmd_begin_video_coding_khr)(_command_buffer, _begin_info);
+    hooked.after("vkCmdBeginVideoCodingKHR", None);
+}
+
+unsafe extern "system" fn vkCmdBindDescriptorBufferEmbeddedSamplers2EXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _bind_descriptor_buffer_embedded_samplers_info: *const BindDescriptorBufferEmbeddedSamplersInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindDescriptorBufferEmbeddedSamplers2EXT");
+    (hooked
+        .commands
+        .cmd_bind_descriptor_buffer_embedded_samplers2_ext)(
+        _command_buffer,
+        _bind_descriptor_buffer_embedded_samplers_info,
+    );
+    hooked.after("vkCmdBindDescriptorBufferEmbeddedSamplers2EXT", None);
+}
+
+unsafe extern "system" fn vkCmdBindDescriptorBufferEmbeddedSamplersEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _pipeline_bind_point: PipelineBindPoint,
+    _layout: PipelineLayout,
+    _set: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindDescriptorBufferEmbeddedSamplersEXT");
+    (hooked
+        .commands
+        .cmd_bind_descriptor_buffer_embedded_samplers_ext)(
+        _command_buffer,
+        _pipeline_bind_point,
+        _layout,
+        _set,
+    );
+    hooked.after("vkCmdBindDescriptorBufferEmbeddedSamplersEXT", None);
+}
+
+unsafe extern "system" fn vkCmdBindDescriptorBuffersEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer_count: u32,
+    _binding_infos: *const DescriptorBufferBindingInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindDescriptorBuffersEXT");
+    (hooked.commands.cmd_bind_descriptor_buffers_ext)(
+        _command_buffer,
+        _buffer_count,
+        _binding_infos,
+    );
+    hooked.after("vkCmdBindDescriptorBuffersEXT", None);
+}
+
+unsafe extern "system" fn vkCmdBindDescriptorSets<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _pipeline_bind_point: PipelineBindPoint,
+    _layout: PipelineLayout,
+    _first_set: u32,
+    _descriptor_set_count: u32,
+    _descriptor_sets: *const DescriptorSet,
+    _dynamic_offset_count: u32,
+    _dynamic_offsets: *const u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindDescriptorSets");
+    (hooked.commands.cmd_bind_descriptor_sets)(
+        _command_buffer,
+        _pipeline_bind_point,
+        _layout,
+        _first_set,
+        _descriptor_set_count,
+        _descriptor_sets,
+        _dynamic_offset_count,
+        _dynamic_offsets,
+    );
+    hooked.after("vkCmdBindDescriptorSets", None);
+}
+
+unsafe extern "system" fn vkCmdBindDescriptorSets2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _bind_descriptor_sets_info: *const BindDescriptorSetsInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindDescriptorSets2");
+    (hooked.commands.cmd_bind_descriptor_sets2)(_command_buffer, _bind_descriptor_sets_info);
+    hooked.after("vkCmdBindDescriptorSets2", None);
+}
+
+unsafe extern "system" fn vkCmdBindDescriptorSets2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _bind_descriptor_sets_info: *const BindDescriptorSetsInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindDescriptorSets2KHR");
+    (hooked.commands.cmd_bind_descriptor_sets2_khr)(_command_buffer, _bind_descriptor_sets_info);
+    hooked.after("vkCmdBindDescriptorSets2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdBindIndexBuffer<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _index_type: IndexType,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindIndexBuffer");
+    (hooked.commands.cmd_bind_index_buffer)(_command_buffer, _buffer, _offset, _index_type);
+    hooked.after("vkCmdBindIndexBuffer", None);
+}
+
+unsafe extern "system" fn vkCmdBindIndexBuffer2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _size: DeviceSize,
+    _index_type: IndexType,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindIndexBuffer2");
+    (hooked.commands.cmd_bind_index_buffer2)(_command_buffer, _buffer, _offset, _size, _index_type);
+    hooked.after("vkCmdBindIndexBuffer2", None);
+}
+
+unsafe extern "system" fn vkCmdBindIndexBuffer2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _size: DeviceSize,
+    _index_type: IndexType,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindIndexBuffer2KHR");
+    (hooked.commands.cmd_bind_index_buffer2_khr)(
+        _command_buffer,
+        _buffer,
+        _offset,
+        _size,
+        _index_type,
+    );
+    hooked.after("vkCmdBindIndexBuffer2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdBindInvocationMaskHUAWEI<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _image_view: ImageView,
+    _image_layout: ImageLayout,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindInvocationMaskHUAWEI");
+    (hooked.commands.cmd_bind_invocation_mask_huawei)(_command_buffer, _image_view, _image_layout);
+    hooked.after("vkCmdBindInvocationMaskHUAWEI", None);
+}
+
+unsafe extern "system" fn vkCmdBindPipeline<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _pipeline_bind_point: PipelineBindPoint,
+    _pipeline: Pipeline,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindPipeline");
+    (hooked.commands.cmd_bind_pipeline)(_command_buffer, _pipeline_bind_point, _pipeline);
+    hooked.after("vkCmdBindPipeline", None);
+}
+
+unsafe extern "system" fn vkCmdBindPipelineShaderGroupNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _pipeline_bind_point: PipelineBindPoint,
+    _pipeline: Pipeline,
+    _group_index: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindPipelineShaderGroupNV");
+    (hooked.commands.cmd_bind_pipeline_shader_group_nv)(
+        _command_buffer,
+        _pipeline_bind_point,
+        _pipeline,
+        _group_index,
+    );
+    hooked.after("vkCmdBindPipelineShaderGroupNV", None);
+}
+
+unsafe extern "system" fn vkCmdBindResourceHeapEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _bind_info: *const BindHeapInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindResourceHeapEXT");
+    (hooked.commands.cmd_bind_resource_heap_ext)(_command_buffer, _bind_info);
+    hooked.after("vkCmdBindResourceHeapEXT", None);
+}
+
+unsafe extern "system" fn vkCmdBindSamplerHeapEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _bind_info: *const BindHeapInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindSamplerHeapEXT");
+    (hooked.commands.cmd_bind_sampler_heap_ext)(_command_buffer, _bind_info);
+    hooked.after("vkCmdBindSamplerHeapEXT", None);
+}
+
+unsafe extern "system" fn vkCmdBindShadersEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _stage_count: u32,
+    _stages: *const ShaderStageFlags,
+    _shaders: *const ShaderEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindShadersEXT");
+    (hooked.commands.cmd_bind_shaders_ext)(_command_buffer, _stage_count, _stages, _shaders);
+    hooked.after("vkCmdBindShadersEXT", None);
+}
+
+unsafe extern "system" fn vkCmdBindShadingRateImageNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _image_view: ImageView,
+    _image_layout: ImageLayout,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindShadingRateImageNV");
+    (hooked.commands.cmd_bind_shading_rate_image_nv)(_command_buffer, _image_view, _image_layout);
+    hooked.after("vkCmdBindShadingRateImageNV", None);
+}
+
+unsafe extern "system" fn vkCmdBindTileMemoryQCOM<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _tile_memory_bind_info: *const TileMemoryBindInfoQCOM,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindTileMemoryQCOM");
+    (hooked.commands.cmd_bind_tile_memory_qcom)(_command_buffer, _tile_memory_bind_info);
+    hooked.after("vkCmdBindTileMemoryQCOM", None);
+}
+
+unsafe extern "system" fn vkCmdBindTransformFeedbackBuffersEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_binding: u32,
+    _binding_count: u32,
+    _buffers: *const Buffer,
+    _offsets: *const DeviceSize,
+    _sizes: *const DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindTransformFeedbackBuffersEXT");
+    (hooked.commands.cmd_bind_transform_feedback_buffers_ext)(
+        _command_buffer,
+        _first_binding,
+        _binding_count,
+        _buffers,
+        _offsets,
+        _sizes,
+    );
+    hooked.after("vkCmdBindTransformFeedbackBuffersEXT", None);
+}
+
+unsafe extern "system" fn vkCmdBindVertexBuffers<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_binding: u32,
+    _binding_count: u32,
+    _buffers: *const Buffer,
+    _offsets: *const DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindVertexBuffers");
+    (hooked.commands.cmd_bind_vertex_buffers)(
+        _command_buffer,
+        _first_binding,
+        _binding_count,
+        _buffers,
+        _offsets,
+    );
+    hooked.after("vkCmdBindVertexBuffers", None);
+}
+
+unsafe extern "system" fn vkCmdBindVertexBuffers2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_binding: u32,
+    _binding_count: u32,
+    _buffers: *const Buffer,
+    _offsets: *const DeviceSize,
+    _sizes: *const DeviceSize,
+    _strides: *const DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindVertexBuffers2");
+    (hooked.commands.cmd_bind_vertex_buffers2)(
+        _command_buffer,
+        _first_binding,
+        _binding_count,
+        _buffers,
+        _offsets,
+        _sizes,
+        _strides,
+    );
+    hooked.after("vkCmdBindVertexBuffers2", None);
+}
+
+unsafe extern "system" fn vkCmdBindVertexBuffers2EXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_binding: u32,
+    _binding_count: u32,
+    _buffers: *const Buffer,
+    _offsets: *const DeviceSize,
+    _sizes: *const DeviceSize,
+    _strides: *const DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBindVertexBuffers2EXT");
+    (hooked.commands.cmd_bind_vertex_buffers2_ext)(
+        _command_buffer,
+        _first_binding,
+        _binding_count,
+        _buffers,
+        _offsets,
+        _sizes,
+        _strides,
+    );
+    hooked.after("vkCmdBindVertexBuffers2EXT", None);
+}
+
+unsafe extern "system" fn vkCmdBlitImage<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _src_image: Image,
+    _src_image_layout: ImageLayout,
+    _dst_image: Image,
+    _dst_image_layout: ImageLayout,
+    _region_count: u32,
+    _regions: *const ImageBlit,
+    _filter: Filter,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBlitImage");
+    (hooked.commands.cmd_blit_image)(
+        _command_buffer,
+        _src_image,
+        _src_image_layout,
+        _dst_image,
+        _dst_image_layout,
+        _region_count,
+        _regions,
+        _filter,
+    );
+    hooked.after("vkCmdBlitImage", None);
+}
+
+unsafe extern "system" fn vkCmdBlitImage2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _blit_image_info: *const BlitImageInfo2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBlitImage2");
+    (hooked.commands.cmd_blit_image2)(_command_buffer, _blit_image_info);
+    hooked.after("vkCmdBlitImage2", None);
+}
+
+unsafe extern "system" fn vkCmdBlitImage2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _blit_image_info: *const BlitImageInfo2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBlitImage2KHR");
+    (hooked.commands.cmd_blit_image2_khr)(_command_buffer, _blit_image_info);
+    hooked.after("vkCmdBlitImage2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdBuildAccelerationStructureNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _info: *const AccelerationStructureInfoNV,
+    _instance_data: Buffer,
+    _instance_offset: DeviceSize,
+    _update: Bool32,
+    _dst: AccelerationStructureNV,
+    _src: AccelerationStructureNV,
+    _scratch: Buffer,
+    _scratch_offset: DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBuildAccelerationStructureNV");
+    (hooked.commands.cmd_build_acceleration_structure_nv)(
+        _command_buffer,
+        _info,
+        _instance_data,
+        _instance_offset,
+        _update,
+        _dst,
+        _src,
+        _scratch,
+        _scratch_offset,
+    );
+    hooked.after("vkCmdBuildAccelerationStructureNV", None);
+}
+
+unsafe extern "system" fn vkCmdBuildAccelerationStructuresIndirectKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _info_count: u32,
+    _infos: *const AccelerationStructureBuildGeometryInfoKHR,
+    _indirect_device_addresses: *const DeviceAddress,
+    _indirect_strides: *const u32,
+    _max_primitive_counts: *const *const u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBuildAccelerationStructuresIndirectKHR");
+    (hooked
+        .commands
+        .cmd_build_acceleration_structures_indirect_khr)(
+        _command_buffer,
+        _info_count,
+        _infos,
+        _indirect_device_addresses,
+        _indirect_strides,
+        _max_primitive_counts,
+    );
+    hooked.after("vkCmdBuildAccelerationStructuresIndirectKHR", None);
+}
+
+unsafe extern "system" fn vkCmdBuildAccelerationStructuresKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _info_count: u32,
+    _infos: *const AccelerationStructureBuildGeometryInfoKHR,
+    _build_range_infos: *const *const AccelerationStructureBuildRangeInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBuildAccelerationStructuresKHR");
+    (hooked.commands.cmd_build_acceleration_structures_khr)(
+        _command_buffer,
+        _info_count,
+        _infos,
+        _build_range_infos,
+    );
+    hooked.after("vkCmdBuildAccelerationStructuresKHR", None);
+}
+
+unsafe extern "system" fn vkCmdBuildClusterAccelerationStructureIndirectNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _command_infos: *const ClusterAccelerationStructureCommandsInfoNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBuildClusterAccelerationStructureIndirectNV");
+    (hooked
+        .commands
+        .cmd_build_cluster_acceleration_structure_indirect_nv)(_command_buffer, _command_infos);
+    hooked.after("vkCmdBuildClusterAccelerationStructureIndirectNV", None);
+}
+
+unsafe extern "system" fn vkCmdBuildMicromapsEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _info_count: u32,
+    _infos: *const MicromapBuildInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBuildMicromapsEXT");
+    (hooked.commands.cmd_build_micromaps_ext)(_command_buffer, _info_count, _infos);
+    hooked.after("vkCmdBuildMicromapsEXT", None);
+}
+
+unsafe extern "system" fn vkCmdBuildPartitionedAccelerationStructuresNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _build_info: *const BuildPartitionedAccelerationStructureInfoNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdBuildPartitionedAccelerationStructuresNV");
+    (hooked
+        .commands
+        .cmd_build_partitioned_acceleration_structures_nv)(_command_buffer, _build_info);
+    hooked.after("vkCmdBuildPartitionedAccelerationStructuresNV", None);
+}
+
+unsafe extern "system" fn vkCmdClearAttachments<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _attachment_count: u32,
+    _attachments: *const ClearAttachment,
+    _rect_count: u32,
+    _rects: *const ClearRect,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdClearAttachments");
+    (hooked.commands.cmd_clear_attachments)(
+        _command_buffer,
+        _attachment_count,
+        _attachments,
+        _rect_count,
+        _rects,
+    );
+    hooked.after("vkCmdClearAttachments", None);
+}
+
+unsafe extern "system" fn vkCmdClearColorImage<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _image: Image,
+    _image_layout: ImageLayout,
+    _color: *const ClearColorValue,
+    _range_count: u32,
+    _ranges: *const ImageSubresourceRange,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdClearColorImage");
+    (hooked.commands.cmd_clear_color_image)(
+        _command_buffer,
+        _image,
+        _image_layout,
+        _color,
+        _range_count,
+        _ranges,
+    );
+    hooked.after("vkCmdClearColorImage", None);
+}
+
+unsafe extern "system" fn vkCmdClearDepthStencilImage<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _image: Image,
+    _image_layout: ImageLayout,
+    _depth_stencil: *const ClearDepthStencilValue,
+    _range_count: u32,
+    _ranges: *const ImageSubresourceRange,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdClearDepthStencilImage");
+    (hooked.commands.cmd_clear_depth_stencil_image)(
+        _command_buffer,
+        _image,
+        _image_layout,
+        _depth_stencil,
+        _range_count,
+        _ranges,
+    );
+    hooked.after("vkCmdClearDepthStencilImage", None);
+}
+
+unsafe extern "system" fn vkCmdControlVideoCodingKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _coding_control_info: *const VideoCodingControlInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdControlVideoCodingKHR");
+    (hooked.commands.cmd_control_video_coding_khr)(_command_buffer, _coding_control_info);
+    hooked.after("vkCmdControlVideoCodingKHR", None);
+}
+
+unsafe extern "system" fn vkCmdConvertCooperativeVectorMatrixNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _info_count: u32,
+    _infos: *const ConvertCooperativeVectorMatrixInfoNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdConvertCooperativeVectorMatrixNV");
+    (hooked.commands.cmd_convert_cooperative_vector_matrix_nv)(
+        _command_buffer,
+        _info_count,
+        _infos,
+    );
+    hooked.after("vkCmdConvertCooperativeVectorMatrixNV", None);
+}
+
+unsafe extern "system" fn vkCmdCopyAccelerationStructureKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _info: *const CopyAccelerationStructureInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyAccelerationStructureKHR");
+    (hooked.commands.cmd_copy_acceleration_structure_khr)(_command_buffer, _info);
+    hooked.after("vkCmdCopyAccelerationStructureKHR", None);
+}
+
+unsafe extern "system" fn vkCmdCopyAccelerationStructureNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _dst: AccelerationStructureNV,
+    _src: AccelerationStructureNV,
+    _mode: CopyAccelerationStructureModeKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyAccelerationStructureNV");
+    (hooked.commands.cmd_copy_acceleration_structure_nv)(_command_buffer, _dst, _src, _mode);
+    hooked.after("vkCmdCopyAccelerationStructureNV", None);
+}
+
+unsafe extern "system" fn vkCmdCopyAccelerationStructureToMemoryKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _info: *const CopyAccelerationStructureToMemoryInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyAccelerationStructureToMemoryKHR");
+    (hooked
+        .commands
+        .cmd_copy_acceleration_structure_to_memory_khr)(_command_buffer, _info);
+    hooked.after("vkCmdCopyAccelerationStructureToMemoryKHR", None);
+}
+
+unsafe extern "system" fn vkCmdCopyBuffer<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _src_buffer: Buffer,
+    _dst_buffer: Buffer,
+    _region_count: u32,
+    _regions: *const BufferCopy,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyBuffer");
+    (hooked.commands.cmd_copy_buffer)(
+        _command_buffer,
+        _src_buffer,
+        _dst_buffer,
+        _region_count,
+        _regions,
+    );
+    hooked.after("vkCmdCopyBuffer", None);
+}
+
+unsafe extern "system" fn vkCmdCopyBuffer2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_buffer_info: *const CopyBufferInfo2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyBuffer2");
+    (hooked.commands.cmd_copy_buffer2)(_command_buffer, _copy_buffer_info);
+    hooked.after("vkCmdCopyBuffer2", None);
+}
+
+unsafe extern "system" fn vkCmdCopyBuffer2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_buffer_info: *const CopyBufferInfo2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyBuffer2KHR");
+    (hooked.commands.cmd_copy_buffer2_khr)(_command_buffer, _copy_buffer_info);
+    hooked.after("vkCmdCopyBuffer2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdCopyBufferToImage<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _src_buffer: Buffer,
+    _dst_image: Image,
+    _dst_image_layout: ImageLayout,
+    _region_count: u32,
+    _regions: *const BufferImageCopy,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyBufferToImage");
+    (hooked.commands.cmd_copy_buffer_to_image)(
+        _command_buffer,
+        _src_buffer,
+        _dst_image,
+        _dst_image_layout,
+        _region_count,
+        _regions,
+    );
+    hooked.after("vkCmdCopyBufferToImage", None);
+}
+
+unsafe extern "system" fn vkCmdCopyBufferToImage2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_buffer_to_image_info: *const CopyBufferToImageInfo2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyBufferToImage2");
+    (hooked.commands.cmd_copy_buffer_to_image2)(_command_buffer, _copy_buffer_to_image_info);
+    hooked.after("vkCmdCopyBufferToImage2", None);
+}
+
+unsafe extern "system" fn vkCmdCopyBufferToImage2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_buffer_to_image_info: *const CopyBufferToImageInfo2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyBufferToImage2KHR");
+    (hooked.commands.cmd_copy_buffer_to_image2_khr)(_command_buffer, _copy_buffer_to_image_info);
+    hooked.after("vkCmdCopyBufferToImage2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdCopyImage<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _src_image: Image,
+    _src_image_layout: ImageLayout,
+    _dst_image: Image,
+    _dst_image_layout: ImageLayout,
+    _region_count: u32,
+    _regions: *const ImageCopy,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyImage");
+    (hooked.commands.cmd_copy_image)(
+        _command_buffer,
+        _src_image,
+        _src_image_layout,
+        _dst_image,
+        _dst_image_layout,
+        _region_count,
+        _regions,
+    );
+    hooked.after("vkCmdCopyImage", None);
+}
+
+unsafe extern "system" fn vkCmdCopyImage2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_image_info: *const CopyImageInfo2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyImage2");
+    (hooked.commands.cmd_copy_image2)(_command_buffer, _copy_image_info);
+    hooked.after("vkCmdCopyImage2", None);
+}
+
+unsafe extern "system" fn vkCmdCopyImage2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_image_info: *const CopyImageInfo2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyImage2KHR");
+    (hooked.commands.cmd_copy_image2_khr)(_command_buffer, _copy_image_info);
+    hooked.after("vkCmdCopyImage2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdCopyImageToBuffer<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _src_image: Image,
+    _src_image_layout: ImageLayout,
+    _dst_buffer: Buffer,
+    _region_count: u32,
+    _regions: *const BufferImageCopy,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyImageToBuffer");
+    (hooked.commands.cmd_copy_image_to_buffer)(
+        _command_buffer,
+        _src_image,
+        _src_image_layout,
+        _dst_buffer,
+        _region_count,
+        _regions,
+    );
+    hooked.after("vkCmdCopyImageToBuffer", None);
+}
+
+unsafe extern "system" fn vkCmdCopyImageToBuffer2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_image_to_buffer_info: *const CopyImageToBufferInfo2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyImageToBuffer2");
+    (hooked.commands.cmd_copy_image_to_buffer2)(_command_buffer, _copy_image_to_buffer_info);
+    hooked.after("vkCmdCopyImageToBuffer2", None);
+}
+
+unsafe extern "system" fn vkCmdCopyImageToBuffer2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_image_to_buffer_info: *const CopyImageToBufferInfo2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyImageToBuffer2KHR");
+    (hooked.commands.cmd_copy_image_to_buffer2_khr)(_command_buffer, _copy_image_to_buffer_info);
+    hooked.after("vkCmdCopyImageToBuffer2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdCopyMemoryIndirectKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_memory_indirect_info: *const CopyMemoryIndirectInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyMemoryIndirectKHR");
+    (hooked.commands.cmd_copy_memory_indirect_khr)(_command_buffer, _copy_memory_indirect_info);
+    hooked.after("vkCmdCopyMemoryIndirectKHR", None);
+}
+
+unsafe extern "system" fn vkCmdCopyMemoryIndirectNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_buffer_address: DeviceAddress,
+    _copy_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyMemoryIndirectNV");
+    (hooked.commands.cmd_copy_memory_indirect_nv)(
+        _command_buffer,
+        _copy_buffer_address,
+        _copy_count,
+        _stride,
+    );
+    hooked.after("vkCmdCopyMemoryIndirectNV", None);
+}
+
+unsafe extern "system" fn vkCmdCopyMemoryToAccelerationStructureKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _info: *const CopyMemoryToAccelerationStructureInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyMemoryToAccelerationStructureKHR");
+    (hooked
+        .commands
+        .cmd_copy_memory_to_acceleration_structure_khr)(_command_buffer, _info);
+    hooked.after("vkCmdCopyMemoryToAccelerationStructureKHR", None);
+}
+
+unsafe extern "system" fn vkCmdCopyMemoryToImageIndirectKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_memory_to_image_indirect_info: *const CopyMemoryToImageIndirectInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyMemoryToImageIndirectKHR");
+    (hooked.commands.cmd_copy_memory_to_image_indirect_khr)(
+        _command_buffer,
+        _copy_memory_to_image_indirect_info,
+    );
+    hooked.after("vkCmdCopyMemoryToImageIndirectKHR", None);
+}
+
+unsafe extern "system" fn vkCmdCopyMemoryToImageIndirectNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_buffer_address: DeviceAddress,
+    _copy_count: u32,
+    _stride: u32,
+    _dst_image: Image,
+    _dst_image_layout: ImageLayout,
+    _image_subresources: *const ImageSubresourceLayers,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyMemoryToImageIndirectNV");
+    (hooked.commands.cmd_copy_memory_to_image_indirect_nv)(
+        _command_buffer,
+        _copy_buffer_address,
+        _copy_count,
+        _stride,
+        _dst_image,
+        _dst_image_layout,
+        _image_subresources,
+    );
+    hooked.after("vkCmdCopyMemoryToImageIndirectNV", None);
+}
+
+unsafe extern "system" fn vkCmdCopyMemoryToMicromapEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _info: *const CopyMemoryToMicromapInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyMemoryToMicromapEXT");
+    (hooked.commands.cmd_copy_memory_to_micromap_ext)(_command_buffer, _info);
+    hooked.after("vkCmdCopyMemoryToMicromapEXT", None);
+}
+
+unsafe extern "system" fn vkCmdCopyMicromapEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _info: *const CopyMicromapInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyMicromapEXT");
+    (hooked.commands.cmd_copy_micromap_ext)(_command_buffer, _info);
+    hooked.after("vkCmdCopyMicromapEXT", None);
+}
+
+unsafe extern "system" fn vkCmdCopyMicromapToMemoryEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _info: *const CopyMicromapToMemoryInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyMicromapToMemoryEXT");
+    (hooked.commands.cmd_copy_micromap_to_memory_ext)(_command_buffer, _info);
+    hooked.after("vkCmdCopyMicromapToMemoryEXT", None);
+}
+
+unsafe extern "system" fn vkCmdCopyQueryPoolResults<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _query_pool: QueryPool,
+    _first_query: u32,
+    _query_count: u32,
+    _dst_buffer: Buffer,
+    _dst_offset: DeviceSize,
+    _stride: DeviceSize,
+    _flags: QueryResultFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyQueryPoolResults");
+    (hooked.commands.cmd_copy_query_pool_results)(
+        _command_buffer,
+        _query_pool,
+        _first_query,
+        _query_count,
+        _dst_buffer,
+        _dst_offset,
+        _stride,
+        _flags,
+    );
+    hooked.after("vkCmdCopyQueryPoolResults", None);
+}
+
+unsafe extern "system" fn vkCmdCopyTensorARM<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _copy_tensor_info: *const CopyTensorInfoARM,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCopyTensorARM");
+    (hooked.commands.cmd_copy_tensor_arm)(_command_buffer, _copy_tensor_info);
+    hooked.after("vkCmdCopyTensorARM", None);
+}
+
+unsafe extern "system" fn vkCmdCuLaunchKernelNVX<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _launch_info: *const CuLaunchInfoNVX,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCuLaunchKernelNVX");
+    (hooked.commands.cmd_cu_launch_kernel_nvx)(_command_buffer, _launch_info);
+    hooked.after("vkCmdCuLaunchKernelNVX", None);
+}
+
+unsafe extern "system" fn vkCmdCudaLaunchKernelNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _launch_info: *const CudaLaunchInfoNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdCudaLaunchKernelNV");
+    (hooked.commands.cmd_cuda_launch_kernel_nv)(_command_buffer, _launch_info);
+    hooked.after("vkCmdCudaLaunchKernelNV", None);
+}
+
+unsafe extern "system" fn vkCmdDebugMarkerBeginEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _marker_info: *const DebugMarkerMarkerInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDebugMarkerBeginEXT");
+    (hooked.commands.cmd_debug_marker_begin_ext)(_command_buffer, _marker_info);
+    hooked.after("vkCmdDebugMarkerBeginEXT", None);
+}
+
+unsafe extern "system" fn vkCmdDebugMarkerEndEXT<const S: usize>(_command_buffer: CommandBuffer) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDebugMarkerEndEXT");
+    (hooked.commands.cmd_debug_marker_end_ext)(_command_buffer);
+    hooked.after("vkCmdDebugMarkerEndEXT", None);
+}
+
+unsafe extern "system" fn vkCmdDebugMarkerInsertEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _marker_info: *const DebugMarkerMarkerInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDebugMarkerInsertEXT");
+    (hooked.commands.cmd_debug_marker_insert_ext)(_command_buffer, _marker_info);
+    hooked.after("vkCmdDebugMarkerInsertEXT", None);
+}
+
+unsafe extern "system" fn vkCmdDecodeVideoKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _decode_info: *const VideoDecodeInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDecodeVideoKHR");
+    (hooked.commands.cmd_decode_video_khr)(_command_buffer, _decode_info);
+    hooked.after("vkCmdDecodeVideoKHR", None);
+}
+
+unsafe extern "system" fn vkCmdDecompressMemoryEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _decompress_memory_info_ext: *const DecompressMemoryInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDecompressMemoryEXT");
+    (hooked.commands.cmd_decompress_memory_ext)(_command_buffer, _decompress_memory_info_ext);
+    hooked.after("vkCmdDecompressMemoryEXT", None);
+}
+
+unsafe extern "system" fn vkCmdDecompressMemoryIndirectCountEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _decompression_method: MemoryDecompressionMethodFlagsEXT,
+    _indirect_commands_address: DeviceAddress,
+    _indirect_commands_count_address: DeviceAddress,
+    _max_decompression_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDecompressMemoryIndirectCountEXT");
+    (hooked.commands.cmd_decompress_memory_indirect_count_ext)(
+        _command_buffer,
+        _decompression_method,
+        _indirect_commands_address,
+        _indirect_commands_count_address,
+        _max_decompression_count,
+        _stride,
+    );
+    hooked.after("vkCmdDecompressMemoryIndirectCountEXT", None);
+}
+
+unsafe extern "system" fn vkCmdDecompressMemoryIndirectCountNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _indirect_commands_address: DeviceAddress,
+    _indirect_commands_count_address: DeviceAddress,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDecompressMemoryIndirectCountNV");
+    (hooked.commands.cmd_decompress_memory_indirect_count_nv)(
+        _command_buffer,
+        _indirect_commands_address,
+        _indirect_commands_count_address,
+        _stride,
+    );
+    hooked.after("vkCmdDecompressMemoryIndirectCountNV", None);
+}
+
+unsafe extern "system" fn vkCmdDecompressMemoryNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _decompress_region_count: u32,
+    _decompress_memory_regions: *const DecompressMemoryRegionNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDecompressMemoryNV");
+    (hooked.commands.cmd_decompress_memory_nv)(
+        _command_buffer,
+        _decompress_region_count,
+        _decompress_memory_regions,
+    );
+    hooked.after("vkCmdDecompressMemoryNV", None);
+}
+
+unsafe extern "system" fn vkCmdDispatch<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _group_count_x: u32,
+    _group_count_y: u32,
+    _group_count_z: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDispatch");
+    (hooked.commands.cmd_dispatch)(
+        _command_buffer,
+        _group_count_x,
+        _group_count_y,
+        _group_count_z,
+    );
+    hooked.after("vkCmdDispatch", None);
+}
+
+unsafe extern "system" fn vkCmdDispatchBase<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _base_group_x: u32,
+    _base_group_y: u32,
+    _base_group_z: u32,
+    _group_count_x: u32,
+    _group_count_y: u32,
+    _group_count_z: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDispatchBase");
+    (hooked.commands.cmd_dispatch_base)(
+        _command_buffer,
+        _base_group_x,
+        _base_group_y,
+        _base_group_z,
+        _group_count_x,
+        _group_count_y,
+        _group_count_z,
+    );
+    hooked.after("vkCmdDispatchBase", None);
+}
+
+unsafe extern "system" fn vkCmdDispatchBaseKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _base_group_x: u32,
+    _base_group_y: u32,
+    _base_group_z: u32,
+    _group_count_x: u32,
+    _group_count_y: u32,
+    _group_count_z: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDispatchBaseKHR");
+    (hooked.commands.cmd_dispatch_base_khr)(
+        _command_buffer,
+        _base_group_x,
+        _base_group_y,
+        _base_group_z,
+        _group_count_x,
+        _group_count_y,
+        _group_count_z,
+    );
+    hooked.after("vkCmdDispatchBaseKHR", None);
+}
+
+unsafe extern "system" fn vkCmdDispatchDataGraphARM<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _session: DataGraphPipelineSessionARM,
+    _info: *const DataGraphPipelineDispatchInfoARM,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDispatchDataGraphARM");
+    (hooked.commands.cmd_dispatch_data_graph_arm)(_command_buffer, _session, _info);
+    hooked.after("vkCmdDispatchDataGraphARM", None);
+}
+
+unsafe extern "system" fn vkCmdDispatchGraphAMDX<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _scratch: DeviceAddress,
+    _scratch_size: DeviceSize,
+    _count_info: *const DispatchGraphCountInfoAMDX,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDispatchGraphAMDX");
+    (hooked.commands.cmd_dispatch_graph_amdx)(
+        _command_buffer,
+        _scratch,
+        _scratch_size,
+        _count_info,
+    );
+    hooked.after("vkCmdDispatchGraphAMDX", None);
+}
+
+unsafe extern "system" fn vkCmdDispatchGraphIndirectAMDX<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _scratch: DeviceAddress,
+    _scratch_size: DeviceSize,
+    _count_info: *const DispatchGraphCountInfoAMDX,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDispatchGraphIndirectAMDX");
+    (hooked.commands.cmd_dispatch_graph_indirect_amdx)(
+        _command_buffer,
+        _scratch,
+        _scratch_size,
+        _count_info,
+    );
+    hooked.after("vkCmdDispatchGraphIndirectAMDX", None);
+}
+
+unsafe extern "system" fn vkCmdDispatchGraphIndirectCountAMDX<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _scratch: DeviceAddress,
+    _scratch_size: DeviceSize,
+    _count_info: DeviceAddress,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDispatchGraphIndirectCountAMDX");
+    (hooked.commands.cmd_dispatch_graph_indirect_count_amdx)(
+        _command_buffer,
+        _scratch,
+        _scratch_size,
+        _count_info,
+    );
+    hooked.after("vkCmdDispatchGraphIndirectCountAMDX", None);
+}
+
+unsafe extern "system" fn vkCmdDispatchIndirect<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDispatchIndirect");
+    (hooked.commands.cmd_dispatch_indirect)(_command_buffer, _buffer, _offset);
+    hooked.after("vkCmdDispatchIndirect", None);
+}
+
+unsafe extern "system" fn vkCmdDispatchTileQCOM<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _dispatch_tile_info: *const DispatchTileInfoQCOM,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDispatchTileQCOM");
+    (hooked.commands.cmd_dispatch_tile_qcom)(_command_buffer, _dispatch_tile_info);
+    hooked.after("vkCmdDispatchTileQCOM", None);
+}
+
+unsafe extern "system" fn vkCmdDraw<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _vertex_count: u32,
+    _instance_count: u32,
+    _first_vertex: u32,
+    _first_instance: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDraw");
+    (hooked.commands.cmd_draw)(
+        _command_buffer,
+        _vertex_count,
+        _instance_count,
+        _first_vertex,
+        _first_instance,
+    );
+    hooked.after("vkCmdDraw", None);
+}
+
+unsafe extern "system" fn vkCmdDrawClusterHUAWEI<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _group_count_x: u32,
+    _group_count_y: u32,
+    _group_count_z: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawClusterHUAWEI");
+    (hooked.commands.cmd_draw_cluster_huawei)(
+        _command_buffer,
+        _group_count_x,
+        _group_count_y,
+        _group_count_z,
+    );
+    hooked.after("vkCmdDrawClusterHUAWEI", None);
+}
+
+unsafe extern "system" fn vkCmdDrawClusterIndirectHUAWEI<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawClusterIndirectHUAWEI");
+    (hooked.commands.cmd_draw_cluster_indirect_huawei)(_command_buffer, _buffer, _offset);
+    hooked.after("vkCmdDrawClusterIndirectHUAWEI", None);
+}
+
+unsafe extern "system" fn vkCmdDrawIndexed<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _index_count: u32,
+    _instance_count: u32,
+    _first_index: u32,
+    _vertex_offset: i32,
+    _first_instance: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawIndexed");
+    (hooked.commands.cmd_draw_indexed)(
+        _command_buffer,
+        _index_count,
+        _instance_count,
+        _first_index,
+        _vertex_offset,
+        _first_instance,
+    );
+    hooked.after("vkCmdDrawIndexed", None);
+}
+
+unsafe extern "system" fn vkCmdDrawIndexedIndirect<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _draw_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawIndexedIndirect");
+    (hooked.commands.cmd_draw_indexed_indirect)(
+        _command_buffer,
+        _buffer,
+        _offset,
+        _draw_count,
+        _stride,
+    );
+    hooked.after("vkCmdDrawIndexedIndirect", None);
+}
+
+unsafe extern "system" fn vkCmdDrawIndexedIndirectCount<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _count_buffer: Buffer,
+    _count_buffer_offset: DeviceSize,
+    _max_draw_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawIndexedIndirectCount");
+    (hooked.commands.cmd_draw_indexed_indirect_count)(
+        _command_buffer,
+        _buffer,
+        _offset,
+        _count_buffer,
+        _count_buffer_offset,
+        _max_draw_count,
+        _stride,
+    );
+    hooked.after("vkCmdDrawIndexedIndirectCount", None);
+}
+
+unsafe extern "system" fn vkCmdDrawIndexedIndirectCountAMD<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _count_buffer: Buffer,
+    _count_buffer_offset: DeviceSize,
+    _max_draw_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawIndexedIndirectCountAMD");
+    (hooked.commands.cmd_draw_indexed_indirect_count_amd)(
+        _command_buffer,
+        _buffer,
+        _offset,
+        _count_buffer,
+        _count_buffer_offset,
+        _max_draw_count,
+        _stride,
+    );
+    hooked.after("vkCmdDrawIndexedIndirectCountAMD", None);
+}
+
+unsafe extern "system" fn vkCmdDrawIndexedIndirectCountKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _count_buffer: Buffer,
+    _count_buffer_offset: DeviceSize,
+    _max_draw_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawIndexedIndirectCountKHR");
+    (hooked.commands.cmd_draw_indexed_indirect_count_khr)(
+        _command_buffer,
+        _buffer,
+        _offset,
+        _count_buffer,
+        _count_buffer_offset,
+        _max_draw_count,
+        _stride,
+    );
+    hooked.after("vkCmdDrawIndexedIndirectCountKHR", None);
+}
+
+unsafe extern "system" fn vkCmdDrawIndirect<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _draw_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawIndirect");
+    (hooked.commands.cmd_draw_indirect)(_command_buffer, _buffer, _offset, _draw_count, _stride);
+    hooked.after("vkCmdDrawIndirect", None);
+}
+
+unsafe extern "system" fn vkCmdDrawIndirectByteCountEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _instance_count: u32,
+    _first_instance: u32,
+    _counter_buffer: Buffer,
+    _counter_buffer_offset: DeviceSize,
+    _counter_offset: u32,
+    _vertex_stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawIndirectByteCountEXT");
+    (hooked.commands.cmd_draw_indirect_byte_count_ext)(
+        _command_buffer,
+        _instance_count,
+        _first_instance,
+        _counter_buffer,
+        _counter_buffer_offset,
+        _counter_offset,
+        _vertex_stride,
+    );
+    hooked.after("vkCmdDrawIndirectByteCountEXT", None);
+}
+
+unsafe extern "system" fn vkCmdDrawIndirectCount<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _count_buffer: Buffer,
+    _count_buffer_offset: DeviceSize,
+    _max_draw_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawIndirectCount");
+    (hooked.commands.cmd_draw_indirect_count)(
+        _command_buffer,
+        _buffer,
+        _offset,
+        _count_buffer,
+        _count_buffer_offset,
+        _max_draw_count,
+        _stride,
+    );
+    hooked.after("vkCmdDrawIndirectCount", None);
+}
+
+unsafe extern "system" fn vkCmdDrawIndirectCountAMD<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _count_buffer: Buffer,
+    _count_buffer_offset: DeviceSize,
+    _max_draw_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawIndirectCountAMD");
+    (hooked.commands.cmd_draw_indirect_count_amd)(
+        _command_buffer,
+        _buffer,
+        _offset,
+        _count_buffer,
+        _count_buffer_offset,
+        _max_draw_count,
+        _stride,
+    );
+    hooked.after("vkCmdDrawIndirectCountAMD", None);
+}
+
+unsafe extern "system" fn vkCmdDrawIndirectCountKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _count_buffer: Buffer,
+    _count_buffer_offset: DeviceSize,
+    _max_draw_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawIndirectCountKHR");
+    (hooked.commands.cmd_draw_indirect_count_khr)(
+        _command_buffer,
+        _buffer,
+        _offset,
+        _count_buffer,
+        _count_buffer_offset,
+        _max_draw_count,
+        _stride,
+    );
+    hooked.after("vkCmdDrawIndirectCountKHR", None);
+}
+
+unsafe extern "system" fn vkCmdDrawMeshTasksEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _group_count_x: u32,
+    _group_count_y: u32,
+    _group_count_z: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawMeshTasksEXT");
+    (hooked.commands.cmd_draw_mesh_tasks_ext)(
+        _command_buffer,
+        _group_count_x,
+        _group_count_y,
+        _group_count_z,
+    );
+    hooked.after("vkCmdDrawMeshTasksEXT", None);
+}
+
+unsafe extern "system" fn vkCmdDrawMeshTasksIndirectCountEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _count_buffer: Buffer,
+    _count_buffer_offset: DeviceSize,
+    _max_draw_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawMeshTasksIndirectCountEXT");
+    (hooked.commands.cmd_draw_mesh_tasks_indirect_count_ext)(
+        _command_buffer,
+        _buffer,
+        _offset,
+        _count_buffer,
+        _count_buffer_offset,
+        _max_draw_count,
+        _stride,
+    );
+    hooked.after("vkCmdDrawMeshTasksIndirectCountEXT", None);
+}
+
+unsafe extern "system" fn vkCmdDrawMeshTasksIndirectCountNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _count_buffer: Buffer,
+    _count_buffer_offset: DeviceSize,
+    _max_draw_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawMeshTasksIndirectCountNV");
+    (hooked.commands.cmd_draw_mesh_tasks_indirect_count_nv)(
+        _command_buffer,
+        _buffer,
+        _offset,
+        _count_buffer,
+        _count_buffer_offset,
+        _max_draw_count,
+        _stride,
+    );
+    hooked.after("vkCmdDrawMeshTasksIndirectCountNV", None);
+}
+
+unsafe extern "system" fn vkCmdDrawMeshTasksIndirectEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _draw_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawMeshTasksIndirectEXT");
+    (hooked.commands.cmd_draw_mesh_tasks_indirect_ext)(
+        _command_buffer,
+        _buffer,
+        _offset,
+        _draw_count,
+        _stride,
+    );
+    hooked.after("vkCmdDrawMeshTasksIndirectEXT", None);
+}
+
+unsafe extern "system" fn vkCmdDrawMeshTasksIndirectNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _buffer: Buffer,
+    _offset: DeviceSize,
+    _draw_count: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawMeshTasksIndirectNV");
+    (hooked.commands.cmd_draw_mesh_tasks_indirect_nv)(
+        _command_buffer,
+        _buffer,
+        _offset,
+        _draw_count,
+        _stride,
+    );
+    hooked.after("vkCmdDrawMeshTasksIndirectNV", None);
+}
+
+unsafe extern "system" fn vkCmdDrawMeshTasksNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _task_count: u32,
+    _first_task: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawMeshTasksNV");
+    (hooked.commands.cmd_draw_mesh_tasks_nv)(_command_buffer, _task_count, _first_task);
+    hooked.after("vkCmdDrawMeshTasksNV", None);
+}
+
+unsafe extern "system" fn vkCmdDrawMultiEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _draw_count: u32,
+    _vertex_info: *const MultiDrawInfoEXT,
+    _instance_count: u32,
+    _first_instance: u32,
+    _stride: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawMultiEXT");
+    (hooked.commands.cmd_draw_multi_ext)(
+        _command_buffer,
+        _draw_count,
+        _vertex_info,
+        _instance_count,
+        _first_instance,
+        _stride,
+    );
+    hooked.after("vkCmdDrawMultiEXT", None);
+}
+
+unsafe extern "system" fn vkCmdDrawMultiIndexedEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _draw_count: u32,
+    _index_info: *const MultiDrawIndexedInfoEXT,
+    _instance_count: u32,
+    _first_instance: u32,
+    _stride: u32,
+    _vertex_offset: *const i32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdDrawMultiIndexedEXT");
+    (hooked.commands.cmd_draw_multi_indexed_ext)(
+        _command_buffer,
+        _draw_count,
+        _index_info,
+        _instance_count,
+        _first_instance,
+        _stride,
+        _vertex_offset,
+    );
+    hooked.after("vkCmdDrawMultiIndexedEXT", None);
+}
+
+unsafe extern "system" fn vkCmdEncodeVideoKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _encode_info: *const VideoEncodeInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEncodeVideoKHR");
+    (hooked.commands.cmd_encode_video_khr)(_command_buffer, _encode_info);
+    hooked.after("vkCmdEncodeVideoKHR", None);
+}
+
+unsafe extern "system" fn vkCmdEndConditionalRenderingEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndConditionalRenderingEXT");
+    (hooked.commands.cmd_end_conditional_rendering_ext)(_command_buffer);
+    hooked.after("vkCmdEndConditionalRenderingEXT", None);
+}
+
+unsafe extern "system" fn vkCmdEndPerTileExecutionQCOM<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _per_tile_end_info: *const PerTileEndInfoQCOM,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndPerTileExecutionQCOM");
+    (hooked.commands.cmd_end_per_tile_execution_qcom)(_command_buffer, _per_tile_end_info);
+    hooked.after("vkCmdEndPerTileExecutionQCOM", None);
+}
+
+unsafe extern "system" fn vkCmdEndQuery<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _query_pool: QueryPool,
+    _query: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndQuery");
+    (hooked.commands.cmd_end_query)(_command_buffer, _query_pool, _query);
+    hooked.after("vkCmdEndQuery", None);
+}
+
+unsafe extern "system" fn vkCmdEndQueryIndexedEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _query_pool: QueryPool,
+    _query: u32,
+    _index: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndQueryIndexedEXT");
+    (hooked.commands.cmd_end_query_indexed_ext)(_command_buffer, _query_pool, _query, _index);
+    hooked.after("vkCmdEndQueryIndexedEXT", None);
+}
+
+unsafe extern "system" fn vkCmdEndRenderPass<const S: usize>(_command_buffer: CommandBuffer) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndRenderPass");
+    (hooked.commands.cmd_end_render_pass)(_command_buffer);
+    hooked.after("vkCmdEndRenderPass", None);
+}
+
+unsafe extern "system" fn vkCmdEndRenderPass2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _subpass_end_info: *const SubpassEndInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndRenderPass2");
+    (hooked.commands.cmd_end_render_pass2)(_command_buffer, _subpass_end_info);
+    hooked.after("vkCmdEndRenderPass2", None);
+}
+
+unsafe extern "system" fn vkCmdEndRenderPass2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _subpass_end_info: *const SubpassEndInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndRenderPass2KHR");
+    (hooked.commands.cmd_end_render_pass2_khr)(_command_buffer, _subpass_end_info);
+    hooked.after("vkCmdEndRenderPass2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdEndRendering<const S: usize>(_command_buffer: CommandBuffer) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndRendering");
+    (hooked.commands.cmd_end_rendering)(_command_buffer);
+    hooked.after("vkCmdEndRendering", None);
+}
+
+unsafe extern "system" fn vkCmdEndRendering2EXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _rendering_end_info: *const RenderingEndInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndRendering2EXT");
+    (hooked.commands.cmd_end_rendering2_ext)(_command_buffer, _rendering_end_info);
+    hooked.after("vkCmdEndRendering2EXT", None);
+}
+
+unsafe extern "system" fn vkCmdEndRendering2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _rendering_end_info: *const RenderingEndInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndRendering2KHR");
+    (hooked.commands.cmd_end_rendering2_khr)(_command_buffer, _rendering_end_info);
+    hooked.after("vkCmdEndRendering2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdEndRenderingKHR<const S: usize>(_command_buffer: CommandBuffer) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndRenderingKHR");
+    (hooked.commands.cmd_end_rendering_khr)(_command_buffer);
+    hooked.after("vkCmdEndRenderingKHR", None);
+}
+
+unsafe extern "system" fn vkCmdEndTransformFeedbackEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_counter_buffer: u32,
+    _counter_buffer_count: u32,
+    _counter_buffers: *const Buffer,
+    _counter_buffer_offsets: *const DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndTransformFeedbackEXT");
+    (hooked.commands.cmd_end_transform_feedback_ext)(
+        _command_buffer,
+        _first_counter_buffer,
+        _counter_buffer_count,
+        _counter_buffers,
+        _counter_buffer_offsets,
+    );
+    hooked.after("vkCmdEndTransformFeedbackEXT", None);
+}
+
+unsafe extern "system" fn vkCmdEndVideoCodingKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _end_coding_info: *const VideoEndCodingInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdEndVideoCodingKHR");
+    (hooked.commands.cmd_end_video_coding_khr)(_command_buffer, _end_coding_info);
+    hooked.after("vkCmdEndVideoCodingKHR", None);
+}
+
+unsafe extern "system" fn vkCmdExecuteCommands<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _command_buffer_count: u32,
+    _command_buffers: *const CommandBuffer,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdExecuteCommands");
+    (hooked.commands.cmd_execute_commands)(
+        _command_buffer,
+        _command_buffer_count,
+        _command_buffers,
+    );
+    hooked.after("vkCmdExecuteCommands", None);
+}
+
+unsafe extern "system" fn vkCmdExecuteGeneratedCommandsEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _is_preprocessed: Bool32,
+    _generated_commands_info: *const GeneratedCommandsInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdExecuteGeneratedCommandsEXT");
+    (hooked.commands.cmd_execute_generated_commands_ext)(
+        _command_buffer,
+        _is_preprocessed,
+        _generated_commands_info,
+    );
+    hooked.after("vkCmdExecuteGeneratedCommandsEXT", None);
+}
+
+unsafe extern "system" fn vkCmdExecuteGeneratedCommandsNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _is_preprocessed: Bool32,
+    _generated_commands_info: *const GeneratedCommandsInfoNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdExecuteGeneratedCommandsNV");
+    (hooked.commands.cmd_execute_generated_commands_nv)(
+        _command_buffer,
+        _is_preprocessed,
+        _generated_commands_info,
+    );
+    hooked.after("vkCmdExecuteGeneratedCommandsNV", None);
+}
+
+unsafe extern "system" fn vkCmdFillBuffer<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _dst_buffer: Buffer,
+    _dst_offset: DeviceSize,
+    _size: DeviceSize,
+    _data: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdFillBuffer");
+    (hooked.commands.cmd_fill_buffer)(_command_buffer, _dst_buffer, _dst_offset, _size, _data);
+    hooked.after("vkCmdFillBuffer", None);
+}
+
+unsafe extern "system" fn vkCmdInitializeGraphScratchMemoryAMDX<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _execution_graph: Pipeline,
+    _scratch: DeviceAddress,
+    _scratch_size: DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdInitializeGraphScratchMemoryAMDX");
+    (hooked.commands.cmd_initialize_graph_scratch_memory_amdx)(
+        _command_buffer,
+        _execution_graph,
+        _scratch,
+        _scratch_size,
+    );
+    hooked.after("vkCmdInitializeGraphScratchMemoryAMDX", None);
+}
+
+unsafe extern "system" fn vkCmdNextSubpass<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _contents: SubpassContents,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdNextSubpass");
+    (hooked.commands.cmd_next_subpass)(_command_buffer, _contents);
+    hooked.after("vkCmdNextSubpass", None);
+}
+
+unsafe extern "system" fn vkCmdNextSubpass2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _subpass_begin_info: *const SubpassBeginInfo,
+    _subpass_end_info: *const SubpassEndInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdNextSubpass2");
+    (hooked.commands.cmd_next_subpass2)(_command_buffer, _subpass_begin_info, _subpass_end_info);
+    hooked.after("vkCmdNextSubpass2", None);
+}
+
+unsafe extern "system" fn vkCmdNextSubpass2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _subpass_begin_info: *const SubpassBeginInfo,
+    _subpass_end_info: *const SubpassEndInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdNextSubpass2KHR");
+    (hooked.commands.cmd_next_subpass2_khr)(
+        _command_buffer,
+        _subpass_begin_info,
+        _subpass_end_info,
+    );
+    hooked.after("vkCmdNextSubpass2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdOpticalFlowExecuteNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _session: OpticalFlowSessionNV,
+    _execute_info: *const OpticalFlowExecuteInfoNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdOpticalFlowExecuteNV");
+    (hooked.commands.cmd_optical_flow_execute_nv)(_command_buffer, _session, _execute_info);
+    hooked.after("vkCmdOpticalFlowExecuteNV", None);
+}
+
+unsafe extern "system" fn vkCmdPipelineBarrier<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _src_stage_mask: PipelineStageFlags,
+    _dst_stage_mask: PipelineStageFlags,
+    _dependency_flags: DependencyFlags,
+    _memory_barrier_count: u32,
+    _memory_barriers: *const MemoryBarrier,
+    _buffer_memory_barrier_count: u32,
+    _buffer_memory_barriers: *const BufferMemoryBarrier,
+    _image_memory_barrier_count: u32,
+    _image_memory_barriers: *const ImageMemoryBarrier,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPipelineBarrier");
+    (hooked.commands.cmd_pipeline_barrier)(
+        _command_buffer,
+        _src_stage_mask,
+        _dst_stage_mask,
+        _dependency_flags,
+        _memory_barrier_count,
+        _memory_barriers,
+        _buffer_memory_barrier_count,
+        _buffer_memory_barriers,
+        _image_memory_barrier_count,
+        _image_memory_barriers,
+    );
+    hooked.after("vkCmdPipelineBarrier", None);
+}
+
+unsafe extern "system" fn vkCmdPipelineBarrier2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _dependency_info: *const DependencyInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPipelineBarrier2");
+    (hooked.commands.cmd_pipeline_barrier2)(_command_buffer, _dependency_info);
+    hooked.after("vkCmdPipelineBarrier2", None);
+}
+
+unsafe extern "system" fn vkCmdPipelineBarrier2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _dependency_info: *const DependencyInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPipelineBarrier2KHR");
+    (hooked.commands.cmd_pipeline_barrier2_khr)(_command_buffer, _dependency_info);
+    hooked.after("vkCmdPipelineBarrier2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdPreprocessGeneratedCommandsEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _generated_commands_info: *const GeneratedCommandsInfoEXT,
+    _state_command_buffer: CommandBuffer,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPreprocessGeneratedCommandsEXT");
+    (hooked.commands.cmd_preprocess_generated_commands_ext)(
+        _command_buffer,
+        _generated_commands_info,
+        _state_command_buffer,
+    );
+    hooked.after("vkCmdPreprocessGeneratedCommandsEXT", None);
+}
+
+unsafe extern "system" fn vkCmdPreprocessGeneratedCommandsNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _generated_commands_info: *const GeneratedCommandsInfoNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPreprocessGeneratedCommandsNV");
+    (hooked.commands.cmd_preprocess_generated_commands_nv)(
+        _command_buffer,
+        _generated_commands_info,
+    );
+    hooked.after("vkCmdPreprocessGeneratedCommandsNV", None);
+}
+
+unsafe extern "system" fn vkCmdPushConstants<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _layout: PipelineLayout,
+    _stage_flags: ShaderStageFlags,
+    _offset: u32,
+    _size: u32,
+    _values: *const c_void,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPushConstants");
+    (hooked.commands.cmd_push_constants)(
+        _command_buffer,
+        _layout,
+        _stage_flags,
+        _offset,
+        _size,
+        _values,
+    );
+    hooked.after("vkCmdPushConstants", None);
+}
+
+unsafe extern "system" fn vkCmdPushConstants2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _push_constants_info: *const PushConstantsInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPushConstants2");
+    (hooked.commands.cmd_push_constants2)(_command_buffer, _push_constants_info);
+    hooked.after("vkCmdPushConstants2", None);
+}
+
+unsafe extern "system" fn vkCmdPushConstants2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _push_constants_info: *const PushConstantsInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPushConstants2KHR");
+    (hooked.commands.cmd_push_constants2_khr)(_command_buffer, _push_constants_info);
+    hooked.after("vkCmdPushConstants2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdPushDataEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _push_data_info: *const PushDataInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPushDataEXT");
+    (hooked.commands.cmd_push_data_ext)(_command_buffer, _push_data_info);
+    hooked.after("vkCmdPushDataEXT", None);
+}
+
+unsafe extern "system" fn vkCmdPushDescriptorSet<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _pipeline_bind_point: PipelineBindPoint,
+    _layout: PipelineLayout,
+    _set: u32,
+    _descriptor_write_count: u32,
+    _descriptor_writes: *const WriteDescriptorSet,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPushDescriptorSet");
+    (hooked.commands.cmd_push_descriptor_set)(
+        _command_buffer,
+        _pipeline_bind_point,
+        _layout,
+        _set,
+        _descriptor_write_count,
+        _descriptor_writes,
+    );
+    hooked.after("vkCmdPushDescriptorSet", None);
+}
+
+unsafe extern "system" fn vkCmdPushDescriptorSet2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _push_descriptor_set_info: *const PushDescriptorSetInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPushDescriptorSet2");
+    (hooked.commands.cmd_push_descriptor_set2)(_command_buffer, _push_descriptor_set_info);
+    hooked.after("vkCmdPushDescriptorSet2", None);
+}
+
+unsafe extern "system" fn vkCmdPushDescriptorSet2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _push_descriptor_set_info: *const PushDescriptorSetInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPushDescriptorSet2KHR");
+    (hooked.commands.cmd_push_descriptor_set2_khr)(_command_buffer, _push_descriptor_set_info);
+    hooked.after("vkCmdPushDescriptorSet2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdPushDescriptorSetKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _pipeline_bind_point: PipelineBindPoint,
+    _layout: PipelineLayout,
+    _set: u32,
+    _descriptor_write_count: u32,
+    _descriptor_writes: *const WriteDescriptorSet,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPushDescriptorSetKHR");
+    (hooked.commands.cmd_push_descriptor_set_khr)(
+        _command_buffer,
+        _pipeline_bind_point,
+        _layout,
+        _set,
+        _descriptor_write_count,
+        _descriptor_writes,
+    );
+    hooked.after("vkCmdPushDescriptorSetKHR", None);
+}
+
+unsafe extern "system" fn vkCmdPushDescriptorSetWithTemplate<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _descriptor_update_template: DescriptorUpdateTemplate,
+    _layout: PipelineLayout,
+    _set: u32,
+    _data: *const c_void,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPushDescriptorSetWithTemplate");
+    (hooked.commands.cmd_push_descriptor_set_with_template)(
+        _command_buffer,
+        _descriptor_update_template,
+        _layout,
+        _set,
+        _data,
+    );
+    hooked.after("vkCmdPushDescriptorSetWithTemplate", None);
+}
+
+unsafe extern "system" fn vkCmdPushDescriptorSetWithTemplate2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _push_descriptor_set_with_template_info: *const PushDescriptorSetWithTemplateInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPushDescriptorSetWithTemplate2");
+    (hooked.commands.cmd_push_descriptor_set_with_template2)(
+        _command_buffer,
+        _push_descriptor_set_with_template_info,
+    );
+    hooked.after("vkCmdPushDescriptorSetWithTemplate2", None);
+}
+
+unsafe extern "system" fn vkCmdPushDescriptorSetWithTemplate2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _push_descriptor_set_with_template_info: *const PushDescriptorSetWithTemplateInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPushDescriptorSetWithTemplate2KHR");
+    (hooked.commands.cmd_push_descriptor_set_with_template2_khr)(
+        _command_buffer,
+        _push_descriptor_set_with_template_info,
+    );
+    hooked.after("vkCmdPushDescriptorSetWithTemplate2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdPushDescriptorSetWithTemplateKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _descriptor_update_template: DescriptorUpdateTemplate,
+    _layout: PipelineLayout,
+    _set: u32,
+    _data: *const c_void,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdPushDescriptorSetWithTemplateKHR");
+    (hooked.commands.cmd_push_descriptor_set_with_template_khr)(
+        _command_buffer,
+        _descriptor_update_template,
+        _layout,
+        _set,
+        _data,
+    );
+    hooked.after("vkCmdPushDescriptorSetWithTemplateKHR", None);
+}
+
+unsafe extern "system" fn vkCmdRefreshObjectsKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _refresh_objects: *const RefreshObjectListKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdRefreshObjectsKHR");
+    (hooked.commands.cmd_refresh_objects_khr)(_command_buffer, _refresh_objects);
+    hooked.after("vkCmdRefreshObjectsKHR", None);
+}
+
+unsafe extern "system" fn vkCmdResetEvent<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _event: Event,
+    _stage_mask: PipelineStageFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdResetEvent");
+    (hooked.commands.cmd_reset_event)(_command_buffer, _event, _stage_mask);
+    hooked.after("vkCmdResetEvent", None);
+}
+
+unsafe extern "system" fn vkCmdResetEvent2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _event: Event,
+    _stage_mask: PipelineStageFlags2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdResetEvent2");
+    (hooked.commands.cmd_reset_event2)(_command_buffer, _event, _stage_mask);
+    hooked.after("vkCmdResetEvent2", None);
+}
+
+unsafe extern "system" fn vkCmdResetEvent2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _event: Event,
+    _stage_mask: PipelineStageFlags2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdResetEvent2KHR");
+    (hooked.commands.cmd_reset_event2_khr)(_command_buffer, _event, _stage_mask);
+    hooked.after("vkCmdResetEvent2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdResetQueryPool<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _query_pool: QueryPool,
+    _first_query: u32,
+    _query_count: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdResetQueryPool");
+    (hooked.commands.cmd_reset_query_pool)(
+        _command_buffer,
+        _query_pool,
+        _first_query,
+        _query_count,
+    );
+    hooked.after("vkCmdResetQueryPool", None);
+}
+
+unsafe extern "system" fn vkCmdResolveImage<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _src_image: Image,
+    _src_image_layout: ImageLayout,
+    _dst_image: Image,
+    _dst_image_layout: ImageLayout,
+    _region_count: u32,
+    _regions: *const ImageResolve,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdResolveImage");
+    (hooked.commands.cmd_resolve_image)(
+        _command_buffer,
+        _src_image,
+        _src_image_layout,
+        _dst_image,
+        _dst_image_layout,
+        _region_count,
+        _regions,
+    );
+    hooked.after("vkCmdResolveImage", None);
+}
+
+unsafe extern "system" fn vkCmdResolveImage2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _resolve_image_info: *const ResolveImageInfo2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdResolveImage2");
+    (hooked.commands.cmd_resolve_image2)(_command_buffer, _resolve_image_info);
+    hooked.after("vkCmdResolveImage2", None);
+}
+
+unsafe extern "system" fn vkCmdResolveImage2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _resolve_image_info: *const ResolveImageInfo2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdResolveImage2KHR");
+    (hooked.commands.cmd_resolve_image2_khr)(_command_buffer, _resolve_image_info);
+    hooked.after("vkCmdResolveImage2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdSetAlphaToCoverageEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _alpha_to_coverage_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetAlphaToCoverageEnableEXT");
+    (hooked.commands.cmd_set_alpha_to_coverage_enable_ext)(
+        _command_buffer,
+        _alpha_to_coverage_enable,
+    );
+    hooked.after("vkCmdSetAlphaToCoverageEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetAlphaToOneEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _alpha_to_one_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetAlphaToOneEnableEXT");
+    (hooked.commands.cmd_set_alpha_to_one_enable_ext)(_command_buffer, _alpha_to_one_enable);
+    hooked.after("vkCmdSetAlphaToOneEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetAttachmentFeedbackLoopEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _aspect_mask: ImageAspectFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetAttachmentFeedbackLoopEnableEXT");
+    (hooked.commands.cmd_set_attachment_feedback_loop_enable_ext)(_command_buffer, _aspect_mask);
+    hooked.after("vkCmdSetAttachmentFeedbackLoopEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetBlendConstants<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _blend_constants: *const f32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetBlendConstants");
+    (hooked.commands.cmd_set_blend_constants)(_command_buffer, _blend_constants);
+    hooked.after("vkCmdSetBlendConstants", None);
+}
+
+unsafe extern "system" fn vkCmdSetCheckpointNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _checkpoint_marker: *const c_void,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetCheckpointNV");
+    (hooked.commands.cmd_set_checkpoint_nv)(_command_buffer, _checkpoint_marker);
+    hooked.after("vkCmdSetCheckpointNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetCoarseSampleOrderNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _sample_order_type: CoarseSampleOrderTypeNV,
+    _custom_sample_order_count: u32,
+    _custom_sample_orders: *const CoarseSampleOrderCustomNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetCoarseSampleOrderNV");
+    (hooked.commands.cmd_set_coarse_sample_order_nv)(
+        _command_buffer,
+        _sample_order_type,
+        _custom_sample_order_count,
+        _custom_sample_orders,
+    );
+    hooked.after("vkCmdSetCoarseSampleOrderNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetColorBlendAdvancedEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_attachment: u32,
+    _attachment_count: u32,
+    _color_blend_advanced: *const ColorBlendAdvancedEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetColorBlendAdvancedEXT");
+    (hooked.commands.cmd_set_color_blend_advanced_ext)(
+        _command_buffer,
+        _first_attachment,
+        _attachment_count,
+        _color_blend_advanced,
+    );
+    hooked.after("vkCmdSetColorBlendAdvancedEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetColorBlendEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_attachment: u32,
+    _attachment_count: u32,
+    _color_blend_enables: *const Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetColorBlendEnableEXT");
+    (hooked.commands.cmd_set_color_blend_enable_ext)(
+        _command_buffer,
+        _first_attachment,
+        _attachment_count,
+        _color_blend_enables,
+    );
+    hooked.after("vkCmdSetColorBlendEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetColorBlendEquationEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_attachment: u32,
+    _attachment_count: u32,
+    _color_blend_equations: *const ColorBlendEquationEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetColorBlendEquationEXT");
+    (hooked.commands.cmd_set_color_blend_equation_ext)(
+        _command_buffer,
+        _first_attachment,
+        _attachment_count,
+        _color_blend_equations,
+    );
+    hooked.after("vkCmdSetColorBlendEquationEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetColorWriteEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _attachment_count: u32,
+    _color_write_enables: *const Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetColorWriteEnableEXT");
+    (hooked.commands.cmd_set_color_write_enable_ext)(
+        _command_buffer,
+        _attachment_count,
+        _color_write_enables,
+    );
+    hooked.after("vkCmdSetColorWriteEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetColorWriteMaskEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_attachment: u32,
+    _attachment_count: u32,
+    _color_write_masks: *const ColorComponentFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetColorWriteMaskEXT");
+    (hooked.commands.cmd_set_color_write_mask_ext)(
+        _command_buffer,
+        _first_attachment,
+        _attachment_count,
+        _color_write_masks,
+    );
+    hooked.after("vkCmdSetColorWriteMaskEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetComputeOccupancyPriorityNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _parameters: *const ComputeOccupancyPriorityParametersNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetComputeOccupancyPriorityNV");
+    (hooked.commands.cmd_set_compute_occupancy_priority_nv)(_command_buffer, _parameters);
+    hooked.after("vkCmdSetComputeOccupancyPriorityNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetConservativeRasterizationModeEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _conservative_rasterization_mode: ConservativeRasterizationModeEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetConservativeRasterizationModeEXT");
+    (hooked.commands.cmd_set_conservative_rasterization_mode_ext)(
+        _command_buffer,
+        _conservative_rasterization_mode,
+    );
+    hooked.after("vkCmdSetConservativeRasterizationModeEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetCoverageModulationModeNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _coverage_modulation_mode: CoverageModulationModeNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetCoverageModulationModeNV");
+    (hooked.commands.cmd_set_coverage_modulation_mode_nv)(
+        _command_buffer,
+        _coverage_modulation_mode,
+    );
+    hooked.after("vkCmdSetCoverageModulationModeNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetCoverageModulationTableEnableNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _coverage_modulation_table_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetCoverageModulationTableEnableNV");
+    (hooked.commands.cmd_set_coverage_modulation_table_enable_nv)(
+        _command_buffer,
+        _coverage_modulation_table_enable,
+    );
+    hooked.after("vkCmdSetCoverageModulationTableEnableNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetCoverageModulationTableNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _coverage_modulation_table_count: u32,
+    _coverage_modulation_table: *const f32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetCoverageModulationTableNV");
+    (hooked.commands.cmd_set_coverage_modulation_table_nv)(
+        _command_buffer,
+        _coverage_modulation_table_count,
+        _coverage_modulation_table,
+    );
+    hooked.after("vkCmdSetCoverageModulationTableNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetCoverageReductionModeNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _coverage_reduction_mode: CoverageReductionModeNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetCoverageReductionModeNV");
+    (hooked.commands.cmd_set_coverage_reduction_mode_nv)(_command_buffer, _coverage_reduction_mode);
+    hooked.after("vkCmdSetCoverageReductionModeNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetCoverageToColorEnableNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _coverage_to_color_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetCoverageToColorEnableNV");
+    (hooked.commands.cmd_set_coverage_to_color_enable_nv)(
+        _command_buffer,
+        _coverage_to_color_enable,
+    );
+    hooked.after("vkCmdSetCoverageToColorEnableNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetCoverageToColorLocationNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _coverage_to_color_location: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetCoverageToColorLocationNV");
+    (hooked.commands.cmd_set_coverage_to_color_location_nv)(
+        _command_buffer,
+        _coverage_to_color_location,
+    );
+    hooked.after("vkCmdSetCoverageToColorLocationNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetCullMode<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _cull_mode: CullModeFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetCullMode");
+    (hooked.commands.cmd_set_cull_mode)(_command_buffer, _cull_mode);
+    hooked.after("vkCmdSetCullMode", None);
+}
+
+unsafe extern "system" fn vkCmdSetCullModeEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _cull_mode: CullModeFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetCullModeEXT");
+    (hooked.commands.cmd_set_cull_mode_ext)(_command_buffer, _cull_mode);
+    hooked.after("vkCmdSetCullModeEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthBias<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_bias_constant_factor: f32,
+    _depth_bias_clamp: f32,
+    _depth_bias_slope_factor: f32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthBias");
+    (hooked.commands.cmd_set_depth_bias)(
+        _command_buffer,
+        _depth_bias_constant_factor,
+        _depth_bias_clamp,
+        _depth_bias_slope_factor,
+    );
+    hooked.after("vkCmdSetDepthBias", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthBias2EXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_bias_info: *const DepthBiasInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthBias2EXT");
+    (hooked.commands.cmd_set_depth_bias2_ext)(_command_buffer, _depth_bias_info);
+    hooked.after("vkCmdSetDepthBias2EXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthBiasEnable<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_bias_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthBiasEnable");
+    (hooked.commands.cmd_set_depth_bias_enable)(_command_buffer, _depth_bias_enable);
+    hooked.after("vkCmdSetDepthBiasEnable", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthBiasEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_bias_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthBiasEnableEXT");
+    (hooked.commands.cmd_set_depth_bias_enable_ext)(_command_buffer, _depth_bias_enable);
+    hooked.after("vkCmdSetDepthBiasEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthBounds<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _min_depth_bounds: f32,
+    _max_depth_bounds: f32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthBounds");
+    (hooked.commands.cmd_set_depth_bounds)(_command_buffer, _min_depth_bounds, _max_depth_bounds);
+    hooked.after("vkCmdSetDepthBounds", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthBoundsTestEnable<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_bounds_test_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthBoundsTestEnable");
+    (hooked.commands.cmd_set_depth_bounds_test_enable)(_command_buffer, _depth_bounds_test_enable);
+    hooked.after("vkCmdSetDepthBoundsTestEnable", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthBoundsTestEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_bounds_test_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthBoundsTestEnableEXT");
+    (hooked.commands.cmd_set_depth_bounds_test_enable_ext)(
+        _command_buffer,
+        _depth_bounds_test_enable,
+    );
+    hooked.after("vkCmdSetDepthBoundsTestEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthClampEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_clamp_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthClampEnableEXT");
+    (hooked.commands.cmd_set_depth_clamp_enable_ext)(_command_buffer, _depth_clamp_enable);
+    hooked.after("vkCmdSetDepthClampEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthClampRangeEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_clamp_mode: DepthClampModeEXT,
+    _depth_clamp_range: *const DepthClampRangeEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthClampRangeEXT");
+    (hooked.commands.cmd_set_depth_clamp_range_ext)(
+        _command_buffer,
+        _depth_clamp_mode,
+        _depth_clamp_range,
+    );
+    hooked.after("vkCmdSetDepthClampRangeEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthClipEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_clip_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthClipEnableEXT");
+    (hooked.commands.cmd_set_depth_clip_enable_ext)(_command_buffer, _depth_clip_enable);
+    hooked.after("vkCmdSetDepthClipEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthClipNegativeOneToOneEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _negative_one_to_one: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthClipNegativeOneToOneEXT");
+    (hooked.commands.cmd_set_depth_clip_negative_one_to_one_ext)(
+        _command_buffer,
+        _negative_one_to_one,
+    );
+    hooked.after("vkCmdSetDepthClipNegativeOneToOneEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthCompareOp<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_compare_op: CompareOp,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthCompareOp");
+    (hooked.commands.cmd_set_depth_compare_op)(_command_buffer, _depth_compare_op);
+    hooked.after("vkCmdSetDepthCompareOp", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthCompareOpEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_compare_op: CompareOp,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthCompareOpEXT");
+    (hooked.commands.cmd_set_depth_compare_op_ext)(_command_buffer, _depth_compare_op);
+    hooked.after("vkCmdSetDepthCompareOpEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthTestEnable<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_test_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthTestEnable");
+    (hooked.commands.cmd_set_depth_test_enable)(_command_buffer, _depth_test_enable);
+    hooked.after("vkCmdSetDepthTestEnable", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthTestEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_test_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthTestEnableEXT");
+    (hooked.commands.cmd_set_depth_test_enable_ext)(_command_buffer, _depth_test_enable);
+    hooked.after("vkCmdSetDepthTestEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthWriteEnable<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_write_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthWriteEnable");
+    (hooked.commands.cmd_set_depth_write_enable)(_command_buffer, _depth_write_enable);
+    hooked.after("vkCmdSetDepthWriteEnable", None);
+}
+
+unsafe extern "system" fn vkCmdSetDepthWriteEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _depth_write_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDepthWriteEnableEXT");
+    (hooked.commands.cmd_set_depth_write_enable_ext)(_command_buffer, _depth_write_enable);
+    hooked.after("vkCmdSetDepthWriteEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDescriptorBufferOffsets2EXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _set_descriptor_buffer_offsets_info: *const SetDescriptorBufferOffsetsInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDescriptorBufferOffsets2EXT");
+    (hooked.commands.cmd_set_descriptor_buffer_offsets2_ext)(
+        _command_buffer,
+        _set_descriptor_buffer_offsets_info,
+    );
+    hooked.after("vkCmdSetDescriptorBufferOffsets2EXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDescriptorBufferOffsetsEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _pipeline_bind_point: PipelineBindPoint,
+    _layout: PipelineLayout,
+    _first_set: u32,
+    _set_count: u32,
+    _buffer_indices: *const u32,
+    _offsets: *const DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDescriptorBufferOffsetsEXT");
+    (hooked.commands.cmd_set_descriptor_buffer_offsets_ext)(
+        _command_buffer,
+        _pipeline_bind_point,
+        _layout,
+        _first_set,
+        _set_count,
+        _buffer_indices,
+        _offsets,
+    );
+    hooked.after("vkCmdSetDescriptorBufferOffsetsEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDeviceMask<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _device_mask: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDeviceMask");
+    (hooked.commands.cmd_set_device_mask)(_command_buffer, _device_mask);
+    hooked.after("vkCmdSetDeviceMask", None);
+}
+
+unsafe extern "system" fn vkCmdSetDeviceMaskKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _device_mask: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDeviceMaskKHR");
+    (hooked.commands.cmd_set_device_mask_khr)(_command_buffer, _device_mask);
+    hooked.after("vkCmdSetDeviceMaskKHR", None);
+}
+
+unsafe extern "system" fn vkCmdSetDiscardRectangleEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_discard_rectangle: u32,
+    _discard_rectangle_count: u32,
+    _discard_rectangles: *const Rect2D,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDiscardRectangleEXT");
+    (hooked.commands.cmd_set_discard_rectangle_ext)(
+        _command_buffer,
+        _first_discard_rectangle,
+        _discard_rectangle_count,
+        _discard_rectangles,
+    );
+    hooked.after("vkCmdSetDiscardRectangleEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDiscardRectangleEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _discard_rectangle_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDiscardRectangleEnableEXT");
+    (hooked.commands.cmd_set_discard_rectangle_enable_ext)(
+        _command_buffer,
+        _discard_rectangle_enable,
+    );
+    hooked.after("vkCmdSetDiscardRectangleEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetDiscardRectangleModeEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _discard_rectangle_mode: DiscardRectangleModeEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetDiscardRectangleModeEXT");
+    (hooked.commands.cmd_set_discard_rectangle_mode_ext)(_command_buffer, _discard_rectangle_mode);
+    hooked.after("vkCmdSetDiscardRectangleModeEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetEvent<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _event: Event,
+    _stage_mask: PipelineStageFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetEvent");
+    (hooked.commands.cmd_set_event)(_command_buffer, _event, _stage_mask);
+    hooked.after("vkCmdSetEvent", None);
+}
+
+unsafe extern "system" fn vkCmdSetEvent2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _event: Event,
+    _dependency_info: *const DependencyInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetEvent2");
+    (hooked.commands.cmd_set_event2)(_command_buffer, _event, _dependency_info);
+    hooked.after("vkCmdSetEvent2", None);
+}
+
+unsafe extern "system" fn vkCmdSetEvent2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _event: Event,
+    _dependency_info: *const DependencyInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetEvent2KHR");
+    (hooked.commands.cmd_set_event2_khr)(_command_buffer, _event, _dependency_info);
+    hooked.after("vkCmdSetEvent2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdSetExclusiveScissorEnableNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_exclusive_scissor: u32,
+    _exclusive_scissor_count: u32,
+    _exclusive_scissor_enables: *const Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetExclusiveScissorEnableNV");
+    (hooked.commands.cmd_set_exclusive_scissor_enable_nv)(
+        _command_buffer,
+        _first_exclusive_scissor,
+        _exclusive_scissor_count,
+        _exclusive_scissor_enables,
+    );
+    hooked.after("vkCmdSetExclusiveScissorEnableNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetExclusiveScissorNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_exclusive_scissor: u32,
+    _exclusive_scissor_count: u32,
+    _exclusive_scissors: *const Rect2D,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetExclusiveScissorNV");
+    (hooked.commands.cmd_set_exclusive_scissor_nv)(
+        _command_buffer,
+        _first_exclusive_scissor,
+        _exclusive_scissor_count,
+        _exclusive_scissors,
+    );
+    hooked.after("vkCmdSetExclusiveScissorNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetExtraPrimitiveOverestimationSizeEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _extra_primitive_overestimation_size: f32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetExtraPrimitiveOverestimationSizeEXT");
+    (hooked
+        .commands
+        .cmd_set_extra_primitive_overestimation_size_ext)(
+        _command_buffer,
+        _extra_primitive_overestimation_size,
+    );
+    hooked.after("vkCmdSetExtraPrimitiveOverestimationSizeEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetFragmentShadingRateEnumNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _shading_rate: FragmentShadingRateNV,
+    _combiner_ops: *const FragmentShadingRateCombinerOpKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetFragmentShadingRateEnumNV");
+    (hooked.commands.cmd_set_fragment_shading_rate_enum_nv)(
+        _command_buffer,
+        _shading_rate,
+        _combiner_ops,
+    );
+    hooked.after("vkCmdSetFragmentShadingRateEnumNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetFragmentShadingRateKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _fragment_size: *const Extent2D,
+    _combiner_ops: *const FragmentShadingRateCombinerOpKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetFragmentShadingRateKHR");
+    (hooked.commands.cmd_set_fragment_shading_rate_khr)(
+        _command_buffer,
+        _fragment_size,
+        _combiner_ops,
+    );
+    hooked.after("vkCmdSetFragmentShadingRateKHR", None);
+}
+
+unsafe extern "system" fn vkCmdSetFrontFace<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _front_face: FrontFace,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetFrontFace");
+    (hooked.commands.cmd_set_front_face)(_command_buffer, _front_face);
+    hooked.after("vkCmdSetFrontFace", None);
+}
+
+unsafe extern "system" fn vkCmdSetFrontFaceEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _front_face: FrontFace,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetFrontFaceEXT");
+    (hooked.commands.cmd_set_front_face_ext)(_command_buffer, _front_face);
+    hooked.after("vkCmdSetFrontFaceEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetLineRasterizationModeEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _line_rasterization_mode: LineRasterizationModeEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetLineRasterizationModeEXT");
+    (hooked.commands.cmd_set_line_rasterization_mode_ext)(
+        _command_buffer,
+        _line_rasterization_mode,
+    );
+    hooked.after("vkCmdSetLineRasterizationModeEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetLineStipple<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _line_stipple_factor: u32,
+    _line_stipple_pattern: u16,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetLineStipple");
+    (hooked.commands.cmd_set_line_stipple)(
+        _command_buffer,
+        _line_stipple_factor,
+        _line_stipple_pattern,
+    );
+    hooked.after("vkCmdSetLineStipple", None);
+}
+
+unsafe extern "system" fn vkCmdSetLineStippleEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _line_stipple_factor: u32,
+    _line_stipple_pattern: u16,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetLineStippleEXT");
+    (hooked.commands.cmd_set_line_stipple_ext)(
+        _command_buffer,
+        _line_stipple_factor,
+        _line_stipple_pattern,
+    );
+    hooked.after("vkCmdSetLineStippleEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetLineStippleEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _stippled_line_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetLineStippleEnableEXT");
+    (hooked.commands.cmd_set_line_stipple_enable_ext)(_command_buffer, _stippled_line_enable);
+    hooked.after("vkCmdSetLineStippleEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetLineStippleKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _line_stipple_factor: u32,
+    _line_stipple_pattern: u16,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetLineStippleKHR");
+    (hooked.commands.cmd_set_line_stipple_khr)(
+        _command_buffer,
+        _line_stipple_factor,
+        _line_stipple_pattern,
+    );
+    hooked.after("vkCmdSetLineStippleKHR", None);
+}
+
+unsafe extern "system" fn vkCmdSetLineWidth<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _line_width: f32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetLineWidth");
+    (hooked.commands.cmd_set_line_width)(_command_buffer, _line_width);
+    hooked.after("vkCmdSetLineWidth", None);
+}
+
+unsafe extern "system" fn vkCmdSetLogicOpEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _logic_op: LogicOp,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetLogicOpEXT");
+    (hooked.commands.cmd_set_logic_op_ext)(_command_buffer, _logic_op);
+    hooked.after("vkCmdSetLogicOpEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetLogicOpEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _logic_op_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetLogicOpEnableEXT");
+    (hooked.commands.cmd_set_logic_op_enable_ext)(_command_buffer, _logic_op_enable);
+    hooked.after("vkCmdSetLogicOpEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetPatchControlPointsEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _patch_control_points: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetPatchControlPointsEXT");
+    (hooked.commands.cmd_set_patch_control_points_ext)(_command_buffer, _patch_control_points);
+    hooked.after("vkCmdSetPatchControlPointsEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetPerformanceMarkerINTEL<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _marker_info: *const PerformanceMarkerInfoINTEL,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCmdSetPerformanceMarkerINTEL") {
+        Some(result) => result,
+        None => (hooked.commands.cmd_set_performance_marker_intel)(_command_buffer, _marker_info),
+    };
+    hooked.after("vkCmdSetPerformanceMarkerINTEL", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCmdSetPerformanceOverrideINTEL<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _override_info: *const PerformanceOverrideInfoINTEL,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCmdSetPerformanceOverrideINTEL") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.cmd_set_performance_override_intel)(_command_buffer, _override_info)
+        }
+    };
+    hooked.after("vkCmdSetPerformanceOverrideINTEL", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCmdSetPerformanceStreamMarkerINTEL<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _marker_info: *const PerformanceStreamMarkerInfoINTEL,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCmdSetPerformanceStreamMarkerINTEL") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.cmd_set_performance_stream_marker_intel)(_command_buffer, _marker_info)
+        }
+    };
+    hooked.after("vkCmdSetPerformanceStreamMarkerINTEL", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCmdSetPolygonModeEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _polygon_mode: PolygonMode,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetPolygonModeEXT");
+    (hooked.commands.cmd_set_polygon_mode_ext)(_command_buffer, _polygon_mode);
+    hooked.after("vkCmdSetPolygonModeEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetPrimitiveRestartEnable<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _primitive_restart_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetPrimitiveRestartEnable");
+    (hooked.commands.cmd_set_primitive_restart_enable)(_command_buffer, _primitive_restart_enable);
+    hooked.after("vkCmdSetPrimitiveRestartEnable", None);
+}
+
+unsafe extern "system" fn vkCmdSetPrimitiveRestartEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _primitive_restart_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetPrimitiveRestartEnableEXT");
+    (hooked.commands.cmd_set_primitive_restart_enable_ext)(
+        _command_buffer,
+        _primitive_restart_enable,
+    );
+    hooked.after("vkCmdSetPrimitiveRestartEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetPrimitiveTopology<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _primitive_topology: PrimitiveTopology,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetPrimitiveTopology");
+    (hooked.commands.cmd_set_primitive_topology)(_command_buffer, _primitive_topology);
+    hooked.after("vkCmdSetPrimitiveTopology", None);
+}
+
+unsafe extern "system" fn vkCmdSetPrimitiveTopologyEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _primitive_topology: PrimitiveTopology,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetPrimitiveTopologyEXT");
+    (hooked.commands.cmd_set_primitive_topology_ext)(_command_buffer, _primitive_topology);
+    hooked.after("vkCmdSetPrimitiveTopologyEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetProvokingVertexModeEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _provoking_vertex_mode: ProvokingVertexModeEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetProvokingVertexModeEXT");
+    (hooked.commands.cmd_set_provoking_vertex_mode_ext)(_command_buffer, _provoking_vertex_mode);
+    hooked.after("vkCmdSetProvokingVertexModeEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetRasterizationSamplesEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _rasterization_samples: SampleCountFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetRasterizationSamplesEXT");
+    (hooked.commands.cmd_set_rasterization_samples_ext)(_command_buffer, _rasterization_samples);
+    hooked.after("vkCmdSetRasterizationSamplesEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetRasterizationStreamEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _rasterization_stream: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetRasterizationStreamEXT");
+    (hooked.commands.cmd_set_rasterization_stream_ext)(_command_buffer, _rasterization_stream);
+    hooked.after("vkCmdSetRasterizationStreamEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetRasterizerDiscardEnable<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _rasterizer_discard_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetRasterizerDiscardEnable");
+    (hooked.commands.cmd_set_rasterizer_discard_enable)(
+        _command_buffer,
+        _rasterizer_discard_enable,
+    );
+    hooked.after("vkCmdSetRasterizerDiscardEnable", None);
+}
+
+unsafe extern "system" fn vkCmdSetRasterizerDiscardEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _rasterizer_discard_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetRasterizerDiscardEnableEXT");
+    (hooked.commands.cmd_set_rasterizer_discard_enable_ext)(
+        _command_buffer,
+        _rasterizer_discard_enable,
+    );
+    hooked.after("vkCmdSetRasterizerDiscardEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetRayTracingPipelineStackSizeKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _pipeline_stack_size: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetRayTracingPipelineStackSizeKHR");
+    (hooked.commands.cmd_set_ray_tracing_pipeline_stack_size_khr)(
+        _command_buffer,
+        _pipeline_stack_size,
+    );
+    hooked.after("vkCmdSetRayTracingPipelineStackSizeKHR", None);
+}
+
+unsafe extern "system" fn vkCmdSetRenderingAttachmentLocations<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _location_info: *const RenderingAttachmentLocationInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetRenderingAttachmentLocations");
+    (hooked.commands.cmd_set_rendering_attachment_locations)(_command_buffer, _location_info);
+    hooked.after("vkCmdSetRenderingAttachmentLocations", None);
+}
+
+unsafe extern "system" fn vkCmdSetRenderingAttachmentLocationsKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _location_info: *const RenderingAttachmentLocationInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetRenderingAttachmentLocationsKHR");
+    (hooked.commands.cmd_set_rendering_attachment_locations_khr)(_command_buffer, _location_info);
+    hooked.after("vkCmdSetRenderingAttachmentLocationsKHR", None);
+}
+
+unsafe extern "system" fn vkCmdSetRenderingInputAttachmentIndices<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _input_attachment_index_info: *const RenderingInputAttachmentIndexInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetRenderingInputAttachmentIndices");
+    (hooked.commands.cmd_set_rendering_input_attachment_indices)(
+        _command_buffer,
+        _input_attachment_index_info,
+    );
+    hooked.after("vkCmdSetRenderingInputAttachmentIndices", None);
+}
+
+unsafe extern "system" fn vkCmdSetRenderingInputAttachmentIndicesKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _input_attachment_index_info: *const RenderingInputAttachmentIndexInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetRenderingInputAttachmentIndicesKHR");
+    (hooked
+        .commands
+        .cmd_set_rendering_input_attachment_indices_khr)(
+        _command_buffer,
+        _input_attachment_index_info,
+    );
+    hooked.after("vkCmdSetRenderingInputAttachmentIndicesKHR", None);
+}
+
+unsafe extern "system" fn vkCmdSetRepresentativeFragmentTestEnableNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _representative_fragment_test_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetRepresentativeFragmentTestEnableNV");
+    (hooked
+        .commands
+        .cmd_set_representative_fragment_test_enable_nv)(
+        _command_buffer,
+        _representative_fragment_test_enable,
+    );
+    hooked.after("vkCmdSetRepresentativeFragmentTestEnableNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetSampleLocationsEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _sample_locations_info: *const SampleLocationsInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetSampleLocationsEXT");
+    (hooked.commands.cmd_set_sample_locations_ext)(_command_buffer, _sample_locations_info);
+    hooked.after("vkCmdSetSampleLocationsEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetSampleLocationsEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _sample_locations_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetSampleLocationsEnableEXT");
+    (hooked.commands.cmd_set_sample_locations_enable_ext)(
+        _command_buffer,
+        _sample_locations_enable,
+    );
+    hooked.after("vkCmdSetSampleLocationsEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetSampleMaskEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _samples: SampleCountFlags,
+    _sample_mask: *const SampleMask,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetSampleMaskEXT");
+    (hooked.commands.cmd_set_sample_mask_ext)(_command_buffer, _samples, _sample_mask);
+    hooked.after("vkCmdSetSampleMaskEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetScissor<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_scissor: u32,
+    _scissor_count: u32,
+    _scissors: *const Rect2D,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetScissor");
+    (hooked.commands.cmd_set_scissor)(_command_buffer, _first_scissor, _scissor_count, _scissors);
+    hooked.after("vkCmdSetScissor", None);
+}
+
+unsafe extern "system" fn vkCmdSetScissorWithCount<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _scissor_count: u32,
+    _scissors: *const Rect2D,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetScissorWithCount");
+    (hooked.commands.cmd_set_scissor_with_count)(_command_buffer, _scissor_count, _scissors);
+    hooked.after("vkCmdSetScissorWithCount", None);
+}
+
+unsafe extern "system" fn vkCmdSetScissorWithCountEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _scissor_count: u32,
+    _scissors: *const Rect2D,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetScissorWithCountEXT");
+    (hooked.commands.cmd_set_scissor_with_count_ext)(_command_buffer, _scissor_count, _scissors);
+    hooked.after("vkCmdSetScissorWithCountEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetShadingRateImageEnableNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _shading_rate_image_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetShadingRateImageEnableNV");
+    (hooked.commands.cmd_set_shading_rate_image_enable_nv)(
+        _command_buffer,
+        _shading_rate_image_enable,
+    );
+    hooked.after("vkCmdSetShadingRateImageEnableNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetStencilCompareMask<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _face_mask: StencilFaceFlags,
+    _compare_mask: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetStencilCompareMask");
+    (hooked.commands.cmd_set_stencil_compare_mask)(_command_buffer, _face_mask, _compare_mask);
+    hooked.after("vkCmdSetStencilCompareMask", None);
+}
+
+unsafe extern "system" fn vkCmdSetStencilOp<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _face_mask: StencilFaceFlags,
+    _fail_op: StencilOp,
+    _pass_op: StencilOp,
+    _depth_fail_op: StencilOp,
+    _compare_op: CompareOp,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetStencilOp");
+    (hooked.commands.cmd_set_stencil_op)(
+        _command_buffer,
+        _face_mask,
+        _fail_op,
+        _pass_op,
+        _depth_fail_op,
+        _compare_op,
+    );
+    hooked.after("vkCmdSetStencilOp", None);
+}
+
+unsafe extern "system" fn vkCmdSetStencilOpEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _face_mask: StencilFaceFlags,
+    _fail_op: StencilOp,
+    _pass_op: StencilOp,
+    _depth_fail_op: StencilOp,
+    _compare_op: CompareOp,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetStencilOpEXT");
+    (hooked.commands.cmd_set_stencil_op_ext)(
+        _command_buffer,
+        _face_mask,
+        _fail_op,
+        _pass_op,
+        _depth_fail_op,
+        _compare_op,
+    );
+    hooked.after("vkCmdSetStencilOpEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetStencilReference<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _face_mask: StencilFaceFlags,
+    _reference: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetStencilReference");
+    (hooked.commands.cmd_set_stencil_reference)(_command_buffer, _face_mask, _reference);
+    hooked.after("vkCmdSetStencilReference", None);
+}
+
+unsafe extern "system" fn vkCmdSetStencilTestEnable<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _stencil_test_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetStencilTestEnable");
+    (hooked.commands.cmd_set_stencil_test_enable)(_command_buffer, _stencil_test_enable);
+    hooked.after("vkCmdSetStencilTestEnable", None);
+}
+
+unsafe extern "system" fn vkCmdSetStencilTestEnableEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _stencil_test_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetStencilTestEnableEXT");
+    (hooked.commands.cmd_set_stencil_test_enable_ext)(_command_buffer, _stencil_test_enable);
+    hooked.after("vkCmdSetStencilTestEnableEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetStencilWriteMask<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _face_mask: StencilFaceFlags,
+    _write_mask: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetStencilWriteMask");
+    (hooked.commands.cmd_set_stencil_write_mask)(_command_buffer, _face_mask, _write_mask);
+    hooked.after("vkCmdSetStencilWriteMask", None);
+}
+
+unsafe extern "system" fn vkCmdSetTessellationDomainOriginEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _domain_origin: TessellationDomainOrigin,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetTessellationDomainOriginEXT");
+    (hooked.commands.cmd_set_tessellation_domain_origin_ext)(_command_buffer, _domain_origin);
+    hooked.after("vkCmdSetTessellationDomainOriginEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetVertexInputEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _vertex_binding_description_count: u32,
+    _vertex_binding_descriptions: *const VertexInputBindingDescription2EXT,
+    _vertex_attribute_description_count: u32,
+    _vertex_attribute_descriptions: *const VertexInputAttributeDescription2EXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetVertexInputEXT");
+    (hooked.commands.cmd_set_vertex_input_ext)(
+        _command_buffer,
+        _vertex_binding_description_count,
+        _vertex_binding_descriptions,
+        _vertex_attribute_description_count,
+        _vertex_attribute_descriptions,
+    );
+    hooked.after("vkCmdSetVertexInputEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSetViewport<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_viewport: u32,
+    _viewport_count: u32,
+    _viewports: *const Viewport,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetViewport");
+    (hooked.commands.cmd_set_viewport)(
+        _command_buffer,
+        _first_viewport,
+        _viewport_count,
+        _viewports,
+    );
+    hooked.after("vkCmdSetViewport", None);
+}
+
+unsafe extern "system" fn vkCmdSetViewportShadingRatePaletteNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_viewport: u32,
+    _viewport_count: u32,
+    _shading_rate_palettes: *const ShadingRatePaletteNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetViewportShadingRatePaletteNV");
+    (hooked.commands.cmd_set_viewport_shading_rate_palette_nv)(
+        _command_buffer,
+        _first_viewport,
+        _viewport_count,
+        _shading_rate_palettes,
+    );
+    hooked.after("vkCmdSetViewportShadingRatePaletteNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetViewportSwizzleNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_viewport: u32,
+    _viewport_count: u32,
+    _viewport_swizzles: *const ViewportSwizzleNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetViewportSwizzleNV");
+    (hooked.commands.cmd_set_viewport_swizzle_nv)(
+        _command_buffer,
+        _first_viewport,
+        _viewport_count,
+        _viewport_swizzles,
+    );
+    hooked.after("vkCmdSetViewportSwizzleNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetViewportWScalingEnableNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _viewport_w_scaling_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetViewportWScalingEnableNV");
+    (hooked.commands.cmd_set_viewport_w_scaling_enable_nv)(
+        _command_buffer,
+        _viewport_w_scaling_enable,
+    );
+    hooked.after("vkCmdSetViewportWScalingEnableNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetViewportWScalingNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _first_viewport: u32,
+    _viewport_count: u32,
+    _viewport_w_scalings: *const ViewportWScalingNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetViewportWScalingNV");
+    (hooked.commands.cmd_set_viewport_w_scaling_nv)(
+        _command_buffer,
+        _first_viewport,
+        _viewport_count,
+        _viewport_w_scalings,
+    );
+    hooked.after("vkCmdSetViewportWScalingNV", None);
+}
+
+unsafe extern "system" fn vkCmdSetViewportWithCount<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _viewport_count: u32,
+    _viewports: *const Viewport,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetViewportWithCount");
+    (hooked.commands.cmd_set_viewport_with_count)(_command_buffer, _viewport_count, _viewports);
+    hooked.after("vkCmdSetViewportWithCount", None);
+}
+
+unsafe extern "system" fn vkCmdSetViewportWithCountEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _viewport_count: u32,
+    _viewports: *const Viewport,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSetViewportWithCountEXT");
+    (hooked.commands.cmd_set_viewport_with_count_ext)(_command_buffer, _viewport_count, _viewports);
+    hooked.after("vkCmdSetViewportWithCountEXT", None);
+}
+
+unsafe extern "system" fn vkCmdSubpassShadingHUAWEI<const S: usize>(
+    _command_buffer: CommandBuffer,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdSubpassShadingHUAWEI");
+    (hooked.commands.cmd_subpass_shading_huawei)(_command_buffer);
+    hooked.after("vkCmdSubpassShadingHUAWEI", None);
+}
+
+unsafe extern "system" fn vkCmdTraceRaysIndirect2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _indirect_device_address: DeviceAddress,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdTraceRaysIndirect2KHR");
+    (hooked.commands.cmd_trace_rays_indirect2_khr)(_command_buffer, _indirect_device_address);
+    hooked.after("vkCmdTraceRaysIndirect2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdTraceRaysIndirectKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _raygen_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    _miss_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    _hit_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    _callable_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    _indirect_device_address: DeviceAddress,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdTraceRaysIndirectKHR");
+    (hooked.commands.cmd_trace_rays_indirect_khr)(
+        _command_buffer,
+        _raygen_shader_binding_table,
+        _miss_shader_binding_table,
+        _hit_shader_binding_table,
+        _callable_shader_binding_table,
+        _indirect_device_address,
+    );
+    hooked.after("vkCmdTraceRaysIndirectKHR", None);
+}
+
+unsafe extern "system" fn vkCmdTraceRaysKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _raygen_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    _miss_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    _hit_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    _callable_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+    _width: u32,
+    _height: u32,
+    _depth: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdTraceRaysKHR");
+    (hooked.commands.cmd_trace_rays_khr)(
+        _command_buffer,
+        _raygen_shader_binding_table,
+        _miss_shader_binding_table,
+        _hit_shader_binding_table,
+        _callable_shader_binding_table,
+        _width,
+        _height,
+        _depth,
+    );
+    hooked.after("vkCmdTraceRaysKHR", None);
+}
+
+unsafe extern "system" fn vkCmdTraceRaysNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _raygen_shader_binding_table_buffer: Buffer,
+    _raygen_shader_binding_offset: DeviceSize,
+    _miss_shader_binding_table_buffer: Buffer,
+    _miss_shader_binding_offset: DeviceSize,
+    _miss_shader_binding_stride: DeviceSize,
+    _hit_shader_binding_table_buffer: Buffer,
+    _hit_shader_binding_offset: DeviceSize,
+    _hit_shader_binding_stride: DeviceSize,
+    _callable_shader_binding_table_buffer: Buffer,
+    _callable_shader_binding_offset: DeviceSize,
+    _callable_shader_binding_stride: DeviceSize,
+    _width: u32,
+    _height: u32,
+    _depth: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdTraceRaysNV");
+    (hooked.commands.cmd_trace_rays_nv)(
+        _command_buffer,
+        _raygen_shader_binding_table_buffer,
+        _raygen_shader_binding_offset,
+        _miss_shader_binding_table_buffer,
+        _miss_shader_binding_offset,
+        _miss_shader_binding_stride,
+        _hit_shader_binding_table_buffer,
+        _hit_shader_binding_offset,
+        _hit_shader_binding_stride,
+        _callable_shader_binding_table_buffer,
+        _callable_shader_binding_offset,
+        _callable_shader_binding_stride,
+        _width,
+        _height,
+        _depth,
+    );
+    hooked.after("vkCmdTraceRaysNV", None);
+}
+
+unsafe extern "system" fn vkCmdUpdateBuffer<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _dst_buffer: Buffer,
+    _dst_offset: DeviceSize,
+    _data_size: DeviceSize,
+    _data: *const c_void,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdUpdateBuffer");
+    (hooked.commands.cmd_update_buffer)(
+        _command_buffer,
+        _dst_buffer,
+        _dst_offset,
+        _data_size,
+        _data,
+    );
+    hooked.after("vkCmdUpdateBuffer", None);
+}
+
+unsafe extern "system" fn vkCmdUpdatePipelineIndirectBufferNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _pipeline_bind_point: PipelineBindPoint,
+    _pipeline: Pipeline,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdUpdatePipelineIndirectBufferNV");
+    (hooked.commands.cmd_update_pipeline_indirect_buffer_nv)(
+        _command_buffer,
+        _pipeline_bind_point,
+        _pipeline,
+    );
+    hooked.after("vkCmdUpdatePipelineIndirectBufferNV", None);
+}
+
+unsafe extern "system" fn vkCmdWaitEvents<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _event_count: u32,
+    _events: *const Event,
+    _src_stage_mask: PipelineStageFlags,
+    _dst_stage_mask: PipelineStageFlags,
+    _memory_barrier_count: u32,
+    _memory_barriers: *const MemoryBarrier,
+    _buffer_memory_barrier_count: u32,
+    _buffer_memory_barriers: *const BufferMemoryBarrier,
+    _image_memory_barrier_count: u32,
+    _image_memory_barriers: *const ImageMemoryBarrier,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdWaitEvents");
+    (hooked.commands.cmd_wait_events)(
+        _command_buffer,
+        _event_count,
+        _events,
+        _src_stage_mask,
+        _dst_stage_mask,
+        _memory_barrier_count,
+        _memory_barriers,
+        _buffer_memory_barrier_count,
+        _buffer_memory_barriers,
+        _image_memory_barrier_count,
+        _image_memory_barriers,
+    );
+    hooked.after("vkCmdWaitEvents", None);
+}
+
+unsafe extern "system" fn vkCmdWaitEvents2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _event_count: u32,
+    _events: *const Event,
+    _dependency_infos: *const DependencyInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdWaitEvents2");
+    (hooked.commands.cmd_wait_events2)(_command_buffer, _event_count, _events, _dependency_infos);
+    hooked.after("vkCmdWaitEvents2", None);
+}
+
+unsafe extern "system" fn vkCmdWaitEvents2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _event_count: u32,
+    _events: *const Event,
+    _dependency_infos: *const DependencyInfo,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdWaitEvents2KHR");
+    (hooked.commands.cmd_wait_events2_khr)(
+        _command_buffer,
+        _event_count,
+        _events,
+        _dependency_infos,
+    );
+    hooked.after("vkCmdWaitEvents2KHR", None);
+}
+
+unsafe extern "system" fn vkCmdWriteAccelerationStructuresPropertiesKHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _acceleration_structure_count: u32,
+    _acceleration_structures: *const AccelerationStructureKHR,
+    _query_type: QueryType,
+    _query_pool: QueryPool,
+    _first_query: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdWriteAccelerationStructuresPropertiesKHR");
+    (hooked
+        .commands
+        .cmd_write_acceleration_structures_properties_khr)(
+        _command_buffer,
+        _acceleration_structure_count,
+        _acceleration_structures,
+        _query_type,
+        _query_pool,
+        _first_query,
+    );
+    hooked.after("vkCmdWriteAccelerationStructuresPropertiesKHR", None);
+}
+
+unsafe extern "system" fn vkCmdWriteAccelerationStructuresPropertiesNV<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _acceleration_structure_count: u32,
+    _acceleration_structures: *const AccelerationStructureNV,
+    _query_type: QueryType,
+    _query_pool: QueryPool,
+    _first_query: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdWriteAccelerationStructuresPropertiesNV");
+    (hooked
+        .commands
+        .cmd_write_acceleration_structures_properties_nv)(
+        _command_buffer,
+        _acceleration_structure_count,
+        _acceleration_structures,
+        _query_type,
+        _query_pool,
+        _first_query,
+    );
+    hooked.after("vkCmdWriteAccelerationStructuresPropertiesNV", None);
+}
+
+unsafe extern "system" fn vkCmdWriteBufferMarker2AMD<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _stage: PipelineStageFlags2,
+    _dst_buffer: Buffer,
+    _dst_offset: DeviceSize,
+    _marker: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdWriteBufferMarker2AMD");
+    (hooked.commands.cmd_write_buffer_marker2_amd)(
+        _command_buffer,
+        _stage,
+        _dst_buffer,
+        _dst_offset,
+        _marker,
+    );
+    hooked.after("vkCmdWriteBufferMarker2AMD", None);
+}
+
+unsafe extern "system" fn vkCmdWriteBufferMarkerAMD<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _pipeline_stage: PipelineStageFlags,
+    _dst_buffer: Buffer,
+    _dst_offset: DeviceSize,
+    _marker: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdWriteBufferMarkerAMD");
+    (hooked.commands.cmd_write_buffer_marker_amd)(
+        _command_buffer,
+        _pipeline_stage,
+        _dst_buffer,
+        _dst_offset,
+        _marker,
+    );
+    hooked.after("vkCmdWriteBufferMarkerAMD", None);
+}
+
+unsafe extern "system" fn vkCmdWriteMicromapsPropertiesEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _micromap_count: u32,
+    _micromaps: *const MicromapEXT,
+    _query_type: QueryType,
+    _query_pool: QueryPool,
+    _first_query: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdWriteMicromapsPropertiesEXT");
+    (hooked.commands.cmd_write_micromaps_properties_ext)(
+        _command_buffer,
+        _micromap_count,
+        _micromaps,
+        _query_type,
+        _query_pool,
+        _first_query,
+    );
+    hooked.after("vkCmdWriteMicromapsPropertiesEXT", None);
+}
+
+unsafe extern "system" fn vkCmdWriteTimestamp<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _pipeline_stage: PipelineStageFlags,
+    _query_pool: QueryPool,
+    _query: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdWriteTimestamp");
+    (hooked.commands.cmd_write_timestamp)(_command_buffer, _pipeline_stage, _query_pool, _query);
+    hooked.after("vkCmdWriteTimestamp", None);
+}
+
+unsafe extern "system" fn vkCmdWriteTimestamp2<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _stage: PipelineStageFlags2,
+    _query_pool: QueryPool,
+    _query: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdWriteTimestamp2");
+    (hooked.commands.cmd_write_timestamp2)(_command_buffer, _stage, _query_pool, _query);
+    hooked.after("vkCmdWriteTimestamp2", None);
+}
+
+unsafe extern "system" fn vkCmdWriteTimestamp2KHR<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _stage: PipelineStageFlags2,
+    _query_pool: QueryPool,
+    _query: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkCmdWriteTimestamp2KHR");
+    (hooked.commands.cmd_write_timestamp2_khr)(_command_buffer, _stage, _query_pool, _query);
+    hooked.after("vkCmdWriteTimestamp2KHR", None);
+}
+
+unsafe extern "system" fn vkCompileDeferredNV<const S: usize>(
+    _device: Device,
+    _pipeline: Pipeline,
+    _shader: u32,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCompileDeferredNV") {
+        Some(result) => result,
+        None => (hooked.commands.compile_deferred_nv)(_device, _pipeline, _shader),
+    };
+    hooked.after("vkCompileDeferredNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkConvertCooperativeVectorMatrixNV<const S: usize>(
+    _device: Device,
+    _info: *const ConvertCooperativeVectorMatrixInfoNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkConvertCooperativeVectorMatrixNV") {
+        Some(result) => result,
+        None => (hooked.commands.convert_cooperative_vector_matrix_nv)(_device, _info),
+    };
+    hooked.after("vkConvertCooperativeVectorMatrixNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCopyAccelerationStructureKHR<const S: usize>(
+    _device: Device,
+    _deferred_operation: DeferredOperationKHR,
+    _info: *const CopyAccelerationStructureInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCopyAccelerationStructureKHR") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.copy_acceleration_structure_khr)(_device, _deferred_operation, _info)
+        }
+    };
+    hooked.after("vkCopyAccelerationStructureKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCopyAccelerationStructureToMemoryKHR<const S: usize>(
+    _device: Device,
+    _deferred_operation: DeferredOperationKHR,
+    _info: *const CopyAccelerationStructureToMemoryInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCopyAccelerationStructureToMemoryKHR") {
+        Some(result) => result,
+        None => (hooked.commands.copy_acceleration_structure_to_memory_khr)(
+            _device,
+            _deferred_operation,
+            _info,
+        ),
+    };
+    hooked.after("vkCopyAccelerationStructureToMemoryKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCopyImageToImage<const S: usize>(
+    _device: Device,
+    _copy_image_to_image_info: *const CopyImageToImageInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCopyImageToImage") {
+        Some(result) => result,
+        None => (hooked.commands.copy_image_to_image)(_device, _copy_image_to_image_info),
+    };
+    hooked.after("vkCopyImageToImage", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCopyImageToImageEXT<const S: usize>(
+    _device: Device,
+    _copy_image_to_image_info: *const CopyImageToImageInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCopyImageToImageEXT") {
+        Some(result) => result,
+        None => (hooked.commands.copy_image_to_image_ext)(_device, _copy_image_to_image_info),
+    };
+    hooked.after("vkCopyImageToImageEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCopyImageToMemory<const S: usize>(
+    _device: Device,
+    _copy_image_to_memory_info: *const CopyImageToMemoryInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCopyImageToMemory") {
+        Some(result) => result,
+        None => (hooked.commands.copy_image_to_memory)(_device, _copy_image_to_memory_info),
+    };
+    hooked.after("vkCopyImageToMemory", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCopyImageToMemoryEXT<const S: usize>(
+    _device: Device,
+    _copy_image_to_memory_info: *const CopyImageToMemoryInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCopyImageToMemoryEXT") {
+        Some(result) => result,
+        None => (hooked.commands.copy_image_to_memory_ext)(_device, _copy_image_to_memory_info),
+    };
+    hooked.after("vkCopyImageToMemoryEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCopyMemoryToAccelerationStructureKHR<const S: usize>(
+    _device: Device,
+    _deferred_operation: DeferredOperationKHR,
+    _info: *const CopyMemoryToAccelerationStructureInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCopyMemoryToAccelerationStructureKHR") {
+        Some(result) => result,
+        None => (hooked.commands.copy_memory_to_acceleration_structure_khr)(
+            _device,
+            _deferred_operation,
+            _info,
+        ),
+    };
+    hooked.after("vkCopyMemoryToAccelerationStructureKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCopyMemoryToImage<const S: usize>(
+    _device: Device,
+    _copy_memory_to_image_info: *const CopyMemoryToImageInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCopyMemoryToImage") {
+        Some(result) => result,
+        None => (hooked.commands.copy_memory_to_image)(_device, _copy_memory_to_image_info),
+    };
+    hooked.after("vkCopyMemoryToImage", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCopyMemoryToImageEXT<const S: usize>(
+    _device: Device,
+    _copy_memory_to_image_info: *const CopyMemoryToImageInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCopyMemoryToImageEXT") {
+        Some(result) => result,
+        None => (hooked.commands.copy_memory_to_image_ext)(_device, _copy_memory_to_image_info),
+    };
+    hooked.after("vkCopyMemoryToImageEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCopyMemoryToMicromapEXT<const S: usize>(
+    _device: Device,
+    _deferred_operation: DeferredOperationKHR,
+    _info: *const CopyMemoryToMicromapInfoEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCopyMemoryToMicromapEXT") {
+        Some(result) => result,
+        None => (hooked.commands.copy_memory_to_micromap_ext)(_device, _deferred_operation, _info),
+    };
+    hooked.after("vkCopyMemoryToMicromapEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCopyMicromapEXT<const S: usize>(
+    _device: Device,
+    _deferred_operation: DeferredOperationKHR,
+    _info: *const CopyMicromapInfoEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCopyMicromapEXT") {
+        Some(result) => result,
+        None => (hooked.commands.copy_micromap_ext)(_device, _deferred_operation, _info),
+    };
+    hooked.after("vkCopyMicromapEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCopyMicromapToMemoryEXT<const S: usize>(
+    _device: Device,
+    _deferred_operation: DeferredOperationKHR,
+    _info: *const CopyMicromapToMemoryInfoEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCopyMicromapToMemoryEXT") {
+        Some(result) => result,
+        None => (hooked.commands.copy_micromap_to_memory_ext)(_device, _deferred_operation, _info),
+    };
+    hooked.after("vkCopyMicromapToMemoryEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateAccelerationStructureKHR<const S: usize>(
+    _device: Device,
+    _create_info: *const AccelerationStructureCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _acceleration_structure: *mut AccelerationStructureKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateAccelerationStructureKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_acceleration_structure_khr)(
+            _device,
+            _create_info,
+            _allocator,
+            _acceleration_structure,
+        ),
+    };
+    hooked.after("vkCreateAccelerationStructureKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateAccelerationStructureNV<const S: usize>(
+    _device: Device,
+    _create_info: *const AccelerationStructureCreateInfoNV,
+    _allocator: *const AllocationCallbacks,
+    _acceleration_structure: *mut AccelerationStructureNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateAccelerationStructureNV") {
+        Some(result) => result,
+        None => (hooked.commands.create_acceleration_structure_nv)(
+            _device,
+            _create_info,
+            _allocator,
+            _acceleration_structure,
+        ),
+    };
+    hooked.after("vkCreateAccelerationStructureNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateBuffer<const S: usize>(
+    _device: Device,
+    _create_info: *const BufferCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _buffer: *mut Buffer,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateBuffer") {
+        Some(result) => result,
+        None => (hooked.commands.create_buffer)(_device, _create_info, _allocator, _buffer),
+    };
+    hooked.after("vkCreateBuffer", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateBufferCollectionFUCHSIA<const S: usize>(
+    _device: Device,
+    _create_info: *const BufferCollectionCreateInfoFUCHSIA,
+    _allocator: *const AllocationCallbacks,
+    _collection: *mut BufferCollectionFUCHSIA,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateBufferCollectionFUCHSIA") {
+        Some(result) => result,
+        None => (hooked.commands.create_buffer_collection_fuchsia)(
+            _device,
+            _create_info,
+            _allocator,
+            _collection,
+        ),
+    };
+    hooked.after("vkCreateBufferCollectionFUCHSIA", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateBufferView<const S: usize>(
+    _device: Device,
+    _create_info: *const BufferViewCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _view: *mut BufferView,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateBufferView") {
+        Some(result) => result,
+        None => (hooked.commands.create_buffer_view)(_device, _create_info, _allocator, _view),
+    };
+    hooked.after("vkCreateBufferView", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateCommandPool<const S: usize>(
+    _device: Device,
+    _create_info: *const CommandPoolCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _command_pool: *mut CommandPool,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateCommandPool") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_command_pool)(_device, _create_info, _allocator, _command_pool)
+        }
+    };
+    hooked.after("vkCreateCommandPool", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateComputePipelines<const S: usize>(
+    _device: Device,
+    _pipeline_cache: PipelineCache,
+    _create_info_count: u32,
+    _create_infos: *const ComputePipelineCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _pipelines: *mut Pipeline,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateComputePipelines") {
+        Some(result) => result,
+        None => (hooked.commands.create_compute_pipelines)(
+            _device,
+            _pipeline_cache,
+            _create_info_count,
+            _create_infos,
+            _allocator,
+            _pipelines,
+        ),
+    };
+    hooked.after("vkCreateComputePipelines", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateCuFunctionNVX<const S: usize>(
+    _device: Device,
+    _create_info: *const CuFunctionCreateInfoNVX,
+    _allocator: *const AllocationCallbacks,
+    _function: *mut CuFunctionNVX,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateCuFunctionNVX") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_cu_function_nvx)(_device, _create_info, _allocator, _function)
+        }
+    };
+    hooked.after("vkCreateCuFunctionNVX", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateCuModuleNVX<const S: usize>(
+    _device: Device,
+    _create_info: *const CuModuleCreateInfoNVX,
+    _allocator: *const AllocationCallbacks,
+    _module: *mut CuModuleNVX,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateCuModuleNVX") {
+        Some(result) => result,
+        None => (hooked.commands.create_cu_module_nvx)(_device, _create_info, _allocator, _module),
+    };
+    hooked.after("vkCreateCuModuleNVX", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateCudaFunctionNV<const S: usize>(
+    _device: Device,
+    _create_info: *const CudaFunctionCreateInfoNV,
+    _allocator: *const AllocationCallbacks,
+    _function: *mut CudaFunctionNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateCudaFunctionNV") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_cuda_function_nv)(_device, _create_info, _allocator, _function)
+        }
+    };
+    hooked.after("vkCreateCudaFunctionNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateCudaModuleNV<const S: usize>(
+    _device: Device,
+    _create_info: *const CudaModuleCreateInfoNV,
+    _allocator: *const AllocationCallbacks,
+    _module: *mut CudaModuleNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateCudaModuleNV") {
+        Some(result) => result,
+        None => (hooked.commands.create_cuda_module_nv)(_device, _create_info, _allocator, _module),
+    };
+    hooked.after("vkCreateCudaModuleNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDataGraphPipelineSessionARM<const S: usize>(
+    _device: Device,
+    _create_info: *const DataGraphPipelineSessionCreateInfoARM,
+    _allocator: *const AllocationCallbacks,
+    _session: *mut DataGraphPipelineSessionARM,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateDataGraphPipelineSessionARM") {
+        Some(result) => result,
+        None => (hooked.commands.create_data_graph_pipeline_session_arm)(
+            _device,
+            _create_info,
+            _allocator,
+            _session,
+        ),
+    };
+    hooked.after("vkCreateDataGraphPipelineSessionARM", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDataGraphPipelinesARM<const S: usize>(
+    _device: Device,
+    _deferred_operation: DeferredOperationKHR,
+    _pipeline_cache: PipelineCache,
+    _create_info_count: u32,
+    _create_infos: *const DataGraphPipelineCreateInfoARM,
+    _allocator: *const AllocationCallbacks,
+    _pipelines: *mut Pipeline,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateDataGraphPipelinesARM") {
+        Some(result) => result,
+        None => (hooked.commands.create_data_graph_pipelines_arm)(
+            _device,
+            _deferred_operation,
+            _pipeline_cache,
+            _create_info_count,
+            _create_infos,
+            _allocator,
+            _pipelines,
+        ),
+    };
+    hooked.after("vkCreateDataGraphPipelinesARM", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDeferredOperationKHR<const S: usize>(
+    _device: Device,
+    _allocator: *const AllocationCallbacks,
+    _deferred_operation: *mut DeferredOperationKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateDeferredOperationKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_deferred_operation_khr)(
+            _device,
+            _allocator,
+            _deferred_operation,
+        ),
+    };
+    hooked.after("vkCreateDeferredOperationKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDescriptorPool<const S: usize>(
+    _device: Device,
+    _create_info: *const DescriptorPoolCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _descriptor_pool: *mut DescriptorPool,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateDescriptorPool") {
+        Some(result) => result,
+        None => (hooked.commands.create_descriptor_pool)(
+            _device,
+            _create_info,
+            _allocator,
+            _descriptor_pool,
+        ),
+    };
+    hooked.after("vkCreateDescriptorPool", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDescriptorSetLayout<const S: usize>(
+    _device: Device,
+    _create_info: *const DescriptorSetLayoutCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _set_layout: *mut DescriptorSetLayout,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateDescriptorSetLayout") {
+        Some(result) => result,
+        None => (hooked.commands.create_descriptor_set_layout)(
+            _device,
+            _create_info,
+            _allocator,
+            _set_layout,
+        ),
+    };
+    hooked.after("vkCreateDescriptorSetLayout", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDescriptorUpdateTemplate<const S: usize>(
+    _device: Device,
+    _create_info: *const DescriptorUpdateTemplateCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _descriptor_update_template: *mut DescriptorUpdateTemplate,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateDescriptorUpdateTemplate") {
+        Some(result) => result,
+        None => (hooked.commands.create_descriptor_update_template)(
+            _device,
+            _create_info,
+            _allocator,
+            _descriptor_update_template,
+        ),
+    };
+    hooked.after("vkCreateDescriptorUpdateTemplate", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDescriptorUpdateTemplateKHR<const S: usize>(
+    _device: Device,
+    _create_info: *const DescriptorUpdateTemplateCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _descriptor_update_template: *mut DescriptorUpdateTemplate,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateDescriptorUpdateTemplateKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_descriptor_update_template_khr)(
+            _device,
+            _create_info,
+            _allocator,
+            _descriptor_update_template,
+        ),
+    };
+    hooked.after("vkCreateDescriptorUpdateTemplateKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateEvent<const S: usize>(
+    _device: Device,
+    _create_info: *const EventCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _event: *mut Event,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateEvent") {
+        Some(result) => result,
+        None => (hooked.commands.create_event)(_device, _create_info, _allocator, _event),
+    };
+    hooked.after("vkCreateEvent", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateExecutionGraphPipelinesAMDX<const S: usize>(
+    _device: Device,
+    _pipeline_cache: PipelineCache,
+    _create_info_count: u32,
+    _create_infos: *const ExecutionGraphPipelineCreateInfoAMDX,
+    _allocator: *const AllocationCallbacks,
+    _pipelines: *mut Pipeline,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateExecutionGraphPipelinesAMDX") {
+        Some(result) => result,
+        None => (hooked.commands.create_execution_graph_pipelines_amdx)(
+            _device,
+            _pipeline_cache,
+            _create_info_count,
+            _create_infos,
+            _allocator,
+            _pipelines,
+        ),
+    };
+    hooked.after("vkCreateExecutionGraphPipelinesAMDX", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateExternalComputeQueueNV<const S: usize>(
+    _device: Device,
+    _create_info: *const ExternalComputeQueueCreateInfoNV,
+    _allocator: *const AllocationCallbacks,
+    _external_queue: *mut ExternalComputeQueueNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateExternalComputeQueueNV") {
+        Some(result) => result,
+        None => (hooked.commands.create_external_compute_queue_nv)(
+            _device,
+            _create_info,
+            _allocator,
+            _external_queue,
+        ),
+    };
+    hooked.after("vkCreateExternalComputeQueueNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateFence<const S: usize>(
+    _device: Device,
+    _create_info: *const FenceCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _fence: *mut Fence,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateFence") {
+        Some(result) => result,
+        None => (hooked.commands.create_fence)(_device, _create_info, _allocator, _fence),
+    };
+    hooked.after("vkCreateFence", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateFramebuffer<const S: usize>(
+    _device: Device,
+    _create_info: *const FramebufferCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _framebuffer: *mut Framebuffer,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateFramebuffer") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_framebuffer)(_device, _create_info, _allocator, _framebuffer)
+        }
+    };
+    hooked.after("vkCreateFramebuffer", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateGraphicsPipelines<const S: usize>(
+    _device: Device,
+    _pipeline_cache: PipelineCache,
+    _create_info_count: u32,
+    _create_infos: *const GraphicsPipelineCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _pipelines: *mut Pipeline,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateGraphicsPipelines") {
+        Some(result) => result,
+        None => (hooked.commands.create_graphics_pipelines)(
+            _device,
+            _pipeline_cache,
+            _create_info_count,
+            _create_infos,
+            _allocator,
+            _pipelines,
+        ),
+    };
+    hooked.after("vkCreateGraphicsPipelines", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateImage<const S: usize>(
+    _device: Device,
+    _create_info: *const ImageCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _image: *mut Image,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateImage") {
+        Some(result) => result,
+        None => (hooked.commands.create_image)(_device, _create_info, _allocator, _image),
+    };
+    hooked.after("vkCreateImage", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateImageView<const S: usize>(
+    _device: Device,
+    _create_info: *const ImageViewCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _view: *mut ImageView,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateImageView") {
+        Some(result) => result,
+        None => (hooked.commands.create_image_view)(_device, _create_info, _allocator, _view),
+    };
+    hooked.after("vkCreateImageView", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateIndirectCommandsLayoutEXT<const S: usize>(
+    _device: Device,
+    _create_info: *const IndirectCommandsLayoutCreateInfoEXT,
+    _allocator: *const AllocationCallbacks,
+    _indirect_commands_layout: *mut IndirectCommandsLayoutEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateIndirectCommandsLayoutEXT") {
+        Some(result) => result,
+        None => (hooked.commands.create_indirect_commands_layout_ext)(
+            _device,
+            _create_info,
+            _allocator,
+            _indirect_commands_layout,
+        ),
+    };
+    hooked.after("vkCreateIndirectCommandsLayoutEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateIndirectCommandsLayoutNV<const S: usize>(
+    _device: Device,
+    _create_info: *const IndirectCommandsLayoutCreateInfoNV,
+    _allocator: *const AllocationCallbacks,
+    _indirect_commands_layout: *mut IndirectCommandsLayoutNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateIndirectCommandsLayoutNV") {
+        Some(result) => result,
+        None => (hooked.commands.create_indirect_commands_layout_nv)(
+            _device,
+            _create_info,
+            _allocator,
+            _indirect_commands_layout,
+        ),
+    };
+    hooked.after("vkCreateIndirectCommandsLayoutNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateIndirectExecutionSetEXT<const S: usize>(
+    _device: Device,
+    _create_info: *const IndirectExecutionSetCreateInfoEXT,
+    _allocator: *const AllocationCallbacks,
+    _indirect_execution_set: *mut IndirectExecutionSetEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateIndirectExecutionSetEXT") {
+        Some(result) => result,
+        None => (hooked.commands.create_indirect_execution_set_ext)(
+            _device,
+            _create_info,
+            _allocator,
+            _indirect_execution_set,
+        ),
+    };
+    hooked.after("vkCreateIndirectExecutionSetEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateMicromapEXT<const S: usize>(
+    _device: Device,
+    _create_info: *const MicromapCreateInfoEXT,
+    _allocator: *const AllocationCallbacks,
+    _micromap: *mut MicromapEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateMicromapEXT") {
+        Some(result) => result,
+        None => (hooked.commands.create_micromap_ext)(_device, _create_info, _allocator, _micromap),
+    };
+    hooked.after("vkCreateMicromapEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateOpticalFlowSessionNV<const S: usize>(
+    _device: Device,
+    _create_info: *const OpticalFlowSessionCreateInfoNV,
+    _allocator: *const AllocationCallbacks,
+    _session: *mut OpticalFlowSessionNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateOpticalFlowSessionNV") {
+        Some(result) => result,
+        None => (hooked.commands.create_optical_flow_session_nv)(
+            _device,
+            _create_info,
+            _allocator,
+            _session,
+        ),
+    };
+    hooked.after("vkCreateOpticalFlowSessionNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreatePipelineBinariesKHR<const S: usize>(
+    _device: Device,
+    _create_info: *const PipelineBinaryCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _binaries: *mut PipelineBinaryHandlesInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreatePipelineBinariesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_pipeline_binaries_khr)(
+            _device,
+            _create_info,
+            _allocator,
+            _binaries,
+        ),
+    };
+    hooked.after("vkCreatePipelineBinariesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreatePipelineCache<const S: usize>(
+    _device: Device,
+    _create_info: *const PipelineCacheCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _pipeline_cache: *mut PipelineCache,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreatePipelineCache") {
+        Some(result) => result,
+        None => (hooked.commands.create_pipeline_cache)(
+            _device,
+            _create_info,
+            _allocator,
+            _pipeline_cache,
+        ),
+    };
+    hooked.after("vkCreatePipelineCache", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreatePipelineLayout<const S: usize>(
+    _device: Device,
+    _create_info: *const PipelineLayoutCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _pipeline_layout: *mut PipelineLayout,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreatePipelineLayout") {
+        Some(result) => result,
+        None => (hooked.commands.create_pipeline_layout)(
+            _device,
+            _create_info,
+            _allocator,
+            _pipeline_layout,
+        ),
+    };
+    hooked.after("vkCreatePipelineLayout", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreatePrivateDataSlot<const S: usize>(
+    _device: Device,
+    _create_info: *const PrivateDataSlotCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _private_data_slot: *mut PrivateDataSlot,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreatePrivateDataSlot") {
+        Some(result) => result,
+        None => (hooked.commands.create_private_data_slot)(
+            _device,
+            _create_info,
+            _allocator,
+            _private_data_slot,
+        ),
+    };
+    hooked.after("vkCreatePrivateDataSlot", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreatePrivateDataSlotEXT<const S: usize>(
+    _device: Device,
+    _create_info: *const PrivateDataSlotCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _private_data_slot: *mut PrivateDataSlot,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreatePrivateDataSlotEXT") {
+        Some(result) => result,
+        None => (hooked.commands.create_private_data_slot_ext)(
+            _device,
+            _create_info,
+            _allocator,
+            _private_data_slot,
+        ),
+    };
+    hooked.after("vkCreatePrivateDataSlotEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateQueryPool<const S: usize>(
+    _device: Device,
+    _create_info: *const QueryPoolCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _query_pool: *mut QueryPool,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateQueryPool") {
+        Some(result) => result,
+        None => (hooked.commands.create_query_pool)(_device, _create_info, _allocator, _query_pool),
+    };
+    hooked.after("vkCreateQueryPool", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateRayTracingPipelinesKHR<const S: usize>(
+    _device: Device,
+    _deferred_operation: DeferredOperationKHR,
+    _pipeline_cache: PipelineCache,
+    _create_info_count: u32,
+    _create_infos: *const RayTracingPipelineCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _pipelines: *mut Pipeline,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateRayTracingPipelinesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_ray_tracing_pipelines_khr)(
+            _device,
+            _deferred_operation,
+            _pipeline_cache,
+            _create_info_count,
+            _create_infos,
+            _allocator,
+            _pipelines,
+        ),
+    };
+    hooked.after("vkCreateRayTracingPipelinesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateRayTracingPipelinesNV<const S: usize>(
+    _device: Device,
+    _pipeline_cache: PipelineCache,
+    _create_info_count: u32,
+    _create_infos: *const RayTracingPipelineCreateInfoNV,
+    _allocator: *const AllocationCallbacks,
+    _pipelines: *mut Pipeline,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateRayTracingPipelinesNV") {
+        Some(result) => result,
+        None => (hooked.commands.create_ray_tracing_pipelines_nv)(
+            _device,
+            _pipeline_cache,
+            _create_info_count,
+            _create_infos,
+            _allocator,
+            _pipelines,
+        ),
+    };
+    hooked.after("vkCreateRayTracingPipelinesNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateRenderPass<const S: usize>(
+    _device: Device,
+    _create_info: *const RenderPassCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _render_pass: *mut RenderPass,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateRenderPass") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_render_pass)(_device, _create_info, _allocator, _render_pass)
+        }
+    };
+    hooked.after("vkCreateRenderPass", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateRenderPass2<const S: usize>(
+    _device: Device,
+    _create_info: *const RenderPassCreateInfo2,
+    _allocator: *const AllocationCallbacks,
+    _render_pass: *mut RenderPass,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateRenderPass2") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_render_pass2)(_device, _create_info, _allocator, _render_pass)
+        }
+    };
+    hooked.after("vkCreateRenderPass2", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateRenderPass2KHR<const S: usize>(
+    _device: Device,
+    _create_info: *const RenderPassCreateInfo2,
+    _allocator: *const AllocationCallbacks,
+    _render_pass: *mut RenderPass,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateRenderPass2KHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_render_pass2_khr)(
+            _device,
+            _create_info,
+            _allocator,
+            _render_pass,
+        ),
+    };
+    hooked.after("vkCreateRenderPass2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateSampler<const S: usize>(
+    _device: Device,
+    _create_info: *const SamplerCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _sampler: *mut Sampler,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateSampler") {
+        Some(result) => result,
+        None => (hooked.commands.create_sampler)(_device, _create_info, _allocator, _sampler),
+    };
+    hooked.after("vkCreateSampler", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateSamplerYcbcrConversion<const S: usize>(
+    _device: Device,
+    _create_info: *const SamplerYcbcrConversionCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _ycbcr_conversion: *mut SamplerYcbcrConversion,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateSamplerYcbcrConversion") {
+        Some(result) => result,
+        None => (hooked.commands.create_sampler_ycbcr_conversion)(
+            _device,
+            _create_info,
+            _allocator,
+            _ycbcr_conversion,
+        ),
+    };
+    hooked.after("vkCreateSamplerYcbcrConversion", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateSamplerYcbcrConversionKHR<const S: usize>(
+    _device: Device,
+    _create_info: *const SamplerYcbcrConversionCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _ycbcr_conversion: *mut SamplerYcbcrConversion,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateSamplerYcbcrConversionKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_sampler_ycbcr_conversion_khr)(
+            _device,
+            _create_info,
+            _allocator,
+            _ycbcr_conversion,
+        ),
+    };
+    hooked.after("vkCreateSamplerYcbcrConversionKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateSemaphore<const S: usize>(
+    _device: Device,
+    _create_info: *const SemaphoreCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _semaphore: *mut Semaphore,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateSemaphore") {
+        Some(result) => result,
+        None => (hooked.commands.create_semaphore)(_device, _create_info, _allocator, _semaphore),
+    };
+    hooked.after("vkCreateSemaphore", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateSemaphoreSciSyncPoolNV<const S: usize>(
+    _device: Device,
+    _create_info: *const SemaphoreSciSyncPoolCreateInfoNV,
+    _allocator: *const AllocationCallbacks,
+    _semaphore_pool: *mut SemaphoreSciSyncPoolNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateSemaphoreSciSyncPoolNV") {
+        Some(result) => result,
+        None => (hooked.commands.create_semaphore_sci_sync_pool_nv)(
+            _device,
+            _create_info,
+            _allocator,
+            _semaphore_pool,
+        ),
+    };
+    hooked.after("vkCreateSemaphoreSciSyncPoolNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateShaderModule<const S: usize>(
+    _device: Device,
+    _create_info: *const ShaderModuleCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _shader_module: *mut ShaderModule,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateShaderModule") {
+        Some(result) => result,
+        None => (hooked.commands.create_shader_module)(
+            _device,
+            _create_info,
+            _allocator,
+            _shader_module,
+        ),
+    };
+    hooked.after("vkCreateShaderModule", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateShadersEXT<const S: usize>(
+    _device: Device,
+    _create_info_count: u32,
+    _create_infos: *const ShaderCreateInfoEXT,
+    _allocator: *const AllocationCallbacks,
+    _shaders: *mut ShaderEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateShadersEXT") {
+        Some(result) => result,
+        None => (hooked.commands.create_shaders_ext)(
+            _device,
+            _create_info_count,
+            _create_infos,
+            _allocator,
+            _shaders,
+        ),
+    };
+    hooked.after("vkCreateShadersEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateSharedSwapchainsKHR<const S: usize>(
+    _device: Device,
+    _swapchain_count: u32,
+    _create_infos: *const SwapchainCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _swapchains: *mut SwapchainKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateSharedSwapchainsKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_shared_swapchains_khr)(
+            _device,
+            _swapchain_count,
+            _create_infos,
+            _allocator,
+            _swapchains,
+        ),
+    };
+    hooked.after("vkCreateSharedSwapchainsKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateSwapchainKHR<const S: usize>(
+    _device: Device,
+    _create_info: *const SwapchainCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _swapchain: *mut SwapchainKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateSwapchainKHR") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_swapchain_khr)(_device, _create_info, _allocator, _swapchain)
+        }
+    };
+    hooked.after("vkCreateSwapchainKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateTensorARM<const S: usize>(
+    _device: Device,
+    _create_info: *const TensorCreateInfoARM,
+    _allocator: *const AllocationCallbacks,
+    _tensor: *mut TensorARM,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateTensorARM") {
+        Some(result) => result,
+        None => (hooked.commands.create_tensor_arm)(_device, _create_info, _allocator, _tensor),
+    };
+    hooked.after("vkCreateTensorARM", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateTensorViewARM<const S: usize>(
+    _device: Device,
+    _create_info: *const TensorViewCreateInfoARM,
+    _allocator: *const AllocationCallbacks,
+    _view: *mut TensorViewARM,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateTensorViewARM") {
+        Some(result) => result,
+        None => (hooked.commands.create_tensor_view_arm)(_device, _create_info, _allocator, _view),
+    };
+    hooked.after("vkCreateTensorViewARM", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateValidationCacheEXT<const S: usize>(
+    _device: Device,
+    _create_info: *const ValidationCacheCreateInfoEXT,
+    _allocator: *const AllocationCallbacks,
+    _validation_cache: *mut ValidationCacheEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateValidationCacheEXT") {
+        Some(result) => result,
+        None => (hooked.commands.create_validation_cache_ext)(
+            _device,
+            _create_info,
+            _allocator,
+            _validation_cache,
+        ),
+    };
+    hooked.after("vkCreateValidationCacheEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateVideoSessionKHR<const S: usize>(
+    _device: Device,
+    _create_info: *const VideoSessionCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _video_session: *mut VideoSessionKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateVideoSessionKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_video_session_khr)(
+            _device,
+            _create_info,
+            _allocator,
+            _video_session,
+        ),
+    };
+    hooked.after("vkCreateVideoSessionKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateVideoSessionParametersKHR<const S: usize>(
+    _device: Device,
+    _create_info: *const VideoSessionParametersCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _video_session_parameters: *mut VideoSessionParametersKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkCreateVideoSessionParametersKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_video_session_parameters_khr)(
+            _device,
+            _create_info,
+            _allocator,
+            _video_session_parameters,
+        ),
+    };
+    hooked.after("vkCreateVideoSessionParametersKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkDebugMarkerSetObjectNameEXT<const S: usize>(
+    _device: Device,
+    _name_info: *const DebugMarkerObjectNameInfoEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkDebugMarkerSetObjectNameEXT") {
+        Some(result) => result,
+        None => (hooked.commands.debug_marker_set_object_name_ext)(_device, _name_info),
+    };
+    hooked.after("vkDebugMarkerSetObjectNameEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkDebugMarkerSetObjectTagEXT<const S: usize>(
+    _device: Device,
+    _tag_info: *const DebugMarkerObjectTagInfoEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkDebugMarkerSetObjectTagEXT") {
+        Some(result) => result,
+        None => (hooked.commands.debug_marker_set_object_tag_ext)(_device, _tag_info),
+    };
+    hooked.after("vkDebugMarkerSetObjectTagEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkDeferredOperationJoinKHR<const S: usize>(
+    _device: Device,
+    _operation: DeferredOperationKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkDeferredOperationJoinKHR") {
+        Some(result) => result,
+        None => (hooked.commands.deferred_operation_join_khr)(_device, _operation),
+    };
+    hooked.after("vkDeferredOperationJoinKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkDestroyAccelerationStructureKHR<const S: usize>(
+    _device: Device,
+    _acceleration_structure: AccelerationStructureKHR,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyAccelerationStructureKHR");
+    (hooked.commands.destroy_acceleration_structure_khr)(
+        _device,
+        _acceleration_structure,
+        _allocator,
+    );
+    hooked.after("vkDestroyAccelerationStructureKHR", None);
+}
+
+unsafe extern "system" fn vkDestroyAccelerationStructureNV<const S: usize>(
+    _device: Device,
+    _acceleration_structure: AccelerationStructureNV,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyAccelerationStructureNV");
+    (hooked.commands.destroy_acceleration_structure_nv)(
+        _device,
+        _acceleration_structure,
+        _allocator,
+    );
+    hooked.after("vkDestroyAccelerationStructureNV", None);
+}
+
+unsafe extern "system" fn vkDestroyBuffer<const S: usize>(
+    _device: Device,
+    _buffer: Buffer,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyBuffer");
+    (hooked.commands.destroy_buffer)(_device, _buffer, _allocator);
+    hooked.after("vkDestroyBuffer", None);
+}
+
+unsafe extern "system" fn vkDestroyBufferCollectionFUCHSIA<const S: usize>(
+    _device: Device,
+    _collection: BufferCollectionFUCHSIA,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyBufferCollectionFUCHSIA");
+    (hooked.commands.destroy_buffer_collection_fuchsia)(_device, _collection, _allocator);
+    hooked.after("vkDestroyBufferCollectionFUCHSIA", None);
+}
+
+unsafe extern "system" fn vkDestroyBufferView<const S: usize>(
+    _device: Device,
+    _buffer_view: BufferView,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyBufferView");
+    (hooked.commands.destroy_buffer_view)(_device, _buffer_view, _allocator);
+    hooked.after("vkDestroyBufferView", None);
+}
+
+unsafe extern "system" fn vkDestroyCommandPool<const S: usize>(
+    _device: Device,
+    _command_pool: CommandPool,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyCommandPool");
+    (hooked.commands.destroy_command_pool)(_device, _command_pool, _allocator);
+    hooked.after("vkDestroyCommandPool", None);
+}
+
+unsafe extern "system" fn vkDestroyCuFunctionNVX<const S: usize>(
+    _device: Device,
+    _function: CuFunctionNVX,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyCuFunctionNVX");
+    (hooked.commands.destroy_cu_function_nvx)(_device, _function, _allocator);
+    hooked.after("vkDestroyCuFunctionNVX", None);
+}
+
+unsafe extern "system" fn vkDestroyCuModuleNVX<const S: usize>(
+    _device: Device,
+    _module: CuModuleNVX,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyCuModuleNVX");
+    (hooked.commands.destroy_cu_module_nvx)(_device, _module, _allocator);
+    hooked.after("vkDestroyCuModuleNVX", None);
+}
+
+unsafe extern "system" fn vkDestroyCudaFunctionNV<const S: usize>(
+    _device: Device,
+    _function: CudaFunctionNV,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyCudaFunctionNV");
+    (hooked.commands.destroy_cuda_function_nv)(_device, _function, _allocator);
+    hooked.after("vkDestroyCudaFunctionNV", None);
+}
+
+unsafe extern "system" fn vkDestroyCudaModuleNV<const S: usize>(
+    _device: Device,
+    _module: CudaModuleNV,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyCudaModuleNV");
+    (hooked.commands.destroy_cuda_module_nv)(_device, _module, _allocator);
+    hooked.after("vkDestroyCudaModuleNV", None);
+}
+
+unsafe extern "system" fn vkDestroyDataGraphPipelineSessionARM<const S: usize>(
+    _device: Device,
+    _session: DataGraphPipelineSessionARM,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyDataGraphPipelineSessionARM");
+    (hooked.commands.destroy_data_graph_pipeline_session_arm)(_device, _session, _allocator);
+    hooked.after("vkDestroyDataGraphPipelineSessionARM", None);
+}
+
+unsafe extern "system" fn vkDestroyDeferredOperationKHR<const S: usize>(
+    _device: Device,
+    _operation: DeferredOperationKHR,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyDeferredOperationKHR");
+    (hooked.commands.destroy_deferred_operation_khr)(_device, _operation, _allocator);
+    hooked.after("vkDestroyDeferredOperationKHR", None);
+}
+
+unsafe extern "system" fn vkDestroyDescriptorPool<const S: usize>(
+    _device: Device,
+    _descriptor_pool: DescriptorPool,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyDescriptorPool");
+    (hooked.commands.destroy_descriptor_pool)(_device, _descriptor_pool, _allocator);
+    hooked.after("vkDestroyDescriptorPool", None);
+}
+
+unsafe extern "system" fn vkDestroyDescriptorSetLayout<const S: usize>(
+    _device: Device,
+    _descriptor_set_layout: DescriptorSetLayout,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyDescriptorSetLayout");
+    (hooked.commands.destroy_descriptor_set_layout)(_device, _descriptor_set_layout, _allocator);
+    hooked.after("vkDestroyDescriptorSetLayout", None);
+}
+
+unsafe extern "system" fn vkDestroyDescriptorUpdateTemplate<const S: usize>(
+    _device: Device,
+    _descriptor_update_template: DescriptorUpdateTemplate,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyDescriptorUpdateTemplate");
+    (hooked.commands.destroy_descriptor_update_template)(
+        _device,
+        _descriptor_update_template,
+        _allocator,
+    );
+    hooked.after("vkDestroyDescriptorUpdateTemplate", None);
+}
+
+unsafe extern "system" fn vkDestroyDescriptorUpdateTemplateKHR<const S: usize>(
+    _device: Device,
+    _descriptor_update_template: DescriptorUpdateTemplate,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyDescriptorUpdateTemplateKHR");
+    (hooked.commands.destroy_descriptor_update_template_khr)(
+        _device,
+        _descriptor_update_template,
+        _allocator,
+    );
+    hooked.after("vkDestroyDescriptorUpdateTemplateKHR", None);
+}
+
+unsafe extern "system" fn vkDestroyDevice<const S: usize>(
+    _device: Device,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyDevice");
+    (hooked.commands.destroy_device)(_device, _allocator);
+    hooked.after("vkDestroyDevice", None);
+}
+
+unsafe extern "system" fn vkDestroyEvent<const S: usize>(
+    _device: Device,
+    _event: Event,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyEvent");
+    (hooked.commands.destroy_event)(_device, _event, _allocator);
+    hooked.after("vkDestroyEvent", None);
+}
+
+unsafe extern "system" fn vkDestroyExternalComputeQueueNV<const S: usize>(
+    _device: Device,
+    _external_queue: ExternalComputeQueueNV,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyExternalComputeQueueNV");
+    (hooked.commands.destroy_external_compute_queue_nv)(_device, _external_queue, _allocator);
+    hooked.after("vkDestroyExternalComputeQueueNV", None);
+}
+
+unsafe extern "system" fn vkDestroyFence<const S: usize>(
+    _device: Device,
+    _fence: Fence,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyFence");
+    (hooked.commands.destroy_fence)(_device, _fence, _allocator);
+    hooked.after("vkDestroyFence", None);
+}
+
+unsafe extern "system" fn vkDestroyFramebuffer<const S: usize>(
+    _device: Device,
+    _framebuffer: Framebuffer,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyFramebuffer");
+    (hooked.commands.destroy_framebuffer)(_device, _framebuffer, _allocator);
+    hooked.after("vkDestroyFramebuffer", None);
+}
+
+unsafe extern "system" fn vkDestroyImage<const S: usize>(
+    _device: Device,
+    _image: Image,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyImage");
+    (hooked.commands.destroy_image)(_device, _image, _allocator);
+    hooked.after("vkDestroyImage", None);
+}
+
+unsafe extern "system" fn vkDestroyImageView<const S: usize>(
+    _device: Device,
+    _image_view: ImageView,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyImageView");
+    (hooked.commands.destroy_image_view)(_device, _image_view, _allocator);
+    hooked.after("vkDestroyImageView", None);
+}
+
+unsafe extern "system" fn vkDestroyIndirectCommandsLayoutEXT<const S: usize>(
+    _device: Device,
+    _indirect_commands_layout: IndirectCommandsLayoutEXT,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyIndirectCommandsLayoutEXT");
+    (hooked.commands.destroy_indirect_commands_layout_ext)(
+        _device,
+        _indirect_commands_layout,
+        _allocator,
+    );
+    hooked.after("vkDestroyIndirectCommandsLayoutEXT", None);
+}
+
+unsafe extern "system" fn vkDestroyIndirectCommandsLayoutNV<const S: usize>(
+    _device: Device,
+    _indirect_commands_layout: IndirectCommandsLayoutNV,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyIndirectCommandsLayoutNV");
+    (hooked.commands.destroy_indirect_commands_layout_nv)(
+        _device,
+        _indirect_commands_layout,
+        _allocator,
+    );
+    hooked.after("vkDestroyIndirectCommandsLayoutNV", None);
+}
+
+unsafe extern "system" fn vkDestroyIndirectExecutionSetEXT<const S: usize>(
+    _device: Device,
+    _indirect_execution_set: IndirectExecutionSetEXT,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyIndirectExecutionSetEXT");
+    (hooked.commands.destroy_indirect_execution_set_ext)(
+        _device,
+        _indirect_execution_set,
+        _allocator,
+    );
+    hooked.after("vkDestroyIndirectExecutionSetEXT", None);
+}
+
+unsafe extern "system" fn vkDestroyMicromapEXT<const S: usize>(
+    _device: Device,
+    _micromap: MicromapEXT,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyMicromapEXT");
+    (hooked.commands.destroy_micromap_ext)(_device, _micromap, _allocator);
+    hooked.after("vkDestroyMicromapEXT", None);
+}
+
+unsafe extern "system" fn vkDestroyOpticalFlowSessionNV<const S: usize>(
+    _device: Device,
+    _session: OpticalFlowSessionNV,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyOpticalFlowSessionNV");
+    (hooked.commands.destroy_optical_flow_session_nv)(_device, _session, _allocator);
+    hooked.after("vkDestroyOpticalFlowSessionNV", None);
+}
+
+unsafe extern "system" fn vkDestroyPipeline<const S: usize>(
+    _device: Device,
+    _pipeline: Pipeline,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyPipeline");
+    (hooked.commands.destroy_pipeline)(_device, _pipeline, _allocator);
+    hooked.after("vkDestroyPipeline", None);
+}
+
+unsafe extern "system" fn vkDestroyPipelineBinaryKHR<const S: usize>(
+    _device: Device,
+    _pipeline_binary: PipelineBinaryKHR,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyPipelineBinaryKHR");
+    (hooked.commands.destroy_pipeline_binary_khr)(_device, _pipeline_binary, _allocator);
+    hooked.after("vkDestroyPipelineBinaryKHR", None);
+}
+
+unsafe extern "system" fn vkDestroyPipelineCache<const S: usize>(
+    _device: Device,
+    _pipeline_cache: PipelineCache,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyPipelineCache");
+    (hooked.commands.destroy_pipeline_cache)(_device, _pipeline_cache, _allocator);
+    hooked.after("vkDestroyPipelineCache", None);
+}
+
+unsafe extern "system" fn vkDestroyPipelineLayout<const S: usize>(
+    _device: Device,
+    _pipeline_layout: PipelineLayout,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyPipelineLayout");
+    (hooked.commands.destroy_pipeline_layout)(_device, _pipeline_layout, _allocator);
+    hooked.after("vkDestroyPipelineLayout", None);
+}
+
+unsafe extern "system" fn vkDestroyPrivateDataSlot<const S: usize>(
+    _device: Device,
+    _private_data_slot: PrivateDataSlot,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyPrivateDataSlot");
+    (hooked.commands.destroy_private_data_slot)(_device, _private_data_slot, _allocator);
+    hooked.after("vkDestroyPrivateDataSlot", None);
+}
+
+unsafe extern "system" fn vkDestroyPrivateDataSlotEXT<const S: usize>(
+    _device: Device,
+    _private_data_slot: PrivateDataSlot,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyPrivateDataSlotEXT");
+    (hooked.commands.destroy_private_data_slot_ext)(_device, _private_data_slot, _allocator);
+    hooked.after("vkDestroyPrivateDataSlotEXT", None);
+}
+
+unsafe extern "system" fn vkDestroyQueryPool<const S: usize>(
+    _device: Device,
+    _query_pool: QueryPool,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyQueryPool");
+    (hooked.commands.destroy_query_pool)(_device, _query_pool, _allocator);
+    hooked.after("vkDestroyQueryPool", None);
+}
+
+unsafe extern "system" fn vkDestroyRenderPass<const S: usize>(
+    _device: Device,
+    _render_pass: RenderPass,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyRenderPass");
+    (hooked.commands.destroy_render_pass)(_device, _render_pass, _allocator);
+    hooked.after("vkDestroyRenderPass", None);
+}
+
+unsafe extern "system" fn vkDestroySampler<const S: usize>(
+    _device: Device,
+    _sampler: Sampler,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroySampler");
+    (hooked.commands.destroy_sampler)(_device, _sampler, _allocator);
+    hooked.after("vkDestroySampler", None);
+}
+
+unsafe extern "system" fn vkDestroySamplerYcbcrConversion<const S: usize>(
+    _device: Device,
+    _ycbcr_conversion: SamplerYcbcrConversion,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroySamplerYcbcrConversion");
+    (hooked.commands.destroy_sampler_ycbcr_conversion)(_device, _ycbcr_conversion, _allocator);
+    hooked.after("vkDestroySamplerYcbcrConversion", None);
+}
+
+unsafe extern "system" fn vkDestroySamplerYcbcrConversionKHR<const S: usize>(
+    _device: Device,
+    _ycbcr_conversion: SamplerYcbcrConversion,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroySamplerYcbcrConversionKHR");
+    (hooked.commands.destroy_sampler_ycbcr_conversion_khr)(_device, _ycbcr_conversion, _allocator);
+    hooked.after("vkDestroySamplerYcbcrConversionKHR", None);
+}
+
+unsafe extern "system" fn vkDestroySemaphore<const S: usize>(
+    _device: Device,
+    _semaphore: Semaphore,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroySemaphore");
+    (hooked.commands.destroy_semaphore)(_device, _semaphore, _allocator);
+    hooked.after("vkDestroySemaphore", None);
+}
+
+unsafe extern "system" fn vkDestroySemaphoreSciSyncPoolNV<const S: usize>(
+    _device: Device,
+    _semaphore_pool: SemaphoreSciSyncPoolNV,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroySemaphoreSciSyncPoolNV");
+    (hooked.commands.destroy_semaphore_sci_sync_pool_nv)(_device, _semaphore_pool, _allocator);
+    hooked.after("vkDestroySemaphoreSciSyncPoolNV", None);
+}
+
+unsafe extern "system" fn vkDestroyShaderEXT<const S: usize>(
+    _device: Device,
+    _shader: ShaderEXT,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyShaderEXT");
+    (hooked.commands.destroy_shader_ext)(_device, _shader, _allocator);
+    hooked.after("vkDestroyShaderEXT", None);
+}
+
+unsafe extern "system" fn vkDestroyShaderModule<const S: usize>(
+    _device: Device,
+    _shader_module: ShaderModule,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyShaderModule");
+    (hooked.commands.destroy_shader_module)(_device, _shader_module, _allocator);
+    hooked.after("vkDestroyShaderModule", None);
+}
+
+unsafe extern "system" fn vkDestroySwapchainKHR<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroySwapchainKHR");
+    (hooked.commands.destroy_swapchain_khr)(_device, _swapchain, _allocator);
+    hooked.after("vkDestroySwapchainKHR", None);
+}
+
+unsafe extern "system" fn vkDestroyTensorARM<const S: usize>(
+    _device: Device,
+    _tensor: TensorARM,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyTensorARM");
+    (hooked.commands.destroy_tensor_arm)(_device, _tensor, _allocator);
+    hooked.after("vkDestroyTensorARM", None);
+}
+
+unsafe extern "system" fn vkDestroyTensorViewARM<const S: usize>(
+    _device: Device,
+    _tensor_view: TensorViewARM,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyTensorViewARM");
+    (hooked.commands.destroy_tensor_view_arm)(_device, _tensor_view, _allocator);
+    hooked.after("vkDestroyTensorViewARM", None);
+}
+
+unsafe extern "system" fn vkDestroyValidationCacheEXT<const S: usize>(
+    _device: Device,
+    _validation_cache: ValidationCacheEXT,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyValidationCacheEXT");
+    (hooked.commands.destroy_validation_cache_ext)(_device, _validation_cache, _allocator);
+    hooked.after("vkDestroyValidationCacheEXT", None);
+}
+
+unsafe extern "system" fn vkDestroyVideoSessionKHR<const S: usize>(
+    _device: Device,
+    _video_session: VideoSessionKHR,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyVideoSessionKHR");
+    (hooked.commands.destroy_video_session_khr)(_device, _video_session, _allocator);
+    hooked.after("vkDestroyVideoSessionKHR", None);
+}
+
+unsafe extern "system" fn vkDestroyVideoSessionParametersKHR<const S: usize>(
+    _device: Device,
+    _video_session_parameters: VideoSessionParametersKHR,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkDestroyVideoSessionParametersKHR");
+    (hooked.commands.destroy_video_session_parameters_khr)(
+        _device,
+        _video_session_parameters,
+        _allocator,
+    );
+    hooked.after("vkDestroyVideoSessionParametersKHR", None);
+}
+
+unsafe extern "system" fn vkDeviceWaitIdle<const S: usize>(_device: Device) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkDeviceWaitIdle") {
+        Some(result) => result,
+        None => (hooked.commands.device_wait_idle)(_device),
+    };
+    hooked.after("vkDeviceWaitIdle", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkDisplayPowerControlEXT<const S: usize>(
+    _device: Device,
+    _display: DisplayKHR,
+    _display_power_info: *const DisplayPowerInfoEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkDisplayPowerControlEXT") {
+        Some(result) => result,
+        None => (hooked.commands.display_power_control_ext)(_device, _display, _display_power_info),
+    };
+    hooked.after("vkDisplayPowerControlEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkEndCommandBuffer<const S: usize>(
+    _command_buffer: CommandBuffer,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkEndCommandBuffer") {
+        Some(result) => result,
+        None => (hooked.commands.end_command_buffer)(_command_buffer),
+    };
+    hooked.after("vkEndCommandBuffer", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkExportMetalObjectsEXT<const S: usize>(
+    _device: Device,
+    _metal_objects_info: *mut ExportMetalObjectsInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkExportMetalObjectsEXT");
+    (hooked.commands.export_metal_objects_ext)(_device, _metal_objects_info);
+    hooked.after("vkExportMetalObjectsEXT", None);
+}
+
+unsafe extern "system" fn vkFlushMappedMemoryRanges<const S: usize>(
+    _device: Device,
+    _memory_range_count: u32,
+    _memory_ranges: *const MappedMemoryRange,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkFlushMappedMemoryRanges") {
+        Some(result) => result,
+        None => (hooked.commands.flush_mapped_memory_ranges)(
+            _device,
+            _memory_range_count,
+            _memory_ranges,
+        ),
+    };
+    hooked.after("vkFlushMappedMemoryRanges", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkFreeCommandBuffers<const S: usize>(
+    _device: Device,
+    _command_pool: CommandPool,
+    _command_buffer_count: u32,
+    _command_buffers: *const CommandBuffer,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkFreeCommandBuffers");
+    (hooked.commands.free_command_buffers)(
+        _device,
+        _command_pool,
+        _command_buffer_count,
+        _command_buffers,
+    );
+    hooked.after("vkFreeCommandBuffers", None);
+}
+
+unsafe extern "system" fn vkFreeDescriptorSets<const S: usize>(
+    _device: Device,
+    _descriptor_pool: DescriptorPool,
+    _descriptor_set_count: u32,
+    _descriptor_sets: *const DescriptorSet,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkFreeDescriptorSets") {
+        Some(result) => result,
+        None => (hooked.commands.free_descriptor_sets)(
+            _device,
+            _descriptor_pool,
+            _descriptor_set_count,
+            _descriptor_sets,
+        ),
+    };
+    hooked.after("vkFreeDescriptorSets", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkFreeMemory<const S: usize>(
+    _device: Device,
+    _memory: DeviceMemory,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkFreeMemory");
+    (hooked.commands.free_memory)(_device, _memory, _allocator);
+    hooked.after("vkFreeMemory", None);
+}
+
+unsafe extern "system" fn vkGetAccelerationStructureBuildSizesKHR<const S: usize>(
+    _device: Device,
+    _build_type: AccelerationStructureBuildTypeKHR,
+    _build_info: *const AccelerationStructureBuildGeometryInfoKHR,
+    _max_primitive_counts: *const u32,
+    _size_info: *mut AccelerationStructureBuildSizesInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetAccelerationStructureBuildSizesKHR");
+    (hooked.commands.get_acceleration_structure_build_sizes_khr)(
+        _device,
+        _build_type,
+        _build_info,
+        _max_primitive_counts,
+        _size_info,
+    );
+    hooked.after("vkGetAccelerationStructureBuildSizesKHR", None);
+}
+
+unsafe extern "system" fn vkGetAccelerationStructureDeviceAddressKHR<const S: usize>(
+    _device: Device,
+    _info: *const AccelerationStructureDeviceAddressInfoKHR,
+) -> DeviceAddress {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetAccelerationStructureDeviceAddressKHR");
+    let __result = (hooked
+        .commands
+        .get_acceleration_structure_device_address_khr)(_device, _info);
+    hooked.after("vkGetAccelerationStructureDeviceAddressKHR", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetAccelerationStructureHandleNV<const S: usize>(
+    _device: Device,
+    _acceleration_structure: AccelerationStructureNV,
+    _data_size: usize,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetAccelerationStructureHandleNV") {
+        Some(result) => result,
+        None => (hooked.commands.get_acceleration_structure_handle_nv)(
+            _device,
+            _acceleration_structure,
+            _data_size,
+            _data,
+        ),
+    };
+    hooked.after("vkGetAccelerationStructureHandleNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetAccelerationStructureMemoryRequirementsNV<const S: usize>(
+    _device: Device,
+    _info: *const AccelerationStructureMemoryRequirementsInfoNV,
+    _memory_requirements: *mut MemoryRequirements2KHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetAccelerationStructureMemoryRequirementsNV");
+    (hooked
+        .commands
+        .get_acceleration_structure_memory_requirements_nv)(
+        _device, _info, _memory_requirements
+    );
+    hooked.after("vkGetAccelerationStructureMemoryRequirementsNV", None);
+}
+
+unsafe extern "system" fn vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT<
+    const S: usize,
+>(
+    _device: Device,
+    _info: *const AccelerationStructureCaptureDescriptorDataInfoEXT,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_acceleration_structure_opaque_capture_descriptor_data_ext)(
+            _device, _info, _data
+        ),
+    };
+    hooked.after(
+        "vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetAndroidHardwareBufferPropertiesANDROID<const S: usize>(
+    _device: Device,
+    _buffer: *const AHardwareBuffer,
+    _properties: *mut AndroidHardwareBufferPropertiesANDROID,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetAndroidHardwareBufferPropertiesANDROID") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_android_hardware_buffer_properties_android)(
+            _device, _buffer, _properties
+        ),
+    };
+    hooked.after(
+        "vkGetAndroidHardwareBufferPropertiesANDROID",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetBufferCollectionPropertiesFUCHSIA<const S: usize>(
+    _device: Device,
+    _collection: BufferCollectionFUCHSIA,
+    _properties: *mut BufferCollectionPropertiesFUCHSIA,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetBufferCollectionPropertiesFUCHSIA") {
+        Some(result) => result,
+        None => (hooked.commands.get_buffer_collection_properties_fuchsia)(
+            _device,
+            _collection,
+            _properties,
+        ),
+    };
+    hooked.after("vkGetBufferCollectionPropertiesFUCHSIA", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetBufferDeviceAddress<const S: usize>(
+    _device: Device,
+    _info: *const BufferDeviceAddressInfo,
+) -> DeviceAddress {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetBufferDeviceAddress");
+    let __result = (hooked.commands.get_buffer_device_address)(_device, _info);
+    hooked.after("vkGetBufferDeviceAddress", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetBufferDeviceAddressEXT<const S: usize>(
+    _device: Device,
+    _info: *const BufferDeviceAddressInfo,
+) -> DeviceAddress {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetBufferDeviceAddressEXT");
+    let __result = (hooked.commands.get_buffer_device_address_ext)(_device, _info);
+    hooked.after("vkGetBufferDeviceAddressEXT", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetBufferDeviceAddressKHR<const S: usize>(
+    _device: Device,
+    _info: *const BufferDeviceAddressInfo,
+) -> DeviceAddress {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetBufferDeviceAddressKHR");
+    let __result = (hooked.commands.get_buffer_device_address_khr)(_device, _info);
+    hooked.after("vkGetBufferDeviceAddressKHR", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetBufferMemoryRequirements<const S: usize>(
+    _device: Device,
+    _buffer: Buffer,
+    _memory_requirements: *mut MemoryRequirements,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetBufferMemoryRequirements");
+    (hooked.commands.get_buffer_memory_requirements)(_device, _buffer, _memory_requirements);
+    hooked.after("vkGetBufferMemoryRequirements", None);
+}
+
+unsafe extern "system" fn vkGetBufferMemoryRequirements2<const S: usize>(
+    _device: Device,
+    _info: *const BufferMemoryRequirementsInfo2,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetBufferMemoryRequirements2");
+    (hooked.commands.get_buffer_memory_requirements2)(_device, _info, _memory_requirements);
+    hooked.after("vkGetBufferMemoryRequirements2", None);
+}
+
+unsafe extern "system" fn vkGetBufferMemoryRequirements2KHR<const S: usize>(
+    _device: Device,
+    _info: *const BufferMemoryRequirementsInfo2,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetBufferMemoryRequirements2KHR");
+    (hooked.commands.get_buffer_memory_requirements2_khr)(_device, _info, _memory_requirements);
+    hooked.after("vkGetBufferMemoryRequirements2KHR", None);
+}
+
+unsafe extern "system" fn vkGetBufferOpaqueCaptureAddress<const S: usize>(
+    _device: Device,
+    _info: *const BufferDeviceAddressInfo,
+) -> u64 {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetBufferOpaqueCaptureAddress");
+    let __result = (hooked.commands.get_buffer_opaque_capture_address)(_device, _info);
+    hooked.after("vkGetBufferOpaqueCaptureAddress", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetBufferOpaqueCaptureAddressKHR<const S: usize>(
+    _device: Device,
+    _info: *const BufferDeviceAddressInfo,
+) -> u64 {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetBufferOpaqueCaptureAddressKHR");
+    let __result = (hooked.commands.get_buffer_opaque_capture_address_khr)(_device, _info);
+    hooked.after("vkGetBufferOpaqueCaptureAddressKHR", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetBufferOpaqueCaptureDescriptorDataEXT<const S: usize>(
+    _device: Device,
+    _info: *const BufferCaptureDescriptorDataInfoEXT,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetBufferOpaqueCaptureDescriptorDataEXT") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_buffer_opaque_capture_descriptor_data_ext)(_device, _info, _data),
+    };
+    hooked.after("vkGetBufferOpaqueCaptureDescriptorDataEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetCalibratedTimestampsEXT<const S: usize>(
+    _device: Device,
+    _timestamp_count: u32,
+    _timestamp_infos: *const CalibratedTimestampInfoKHR,
+    _timestamps: *mut u64,
+    _max_deviation: *mut u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetCalibratedTimestampsEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_calibrated_timestamps_ext)(
+            _device,
+            _timestamp_count,
+            _timestamp_infos,
+            _timestamps,
+            _max_deviation,
+        ),
+    };
+    hooked.after("vkGetCalibratedTimestampsEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetCalibratedTimestampsKHR<const S: usize>(
+    _device: Device,
+    _timestamp_count: u32,
+    _timestamp_infos: *const CalibratedTimestampInfoKHR,
+    _timestamps: *mut u64,
+    _max_deviation: *mut u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetCalibratedTimestampsKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_calibrated_timestamps_khr)(
+            _device,
+            _timestamp_count,
+            _timestamp_infos,
+            _timestamps,
+            _max_deviation,
+        ),
+    };
+    hooked.after("vkGetCalibratedTimestampsKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetClusterAccelerationStructureBuildSizesNV<const S: usize>(
+    _device: Device,
+    _info: *const ClusterAccelerationStructureInputInfoNV,
+    _size_info: *mut AccelerationStructureBuildSizesInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetClusterAccelerationStructureBuildSizesNV");
+    (hooked
+        .commands
+        .get_cluster_acceleration_structure_build_sizes_nv)(_device, _info, _size_info);
+    hooked.after("vkGetClusterAccelerationStructureBuildSizesNV", None);
+}
+
+unsafe extern "system" fn vkGetCudaModuleCacheNV<const S: usize>(
+    _device: Device,
+    _module: CudaModuleNV,
+    _cache_size: *mut usize,
+    _cache_data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetCudaModuleCacheNV") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_cuda_module_cache_nv)(_device, _module, _cache_size, _cache_data)
+        }
+    };
+    hooked.after("vkGetCudaModuleCacheNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDataGraphPipelineAvailablePropertiesARM<const S: usize>(
+    _device: Device,
+    _pipeline_info: *const DataGraphPipelineInfoARM,
+    _properties_count: *mut u32,
+    _properties: *mut DataGraphPipelinePropertyARM,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetDataGraphPipelineAvailablePropertiesARM") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_data_graph_pipeline_available_properties_arm)(
+            _device,
+            _pipeline_info,
+            _properties_count,
+            _properties,
+        ),
+    };
+    hooked.after(
+        "vkGetDataGraphPipelineAvailablePropertiesARM",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetDataGraphPipelinePropertiesARM<const S: usize>(
+    _device: Device,
+    _pipeline_info: *const DataGraphPipelineInfoARM,
+    _properties_count: u32,
+    _properties: *mut DataGraphPipelinePropertyQueryResultARM,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetDataGraphPipelinePropertiesARM") {
+        Some(result) => result,
+        None => (hooked.commands.get_data_graph_pipeline_properties_arm)(
+            _device,
+            _pipeline_info,
+            _properties_count,
+            _properties,
+        ),
+    };
+    hooked.after("vkGetDataGraphPipelinePropertiesARM", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDataGraphPipelineSessionBindPointRequirementsARM<const S: usize>(
+    _device: Device,
+    _info: *const DataGraphPipelineSessionBindPointRequirementsInfoARM,
+    _bind_point_requirement_count: *mut u32,
+    _bind_point_requirements: *mut DataGraphPipelineSessionBindPointRequirementARM,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetDataGraphPipelineSessionBindPointRequirementsARM") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_data_graph_pipeline_session_bind_point_requirements_arm)(
+            _device,
+            _info,
+            _bind_point_requirement_count,
+            _bind_point_requirements,
+        ),
+    };
+    hooked.after(
+        "vkGetDataGraphPipelineSessionBindPointRequirementsARM",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetDataGraphPipelineSessionMemoryRequirementsARM<const S: usize>(
+    _device: Device,
+    _info: *const DataGraphPipelineSessionMemoryRequirementsInfoARM,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDataGraphPipelineSessionMemoryRequirementsARM");
+    (hooked
+        .commands
+        .get_data_graph_pipeline_session_memory_requirements_arm)(
+        _device,
+        _info,
+        _memory_requirements,
+    );
+    hooked.after("vkGetDataGraphPipelineSessionMemoryRequirementsARM", None);
+}
+
+unsafe extern "system" fn vkGetDeferredOperationMaxConcurrencyKHR<const S: usize>(
+    _device: Device,
+    _operation: DeferredOperationKHR,
+) -> u32 {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeferredOperationMaxConcurrencyKHR");
+    let __result =
+        (hooked.commands.get_deferred_operation_max_concurrency_khr)(_device, _operation);
+    hooked.after("vkGetDeferredOperationMaxConcurrencyKHR", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetDeferredOperationResultKHR<const S: usize>(
+    _device: Device,
+    _operation: DeferredOperationKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetDeferredOperationResultKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_deferred_operation_result_khr)(_device, _operation),
+    };
+    hooked.after("vkGetDeferredOperationResultKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDescriptorEXT<const S: usize>(
+    _device: Device,
+    _descriptor_info: *const DescriptorGetInfoEXT,
+    _data_size: usize,
+    _descriptor: *mut c_void,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDescriptorEXT");
+    (hooked.commands.get_descriptor_ext)(_device, _descriptor_info, _data_size, _descriptor);
+    hooked.after("vkGetDescriptorEXT", None);
+}
+
+unsafe extern "system" fn vkGetDescriptorSetHostMappingVALVE<const S: usize>(
+    _device: Device,
+    _descriptor_set: DescriptorSet,
+    _data: *mut *mut c_void,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDescriptorSetHostMappingVALVE");
+    (hooked.commands.get_descriptor_set_host_mapping_valve)(_device, _descriptor_set, _data);
+    hooked.after("vkGetDescriptorSetHostMappingVALVE", None);
+}
+
+unsafe extern "system" fn vkGetDescriptorSetLayoutBindingOffsetEXT<const S: usize>(
+    _device: Device,
+    _layout: DescriptorSetLayout,
+    _binding: u32,
+    _offset: *mut DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDescriptorSetLayoutBindingOffsetEXT");
+    (hooked.commands.get_descriptor_set_layout_binding_offset_ext)(
+        _device, _layout, _binding, _offset,
+    );
+    hooked.after("vkGetDescriptorSetLayoutBindingOffsetEXT", None);
+}
+
+unsafe extern "system" fn vkGetDescriptorSetLayoutHostMappingInfoVALVE<const S: usize>(
+    _device: Device,
+    _binding_reference: *const DescriptorSetBindingReferenceVALVE,
+    _host_mapping: *mut DescriptorSetLayoutHostMappingInfoVALVE,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDescriptorSetLayoutHostMappingInfoVALVE");
+    (hooked
+        .commands
+        .get_descriptor_set_layout_host_mapping_info_valve)(
+        _device,
+        _binding_reference,
+        _host_mapping,
+    );
+    hooked.after("vkGetDescriptorSetLayoutHostMappingInfoVALVE", None);
+}
+
+unsafe extern "system" fn vkGetDescriptorSetLayoutSizeEXT<const S: usize>(
+    _device: Device,
+    _layout: DescriptorSetLayout,
+    _layout_size_in_bytes: *mut DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDescriptorSetLayoutSizeEXT");
+    (hooked.commands.get_descriptor_set_layout_size_ext)(_device, _layout, _layout_size_in_bytes);
+    hooked.after("vkGetDescriptorSetLayoutSizeEXT", None);
+}
+
+unsafe extern "system" fn vkGetDescriptorSetLayoutSupport<const S: usize>(
+    _device: Device,
+    _create_info: *const DescriptorSetLayoutCreateInfo,
+    _support: *mut DescriptorSetLayoutSupport,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDescriptorSetLayoutSupport");
+    (hooked.commands.get_descriptor_set_layout_support)(_device, _create_info, _support);
+    hooked.after("vkGetDescriptorSetLayoutSupport", None);
+}
+
+unsafe extern "system" fn vkGetDescriptorSetLayoutSupportKHR<const S: usize>(
+    _device: Device,
+    _create_info: *const DescriptorSetLayoutCreateInfo,
+    _support: *mut DescriptorSetLayoutSupport,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDescriptorSetLayoutSupportKHR");
+    (hooked.commands.get_descriptor_set_layout_support_khr)(_device, _create_info, _support);
+    hooked.after("vkGetDescriptorSetLayoutSupportKHR", None);
+}
+
+unsafe extern "system" fn vkGetDeviceAccelerationStructureCompatibilityKHR<const S: usize>(
+    _device: Device,
+    _version_info: *const AccelerationStructureVersionInfoKHR,
+    _compatibility: *mut AccelerationStructureCompatibilityKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceAccelerationStructureCompatibilityKHR");
+    (hooked
+        .commands
+        .get_device_acceleration_structure_compatibility_khr)(
+        _device,
+        _version_info,
+        _compatibility,
+    );
+    hooked.after("vkGetDeviceAccelerationStructureCompatibilityKHR", None);
+}
+
+unsafe extern "system" fn vkGetDeviceBufferMemoryRequirements<const S: usize>(
+    _device: Device,
+    _info: *const DeviceBufferMemoryRequirements,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceBufferMemoryRequirements");
+    (hooked.commands.get_device_buffer_memory_requirements)(_device, _info, _memory_requirements);
+    hooked.after("vkGetDeviceBufferMemoryRequirements", None);
+}
+
+unsafe extern "system" fn vkGetDeviceBufferMemoryRequirementsKHR<const S: usize>(
+    _device: Device,
+    _info: *const DeviceBufferMemoryRequirements,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceBufferMemoryRequirementsKHR");
+    (hooked.commands.get_device_buffer_memory_requirements_khr)(
+        _device,
+        _info,
+        _memory_requirements,
+    );
+    hooked.after("vkGetDeviceBufferMemoryRequirementsKHR", None);
+}
+
+unsafe extern "system" fn vkGetDeviceCombinedImageSamplerIndexNVX<const S: usize>(
+    _device: Device,
+    _image_view_index: u64,
+    _sampler_index: u64,
+) -> u64 {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceCombinedImageSamplerIndexNVX");
+    let __result = (hooked.commands.get_device_combined_image_sampler_index_nvx)(
+        _device,
+        _image_view_index,
+        _sampler_index,
+    );
+    hooked.after("vkGetDeviceCombinedImageSamplerIndexNVX", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetDeviceFaultInfoEXT<const S: usize>(
+    _device: Device,
+    _fault_counts: *mut DeviceFaultCountsEXT,
+    _fault_info: *mut DeviceFaultInfoEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetDeviceFaultInfoEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_device_fault_info_ext)(_device, _fault_counts, _fault_info),
+    };
+    hooked.after("vkGetDeviceFaultInfoEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDeviceGroupPeerMemoryFeatures<const S: usize>(
+    _device: Device,
+    _heap_index: u32,
+    _local_device_index: u32,
+    _remote_device_index: u32,
+    _peer_memory_features: *mut PeerMemoryFeatureFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceGroupPeerMemoryFeatures");
+    (hooked.commands.get_device_group_peer_memory_features)(
+        _device,
+        _heap_index,
+        _local_device_index,
+        _remote_device_index,
+        _peer_memory_features,
+    );
+    hooked.after("vkGetDeviceGroupPeerMemoryFeatures", None);
+}
+
+unsafe extern "system" fn vkGetDeviceGroupPeerMemoryFeaturesKHR<const S: usize>(
+    _device: Device,
+    _heap_index: u32,
+    _local_device_index: u32,
+    _remote_device_index: u32,
+    _peer_memory_features: *mut PeerMemoryFeatureFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceGroupPeerMemoryFeaturesKHR");
+    (hooked.commands.get_device_group_peer_memory_features_khr)(
+        _device,
+        _heap_index,
+        _local_device_index,
+        _remote_device_index,
+        _peer_memory_features,
+    );
+    hooked.after("vkGetDeviceGroupPeerMemoryFeaturesKHR", None);
+}
+
+unsafe extern "system" fn vkGetDeviceGroupPresentCapabilitiesKHR<const S: usize>(
+    _device: Device,
+    _device_group_present_capabilities: *mut DeviceGroupPresentCapabilitiesKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetDeviceGroupPresentCapabilitiesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_device_group_present_capabilities_khr)(
+            _device,
+            _device_group_present_capabilities,
+        ),
+    };
+    hooked.after("vkGetDeviceGroupPresentCapabilitiesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDeviceGroupSurfacePresentModes2EXT<const S: usize>(
+    _device: Device,
+    _surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+    _modes: *mut DeviceGroupPresentModeFlagsKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetDeviceGroupSurfacePresentModes2EXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_device_group_surface_present_modes2_ext)(
+            _device,
+            _surface_info,
+            _modes,
+        ),
+    };
+    hooked.after("vkGetDeviceGroupSurfacePresentModes2EXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDeviceGroupSurfacePresentModesKHR<const S: usize>(
+    _device: Device,
+    _surface: SurfaceKHR,
+    _modes: *mut DeviceGroupPresentModeFlagsKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetDeviceGroupSurfacePresentModesKHR") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_device_group_surface_present_modes_khr)(_device, _surface, _modes)
+        }
+    };
+    hooked.after("vkGetDeviceGroupSurfacePresentModesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDeviceImageMemoryRequirements<const S: usize>(
+    _device: Device,
+    _info: *const DeviceImageMemoryRequirements,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceImageMemoryRequirements");
+    (hooked.commands.get_device_image_memory_requirements)(_device, _info, _memory_requirements);
+    hooked.after("vkGetDeviceImageMemoryRequirements", None);
+}
+
+unsafe extern "system" fn vkGetDeviceImageMemoryRequirementsKHR<const S: usize>(
+    _device: Device,
+    _info: *const DeviceImageMemoryRequirements,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceImageMemoryRequirementsKHR");
+    (hooked.commands.get_device_image_memory_requirements_khr)(
+        _device,
+        _info,
+        _memory_requirements,
+    );
+    hooked.after("vkGetDeviceImageMemoryRequirementsKHR", None);
+}
+
+unsafe extern "system" fn vkGetDeviceImageSparseMemoryRequirements<const S: usize>(
+    _device: Device,
+    _info: *const DeviceImageMemoryRequirements,
+    _sparse_memory_requirement_count: *mut u32,
+    _sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceImageSparseMemoryRequirements");
+    (hooked.commands.get_device_image_sparse_memory_requirements)(
+        _device,
+        _info,
+        _sparse_memory_requirement_count,
+        _sparse_memory_requirements,
+    );
+    hooked.after("vkGetDeviceImageSparseMemoryRequirements", None);
+}
+
+unsafe extern "system" fn vkGetDeviceImageSparseMemoryRequirementsKHR<const S: usize>(
+    _device: Device,
+    _info: *const DeviceImageMemoryRequirements,
+    _sparse_memory_requirement_count: *mut u32,
+    _sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceImageSparseMemoryRequirementsKHR");
+    (hooked
+        .commands
+        .get_device_image_sparse_memory_requirements_khr)(
+        _device,
+        _info,
+        _sparse_memory_requirement_count,
+        _sparse_memory_requirements,
+    );
+    hooked.after("vkGetDeviceImageSparseMemoryRequirementsKHR", None);
+}
+
+unsafe extern "system" fn vkGetDeviceImageSubresourceLayout<const S: usize>(
+    _device: Device,
+    _info: *const DeviceImageSubresourceInfo,
+    _layout: *mut SubresourceLayout2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceImageSubresourceLayout");
+    (hooked.commands.get_device_image_subresource_layout)(_device, _info, _layout);
+    hooked.after("vkGetDeviceImageSubresourceLayout", None);
+}
+
+unsafe extern "system" fn vkGetDeviceImageSubresourceLayoutKHR<const S: usize>(
+    _device: Device,
+    _info: *const DeviceImageSubresourceInfo,
+    _layout: *mut SubresourceLayout2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceImageSubresourceLayoutKHR");
+    (hooked.commands.get_device_image_subresource_layout_khr)(_device, _info, _layout);
+    hooked.after("vkGetDeviceImageSubresourceLayoutKHR", None);
+}
+
+unsafe extern "system" fn vkGetDeviceMemoryCommitment<const S: usize>(
+    _device: Device,
+    _memory: DeviceMemory,
+    _committed_memory_in_bytes: *mut DeviceSize,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceMemoryCommitment");
+    (hooked.commands.get_device_memory_commitment)(_device, _memory, _committed_memory_in_bytes);
+    hooked.after("vkGetDeviceMemoryCommitment", None);
+}
+
+unsafe extern "system" fn vkGetDeviceMemoryOpaqueCaptureAddress<const S: usize>(
+    _device: Device,
+    _info: *const DeviceMemoryOpaqueCaptureAddressInfo,
+) -> u64 {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceMemoryOpaqueCaptureAddress");
+    let __result = (hooked.commands.get_device_memory_opaque_capture_address)(_device, _info);
+    hooked.after("vkGetDeviceMemoryOpaqueCaptureAddress", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetDeviceMemoryOpaqueCaptureAddressKHR<const S: usize>(
+    _device: Device,
+    _info: *const DeviceMemoryOpaqueCaptureAddressInfo,
+) -> u64 {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceMemoryOpaqueCaptureAddressKHR");
+    let __result = (hooked.commands.get_device_memory_opaque_capture_address_khr)(_device, _info);
+    hooked.after("vkGetDeviceMemoryOpaqueCaptureAddressKHR", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetDeviceMicromapCompatibilityEXT<const S: usize>(
+    _device: Device,
+    _version_info: *const MicromapVersionInfoEXT,
+    _compatibility: *mut AccelerationStructureCompatibilityKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceMicromapCompatibilityEXT");
+    (hooked.commands.get_device_micromap_compatibility_ext)(_device, _version_info, _compatibility);
+    hooked.after("vkGetDeviceMicromapCompatibilityEXT", None);
+}
+
+unsafe extern "system" fn vkGetDeviceQueue<const S: usize>(
+    _device: Device,
+    _queue_family_index: u32,
+    _queue_index: u32,
+    _queue: *mut Queue,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceQueue");
+    (hooked.commands.get_device_queue)(_device, _queue_family_index, _queue_index, _queue);
+    hooked.after("vkGetDeviceQueue", None);
+}
+
+unsafe extern "system" fn vkGetDeviceQueue2<const S: usize>(
+    _device: Device,
+    _queue_info: *const DeviceQueueInfo2,
+    _queue: *mut Queue,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceQueue2");
+    (hooked.commands.get_device_queue2)(_device, _queue_info, _queue);
+    hooked.after("vkGetDeviceQueue2", None);
+}
+
+unsafe extern "system" fn vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI<const S: usize>(
+    _device: Device,
+    _renderpass: RenderPass,
+    _max_workgroup_size: *mut Extent2D,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_device_subpass_shading_max_workgroup_size_huawei)(
+            _device,
+            _renderpass,
+            _max_workgroup_size,
+        ),
+    };
+    hooked.after(
+        "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetDeviceTensorMemoryRequirementsARM<const S: usize>(
+    _device: Device,
+    _info: *const DeviceTensorMemoryRequirementsARM,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetDeviceTensorMemoryRequirementsARM");
+    (hooked.commands.get_device_tensor_memory_requirements_arm)(
+        _device,
+        _info,
+        _memory_requirements,
+    );
+    hooked.after("vkGetDeviceTensorMemoryRequirementsARM", None);
+}
+
+unsafe extern "system" fn vkGetDynamicRenderingTilePropertiesQCOM<const S: usize>(
+    _device: Device,
+    _rendering_info: *const RenderingInfo,
+    _properties: *mut TilePropertiesQCOM,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetDynamicRenderingTilePropertiesQCOM") {
+        Some(result) => result,
+        None => (hooked.commands.get_dynamic_rendering_tile_properties_qcom)(
+            _device,
+            _rendering_info,
+            _properties,
+        ),
+    };
+    hooked.after("vkGetDynamicRenderingTilePropertiesQCOM", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetEncodedVideoSessionParametersKHR<const S: usize>(
+    _device: Device,
+    _video_session_parameters_info: *const VideoEncodeSessionParametersGetInfoKHR,
+    _feedback_info: *mut VideoEncodeSessionParametersFeedbackInfoKHR,
+    _data_size: *mut usize,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetEncodedVideoSessionParametersKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_encoded_video_session_parameters_khr)(
+            _device,
+            _video_session_parameters_info,
+            _feedback_info,
+            _data_size,
+            _data,
+        ),
+    };
+    hooked.after("vkGetEncodedVideoSessionParametersKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetEventStatus<const S: usize>(
+    _device: Device,
+    _event: Event,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetEventStatus") {
+        Some(result) => result,
+        None => (hooked.commands.get_event_status)(_device, _event),
+    };
+    hooked.after("vkGetEventStatus", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetExecutionGraphPipelineNodeIndexAMDX<const S: usize>(
+    _device: Device,
+    _execution_graph: Pipeline,
+    _node_info: *const PipelineShaderStageNodeCreateInfoAMDX,
+    _node_index: *mut u32,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetExecutionGraphPipelineNodeIndexAMDX") {
+        Some(result) => result,
+        None => (hooked.commands.get_execution_graph_pipeline_node_index_amdx)(
+            _device,
+            _execution_graph,
+            _node_info,
+            _node_index,
+        ),
+    };
+    hooked.after("vkGetExecutionGraphPipelineNodeIndexAMDX", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetExecutionGraphPipelineScratchSizeAMDX<const S: usize>(
+    _device: Device,
+    _execution_graph: Pipeline,
+    _size_info: *mut ExecutionGraphPipelineScratchSizeAMDX,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetExecutionGraphPipelineScratchSizeAMDX") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_execution_graph_pipeline_scratch_size_amdx)(
+            _device, _execution_graph, _size_info
+        ),
+    };
+    hooked.after("vkGetExecutionGraphPipelineScratchSizeAMDX", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetExternalComputeQueueDataNV<const S: usize>(
+    _external_queue: ExternalComputeQueueNV,
+    _params: *mut ExternalComputeQueueDataParamsNV,
+    _data: *mut c_void,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetExternalComputeQueueDataNV");
+    (hooked.commands.get_external_compute_queue_data_nv)(_external_queue, _params, _data);
+    hooked.after("vkGetExternalComputeQueueDataNV", None);
+}
+
+unsafe extern "system" fn vkGetFenceFdKHR<const S: usize>(
+    _device: Device,
+    _get_fd_info: *const FenceGetFdInfoKHR,
+    _fd: *mut c_int,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetFenceFdKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_fence_fd_khr)(_device, _get_fd_info, _fd),
+    };
+    hooked.after("vkGetFenceFdKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetFenceSciSyncFenceNV<const S: usize>(
+    _device: Device,
+    _get_sci_sync_handle_info: *const FenceGetSciSyncInfoNV,
+    _handle: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetFenceSciSyncFenceNV") {
+        Some(result) => result,
+        None => (hooked.commands.get_fence_sci_sync_fence_nv)(
+            _device,
+            _get_sci_sync_handle_info,
+            _handle,
+        ),
+    };
+    hooked.after("vkGetFenceSciSyncFenceNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetFenceSciSyncObjNV<const S: usize>(
+    _device: Device,
+    _get_sci_sync_handle_info: *const FenceGetSciSyncInfoNV,
+    _handle: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetFenceSciSyncObjNV") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_fence_sci_sync_obj_nv)(_device, _get_sci_sync_handle_info, _handle)
+        }
+    };
+    hooked.after("vkGetFenceSciSyncObjNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetFenceStatus<const S: usize>(
+    _device: Device,
+    _fence: Fence,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetFenceStatus") {
+        Some(result) => result,
+        None => (hooked.commands.get_fence_status)(_device, _fence),
+    };
+    hooked.after("vkGetFenceStatus", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetFenceWin32HandleKHR<const S: usize>(
+    _device: Device,
+    _get_win32_handle_info: *const FenceGetWin32HandleInfoKHR,
+    _handle: *mut HANDLE,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetFenceWin32HandleKHR") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_fence_win32_handle_khr)(_device, _get_win32_handle_info, _handle)
+        }
+    };
+    hooked.after("vkGetFenceWin32HandleKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetFramebufferTilePropertiesQCOM<const S: usize>(
+    _device: Device,
+    _framebuffer: Framebuffer,
+    _properties_count: *mut u32,
+    _properties: *mut TilePropertiesQCOM,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetFramebufferTilePropertiesQCOM") {
+        Some(result) => result,
+        None => (hooked.commands.get_framebuffer_tile_properties_qcom)(
+            _device,
+            _framebuffer,
+            _properties_count,
+            _properties,
+        ),
+    };
+    hooked.after("vkGetFramebufferTilePropertiesQCOM", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetGeneratedCommandsMemoryRequirementsEXT<const S: usize>(
+    _device: Device,
+    _info: *const GeneratedCommandsMemoryRequirementsInfoEXT,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetGeneratedCommandsMemoryRequirementsEXT");
+    (hooked
+        .commands
+        .get_generated_commands_memory_requirements_ext)(_device, _info, _memory_requirements);
+    hooked.after("vkGetGeneratedCommandsMemoryRequirementsEXT", None);
+}
+
+unsafe extern "system" fn vkGetGeneratedCommandsMemoryRequirementsNV<const S: usize>(
+    _device: Device,
+    _info: *const GeneratedCommandsMemoryRequirementsInfoNV,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetGeneratedCommandsMemoryRequirementsNV");
+    (hooked
+        .commands
+        .get_generated_commands_memory_requirements_nv)(_device, _info, _memory_requirements);
+    hooked.after("vkGetGeneratedCommandsMemoryRequirementsNV", None);
+}
+
+unsafe extern "system" fn vkGetImageDrmFormatModifierPropertiesEXT<const S: usize>(
+    _device: Device,
+    _image: Image,
+    _properties: *mut ImageDrmFormatModifierPropertiesEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetImageDrmFormatModifierPropertiesEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_image_drm_format_modifier_properties_ext)(
+            _device,
+            _image,
+            _properties,
+        ),
+    };
+    hooked.after("vkGetImageDrmFormatModifierPropertiesEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetImageMemoryRequirements<const S: usize>(
+    _device: Device,
+    _image: Image,
+    _memory_requirements: *mut MemoryRequirements,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetImageMemoryRequirements");
+    (hooked.commands.get_image_memory_requirements)(_device, _image, _memory_requirements);
+    hooked.after("vkGetImageMemoryRequirements", None);
+}
+
+unsafe extern "system" fn vkGetImageMemoryRequirements2<const S: usize>(
+    _device: Device,
+    _info: *const ImageMemoryRequirementsInfo2,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetImageMemoryRequirements2");
+    (hooked.commands.get_image_memory_requirements2)(_device, _info, _memory_requirements);
+    hooked.after("vkGetImageMemoryRequirements2", None);
+}
+
+unsafe extern "system" fn vkGetImageMemoryRequirements2KHR<const S: usize>(
+    _device: Device,
+    _info: *const ImageMemoryRequirementsInfo2,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetImageMemoryRequirements2KHR");
+    (hooked.commands.get_image_memory_requirements2_khr)(_device, _info, _memory_requirements);
+    hooked.after("vkGetImageMemoryRequirements2KHR", None);
+}
+
+unsafe extern "system" fn vkGetImageOpaqueCaptureDataEXT<const S: usize>(
+    _device: Device,
+    _image_count: u32,
+    _images: *const Image,
+    _datas: *mut HostAddressRangeEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetImageOpaqueCaptureDataEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_image_opaque_capture_data_ext)(
+            _device,
+            _image_count,
+            _images,
+            _datas,
+        ),
+    };
+    hooked.after("vkGetImageOpaqueCaptureDataEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetImageOpaqueCaptureDescriptorDataEXT<const S: usize>(
+    _device: Device,
+    _info: *const ImageCaptureDescriptorDataInfoEXT,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetImageOpaqueCaptureDescriptorDataEXT") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_image_opaque_capture_descriptor_data_ext)(_device, _info, _data)
+        }
+    };
+    hooked.after("vkGetImageOpaqueCaptureDescriptorDataEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetImageSparseMemoryRequirements<const S: usize>(
+    _device: Device,
+    _image: Image,
+    _sparse_memory_requirement_count: *mut u32,
+    _sparse_memory_requirements: *mut SparseImageMemoryRequirements,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetImageSparseMemoryRequirements");
+    (hooked.commands.get_image_sparse_memory_requirements)(
+        _device,
+        _image,
+        _sparse_memory_requirement_count,
+        _sparse_memory_requirements,
+    );
+    hooked.after("vkGetImageSparseMemoryRequirements", None);
+}
+
+unsafe extern "system" fn vkGetImageSparseMemoryRequirements2<const S: usize>(
+    _device: Device,
+    _info: *const ImageSparseMemoryRequirementsInfo2,
+    _sparse_memory_requirement_count: *mut u32,
+    _sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetImageSparseMemoryRequirements2");
+    (hooked.commands.get_image_sparse_memory_requirements2)(
+        _device,
+        _info,
+        _sparse_memory_requirement_count,
+        _sparse_memory_requirements,
+    );
+    hooked.after("vkGetImageSparseMemoryRequirements2", None);
+}
+
+unsafe extern "system" fn vkGetImageSparseMemoryRequirements2KHR<const S: usize>(
+    _device: Device,
+    _info: *const ImageSparseMemoryRequirementsInfo2,
+    _sparse_memory_requirement_count: *mut u32,
+    _sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetImageSparseMemoryRequirements2KHR");
+    (hooked.commands.get_image_sparse_memory_requirements2_khr)(
+        _device,
+        _info,
+        _sparse_memory_requirement_count,
+        _sparse_memory_requirements,
+    );
+    hooked.after("vkGetImageSparseMemoryRequirements2KHR", None);
+}
+
+unsafe extern "system" fn vkGetImageSubresourceLayout<const S: usize>(
+    _device: Device,
+    _image: Image,
+    _subresource: *const ImageSubresource,
+    _layout: *mut SubresourceLayout,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetImageSubresourceLayout");
+    (hooked.commands.get_image_subresource_layout)(_device, _image, _subresource, _layout);
+    hooked.after("vkGetImageSubresourceLayout", None);
+}
+
+unsafe extern "system" fn vkGetImageSubresourceLayout2<const S: usize>(
+    _device: Device,
+    _image: Image,
+    _subresource: *const ImageSubresource2,
+    _layout: *mut SubresourceLayout2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetImageSubresourceLayout2");
+    (hooked.commands.get_image_subresource_layout2)(_device, _image, _subresource, _layout);
+    hooked.after("vkGetImageSubresourceLayout2", None);
+}
+
+unsafe extern "system" fn vkGetImageSubresourceLayout2EXT<const S: usize>(
+    _device: Device,
+    _image: Image,
+    _subresource: *const ImageSubresource2,
+    _layout: *mut SubresourceLayout2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetImageSubresourceLayout2EXT");
+    (hooked.commands.get_image_subresource_layout2_ext)(_device, _image, _subresource, _layout);
+    hooked.after("vkGetImageSubresourceLayout2EXT", None);
+}
+
+unsafe extern "system" fn vkGetImageSubresourceLayout2KHR<const S: usize>(
+    _device: Device,
+    _image: Image,
+    _subresource: *const ImageSubresource2,
+    _layout: *mut SubresourceLayout2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetImageSubresourceLayout2KHR");
+    (hooked.commands.get_image_subresource_layout2_khr)(_device, _image, _subresource, _layout);
+    hooked.after("vkGetImageSubresourceLayout2KHR", None);
+}
+
+unsafe extern "system" fn vkGetImageViewAddressNVX<const S: usize>(
+    _device: Device,
+    _image_view: ImageView,
+    _properties: *mut ImageViewAddressPropertiesNVX,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetImageViewAddressNVX") {
+        Some(result) => result,
+        None => (hooked.commands.get_image_view_address_nvx)(_device, _image_view, _properties),
+    };
+    hooked.after("vkGetImageViewAddressNVX", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetImageViewHandle64NVX<const S: usize>(
+    _device: Device,
+    _info: *const ImageViewHandleInfoNVX,
+) -> u64 {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetImageViewHandle64NVX");
+    let __result = (hooked.commands.get_image_view_handle64_nvx)(_device, _info);
+    hooked.after("vkGetImageViewHandle64NVX", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetImageViewHandleNVX<const S: usize>(
+    _device: Device,
+    _info: *const ImageViewHandleInfoNVX,
+) -> u32 {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetImageViewHandleNVX");
+    let __result = (hooked.commands.get_image_view_handle_nvx)(_device, _info);
+    hooked.after("vkGetImageViewHandleNVX", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetImageViewOpaqueCaptureDescriptorDataEXT<const S: usize>(
+    _device: Device,
+    _info: *const ImageViewCaptureDescriptorDataInfoEXT,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetImageViewOpaqueCaptureDescriptorDataEXT") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_image_view_opaque_capture_descriptor_data_ext)(
+            _device, _info, _data
+        ),
+    };
+    hooked.after(
+        "vkGetImageViewOpaqueCaptureDescriptorDataEXT",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetLatencyTimingsNV<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _latency_marker_info: *mut GetLatencyMarkerInfoNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetLatencyTimingsNV");
+    (hooked.commands.get_latency_timings_nv)(_device, _swapchain, _latency_marker_info);
+    hooked.after("vkGetLatencyTimingsNV", None);
+}
+
+unsafe extern "system" fn vkGetMemoryAndroidHardwareBufferANDROID<const S: usize>(
+    _device: Device,
+    _info: *const MemoryGetAndroidHardwareBufferInfoANDROID,
+    _buffer: *mut *mut AHardwareBuffer,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryAndroidHardwareBufferANDROID") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_memory_android_hardware_buffer_android)(_device, _info, _buffer)
+        }
+    };
+    hooked.after("vkGetMemoryAndroidHardwareBufferANDROID", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemoryFdKHR<const S: usize>(
+    _device: Device,
+    _get_fd_info: *const MemoryGetFdInfoKHR,
+    _fd: *mut c_int,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryFdKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_memory_fd_khr)(_device, _get_fd_info, _fd),
+    };
+    hooked.after("vkGetMemoryFdKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemoryFdPropertiesKHR<const S: usize>(
+    _device: Device,
+    _handle_type: ExternalMemoryHandleTypeFlags,
+    _fd: c_int,
+    _memory_fd_properties: *mut MemoryFdPropertiesKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryFdPropertiesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_memory_fd_properties_khr)(
+            _device,
+            _handle_type,
+            _fd,
+            _memory_fd_properties,
+        ),
+    };
+    hooked.after("vkGetMemoryFdPropertiesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemoryHostPointerPropertiesEXT<const S: usize>(
+    _device: Device,
+    _handle_type: ExternalMemoryHandleTypeFlags,
+    _host_pointer: *const c_void,
+    _memory_host_pointer_properties: *mut MemoryHostPointerPropertiesEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryHostPointerPropertiesEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_memory_host_pointer_properties_ext)(
+            _device,
+            _handle_type,
+            _host_pointer,
+            _memory_host_pointer_properties,
+        ),
+    };
+    hooked.after("vkGetMemoryHostPointerPropertiesEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemoryMetalHandleEXT<const S: usize>(
+    _device: Device,
+    _get_metal_handle_info: *const MemoryGetMetalHandleInfoEXT,
+    _handle: *mut *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryMetalHandleEXT") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_memory_metal_handle_ext)(_device, _get_metal_handle_info, _handle)
+        }
+    };
+    hooked.after("vkGetMemoryMetalHandleEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemoryMetalHandlePropertiesEXT<const S: usize>(
+    _device: Device,
+    _handle_type: ExternalMemoryHandleTypeFlags,
+    _handle: *const c_void,
+    _memory_metal_handle_properties: *mut MemoryMetalHandlePropertiesEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryMetalHandlePropertiesEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_memory_metal_handle_properties_ext)(
+            _device,
+            _handle_type,
+            _handle,
+            _memory_metal_handle_properties,
+        ),
+    };
+    hooked.after("vkGetMemoryMetalHandlePropertiesEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemoryNativeBufferOHOS<const S: usize>(
+    _device: Device,
+    _info: *const MemoryGetNativeBufferInfoOHOS,
+    _buffer: *mut *mut OH_NativeBuffer,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryNativeBufferOHOS") {
+        Some(result) => result,
+        None => (hooked.commands.get_memory_native_buffer_ohos)(_device, _info, _buffer),
+    };
+    hooked.after("vkGetMemoryNativeBufferOHOS", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemoryRemoteAddressNV<const S: usize>(
+    _device: Device,
+    _memory_get_remote_address_info: *const MemoryGetRemoteAddressInfoNV,
+    _address: *mut RemoteAddressNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryRemoteAddressNV") {
+        Some(result) => result,
+        None => (hooked.commands.get_memory_remote_address_nv)(
+            _device,
+            _memory_get_remote_address_info,
+            _address,
+        ),
+    };
+    hooked.after("vkGetMemoryRemoteAddressNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemorySciBufNV<const S: usize>(
+    _device: Device,
+    _get_sci_buf_info: *const MemoryGetSciBufInfoNV,
+    _handle: *mut NvSciBufObj,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemorySciBufNV") {
+        Some(result) => result,
+        None => (hooked.commands.get_memory_sci_buf_nv)(_device, _get_sci_buf_info, _handle),
+    };
+    hooked.after("vkGetMemorySciBufNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemoryWin32HandleKHR<const S: usize>(
+    _device: Device,
+    _get_win32_handle_info: *const MemoryGetWin32HandleInfoKHR,
+    _handle: *mut HANDLE,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryWin32HandleKHR") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_memory_win32_handle_khr)(_device, _get_win32_handle_info, _handle)
+        }
+    };
+    hooked.after("vkGetMemoryWin32HandleKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemoryWin32HandleNV<const S: usize>(
+    _device: Device,
+    _memory: DeviceMemory,
+    _handle_type: ExternalMemoryHandleTypeFlagsNV,
+    _handle: *mut HANDLE,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryWin32HandleNV") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_memory_win32_handle_nv)(_device, _memory, _handle_type, _handle)
+        }
+    };
+    hooked.after("vkGetMemoryWin32HandleNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemoryWin32HandlePropertiesKHR<const S: usize>(
+    _device: Device,
+    _handle_type: ExternalMemoryHandleTypeFlags,
+    _handle: HANDLE,
+    _memory_win32_handle_properties: *mut MemoryWin32HandlePropertiesKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryWin32HandlePropertiesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_memory_win32_handle_properties_khr)(
+            _device,
+            _handle_type,
+            _handle,
+            _memory_win32_handle_properties,
+        ),
+    };
+    hooked.after("vkGetMemoryWin32HandlePropertiesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemoryZirconHandleFUCHSIA<const S: usize>(
+    _device: Device,
+    _get_zircon_handle_info: *const MemoryGetZirconHandleInfoFUCHSIA,
+    _zircon_handle: *mut zx_handle_t,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryZirconHandleFUCHSIA") {
+        Some(result) => result,
+        None => (hooked.commands.get_memory_zircon_handle_fuchsia)(
+            _device,
+            _get_zircon_handle_info,
+            _zircon_handle,
+        ),
+    };
+    hooked.after("vkGetMemoryZirconHandleFUCHSIA", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMemoryZirconHandlePropertiesFUCHSIA<const S: usize>(
+    _device: Device,
+    _handle_type: ExternalMemoryHandleTypeFlags,
+    _zircon_handle: zx_handle_t,
+    _memory_zircon_handle_properties: *mut MemoryZirconHandlePropertiesFUCHSIA,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetMemoryZirconHandlePropertiesFUCHSIA") {
+        Some(result) => result,
+        None => (hooked.commands.get_memory_zircon_handle_properties_fuchsia)(
+            _device,
+            _handle_type,
+            _zircon_handle,
+            _memory_zircon_handle_properties,
+        ),
+    };
+    hooked.after("vkGetMemoryZirconHandlePropertiesFUCHSIA", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetMicromapBuildSizesEXT<const S: usize>(
+    _device: Device,
+    _build_type: AccelerationStructureBuildTypeKHR,
+    _build_info: *const MicromapBuildInfoEXT,
+    _size_info: *mut MicromapBuildSizesInfoEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetMicromapBuildSizesEXT");
+    (hooked.commands.get_micromap_build_sizes_ext)(_device, _build_type, _build_info, _size_info);
+    hooked.after("vkGetMicromapBuildSizesEXT", None);
+}
+
+unsafe extern "system" fn vkGetNativeBufferPropertiesOHOS<const S: usize>(
+    _device: Device,
+    _buffer: *const OH_NativeBuffer,
+    _properties: *mut NativeBufferPropertiesOHOS,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetNativeBufferPropertiesOHOS") {
+        Some(result) => result,
+        None => (hooked.commands.get_native_buffer_properties_ohos)(_device, _buffer, _properties),
+    };
+    hooked.after("vkGetNativeBufferPropertiesOHOS", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPartitionedAccelerationStructuresBuildSizesNV<const S: usize>(
+    _device: Device,
+    _info: *const PartitionedAccelerationStructureInstancesInputNV,
+    _size_info: *mut AccelerationStructureBuildSizesInfoKHR,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetPartitionedAccelerationStructuresBuildSizesNV");
+    (hooked
+        .commands
+        .get_partitioned_acceleration_structures_build_sizes_nv)(_device, _info, _size_info);
+    hooked.after("vkGetPartitionedAccelerationStructuresBuildSizesNV", None);
+}
+
+unsafe extern "system" fn vkGetPastPresentationTimingEXT<const S: usize>(
+    _device: Device,
+    _past_presentation_timing_info: *const PastPresentationTimingInfoEXT,
+    _past_presentation_timing_properties: *mut PastPresentationTimingPropertiesEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetPastPresentationTimingEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_past_presentation_timing_ext)(
+            _device,
+            _past_presentation_timing_info,
+            _past_presentation_timing_properties,
+        ),
+    };
+    hooked.after("vkGetPastPresentationTimingEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPastPresentationTimingGOOGLE<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _presentation_timing_count: *mut u32,
+    _presentation_timings: *mut PastPresentationTimingGOOGLE,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetPastPresentationTimingGOOGLE") {
+        Some(result) => result,
+        None => (hooked.commands.get_past_presentation_timing_google)(
+            _device,
+            _swapchain,
+            _presentation_timing_count,
+            _presentation_timings,
+        ),
+    };
+    hooked.after("vkGetPastPresentationTimingGOOGLE", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPerformanceParameterINTEL<const S: usize>(
+    _device: Device,
+    _parameter: PerformanceParameterTypeINTEL,
+    _value: *mut PerformanceValueINTEL,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetPerformanceParameterINTEL") {
+        Some(result) => result,
+        None => (hooked.commands.get_performance_parameter_intel)(_device, _parameter, _value),
+    };
+    hooked.after("vkGetPerformanceParameterINTEL", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPipelineBinaryDataKHR<const S: usize>(
+    _device: Device,
+    _info: *const PipelineBinaryDataInfoKHR,
+    _pipeline_binary_key: *mut PipelineBinaryKeyKHR,
+    _pipeline_binary_data_size: *mut usize,
+    _pipeline_binary_data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetPipelineBinaryDataKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_pipeline_binary_data_khr)(
+            _device,
+            _info,
+            _pipeline_binary_key,
+            _pipeline_binary_data_size,
+            _pipeline_binary_data,
+        ),
+    };
+    hooked.after("vkGetPipelineBinaryDataKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPipelineCacheData<const S: usize>(
+    _device: Device,
+    _pipeline_cache: PipelineCache,
+    _data_size: *mut usize,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetPipelineCacheData") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_pipeline_cache_data)(_device, _pipeline_cache, _data_size, _data)
+        }
+    };
+    hooked.after("vkGetPipelineCacheData", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPipelineExecutableInternalRepresentationsKHR<const S: usize>(
+    _device: Device,
+    _executable_info: *const PipelineExecutableInfoKHR,
+    _internal_representation_count: *mut u32,
+    _internal_representations: *mut PipelineExecutableInternalRepresentationKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetPipelineExecutableInternalRepresentationsKHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_pipeline_executable_internal_representations_khr)(
+            _device,
+            _executable_info,
+            _internal_representation_count,
+            _internal_representations,
+        ),
+    };
+    hooked.after(
+        "vkGetPipelineExecutableInternalRepresentationsKHR",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPipelineExecutablePropertiesKHR<const S: usize>(
+    _device: Device,
+    _pipeline_info: *const PipelineInfoKHR,
+    _executable_count: *mut u32,
+    _properties: *mut PipelineExecutablePropertiesKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetPipelineExecutablePropertiesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_pipeline_executable_properties_khr)(
+            _device,
+            _pipeline_info,
+            _executable_count,
+            _properties,
+        ),
+    };
+    hooked.after("vkGetPipelineExecutablePropertiesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPipelineExecutableStatisticsKHR<const S: usize>(
+    _device: Device,
+    _executable_info: *const PipelineExecutableInfoKHR,
+    _statistic_count: *mut u32,
+    _statistics: *mut PipelineExecutableStatisticKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetPipelineExecutableStatisticsKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_pipeline_executable_statistics_khr)(
+            _device,
+            _executable_info,
+            _statistic_count,
+            _statistics,
+        ),
+    };
+    hooked.after("vkGetPipelineExecutableStatisticsKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPipelineIndirectDeviceAddressNV<const S: usize>(
+    _device: Device,
+    _info: *const PipelineIndirectDeviceAddressInfoNV,
+) -> DeviceAddress {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetPipelineIndirectDeviceAddressNV");
+    let __result = (hooked.commands.get_pipeline_indirect_device_address_nv)(_device, _info);
+    hooked.after("vkGetPipelineIndirectDeviceAddressNV", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetPipelineIndirectMemoryRequirementsNV<const S: usize>(
+    _device: Device,
+    _create_info: *const ComputePipelineCreateInfo,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetPipelineIndirectMemoryRequirementsNV");
+    (hooked.commands.get_pipeline_indirect_memory_requirements_nv)(
+        _device,
+        _create_info,
+        _memory_requirements,
+    );
+    hooked.after("vkGetPipelineIndirectMemoryRequirementsNV", None);
+}
+
+unsafe extern "system" fn vkGetPipelineKeyKHR<const S: usize>(
+    _device: Device,
+    _pipeline_create_info: *const PipelineCreateInfoKHR,
+    _pipeline_key: *mut PipelineBinaryKeyKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetPipelineKeyKHR") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_pipeline_key_khr)(_device, _pipeline_create_info, _pipeline_key)
+        }
+    };
+    hooked.after("vkGetPipelineKeyKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPipelinePropertiesEXT<const S: usize>(
+    _device: Device,
+    _pipeline_info: *const PipelineInfoEXT,
+    _pipeline_properties: *mut BaseOutStructure,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetPipelinePropertiesEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_pipeline_properties_ext)(
+            _device,
+            _pipeline_info,
+            _pipeline_properties,
+        ),
+    };
+    hooked.after("vkGetPipelinePropertiesEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPrivateData<const S: usize>(
+    _device: Device,
+    _object_type: ObjectType,
+    _object_handle: u64,
+    _private_data_slot: PrivateDataSlot,
+    _data: *mut u64,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetPrivateData");
+    (hooked.commands.get_private_data)(
+        _device,
+        _object_type,
+        _object_handle,
+        _private_data_slot,
+        _data,
+    );
+    hooked.after("vkGetPrivateData", None);
+}
+
+unsafe extern "system" fn vkGetPrivateDataEXT<const S: usize>(
+    _device: Device,
+    _object_type: ObjectType,
+    _object_handle: u64,
+    _private_data_slot: PrivateDataSlot,
+    _data: *mut u64,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetPrivateDataEXT");
+    (hooked.commands.get_private_data_ext)(
+        _device,
+        _object_type,
+        _object_handle,
+        _private_data_slot,
+        _data,
+    );
+    hooked.after("vkGetPrivateDataEXT", None);
+}
+
+unsafe extern "system" fn vkGetQueryPoolResults<const S: usize>(
+    _device: Device,
+    _query_pool: QueryPool,
+    _first_query: u32,
+    _query_count: u32,
+    _data_size: usize,
+    _data: *mut c_void,
+    _stride: DeviceSize,
+    _flags: QueryResultFlags,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetQueryPoolResults") {
+        Some(result) => result,
+        None => (hooked.commands.get_query_pool_results)(
+            _device,
+            _query_pool,
+            _first_query,
+            _query_count,
+            _data_size,
+            _data,
+            _stride,
+            _flags,
+        ),
+    };
+    hooked.after("vkGetQueryPoolResults", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetQueueCheckpointData2NV<const S: usize>(
+    _queue: Queue,
+    _checkpoint_data_count: *mut u32,
+    _checkpoint_data: *mut CheckpointData2NV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetQueueCheckpointData2NV");
+    (hooked.commands.get_queue_checkpoint_data2_nv)(
+        _queue,
+        _checkpoint_data_count,
+        _checkpoint_data,
+    );
+    hooked.after("vkGetQueueCheckpointData2NV", None);
+}
+
+unsafe extern "system" fn vkGetQueueCheckpointDataNV<const S: usize>(
+    _queue: Queue,
+    _checkpoint_data_count: *mut u32,
+    _checkpoint_data: *mut CheckpointDataNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetQueueCheckpointDataNV");
+    (hooked.commands.get_queue_checkpoint_data_nv)(
+        _queue,
+        _checkpoint_data_count,
+        _checkpoint_data,
+    );
+    hooked.after("vkGetQueueCheckpointDataNV", None);
+}
+
+unsafe extern "system" fn vkGetRayTracingCaptureReplayShaderGroupHandlesKHR<const S: usize>(
+    _device: Device,
+    _pipeline: Pipeline,
+    _first_group: u32,
+    _group_count: u32,
+    _data_size: usize,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetRayTracingCaptureReplayShaderGroupHandlesKHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_ray_tracing_capture_replay_shader_group_handles_khr)(
+            _device,
+            _pipeline,
+            _first_group,
+            _group_count,
+            _data_size,
+            _data,
+        ),
+    };
+    hooked.after(
+        "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetRayTracingShaderGroupHandlesKHR<const S: usize>(
+    _device: Device,
+    _pipeline: Pipeline,
+    _first_group: u32,
+    _group_count: u32,
+    _data_size: usize,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetRayTracingShaderGroupHandlesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_ray_tracing_shader_group_handles_khr)(
+            _device,
+            _pipeline,
+            _first_group,
+            _group_count,
+            _data_size,
+            _data,
+        ),
+    };
+    hooked.after("vkGetRayTracingShaderGroupHandlesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetRayTracingShaderGroupHandlesNV<const S: usize>(
+    _device: Device,
+    _pipeline: Pipeline,
+    _first_group: u32,
+    _group_count: u32,
+    _data_size: usize,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetRayTracingShaderGroupHandlesNV") {
+        Some(result) => result,
+        None => (hooked.commands.get_ray_tracing_shader_group_handles_nv)(
+            _device,
+            _pipeline,
+            _first_group,
+            _group_count,
+            _data_size,
+            _data,
+        ),
+    };
+    hooked.after("vkGetRayTracingShaderGroupHandlesNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetRayTracingShaderGroupStackSizeKHR<const S: usize>(
+    _device: Device,
+    _pipeline: Pipeline,
+    _group: u32,
+    _group_shader: ShaderGroupShaderKHR,
+) -> DeviceSize {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetRayTracingShaderGroupStackSizeKHR");
+    let __result = (hooked.commands.get_ray_tracing_shader_group_stack_size_khr)(
+        _device,
+        _pipeline,
+        _group,
+        _group_shader,
+    );
+    hooked.after("vkGetRayTracingShaderGroupStackSizeKHR", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetRefreshCycleDurationGOOGLE<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _display_timing_properties: *mut RefreshCycleDurationGOOGLE,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetRefreshCycleDurationGOOGLE") {
+        Some(result) => result,
+        None => (hooked.commands.get_refresh_cycle_duration_google)(
+            _device,
+            _swapchain,
+            _display_timing_properties,
+        ),
+    };
+    hooked.after("vkGetRefreshCycleDurationGOOGLE", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetRenderAreaGranularity<const S: usize>(
+    _device: Device,
+    _render_pass: RenderPass,
+    _granularity: *mut Extent2D,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetRenderAreaGranularity");
+    (hooked.commands.get_render_area_granularity)(_device, _render_pass, _granularity);
+    hooked.after("vkGetRenderAreaGranularity", None);
+}
+
+unsafe extern "system" fn vkGetRenderingAreaGranularity<const S: usize>(
+    _device: Device,
+    _rendering_area_info: *const RenderingAreaInfo,
+    _granularity: *mut Extent2D,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetRenderingAreaGranularity");
+    (hooked.commands.get_rendering_area_granularity)(_device, _rendering_area_info, _granularity);
+    hooked.after("vkGetRenderingAreaGranularity", None);
+}
+
+unsafe extern "system" fn vkGetRenderingAreaGranularityKHR<const S: usize>(
+    _device: Device,
+    _rendering_area_info: *const RenderingAreaInfo,
+    _granularity: *mut Extent2D,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetRenderingAreaGranularityKHR");
+    (hooked.commands.get_rendering_area_granularity_khr)(
+        _device,
+        _rendering_area_info,
+        _granularity,
+    );
+    hooked.after("vkGetRenderingAreaGranularityKHR", None);
+}
+
+unsafe extern "system" fn vkGetSamplerOpaqueCaptureDescriptorDataEXT<const S: usize>(
+    _device: Device,
+    _info: *const SamplerCaptureDescriptorDataInfoEXT,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetSamplerOpaqueCaptureDescriptorDataEXT") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_sampler_opaque_capture_descriptor_data_ext)(_device, _info, _data),
+    };
+    hooked.after("vkGetSamplerOpaqueCaptureDescriptorDataEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetScreenBufferPropertiesQNX<const S: usize>(
+    _device: Device,
+    _buffer: *const _screen_buffer,
+    _properties: *mut ScreenBufferPropertiesQNX,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetScreenBufferPropertiesQNX") {
+        Some(result) => result,
+        None => (hooked.commands.get_screen_buffer_properties_qnx)(_device, _buffer, _properties),
+    };
+    hooked.after("vkGetScreenBufferPropertiesQNX", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetSemaphoreCounterValue<const S: usize>(
+    _device: Device,
+    _semaphore: Semaphore,
+    _value: *mut u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetSemaphoreCounterValue") {
+        Some(result) => result,
+        None => (hooked.commands.get_semaphore_counter_value)(_device, _semaphore, _value),
+    };
+    hooked.after("vkGetSemaphoreCounterValue", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetSemaphoreCounterValueKHR<const S: usize>(
+    _device: Device,
+    _semaphore: Semaphore,
+    _value: *mut u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetSemaphoreCounterValueKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_semaphore_counter_value_khr)(_device, _semaphore, _value),
+    };
+    hooked.after("vkGetSemaphoreCounterValueKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetSemaphoreFdKHR<const S: usize>(
+    _device: Device,
+    _get_fd_info: *const SemaphoreGetFdInfoKHR,
+    _fd: *mut c_int,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetSemaphoreFdKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_semaphore_fd_khr)(_device, _get_fd_info, _fd),
+    };
+    hooked.after("vkGetSemaphoreFdKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetSemaphoreSciSyncObjNV<const S: usize>(
+    _device: Device,
+    _get_sci_sync_info: *const SemaphoreGetSciSyncInfoNV,
+    _handle: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetSemaphoreSciSyncObjNV") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_semaphore_sci_sync_obj_nv)(_device, _get_sci_sync_info, _handle)
+        }
+    };
+    hooked.after("vkGetSemaphoreSciSyncObjNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetSemaphoreWin32HandleKHR<const S: usize>(
+    _device: Device,
+    _get_win32_handle_info: *const SemaphoreGetWin32HandleInfoKHR,
+    _handle: *mut HANDLE,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetSemaphoreWin32HandleKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_semaphore_win32_handle_khr)(
+            _device,
+            _get_win32_handle_info,
+            _handle,
+        ),
+    };
+    hooked.after("vkGetSemaphoreWin32HandleKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetSemaphoreZirconHandleFUCHSIA<const S: usize>(
+    _device: Device,
+    _get_zircon_handle_info: *const SemaphoreGetZirconHandleInfoFUCHSIA,
+    _zircon_handle: *mut zx_handle_t,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetSemaphoreZirconHandleFUCHSIA") {
+        Some(result) => result,
+        None => (hooked.commands.get_semaphore_zircon_handle_fuchsia)(
+            _device,
+            _get_zircon_handle_info,
+            _zircon_handle,
+        ),
+    };
+    hooked.after("vkGetSemaphoreZirconHandleFUCHSIA", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetShaderBinaryDataEXT<const S: usize>(
+    _device: Device,
+    _shader: ShaderEXT,
+    _data_size: *mut usize,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetShaderBinaryDataEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_shader_binary_data_ext)(_device, _shader, _data_size, _data),
+    };
+    hooked.after("vkGetShaderBinaryDataEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetShaderInfoAMD<const S: usize>(
+    _device: Device,
+    _pipeline: Pipeline,
+    _shader_stage: ShaderStageFlags,
+    _info_type: ShaderInfoTypeAMD,
+    _info_size: *mut usize,
+    _info: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetShaderInfoAMD") {
+        Some(result) => result,
+        None => (hooked.commands.get_shader_info_amd)(
+            _device,
+            _pipeline,
+            _shader_stage,
+            _info_type,
+            _info_size,
+            _info,
+        ),
+    };
+    hooked.after("vkGetShaderInfoAMD", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetShaderModuleCreateInfoIdentifierEXT<const S: usize>(
+    _device: Device,
+    _create_info: *const ShaderModuleCreateInfo,
+    _identifier: *mut ShaderModuleIdentifierEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetShaderModuleCreateInfoIdentifierEXT");
+    (hooked.commands.get_shader_module_create_info_identifier_ext)(
+        _device,
+        _create_info,
+        _identifier,
+    );
+    hooked.after("vkGetShaderModuleCreateInfoIdentifierEXT", None);
+}
+
+unsafe extern "system" fn vkGetShaderModuleIdentifierEXT<const S: usize>(
+    _device: Device,
+    _shader_module: ShaderModule,
+    _identifier: *mut ShaderModuleIdentifierEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetShaderModuleIdentifierEXT");
+    (hooked.commands.get_shader_module_identifier_ext)(_device, _shader_module, _identifier);
+    hooked.after("vkGetShaderModuleIdentifierEXT", None);
+}
+
+unsafe extern "system" fn vkGetSwapchainCounterEXT<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _counter: SurfaceCounterFlagsEXT,
+    _counter_value: *mut u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetSwapchainCounterEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_swapchain_counter_ext)(
+            _device,
+            _swapchain,
+            _counter,
+            _counter_value,
+        ),
+    };
+    hooked.after("vkGetSwapchainCounterEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetSwapchainImagesKHR<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _swapchain_image_count: *mut u32,
+    _swapchain_images: *mut Image,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetSwapchainImagesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_swapchain_images_khr)(
+            _device,
+            _swapchain,
+            _swapchain_image_count,
+            _swapchain_images,
+        ),
+    };
+    hooked.after("vkGetSwapchainImagesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetSwapchainStatusKHR<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetSwapchainStatusKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_swapchain_status_khr)(_device, _swapchain),
+    };
+    hooked.after("vkGetSwapchainStatusKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetSwapchainTimeDomainPropertiesEXT<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _swapchain_time_domain_properties: *mut SwapchainTimeDomainPropertiesEXT,
+    _time_domains_counter: *mut u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetSwapchainTimeDomainPropertiesEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_swapchain_time_domain_properties_ext)(
+            _device,
+            _swapchain,
+            _swapchain_time_domain_properties,
+            _time_domains_counter,
+        ),
+    };
+    hooked.after("vkGetSwapchainTimeDomainPropertiesEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetSwapchainTimingPropertiesEXT<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _swapchain_timing_properties: *mut SwapchainTimingPropertiesEXT,
+    _swapchain_timing_properties_counter: *mut u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetSwapchainTimingPropertiesEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_swapchain_timing_properties_ext)(
+            _device,
+            _swapchain,
+            _swapchain_timing_properties,
+            _swapchain_timing_properties_counter,
+        ),
+    };
+    hooked.after("vkGetSwapchainTimingPropertiesEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetTensorMemoryRequirementsARM<const S: usize>(
+    _device: Device,
+    _info: *const TensorMemoryRequirementsInfoARM,
+    _memory_requirements: *mut MemoryRequirements2,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkGetTensorMemoryRequirementsARM");
+    (hooked.commands.get_tensor_memory_requirements_arm)(_device, _info, _memory_requirements);
+    hooked.after("vkGetTensorMemoryRequirementsARM", None);
+}
+
+unsafe extern "system" fn vkGetTensorOpaqueCaptureDataARM<const S: usize>(
+    _device: Device,
+    _tensor_count: u32,
+    _tensors: *const TensorARM,
+    _datas: *mut HostAddressRangeEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetTensorOpaqueCaptureDataARM") {
+        Some(result) => result,
+        None => (hooked.commands.get_tensor_opaque_capture_data_arm)(
+            _device,
+            _tensor_count,
+            _tensors,
+            _datas,
+        ),
+    };
+    hooked.after("vkGetTensorOpaqueCaptureDataARM", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetTensorOpaqueCaptureDescriptorDataARM<const S: usize>(
+    _device: Device,
+    _info: *const TensorCaptureDescriptorDataInfoARM,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetTensorOpaqueCaptureDescriptorDataARM") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_tensor_opaque_capture_descriptor_data_arm)(_device, _info, _data),
+    };
+    hooked.after("vkGetTensorOpaqueCaptureDescriptorDataARM", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetTensorViewOpaqueCaptureDescriptorDataARM<const S: usize>(
+    _device: Device,
+    _info: *const TensorViewCaptureDescriptorDataInfoARM,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetTensorViewOpaqueCaptureDescriptorDataARM") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_tensor_view_opaque_capture_descriptor_data_arm)(
+            _device, _info, _data
+        ),
+    };
+    hooked.after(
+        "vkGetTensorViewOpaqueCaptureDescriptorDataARM",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetValidationCacheDataEXT<const S: usize>(
+    _device: Device,
+    _validation_cache: ValidationCacheEXT,
+    _data_size: *mut usize,
+    _data: *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetValidationCacheDataEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_validation_cache_data_ext)(
+            _device,
+            _validation_cache,
+            _data_size,
+            _data,
+        ),
+    };
+    hooked.after("vkGetValidationCacheDataEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetVideoSessionMemoryRequirementsKHR<const S: usize>(
+    _device: Device,
+    _video_session: VideoSessionKHR,
+    _memory_requirements_count: *mut u32,
+    _memory_requirements: *mut VideoSessionMemoryRequirementsKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkGetVideoSessionMemoryRequirementsKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_video_session_memory_requirements_khr)(
+            _device,
+            _video_session,
+            _memory_requirements_count,
+            _memory_requirements,
+        ),
+    };
+    hooked.after("vkGetVideoSessionMemoryRequirementsKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkImportFenceFdKHR<const S: usize>(
+    _device: Device,
+    _import_fence_fd_info: *const ImportFenceFdInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkImportFenceFdKHR") {
+        Some(result) => result,
+        None => (hooked.commands.import_fence_fd_khr)(_device, _import_fence_fd_info),
+    };
+    hooked.after("vkImportFenceFdKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkImportFenceSciSyncFenceNV<const S: usize>(
+    _device: Device,
+    _import_fence_sci_sync_info: *const ImportFenceSciSyncInfoNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkImportFenceSciSyncFenceNV") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.import_fence_sci_sync_fence_nv)(_device, _import_fence_sci_sync_info)
+        }
+    };
+    hooked.after("vkImportFenceSciSyncFenceNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkImportFenceSciSyncObjNV<const S: usize>(
+    _device: Device,
+    _import_fence_sci_sync_info: *const ImportFenceSciSyncInfoNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkImportFenceSciSyncObjNV") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.import_fence_sci_sync_obj_nv)(_device, _import_fence_sci_sync_info)
+        }
+    };
+    hooked.after("vkImportFenceSciSyncObjNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkImportFenceWin32HandleKHR<const S: usize>(
+    _device: Device,
+    _import_fence_win32_handle_info: *const ImportFenceWin32HandleInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkImportFenceWin32HandleKHR") {
+        Some(result) => result,
+        None => (hooked.commands.import_fence_win32_handle_khr)(
+            _device,
+            _import_fence_win32_handle_info,
+        ),
+    };
+    hooked.after("vkImportFenceWin32HandleKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkImportSemaphoreFdKHR<const S: usize>(
+    _device: Device,
+    _import_semaphore_fd_info: *const ImportSemaphoreFdInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkImportSemaphoreFdKHR") {
+        Some(result) => result,
+        None => (hooked.commands.import_semaphore_fd_khr)(_device, _import_semaphore_fd_info),
+    };
+    hooked.after("vkImportSemaphoreFdKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkImportSemaphoreSciSyncObjNV<const S: usize>(
+    _device: Device,
+    _import_semaphore_sci_sync_info: *const ImportSemaphoreSciSyncInfoNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkImportSemaphoreSciSyncObjNV") {
+        Some(result) => result,
+        None => (hooked.commands.import_semaphore_sci_sync_obj_nv)(
+            _device,
+            _import_semaphore_sci_sync_info,
+        ),
+    };
+    hooked.after("vkImportSemaphoreSciSyncObjNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkImportSemaphoreWin32HandleKHR<const S: usize>(
+    _device: Device,
+    _import_semaphore_win32_handle_info: *const ImportSemaphoreWin32HandleInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkImportSemaphoreWin32HandleKHR") {
+        Some(result) => result,
+        None => (hooked.commands.import_semaphore_win32_handle_khr)(
+            _device,
+            _import_semaphore_win32_handle_info,
+        ),
+    };
+    hooked.after("vkImportSemaphoreWin32HandleKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkImportSemaphoreZirconHandleFUCHSIA<const S: usize>(
+    _device: Device,
+    _import_semaphore_zircon_handle_info: *const ImportSemaphoreZirconHandleInfoFUCHSIA,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkImportSemaphoreZirconHandleFUCHSIA") {
+        Some(result) => result,
+        None => (hooked.commands.import_semaphore_zircon_handle_fuchsia)(
+            _device,
+            _import_semaphore_zircon_handle_info,
+        ),
+    };
+    hooked.after("vkImportSemaphoreZirconHandleFUCHSIA", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkInitializePerformanceApiINTEL<const S: usize>(
+    _device: Device,
+    _initialize_info: *const InitializePerformanceApiInfoINTEL,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkInitializePerformanceApiINTEL") {
+        Some(result) => result,
+        None => (hooked.commands.initialize_performance_api_intel)(_device, _initialize_info),
+    };
+    hooked.after("vkInitializePerformanceApiINTEL", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkInvalidateMappedMemoryRanges<const S: usize>(
+    _device: Device,
+    _memory_range_count: u32,
+    _memory_ranges: *const MappedMemoryRange,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkInvalidateMappedMemoryRanges") {
+        Some(result) => result,
+        None => (hooked.commands.invalidate_mapped_memory_ranges)(
+            _device,
+            _memory_range_count,
+            _memory_ranges,
+        ),
+    };
+    hooked.after("vkInvalidateMappedMemoryRanges", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkLatencySleepNV<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _sleep_info: *const LatencySleepInfoNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkLatencySleepNV") {
+        Some(result) => result,
+        None => (hooked.commands.latency_sleep_nv)(_device, _swapchain, _sleep_info),
+    };
+    hooked.after("vkLatencySleepNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkMapMemory<const S: usize>(
+    _device: Device,
+    _memory: DeviceMemory,
+    _offset: DeviceSize,
+    _size: DeviceSize,
+    _flags: MemoryMapFlags,
+    _data: *mut *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkMapMemory") {
+        Some(result) => result,
+        None => (hooked.commands.map_memory)(_device, _memory, _offset, _size, _flags, _data),
+    };
+    hooked.after("vkMapMemory", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkMapMemory2<const S: usize>(
+    _device: Device,
+    _memory_map_info: *const MemoryMapInfo,
+    _data: *mut *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkMapMemory2") {
+        Some(result) => result,
+        None => (hooked.commands.map_memory2)(_device, _memory_map_info, _data),
+    };
+    hooked.after("vkMapMemory2", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkMapMemory2KHR<const S: usize>(
+    _device: Device,
+    _memory_map_info: *const MemoryMapInfo,
+    _data: *mut *mut c_void,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkMapMemory2KHR") {
+        Some(result) => result,
+        None => (hooked.commands.map_memory2_khr)(_device, _memory_map_info, _data),
+    };
+    hooked.after("vkMapMemory2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkMergePipelineCaches<const S: usize>(
+    _device: Device,
+    _dst_cache: PipelineCache,
+    _src_cache_count: u32,
+    _src_caches: *const PipelineCache,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkMergePipelineCaches") {
+        Some(result) => result,
+        None => (hooked.commands.merge_pipeline_caches)(
+            _device,
+            _dst_cache,
+            _src_cache_count,
+            _src_caches,
+        ),
+    };
+    hooked.after("vkMergePipelineCaches", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkMergeValidationCachesEXT<const S: usize>(
+    _device: Device,
+    _dst_cache: ValidationCacheEXT,
+    _src_cache_count: u32,
+    _src_caches: *const ValidationCacheEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkMergeValidationCachesEXT") {
+        Some(result) => result,
+        None => (hooked.commands.merge_validation_caches_ext)(
+            _device,
+            _dst_cache,
+            _src_cache_count,
+            _src_caches,
+        ),
+    };
+    hooked.after("vkMergeValidationCachesEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkQueueBindSparse<const S: usize>(
+    _queue: Queue,
+    _bind_info_count: u32,
+    _bind_info: *const BindSparseInfo,
+    _fence: Fence,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkQueueBindSparse") {
+        Some(result) => result,
+        None => (hooked.commands.queue_bind_sparse)(_queue, _bind_info_count, _bind_info, _fence),
+    };
+    hooked.after("vkQueueBindSparse", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkQueueNotifyOutOfBandNV<const S: usize>(
+    _queue: Queue,
+    _queue_type_info: *const OutOfBandQueueTypeInfoNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkQueueNotifyOutOfBandNV");
+    (hooked.commands.queue_notify_out_of_band_nv)(_queue, _queue_type_info);
+    hooked.after("vkQueueNotifyOutOfBandNV", None);
+}
+
+unsafe extern "system" fn vkQueuePresentKHR<const S: usize>(
+    _queue: Queue,
+    _present_info: *const PresentInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkQueuePresentKHR") {
+        Some(result) => result,
+        None => (hooked.commands.queue_present_khr)(_queue, _present_info),
+    };
+    hooked.after("vkQueuePresentKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkQueueSetPerformanceConfigurationINTEL<const S: usize>(
+    _queue: Queue,
+    _configuration: PerformanceConfigurationINTEL,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkQueueSetPerformanceConfigurationINTEL") {
+        Some(result) => result,
+        None => (hooked.commands.queue_set_performance_configuration_intel)(_queue, _configuration),
+    };
+    hooked.after("vkQueueSetPerformanceConfigurationINTEL", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkQueueSubmit<const S: usize>(
+    _queue: Queue,
+    _submit_count: u32,
+    _submits: *const SubmitInfo,
+    _fence: Fence,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkQueueSubmit") {
+        Some(result) => result,
+        None => (hooked.commands.queue_submit)(_queue, _submit_count, _submits, _fence),
+    };
+    hooked.after("vkQueueSubmit", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkQueueSubmit2<const S: usize>(
+    _queue: Queue,
+    _submit_count: u32,
+    _submits: *const SubmitInfo2,
+    _fence: Fence,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkQueueSubmit2") {
+        Some(result) => result,
+        None => (hooked.commands.queue_submit2)(_queue, _submit_count, _submits, _fence),
+    };
+    hooked.after("vkQueueSubmit2", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkQueueSubmit2KHR<const S: usize>(
+    _queue: Queue,
+    _submit_count: u32,
+    _submits: *const SubmitInfo2,
+    _fence: Fence,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkQueueSubmit2KHR") {
+        Some(result) => result,
+        None => (hooked.commands.queue_submit2_khr)(_queue, _submit_count, _submits, _fence),
+    };
+    hooked.after("vkQueueSubmit2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkQueueWaitIdle<const S: usize>(_queue: Queue) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkQueueWaitIdle") {
+        Some(result) => result,
+        None => (hooked.commands.queue_wait_idle)(_queue),
+    };
+    hooked.after("vkQueueWaitIdle", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkRegisterCustomBorderColorEXT<const S: usize>(
+    _device: Device,
+    _border_color: *const SamplerCustomBorderColorCreateInfoEXT,
+    _request_index: Bool32,
+    _index: *mut u32,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkRegisterCustomBorderColorEXT") {
+        Some(result) => result,
+        None => (hooked.commands.register_custom_border_color_ext)(
+            _device,
+            _border_color,
+            _request_index,
+            _index,
+        ),
+    };
+    hooked.after("vkRegisterCustomBorderColorEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkRegisterDeviceEventEXT<const S: usize>(
+    _device: Device,
+    _device_event_info: *const DeviceEventInfoEXT,
+    _allocator: *const AllocationCallbacks,
+    _fence: *mut Fence,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkRegisterDeviceEventEXT") {
+        Some(result) => result,
+        None => (hooked.commands.register_device_event_ext)(
+            _device,
+            _device_event_info,
+            _allocator,
+            _fence,
+        ),
+    };
+    hooked.after("vkRegisterDeviceEventEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkRegisterDisplayEventEXT<const S: usize>(
+    _device: Device,
+    _display: DisplayKHR,
+    _display_event_info: *const DisplayEventInfoEXT,
+    _allocator: *const AllocationCallbacks,
+    _fence: *mut Fence,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkRegisterDisplayEventEXT") {
+        Some(result) => result,
+        None => (hooked.commands.register_display_event_ext)(
+            _device,
+            _display,
+            _display_event_info,
+            _allocator,
+            _fence,
+        ),
+    };
+    hooked.after("vkRegisterDisplayEventEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkReleaseCapturedPipelineDataKHR<const S: usize>(
+    _device: Device,
+    _info: *const ReleaseCapturedPipelineDataInfoKHR,
+    _allocator: *const AllocationCallbacks,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkReleaseCapturedPipelineDataKHR") {
+        Some(result) => result,
+        None => (hooked.commands.release_captured_pipeline_data_khr)(_device, _info, _allocator),
+    };
+    hooked.after("vkReleaseCapturedPipelineDataKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkReleaseFullScreenExclusiveModeEXT<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkReleaseFullScreenExclusiveModeEXT") {
+        Some(result) => result,
+        None => (hooked.commands.release_full_screen_exclusive_mode_ext)(_device, _swapchain),
+    };
+    hooked.after("vkReleaseFullScreenExclusiveModeEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkReleasePerformanceConfigurationINTEL<const S: usize>(
+    _device: Device,
+    _configuration: PerformanceConfigurationINTEL,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkReleasePerformanceConfigurationINTEL") {
+        Some(result) => result,
+        None => (hooked.commands.release_performance_configuration_intel)(_device, _configuration),
+    };
+    hooked.after("vkReleasePerformanceConfigurationINTEL", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkReleaseProfilingLockKHR<const S: usize>(_device: Device) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkReleaseProfilingLockKHR");
+    (hooked.commands.release_profiling_lock_khr)(_device);
+    hooked.after("vkReleaseProfilingLockKHR", None);
+}
+
+unsafe extern "system" fn vkReleaseSwapchainImagesEXT<const S: usize>(
+    _device: Device,
+    _release_info: *const ReleaseSwapchainImagesInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkReleaseSwapchainImagesEXT") {
+        Some(result) => result,
+        None => (hooked.commands.release_swapchain_images_ext)(_device, _release_info),
+    };
+    hooked.after("vkReleaseSwapchainImagesEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkReleaseSwapchainImagesKHR<const S: usize>(
+    _device: Device,
+    _release_info: *const ReleaseSwapchainImagesInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkReleaseSwapchainImagesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.release_swapchain_images_khr)(_device, _release_info),
+    };
+    hooked.after("vkReleaseSwapchainImagesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkResetCommandBuffer<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _flags: CommandBufferResetFlags,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkResetCommandBuffer") {
+        Some(result) => result,
+        None => (hooked.commands.reset_command_buffer)(_command_buffer, _flags),
+    };
+    hooked.after("vkResetCommandBuffer", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkResetCommandPool<const S: usize>(
+    _device: Device,
+    _command_pool: CommandPool,
+    _flags: CommandPoolResetFlags,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkResetCommandPool") {
+        Some(result) => result,
+        None => (hooked.commands.reset_command_pool)(_device, _command_pool, _flags),
+    };
+    hooked.after("vkResetCommandPool", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkResetDescriptorPool<const S: usize>(
+    _device: Device,
+    _descriptor_pool: DescriptorPool,
+    _flags: DescriptorPoolResetFlags,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkResetDescriptorPool") {
+        Some(result) => result,
+        None => (hooked.commands.reset_descriptor_pool)(_device, _descriptor_pool, _flags),
+    };
+    hooked.after("vkResetDescriptorPool", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkResetEvent<const S: usize>(_device: Device, _event: Event) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkResetEvent") {
+        Some(result) => result,
+        None => (hooked.commands.reset_event)(_device, _event),
+    };
+    hooked.after("vkResetEvent", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkResetFences<const S: usize>(
+    _device: Device,
+    _fence_count: u32,
+    _fences: *const Fence,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkResetFences") {
+        Some(result) => result,
+        None => (hooked.commands.reset_fences)(_device, _fence_count, _fences),
+    };
+    hooked.after("vkResetFences", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkResetQueryPool<const S: usize>(
+    _device: Device,
+    _query_pool: QueryPool,
+    _first_query: u32,
+    _query_count: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkResetQueryPool");
+    (hooked.commands.reset_query_pool)(_device, _query_pool, _first_query, _query_count);
+    hooked.after("vkResetQueryPool", None);
+}
+
+unsafe extern "system" fn vkResetQueryPoolEXT<const S: usize>(
+    _device: Device,
+    _query_pool: QueryPool,
+    _first_query: u32,
+    _query_count: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkResetQueryPoolEXT");
+    (hooked.commands.reset_query_pool_ext)(_device, _query_pool, _first_query, _query_count);
+    hooked.after("vkResetQueryPoolEXT", None);
+}
+
+unsafe extern "system" fn vkSetBufferCollectionBufferConstraintsFUCHSIA<const S: usize>(
+    _device: Device,
+    _collection: BufferCollectionFUCHSIA,
+    _buffer_constraints_info: *const BufferConstraintsInfoFUCHSIA,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkSetBufferCollectionBufferConstraintsFUCHSIA") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .set_buffer_collection_buffer_constraints_fuchsia)(
+            _device,
+            _collection,
+            _buffer_constraints_info,
+        ),
+    };
+    hooked.after(
+        "vkSetBufferCollectionBufferConstraintsFUCHSIA",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkSetBufferCollectionImageConstraintsFUCHSIA<const S: usize>(
+    _device: Device,
+    _collection: BufferCollectionFUCHSIA,
+    _image_constraints_info: *const ImageConstraintsInfoFUCHSIA,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkSetBufferCollectionImageConstraintsFUCHSIA") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .set_buffer_collection_image_constraints_fuchsia)(
+            _device,
+            _collection,
+            _image_constraints_info,
+        ),
+    };
+    hooked.after(
+        "vkSetBufferCollectionImageConstraintsFUCHSIA",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkSetDeviceMemoryPriorityEXT<const S: usize>(
+    _device: Device,
+    _memory: DeviceMemory,
+    _priority: f32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkSetDeviceMemoryPriorityEXT");
+    (hooked.commands.set_device_memory_priority_ext)(_device, _memory, _priority);
+    hooked.after("vkSetDeviceMemoryPriorityEXT", None);
+}
+
+unsafe extern "system" fn vkSetEvent<const S: usize>(_device: Device, _event: Event) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkSetEvent") {
+        Some(result) => result,
+        None => (hooked.commands.set_event)(_device, _event),
+    };
+    hooked.after("vkSetEvent", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkSetHdrMetadataEXT<const S: usize>(
+    _device: Device,
+    _swapchain_count: u32,
+    _swapchains: *const SwapchainKHR,
+    _metadata: *const HdrMetadataEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkSetHdrMetadataEXT");
+    (hooked.commands.set_hdr_metadata_ext)(_device, _swapchain_count, _swapchains, _metadata);
+    hooked.after("vkSetHdrMetadataEXT", None);
+}
+
+unsafe extern "system" fn vkSetLatencyMarkerNV<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _latency_marker_info: *const SetLatencyMarkerInfoNV,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkSetLatencyMarkerNV");
+    (hooked.commands.set_latency_marker_nv)(_device, _swapchain, _latency_marker_info);
+    hooked.after("vkSetLatencyMarkerNV", None);
+}
+
+unsafe extern "system" fn vkSetLatencySleepModeNV<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _sleep_mode_info: *const LatencySleepModeInfoNV,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkSetLatencySleepModeNV") {
+        Some(result) => result,
+        None => (hooked.commands.set_latency_sleep_mode_nv)(_device, _swapchain, _sleep_mode_info),
+    };
+    hooked.after("vkSetLatencySleepModeNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkSetLocalDimmingAMD<const S: usize>(
+    _device: Device,
+    _swap_chain: SwapchainKHR,
+    _local_dimming_enable: Bool32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkSetLocalDimmingAMD");
+    (hooked.commands.set_local_dimming_amd)(_device, _swap_chain, _local_dimming_enable);
+    hooked.after("vkSetLocalDimmingAMD", None);
+}
+
+unsafe extern "system" fn vkSetPrivateData<const S: usize>(
+    _device: Device,
+    _object_type: ObjectType,
+    _object_handle: u64,
+    _private_data_slot: PrivateDataSlot,
+    _data: u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkSetPrivateData") {
+        Some(result) => result,
+        None => (hooked.commands.set_private_data)(
+            _device,
+            _object_type,
+            _object_handle,
+            _private_data_slot,
+            _data,
+        ),
+    };
+    hooked.after("vkSetPrivateData", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkSetPrivateDataEXT<const S: usize>(
+    _device: Device,
+    _object_type: ObjectType,
+    _object_handle: u64,
+    _private_data_slot: PrivateDataSlot,
+    _data: u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkSetPrivateDataEXT") {
+        Some(result) => result,
+        None => (hooked.commands.set_private_data_ext)(
+            _device,
+            _object_type,
+            _object_handle,
+            _private_data_slot,
+            _data,
+        ),
+    };
+    hooked.after("vkSetPrivateDataEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkSetSwapchainPresentTimingQueueSizeEXT<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _size: u32,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkSetSwapchainPresentTimingQueueSizeEXT") {
+        Some(result) => result,
+        None => (hooked.commands.set_swapchain_present_timing_queue_size_ext)(
+            _device, _swapchain, _size,
+        ),
+    };
+    hooked.after("vkSetSwapchainPresentTimingQueueSizeEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkSignalSemaphore<const S: usize>(
+    _device: Device,
+    _signal_info: *const SemaphoreSignalInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkSignalSemaphore") {
+        Some(result) => result,
+        None => (hooked.commands.signal_semaphore)(_device, _signal_info),
+    };
+    hooked.after("vkSignalSemaphore", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkSignalSemaphoreKHR<const S: usize>(
+    _device: Device,
+    _signal_info: *const SemaphoreSignalInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkSignalSemaphoreKHR") {
+        Some(result) => result,
+        None => (hooked.commands.signal_semaphore_khr)(_device, _signal_info),
+    };
+    hooked.after("vkSignalSemaphoreKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkTransitionImageLayout<const S: usize>(
+    _device: Device,
+    _transition_count: u32,
+    _transitions: *const HostImageLayoutTransitionInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkTransitionImageLayout") {
+        Some(result) => result,
+        None => (hooked.commands.transition_image_layout)(_device, _transition_count, _transitions),
+    };
+    hooked.after("vkTransitionImageLayout", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkTransitionImageLayoutEXT<const S: usize>(
+    _device: Device,
+    _transition_count: u32,
+    _transitions: *const HostImageLayoutTransitionInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkTransitionImageLayoutEXT") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.transition_image_layout_ext)(_device, _transition_count, _transitions)
+        }
+    };
+    hooked.after("vkTransitionImageLayoutEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkTrimCommandPool<const S: usize>(
+    _device: Device,
+    _command_pool: CommandPool,
+    _flags: CommandPoolTrimFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkTrimCommandPool");
+    (hooked.commands.trim_command_pool)(_device, _command_pool, _flags);
+    hooked.after("vkTrimCommandPool", None);
+}
+
+unsafe extern "system" fn vkTrimCommandPoolKHR<const S: usize>(
+    _device: Device,
+    _command_pool: CommandPool,
+    _flags: CommandPoolTrimFlags,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkTrimCommandPoolKHR");
+    (hooked.commands.trim_command_pool_khr)(_device, _command_pool, _flags);
+    hooked.after("vkTrimCommandPoolKHR", None);
+}
+
+unsafe extern "system" fn vkUninitializePerformanceApiINTEL<const S: usize>(_device: Device) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkUninitializePerformanceApiINTEL");
+    (hooked.commands.uninitialize_performance_api_intel)(_device);
+    hooked.after("vkUninitializePerformanceApiINTEL", None);
+}
+
+unsafe extern "system" fn vkUnmapMemory<const S: usize>(_device: Device, _memory: DeviceMemory) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkUnmapMemory");
+    (hooked.commands.unmap_memory)(_device, _memory);
+    hooked.after("vkUnmapMemory", None);
+}
+
+unsafe extern "system" fn vkUnmapMemory2<const S: usize>(
+    _device: Device,
+    _memory_unmap_info: *const MemoryUnmapInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkUnmapMemory2") {
+        Some(result) => result,
+        None => (hooked.commands.unmap_memory2)(_device, _memory_unmap_info),
+    };
+    hooked.after("vkUnmapMemory2", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkUnmapMemory2KHR<const S: usize>(
+    _device: Device,
+    _memory_unmap_info: *const MemoryUnmapInfo,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkUnmapMemory2KHR") {
+        Some(result) => result,
+        None => (hooked.commands.unmap_memory2_khr)(_device, _memory_unmap_info),
+    };
+    hooked.after("vkUnmapMemory2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkUnregisterCustomBorderColorEXT<const S: usize>(
+    _device: Device,
+    _index: u32,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkUnregisterCustomBorderColorEXT");
+    (hooked.commands.unregister_custom_border_color_ext)(_device, _index);
+    hooked.after("vkUnregisterCustomBorderColorEXT", None);
+}
+
+unsafe extern "system" fn vkUpdateDescriptorSetWithTemplate<const S: usize>(
+    _device: Device,
+    _descriptor_set: DescriptorSet,
+    _descriptor_update_template: DescriptorUpdateTemplate,
+    _data: *const c_void,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkUpdateDescriptorSetWithTemplate");
+    (hooked.commands.update_descriptor_set_with_template)(
+        _device,
+        _descriptor_set,
+        _descriptor_update_template,
+        _data,
+    );
+    hooked.after("vkUpdateDescriptorSetWithTemplate", None);
+}
+
+unsafe extern "system" fn vkUpdateDescriptorSetWithTemplateKHR<const S: usize>(
+    _device: Device,
+    _descriptor_set: DescriptorSet,
+    _descriptor_update_template: DescriptorUpdateTemplate,
+    _data: *const c_void,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkUpdateDescriptorSetWithTemplateKHR");
+    (hooked.commands.update_descriptor_set_with_template_khr)(
+        _device,
+        _descriptor_set,
+        _descriptor_update_template,
+        _data,
+    );
+    hooked.after("vkUpdateDescriptorSetWithTemplateKHR", None);
+}
+
+unsafe extern "system" fn vkUpdateDescriptorSets<const S: usize>(
+    _device: Device,
+    _descriptor_write_count: u32,
+    _descriptor_writes: *const WriteDescriptorSet,
+    _descriptor_copy_count: u32,
+    _descriptor_copies: *const CopyDescriptorSet,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkUpdateDescriptorSets");
+    (hooked.commands.update_descriptor_sets)(
+        _device,
+        _descriptor_write_count,
+        _descriptor_writes,
+        _descriptor_copy_count,
+        _descriptor_copies,
+    );
+    hooked.after("vkUpdateDescriptorSets", None);
+}
+
+unsafe extern "system" fn vkUpdateIndirectExecutionSetPipelineEXT<const S: usize>(
+    _device: Device,
+    _indirect_execution_set: IndirectExecutionSetEXT,
+    _execution_set_write_count: u32,
+    _execution_set_writes: *const WriteIndirectExecutionSetPipelineEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkUpdateIndirectExecutionSetPipelineEXT");
+    (hooked.commands.update_indirect_execution_set_pipeline_ext)(
+        _device,
+        _indirect_execution_set,
+        _execution_set_write_count,
+        _execution_set_writes,
+    );
+    hooked.after("vkUpdateIndirectExecutionSetPipelineEXT", None);
+}
+
+unsafe extern "system" fn vkUpdateIndirectExecutionSetShaderEXT<const S: usize>(
+    _device: Device,
+    _indirect_execution_set: IndirectExecutionSetEXT,
+    _execution_set_write_count: u32,
+    _execution_set_writes: *const WriteIndirectExecutionSetShaderEXT,
+) {
+    let hooked = crate::hook::device::<S>();
+
+    hooked.before("vkUpdateIndirectExecutionSetShaderEXT");
+    (hooked.commands.update_indirect_execution_set_shader_ext)(
+        _device,
+        _indirect_execution_set,
+        _execution_set_write_count,
+        _execution_set_writes,
+    );
+    hooked.after("vkUpdateIndirectExecutionSetShaderEXT", None);
+}
+
+unsafe extern "system" fn vkUpdateVideoSessionParametersKHR<const S: usize>(
+    _device: Device,
+    _video_session_parameters: VideoSessionParametersKHR,
+    _update_info: *const VideoSessionParametersUpdateInfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkUpdateVideoSessionParametersKHR") {
+        Some(result) => result,
+        None => (hooked.commands.update_video_session_parameters_khr)(
+            _device,
+            _video_session_parameters,
+            _update_info,
+        ),
+    };
+    hooked.after("vkUpdateVideoSessionParametersKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkWaitForFences<const S: usize>(
+    _device: Device,
+    _fence_count: u32,
+    _fences: *const Fence,
+    _wait_all: Bool32,
+    _timeout: u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkWaitForFences") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.wait_for_fences)(_device, _fence_count, _fences, _wait_all, _timeout)
+        }
+    };
+    hooked.after("vkWaitForFences", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkWaitForPresent2KHR<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _present_wait2_info: *const PresentWait2InfoKHR,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkWaitForPresent2KHR") {
+        Some(result) => result,
+        None => (hooked.commands.wait_for_present2_khr)(_device, _swapchain, _present_wait2_info),
+    };
+    hooked.after("vkWaitForPresent2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkWaitForPresentKHR<const S: usize>(
+    _device: Device,
+    _swapchain: SwapchainKHR,
+    _present_id: u64,
+    _timeout: u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkWaitForPresentKHR") {
+        Some(result) => result,
+        None => (hooked.commands.wait_for_present_khr)(_device, _swapchain, _present_id, _timeout),
+    };
+    hooked.after("vkWaitForPresentKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkWaitSemaphores<const S: usize>(
+    _device: Device,
+    _wait_info: *const SemaphoreWaitInfo,
+    _timeout: u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkWaitSemaphores") {
+        Some(result) => result,
+        None => (hooked.commands.wait_semaphores)(_device, _wait_info, _timeout),
+    };
+    hooked.after("vkWaitSemaphores", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkWaitSemaphoresKHR<const S: usize>(
+    _device: Device,
+    _wait_info: *const SemaphoreWaitInfo,
+    _timeout: u64,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkWaitSemaphoresKHR") {
+        Some(result) => result,
+        None => (hooked.commands.wait_semaphores_khr)(_device, _wait_info, _timeout),
+    };
+    hooked.after("vkWaitSemaphoresKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkWriteAccelerationStructuresPropertiesKHR<const S: usize>(
+    _device: Device,
+    _acceleration_structure_count: u32,
+    _acceleration_structures: *const AccelerationStructureKHR,
+    _query_type: QueryType,
+    _data_size: usize,
+    _data: *mut c_void,
+    _stride: usize,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkWriteAccelerationStructuresPropertiesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.write_acceleration_structures_properties_khr)(
+            _device,
+            _acceleration_structure_count,
+            _acceleration_structures,
+            _query_type,
+            _data_size,
+            _data,
+            _stride,
+        ),
+    };
+    hooked.after("vkWriteAccelerationStructuresPropertiesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkWriteMicromapsPropertiesEXT<const S: usize>(
+    _device: Device,
+    _micromap_count: u32,
+    _micromaps: *const MicromapEXT,
+    _query_type: QueryType,
+    _data_size: usize,
+    _data: *mut c_void,
+    _stride: usize,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkWriteMicromapsPropertiesEXT") {
+        Some(result) => result,
+        None => (hooked.commands.write_micromaps_properties_ext)(
+            _device,
+            _micromap_count,
+            _micromaps,
+            _query_type,
+            _data_size,
+            _data,
+            _stride,
+        ),
+    };
+    hooked.after("vkWriteMicromapsPropertiesEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkWriteResourceDescriptorsEXT<const S: usize>(
+    _device: Device,
+    _resource_count: u32,
+    _resources: *const ResourceDescriptorInfoEXT,
+    _descriptors: *const HostAddressRangeEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkWriteResourceDescriptorsEXT") {
+        Some(result) => result,
+        None => (hooked.commands.write_resource_descriptors_ext)(
+            _device,
+            _resource_count,
+            _resources,
+            _descriptors,
+        ),
+    };
+    hooked.after("vkWriteResourceDescriptorsEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkWriteSamplerDescriptorsEXT<const S: usize>(
+    _device: Device,
+    _sampler_count: u32,
+    _samplers: *const SamplerCreateInfo,
+    _descriptors: *const HostAddressRangeEXT,
+) -> Result {
+    let hooked = crate::hook::device::<S>();
+
+    let __result = match hooked.before("vkWriteSamplerDescriptorsEXT") {
+        Some(result) => result,
+        None => (hooked.commands.write_sampler_descriptors_ext)(
+            _device,
+            _sampler_count,
+            _samplers,
+            _descriptors,
+        ),
+    };
+    hooked.after("vkWriteSamplerDescriptorsEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkAcquireDrmDisplayEXT<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _drm_fd: i32,
+    _display: DisplayKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkAcquireDrmDisplayEXT") {
+        Some(result) => result,
+        None => (hooked.commands.acquire_drm_display_ext)(_physical_device, _drm_fd, _display),
+    };
+    hooked.after("vkAcquireDrmDisplayEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkAcquireWinrtDisplayNV<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _display: DisplayKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkAcquireWinrtDisplayNV") {
+        Some(result) => result,
+        None => (hooked.commands.acquire_winrt_display_nv)(_physical_device, _display),
+    };
+    hooked.after("vkAcquireWinrtDisplayNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkAcquireXlibDisplayEXT<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _dpy: *mut Display,
+    _display: DisplayKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkAcquireXlibDisplayEXT") {
+        Some(result) => result,
+        None => (hooked.commands.acquire_xlib_display_ext)(_physical_device, _dpy, _display),
+    };
+    hooked.after("vkAcquireXlibDisplayEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCmdBeginDebugUtilsLabelEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _label_info: *const DebugUtilsLabelEXT,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkCmdBeginDebugUtilsLabelEXT");
+    (hooked.commands.cmd_begin_debug_utils_label_ext)(_command_buffer, _label_info);
+    hooked.after("vkCmdBeginDebugUtilsLabelEXT", None);
+}
+
+unsafe extern "system" fn vkCmdEndDebugUtilsLabelEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkCmdEndDebugUtilsLabelEXT");
+    (hooked.commands.cmd_end_debug_utils_label_ext)(_command_buffer);
+    hooked.after("vkCmdEndDebugUtilsLabelEXT", None);
+}
+
+unsafe extern "system" fn vkCmdInsertDebugUtilsLabelEXT<const S: usize>(
+    _command_buffer: CommandBuffer,
+    _label_info: *const DebugUtilsLabelEXT,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkCmdInsertDebugUtilsLabelEXT");
+    (hooked.commands.cmd_insert_debug_utils_label_ext)(_command_buffer, _label_info);
+    hooked.after("vkCmdInsertDebugUtilsLabelEXT", None);
+}
+
+unsafe extern "system" fn vkCreateAndroidSurfaceKHR<const S: usize>(
+    _instance: Instance,
+    _create_info: *const AndroidSurfaceCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateAndroidSurfaceKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_android_surface_khr)(
+            _instance,
+            _create_info,
+            _allocator,
+            _surface,
+        ),
+    };
+    hooked.after("vkCreateAndroidSurfaceKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDebugReportCallbackEXT<const S: usize>(
+    _instance: Instance,
+    _create_info: *const DebugReportCallbackCreateInfoEXT,
+    _allocator: *const AllocationCallbacks,
+    _callback: *mut DebugReportCallbackEXT,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateDebugReportCallbackEXT") {
+        Some(result) => result,
+        None => (hooked.commands.create_debug_report_callback_ext)(
+            _instance,
+            _create_info,
+            _allocator,
+            _callback,
+        ),
+    };
+    hooked.after("vkCreateDebugReportCallbackEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDebugUtilsMessengerEXT<const S: usize>(
+    _instance: Instance,
+    _create_info: *const DebugUtilsMessengerCreateInfoEXT,
+    _allocator: *const AllocationCallbacks,
+    _messenger: *mut DebugUtilsMessengerEXT,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateDebugUtilsMessengerEXT") {
+        Some(result) => result,
+        None => (hooked.commands.create_debug_utils_messenger_ext)(
+            _instance,
+            _create_info,
+            _allocator,
+            _messenger,
+        ),
+    };
+    hooked.after("vkCreateDebugUtilsMessengerEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDevice<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _create_info: *const DeviceCreateInfo,
+    _allocator: *const AllocationCallbacks,
+    _device: *mut Device,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateDevice") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_device)(_physical_device, _create_info, _allocator, _device)
+        }
+    };
+    hooked.after("vkCreateDevice", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDirectFBSurfaceEXT<const S: usize>(
+    _instance: Instance,
+    _create_info: *const DirectFBSurfaceCreateInfoEXT,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateDirectFBSurfaceEXT") {
+        Some(result) => result,
+        None => (hooked.commands.create_direct_fb_surface_ext)(
+            _instance,
+            _create_info,
+            _allocator,
+            _surface,
+        ),
+    };
+    hooked.after("vkCreateDirectFBSurfaceEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDisplayModeKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _display: DisplayKHR,
+    _create_info: *const DisplayModeCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _mode: *mut DisplayModeKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateDisplayModeKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_display_mode_khr)(
+            _physical_device,
+            _display,
+            _create_info,
+            _allocator,
+            _mode,
+        ),
+    };
+    hooked.after("vkCreateDisplayModeKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateDisplayPlaneSurfaceKHR<const S: usize>(
+    _instance: Instance,
+    _create_info: *const DisplaySurfaceCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateDisplayPlaneSurfaceKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_display_plane_surface_khr)(
+            _instance,
+            _create_info,
+            _allocator,
+            _surface,
+        ),
+    };
+    hooked.after("vkCreateDisplayPlaneSurfaceKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateHeadlessSurfaceEXT<const S: usize>(
+    _instance: Instance,
+    _create_info: *const HeadlessSurfaceCreateInfoEXT,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateHeadlessSurfaceEXT") {
+        Some(result) => result,
+        None => (hooked.commands.create_headless_surface_ext)(
+            _instance,
+            _create_info,
+            _allocator,
+            _surface,
+        ),
+    };
+    hooked.after("vkCreateHeadlessSurfaceEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateIOSSurfaceMVK<const S: usize>(
+    _instance: Instance,
+    _create_info: *const IOSSurfaceCreateInfoMVK,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateIOSSurfaceMVK") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_ios_surface_mvk)(_instance, _create_info, _allocator, _surface)
+        }
+    };
+    hooked.after("vkCreateIOSSurfaceMVK", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateImagePipeSurfaceFUCHSIA<const S: usize>(
+    _instance: Instance,
+    _create_info: *const ImagePipeSurfaceCreateInfoFUCHSIA,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateImagePipeSurfaceFUCHSIA") {
+        Some(result) => result,
+        None => (hooked.commands.create_image_pipe_surface_fuchsia)(
+            _instance,
+            _create_info,
+            _allocator,
+            _surface,
+        ),
+    };
+    hooked.after("vkCreateImagePipeSurfaceFUCHSIA", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateMacOSSurfaceMVK<const S: usize>(
+    _instance: Instance,
+    _create_info: *const MacOSSurfaceCreateInfoMVK,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateMacOSSurfaceMVK") {
+        Some(result) => result,
+        None => (hooked.commands.create_mac_os_surface_mvk)(
+            _instance,
+            _create_info,
+            _allocator,
+            _surface,
+        ),
+    };
+    hooked.after("vkCreateMacOSSurfaceMVK", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateMetalSurfaceEXT<const S: usize>(
+    _instance: Instance,
+    _create_info: *const MetalSurfaceCreateInfoEXT,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateMetalSurfaceEXT") {
+        Some(result) => result,
+        None => (hooked.commands.create_metal_surface_ext)(
+            _instance,
+            _create_info,
+            _allocator,
+            _surface,
+        ),
+    };
+    hooked.after("vkCreateMetalSurfaceEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateScreenSurfaceQNX<const S: usize>(
+    _instance: Instance,
+    _create_info: *const ScreenSurfaceCreateInfoQNX,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateScreenSurfaceQNX") {
+        Some(result) => result,
+        None => (hooked.commands.create_screen_surface_qnx)(
+            _instance,
+            _create_info,
+            _allocator,
+            _surface,
+        ),
+    };
+    hooked.after("vkCreateScreenSurfaceQNX", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateStreamDescriptorSurfaceGGP<const S: usize>(
+    _instance: Instance,
+    _create_info: *const StreamDescriptorSurfaceCreateInfoGGP,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateStreamDescriptorSurfaceGGP") {
+        Some(result) => result,
+        None => (hooked.commands.create_stream_descriptor_surface_ggp)(
+            _instance,
+            _create_info,
+            _allocator,
+            _surface,
+        ),
+    };
+    hooked.after("vkCreateStreamDescriptorSurfaceGGP", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateSurfaceOHOS<const S: usize>(
+    _instance: Instance,
+    _create_info: *const SurfaceCreateInfoOHOS,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateSurfaceOHOS") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_surface_ohos)(_instance, _create_info, _allocator, _surface)
+        }
+    };
+    hooked.after("vkCreateSurfaceOHOS", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateUbmSurfaceSEC<const S: usize>(
+    _instance: Instance,
+    _create_info: *const UbmSurfaceCreateInfoSEC,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateUbmSurfaceSEC") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_ubm_surface_sec)(_instance, _create_info, _allocator, _surface)
+        }
+    };
+    hooked.after("vkCreateUbmSurfaceSEC", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateViSurfaceNN<const S: usize>(
+    _instance: Instance,
+    _create_info: *const ViSurfaceCreateInfoNN,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateViSurfaceNN") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_vi_surface_nn)(_instance, _create_info, _allocator, _surface)
+        }
+    };
+    hooked.after("vkCreateViSurfaceNN", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateWaylandSurfaceKHR<const S: usize>(
+    _instance: Instance,
+    _create_info: *const WaylandSurfaceCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateWaylandSurfaceKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_wayland_surface_khr)(
+            _instance,
+            _create_info,
+            _allocator,
+            _surface,
+        ),
+    };
+    hooked.after("vkCreateWaylandSurfaceKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateWin32SurfaceKHR<const S: usize>(
+    _instance: Instance,
+    _create_info: *const Win32SurfaceCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateWin32SurfaceKHR") {
+        Some(result) => result,
+        None => (hooked.commands.create_win32_surface_khr)(
+            _instance,
+            _create_info,
+            _allocator,
+            _surface,
+        ),
+    };
+    hooked.after("vkCreateWin32SurfaceKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateXcbSurfaceKHR<const S: usize>(
+    _instance: Instance,
+    _create_info: *const XcbSurfaceCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateXcbSurfaceKHR") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_xcb_surface_khr)(_instance, _create_info, _allocator, _surface)
+        }
+    };
+    hooked.after("vkCreateXcbSurfaceKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkCreateXlibSurfaceKHR<const S: usize>(
+    _instance: Instance,
+    _create_info: *const XlibSurfaceCreateInfoKHR,
+    _allocator: *const AllocationCallbacks,
+    _surface: *mut SurfaceKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkCreateXlibSurfaceKHR") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.create_xlib_surface_khr)(_instance, _create_info, _allocator, _surface)
+        }
+    };
+    hooked.after("vkCreateXlibSurfaceKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkDebugReportMessageEXT<const S: usize>(
+    _instance: Instance,
+    _flags: DebugReportFlagsEXT,
+    _object_type: DebugReportObjectTypeEXT,
+    _object: u64,
+    _location: usize,
+    _message_code: i32,
+    _layer_prefix: *const c_char,
+    _message: *const c_char,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkDebugReportMessageEXT");
+    (hooked.commands.debug_report_message_ext)(
+        _instance,
+        _flags,
+        _object_type,
+        _object,
+        _location,
+        _message_code,
+        _layer_prefix,
+        _message,
+    );
+    hooked.after("vkDebugReportMessageEXT", None);
+}
+
+unsafe extern "system" fn vkDestroyDebugReportCallbackEXT<const S: usize>(
+    _instance: Instance,
+    _callback: DebugReportCallbackEXT,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkDestroyDebugReportCallbackEXT");
+    (hooked.commands.destroy_debug_report_callback_ext)(_instance, _callback, _allocator);
+    hooked.after("vkDestroyDebugReportCallbackEXT", None);
+}
+
+unsafe extern "system" fn vkDestroyDebugUtilsMessengerEXT<const S: usize>(
+    _instance: Instance,
+    _messenger: DebugUtilsMessengerEXT,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkDestroyDebugUtilsMessengerEXT");
+    (hooked.commands.destroy_debug_utils_messenger_ext)(_instance, _messenger, _allocator);
+    hooked.after("vkDestroyDebugUtilsMessengerEXT", None);
+}
+
+unsafe extern "system" fn vkDestroyInstance<const S: usize>(
+    _instance: Instance,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkDestroyInstance");
+    (hooked.commands.destroy_instance)(_instance, _allocator);
+    hooked.after("vkDestroyInstance", None);
+}
+
+unsafe extern "system" fn vkDestroySurfaceKHR<const S: usize>(
+    _instance: Instance,
+    _surface: SurfaceKHR,
+    _allocator: *const AllocationCallbacks,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkDestroySurfaceKHR");
+    (hooked.commands.destroy_surface_khr)(_instance, _surface, _allocator);
+    hooked.after("vkDestroySurfaceKHR", None);
+}
+
+unsafe extern "system" fn vkEnumerateDeviceExtensionProperties<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _layer_name: *const c_char,
+    _property_count: *mut u32,
+    _properties: *mut ExtensionProperties,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkEnumerateDeviceExtensionProperties") {
+        Some(result) => result,
+        None => (hooked.commands.enumerate_device_extension_properties)(
+            _physical_device,
+            _layer_name,
+            _property_count,
+            _properties,
+        ),
+    };
+    hooked.after("vkEnumerateDeviceExtensionProperties", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkEnumerateDeviceLayerProperties<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _property_count: *mut u32,
+    _properties: *mut LayerProperties,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkEnumerateDeviceLayerProperties") {
+        Some(result) => result,
+        None => (hooked.commands.enumerate_device_layer_properties)(
+            _physical_device,
+            _property_count,
+            _properties,
+        ),
+    };
+    hooked.after("vkEnumerateDeviceLayerProperties", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkEnumeratePhysicalDeviceGroups<const S: usize>(
+    _instance: Instance,
+    _physical_device_group_count: *mut u32,
+    _physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkEnumeratePhysicalDeviceGroups") {
+        Some(result) => result,
+        None => (hooked.commands.enumerate_physical_device_groups)(
+            _instance,
+            _physical_device_group_count,
+            _physical_device_group_properties,
+        ),
+    };
+    hooked.after("vkEnumeratePhysicalDeviceGroups", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkEnumeratePhysicalDeviceGroupsKHR<const S: usize>(
+    _instance: Instance,
+    _physical_device_group_count: *mut u32,
+    _physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkEnumeratePhysicalDeviceGroupsKHR") {
+        Some(result) => result,
+        None => (hooked.commands.enumerate_physical_device_groups_khr)(
+            _instance,
+            _physical_device_group_count,
+            _physical_device_group_properties,
+        ),
+    };
+    hooked.after("vkEnumeratePhysicalDeviceGroupsKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkEnumeratePhysicalDeviceQueueFamilyPerformanceCountersByRegionARM<
+    const S: usize,
+>(
+    _physical_device: PhysicalDevice,
+    _queue_family_index: u32,
+    _counter_count: *mut u32,
+    _counters: *mut PerformanceCounterARM,
+    _counter_descriptions: *mut PerformanceCounterDescriptionARM,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result =
+        match hooked.before("vkEnumeratePhysicalDeviceQueueFamilyPerformanceCountersByRegionARM") {
+            Some(result) => result,
+            None => (hooked
+                .commands
+                .enumerate_physical_device_queue_family_performance_counters_by_region_arm)(
+                _physical_device,
+                _queue_family_index,
+                _counter_count,
+                _counters,
+                _counter_descriptions,
+            ),
+        };
+    hooked.after(
+        "vkEnumeratePhysicalDeviceQueueFamilyPerformanceCountersByRegionARM",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR<
+    const S: usize,
+>(
+    _physical_device: PhysicalDevice,
+    _queue_family_index: u32,
+    _counter_count: *mut u32,
+    _counters: *mut PerformanceCounterKHR,
+    _counter_descriptions: *mut PerformanceCounterDescriptionKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result =
+        match hooked.before("vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR") {
+            Some(result) => result,
+            None => (hooked
+                .commands
+                .enumerate_physical_device_queue_family_performance_query_counters_khr)(
+                _physical_device,
+                _queue_family_index,
+                _counter_count,
+                _counters,
+                _counter_descriptions,
+            ),
+        };
+    hooked.after(
+        "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkEnumeratePhysicalDevices<const S: usize>(
+    _instance: Instance,
+    _physical_device_count: *mut u32,
+    _physical_devices: *mut PhysicalDevice,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkEnumeratePhysicalDevices") {
+        Some(result) => result,
+        None => (hooked.commands.enumerate_physical_devices)(
+            _instance,
+            _physical_device_count,
+            _physical_devices,
+        ),
+    };
+    hooked.after("vkEnumeratePhysicalDevices", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDeviceProcAddr<const S: usize>(
+    _device: Device,
+    _name: *const c_char,
+) -> PFN_vkVoidFunction {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetDeviceProcAddr");
+    let __result = (hooked.commands.get_device_proc_addr)(_device, _name);
+    hooked.after("vkGetDeviceProcAddr", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetDisplayModeProperties2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _display: DisplayKHR,
+    _property_count: *mut u32,
+    _properties: *mut DisplayModeProperties2KHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetDisplayModeProperties2KHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_display_mode_properties2_khr)(
+            _physical_device,
+            _display,
+            _property_count,
+            _properties,
+        ),
+    };
+    hooked.after("vkGetDisplayModeProperties2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDisplayModePropertiesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _display: DisplayKHR,
+    _property_count: *mut u32,
+    _properties: *mut DisplayModePropertiesKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetDisplayModePropertiesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_display_mode_properties_khr)(
+            _physical_device,
+            _display,
+            _property_count,
+            _properties,
+        ),
+    };
+    hooked.after("vkGetDisplayModePropertiesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDisplayPlaneCapabilities2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _display_plane_info: *const DisplayPlaneInfo2KHR,
+    _capabilities: *mut DisplayPlaneCapabilities2KHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetDisplayPlaneCapabilities2KHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_display_plane_capabilities2_khr)(
+            _physical_device,
+            _display_plane_info,
+            _capabilities,
+        ),
+    };
+    hooked.after("vkGetDisplayPlaneCapabilities2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDisplayPlaneCapabilitiesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _mode: DisplayModeKHR,
+    _plane_index: u32,
+    _capabilities: *mut DisplayPlaneCapabilitiesKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetDisplayPlaneCapabilitiesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_display_plane_capabilities_khr)(
+            _physical_device,
+            _mode,
+            _plane_index,
+            _capabilities,
+        ),
+    };
+    hooked.after("vkGetDisplayPlaneCapabilitiesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDisplayPlaneSupportedDisplaysKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _plane_index: u32,
+    _display_count: *mut u32,
+    _displays: *mut DisplayKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetDisplayPlaneSupportedDisplaysKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_display_plane_supported_displays_khr)(
+            _physical_device,
+            _plane_index,
+            _display_count,
+            _displays,
+        ),
+    };
+    hooked.after("vkGetDisplayPlaneSupportedDisplaysKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetDrmDisplayEXT<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _drm_fd: i32,
+    _connector_id: u32,
+    _display: *mut DisplayKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetDrmDisplayEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_drm_display_ext)(
+            _physical_device,
+            _drm_fd,
+            _connector_id,
+            _display,
+        ),
+    };
+    hooked.after("vkGetDrmDisplayEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceCalibrateableTimeDomainsEXT<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _time_domain_count: *mut u32,
+    _time_domains: *mut TimeDomainKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceCalibrateableTimeDomainsEXT") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_calibrateable_time_domains_ext)(
+            _physical_device,
+            _time_domain_count,
+            _time_domains,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceCalibrateableTimeDomainsKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _time_domain_count: *mut u32,
+    _time_domains: *mut TimeDomainKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceCalibrateableTimeDomainsKHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_calibrateable_time_domains_khr)(
+            _physical_device,
+            _time_domain_count,
+            _time_domains,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceCalibrateableTimeDomainsKHR",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceCooperativeMatrixFlexibleDimensionsPropertiesNV<
+    const S: usize,
+>(
+    _physical_device: PhysicalDevice,
+    _property_count: *mut u32,
+    _properties: *mut CooperativeMatrixFlexibleDimensionsPropertiesNV,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result =
+        match hooked.before("vkGetPhysicalDeviceCooperativeMatrixFlexibleDimensionsPropertiesNV") {
+            Some(result) => result,
+            None => (hooked
+                .commands
+                .get_physical_device_cooperative_matrix_flexible_dimensions_properties_nv)(
+                _physical_device,
+                _property_count,
+                _properties,
+            ),
+        };
+    hooked.after(
+        "vkGetPhysicalDeviceCooperativeMatrixFlexibleDimensionsPropertiesNV",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceCooperativeMatrixPropertiesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _property_count: *mut u32,
+    _properties: *mut CooperativeMatrixPropertiesKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceCooperativeMatrixPropertiesKHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_cooperative_matrix_properties_khr)(
+            _physical_device,
+            _property_count,
+            _properties,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceCooperativeMatrixPropertiesKHR",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceCooperativeMatrixPropertiesNV<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _property_count: *mut u32,
+    _properties: *mut CooperativeMatrixPropertiesNV,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceCooperativeMatrixPropertiesNV") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_cooperative_matrix_properties_nv)(
+            _physical_device,
+            _property_count,
+            _properties,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceCooperativeVectorPropertiesNV<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _property_count: *mut u32,
+    _properties: *mut CooperativeVectorPropertiesNV,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceCooperativeVectorPropertiesNV") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_cooperative_vector_properties_nv)(
+            _physical_device,
+            _property_count,
+            _properties,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceCooperativeVectorPropertiesNV",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceDescriptorSizeEXT<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _descriptor_type: DescriptorType,
+) -> DeviceSize {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceDescriptorSizeEXT");
+    let __result = (hooked.commands.get_physical_device_descriptor_size_ext)(
+        _physical_device,
+        _descriptor_type,
+    );
+    hooked.after("vkGetPhysicalDeviceDescriptorSizeEXT", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceDirectFBPresentationSupportEXT<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _queue_family_index: u32,
+    _dfb: *mut IDirectFB,
+) -> Bool32 {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceDirectFBPresentationSupportEXT");
+    let __result = (hooked
+        .commands
+        .get_physical_device_direct_fb_presentation_support_ext)(
+        _physical_device,
+        _queue_family_index,
+        _dfb,
+    );
+    hooked.after("vkGetPhysicalDeviceDirectFBPresentationSupportEXT", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceDisplayPlaneProperties2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _property_count: *mut u32,
+    _properties: *mut DisplayPlaneProperties2KHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceDisplayPlaneProperties2KHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_display_plane_properties2_khr)(
+            _physical_device,
+            _property_count,
+            _properties,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceDisplayPlanePropertiesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _property_count: *mut u32,
+    _properties: *mut DisplayPlanePropertiesKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceDisplayPlanePropertiesKHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_display_plane_properties_khr)(
+            _physical_device,
+            _property_count,
+            _properties,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceDisplayProperties2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _property_count: *mut u32,
+    _properties: *mut DisplayProperties2KHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceDisplayProperties2KHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_display_properties2_khr)(
+            _physical_device,
+            _property_count,
+            _properties,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceDisplayProperties2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceDisplayPropertiesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _property_count: *mut u32,
+    _properties: *mut DisplayPropertiesKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceDisplayPropertiesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_display_properties_khr)(
+            _physical_device,
+            _property_count,
+            _properties,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceDisplayPropertiesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceExternalBufferProperties<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
+    _external_buffer_properties: *mut ExternalBufferProperties,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceExternalBufferProperties");
+    (hooked
+        .commands
+        .get_physical_device_external_buffer_properties)(
+        _physical_device,
+        _external_buffer_info,
+        _external_buffer_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceExternalBufferProperties", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceExternalBufferPropertiesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
+    _external_buffer_properties: *mut ExternalBufferProperties,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceExternalBufferPropertiesKHR");
+    (hooked
+        .commands
+        .get_physical_device_external_buffer_properties_khr)(
+        _physical_device,
+        _external_buffer_info,
+        _external_buffer_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceExternalBufferPropertiesKHR", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceExternalFenceProperties<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _external_fence_info: *const PhysicalDeviceExternalFenceInfo,
+    _external_fence_properties: *mut ExternalFenceProperties,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceExternalFenceProperties");
+    (hooked
+        .commands
+        .get_physical_device_external_fence_properties)(
+        _physical_device,
+        _external_fence_info,
+        _external_fence_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceExternalFenceProperties", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceExternalFencePropertiesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _external_fence_info: *const PhysicalDeviceExternalFenceInfo,
+    _external_fence_properties: *mut ExternalFenceProperties,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceExternalFencePropertiesKHR");
+    (hooked
+        .commands
+        .get_physical_device_external_fence_properties_khr)(
+        _physical_device,
+        _external_fence_info,
+        _external_fence_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceExternalFencePropertiesKHR", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceExternalImageFormatPropertiesNV<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _format: Format,
+    _type_: ImageType,
+    _tiling: ImageTiling,
+    _usage: ImageUsageFlags,
+    _flags: ImageCreateFlags,
+    _external_handle_type: ExternalMemoryHandleTypeFlagsNV,
+    _external_image_format_properties: *mut ExternalImageFormatPropertiesNV,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceExternalImageFormatPropertiesNV") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_external_image_format_properties_nv)(
+            _physical_device,
+            _format,
+            _type_,
+            _tiling,
+            _usage,
+            _flags,
+            _external_handle_type,
+            _external_image_format_properties,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceExternalImageFormatPropertiesNV",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceExternalMemorySciBufPropertiesNV<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _handle_type: ExternalMemoryHandleTypeFlags,
+    _handle: NvSciBufObj,
+    _memory_sci_buf_properties: *mut MemorySciBufPropertiesNV,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceExternalMemorySciBufPropertiesNV") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_external_memory_sci_buf_properties_nv)(
+            _physical_device,
+            _handle_type,
+            _handle,
+            _memory_sci_buf_properties,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceExternalMemorySciBufPropertiesNV",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceExternalSemaphoreProperties<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
+    _external_semaphore_properties: *mut ExternalSemaphoreProperties,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceExternalSemaphoreProperties");
+    (hooked
+        .commands
+        .get_physical_device_external_semaphore_properties)(
+        _physical_device,
+        _external_semaphore_info,
+        _external_semaphore_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceExternalSemaphoreProperties", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceExternalSemaphorePropertiesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
+    _external_semaphore_properties: *mut ExternalSemaphoreProperties,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceExternalSemaphorePropertiesKHR");
+    (hooked
+        .commands
+        .get_physical_device_external_semaphore_properties_khr)(
+        _physical_device,
+        _external_semaphore_info,
+        _external_semaphore_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceExternalSemaphorePropertiesKHR", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceExternalTensorPropertiesARM<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _external_tensor_info: *const PhysicalDeviceExternalTensorInfoARM,
+    _external_tensor_properties: *mut ExternalTensorPropertiesARM,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceExternalTensorPropertiesARM");
+    (hooked
+        .commands
+        .get_physical_device_external_tensor_properties_arm)(
+        _physical_device,
+        _external_tensor_info,
+        _external_tensor_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceExternalTensorPropertiesARM", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceFeatures<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _features: *mut PhysicalDeviceFeatures,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceFeatures");
+    (hooked.commands.get_physical_device_features)(_physical_device, _features);
+    hooked.after("vkGetPhysicalDeviceFeatures", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceFeatures2<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _features: *mut PhysicalDeviceFeatures2,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceFeatures2");
+    (hooked.commands.get_physical_device_features2)(_physical_device, _features);
+    hooked.after("vkGetPhysicalDeviceFeatures2", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceFeatures2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _features: *mut PhysicalDeviceFeatures2,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceFeatures2KHR");
+    (hooked.commands.get_physical_device_features2_khr)(_physical_device, _features);
+    hooked.after("vkGetPhysicalDeviceFeatures2KHR", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceFormatProperties<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _format: Format,
+    _format_properties: *mut FormatProperties,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceFormatProperties");
+    (hooked.commands.get_physical_device_format_properties)(
+        _physical_device,
+        _format,
+        _format_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceFormatProperties", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceFormatProperties2<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _format: Format,
+    _format_properties: *mut FormatProperties2,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceFormatProperties2");
+    (hooked.commands.get_physical_device_format_properties2)(
+        _physical_device,
+        _format,
+        _format_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceFormatProperties2", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceFormatProperties2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _format: Format,
+    _format_properties: *mut FormatProperties2,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceFormatProperties2KHR");
+    (hooked.commands.get_physical_device_format_properties2_khr)(
+        _physical_device,
+        _format,
+        _format_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceFormatProperties2KHR", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceFragmentShadingRatesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _fragment_shading_rate_count: *mut u32,
+    _fragment_shading_rates: *mut PhysicalDeviceFragmentShadingRateKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceFragmentShadingRatesKHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_fragment_shading_rates_khr)(
+            _physical_device,
+            _fragment_shading_rate_count,
+            _fragment_shading_rates,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceFragmentShadingRatesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceImageFormatProperties<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _format: Format,
+    _type_: ImageType,
+    _tiling: ImageTiling,
+    _usage: ImageUsageFlags,
+    _flags: ImageCreateFlags,
+    _image_format_properties: *mut ImageFormatProperties,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceImageFormatProperties") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_image_format_properties)(
+            _physical_device,
+            _format,
+            _type_,
+            _tiling,
+            _usage,
+            _flags,
+            _image_format_properties,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceImageFormatProperties", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceImageFormatProperties2<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _image_format_info: *const PhysicalDeviceImageFormatInfo2,
+    _image_format_properties: *mut ImageFormatProperties2,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceImageFormatProperties2") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_image_format_properties2)(
+            _physical_device,
+            _image_format_info,
+            _image_format_properties,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceImageFormatProperties2", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceImageFormatProperties2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _image_format_info: *const PhysicalDeviceImageFormatInfo2,
+    _image_format_properties: *mut ImageFormatProperties2,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceImageFormatProperties2KHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_image_format_properties2_khr)(
+            _physical_device,
+            _image_format_info,
+            _image_format_properties,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceImageFormatProperties2KHR",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceMemoryProperties<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _memory_properties: *mut PhysicalDeviceMemoryProperties,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceMemoryProperties");
+    (hooked.commands.get_physical_device_memory_properties)(_physical_device, _memory_properties);
+    hooked.after("vkGetPhysicalDeviceMemoryProperties", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceMemoryProperties2<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _memory_properties: *mut PhysicalDeviceMemoryProperties2,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceMemoryProperties2");
+    (hooked.commands.get_physical_device_memory_properties2)(_physical_device, _memory_properties);
+    hooked.after("vkGetPhysicalDeviceMemoryProperties2", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceMemoryProperties2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _memory_properties: *mut PhysicalDeviceMemoryProperties2,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceMemoryProperties2KHR");
+    (hooked.commands.get_physical_device_memory_properties2_khr)(
+        _physical_device,
+        _memory_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceMemoryProperties2KHR", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceMultisamplePropertiesEXT<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _samples: SampleCountFlags,
+    _multisample_properties: *mut MultisamplePropertiesEXT,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceMultisamplePropertiesEXT");
+    (hooked
+        .commands
+        .get_physical_device_multisample_properties_ext)(
+        _physical_device,
+        _samples,
+        _multisample_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceMultisamplePropertiesEXT", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceOpticalFlowImageFormatsNV<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _optical_flow_image_format_info: *const OpticalFlowImageFormatInfoNV,
+    _format_count: *mut u32,
+    _image_format_properties: *mut OpticalFlowImageFormatPropertiesNV,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceOpticalFlowImageFormatsNV") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_optical_flow_image_formats_nv)(
+            _physical_device,
+            _optical_flow_image_format_info,
+            _format_count,
+            _image_format_properties,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceOpticalFlowImageFormatsNV",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDevicePresentRectanglesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _surface: SurfaceKHR,
+    _rect_count: *mut u32,
+    _rects: *mut Rect2D,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDevicePresentRectanglesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_present_rectangles_khr)(
+            _physical_device,
+            _surface,
+            _rect_count,
+            _rects,
+        ),
+    };
+    hooked.after("vkGetPhysicalDevicePresentRectanglesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceProperties<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _properties: *mut PhysicalDeviceProperties,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceProperties");
+    (hooked.commands.get_physical_device_properties)(_physical_device, _properties);
+    hooked.after("vkGetPhysicalDeviceProperties", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceProperties2<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _properties: *mut PhysicalDeviceProperties2,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceProperties2");
+    (hooked.commands.get_physical_device_properties2)(_physical_device, _properties);
+    hooked.after("vkGetPhysicalDeviceProperties2", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceProperties2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _properties: *mut PhysicalDeviceProperties2,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceProperties2KHR");
+    (hooked.commands.get_physical_device_properties2_khr)(_physical_device, _properties);
+    hooked.after("vkGetPhysicalDeviceProperties2KHR", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceQueueFamilyDataGraphProcessingEnginePropertiesARM<
+    const S: usize,
+>(
+    _physical_device: PhysicalDevice,
+    _queue_family_data_graph_processing_engine_info: *const PhysicalDeviceQueueFamilyDataGraphProcessingEngineInfoARM,
+    _queue_family_data_graph_processing_engine_properties: *mut QueueFamilyDataGraphProcessingEnginePropertiesARM,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceQueueFamilyDataGraphProcessingEnginePropertiesARM");
+    (hooked
+        .commands
+        .get_physical_device_queue_family_data_graph_processing_engine_properties_arm)(
+        _physical_device,
+        _queue_family_data_graph_processing_engine_info,
+        _queue_family_data_graph_processing_engine_properties,
+    );
+    hooked.after(
+        "vkGetPhysicalDeviceQueueFamilyDataGraphProcessingEnginePropertiesARM",
+        None,
+    );
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceQueueFamilyDataGraphPropertiesARM<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _queue_family_index: u32,
+    _queue_family_data_graph_property_count: *mut u32,
+    _queue_family_data_graph_properties: *mut QueueFamilyDataGraphPropertiesARM,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceQueueFamilyDataGraphPropertiesARM") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_queue_family_data_graph_properties_arm)(
+            _physical_device,
+            _queue_family_index,
+            _queue_family_data_graph_property_count,
+            _queue_family_data_graph_properties,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceQueueFamilyDataGraphPropertiesARM",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR<
+    const S: usize,
+>(
+    _physical_device: PhysicalDevice,
+    _performance_query_create_info: *const QueryPoolPerformanceCreateInfoKHR,
+    _num_passes: *mut u32,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR");
+    (hooked
+        .commands
+        .get_physical_device_queue_family_performance_query_passes_khr)(
+        _physical_device,
+        _performance_query_create_info,
+        _num_passes,
+    );
+    hooked.after(
+        "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR",
+        None,
+    );
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceQueueFamilyProperties<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _queue_family_property_count: *mut u32,
+    _queue_family_properties: *mut QueueFamilyProperties,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceQueueFamilyProperties");
+    (hooked.commands.get_physical_device_queue_family_properties)(
+        _physical_device,
+        _queue_family_property_count,
+        _queue_family_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceQueueFamilyProperties", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceQueueFamilyProperties2<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _queue_family_property_count: *mut u32,
+    _queue_family_properties: *mut QueueFamilyProperties2,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceQueueFamilyProperties2");
+    (hooked.commands.get_physical_device_queue_family_properties2)(
+        _physical_device,
+        _queue_family_property_count,
+        _queue_family_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceQueueFamilyProperties2", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceQueueFamilyProperties2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _queue_family_property_count: *mut u32,
+    _queue_family_properties: *mut QueueFamilyProperties2,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceQueueFamilyProperties2KHR");
+    (hooked
+        .commands
+        .get_physical_device_queue_family_properties2_khr)(
+        _physical_device,
+        _queue_family_property_count,
+        _queue_family_properties,
+    );
+    hooked.after("vkGetPhysicalDeviceQueueFamilyProperties2KHR", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceRefreshableObjectTypesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _refreshable_object_type_count: *mut u32,
+    _refreshable_object_types: *mut ObjectType,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceRefreshableObjectTypesKHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_refreshable_object_types_khr)(
+            _physical_device,
+            _refreshable_object_type_count,
+            _refreshable_object_types,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceRefreshableObjectTypesKHR",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSciBufAttributesNV<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _attributes: NvSciBufAttrList,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceSciBufAttributesNV") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_sci_buf_attributes_nv)(
+            _physical_device,
+            _attributes,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceSciBufAttributesNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSciSyncAttributesNV<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _sci_sync_attributes_info: *const SciSyncAttributesInfoNV,
+    _attributes: NvSciSyncAttrList,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceSciSyncAttributesNV") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_sci_sync_attributes_nv)(
+            _physical_device,
+            _sci_sync_attributes_info,
+            _attributes,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceSciSyncAttributesNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceScreenPresentationSupportQNX<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _queue_family_index: u32,
+    _window: *mut _screen_window,
+) -> Bool32 {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceScreenPresentationSupportQNX");
+    let __result = (hooked
+        .commands
+        .get_physical_device_screen_presentation_support_qnx)(
+        _physical_device,
+        _queue_family_index,
+        _window,
+    );
+    hooked.after("vkGetPhysicalDeviceScreenPresentationSupportQNX", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSparseImageFormatProperties<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _format: Format,
+    _type_: ImageType,
+    _samples: SampleCountFlags,
+    _usage: ImageUsageFlags,
+    _tiling: ImageTiling,
+    _property_count: *mut u32,
+    _properties: *mut SparseImageFormatProperties,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceSparseImageFormatProperties");
+    (hooked
+        .commands
+        .get_physical_device_sparse_image_format_properties)(
+        _physical_device,
+        _format,
+        _type_,
+        _samples,
+        _usage,
+        _tiling,
+        _property_count,
+        _properties,
+    );
+    hooked.after("vkGetPhysicalDeviceSparseImageFormatProperties", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSparseImageFormatProperties2<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _format_info: *const PhysicalDeviceSparseImageFormatInfo2,
+    _property_count: *mut u32,
+    _properties: *mut SparseImageFormatProperties2,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceSparseImageFormatProperties2");
+    (hooked
+        .commands
+        .get_physical_device_sparse_image_format_properties2)(
+        _physical_device,
+        _format_info,
+        _property_count,
+        _properties,
+    );
+    hooked.after("vkGetPhysicalDeviceSparseImageFormatProperties2", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSparseImageFormatProperties2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _format_info: *const PhysicalDeviceSparseImageFormatInfo2,
+    _property_count: *mut u32,
+    _properties: *mut SparseImageFormatProperties2,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceSparseImageFormatProperties2KHR");
+    (hooked
+        .commands
+        .get_physical_device_sparse_image_format_properties2_khr)(
+        _physical_device,
+        _format_info,
+        _property_count,
+        _properties,
+    );
+    hooked.after("vkGetPhysicalDeviceSparseImageFormatProperties2KHR", None);
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV<
+    const S: usize,
+>(
+    _physical_device: PhysicalDevice,
+    _combination_count: *mut u32,
+    _combinations: *mut FramebufferMixedSamplesCombinationNV,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result =
+        match hooked.before("vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV") {
+            Some(result) => result,
+            None => (hooked
+                .commands
+                .get_physical_device_supported_framebuffer_mixed_samples_combinations_nv)(
+                _physical_device,
+                _combination_count,
+                _combinations,
+            ),
+        };
+    hooked.after(
+        "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSurfaceCapabilities2EXT<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _surface: SurfaceKHR,
+    _surface_capabilities: *mut SurfaceCapabilities2EXT,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceSurfaceCapabilities2EXT") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_surface_capabilities2_ext)(
+            _physical_device,
+            _surface,
+            _surface_capabilities,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceSurfaceCapabilities2EXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSurfaceCapabilities2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+    _surface_capabilities: *mut SurfaceCapabilities2KHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceSurfaceCapabilities2KHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_surface_capabilities2_khr)(
+            _physical_device,
+            _surface_info,
+            _surface_capabilities,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceSurfaceCapabilities2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSurfaceCapabilitiesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _surface: SurfaceKHR,
+    _surface_capabilities: *mut SurfaceCapabilitiesKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceSurfaceCapabilitiesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_surface_capabilities_khr)(
+            _physical_device,
+            _surface,
+            _surface_capabilities,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceSurfaceCapabilitiesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSurfaceFormats2KHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+    _surface_format_count: *mut u32,
+    _surface_formats: *mut SurfaceFormat2KHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceSurfaceFormats2KHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_surface_formats2_khr)(
+            _physical_device,
+            _surface_info,
+            _surface_format_count,
+            _surface_formats,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceSurfaceFormats2KHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSurfaceFormatsKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _surface: SurfaceKHR,
+    _surface_format_count: *mut u32,
+    _surface_formats: *mut SurfaceFormatKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceSurfaceFormatsKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_surface_formats_khr)(
+            _physical_device,
+            _surface,
+            _surface_format_count,
+            _surface_formats,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceSurfaceFormatsKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSurfacePresentModes2EXT<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+    _present_mode_count: *mut u32,
+    _present_modes: *mut PresentModeKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceSurfacePresentModes2EXT") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_surface_present_modes2_ext)(
+            _physical_device,
+            _surface_info,
+            _present_mode_count,
+            _present_modes,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceSurfacePresentModes2EXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSurfacePresentModesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _surface: SurfaceKHR,
+    _present_mode_count: *mut u32,
+    _present_modes: *mut PresentModeKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceSurfacePresentModesKHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_surface_present_modes_khr)(
+            _physical_device,
+            _surface,
+            _present_mode_count,
+            _present_modes,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceSurfacePresentModesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceSurfaceSupportKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _queue_family_index: u32,
+    _surface: SurfaceKHR,
+    _supported: *mut Bool32,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceSurfaceSupportKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_surface_support_khr)(
+            _physical_device,
+            _queue_family_index,
+            _surface,
+            _supported,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceSurfaceSupportKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceToolProperties<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _tool_count: *mut u32,
+    _tool_properties: *mut PhysicalDeviceToolProperties,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceToolProperties") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_tool_properties)(
+            _physical_device,
+            _tool_count,
+            _tool_properties,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceToolProperties", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceToolPropertiesEXT<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _tool_count: *mut u32,
+    _tool_properties: *mut PhysicalDeviceToolProperties,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceToolPropertiesEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_tool_properties_ext)(
+            _physical_device,
+            _tool_count,
+            _tool_properties,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceToolPropertiesEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceUbmPresentationSupportSEC<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _queue_family_index: u32,
+    _device: *mut ubm_device,
+) -> Bool32 {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceUbmPresentationSupportSEC");
+    let __result = (hooked
+        .commands
+        .get_physical_device_ubm_presentation_support_sec)(
+        _physical_device,
+        _queue_family_index,
+        _device,
+    );
+    hooked.after("vkGetPhysicalDeviceUbmPresentationSupportSEC", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceVideoCapabilitiesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _video_profile: *const VideoProfileInfoKHR,
+    _capabilities: *mut VideoCapabilitiesKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceVideoCapabilitiesKHR") {
+        Some(result) => result,
+        None => (hooked.commands.get_physical_device_video_capabilities_khr)(
+            _physical_device,
+            _video_profile,
+            _capabilities,
+        ),
+    };
+    hooked.after("vkGetPhysicalDeviceVideoCapabilitiesKHR", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR<
+    const S: usize,
+>(
+    _physical_device: PhysicalDevice,
+    _quality_level_info: *const PhysicalDeviceVideoEncodeQualityLevelInfoKHR,
+    _quality_level_properties: *mut VideoEncodeQualityLevelPropertiesKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_video_encode_quality_level_properties_khr)(
+            _physical_device,
+            _quality_level_info,
+            _quality_level_properties,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceVideoFormatPropertiesKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _video_format_info: *const PhysicalDeviceVideoFormatInfoKHR,
+    _video_format_property_count: *mut u32,
+    _video_format_properties: *mut VideoFormatPropertiesKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetPhysicalDeviceVideoFormatPropertiesKHR") {
+        Some(result) => result,
+        None => (hooked
+            .commands
+            .get_physical_device_video_format_properties_khr)(
+            _physical_device,
+            _video_format_info,
+            _video_format_property_count,
+            _video_format_properties,
+        ),
+    };
+    hooked.after(
+        "vkGetPhysicalDeviceVideoFormatPropertiesKHR",
+        Some(__result),
+    );
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceWaylandPresentationSupportKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _queue_family_index: u32,
+    _display: *mut wl_display,
+) -> Bool32 {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceWaylandPresentationSupportKHR");
+    let __result = (hooked
+        .commands
+        .get_physical_device_wayland_presentation_support_khr)(
+        _physical_device,
+        _queue_family_index,
+        _display,
+    );
+    hooked.after("vkGetPhysicalDeviceWaylandPresentationSupportKHR", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceWin32PresentationSupportKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _queue_family_index: u32,
+) -> Bool32 {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceWin32PresentationSupportKHR");
+    let __result = (hooked
+        .commands
+        .get_physical_device_win32_presentation_support_khr)(
+        _physical_device, _queue_family_index
+    );
+    hooked.after("vkGetPhysicalDeviceWin32PresentationSupportKHR", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceXcbPresentationSupportKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _queue_family_index: u32,
+    _connection: *mut xcb_connection_t,
+    _visual_id: xcb_visualid_t,
+) -> Bool32 {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceXcbPresentationSupportKHR");
+    let __result = (hooked
+        .commands
+        .get_physical_device_xcb_presentation_support_khr)(
+        _physical_device,
+        _queue_family_index,
+        _connection,
+        _visual_id,
+    );
+    hooked.after("vkGetPhysicalDeviceXcbPresentationSupportKHR", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetPhysicalDeviceXlibPresentationSupportKHR<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _queue_family_index: u32,
+    _dpy: *mut Display,
+    _visual_id: VisualID,
+) -> Bool32 {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkGetPhysicalDeviceXlibPresentationSupportKHR");
+    let __result = (hooked
+        .commands
+        .get_physical_device_xlib_presentation_support_khr)(
+        _physical_device,
+        _queue_family_index,
+        _dpy,
+        _visual_id,
+    );
+    hooked.after("vkGetPhysicalDeviceXlibPresentationSupportKHR", None);
+    __result
+}
+
+unsafe extern "system" fn vkGetRandROutputDisplayEXT<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _dpy: *mut Display,
+    _rr_output: RROutput,
+    _display: *mut DisplayKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetRandROutputDisplayEXT") {
+        Some(result) => result,
+        None => (hooked.commands.get_rand_r_output_display_ext)(
+            _physical_device,
+            _dpy,
+            _rr_output,
+            _display,
+        ),
+    };
+    hooked.after("vkGetRandROutputDisplayEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkGetWinrtDisplayNV<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _device_relative_id: u32,
+    _display: *mut DisplayKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkGetWinrtDisplayNV") {
+        Some(result) => result,
+        None => {
+            (hooked.commands.get_winrt_display_nv)(_physical_device, _device_relative_id, _display)
+        }
+    };
+    hooked.after("vkGetWinrtDisplayNV", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkQueueBeginDebugUtilsLabelEXT<const S: usize>(
+    _queue: Queue,
+    _label_info: *const DebugUtilsLabelEXT,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkQueueBeginDebugUtilsLabelEXT");
+    (hooked.commands.queue_begin_debug_utils_label_ext)(_queue, _label_info);
+    hooked.after("vkQueueBeginDebugUtilsLabelEXT", None);
+}
+
+unsafe extern "system" fn vkQueueEndDebugUtilsLabelEXT<const S: usize>(_queue: Queue) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkQueueEndDebugUtilsLabelEXT");
+    (hooked.commands.queue_end_debug_utils_label_ext)(_queue);
+    hooked.after("vkQueueEndDebugUtilsLabelEXT", None);
+}
+
+unsafe extern "system" fn vkQueueInsertDebugUtilsLabelEXT<const S: usize>(
+    _queue: Queue,
+    _label_info: *const DebugUtilsLabelEXT,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkQueueInsertDebugUtilsLabelEXT");
+    (hooked.commands.queue_insert_debug_utils_label_ext)(_queue, _label_info);
+    hooked.after("vkQueueInsertDebugUtilsLabelEXT", None);
+}
+
+unsafe extern "system" fn vkReleaseDisplayEXT<const S: usize>(
+    _physical_device: PhysicalDevice,
+    _display: DisplayKHR,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkReleaseDisplayEXT") {
+        Some(result) => result,
+        None => (hooked.commands.release_display_ext)(_physical_device, _display),
+    };
+    hooked.after("vkReleaseDisplayEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkSetDebugUtilsObjectNameEXT<const S: usize>(
+    _device: Device,
+    _name_info: *const DebugUtilsObjectNameInfoEXT,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkSetDebugUtilsObjectNameEXT") {
+        Some(result) => result,
+        None => (hooked.commands.set_debug_utils_object_name_ext)(_device, _name_info),
+    };
+    hooked.after("vkSetDebugUtilsObjectNameEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkSetDebugUtilsObjectTagEXT<const S: usize>(
+    _device: Device,
+    _tag_info: *const DebugUtilsObjectTagInfoEXT,
+) -> Result {
+    let hooked = crate::hook::instance::<S>();
+
+    let __result = match hooked.before("vkSetDebugUtilsObjectTagEXT") {
+        Some(result) => result,
+        None => (hooked.commands.set_debug_utils_object_tag_ext)(_device, _tag_info),
+    };
+    hooked.after("vkSetDebugUtilsObjectTagEXT", Some(__result));
+    __result
+}
+
+unsafe extern "system" fn vkSubmitDebugUtilsMessageEXT<const S: usize>(
+    _instance: Instance,
+    _message_severity: DebugUtilsMessageSeverityFlagsEXT,
+    _message_types: DebugUtilsMessageTypeFlagsEXT,
+    _callback_data: *const DebugUtilsMessengerCallbackDataEXT,
+) {
+    let hooked = crate::hook::instance::<S>();
+
+    hooked.before("vkSubmitDebugUtilsMessageEXT");
+    (hooked.commands.submit_debug_utils_message_ext)(
+        _instance,
+        _message_severity,
+        _message_types,
+        _callback_data,
+    );
+    hooked.after("vkSubmitDebugUtilsMessageEXT", None);
+}
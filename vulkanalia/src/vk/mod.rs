@@ -9,10 +9,17 @@ mod chains;
 #[rustfmt::skip]
 mod commands;
 #[rustfmt::skip]
+mod destroy;
+#[rustfmt::skip]
 mod enums;
 #[rustfmt::skip]
 mod extensions;
 #[rustfmt::skip]
+mod features;
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+mod hooks;
+#[rustfmt::skip]
 mod versions;
 
 pub use vulkanalia_sys::*;
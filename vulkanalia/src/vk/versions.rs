@@ -9,6 +9,7 @@
     non_camel_case_types,
     non_snake_case,
     clippy::bad_bit_mask,
+    clippy::let_and_return,
     clippy::let_unit_value,
     clippy::missing_safety_doc,
     clippy::missing_transmute_annotations,
@@ -39,6 +40,10 @@ pub trait EntryV1_0 {
         create_info: &InstanceCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<Instance> {
+        #[cfg(feature = "tracing")]
+        let __span =
+            tracing::trace_span!("vkCreateInstance", result = tracing::field::Empty).entered();
+
         let mut instance = MaybeUninit::<Instance>::uninit();
 
         let __result = (self.commands().create_instance)(
@@ -47,6 +52,9 @@ pub trait EntryV1_0 {
             instance.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(instance.assume_init())
         } else {
@@ -60,53 +68,219 @@ pub trait EntryV1_0 {
         &self,
         layer_name: Option<&CStr>,
     ) -> crate::VkResult<Vec<ExtensionProperties>> {
-        let mut property_count = 0;
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumerateInstanceExtensionProperties",
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        (self.commands().enumerate_instance_extension_properties)(
-            layer_name.map_or(ptr::null(), |v| v.as_ptr().cast()),
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        let mut property_count = 0;
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+        loop {
+            let __result = (self.commands().enumerate_instance_extension_properties)(
+                layer_name.map_or(ptr::null(), |v| v.as_ptr().cast()),
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut properties = Vec::with_capacity(property_count as usize);
+
+            let __result = (self.commands().enumerate_instance_extension_properties)(
+                layer_name.map_or(ptr::null(), |v| v.as_ptr().cast()),
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
 
-        let __result = (self.commands().enumerate_instance_extension_properties)(
-            layer_name.map_or(ptr::null(), |v| v.as_ptr().cast()),
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEnumerateInstanceExtensionProperties.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::enumerate_instance_extension_properties`].
+    #[inline]
+    unsafe fn enumerate_instance_extension_properties_into(
+        &self,
+        layer_name: Option<&CStr>,
+        properties: &mut Vec<ExtensionProperties>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumerateInstanceExtensionProperties",
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+        let mut property_count = 0;
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+        loop {
+            let __result = (self.commands().enumerate_instance_extension_properties)(
+                layer_name.map_or(ptr::null(), |v| v.as_ptr().cast()),
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self.commands().enumerate_instance_extension_properties)(
+                layer_name.map_or(ptr::null(), |v| v.as_ptr().cast()),
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEnumerateInstanceLayerProperties.html>
     #[inline]
     unsafe fn enumerate_instance_layer_properties(&self) -> crate::VkResult<Vec<LayerProperties>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumerateInstanceLayerProperties",
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut property_count = 0;
 
-        (self.commands().enumerate_instance_layer_properties)(&mut property_count, ptr::null_mut());
+        loop {
+            let __result = (self.commands().enumerate_instance_layer_properties)(
+                &mut property_count,
+                ptr::null_mut(),
+            );
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
 
-        let __result = (self.commands().enumerate_instance_layer_properties)(
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+            let mut properties = Vec::with_capacity(property_count as usize);
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+            let __result = (self.commands().enumerate_instance_layer_properties)(
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEnumerateInstanceLayerProperties.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::enumerate_instance_layer_properties`].
+    #[inline]
+    unsafe fn enumerate_instance_layer_properties_into(
+        &self,
+        properties: &mut Vec<LayerProperties>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumerateInstanceLayerProperties",
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        let mut property_count = 0;
+
+        loop {
+            let __result = (self.commands().enumerate_instance_layer_properties)(
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self.commands().enumerate_instance_layer_properties)(
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -114,7 +288,7 @@ pub trait EntryV1_0 {
 impl EntryV1_0 for crate::Entry {
     #[inline]
     fn commands(&self) -> &EntryCommands {
-        &self.commands
+        &self.state.commands
     }
 }
 
@@ -139,6 +313,14 @@ pub trait InstanceV1_0 {
         create_info: &DeviceCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<Device> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDevice",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut device = MaybeUninit::<Device>::uninit();
 
         let __result = (self.commands().create_device)(
@@ -148,6 +330,9 @@ pub trait InstanceV1_0 {
             device.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(device.assume_init())
         } else {
@@ -158,6 +343,13 @@ pub trait InstanceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkDestroyInstance.html>
     #[inline]
     unsafe fn destroy_instance(&self, allocator: Option<&AllocationCallbacks>) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyInstance",
+            instance = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result =
             (self.commands().destroy_instance)(self.handle(), allocator.map_or(ptr::null(), |v| v));
     }
@@ -169,31 +361,119 @@ pub trait InstanceV1_0 {
         physical_device: PhysicalDevice,
         layer_name: Option<&CStr>,
     ) -> crate::VkResult<Vec<ExtensionProperties>> {
-        let mut property_count = 0;
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumerateDeviceExtensionProperties",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        (self.commands().enumerate_device_extension_properties)(
-            physical_device,
-            layer_name.map_or(ptr::null(), |v| v.as_ptr().cast()),
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        let mut property_count = 0;
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+        loop {
+            let __result = (self.commands().enumerate_device_extension_properties)(
+                physical_device,
+                layer_name.map_or(ptr::null(), |v| v.as_ptr().cast()),
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut properties = Vec::with_capacity(property_count as usize);
+
+            let __result = (self.commands().enumerate_device_extension_properties)(
+                physical_device,
+                layer_name.map_or(ptr::null(), |v| v.as_ptr().cast()),
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
 
-        let __result = (self.commands().enumerate_device_extension_properties)(
-            physical_device,
-            layer_name.map_or(ptr::null(), |v| v.as_ptr().cast()),
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEnumerateDeviceExtensionProperties.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::enumerate_device_extension_properties`].
+    #[inline]
+    unsafe fn enumerate_device_extension_properties_into(
+        &self,
+        physical_device: PhysicalDevice,
+        layer_name: Option<&CStr>,
+        properties: &mut Vec<ExtensionProperties>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumerateDeviceExtensionProperties",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+        let mut property_count = 0;
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+        loop {
+            let __result = (self.commands().enumerate_device_extension_properties)(
+                physical_device,
+                layer_name.map_or(ptr::null(), |v| v.as_ptr().cast()),
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self.commands().enumerate_device_extension_properties)(
+                physical_device,
+                layer_name.map_or(ptr::null(), |v| v.as_ptr().cast()),
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -203,64 +483,240 @@ pub trait InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<LayerProperties>> {
-        let mut property_count = 0;
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumerateDeviceLayerProperties",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        (self.commands().enumerate_device_layer_properties)(
-            physical_device,
-            &mut property_count,
-            ptr::null_mut(),
-        );
+        let mut property_count = 0;
 
-        let mut properties = Vec::with_capacity(property_count as usize);
+        loop {
+            let __result = (self.commands().enumerate_device_layer_properties)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut properties = Vec::with_capacity(property_count as usize);
+
+            let __result = (self.commands().enumerate_device_layer_properties)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(properties.capacity() >= property_count as usize);
+            properties.set_len(property_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
 
-        let __result = (self.commands().enumerate_device_layer_properties)(
-            physical_device,
-            &mut property_count,
-            properties.as_mut_ptr(),
-        );
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEnumerateDeviceLayerProperties.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::enumerate_device_layer_properties`].
+    #[inline]
+    unsafe fn enumerate_device_layer_properties_into(
+        &self,
+        physical_device: PhysicalDevice,
+        properties: &mut Vec<LayerProperties>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumerateDeviceLayerProperties",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        debug_assert!(properties.capacity() >= property_count as usize);
-        properties.set_len(property_count as usize);
+        let mut property_count = 0;
 
-        if __result == Result::SUCCESS {
-            Ok(properties)
-        } else {
-            Err(__result.into())
+        loop {
+            let __result = (self.commands().enumerate_device_layer_properties)(
+                physical_device,
+                &mut property_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            properties.clear();
+            properties.reserve(property_count as usize);
+
+            let __result = (self.commands().enumerate_device_layer_properties)(
+                physical_device,
+                &mut property_count,
+                properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(properties.capacity() >= property_count as usize);
+                properties.set_len(property_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEnumeratePhysicalDevices.html>
     #[inline]
     unsafe fn enumerate_physical_devices(&self) -> crate::VkResult<Vec<PhysicalDevice>> {
-        let mut physical_device_count = 0;
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumeratePhysicalDevices",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        (self.commands().enumerate_physical_devices)(
-            self.handle(),
-            &mut physical_device_count,
-            ptr::null_mut(),
-        );
+        let mut physical_device_count = 0;
 
-        let mut physical_devices = Vec::with_capacity(physical_device_count as usize);
+        loop {
+            let __result = (self.commands().enumerate_physical_devices)(
+                self.handle(),
+                &mut physical_device_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut physical_devices = Vec::with_capacity(physical_device_count as usize);
+
+            let __result = (self.commands().enumerate_physical_devices)(
+                self.handle(),
+                &mut physical_device_count,
+                physical_devices.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(physical_devices.capacity() >= physical_device_count as usize);
+            physical_devices.set_len(physical_device_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(physical_devices)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
 
-        let __result = (self.commands().enumerate_physical_devices)(
-            self.handle(),
-            &mut physical_device_count,
-            physical_devices.as_mut_ptr(),
-        );
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEnumeratePhysicalDevices.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::enumerate_physical_devices`].
+    #[inline]
+    unsafe fn enumerate_physical_devices_into(
+        &self,
+        physical_devices: &mut Vec<PhysicalDevice>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumeratePhysicalDevices",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        debug_assert!(physical_devices.capacity() >= physical_device_count as usize);
-        physical_devices.set_len(physical_device_count as usize);
+        let mut physical_device_count = 0;
 
-        if __result == Result::SUCCESS {
-            Ok(physical_devices)
-        } else {
-            Err(__result.into())
+        loop {
+            let __result = (self.commands().enumerate_physical_devices)(
+                self.handle(),
+                &mut physical_device_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            physical_devices.clear();
+            physical_devices.reserve(physical_device_count as usize);
+
+            let __result = (self.commands().enumerate_physical_devices)(
+                self.handle(),
+                &mut physical_device_count,
+                physical_devices.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(physical_devices.capacity() >= physical_device_count as usize);
+                physical_devices.set_len(physical_device_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetDeviceProcAddr.html>
     #[inline]
     unsafe fn get_device_proc_addr(&self, device: Device, name: &CStr) -> PFN_vkVoidFunction {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceProcAddr",
+            device = tracing::field::debug(device)
+        )
+        .entered();
+
         let __result = (self.commands().get_device_proc_addr)(device, name.as_ptr().cast());
 
         __result
@@ -272,6 +728,13 @@ pub trait InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> PhysicalDeviceFeatures {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceFeatures",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut features = MaybeUninit::<PhysicalDeviceFeatures>::uninit();
 
         let __result =
@@ -287,6 +750,13 @@ pub trait InstanceV1_0 {
         physical_device: PhysicalDevice,
         format: Format,
     ) -> FormatProperties {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceFormatProperties",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut format_properties = MaybeUninit::<FormatProperties>::uninit();
 
         let __result = (self.commands().get_physical_device_format_properties)(
@@ -309,6 +779,14 @@ pub trait InstanceV1_0 {
         usage: ImageUsageFlags,
         flags: ImageCreateFlags,
     ) -> crate::VkResult<ImageFormatProperties> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceImageFormatProperties",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut image_format_properties = MaybeUninit::<ImageFormatProperties>::uninit();
 
         let __result = (self.commands().get_physical_device_image_format_properties)(
@@ -321,6 +799,9 @@ pub trait InstanceV1_0 {
             image_format_properties.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(image_format_properties.assume_init())
         } else {
@@ -334,6 +815,13 @@ pub trait InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> PhysicalDeviceMemoryProperties {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceMemoryProperties",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut memory_properties = MaybeUninit::<PhysicalDeviceMemoryProperties>::uninit();
 
         let __result = (self.commands().get_physical_device_memory_properties)(
@@ -350,6 +838,13 @@ pub trait InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> PhysicalDeviceProperties {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceProperties",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut properties = MaybeUninit::<PhysicalDeviceProperties>::uninit();
 
         let __result = (self.commands().get_physical_device_properties)(
@@ -366,6 +861,13 @@ pub trait InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> Vec<QueueFamilyProperties> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceQueueFamilyProperties",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut queue_family_property_count = 0;
 
         (self.commands().get_physical_device_queue_family_properties)(
@@ -388,6 +890,44 @@ pub trait InstanceV1_0 {
         queue_family_properties
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceQueueFamilyProperties.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_queue_family_properties`].
+    #[inline]
+    unsafe fn get_physical_device_queue_family_properties_into(
+        &self,
+        physical_device: PhysicalDevice,
+        queue_family_properties: &mut Vec<QueueFamilyProperties>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceQueueFamilyProperties",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
+        let mut queue_family_property_count = 0;
+
+        (self.commands().get_physical_device_queue_family_properties)(
+            physical_device,
+            &mut queue_family_property_count,
+            ptr::null_mut(),
+        );
+
+        queue_family_properties.clear();
+        queue_family_properties.reserve(queue_family_property_count as usize);
+
+        let __result = (self.commands().get_physical_device_queue_family_properties)(
+            physical_device,
+            &mut queue_family_property_count,
+            queue_family_properties.as_mut_ptr(),
+        );
+
+        debug_assert!(queue_family_properties.capacity() >= queue_family_property_count as usize);
+        queue_family_properties.set_len(queue_family_property_count as usize);
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceSparseImageFormatProperties.html>
     #[inline]
     unsafe fn get_physical_device_sparse_image_format_properties(
@@ -399,6 +939,13 @@ pub trait InstanceV1_0 {
         usage: ImageUsageFlags,
         tiling: ImageTiling,
     ) -> Vec<SparseImageFormatProperties> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSparseImageFormatProperties",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut property_count = 0;
 
         (self
@@ -434,12 +981,69 @@ pub trait InstanceV1_0 {
 
         properties
     }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceSparseImageFormatProperties.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_sparse_image_format_properties`].
+    #[inline]
+    unsafe fn get_physical_device_sparse_image_format_properties_into(
+        &self,
+        physical_device: PhysicalDevice,
+        format: Format,
+        type_: ImageType,
+        samples: SampleCountFlags,
+        usage: ImageUsageFlags,
+        tiling: ImageTiling,
+        properties: &mut Vec<SparseImageFormatProperties>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSparseImageFormatProperties",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
+        let mut property_count = 0;
+
+        (self
+            .commands()
+            .get_physical_device_sparse_image_format_properties)(
+            physical_device,
+            format,
+            type_,
+            samples,
+            usage,
+            tiling,
+            &mut property_count,
+            ptr::null_mut(),
+        );
+
+        properties.clear();
+        properties.reserve(property_count as usize);
+
+        let __result = (self
+            .commands()
+            .get_physical_device_sparse_image_format_properties)(
+            physical_device,
+            format,
+            type_,
+            samples,
+            usage,
+            tiling,
+            &mut property_count,
+            properties.as_mut_ptr(),
+        );
+
+        debug_assert!(properties.capacity() >= property_count as usize);
+        properties.set_len(property_count as usize);
+    }
 }
 
 impl InstanceV1_0 for crate::Instance {
     #[inline]
     fn commands(&self) -> &InstanceCommands {
-        &self.commands
+        &self.state.commands
     }
 
     #[inline]
@@ -472,6 +1076,14 @@ pub trait DeviceV1_0 {
         &self,
         allocate_info: &CommandBufferAllocateInfo,
     ) -> crate::VkResult<Vec<CommandBuffer>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAllocateCommandBuffers",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut command_buffers =
             Vec::with_capacity(allocate_info.as_ref().command_buffer_count as usize);
 
@@ -481,6 +1093,9 @@ pub trait DeviceV1_0 {
             command_buffers.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         command_buffers.set_len(allocate_info.as_ref().command_buffer_count as usize);
 
         if __result == Result::SUCCESS {
@@ -490,12 +1105,61 @@ pub trait DeviceV1_0 {
         }
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkAllocateCommandBuffers.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::allocate_command_buffers`].
+    #[inline]
+    unsafe fn allocate_command_buffers_into(
+        &self,
+        allocate_info: &CommandBufferAllocateInfo,
+        command_buffers: &mut Vec<CommandBuffer>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAllocateCommandBuffers",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        command_buffers.clear();
+        command_buffers.reserve(allocate_info.as_ref().command_buffer_count as usize);
+
+        let __result = (self.commands().allocate_command_buffers)(
+            self.handle(),
+            allocate_info,
+            command_buffers.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            command_buffers.set_len(allocate_info.as_ref().command_buffer_count as usize);
+        }
+
+        if __result == Result::SUCCESS {
+            Ok(())
+        } else {
+            Err(__result.into())
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkAllocateDescriptorSets.html>
     #[inline]
     unsafe fn allocate_descriptor_sets(
         &self,
         allocate_info: &DescriptorSetAllocateInfo,
     ) -> crate::VkResult<Vec<DescriptorSet>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAllocateDescriptorSets",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut descriptor_sets =
             Vec::with_capacity(allocate_info.as_ref().descriptor_set_count as usize);
 
@@ -505,6 +1169,9 @@ pub trait DeviceV1_0 {
             descriptor_sets.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         descriptor_sets.set_len(allocate_info.as_ref().descriptor_set_count as usize);
 
         if __result == Result::SUCCESS {
@@ -514,6 +1181,47 @@ pub trait DeviceV1_0 {
         }
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkAllocateDescriptorSets.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::allocate_descriptor_sets`].
+    #[inline]
+    unsafe fn allocate_descriptor_sets_into(
+        &self,
+        allocate_info: &DescriptorSetAllocateInfo,
+        descriptor_sets: &mut Vec<DescriptorSet>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAllocateDescriptorSets",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        descriptor_sets.clear();
+        descriptor_sets.reserve(allocate_info.as_ref().descriptor_set_count as usize);
+
+        let __result = (self.commands().allocate_descriptor_sets)(
+            self.handle(),
+            allocate_info,
+            descriptor_sets.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            descriptor_sets.set_len(allocate_info.as_ref().descriptor_set_count as usize);
+        }
+
+        if __result == Result::SUCCESS {
+            Ok(())
+        } else {
+            Err(__result.into())
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkAllocateMemory.html>
     #[inline]
     unsafe fn allocate_memory(
@@ -521,6 +1229,14 @@ pub trait DeviceV1_0 {
         allocate_info: &MemoryAllocateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<DeviceMemory> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkAllocateMemory",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut memory = MaybeUninit::<DeviceMemory>::uninit();
 
         let __result = (self.commands().allocate_memory)(
@@ -530,6 +1246,9 @@ pub trait DeviceV1_0 {
             memory.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(memory.assume_init())
         } else {
@@ -544,8 +1263,19 @@ pub trait DeviceV1_0 {
         command_buffer: CommandBuffer,
         begin_info: &CommandBufferBeginInfo,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBeginCommandBuffer",
+            command_buffer = tracing::field::debug(command_buffer),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().begin_command_buffer)(command_buffer, begin_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -561,9 +1291,22 @@ pub trait DeviceV1_0 {
         memory: DeviceMemory,
         memory_offset: DeviceSize,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBindBufferMemory",
+            device = tracing::field::debug(self.handle()),
+            buffer = tracing::field::debug(buffer),
+            memory = tracing::field::debug(memory),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().bind_buffer_memory)(self.handle(), buffer, memory, memory_offset);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -579,9 +1322,22 @@ pub trait DeviceV1_0 {
         memory: DeviceMemory,
         memory_offset: DeviceSize,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBindImageMemory",
+            device = tracing::field::debug(self.handle()),
+            image = tracing::field::debug(image),
+            memory = tracing::field::debug(memory),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().bind_image_memory)(self.handle(), image, memory, memory_offset);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -598,6 +1354,14 @@ pub trait DeviceV1_0 {
         query: u32,
         flags: QueryControlFlags,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginQuery",
+            command_buffer = tracing::field::debug(command_buffer),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_begin_query)(command_buffer, query_pool, query, flags);
     }
 
@@ -609,6 +1373,13 @@ pub trait DeviceV1_0 {
         render_pass_begin: &RenderPassBeginInfo,
         contents: SubpassContents,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginRenderPass",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_begin_render_pass)(command_buffer, render_pass_begin, contents);
     }
@@ -624,6 +1395,14 @@ pub trait DeviceV1_0 {
         descriptor_sets: &[DescriptorSet],
         dynamic_offsets: &[u32],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindDescriptorSets",
+            command_buffer = tracing::field::debug(command_buffer),
+            layout = tracing::field::debug(layout)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_descriptor_sets)(
             command_buffer,
             pipeline_bind_point,
@@ -645,6 +1424,14 @@ pub trait DeviceV1_0 {
         offset: DeviceSize,
         index_type: IndexType,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindIndexBuffer",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_bind_index_buffer)(command_buffer, buffer, offset, index_type);
     }
@@ -657,6 +1444,14 @@ pub trait DeviceV1_0 {
         pipeline_bind_point: PipelineBindPoint,
         pipeline: Pipeline,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindPipeline",
+            command_buffer = tracing::field::debug(command_buffer),
+            pipeline = tracing::field::debug(pipeline)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_bind_pipeline)(command_buffer, pipeline_bind_point, pipeline);
     }
@@ -670,6 +1465,13 @@ pub trait DeviceV1_0 {
         buffers: &[Buffer],
         offsets: &[DeviceSize],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindVertexBuffers",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_vertex_buffers)(
             command_buffer,
             first_binding,
@@ -691,6 +1493,15 @@ pub trait DeviceV1_0 {
         regions: &[impl Cast<Target = ImageBlit>],
         filter: Filter,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBlitImage",
+            command_buffer = tracing::field::debug(command_buffer),
+            src_image = tracing::field::debug(src_image),
+            dst_image = tracing::field::debug(dst_image)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_blit_image)(
             command_buffer,
             src_image,
@@ -711,6 +1522,13 @@ pub trait DeviceV1_0 {
         attachments: &[impl Cast<Target = ClearAttachment>],
         rects: &[impl Cast<Target = ClearRect>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdClearAttachments",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_clear_attachments)(
             command_buffer,
             attachments.len() as u32,
@@ -730,6 +1548,14 @@ pub trait DeviceV1_0 {
         color: &ClearColorValue,
         ranges: &[impl Cast<Target = ImageSubresourceRange>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdClearColorImage",
+            command_buffer = tracing::field::debug(command_buffer),
+            image = tracing::field::debug(image)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_clear_color_image)(
             command_buffer,
             image,
@@ -750,6 +1576,14 @@ pub trait DeviceV1_0 {
         depth_stencil: &ClearDepthStencilValue,
         ranges: &[impl Cast<Target = ImageSubresourceRange>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdClearDepthStencilImage",
+            command_buffer = tracing::field::debug(command_buffer),
+            image = tracing::field::debug(image)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_clear_depth_stencil_image)(
             command_buffer,
             image,
@@ -769,6 +1603,15 @@ pub trait DeviceV1_0 {
         dst_buffer: Buffer,
         regions: &[impl Cast<Target = BufferCopy>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyBuffer",
+            command_buffer = tracing::field::debug(command_buffer),
+            src_buffer = tracing::field::debug(src_buffer),
+            dst_buffer = tracing::field::debug(dst_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_buffer)(
             command_buffer,
             src_buffer,
@@ -788,6 +1631,15 @@ pub trait DeviceV1_0 {
         dst_image_layout: ImageLayout,
         regions: &[impl Cast<Target = BufferImageCopy>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyBufferToImage",
+            command_buffer = tracing::field::debug(command_buffer),
+            src_buffer = tracing::field::debug(src_buffer),
+            dst_image = tracing::field::debug(dst_image)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_buffer_to_image)(
             command_buffer,
             src_buffer,
@@ -809,6 +1661,15 @@ pub trait DeviceV1_0 {
         dst_image_layout: ImageLayout,
         regions: &[impl Cast<Target = ImageCopy>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyImage",
+            command_buffer = tracing::field::debug(command_buffer),
+            src_image = tracing::field::debug(src_image),
+            dst_image = tracing::field::debug(dst_image)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_image)(
             command_buffer,
             src_image,
@@ -830,6 +1691,15 @@ pub trait DeviceV1_0 {
         dst_buffer: Buffer,
         regions: &[impl Cast<Target = BufferImageCopy>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyImageToBuffer",
+            command_buffer = tracing::field::debug(command_buffer),
+            src_image = tracing::field::debug(src_image),
+            dst_buffer = tracing::field::debug(dst_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_image_to_buffer)(
             command_buffer,
             src_image,
@@ -853,6 +1723,15 @@ pub trait DeviceV1_0 {
         stride: DeviceSize,
         flags: QueryResultFlags,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyQueryPoolResults",
+            command_buffer = tracing::field::debug(command_buffer),
+            query_pool = tracing::field::debug(query_pool),
+            dst_buffer = tracing::field::debug(dst_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_query_pool_results)(
             command_buffer,
             query_pool,
@@ -874,6 +1753,13 @@ pub trait DeviceV1_0 {
         group_count_y: u32,
         group_count_z: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDispatch",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_dispatch)(
             command_buffer,
             group_count_x,
@@ -890,6 +1776,14 @@ pub trait DeviceV1_0 {
         buffer: Buffer,
         offset: DeviceSize,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDispatchIndirect",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_dispatch_indirect)(command_buffer, buffer, offset);
     }
 
@@ -903,6 +1797,13 @@ pub trait DeviceV1_0 {
         first_vertex: u32,
         first_instance: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDraw",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw)(
             command_buffer,
             vertex_count,
@@ -923,6 +1824,13 @@ pub trait DeviceV1_0 {
         vertex_offset: i32,
         first_instance: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawIndexed",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_indexed)(
             command_buffer,
             index_count,
@@ -943,6 +1851,14 @@ pub trait DeviceV1_0 {
         draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawIndexedIndirect",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_indexed_indirect)(
             command_buffer,
             buffer,
@@ -962,6 +1878,14 @@ pub trait DeviceV1_0 {
         draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawIndirect",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_draw_indirect)(command_buffer, buffer, offset, draw_count, stride);
     }
@@ -974,12 +1898,27 @@ pub trait DeviceV1_0 {
         query_pool: QueryPool,
         query: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndQuery",
+            command_buffer = tracing::field::debug(command_buffer),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_end_query)(command_buffer, query_pool, query);
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdEndRenderPass.html>
     #[inline]
     unsafe fn cmd_end_render_pass(&self, command_buffer: CommandBuffer) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndRenderPass",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_end_render_pass)(command_buffer);
     }
 
@@ -990,6 +1929,13 @@ pub trait DeviceV1_0 {
         command_buffer: CommandBuffer,
         command_buffers: &[CommandBuffer],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdExecuteCommands",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_execute_commands)(
             command_buffer,
             command_buffers.len() as u32,
@@ -1007,6 +1953,14 @@ pub trait DeviceV1_0 {
         size: DeviceSize,
         data: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdFillBuffer",
+            command_buffer = tracing::field::debug(command_buffer),
+            dst_buffer = tracing::field::debug(dst_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_fill_buffer)(command_buffer, dst_buffer, dst_offset, size, data);
     }
@@ -1014,6 +1968,13 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdNextSubpass.html>
     #[inline]
     unsafe fn cmd_next_subpass(&self, command_buffer: CommandBuffer, contents: SubpassContents) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdNextSubpass",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_next_subpass)(command_buffer, contents);
     }
 
@@ -1029,6 +1990,13 @@ pub trait DeviceV1_0 {
         buffer_memory_barriers: &[impl Cast<Target = BufferMemoryBarrier>],
         image_memory_barriers: &[impl Cast<Target = ImageMemoryBarrier>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPipelineBarrier",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_pipeline_barrier)(
             command_buffer,
             src_stage_mask,
@@ -1053,6 +2021,14 @@ pub trait DeviceV1_0 {
         offset: u32,
         values: &[u8],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushConstants",
+            command_buffer = tracing::field::debug(command_buffer),
+            layout = tracing::field::debug(layout)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_push_constants)(
             command_buffer,
             layout,
@@ -1071,6 +2047,14 @@ pub trait DeviceV1_0 {
         event: Event,
         stage_mask: PipelineStageFlags,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdResetEvent",
+            command_buffer = tracing::field::debug(command_buffer),
+            event = tracing::field::debug(event)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_reset_event)(command_buffer, event, stage_mask);
     }
 
@@ -1083,6 +2067,14 @@ pub trait DeviceV1_0 {
         first_query: u32,
         query_count: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdResetQueryPool",
+            command_buffer = tracing::field::debug(command_buffer),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_reset_query_pool)(
             command_buffer,
             query_pool,
@@ -1102,6 +2094,15 @@ pub trait DeviceV1_0 {
         dst_image_layout: ImageLayout,
         regions: &[impl Cast<Target = ImageResolve>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdResolveImage",
+            command_buffer = tracing::field::debug(command_buffer),
+            src_image = tracing::field::debug(src_image),
+            dst_image = tracing::field::debug(dst_image)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_resolve_image)(
             command_buffer,
             src_image,
@@ -1120,6 +2121,13 @@ pub trait DeviceV1_0 {
         command_buffer: CommandBuffer,
         blend_constants: [f32; 4],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetBlendConstants",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_blend_constants)(command_buffer, blend_constants.as_ptr());
     }
@@ -1133,6 +2141,13 @@ pub trait DeviceV1_0 {
         depth_bias_clamp: f32,
         depth_bias_slope_factor: f32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthBias",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_bias)(
             command_buffer,
             depth_bias_constant_factor,
@@ -1149,6 +2164,13 @@ pub trait DeviceV1_0 {
         min_depth_bounds: f32,
         max_depth_bounds: f32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthBounds",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_bounds)(
             command_buffer,
             min_depth_bounds,
@@ -1164,12 +2186,27 @@ pub trait DeviceV1_0 {
         event: Event,
         stage_mask: PipelineStageFlags,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetEvent",
+            command_buffer = tracing::field::debug(command_buffer),
+            event = tracing::field::debug(event)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_event)(command_buffer, event, stage_mask);
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdSetLineWidth.html>
     #[inline]
     unsafe fn cmd_set_line_width(&self, command_buffer: CommandBuffer, line_width: f32) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetLineWidth",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_line_width)(command_buffer, line_width);
     }
 
@@ -1181,6 +2218,13 @@ pub trait DeviceV1_0 {
         first_scissor: u32,
         scissors: &[impl Cast<Target = Rect2D>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetScissor",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_scissor)(
             command_buffer,
             first_scissor,
@@ -1197,6 +2241,13 @@ pub trait DeviceV1_0 {
         face_mask: StencilFaceFlags,
         compare_mask: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetStencilCompareMask",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_stencil_compare_mask)(command_buffer, face_mask, compare_mask);
     }
@@ -1209,6 +2260,13 @@ pub trait DeviceV1_0 {
         face_mask: StencilFaceFlags,
         reference: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetStencilReference",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_stencil_reference)(command_buffer, face_mask, reference);
     }
@@ -1221,6 +2279,13 @@ pub trait DeviceV1_0 {
         face_mask: StencilFaceFlags,
         write_mask: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetStencilWriteMask",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_stencil_write_mask)(command_buffer, face_mask, write_mask);
     }
@@ -1233,6 +2298,13 @@ pub trait DeviceV1_0 {
         first_viewport: u32,
         viewports: &[impl Cast<Target = Viewport>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetViewport",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_viewport)(
             command_buffer,
             first_viewport,
@@ -1250,6 +2322,14 @@ pub trait DeviceV1_0 {
         dst_offset: DeviceSize,
         data: &[u8],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdUpdateBuffer",
+            command_buffer = tracing::field::debug(command_buffer),
+            dst_buffer = tracing::field::debug(dst_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_update_buffer)(
             command_buffer,
             dst_buffer,
@@ -1271,6 +2351,13 @@ pub trait DeviceV1_0 {
         buffer_memory_barriers: &[impl Cast<Target = BufferMemoryBarrier>],
         image_memory_barriers: &[impl Cast<Target = ImageMemoryBarrier>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdWaitEvents",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_wait_events)(
             command_buffer,
             events.len() as u32,
@@ -1295,6 +2382,14 @@ pub trait DeviceV1_0 {
         query_pool: QueryPool,
         query: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdWriteTimestamp",
+            command_buffer = tracing::field::debug(command_buffer),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_write_timestamp)(
             command_buffer,
             pipeline_stage,
@@ -1310,6 +2405,14 @@ pub trait DeviceV1_0 {
         create_info: &BufferCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<Buffer> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateBuffer",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut buffer = MaybeUninit::<Buffer>::uninit();
 
         let __result = (self.commands().create_buffer)(
@@ -1319,6 +2422,9 @@ pub trait DeviceV1_0 {
             buffer.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(buffer.assume_init())
         } else {
@@ -1333,6 +2439,14 @@ pub trait DeviceV1_0 {
         create_info: &BufferViewCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<BufferView> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateBufferView",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut view = MaybeUninit::<BufferView>::uninit();
 
         let __result = (self.commands().create_buffer_view)(
@@ -1342,6 +2456,9 @@ pub trait DeviceV1_0 {
             view.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(view.assume_init())
         } else {
@@ -1356,6 +2473,14 @@ pub trait DeviceV1_0 {
         create_info: &CommandPoolCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<CommandPool> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateCommandPool",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut command_pool = MaybeUninit::<CommandPool>::uninit();
 
         let __result = (self.commands().create_command_pool)(
@@ -1365,6 +2490,9 @@ pub trait DeviceV1_0 {
             command_pool.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(command_pool.assume_init())
         } else {
@@ -1380,6 +2508,15 @@ pub trait DeviceV1_0 {
         create_infos: &[impl Cast<Target = ComputePipelineCreateInfo>],
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkSuccessResult<Vec<Pipeline>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateComputePipelines",
+            device = tracing::field::debug(self.handle()),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut pipelines = Vec::with_capacity(create_infos.len() as usize);
 
         let __result = (self.commands().create_compute_pipelines)(
@@ -1391,6 +2528,9 @@ pub trait DeviceV1_0 {
             pipelines.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         pipelines.set_len(create_infos.len() as usize);
 
         if __result >= Result::SUCCESS {
@@ -1400,6 +2540,53 @@ pub trait DeviceV1_0 {
         }
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCreateComputePipelines.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::create_compute_pipelines`].
+    #[inline]
+    unsafe fn create_compute_pipelines_into(
+        &self,
+        pipeline_cache: PipelineCache,
+        create_infos: &[impl Cast<Target = ComputePipelineCreateInfo>],
+        allocator: Option<&AllocationCallbacks>,
+        pipelines: &mut Vec<Pipeline>,
+    ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateComputePipelines",
+            device = tracing::field::debug(self.handle()),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        pipelines.clear();
+        pipelines.reserve(create_infos.len() as usize);
+
+        let __result = (self.commands().create_compute_pipelines)(
+            self.handle(),
+            pipeline_cache,
+            create_infos.len() as u32,
+            create_infos.as_ptr().cast(),
+            allocator.map_or(ptr::null(), |v| v),
+            pipelines.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            pipelines.set_len(create_infos.len() as usize);
+        }
+
+        if __result >= Result::SUCCESS {
+            Ok(__result.into())
+        } else {
+            Err(__result.into())
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCreateDescriptorPool.html>
     #[inline]
     unsafe fn create_descriptor_pool(
@@ -1407,6 +2594,14 @@ pub trait DeviceV1_0 {
         create_info: &DescriptorPoolCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<DescriptorPool> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDescriptorPool",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut descriptor_pool = MaybeUninit::<DescriptorPool>::uninit();
 
         let __result = (self.commands().create_descriptor_pool)(
@@ -1416,6 +2611,9 @@ pub trait DeviceV1_0 {
             descriptor_pool.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(descriptor_pool.assume_init())
         } else {
@@ -1430,6 +2628,14 @@ pub trait DeviceV1_0 {
         create_info: &DescriptorSetLayoutCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<DescriptorSetLayout> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDescriptorSetLayout",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut set_layout = MaybeUninit::<DescriptorSetLayout>::uninit();
 
         let __result = (self.commands().create_descriptor_set_layout)(
@@ -1439,6 +2645,9 @@ pub trait DeviceV1_0 {
             set_layout.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(set_layout.assume_init())
         } else {
@@ -1453,6 +2662,14 @@ pub trait DeviceV1_0 {
         create_info: &EventCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<Event> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateEvent",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut event = MaybeUninit::<Event>::uninit();
 
         let __result = (self.commands().create_event)(
@@ -1462,6 +2679,9 @@ pub trait DeviceV1_0 {
             event.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(event.assume_init())
         } else {
@@ -1476,6 +2696,14 @@ pub trait DeviceV1_0 {
         create_info: &FenceCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<Fence> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateFence",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut fence = MaybeUninit::<Fence>::uninit();
 
         let __result = (self.commands().create_fence)(
@@ -1485,6 +2713,9 @@ pub trait DeviceV1_0 {
             fence.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(fence.assume_init())
         } else {
@@ -1499,6 +2730,14 @@ pub trait DeviceV1_0 {
         create_info: &FramebufferCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<Framebuffer> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateFramebuffer",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut framebuffer = MaybeUninit::<Framebuffer>::uninit();
 
         let __result = (self.commands().create_framebuffer)(
@@ -1508,6 +2747,9 @@ pub trait DeviceV1_0 {
             framebuffer.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(framebuffer.assume_init())
         } else {
@@ -1523,6 +2765,15 @@ pub trait DeviceV1_0 {
         create_infos: &[impl Cast<Target = GraphicsPipelineCreateInfo>],
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkSuccessResult<Vec<Pipeline>> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateGraphicsPipelines",
+            device = tracing::field::debug(self.handle()),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut pipelines = Vec::with_capacity(create_infos.len() as usize);
 
         let __result = (self.commands().create_graphics_pipelines)(
@@ -1534,6 +2785,9 @@ pub trait DeviceV1_0 {
             pipelines.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         pipelines.set_len(create_infos.len() as usize);
 
         if __result >= Result::SUCCESS {
@@ -1543,6 +2797,53 @@ pub trait DeviceV1_0 {
         }
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCreateGraphicsPipelines.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::create_graphics_pipelines`].
+    #[inline]
+    unsafe fn create_graphics_pipelines_into(
+        &self,
+        pipeline_cache: PipelineCache,
+        create_infos: &[impl Cast<Target = GraphicsPipelineCreateInfo>],
+        allocator: Option<&AllocationCallbacks>,
+        pipelines: &mut Vec<Pipeline>,
+    ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateGraphicsPipelines",
+            device = tracing::field::debug(self.handle()),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
+        pipelines.clear();
+        pipelines.reserve(create_infos.len() as usize);
+
+        let __result = (self.commands().create_graphics_pipelines)(
+            self.handle(),
+            pipeline_cache,
+            create_infos.len() as u32,
+            create_infos.as_ptr().cast(),
+            allocator.map_or(ptr::null(), |v| v),
+            pipelines.as_mut_ptr(),
+        );
+
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
+        if __result >= Result::SUCCESS {
+            pipelines.set_len(create_infos.len() as usize);
+        }
+
+        if __result >= Result::SUCCESS {
+            Ok(__result.into())
+        } else {
+            Err(__result.into())
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCreateImage.html>
     #[inline]
     unsafe fn create_image(
@@ -1550,6 +2851,14 @@ pub trait DeviceV1_0 {
         create_info: &ImageCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<Image> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateImage",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut image = MaybeUninit::<Image>::uninit();
 
         let __result = (self.commands().create_image)(
@@ -1559,6 +2868,9 @@ pub trait DeviceV1_0 {
             image.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(image.assume_init())
         } else {
@@ -1573,6 +2885,14 @@ pub trait DeviceV1_0 {
         create_info: &ImageViewCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<ImageView> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateImageView",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut view = MaybeUninit::<ImageView>::uninit();
 
         let __result = (self.commands().create_image_view)(
@@ -1582,6 +2902,9 @@ pub trait DeviceV1_0 {
             view.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(view.assume_init())
         } else {
@@ -1596,6 +2919,14 @@ pub trait DeviceV1_0 {
         create_info: &PipelineCacheCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<PipelineCache> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreatePipelineCache",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut pipeline_cache = MaybeUninit::<PipelineCache>::uninit();
 
         let __result = (self.commands().create_pipeline_cache)(
@@ -1605,6 +2936,9 @@ pub trait DeviceV1_0 {
             pipeline_cache.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(pipeline_cache.assume_init())
         } else {
@@ -1619,6 +2953,14 @@ pub trait DeviceV1_0 {
         create_info: &PipelineLayoutCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<PipelineLayout> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreatePipelineLayout",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut pipeline_layout = MaybeUninit::<PipelineLayout>::uninit();
 
         let __result = (self.commands().create_pipeline_layout)(
@@ -1628,6 +2970,9 @@ pub trait DeviceV1_0 {
             pipeline_layout.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(pipeline_layout.assume_init())
         } else {
@@ -1642,6 +2987,14 @@ pub trait DeviceV1_0 {
         create_info: &QueryPoolCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<QueryPool> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateQueryPool",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut query_pool = MaybeUninit::<QueryPool>::uninit();
 
         let __result = (self.commands().create_query_pool)(
@@ -1651,6 +3004,9 @@ pub trait DeviceV1_0 {
             query_pool.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(query_pool.assume_init())
         } else {
@@ -1665,6 +3021,14 @@ pub trait DeviceV1_0 {
         create_info: &RenderPassCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<RenderPass> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateRenderPass",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut render_pass = MaybeUninit::<RenderPass>::uninit();
 
         let __result = (self.commands().create_render_pass)(
@@ -1674,6 +3038,9 @@ pub trait DeviceV1_0 {
             render_pass.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(render_pass.assume_init())
         } else {
@@ -1688,6 +3055,14 @@ pub trait DeviceV1_0 {
         create_info: &SamplerCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<Sampler> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateSampler",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut sampler = MaybeUninit::<Sampler>::uninit();
 
         let __result = (self.commands().create_sampler)(
@@ -1697,6 +3072,9 @@ pub trait DeviceV1_0 {
             sampler.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(sampler.assume_init())
         } else {
@@ -1711,6 +3089,14 @@ pub trait DeviceV1_0 {
         create_info: &SemaphoreCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<Semaphore> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateSemaphore",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut semaphore = MaybeUninit::<Semaphore>::uninit();
 
         let __result = (self.commands().create_semaphore)(
@@ -1720,6 +3106,9 @@ pub trait DeviceV1_0 {
             semaphore.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(semaphore.assume_init())
         } else {
@@ -1734,6 +3123,14 @@ pub trait DeviceV1_0 {
         create_info: &ShaderModuleCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<ShaderModule> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateShaderModule",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut shader_module = MaybeUninit::<ShaderModule>::uninit();
 
         let __result = (self.commands().create_shader_module)(
@@ -1743,6 +3140,9 @@ pub trait DeviceV1_0 {
             shader_module.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(shader_module.assume_init())
         } else {
@@ -1753,6 +3153,14 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkDestroyBuffer.html>
     #[inline]
     unsafe fn destroy_buffer(&self, buffer: Buffer, allocator: Option<&AllocationCallbacks>) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyBuffer",
+            device = tracing::field::debug(self.handle()),
+            buffer = tracing::field::debug(buffer)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_buffer)(
             self.handle(),
             buffer,
@@ -1767,6 +3175,14 @@ pub trait DeviceV1_0 {
         buffer_view: BufferView,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyBufferView",
+            device = tracing::field::debug(self.handle()),
+            buffer_view = tracing::field::debug(buffer_view)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_buffer_view)(
             self.handle(),
             buffer_view,
@@ -1781,6 +3197,14 @@ pub trait DeviceV1_0 {
         command_pool: CommandPool,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyCommandPool",
+            device = tracing::field::debug(self.handle()),
+            command_pool = tracing::field::debug(command_pool)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_command_pool)(
             self.handle(),
             command_pool,
@@ -1795,6 +3219,14 @@ pub trait DeviceV1_0 {
         descriptor_pool: DescriptorPool,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyDescriptorPool",
+            device = tracing::field::debug(self.handle()),
+            descriptor_pool = tracing::field::debug(descriptor_pool)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_descriptor_pool)(
             self.handle(),
             descriptor_pool,
@@ -1809,6 +3241,14 @@ pub trait DeviceV1_0 {
         descriptor_set_layout: DescriptorSetLayout,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyDescriptorSetLayout",
+            device = tracing::field::debug(self.handle()),
+            descriptor_set_layout = tracing::field::debug(descriptor_set_layout)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_descriptor_set_layout)(
             self.handle(),
             descriptor_set_layout,
@@ -1819,6 +3259,13 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkDestroyDevice.html>
     #[inline]
     unsafe fn destroy_device(&self, allocator: Option<&AllocationCallbacks>) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyDevice",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result =
             (self.commands().destroy_device)(self.handle(), allocator.map_or(ptr::null(), |v| v));
     }
@@ -1826,6 +3273,14 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkDestroyEvent.html>
     #[inline]
     unsafe fn destroy_event(&self, event: Event, allocator: Option<&AllocationCallbacks>) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyEvent",
+            device = tracing::field::debug(self.handle()),
+            event = tracing::field::debug(event)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_event)(
             self.handle(),
             event,
@@ -1836,6 +3291,14 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkDestroyFence.html>
     #[inline]
     unsafe fn destroy_fence(&self, fence: Fence, allocator: Option<&AllocationCallbacks>) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyFence",
+            device = tracing::field::debug(self.handle()),
+            fence = tracing::field::debug(fence)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_fence)(
             self.handle(),
             fence,
@@ -1850,6 +3313,14 @@ pub trait DeviceV1_0 {
         framebuffer: Framebuffer,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyFramebuffer",
+            device = tracing::field::debug(self.handle()),
+            framebuffer = tracing::field::debug(framebuffer)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_framebuffer)(
             self.handle(),
             framebuffer,
@@ -1860,6 +3331,14 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkDestroyImage.html>
     #[inline]
     unsafe fn destroy_image(&self, image: Image, allocator: Option<&AllocationCallbacks>) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyImage",
+            device = tracing::field::debug(self.handle()),
+            image = tracing::field::debug(image)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_image)(
             self.handle(),
             image,
@@ -1874,6 +3353,14 @@ pub trait DeviceV1_0 {
         image_view: ImageView,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyImageView",
+            device = tracing::field::debug(self.handle()),
+            image_view = tracing::field::debug(image_view)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_image_view)(
             self.handle(),
             image_view,
@@ -1884,6 +3371,14 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkDestroyPipeline.html>
     #[inline]
     unsafe fn destroy_pipeline(&self, pipeline: Pipeline, allocator: Option<&AllocationCallbacks>) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyPipeline",
+            device = tracing::field::debug(self.handle()),
+            pipeline = tracing::field::debug(pipeline)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_pipeline)(
             self.handle(),
             pipeline,
@@ -1898,6 +3393,14 @@ pub trait DeviceV1_0 {
         pipeline_cache: PipelineCache,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyPipelineCache",
+            device = tracing::field::debug(self.handle()),
+            pipeline_cache = tracing::field::debug(pipeline_cache)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_pipeline_cache)(
             self.handle(),
             pipeline_cache,
@@ -1912,6 +3415,14 @@ pub trait DeviceV1_0 {
         pipeline_layout: PipelineLayout,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyPipelineLayout",
+            device = tracing::field::debug(self.handle()),
+            pipeline_layout = tracing::field::debug(pipeline_layout)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_pipeline_layout)(
             self.handle(),
             pipeline_layout,
@@ -1926,6 +3437,14 @@ pub trait DeviceV1_0 {
         query_pool: QueryPool,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyQueryPool",
+            device = tracing::field::debug(self.handle()),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_query_pool)(
             self.handle(),
             query_pool,
@@ -1940,6 +3459,14 @@ pub trait DeviceV1_0 {
         render_pass: RenderPass,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyRenderPass",
+            device = tracing::field::debug(self.handle()),
+            render_pass = tracing::field::debug(render_pass)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_render_pass)(
             self.handle(),
             render_pass,
@@ -1950,6 +3477,14 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkDestroySampler.html>
     #[inline]
     unsafe fn destroy_sampler(&self, sampler: Sampler, allocator: Option<&AllocationCallbacks>) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroySampler",
+            device = tracing::field::debug(self.handle()),
+            sampler = tracing::field::debug(sampler)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_sampler)(
             self.handle(),
             sampler,
@@ -1964,6 +3499,14 @@ pub trait DeviceV1_0 {
         semaphore: Semaphore,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroySemaphore",
+            device = tracing::field::debug(self.handle()),
+            semaphore = tracing::field::debug(semaphore)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_semaphore)(
             self.handle(),
             semaphore,
@@ -1978,6 +3521,14 @@ pub trait DeviceV1_0 {
         shader_module: ShaderModule,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyShaderModule",
+            device = tracing::field::debug(self.handle()),
+            shader_module = tracing::field::debug(shader_module)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_shader_module)(
             self.handle(),
             shader_module,
@@ -1988,8 +3539,19 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkDeviceWaitIdle.html>
     #[inline]
     unsafe fn device_wait_idle(&self) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDeviceWaitIdle",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().device_wait_idle)(self.handle());
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2000,8 +3562,19 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEndCommandBuffer.html>
     #[inline]
     unsafe fn end_command_buffer(&self, command_buffer: CommandBuffer) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEndCommandBuffer",
+            command_buffer = tracing::field::debug(command_buffer),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().end_command_buffer)(command_buffer);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2015,12 +3588,23 @@ pub trait DeviceV1_0 {
         &self,
         memory_ranges: &[impl Cast<Target = MappedMemoryRange>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkFlushMappedMemoryRanges",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().flush_mapped_memory_ranges)(
             self.handle(),
             memory_ranges.len() as u32,
             memory_ranges.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2035,6 +3619,14 @@ pub trait DeviceV1_0 {
         command_pool: CommandPool,
         command_buffers: &[CommandBuffer],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkFreeCommandBuffers",
+            device = tracing::field::debug(self.handle()),
+            command_pool = tracing::field::debug(command_pool)
+        )
+        .entered();
+
         let __result = (self.commands().free_command_buffers)(
             self.handle(),
             command_pool,
@@ -2050,6 +3642,15 @@ pub trait DeviceV1_0 {
         descriptor_pool: DescriptorPool,
         descriptor_sets: &[DescriptorSet],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkFreeDescriptorSets",
+            device = tracing::field::debug(self.handle()),
+            descriptor_pool = tracing::field::debug(descriptor_pool),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().free_descriptor_sets)(
             self.handle(),
             descriptor_pool,
@@ -2057,6 +3658,9 @@ pub trait DeviceV1_0 {
             descriptor_sets.as_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2067,6 +3671,14 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkFreeMemory.html>
     #[inline]
     unsafe fn free_memory(&self, memory: DeviceMemory, allocator: Option<&AllocationCallbacks>) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkFreeMemory",
+            device = tracing::field::debug(self.handle()),
+            memory = tracing::field::debug(memory)
+        )
+        .entered();
+
         let __result = (self.commands().free_memory)(
             self.handle(),
             memory,
@@ -2077,6 +3689,14 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetBufferMemoryRequirements.html>
     #[inline]
     unsafe fn get_buffer_memory_requirements(&self, buffer: Buffer) -> MemoryRequirements {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetBufferMemoryRequirements",
+            device = tracing::field::debug(self.handle()),
+            buffer = tracing::field::debug(buffer)
+        )
+        .entered();
+
         let mut memory_requirements = MaybeUninit::<MemoryRequirements>::uninit();
 
         let __result = (self.commands().get_buffer_memory_requirements)(
@@ -2091,6 +3711,14 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetDeviceMemoryCommitment.html>
     #[inline]
     unsafe fn get_device_memory_commitment(&self, memory: DeviceMemory) -> DeviceSize {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceMemoryCommitment",
+            device = tracing::field::debug(self.handle()),
+            memory = tracing::field::debug(memory)
+        )
+        .entered();
+
         let mut committed_memory_in_bytes = MaybeUninit::<DeviceSize>::uninit();
 
         let __result = (self.commands().get_device_memory_commitment)(
@@ -2105,6 +3733,13 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetDeviceQueue.html>
     #[inline]
     unsafe fn get_device_queue(&self, queue_family_index: u32, queue_index: u32) -> Queue {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceQueue",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut queue = MaybeUninit::<Queue>::uninit();
 
         let __result = (self.commands().get_device_queue)(
@@ -2120,8 +3755,20 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetEventStatus.html>
     #[inline]
     unsafe fn get_event_status(&self, event: Event) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetEventStatus",
+            device = tracing::field::debug(self.handle()),
+            event = tracing::field::debug(event),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_event_status)(self.handle(), event);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -2132,8 +3779,20 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetFenceStatus.html>
     #[inline]
     unsafe fn get_fence_status(&self, fence: Fence) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetFenceStatus",
+            device = tracing::field::debug(self.handle()),
+            fence = tracing::field::debug(fence),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_fence_status)(self.handle(), fence);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -2144,6 +3803,14 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetImageMemoryRequirements.html>
     #[inline]
     unsafe fn get_image_memory_requirements(&self, image: Image) -> MemoryRequirements {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageMemoryRequirements",
+            device = tracing::field::debug(self.handle()),
+            image = tracing::field::debug(image)
+        )
+        .entered();
+
         let mut memory_requirements = MaybeUninit::<MemoryRequirements>::uninit();
 
         let __result = (self.commands().get_image_memory_requirements)(
@@ -2161,6 +3828,14 @@ pub trait DeviceV1_0 {
         &self,
         image: Image,
     ) -> Vec<SparseImageMemoryRequirements> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageSparseMemoryRequirements",
+            device = tracing::field::debug(self.handle()),
+            image = tracing::field::debug(image)
+        )
+        .entered();
+
         let mut sparse_memory_requirement_count = 0;
 
         (self.commands().get_image_sparse_memory_requirements)(
@@ -2188,6 +3863,49 @@ pub trait DeviceV1_0 {
         sparse_memory_requirements
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetImageSparseMemoryRequirements.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_image_sparse_memory_requirements`].
+    #[inline]
+    unsafe fn get_image_sparse_memory_requirements_into(
+        &self,
+        image: Image,
+        sparse_memory_requirements: &mut Vec<SparseImageMemoryRequirements>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageSparseMemoryRequirements",
+            device = tracing::field::debug(self.handle()),
+            image = tracing::field::debug(image)
+        )
+        .entered();
+
+        let mut sparse_memory_requirement_count = 0;
+
+        (self.commands().get_image_sparse_memory_requirements)(
+            self.handle(),
+            image,
+            &mut sparse_memory_requirement_count,
+            ptr::null_mut(),
+        );
+
+        sparse_memory_requirements.clear();
+        sparse_memory_requirements.reserve(sparse_memory_requirement_count as usize);
+
+        let __result = (self.commands().get_image_sparse_memory_requirements)(
+            self.handle(),
+            image,
+            &mut sparse_memory_requirement_count,
+            sparse_memory_requirements.as_mut_ptr(),
+        );
+
+        debug_assert!(
+            sparse_memory_requirements.capacity() >= sparse_memory_requirement_count as usize
+        );
+        sparse_memory_requirements.set_len(sparse_memory_requirement_count as usize);
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetImageSubresourceLayout.html>
     #[inline]
     unsafe fn get_image_subresource_layout(
@@ -2195,6 +3913,14 @@ pub trait DeviceV1_0 {
         image: Image,
         subresource: &ImageSubresource,
     ) -> SubresourceLayout {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageSubresourceLayout",
+            device = tracing::field::debug(self.handle()),
+            image = tracing::field::debug(image)
+        )
+        .entered();
+
         let mut layout = MaybeUninit::<SubresourceLayout>::uninit();
 
         let __result = (self.commands().get_image_subresource_layout)(
@@ -2213,31 +3939,120 @@ pub trait DeviceV1_0 {
         &self,
         pipeline_cache: PipelineCache,
     ) -> crate::VkResult<Vec<u8>> {
-        let mut data_size = 0;
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineCacheData",
+            device = tracing::field::debug(self.handle()),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        (self.commands().get_pipeline_cache_data)(
-            self.handle(),
-            pipeline_cache,
-            &mut data_size,
-            ptr::null_mut(),
-        );
+        let mut data_size = 0;
 
-        let mut data = Vec::with_capacity(data_size as usize);
+        loop {
+            let __result = (self.commands().get_pipeline_cache_data)(
+                self.handle(),
+                pipeline_cache,
+                &mut data_size,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut data = Vec::with_capacity(data_size as usize);
+
+            let __result = (self.commands().get_pipeline_cache_data)(
+                self.handle(),
+                pipeline_cache,
+                &mut data_size,
+                data.as_mut_ptr() as *mut c_void,
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(data.capacity() >= data_size as usize);
+            data.set_len(data_size as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(data)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
 
-        let __result = (self.commands().get_pipeline_cache_data)(
-            self.handle(),
-            pipeline_cache,
-            &mut data_size,
-            data.as_mut_ptr() as *mut c_void,
-        );
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPipelineCacheData.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_pipeline_cache_data`].
+    #[inline]
+    unsafe fn get_pipeline_cache_data_into(
+        &self,
+        pipeline_cache: PipelineCache,
+        data: &mut Vec<u8>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPipelineCacheData",
+            device = tracing::field::debug(self.handle()),
+            pipeline_cache = tracing::field::debug(pipeline_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        debug_assert!(data.capacity() >= data_size as usize);
-        data.set_len(data_size as usize);
+        let mut data_size = 0;
 
-        if __result == Result::SUCCESS {
-            Ok(data)
-        } else {
-            Err(__result.into())
+        loop {
+            let __result = (self.commands().get_pipeline_cache_data)(
+                self.handle(),
+                pipeline_cache,
+                &mut data_size,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            data.clear();
+            data.reserve(data_size as usize);
+
+            let __result = (self.commands().get_pipeline_cache_data)(
+                self.handle(),
+                pipeline_cache,
+                &mut data_size,
+                data.as_mut_ptr() as *mut c_void,
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(data.capacity() >= data_size as usize);
+                data.set_len(data_size as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -2252,6 +4067,15 @@ pub trait DeviceV1_0 {
         stride: DeviceSize,
         flags: QueryResultFlags,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetQueryPoolResults",
+            device = tracing::field::debug(self.handle()),
+            query_pool = tracing::field::debug(query_pool),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_query_pool_results)(
             self.handle(),
             query_pool,
@@ -2263,6 +4087,9 @@ pub trait DeviceV1_0 {
             flags,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -2273,6 +4100,14 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetRenderAreaGranularity.html>
     #[inline]
     unsafe fn get_render_area_granularity(&self, render_pass: RenderPass) -> Extent2D {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetRenderAreaGranularity",
+            device = tracing::field::debug(self.handle()),
+            render_pass = tracing::field::debug(render_pass)
+        )
+        .entered();
+
         let mut granularity = MaybeUninit::<Extent2D>::uninit();
 
         let __result = (self.commands().get_render_area_granularity)(
@@ -2290,12 +4125,23 @@ pub trait DeviceV1_0 {
         &self,
         memory_ranges: &[impl Cast<Target = MappedMemoryRange>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkInvalidateMappedMemoryRanges",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().invalidate_mapped_memory_ranges)(
             self.handle(),
             memory_ranges.len() as u32,
             memory_ranges.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2312,6 +4158,15 @@ pub trait DeviceV1_0 {
         size: DeviceSize,
         flags: MemoryMapFlags,
     ) -> crate::VkResult<*mut c_void> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkMapMemory",
+            device = tracing::field::debug(self.handle()),
+            memory = tracing::field::debug(memory),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut data = MaybeUninit::<*mut c_void>::uninit();
 
         let __result = (self.commands().map_memory)(
@@ -2323,6 +4178,9 @@ pub trait DeviceV1_0 {
             data.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(data.assume_init())
         } else {
@@ -2337,6 +4195,15 @@ pub trait DeviceV1_0 {
         dst_cache: PipelineCache,
         src_caches: &[PipelineCache],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkMergePipelineCaches",
+            device = tracing::field::debug(self.handle()),
+            dst_cache = tracing::field::debug(dst_cache),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().merge_pipeline_caches)(
             self.handle(),
             dst_cache,
@@ -2344,6 +4211,9 @@ pub trait DeviceV1_0 {
             src_caches.as_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2359,6 +4229,15 @@ pub trait DeviceV1_0 {
         bind_info: &[impl Cast<Target = BindSparseInfo>],
         fence: Fence,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkQueueBindSparse",
+            queue = tracing::field::debug(queue),
+            fence = tracing::field::debug(fence),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().queue_bind_sparse)(
             queue,
             bind_info.len() as u32,
@@ -2366,6 +4245,9 @@ pub trait DeviceV1_0 {
             fence,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2381,6 +4263,15 @@ pub trait DeviceV1_0 {
         submits: &[impl Cast<Target = SubmitInfo>],
         fence: Fence,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkQueueSubmit",
+            queue = tracing::field::debug(queue),
+            fence = tracing::field::debug(fence),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().queue_submit)(
             queue,
             submits.len() as u32,
@@ -2388,6 +4279,9 @@ pub trait DeviceV1_0 {
             fence,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2398,8 +4292,19 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkQueueWaitIdle.html>
     #[inline]
     unsafe fn queue_wait_idle(&self, queue: Queue) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkQueueWaitIdle",
+            queue = tracing::field::debug(queue),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().queue_wait_idle)(queue);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2414,8 +4319,19 @@ pub trait DeviceV1_0 {
         command_buffer: CommandBuffer,
         flags: CommandBufferResetFlags,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkResetCommandBuffer",
+            command_buffer = tracing::field::debug(command_buffer),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().reset_command_buffer)(command_buffer, flags);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2430,8 +4346,20 @@ pub trait DeviceV1_0 {
         command_pool: CommandPool,
         flags: CommandPoolResetFlags,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkResetCommandPool",
+            device = tracing::field::debug(self.handle()),
+            command_pool = tracing::field::debug(command_pool),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().reset_command_pool)(self.handle(), command_pool, flags);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2446,9 +4374,21 @@ pub trait DeviceV1_0 {
         descriptor_pool: DescriptorPool,
         flags: DescriptorPoolResetFlags,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkResetDescriptorPool",
+            device = tracing::field::debug(self.handle()),
+            descriptor_pool = tracing::field::debug(descriptor_pool),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().reset_descriptor_pool)(self.handle(), descriptor_pool, flags);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2459,8 +4399,20 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkResetEvent.html>
     #[inline]
     unsafe fn reset_event(&self, event: Event) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkResetEvent",
+            device = tracing::field::debug(self.handle()),
+            event = tracing::field::debug(event),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().reset_event)(self.handle(), event);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2471,9 +4423,20 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkResetFences.html>
     #[inline]
     unsafe fn reset_fences(&self, fences: &[Fence]) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkResetFences",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().reset_fences)(self.handle(), fences.len() as u32, fences.as_ptr());
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2484,8 +4447,20 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkSetEvent.html>
     #[inline]
     unsafe fn set_event(&self, event: Event) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetEvent",
+            device = tracing::field::debug(self.handle()),
+            event = tracing::field::debug(event),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().set_event)(self.handle(), event);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2496,6 +4471,14 @@ pub trait DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkUnmapMemory.html>
     #[inline]
     unsafe fn unmap_memory(&self, memory: DeviceMemory) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkUnmapMemory",
+            device = tracing::field::debug(self.handle()),
+            memory = tracing::field::debug(memory)
+        )
+        .entered();
+
         let __result = (self.commands().unmap_memory)(self.handle(), memory);
     }
 
@@ -2506,6 +4489,13 @@ pub trait DeviceV1_0 {
         descriptor_writes: &[impl Cast<Target = WriteDescriptorSet>],
         descriptor_copies: &[impl Cast<Target = CopyDescriptorSet>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkUpdateDescriptorSets",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().update_descriptor_sets)(
             self.handle(),
             descriptor_writes.len() as u32,
@@ -2523,6 +4513,14 @@ pub trait DeviceV1_0 {
         wait_all: bool,
         timeout: u64,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkWaitForFences",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().wait_for_fences)(
             self.handle(),
             fences.len() as u32,
@@ -2531,6 +4529,9 @@ pub trait DeviceV1_0 {
             timeout,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -2542,7 +4543,7 @@ pub trait DeviceV1_0 {
 impl DeviceV1_0 for crate::Device {
     #[inline]
     fn commands(&self) -> &DeviceCommands {
-        &self.commands
+        &self.state.commands
     }
 
     #[inline]
@@ -2568,10 +4569,18 @@ pub trait EntryV1_1: EntryV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEnumerateInstanceVersion.html>
     #[inline]
     unsafe fn enumerate_instance_version(&self) -> crate::VkResult<u32> {
+        #[cfg(feature = "tracing")]
+        let __span =
+            tracing::trace_span!("vkEnumerateInstanceVersion", result = tracing::field::Empty)
+                .entered();
+
         let mut api_version = MaybeUninit::<u32>::uninit();
 
         let __result = (self.commands().enumerate_instance_version)(api_version.as_mut_ptr());
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(api_version.assume_init())
         } else {
@@ -2589,32 +4598,119 @@ pub trait InstanceV1_1: InstanceV1_0 {
     unsafe fn enumerate_physical_device_groups(
         &self,
     ) -> crate::VkResult<Vec<PhysicalDeviceGroupProperties>> {
-        let mut physical_device_group_count = 0;
-
-        (self.commands().enumerate_physical_device_groups)(
-            self.handle(),
-            &mut physical_device_group_count,
-            ptr::null_mut(),
-        );
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumeratePhysicalDeviceGroups",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        let mut physical_device_group_properties =
-            Vec::with_capacity(physical_device_group_count as usize);
+        let mut physical_device_group_count = 0;
 
-        let __result = (self.commands().enumerate_physical_device_groups)(
-            self.handle(),
-            &mut physical_device_group_count,
-            physical_device_group_properties.as_mut_ptr(),
-        );
+        loop {
+            let __result = (self.commands().enumerate_physical_device_groups)(
+                self.handle(),
+                &mut physical_device_group_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut physical_device_group_properties =
+                Vec::with_capacity(physical_device_group_count as usize);
+
+            let __result = (self.commands().enumerate_physical_device_groups)(
+                self.handle(),
+                &mut physical_device_group_count,
+                physical_device_group_properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(
+                physical_device_group_properties.capacity() >= physical_device_group_count as usize
+            );
+            physical_device_group_properties.set_len(physical_device_group_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(physical_device_group_properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
 
-        debug_assert!(
-            physical_device_group_properties.capacity() >= physical_device_group_count as usize
-        );
-        physical_device_group_properties.set_len(physical_device_group_count as usize);
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkEnumeratePhysicalDeviceGroups.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::enumerate_physical_device_groups`].
+    #[inline]
+    unsafe fn enumerate_physical_device_groups_into(
+        &self,
+        physical_device_group_properties: &mut Vec<PhysicalDeviceGroupProperties>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkEnumeratePhysicalDeviceGroups",
+            instance = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        if __result == Result::SUCCESS {
-            Ok(physical_device_group_properties)
-        } else {
-            Err(__result.into())
+        let mut physical_device_group_count = 0;
+
+        loop {
+            let __result = (self.commands().enumerate_physical_device_groups)(
+                self.handle(),
+                &mut physical_device_group_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            physical_device_group_properties.clear();
+            physical_device_group_properties.reserve(physical_device_group_count as usize);
+
+            let __result = (self.commands().enumerate_physical_device_groups)(
+                self.handle(),
+                &mut physical_device_group_count,
+                physical_device_group_properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(
+                    physical_device_group_properties.capacity()
+                        >= physical_device_group_count as usize
+                );
+                physical_device_group_properties.set_len(physical_device_group_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 
@@ -2626,6 +4722,13 @@ pub trait InstanceV1_1: InstanceV1_0 {
         external_buffer_info: &PhysicalDeviceExternalBufferInfo,
         external_buffer_properties: &mut ExternalBufferProperties,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceExternalBufferProperties",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_external_buffer_properties)(
@@ -2643,6 +4746,13 @@ pub trait InstanceV1_1: InstanceV1_0 {
         external_fence_info: &PhysicalDeviceExternalFenceInfo,
         external_fence_properties: &mut ExternalFenceProperties,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceExternalFenceProperties",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_external_fence_properties)(
@@ -2660,6 +4770,13 @@ pub trait InstanceV1_1: InstanceV1_0 {
         external_semaphore_info: &PhysicalDeviceExternalSemaphoreInfo,
         external_semaphore_properties: &mut ExternalSemaphoreProperties,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceExternalSemaphoreProperties",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self
             .commands()
             .get_physical_device_external_semaphore_properties)(
@@ -2676,6 +4793,13 @@ pub trait InstanceV1_1: InstanceV1_0 {
         physical_device: PhysicalDevice,
         features: &mut PhysicalDeviceFeatures2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceFeatures2",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self.commands().get_physical_device_features2)(physical_device, features);
     }
 
@@ -2687,6 +4811,13 @@ pub trait InstanceV1_1: InstanceV1_0 {
         format: Format,
         format_properties: &mut FormatProperties2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceFormatProperties2",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self.commands().get_physical_device_format_properties2)(
             physical_device,
             format,
@@ -2702,12 +4833,23 @@ pub trait InstanceV1_1: InstanceV1_0 {
         image_format_info: &PhysicalDeviceImageFormatInfo2,
         image_format_properties: &mut ImageFormatProperties2,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceImageFormatProperties2",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().get_physical_device_image_format_properties2)(
             physical_device,
             image_format_info,
             image_format_properties,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2722,6 +4864,13 @@ pub trait InstanceV1_1: InstanceV1_0 {
         physical_device: PhysicalDevice,
         memory_properties: &mut PhysicalDeviceMemoryProperties2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceMemoryProperties2",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result = (self.commands().get_physical_device_memory_properties2)(
             physical_device,
             memory_properties,
@@ -2735,6 +4884,13 @@ pub trait InstanceV1_1: InstanceV1_0 {
         physical_device: PhysicalDevice,
         properties: &mut PhysicalDeviceProperties2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceProperties2",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let __result =
             (self.commands().get_physical_device_properties2)(physical_device, properties);
     }
@@ -2745,6 +4901,13 @@ pub trait InstanceV1_1: InstanceV1_0 {
         &self,
         physical_device: PhysicalDevice,
     ) -> Vec<QueueFamilyProperties2> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceQueueFamilyProperties2",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut queue_family_property_count = 0;
 
         (self.commands().get_physical_device_queue_family_properties2)(
@@ -2767,6 +4930,44 @@ pub trait InstanceV1_1: InstanceV1_0 {
         queue_family_properties
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceQueueFamilyProperties2.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_queue_family_properties2`].
+    #[inline]
+    unsafe fn get_physical_device_queue_family_properties2_into(
+        &self,
+        physical_device: PhysicalDevice,
+        queue_family_properties: &mut Vec<QueueFamilyProperties2>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceQueueFamilyProperties2",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
+        let mut queue_family_property_count = 0;
+
+        (self.commands().get_physical_device_queue_family_properties2)(
+            physical_device,
+            &mut queue_family_property_count,
+            ptr::null_mut(),
+        );
+
+        queue_family_properties.clear();
+        queue_family_properties.reserve(queue_family_property_count as usize);
+
+        let __result = (self.commands().get_physical_device_queue_family_properties2)(
+            physical_device,
+            &mut queue_family_property_count,
+            queue_family_properties.as_mut_ptr(),
+        );
+
+        debug_assert!(queue_family_properties.capacity() >= queue_family_property_count as usize);
+        queue_family_properties.set_len(queue_family_property_count as usize);
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceSparseImageFormatProperties2.html>
     #[inline]
     unsafe fn get_physical_device_sparse_image_format_properties2(
@@ -2774,6 +4975,13 @@ pub trait InstanceV1_1: InstanceV1_0 {
         physical_device: PhysicalDevice,
         format_info: &PhysicalDeviceSparseImageFormatInfo2,
     ) -> Vec<SparseImageFormatProperties2> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSparseImageFormatProperties2",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
         let mut property_count = 0;
 
         (self
@@ -2801,6 +5009,51 @@ pub trait InstanceV1_1: InstanceV1_0 {
 
         properties
     }
+
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceSparseImageFormatProperties2.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_sparse_image_format_properties2`].
+    #[inline]
+    unsafe fn get_physical_device_sparse_image_format_properties2_into(
+        &self,
+        physical_device: PhysicalDevice,
+        format_info: &PhysicalDeviceSparseImageFormatInfo2,
+        properties: &mut Vec<SparseImageFormatProperties2>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceSparseImageFormatProperties2",
+            physical_device = tracing::field::debug(physical_device)
+        )
+        .entered();
+
+        let mut property_count = 0;
+
+        (self
+            .commands()
+            .get_physical_device_sparse_image_format_properties2)(
+            physical_device,
+            format_info,
+            &mut property_count,
+            ptr::null_mut(),
+        );
+
+        properties.clear();
+        properties.reserve(property_count as usize);
+
+        let __result = (self
+            .commands()
+            .get_physical_device_sparse_image_format_properties2)(
+            physical_device,
+            format_info,
+            &mut property_count,
+            properties.as_mut_ptr(),
+        );
+
+        debug_assert!(properties.capacity() >= property_count as usize);
+        properties.set_len(property_count as usize);
+    }
 }
 
 impl<C: InstanceV1_0 + ?Sized> InstanceV1_1 for C {}
@@ -2813,12 +5066,23 @@ pub trait DeviceV1_1: DeviceV1_0 {
         &self,
         bind_infos: &[impl Cast<Target = BindBufferMemoryInfo>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBindBufferMemory2",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().bind_buffer_memory2)(
             self.handle(),
             bind_infos.len() as u32,
             bind_infos.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2832,12 +5096,23 @@ pub trait DeviceV1_1: DeviceV1_0 {
         &self,
         bind_infos: &[impl Cast<Target = BindImageMemoryInfo>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkBindImageMemory2",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().bind_image_memory2)(
             self.handle(),
             bind_infos.len() as u32,
             bind_infos.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -2857,6 +5132,13 @@ pub trait DeviceV1_1: DeviceV1_0 {
         group_count_y: u32,
         group_count_z: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDispatchBase",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_dispatch_base)(
             command_buffer,
             base_group_x,
@@ -2871,6 +5153,13 @@ pub trait DeviceV1_1: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdSetDeviceMask.html>
     #[inline]
     unsafe fn cmd_set_device_mask(&self, command_buffer: CommandBuffer, device_mask: u32) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDeviceMask",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_device_mask)(command_buffer, device_mask);
     }
 
@@ -2881,6 +5170,14 @@ pub trait DeviceV1_1: DeviceV1_0 {
         create_info: &DescriptorUpdateTemplateCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<DescriptorUpdateTemplate> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateDescriptorUpdateTemplate",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut descriptor_update_template = MaybeUninit::<DescriptorUpdateTemplate>::uninit();
 
         let __result = (self.commands().create_descriptor_update_template)(
@@ -2890,6 +5187,9 @@ pub trait DeviceV1_1: DeviceV1_0 {
             descriptor_update_template.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(descriptor_update_template.assume_init())
         } else {
@@ -2904,6 +5204,14 @@ pub trait DeviceV1_1: DeviceV1_0 {
         create_info: &SamplerYcbcrConversionCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<SamplerYcbcrConversion> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateSamplerYcbcrConversion",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut ycbcr_conversion = MaybeUninit::<SamplerYcbcrConversion>::uninit();
 
         let __result = (self.commands().create_sampler_ycbcr_conversion)(
@@ -2913,6 +5221,9 @@ pub trait DeviceV1_1: DeviceV1_0 {
             ycbcr_conversion.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(ycbcr_conversion.assume_init())
         } else {
@@ -2927,6 +5238,14 @@ pub trait DeviceV1_1: DeviceV1_0 {
         descriptor_update_template: DescriptorUpdateTemplate,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyDescriptorUpdateTemplate",
+            device = tracing::field::debug(self.handle()),
+            descriptor_update_template = tracing::field::debug(descriptor_update_template)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_descriptor_update_template)(
             self.handle(),
             descriptor_update_template,
@@ -2941,6 +5260,14 @@ pub trait DeviceV1_1: DeviceV1_0 {
         ycbcr_conversion: SamplerYcbcrConversion,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroySamplerYcbcrConversion",
+            device = tracing::field::debug(self.handle()),
+            ycbcr_conversion = tracing::field::debug(ycbcr_conversion)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_sampler_ycbcr_conversion)(
             self.handle(),
             ycbcr_conversion,
@@ -2955,6 +5282,13 @@ pub trait DeviceV1_1: DeviceV1_0 {
         info: &BufferMemoryRequirementsInfo2,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetBufferMemoryRequirements2",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_buffer_memory_requirements2)(
             self.handle(),
             info,
@@ -2969,6 +5303,13 @@ pub trait DeviceV1_1: DeviceV1_0 {
         create_info: &DescriptorSetLayoutCreateInfo,
         support: &mut DescriptorSetLayoutSupport,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDescriptorSetLayoutSupport",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_descriptor_set_layout_support)(
             self.handle(),
             create_info,
@@ -2984,6 +5325,13 @@ pub trait DeviceV1_1: DeviceV1_0 {
         local_device_index: u32,
         remote_device_index: u32,
     ) -> PeerMemoryFeatureFlags {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceGroupPeerMemoryFeatures",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut peer_memory_features = MaybeUninit::<PeerMemoryFeatureFlags>::uninit();
 
         let __result = (self.commands().get_device_group_peer_memory_features)(
@@ -3000,6 +5348,13 @@ pub trait DeviceV1_1: DeviceV1_0 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetDeviceQueue2.html>
     #[inline]
     unsafe fn get_device_queue2(&self, queue_info: &DeviceQueueInfo2) -> Queue {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceQueue2",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut queue = MaybeUninit::<Queue>::uninit();
 
         let __result =
@@ -3015,6 +5370,13 @@ pub trait DeviceV1_1: DeviceV1_0 {
         info: &ImageMemoryRequirementsInfo2,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageMemoryRequirements2",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_image_memory_requirements2)(
             self.handle(),
             info,
@@ -3028,6 +5390,13 @@ pub trait DeviceV1_1: DeviceV1_0 {
         &self,
         info: &ImageSparseMemoryRequirementsInfo2,
     ) -> Vec<SparseImageMemoryRequirements2> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageSparseMemoryRequirements2",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut sparse_memory_requirement_count = 0;
 
         (self.commands().get_image_sparse_memory_requirements2)(
@@ -3055,9 +5424,59 @@ pub trait DeviceV1_1: DeviceV1_0 {
         sparse_memory_requirements
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetImageSparseMemoryRequirements2.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_image_sparse_memory_requirements2`].
+    #[inline]
+    unsafe fn get_image_sparse_memory_requirements2_into(
+        &self,
+        info: &ImageSparseMemoryRequirementsInfo2,
+        sparse_memory_requirements: &mut Vec<SparseImageMemoryRequirements2>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageSparseMemoryRequirements2",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
+        let mut sparse_memory_requirement_count = 0;
+
+        (self.commands().get_image_sparse_memory_requirements2)(
+            self.handle(),
+            info,
+            &mut sparse_memory_requirement_count,
+            ptr::null_mut(),
+        );
+
+        sparse_memory_requirements.clear();
+        sparse_memory_requirements.reserve(sparse_memory_requirement_count as usize);
+
+        let __result = (self.commands().get_image_sparse_memory_requirements2)(
+            self.handle(),
+            info,
+            &mut sparse_memory_requirement_count,
+            sparse_memory_requirements.as_mut_ptr(),
+        );
+
+        debug_assert!(
+            sparse_memory_requirements.capacity() >= sparse_memory_requirement_count as usize
+        );
+        sparse_memory_requirements.set_len(sparse_memory_requirement_count as usize);
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkTrimCommandPool.html>
     #[inline]
     unsafe fn trim_command_pool(&self, command_pool: CommandPool, flags: CommandPoolTrimFlags) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkTrimCommandPool",
+            device = tracing::field::debug(self.handle()),
+            command_pool = tracing::field::debug(command_pool)
+        )
+        .entered();
+
         let __result = (self.commands().trim_command_pool)(self.handle(), command_pool, flags);
     }
 
@@ -3069,6 +5488,15 @@ pub trait DeviceV1_1: DeviceV1_0 {
         descriptor_update_template: DescriptorUpdateTemplate,
         data: *const c_void,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkUpdateDescriptorSetWithTemplate",
+            device = tracing::field::debug(self.handle()),
+            descriptor_set = tracing::field::debug(descriptor_set),
+            descriptor_update_template = tracing::field::debug(descriptor_update_template)
+        )
+        .entered();
+
         let __result = (self.commands().update_descriptor_set_with_template)(
             self.handle(),
             descriptor_set,
@@ -3100,6 +5528,13 @@ pub trait DeviceV1_2: DeviceV1_1 {
         render_pass_begin: &RenderPassBeginInfo,
         subpass_begin_info: &SubpassBeginInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginRenderPass2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_begin_render_pass2)(
             command_buffer,
             render_pass_begin,
@@ -3119,6 +5554,15 @@ pub trait DeviceV1_2: DeviceV1_1 {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawIndexedIndirectCount",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer),
+            count_buffer = tracing::field::debug(count_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_indexed_indirect_count)(
             command_buffer,
             buffer,
@@ -3142,6 +5586,15 @@ pub trait DeviceV1_2: DeviceV1_1 {
         max_draw_count: u32,
         stride: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdDrawIndirectCount",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer),
+            count_buffer = tracing::field::debug(count_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_draw_indirect_count)(
             command_buffer,
             buffer,
@@ -3160,6 +5613,13 @@ pub trait DeviceV1_2: DeviceV1_1 {
         command_buffer: CommandBuffer,
         subpass_end_info: &SubpassEndInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndRenderPass2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_end_render_pass2)(command_buffer, subpass_end_info);
     }
 
@@ -3171,6 +5631,13 @@ pub trait DeviceV1_2: DeviceV1_1 {
         subpass_begin_info: &SubpassBeginInfo,
         subpass_end_info: &SubpassEndInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdNextSubpass2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_next_subpass2)(
             command_buffer,
             subpass_begin_info,
@@ -3185,6 +5652,14 @@ pub trait DeviceV1_2: DeviceV1_1 {
         create_info: &RenderPassCreateInfo2,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<RenderPass> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreateRenderPass2",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut render_pass = MaybeUninit::<RenderPass>::uninit();
 
         let __result = (self.commands().create_render_pass2)(
@@ -3194,6 +5669,9 @@ pub trait DeviceV1_2: DeviceV1_1 {
             render_pass.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(render_pass.assume_init())
         } else {
@@ -3204,6 +5682,13 @@ pub trait DeviceV1_2: DeviceV1_1 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetBufferDeviceAddress.html>
     #[inline]
     unsafe fn get_buffer_device_address(&self, info: &BufferDeviceAddressInfo) -> DeviceAddress {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetBufferDeviceAddress",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_buffer_device_address)(self.handle(), info);
 
         __result
@@ -3212,6 +5697,13 @@ pub trait DeviceV1_2: DeviceV1_1 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetBufferOpaqueCaptureAddress.html>
     #[inline]
     unsafe fn get_buffer_opaque_capture_address(&self, info: &BufferDeviceAddressInfo) -> u64 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetBufferOpaqueCaptureAddress",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_buffer_opaque_capture_address)(self.handle(), info);
 
         __result
@@ -3223,6 +5715,13 @@ pub trait DeviceV1_2: DeviceV1_1 {
         &self,
         info: &DeviceMemoryOpaqueCaptureAddressInfo,
     ) -> u64 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceMemoryOpaqueCaptureAddress",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result =
             (self.commands().get_device_memory_opaque_capture_address)(self.handle(), info);
 
@@ -3232,6 +5731,15 @@ pub trait DeviceV1_2: DeviceV1_1 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetSemaphoreCounterValue.html>
     #[inline]
     unsafe fn get_semaphore_counter_value(&self, semaphore: Semaphore) -> crate::VkResult<u64> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetSemaphoreCounterValue",
+            device = tracing::field::debug(self.handle()),
+            semaphore = tracing::field::debug(semaphore),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut value = MaybeUninit::<u64>::uninit();
 
         let __result = (self.commands().get_semaphore_counter_value)(
@@ -3240,6 +5748,9 @@ pub trait DeviceV1_2: DeviceV1_1 {
             value.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(value.assume_init())
         } else {
@@ -3250,6 +5761,14 @@ pub trait DeviceV1_2: DeviceV1_1 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkResetQueryPool.html>
     #[inline]
     unsafe fn reset_query_pool(&self, query_pool: QueryPool, first_query: u32, query_count: u32) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkResetQueryPool",
+            device = tracing::field::debug(self.handle()),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result =
             (self.commands().reset_query_pool)(self.handle(), query_pool, first_query, query_count);
     }
@@ -3257,8 +5776,19 @@ pub trait DeviceV1_2: DeviceV1_1 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkSignalSemaphore.html>
     #[inline]
     unsafe fn signal_semaphore(&self, signal_info: &SemaphoreSignalInfo) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSignalSemaphore",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().signal_semaphore)(self.handle(), signal_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3273,8 +5803,19 @@ pub trait DeviceV1_2: DeviceV1_1 {
         wait_info: &SemaphoreWaitInfo,
         timeout: u64,
     ) -> crate::VkResult<SuccessCode> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkWaitSemaphores",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().wait_semaphores)(self.handle(), wait_info, timeout);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result >= Result::SUCCESS {
             Ok(__result.into())
         } else {
@@ -3298,29 +5839,114 @@ pub trait InstanceV1_3: InstanceV1_2 {
         &self,
         physical_device: PhysicalDevice,
     ) -> crate::VkResult<Vec<PhysicalDeviceToolProperties>> {
-        let mut tool_count = 0;
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceToolProperties",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        (self.commands().get_physical_device_tool_properties)(
-            physical_device,
-            &mut tool_count,
-            ptr::null_mut(),
-        );
+        let mut tool_count = 0;
 
-        let mut tool_properties = Vec::with_capacity(tool_count as usize);
+        loop {
+            let __result = (self.commands().get_physical_device_tool_properties)(
+                physical_device,
+                &mut tool_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            let mut tool_properties = Vec::with_capacity(tool_count as usize);
+
+            let __result = (self.commands().get_physical_device_tool_properties)(
+                physical_device,
+                &mut tool_count,
+                tool_properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            debug_assert!(tool_properties.capacity() >= tool_count as usize);
+            tool_properties.set_len(tool_count as usize);
+
+            break if __result == Result::SUCCESS {
+                Ok(tool_properties)
+            } else {
+                Err(__result.into())
+            };
+        }
+    }
 
-        let __result = (self.commands().get_physical_device_tool_properties)(
-            physical_device,
-            &mut tool_count,
-            tool_properties.as_mut_ptr(),
-        );
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPhysicalDeviceToolProperties.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_physical_device_tool_properties`].
+    #[inline]
+    unsafe fn get_physical_device_tool_properties_into(
+        &self,
+        physical_device: PhysicalDevice,
+        tool_properties: &mut Vec<PhysicalDeviceToolProperties>,
+    ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPhysicalDeviceToolProperties",
+            physical_device = tracing::field::debug(physical_device),
+            result = tracing::field::Empty
+        )
+        .entered();
 
-        debug_assert!(tool_properties.capacity() >= tool_count as usize);
-        tool_properties.set_len(tool_count as usize);
+        let mut tool_count = 0;
 
-        if __result == Result::SUCCESS {
-            Ok(tool_properties)
-        } else {
-            Err(__result.into())
+        loop {
+            let __result = (self.commands().get_physical_device_tool_properties)(
+                physical_device,
+                &mut tool_count,
+                ptr::null_mut(),
+            );
+
+            if __result < Result::SUCCESS {
+                #[cfg(feature = "tracing")]
+                __span.record("result", tracing::field::debug(__result));
+                break Err(__result.into());
+            }
+
+            tool_properties.clear();
+            tool_properties.reserve(tool_count as usize);
+
+            let __result = (self.commands().get_physical_device_tool_properties)(
+                physical_device,
+                &mut tool_count,
+                tool_properties.as_mut_ptr(),
+            );
+
+            if __result == Result::INCOMPLETE {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            __span.record("result", tracing::field::debug(__result));
+
+            if __result >= Result::SUCCESS {
+                debug_assert!(tool_properties.capacity() >= tool_count as usize);
+                tool_properties.set_len(tool_count as usize);
+            }
+
+            break if __result == Result::SUCCESS {
+                Ok(())
+            } else {
+                Err(__result.into())
+            };
         }
     }
 }
@@ -3336,6 +5962,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         rendering_info: &RenderingInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBeginRendering",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_begin_rendering)(command_buffer, rendering_info);
     }
 
@@ -3350,6 +5983,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         sizes: &[DeviceSize],
         strides: &[DeviceSize],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindVertexBuffers2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_vertex_buffers2)(
             command_buffer,
             first_binding,
@@ -3368,6 +6008,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         blit_image_info: &BlitImageInfo2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBlitImage2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_blit_image2)(command_buffer, blit_image_info);
     }
 
@@ -3378,6 +6025,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         copy_buffer_info: &CopyBufferInfo2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyBuffer2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_buffer2)(command_buffer, copy_buffer_info);
     }
 
@@ -3388,6 +6042,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         copy_buffer_to_image_info: &CopyBufferToImageInfo2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyBufferToImage2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_copy_buffer_to_image2)(command_buffer, copy_buffer_to_image_info);
     }
@@ -3399,6 +6060,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         copy_image_info: &CopyImageInfo2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyImage2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_copy_image2)(command_buffer, copy_image_info);
     }
 
@@ -3409,6 +6077,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         copy_image_to_buffer_info: &CopyImageToBufferInfo2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdCopyImageToBuffer2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_copy_image_to_buffer2)(command_buffer, copy_image_to_buffer_info);
     }
@@ -3416,6 +6091,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdEndRendering.html>
     #[inline]
     unsafe fn cmd_end_rendering(&self, command_buffer: CommandBuffer) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdEndRendering",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_end_rendering)(command_buffer);
     }
 
@@ -3426,6 +6108,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         dependency_info: &DependencyInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPipelineBarrier2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_pipeline_barrier2)(command_buffer, dependency_info);
     }
 
@@ -3437,6 +6126,14 @@ pub trait DeviceV1_3: DeviceV1_2 {
         event: Event,
         stage_mask: PipelineStageFlags2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdResetEvent2",
+            command_buffer = tracing::field::debug(command_buffer),
+            event = tracing::field::debug(event)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_reset_event2)(command_buffer, event, stage_mask);
     }
 
@@ -3447,12 +6144,26 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         resolve_image_info: &ResolveImageInfo2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdResolveImage2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_resolve_image2)(command_buffer, resolve_image_info);
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdSetCullMode.html>
     #[inline]
     unsafe fn cmd_set_cull_mode(&self, command_buffer: CommandBuffer, cull_mode: CullModeFlags) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetCullMode",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_cull_mode)(command_buffer, cull_mode);
     }
 
@@ -3463,6 +6174,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         depth_bias_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthBiasEnable",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_bias_enable)(
             command_buffer,
             depth_bias_enable as Bool32,
@@ -3476,6 +6194,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         depth_bounds_test_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthBoundsTestEnable",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_bounds_test_enable)(
             command_buffer,
             depth_bounds_test_enable as Bool32,
@@ -3489,6 +6214,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         depth_compare_op: CompareOp,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthCompareOp",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_compare_op)(command_buffer, depth_compare_op);
     }
 
@@ -3499,6 +6231,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         depth_test_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthTestEnable",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_test_enable)(
             command_buffer,
             depth_test_enable as Bool32,
@@ -3512,6 +6251,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         depth_write_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetDepthWriteEnable",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_depth_write_enable)(
             command_buffer,
             depth_write_enable as Bool32,
@@ -3526,12 +6272,27 @@ pub trait DeviceV1_3: DeviceV1_2 {
         event: Event,
         dependency_info: &DependencyInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetEvent2",
+            command_buffer = tracing::field::debug(command_buffer),
+            event = tracing::field::debug(event)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_event2)(command_buffer, event, dependency_info);
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkCmdSetFrontFace.html>
     #[inline]
     unsafe fn cmd_set_front_face(&self, command_buffer: CommandBuffer, front_face: FrontFace) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetFrontFace",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_front_face)(command_buffer, front_face);
     }
 
@@ -3542,6 +6303,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         primitive_restart_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPrimitiveRestartEnable",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_primitive_restart_enable)(
             command_buffer,
             primitive_restart_enable as Bool32,
@@ -3555,6 +6323,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         primitive_topology: PrimitiveTopology,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetPrimitiveTopology",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_primitive_topology)(command_buffer, primitive_topology);
     }
@@ -3566,6 +6341,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         rasterizer_discard_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRasterizerDiscardEnable",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_rasterizer_discard_enable)(
             command_buffer,
             rasterizer_discard_enable as Bool32,
@@ -3579,6 +6361,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         scissors: &[impl Cast<Target = Rect2D>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetScissorWithCount",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_scissor_with_count)(
             command_buffer,
             scissors.len() as u32,
@@ -3597,6 +6386,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         depth_fail_op: StencilOp,
         compare_op: CompareOp,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetStencilOp",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_stencil_op)(
             command_buffer,
             face_mask,
@@ -3614,6 +6410,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         stencil_test_enable: bool,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetStencilTestEnable",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_stencil_test_enable)(
             command_buffer,
             stencil_test_enable as Bool32,
@@ -3627,6 +6430,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         command_buffer: CommandBuffer,
         viewports: &[impl Cast<Target = Viewport>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetViewportWithCount",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_viewport_with_count)(
             command_buffer,
             viewports.len() as u32,
@@ -3642,6 +6452,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         events: &[Event],
         dependency_infos: &[impl Cast<Target = DependencyInfo>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdWaitEvents2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_wait_events2)(
             command_buffer,
             events.len() as u32,
@@ -3659,6 +6476,14 @@ pub trait DeviceV1_3: DeviceV1_2 {
         query_pool: QueryPool,
         query: u32,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdWriteTimestamp2",
+            command_buffer = tracing::field::debug(command_buffer),
+            query_pool = tracing::field::debug(query_pool)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_write_timestamp2)(command_buffer, stage, query_pool, query);
     }
@@ -3670,6 +6495,14 @@ pub trait DeviceV1_3: DeviceV1_2 {
         create_info: &PrivateDataSlotCreateInfo,
         allocator: Option<&AllocationCallbacks>,
     ) -> crate::VkResult<PrivateDataSlot> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCreatePrivateDataSlot",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut private_data_slot = MaybeUninit::<PrivateDataSlot>::uninit();
 
         let __result = (self.commands().create_private_data_slot)(
@@ -3679,6 +6512,9 @@ pub trait DeviceV1_3: DeviceV1_2 {
             private_data_slot.as_mut_ptr(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(private_data_slot.assume_init())
         } else {
@@ -3693,6 +6529,14 @@ pub trait DeviceV1_3: DeviceV1_2 {
         private_data_slot: PrivateDataSlot,
         allocator: Option<&AllocationCallbacks>,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkDestroyPrivateDataSlot",
+            device = tracing::field::debug(self.handle()),
+            private_data_slot = tracing::field::debug(private_data_slot)
+        )
+        .entered();
+
         let __result = (self.commands().destroy_private_data_slot)(
             self.handle(),
             private_data_slot,
@@ -3707,6 +6551,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         info: &DeviceBufferMemoryRequirements,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceBufferMemoryRequirements",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_device_buffer_memory_requirements)(
             self.handle(),
             info,
@@ -3721,6 +6572,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         info: &DeviceImageMemoryRequirements,
         memory_requirements: &mut MemoryRequirements2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceImageMemoryRequirements",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result = (self.commands().get_device_image_memory_requirements)(
             self.handle(),
             info,
@@ -3734,6 +6592,13 @@ pub trait DeviceV1_3: DeviceV1_2 {
         &self,
         info: &DeviceImageMemoryRequirements,
     ) -> Vec<SparseImageMemoryRequirements2> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceImageSparseMemoryRequirements",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut sparse_memory_requirement_count = 0;
 
         (self.commands().get_device_image_sparse_memory_requirements)(
@@ -3761,6 +6626,48 @@ pub trait DeviceV1_3: DeviceV1_2 {
         sparse_memory_requirements
     }
 
+    /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetDeviceImageSparseMemoryRequirements.html>
+    ///
+    /// Writes the output array(s) into the supplied `Vec`(s) (which are cleared
+    /// first) instead of allocating new `Vec`(s) like [`Self::get_device_image_sparse_memory_requirements`].
+    #[inline]
+    unsafe fn get_device_image_sparse_memory_requirements_into(
+        &self,
+        info: &DeviceImageMemoryRequirements,
+        sparse_memory_requirements: &mut Vec<SparseImageMemoryRequirements2>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceImageSparseMemoryRequirements",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
+        let mut sparse_memory_requirement_count = 0;
+
+        (self.commands().get_device_image_sparse_memory_requirements)(
+            self.handle(),
+            info,
+            &mut sparse_memory_requirement_count,
+            ptr::null_mut(),
+        );
+
+        sparse_memory_requirements.clear();
+        sparse_memory_requirements.reserve(sparse_memory_requirement_count as usize);
+
+        let __result = (self.commands().get_device_image_sparse_memory_requirements)(
+            self.handle(),
+            info,
+            &mut sparse_memory_requirement_count,
+            sparse_memory_requirements.as_mut_ptr(),
+        );
+
+        debug_assert!(
+            sparse_memory_requirements.capacity() >= sparse_memory_requirement_count as usize
+        );
+        sparse_memory_requirements.set_len(sparse_memory_requirement_count as usize);
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkGetPrivateData.html>
     #[inline]
     unsafe fn get_private_data(
@@ -3769,6 +6676,14 @@ pub trait DeviceV1_3: DeviceV1_2 {
         object_handle: u64,
         private_data_slot: PrivateDataSlot,
     ) -> u64 {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetPrivateData",
+            device = tracing::field::debug(self.handle()),
+            private_data_slot = tracing::field::debug(private_data_slot)
+        )
+        .entered();
+
         let mut data = MaybeUninit::<u64>::uninit();
 
         let __result = (self.commands().get_private_data)(
@@ -3790,6 +6705,15 @@ pub trait DeviceV1_3: DeviceV1_2 {
         submits: &[impl Cast<Target = SubmitInfo2>],
         fence: Fence,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkQueueSubmit2",
+            queue = tracing::field::debug(queue),
+            fence = tracing::field::debug(fence),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().queue_submit2)(
             queue,
             submits.len() as u32,
@@ -3797,6 +6721,9 @@ pub trait DeviceV1_3: DeviceV1_2 {
             fence,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3813,6 +6740,15 @@ pub trait DeviceV1_3: DeviceV1_2 {
         private_data_slot: PrivateDataSlot,
         data: u64,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkSetPrivateData",
+            device = tracing::field::debug(self.handle()),
+            private_data_slot = tracing::field::debug(private_data_slot),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().set_private_data)(
             self.handle(),
             object_type,
@@ -3821,6 +6757,9 @@ pub trait DeviceV1_3: DeviceV1_2 {
             data,
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -3850,6 +6789,13 @@ pub trait DeviceV1_4: DeviceV1_3 {
         command_buffer: CommandBuffer,
         bind_descriptor_sets_info: &BindDescriptorSetsInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindDescriptorSets2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_bind_descriptor_sets2)(command_buffer, bind_descriptor_sets_info);
     }
@@ -3864,6 +6810,14 @@ pub trait DeviceV1_4: DeviceV1_3 {
         size: DeviceSize,
         index_type: IndexType,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdBindIndexBuffer2",
+            command_buffer = tracing::field::debug(command_buffer),
+            buffer = tracing::field::debug(buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_bind_index_buffer2)(
             command_buffer,
             buffer,
@@ -3880,6 +6834,13 @@ pub trait DeviceV1_4: DeviceV1_3 {
         command_buffer: CommandBuffer,
         push_constants_info: &PushConstantsInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushConstants2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_push_constants2)(command_buffer, push_constants_info);
     }
 
@@ -3893,6 +6854,14 @@ pub trait DeviceV1_4: DeviceV1_3 {
         set: u32,
         descriptor_writes: &[impl Cast<Target = WriteDescriptorSet>],
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushDescriptorSet",
+            command_buffer = tracing::field::debug(command_buffer),
+            layout = tracing::field::debug(layout)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_push_descriptor_set)(
             command_buffer,
             pipeline_bind_point,
@@ -3910,6 +6879,13 @@ pub trait DeviceV1_4: DeviceV1_3 {
         command_buffer: CommandBuffer,
         push_descriptor_set_info: &PushDescriptorSetInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushDescriptorSet2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_push_descriptor_set2)(command_buffer, push_descriptor_set_info);
     }
@@ -3924,6 +6900,15 @@ pub trait DeviceV1_4: DeviceV1_3 {
         set: u32,
         data: *const c_void,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushDescriptorSetWithTemplate",
+            command_buffer = tracing::field::debug(command_buffer),
+            descriptor_update_template = tracing::field::debug(descriptor_update_template),
+            layout = tracing::field::debug(layout)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_push_descriptor_set_with_template)(
             command_buffer,
             descriptor_update_template,
@@ -3940,6 +6925,13 @@ pub trait DeviceV1_4: DeviceV1_3 {
         command_buffer: CommandBuffer,
         push_descriptor_set_with_template_info: &PushDescriptorSetWithTemplateInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdPushDescriptorSetWithTemplate2",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_push_descriptor_set_with_template2)(
             command_buffer,
             push_descriptor_set_with_template_info,
@@ -3954,6 +6946,13 @@ pub trait DeviceV1_4: DeviceV1_3 {
         line_stipple_factor: u32,
         line_stipple_pattern: u16,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetLineStipple",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_line_stipple)(
             command_buffer,
             line_stipple_factor,
@@ -3968,6 +6967,13 @@ pub trait DeviceV1_4: DeviceV1_3 {
         command_buffer: CommandBuffer,
         location_info: &RenderingAttachmentLocationInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRenderingAttachmentLocations",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result =
             (self.commands().cmd_set_rendering_attachment_locations)(command_buffer, location_info);
     }
@@ -3979,6 +6985,13 @@ pub trait DeviceV1_4: DeviceV1_3 {
         command_buffer: CommandBuffer,
         input_attachment_index_info: &RenderingInputAttachmentIndexInfo,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCmdSetRenderingInputAttachmentIndices",
+            command_buffer = tracing::field::debug(command_buffer)
+        )
+        .entered();
+
         let __result = (self.commands().cmd_set_rendering_input_attachment_indices)(
             command_buffer,
             input_attachment_index_info,
@@ -3991,9 +7004,20 @@ pub trait DeviceV1_4: DeviceV1_3 {
         &self,
         copy_image_to_image_info: &CopyImageToImageInfo,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCopyImageToImage",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().copy_image_to_image)(self.handle(), copy_image_to_image_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -4007,9 +7031,20 @@ pub trait DeviceV1_4: DeviceV1_3 {
         &self,
         copy_image_to_memory_info: &CopyImageToMemoryInfo,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCopyImageToMemory",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().copy_image_to_memory)(self.handle(), copy_image_to_memory_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -4023,9 +7058,20 @@ pub trait DeviceV1_4: DeviceV1_3 {
         &self,
         copy_memory_to_image_info: &CopyMemoryToImageInfo,
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkCopyMemoryToImage",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result =
             (self.commands().copy_memory_to_image)(self.handle(), copy_memory_to_image_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -4040,6 +7086,13 @@ pub trait DeviceV1_4: DeviceV1_3 {
         info: &DeviceImageSubresourceInfo,
         layout: &mut SubresourceLayout2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetDeviceImageSubresourceLayout",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let __result =
             (self.commands().get_device_image_subresource_layout)(self.handle(), info, layout);
     }
@@ -4052,6 +7105,14 @@ pub trait DeviceV1_4: DeviceV1_3 {
         subresource: &ImageSubresource2,
         layout: &mut SubresourceLayout2,
     ) {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetImageSubresourceLayout2",
+            device = tracing::field::debug(self.handle()),
+            image = tracing::field::debug(image)
+        )
+        .entered();
+
         let __result = (self.commands().get_image_subresource_layout2)(
             self.handle(),
             image,
@@ -4066,6 +7127,13 @@ pub trait DeviceV1_4: DeviceV1_3 {
         &self,
         rendering_area_info: &RenderingAreaInfo,
     ) -> Extent2D {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkGetRenderingAreaGranularity",
+            device = tracing::field::debug(self.handle())
+        )
+        .entered();
+
         let mut granularity = MaybeUninit::<Extent2D>::uninit();
 
         let __result = (self.commands().get_rendering_area_granularity)(
@@ -4080,11 +7148,22 @@ pub trait DeviceV1_4: DeviceV1_3 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkMapMemory2.html>
     #[inline]
     unsafe fn map_memory2(&self, memory_map_info: &MemoryMapInfo) -> crate::VkResult<*mut c_void> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkMapMemory2",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let mut data = MaybeUninit::<*mut c_void>::uninit();
 
         let __result =
             (self.commands().map_memory2)(self.handle(), memory_map_info, data.as_mut_ptr());
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(data.assume_init())
         } else {
@@ -4098,12 +7177,23 @@ pub trait DeviceV1_4: DeviceV1_3 {
         &self,
         transitions: &[impl Cast<Target = HostImageLayoutTransitionInfo>],
     ) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkTransitionImageLayout",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().transition_image_layout)(
             self.handle(),
             transitions.len() as u32,
             transitions.as_ptr().cast(),
         );
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
@@ -4114,8 +7204,19 @@ pub trait DeviceV1_4: DeviceV1_3 {
     /// <https://www.khronos.org/registry/vulkan/specs/latest/man/html/vkUnmapMemory2.html>
     #[inline]
     unsafe fn unmap_memory2(&self, memory_unmap_info: &MemoryUnmapInfo) -> crate::VkResult<()> {
+        #[cfg(feature = "tracing")]
+        let __span = tracing::trace_span!(
+            "vkUnmapMemory2",
+            device = tracing::field::debug(self.handle()),
+            result = tracing::field::Empty
+        )
+        .entered();
+
         let __result = (self.commands().unmap_memory2)(self.handle(), memory_unmap_info);
 
+        #[cfg(feature = "tracing")]
+        __span.record("result", tracing::field::debug(__result));
+
         if __result == Result::SUCCESS {
             Ok(())
         } else {
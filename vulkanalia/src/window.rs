@@ -1,16 +1,83 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Window integration.
+//!
+//! Surfaces can be created for windows from libraries that support
+//! `raw-window-handle` with [`create_surface`], without any window or display
+//! with [`create_headless_surface`] (e.g., for testing), or for displays
+//! attached to a physical device with the [`display`] module.
+//!
+//! A [`Swapchain`] manages the swapchain (and its images and image views) for
+//! a surface and handles recreating the swapchain when it no longer matches
+//! the surface.
 
-use raw_window_handle::{HasDisplayHandle, HasWindowHandle, RawDisplayHandle, RawWindowHandle};
+pub mod display;
+mod swapchain;
+
+pub use self::swapchain::*;
+
+use core::fmt;
+
+#[cfg(all(feature = "no_std_error", not(feature = "std")))]
+use core::error;
+#[cfg(feature = "std")]
+use std::error;
+
+use raw_window_handle::{
+    HandleError, HasDisplayHandle, HasWindowHandle, RawDisplayHandle, RawWindowHandle,
+};
 
 use crate::prelude::v1_0::*;
 
-/// Gets the required instance extensions for window integration.
+/// An error encountered while integrating with a window system.
+#[derive(Clone, Debug)]
+pub enum WindowError {
+    /// The display or window handle is of a kind that is not supported (on
+    /// the current platform).
+    Unsupported,
+    /// The display handle is not available.
+    MissingDisplay(HandleError),
+    /// The window handle is not available.
+    Unavailable(HandleError),
+    /// A Vulkan command failed.
+    Vulkan(vk::ErrorCode),
+}
+
+impl From<vk::ErrorCode> for WindowError {
+    #[inline]
+    fn from(error: vk::ErrorCode) -> Self {
+        Self::Vulkan(error)
+    }
+}
+
+impl fmt::Display for WindowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unsupported => write!(f, "unsupported display or window handle"),
+            Self::MissingDisplay(error) => write!(f, "display handle not available ({error})"),
+            Self::Unavailable(error) => write!(f, "window handle not available ({error})"),
+            Self::Vulkan(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "no_std_error"))]
+impl error::Error for WindowError {}
+
+/// Gets the required instance extensions for window integration with a
+/// display (e.g., a Wayland or X11 connection).
+///
+/// Only a display is required so the extensions can be determined before any
+/// windows are created.
 pub fn get_required_instance_extensions(
-    window: &dyn HasWindowHandle,
-) -> &'static [&'static vk::ExtensionName] {
-    match window.window_handle().map(|handle| handle.as_raw()) {
+    display: &dyn HasDisplayHandle,
+) -> Result<&'static [&'static vk::ExtensionName], WindowError> {
+    let display = display
+        .display_handle()
+        .map_err(WindowError::MissingDisplay)?
+        .as_raw();
+
+    match display {
         // BSD / Linux
         #[cfg(any(
             target_os = "dragonfly",
@@ -19,10 +86,10 @@ pub fn get_required_instance_extensions(
             target_os = "netbsd",
             target_os = "openbsd"
         ))]
-        Ok(RawWindowHandle::Wayland(_window)) => &[
+        RawDisplayHandle::Wayland(_) => Ok(&[
             &vk::KHR_SURFACE_EXTENSION.name,
             &vk::KHR_WAYLAND_SURFACE_EXTENSION.name,
-        ],
+        ]),
         #[cfg(any(
             target_os = "dragonfly",
             target_os = "freebsd",
@@ -30,10 +97,10 @@ pub fn get_required_instance_extensions(
             target_os = "netbsd",
             target_os = "openbsd"
         ))]
-        Ok(RawWindowHandle::Xcb(_window)) => &[
+        RawDisplayHandle::Xcb(_) => Ok(&[
             &vk::KHR_SURFACE_EXTENSION.name,
             &vk::KHR_XCB_SURFACE_EXTENSION.name,
-        ],
+        ]),
         #[cfg(any(
             target_os = "dragonfly",
             target_os = "freebsd",
@@ -41,30 +108,30 @@ pub fn get_required_instance_extensions(
             target_os = "netbsd",
             target_os = "openbsd"
         ))]
-        Ok(RawWindowHandle::Xlib(_window)) => &[
+        RawDisplayHandle::Xlib(_) => Ok(&[
             &vk::KHR_SURFACE_EXTENSION.name,
             &vk::KHR_XLIB_SURFACE_EXTENSION.name,
-        ],
+        ]),
         // macOS
         #[cfg(target_os = "macos")]
-        Ok(RawWindowHandle::AppKit(_window)) => &[
+        RawDisplayHandle::AppKit(_) => Ok(&[
             &vk::KHR_SURFACE_EXTENSION.name,
             &vk::EXT_METAL_SURFACE_EXTENSION.name,
-        ],
+        ]),
         // Windows
         #[cfg(target_os = "windows")]
-        Ok(RawWindowHandle::Win32(_window)) => &[
+        RawDisplayHandle::Windows(_) => Ok(&[
             &vk::KHR_SURFACE_EXTENSION.name,
             &vk::KHR_WIN32_SURFACE_EXTENSION.name,
-        ],
+        ]),
         // Android
         #[cfg(target_os = "android")]
-        Ok(RawWindowHandle::AndroidNdk(_window)) => &[
+        RawDisplayHandle::Android(_) => Ok(&[
             &vk::KHR_SURFACE_EXTENSION.name,
             &vk::KHR_ANDROID_SURFACE_EXTENSION.name,
-        ],
+        ]),
         // Unsupported (currently)
-        _ => unimplemented!(),
+        _ => Err(WindowError::Unsupported),
     }
 }
 
@@ -80,11 +147,17 @@ pub unsafe fn create_surface(
     instance: &Instance,
     display: &dyn HasDisplayHandle,
     window: &dyn HasWindowHandle,
-) -> VkResult<vk::SurfaceKHR> {
-    match (
-        display.display_handle().map(|handle| handle.as_raw()),
-        window.window_handle().map(|handle| handle.as_raw()),
-    ) {
+) -> Result<vk::SurfaceKHR, WindowError> {
+    let display = display
+        .display_handle()
+        .map_err(WindowError::MissingDisplay)?
+        .as_raw();
+    let window = window
+        .window_handle()
+        .map_err(WindowError::Unavailable)?
+        .as_raw();
+
+    match (display, window) {
         // BSD / Linux
         #[cfg(any(
             target_os = "dragonfly",
@@ -93,14 +166,14 @@ pub unsafe fn create_surface(
             target_os = "netbsd",
             target_os = "openbsd"
         ))]
-        (Ok(RawDisplayHandle::Wayland(display)), Ok(RawWindowHandle::Wayland(window))) => {
+        (RawDisplayHandle::Wayland(display), RawWindowHandle::Wayland(window)) => {
             use vk::KhrWaylandSurfaceExtensionInstanceCommands;
 
             let info = vk::WaylandSurfaceCreateInfoKHR::builder()
                 .display(display.display.as_ptr())
                 .surface(window.surface.as_ptr());
 
-            instance.create_wayland_surface_khr(&info, None)
+            Ok(instance.create_wayland_surface_khr(&info, None)?)
         }
         #[cfg(any(
             target_os = "dragonfly",
@@ -109,7 +182,7 @@ pub unsafe fn create_surface(
             target_os = "netbsd",
             target_os = "openbsd"
         ))]
-        (Ok(RawDisplayHandle::Xcb(display)), Ok(RawWindowHandle::Xcb(window))) => {
+        (RawDisplayHandle::Xcb(display), RawWindowHandle::Xcb(window)) => {
             use vk::KhrXcbSurfaceExtensionInstanceCommands;
 
             let connection_ptr = display
@@ -121,7 +194,7 @@ pub unsafe fn create_surface(
                 .connection(connection_ptr)
                 .window(window.window.get() as _);
 
-            instance.create_xcb_surface_khr(&info, None)
+            Ok(instance.create_xcb_surface_khr(&info, None)?)
         }
         #[cfg(any(
             target_os = "dragonfly",
@@ -130,7 +203,7 @@ pub unsafe fn create_surface(
             target_os = "netbsd",
             target_os = "openbsd"
         ))]
-        (Ok(RawDisplayHandle::Xlib(display)), Ok(RawWindowHandle::Xlib(window))) => {
+        (RawDisplayHandle::Xlib(display), RawWindowHandle::Xlib(window)) => {
             use vk::KhrXlibSurfaceExtensionInstanceCommands;
 
             let display_ptr = display
@@ -142,11 +215,11 @@ pub unsafe fn create_surface(
                 .dpy(&mut *(display_ptr as *mut _))
                 .window(window.window);
 
-            instance.create_xlib_surface_khr(&info, None)
+            Ok(instance.create_xlib_surface_khr(&info, None)?)
         }
         // macOS
         #[cfg(target_os = "macos")]
-        (Ok(RawDisplayHandle::AppKit(_)), Ok(RawWindowHandle::AppKit(window))) => {
+        (RawDisplayHandle::AppKit(_), RawWindowHandle::AppKit(window)) => {
             use std::os::raw::c_void;
 
             use cocoa::appkit::{NSView, NSWindow};
@@ -173,11 +246,11 @@ pub unsafe fn create_surface(
 
             let layer = (layer.as_ref() as *const MetalLayerRef).cast::<c_void>();
             let info = vk::MetalSurfaceCreateInfoEXT::builder().layer(layer);
-            instance.create_metal_surface_ext(&info, None)
+            Ok(instance.create_metal_surface_ext(&info, None)?)
         }
         // Windows
         #[cfg(target_os = "windows")]
-        (Ok(RawDisplayHandle::Windows(_)), Ok(RawWindowHandle::Win32(window))) => {
+        (RawDisplayHandle::Windows(_), RawWindowHandle::Win32(window)) => {
             use vk::KhrWin32SurfaceExtensionInstanceCommands;
 
             let hinstance_ptr = window
@@ -190,19 +263,41 @@ pub unsafe fn create_surface(
                 .hinstance(hinstance_ptr)
                 .hwnd(hwnd_ptr);
 
-            instance.create_win32_surface_khr(&info, None)
+            Ok(instance.create_win32_surface_khr(&info, None)?)
         }
         // Android
         #[cfg(target_os = "android")]
-        (Ok(RawDisplayHandle::Android(_)), Ok(RawWindowHandle::AndroidNdk(window))) => {
+        (RawDisplayHandle::Android(_), RawWindowHandle::AndroidNdk(window)) => {
             use vk::KhrAndroidSurfaceExtensionInstanceCommands;
 
             let info = vk::AndroidSurfaceCreateInfoKHR::builder()
                 .window(window.a_native_window.cast().as_mut());
 
-            instance.create_android_surface_khr(&info, None)
+            Ok(instance.create_android_surface_khr(&info, None)?)
         }
         // Unsupported (currently)
-        _ => unimplemented!(),
+        _ => Err(WindowError::Unsupported),
     }
 }
+
+/// Gets the required instance extensions for headless surfaces.
+pub fn get_required_headless_instance_extensions() -> &'static [&'static vk::ExtensionName] {
+    &[
+        &vk::KHR_SURFACE_EXTENSION.name,
+        &vk::EXT_HEADLESS_SURFACE_EXTENSION.name,
+    ]
+}
+
+/// Creates a headless surface (a surface which is not associated with any
+/// window or display and whose presented images are discarded).
+///
+/// # Safety
+///
+/// `instance` must have been created with the extensions returned by
+/// [`get_required_headless_instance_extensions`] enabled.
+pub unsafe fn create_headless_surface(instance: &Instance) -> VkResult<vk::SurfaceKHR> {
+    use vk::ExtHeadlessSurfaceExtensionInstanceCommands;
+
+    let info = vk::HeadlessSurfaceCreateInfoEXT::builder();
+    instance.create_headless_surface_ext(&info, None)
+}
@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Direct display integration.
+//!
+//! Surfaces can be created for displays attached to a physical device without
+//! a window system (e.g., on kiosk devices) with `VK_KHR_display`. The
+//! displays (and their modes) and the display planes of a physical device can
+//! be enumerated with [`get_displays`] and [`get_planes`] and then a surface
+//! can be created for a display mode on a display plane with
+//! [`create_surface`].
+//!
+//! ```no_run
+//! # use vulkanalia::prelude::v1_0::*;
+//! # use vulkanalia::window::display;
+//! # fn example(instance: &Instance, physical_device: vk::PhysicalDevice) -> VkResult<()> {
+//! let displays = unsafe { display::get_displays(instance, physical_device)? };
+//! let planes = unsafe { display::get_planes(instance, physical_device)? };
+//!
+//! let display = &displays[0];
+//! let mode = &display.modes[0];
+//! let plane = planes.iter().find(|p| p.supports(display.handle)).unwrap();
+//!
+//! let surface =
+//!     unsafe { display::create_surface(instance, physical_device, display, mode, plane)? };
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::CStr;
+
+use crate::prelude::v1_0::*;
+use crate::vk::KhrDisplayExtensionInstanceCommands;
+
+/// Gets the required instance extensions for direct display integration.
+pub fn get_required_instance_extensions() -> &'static [&'static vk::ExtensionName] {
+    &[
+        &vk::KHR_SURFACE_EXTENSION.name,
+        &vk::KHR_DISPLAY_EXTENSION.name,
+    ]
+}
+
+/// A display attached to a physical device.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Display {
+    /// The handle of the display.
+    pub handle: vk::DisplayKHR,
+    /// The name of the display (if any).
+    pub name: Option<String>,
+    /// The physical width and height of the visible portion of the display (in
+    /// millimeters).
+    pub physical_dimensions: vk::Extent2D,
+    /// The physical native (or preferred) resolution of the display.
+    pub physical_resolution: vk::Extent2D,
+    /// The transforms supported by the display.
+    pub supported_transforms: vk::SurfaceTransformFlagsKHR,
+    /// Whether the planes on the display can have their stack order changed.
+    pub plane_reorder_possible: bool,
+    /// Whether the display supports self-refresh (or internal buffering).
+    pub persistent_content: bool,
+    /// The modes supported by the display.
+    pub modes: Vec<DisplayMode>,
+}
+
+/// A mode supported by a display.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DisplayMode {
+    /// The handle of the display mode.
+    pub handle: vk::DisplayModeKHR,
+    /// The visible region of the display mode (in pixels).
+    pub visible_region: vk::Extent2D,
+    /// The refresh rate of the display mode (in millihertz).
+    pub refresh_rate: u32,
+}
+
+/// A display plane of a physical device.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayPlane {
+    /// The index of the display plane.
+    pub index: u32,
+    /// The display the plane is currently associated with (if any).
+    pub current_display: Option<vk::DisplayKHR>,
+    /// The current z-order of the plane.
+    pub current_stack_index: u32,
+    /// The displays the plane can be used with.
+    pub supported_displays: Vec<vk::DisplayKHR>,
+}
+
+impl DisplayPlane {
+    /// Returns whether this plane can be used with a display.
+    #[inline]
+    pub fn supports(&self, display: vk::DisplayKHR) -> bool {
+        self.supported_displays.contains(&display)
+    }
+}
+
+/// Gets the displays (and their modes) attached to a physical device.
+///
+/// # Safety
+///
+/// `instance` must have been created with the extensions returned by
+/// [`get_required_instance_extensions`] enabled.
+pub unsafe fn get_displays(
+    instance: &Instance,
+    physical_device: vk::PhysicalDevice,
+) -> VkResult<Vec<Display>> {
+    let properties = instance.get_physical_device_display_properties_khr(physical_device)?;
+
+    let mut displays = Vec::with_capacity(properties.len());
+    for properties in properties {
+        let modes = instance
+            .get_display_mode_properties_khr(physical_device, properties.display)?
+            .into_iter()
+            .map(|m| DisplayMode {
+                handle: m.display_mode,
+                visible_region: m.parameters.visible_region,
+                refresh_rate: m.parameters.refresh_rate,
+            })
+            .collect();
+
+        let name = if properties.display_name.is_null() {
+            None
+        } else {
+            let name = CStr::from_ptr(properties.display_name);
+            Some(name.to_string_lossy().into_owned())
+        };
+
+        displays.push(Display {
+            handle: properties.display,
+            name,
+            physical_dimensions: properties.physical_dimensions,
+            physical_resolution: properties.physical_resolution,
+            supported_transforms: properties.supported_transforms,
+            plane_reorder_possible: properties.plane_reorder_possible == vk::TRUE,
+            persistent_content: properties.persistent_content == vk::TRUE,
+            modes,
+        });
+    }
+
+    Ok(displays)
+}
+
+/// Gets the display planes of a physical device.
+///
+/// # Safety
+///
+/// `instance` must have been created with the extensions returned by
+/// [`get_required_instance_extensions`] enabled.
+pub unsafe fn get_planes(
+    instance: &Instance,
+    physical_device: vk::PhysicalDevice,
+) -> VkResult<Vec<DisplayPlane>> {
+    let properties = instance.get_physical_device_display_plane_properties_khr(physical_device)?;
+
+    let mut planes = Vec::with_capacity(properties.len());
+    for (index, properties) in properties.into_iter().enumerate() {
+        let index = index as u32;
+        let supported_displays =
+            instance.get_display_plane_supported_displays_khr(physical_device, index)?;
+
+        let current_display = if properties.current_display.is_null() {
+            None
+        } else {
+            Some(properties.current_display)
+        };
+
+        planes.push(DisplayPlane {
+            index,
+            current_display,
+            current_stack_index: properties.current_stack_index,
+            supported_displays,
+        });
+    }
+
+    Ok(planes)
+}
+
+/// Creates a surface for a display mode of a display on a display plane.
+///
+/// The surface covers the entire visible region of the display mode and uses
+/// the identity transform and opaque alpha if they are supported (or else the
+/// first supported transform and alpha mode).
+///
+/// # Safety
+///
+/// `instance` must have been created with the extensions returned by
+/// [`get_required_instance_extensions`] enabled, `display` and `plane` must
+/// have been retrieved for `physical_device`, and `mode` must be a mode of
+/// `display`.
+pub unsafe fn create_surface(
+    instance: &Instance,
+    physical_device: vk::PhysicalDevice,
+    display: &Display,
+    mode: &DisplayMode,
+    plane: &DisplayPlane,
+) -> VkResult<vk::SurfaceKHR> {
+    let capabilities =
+        instance.get_display_plane_capabilities_khr(physical_device, mode.handle, plane.index)?;
+
+    let transforms = display.supported_transforms;
+    let transform =
+        if transforms.is_empty() || transforms.contains(vk::SurfaceTransformFlagsKHR::IDENTITY) {
+            vk::SurfaceTransformFlagsKHR::IDENTITY
+        } else {
+            let bits = transforms.bits();
+            vk::SurfaceTransformFlagsKHR::from_bits_truncate(bits & bits.wrapping_neg())
+        };
+
+    let alpha_modes = capabilities.supported_alpha;
+    let alpha_mode =
+        if alpha_modes.is_empty() || alpha_modes.contains(vk::DisplayPlaneAlphaFlagsKHR::OPAQUE) {
+            vk::DisplayPlaneAlphaFlagsKHR::OPAQUE
+        } else {
+            let bits = alpha_modes.bits();
+            vk::DisplayPlaneAlphaFlagsKHR::from_bits_truncate(bits & bits.wrapping_neg())
+        };
+
+    let info = vk::DisplaySurfaceCreateInfoKHR::builder()
+        .display_mode(mode.handle)
+        .plane_index(plane.index)
+        .plane_stack_index(plane.current_stack_index)
+        .transform(transform)
+        .global_alpha(1.0)
+        .alpha_mode(alpha_mode)
+        .image_extent(mode.visible_region);
+
+    instance.create_display_plane_surface_khr(&info, None)
+}
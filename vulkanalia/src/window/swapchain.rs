@@ -0,0 +1,554 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Swapchain management.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::prelude::v1_0::*;
+use crate::vk::{KhrSurfaceExtensionInstanceCommands, KhrSwapchainExtensionDeviceCommands};
+
+/// The configuration of a [`Swapchain`].
+#[derive(Clone, Debug)]
+pub struct SwapchainConfig {
+    /// The preferred surface formats (most preferred first).
+    ///
+    /// The first supported format in this list is used. If none of these
+    /// formats are supported, the first format supported by the surface is
+    /// used.
+    pub formats: Vec<vk::SurfaceFormatKHR>,
+    /// The preferred present modes (most preferred first).
+    ///
+    /// The first supported present mode in this list is used. If none of these
+    /// present modes are supported, `FIFO` (which is always supported) is used.
+    pub present_modes: Vec<vk::PresentModeKHR>,
+    /// The minimum number of swapchain images.
+    ///
+    /// If this is `None`, one more than the minimum number of images required
+    /// by the surface is used. The number of images is always clamped to the
+    /// limits of the surface.
+    pub min_image_count: Option<u32>,
+    /// The usage of the swapchain images.
+    pub image_usage: vk::ImageUsageFlags,
+    /// The preferred alpha compositing mode.
+    ///
+    /// If this mode is not supported, the first supported mode is used.
+    pub composite_alpha: vk::CompositeAlphaFlagsKHR,
+    /// The queue families that will access the swapchain images.
+    ///
+    /// If this contains more than one distinct queue family, the swapchain
+    /// images are shared concurrently between those queue families.
+    pub queue_family_indices: Vec<u32>,
+    /// Whether the presentable images may discard rendering operations which
+    /// affect regions of the surface that are not visible.
+    pub clipped: bool,
+}
+
+impl Default for SwapchainConfig {
+    fn default() -> Self {
+        Self {
+            formats: vec![
+                vk::SurfaceFormatKHR {
+                    format: vk::Format::B8G8R8A8_SRGB,
+                    color_space: vk::ColorSpaceKHR::SRGB_NONLINEAR,
+                },
+                vk::SurfaceFormatKHR {
+                    format: vk::Format::R8G8B8A8_SRGB,
+                    color_space: vk::ColorSpaceKHR::SRGB_NONLINEAR,
+                },
+            ],
+            present_modes: vec![vk::PresentModeKHR::MAILBOX, vk::PresentModeKHR::FIFO],
+            min_image_count: None,
+            image_usage: vk::ImageUsageFlags::COLOR_ATTACHMENT,
+            composite_alpha: vk::CompositeAlphaFlagsKHR::OPAQUE,
+            queue_family_indices: Vec::new(),
+            clipped: true,
+        }
+    }
+}
+
+/// The outcome of acquiring an image from a [`Swapchain`].
+#[must_use]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Acquire {
+    /// An image was acquired.
+    Image {
+        /// The index of the acquired image.
+        index: u32,
+        /// Whether the swapchain no longer matches the surface exactly (but
+        /// can still be presented to).
+        suboptimal: bool,
+    },
+    /// No image was available before the timeout expired.
+    Timeout,
+    /// The swapchain no longer matches the surface and must be recreated
+    /// before an image can be acquired.
+    OutOfDate,
+}
+
+impl Acquire {
+    /// Returns whether the swapchain should be recreated.
+    #[inline]
+    pub fn needs_recreate(self) -> bool {
+        matches!(
+            self,
+            Self::Image {
+                suboptimal: true,
+                ..
+            } | Self::OutOfDate
+        )
+    }
+}
+
+/// The outcome of presenting an image from a [`Swapchain`].
+#[must_use]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Present {
+    /// The image was presented.
+    Presented,
+    /// The image was presented but the swapchain no longer matches the
+    /// surface exactly.
+    Suboptimal,
+    /// The image was not presented because the swapchain no longer matches
+    /// the surface.
+    OutOfDate,
+}
+
+impl Present {
+    /// Returns whether the swapchain should be recreated.
+    #[inline]
+    pub fn needs_recreate(self) -> bool {
+        self != Self::Presented
+    }
+}
+
+/// A swapchain along with its images and image views which are destroyed when
+/// dropped.
+///
+/// The [`Instance`] and [`Device`] used to create the swapchain are cloned
+/// (which only increments a reference count) and stored alongside the
+/// swapchain, so the swapchain must be dropped before the device is destroyed.
+/// The surface is not owned by the swapchain and must be destroyed after the
+/// swapchain is dropped.
+///
+/// ```no_run
+/// # use vulkanalia::prelude::v1_0::*;
+/// # use vulkanalia::window::{Acquire, Swapchain, SwapchainConfig};
+/// # fn example(
+/// #     instance: &Instance,
+/// #     device: &Device,
+/// #     physical_device: vk::PhysicalDevice,
+/// #     surface: vk::SurfaceKHR,
+/// #     queue: vk::Queue,
+/// #     semaphore: vk::Semaphore,
+/// # ) -> VkResult<()> {
+/// let extent = vk::Extent2D { width: 1024, height: 768 };
+/// let config = SwapchainConfig::default();
+/// let swapchain =
+///     unsafe { Swapchain::new(instance, device, physical_device, surface, extent, config)? };
+/// let Some(mut swapchain) = swapchain else {
+///     // The window is minimized.
+///     return Ok(());
+/// };
+///
+/// match unsafe { swapchain.acquire(u64::MAX, semaphore, vk::Fence::null())? } {
+///     Acquire::Image { index, .. } => {
+///         // Render to `swapchain.image_views()[index as usize]`...
+///         let present = unsafe { swapchain.present(queue, index, &[])? };
+///         if present.needs_recreate() {
+///             unsafe { device.device_wait_idle()? };
+///             unsafe { swapchain.recreate(extent)? };
+///         }
+///     }
+///     _ => {
+///         unsafe { device.device_wait_idle()? };
+///         unsafe { swapchain.recreate(extent)? };
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Swapchain {
+    instance: Instance,
+    device: Device,
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    config: SwapchainConfig,
+    handle: vk::SwapchainKHR,
+    format: vk::SurfaceFormatKHR,
+    present_mode: vk::PresentModeKHR,
+    extent: vk::Extent2D,
+    images: Vec<vk::Image>,
+    image_views: Vec<vk::ImageView>,
+}
+
+impl Swapchain {
+    /// Creates a swapchain for a surface.
+    ///
+    /// `extent` is the extent of the window (in pixels) which is only used if
+    /// the surface does not determine the extent of the swapchain.
+    ///
+    /// Returns `None` without creating a swapchain if the extent of the window
+    /// or of the swapchain is zero (e.g., because the window is minimized).
+    ///
+    /// # Safety
+    ///
+    /// `instance` must have been created with `VK_KHR_surface` enabled,
+    /// `device` must have been created from `physical_device` with
+    /// `VK_KHR_swapchain` enabled, and `surface` must have been created with
+    /// `instance` and must not have a non-retired swapchain.
+    pub unsafe fn new(
+        instance: &Instance,
+        device: &Device,
+        physical_device: vk::PhysicalDevice,
+        surface: vk::SurfaceKHR,
+        extent: vk::Extent2D,
+        config: SwapchainConfig,
+    ) -> VkResult<Option<Self>> {
+        let mut swapchain = Self {
+            instance: instance.clone(),
+            device: device.clone(),
+            physical_device,
+            surface,
+            config,
+            handle: vk::SwapchainKHR::null(),
+            format: vk::SurfaceFormatKHR::default(),
+            present_mode: vk::PresentModeKHR::FIFO,
+            extent,
+            images: Vec::new(),
+            image_views: Vec::new(),
+        };
+
+        Ok(swapchain.create(extent)?.then_some(swapchain))
+    }
+
+    /// Gets the Vulkan handle of this swapchain.
+    #[inline]
+    pub fn handle(&self) -> vk::SwapchainKHR {
+        self.handle
+    }
+
+    /// Gets the surface of this swapchain.
+    #[inline]
+    pub fn surface(&self) -> vk::SurfaceKHR {
+        self.surface
+    }
+
+    /// Gets the configuration of this swapchain.
+    #[inline]
+    pub fn config(&self) -> &SwapchainConfig {
+        &self.config
+    }
+
+    /// Gets the format of the images of this swapchain.
+    #[inline]
+    pub fn format(&self) -> vk::SurfaceFormatKHR {
+        self.format
+    }
+
+    /// Gets the present mode of this swapchain.
+    #[inline]
+    pub fn present_mode(&self) -> vk::PresentModeKHR {
+        self.present_mode
+    }
+
+    /// Gets the extent of the images of this swapchain.
+    #[inline]
+    pub fn extent(&self) -> vk::Extent2D {
+        self.extent
+    }
+
+    /// Gets the images of this swapchain.
+    #[inline]
+    pub fn images(&self) -> &[vk::Image] {
+        &self.images
+    }
+
+    /// Gets the image views for the images of this swapchain.
+    #[inline]
+    pub fn image_views(&self) -> &[vk::ImageView] {
+        &self.image_views
+    }
+
+    /// Acquires an image from this swapchain.
+    ///
+    /// # Safety
+    ///
+    /// `semaphore` and `fence` must be null or unsignaled with no pending
+    /// operations.
+    pub unsafe fn acquire(
+        &self,
+        timeout: u64,
+        semaphore: vk::Semaphore,
+        fence: vk::Fence,
+    ) -> VkResult<Acquire> {
+        let result = self
+            .device
+            .acquire_next_image_khr(self.handle, timeout, semaphore, fence);
+
+        match result {
+            Ok((index, vk::SuccessCode::SUBOPTIMAL_KHR)) => Ok(Acquire::Image {
+                index,
+                suboptimal: true,
+            }),
+            Ok((_, vk::SuccessCode::TIMEOUT | vk::SuccessCode::NOT_READY)) => Ok(Acquire::Timeout),
+            Ok((index, _)) => Ok(Acquire::Image {
+                index,
+                suboptimal: false,
+            }),
+            Err(vk::ErrorCode::OUT_OF_DATE_KHR) => Ok(Acquire::OutOfDate),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Presents an image of this swapchain once the supplied semaphores are
+    /// signaled.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be the index of an image acquired from this swapchain
+    /// which has not been presented and `queue` must support presenting to
+    /// the surface of this swapchain.
+    pub unsafe fn present(
+        &self,
+        queue: vk::Queue,
+        index: u32,
+        wait_semaphores: &[vk::Semaphore],
+    ) -> VkResult<Present> {
+        let swapchains = &[self.handle];
+        let image_indices = &[index];
+        let info = vk::PresentInfoKHR::builder()
+            .wait_semaphores(wait_semaphores)
+            .swapchains(swapchains)
+            .image_indices(image_indices);
+
+        match self.device.queue_present_khr(queue, &info) {
+            Ok(vk::SuccessCode::SUBOPTIMAL_KHR) => Ok(Present::Suboptimal),
+            Ok(_) => Ok(Present::Presented),
+            Err(vk::ErrorCode::OUT_OF_DATE_KHR) => Ok(Present::OutOfDate),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Recreates this swapchain (e.g., after the window has been resized).
+    ///
+    /// The current swapchain is passed as the old swapchain when creating the
+    /// new swapchain and is destroyed (along with its image views) once the
+    /// new swapchain has been created. If creating the new swapchain fails,
+    /// the current swapchain is retired and can no longer be used to acquire
+    /// images.
+    ///
+    /// `extent` is the extent of the window (in pixels) which is only used if
+    /// the surface does not determine the extent of the swapchain.
+    ///
+    /// Returns `false` without recreating this swapchain if the extent of the
+    /// window or of the new swapchain is zero (e.g., because the window is
+    /// minimized).
+    /// In this case, rendering should be skipped until the extent of the
+    /// window is no longer zero and this swapchain has been recreated.
+    ///
+    /// # Safety
+    ///
+    /// The images and image views of this swapchain must not be in use (e.g.,
+    /// the device or the relevant queues must be idle).
+    pub unsafe fn recreate(&mut self, extent: vk::Extent2D) -> VkResult<bool> {
+        self.create(extent)
+    }
+
+    /// Creates a swapchain (retiring and destroying the current one, if any).
+    ///
+    /// Returns `false` without creating a swapchain if the extent of the
+    /// window or of the swapchain is zero.
+    unsafe fn create(&mut self, extent: vk::Extent2D) -> VkResult<bool> {
+        let capabilities = self
+            .instance
+            .get_physical_device_surface_capabilities_khr(self.physical_device, self.surface)?;
+        let formats = self
+            .instance
+            .get_physical_device_surface_formats_khr(self.physical_device, self.surface)?;
+        let present_modes = self
+            .instance
+            .get_physical_device_surface_present_modes_khr(self.physical_device, self.surface)?;
+
+        let format = choose_format(&self.config.formats, &formats);
+        let present_mode = choose_present_mode(&self.config.present_modes, &present_modes);
+        let window_extent = extent;
+        let extent = choose_extent(&capabilities, extent);
+
+        // Swapchains can't be created with an extent of zero (and the extent
+        // of a minimized window may be clamped to the minimum image extent).
+        let zero = |e: vk::Extent2D| e.width == 0 || e.height == 0;
+        if zero(window_extent) || zero(extent) {
+            return Ok(false);
+        }
+
+        // A max image count of 0 indicates that there is no maximum.
+        let min_image_count = self
+            .config
+            .min_image_count
+            .unwrap_or(capabilities.min_image_count + 1)
+            .max(capabilities.min_image_count);
+        let min_image_count = if capabilities.max_image_count != 0 {
+            min_image_count.min(capabilities.max_image_count)
+        } else {
+            min_image_count
+        };
+
+        let composite_alpha = choose_composite_alpha(
+            self.config.composite_alpha,
+            capabilities.supported_composite_alpha,
+        );
+
+        let mut queue_family_indices = self.config.queue_family_indices.clone();
+        queue_family_indices.sort_unstable();
+        queue_family_indices.dedup();
+        let sharing_mode = if queue_family_indices.len() > 1 {
+            vk::SharingMode::CONCURRENT
+        } else {
+            queue_family_indices.clear();
+            vk::SharingMode::EXCLUSIVE
+        };
+
+        let info = vk::SwapchainCreateInfoKHR::builder()
+            .surface(self.surface)
+            .min_image_count(min_image_count)
+            .image_format(format.format)
+            .image_color_space(format.color_space)
+            .image_extent(extent)
+            .image_array_layers(1)
+            .image_usage(self.config.image_usage)
+            .image_sharing_mode(sharing_mode)
+            .queue_family_indices(&queue_family_indices)
+            .pre_transform(capabilities.current_transform)
+            .composite_alpha(composite_alpha)
+            .present_mode(present_mode)
+            .clipped(self.config.clipped)
+            .old_swapchain(self.handle);
+
+        let handle = self.device.create_swapchain_khr(&info, None)?;
+        self.destroy();
+
+        self.handle = handle;
+        self.format = format;
+        self.present_mode = present_mode;
+        self.extent = extent;
+        self.images = self.device.get_swapchain_images_khr(handle)?;
+
+        for image in &self.images {
+            let subresource_range = vk::ImageSubresourceRange::builder()
+                .aspect_mask(vk::ImageAspectFlags::COLOR)
+                .base_mip_level(0)
+                .level_count(1)
+                .base_array_layer(0)
+                .layer_count(1);
+
+            let info = vk::ImageViewCreateInfo::builder()
+                .image(*image)
+                .view_type(vk::ImageViewType::_2D)
+                .format(format.format)
+                .subresource_range(subresource_range);
+
+            let view = self.device.create_image_view(&info, None)?;
+            self.image_views.push(view);
+        }
+
+        Ok(true)
+    }
+
+    /// Destroys the current swapchain (if any) and its image views.
+    unsafe fn destroy(&mut self) {
+        for view in self.image_views.drain(..) {
+            self.device.destroy_image_view(view, None);
+        }
+
+        self.images.clear();
+
+        if !self.handle.is_null() {
+            self.device.destroy_swapchain_khr(self.handle, None);
+            self.handle = vk::SwapchainKHR::null();
+        }
+    }
+}
+
+impl fmt::Debug for Swapchain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Swapchain")
+            .field("handle", &self.handle)
+            .field("surface", &self.surface)
+            .field("format", &self.format)
+            .field("present_mode", &self.present_mode)
+            .field("extent", &self.extent)
+            .field("images", &self.images)
+            .finish()
+    }
+}
+
+impl Drop for Swapchain {
+    fn drop(&mut self) {
+        unsafe { self.destroy() };
+    }
+}
+
+/// Chooses the first preferred surface format that is supported.
+fn choose_format(
+    preferred: &[vk::SurfaceFormatKHR],
+    supported: &[vk::SurfaceFormatKHR],
+) -> vk::SurfaceFormatKHR {
+    // Some older implementations report a single undefined format to indicate
+    // that any format can be used.
+    if let [only] = supported
+        && only.format == vk::Format::UNDEFINED
+    {
+        return preferred.first().copied().unwrap_or(*only);
+    }
+
+    preferred
+        .iter()
+        .find(|f| supported.contains(f))
+        .or_else(|| supported.first())
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Chooses the first preferred present mode that is supported.
+fn choose_present_mode(
+    preferred: &[vk::PresentModeKHR],
+    supported: &[vk::PresentModeKHR],
+) -> vk::PresentModeKHR {
+    preferred
+        .iter()
+        .find(|m| supported.contains(m))
+        .copied()
+        .unwrap_or(vk::PresentModeKHR::FIFO)
+}
+
+/// Chooses the extent of the swapchain images.
+fn choose_extent(capabilities: &vk::SurfaceCapabilitiesKHR, extent: vk::Extent2D) -> vk::Extent2D {
+    // A current extent of `u32::MAX` indicates that the extent of the surface
+    // is determined by the extent of the swapchain.
+    if capabilities.current_extent.width != u32::MAX {
+        return capabilities.current_extent;
+    }
+
+    let min = capabilities.min_image_extent;
+    let max = capabilities.max_image_extent;
+    vk::Extent2D {
+        width: extent.width.clamp(min.width, max.width.max(min.width)),
+        height: extent.height.clamp(min.height, max.height.max(min.height)),
+    }
+}
+
+/// Chooses the preferred alpha compositing mode if it is supported or else
+/// the first supported alpha compositing mode.
+fn choose_composite_alpha(
+    preferred: vk::CompositeAlphaFlagsKHR,
+    supported: vk::CompositeAlphaFlagsKHR,
+) -> vk::CompositeAlphaFlagsKHR {
+    if supported.is_empty() || supported.contains(preferred) {
+        preferred
+    } else {
+        let bits = supported.bits();
+        vk::CompositeAlphaFlagsKHR::from_bits_truncate(bits & bits.wrapping_neg())
+    }
+}
@@ -0,0 +1,33 @@
+extern crate vulkanalia;
+
+use std::ffi::CStr;
+
+use vulkanalia::vk::{self, DeviceCommands};
+
+unsafe fn load_enabled(version: u32, extensions: &[&CStr]) -> Vec<String> {
+    let mut names = vec![];
+    let loader = |n| {
+        names.push(unsafe { CStr::from_ptr(n) }.to_str().unwrap().to_string());
+        None
+    };
+
+    unsafe { DeviceCommands::load_enabled(loader, version, |e| extensions.contains(&e)) };
+    names
+}
+
+#[test]
+fn test_load_enabled() {
+    let names = unsafe { load_enabled(vk::make_version(1, 2, 0), &[]) };
+    assert!(names.iter().any(|n| n == "vkCreateBuffer"));
+    assert!(names.iter().any(|n| n == "vkCreateRenderPass2"));
+    assert!(!names.iter().any(|n| n == "vkCmdBeginRendering"));
+    assert!(!names.iter().any(|n| n == "vkCmdBeginRenderingKHR"));
+    assert!(!names.iter().any(|n| n == "vkCreateSwapchainKHR"));
+
+    let extensions = &[c"VK_KHR_dynamic_rendering", c"VK_KHR_swapchain"];
+    let names = unsafe { load_enabled(vk::make_version(1, 3, 0), extensions) };
+    assert!(names.iter().any(|n| n == "vkCmdBeginRendering"));
+    assert!(names.iter().any(|n| n == "vkCmdBeginRenderingKHR"));
+    assert!(names.iter().any(|n| n == "vkCreateSwapchainKHR"));
+    assert!(!names.iter().any(|n| n == "vkCmdDrawMeshTasksEXT"));
+}
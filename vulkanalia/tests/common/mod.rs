@@ -0,0 +1,71 @@
+//! Fixtures shared by the tests which use the mock Vulkan driver.
+
+#![allow(dead_code)]
+
+use vulkanalia::loader::{MockConfig, MockDriver, MockLoader, MockPhysicalDevice};
+use vulkanalia::prelude::v1_0::*;
+
+/// Gets the configuration of a mock Vulkan driver with a physical device that
+/// supports timeline semaphores.
+pub fn timeline_semaphore_config() -> MockConfig {
+    let physical_device = MockPhysicalDevice {
+        vulkan12_features: vk::PhysicalDeviceVulkan12Features {
+            timeline_semaphore: vk::TRUE,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    MockConfig {
+        physical_devices: vec![physical_device],
+        ..Default::default()
+    }
+}
+
+/// Creates an instance with a mock Vulkan driver which requests the version
+/// reported by the driver and enables the supplied instance extensions.
+pub fn create_instance(
+    config: MockConfig,
+    extensions: &[&vk::ExtensionName],
+) -> (MockDriver, Entry, Instance) {
+    let version = config.version;
+    let loader = MockLoader::new(config);
+    let driver = loader.driver();
+    let entry = unsafe { Entry::new(loader) }.unwrap();
+
+    let application_info = vk::ApplicationInfo::builder().api_version(version.into());
+    let extensions = extensions.iter().map(|e| e.as_ptr()).collect::<Vec<_>>();
+    let info = vk::InstanceCreateInfo::builder()
+        .application_info(&application_info)
+        .enabled_extension_names(&extensions);
+    let instance = unsafe { entry.create_instance(&info, None) }.unwrap();
+
+    (driver, entry, instance)
+}
+
+/// Creates a device from the first physical device of an instance with a queue
+/// from the first queue family which enables the supplied device extensions
+/// (and Vulkan 1.2 features, if any).
+pub fn create_device(
+    instance: &Instance,
+    extensions: &[&vk::ExtensionName],
+    vulkan12_features: Option<&mut vk::PhysicalDeviceVulkan12Features>,
+) -> (vk::PhysicalDevice, Device) {
+    let physical_device = unsafe { instance.enumerate_physical_devices() }.unwrap()[0];
+
+    let priorities = &[1.0];
+    let queue_infos = &[vk::DeviceQueueCreateInfo::builder()
+        .queue_family_index(0)
+        .queue_priorities(priorities)];
+    let extensions = extensions.iter().map(|e| e.as_ptr()).collect::<Vec<_>>();
+    let mut info = vk::DeviceCreateInfo::builder()
+        .queue_create_infos(queue_infos)
+        .enabled_extension_names(&extensions);
+    if let Some(vulkan12_features) = vulkan12_features {
+        info = info.push_next(vulkan12_features);
+    }
+
+    let device = unsafe { instance.create_device(physical_device, &info, None) }.unwrap();
+
+    (physical_device, device)
+}
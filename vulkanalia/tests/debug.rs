@@ -0,0 +1,288 @@
+extern crate vulkanalia;
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use vulkanalia::debug::{DebugMessenger, Label, Message, Object};
+use vulkanalia::loader::{MockConfig, MockDriver, MockPhysicalDevice};
+use vulkanalia::prelude::v1_0::*;
+use vulkanalia::vk::ExtDebugUtilsExtensionInstanceCommands;
+
+fn create_instance() -> (MockDriver, Entry, Instance) {
+    let config = MockConfig::default().with_extensions(&[vk::EXT_DEBUG_UTILS_EXTENSION]);
+    common::create_instance(config, &[&vk::EXT_DEBUG_UTILS_EXTENSION.name])
+}
+
+fn create_messenger(
+    instance: &Instance,
+    severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+) -> (DebugMessenger, Arc<Mutex<Vec<Message>>>) {
+    let messages = Arc::new(Mutex::new(vec![]));
+    let callback = {
+        let messages = messages.clone();
+        move |m: &Message| messages.lock().unwrap().push(m.clone())
+    };
+
+    let types = vk::DebugUtilsMessageTypeFlagsEXT::all();
+    let messenger = unsafe { DebugMessenger::new(instance, severity, types, callback) }.unwrap();
+    (messenger, messages)
+}
+
+#[test]
+fn test_debug_messenger() {
+    let (driver, _, instance) = create_instance();
+
+    let (messenger, messages) =
+        create_messenger(&instance, vk::DebugUtilsMessageSeverityFlagsEXT::all());
+    let object = (
+        vk::ObjectType::DEBUG_UTILS_MESSENGER_EXT,
+        messenger.handle().as_raw(),
+    );
+    assert!(driver.live_objects().contains(&object));
+
+    let queue_labels = &[vk::DebugUtilsLabelEXT::builder()
+        .label_name(b"frame\0")
+        .color([1.0, 0.0, 0.0, 1.0])];
+    let objects = &[
+        vk::DebugUtilsObjectNameInfoEXT::builder()
+            .object_type(vk::ObjectType::BUFFER)
+            .object_handle(42)
+            .object_name(b"vertices\0"),
+        vk::DebugUtilsObjectNameInfoEXT::builder()
+            .object_type(vk::ObjectType::IMAGE)
+            .object_handle(64),
+    ];
+
+    let data = vk::DebugUtilsMessengerCallbackDataEXT::builder()
+        .message_id_name(b"VUID-test\0")
+        .message_id_number(7)
+        .message(b"something happened\0")
+        .queue_labels(queue_labels)
+        .objects(objects);
+
+    unsafe {
+        instance.submit_debug_utils_message_ext(
+            vk::DebugUtilsMessageSeverityFlagsEXT::WARNING,
+            vk::DebugUtilsMessageTypeFlagsEXT::VALIDATION,
+            &data,
+        )
+    };
+
+    assert_eq!(
+        messages.lock().unwrap().as_slice(),
+        &[Message {
+            severity: vk::DebugUtilsMessageSeverityFlagsEXT::WARNING,
+            types: vk::DebugUtilsMessageTypeFlagsEXT::VALIDATION,
+            id_name: Some("VUID-test".into()),
+            id_number: 7,
+            message: "something happened".into(),
+            queue_labels: vec![Label {
+                name: "frame".into(),
+                color: [1.0, 0.0, 0.0, 1.0],
+            }],
+            cmd_buf_labels: vec![],
+            objects: vec![
+                Object {
+                    object_type: vk::ObjectType::BUFFER,
+                    handle: 42,
+                    name: Some("vertices".into()),
+                },
+                Object {
+                    object_type: vk::ObjectType::IMAGE,
+                    handle: 64,
+                    name: None,
+                },
+            ],
+        }]
+    );
+
+    // Debug messengers are destroyed when dropped.
+
+    drop(messenger);
+    assert!(!driver.live_objects().contains(&object));
+    assert_eq!(driver.calls_to("vkDestroyDebugUtilsMessengerEXT").len(), 1);
+
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_debug_messenger_severity() {
+    let (_, _, instance) = create_instance();
+
+    let severity = vk::DebugUtilsMessageSeverityFlagsEXT::ERROR;
+    let (messenger, messages) = create_messenger(&instance, severity);
+
+    let submit = |severity, message: &[u8]| {
+        let data = vk::DebugUtilsMessengerCallbackDataEXT::builder().message(message);
+        let types = vk::DebugUtilsMessageTypeFlagsEXT::GENERAL;
+        unsafe { instance.submit_debug_utils_message_ext(severity, types, &data) };
+    };
+
+    submit(vk::DebugUtilsMessageSeverityFlagsEXT::INFO, b"info\0");
+    submit(vk::DebugUtilsMessageSeverityFlagsEXT::ERROR, b"error\0");
+
+    let messages = messages.lock().unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].message, "error");
+    assert_eq!(messages[0].id_name, None);
+
+    drop(messenger);
+    unsafe { instance.destroy_instance(None) };
+}
+
+fn create_device(
+    instance_extensions: &[vk::Extension],
+    device_extensions: &[vk::Extension],
+) -> (MockDriver, Instance, Device) {
+    let config = MockConfig::default().with_extensions(instance_extensions);
+    create_device_with(config, instance_extensions, device_extensions)
+}
+
+fn create_device_with(
+    config: MockConfig,
+    instance_extensions: &[vk::Extension],
+    device_extensions: &[vk::Extension],
+) -> (MockDriver, Instance, Device) {
+    let config = MockConfig {
+        physical_devices: vec![MockPhysicalDevice::default().with_extensions(device_extensions)],
+        ..config
+    };
+
+    let extensions = instance_extensions
+        .iter()
+        .map(|e| &e.name)
+        .collect::<Vec<_>>();
+    let (driver, _, instance) = common::create_instance(config, &extensions);
+    let extensions = device_extensions
+        .iter()
+        .map(|e| &e.name)
+        .collect::<Vec<_>>();
+    let (_, device) = common::create_device(&instance, &extensions, None);
+
+    (driver, instance, device)
+}
+
+fn create_buffer(device: &Device) -> vk::Buffer {
+    let info = vk::BufferCreateInfo::builder()
+        .size(1024)
+        .usage(vk::BufferUsageFlags::VERTEX_BUFFER);
+    unsafe { device.create_buffer(&info, None) }.unwrap()
+}
+
+#[test]
+fn test_object_names_debug_utils() {
+    let extensions = &[vk::EXT_DEBUG_UTILS_EXTENSION];
+    let (driver, instance, device) = create_device(extensions, &[vk::EXT_DEBUG_MARKER_EXTENSION]);
+
+    // `VK_EXT_debug_utils` is an instance extension (not a device extension).
+    assert!(!device.is_command_loaded("vkSetDebugUtilsObjectNameEXT"));
+
+    let buffer = create_buffer(&device);
+    unsafe { device.set_object_name(buffer, "vertices") }.unwrap();
+    unsafe { device.set_object_name(device.handle(), "main\0device") }.unwrap();
+    unsafe { device.set_object_tag(buffer, 7, b"tag") }.unwrap();
+
+    assert_eq!(driver.object_name(buffer.as_raw()), Some("vertices".into()));
+    assert_eq!(
+        driver.object_name(device.handle().as_raw() as u64),
+        Some("main".into())
+    );
+
+    let calls = driver.calls_to("vkSetDebugUtilsObjectNameEXT");
+    assert_eq!(calls.len(), 2);
+    assert_eq!(
+        calls[0].args,
+        &[
+            device.handle().as_raw() as u64,
+            vk::ObjectType::BUFFER.as_raw() as u64,
+            buffer.as_raw(),
+        ]
+    );
+
+    let calls = driver.calls_to("vkSetDebugUtilsObjectTagEXT");
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].args[3], 7);
+
+    assert!(driver.calls_to("vkDebugMarkerSetObjectNameEXT").is_empty());
+
+    unsafe { device.destroy_buffer(buffer, None) };
+    assert_eq!(driver.object_name(buffer.as_raw()), None);
+
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_object_names_debug_marker() {
+    let (driver, instance, device) = create_device(&[], &[vk::EXT_DEBUG_MARKER_EXTENSION]);
+    assert!(!device.is_command_loaded("vkSetDebugUtilsObjectNameEXT"));
+
+    let buffer = create_buffer(&device);
+    let surface = vk::SurfaceKHR::from_raw(1234);
+    unsafe { device.set_object_name(buffer, "vertices") }.unwrap();
+    unsafe { device.set_object_name(surface, "window") }.unwrap();
+    unsafe { device.set_object_tag(buffer, 7, b"tag") }.unwrap();
+
+    assert_eq!(driver.object_name(buffer.as_raw()), Some("vertices".into()));
+    assert_eq!(driver.object_name(surface.as_raw()), Some("window".into()));
+
+    let calls = driver.calls_to("vkDebugMarkerSetObjectNameEXT");
+    assert_eq!(calls.len(), 2);
+    assert_eq!(
+        calls[0].args[1],
+        vk::DebugReportObjectTypeEXT::BUFFER.as_raw() as u64
+    );
+    assert_eq!(
+        calls[1].args[1],
+        vk::DebugReportObjectTypeEXT::SURFACE_KHR.as_raw() as u64
+    );
+    assert_eq!(driver.calls_to("vkDebugMarkerSetObjectTagEXT").len(), 1);
+
+    unsafe { device.destroy_buffer(buffer, None) };
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_object_names_unavailable() {
+    let (driver, instance, device) = create_device(&[], &[]);
+
+    let buffer = create_buffer(&device);
+    unsafe { device.set_object_name(buffer, "vertices") }.unwrap();
+    unsafe { device.set_object_tag(buffer, 7, b"tag") }.unwrap();
+
+    assert_eq!(driver.object_name(buffer.as_raw()), None);
+    assert!(driver.calls_to("vkSetDebugUtilsObjectNameEXT").is_empty());
+    assert!(driver.calls_to("vkDebugMarkerSetObjectNameEXT").is_empty());
+
+    unsafe { device.destroy_buffer(buffer, None) };
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_object_names_debug_utils_not_enabled() {
+    // Some drivers return the `VK_EXT_debug_utils` device commands even if the
+    // extension was not enabled for the instance.
+    let config = MockConfig {
+        unfiltered_device_commands: true,
+        ..MockConfig::default().with_extensions(&[vk::EXT_DEBUG_UTILS_EXTENSION])
+    };
+    let (driver, instance, device) =
+        create_device_with(config, &[], &[vk::EXT_DEBUG_MARKER_EXTENSION]);
+
+    let buffer = create_buffer(&device);
+    unsafe { device.set_object_name(buffer, "vertices") }.unwrap();
+    unsafe { device.set_object_tag(buffer, 7, b"tag") }.unwrap();
+
+    assert_eq!(driver.object_name(buffer.as_raw()), Some("vertices".into()));
+    assert!(driver.calls_to("vkSetDebugUtilsObjectNameEXT").is_empty());
+    assert!(driver.calls_to("vkSetDebugUtilsObjectTagEXT").is_empty());
+    assert_eq!(driver.calls_to("vkDebugMarkerSetObjectNameEXT").len(), 1);
+    assert_eq!(driver.calls_to("vkDebugMarkerSetObjectTagEXT").len(), 1);
+
+    unsafe { device.destroy_buffer(buffer, None) };
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
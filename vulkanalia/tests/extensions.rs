@@ -0,0 +1,94 @@
+extern crate vulkanalia;
+
+use vulkanalia::vk;
+
+#[test]
+fn test_extension_by_name() {
+    let swapchain = vk::Extension::by_name(&vk::KHR_SWAPCHAIN_EXTENSION.name).unwrap();
+    assert_eq!(swapchain.name, vk::KHR_SWAPCHAIN_EXTENSION.name);
+    assert_eq!(swapchain.number, 2);
+    assert_eq!(swapchain.type_, "device");
+    assert_eq!(swapchain.author, "KHR");
+
+    let win32 = vk::Extension::by_name(&vk::KHR_WIN32_SURFACE_EXTENSION.name).unwrap();
+    assert_eq!(win32.type_, "instance");
+    assert_eq!(win32.platform, Some("win32"));
+
+    let unknown = vk::ExtensionName::from_bytes(b"VK_FOO_unknown");
+    assert!(vk::Extension::by_name(&unknown).is_none());
+}
+
+#[test]
+fn test_extension_by_number() {
+    let surface = vk::Extension::by_number(1).unwrap();
+    assert_eq!(surface.name, vk::KHR_SURFACE_EXTENSION.name);
+
+    let number = vk::KHR_MAINTENANCE1_EXTENSION.number;
+    let maintenance1 = vk::Extension::by_number(number).unwrap();
+    assert_eq!(maintenance1.name, vk::KHR_MAINTENANCE1_EXTENSION.name);
+    assert_eq!(maintenance1.promoted_to, Some("VK_VERSION_1_1"));
+
+    assert!(vk::Extension::by_number(-1).is_none());
+}
+
+#[test]
+fn test_extensions() {
+    assert!(!vk::EXTENSIONS.is_empty());
+    assert!(vk::EXTENSIONS.windows(2).all(|w| w[0].name < w[1].name));
+    for extension in vk::EXTENSIONS {
+        let found = vk::Extension::by_name(&extension.name).unwrap();
+        assert_eq!(found.number, extension.number);
+    }
+}
+
+#[test]
+fn test_extension_commands() {
+    let commands = vk::KHR_DYNAMIC_RENDERING_EXTENSION.commands;
+    assert_eq!(
+        commands,
+        &["vkCmdBeginRenderingKHR", "vkCmdEndRenderingKHR"]
+    );
+    assert!(
+        vk::KHR_SURFACE_EXTENSION
+            .commands
+            .contains(&"vkDestroySurfaceKHR")
+    );
+    assert!(vk::KHR_SHADER_DRAW_PARAMETERS_EXTENSION.commands.is_empty());
+}
+
+#[test]
+fn test_command_metadata() {
+    let khr = vk::CommandMetadata::by_name("vkCmdBeginRenderingKHR").unwrap();
+    assert_eq!(khr.alias, Some("vkCmdBeginRendering"));
+    assert_eq!(khr.type_, "device");
+    assert_eq!(khr.version, None);
+    assert_eq!(khr.extensions, &[vk::KHR_DYNAMIC_RENDERING_EXTENSION.name]);
+
+    let core = vk::CommandMetadata::by_name(khr.alias.unwrap()).unwrap();
+    assert_eq!(core.alias, None);
+    assert_eq!(core.version, Some("1.3"));
+    assert!(core.extensions.is_empty());
+
+    let create = vk::CommandMetadata::by_name("vkCreateInstance").unwrap();
+    assert_eq!(create.type_, "entry");
+    assert_eq!(create.version, Some("1.0"));
+
+    let static_ = vk::CommandMetadata::by_name("vkGetInstanceProcAddr").unwrap();
+    assert_eq!(static_.type_, "static");
+
+    assert!(vk::CommandMetadata::by_name("vkFoo").is_none());
+}
+
+#[test]
+fn test_commands() {
+    for extension in vk::EXTENSIONS {
+        for name in extension.commands {
+            let command = vk::CommandMetadata::by_name(name).unwrap();
+            assert!(command.extensions.contains(&extension.name));
+        }
+    }
+
+    for command in vk::COMMANDS {
+        assert!(command.version.is_some() || !command.extensions.is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+extern crate vulkanalia;
+
+use vulkanalia::Version;
+use vulkanalia::features::{Feature, FeatureSet, Features};
+use vulkanalia::vk::{self, HasBuilder};
+
+#[test]
+fn test_features() {
+    let features = vk::PhysicalDeviceVulkan13Features {
+        robust_image_access: vk::TRUE,
+        maintenance4: vk::TRUE,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        <vk::PhysicalDeviceVulkan13Features as Features>::NAME,
+        "VkPhysicalDeviceVulkan13Features"
+    );
+    assert_eq!(
+        features.flags().len(),
+        vk::PhysicalDeviceVulkan13Features::NAMES.len()
+    );
+    assert_eq!(features.names(), &["robust_image_access", "maintenance4"]);
+
+    let other = vk::PhysicalDeviceVulkan13Features {
+        maintenance4: vk::TRUE,
+        dynamic_rendering: vk::TRUE,
+        ..Default::default()
+    };
+
+    assert_eq!(features.intersection(&other).names(), &["maintenance4"]);
+    assert_eq!(
+        features.union(&other).names(),
+        &["robust_image_access", "dynamic_rendering", "maintenance4"]
+    );
+    assert_eq!(
+        features.difference(&other).names(),
+        &["robust_image_access"]
+    );
+    assert!(features.union(&other).is_superset(&features));
+    assert!(!features.is_superset(&other));
+    assert!(features.difference(&features).is_empty());
+
+    let mut core = vk::PhysicalDeviceFeatures::default();
+    core.flags_mut()[0] = vk::TRUE;
+    assert_eq!(core.robust_buffer_access, vk::TRUE);
+    assert_eq!(core.names(), &["robust_buffer_access"]);
+}
+
+#[test]
+fn test_feature_set() {
+    let mut set = FeatureSet::core(Version::V1_2_0).with(vk::PhysicalDeviceMeshShaderFeaturesEXT {
+        mesh_shader: vk::TRUE,
+        ..Default::default()
+    });
+
+    assert!(set.get::<vk::PhysicalDeviceVulkan11Features>().is_some());
+    assert!(set.get::<vk::PhysicalDeviceVulkan13Features>().is_none());
+
+    set.features_mut().geometry_shader = vk::TRUE;
+    set.get_mut::<vk::PhysicalDeviceVulkan12Features>()
+        .unwrap()
+        .timeline_semaphore = vk::TRUE;
+
+    let feature = |structure, name| Feature { structure, name };
+    assert_eq!(
+        set.enabled(),
+        &[
+            feature("VkPhysicalDeviceFeatures", "geometry_shader"),
+            feature("VkPhysicalDeviceVulkan12Features", "timeline_semaphore"),
+            feature("VkPhysicalDeviceMeshShaderFeaturesEXT", "mesh_shader"),
+        ]
+    );
+
+    // Set operations.
+
+    let mut available = FeatureSet::core(Version::V1_3_0);
+    available.features_mut().geometry_shader = vk::TRUE;
+    available
+        .get_mut::<vk::PhysicalDeviceVulkan13Features>()
+        .unwrap()
+        .synchronization2 = vk::TRUE;
+
+    assert!(!available.is_superset(&set));
+    assert!(set.is_superset(&set.intersection(&available)));
+    assert_eq!(
+        set.missing(&available),
+        &[
+            feature("VkPhysicalDeviceVulkan12Features", "timeline_semaphore"),
+            feature("VkPhysicalDeviceMeshShaderFeaturesEXT", "mesh_shader"),
+        ]
+    );
+    assert_eq!(
+        set.intersection(&available).enabled(),
+        &[feature("VkPhysicalDeviceFeatures", "geometry_shader")]
+    );
+
+    let union = set.union(&available);
+    assert!(union.is_superset(&set));
+    assert!(union.is_superset(&available));
+    assert_eq!(union.enabled().len(), 4);
+
+    // Pointer chain.
+
+    let chain = set.chain();
+    let mut types = vec![];
+    let mut next = chain.next.cast::<vk::BaseOutStructure>();
+    while !next.is_null() {
+        types.push(unsafe { (*next).s_type });
+        next = unsafe { (*next).next };
+    }
+
+    assert_eq!(
+        types,
+        &[
+            vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES,
+            vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES,
+            vk::StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT,
+        ]
+    );
+
+    let mut clone = set.clone();
+    assert_ne!(clone.chain().next, set.chain().next);
+
+    let info = vk::DeviceCreateInfo::builder().push_next(set.chain());
+    assert!(!info.next.is_null());
+}
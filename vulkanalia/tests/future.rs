@@ -0,0 +1,181 @@
+extern crate vulkanalia;
+
+mod common;
+
+use std::future::Future;
+use std::pin::{Pin, pin};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+use vulkanalia::future::Waiter;
+use vulkanalia::loader::MockDriver;
+use vulkanalia::prelude::v1_2::*;
+
+struct Unpark(Thread);
+
+impl Wake for Unpark {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Polls a future on the current thread until it completes.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Polls a future once.
+fn poll_once<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+    let mut context = Context::from_waker(Waker::noop());
+    Pin::new(future).poll(&mut context)
+}
+
+fn create_device() -> (MockDriver, Instance, Device) {
+    create_device_with(true)
+}
+
+fn create_device_with(timeline_semaphore: bool) -> (MockDriver, Instance, Device) {
+    let config = common::timeline_semaphore_config();
+    let (driver, _, instance) = common::create_instance(config, &[]);
+    let mut vulkan12_features =
+        vk::PhysicalDeviceVulkan12Features::builder().timeline_semaphore(timeline_semaphore);
+    let (_, device) = common::create_device(&instance, &[], Some(&mut vulkan12_features));
+
+    (driver, instance, device)
+}
+
+fn create_fence(device: &Device) -> vk::Fence {
+    let info = vk::FenceCreateInfo::builder();
+    unsafe { device.create_fence(&info, None) }.unwrap()
+}
+
+fn create_timeline_semaphore(device: &Device) -> vk::Semaphore {
+    let mut type_info = vk::SemaphoreTypeCreateInfo::builder()
+        .semaphore_type(vk::SemaphoreType::TIMELINE)
+        .initial_value(0);
+    let info = vk::SemaphoreCreateInfo::builder().push_next(&mut type_info);
+    unsafe { device.create_semaphore(&info, None) }.unwrap()
+}
+
+/// Waits until the mock driver has recorded a call matching a predicate.
+fn wait_for_call(driver: &MockDriver, command: &str, predicate: impl Fn(&[u64]) -> bool) {
+    let start = Instant::now();
+    while !driver.calls_to(command).iter().any(|c| predicate(&c.args)) {
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "{command} not called"
+        );
+        thread::yield_now();
+    }
+}
+
+#[test]
+fn test_wait_fences() {
+    let (driver, instance, device) = create_device();
+    let queue = unsafe { device.get_device_queue(0, 0) };
+    let waiter = unsafe { Waiter::new(&device) };
+
+    let fences = [create_fence(&device), create_fence(&device)];
+    let mut first = unsafe { waiter.wait_fence(fences[0]) };
+    let mut second = unsafe { waiter.wait_fence(fences[1]) };
+
+    // Both fences are waited on at once.
+    let raw = fences.map(|f| f.as_raw());
+    wait_for_call(&driver, "vkWaitForFences", |a| a[1..3] == raw);
+
+    assert!(poll_once(&mut first).is_pending());
+    assert!(poll_once(&mut second).is_pending());
+
+    unsafe { device.queue_submit(queue, &[] as &[vk::SubmitInfo], fences[1]) }.unwrap();
+    assert_eq!(block_on(&mut second), Ok(()));
+    assert!(poll_once(&mut first).is_pending());
+
+    unsafe { device.queue_submit(queue, &[] as &[vk::SubmitInfo], fences[0]) }.unwrap();
+    assert_eq!(block_on(first), Ok(()));
+
+    drop(waiter);
+    for fence in fences {
+        unsafe { device.destroy_fence(fence, None) };
+    }
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_wait_semaphores() {
+    let (_, instance, device) = create_device();
+    let waiter = unsafe { Waiter::new(&device) };
+
+    let semaphore = create_timeline_semaphore(&device);
+    let mut future = unsafe { waiter.wait_semaphore(semaphore, 2) };
+
+    let info = vk::SemaphoreSignalInfo::builder()
+        .semaphore(semaphore)
+        .value(1);
+    unsafe { device.signal_semaphore(&info) }.unwrap();
+    assert!(poll_once(&mut future).is_pending());
+
+    let info = vk::SemaphoreSignalInfo::builder()
+        .semaphore(semaphore)
+        .value(2);
+    unsafe { device.signal_semaphore(&info) }.unwrap();
+    assert_eq!(block_on(future), Ok(()));
+
+    // Values which have already been reached complete immediately.
+    assert_eq!(
+        block_on(unsafe { waiter.wait_semaphore(semaphore, 1) }),
+        Ok(())
+    );
+
+    drop(waiter);
+    unsafe { device.destroy_semaphore(semaphore, None) };
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_wait_semaphores_unsupported() {
+    let (driver, instance, device) = create_device_with(false);
+    let waiter = unsafe { Waiter::new(&device) };
+
+    // Timeline semaphores can't be waited on without the `timelineSemaphore` feature.
+    let future = unsafe { waiter.wait_semaphore(vk::Semaphore::null(), 1) };
+    assert_eq!(block_on(future), Err(vk::ErrorCode::FEATURE_NOT_PRESENT));
+    assert!(driver.calls_to("vkWaitSemaphores").is_empty());
+
+    drop(waiter);
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_wait_errors() {
+    let (driver, instance, device) = create_device();
+    let waiter = unsafe { Waiter::new(&device) };
+
+    // Waits fail when waiting fails.
+    let fence = create_fence(&device);
+    driver.set_result("vkWaitForFences", vk::ErrorCode::DEVICE_LOST);
+    let future = unsafe { waiter.wait_fence(fence) };
+    assert_eq!(block_on(future), Err(vk::ErrorCode::DEVICE_LOST));
+    driver.clear_results();
+
+    // Pending waits fail when the waiter is dropped.
+    let future = unsafe { waiter.wait_fence(fence) };
+    drop(waiter);
+    assert_eq!(block_on(future), Err(vk::ErrorCode::UNKNOWN));
+
+    unsafe { device.destroy_fence(fence, None) };
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
@@ -0,0 +1,829 @@
+extern crate vulkanalia;
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use vulkanalia::features::{Feature, FeatureError, FeatureSet};
+use vulkanalia::hook::{CommandHook, MAX_HOOKED};
+use vulkanalia::instance::InstanceError;
+use vulkanalia::loader::{MockConfig, MockDriver, MockLoader, MockPhysicalDevice};
+use vulkanalia::owned::{Owned, OwnedDevice, OwnedInstance};
+use vulkanalia::physical_device::{Rejection, SelectError};
+use vulkanalia::prelude::v1_0::*;
+use vulkanalia::{DeviceQueueFamily, Version};
+
+#[test]
+fn test_mock_instance() {
+    let mut physical_device = MockPhysicalDevice::default();
+    physical_device.properties.device_type = vk::PhysicalDeviceType::INTEGRATED_GPU;
+
+    let config = MockConfig {
+        version: Version::V1_2_0,
+        physical_devices: vec![MockPhysicalDevice::default(), physical_device],
+        ..Default::default()
+    };
+
+    let (driver, entry, instance) = common::create_instance(config, &[]);
+    assert_eq!(entry.version().unwrap(), Version::V1_2_0);
+
+    let physical_devices = unsafe { instance.enumerate_physical_devices() }.unwrap();
+    assert_eq!(physical_devices.len(), 2);
+
+    let properties = unsafe { instance.get_physical_device_properties(physical_devices[1]) };
+    assert_eq!(
+        properties.device_type,
+        vk::PhysicalDeviceType::INTEGRATED_GPU
+    );
+    assert_eq!(
+        properties.device_name.to_string_lossy(),
+        "vulkanalia mock device"
+    );
+
+    unsafe { instance.destroy_instance(None) };
+    assert!(driver.live_objects().is_empty());
+}
+
+#[test]
+fn test_mock_extensions() {
+    let config = MockConfig::default().with_extensions(&[vk::KHR_SURFACE_EXTENSION]);
+    let loader = MockLoader::new(config);
+    let entry = unsafe { Entry::new(loader) }.unwrap();
+
+    let extensions = unsafe { entry.enumerate_instance_extension_properties(None) }.unwrap();
+    assert_eq!(extensions.len(), 1);
+    assert_eq!(extensions[0].extension_name, vk::KHR_SURFACE_EXTENSION.name);
+
+    let names = &[vk::KHR_SWAPCHAIN_EXTENSION.name.as_ptr()];
+    let info = vk::InstanceCreateInfo::builder().enabled_extension_names(names);
+    let error = unsafe { entry.create_instance(&info, None) }.unwrap_err();
+    assert_eq!(error, vk::ErrorCode::EXTENSION_NOT_PRESENT);
+}
+
+#[test]
+fn test_mock_device() {
+    let (driver, _, instance) = common::create_instance(MockConfig::default(), &[]);
+    let (_, device) = common::create_device(&instance, &[], None);
+
+    let info = vk::BufferCreateInfo::builder()
+        .size(1000)
+        .usage(vk::BufferUsageFlags::VERTEX_BUFFER);
+    let buffer = unsafe { device.create_buffer(&info, None) }.unwrap();
+
+    let requirements = unsafe { device.get_buffer_memory_requirements(buffer) };
+    assert_eq!(requirements.size, 1024);
+
+    let calls = driver.calls_to("vkCreateBuffer");
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].args, vec![device.handle().as_raw() as u64, 1000]);
+
+    unsafe { device.destroy_buffer(buffer, None) };
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+    assert!(driver.live_objects().is_empty());
+}
+
+#[test]
+fn test_mock_results() {
+    let (driver, _, instance) = common::create_instance(MockConfig::default(), &[]);
+    let (_, device) = common::create_device(&instance, &[], None);
+
+    let queue = unsafe { device.get_device_queue(0, 0) };
+    let fence = unsafe { device.create_fence(&vk::FenceCreateInfo::default(), None) }.unwrap();
+    assert_eq!(
+        unsafe { device.get_fence_status(fence) },
+        Ok(vk::SuccessCode::NOT_READY)
+    );
+
+    driver.queue_result("vkQueueSubmit", vk::ErrorCode::DEVICE_LOST);
+    let error = unsafe { device.queue_submit(queue, &[] as &[vk::SubmitInfo], fence) }.unwrap_err();
+    assert_eq!(error, vk::ErrorCode::DEVICE_LOST);
+    assert_eq!(
+        unsafe { device.get_fence_status(fence) },
+        Ok(vk::SuccessCode::NOT_READY)
+    );
+
+    unsafe { device.queue_submit(queue, &[] as &[vk::SubmitInfo], fence) }.unwrap();
+    assert_eq!(
+        unsafe { device.get_fence_status(fence) },
+        Ok(vk::SuccessCode::SUCCESS)
+    );
+    assert_eq!(driver.calls_to("vkQueueSubmit").len(), 2);
+}
+
+#[test]
+fn test_mock_incomplete() {
+    let (driver, _, instance) = common::create_instance(MockConfig::default(), &[]);
+    let expected = unsafe { instance.enumerate_physical_devices() }.unwrap();
+    driver.clear_calls();
+
+    // The count is not written, so the second call is also incomplete.
+    driver.queue_result("vkEnumeratePhysicalDevices", vk::SuccessCode::INCOMPLETE);
+    let physical_devices = unsafe { instance.enumerate_physical_devices() }.unwrap();
+    assert_eq!(physical_devices, expected);
+    assert_eq!(driver.calls_to("vkEnumeratePhysicalDevices").len(), 4);
+}
+
+#[test]
+fn test_mock_into() {
+    let (_, _, instance) = common::create_instance(MockConfig::default(), &[]);
+    let expected = unsafe { instance.enumerate_physical_devices() }.unwrap();
+
+    let mut physical_devices = Vec::with_capacity(16);
+    physical_devices.push(vk::PhysicalDevice::null());
+    let pointer = physical_devices.as_ptr();
+    unsafe { instance.enumerate_physical_devices_into(&mut physical_devices) }.unwrap();
+    assert_eq!(physical_devices, expected);
+    assert_eq!(physical_devices.as_ptr(), pointer);
+
+    let physical_device = expected[0];
+    let expected = unsafe { instance.get_physical_device_queue_family_properties(physical_device) };
+    let mut properties = Vec::new();
+    unsafe {
+        instance.get_physical_device_queue_family_properties_into(physical_device, &mut properties)
+    };
+    assert_eq!(properties, expected);
+}
+
+#[test]
+fn test_mock_owned() {
+    let (driver, _, instance) = common::create_instance(MockConfig::default(), &[]);
+    let (_, device) = common::create_device(&instance, &[], None);
+    let info = vk::FenceCreateInfo::default();
+    let fences = || {
+        let objects = driver.live_objects().into_iter();
+        objects
+            .filter(|(t, _)| *t == vk::ObjectType::FENCE)
+            .collect::<Vec<_>>()
+    };
+
+    let fence = unsafe { Owned::new(&device, device.create_fence(&info, None).unwrap()) };
+    assert_eq!(fences(), vec![(vk::ObjectType::FENCE, fence.as_raw())]);
+    assert_eq!(
+        unsafe { device.get_fence_status(*fence) },
+        Ok(vk::SuccessCode::NOT_READY)
+    );
+    drop(fence);
+    assert!(fences().is_empty());
+    assert_eq!(driver.calls_to("vkDestroyFence").len(), 1);
+
+    let fence = unsafe { Owned::new(&device, device.create_fence(&info, None).unwrap()) };
+    let raw = fence.into_raw();
+    assert_eq!(fences(), vec![(vk::ObjectType::FENCE, raw.as_raw())]);
+    unsafe { device.destroy_fence(raw, None) };
+}
+
+#[test]
+fn test_mock_owned_promoted() {
+    use vulkanalia::vk::{DeviceV1_1, KhrSamplerYcbcrConversionExtensionDeviceCommands};
+
+    let conversions = |driver: &MockDriver| {
+        let objects = driver.live_objects().into_iter();
+        objects
+            .filter(|(t, _)| *t == vk::ObjectType::SAMPLER_YCBCR_CONVERSION)
+            .count()
+    };
+
+    // Vulkan 1.0 (the extension command is used).
+
+    let extension = vk::KHR_SAMPLER_YCBCR_CONVERSION_EXTENSION;
+    let config = MockConfig {
+        version: Version::V1_0_0,
+        physical_devices: vec![MockPhysicalDevice::default().with_extensions(&[extension])],
+        ..Default::default()
+    };
+
+    let (driver, _, instance) = common::create_instance(config, &[]);
+    let physical_device = unsafe { instance.enumerate_physical_devices() }.unwrap()[0];
+    let priorities = &[1.0];
+    let queue_infos = &[vk::DeviceQueueCreateInfo::builder()
+        .queue_family_index(0)
+        .queue_priorities(priorities)];
+    let extensions = &[extension.name.as_ptr()];
+    let info = vk::DeviceCreateInfo::builder()
+        .queue_create_infos(queue_infos)
+        .enabled_extension_names(extensions);
+    let device = unsafe { instance.create_device(physical_device, &info, None) }.unwrap();
+    assert!(!device.is_command_loaded("vkDestroySamplerYcbcrConversion"));
+
+    let info = vk::SamplerYcbcrConversionCreateInfo::default();
+    let conversion = unsafe { device.create_sampler_ycbcr_conversion_khr(&info, None) }.unwrap();
+    drop(unsafe { Owned::new(&device, conversion) });
+    assert_eq!(conversions(&driver), 0);
+    assert!(
+        driver
+            .calls_to("vkDestroySamplerYcbcrConversion")
+            .is_empty()
+    );
+    assert_eq!(
+        driver.calls_to("vkDestroySamplerYcbcrConversionKHR").len(),
+        1
+    );
+
+    // Vulkan 1.3 (the core command is used).
+
+    let (driver, _, instance) = common::create_instance(MockConfig::default(), &[]);
+    let (_, device) = common::create_device(&instance, &[], None);
+
+    let conversion = unsafe { device.create_sampler_ycbcr_conversion(&info, None) }.unwrap();
+    drop(unsafe { Owned::new(&device, conversion) });
+    assert_eq!(conversions(&driver), 0);
+    assert_eq!(driver.calls_to("vkDestroySamplerYcbcrConversion").len(), 1);
+    assert!(
+        driver
+            .calls_to("vkDestroySamplerYcbcrConversionKHR")
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_mock_owned_device() {
+    let loader = MockLoader::new(MockConfig::default());
+    let driver = loader.driver();
+    let entry = unsafe { Entry::new(loader) }.unwrap();
+
+    let info = vk::InstanceCreateInfo::builder();
+    let instance = unsafe { OwnedInstance::create(&entry, &info) }.unwrap();
+    let physical_device = unsafe { instance.enumerate_physical_devices() }.unwrap()[0];
+
+    let priorities = &[1.0];
+    let queue_infos = &[vk::DeviceQueueCreateInfo::builder()
+        .queue_family_index(0)
+        .queue_priorities(priorities)];
+    let info = vk::DeviceCreateInfo::builder().queue_create_infos(queue_infos);
+    let device = unsafe { OwnedDevice::create(&instance, physical_device, &info) }.unwrap();
+
+    drop(entry);
+    drop(instance);
+    assert!(driver.calls_to("vkDestroyInstance").is_empty());
+
+    let clone = device.clone();
+    drop(device);
+    assert!(driver.calls_to("vkDestroyDevice").is_empty());
+
+    driver.clear_calls();
+    drop(clone);
+    let calls = driver
+        .calls()
+        .into_iter()
+        .map(|c| c.command)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        calls,
+        &["vkDeviceWaitIdle", "vkDestroyDevice", "vkDestroyInstance"]
+    );
+    assert!(driver.live_objects().is_empty());
+}
+
+#[test]
+fn test_mock_instance_builder() {
+    let validation = vk::ExtensionName::from_bytes(b"VK_LAYER_KHRONOS_validation");
+    let missing = vk::ExtensionName::from_bytes(b"VK_LAYER_missing");
+
+    let config = MockConfig {
+        version: Version::V1_1_0,
+        ..Default::default()
+    };
+    let config = config
+        .with_extensions(&[
+            vk::KHR_SURFACE_EXTENSION,
+            vk::KHR_PORTABILITY_ENUMERATION_EXTENSION,
+        ])
+        .with_layers(&[validation]);
+
+    let loader = MockLoader::new(config);
+    let entry = unsafe { Entry::new(loader) }.unwrap();
+
+    let builder = entry
+        .instance_builder()
+        .optional_layer(validation)
+        .extension(vk::KHR_SURFACE_EXTENSION.name)
+        .optional_extension(vk::EXT_DEBUG_UTILS_EXTENSION.name);
+
+    let error = unsafe {
+        builder
+            .clone()
+            .layer(missing)
+            .extension(vk::KHR_WIN32_SURFACE_EXTENSION.name)
+            .create()
+    };
+    assert_eq!(
+        error.unwrap_err(),
+        InstanceError::Missing {
+            layers: vec![missing],
+            extensions: vec![vk::KHR_WIN32_SURFACE_EXTENSION.name],
+        }
+    );
+
+    let error = unsafe {
+        builder
+            .clone()
+            .minimum_api_version(Version::V1_2_0)
+            .create()
+    };
+    assert_eq!(
+        error.unwrap_err(),
+        InstanceError::Version {
+            required: Version::V1_2_0,
+            available: Version::V1_1_0,
+        }
+    );
+
+    let instance = unsafe { builder.clone().api_version(Version::V1_3_0).create() }.unwrap();
+    assert_eq!(instance.version(), Version::V1_1_0);
+    assert_eq!(instance.layers().iter().collect::<Vec<_>>(), [&validation]);
+    assert_eq!(
+        instance.extensions().iter().collect::<Vec<_>>(),
+        [&vk::KHR_SURFACE_EXTENSION.name]
+    );
+    unsafe { instance.destroy_instance(None) };
+
+    let instance = unsafe { builder.portability_enumeration(true).create() }.unwrap();
+    assert!(
+        instance
+            .extensions()
+            .contains(&vk::KHR_PORTABILITY_ENUMERATION_EXTENSION.name)
+    );
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_mock_physical_device_selector() {
+    let integrated = MockPhysicalDevice {
+        properties: vk::PhysicalDeviceProperties {
+            api_version: Version::V1_2_0.into(),
+            device_type: vk::PhysicalDeviceType::INTEGRATED_GPU,
+            device_name: vk::StringArray::from_bytes(b"integrated"),
+            ..Default::default()
+        },
+        vulkan12_features: vk::PhysicalDeviceVulkan12Features {
+            timeline_semaphore: vk::TRUE,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let discrete = MockPhysicalDevice {
+        properties: vk::PhysicalDeviceProperties {
+            api_version: Version::V1_1_0.into(),
+            device_type: vk::PhysicalDeviceType::DISCRETE_GPU,
+            device_name: vk::StringArray::from_bytes(b"discrete"),
+            ..Default::default()
+        },
+        surface_support: false,
+        ..Default::default()
+    };
+
+    let config = MockConfig {
+        physical_devices: vec![
+            integrated.with_extensions(&[vk::KHR_SWAPCHAIN_EXTENSION]),
+            discrete,
+        ],
+        ..Default::default()
+    };
+    let config = config.with_extensions(&[vk::KHR_SURFACE_EXTENSION]);
+
+    let loader = MockLoader::new(config);
+    let entry = unsafe { Entry::new(loader) }.unwrap();
+    let application_info = vk::ApplicationInfo::builder().api_version(Version::V1_2_0.into());
+    let extensions = &[vk::KHR_SURFACE_EXTENSION.name.as_ptr()];
+    let info = vk::InstanceCreateInfo::builder()
+        .application_info(&application_info)
+        .enabled_extension_names(extensions);
+    let instance = unsafe { entry.create_instance(&info, None) }.unwrap();
+
+    let surface = vk::SurfaceKHR::from_raw(1);
+    let selector = instance
+        .physical_device_selector()
+        .minimum_api_version(Version::V1_2_0)
+        .extension(vk::KHR_SWAPCHAIN_EXTENSION.name)
+        .vulkan12_features(vk::PhysicalDeviceVulkan12Features {
+            timeline_semaphore: vk::TRUE,
+            ..Default::default()
+        })
+        .queue(vk::QueueFlags::GRAPHICS)
+        .surface(surface);
+
+    // Suitable physical devices are ranked first.
+
+    let candidates = unsafe { selector.select() }.unwrap();
+    assert_eq!(candidates.len(), 2);
+    assert!(candidates[0].is_suitable());
+    assert_eq!(
+        candidates[0].properties.device_name.as_cstr(),
+        c"integrated"
+    );
+    assert_eq!(candidates[0].queue_families, &[Some(0)]);
+    assert_eq!(candidates[0].present_queue_family, Some(0));
+    assert_eq!(
+        candidates[1].rejections,
+        &[
+            Rejection::Version {
+                required: Version::V1_2_0,
+                available: Version::V1_1_0,
+            },
+            Rejection::Extensions(vec![vk::KHR_SWAPCHAIN_EXTENSION.name]),
+            Rejection::Features {
+                structure: "VkPhysicalDeviceVulkan12Features",
+                features: vec!["timeline_semaphore"],
+            },
+            Rejection::Present,
+        ]
+    );
+
+    // Preferred physical devices are ranked first among suitable devices.
+
+    let candidates = unsafe { instance.physical_device_selector().select() }.unwrap();
+    assert!(candidates.iter().all(|c| c.is_suitable()));
+    assert_eq!(candidates[0].properties.device_name.as_cstr(), c"discrete");
+
+    let candidates = unsafe {
+        instance
+            .physical_device_selector()
+            .preferred_device_type(vk::PhysicalDeviceType::INTEGRATED_GPU)
+            .select()
+    };
+    let candidates = candidates.unwrap();
+    assert_eq!(
+        candidates[0].properties.device_name.as_cstr(),
+        c"integrated"
+    );
+
+    // Unsuitable physical devices are reported when picking.
+
+    let error = unsafe {
+        selector
+            .clone()
+            .queue(vk::QueueFlags::SPARSE_BINDING)
+            .minimum_memory(1 << 40)
+            .pick()
+    };
+    let SelectError::Unsuitable(candidates) = error.unwrap_err() else {
+        panic!("expected unsuitable physical devices");
+    };
+    assert_eq!(
+        candidates[1].properties.device_name.as_cstr(),
+        c"integrated"
+    );
+    assert_eq!(
+        candidates[1].rejections,
+        &[
+            Rejection::Queue(vk::QueueFlags::SPARSE_BINDING),
+            Rejection::Memory {
+                required: 1 << 40,
+                available: 1 << 30,
+            },
+        ]
+    );
+
+    let candidate = unsafe { selector.pick() }.unwrap();
+    assert_eq!(candidate.properties.device_name.as_cstr(), c"integrated");
+
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_mock_feature_set() {
+    let physical_device = MockPhysicalDevice {
+        features: vk::PhysicalDeviceFeatures {
+            sampler_anisotropy: vk::TRUE,
+            ..Default::default()
+        },
+        vulkan12_features: vk::PhysicalDeviceVulkan12Features {
+            timeline_semaphore: vk::TRUE,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let config = MockConfig {
+        physical_devices: vec![physical_device],
+        ..Default::default()
+    };
+
+    let loader = MockLoader::new(config);
+    let entry = unsafe { Entry::new(loader) }.unwrap();
+    let application_info = vk::ApplicationInfo::builder().api_version(Version::V1_3_0.into());
+    let info = vk::InstanceCreateInfo::builder().application_info(&application_info);
+    let instance = unsafe { entry.create_instance(&info, None) }.unwrap();
+    let physical_device = unsafe { instance.enumerate_physical_devices() }.unwrap()[0];
+
+    let mut requested = FeatureSet::core(Version::V1_3_0);
+    requested.features_mut().sampler_anisotropy = vk::TRUE;
+    requested
+        .get_mut::<vk::PhysicalDeviceVulkan12Features>()
+        .unwrap()
+        .timeline_semaphore = vk::TRUE;
+
+    let supported = unsafe { requested.query(&instance, physical_device) };
+    assert!(supported.is_superset(&requested));
+    assert_eq!(supported.enabled(), requested.enabled());
+
+    // Missing features are reported by name.
+
+    let mut missing = requested.clone();
+    missing.features_mut().geometry_shader = vk::TRUE;
+    missing
+        .get_mut::<vk::PhysicalDeviceVulkan13Features>()
+        .unwrap()
+        .dynamic_rendering = vk::TRUE;
+
+    let error = unsafe { missing.enable(&instance, physical_device) }.unwrap_err();
+    assert_eq!(
+        error,
+        FeatureError::Missing(vec![
+            Feature {
+                structure: "VkPhysicalDeviceFeatures",
+                name: "geometry_shader",
+            },
+            Feature {
+                structure: "VkPhysicalDeviceVulkan13Features",
+                name: "dynamic_rendering",
+            },
+        ])
+    );
+
+    // Supported features are enabled when creating a device.
+
+    let features = unsafe { requested.enable(&instance, physical_device) }.unwrap();
+
+    let priorities = &[1.0];
+    let queue_infos = &[vk::DeviceQueueCreateInfo::builder()
+        .queue_family_index(0)
+        .queue_priorities(priorities)];
+    let info = vk::DeviceCreateInfo::builder()
+        .queue_create_infos(queue_infos)
+        .push_next(features);
+    let device = unsafe { instance.create_device(physical_device, &info, None) }.unwrap();
+    unsafe { device.destroy_device(None) };
+
+    let features = missing.chain();
+    let info = vk::DeviceCreateInfo::builder()
+        .queue_create_infos(queue_infos)
+        .push_next(features);
+    let result = unsafe { instance.create_device(physical_device, &info, None) };
+    assert_eq!(result.unwrap_err(), vk::ErrorCode::FEATURE_NOT_PRESENT);
+
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_mock_device_state() {
+    let physical_device = MockPhysicalDevice {
+        properties: vk::PhysicalDeviceProperties {
+            api_version: Version::V1_2_0.into(),
+            ..MockPhysicalDevice::default().properties
+        },
+        features: vk::PhysicalDeviceFeatures {
+            sampler_anisotropy: vk::TRUE,
+            ..Default::default()
+        },
+        vulkan12_features: vk::PhysicalDeviceVulkan12Features {
+            timeline_semaphore: vk::TRUE,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let config = MockConfig {
+        version: Version::V1_4_0,
+        physical_devices: vec![physical_device],
+        ..Default::default()
+    };
+
+    let (_, _, instance) = common::create_instance(config, &[]);
+    let physical_device = unsafe { instance.enumerate_physical_devices() }.unwrap()[0];
+
+    let features = vk::PhysicalDeviceFeatures::builder().sampler_anisotropy(true);
+    let mut vulkan12_features =
+        vk::PhysicalDeviceVulkan12Features::builder().timeline_semaphore(true);
+    let priorities = &[1.0, 0.5];
+    let queue_infos = &[vk::DeviceQueueCreateInfo::builder()
+        .queue_family_index(0)
+        .queue_priorities(priorities)];
+    let info = vk::DeviceCreateInfo::builder()
+        .queue_create_infos(queue_infos)
+        .enabled_features(&features)
+        .push_next(&mut vulkan12_features);
+    let device = unsafe { instance.create_device(physical_device, &info, None) }.unwrap();
+
+    // The version is limited by the API version of the physical device.
+    assert_eq!(device.version(), Version::V1_2_0);
+
+    let enabled = device.enabled_features();
+    assert_eq!(
+        enabled.enabled(),
+        vec![
+            Feature {
+                structure: "VkPhysicalDeviceFeatures",
+                name: "sampler_anisotropy",
+            },
+            Feature {
+                structure: "VkPhysicalDeviceVulkan12Features",
+                name: "timeline_semaphore",
+            },
+        ]
+    );
+
+    let vulkan12_features = enabled.get::<vk::PhysicalDeviceVulkan12Features>();
+    assert!(vulkan12_features.unwrap().next.is_null());
+
+    assert_eq!(
+        device.queue_families(),
+        &[DeviceQueueFamily {
+            flags: vk::DeviceQueueCreateFlags::empty(),
+            queue_family_index: 0,
+            queue_priorities: vec![1.0, 0.5],
+        }]
+    );
+    assert_eq!(device.queue_families()[0].queue_count(), 2);
+
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_mock_loaded_commands() {
+    let (_, entry, instance) = common::create_instance(MockConfig::default(), &[]);
+    assert!(entry.is_command_loaded("vkCreateInstance"));
+    assert!(instance.is_command_loaded("vkEnumeratePhysicalDevices"));
+    assert!(!instance.is_command_loaded("vkCreateDebugUtilsMessengerEXT"));
+    assert!(!instance.is_command_loaded("vkGetPhysicalDeviceSurfaceCapabilitiesKHR"));
+
+    let (_, device) = common::create_device(&instance, &[], None);
+    assert!(device.is_command_loaded("vkQueueSubmit"));
+    assert!(!device.is_command_loaded("vkCmdDrawMeshTasksEXT"));
+    assert!(!device.is_command_loaded("vkNotACommand"));
+}
+
+#[test]
+fn test_mock_enabled_commands() {
+    let config = MockConfig {
+        version: Version::V1_0_0,
+        ..Default::default()
+    };
+
+    let (_, _, instance) = common::create_instance(config, &[]);
+    let (_, device) = common::create_device(&instance, &[], None);
+    assert!(device.is_command_loaded("vkGetDeviceQueue"));
+    assert!(!device.is_command_loaded("vkGetDeviceQueue2"));
+
+    let (_, _, instance) = common::create_instance(MockConfig::default(), &[]);
+    let (_, device) = common::create_device(&instance, &[], None);
+    assert!(device.is_command_loaded("vkGetDeviceQueue"));
+    assert!(device.is_command_loaded("vkGetDeviceQueue2"));
+
+    // The version is limited by the API version requested by the application
+    // (not the version supported by the loader).
+    let loader = MockLoader::new(MockConfig::default());
+    let entry = unsafe { Entry::new(loader) }.unwrap();
+    let info = vk::InstanceCreateInfo::builder();
+    let instance = unsafe { entry.create_instance(&info, None) }.unwrap();
+    assert_eq!(instance.version(), Version::V1_3_0);
+    assert_eq!(instance.api_version(), Version::V1_0_0);
+
+    let (_, device) = common::create_device(&instance, &[], None);
+    assert_eq!(device.version(), Version::V1_0_0);
+    assert!(!device.is_command_loaded("vkGetDeviceQueue2"));
+
+    // Devices loaded with `from_created` load every device command.
+    let physical_device = unsafe { instance.enumerate_physical_devices() }.unwrap()[0];
+    let priorities = &[1.0];
+    let queue_infos = &[vk::DeviceQueueCreateInfo::builder()
+        .queue_family_index(0)
+        .queue_priorities(priorities)];
+    let info = vk::DeviceCreateInfo::builder().queue_create_infos(queue_infos);
+    let handle = unsafe { InstanceV1_0::create_device(&instance, physical_device, &info, None) };
+    let get_device_proc_addr = instance.commands().get_device_proc_addr;
+    let device = unsafe {
+        Device::from_created(
+            get_device_proc_addr,
+            physical_device,
+            &info,
+            handle.unwrap(),
+        )
+    }
+    .unwrap();
+    assert_eq!(device.version(), Version::V1_4_0);
+    assert!(device.is_command_loaded("vkGetDeviceQueue2"));
+    assert!(device.is_command_loaded("vkCreateSwapchainKHR"));
+}
+
+#[test]
+fn test_mock_shared_commands() {
+    let (_, entry, instance) = common::create_instance(MockConfig::default(), &[]);
+    let (_, device) = common::create_device(&instance, &[], None);
+
+    let cloned = entry.clone();
+    assert!(std::ptr::eq(entry.commands(), cloned.commands()));
+    let cloned = instance.clone();
+    assert!(std::ptr::eq(instance.commands(), cloned.commands()));
+    let cloned = device.clone();
+    assert!(std::ptr::eq(device.commands(), cloned.commands()));
+    assert_eq!(device.handle(), cloned.handle());
+}
+
+#[cfg(feature = "fallible_commands")]
+#[test]
+fn test_mock_fallible_commands() {
+    use vk::KhrSwapchainExtensionDeviceCommands;
+
+    let (_, _, instance) = common::create_instance(MockConfig::default(), &[]);
+    let (_, device) = common::create_device(&instance, &[], None);
+
+    let info = vk::SwapchainCreateInfoKHR::default();
+    let error = unsafe { device.create_swapchain_khr(&info, None) }.unwrap_err();
+    assert_eq!(error, vk::ErrorCode::EXTENSION_NOT_PRESENT);
+}
+
+type Calls = Vec<(&'static str, Option<vk::Result>)>;
+
+#[derive(Clone, Default)]
+struct Recorder {
+    calls: Arc<Mutex<Calls>>,
+}
+
+impl CommandHook for Recorder {
+    fn before(&self, command: &'static str) -> Option<vk::Result> {
+        (command == "vkAllocateMemory").then_some(vk::Result::ERROR_OUT_OF_DEVICE_MEMORY)
+    }
+
+    fn after(&self, command: &'static str, result: Option<vk::Result>) {
+        self.calls.lock().unwrap().push((command, result));
+    }
+}
+
+#[test]
+fn test_mock_hooks() {
+    let (driver, _, instance) = common::create_instance(MockConfig::default(), &[]);
+    let (_, device) = common::create_device(&instance, &[], None);
+
+    let recorder = Recorder::default();
+    let hooked = unsafe { device.with_hook(recorder.clone()) }.unwrap();
+
+    let info = vk::BufferCreateInfo::builder()
+        .size(1000)
+        .usage(vk::BufferUsageFlags::VERTEX_BUFFER);
+    let buffer = unsafe { hooked.create_buffer(&info, None) }.unwrap();
+    let queue = unsafe { hooked.get_device_queue(0, 0) };
+    unsafe { hooked.queue_wait_idle(queue) }.unwrap();
+
+    let info = vk::MemoryAllocateInfo::builder().allocation_size(1024);
+    let error = unsafe { hooked.allocate_memory(&info, None) }.unwrap_err();
+    assert_eq!(error, vk::ErrorCode::OUT_OF_DEVICE_MEMORY);
+    assert!(driver.calls_to("vkAllocateMemory").is_empty());
+
+    // Commands called with the unhooked device are not hooked.
+    unsafe { device.destroy_buffer(buffer, None) };
+
+    // Commands called with other hooked copies of the device are only hooked
+    // by the hooks of that copy (which are called after any existing hooks).
+    let other = Recorder::default();
+    let stacked = unsafe { hooked.with_hook(other.clone()) }.unwrap();
+    unsafe { stacked.device_wait_idle() }.unwrap();
+    assert_eq!(
+        other.calls.lock().unwrap().clone(),
+        vec![("vkDeviceWaitIdle", Some(vk::Result::SUCCESS))],
+    );
+
+    let calls = recorder.calls.lock().unwrap().clone();
+    assert_eq!(
+        calls,
+        vec![
+            ("vkCreateBuffer", Some(vk::Result::SUCCESS)),
+            ("vkGetDeviceQueue", None),
+            ("vkQueueWaitIdle", Some(vk::Result::SUCCESS)),
+            (
+                "vkAllocateMemory",
+                Some(vk::Result::ERROR_OUT_OF_DEVICE_MEMORY)
+            ),
+            ("vkDeviceWaitIdle", Some(vk::Result::SUCCESS)),
+        ]
+    );
+
+    // Commands used after the hooked device has been dropped are not hooked.
+    let commands = *stacked.commands();
+    drop(stacked);
+    unsafe { (commands.device_wait_idle)(device.handle()) };
+    assert_eq!(other.calls.lock().unwrap().len(), 1);
+
+    // Only a limited number of hooked devices can exist at once (and the
+    // first hooked device still exists).
+    let hooked = (0..MAX_HOOKED)
+        .map_while(|_| unsafe { device.with_hook(Recorder::default()) })
+        .collect::<Vec<_>>();
+    assert_eq!(hooked.len(), MAX_HOOKED - 1);
+    assert!(unsafe { device.with_hook(Recorder::default()) }.is_none());
+    drop(hooked);
+    assert!(unsafe { device.with_hook(Recorder::default()) }.is_some());
+
+    // Instances can be hooked too.
+    let recorder = Recorder::default();
+    let hooked = unsafe { instance.with_hook(recorder.clone()) }.unwrap();
+    unsafe { hooked.enumerate_physical_devices() }.unwrap();
+    let calls = recorder.calls.lock().unwrap().clone();
+    assert!(!calls.is_empty());
+    assert!(calls.iter().all(|c| c.0 == "vkEnumeratePhysicalDevices"));
+
+    unsafe { device.destroy_device(None) };
+}
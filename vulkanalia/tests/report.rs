@@ -0,0 +1,145 @@
+extern crate vulkanalia;
+
+mod common;
+
+use serde_json::Value;
+use vulkanalia::loader::{MockConfig, MockPhysicalDevice};
+use vulkanalia::prelude::v1_0::*;
+use vulkanalia::report::{Difference, FormatReport, Report};
+
+fn create_report(physical_devices: Vec<MockPhysicalDevice>) -> Report {
+    let config = MockConfig {
+        physical_devices,
+        ..Default::default()
+    };
+
+    let (_, _, instance) = common::create_instance(config, &[]);
+    let report = unsafe { Report::new(&instance, None) }.unwrap();
+    unsafe { instance.destroy_instance(None) };
+    report
+}
+
+fn physical_device() -> MockPhysicalDevice {
+    let mut physical_device = MockPhysicalDevice {
+        vulkan12_features: vk::PhysicalDeviceVulkan12Features {
+            timeline_semaphore: vk::TRUE,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    physical_device.properties.limits.max_image_dimension_2d = 4096;
+    physical_device.format_properties.insert(
+        vk::Format::R8G8B8A8_UNORM,
+        vk::FormatProperties {
+            optimal_tiling_features: vk::FormatFeatureFlags::SAMPLED_IMAGE
+                | vk::FormatFeatureFlags::COLOR_ATTACHMENT,
+            ..Default::default()
+        },
+    );
+
+    physical_device.with_extensions(&[vk::KHR_SWAPCHAIN_EXTENSION])
+}
+
+#[test]
+fn test_report() {
+    let report = create_report(vec![physical_device()]);
+    assert_eq!(report.version, "1.3.0");
+    assert_eq!(report.devices.len(), 1);
+
+    let device = &report.devices[0];
+    assert_eq!(device.properties.device_name, "vulkanalia mock device");
+    assert_eq!(device.properties.device_type, "DISCRETE_GPU");
+    assert_eq!(device.properties.api_version, "1.3.0");
+    assert_eq!(device.limits["max_image_dimension_2d"], Value::from(4096));
+    assert_eq!(device.extensions["VK_KHR_swapchain"], 1);
+
+    let vulkan12 = &device.features["VkPhysicalDeviceVulkan12Features"];
+    assert!(vulkan12["timeline_semaphore"]);
+    assert!(!vulkan12["buffer_device_address"]);
+    assert!(
+        device
+            .features
+            .contains_key("VkPhysicalDeviceVulkan13Features")
+    );
+    assert!(
+        !device
+            .features
+            .contains_key("VkPhysicalDeviceVulkan14Features")
+    );
+
+    assert_eq!(device.queue_families.len(), 1);
+    assert_eq!(
+        device.queue_families[0].queue_flags,
+        &["GRAPHICS", "COMPUTE", "TRANSFER"]
+    );
+    assert_eq!(device.memory_heaps.len(), 2);
+    assert_eq!(device.memory_heaps[0].flags, &["DEVICE_LOCAL"]);
+    assert_eq!(device.memory_types.len(), 2);
+
+    assert_eq!(device.formats.len(), 1);
+    assert_eq!(
+        device.formats["R8G8B8A8_UNORM"],
+        FormatReport {
+            optimal_tiling_features: vec!["SAMPLED_IMAGE".into(), "COLOR_ATTACHMENT".into()],
+            ..Default::default()
+        }
+    );
+
+    assert!(device.surface.is_none());
+
+    // Reports can be serialized as JSON and parsed again.
+
+    let json = report.to_json();
+    assert_eq!(Report::from_json(&json).unwrap(), report);
+    assert!(report.diff(&Report::from_json(&json).unwrap()).is_empty());
+}
+
+#[test]
+fn test_report_diff() {
+    let left = create_report(vec![physical_device()]);
+
+    let mut other = physical_device();
+    other.properties.limits.max_image_dimension_2d = 8192;
+    other.vulkan12_features.buffer_device_address = vk::TRUE;
+    other.extensions.clear();
+
+    let mut second = MockPhysicalDevice::default();
+    second.properties.device_name = vk::StringArray::from_bytes(b"other device");
+
+    let right = create_report(vec![other, second]);
+
+    let device = "devices[vulkanalia mock device]";
+    assert_eq!(
+        left.diff(&right),
+        &[
+            Difference {
+                path: "devices[other device]".into(),
+                left: None,
+                right: Some(serde_json::to_value(&right.devices[1]).unwrap()),
+            },
+            Difference {
+                path: format!("{device}.extensions.VK_KHR_swapchain"),
+                left: Some(Value::from(1)),
+                right: None,
+            },
+            Difference {
+                path: format!(
+                    "{device}.features.VkPhysicalDeviceVulkan12Features.buffer_device_address"
+                ),
+                left: Some(Value::from(false)),
+                right: Some(Value::from(true)),
+            },
+            Difference {
+                path: format!("{device}.limits.max_image_dimension_2d"),
+                left: Some(Value::from(4096)),
+                right: Some(Value::from(8192)),
+            },
+        ]
+    );
+
+    assert_eq!(
+        left.diff(&right)[3].to_string(),
+        format!("{device}.limits.max_image_dimension_2d: 4096 != 8192")
+    );
+}
@@ -0,0 +1,135 @@
+extern crate vulkanalia;
+
+use vulkanalia::Version;
+use vulkanalia::resolve::{ResolveError, ResolveWarning, resolve, resolve_with};
+use vulkanalia::vk;
+
+const fn extension(
+    name: &[u8],
+    type_: &'static str,
+    required_extensions: Option<&'static [vk::ExtensionName]>,
+    required_version: Option<&'static str>,
+    promoted_to: Option<&'static str>,
+) -> vk::Extension {
+    let mut extension = vk::KHR_SURFACE_EXTENSION;
+    extension.name = vk::ExtensionName::from_bytes(name);
+    extension.number = 0;
+    extension.type_ = type_;
+    extension.required_extensions = required_extensions;
+    extension.required_version = required_version;
+    extension.deprecated_by = None;
+    extension.obsoleted_by = None;
+    extension.promoted_to = promoted_to;
+    extension.commands = &[];
+    extension
+}
+
+const SURFACE: vk::ExtensionName = vk::ExtensionName::from_bytes(b"VK_KHR_surface");
+const PROPERTIES: vk::ExtensionName = vk::ExtensionName::from_bytes(b"VK_KHR_properties");
+const SWAPCHAIN: vk::ExtensionName = vk::ExtensionName::from_bytes(b"VK_KHR_swapchain");
+const PRESENT: vk::ExtensionName = vk::ExtensionName::from_bytes(b"VK_KHR_present");
+const NEW: vk::ExtensionName = vk::ExtensionName::from_bytes(b"VK_KHR_new");
+
+const EXTENSIONS: &[vk::Extension] = &[
+    extension(b"VK_KHR_surface", "instance", None, None, None),
+    extension(
+        b"VK_KHR_properties",
+        "instance",
+        None,
+        None,
+        Some("VK_VERSION_1_1"),
+    ),
+    extension(b"VK_KHR_swapchain", "device", Some(&[SURFACE]), None, None),
+    extension(
+        b"VK_KHR_present",
+        "device",
+        Some(&[SWAPCHAIN, PROPERTIES]),
+        None,
+        None,
+    ),
+    extension(
+        b"VK_KHR_new",
+        "device",
+        Some(&[SWAPCHAIN]),
+        Some("1.2"),
+        None,
+    ),
+];
+
+#[test]
+fn test_resolve_with() {
+    let resolved = resolve_with(EXTENSIONS, &[PRESENT, SWAPCHAIN], Version::V1_0_0).unwrap();
+    assert_eq!(resolved.instance_extensions, &[SURFACE, PROPERTIES]);
+    assert_eq!(resolved.device_extensions, &[SWAPCHAIN, PRESENT]);
+    assert!(resolved.promoted.is_empty());
+    assert!(resolved.warnings.is_empty());
+
+    let resolved = resolve_with(EXTENSIONS, &[PRESENT], Version::V1_1_0).unwrap();
+    assert_eq!(resolved.instance_extensions, &[SURFACE]);
+    assert_eq!(resolved.device_extensions, &[SWAPCHAIN, PRESENT]);
+    assert_eq!(resolved.promoted, &[PROPERTIES]);
+
+    let error = resolve_with(EXTENSIONS, &[NEW], Version::V1_1_0).unwrap_err();
+    assert_eq!(
+        error,
+        ResolveError::Version {
+            extension: NEW,
+            required: Version::V1_2_0,
+        }
+    );
+
+    let resolved = resolve_with(EXTENSIONS, &[NEW], Version::V1_2_0).unwrap();
+    assert_eq!(resolved.device_extensions, &[SWAPCHAIN, NEW]);
+
+    let unknown = vk::ExtensionName::from_bytes(b"VK_KHR_unknown");
+    let error = resolve_with(EXTENSIONS, &[unknown], Version::V1_0_0).unwrap_err();
+    assert_eq!(error, ResolveError::Unknown(unknown));
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_resolve() {
+    let wanted = [
+        vk::EXT_DEBUG_REPORT_EXTENSION.name,
+        vk::KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION.name,
+        vk::KHR_SWAPCHAIN_EXTENSION.name,
+    ];
+
+    let resolved = resolve(&wanted, Version::V1_1_0).unwrap();
+    assert!(
+        resolved
+            .instance_extensions
+            .contains(&vk::EXT_DEBUG_REPORT_EXTENSION.name)
+    );
+    assert!(
+        resolved
+            .device_extensions
+            .contains(&vk::KHR_SWAPCHAIN_EXTENSION.name)
+    );
+    assert_eq!(
+        resolved.promoted,
+        &[vk::KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION.name]
+    );
+    assert_eq!(
+        resolved.warnings,
+        &[ResolveWarning::Deprecated {
+            extension: vk::EXT_DEBUG_REPORT_EXTENSION.name,
+            by: Some("VK_EXT_debug_utils"),
+        }]
+    );
+}
+
+#[test]
+#[ignore = "requires bindings generated from the `depends` attributes in the Vulkan API registry"]
+fn test_resolve_dependencies() {
+    let wanted = [vk::KHR_SWAPCHAIN_EXTENSION.name];
+    let resolved = resolve(&wanted, Version::V1_0_0).unwrap();
+    assert_eq!(
+        resolved.instance_extensions,
+        &[vk::KHR_SURFACE_EXTENSION.name]
+    );
+    assert_eq!(
+        resolved.device_extensions,
+        &[vk::KHR_SWAPCHAIN_EXTENSION.name]
+    );
+}
@@ -0,0 +1,143 @@
+extern crate vulkanalia;
+
+mod common;
+
+use vulkanalia::loader::MockDriver;
+use vulkanalia::prelude::v1_2::*;
+use vulkanalia::sync::FrameSync;
+
+fn create_device(timeline: bool) -> (MockDriver, Instance, Device) {
+    let config = common::timeline_semaphore_config();
+    let (driver, _, instance) = common::create_instance(config, &[]);
+    let mut vulkan12_features =
+        vk::PhysicalDeviceVulkan12Features::builder().timeline_semaphore(timeline);
+    let (_, device) = common::create_device(&instance, &[], Some(&mut vulkan12_features));
+
+    (driver, instance, device)
+}
+
+fn live(driver: &MockDriver, type_: vk::ObjectType) -> usize {
+    let objects = driver.live_objects();
+    objects.iter().filter(|(t, _)| *t == type_).count()
+}
+
+#[test]
+fn test_frame_sync_fences() {
+    let (driver, instance, device) = create_device(false);
+    let queue = unsafe { device.get_device_queue(0, 0) };
+
+    let mut sync = unsafe { FrameSync::new(&device, 2) }.unwrap();
+    assert_eq!(sync.frames_in_flight(), 2);
+    assert!(!sync.is_timeline());
+    assert_eq!(live(&driver, vk::ObjectType::FENCE), 2);
+    assert_eq!(live(&driver, vk::ObjectType::SEMAPHORE), 4);
+
+    // Frame 0 (image 0)
+
+    let frame0 = unsafe { sync.begin(u64::MAX) }.unwrap().unwrap();
+    assert_eq!(frame0.index, 0);
+    assert!(!frame0.fence.is_null());
+    assert!(frame0.timeline.is_null());
+
+    assert!(unsafe { sync.image_acquired(0, u64::MAX) }.unwrap());
+    assert_eq!(
+        driver.calls_to("vkResetFences")[0].args[1],
+        frame0.fence.as_raw()
+    );
+
+    // The fence is unsignaled until the frame is submitted.
+    assert_eq!(unsafe { sync.begin(0) }, Ok(None));
+
+    let stage = vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT;
+    unsafe { sync.submit(queue, &[], stage) }.unwrap();
+    assert_eq!(
+        driver.calls_to("vkQueueSubmit")[0].args[2],
+        frame0.fence.as_raw()
+    );
+
+    sync.advance();
+
+    // Frame 1 (image 0)
+
+    let frame1 = unsafe { sync.begin(u64::MAX) }.unwrap().unwrap();
+    assert_eq!(frame1.index, 1);
+    assert_ne!(frame1.fence, frame0.fence);
+    assert_ne!(frame1.image_available, frame0.image_available);
+    assert_ne!(frame1.render_finished, frame0.render_finished);
+
+    // Image 0 was last used by frame 0 so its fence is waited on.
+    driver.clear_calls();
+    assert!(unsafe { sync.image_acquired(0, u64::MAX) }.unwrap());
+    let waits = driver.calls_to("vkWaitForFences");
+    assert_eq!(waits.len(), 1);
+    assert_eq!(waits[0].args[1], frame0.fence.as_raw());
+
+    unsafe { sync.submit(queue, &[], stage) }.unwrap();
+    sync.advance();
+
+    // Frame 0 (image 1)
+
+    let frame = unsafe { sync.begin(u64::MAX) }.unwrap().unwrap();
+    assert_eq!(frame, frame0);
+
+    driver.clear_calls();
+    assert!(unsafe { sync.image_acquired(1, u64::MAX) }.unwrap());
+    assert!(driver.calls_to("vkWaitForFences").is_empty());
+
+    drop(sync);
+    assert_eq!(live(&driver, vk::ObjectType::FENCE), 0);
+    assert_eq!(live(&driver, vk::ObjectType::SEMAPHORE), 0);
+
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_frame_sync_timeline() {
+    let (driver, instance, device) = create_device(true);
+    let queue = unsafe { device.get_device_queue(0, 0) };
+
+    let mut sync = unsafe { FrameSync::timeline(&device, 2) }.unwrap();
+    assert!(sync.is_timeline());
+    assert_eq!(live(&driver, vk::ObjectType::FENCE), 0);
+    assert_eq!(live(&driver, vk::ObjectType::SEMAPHORE), 5);
+
+    let stage = vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT;
+    for (i, image) in [0, 1, 0].into_iter().enumerate() {
+        let frame = unsafe { sync.begin(u64::MAX) }.unwrap().unwrap();
+        assert_eq!(frame.index, i % 2);
+        assert!(frame.fence.is_null());
+        assert!(!frame.timeline.is_null());
+        assert_eq!(frame.timeline_value, i as u64 + 1);
+
+        assert!(unsafe { sync.image_acquired(image, u64::MAX) }.unwrap());
+
+        // The timeline semaphore has not yet been signaled for this frame.
+        let value = unsafe { device.get_semaphore_counter_value(frame.timeline) };
+        assert_eq!(value, Ok(i as u64));
+
+        unsafe { sync.submit(queue, &[], stage) }.unwrap();
+        let value = unsafe { device.get_semaphore_counter_value(frame.timeline) };
+        assert_eq!(value, Ok(frame.timeline_value));
+
+        sync.advance();
+    }
+
+    drop(sync);
+    assert_eq!(live(&driver, vk::ObjectType::SEMAPHORE), 0);
+
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_frame_sync_timeline_unsupported() {
+    let (driver, instance, device) = create_device(false);
+
+    let sync = unsafe { FrameSync::timeline(&device, 2) };
+    assert_eq!(sync.map(|_| ()), Err(vk::ErrorCode::FEATURE_NOT_PRESENT));
+    assert_eq!(live(&driver, vk::ObjectType::SEMAPHORE), 0);
+
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
@@ -0,0 +1,97 @@
+extern crate vulkanalia;
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use vulkanalia::loader::MockConfig;
+use vulkanalia::prelude::v1_0::*;
+
+type Spans = Vec<(&'static str, Vec<(&'static str, String)>)>;
+
+/// A subscriber which records the names and fields of spans.
+#[derive(Clone, Default)]
+struct Recorder {
+    spans: Arc<Mutex<Spans>>,
+}
+
+struct Visitor<'a>(&'a mut Vec<(&'static str, String)>);
+
+impl Visit for Visitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push((field.name(), format!("{value:?}")));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut spans = self.spans.lock().unwrap();
+        let mut fields = vec![];
+        span.record(&mut Visitor(&mut fields));
+        spans.push((span.metadata().name(), fields));
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        let index = span.into_u64() as usize - 1;
+        values.record(&mut Visitor(&mut spans[index].1));
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn test_tracing() {
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+
+    let (driver, _, instance) = common::create_instance(MockConfig::default(), &[]);
+    let (_, device) = common::create_device(&instance, &[], None);
+
+    recorder.spans.lock().unwrap().clear();
+
+    let info = vk::FenceCreateInfo::default();
+    driver.queue_result("vkCreateFence", vk::ErrorCode::OUT_OF_HOST_MEMORY);
+    let error = unsafe { device.create_fence(&info, None) }.unwrap_err();
+    assert_eq!(error, vk::ErrorCode::OUT_OF_HOST_MEMORY);
+
+    let fence = unsafe { device.create_fence(&info, None) }.unwrap();
+    unsafe { device.destroy_fence(fence, None) };
+
+    let device = format!("{:?}", device.handle());
+    let spans = recorder.spans.lock().unwrap().clone();
+    assert_eq!(
+        spans,
+        [
+            (
+                "vkCreateFence",
+                vec![
+                    ("device", device.clone()),
+                    ("result", "ERROR_OUT_OF_HOST_MEMORY".into()),
+                ]
+            ),
+            (
+                "vkCreateFence",
+                vec![("device", device.clone()), ("result", "SUCCESS".into())]
+            ),
+            (
+                "vkDestroyFence",
+                vec![("device", device), ("fence", format!("{fence:?}"))]
+            ),
+        ]
+    );
+}
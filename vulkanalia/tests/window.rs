@@ -0,0 +1,358 @@
+extern crate vulkanalia;
+
+mod common;
+
+use raw_window_handle::{
+    DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, RawDisplayHandle,
+    RawWindowHandle, WebDisplayHandle, WebWindowHandle, WindowHandle,
+};
+use vulkanalia::loader::{MockConfig, MockDisplay, MockDriver, MockPhysicalDevice};
+use vulkanalia::prelude::v1_0::*;
+use vulkanalia::vk::KhrSurfaceExtensionInstanceCommands;
+use vulkanalia::window::{
+    self as vk_window, Acquire, Present, Swapchain, SwapchainConfig, WindowError, display,
+};
+
+/// A window with web handles (which are not supported).
+struct Web;
+
+impl HasDisplayHandle for Web {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        let handle = RawDisplayHandle::Web(WebDisplayHandle::new());
+        Ok(unsafe { DisplayHandle::borrow_raw(handle) })
+    }
+}
+
+impl HasWindowHandle for Web {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        let handle = RawWindowHandle::Web(WebWindowHandle::new(1));
+        Ok(unsafe { WindowHandle::borrow_raw(handle) })
+    }
+}
+
+/// A window whose handles are not available.
+struct Unavailable;
+
+impl HasDisplayHandle for Unavailable {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        Err(HandleError::Unavailable)
+    }
+}
+
+impl HasWindowHandle for Unavailable {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        Err(HandleError::Unavailable)
+    }
+}
+
+#[test]
+fn test_window_errors() {
+    let (_, _, instance) = common::create_instance(MockConfig::default(), &[]);
+
+    assert!(matches!(
+        vk_window::get_required_instance_extensions(&Web),
+        Err(WindowError::Unsupported),
+    ));
+    assert!(matches!(
+        vk_window::get_required_instance_extensions(&Unavailable),
+        Err(WindowError::MissingDisplay(HandleError::Unavailable)),
+    ));
+
+    assert!(matches!(
+        unsafe { vk_window::create_surface(&instance, &Web, &Web) },
+        Err(WindowError::Unsupported),
+    ));
+    assert!(matches!(
+        unsafe { vk_window::create_surface(&instance, &Web, &Unavailable) },
+        Err(WindowError::Unavailable(HandleError::Unavailable)),
+    ));
+    assert!(matches!(
+        unsafe { vk_window::create_surface(&instance, &Unavailable, &Web) },
+        Err(WindowError::MissingDisplay(HandleError::Unavailable)),
+    ));
+
+    unsafe { instance.destroy_instance(None) };
+}
+
+fn create_instance(
+    config: MockConfig,
+    extensions: &[&vk::ExtensionName],
+) -> (MockDriver, Entry, Instance) {
+    let config = MockConfig {
+        extensions: extensions
+            .iter()
+            .map(|e| vk::ExtensionProperties {
+                extension_name: **e,
+                spec_version: 1,
+            })
+            .collect(),
+        ..config
+    };
+
+    common::create_instance(config, extensions)
+}
+
+#[test]
+fn test_headless_surface() {
+    let extensions = vk_window::get_required_headless_instance_extensions();
+    assert_eq!(
+        extensions,
+        &[
+            &vk::KHR_SURFACE_EXTENSION.name,
+            &vk::EXT_HEADLESS_SURFACE_EXTENSION.name,
+        ]
+    );
+
+    let (driver, _, instance) = create_instance(MockConfig::default(), extensions);
+
+    let surface = unsafe { vk_window::create_headless_surface(&instance) }.unwrap();
+    let object = (vk::ObjectType::SURFACE_KHR, surface.as_raw());
+    assert!(driver.live_objects().contains(&object));
+
+    unsafe { instance.destroy_surface_khr(surface, None) };
+    assert!(!driver.live_objects().contains(&object));
+
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_display_surface() {
+    let mode = vk::DisplayModeParametersKHR {
+        visible_region: vk::Extent2D {
+            width: 1920,
+            height: 1080,
+        },
+        refresh_rate: 60000,
+    };
+
+    let physical_device = MockPhysicalDevice {
+        displays: vec![MockDisplay {
+            name: "kiosk".into(),
+            physical_resolution: mode.visible_region,
+            supported_transforms: vk::SurfaceTransformFlagsKHR::IDENTITY,
+            modes: vec![mode],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let config = MockConfig {
+        physical_devices: vec![physical_device],
+        ..Default::default()
+    };
+
+    let extensions = display::get_required_instance_extensions();
+    let (driver, _, instance) = create_instance(config, extensions);
+    let physical_device = unsafe { instance.enumerate_physical_devices() }.unwrap()[0];
+
+    let displays = unsafe { display::get_displays(&instance, physical_device) }.unwrap();
+    assert_eq!(displays.len(), 1);
+    let display = &displays[0];
+    assert_eq!(display.name.as_deref(), Some("kiosk"));
+    assert_eq!(display.physical_resolution, mode.visible_region);
+    assert_eq!(display.modes.len(), 1);
+    assert_eq!(display.modes[0].visible_region, mode.visible_region);
+    assert_eq!(display.modes[0].refresh_rate, 60000);
+
+    let planes = unsafe { display::get_planes(&instance, physical_device) }.unwrap();
+    assert_eq!(planes.len(), 1);
+    assert_eq!(planes[0].index, 0);
+    assert_eq!(planes[0].current_display, Some(display.handle));
+    assert!(planes[0].supports(display.handle));
+
+    let surface = unsafe {
+        display::create_surface(
+            &instance,
+            physical_device,
+            display,
+            &display.modes[0],
+            &planes[0],
+        )
+    }
+    .unwrap();
+    assert!(
+        driver
+            .live_objects()
+            .contains(&(vk::ObjectType::SURFACE_KHR, surface.as_raw()))
+    );
+
+    unsafe { instance.destroy_surface_khr(surface, None) };
+    unsafe { instance.destroy_instance(None) };
+}
+
+fn create_swapchain_device(
+    physical_device: MockPhysicalDevice,
+) -> (MockDriver, Instance, vk::PhysicalDevice, Device) {
+    let config = MockConfig {
+        physical_devices: vec![physical_device.with_extensions(&[vk::KHR_SWAPCHAIN_EXTENSION])],
+        ..Default::default()
+    };
+
+    let extensions = vk_window::get_required_headless_instance_extensions();
+    let (driver, _, instance) = create_instance(config, extensions);
+    let extensions = &[&vk::KHR_SWAPCHAIN_EXTENSION.name];
+    let (physical_device, device) = common::create_device(&instance, extensions, None);
+
+    (driver, instance, physical_device, device)
+}
+
+#[test]
+fn test_swapchain() {
+    let (driver, instance, physical_device, device) =
+        create_swapchain_device(MockPhysicalDevice::default());
+    let queue = unsafe { device.get_device_queue(0, 0) };
+
+    let surface = unsafe { vk_window::create_headless_surface(&instance) }.unwrap();
+
+    // Create
+
+    let extent = vk::Extent2D {
+        width: 800,
+        height: 600,
+    };
+
+    let mut swapchain = unsafe {
+        Swapchain::new(
+            &instance,
+            &device,
+            physical_device,
+            surface,
+            extent,
+            SwapchainConfig::default(),
+        )
+    }
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(swapchain.format().format, vk::Format::B8G8R8A8_SRGB);
+    assert_eq!(swapchain.present_mode(), vk::PresentModeKHR::MAILBOX);
+    assert_eq!(swapchain.extent(), extent);
+    assert_eq!(swapchain.images().len(), 3);
+    assert_eq!(swapchain.image_views().len(), 3);
+
+    // Acquire and present
+
+    let acquire = unsafe { swapchain.acquire(u64::MAX, vk::Semaphore::null(), vk::Fence::null()) };
+    assert_eq!(
+        acquire,
+        Ok(Acquire::Image {
+            index: 0,
+            suboptimal: false
+        })
+    );
+    assert!(!acquire.unwrap().needs_recreate());
+
+    let present = unsafe { swapchain.present(queue, 0, &[]) };
+    assert_eq!(present, Ok(Present::Presented));
+
+    driver.queue_result("vkAcquireNextImageKHR", vk::SuccessCode::SUBOPTIMAL_KHR);
+    let acquire = unsafe { swapchain.acquire(u64::MAX, vk::Semaphore::null(), vk::Fence::null()) };
+    assert_eq!(
+        acquire,
+        Ok(Acquire::Image {
+            index: 1,
+            suboptimal: true
+        })
+    );
+    assert!(acquire.unwrap().needs_recreate());
+
+    driver.queue_result("vkQueuePresentKHR", vk::ErrorCode::OUT_OF_DATE_KHR);
+    let present = unsafe { swapchain.present(queue, 1, &[]) };
+    assert_eq!(present, Ok(Present::OutOfDate));
+
+    driver.queue_result("vkAcquireNextImageKHR", vk::ErrorCode::OUT_OF_DATE_KHR);
+    let acquire = unsafe { swapchain.acquire(u64::MAX, vk::Semaphore::null(), vk::Fence::null()) };
+    assert_eq!(acquire, Ok(Acquire::OutOfDate));
+
+    driver.queue_result("vkAcquireNextImageKHR", vk::ErrorCode::SURFACE_LOST_KHR);
+    let acquire = unsafe { swapchain.acquire(u64::MAX, vk::Semaphore::null(), vk::Fence::null()) };
+    assert_eq!(acquire, Err(vk::ErrorCode::SURFACE_LOST_KHR));
+
+    // Recreate
+
+    let old = swapchain.handle();
+    let old_views = swapchain.image_views().to_vec();
+
+    let extent = vk::Extent2D {
+        width: 1024,
+        height: 768,
+    };
+
+    assert_eq!(unsafe { swapchain.recreate(extent) }, Ok(true));
+    assert_ne!(swapchain.handle(), old);
+    assert_eq!(swapchain.extent(), extent);
+
+    let calls = driver.calls_to("vkCreateSwapchainKHR");
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[1].args[3], old.as_raw());
+
+    let live = driver.live_objects();
+    assert!(!live.contains(&(vk::ObjectType::SWAPCHAIN_KHR, old.as_raw())));
+    for view in old_views {
+        assert!(!live.contains(&(vk::ObjectType::IMAGE_VIEW, view.as_raw())));
+    }
+
+    // Recreate (minimized)
+
+    let current = swapchain.handle();
+
+    let minimized = vk::Extent2D {
+        width: 0,
+        height: 0,
+    };
+
+    assert_eq!(unsafe { swapchain.recreate(minimized) }, Ok(false));
+    assert_eq!(swapchain.handle(), current);
+    assert_eq!(swapchain.extent(), extent);
+    assert_eq!(driver.calls_to("vkCreateSwapchainKHR").len(), 2);
+
+    // Drop
+
+    drop(swapchain);
+    let live = driver.live_objects();
+    assert!(
+        !live
+            .iter()
+            .any(|(t, _)| *t == vk::ObjectType::SWAPCHAIN_KHR)
+    );
+    assert!(!live.iter().any(|(t, _)| *t == vk::ObjectType::IMAGE_VIEW));
+
+    unsafe { instance.destroy_surface_khr(surface, None) };
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}
+
+#[test]
+fn test_swapchain_zero_extent() {
+    let mut physical_device = MockPhysicalDevice::default();
+    physical_device.surface_capabilities.current_extent = vk::Extent2D {
+        width: 0,
+        height: 0,
+    };
+
+    let (driver, instance, physical_device, device) = create_swapchain_device(physical_device);
+    let surface = unsafe { vk_window::create_headless_surface(&instance) }.unwrap();
+
+    let extent = vk::Extent2D {
+        width: 800,
+        height: 600,
+    };
+
+    let swapchain = unsafe {
+        Swapchain::new(
+            &instance,
+            &device,
+            physical_device,
+            surface,
+            extent,
+            SwapchainConfig::default(),
+        )
+    };
+
+    assert!(matches!(swapchain, Ok(None)));
+    assert!(driver.calls_to("vkCreateSwapchainKHR").is_empty());
+
+    unsafe { instance.destroy_surface_khr(surface, None) };
+    unsafe { device.destroy_device(None) };
+    unsafe { instance.destroy_instance(None) };
+}